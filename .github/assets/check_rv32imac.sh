@@ -15,12 +15,15 @@ crates_to_check=(
     reth-storage-errors
     reth-execution-errors
     reth-execution-types
+    "reth-execution-types --features serde-bincode-compat"
     reth-db-models
     reth-evm
+    "reth-evm --features serde"
     reth-storage-api
 
     ## ethereum
     reth-evm-ethereum
+    reth-ethereum-consensus
     reth-ethereum-forks
     reth-ethereum-primitives
 
@@ -2,16 +2,17 @@
 
 use crate::{
     args::LogArgs,
-    commands::debug_cmd,
+    commands::{debug_cmd, devnet},
     version::{LONG_VERSION, SHORT_VERSION},
 };
 use clap::{value_parser, Parser, Subcommand};
 use reth_chainspec::ChainSpec;
 use reth_cli::chainspec::ChainSpecParser;
 use reth_cli_commands::{
-    config_cmd, db, dump_genesis, import, init_cmd, init_state,
+    bench, blob, config_cmd, db, dump_genesis, export_prover_inputs, import, import_era, init_cmd,
+    init_state, keys,
     node::{self, NoArgs},
-    p2p, prune, recover, stage,
+    p2p, prune, recover, stage, static_files,
 };
 use reth_cli_runner::CliRunner;
 use reth_db::DatabaseEnv;
@@ -187,7 +188,7 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>, Ext: clap::Args + fmt::Debug> Cl
         let components = |spec: Arc<C::ChainSpec>| {
             (EthExecutorProvider::ethereum(spec.clone()), EthBeaconConsensus::new(spec))
         };
-        match self.command {
+        let result = match self.command {
             Commands::Node(command) => {
                 runner.run_command_until_exit(|ctx| command.execute(ctx, launcher))
             }
@@ -200,6 +201,12 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>, Ext: clap::Args + fmt::Debug> Cl
             Commands::Import(command) => {
                 runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode, _, _>(components))
             }
+            Commands::ImportEra(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode, _, _>(components))
+            }
+            Commands::ExportProverInputs(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode, _, _>(components))
+            }
             Commands::DumpGenesis(command) => runner.run_blocking_until_ctrl_c(command.execute()),
             Commands::Db(command) => {
                 runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode>())
@@ -213,14 +220,27 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>, Ext: clap::Args + fmt::Debug> Cl
             #[cfg(feature = "dev")]
             Commands::TestVectors(command) => runner.run_until_ctrl_c(command.execute()),
             Commands::Config(command) => runner.run_until_ctrl_c(command.execute()),
+            Commands::Keys(command) => runner.run_until_ctrl_c(command.execute()),
+            Commands::Blob(command) => runner.run_until_ctrl_c(command.execute()),
             Commands::Debug(command) => {
                 runner.run_command_until_exit(|ctx| command.execute::<EthereumNode>(ctx))
             }
+            Commands::Devnet(command) => runner.run_command_until_exit(|ctx| command.execute(ctx)),
             Commands::Recover(command) => {
                 runner.run_command_until_exit(|ctx| command.execute::<EthereumNode>(ctx))
             }
             Commands::Prune(command) => runner.run_until_ctrl_c(command.execute::<EthereumNode>()),
-        }
+            Commands::StaticFiles(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode>())
+            }
+            Commands::Bench(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<EthereumNode, _, _>(components))
+            }
+        };
+
+        // flush spans that haven't been exported yet
+        reth_tracing::shutdown_otlp();
+        result
     }
 
     /// Initializes tracing with the configured options.
@@ -249,6 +269,12 @@ pub enum Commands<C: ChainSpecParser, Ext: clap::Args + fmt::Debug> {
     /// This syncs RLP encoded blocks from a file.
     #[command(name = "import")]
     Import(import::ImportCommand<C>),
+    /// Syncs the chain from a directory of era1 files without networking.
+    #[command(name = "import-era")]
+    ImportEra(import_era::ImportEraCommand<C>),
+    /// Exports the inputs to prove the execution of a range of synced blocks.
+    #[command(name = "export-prover-inputs")]
+    ExportProverInputs(export_prover_inputs::ExportProverInputsCommand<C>),
     /// Dumps genesis block JSON configuration to stdout.
     DumpGenesis(dump_genesis::DumpGenesisCommand<C>),
     /// Database debugging utilities
@@ -264,18 +290,33 @@ pub enum Commands<C: ChainSpecParser, Ext: clap::Args + fmt::Debug> {
     #[cfg(feature = "dev")]
     #[command(name = "test-vectors")]
     TestVectors(reth_cli_commands::test_vectors::Command),
-    /// Write config to stdout
+    /// Write config to stdout or validate it
     #[command(name = "config")]
     Config(config_cmd::Command),
+    /// Manage the encrypted p2p secret key and JWT secret
+    #[command(name = "keys")]
+    Keys(keys::Command),
+    /// Read blob sidecars from a blob archive
+    #[command(name = "blob")]
+    Blob(blob::Command),
     /// Various debug routines
     #[command(name = "debug")]
     Debug(Box<debug_cmd::Command<C>>),
+    /// Launch a local devnet of multiple interconnected dev nodes
+    #[command(name = "devnet")]
+    Devnet(devnet::Command<C>),
     /// Scripts for node recovery
     #[command(name = "recover")]
     Recover(recover::Command<C>),
     /// Prune according to the configuration without any limits
     #[command(name = "prune")]
     Prune(prune::PruneCommand<C>),
+    /// Verify the integrity of the static files
+    #[command(name = "static-files")]
+    StaticFiles(static_files::Command<C>),
+    /// Benchmark the node on its local database
+    #[command(name = "bench")]
+    Bench(bench::Command<C>),
 }
 
 #[cfg(test)]
@@ -0,0 +1,148 @@
+//! Command for dumping and replaying stored engine API messages.
+use alloy_rpc_types::engine::{ExecutionPayload, ExecutionPayloadInputV2, JwtSecret};
+use clap::Parser;
+use eyre::Context;
+use jsonrpsee::http_client::HttpClientBuilder;
+use reth_engine_util::engine_store::{EngineMessageStore, StoredEngineApiMessage};
+use reth_fs_util as fs;
+use reth_node_ethereum::EthEngineTypes;
+use reth_rpc_api::EngineApiClient;
+use reth_rpc_layer::AuthClientLayer;
+use std::path::PathBuf;
+use tracing::*;
+
+/// `reth debug dump-engine-messages` command
+///
+/// Prints the engine API messages stored with `--debug.engine-api-store`, one JSON message per
+/// line, in the order they were received. The messages can also be replayed against the
+/// authenticated engine API of a running node.
+#[derive(Debug, Parser)]
+pub struct Command {
+    /// The path to the engine API messages stored with `--debug.engine-api-store`.
+    #[arg(long = "engine-api-store", value_name = "PATH")]
+    engine_api_store: PathBuf,
+
+    /// The URL of the authenticated engine API to replay the messages to, instead of printing
+    /// them.
+    #[arg(long, value_name = "URL", requires = "jwt_secret")]
+    replay: Option<String>,
+
+    /// The path to the JWT secret of the engine API to replay the messages to.
+    #[arg(long = "jwt-secret", value_name = "PATH")]
+    jwt_secret: Option<PathBuf>,
+}
+
+impl Command {
+    /// Execute `debug dump-engine-messages` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        let store = EngineMessageStore::new(self.engine_api_store);
+
+        let Some(url) = self.replay else {
+            for filepath in store.engine_messages_iter()? {
+                let contents = fs::read_to_string(&filepath)?;
+                // ensure that the file contains a valid message
+                serde_json::from_str::<StoredEngineApiMessage<EthEngineTypes>>(&contents)
+                    .wrap_err_with(|| format!("failed to parse {}", filepath.display()))?;
+                println!("{}", contents.trim());
+            }
+            return Ok(())
+        };
+
+        let jwt_secret = self.jwt_secret.expect("required by clap");
+        let secret = JwtSecret::from_file(&jwt_secret)
+            .wrap_err_with(|| format!("failed to read JWT secret from {}", jwt_secret.display()))?;
+        let middleware = tower::ServiceBuilder::default().layer(AuthClientLayer::new(secret));
+        let client = HttpClientBuilder::default().set_http_middleware(middleware).build(&url)?;
+
+        for filepath in store.engine_messages_iter()? {
+            let contents = fs::read(&filepath)?;
+            let message = serde_json::from_slice(&contents)
+                .wrap_err_with(|| format!("failed to parse {}", filepath.display()))?;
+            debug!(target: "reth::cli", filepath = %filepath.display(), ?message, "Replaying engine API message");
+            match message {
+                StoredEngineApiMessage::<EthEngineTypes>::ForkchoiceUpdated {
+                    state,
+                    payload_attrs,
+                } => {
+                    let response = match &payload_attrs {
+                        Some(attrs) if attrs.parent_beacon_block_root.is_some() => {
+                            EngineApiClient::<EthEngineTypes>::fork_choice_updated_v3(
+                                &client,
+                                state,
+                                payload_attrs,
+                            )
+                            .await?
+                        }
+                        Some(attrs) if attrs.withdrawals.is_some() => {
+                            EngineApiClient::<EthEngineTypes>::fork_choice_updated_v2(
+                                &client,
+                                state,
+                                payload_attrs,
+                            )
+                            .await?
+                        }
+                        _ => {
+                            EngineApiClient::<EthEngineTypes>::fork_choice_updated_v1(
+                                &client,
+                                state,
+                                payload_attrs,
+                            )
+                            .await?
+                        }
+                    };
+                    info!(target: "reth::cli", head = %state.head_block_hash, status = ?response.payload_status.status, "Replayed forkchoice update");
+                }
+                StoredEngineApiMessage::NewPayload { payload } => {
+                    let block_hash = payload.payload.block_hash();
+                    let sidecar = payload.sidecar;
+                    let status = match payload.payload {
+                        ExecutionPayload::V1(payload) => {
+                            EngineApiClient::<EthEngineTypes>::new_payload_v1(&client, payload)
+                                .await?
+                        }
+                        ExecutionPayload::V2(payload) => {
+                            EngineApiClient::<EthEngineTypes>::new_payload_v2(
+                                &client,
+                                ExecutionPayloadInputV2 {
+                                    execution_payload: payload.payload_inner,
+                                    withdrawals: Some(payload.withdrawals),
+                                },
+                            )
+                            .await?
+                        }
+                        ExecutionPayload::V3(payload) => {
+                            let versioned_hashes =
+                                sidecar.versioned_hashes().cloned().unwrap_or_default();
+                            let parent_beacon_block_root =
+                                sidecar.parent_beacon_block_root().unwrap_or_default();
+                            match sidecar.requests() {
+                                Some(requests) => {
+                                    EngineApiClient::<EthEngineTypes>::new_payload_v4(
+                                        &client,
+                                        payload,
+                                        versioned_hashes,
+                                        parent_beacon_block_root,
+                                        requests.clone(),
+                                    )
+                                    .await?
+                                }
+                                None => {
+                                    EngineApiClient::<EthEngineTypes>::new_payload_v3(
+                                        &client,
+                                        payload,
+                                        versioned_hashes,
+                                        parent_beacon_block_root,
+                                    )
+                                    .await?
+                                }
+                            }
+                        }
+                    };
+                    info!(target: "reth::cli", %block_hash, status = ?status.status, "Replayed new payload");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -76,9 +76,15 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
         default_peers_path: PathBuf,
     ) -> eyre::Result<NetworkHandle> {
         let secret_key = get_secret_key(&network_secret_path)?;
+        let network_config = self.network.network_config(
+            config,
+            provider_factory.chain_spec(),
+            secret_key,
+            default_peers_path,
+        );
         let network = self
             .network
-            .network_config(config, provider_factory.chain_spec(), secret_key, default_peers_path)
+            .apply_allowlist(network_config)?
             .with_task_executor(Box::new(task_executor))
             .build(provider_factory)
             .start_network()
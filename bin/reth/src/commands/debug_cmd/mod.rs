@@ -9,6 +9,7 @@ use reth_ethereum_primitives::EthPrimitives;
 use reth_node_ethereum::EthEngineTypes;
 
 mod build_block;
+mod dump_engine_messages;
 mod execution;
 mod in_memory_merkle;
 mod merkle;
@@ -31,6 +32,8 @@ pub enum Subcommands<C: ChainSpecParser> {
     InMemoryMerkle(in_memory_merkle::Command<C>),
     /// Debug block building.
     BuildBlock(build_block::Command<C>),
+    /// Dump or replay stored engine API messages.
+    DumpEngineMessages(dump_engine_messages::Command),
 }
 
 impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
@@ -46,6 +49,7 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
             Subcommands::Merkle(command) => command.execute::<N>(ctx).await,
             Subcommands::InMemoryMerkle(command) => command.execute::<N>(ctx).await,
             Subcommands::BuildBlock(command) => command.execute::<N>(ctx).await,
+            Subcommands::DumpEngineMessages(command) => command.execute().await,
         }
     }
 }
@@ -0,0 +1,439 @@
+//! Command for running a local devnet of multiple nodes.
+
+use alloy_consensus::BlockHeader;
+use alloy_eips::{eip4844::kzg_to_versioned_hash, eip7685::Requests};
+use alloy_primitives::B256;
+use alloy_rpc_types::{
+    engine::{BlobsBundleV1, ExecutionPayloadV3, ForkchoiceState, PayloadStatusEnum},
+    Block, Header,
+};
+use clap::{value_parser, Parser};
+use eyre::{bail, OptionExt, WrapErr};
+use futures::{future::select_all, Future};
+use jsonrpsee::{
+    core::client::ClientT,
+    http_client::{transport::HttpBackend, HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use reth_chainspec::{ChainSpec, EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_cli_commands::node::NodeCommand;
+use reth_cli_runner::CliContext;
+use reth_cli_util::get_secret_key;
+use reth_engine_local::LocalPayloadAttributesBuilder;
+use reth_fs_util as fs;
+use reth_network_peers::NodeRecord;
+use reth_node_core::{
+    args::{NetworkArgs, RpcServerArgs},
+    utils::get_or_create_jwt_secret_from_path,
+};
+use reth_node_ethereum::{EthEngineTypes, EthereumNode};
+use reth_payload_primitives::PayloadAttributesBuilder;
+use reth_rpc_api::EngineApiClient;
+use reth_rpc_layer::{AuthClientLayer, AuthClientService};
+use std::{
+    ffi::OsString,
+    net::{Ipv4Addr, SocketAddr},
+    path::{Path, PathBuf},
+    pin::Pin,
+    process::Stdio,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tracing::*;
+
+/// The authenticated engine API client of a devnet node.
+type AuthClient = HttpClient<AuthClientService<HttpBackend>>;
+
+/// A running devnet node, resolves when the node exits.
+type NodeFuture<'a> = Pin<Box<dyn Future<Output = eyre::Result<()>> + 'a>>;
+
+/// `reth devnet` command
+///
+/// Launches multiple dev nodes that share the genesis of `--chain` and are statically peered with
+/// each other. Blocks are produced by the nodes in turn: this command drives the authenticated
+/// engine API of all nodes, builds each block on the next node and sends it to all other nodes.
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    /// The chain the devnet is running.
+    ///
+    /// Possible values are either a built-in chain or the path to a chain specification file.
+    #[arg(
+        long,
+        value_name = "CHAIN_OR_PATH",
+        long_help = C::help_message(),
+        default_value = "dev",
+        value_parser = C::parser(),
+        required = false,
+    )]
+    chain: Arc<C::ChainSpec>,
+
+    /// The number of nodes to launch.
+    #[arg(long, value_name = "N", default_value_t = 2, value_parser = value_parser!(u16).range(1..=200))]
+    nodes: u16,
+
+    /// The directory to store the data of the nodes in, one `node-<i>` directory per node.
+    ///
+    /// Defaults to a temporary directory that is removed on exit.
+    #[arg(long, value_name = "PATH")]
+    datadir: Option<PathBuf>,
+
+    /// Interval between blocks.
+    ///
+    /// Parses strings using [`humantime::parse_duration`]
+    /// --block-time 12s
+    #[arg(
+        long,
+        value_name = "DURATION",
+        default_value = "2s",
+        value_parser = humantime::parse_duration,
+        verbatim_doc_comment
+    )]
+    block_time: Duration,
+
+    /// Launch all nodes in this process instead of as child processes.
+    #[arg(long)]
+    in_process: bool,
+
+    /// Additional arguments that are passed to `reth node` of every node, after `--`.
+    #[arg(last = true, value_name = "NODE_ARGS")]
+    node_args: Vec<OsString>,
+}
+
+/// The configuration of a single devnet node.
+#[derive(Debug)]
+struct DevnetNode {
+    /// The index of the node, which is also the `--instance` minus one.
+    index: usize,
+    /// The datadir of the node.
+    datadir: PathBuf,
+    /// The p2p record of the node.
+    record: NodeRecord,
+    /// The RPC arguments with the ports of the node.
+    rpc: RpcServerArgs,
+}
+
+impl DevnetNode {
+    const fn instance(&self) -> u16 {
+        self.index as u16 + 1
+    }
+
+    /// Returns the `reth node` arguments of this node.
+    fn args(&self, chain: &OsString, jwt_secret: &Path, peers: &[NodeRecord]) -> Vec<OsString> {
+        let mut args: Vec<OsString> = vec![
+            "--chain".into(),
+            chain.clone(),
+            "--datadir".into(),
+            self.datadir.clone().into(),
+            "--instance".into(),
+            self.instance().to_string().into(),
+            "--p2p-secret-key".into(),
+            self.datadir.join("p2p-secret").into(),
+            "--authrpc.jwtsecret".into(),
+            jwt_secret.into(),
+            "--disable-discovery".into(),
+            "--http".into(),
+            "--ws".into(),
+        ];
+        let peers = peers
+            .iter()
+            .filter(|peer| peer.id != self.record.id)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if !peers.is_empty() {
+            args.extend(["--trusted-peers".into(), peers.join(",").into()]);
+        }
+        args
+    }
+}
+
+impl<C: ChainSpecParser<ChainSpec = ChainSpec>> Command<C> {
+    /// Execute `devnet` command
+    pub async fn execute(self, ctx: CliContext) -> eyre::Result<()> {
+        if !self.chain.is_cancun_active_at_timestamp(self.chain.genesis_timestamp()) {
+            bail!("devnet requires a chain with Cancun active at genesis")
+        }
+
+        // the temporary datadir is removed when this is dropped, after all nodes have exited
+        let (root, _tempdir) = match &self.datadir {
+            Some(datadir) => (datadir.clone(), None),
+            None => {
+                let dir = tempfile::Builder::new()
+                    .prefix("reth-devnet-")
+                    .tempdir()
+                    .wrap_err("Could not create devnet datadir")?;
+                (dir.path().to_path_buf(), Some(dir))
+            }
+        };
+        fs::create_dir_all(&root)?;
+
+        let jwt_path = root.join("jwt.hex");
+        let secret = get_or_create_jwt_secret_from_path(&jwt_path)?;
+        let chain = self.chain_arg(&root)?;
+
+        let nodes = (0..self.nodes as usize)
+            .map(|index| {
+                let instance = index as u16 + 1;
+                let datadir = root.join(format!("node-{index}"));
+                fs::create_dir_all(&datadir)?;
+                let key = get_secret_key(&datadir.join("p2p-secret"))?;
+
+                let mut network = NetworkArgs::default();
+                network.adjust_instance_ports(instance);
+                let mut rpc = RpcServerArgs::default();
+                rpc.adjust_instance_ports(instance);
+
+                let record = NodeRecord::from_secret_key(
+                    SocketAddr::from((Ipv4Addr::LOCALHOST, network.port)),
+                    &key,
+                );
+                Ok(DevnetNode { index, datadir, record, rpc })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let peers = nodes.iter().map(|node| node.record).collect::<Vec<_>>();
+
+        info!(target: "reth::cli", path = %root.display(), nodes = nodes.len(), "Launching devnet");
+        let mut running: Vec<NodeFuture<'_>> = Vec::with_capacity(nodes.len());
+        for node in &nodes {
+            let mut args = node.args(&chain, &jwt_path, &peers);
+            args.extend(self.node_args.iter().cloned());
+            if self.in_process {
+                running.push(Box::pin(launch_in_process::<C>(node.index, args, &ctx)));
+            } else {
+                running.push(Box::pin(launch_process(node, args)?));
+            }
+
+            println!(
+                "node-{}: http://127.0.0.1:{} ws://127.0.0.1:{} authrpc http://127.0.0.1:{} {}",
+                node.index, node.rpc.http_port, node.rpc.ws_port, node.rpc.auth_port, node.record
+            );
+        }
+
+        let clients = nodes
+            .iter()
+            .map(|node| {
+                let middleware =
+                    tower::ServiceBuilder::default().layer(AuthClientLayer::new(secret));
+                HttpClientBuilder::default()
+                    .set_http_middleware(middleware)
+                    .build(format!("http://127.0.0.1:{}", node.rpc.auth_port))
+                    .map_err(Into::into)
+            })
+            .collect::<eyre::Result<Vec<AuthClient>>>()?;
+
+        tokio::select! {
+            (res, index, _) = select_all(running) => {
+                res.wrap_err_with(|| format!("node-{index} failed"))?;
+                bail!("node-{index} exited")
+            }
+            res = self.produce_blocks(&clients) => res,
+        }
+    }
+
+    /// Returns the `--chain` argument of the nodes.
+    ///
+    /// This is the name of the chain if it's a built-in chain, otherwise the genesis is written to
+    /// the devnet datadir.
+    fn chain_arg(&self, root: &Path) -> eyre::Result<OsString> {
+        let genesis_hash = self.chain.genesis_hash();
+        if let Some(name) = C::SUPPORTED_CHAINS
+            .iter()
+            .find(|name| C::parse(name).is_ok_and(|spec| spec.genesis_hash() == genesis_hash))
+        {
+            return Ok(name.into())
+        }
+
+        let path = root.join("genesis.json");
+        fs::write_json_file(&path, self.chain.genesis())?;
+        Ok(path.into())
+    }
+
+    /// Produces blocks on the nodes in turn, every `--block-time`.
+    async fn produce_blocks(&self, clients: &[AuthClient]) -> eyre::Result<()> {
+        let mut head = wait_for_node(&clients[0]).await?;
+        for client in &clients[1..] {
+            wait_for_node(client).await?;
+        }
+        info!(target: "reth::cli", number = head.number(), hash = %head.hash, "Devnet is ready");
+
+        let attributes_builder = LocalPayloadAttributesBuilder::new(self.chain.clone());
+        let mut interval = tokio::time::interval(self.block_time);
+        loop {
+            interval.tick().await;
+
+            let number = head.number() + 1;
+            let producer = number as usize % clients.len();
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+            let attributes = attributes_builder.build(now.max(head.timestamp() + 1));
+            let timestamp = attributes.timestamp;
+            let parent_beacon_block_root =
+                attributes.parent_beacon_block_root.ok_or_eyre("missing parent beacon root")?;
+
+            let state = ForkchoiceState {
+                head_block_hash: head.hash,
+                safe_block_hash: head.hash,
+                finalized_block_hash: head.hash,
+            };
+            let client = &clients[producer];
+            let response = EngineApiClient::<EthEngineTypes>::fork_choice_updated_v3(
+                client,
+                state,
+                Some(attributes),
+            )
+            .await?;
+            let Some(payload_id) = response.payload_id else {
+                warn!(target: "reth::cli", producer, status = ?response.payload_status.status, "Node did not start building a block");
+                continue
+            };
+
+            let (payload, versioned_hashes, requests) = if self
+                .chain
+                .is_prague_active_at_timestamp(timestamp)
+            {
+                let envelope =
+                    EngineApiClient::<EthEngineTypes>::get_payload_v4(client, payload_id).await?;
+                let versioned_hashes = versioned_hashes(&envelope.envelope_inner.blobs_bundle);
+                (
+                    envelope.envelope_inner.execution_payload,
+                    versioned_hashes,
+                    Some(envelope.execution_requests),
+                )
+            } else {
+                let envelope =
+                    EngineApiClient::<EthEngineTypes>::get_payload_v3(client, payload_id).await?;
+                let versioned_hashes = versioned_hashes(&envelope.blobs_bundle);
+                (envelope.execution_payload, versioned_hashes, None)
+            };
+
+            let block_hash = payload.payload_inner.payload_inner.block_hash;
+            let transactions = payload.payload_inner.payload_inner.transactions.len();
+            let state = ForkchoiceState {
+                head_block_hash: block_hash,
+                safe_block_hash: block_hash,
+                finalized_block_hash: block_hash,
+            };
+            for (index, client) in clients.iter().enumerate() {
+                let status = new_payload(
+                    client,
+                    payload.clone(),
+                    versioned_hashes.clone(),
+                    parent_beacon_block_root,
+                    requests.clone(),
+                )
+                .await?;
+                if !matches!(status, PayloadStatusEnum::Valid) {
+                    warn!(target: "reth::cli", node = index, %block_hash, ?status, "Node did not accept block");
+                }
+                EngineApiClient::<EthEngineTypes>::fork_choice_updated_v3(client, state, None)
+                    .await?;
+            }
+            info!(target: "reth::cli", number, hash = %block_hash, producer, transactions, "Produced block");
+
+            head = latest_block(&clients[producer]).await?;
+        }
+    }
+}
+
+/// Launches a node as a child process of this process.
+///
+/// The output of the node is written to `node.log` in its datadir.
+fn launch_process(
+    node: &DevnetNode,
+    args: Vec<OsString>,
+) -> eyre::Result<impl Future<Output = eyre::Result<()>>> {
+    let log = fs::create_file(node.datadir.join("node.log"))?;
+    let mut child = tokio::process::Command::new(std::env::current_exe()?)
+        .arg("node")
+        .args(args)
+        // the output is already captured in `node.log`
+        .args(["--log.file.max-files", "0"])
+        .stdout(Stdio::from(log.try_clone()?))
+        .stderr(Stdio::from(log))
+        .kill_on_drop(true)
+        .spawn()
+        .wrap_err_with(|| format!("Could not launch node-{}", node.index))?;
+
+    Ok(async move {
+        let status = child.wait().await?;
+        bail!("node exited with {status}")
+    })
+}
+
+/// Launches a node in this process.
+async fn launch_in_process<C: ChainSpecParser<ChainSpec = ChainSpec>>(
+    index: usize,
+    args: Vec<OsString>,
+    ctx: &CliContext,
+) -> eyre::Result<()> {
+    let command = NodeCommand::<C>::try_parse_args_from(
+        std::iter::once(OsString::from(format!("node-{index}"))).chain(args),
+    )?;
+    let ctx = CliContext { task_executor: ctx.task_executor.clone() };
+    command
+        .execute(ctx, async move |builder, _| {
+            let handle = builder.launch_node(EthereumNode::default()).await?;
+            handle.node_exit_future.await
+        })
+        .await
+}
+
+/// Waits until the engine API of the node is reachable and returns its latest block.
+async fn wait_for_node(client: &AuthClient) -> eyre::Result<Header> {
+    loop {
+        match latest_block(client).await {
+            Ok(header) => return Ok(header),
+            Err(err) => {
+                trace!(target: "reth::cli", %err, "Waiting for node");
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+        }
+    }
+}
+
+/// Returns the header of the latest block of the node.
+async fn latest_block(client: &AuthClient) -> eyre::Result<Header> {
+    let block: Option<Block> =
+        client.request("eth_getBlockByNumber", rpc_params!["latest", false]).await?;
+    Ok(block.ok_or_eyre("missing latest block")?.header)
+}
+
+/// Sends a new payload to the node with the engine API version of the payload.
+async fn new_payload(
+    client: &AuthClient,
+    payload: ExecutionPayloadV3,
+    versioned_hashes: Vec<B256>,
+    parent_beacon_block_root: B256,
+    requests: Option<Requests>,
+) -> eyre::Result<PayloadStatusEnum> {
+    let status = match requests {
+        Some(requests) => {
+            EngineApiClient::<EthEngineTypes>::new_payload_v4(
+                client,
+                payload,
+                versioned_hashes,
+                parent_beacon_block_root,
+                requests,
+            )
+            .await?
+        }
+        None => {
+            EngineApiClient::<EthEngineTypes>::new_payload_v3(
+                client,
+                payload,
+                versioned_hashes,
+                parent_beacon_block_root,
+            )
+            .await?
+        }
+    };
+    Ok(status.status)
+}
+
+/// Returns the versioned hashes of the blobs in the bundle.
+fn versioned_hashes(bundle: &BlobsBundleV1) -> Vec<B256> {
+    bundle
+        .commitments
+        .iter()
+        .map(|commitment| kzg_to_versioned_hash(commitment.as_slice()))
+        .collect()
+}
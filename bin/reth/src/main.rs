@@ -3,13 +3,30 @@
 #[global_allocator]
 static ALLOC: reth_cli_util::allocator::Allocator = reth_cli_util::allocator::new_allocator();
 
-use clap::Parser;
-use reth::{args::RessArgs, cli::Cli, ress::install_ress_subprotocol};
+use clap::{Args, Parser};
+use reth::{
+    args::{RessArgs, WasmPluginArgs},
+    cli::Cli,
+    ress::install_ress_subprotocol,
+};
 use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
-use reth_node_builder::NodeHandle;
-use reth_node_ethereum::EthereumNode;
+use reth_ethereum_payload_builder::{EthereumBuilderSettings, Preconfirmations};
+use reth_node_builder::{components::BasicPayloadServiceBuilder, NodeHandle, NoopPoolBuilder};
+use reth_node_ethereum::{
+    node::{EthereumAddOns, EthereumPayloadBuilder},
+    EthereumNode,
+};
 use tracing::info;
 
+/// Additional arguments of the `node` command.
+#[derive(Debug, Clone, Args)]
+struct ExtArgs {
+    #[command(flatten)]
+    ress: RessArgs,
+    #[command(flatten)]
+    wasm: WasmPluginArgs,
+}
+
 fn main() {
     reth_cli_util::sigsegv_handler::install();
 
@@ -19,24 +36,72 @@ fn main() {
     }
 
     if let Err(err) =
-        Cli::<EthereumChainSpecParser, RessArgs>::parse().run(async move |builder, ress_args| {
-            info!(target: "reth::cli", "Launching node");
-            let NodeHandle { node, node_exit_future } =
-                builder.node(EthereumNode::default()).launch_with_debug_capabilities().await?;
-
-            // Install ress subprotocol.
-            if ress_args.enabled {
-                install_ress_subprotocol(
-                    ress_args,
-                    node.provider,
-                    node.block_executor,
-                    node.network,
-                    node.task_executor,
-                    node.add_ons_handle.engine_events.new_listener(),
-                )?;
+        Cli::<EthereumChainSpecParser, ExtArgs>::parse().run(async move |builder, ext_args| {
+            let ExtArgs { ress: ress_args, wasm: wasm_args } = ext_args;
+
+            /// Installs the plugins and ress on the configured node builder and launches it.
+            macro_rules! launch_node {
+                ($builder:expr) => {{
+                    let builder = $builder;
+
+                    #[cfg(feature = "wasm-plugins")]
+                    let builder = builder.install_exex_if(
+                        !wasm_args.plugins.is_empty(),
+                        "wasm-plugins",
+                        move |ctx| async move {
+                            Ok(reth_exex::wasm::WasmExEx::load(ctx, wasm_args.plugins)?.run())
+                        },
+                    );
+                    #[cfg(not(feature = "wasm-plugins"))]
+                    if !wasm_args.plugins.is_empty() {
+                        eyre::bail!(
+                            "WASM plugins require reth to be built with the `wasm-plugins` feature"
+                        );
+                    }
+
+                    let NodeHandle { node, node_exit_future } =
+                        builder.launch_with_debug_capabilities().await?;
+
+                    // Install ress subprotocol.
+                    if ress_args.enabled {
+                        install_ress_subprotocol(
+                            ress_args,
+                            node.provider,
+                            node.block_executor,
+                            node.network,
+                            node.task_executor,
+                            node.add_ons_handle.engine_events.new_listener(),
+                        )?;
+                    }
+
+                    node_exit_future.await
+                }};
             }
 
-            node_exit_future.await
+            info!(target: "reth::cli", "Launching node");
+            // the payload builder shares its settings with the `admin` RPC namespace and its
+            // preconfirmations with the `reth_preconfirmTransaction` endpoint
+            let builder_settings = EthereumBuilderSettings::default();
+            let preconfirmations = Preconfirmations::default();
+            let components = EthereumNode::components().payload(BasicPayloadServiceBuilder::new(
+                EthereumPayloadBuilder::default()
+                    .with_settings(builder_settings.clone())
+                    .with_preconfirmations(preconfirmations.clone()),
+            ));
+            let add_ons = EthereumAddOns::default()
+                .with_builder_settings(builder_settings)
+                .with_preconfirmations(preconfirmations);
+
+            let txpool_disabled = builder.config().txpool.disabled;
+            let builder = builder.with_types::<EthereumNode>();
+            if txpool_disabled {
+                // the node runs with the regular components, except for the pool
+                launch_node!(builder
+                    .with_components(components.pool(NoopPoolBuilder::default()))
+                    .with_add_ons(add_ons))
+            } else {
+                launch_node!(builder.with_components(components).with_add_ons(add_ons))
+            }
         })
     {
         eprintln!("Error: {err:?}");
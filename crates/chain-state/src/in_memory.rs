@@ -2,7 +2,7 @@
 
 use crate::{
     CanonStateNotification, CanonStateNotificationSender, CanonStateNotifications,
-    ChainInfoTracker, MemoryOverlayStateProvider,
+    CanonStateSubscriptionOptions, ChainInfoTracker, MemoryOverlayStateProvider,
 };
 use alloy_consensus::{transaction::TransactionMeta, BlockHeader};
 use alloy_eips::{eip2718::Encodable2718, BlockHashOrNumber, BlockNumHash};
@@ -139,6 +139,9 @@ pub(crate) struct CanonicalInMemoryStateInner<N: NodePrimitives> {
     pub(crate) in_memory_state: InMemoryState<N>,
     /// A broadcast stream that emits events when the canonical chain is updated.
     pub(crate) canon_state_notification_sender: CanonStateNotificationSender<N>,
+    /// A broadcast stream that emits the same events with the trie updates of the committed
+    /// blocks attached, for subscribers that requested them.
+    pub(crate) canon_state_with_trie_updates_sender: CanonStateNotificationSender<N>,
 }
 
 impl<N: NodePrimitives> CanonicalInMemoryStateInner<N> {
@@ -186,12 +189,15 @@ impl<N: NodePrimitives> CanonicalInMemoryState<N> {
         let chain_info_tracker = ChainInfoTracker::new(header, finalized, safe);
         let (canon_state_notification_sender, _) =
             broadcast::channel(CANON_STATE_NOTIFICATION_CHANNEL_SIZE);
+        let (canon_state_with_trie_updates_sender, _) =
+            broadcast::channel(CANON_STATE_NOTIFICATION_CHANNEL_SIZE);
 
         Self {
             inner: Arc::new(CanonicalInMemoryStateInner {
                 chain_info_tracker,
                 in_memory_state,
                 canon_state_notification_sender,
+                canon_state_with_trie_updates_sender,
             }),
         }
     }
@@ -212,10 +218,13 @@ impl<N: NodePrimitives> CanonicalInMemoryState<N> {
         let in_memory_state = InMemoryState::default();
         let (canon_state_notification_sender, _) =
             broadcast::channel(CANON_STATE_NOTIFICATION_CHANNEL_SIZE);
+        let (canon_state_with_trie_updates_sender, _) =
+            broadcast::channel(CANON_STATE_NOTIFICATION_CHANNEL_SIZE);
         let inner = CanonicalInMemoryStateInner {
             chain_info_tracker,
             in_memory_state,
             canon_state_notification_sender,
+            canon_state_with_trie_updates_sender,
         };
 
         Self { inner: Arc::new(inner) }
@@ -501,6 +510,27 @@ impl<N: NodePrimitives> CanonicalInMemoryState<N> {
         self.inner.canon_state_notification_sender.subscribe()
     }
 
+    /// Subscribe to new blocks events with the given [`CanonStateSubscriptionOptions`].
+    pub fn subscribe_canon_state_with(
+        &self,
+        options: CanonStateSubscriptionOptions,
+    ) -> CanonStateNotifications<N> {
+        if options.trie_updates {
+            self.inner.canon_state_with_trie_updates_sender.subscribe()
+        } else {
+            self.subscribe_canon_state()
+        }
+    }
+
+    /// Returns `true` if there are active subscribers that requested the trie updates of the
+    /// committed blocks.
+    ///
+    /// If so, the new chain should be sent with
+    /// [`notify_canon_state_with_trie_updates`](Self::notify_canon_state_with_trie_updates).
+    pub fn has_trie_updates_subscribers(&self) -> bool {
+        self.inner.canon_state_with_trie_updates_sender.receiver_count() > 0
+    }
+
     /// Subscribe to new safe block events.
     pub fn subscribe_safe_block(&self) -> watch::Receiver<Option<SealedHeader<N::BlockHeader>>> {
         self.inner.chain_info_tracker.subscribe_safe_block()
@@ -518,6 +548,12 @@ impl<N: NodePrimitives> CanonicalInMemoryState<N> {
         self.inner.canon_state_notification_sender.send(event).ok();
     }
 
+    /// Attempts to send a new [`CanonStateNotification`] with the trie updates of the committed
+    /// blocks attached to all active Receiver handles that requested them.
+    pub fn notify_canon_state_with_trie_updates(&self, event: CanonStateNotification<N>) {
+        self.inner.canon_state_with_trie_updates_sender.send(event).ok();
+    }
+
     /// Return state provider with reference to in-memory blocks that overlay database state.
     ///
     /// This merges the state of all blocks that are part of the chain that the requested block is
@@ -897,30 +933,43 @@ impl<N: NodePrimitives<SignedTx: SignedTransaction>> NewCanonicalChain<N> {
 
     /// Converts the new chain into a notification that will be emitted to listeners
     pub fn to_chain_notification(&self) -> CanonStateNotification<N> {
-        match self {
-            Self::Commit { new } => {
-                let new = Arc::new(new.iter().fold(Chain::default(), |mut chain, exec| {
-                    chain.append_block(
-                        exec.recovered_block().clone(),
-                        exec.execution_outcome().clone(),
+        self.chain_notification(false)
+    }
+
+    /// Converts the new chain into a notification with the trie updates of the committed blocks
+    /// attached, that will be emitted to listeners that requested them.
+    pub fn to_chain_notification_with_trie_updates(&self) -> CanonStateNotification<N> {
+        self.chain_notification(true)
+    }
+
+    /// Converts the new chain into a notification, optionally with the trie updates of the
+    /// committed blocks attached.
+    fn chain_notification(&self, with_trie_updates: bool) -> CanonStateNotification<N> {
+        let committed = |new: &[ExecutedBlockWithTrieUpdates<N>]| {
+            Arc::new(new.iter().fold(Chain::default(), |mut chain, exec| {
+                chain
+                    .append_block(exec.recovered_block().clone(), exec.execution_outcome().clone())
+                    .expect("executed blocks of the canonical chain are consecutive");
+                if with_trie_updates {
+                    chain.set_block_trie_updates(
+                        exec.recovered_block().header().number(),
+                        exec.trie.clone(),
                     );
-                    chain
-                }));
-                CanonStateNotification::Commit { new }
-            }
+                }
+                chain
+            }))
+        };
+        match self {
+            Self::Commit { new } => CanonStateNotification::Commit { new: committed(new) },
             Self::Reorg { new, old } => {
-                let new = Arc::new(new.iter().fold(Chain::default(), |mut chain, exec| {
-                    chain.append_block(
-                        exec.recovered_block().clone(),
-                        exec.execution_outcome().clone(),
-                    );
-                    chain
-                }));
+                let new = committed(new);
                 let old = Arc::new(old.iter().fold(Chain::default(), |mut chain, exec| {
-                    chain.append_block(
-                        exec.recovered_block().clone(),
-                        exec.execution_outcome().clone(),
-                    );
+                    chain
+                        .append_block(
+                            exec.recovered_block().clone(),
+                            exec.execution_outcome().clone(),
+                        )
+                        .expect("executed blocks of the canonical chain are consecutive");
                     chain
                 }));
                 CanonStateNotification::Reorg { new, old }
@@ -1562,20 +1611,56 @@ mod tests {
             old: vec![block1.block.clone(), block2.block.clone()],
         };
 
+        // the execution outcomes of the reorged chains start at their first block, not at genesis
         assert_eq!(
             chain_reorg.to_chain_notification(),
             CanonStateNotification::Reorg {
                 old: Arc::new(Chain::new(
                     vec![block1.recovered_block().clone(), block2.recovered_block().clone()],
-                    sample_execution_outcome.clone(),
+                    ExecutionOutcome { first_block: 1, ..sample_execution_outcome.clone() },
                     None
                 )),
                 new: Arc::new(Chain::new(
                     vec![block1a.recovered_block().clone(), block2a.recovered_block().clone()],
-                    sample_execution_outcome,
+                    ExecutionOutcome { first_block: 1, ..sample_execution_outcome },
                     None
                 ))
             }
         );
     }
+
+    #[test]
+    fn test_to_chain_notification_with_trie_updates() {
+        let mut test_block_builder: TestBlockBuilder = TestBlockBuilder::default();
+        let block0 = test_block_builder.get_executed_block_with_number(0, B256::random());
+        let block1 =
+            test_block_builder.get_executed_block_with_number(1, block0.recovered_block.hash());
+
+        let chain_commit = NewCanonicalChain::Commit { new: vec![block0.clone(), block1.clone()] };
+
+        // the trie updates are only attached if requested
+        assert!(chain_commit.to_chain_notification().committed().blocks_trie_updates().is_empty());
+
+        let committed = chain_commit.to_chain_notification_with_trie_updates().committed();
+        assert_eq!(committed.blocks_trie_updates().len(), 2);
+        assert_eq!(committed.block_trie_updates(0), Some(&block0.trie));
+        assert_eq!(committed.block_trie_updates(1), Some(&block1.trie));
+    }
+
+    #[test]
+    fn test_subscribe_canon_state_with_trie_updates() {
+        let state: CanonicalInMemoryState = CanonicalInMemoryState::empty();
+        assert!(!state.has_trie_updates_subscribers());
+
+        let _rx = state.subscribe_canon_state();
+        assert!(!state.has_trie_updates_subscribers());
+
+        let rx = state.subscribe_canon_state_with(
+            CanonStateSubscriptionOptions::default().with_trie_updates(),
+        );
+        assert!(state.has_trie_updates_subscribers());
+
+        drop(rx);
+        assert!(!state.has_trie_updates_subscribers());
+    }
 }
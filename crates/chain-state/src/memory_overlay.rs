@@ -228,6 +228,53 @@ impl<N: NodePrimitives> StateProvider for MemoryOverlayStateProviderRef<'_, N> {
         self.historical.storage(address, storage_key)
     }
 
+    fn storage_multi(
+        &self,
+        address: Address,
+        storage_keys: &[StorageKey],
+    ) -> ProviderResult<Vec<Option<StorageValue>>> {
+        let values = storage_keys
+            .iter()
+            .map(|key| {
+                self.in_memory
+                    .iter()
+                    .find_map(|block| block.execution_output.storage(&address, (*key).into()))
+            })
+            .collect::<Vec<_>>();
+
+        // only look up the slots that were not changed by any in-memory block
+        let missing = storage_keys
+            .iter()
+            .zip(&values)
+            .filter_map(|(key, value)| value.is_none().then_some(*key))
+            .collect::<Vec<_>>();
+        let mut historical = self.historical.storage_multi(address, &missing)?.into_iter();
+
+        Ok(values.into_iter().map(|value| value.or_else(|| historical.next().flatten())).collect())
+    }
+
+    fn basic_accounts(&self, addresses: &[Address]) -> ProviderResult<Vec<Option<Account>>> {
+        let accounts = addresses
+            .iter()
+            .map(|address| {
+                self.in_memory.iter().find_map(|block| block.execution_output.account(address))
+            })
+            .collect::<Vec<_>>();
+
+        // only look up the accounts that were not changed by any in-memory block
+        let missing = addresses
+            .iter()
+            .zip(&accounts)
+            .filter_map(|(address, account)| account.is_none().then_some(*address))
+            .collect::<Vec<_>>();
+        let mut historical = self.historical.basic_accounts(&missing)?.into_iter();
+
+        Ok(accounts
+            .into_iter()
+            .map(|account| account.unwrap_or_else(|| historical.next().flatten()))
+            .collect())
+    }
+
     fn bytecode_by_hash(&self, code_hash: &B256) -> ProviderResult<Option<Bytecode>> {
         for block in &self.in_memory {
             if let Some(contract) = block.execution_output.bytecode(code_hash) {
@@ -32,12 +32,35 @@ pub trait CanonStateSubscriptions: NodePrimitivesProvider + Send + Sync {
     /// A canonical chain be one or more blocks, a reorg or a revert.
     fn subscribe_to_canonical_state(&self) -> CanonStateNotifications<Self::Primitives>;
 
+    /// Get notified when a new canonical chain was imported, with the given
+    /// [`CanonStateSubscriptionOptions`].
+    ///
+    /// Implementations that don't support the options return the regular subscription.
+    fn subscribe_to_canonical_state_with(
+        &self,
+        options: CanonStateSubscriptionOptions,
+    ) -> CanonStateNotifications<Self::Primitives> {
+        let _ = options;
+        self.subscribe_to_canonical_state()
+    }
+
     /// Convenience method to get a stream of [`CanonStateNotification`].
     fn canonical_state_stream(&self) -> CanonStateNotificationStream<Self::Primitives> {
         CanonStateNotificationStream {
             st: BroadcastStream::new(self.subscribe_to_canonical_state()),
         }
     }
+
+    /// Convenience method to get a stream of [`CanonStateNotification`] with the given
+    /// [`CanonStateSubscriptionOptions`].
+    fn canonical_state_stream_with(
+        &self,
+        options: CanonStateSubscriptionOptions,
+    ) -> CanonStateNotificationStream<Self::Primitives> {
+        CanonStateNotificationStream {
+            st: BroadcastStream::new(self.subscribe_to_canonical_state_with(options)),
+        }
+    }
 }
 
 impl<T: CanonStateSubscriptions> CanonStateSubscriptions for &T {
@@ -45,9 +68,43 @@ impl<T: CanonStateSubscriptions> CanonStateSubscriptions for &T {
         (*self).subscribe_to_canonical_state()
     }
 
+    fn subscribe_to_canonical_state_with(
+        &self,
+        options: CanonStateSubscriptionOptions,
+    ) -> CanonStateNotifications<Self::Primitives> {
+        (*self).subscribe_to_canonical_state_with(options)
+    }
+
     fn canonical_state_stream(&self) -> CanonStateNotificationStream<Self::Primitives> {
         (*self).canonical_state_stream()
     }
+
+    fn canonical_state_stream_with(
+        &self,
+        options: CanonStateSubscriptionOptions,
+    ) -> CanonStateNotificationStream<Self::Primitives> {
+        (*self).canonical_state_stream_with(options)
+    }
+}
+
+/// Options of a subscription to [`CanonStateNotification`]s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CanonStateSubscriptionOptions {
+    /// Whether the trie updates of the committed blocks are attached to the notifications, see
+    /// [`Chain::block_trie_updates`].
+    pub trie_updates: bool,
+}
+
+impl CanonStateSubscriptionOptions {
+    /// Attaches the trie updates of the committed blocks to the notifications.
+    ///
+    /// This avoids recomputing the trie changes of the blocks, but keeps them in memory until
+    /// all subscribers have received the notification, so it should only be requested if they're
+    /// needed.
+    pub const fn with_trie_updates(mut self) -> Self {
+        self.trie_updates = true;
+        self
+    }
 }
 
 /// A Stream of [`CanonStateNotification`].
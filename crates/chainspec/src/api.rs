@@ -1,4 +1,4 @@
-use crate::{ChainSpec, DepositContract};
+use crate::{ChainSpec, DepositContract, ExecutionFeatures, DEFAULT_MAX_REORG_DEPTH};
 use alloc::{boxed::Box, vec::Vec};
 use alloy_chains::Chain;
 use alloy_consensus::Header;
@@ -65,6 +65,17 @@ pub trait EthChainSpec: Send + Sync + Unpin + Debug {
 
     /// Returns the final total difficulty if the Paris hardfork is known.
     fn final_paris_total_difficulty(&self) -> Option<U256>;
+
+    /// Returns the mainnet-specific system calls and header fields that are enabled on this chain.
+    fn execution_features(&self) -> ExecutionFeatures {
+        ExecutionFeatures::default()
+    }
+
+    /// Returns the number of blocks below the tip after which a block is assumed to be final and
+    /// can no longer be reorged out.
+    fn max_reorg_depth(&self) -> u64 {
+        DEFAULT_MAX_REORG_DEPTH
+    }
 }
 
 impl EthChainSpec for ChainSpec {
@@ -127,4 +138,8 @@ impl EthChainSpec for ChainSpec {
     fn final_paris_total_difficulty(&self) -> Option<U256> {
         self.paris_block_and_final_difficulty.map(|(_, final_difficulty)| final_difficulty)
     }
+
+    fn execution_features(&self) -> ExecutionFeatures {
+        self.execution_features
+    }
 }
@@ -8,6 +8,11 @@ pub const MIN_TRANSACTION_GAS: u64 = 21_000u64;
 /// Mainnet prune delete limit.
 pub const MAINNET_PRUNE_DELETE_LIMIT: usize = 20000;
 
+/// Default number of blocks below the tip after which a block is assumed to be final.
+///
+/// This is the length of the two epochs it takes the beacon chain to finalize a block.
+pub const DEFAULT_MAX_REORG_DEPTH: u64 = 64;
+
 /// Deposit contract address: `0x00000000219ab540356cbb839cbe05303d7705fa`
 pub(crate) const MAINNET_DEPOSIT_CONTRACT: DepositContract = DepositContract::new(
     MAINNET_DEPOSIT_CONTRACT_ADDRESS,
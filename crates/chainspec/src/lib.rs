@@ -31,8 +31,8 @@ pub use info::ChainInfo;
 pub use spec::test_fork_ids;
 pub use spec::{
     make_genesis_header, BaseFeeParams, BaseFeeParamsKind, ChainSpec, ChainSpecBuilder,
-    ChainSpecProvider, DepositContract, ForkBaseFeeParams, HardforkBlobParams, DEV, HOLESKY,
-    MAINNET, SEPOLIA,
+    ChainSpecBuilderError, ChainSpecProvider, DepositContract, ExecutionFeatures,
+    ForkBaseFeeParams, HardforkBlobParams, DEV, HOLESKY, MAINNET, SEPOLIA,
 };
 
 use reth_primitives_traits::sync::OnceLock;
@@ -5,7 +5,7 @@ use crate::{
     constants::{MAINNET_DEPOSIT_CONTRACT, MAINNET_PRUNE_DELETE_LIMIT},
     EthChainSpec,
 };
-use alloc::{boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec, vec::Vec};
 use alloy_chains::{Chain, NamedChain};
 use alloy_consensus::{
     constants::{
@@ -18,8 +18,8 @@ use alloy_eips::{
     eip1559::INITIAL_BASE_FEE, eip6110::MAINNET_DEPOSIT_CONTRACT_ADDRESS,
     eip7685::EMPTY_REQUESTS_HASH, eip7840::BlobParams,
 };
-use alloy_genesis::Genesis;
-use alloy_primitives::{address, b256, Address, BlockNumber, B256, U256};
+use alloy_genesis::{Genesis, GenesisAccount};
+use alloy_primitives::{address, b256, Address, BlockNumber, Bytes, B256, U256};
 use alloy_trie::root::state_root_ref_unhashed;
 use derive_more::From;
 use reth_ethereum_forks::{
@@ -111,6 +111,7 @@ pub static MAINNET: LazyLock<Arc<ChainSpec>> = LazyLock::new(|| {
         base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
         prune_delete_limit: MAINNET_PRUNE_DELETE_LIMIT,
         blob_params: HardforkBlobParams::default(),
+        execution_features: ExecutionFeatures::default(),
     };
     spec.genesis.config.dao_fork_support = true;
     spec.into()
@@ -140,6 +141,7 @@ pub static SEPOLIA: LazyLock<Arc<ChainSpec>> = LazyLock::new(|| {
         base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
         prune_delete_limit: 10000,
         blob_params: HardforkBlobParams::default(),
+        execution_features: ExecutionFeatures::default(),
     };
     spec.genesis.config.dao_fork_support = true;
     spec.into()
@@ -167,6 +169,7 @@ pub static HOLESKY: LazyLock<Arc<ChainSpec>> = LazyLock::new(|| {
         base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
         prune_delete_limit: 10000,
         blob_params: HardforkBlobParams::default(),
+        execution_features: ExecutionFeatures::default(),
     };
     spec.genesis.config.dao_fork_support = true;
     spec.into()
@@ -213,6 +216,60 @@ impl Default for BaseFeeParamsKind {
     }
 }
 
+impl BaseFeeParamsKind {
+    /// Reads the EIP-1559 parameters per hardfork from the `baseFeeSchedule` of the genesis
+    /// config, which maps hardfork names to their parameters:
+    ///
+    /// ```json
+    /// "baseFeeSchedule": {
+    ///     "london": { "maxChangeDenominator": 8, "elasticityMultiplier": 2 },
+    ///     "prague": { "maxChangeDenominator": 16, "elasticityMultiplier": 4 }
+    /// }
+    /// ```
+    ///
+    /// Only hardforks that are activated in `hardforks` are considered. The ethereum parameters
+    /// apply from London until the first scheduled hardfork, unless London itself is scheduled.
+    pub fn from_genesis_schedule(genesis: &Genesis, hardforks: &ChainHardforks) -> Self {
+        let Some(schedule) =
+            genesis.config.extra_fields.get("baseFeeSchedule").and_then(|s| s.as_object())
+        else {
+            return Self::default()
+        };
+
+        let mut forks: Vec<(Box<dyn Hardfork>, BaseFeeParams)> =
+            vec![(Box::new(EthereumHardfork::London), BaseFeeParams::ethereum())];
+        for (fork, _) in hardforks.forks_iter() {
+            let Ok(fork) = fork.name().parse::<EthereumHardfork>() else { continue };
+            let Some(params) = schedule
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(fork.name()))
+                .map(|(_, params)| params)
+            else {
+                continue
+            };
+            let param = |key: &str| params.get(key).and_then(|value| value.as_u64());
+            let (Some(max_change_denominator), Some(elasticity_multiplier)) =
+                (param("maxChangeDenominator"), param("elasticityMultiplier"))
+            else {
+                continue
+            };
+
+            let params =
+                BaseFeeParams::new(max_change_denominator as u128, elasticity_multiplier as u128);
+            if fork == EthereumHardfork::London {
+                forks[0].1 = params;
+            } else {
+                forks.push((Box::new(fork), params));
+            }
+        }
+
+        match forks.as_slice() {
+            [(_, params)] => Self::Constant(*params),
+            _ => ForkBaseFeeParams(forks).into(),
+        }
+    }
+}
+
 impl From<BaseFeeParams> for BaseFeeParamsKind {
     fn from(params: BaseFeeParams) -> Self {
         Self::Constant(params)
@@ -311,6 +368,9 @@ pub struct ChainSpec {
 
     /// The settings passed for blob configurations for specific hardforks.
     pub blob_params: HardforkBlobParams,
+
+    /// The mainnet-specific system calls and header fields that are enabled on this chain.
+    pub execution_features: ExecutionFeatures,
 }
 
 impl Default for ChainSpec {
@@ -325,6 +385,7 @@ impl Default for ChainSpec {
             base_fee_params: BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()),
             prune_delete_limit: MAINNET_PRUNE_DELETE_LIMIT,
             blob_params: Default::default(),
+            execution_features: Default::default(),
         }
     }
 }
@@ -737,14 +798,22 @@ impl From<Genesis> for ChainSpec {
 
         let hardforks = ChainHardforks::new(ordered_hardforks);
 
+        let base_fee_params = BaseFeeParamsKind::from_genesis_schedule(&genesis, &hardforks);
+
+        let execution_features = ExecutionFeatures::from_genesis(&genesis);
+        let mut genesis_header = make_genesis_header(&genesis, &hardforks);
+        execution_features.clear_disabled_header_fields(&mut genesis_header);
+
         Self {
             chain: genesis.config.chain_id.into(),
-            genesis_header: SealedHeader::new_unhashed(make_genesis_header(&genesis, &hardforks)),
+            genesis_header: SealedHeader::new_unhashed(genesis_header),
             genesis,
             hardforks,
             paris_block_and_final_difficulty,
             deposit_contract,
+            base_fee_params,
             blob_params,
+            execution_features,
             ..Default::default()
         }
     }
@@ -794,16 +863,16 @@ pub struct ChainSpecBuilder {
     chain: Option<Chain>,
     genesis: Option<Genesis>,
     hardforks: ChainHardforks,
+    deposit_contract: Option<DepositContract>,
+    base_fee_params: BaseFeeParamsKind,
+    blob_params: HardforkBlobParams,
+    execution_features: ExecutionFeatures,
 }
 
 impl ChainSpecBuilder {
     /// Construct a new builder from the mainnet chain spec.
     pub fn mainnet() -> Self {
-        Self {
-            chain: Some(MAINNET.chain),
-            genesis: Some(MAINNET.genesis.clone()),
-            hardforks: MAINNET.hardforks.clone(),
-        }
+        Self::from(&*MAINNET)
     }
 }
 
@@ -838,6 +907,90 @@ impl ChainSpecBuilder {
         self
     }
 
+    /// Activate the given fork at the given block.
+    pub fn with_fork_at_block<H: Hardfork>(self, fork: H, block: BlockNumber) -> Self {
+        self.with_fork(fork, ForkCondition::Block(block))
+    }
+
+    /// Activate the given fork at the given timestamp.
+    pub fn with_fork_at_timestamp<H: Hardfork>(self, fork: H, timestamp: u64) -> Self {
+        self.with_fork(fork, ForkCondition::Timestamp(timestamp))
+    }
+
+    /// Set the `PoS` deposit contract.
+    pub const fn deposit_contract(mut self, deposit_contract: DepositContract) -> Self {
+        self.deposit_contract = Some(deposit_contract);
+        self
+    }
+
+    /// Set the parameters that configure how a block's base fee is computed.
+    ///
+    /// This replaces any parameters set with [`Self::with_fork_base_fee_params`].
+    pub fn base_fee_params(mut self, params: impl Into<BaseFeeParamsKind>) -> Self {
+        self.base_fee_params = params.into();
+        self
+    }
+
+    /// Use the given base fee parameters from the activation of the given fork onwards.
+    ///
+    /// Forks must be added in activation order. If constant parameters were set before, they stay
+    /// in effect until the first fork with its own parameters is activated.
+    pub fn with_fork_base_fee_params<H: Hardfork>(
+        mut self,
+        fork: H,
+        params: BaseFeeParams,
+    ) -> Self {
+        let mut forks = match self.base_fee_params {
+            BaseFeeParamsKind::Constant(params) => {
+                vec![(Box::new(EthereumHardfork::Frontier) as Box<dyn Hardfork>, params)]
+            }
+            BaseFeeParamsKind::Variable(ForkBaseFeeParams(forks)) => forks,
+        };
+        forks.push((Box::new(fork), params));
+        self.base_fee_params = ForkBaseFeeParams(forks).into();
+        self
+    }
+
+    /// Set the blob parameters of the hardforks that use blobs.
+    pub const fn blob_params(mut self, blob_params: HardforkBlobParams) -> Self {
+        self.blob_params = blob_params;
+        self
+    }
+
+    /// Set the mainnet-specific system calls and header fields that are enabled.
+    pub const fn execution_features(mut self, execution_features: ExecutionFeatures) -> Self {
+        self.execution_features = execution_features;
+        self
+    }
+
+    /// Add the account to the genesis allocation, replacing any existing account at the address.
+    ///
+    /// Uses a default genesis if none is set yet.
+    pub fn with_genesis_account(mut self, address: Address, account: GenesisAccount) -> Self {
+        self.genesis.get_or_insert_with(Default::default).alloc.insert(address, account);
+        self
+    }
+
+    /// Fund the address with the given balance at genesis.
+    pub fn with_genesis_balance(self, address: Address, balance: U256) -> Self {
+        self.with_genesis_account(address, GenesisAccount::default().with_balance(balance))
+    }
+
+    /// Deploy a contract with the given code and storage at genesis.
+    pub fn with_genesis_contract(
+        self,
+        address: Address,
+        code: Bytes,
+        storage: BTreeMap<B256, B256>,
+    ) -> Self {
+        self.with_genesis_account(
+            address,
+            GenesisAccount::default()
+                .with_code(Some(code))
+                .with_storage((!storage.is_empty()).then_some(storage)),
+        )
+    }
+
     /// Enable the Paris hardfork at the given TTD.
     ///
     /// Does not set the merge netsplit block.
@@ -959,6 +1112,40 @@ impl ChainSpecBuilder {
         self
     }
 
+    /// Build the resulting [`ChainSpec`], returning an error if the chain ID or genesis is not
+    /// set or the hardforks are not activated in order.
+    pub fn try_build(self) -> Result<ChainSpec, ChainSpecBuilderError> {
+        if self.chain.is_none() {
+            return Err(ChainSpecBuilderError::MissingChain)
+        }
+        if self.genesis.is_none() {
+            return Err(ChainSpecBuilderError::MissingGenesis)
+        }
+
+        // block based forks must not be activated after timestamp based forks, and the activation
+        // of each kind must not decrease
+        let mut last_block = None;
+        let mut last_timestamp = None;
+        for (fork, condition) in self.hardforks.forks_iter() {
+            let out_of_order = match condition {
+                ForkCondition::Block(block) => {
+                    last_timestamp.is_some() ||
+                        last_block.replace(block).is_some_and(|last| last > block)
+                }
+                ForkCondition::Timestamp(timestamp) => {
+                    last_timestamp.replace(timestamp).is_some_and(|last| last > timestamp)
+                }
+                ForkCondition::TTD { .. } => last_timestamp.is_some(),
+                ForkCondition::Never => false,
+            };
+            if out_of_order {
+                return Err(ChainSpecBuilderError::ForkOutOfOrder(fork.name().into()))
+            }
+        }
+
+        Ok(self.build())
+    }
+
     /// Build the resulting [`ChainSpec`].
     ///
     /// # Panics
@@ -976,16 +1163,18 @@ impl ChainSpecBuilder {
             })
         };
         let genesis = self.genesis.expect("The genesis is required");
+        let mut genesis_header = make_genesis_header(&genesis, &self.hardforks);
+        self.execution_features.clear_disabled_header_fields(&mut genesis_header);
         ChainSpec {
             chain: self.chain.expect("The chain is required"),
-            genesis_header: SealedHeader::new_unhashed(make_genesis_header(
-                &genesis,
-                &self.hardforks,
-            )),
+            genesis_header: SealedHeader::new_unhashed(genesis_header),
             genesis,
             hardforks: self.hardforks,
             paris_block_and_final_difficulty,
-            deposit_contract: None,
+            deposit_contract: self.deposit_contract,
+            base_fee_params: self.base_fee_params,
+            blob_params: self.blob_params,
+            execution_features: self.execution_features,
             ..Default::default()
         }
     }
@@ -997,10 +1186,28 @@ impl From<&Arc<ChainSpec>> for ChainSpecBuilder {
             chain: Some(value.chain),
             genesis: Some(value.genesis.clone()),
             hardforks: value.hardforks.clone(),
+            deposit_contract: value.deposit_contract,
+            base_fee_params: value.base_fee_params.clone(),
+            blob_params: value.blob_params.clone(),
+            execution_features: value.execution_features,
         }
     }
 }
 
+/// Errors that can occur when building a [`ChainSpec`] with [`ChainSpecBuilder::try_build`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ChainSpecBuilderError {
+    /// The chain ID is not set.
+    #[error("the chain is required")]
+    MissingChain,
+    /// The genesis is not set.
+    #[error("the genesis is required")]
+    MissingGenesis,
+    /// The hardfork is activated before a hardfork that precedes it.
+    #[error("hardfork {0} is activated out of order")]
+    ForkOutOfOrder(String),
+}
+
 impl EthExecutorSpec for ChainSpec {
     fn deposit_contract_address(&self) -> Option<Address> {
         self.deposit_contract.map(|deposit_contract| deposit_contract.address)
@@ -1025,6 +1232,67 @@ impl DepositContract {
     }
 }
 
+/// The mainnet-specific system calls and header fields of the execution layer that are enabled on
+/// a chain.
+///
+/// All features are enabled by default. Custom chains, e.g. L2s, can disable them in the
+/// `executionFeatures` of the genesis config:
+///
+/// ```json
+/// "executionFeatures": { "beaconRoot": false, "withdrawals": false, "requests": false }
+/// ```
+///
+/// A disabled feature is not applied during execution even if the hardfork that introduced it is
+/// active, and the corresponding header field must be absent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionFeatures {
+    /// The EIP-4788 beacon roots contract call and the `parent_beacon_block_root` header field,
+    /// introduced in Cancun.
+    pub beacon_root: bool,
+    /// The EIP-4895 withdrawals and the `withdrawals_root` header field, introduced in Shanghai.
+    pub withdrawals: bool,
+    /// The EIP-7685 requests, collected from the EIP-6110 deposits and the EIP-7002 and EIP-7251
+    /// system calls, and the `requests_hash` header field, introduced in Prague.
+    pub requests: bool,
+}
+
+impl Default for ExecutionFeatures {
+    fn default() -> Self {
+        Self { beacon_root: true, withdrawals: true, requests: true }
+    }
+}
+
+impl ExecutionFeatures {
+    /// Reads the features from the `executionFeatures` of the genesis config. Features that are
+    /// not configured are enabled.
+    pub fn from_genesis(genesis: &Genesis) -> Self {
+        let Some(features) =
+            genesis.config.extra_fields.get("executionFeatures").and_then(|f| f.as_object())
+        else {
+            return Self::default()
+        };
+        let enabled = |key: &str| features.get(key).and_then(|v| v.as_bool()).unwrap_or(true);
+        Self {
+            beacon_root: enabled("beaconRoot"),
+            withdrawals: enabled("withdrawals"),
+            requests: enabled("requests"),
+        }
+    }
+
+    /// Removes the header fields of the disabled features from the header.
+    pub fn clear_disabled_header_fields(&self, header: &mut Header) {
+        if !self.beacon_root {
+            header.parent_beacon_block_root = None;
+        }
+        if !self.withdrawals {
+            header.withdrawals_root = None;
+        }
+        if !self.requests {
+            header.requests_hash = None;
+        }
+    }
+}
+
 /// Verifies [`ChainSpec`] configuration against expected data in given cases.
 #[cfg(any(test, feature = "test-utils"))]
 pub fn test_fork_ids(spec: &ChainSpec, cases: &[(Head, ForkId)]) {
@@ -2181,6 +2449,45 @@ Post-merge hard forks (timestamp based):
         assert_eq!(genesis.base_fee_per_gas, Some(0x1337));
     }
 
+    #[test]
+    fn test_parse_genesis_base_fee_schedule() {
+        let s = r#"{"config":{"chainId":1337,"homesteadBlock":0,"eip150Block":0,"eip155Block":0,"eip158Block":0,"byzantiumBlock":0,"constantinopleBlock":0,"petersburgBlock":0,"istanbulBlock":0,"berlinBlock":0,"londonBlock":0,"terminalTotalDifficulty":0,"shanghaiTime":0,"cancunTime":10,"pragueTime":20,"baseFeeSchedule":{"cancun":{"maxChangeDenominator":50,"elasticityMultiplier":4},"prague":{"maxChangeDenominator":250,"elasticityMultiplier":6},"osaka":{"maxChangeDenominator":1,"elasticityMultiplier":1}}},"alloc":{}}"#;
+        let genesis: Genesis = serde_json::from_str(s).unwrap();
+        let spec = ChainSpec::from_genesis(genesis);
+
+        assert_eq!(spec.base_fee_params_at_timestamp(0), BaseFeeParams::ethereum());
+        assert_eq!(spec.base_fee_params_at_timestamp(10), BaseFeeParams::new(50, 4));
+        assert_eq!(spec.base_fee_params_at_timestamp(30), BaseFeeParams::new(250, 6));
+
+        // without a schedule, the ethereum parameters apply
+        let spec = ChainSpec::from_genesis(
+            serde_json::from_str(r#"{"config":{"chainId":1337,"londonBlock":0},"alloc":{}}"#)
+                .unwrap(),
+        );
+        assert_eq!(spec.base_fee_params, BaseFeeParamsKind::Constant(BaseFeeParams::ethereum()));
+    }
+
+    #[test]
+    fn test_parse_genesis_execution_features() {
+        let s = r#"{"config":{"chainId":1337,"londonBlock":0,"terminalTotalDifficulty":0,"shanghaiTime":0,"cancunTime":0,"pragueTime":0,"executionFeatures":{"beaconRoot":false,"requests":false}},"alloc":{}}"#;
+        let spec = ChainSpec::from_genesis(serde_json::from_str(s).unwrap());
+
+        assert_eq!(
+            spec.execution_features,
+            ExecutionFeatures { beacon_root: false, withdrawals: true, requests: false }
+        );
+        assert_eq!(spec.genesis_header.parent_beacon_block_root, None);
+        assert_eq!(spec.genesis_header.requests_hash, None);
+        assert_eq!(spec.genesis_header.withdrawals_root, Some(EMPTY_WITHDRAWALS));
+
+        // without features, all are enabled
+        let spec = ChainSpec::from_genesis(
+            serde_json::from_str(r#"{"config":{"chainId":1337,"londonBlock":0},"alloc":{}}"#)
+                .unwrap(),
+        );
+        assert_eq!(spec.execution_features, ExecutionFeatures::default());
+    }
+
     #[test]
     fn test_parse_cancun_genesis_json() {
         let s = r#"{"config":{"ethash":{},"chainId":1337,"homesteadBlock":0,"eip150Block":0,"eip155Block":0,"eip158Block":0,"byzantiumBlock":0,"constantinopleBlock":0,"petersburgBlock":0,"istanbulBlock":0,"berlinBlock":0,"londonBlock":0,"terminalTotalDifficulty":0,"terminalTotalDifficultyPassed":true,"shanghaiTime":0,"cancunTime":4661},"nonce":"0x0","timestamp":"0x0","extraData":"0x","gasLimit":"0x4c4b40","difficulty":"0x1","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","coinbase":"0x0000000000000000000000000000000000000000","alloc":{"658bdf435d810c91414ec09147daa6db62406379":{"balance":"0x487a9a304539440000"},"aa00000000000000000000000000000000000000":{"code":"0x6042","storage":{"0x0000000000000000000000000000000000000000000000000000000000000000":"0x0000000000000000000000000000000000000000000000000000000000000000","0x0100000000000000000000000000000000000000000000000000000000000000":"0x0100000000000000000000000000000000000000000000000000000000000000","0x0200000000000000000000000000000000000000000000000000000000000000":"0x0200000000000000000000000000000000000000000000000000000000000000","0x0300000000000000000000000000000000000000000000000000000000000000":"0x0000000000000000000000000000000000000000000000000000000000000303"},"balance":"0x1","nonce":"0x1"},"bb00000000000000000000000000000000000000":{"code":"0x600154600354","storage":{"0x0000000000000000000000000000000000000000000000000000000000000000":"0x0000000000000000000000000000000000000000000000000000000000000000","0x0100000000000000000000000000000000000000000000000000000000000000":"0x0100000000000000000000000000000000000000000000000000000000000000","0x0200000000000000000000000000000000000000000000000000000000000000":"0x0200000000000000000000000000000000000000000000000000000000000000","0x0300000000000000000000000000000000000000000000000000000000000000":"0x0000000000000000000000000000000000000000000000000000000000000303"},"balance":"0x2","nonce":"0x1"}},"number":"0x0","gasUsed":"0x0","parentHash":"0x0000000000000000000000000000000000000000000000000000000000000000","baseFeePerGas":"0x3b9aca00"}"#;
@@ -2460,4 +2767,48 @@ Post-merge hard forks (timestamp based):
             assert_eq!(block_reward(base_reward, num_ommers), expected_reward);
         }
     }
+
+    #[test]
+    fn builder_custom_network() {
+        let address = Address::with_last_byte(1);
+        let contract = Address::with_last_byte(2);
+        let storage = BTreeMap::from([(B256::ZERO, B256::with_last_byte(1))]);
+        let deposit_contract = DepositContract::new(contract, 0, B256::ZERO);
+        let spec = ChainSpecBuilder::default()
+            .chain(Chain::from_id(1337))
+            .london_activated()
+            .with_fork_at_timestamp(EthereumHardfork::Shanghai, 100)
+            .deposit_contract(deposit_contract)
+            .base_fee_params(BaseFeeParams::new(4, 2))
+            .with_fork_base_fee_params(EthereumHardfork::Shanghai, BaseFeeParams::ethereum())
+            .with_genesis_balance(address, U256::from(1))
+            .with_genesis_contract(contract, Bytes::from_static(&[0x00]), storage.clone())
+            .try_build()
+            .unwrap();
+
+        assert_eq!(spec.deposit_contract, Some(deposit_contract));
+        assert_eq!(spec.base_fee_params_at_timestamp(0), BaseFeeParams::new(4, 2));
+        assert_eq!(spec.base_fee_params_at_timestamp(100), BaseFeeParams::ethereum());
+        assert_eq!(spec.genesis.alloc[&address].balance, U256::from(1));
+        assert_eq!(spec.genesis.alloc[&contract].storage, Some(storage));
+    }
+
+    #[test]
+    fn builder_rejects_invalid_spec() {
+        assert_eq!(
+            ChainSpecBuilder::default().london_activated().try_build(),
+            Err(ChainSpecBuilderError::MissingChain)
+        );
+        assert_eq!(
+            ChainSpecBuilder::default().chain(Chain::mainnet()).try_build(),
+            Err(ChainSpecBuilderError::MissingGenesis)
+        );
+        assert_eq!(
+            ChainSpecBuilder::mainnet()
+                .with_fork_at_timestamp(EthereumHardfork::Prague, 0)
+                .try_build(),
+            Err(ChainSpecBuilderError::ForkOutOfOrder("Prague".into()))
+        );
+        assert!(ChainSpecBuilder::mainnet().try_build().is_ok());
+    }
 }
@@ -0,0 +1,240 @@
+//! Command that re-executes a range of synced blocks from the local database.
+
+use crate::common::{AccessRights, CliNodeComponents, CliNodeTypes, Environment, EnvironmentArgs};
+use alloy_consensus::BlockHeader;
+use alloy_primitives::BlockNumber;
+use clap::Parser;
+use eyre::WrapErr;
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_consensus::{ConsensusError, FullConsensus};
+use reth_evm::execute::{BlockExecutorProvider, Executor};
+use reth_primitives_traits::{format_gas, format_gas_throughput, BlockBody};
+use reth_provider::{
+    providers::ProviderNodeTypes, BlockReader, ChainSpecProvider, ProviderError, ProviderFactory,
+    StageCheckpointReader, TransactionVariant,
+};
+use reth_revm::database::StateProviderDatabase;
+use reth_stages::StageId;
+use std::{
+    ops::{AddAssign, RangeInclusive},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tracing::info;
+
+/// `reth bench execute` command
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The first block to execute.
+    #[arg(long, value_name = "BLOCK_NUMBER", value_parser = clap::value_parser!(u64).range(1..))]
+    from: BlockNumber,
+
+    /// The last block to execute, inclusive.
+    #[arg(long, value_name = "BLOCK_NUMBER")]
+    to: BlockNumber,
+
+    /// Number of threads that execute consecutive parts of the range concurrently.
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+    threads: u64,
+
+    /// Number of consecutive blocks executed on top of the same state.
+    ///
+    /// Accounts and storage read from the database are cached for the whole batch, so a batch
+    /// size of 1 executes every block with a cold cache. Larger batches use more memory.
+    #[arg(long, default_value_t = 1_000, value_parser = clap::value_parser!(u64).range(1..))]
+    batch_size: u64,
+
+    /// Skip validating the execution output of the blocks, e.g. their receipts root and gas used.
+    #[arg(long)]
+    no_validation: bool,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
+    /// Execute `bench execute` command
+    pub async fn execute<N, Comp, F>(self, components: F) -> eyre::Result<()>
+    where
+        N: CliNodeTypes<ChainSpec = C::ChainSpec>,
+        Comp: CliNodeComponents<N>,
+        F: FnOnce(Arc<C::ChainSpec>) -> Comp,
+    {
+        if self.from > self.to {
+            eyre::bail!("--from {} is higher than --to {}", self.from, self.to)
+        }
+
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+        let components = components(provider_factory.chain_spec());
+        let executor = components.executor();
+        let consensus = (!self.no_validation).then(|| components.consensus());
+
+        let executed = provider_factory
+            .provider()?
+            .get_stage_checkpoint(StageId::Execution)?
+            .unwrap_or_default()
+            .block_number;
+        if self.to > executed {
+            eyre::bail!(
+                "Block {} is not synced yet, the highest executed block is {executed}",
+                self.to
+            )
+        }
+
+        let ranges = split_range(self.from..=self.to, self.threads);
+        let threads = ranges.len();
+        let batch_size = self.batch_size;
+        info!(target: "reth::cli", from = self.from, to = self.to, threads, batch_size, "Executing blocks");
+
+        let start = Instant::now();
+        let stats = std::thread::scope(|scope| {
+            let handles = ranges
+                .into_iter()
+                .map(|range| {
+                    let provider_factory = &provider_factory;
+                    scope.spawn(move || {
+                        execute_range(provider_factory, executor, consensus, range, batch_size)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let mut stats = ExecutionStats::default();
+            for handle in handles {
+                stats += handle.join().map_err(|_| eyre::eyre!("execution thread panicked"))??;
+            }
+            eyre::Ok(stats)
+        })?;
+        let elapsed = start.elapsed();
+
+        println!(
+            "Executed {} blocks with {} transactions and {} in {elapsed:?} on {threads} threads",
+            stats.blocks,
+            stats.transactions,
+            format_gas(stats.gas),
+        );
+        println!("Throughput: {}", format_gas_throughput(stats.gas, elapsed));
+        println!("Time spent per stage, summed over all threads:");
+        println!("  Fetching blocks:   {:?}", stats.fetch_duration);
+        println!(
+            "  Execution:         {:?} ({})",
+            stats.execution_duration,
+            format_gas_throughput(stats.gas, stats.execution_duration)
+        );
+        println!("  Validation:        {:?}", stats.validation_duration);
+
+        Ok(())
+    }
+}
+
+/// Statistics of the executed blocks.
+#[derive(Debug, Default, Clone, Copy)]
+struct ExecutionStats {
+    blocks: u64,
+    transactions: u64,
+    gas: u64,
+    fetch_duration: Duration,
+    execution_duration: Duration,
+    validation_duration: Duration,
+}
+
+impl AddAssign for ExecutionStats {
+    fn add_assign(&mut self, other: Self) {
+        self.blocks += other.blocks;
+        self.transactions += other.transactions;
+        self.gas += other.gas;
+        self.fetch_duration += other.fetch_duration;
+        self.execution_duration += other.execution_duration;
+        self.validation_duration += other.validation_duration;
+    }
+}
+
+/// Executes the blocks of the range in batches of `batch_size` blocks, each on top of the
+/// historical state before the batch.
+fn execute_range<N, E, C>(
+    provider_factory: &ProviderFactory<N>,
+    executor_provider: &E,
+    consensus: Option<&C>,
+    range: RangeInclusive<BlockNumber>,
+    batch_size: u64,
+) -> eyre::Result<ExecutionStats>
+where
+    N: ProviderNodeTypes,
+    E: BlockExecutorProvider<Primitives = N::Primitives>,
+    C: FullConsensus<N::Primitives, Error = ConsensusError>,
+{
+    let mut stats = ExecutionStats::default();
+    let mut batch_start = *range.start();
+    while batch_start <= *range.end() {
+        let batch_end = batch_start.saturating_add(batch_size - 1).min(*range.end());
+        let batch_stats = stats;
+
+        let state = provider_factory.history_by_block_number(batch_start - 1)?;
+        let mut executor = executor_provider.executor(StateProviderDatabase::new(state));
+
+        for block_number in batch_start..=batch_end {
+            let fetch_start = Instant::now();
+            let block = provider_factory
+                .recovered_block(block_number.into(), TransactionVariant::NoHash)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+            stats.fetch_duration += fetch_start.elapsed();
+
+            let execution_start = Instant::now();
+            let result = executor
+                .execute_one(&block)
+                .wrap_err_with(|| format!("Failed to execute block {block_number}"))?;
+            stats.execution_duration += execution_start.elapsed();
+
+            // the reverts are not needed, and would otherwise grow with the batch
+            executor.with_state_mut(|state| state.bundle_state.take_all_reverts());
+
+            if let Some(consensus) = consensus {
+                let validation_start = Instant::now();
+                consensus.validate_block_post_execution(&block, &result).wrap_err_with(|| {
+                    format!("Invalid execution output of block {block_number}")
+                })?;
+                stats.validation_duration += validation_start.elapsed();
+            }
+
+            stats.blocks += 1;
+            stats.transactions += block.body().transaction_count() as u64;
+            stats.gas += block.header().gas_used();
+        }
+
+        info!(
+            target: "reth::cli",
+            start = batch_start,
+            end = batch_end,
+            throughput = format_gas_throughput(
+                stats.gas - batch_stats.gas,
+                stats.execution_duration - batch_stats.execution_duration
+            ),
+            "Executed batch"
+        );
+        batch_start = batch_end + 1;
+    }
+
+    Ok(stats)
+}
+
+/// Splits the range into at most `parts` consecutive ranges of about equal length.
+fn split_range(range: RangeInclusive<BlockNumber>, parts: u64) -> Vec<RangeInclusive<BlockNumber>> {
+    let len = range.end() - range.start() + 1;
+    let part_len = len.div_ceil(parts);
+    (*range.start()..=*range.end())
+        .step_by(part_len as usize)
+        .map(|start| start..=(start + part_len - 1).min(*range.end()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_block_range() {
+        assert_eq!(split_range(1..=10, 1), vec![1..=10]);
+        assert_eq!(split_range(1..=10, 3), vec![1..=4, 5..=8, 9..=10]);
+        assert_eq!(split_range(5..=6, 4), vec![5..=5, 6..=6]);
+    }
+}
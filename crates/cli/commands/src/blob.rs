@@ -0,0 +1,63 @@
+//! CLI command to read blob sidecars from a blob archive.
+
+use alloy_primitives::{hex, B256};
+use clap::{Parser, Subcommand};
+use eyre::{bail, WrapErr};
+use reth_transaction_pool::blobstore::BlobArchiveLocation;
+
+/// `reth blob` command
+#[derive(Debug, Parser)]
+pub struct Command {
+    #[command(subcommand)]
+    command: Subcommands,
+}
+
+/// `reth blob` subcommands
+#[derive(Debug, Subcommand)]
+pub enum Subcommands {
+    /// Reads an archived blob by its versioned hash and prints it as JSON.
+    Get(GetArgs),
+}
+
+/// Arguments of the `reth blob get` subcommand.
+#[derive(Debug, Parser)]
+pub struct GetArgs {
+    /// The versioned hash of the blob.
+    #[arg(value_name = "VERSIONED_HASH")]
+    versioned_hash: B256,
+
+    /// The blob archive to read from, either a directory or an HTTP(S)/S3 URL.
+    #[arg(long, value_name = "DIR_OR_URL")]
+    archive: BlobArchiveLocation,
+}
+
+impl Command {
+    /// Execute `blob` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::Get(args) => {
+                let GetArgs { versioned_hash, archive } = args;
+                let location = archive.to_string();
+                // the archive backends do blocking io
+                let blob = tokio::task::spawn_blocking(move || {
+                    archive.open()?.get(versioned_hash).map_err(eyre::Report::from)
+                })
+                .await?
+                .wrap_err_with(|| format!("Failed to read from blob archive {location}"))?;
+
+                let Some(blob) = blob else {
+                    bail!("Blob {versioned_hash} not found in blob archive {location}");
+                };
+
+                let json = serde_json::json!({
+                    "versionedHash": versioned_hash,
+                    "commitment": blob.commitment,
+                    "proof": blob.proof,
+                    "blob": hex::encode_prefixed(blob.blob.as_slice()),
+                });
+                println!("{}", serde_json::to_string_pretty(&json)?);
+            }
+        }
+        Ok(())
+    }
+}
@@ -21,7 +21,11 @@ use reth_provider::{
 };
 use reth_stages::{sets::DefaultStages, Pipeline, PipelineTarget};
 use reth_static_file::StaticFileProducer;
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::PathBuf,
+    sync::{Arc, OnceLock},
+};
+use tempfile::TempDir;
 use tokio::sync::watch;
 use tracing::{debug, info, warn};
 
@@ -51,16 +55,34 @@ pub struct EnvironmentArgs<C: ChainSpecParser> {
     /// All database related arguments
     #[command(flatten)]
     pub db: DatabaseArgs,
+
+    /// The ephemeral datadir of `--datadir memory`, removed when the arguments are dropped.
+    #[arg(skip)]
+    memory_datadir: OnceLock<TempDir>,
 }
 
 impl<C: ChainSpecParser> EnvironmentArgs<C> {
+    /// Resolves the datadir of the chain.
+    ///
+    /// For `--datadir memory` an ephemeral datadir is created on first use, which lives as long as
+    /// these arguments.
+    pub fn resolve_datadir(&self) -> eyre::Result<ChainPath<DataDirPath>> {
+        let mut datadir = self.datadir.clone();
+        if let Some(dir) = self.memory_datadir.get() {
+            datadir.datadir = dir.path().to_path_buf().into();
+        } else if let Some(dir) = datadir.create_memory_datadir()? {
+            let _ = self.memory_datadir.set(dir);
+        }
+        Ok(datadir.resolve_datadir(self.chain.chain()))
+    }
+
     /// Initializes environment according to [`AccessRights`] and returns an instance of
     /// [`Environment`].
     pub fn init<N: CliNodeTypes>(&self, access: AccessRights) -> eyre::Result<Environment<N>>
     where
         C: ChainSpecParser<ChainSpec = N::ChainSpec>,
     {
-        let data_dir = self.datadir.clone().resolve_datadir(self.chain.chain());
+        let data_dir = self.resolve_datadir()?;
         let db_path = data_dir.db();
         let sf_path = data_dir.static_files();
 
@@ -83,13 +105,13 @@ impl<C: ChainSpecParser> EnvironmentArgs<C> {
         }
 
         info!(target: "reth::cli", ?db_path, ?sf_path, "Opening storage");
+        let db_args = self.db.with_config(&config.database).database_args();
         let (db, sfp) = match access {
-            AccessRights::RW => (
-                Arc::new(init_db(db_path, self.db.database_args())?),
-                StaticFileProvider::read_write(sf_path)?,
-            ),
+            AccessRights::RW => {
+                (Arc::new(init_db(db_path, db_args)?), StaticFileProvider::read_write(sf_path)?)
+            }
             AccessRights::RO => (
-                Arc::new(open_db_read_only(&db_path, self.db.database_args())?),
+                Arc::new(open_db_read_only(&db_path, db_args)?),
                 StaticFileProvider::read_only(sf_path, false)?,
             ),
         };
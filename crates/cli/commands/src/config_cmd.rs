@@ -1,39 +1,99 @@
-//! CLI command to show configs.
+//! CLI command to show and validate configs.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use eyre::{bail, WrapErr};
 use reth_config::Config;
 
 /// `reth config` command
 #[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct Command {
+    #[command(subcommand)]
+    command: Option<Subcommands>,
+
+    /// Arguments of `reth config print`, kept for backwards compatibility.
+    #[command(flatten)]
+    print: PrintArgs,
+}
+
+/// `reth config` subcommands
+#[derive(Debug, Subcommand)]
+pub enum Subcommands {
+    /// Print the config
+    Print(PrintArgs),
+    /// Validate the config file.
+    ///
+    /// Fails if the file can't be parsed, contains unknown keys, or contains invalid values.
+    Validate(ValidateArgs),
+}
+
+/// Arguments of the `reth config print` subcommand.
+#[derive(Debug, Clone, Parser)]
+pub struct PrintArgs {
     /// The path to the configuration file to use.
     #[arg(long, value_name = "FILE", verbatim_doc_comment)]
     config: Option<PathBuf>,
 
     /// Show the default config
-    #[arg(long, verbatim_doc_comment, conflicts_with = "config")]
+    #[arg(long, visible_alias = "defaults", verbatim_doc_comment, conflicts_with = "config")]
     default: bool,
 }
 
+/// Arguments of the `reth config validate` subcommand.
+#[derive(Debug, Clone, Parser)]
+pub struct ValidateArgs {
+    /// The path to the configuration file to validate.
+    #[arg(long, value_name = "FILE", verbatim_doc_comment)]
+    config: PathBuf,
+}
+
 impl Command {
     /// Execute `config` command
     pub async fn execute(&self) -> eyre::Result<()> {
-        let config = if self.default {
-            Config::default()
-        } else {
-            let path = self.config.clone().unwrap_or_default();
-            // Check if the file exists
-            if !path.exists() {
-                bail!("Config file does not exist: {}", path.display());
-            }
-            // Read the configuration file
-            Config::from_path(&path)
-                .wrap_err_with(|| format!("Could not load config file: {}", path.display()))?
-        };
-        println!("{}", toml::to_string_pretty(&config)?);
-        Ok(())
+        match &self.command {
+            Some(Subcommands::Print(args)) => print(args),
+            Some(Subcommands::Validate(args)) => validate(&args.config),
+            None => print(&self.print),
+        }
     }
 }
+
+/// Prints the configuration file, or the default configuration.
+fn print(args: &PrintArgs) -> eyre::Result<()> {
+    let config = if args.default {
+        Config::default()
+    } else {
+        let path = args.config.clone().unwrap_or_default();
+        // Check if the file exists
+        if !path.exists() {
+            bail!("Config file does not exist: {}", path.display());
+        }
+        // Read the configuration file
+        Config::from_path(&path)
+            .wrap_err_with(|| format!("Could not load config file: {}", path.display()))?
+    };
+    println!("{}", toml::to_string_pretty(&config)?);
+    Ok(())
+}
+
+/// Validates the configuration file.
+fn validate(path: &Path) -> eyre::Result<()> {
+    let contents = reth_fs_util::read_to_string(path)?;
+
+    let unknown_keys = Config::unknown_keys(&contents)
+        .wrap_err_with(|| format!("Invalid config file: {}", path.display()))?;
+    if !unknown_keys.is_empty() {
+        for key in &unknown_keys {
+            println!("Unknown key: {key}");
+        }
+        bail!("Config file contains {} unknown key(s): {}", unknown_keys.len(), path.display());
+    }
+
+    let config: Config = toml::from_str(&contents)?;
+    config.validate().wrap_err_with(|| format!("Invalid config file: {}", path.display()))?;
+
+    println!("Config file is valid: {}", path.display());
+    Ok(())
+}
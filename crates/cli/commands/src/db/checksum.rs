@@ -1,6 +1,9 @@
 use crate::{
     common::CliNodeTypes,
-    db::get::{maybe_json_value_parser, table_key},
+    db::{
+        get::{maybe_json_value_parser, table_key},
+        verify_blooms::{find_bloom_mismatches, verifiable_range},
+    },
 };
 use ahash::RandomState;
 use clap::Parser;
@@ -38,6 +41,13 @@ pub struct Command {
     /// checksum.
     #[arg(long)]
     limit: Option<usize>,
+
+    /// Additionally verifies that the logs blooms of all blocks with receipts match the logs of
+    /// their receipts.
+    ///
+    /// Diverging logs blooms are listed by `reth db verify-blooms`.
+    #[arg(long)]
+    verify_blooms: bool,
 }
 
 impl Command {
@@ -53,6 +63,22 @@ impl Command {
             end_key: self.end_key,
             limit: self.limit,
         })?;
+
+        if self.verify_blooms {
+            let range = verifiable_range(&tool.provider_factory)?;
+            let mismatches = find_bloom_mismatches(&tool.provider_factory, range.clone())?;
+            for mismatch in &mismatches {
+                warn!("Logs bloom of block {} diverges from its receipts", mismatch.number);
+            }
+            if !mismatches.is_empty() {
+                eyre::bail!(
+                    "{} logs blooms in blocks {range:?} diverge from their receipts, list them with `reth db verify-blooms`",
+                    mismatches.len()
+                )
+            }
+            info!("Verified logs blooms of blocks {range:?}");
+        }
+
         Ok(())
     }
 }
@@ -1,7 +1,12 @@
+use alloy_primitives::hex;
 use clap::Parser;
-use reth_db::{open_db_read_only, tables_to_generic, DatabaseEnv};
+use reth_db::{open_db_read_only, static_file::StaticFileCursor, tables_to_generic, DatabaseEnv};
 use reth_db_api::{
-    cursor::DbCursorRO, database::Database, table::Table, transaction::DbTx, Tables,
+    cursor::DbCursorRO,
+    database::Database,
+    table::{Key, Table},
+    transaction::DbTx,
+    RawKey, RawTable, Tables,
 };
 use reth_db_common::DbTool;
 use reth_node_builder::{NodeTypesWithDBAdapter, NodeTypesWithEngine};
@@ -9,12 +14,19 @@ use reth_node_core::{
     args::DatabaseArgs,
     dirs::{DataDirPath, PlatformPath},
 };
+use reth_primitives_traits::NodePrimitives;
+use reth_provider::{
+    providers::{StaticFileJarProvider, StaticFileProvider},
+    ProviderError, ProviderResult, StaticFileProviderFactory,
+};
+use reth_static_file_types::StaticFileSegment;
 use std::{
     collections::HashMap,
     fmt::Debug,
     fs::{self, File},
     hash::Hash,
     io::Write,
+    ops::{Bound, RangeInclusive},
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -35,6 +47,20 @@ pub struct Command {
     #[arg(long, verbatim_doc_comment)]
     table: Option<Tables>,
 
+    /// The range of block or transaction numbers to diff, e.g. `100..200` or `100..=199`.
+    ///
+    /// Only tables keyed by block or transaction number can be diffed by range. If no table is
+    /// specified, all other tables are skipped.
+    #[arg(long, value_parser = parse_range, verbatim_doc_comment)]
+    range: Option<RangeInclusive<u64>>,
+
+    /// The static file segment to diff in addition to the tables.
+    ///
+    /// Rows of the headers and block meta segments are compared by block number, rows of the
+    /// transactions and receipts segments by transaction number.
+    #[arg(long, verbatim_doc_comment)]
+    segment: Option<StaticFileSegment>,
+
     /// The output directory for the diff report.
     #[arg(long, verbatim_doc_comment)]
     output: PlatformPath<PathBuf>,
@@ -68,7 +94,20 @@ impl Command {
             None => Tables::ALL,
         };
 
+        if let (Some(table), Some(_)) = (&self.table, &self.range) {
+            eyre::ensure!(
+                is_keyed_by_number(*table),
+                "Table {table} is not keyed by block or transaction number and cannot be diffed by \
+                 range"
+            );
+        }
+
         for table in tables {
+            if self.range.is_some() && !is_keyed_by_number(*table) {
+                info!("Skipping table {table}, it is not keyed by block or transaction number");
+                continue
+            }
+
             let mut primary_tx = tool.provider_factory.db_ref().tx()?;
             let mut secondary_tx = second_db.tx()?;
 
@@ -78,21 +117,212 @@ impl Command {
             secondary_tx.disable_long_read_transaction_safety();
 
             let output_dir = self.output.clone();
+            let range = self.range.clone();
             tables_to_generic!(table, |Table| find_diffs::<Table>(
                 primary_tx,
                 secondary_tx,
+                range,
                 output_dir
             ))?;
         }
 
+        if let Some(segment) = self.segment {
+            let primary = tool.provider_factory.static_file_provider();
+            let secondary = StaticFileProvider::<T::Primitives>::read_only(
+                self.secondary_datadir.join("static_files"),
+                false,
+            )?;
+            find_static_file_diffs(&primary, &secondary, segment, self.range, &self.output)?;
+        }
+
         Ok(())
     }
 }
 
+/// Parses a range of numbers in the form of `start..end` or `start..=end`.
+fn parse_range(value: &str) -> eyre::Result<RangeInclusive<u64>> {
+    let (start, end) =
+        value.split_once("..").ok_or_else(|| eyre::eyre!("expected a range like `100..200`"))?;
+    let start = start.parse()?;
+    let end = match end.strip_prefix('=') {
+        Some(end) => end.parse()?,
+        None => end
+            .parse::<u64>()?
+            .checked_sub(1)
+            .ok_or_else(|| eyre::eyre!("range end must be greater than zero"))?,
+    };
+    eyre::ensure!(start <= end, "range is empty");
+    Ok(start..=end)
+}
+
+/// Returns `true` if the keys of the table start with a block or transaction number, so that
+/// the table can be diffed by range.
+const fn is_keyed_by_number(table: Tables) -> bool {
+    matches!(
+        table,
+        Tables::CanonicalHeaders |
+            Tables::HeaderTerminalDifficulties |
+            Tables::Headers |
+            Tables::BlockBodyIndices |
+            Tables::BlockOmmers |
+            Tables::BlockWithdrawals |
+            Tables::Transactions |
+            Tables::TransactionBlocks |
+            Tables::Receipts |
+            Tables::AccountChangeSets |
+            Tables::StorageChangeSets |
+            Tables::TransactionSenders |
+            Tables::BlockContractCreations |
+            Tables::CodeChangeSets |
+            Tables::ExecutionRequests
+    )
+}
+
+/// Find diffs for the rows of a static file segment, then write the result to a file.
+fn find_static_file_diffs<N: NodePrimitives>(
+    primary: &StaticFileProvider<N>,
+    secondary: &StaticFileProvider<N>,
+    segment: StaticFileSegment,
+    range: Option<RangeInclusive<u64>>,
+    output_dir: impl AsRef<Path>,
+) -> eyre::Result<()> {
+    let range = match range {
+        Some(range) => range,
+        None => {
+            let highest = |provider: &StaticFileProvider<N>| {
+                if segment.is_tx_based() {
+                    provider.get_highest_static_file_tx(segment)
+                } else {
+                    provider.get_highest_static_file_block(segment)
+                }
+            };
+            match highest(primary).max(highest(secondary)) {
+                Some(highest) => 0..=highest,
+                None => {
+                    info!("No static files found for segment {segment}");
+                    return Ok(())
+                }
+            }
+        }
+    };
+
+    info!("Analyzing static file segment {segment} in range {range:?}...");
+    let mut discrepancies = Vec::new();
+    let mut extra_elements = Vec::new();
+    // select all columns of the segment
+    let mask = (1 << segment.columns()) - 1;
+    let mut number = *range.start();
+    while number <= *range.end() {
+        let primary_jar = static_file_jar(primary, segment, number)?;
+        let secondary_jar = static_file_jar(secondary, segment, number)?;
+
+        // Compare rows up to the end of whichever file ends first, so that each static file is
+        // only opened once.
+        let end = [&primary_jar, &secondary_jar]
+            .into_iter()
+            .flatten()
+            .filter_map(|jar| {
+                if segment.is_tx_based() {
+                    jar.user_header().tx_end()
+                } else {
+                    jar.user_header().block_end()
+                }
+            })
+            .min()
+            .unwrap_or(number)
+            .clamp(number, *range.end());
+
+        let mut primary_cursor = primary_jar.as_ref().map(|jar| jar.cursor()).transpose()?;
+        let mut secondary_cursor = secondary_jar.as_ref().map(|jar| jar.cursor()).transpose()?;
+        for number in number..=end {
+            let primary_row = static_file_row(primary_cursor.as_mut(), number, mask)?;
+            let secondary_row = static_file_row(secondary_cursor.as_mut(), number, mask)?;
+            match (primary_row, secondary_row) {
+                (Some(first), Some(second)) => {
+                    if first != second {
+                        discrepancies.push((number, first, second));
+                    }
+                }
+                (Some(row), None) => extra_elements.push((number, "first", row)),
+                (None, Some(row)) => extra_elements.push((number, "second", row)),
+                (None, None) => {}
+            }
+        }
+
+        let Some(next) = end.checked_add(1) else { break };
+        number = next;
+    }
+    info!("Done analyzing static file segment {segment}!");
+    info!("Found {} discrepancies in static file segment {segment}", discrepancies.len());
+    info!("Found {} extra elements in static file segment {segment}", extra_elements.len());
+
+    fs::create_dir_all(output_dir.as_ref())?;
+    let file_name = output_dir.as_ref().join(format!("static_file_{segment}.txt"));
+    let mut file = File::create(&file_name)?;
+    writeln!(file, "Diff results for static file segment {segment}")?;
+    writeln!(file, "Found {} discrepancies", discrepancies.len())?;
+    writeln!(file, "Found {} extra elements", extra_elements.len())?;
+
+    if !discrepancies.is_empty() {
+        writeln!(file, "Discrepancies:")?;
+    }
+    for (number, first, second) in discrepancies {
+        writeln!(file, "{number}: first {} second {}", encode_row(&first), encode_row(&second))?;
+    }
+
+    if !extra_elements.is_empty() {
+        writeln!(file, "Extra elements:")?;
+    }
+    for (number, side, row) in extra_elements {
+        writeln!(file, "{number}: only in {side} {}", encode_row(&row))?;
+    }
+
+    info!("Done writing diff results for {segment} to {}", file_name.display());
+    Ok(())
+}
+
+/// Returns the static file containing the given block or transaction number, if any.
+fn static_file_jar<N: NodePrimitives>(
+    provider: &StaticFileProvider<N>,
+    segment: StaticFileSegment,
+    number: u64,
+) -> ProviderResult<Option<StaticFileJarProvider<'_, N>>> {
+    let jar = if segment.is_tx_based() {
+        provider.get_segment_provider_from_transaction(segment, number, None)
+    } else {
+        provider.get_segment_provider_from_block(segment, number, None)
+    };
+    match jar {
+        Ok(jar) => Ok(Some(jar)),
+        Err(ProviderError::MissingStaticFileBlock(..) | ProviderError::MissingStaticFileTx(..)) => {
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns the raw columns of the static file row with the given block or transaction number.
+fn static_file_row(
+    cursor: Option<&mut StaticFileCursor<'_>>,
+    number: u64,
+    mask: usize,
+) -> ProviderResult<Option<Vec<Vec<u8>>>> {
+    let Some(cursor) = cursor else { return Ok(None) };
+    Ok(cursor
+        .get(number.into(), mask)?
+        .map(|columns| columns.into_iter().map(<[u8]>::to_vec).collect()))
+}
+
+/// Encodes the columns of a static file row as hex.
+fn encode_row(row: &[Vec<u8>]) -> String {
+    row.iter().map(hex::encode_prefixed).collect::<Vec<_>>().join(",")
+}
+
 /// Find diffs for a table, then analyzing the result
 fn find_diffs<T: Table>(
     primary_tx: impl DbTx,
     secondary_tx: impl DbTx,
+    range: Option<RangeInclusive<u64>>,
     output_dir: impl AsRef<Path>,
 ) -> eyre::Result<()>
 where
@@ -102,7 +332,7 @@ where
     let table = T::NAME;
 
     info!("Analyzing table {table}...");
-    let result = find_diffs_advanced::<T>(&primary_tx, &secondary_tx)?;
+    let result = find_diffs_advanced::<T>(&primary_tx, &secondary_tx, range)?;
     info!("Done analyzing table {table}!");
 
     // Pretty info summary header: newline then header
@@ -173,9 +403,13 @@ where
 
 /// This diff algorithm is slightly different, it will walk _each_ table, cross-checking for the
 /// element in the other table.
+///
+/// If a range is given, only the entries with keys that start with a number in the range are
+/// walked.
 fn find_diffs_advanced<T: Table>(
     primary_tx: &impl DbTx,
     secondary_tx: &impl DbTx,
+    range: Option<RangeInclusive<u64>>,
 ) -> eyre::Result<TableDiffResult<T>>
 where
     T::Value: PartialEq,
@@ -183,12 +417,12 @@ where
 {
     // initialize the zipped walker
     let mut primary_zip_cursor =
-        primary_tx.cursor_read::<T>().expect("Was not able to obtain a cursor.");
-    let primary_walker = primary_zip_cursor.walk(None)?;
+        primary_tx.cursor_read::<RawTable<T>>().expect("Was not able to obtain a cursor.");
+    let primary_walker = primary_zip_cursor.walk_range(raw_key_range(range.as_ref()))?;
 
     let mut secondary_zip_cursor =
-        secondary_tx.cursor_read::<T>().expect("Was not able to obtain a cursor.");
-    let secondary_walker = secondary_zip_cursor.walk(None)?;
+        secondary_tx.cursor_read::<RawTable<T>>().expect("Was not able to obtain a cursor.");
+    let secondary_walker = secondary_zip_cursor.walk_range(raw_key_range(range.as_ref()))?;
     let zipped_cursor = primary_walker.zip(secondary_walker);
 
     // initialize the cursors for seeking when we are cross checking elements
@@ -206,7 +440,9 @@ where
     // values
     for (primary_entry, secondary_entry) in zipped_cursor {
         let (primary_key, primary_value) = primary_entry?;
+        let (primary_key, primary_value) = (primary_key.key()?, primary_value.value()?);
         let (secondary_key, secondary_value) = secondary_entry?;
+        let (secondary_key, secondary_value) = (secondary_key.key()?, secondary_value.value()?);
 
         if primary_key != secondary_key {
             // if the keys are different, we need to check if the key is in the other table
@@ -235,6 +471,25 @@ where
     Ok(result)
 }
 
+/// Bounds of raw table keys.
+type RawKeyBounds<K> = (Bound<RawKey<K>>, Bound<RawKey<K>>);
+
+/// Returns the bounds of the raw keys that start with a number in the range.
+///
+/// Keys are compared by their encoding, which starts with the big-endian block or transaction
+/// number for all tables that can be diffed by range.
+fn raw_key_range<K: Key>(range: Option<&RangeInclusive<u64>>) -> RawKeyBounds<K> {
+    match range {
+        Some(range) => (
+            Bound::Included(RawKey::from_vec(range.start().to_be_bytes().to_vec())),
+            range.end().checked_add(1).map_or(Bound::Unbounded, |end| {
+                Bound::Excluded(RawKey::from_vec(end.to_be_bytes().to_vec()))
+            }),
+        ),
+        None => (Bound::Unbounded, Bound::Unbounded),
+    }
+}
+
 /// Includes a table element between two databases with the same key, but different values
 #[derive(Debug)]
 struct TableDiffElement<T: Table> {
@@ -349,3 +604,18 @@ impl<T: Table> ExtraTableElement<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ranges() {
+        assert_eq!(parse_range("100..200").unwrap(), 100..=199);
+        assert_eq!(parse_range("100..=200").unwrap(), 100..=200);
+        assert_eq!(parse_range("5..=5").unwrap(), 5..=5);
+        assert!(parse_range("5..5").is_err());
+        assert!(parse_range("0..0").is_err());
+        assert!(parse_range("100").is_err());
+    }
+}
@@ -1,4 +1,3 @@
-use alloy_consensus::Header;
 use alloy_primitives::{hex, BlockHash};
 use clap::Parser;
 use reth_db::static_file::{
@@ -9,7 +8,7 @@ use reth_db_api::{
     tables, RawKey, RawTable, Receipts, TableViewer, Transactions,
 };
 use reth_db_common::DbTool;
-use reth_node_api::{ReceiptTy, TxTy};
+use reth_node_api::{HeaderTy, ReceiptTy, TxTy};
 use reth_node_builder::NodeTypesWithDB;
 use reth_provider::{providers::ProviderNodeTypes, StaticFileProviderFactory};
 use reth_static_file_types::StaticFileSegment;
@@ -32,7 +31,8 @@ enum Subcommand {
         #[arg(value_parser = maybe_json_value_parser)]
         key: String,
 
-        /// The subkey to get content for
+        /// The subkey to get content for. If omitted for a dupsort table, all values of the key
+        /// are returned.
         #[arg(value_parser = maybe_json_value_parser)]
         subkey: Option<String>,
 
@@ -63,9 +63,10 @@ impl Command {
             }
             Subcommand::StaticFile { segment, key, raw } => {
                 let (key, mask): (u64, _) = match segment {
-                    StaticFileSegment::Headers => {
-                        (table_key::<tables::Headers>(&key)?, <HeaderWithHashMask<Header>>::MASK)
-                    }
+                    StaticFileSegment::Headers => (
+                        table_key::<tables::Headers>(&key)?,
+                        <HeaderWithHashMask<HeaderTy<N>>>::MASK,
+                    ),
                     StaticFileSegment::Transactions => {
                         (table_key::<tables::Transactions>(&key)?, <TransactionMask<TxTy<N>>>::MASK)
                     }
@@ -94,7 +95,7 @@ impl Command {
                         } else {
                             match segment {
                                 StaticFileSegment::Headers => {
-                                    let header = Header::decompress(content[0].as_slice())?;
+                                    let header = HeaderTy::<N>::decompress(content[0].as_slice())?;
                                     let block_hash = BlockHash::decompress(content[1].as_slice())?;
                                     println!(
                                         "Header\n{}\n\nBlockHash\n{}",
@@ -137,8 +138,8 @@ pub(crate) fn table_key<T: Table>(key: &str) -> Result<T::Key, eyre::Error> {
 }
 
 /// Get an instance of subkey for given dupsort table
-fn table_subkey<T: DupSort>(subkey: Option<&str>) -> Result<T::SubKey, eyre::Error> {
-    serde_json::from_str(subkey.unwrap_or_default()).map_err(|e| eyre::eyre!(e))
+fn table_subkey<T: DupSort>(subkey: &str) -> Result<T::SubKey, eyre::Error> {
+    serde_json::from_str(subkey).map_err(|e| eyre::eyre!(e))
 }
 
 struct GetValueViewer<'a, N: NodeTypesWithDB> {
@@ -178,8 +179,19 @@ impl<N: ProviderNodeTypes> TableViewer<()> for GetValueViewer<'_, N> {
         // get a key for given table
         let key = table_key::<T>(&self.key)?;
 
+        // without a subkey, return all values of the key
+        let Some(subkey) = self.subkey.as_deref() else {
+            let values = self.tool.get_dups::<T>(key)?;
+            if values.is_empty() {
+                error!(target: "reth::cli", "No content for the given table key.");
+            } else {
+                println!("{}", serde_json::to_string_pretty(&values)?);
+            }
+            return Ok(())
+        };
+
         // process dupsort table
-        let subkey = table_subkey::<T>(self.subkey.as_deref())?;
+        let subkey = table_subkey::<T>(subkey)?;
 
         match self.tool.get_dup::<T>(key, subkey)? {
             Some(content) => {
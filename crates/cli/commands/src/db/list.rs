@@ -1,15 +1,29 @@
-use super::tui::DbListTUI;
-use alloy_primitives::hex;
+use super::{
+    get::{maybe_json_value_parser, table_key},
+    tui::DbListTUI,
+};
+use alloy_primitives::{hex, Bytes};
 use clap::Parser;
 use eyre::WrapErr;
 use reth_chainspec::EthereumHardforks;
 use reth_db::DatabaseEnv;
-use reth_db_api::{database::Database, table::Table, RawValue, TableViewer, Tables};
+use reth_db_api::{
+    database::Database,
+    table::{Encode, Table},
+    RawValue, TableViewer, Tables,
+};
 use reth_db_common::{DbTool, ListFilter};
 use reth_node_builder::{NodeTypesWithDBAdapter, NodeTypesWithEngine};
-use std::{cell::RefCell, sync::Arc};
+use std::{
+    cell::RefCell,
+    io::{BufWriter, Write},
+    sync::Arc,
+};
 use tracing::error;
 
+/// The number of entries that are listed by default.
+const DEFAULT_LIST_LEN: usize = 5;
+
 #[derive(Parser, Debug)]
 /// The arguments for the `reth db list` command
 pub struct Command {
@@ -21,9 +35,9 @@ pub struct Command {
     /// Reverse the order of the entries. If enabled last table entries are read.
     #[arg(long, short, default_value_t = false)]
     reverse: bool,
-    /// How many items to take from the walker
-    #[arg(long, short, default_value_t = 5)]
-    len: usize,
+    /// How many items to take from the walker. Defaults to 5, or to all entries with `--ndjson`.
+    #[arg(long, short)]
+    len: Option<usize>,
     /// Search parameter for both keys and values. Prefix it with `0x` to search for binary data,
     /// and text otherwise.
     ///
@@ -43,9 +57,23 @@ pub struct Command {
     /// Returns the number of rows found.
     #[arg(long, short)]
     count: bool,
+    /// Key of the first entry to list, inclusive. Multi-field keys are passed as JSON.
+    #[arg(long, value_parser = maybe_json_value_parser)]
+    start_key: Option<String>,
+    /// Key at which to stop listing, exclusive. Multi-field keys are passed as JSON.
+    #[arg(long, value_parser = maybe_json_value_parser)]
+    end_key: Option<String>,
+    /// Only list entries whose encoded key starts with the given bytes, e.g. the address of
+    /// `PlainStorageState` entries. Must be `0x`-prefixed hex.
+    #[arg(long, value_parser = parse_key_prefix)]
+    key_prefix: Option<Bytes>,
     /// Dump as JSON instead of using TUI.
     #[arg(long, short)]
     json: bool,
+    /// Dump as newline-delimited JSON, one `{"key", "value"}` object per entry, instead of using
+    /// TUI.
+    #[arg(long, conflicts_with = "json")]
+    ndjson: bool,
     /// Output bytes instead of human-readable decoded value
     #[arg(long)]
     raw: bool,
@@ -61,7 +89,7 @@ impl Command {
     }
 
     /// Generate [`ListFilter`] from command.
-    pub fn list_filter(&self) -> ListFilter {
+    pub fn list_filter<T: Table>(&self) -> eyre::Result<ListFilter> {
         let search = self
             .search
             .as_ref()
@@ -73,19 +101,38 @@ impl Command {
             })
             .unwrap_or_default();
 
-        ListFilter {
+        let encoded_key =
+            |key: &String| table_key::<T>(key).map(|key| Encode::encode(key).as_ref().to_vec());
+
+        Ok(ListFilter {
             skip: self.skip,
-            len: self.len,
+            len: self.len(),
             search,
             min_row_size: self.min_row_size,
             min_key_size: self.min_key_size,
             min_value_size: self.min_value_size,
             reverse: self.reverse,
             only_count: self.count,
-        }
+            start_key: self.start_key.as_ref().map(encoded_key).transpose()?,
+            end_key: self.end_key.as_ref().map(encoded_key).transpose()?,
+            key_prefix: self.key_prefix.clone().map(Vec::from).unwrap_or_default(),
+        })
+    }
+
+    /// Returns the number of entries to list.
+    fn len(&self) -> usize {
+        self.len.unwrap_or(if self.ndjson { usize::MAX } else { DEFAULT_LIST_LEN })
     }
 }
 
+/// Parses the `0x`-prefixed hex key prefix.
+fn parse_key_prefix(value: &str) -> eyre::Result<Bytes> {
+    if !value.starts_with("0x") {
+        eyre::bail!("key prefix must be 0x-prefixed hex")
+    }
+    Ok(value.parse()?)
+}
+
 struct ListTableViewer<'a, N: NodeTypesWithEngine> {
     tool: &'a DbTool<NodeTypesWithDBAdapter<N, Arc<DatabaseEnv>>>,
     args: &'a Command,
@@ -112,9 +159,24 @@ impl<N: NodeTypesWithEngine> TableViewer<()> for ListTableViewer<'_, N> {
             }
 
 
-            let list_filter = self.args.list_filter();
+            let list_filter = self.args.list_filter::<T>()?;
 
-            if self.args.json || self.args.count {
+            if self.args.ndjson && !self.args.count {
+                // stream the entries as they're read, the output can be the whole table
+                let mut stdout = BufWriter::new(std::io::stdout().lock());
+                self.tool.list_with::<T>(&list_filter, |(key, value)| {
+                    let line = if self.args.raw {
+                        let value = hex::encode_prefixed(RawValue::new(value).raw_value());
+                        serde_json::json!({ "key": key, "value": value })
+                    } else {
+                        serde_json::json!({ "key": key, "value": value })
+                    };
+                    writeln!(stdout, "{line}")?;
+                    Ok(())
+                })?;
+                stdout.flush()?;
+                Ok(())
+            } else if self.args.json || self.args.count {
                 let (list, count) = self.tool.list::<T>(&list_filter)?;
 
                 if self.args.count {
@@ -131,7 +193,7 @@ impl<N: NodeTypesWithEngine> TableViewer<()> for ListTableViewer<'_, N> {
                 DbListTUI::<_, T>::new(|skip, len| {
                     list_filter.borrow_mut().update_page(skip, len);
                     self.tool.list::<T>(&list_filter.borrow()).unwrap().0
-                }, self.args.skip, self.args.len, total_entries, self.args.raw).run()
+                }, self.args.skip, self.args.len(), total_entries, self.args.raw).run()
             }
         })??;
 
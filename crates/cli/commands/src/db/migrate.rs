@@ -0,0 +1,44 @@
+use clap::Parser;
+use reth_db::{
+    init_db,
+    mdbx::DatabaseArguments,
+    migration::MigrationRegistry,
+    version::{get_db_version, DB_VERSION},
+};
+use std::path::Path;
+use tracing::info;
+
+/// The arguments for the `reth db migrate` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// Only lists the migrations that would be applied, with their rollback notes, without
+    /// applying them.
+    #[arg(long)]
+    dry_run: bool,
+}
+
+impl Command {
+    /// Execute `db migrate` command
+    pub fn execute(self, db_path: &Path, db_args: DatabaseArguments) -> eyre::Result<()> {
+        let version = get_db_version(db_path)?;
+        if version == DB_VERSION {
+            info!(target: "reth::cli", version, "Database is up to date");
+            return Ok(())
+        }
+
+        let steps = MigrationRegistry::new().plan(version)?;
+        for step in &steps {
+            println!("{step}");
+        }
+        if self.dry_run {
+            info!(target: "reth::cli", from_version = version, to_version = DB_VERSION, migrations = steps.len(), "Dry run, not migrating the database");
+            return Ok(())
+        }
+
+        // the migrations are applied by `init_db` once the tables are created
+        init_db(db_path, db_args)?;
+        info!(target: "reth::cli", from_version = version, to_version = DB_VERSION, "Migrated database");
+
+        Ok(())
+    }
+}
@@ -11,9 +11,11 @@ mod clear;
 mod diff;
 mod get;
 mod list;
+mod migrate;
 mod stats;
 /// DB List TUI
 mod tui;
+mod verify_blooms;
 
 /// `reth db` command
 #[derive(Debug, Parser)]
@@ -46,6 +48,11 @@ pub enum Subcommands {
     },
     /// Deletes all table entries
     Clear(clear::Command),
+    /// Recomputes the logs blooms of the headers from the logs of their receipts and reports
+    /// diverging ones
+    VerifyBlooms(verify_blooms::Command),
+    /// Applies the migrations of the database from its local version to the current version
+    Migrate(migrate::Command),
     /// Lists current and local database versions
     Version,
     /// Returns the full database path
@@ -65,7 +72,7 @@ macro_rules! db_ro_exec {
 impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
     /// Execute `db` command
     pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec>>(self) -> eyre::Result<()> {
-        let data_dir = self.env.datadir.clone().resolve_datadir(self.env.chain.chain());
+        let data_dir = self.env.resolve_datadir()?;
         let db_path = data_dir.db();
         let static_files_path = data_dir.static_files();
         let exex_wal_path = data_dir.exex_wal();
@@ -131,6 +138,13 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C>
                 let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RW)?;
                 command.execute(provider_factory)?;
             }
+            Subcommands::VerifyBlooms(command) => {
+                let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+                command.execute(provider_factory)?;
+            }
+            Subcommands::Migrate(command) => {
+                command.execute(&db_path, self.env.db.database_args())?;
+            }
             Subcommands::Version => {
                 let local_db_version = match get_db_version(&db_path) {
                     Ok(version) => Some(version),
@@ -173,4 +187,24 @@ mod tests {
         .unwrap();
         assert_eq!(cmd.env.datadir.resolve_datadir(cmd.env.chain.chain).as_ref(), Path::new(&path));
     }
+
+    #[test]
+    fn resolve_memory_datadir() {
+        let cmd = Command::<EthereumChainSpecParser>::try_parse_from([
+            "reth",
+            "--datadir",
+            "memory",
+            "stats",
+        ])
+        .unwrap();
+
+        let data_dir = cmd.env.resolve_datadir().unwrap();
+        assert_ne!(data_dir.data_dir(), Path::new("memory"));
+        assert!(data_dir.data_dir().is_dir());
+        // the ephemeral datadir is created once and reused
+        assert_eq!(cmd.env.resolve_datadir().unwrap().as_ref(), data_dir.as_ref());
+
+        drop(cmd);
+        assert!(!data_dir.data_dir().exists());
+    }
 }
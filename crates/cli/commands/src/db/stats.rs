@@ -11,7 +11,10 @@ use reth_db_common::DbTool;
 use reth_fs_util as fs;
 use reth_node_builder::{NodePrimitives, NodeTypesWithDB, NodeTypesWithDBAdapter};
 use reth_node_core::dirs::{ChainPath, DataDirPath};
-use reth_provider::providers::{ProviderNodeTypes, StaticFileProvider};
+use reth_provider::{
+    providers::{ProviderNodeTypes, StaticFileProvider},
+    StorageBreakdownProvider,
+};
 use reth_static_file_types::SegmentRangeInclusive;
 use std::{sync::Arc, time::Duration};
 
@@ -34,6 +37,11 @@ pub struct Command {
     /// For individual table checksums, use the `reth db checksum` command.
     #[arg(long, default_value_t = false)]
     checksum: bool,
+
+    /// Show the disk usage attributed to state, history indices, headers, transactions, receipts
+    /// and the transaction index, and the blocks that are due for pruning.
+    #[arg(long, default_value_t = false)]
+    breakdown: bool,
 }
 
 impl Command {
@@ -57,9 +65,84 @@ impl Command {
         let db_stats_table = self.db_stats_table(tool)?;
         println!("{db_stats_table}");
 
+        if self.breakdown {
+            println!("\n");
+
+            let (breakdown_table, prune_backlog_table) = self.breakdown_tables(tool)?;
+            println!("{breakdown_table}");
+
+            if let Some(prune_backlog_table) = prune_backlog_table {
+                println!("\n");
+                println!("{prune_backlog_table}");
+            }
+        }
+
         Ok(())
     }
 
+    fn breakdown_tables<N: ProviderNodeTypes>(
+        &self,
+        tool: &DbTool<N>,
+    ) -> eyre::Result<(ComfyTable, Option<ComfyTable>)> {
+        let breakdown = tool.provider_factory.storage_breakdown()?;
+
+        let mut table = ComfyTable::new();
+        table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+        table.set_header(["Category", "Database Size", "Static Files Size", "Total Size", "Share"]);
+
+        for category in &breakdown.categories {
+            let share = if breakdown.total_size == 0 {
+                0.0
+            } else {
+                category.size() as f64 * 100.0 / breakdown.total_size as f64
+            };
+
+            let mut row = Row::new();
+            row.add_cell(Cell::new(format!("{:?}", category.category)))
+                .add_cell(Cell::new(human_bytes(category.database_size as f64)))
+                .add_cell(Cell::new(human_bytes(category.static_files_size as f64)))
+                .add_cell(Cell::new(human_bytes(category.size() as f64)))
+                .add_cell(Cell::new(format!("{share:.2}%")));
+            table.add_row(row);
+        }
+
+        let max_widths = table.column_max_content_widths();
+        let mut separator = Row::new();
+        for width in max_widths {
+            separator.add_cell(Cell::new("-".repeat(width as usize)));
+        }
+        table.add_row(separator);
+
+        let mut row = Row::new();
+        row.add_cell(Cell::new("Total"))
+            .add_cell(Cell::new(""))
+            .add_cell(Cell::new(""))
+            .add_cell(Cell::new(human_bytes(breakdown.total_size as f64)))
+            .add_cell(Cell::new(""));
+        table.add_row(row);
+
+        if breakdown.prune_backlog.is_empty() {
+            return Ok((table, None))
+        }
+
+        let mut prune_backlog_table = ComfyTable::new();
+        prune_backlog_table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+        prune_backlog_table.set_header(["Prune Segment", "Pruned Block", "Target Block", "Blocks"]);
+
+        for backlog in &breakdown.prune_backlog {
+            let mut row = Row::new();
+            row.add_cell(Cell::new(&backlog.segment))
+                .add_cell(Cell::new(
+                    backlog.pruned_block.map(|block| block.to_string()).unwrap_or_default(),
+                ))
+                .add_cell(Cell::new(backlog.target_block))
+                .add_cell(Cell::new(backlog.blocks));
+            prune_backlog_table.add_row(row);
+        }
+
+        Ok((table, Some(prune_backlog_table)))
+    }
+
     fn db_stats_table<N: NodeTypesWithDB<DB = Arc<DatabaseEnv>>>(
         &self,
         tool: &DbTool<N>,
@@ -0,0 +1,168 @@
+use alloy_consensus::{BlockHeader, TxReceipt};
+use alloy_primitives::{logs_bloom, BlockNumber, Bloom};
+use clap::Parser;
+use reth_provider::{
+    providers::ProviderNodeTypes, HeaderProvider, ProviderError, ProviderFactory,
+    PruneCheckpointReader, ReceiptProvider, StageCheckpointReader,
+};
+use reth_prune::PruneSegment;
+use reth_stages::StageId;
+use std::ops::RangeInclusive;
+use tracing::info;
+
+/// The arguments for the `reth db verify-blooms` command
+#[derive(Parser, Debug)]
+pub struct Command {
+    /// The first block to check.
+    ///
+    /// Defaults to the first block with unpruned receipts.
+    #[arg(long, value_name = "BLOCK_NUMBER")]
+    from: Option<BlockNumber>,
+
+    /// The last block to check, inclusive.
+    ///
+    /// Defaults to the highest executed block.
+    #[arg(long, value_name = "BLOCK_NUMBER")]
+    to: Option<BlockNumber>,
+}
+
+impl Command {
+    /// Execute `db verify-blooms` command
+    ///
+    /// The logs bloom is part of the block hash, so diverging headers are never rewritten. A
+    /// diverging logs bloom means that the stored receipts of the block are wrong, which is fixed
+    /// by unwinding below the block and executing it again.
+    pub fn execute<N: ProviderNodeTypes>(
+        self,
+        provider_factory: ProviderFactory<N>,
+    ) -> eyre::Result<()> {
+        let verifiable = verifiable_range(&provider_factory)?;
+        let from = self.from.unwrap_or(*verifiable.start());
+        let to = self.to.unwrap_or(*verifiable.end());
+        if from < *verifiable.start() || to > *verifiable.end() {
+            eyre::bail!(
+                "Logs blooms can only be recomputed for blocks {verifiable:?}, which are executed and whose receipts are not pruned"
+            )
+        }
+
+        let mismatches = find_bloom_mismatches(&provider_factory, from..=to)?;
+        for mismatch in &mismatches {
+            println!(
+                "Block {}: stored logs bloom diverges from the logs of its receipts",
+                mismatch.number
+            );
+        }
+        println!("Found {} diverging logs blooms in blocks {from}..={to}", mismatches.len());
+
+        if let Some(first) = mismatches.first() {
+            eyre::bail!(
+                "The stored receipts of {} blocks diverge from their headers, unwind below block {} with `reth stage unwind` and sync again to execute them again",
+                mismatches.len(),
+                first.number
+            )
+        }
+
+        Ok(())
+    }
+}
+
+/// A block whose stored logs bloom diverges from the bloom of the logs of its receipts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct BloomMismatch {
+    /// The number of the block.
+    pub(crate) number: BlockNumber,
+    /// The logs bloom recomputed from the stored receipts.
+    pub(crate) computed: Bloom,
+}
+
+/// Returns the range of blocks whose logs blooms can be recomputed: the executed blocks whose
+/// receipts are not pruned.
+pub(crate) fn verifiable_range<N: ProviderNodeTypes>(
+    provider_factory: &ProviderFactory<N>,
+) -> eyre::Result<RangeInclusive<BlockNumber>> {
+    let provider = provider_factory.provider()?;
+    let executed =
+        provider.get_stage_checkpoint(StageId::Execution)?.unwrap_or_default().block_number;
+
+    // receipts are pruned entirely, or filtered by contract address, up to the checkpoint
+    let mut start = 0;
+    for segment in [PruneSegment::Receipts, PruneSegment::ContractLogs] {
+        if let Some(pruned) = provider.get_prune_checkpoint(segment)?.and_then(|c| c.block_number) {
+            start = start.max(pruned + 1);
+        }
+    }
+
+    Ok(start..=executed)
+}
+
+/// Recomputes the logs blooms of the blocks in the range from the logs of their receipts, and
+/// returns the blocks whose stored logs bloom diverges.
+pub(crate) fn find_bloom_mismatches<N: ProviderNodeTypes>(
+    provider_factory: &ProviderFactory<N>,
+    range: RangeInclusive<BlockNumber>,
+) -> eyre::Result<Vec<BloomMismatch>> {
+    let provider = provider_factory.provider()?.disable_long_read_transaction_safety();
+    info!(target: "reth::cli", ?range, "Recomputing logs blooms");
+
+    let mut mismatches = Vec::new();
+    for number in range {
+        if number % 100_000 == 0 {
+            info!(target: "reth::cli", block = number, mismatches = mismatches.len(), "Recomputing logs blooms");
+        }
+
+        let header = provider
+            .header_by_number(number)?
+            .ok_or_else(|| ProviderError::HeaderNotFound(number.into()))?;
+        let receipts = provider
+            .receipts_by_block(number.into())?
+            .ok_or_else(|| ProviderError::BlockBodyIndicesNotFound(number))?;
+
+        let computed = logs_bloom(receipts.iter().flat_map(|receipt| receipt.logs()));
+        if header.logs_bloom() != computed {
+            mismatches.push(BloomMismatch { number, computed });
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_primitives_traits::RecoveredBlock;
+    use reth_provider::{
+        test_utils::{create_test_provider_factory, BlockchainTestData},
+        DatabaseProviderFactory, OriginalValuesKnown, StateWriter, StorageLocation,
+    };
+
+    #[test]
+    fn finds_diverging_blooms() {
+        let factory = create_test_provider_factory();
+        let data = BlockchainTestData::default();
+
+        let provider_rw = factory.database_provider_rw().unwrap();
+        provider_rw.insert_historical_block(data.genesis.try_recover().unwrap()).unwrap();
+        let mut expected = Vec::new();
+        for (block, outcome) in data.blocks {
+            let computed = logs_bloom(outcome.receipts.iter().flatten().flat_map(|r| r.logs()));
+            let (mut block, senders) = block.split();
+            // all blocks but the third one carry the bloom of their receipts
+            if block.header.number == 3 {
+                block.header.logs_bloom = Bloom::repeat_byte(0xff);
+                expected.push(BloomMismatch { number: 3, computed });
+            } else {
+                block.header.logs_bloom = computed;
+            }
+            provider_rw
+                .insert_historical_block(RecoveredBlock::new_unhashed(block, senders))
+                .unwrap();
+            provider_rw
+                .write_state(&outcome, OriginalValuesKnown::No, StorageLocation::Database)
+                .unwrap();
+        }
+        provider_rw.commit().unwrap();
+
+        assert_eq!(find_bloom_mismatches(&factory, 1..=5).unwrap(), expected);
+        assert_eq!(find_bloom_mismatches(&factory, 4..=5).unwrap(), vec![]);
+    }
+}
@@ -0,0 +1,292 @@
+//! Command that exports self-contained inputs of synced blocks for external proving systems.
+//!
+//! For every block of the range, a `<block_number>.json` file with a [`ProverInput`] is written to
+//! the output directory. All binary fields are `0x`-prefixed hex strings:
+//!
+//! ```json
+//! {
+//!   "block": "0x...",
+//!   "witness": {
+//!     "state": ["0x..."],
+//!     "codes": ["0x..."],
+//!     "keys": ["0x..."]
+//!   },
+//!   "ancestorHeaders": ["0x..."],
+//!   "chainConfig": {
+//!     "chainId": 1,
+//!     "hardforks": [
+//!       { "name": "Homestead", "block": 1150000 },
+//!       { "name": "Shanghai", "timestamp": 1681338455 }
+//!     ],
+//!     "baseFeeParams": { "max_change_denominator": "0x8", "elasticity_multiplier": "0x2" },
+//!     "blobParams": { "target": 3, "max": 6, "baseFeeUpdateFraction": 3338477 },
+//!     "depositContract": "0x00000000219ab540356cbb839cbe05303d7705fa"
+//!   }
+//! }
+//! ```
+
+use crate::common::{AccessRights, CliNodeComponents, CliNodeTypes, Environment, EnvironmentArgs};
+use alloy_consensus::BlockHeader;
+use alloy_eips::{eip1559::BaseFeeParams, eip7840::BlobParams};
+use alloy_primitives::{Address, BlockNumber, Bytes};
+use clap::Parser;
+use eyre::WrapErr;
+use reth_chainspec::{EthChainSpec, EthereumHardforks, ForkCondition, Hardforks};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_evm::execute::{BlockExecutorProvider, Executor};
+use reth_provider::{
+    BlockReader, ChainSpecProvider, HeaderProvider, ProviderError, StageCheckpointReader,
+    StateProofProvider, TransactionVariant,
+};
+use reth_revm::{database::StateProviderDatabase, witness::ExecutionWitnessRecord};
+use reth_stages::StageId;
+use serde::{Deserialize, Serialize};
+use std::{path::PathBuf, sync::Arc};
+use tracing::info;
+
+/// Exports the inputs to prove the execution of a range of synced blocks.
+#[derive(Debug, Parser)]
+pub struct ExportProverInputsCommand<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// The first block to export.
+    #[arg(long, value_name = "BLOCK_NUMBER", value_parser = clap::value_parser!(u64).range(1..))]
+    from: BlockNumber,
+
+    /// The last block to export, inclusive.
+    #[arg(long, value_name = "BLOCK_NUMBER")]
+    to: BlockNumber,
+
+    /// The directory the `<block_number>.json` files are written to.
+    #[arg(value_name = "OUTPUT_DIR")]
+    output: PathBuf,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks + Hardforks>>
+    ExportProverInputsCommand<C>
+{
+    /// Execute `export-prover-inputs` command
+    pub async fn execute<N, Comp, F>(self, components: F) -> eyre::Result<()>
+    where
+        N: CliNodeTypes<ChainSpec = C::ChainSpec>,
+        Comp: CliNodeComponents<N>,
+        F: FnOnce(Arc<C::ChainSpec>) -> Comp,
+    {
+        if self.from > self.to {
+            eyre::bail!("--from {} is higher than --to {}", self.from, self.to)
+        }
+
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+        let components = components(provider_factory.chain_spec());
+        let executor_provider = components.executor();
+
+        let executed = provider_factory
+            .provider()?
+            .get_stage_checkpoint(StageId::Execution)?
+            .unwrap_or_default()
+            .block_number;
+        if self.to > executed {
+            eyre::bail!(
+                "Block {} is not synced yet, the highest executed block is {executed}",
+                self.to
+            )
+        }
+
+        reth_fs_util::create_dir_all(&self.output)?;
+        let chain_spec = provider_factory.chain_spec();
+
+        info!(target: "reth::cli", from = self.from, to = self.to, output = %self.output.display(), "Exporting prover inputs");
+        for block_number in self.from..=self.to {
+            let block = provider_factory
+                .recovered_block(block_number.into(), TransactionVariant::NoHash)?
+                .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+
+            // re-execute the block on top of its parent state, recording all accessed state
+            let parent_number = block_number - 1;
+            let state_provider = provider_factory.history_by_block_number(parent_number)?;
+            let mut record = ExecutionWitnessRecord::default();
+            let mut lowest_block_number = None;
+            executor_provider
+                .executor(StateProviderDatabase::new(&state_provider))
+                .execute_with_state_closure(&block, |state| {
+                    record.record_executed_state(state);
+                    // the lowest block whose hash was read with `BLOCKHASH`
+                    lowest_block_number = state.block_hashes.keys().next().copied();
+                })
+                .wrap_err_with(|| format!("Failed to execute block {block_number}"))?;
+
+            let ExecutionWitnessRecord { hashed_state, codes, keys } = record;
+            let state = state_provider.witness(Default::default(), hashed_state)?;
+
+            // the parent header is always included, it commits to the pre-state root
+            let ancestor_headers = provider_factory
+                .headers_range(lowest_block_number.unwrap_or(parent_number)..=parent_number)?
+                .iter()
+                .map(|header| alloy_rlp::encode(header).into())
+                .collect();
+
+            let input = ProverInput {
+                block: alloy_rlp::encode(block.sealed_block()).into(),
+                witness: ProverWitness { state, codes, keys },
+                ancestor_headers,
+                chain_config: ProverChainConfig::from_chain_spec(
+                    &*chain_spec,
+                    block.header().timestamp(),
+                ),
+            };
+            reth_fs_util::write_json_file(
+                &self.output.join(format!("{block_number}.json")),
+                &input,
+            )?;
+
+            if block_number % 100 == 0 || block_number == self.to {
+                info!(target: "reth::cli", block_number, "Exported prover inputs");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Everything needed to prove the execution of a block, without access to a node's database.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProverInput {
+    /// The RLP encoded block.
+    pub block: Bytes,
+    /// The state accessed during the execution of the block.
+    pub witness: ProverWitness,
+    /// The RLP encoded headers of the ancestors of the block, in ascending order and ending with
+    /// the parent header.
+    ///
+    /// The headers start at the lowest block whose hash is read with `BLOCKHASH` during the
+    /// execution of the block, if any.
+    pub ancestor_headers: Vec<Bytes>,
+    /// The rules the block is executed with.
+    pub chain_config: ProverChainConfig,
+}
+
+/// The state accessed during the execution of a block.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProverWitness {
+    /// The RLP encoded trie nodes of the parent state, needed to read the accessed accounts and
+    /// storage slots and to compute the post state root.
+    pub state: Vec<Bytes>,
+    /// The bytecodes of all accessed and created contracts.
+    pub codes: Vec<Bytes>,
+    /// The preimages of the hashed addresses and storage slots of the accessed state.
+    pub keys: Vec<Bytes>,
+}
+
+/// The rules of a chain a block is executed with: the chain id, the hardfork activations and the
+/// parameters that are in effect at the block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProverChainConfig {
+    /// The chain id.
+    pub chain_id: u64,
+    /// The activated hardforks, in the order of their activation.
+    pub hardforks: Vec<HardforkActivation>,
+    /// The EIP-1559 parameters the base fee of the block is computed with.
+    pub base_fee_params: BaseFeeParams,
+    /// The EIP-4844 blob parameters in effect at the block, if blobs are activated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob_params: Option<BlobParams>,
+    /// The address of the deposit contract whose logs are EIP-6110 deposit requests, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deposit_contract: Option<Address>,
+}
+
+impl ProverChainConfig {
+    /// Collects the chain id and all hardforks that are activated on the chain, and the
+    /// parameters that are in effect at the given block timestamp.
+    pub fn from_chain_spec<ChainSpec: EthChainSpec + Hardforks>(
+        chain_spec: &ChainSpec,
+        timestamp: u64,
+    ) -> Self {
+        let hardforks = chain_spec
+            .forks_iter()
+            .filter_map(|(fork, condition)| {
+                let (block, timestamp) = match condition {
+                    ForkCondition::Block(block) => (Some(block), None),
+                    ForkCondition::TTD { activation_block_number, .. } => {
+                        (Some(activation_block_number), None)
+                    }
+                    ForkCondition::Timestamp(timestamp) => (None, Some(timestamp)),
+                    ForkCondition::Never => return None,
+                };
+                Some(HardforkActivation { name: fork.name().to_string(), block, timestamp })
+            })
+            .collect();
+        Self {
+            chain_id: chain_spec.chain_id(),
+            hardforks,
+            base_fee_params: chain_spec.base_fee_params_at_timestamp(timestamp),
+            blob_params: chain_spec.blob_params_at_timestamp(timestamp),
+            deposit_contract: chain_spec.deposit_contract().map(|contract| contract.address),
+        }
+    }
+}
+
+/// The block number or timestamp a hardfork is activated at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HardforkActivation {
+    /// The name of the hardfork.
+    pub name: String,
+    /// The block number the hardfork is activated at, for block based hardforks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block: Option<BlockNumber>,
+    /// The timestamp the hardfork is activated at, for timestamp based hardforks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_chainspec::MAINNET;
+    use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
+
+    #[test]
+    fn parse_export_prover_inputs_command() {
+        let args: ExportProverInputsCommand<EthereumChainSpecParser> =
+            ExportProverInputsCommand::parse_from(["reth", "--from", "10", "--to", "20", "inputs"]);
+        assert_eq!(args.from, 10);
+        assert_eq!(args.to, 20);
+        assert_eq!(args.output, PathBuf::from("inputs"));
+    }
+
+    #[test]
+    fn mainnet_chain_config() {
+        // the Cancun activation on mainnet
+        let config = ProverChainConfig::from_chain_spec(&*MAINNET, 1_710_338_135);
+        assert_eq!(config.chain_id, 1);
+        assert_eq!(
+            config.hardforks.iter().find(|fork| fork.name == "Homestead"),
+            Some(&HardforkActivation {
+                name: "Homestead".to_string(),
+                block: Some(1_150_000),
+                timestamp: None
+            })
+        );
+
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["chainId"], 1);
+        assert_eq!(
+            json["hardforks"][1],
+            serde_json::json!({ "name": "Homestead", "block": 1150000 })
+        );
+        assert_eq!(config.base_fee_params, BaseFeeParams::ethereum());
+        assert_eq!(config.blob_params, Some(BlobParams::cancun()));
+        assert_eq!(
+            config.deposit_contract,
+            Some(alloy_primitives::address!("0x00000000219ab540356cbb839cbe05303d7705fa"))
+        );
+
+        // blobs are not activated before Cancun
+        let config = ProverChainConfig::from_chain_spec(&*MAINNET, 0);
+        assert_eq!(config.blob_params, None);
+        assert!(serde_json::to_value(&config).unwrap().get("blobParams").is_none());
+    }
+}
@@ -0,0 +1,140 @@
+//! Command that syncs the chain from a directory of era1 files, without networking.
+use crate::{
+    common::{AccessRights, CliNodeComponents, CliNodeTypes, Environment, EnvironmentArgs},
+    import::build_import_pipeline,
+};
+use clap::Parser;
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_db_api::{tables, transaction::DbTx};
+use reth_downloaders::{era_file::ERA1_FILE_EXTENSION, file_client::FileClient};
+use reth_node_api::BlockTy;
+use reth_node_core::version::SHORT_VERSION;
+use reth_provider::{BlockNumReader, ChainSpecProvider, StageCheckpointReader};
+use reth_prune::PruneModes;
+use reth_stages::StageId;
+use reth_static_file::StaticFileProducer;
+use std::{path::PathBuf, sync::Arc};
+use tracing::{debug, info};
+
+/// Syncs the chain from a directory of era1 files without networking.
+#[derive(Debug, Parser)]
+pub struct ImportEraCommand<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// Disables stages that require state.
+    #[arg(long, verbatim_doc_comment)]
+    no_state: bool,
+
+    /// The path to a directory of era1 files.
+    ///
+    /// The files are imported in the order of their names, and all stages are executed for the
+    /// blocks of each file. Blocks that are already synced are skipped, so the chain can also be
+    /// synced on top of a state snapshot initialized with `reth init-state`.
+    #[arg(value_name = "ERA_DIR", verbatim_doc_comment)]
+    path: PathBuf,
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> ImportEraCommand<C> {
+    /// Execute `import-era` command
+    pub async fn execute<N, Comp, F>(self, components: F) -> eyre::Result<()>
+    where
+        N: CliNodeTypes<ChainSpec = C::ChainSpec>,
+        Comp: CliNodeComponents<N>,
+        F: FnOnce(Arc<N::ChainSpec>) -> Comp,
+    {
+        info!(target: "reth::cli", "reth {} starting", SHORT_VERSION);
+
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&self.path)? {
+            let path = entry?.path();
+            if path.extension().is_some_and(|extension| extension == ERA1_FILE_EXTENSION) {
+                files.push(path);
+            }
+        }
+        files.sort();
+        if files.is_empty() {
+            eyre::bail!("no era1 files found in {}", self.path.display())
+        }
+
+        let Environment { provider_factory, config, .. } = self.env.init::<N>(AccessRights::RW)?;
+
+        let components = components(provider_factory.chain_spec());
+        let executor = components.executor().clone();
+        let consensus = Arc::new(components.consensus().clone());
+
+        let mut total_decoded_blocks = 0;
+        for file in files {
+            let last_block_number = provider_factory.last_block_number()?;
+            let file_client =
+                FileClient::<BlockTy<N>>::from_era1_file(&file, Some(last_block_number)).await?;
+            if file_client.headers_len() == 0 {
+                debug!(target: "reth::cli", file = %file.display(), "Skipping synced era1 file");
+                continue
+            }
+            if file_client.min_block() != Some(last_block_number + 1) {
+                eyre::bail!(
+                    "era1 file {} doesn't continue the chain at block {}",
+                    file.display(),
+                    last_block_number + 1
+                )
+            }
+
+            info!(target: "reth::cli",
+                file = %file.display(),
+                from = ?file_client.min_block(),
+                to = ?file_client.max_block(),
+                "Importing era1 file"
+            );
+            total_decoded_blocks += file_client.headers_len();
+
+            let tip = file_client.tip().ok_or(eyre::eyre!("file client has no tip"))?;
+            let (mut pipeline, events) = build_import_pipeline(
+                &config,
+                provider_factory.clone(),
+                &consensus,
+                Arc::new(file_client),
+                StaticFileProducer::new(provider_factory.clone(), PruneModes::default()),
+                self.no_state,
+                executor.clone(),
+            )?;
+            pipeline.set_tip(tip);
+
+            let latest_block_number = provider_factory
+                .provider()?
+                .get_stage_checkpoint(StageId::Finish)?
+                .map(|ch| ch.block_number);
+            tokio::spawn(reth_node_events::node::handle_events(None, latest_block_number, events));
+
+            tokio::select! {
+                res = pipeline.run() => res?,
+                _ = tokio::signal::ctrl_c() => return Ok(()),
+            }
+        }
+
+        let total_imported_blocks =
+            provider_factory.provider()?.tx_ref().entries::<tables::HeaderNumbers>()?;
+        info!(target: "reth::cli",
+            total_decoded_blocks,
+            total_imported_blocks,
+            "Era1 files imported"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
+
+    #[test]
+    fn parse_import_era_command() {
+        let args: ImportEraCommand<EthereumChainSpecParser> =
+            ImportEraCommand::parse_from(["reth", "--no-state", "era"]);
+        assert!(args.no_state);
+        assert_eq!(args.path, PathBuf::from("era"));
+    }
+}
@@ -1,9 +1,11 @@
-use alloy_consensus::{BlockHeader, Header};
+use alloy_consensus::BlockHeader;
 use alloy_primitives::{BlockNumber, B256, U256};
 use alloy_rlp::Decodable;
 use reth_codecs::Compact;
 use reth_node_builder::NodePrimitives;
-use reth_primitives_traits::{SealedBlock, SealedHeader, SealedHeaderFor};
+use reth_primitives_traits::{
+    BlockHeaderMut, HeaderTy, SealedBlock, SealedHeader, SealedHeaderFor,
+};
 use reth_provider::{
     providers::StaticFileProvider, BlockWriter, StageCheckpointWriter, StaticFileProviderFactory,
     StaticFileWriter, StorageLocation,
@@ -14,12 +16,12 @@ use std::{fs::File, io::Read, path::PathBuf};
 use tracing::info;
 
 /// Reads the header RLP from a file and returns the Header.
-pub(crate) fn read_header_from_file(path: PathBuf) -> Result<Header, eyre::Error> {
+pub(crate) fn read_header_from_file<H: Decodable>(path: PathBuf) -> Result<H, eyre::Error> {
     let mut file = File::open(path)?;
     let mut buf = Vec::new();
     file.read_to_end(&mut buf)?;
 
-    let header = Header::decode(&mut &buf[..])?;
+    let header = H::decode(&mut &buf[..])?;
     Ok(header)
 }
 
@@ -31,9 +33,10 @@ pub fn setup_without_evm<Provider>(
     total_difficulty: U256,
 ) -> Result<(), eyre::Error>
 where
-    Provider: StaticFileProviderFactory<Primitives: NodePrimitives<BlockHeader = Header>>
+    Provider: StaticFileProviderFactory
         + StageCheckpointWriter
         + BlockWriter<Block = <Provider::Primitives as NodePrimitives>::Block>,
+    HeaderTy<Provider::Primitives>: BlockHeaderMut + Compact,
 {
     info!(target: "reth::cli", "Setting up dummy EVM chain before importing state.");
 
@@ -97,7 +100,7 @@ where
 /// * Headers: It will push an empty block.
 /// * Transactions: It will not push any tx, only increments the end block range.
 /// * Receipts: It will not push any receipt, only increments the end block range.
-fn append_dummy_chain<N: NodePrimitives<BlockHeader = Header>>(
+fn append_dummy_chain<N: NodePrimitives<BlockHeader: BlockHeaderMut + Compact>>(
     sf_provider: &StaticFileProvider<N>,
     target_height: BlockNumber,
 ) -> Result<(), eyre::Error> {
@@ -122,11 +125,11 @@ fn append_dummy_chain<N: NodePrimitives<BlockHeader = Header>>(
     // Spawn job for appending empty headers
     let provider = sf_provider.clone();
     std::thread::spawn(move || {
-        let mut empty_header = Header::default();
+        let mut empty_header = N::BlockHeader::default();
         let result = provider.latest_writer(StaticFileSegment::Headers).and_then(|mut writer| {
             for block_num in 1..=target_height {
                 // TODO: should we fill with real parent_hash?
-                empty_header.number = block_num;
+                empty_header.set_number(block_num);
                 writer.append_header(&empty_header, U256::ZERO, &B256::ZERO)?;
             }
             Ok(())
@@ -0,0 +1,103 @@
+//! CLI command to manage the encrypted p2p secret key and JWT secret of the node.
+
+use alloy_primitives::hex;
+use clap::{Parser, Subcommand};
+use eyre::{bail, WrapErr};
+use reth_cli_util::{
+    keystore::{keystore_password, read_password_file, Keystore},
+    load_secret_key::rng_secret_key,
+};
+use reth_fs_util as fs;
+use std::path::{Path, PathBuf};
+
+/// `reth keys` command
+#[derive(Debug, Parser)]
+pub struct Command {
+    #[command(subcommand)]
+    command: Subcommands,
+}
+
+/// `reth keys` subcommands
+#[derive(Debug, Subcommand)]
+pub enum Subcommands {
+    /// Generates a new secret and stores it encrypted.
+    ///
+    /// The secret is a valid p2p secret key and JWT secret.
+    Generate(KeyArgs),
+    /// Encrypts a plaintext secret file in place.
+    Encrypt(KeyArgs),
+    /// Decrypts an encrypted secret file in place.
+    Decrypt(KeyArgs),
+}
+
+/// Arguments of the `reth keys` subcommands.
+#[derive(Debug, Parser)]
+pub struct KeyArgs {
+    /// The path to the secret file, for example the p2p secret key or the JWT secret.
+    #[arg(value_name = "PATH")]
+    path: PathBuf,
+
+    /// The file that contains the keystore password.
+    ///
+    /// Defaults to the `RETH_KEYSTORE_PASSWORD` and `RETH_KEYSTORE_PASSWORD_FILE` environment
+    /// variables.
+    #[arg(long, value_name = "FILE")]
+    password_file: Option<PathBuf>,
+}
+
+impl KeyArgs {
+    /// Returns the keystore password.
+    fn password(&self) -> eyre::Result<String> {
+        Ok(match &self.password_file {
+            Some(path) => read_password_file(path)?,
+            None => keystore_password()?,
+        })
+    }
+}
+
+impl Command {
+    /// Execute `keys` command
+    pub async fn execute(self) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::Generate(args) => {
+                if args.path.exists() {
+                    bail!("Secret file already exists: {}", args.path.display());
+                }
+                if let Some(dir) = args.path.parent() {
+                    fs::create_dir_all(dir)?;
+                }
+                let secret = rng_secret_key();
+                Keystore::encrypt(secret.as_ref(), &args.password()?)?.write(&args.path)?;
+                println!("Generated encrypted secret {}", args.path.display());
+            }
+            Subcommands::Encrypt(args) => {
+                let contents = read(&args.path)?;
+                if Keystore::is_keystore(&contents) {
+                    bail!("Secret file is already encrypted: {}", args.path.display());
+                }
+                let secret = hex::decode(contents.trim())
+                    .wrap_err_with(|| format!("Invalid secret file: {}", args.path.display()))?;
+                Keystore::encrypt(&secret, &args.password()?)?.write(&args.path)?;
+                println!("Encrypted {}", args.path.display());
+            }
+            Subcommands::Decrypt(args) => {
+                let contents = read(&args.path)?;
+                if !Keystore::is_keystore(&contents) {
+                    bail!("Secret file is not encrypted: {}", args.path.display());
+                }
+                let secret = Keystore::from_json(&contents)?.decrypt(&args.password()?)?;
+                fs::write(&args.path, hex::encode(secret))?;
+                println!("Decrypted {}", args.path.display());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Reads the secret file.
+fn read(path: &Path) -> eyre::Result<String> {
+    if !path.exists() {
+        bail!("Secret file does not exist: {}", path.display());
+    }
+    Ok(fs::read_to_string(path)?)
+}
@@ -8,18 +8,24 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
+pub mod bench;
+pub mod blob;
 pub mod common;
 pub mod config_cmd;
 pub mod db;
 pub mod dump_genesis;
+pub mod export_prover_inputs;
 pub mod import;
+pub mod import_era;
 pub mod init_cmd;
 pub mod init_state;
+pub mod keys;
 pub mod node;
 pub mod p2p;
 pub mod prune;
 pub mod recover;
 pub mod stage;
+pub mod static_files;
 #[cfg(feature = "arbitrary")]
 pub mod test_vectors;
 
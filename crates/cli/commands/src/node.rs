@@ -1,17 +1,20 @@
 //! Main node command for launching a node
 
 use clap::{value_parser, Args, Parser};
+use eyre::WrapErr;
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_cli::chainspec::ChainSpecParser;
 use reth_cli_runner::CliContext;
 use reth_cli_util::parse_socket_address;
+use reth_config::Config;
 use reth_db::{init_db, DatabaseEnv};
 use reth_ethereum_cli::chainspec::EthereumChainSpecParser;
 use reth_node_builder::{NodeBuilder, WithLaunchContext};
 use reth_node_core::{
     args::{
-        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EngineArgs, NetworkArgs, PayloadBuilderArgs,
-        PruningArgs, RpcServerArgs, TxPoolArgs,
+        AlertArgs, ContractIndexArgs, DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EngineArgs,
+        HeaderAccumulatorArgs, NetworkArgs, PayloadBuilderArgs, PipelineArgs, ProposerPaymentsArgs,
+        PruningArgs, RequestIndexArgs, RpcServerArgs, StaticFilesArgs, TxPoolArgs, TxWatcherArgs,
     },
     node_config::NodeConfig,
     version,
@@ -79,6 +82,17 @@ pub struct NodeCommand<
     #[command(flatten)]
     pub network: NetworkArgs,
 
+    /// Disables P2P networking.
+    ///
+    /// The node doesn't discover, dial or accept any peers, and only follows the chain through the
+    /// Engine API of its consensus layer client.
+    #[arg(
+        long,
+        conflicts_with_all = ["trusted_peers", "bootnodes"],
+        help_heading = "Networking"
+    )]
+    pub no_network: bool,
+
     /// All rpc related arguments
     #[command(flatten)]
     pub rpc: RpcServerArgs,
@@ -111,6 +125,38 @@ pub struct NodeCommand<
     #[command(flatten, next_help_heading = "Engine")]
     pub engine: EngineArgs,
 
+    /// All transaction watcher related arguments with --tx-watcher prefix
+    #[command(flatten)]
+    pub tx_watcher: TxWatcherArgs,
+
+    /// All contract creation index related arguments with --contract-index prefix
+    #[command(flatten)]
+    pub contract_index: ContractIndexArgs,
+
+    /// All header accumulator related arguments with --header-accumulator prefix
+    #[command(flatten)]
+    pub header_accumulator: HeaderAccumulatorArgs,
+
+    /// All execution layer request index related arguments with --request-index prefix
+    #[command(flatten)]
+    pub request_index: RequestIndexArgs,
+
+    /// All proposer payment related arguments with --proposer-payments prefix
+    #[command(flatten)]
+    pub proposer_payments: ProposerPaymentsArgs,
+
+    /// All alert related arguments with --alerts prefix
+    #[command(flatten)]
+    pub alerts: AlertArgs,
+
+    /// All static file related arguments with --static-files prefix
+    #[command(flatten)]
+    pub static_files: StaticFilesArgs,
+
+    /// All pipeline related arguments with --pipeline prefix
+    #[command(flatten)]
+    pub pipeline: PipelineArgs,
+
     /// Additional cli arguments
     #[command(flatten, next_help_heading = "Extension")]
     pub ext: Ext,
@@ -149,13 +195,14 @@ impl<
         tracing::info!(target: "reth::cli", version = ?version::SHORT_VERSION, "Starting reth");
 
         let Self {
-            datadir,
+            mut datadir,
             config,
             chain,
             metrics,
             instance,
             with_unused_ports,
-            network,
+            mut network,
+            no_network,
             rpc,
             txpool,
             builder,
@@ -165,8 +212,22 @@ impl<
             pruning,
             ext,
             engine,
+            tx_watcher,
+            contract_index,
+            header_accumulator,
+            request_index,
+            proposer_payments,
+            alerts,
+            static_files,
+            pipeline,
         } = self;
 
+        // the ephemeral datadir is removed when this is dropped, i.e. when the node exits
+        let _memory_datadir =
+            datadir.create_memory_datadir().wrap_err("Could not create ephemeral datadir")?;
+
+        network.no_network = no_network;
+
         // set up node config
         let mut node_config = NodeConfig {
             datadir,
@@ -183,13 +244,30 @@ impl<
             dev,
             pruning,
             engine,
+            tx_watcher,
+            contract_index,
+            header_accumulator,
+            request_index,
+            proposer_payments,
+            alerts,
+            static_files,
+            pipeline,
+            database_read_metrics: None,
         };
 
         let data_dir = node_config.datadir();
         let db_path = data_dir.db();
 
+        // the database section of the config is needed before the node is launched
+        let config_path = node_config.config.clone().unwrap_or_else(|| data_dir.config());
+        let db_config = Config::from_path(&config_path)
+            .wrap_err_with(|| format!("Could not load config file {config_path:?}"))?
+            .database;
+
         tracing::info!(target: "reth::cli", path = ?db_path, "Opening database");
-        let database = Arc::new(init_db(db_path.clone(), self.db.database_args())?.with_metrics());
+        let db_args = self.db.with_config(&db_config).database_args();
+        let database = Arc::new(init_db(db_path.clone(), db_args)?.with_metrics());
+        node_config = node_config.with_database_read_metrics(database.read_metrics().clone());
 
         if with_unused_ports {
             node_config = node_config.with_unused_ports();
@@ -371,6 +449,22 @@ mod tests {
         assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
     }
 
+    #[test]
+    fn parse_no_network() {
+        let cmd: NodeCommand = NodeCommand::parse_from(["reth", "--no-network"]);
+        assert!(cmd.no_network);
+        assert!(!cmd.network.no_network);
+
+        let err = NodeCommand::<EthereumChainSpecParser>::try_parse_args_from([
+            "reth",
+            "--no-network",
+            "--bootnodes",
+            "enode://d860a01f9722d78051619d1e2351aba3f43f943f6f00718d1b9baa4101932a1f5011f16bb2b1bb35db20d6fe28fa0bf09636d26a87d31de9ec6203eeedb1f666@18.138.108.67:30303",
+        ])
+        .unwrap_err();
+        assert_eq!(err.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn with_unused_ports_check_zero() {
         let mut cmd: NodeCommand = NodeCommand::parse_from(["reth"]);
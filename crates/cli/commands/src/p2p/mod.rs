@@ -76,7 +76,10 @@ pub enum Subcommands {
 impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>> Command<C> {
     /// Execute `p2p` command
     pub async fn execute<N: NetworkPrimitives>(self) -> eyre::Result<()> {
-        let data_dir = self.datadir.clone().resolve_datadir(self.chain.chain());
+        // the ephemeral datadir is removed when this is dropped, i.e. when the command exits
+        let mut datadir = self.datadir.clone();
+        let _memory_datadir = datadir.create_memory_datadir()?;
+        let data_dir = datadir.resolve_datadir(self.chain.chain());
         let config_path = self.config.clone().unwrap_or_else(|| data_dir.config());
 
         // Load configuration
@@ -104,7 +107,10 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
             .boot_nodes(boot_nodes.clone())
             .apply(|builder| {
                 self.network.discovery.apply_to_builder(builder, rlpx_socket, boot_nodes)
-            })
+            });
+        let net = self
+            .network
+            .apply_allowlist(net)?
             .build_with_noop_provider(self.chain)
             .manager()
             .await?;
@@ -172,14 +172,15 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
 
                     let default_peers_path = data_dir.known_peers();
 
+                    let network_config = self.network.network_config::<P>(
+                        &config,
+                        provider_factory.chain_spec(),
+                        p2p_secret_key,
+                        default_peers_path,
+                    );
                     let network = self
                         .network
-                        .network_config::<P>(
-                            &config,
-                            provider_factory.chain_spec(),
-                            p2p_secret_key,
-                            default_peers_path,
-                        )
+                        .apply_allowlist(network_config)?
                         .build(provider_factory.clone())
                         .start_network()
                         .await?;
@@ -228,14 +229,15 @@ impl<C: ChainSpecParser<ChainSpec: EthChainSpec + Hardforks + EthereumHardforks>
 
                     let default_peers_path = data_dir.known_peers();
 
+                    let network_config = self.network.network_config::<P>(
+                        &config,
+                        provider_factory.chain_spec(),
+                        p2p_secret_key,
+                        default_peers_path,
+                    );
                     let network = self
                         .network
-                        .network_config::<P>(
-                            &config,
-                            provider_factory.chain_spec(),
-                            p2p_secret_key,
-                            default_peers_path,
-                        )
+                        .apply_allowlist(network_config)?
                         .build(provider_factory.clone())
                         .start_network()
                         .await?;
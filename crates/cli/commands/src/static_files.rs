@@ -0,0 +1,122 @@
+//! `reth static-files` command to manage the static files of the node.
+
+use crate::common::{AccessRights, CliNodeTypes, Environment, EnvironmentArgs};
+use clap::{Parser, Subcommand};
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
+use reth_cli::chainspec::ChainSpecParser;
+use reth_provider::{
+    providers::{StaticFileVerification, StaticFileVerificationOutcome},
+    StaticFileProviderFactory,
+};
+use std::time::Instant;
+use tracing::info;
+
+/// `reth static-files` command
+#[derive(Debug, Parser)]
+pub struct Command<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    #[command(subcommand)]
+    command: Subcommands,
+}
+
+/// `reth static-files` subcommands
+#[derive(Debug, Subcommand)]
+pub enum Subcommands {
+    /// Verifies the sealed static files against the checksums recorded when they were written.
+    ///
+    /// Exits with an error if a static file is corrupt, missing or unreadable.
+    Verify {
+        /// Records the checksums of sealed static files that have none, e.g. because they were
+        /// downloaded with a snapshot.
+        ///
+        /// Only use this if the static files are known to be intact.
+        #[arg(long)]
+        record_missing: bool,
+    },
+}
+
+impl<C: ChainSpecParser<ChainSpec: EthChainSpec + EthereumHardforks>> Command<C> {
+    /// Execute `static-files` command
+    pub async fn execute<N: CliNodeTypes<ChainSpec = C::ChainSpec>>(self) -> eyre::Result<()> {
+        match self.command {
+            Subcommands::Verify { record_missing } => {
+                let access = if record_missing { AccessRights::RW } else { AccessRights::RO };
+                let Environment { provider_factory, .. } = self.env.init::<N>(access)?;
+                let static_file_provider = provider_factory.static_file_provider();
+
+                info!(target: "reth::cli", path = ?static_file_provider.directory(), "Verifying static files");
+                let start = Instant::now();
+                let verifications = static_file_provider.verify_static_files()?;
+
+                let mut damaged = Vec::new();
+                let mut unverified = 0;
+                for verification in &verifications {
+                    match &verification.outcome {
+                        StaticFileVerificationOutcome::Valid => {}
+                        StaticFileVerificationOutcome::Unverified if record_missing => {
+                            static_file_provider.record_checksum(
+                                verification.segment,
+                                verification.block_range.start(),
+                            )?;
+                            println!("Recorded checksum of {}", verification.file_name);
+                        }
+                        StaticFileVerificationOutcome::Unverified => unverified += 1,
+                        outcome => {
+                            println!("{}: {}", verification.file_name, describe(outcome));
+                            damaged.push(verification);
+                        }
+                    }
+                }
+
+                println!(
+                    "Verified {} sealed static files in {:?}: {} damaged, {} without checksum",
+                    verifications.len(),
+                    start.elapsed(),
+                    damaged.len(),
+                    unverified,
+                );
+                if unverified > 0 {
+                    println!(
+                        "Static files without checksum can't be verified. If they are known to be intact, record their checksums with `--record-missing`."
+                    );
+                }
+
+                if let Some(first) = damaged.iter().min_by_key(|v| v.block_range.start()) {
+                    print_remediation(first);
+                    eyre::bail!("{} static files are damaged", damaged.len())
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns a description of a damaged static file.
+fn describe(outcome: &StaticFileVerificationOutcome) -> String {
+    match outcome {
+        StaticFileVerificationOutcome::Corrupt { expected, actual } => {
+            format!("corrupt, expected checksum {expected} but got {actual}")
+        }
+        StaticFileVerificationOutcome::Missing => "missing".to_string(),
+        StaticFileVerificationOutcome::Unreadable(err) => format!("unreadable: {err}"),
+        StaticFileVerificationOutcome::Valid | StaticFileVerificationOutcome::Unverified => {
+            "ok".to_string()
+        }
+    }
+}
+
+/// Prints how to recover from the damaged static file with the lowest block range.
+fn print_remediation(first: &StaticFileVerification) {
+    println!();
+    println!("To recover, either:");
+    println!("  * re-download a snapshot of the data directory, or");
+    match first.block_range.start().checked_sub(1) {
+        Some(target) => println!(
+            "  * re-derive the damaged static files by unwinding with `reth stage unwind to-block {target}` and syncing again."
+        ),
+        None => println!("  * re-derive the damaged static files by syncing from genesis again."),
+    }
+}
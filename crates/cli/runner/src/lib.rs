@@ -10,10 +10,16 @@
 
 //! Entrypoint for running commands.
 
-use reth_tasks::{TaskExecutor, TaskManager};
+use reth_cli_util::sd_notify;
+use reth_tasks::{lane::DEFAULT_BACKGROUND_WORKER_THREADS, TaskExecutor, TaskManager};
 use std::{future::Future, pin::pin, sync::mpsc, time::Duration};
 use tracing::{debug, error, trace};
 
+/// Stop requests of the service control manager if the process runs as a Windows service.
+#[cfg(windows)]
+static SERVICE_STOP: std::sync::LazyLock<tokio::sync::Notify> =
+    std::sync::LazyLock::new(tokio::sync::Notify::new);
+
 /// Executes CLI commands.
 ///
 /// Provides utilities for running a cli command to completion.
@@ -55,7 +61,12 @@ impl CliRunner {
         E: Send + Sync + From<std::io::Error> + From<reth_tasks::PanickedTaskError> + 'static,
     {
         let AsyncCliRunner { context, mut task_manager, tokio_runtime } =
-            AsyncCliRunner::new(self.tokio_runtime);
+            AsyncCliRunner::new(self.tokio_runtime)?;
+
+        #[cfg(windows)]
+        if reth_cli_util::windows_service::start(|| SERVICE_STOP.notify_one())? {
+            debug!(target: "reth::cli", "Running as Windows service");
+        }
 
         // Executes the command until it finished or ctrl-c was fired
         let command_res = tokio_runtime.block_on(run_to_completion_or_panic(
@@ -63,6 +74,10 @@ impl CliRunner {
             run_until_ctrl_c(command(context)),
         ));
 
+        if let Err(err) = sd_notify::stopping() {
+            debug!(target: "reth::cli", %err, "Failed to notify service manager");
+        }
+
         if command_res.is_err() {
             error!(target: "reth::cli", "shutting down due to error");
         } else {
@@ -90,6 +105,11 @@ impl CliRunner {
             debug!(target: "reth::cli", %err, "tokio runtime shutdown timed out");
         });
 
+        #[cfg(windows)]
+        if let Err(err) = reth_cli_util::windows_service::set_stopped(command_res.is_ok()) {
+            debug!(target: "reth::cli", %err, "Failed to report service stop");
+        }
+
         command_res
     }
 
@@ -143,10 +163,13 @@ struct AsyncCliRunner {
 impl AsyncCliRunner {
     /// Given a tokio [`Runtime`](tokio::runtime::Runtime), creates additional context required to
     /// execute commands asynchronously.
-    fn new(tokio_runtime: tokio::runtime::Runtime) -> Self {
-        let task_manager = TaskManager::new(tokio_runtime.handle().clone());
+    ///
+    /// Background tasks are run on a dedicated runtime, see [`reth_tasks::TaskLane::Background`].
+    fn new(tokio_runtime: tokio::runtime::Runtime) -> Result<Self, std::io::Error> {
+        let task_manager = TaskManager::new(tokio_runtime.handle().clone())
+            .with_background_runtime(DEFAULT_BACKGROUND_WORKER_THREADS)?;
         let task_executor = task_manager.executor();
-        Self { context: CliContext { task_executor }, task_manager, tokio_runtime }
+        Ok(Self { context: CliContext { task_executor }, task_manager, tokio_runtime })
     }
 }
 
@@ -186,6 +209,9 @@ where
 /// Runs the future to completion or until:
 /// - `ctrl-c` is received.
 /// - `SIGTERM` is received (unix only).
+/// - `CTRL_CLOSE_EVENT` or `CTRL_SHUTDOWN_EVENT` is received (windows only), which service wrappers
+///   send to stop the process.
+/// - the service control manager requests the Windows service to stop (windows only).
 async fn run_until_ctrl_c<F, E>(fut: F) -> Result<(), E>
 where
     F: Future<Output = Result<(), E>>,
@@ -212,7 +238,34 @@ where
         }
     }
 
-    #[cfg(not(unix))]
+    #[cfg(windows)]
+    {
+        let mut close = tokio::signal::windows::ctrl_close()?;
+        let mut shutdown = tokio::signal::windows::ctrl_shutdown()?;
+        let close = pin!(close.recv());
+        let shutdown = pin!(shutdown.recv());
+        let service_stop = pin!(SERVICE_STOP.notified());
+        let ctrl_c = pin!(ctrl_c);
+        let fut = pin!(fut);
+
+        tokio::select! {
+            _ = ctrl_c => {
+                trace!(target: "reth::cli", "Received ctrl-c");
+            },
+            _ = close => {
+                trace!(target: "reth::cli", "Received ctrl-close");
+            },
+            _ = shutdown => {
+                trace!(target: "reth::cli", "Received ctrl-shutdown");
+            },
+            _ = service_stop => {
+                trace!(target: "reth::cli", "Received service stop request");
+            },
+            res = fut => res?,
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
     {
         let ctrl_c = pin!(ctrl_c);
         let fut = pin!(fut);
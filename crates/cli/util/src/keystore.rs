@@ -0,0 +1,362 @@
+//! Encrypted storage of node secrets.
+//!
+//! Secrets are stored in the keystore format of the Web3 Secret Storage Definition (version 3),
+//! using `scrypt` to derive the key and `aes-128-ctr` to encrypt the secret.
+
+use alloy_primitives::{hex, keccak256};
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use rand::RngCore;
+use reth_fs_util::{self as fs, FsPathError};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::Path,
+};
+use subtle::ConstantTimeEq;
+use thiserror::Error;
+
+/// The environment variable that holds the password of the keystores.
+pub const KEYSTORE_PASSWORD_ENV: &str = "RETH_KEYSTORE_PASSWORD";
+
+/// The environment variable that holds the path to a file containing the password of the
+/// keystores.
+pub const KEYSTORE_PASSWORD_FILE_ENV: &str = "RETH_KEYSTORE_PASSWORD_FILE";
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// Errors returned by reading, encrypting and decrypting a [`Keystore`].
+#[derive(Error, Debug)]
+pub enum KeystoreError {
+    /// Error related to file system path operations.
+    #[error(transparent)]
+    FsPathError(#[from] FsPathError),
+
+    /// The keystore is not valid JSON.
+    #[error("invalid keystore: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A field of the keystore isn't valid hex.
+    #[error("invalid keystore: {0}")]
+    Hex(#[from] hex::FromHexError),
+
+    /// The keystore uses an unsupported cipher, KDF or parameters.
+    #[error("unsupported keystore: {0}")]
+    Unsupported(String),
+
+    /// The password doesn't decrypt the keystore.
+    #[error("wrong keystore password")]
+    WrongPassword,
+
+    /// Neither of the password environment variables is set.
+    #[error(
+        "keystore password required, set {KEYSTORE_PASSWORD_ENV} or {KEYSTORE_PASSWORD_FILE_ENV}"
+    )]
+    MissingPassword,
+}
+
+/// The `scrypt` parameters used to derive the encryption key from the password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KdfParams {
+    /// The base-2 logarithm of the CPU/memory cost.
+    pub log_n: u8,
+    /// The block size.
+    pub r: u32,
+    /// The parallelization.
+    pub p: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self { log_n: 18, r: 8, p: 1 }
+    }
+}
+
+/// A secret encrypted with a password.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    crypto: Crypto,
+    id: String,
+    version: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Crypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: ScryptParams,
+    mac: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ScryptParams {
+    dklen: usize,
+    n: u64,
+    p: u32,
+    r: u32,
+    salt: String,
+}
+
+impl Keystore {
+    /// Encrypts the secret with the password, using the default [`KdfParams`].
+    pub fn encrypt(secret: &[u8], password: &str) -> Result<Self, KeystoreError> {
+        Self::encrypt_with_params(secret, password, KdfParams::default())
+    }
+
+    /// Encrypts the secret with the password.
+    pub fn encrypt_with_params(
+        secret: &[u8],
+        password: &str,
+        params: KdfParams,
+    ) -> Result<Self, KeystoreError> {
+        let mut rng = rand::thread_rng();
+        let mut salt = [0u8; 32];
+        let mut iv = [0u8; 16];
+        let mut id = [0u8; 16];
+        rng.fill_bytes(&mut salt);
+        rng.fill_bytes(&mut iv);
+        rng.fill_bytes(&mut id);
+
+        let key = derive_key(password, &salt, params)?;
+        let mut ciphertext = secret.to_vec();
+        Aes128Ctr::new(key[..16].into(), (&iv).into()).apply_keystream(&mut ciphertext);
+        let mac = mac(&key, &ciphertext);
+
+        Ok(Self {
+            crypto: Crypto {
+                cipher: "aes-128-ctr".to_string(),
+                cipherparams: CipherParams { iv: hex::encode(iv) },
+                ciphertext: hex::encode(ciphertext),
+                kdf: "scrypt".to_string(),
+                kdfparams: ScryptParams {
+                    dklen: key.len(),
+                    n: 1 << params.log_n,
+                    p: params.p,
+                    r: params.r,
+                    salt: hex::encode(salt),
+                },
+                mac: hex::encode(mac),
+            },
+            id: uuid(id),
+            version: 3,
+        })
+    }
+
+    /// Decrypts the secret with the password.
+    pub fn decrypt(&self, password: &str) -> Result<Vec<u8>, KeystoreError> {
+        let crypto = &self.crypto;
+        if self.version != 3 || crypto.cipher != "aes-128-ctr" || crypto.kdf != "scrypt" {
+            return Err(KeystoreError::Unsupported(format!(
+                "version {} with {} and {}",
+                self.version, crypto.cipher, crypto.kdf
+            )))
+        }
+        let kdf = &crypto.kdfparams;
+        if !kdf.n.is_power_of_two() || kdf.dklen != 32 {
+            return Err(KeystoreError::Unsupported(format!(
+                "scrypt n={} dklen={}",
+                kdf.n, kdf.dklen
+            )))
+        }
+        let params = KdfParams { log_n: kdf.n.trailing_zeros() as u8, r: kdf.r, p: kdf.p };
+
+        let key = derive_key(password, &hex::decode(&kdf.salt)?, params)?;
+        let mut secret = hex::decode(&crypto.ciphertext)?;
+        if !bool::from(mac(&key, &secret)[..].ct_eq(&hex::decode(&crypto.mac)?)) {
+            return Err(KeystoreError::WrongPassword)
+        }
+
+        let iv: [u8; 16] = hex::decode(&crypto.cipherparams.iv)?
+            .try_into()
+            .map_err(|_| KeystoreError::Unsupported("iv length".to_string()))?;
+        Aes128Ctr::new(key[..16].into(), (&iv).into()).apply_keystream(&mut secret);
+        Ok(secret)
+    }
+
+    /// Returns `true` if the contents of a secret file are a keystore rather than a plaintext
+    /// hex secret.
+    pub fn is_keystore(contents: &str) -> bool {
+        contents.trim_start().starts_with('{')
+    }
+
+    /// Parses the keystore from the contents of a file.
+    pub fn from_json(contents: &str) -> Result<Self, KeystoreError> {
+        Ok(serde_json::from_str(contents)?)
+    }
+
+    /// Reads the keystore at the given path.
+    pub fn from_path(path: &Path) -> Result<Self, KeystoreError> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Writes the keystore to the given path.
+    ///
+    /// On Unix, the file is only readable and writable by its owner.
+    pub fn write(&self, path: &Path) -> Result<(), KeystoreError> {
+        let mut writer = BufWriter::new(create_secret_file(path)?);
+        serde_json::to_writer_pretty(&mut writer, self)
+            .map_err(|source| FsPathError::WriteJson { source, path: path.into() })?;
+        writer.flush().map_err(|err| FsPathError::write(err, path))?;
+        Ok(())
+    }
+}
+
+/// Returns the password of the keystores from [`KEYSTORE_PASSWORD_ENV`], or from the file at the
+/// path of [`KEYSTORE_PASSWORD_FILE_ENV`].
+pub fn keystore_password() -> Result<String, KeystoreError> {
+    if let Ok(password) = std::env::var(KEYSTORE_PASSWORD_ENV) {
+        return Ok(password)
+    }
+    let path =
+        std::env::var(KEYSTORE_PASSWORD_FILE_ENV).map_err(|_| KeystoreError::MissingPassword)?;
+    read_password_file(Path::new(&path))
+}
+
+/// Reads a password from the given file, without the trailing newline.
+pub fn read_password_file(path: &Path) -> Result<String, KeystoreError> {
+    let password = fs::read_to_string(path)?;
+    Ok(password.trim_end_matches(['\r', '\n']).to_string())
+}
+
+/// Reads the secret stored at the given path, decrypting it with [`keystore_password`] if the file
+/// is a keystore. Plaintext secrets are returned as they are stored.
+pub fn read_secret(path: &Path) -> Result<String, KeystoreError> {
+    let contents = fs::read_to_string(path)?;
+    if Keystore::is_keystore(&contents) {
+        let secret = Keystore::from_json(&contents)?.decrypt(&keystore_password()?)?;
+        Ok(hex::encode(secret))
+    } else {
+        Ok(contents)
+    }
+}
+
+fn derive_key(password: &str, salt: &[u8], params: KdfParams) -> Result<[u8; 32], KeystoreError> {
+    let mut key = [0u8; 32];
+    let scrypt_params = scrypt::Params::new(params.log_n, params.r, params.p, key.len())
+        .map_err(|err| KeystoreError::Unsupported(err.to_string()))?;
+    scrypt::scrypt(password.as_bytes(), salt, &scrypt_params, &mut key)
+        .map_err(|err| KeystoreError::Unsupported(err.to_string()))?;
+    Ok(key)
+}
+
+fn mac(key: &[u8; 32], ciphertext: &[u8]) -> [u8; 32] {
+    let mut preimage = key[16..].to_vec();
+    preimage.extend_from_slice(ciphertext);
+    keccak256(preimage).0
+}
+
+/// Creates or truncates the file at the given path, restricting its permissions to the owner on
+/// Unix.
+fn create_secret_file(path: &Path) -> Result<File, FsPathError> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let file = options.open(path).map_err(|err| FsPathError::create_file(err, path))?;
+
+    // the mode only applies to new files, existing files keep their permissions
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))
+            .map_err(|err| FsPathError::write(err, path))?;
+    }
+
+    Ok(file)
+}
+
+/// Formats random bytes as a version 4 UUID.
+fn uuid(mut bytes: [u8; 16]) -> String {
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: KdfParams = KdfParams { log_n: 4, r: 8, p: 1 };
+
+    #[test]
+    fn encrypt_decrypt() {
+        let keystore = Keystore::encrypt_with_params(&[7; 32], "password", PARAMS).unwrap();
+        assert_eq!(keystore.decrypt("password").unwrap(), vec![7; 32]);
+        assert!(matches!(keystore.decrypt("wrong"), Err(KeystoreError::WrongPassword)));
+
+        let json = serde_json::to_string(&keystore).unwrap();
+        assert!(Keystore::is_keystore(&json));
+        assert_eq!(Keystore::from_json(&json).unwrap(), keystore);
+        assert!(!Keystore::is_keystore(&hex::encode([7; 32])));
+    }
+
+    #[test]
+    fn keystore_format() {
+        let keystore = Keystore::encrypt_with_params(&[7; 32], "password", PARAMS).unwrap();
+        let json = serde_json::to_value(&keystore).unwrap();
+        assert_eq!(json["version"], 3);
+        assert_eq!(json["crypto"]["cipher"], "aes-128-ctr");
+        assert_eq!(json["crypto"]["kdf"], "scrypt");
+        assert_eq!(json["crypto"]["kdfparams"]["n"], 16);
+        assert_eq!(json["crypto"]["kdfparams"]["dklen"], 32);
+        assert_eq!(json["crypto"]["ciphertext"].as_str().unwrap().len(), 64);
+        assert_eq!(json["id"].as_str().unwrap().as_bytes()[14], b'4');
+    }
+
+    // `31_byte_key` of go-ethereum's `accounts/keystore/testdata/v3_test_vector.json`
+    #[test]
+    fn decrypt_test_vector() {
+        let keystore = Keystore::from_json(
+            r#"{
+                "crypto": {
+                    "cipher": "aes-128-ctr",
+                    "cipherparams": { "iv": "e0c41130a323adc1446fc82f724bca2f" },
+                    "ciphertext": "9517cd5bdbe69076f9bf5057248c6c050141e970efa36ce53692d5d59a3984",
+                    "kdf": "scrypt",
+                    "kdfparams": {
+                        "dklen": 32,
+                        "n": 2,
+                        "r": 8,
+                        "p": 1,
+                        "salt": "711f816911c92d649fb4c84b047915679933555030b3552c1212609b38208c63"
+                    },
+                    "mac": "d5e116151c6aa71470e67a7d42c9620c75c4d23229847dcc127794f0732b0db5"
+                },
+                "id": "fecfc4ce-e956-48fd-953b-30f8b52ed66c",
+                "version": 3
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            hex::encode(keystore.decrypt("foo").unwrap()),
+            "fa7b3db73dc7dfdf8c5fbdb796d741e4488628c41fc4febd9160a866ba0f35"
+        );
+        assert!(matches!(keystore.decrypt("bar"), Err(KeystoreError::WrongPassword)));
+    }
+
+    #[test]
+    fn write_read() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secret");
+        std::fs::write(&path, hex::encode([7; 32])).unwrap();
+
+        let keystore = Keystore::encrypt_with_params(&[7; 32], "password", PARAMS).unwrap();
+        keystore.write(&path).unwrap();
+        assert_eq!(Keystore::from_path(&path).unwrap(), keystore);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
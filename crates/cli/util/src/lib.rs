@@ -14,6 +14,13 @@ pub mod allocator;
 pub mod load_secret_key;
 pub use load_secret_key::get_secret_key;
 
+pub mod keystore;
+
+pub mod sd_notify;
+
+#[cfg(windows)]
+pub mod windows_service;
+
 /// Cli parsers functions.
 pub mod parsers;
 pub use parsers::{
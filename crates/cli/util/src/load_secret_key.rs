@@ -1,3 +1,4 @@
+use crate::keystore::{keystore_password, Keystore, KeystoreError};
 use reth_fs_util::{self as fs, FsPathError};
 use secp256k1::{Error as SecretKeyBaseError, SecretKey};
 use std::{
@@ -22,6 +23,10 @@ pub enum SecretKeyError {
     #[error(transparent)]
     SecretKeyFsPathError(#[from] FsPathError),
 
+    /// Error encountered during decryption of an encrypted secret key.
+    #[error(transparent)]
+    SecretKeyKeystoreError(#[from] KeystoreError),
+
     /// Represents an error when failed to access the key file.
     #[error("failed to access key file {secret_file:?}: {error}")]
     FailedToAccessKeyFile {
@@ -35,12 +40,19 @@ pub enum SecretKeyError {
 /// Attempts to load a [`SecretKey`] from a specified path. If no file exists there, then it
 /// generates a secret key and stores it in the provided path. I/O errors might occur during write
 /// operations in the form of a [`SecretKeyError`]
+///
+/// If the file is a [`Keystore`], the secret key is decrypted with the password returned by
+/// [`keystore_password`].
 pub fn get_secret_key(secret_key_path: &Path) -> Result<SecretKey, SecretKeyError> {
     let exists = secret_key_path.try_exists();
 
     match exists {
         Ok(true) => {
             let contents = fs::read_to_string(secret_key_path)?;
+            if Keystore::is_keystore(&contents) {
+                let secret = Keystore::from_json(&contents)?.decrypt(&keystore_password()?)?;
+                return Ok(SecretKey::from_slice(&secret)?)
+            }
             Ok(contents.as_str().parse().map_err(SecretKeyError::SecretKeyDecodeError)?)
         }
         Ok(false) => {
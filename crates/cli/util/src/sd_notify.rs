@@ -0,0 +1,124 @@
+//! Service manager notifications, see `sd_notify(3)`.
+//!
+//! The notifications are only sent if the node is run by a service manager that supports them,
+//! i.e. if the `NOTIFY_SOCKET` environment variable is set, for example by a systemd unit with
+//! `Type=notify`.
+//!
+//! On Windows, the readiness and shutdown of the node are reported to the service control manager
+//! instead if the node is run as a Windows service, see
+//! [`windows_service`](crate::windows_service).
+
+use std::{env, ffi::OsStr, io, time::Duration};
+
+/// The environment variable that contains the path of the service manager's notification socket.
+const NOTIFY_SOCKET: &str = "NOTIFY_SOCKET";
+
+/// The environment variable that contains the watchdog timeout in microseconds.
+const WATCHDOG_USEC: &str = "WATCHDOG_USEC";
+
+/// The environment variable that contains the PID of the process that is watched.
+const WATCHDOG_PID: &str = "WATCHDOG_PID";
+
+/// Notifies the service manager that the node finished starting up.
+pub fn ready() -> io::Result<bool> {
+    #[cfg(windows)]
+    if crate::windows_service::set_running()? {
+        return Ok(true)
+    }
+    notify("READY=1")
+}
+
+/// Notifies the service manager that the node is shutting down.
+pub fn stopping() -> io::Result<bool> {
+    #[cfg(windows)]
+    if crate::windows_service::set_stop_pending()? {
+        return Ok(true)
+    }
+    notify("STOPPING=1")
+}
+
+/// Sends a keep-alive notification to the service manager's watchdog.
+pub fn watchdog() -> io::Result<bool> {
+    notify("WATCHDOG=1")
+}
+
+/// Returns the interval in which keep-alive notifications should be sent to the service manager's
+/// watchdog, which is half of its timeout.
+///
+/// Returns `None` if the watchdog is not enabled for this process.
+pub fn watchdog_interval() -> Option<Duration> {
+    let timeout = env::var(WATCHDOG_USEC).ok()?.parse::<u64>().ok().filter(|usec| *usec > 0)?;
+    if let Ok(pid) = env::var(WATCHDOG_PID) {
+        if pid.parse::<u32>().ok()? != std::process::id() {
+            return None
+        }
+    }
+    Some(Duration::from_micros(timeout / 2))
+}
+
+/// Sends the given state to the service manager.
+///
+/// Returns `false` if the node is not run by a service manager that supports notifications.
+pub fn notify(state: &str) -> io::Result<bool> {
+    let Some(socket) = env::var_os(NOTIFY_SOCKET) else { return Ok(false) };
+    send(&socket, state)?;
+    Ok(true)
+}
+
+/// Sends the state as a datagram to the unix socket at the given path.
+///
+/// Paths that start with `@` refer to sockets in the abstract namespace.
+#[cfg(unix)]
+fn send(socket: &OsStr, state: &str) -> io::Result<()> {
+    use std::os::unix::{ffi::OsStrExt, net::UnixDatagram};
+
+    let sock = UnixDatagram::unbound()?;
+    match socket.as_bytes().strip_prefix(b"@") {
+        #[cfg(target_os = "linux")]
+        Some(name) => {
+            use std::os::{linux::net::SocketAddrExt, unix::net::SocketAddr};
+            sock.send_to_addr(state.as_bytes(), &SocketAddr::from_abstract_name(name)?)?;
+        }
+        #[cfg(not(target_os = "linux"))]
+        Some(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "abstract notification sockets are only supported on linux",
+            ))
+        }
+        None => {
+            sock.send_to(state.as_bytes(), socket)?;
+        }
+    }
+    Ok(())
+}
+
+/// Service manager notifications are not supported on this platform.
+#[cfg(not(unix))]
+fn send(_socket: &OsStr, _state: &str) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "service manager notifications are only supported on unix",
+    ))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixDatagram;
+
+    #[test]
+    fn send_to_socket() {
+        let path = env::temp_dir().join(format!("reth-sd-notify-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixDatagram::bind(&path).unwrap();
+
+        send(path.as_os_str(), "READY=1").unwrap();
+
+        let mut buf = [0; 16];
+        let len = listener.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..len], b"READY=1");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
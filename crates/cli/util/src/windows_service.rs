@@ -0,0 +1,217 @@
+//! Windows service integration, see `StartServiceCtrlDispatcherW`.
+//!
+//! If the process is started by the service control manager, [`start`] connects to it on a
+//! background thread. The state of the node is then reported to the service control manager, and
+//! its stop requests are forwarded to the node.
+
+use std::{
+    ffi::c_void,
+    io, ptr,
+    sync::{
+        atomic::{AtomicIsize, AtomicU32, Ordering},
+        mpsc, Mutex, OnceLock,
+    },
+    time::Duration,
+};
+
+const SERVICE_WIN32_OWN_PROCESS: u32 = 0x10;
+
+const SERVICE_STOPPED: u32 = 1;
+const SERVICE_START_PENDING: u32 = 2;
+const SERVICE_STOP_PENDING: u32 = 3;
+const SERVICE_RUNNING: u32 = 4;
+
+const SERVICE_ACCEPT_STOP: u32 = 0x1;
+const SERVICE_ACCEPT_SHUTDOWN: u32 = 0x4;
+
+const SERVICE_CONTROL_STOP: u32 = 1;
+const SERVICE_CONTROL_INTERROGATE: u32 = 4;
+const SERVICE_CONTROL_SHUTDOWN: u32 = 5;
+
+const NO_ERROR: u32 = 0;
+const ERROR_CALL_NOT_IMPLEMENTED: u32 = 120;
+const ERROR_FAILED_SERVICE_CONTROLLER_CONNECT: i32 = 1063;
+const ERROR_SERVICE_SPECIFIC_ERROR: u32 = 1066;
+
+/// How long the service control manager waits for the next report of a pending state.
+const PENDING_WAIT_HINT: Duration = Duration::from_secs(60);
+
+#[repr(C)]
+struct ServiceStatus {
+    service_type: u32,
+    current_state: u32,
+    controls_accepted: u32,
+    win32_exit_code: u32,
+    service_specific_exit_code: u32,
+    check_point: u32,
+    wait_hint: u32,
+}
+
+#[repr(C)]
+struct ServiceTableEntry {
+    service_name: *mut u16,
+    service_proc: Option<extern "system" fn(u32, *mut *mut u16)>,
+}
+
+type HandlerFunction = extern "system" fn(u32, u32, *mut c_void, *mut c_void) -> u32;
+
+#[link(name = "advapi32")]
+extern "system" {
+    fn StartServiceCtrlDispatcherW(service_start_table: *const ServiceTableEntry) -> i32;
+    fn RegisterServiceCtrlHandlerExW(
+        service_name: *const u16,
+        handler_proc: Option<HandlerFunction>,
+        context: *mut c_void,
+    ) -> isize;
+    fn SetServiceStatus(service_status: isize, status: *const ServiceStatus) -> i32;
+}
+
+/// The service of this process.
+static SERVICE: OnceLock<Service> = OnceLock::new();
+
+struct Service {
+    /// Called when the service control manager requests the service to stop.
+    on_stop: Box<dyn Fn() + Send + Sync>,
+    /// The handle to report the status with, `0` until the service is registered.
+    status_handle: AtomicIsize,
+    /// The check point of the pending state that was reported last.
+    check_point: AtomicU32,
+    /// Sends the result of the registration of the service to [`start`].
+    registered: Mutex<Option<mpsc::Sender<io::Result<()>>>>,
+}
+
+/// Connects to the service control manager if the process was started as a Windows service.
+///
+/// The service is reported as starting until [`set_running`] is called. `on_stop` is called when
+/// the service control manager requests the service to stop, e.g. because the system shuts down.
+///
+/// Returns `false` if the process was not started as a Windows service.
+pub fn start(on_stop: impl Fn() + Send + Sync + 'static) -> io::Result<bool> {
+    let (tx, rx) = mpsc::channel();
+    let service = Service {
+        on_stop: Box::new(on_stop),
+        status_handle: AtomicIsize::new(0),
+        check_point: AtomicU32::new(0),
+        registered: Mutex::new(Some(tx.clone())),
+    };
+    if SERVICE.set(service).is_err() {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, "service was already started"))
+    }
+
+    std::thread::Builder::new().name("windows-service".to_string()).spawn(move || {
+        let mut name = [0u16];
+        let table = [
+            ServiceTableEntry { service_name: name.as_mut_ptr(), service_proc: Some(service_main) },
+            ServiceTableEntry { service_name: ptr::null_mut(), service_proc: None },
+        ];
+        // blocks until the service is stopped
+        // SAFETY: the table is terminated by a null entry and outlives the call
+        if unsafe { StartServiceCtrlDispatcherW(table.as_ptr()) } == 0 {
+            let _ = tx.send(Err(io::Error::last_os_error()));
+        }
+    })?;
+
+    match rx.recv() {
+        Ok(Ok(())) => Ok(true),
+        Ok(Err(err)) if err.raw_os_error() == Some(ERROR_FAILED_SERVICE_CONTROLLER_CONNECT) => {
+            Ok(false)
+        }
+        Ok(Err(err)) => Err(err),
+        Err(_) => Ok(false),
+    }
+}
+
+/// Reports to the service control manager that the service is running.
+///
+/// Returns `false` if the process is not run as a Windows service.
+pub fn set_running() -> io::Result<bool> {
+    set_status(SERVICE_RUNNING, NO_ERROR)
+}
+
+/// Reports to the service control manager that the service is stopping.
+///
+/// Returns `false` if the process is not run as a Windows service.
+pub fn set_stop_pending() -> io::Result<bool> {
+    set_status(SERVICE_STOP_PENDING, NO_ERROR)
+}
+
+/// Reports to the service control manager that the service stopped, which ends the connection to
+/// it.
+///
+/// Returns `false` if the process is not run as a Windows service.
+pub fn set_stopped(success: bool) -> io::Result<bool> {
+    set_status(SERVICE_STOPPED, if success { NO_ERROR } else { ERROR_SERVICE_SPECIFIC_ERROR })
+}
+
+fn set_status(state: u32, win32_exit_code: u32) -> io::Result<bool> {
+    let Some(service) = SERVICE.get() else { return Ok(false) };
+    let status_handle = service.status_handle.load(Ordering::Acquire);
+    if status_handle == 0 {
+        return Ok(false)
+    }
+
+    let pending = matches!(state, SERVICE_START_PENDING | SERVICE_STOP_PENDING);
+    let status = ServiceStatus {
+        service_type: SERVICE_WIN32_OWN_PROCESS,
+        current_state: state,
+        // the node can be stopped while it's starting up
+        controls_accepted: if matches!(state, SERVICE_START_PENDING | SERVICE_RUNNING) {
+            SERVICE_ACCEPT_STOP | SERVICE_ACCEPT_SHUTDOWN
+        } else {
+            0
+        },
+        win32_exit_code,
+        service_specific_exit_code: u32::from(win32_exit_code == ERROR_SERVICE_SPECIFIC_ERROR),
+        check_point: if pending {
+            service.check_point.fetch_add(1, Ordering::Relaxed) + 1
+        } else {
+            0
+        },
+        wait_hint: if pending { PENDING_WAIT_HINT.as_millis() as u32 } else { 0 },
+    };
+    // SAFETY: the handle was returned by `RegisterServiceCtrlHandlerExW`
+    if unsafe { SetServiceStatus(status_handle, &status) } == 0 {
+        return Err(io::Error::last_os_error())
+    }
+    Ok(true)
+}
+
+/// Entry point of the service, called by the service control manager on the dispatcher thread.
+extern "system" fn service_main(_argc: u32, _argv: *mut *mut u16) {
+    let Some(service) = SERVICE.get() else { return };
+    let Some(registered) = service.registered.lock().unwrap().take() else { return };
+
+    // the name is ignored for services that run in their own process
+    let name = [0u16];
+    // SAFETY: the name is null-terminated and the handler is valid for the lifetime of the process
+    let status_handle = unsafe {
+        RegisterServiceCtrlHandlerExW(name.as_ptr(), Some(control_handler), ptr::null_mut())
+    };
+    if status_handle == 0 {
+        let _ = registered.send(Err(io::Error::last_os_error()));
+        return
+    }
+    service.status_handle.store(status_handle, Ordering::Release);
+
+    let _ = registered.send(set_status(SERVICE_START_PENDING, NO_ERROR).map(|_| ()));
+}
+
+/// Handles the control requests of the service control manager.
+extern "system" fn control_handler(
+    control: u32,
+    _event_type: u32,
+    _event_data: *mut c_void,
+    _context: *mut c_void,
+) -> u32 {
+    match control {
+        SERVICE_CONTROL_STOP | SERVICE_CONTROL_SHUTDOWN => {
+            let _ = set_stop_pending();
+            if let Some(service) = SERVICE.get() {
+                (service.on_stop)();
+            }
+            NO_ERROR
+        }
+        SERVICE_CONTROL_INTERROGATE => NO_ERROR,
+        _ => ERROR_CALL_NOT_IMPLEMENTED,
+    }
+}
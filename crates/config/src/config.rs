@@ -1,14 +1,16 @@
 //! Configuration files.
 
-use eyre::eyre;
+use eyre::{ensure, eyre};
+use reth_network_peers::TrustedPeer;
 use reth_network_types::{PeersConfig, SessionsConfig};
 use reth_prune_types::PruneModes;
-use reth_stages_types::ExecutionStageThresholds;
+use reth_stages_types::{ExecutionStageThresholds, StageId};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::{
     ffi::OsStr,
-    fs,
+    fmt, fs,
     path::{Path, PathBuf},
+    str::FromStr,
     time::Duration,
 };
 
@@ -31,6 +33,10 @@ pub struct Config {
     pub peers: PeersConfig,
     /// Configuration for peer sessions.
     pub sessions: SessionsConfig,
+    /// Configuration for bootstrapping peer discovery.
+    pub discovery: DiscoveryConfig,
+    /// Configuration for the database environment.
+    pub database: DatabaseConfig,
 }
 
 impl Config {
@@ -92,12 +98,70 @@ impl Config {
     pub fn update_prune_config(&mut self, prune_config: PruneConfig) {
         self.prune = Some(prune_config);
     }
+
+    /// Returns the keys of the given TOML configuration that are not part of the [`Config`]
+    /// schema, as dotted paths.
+    ///
+    /// Unknown keys are ignored when the configuration is loaded, so they are usually typos.
+    pub fn unknown_keys(toml: &str) -> eyre::Result<Vec<String>> {
+        let table: toml::Table = toml.parse().map_err(|e| eyre!("Failed to parse TOML: {e}"))?;
+        crate::schema::unknown_keys::<Self>(toml::Value::Table(table))
+            .map_err(|e| eyre!("Failed to parse TOML: {e}"))
+    }
+
+    /// Validates the values of the configuration that are not restricted by their types.
+    pub fn validate(&self) -> eyre::Result<()> {
+        let StageConfig { headers, bodies, etl, .. } = &self.stages;
+        ensure!(
+            headers.downloader_min_concurrent_requests <= headers.downloader_max_concurrent_requests,
+            "stages.headers.downloader_min_concurrent_requests must not be greater than stages.headers.downloader_max_concurrent_requests"
+        );
+        ensure!(
+            headers.downloader_request_limit > 0,
+            "stages.headers.downloader_request_limit must be greater than 0"
+        );
+        ensure!(
+            bodies.downloader_min_concurrent_requests <= bodies.downloader_max_concurrent_requests,
+            "stages.bodies.downloader_min_concurrent_requests must not be greater than stages.bodies.downloader_max_concurrent_requests"
+        );
+        ensure!(
+            bodies.downloader_request_limit > 0,
+            "stages.bodies.downloader_request_limit must be greater than 0"
+        );
+        ensure!(etl.file_size > 0, "stages.etl.file_size must be greater than 0");
+
+        ensure!(
+            self.sessions.session_command_buffer > 0,
+            "sessions.session_command_buffer must be greater than 0"
+        );
+        ensure!(
+            self.sessions.session_event_buffer > 0,
+            "sessions.session_event_buffer must be greater than 0"
+        );
+        ensure!(
+            self.sessions.max_message_size > 0,
+            "sessions.max_message_size must be greater than 0"
+        );
+
+        if let (Some(max_size), Some(growth_step)) =
+            (self.database.max_size, self.database.growth_step)
+        {
+            ensure!(
+                growth_step <= max_size,
+                "database.growth_step must not be greater than database.max_size"
+            );
+        }
+
+        Ok(())
+    }
 }
 
 /// Configuration for each stage in the pipeline.
 #[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
 pub struct StageConfig {
+    /// The pipeline profile, selecting which stages run.
+    pub profile: PipelineProfile,
     /// Header stage configuration.
     pub headers: HeadersConfig,
     /// Body stage configuration.
@@ -137,6 +201,101 @@ impl StageConfig {
     }
 }
 
+/// A named selection of the pipeline stages that run, and with them the indices that are built.
+///
+/// Only the pipeline honors the profile. Blocks that are synced live through the engine are written
+/// with all their indices, so a node can only run with the profiles that build all of them, see
+/// [`PipelineProfile::is_supported_by_node`].
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PipelineProfile {
+    /// All stages run and nothing is pruned by default.
+    #[default]
+    Archive,
+    /// All stages run, with the pruning defaults of a full node.
+    Full,
+    /// Only headers, bodies and the transaction lookup index are synced, without executing the
+    /// blocks.
+    ///
+    /// Without the state, the node can't follow the chain after the pipeline finished.
+    ChainDataOnly,
+    /// The blocks are executed and the state root is validated, without building the transaction
+    /// lookup and history indices.
+    ///
+    /// The history indices of the blocks that are synced live can't be pruned entirely, so the
+    /// node can't keep them out of the database after the pipeline finished.
+    ExecutionOnly,
+}
+
+impl PipelineProfile {
+    /// Returns the name of the profile.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Archive => "archive",
+            Self::Full => "full",
+            Self::ChainDataOnly => "chain-data-only",
+            Self::ExecutionOnly => "execution-only",
+        }
+    }
+
+    /// Returns the stages that don't run with this profile.
+    pub const fn disabled_stages(&self) -> &'static [StageId] {
+        match self {
+            Self::Archive | Self::Full => &[],
+            Self::ChainDataOnly => &[
+                StageId::SenderRecovery,
+                StageId::Execution,
+                StageId::MerkleUnwind,
+                StageId::AccountHashing,
+                StageId::StorageHashing,
+                StageId::MerkleExecute,
+                StageId::IndexStorageHistory,
+                StageId::IndexAccountHistory,
+            ],
+            Self::ExecutionOnly => &[
+                StageId::TransactionLookup,
+                StageId::IndexStorageHistory,
+                StageId::IndexAccountHistory,
+            ],
+        }
+    }
+
+    /// Returns `true` if a node can run with this profile.
+    ///
+    /// [`PipelineProfile::ChainDataOnly`] and [`PipelineProfile::ExecutionOnly`] only apply to
+    /// the pipeline and can't be honoured once the node follows the chain through the engine.
+    pub const fn is_supported_by_node(&self) -> bool {
+        matches!(self, Self::Archive | Self::Full)
+    }
+
+    /// Returns `true` if the stage runs with this profile.
+    pub fn is_stage_enabled(&self, stage_id: StageId) -> bool {
+        !self.disabled_stages().contains(&stage_id)
+    }
+}
+
+impl fmt::Display for PipelineProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PipelineProfile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "archive" => Ok(Self::Archive),
+            "full" => Ok(Self::Full),
+            "chain-data-only" => Ok(Self::ChainDataOnly),
+            "execution-only" => Ok(Self::ExecutionOnly),
+            _ => Err(format!(
+                "invalid pipeline profile: {s}, expected one of archive, full, chain-data-only, execution-only"
+            )),
+        }
+    }
+}
+
 /// Header stage configuration.
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq, Serialize)]
 #[serde(default)]
@@ -239,6 +398,19 @@ pub struct ExecutionConfig {
         deserialize_with = "deserialize_duration"
     )]
     pub max_duration: Option<Duration>,
+    /// The number of blocks whose bodies and senders are read ahead of execution on background
+    /// threads. Prefetching is disabled if set to 0.
+    pub prefetch_blocks: usize,
+    /// The number of background threads that read blocks ahead of execution.
+    pub prefetch_threads: usize,
+    /// Only every `verification_interval`th block has its receipts root, logs bloom and requests
+    /// hash validated during historical sync. All blocks are validated if set to 0 or 1.
+    ///
+    /// The receipts of the blocks that are skipped are not verified.
+    pub verification_interval: u64,
+    /// All blocks within this distance of the sync target are fully validated, regardless of the
+    /// `verification_interval`.
+    pub verification_tip_distance: u64,
 }
 
 impl Default for ExecutionConfig {
@@ -250,6 +422,10 @@ impl Default for ExecutionConfig {
             max_cumulative_gas: Some(30_000_000 * 50_000),
             // 10 minutes
             max_duration: Some(Duration::from_secs(10 * 60)),
+            prefetch_blocks: 32,
+            prefetch_threads: 2,
+            verification_interval: 1,
+            verification_tip_distance: 10_000,
         }
     }
 }
@@ -431,6 +607,110 @@ impl PruneConfig {
     }
 }
 
+/// Configuration for bootstrapping peer discovery, e.g. for custom networks.
+///
+/// Values that are set replace the defaults of the chain. The `--bootnodes` command line argument
+/// takes precedence over the boot nodes set here.
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct DiscoveryConfig {
+    /// Boot nodes that replace the boot nodes of the chain entirely.
+    ///
+    /// Nodes can be given by DNS name instead of IP address, e.g.
+    /// `enode://<id>@bootnode.example.org:30303`, and are resolved again periodically.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bootnodes: Option<Vec<TrustedPeer>>,
+    /// EIP-1459 DNS discovery trees to bootstrap from, e.g. `enrtree://<key>@nodes.example.org`,
+    /// replacing the public DNS network of the chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns_networks: Option<Vec<String>>,
+    /// The interval at which the DNS discovery trees and the boot nodes given by DNS name are
+    /// resolved again.
+    ///
+    /// Defaults to 30 minutes.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "humantime_serde::serialize",
+        deserialize_with = "deserialize_duration"
+    )]
+    pub resolve_interval: Option<Duration>,
+}
+
+/// Database environment configuration.
+///
+/// Values that are not set fall back to the `--db.*` command line arguments, or to the defaults of
+/// the database. Command line arguments take precedence over the values set here.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default)]
+pub struct DatabaseConfig {
+    /// Maximum database size in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_size: Option<usize>,
+    /// Database growth step in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub growth_step: Option<usize>,
+    /// How commits are flushed to disk.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_mode: Option<DatabaseSyncMode>,
+    /// Disable OS readahead of the database file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_readahead: Option<bool>,
+}
+
+/// How commits to the database are flushed to disk.
+///
+/// Only the modes that can't corrupt the database on a system crash are supported.
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DatabaseSyncMode {
+    /// Data and metadata are flushed on every commit.
+    #[default]
+    Durable,
+    /// Data is flushed on every commit, metadata is flushed later. A system crash may undo the
+    /// last committed transaction.
+    NoMetaSync,
+    /// Nothing is flushed on commit, the flushes are left to the OS. A system crash may undo all
+    /// transactions since the last flush.
+    SafeNoSync,
+}
+
+impl DatabaseSyncMode {
+    /// Returns the name of the sync mode.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Durable => "durable",
+            Self::NoMetaSync => "no-meta-sync",
+            Self::SafeNoSync => "safe-no-sync",
+        }
+    }
+
+    /// Returns `true` if every commit is flushed to disk.
+    pub const fn is_durable(&self) -> bool {
+        matches!(self, Self::Durable)
+    }
+}
+
+impl fmt::Display for DatabaseSyncMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DatabaseSyncMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "durable" => Ok(Self::Durable),
+            "no-meta-sync" => Ok(Self::NoMetaSync),
+            "safe-no-sync" => Ok(Self::SafeNoSync),
+            _ => Err(format!(
+                "invalid database sync mode: {s}, expected one of durable, no-meta-sync, safe-no-sync"
+            )),
+        }
+    }
+}
+
 /// Helper type to support older versions of Duration deserialization.
 fn deserialize_duration<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
 where
@@ -451,7 +731,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{Config, EXTENSION};
+    use super::{Config, DatabaseSyncMode, DiscoveryConfig, PipelineProfile, StageId, EXTENSION};
     use crate::PruneConfig;
     use alloy_primitives::Address;
     use reth_network_peers::TrustedPeer;
@@ -996,6 +1276,28 @@ connect_trusted_nodes_only = true
         assert!(conf.peers.trusted_nodes_only);
     }
 
+    #[test]
+    fn test_discovery_config() {
+        let reth_toml = r#"
+    [discovery]
+    bootnodes = [
+        "enode://e9675164b5e17b9d9edf0cc2bd79e6b6f487200c74d1331c220abb5b8ee80c2eefbf18213989585e9d0960683e819542e11d4eefb5f2b4019e1e49f9fd8fff18@bootnode.example.org:30303"
+    ]
+    dns_networks = ["enrtree://AKA3AM6LPBYEUDMVNU3BSVQJ5AD45Y7YPOHJLEF6W26QOE4VTUDPE@all.mainnet.ethdisco.net"]
+    resolve_interval = "10m"
+    "#;
+
+        let conf: Config = toml::from_str(reth_toml).unwrap();
+        assert_eq!(conf.discovery.bootnodes.as_ref().map(Vec::len), Some(1));
+        assert_eq!(conf.discovery.dns_networks.as_ref().map(Vec::len), Some(1));
+        assert_eq!(conf.discovery.resolve_interval, Some(Duration::from_secs(600)));
+
+        let conf: Config = toml::from_str(&toml::to_string(&conf).unwrap()).unwrap();
+        assert_eq!(conf.discovery.resolve_interval, Some(Duration::from_secs(600)));
+
+        assert_eq!(Config::default().discovery, DiscoveryConfig::default());
+    }
+
     #[test]
     fn test_can_support_dns_in_trusted_nodes() {
         let reth_toml = r#"
@@ -1019,4 +1321,99 @@ connect_trusted_nodes_only = true
             assert!(conf.peers.trusted_nodes.contains(&node));
         }
     }
+
+    #[test]
+    fn test_database_config() {
+        let s = r#"
+[database]
+growth_step = 8589934592
+sync_mode = "safe-no-sync"
+no_readahead = false
+"#;
+        let conf: Config = toml::from_str(s).unwrap();
+        assert_eq!(conf.database.max_size, None);
+        assert_eq!(conf.database.growth_step, Some(8 * 1024 * 1024 * 1024));
+        assert_eq!(conf.database.sync_mode, Some(DatabaseSyncMode::SafeNoSync));
+        assert_eq!(conf.database.no_readahead, Some(false));
+
+        assert!(toml::from_str::<Config>("[database]\nsync_mode = \"utterly-no-sync\"").is_err());
+    }
+
+    #[test]
+    fn test_pipeline_profile() {
+        let conf: Config = toml::from_str("").unwrap();
+        assert_eq!(conf.stages.profile, PipelineProfile::Archive);
+
+        let conf: Config = toml::from_str("[stages]\nprofile = \"execution-only\"").unwrap();
+        assert_eq!(conf.stages.profile, PipelineProfile::ExecutionOnly);
+        assert!(conf.stages.profile.is_stage_enabled(StageId::Execution));
+        assert!(!conf.stages.profile.is_stage_enabled(StageId::TransactionLookup));
+        assert!(!conf.stages.profile.is_supported_by_node());
+        assert!(PipelineProfile::Full.is_supported_by_node());
+
+        assert!(toml::from_str::<Config>("[stages]\nprofile = \"light\"").is_err());
+    }
+
+    #[test]
+    fn test_unknown_keys() {
+        let s = r#"
+unknown = 1
+
+[stages.headers]
+commit_threshold = 100
+commit_treshold = 100
+
+[stages.execution]
+max_duration = { secs = 600, nanos = 0 }
+
+[prune]
+block_interval = 5
+
+[prune.parts]
+sender_recovery = "full"
+receipts_log = "full"
+
+[prune.parts.receipts_log_filter]
+"0xdac17f958d2ee523a2206206994597c13d831ec7" = { distance = 1000 }
+
+[peers]
+connect_trusted_nodes_only = true
+basic_nodes = []
+
+[sessions.initial_internal_request_timeout]
+secs = 20
+nanos = 0
+millis = 0
+"#;
+        let mut unknown = Config::unknown_keys(s).unwrap();
+        unknown.sort();
+        assert_eq!(
+            unknown,
+            vec![
+                "peers.basic_nodes",
+                "prune.parts.receipts_log",
+                "sessions.initial_internal_request_timeout.millis",
+                "stages.headers.commit_treshold",
+                "unknown",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Config::default().validate().is_ok());
+
+        let mut config = Config::default();
+        config.stages.bodies.downloader_min_concurrent_requests = 200;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::default();
+        config.sessions.session_event_buffer = 0;
+        assert!(config.validate().is_err());
+
+        let mut config = Config::default();
+        config.database.max_size = Some(1024);
+        config.database.growth_step = Some(2048);
+        assert!(config.validate().is_err());
+    }
 }
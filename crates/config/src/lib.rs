@@ -9,4 +9,8 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 pub mod config;
-pub use config::{BodiesConfig, Config, PruneConfig};
+mod schema;
+pub use config::{
+    BodiesConfig, Config, DatabaseConfig, DatabaseSyncMode, DiscoveryConfig, PipelineProfile,
+    PruneConfig,
+};
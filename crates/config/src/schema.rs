@@ -0,0 +1,158 @@
+//! Schema checks of configuration files.
+//!
+//! The schema of the configuration file is the typed [`Config`](crate::Config) and the serde
+//! implementations of its sections. Keys that are not part of the schema are found by deserializing
+//! the file into the typed config while recording the keys of every table that is deserialized into
+//! a struct without a matching field or alias.
+
+use serde::{
+    de::{
+        self, value::StringDeserializer, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess,
+    },
+    forward_to_deserialize_any, Deserialize,
+};
+use toml::{de::Error, Value};
+
+/// Returns the keys of `value` that are not part of the schema of `T`, as dotted paths.
+///
+/// Keys of tables that are deserialized into maps are always part of the schema.
+pub(crate) fn unknown_keys<T: for<'de> Deserialize<'de>>(
+    value: Value,
+) -> Result<Vec<String>, Error> {
+    let mut unknown = Vec::new();
+    T::deserialize(SchemaDeserializer { value, path: String::new(), unknown: &mut unknown })?;
+    Ok(unknown)
+}
+
+/// Returns the dotted path of `key` in the table at `path`.
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+/// Deserializer of a TOML value that records the keys that are not part of the schema.
+struct SchemaDeserializer<'a> {
+    value: Value,
+    /// Dotted path of the value.
+    path: String,
+    /// The keys that are not part of the schema.
+    unknown: &'a mut Vec<String>,
+}
+
+impl<'de> de::Deserializer<'de> for SchemaDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Table(table) => visitor.visit_map(TableAccess {
+                entries: table.into_iter(),
+                next_value: None,
+                path: self.path,
+                unknown: self.unknown,
+            }),
+            Value::Array(values) => visitor.visit_seq(ArrayAccess {
+                values: values.into_iter().enumerate(),
+                path: self.path,
+                unknown: self.unknown,
+            }),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        // the fields of derived implementations include the aliases of the fields
+        if let Value::Table(table) = &self.value {
+            self.unknown.extend(
+                table
+                    .keys()
+                    .filter(|key| !fields.contains(&key.as_str()))
+                    .map(|key| join(&self.path, key)),
+            );
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        // TOML has no null values, so every value that is present is `Some`
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct seq tuple tuple_struct map identifier ignored_any
+    }
+}
+
+/// Access to the entries of a TOML table.
+struct TableAccess<'a> {
+    entries: toml::map::IntoIter,
+    /// The value of the last key, with its dotted path.
+    next_value: Option<(String, Value)>,
+    path: String,
+    unknown: &'a mut Vec<String>,
+}
+
+impl<'de> MapAccess<'de> for TableAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let Some((key, value)) = self.entries.next() else { return Ok(None) };
+        self.next_value = Some((join(&self.path, &key), value));
+        let key: StringDeserializer<Error> = key.into_deserializer();
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let (path, value) =
+            self.next_value.take().ok_or_else(|| de::Error::custom("value is missing"))?;
+        seed.deserialize(SchemaDeserializer { value, path, unknown: &mut *self.unknown })
+    }
+}
+
+/// Access to the values of a TOML array.
+struct ArrayAccess<'a> {
+    values: std::iter::Enumerate<std::vec::IntoIter<Value>>,
+    path: String,
+    unknown: &'a mut Vec<String>,
+}
+
+impl<'de> SeqAccess<'de> for ArrayAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        let Some((index, value)) = self.values.next() else { return Ok(None) };
+        let path = format!("{}[{index}]", self.path);
+        seed.deserialize(SchemaDeserializer { value, path, unknown: &mut *self.unknown }).map(Some)
+    }
+}
@@ -10,6 +10,62 @@ use reth_primitives_traits::{
     Block, BlockBody, BlockHeader, GotExpected, SealedBlock, SealedHeader,
 };
 
+/// Controls which block validation checks that depend on chain rules are performed.
+///
+/// All checks are enabled by default, matching Ethereum mainnet. Chains with divergent rules can
+/// disable individual checks without replacing the consensus implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockValidationPolicy {
+    /// Whether the gas used in the header is compared to the gas used by the execution.
+    pub gas_used: bool,
+    /// Whether the receipts root and logs bloom in the header are compared to the ones calculated
+    /// from the execution receipts.
+    pub receipts_root: bool,
+    /// Whether the requests hash in the header is compared to the one calculated from the
+    /// execution requests.
+    pub requests_hash: bool,
+    /// Whether the blob gas used in the header is compared to the blob gas used by the block
+    /// transactions.
+    pub blob_gas_used: bool,
+}
+
+impl Default for BlockValidationPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BlockValidationPolicy {
+    /// Creates a new policy with all checks enabled.
+    pub const fn new() -> Self {
+        Self { gas_used: true, receipts_root: true, requests_hash: true, blob_gas_used: true }
+    }
+
+    /// Sets whether the gas used in the header is validated.
+    pub const fn with_gas_used(mut self, enabled: bool) -> Self {
+        self.gas_used = enabled;
+        self
+    }
+
+    /// Sets whether the receipts root and logs bloom in the header are validated.
+    pub const fn with_receipts_root(mut self, enabled: bool) -> Self {
+        self.receipts_root = enabled;
+        self
+    }
+
+    /// Sets whether the requests hash in the header is validated.
+    pub const fn with_requests_hash(mut self, enabled: bool) -> Self {
+        self.requests_hash = enabled;
+        self
+    }
+
+    /// Sets whether the blob gas used in the header is validated.
+    pub const fn with_blob_gas_used(mut self, enabled: bool) -> Self {
+        self.blob_gas_used = enabled;
+        self
+    }
+}
+
 /// Gas used needs to be less than gas limit. Gas used is going to be checked after execution.
 #[inline]
 pub fn validate_header_gas<H: BlockHeader>(header: &H) -> Result<(), ConsensusError> {
@@ -63,9 +119,25 @@ pub fn validate_shanghai_withdrawals<B: Block>(
 /// [EIP-4844]: https://eips.ethereum.org/EIPS/eip-4844
 #[inline]
 pub fn validate_cancun_gas<B: Block>(block: &SealedBlock<B>) -> Result<(), ConsensusError> {
+    validate_cancun_gas_with_policy(block, BlockValidationPolicy::default())
+}
+
+/// Validate that blob gas is present in the block if Cancun is active, comparing it to the blob
+/// gas used by the block transactions only if enabled by the given [`BlockValidationPolicy`].
+///
+/// See [`validate_cancun_gas`].
+#[inline]
+pub fn validate_cancun_gas_with_policy<B: Block>(
+    block: &SealedBlock<B>,
+    policy: BlockValidationPolicy,
+) -> Result<(), ConsensusError> {
+    let header_blob_gas_used = block.blob_gas_used().ok_or(ConsensusError::BlobGasUsedMissing)?;
+    if !policy.blob_gas_used {
+        return Ok(())
+    }
+
     // Check that the blob gas used in the header matches the sum of the blob gas used by each
     // blob tx
-    let header_blob_gas_used = block.blob_gas_used().ok_or(ConsensusError::BlobGasUsedMissing)?;
     let total_blob_gas = block.body().blob_gas_used();
     if total_blob_gas != header_blob_gas_used {
         return Err(ConsensusError::BlobGasUsedDiff(GotExpected {
@@ -134,7 +206,23 @@ pub fn validate_block_pre_execution<B, ChainSpec>(
 ) -> Result<(), ConsensusError>
 where
     B: Block,
-    ChainSpec: EthereumHardforks,
+    ChainSpec: EthChainSpec + EthereumHardforks,
+{
+    validate_block_pre_execution_with_policy(block, chain_spec, BlockValidationPolicy::default())
+}
+
+/// Validate a block without regard for state, performing only the checks enabled by the given
+/// [`BlockValidationPolicy`].
+///
+/// See [`validate_block_pre_execution`].
+pub fn validate_block_pre_execution_with_policy<B, ChainSpec>(
+    block: &SealedBlock<B>,
+    chain_spec: &ChainSpec,
+    policy: BlockValidationPolicy,
+) -> Result<(), ConsensusError>
+where
+    B: Block,
+    ChainSpec: EthChainSpec + EthereumHardforks,
 {
     // Check ommers hash
     let ommers_hash = block.body().calculate_ommers_root();
@@ -154,12 +242,14 @@ where
     }
 
     // EIP-4895: Beacon chain push withdrawals as operations
-    if chain_spec.is_shanghai_active_at_timestamp(block.timestamp()) {
+    if chain_spec.execution_features().withdrawals &&
+        chain_spec.is_shanghai_active_at_timestamp(block.timestamp())
+    {
         validate_shanghai_withdrawals(block)?;
     }
 
     if chain_spec.is_cancun_active_at_timestamp(block.timestamp()) {
-        validate_cancun_gas(block)?;
+        validate_cancun_gas_with_policy(block, policy)?;
     }
 
     Ok(())
@@ -175,13 +265,29 @@ where
 ///
 /// Note: This does not enforce any restrictions on `blob_gas_used`
 pub fn validate_4844_header_standalone<H: BlockHeader>(header: &H) -> Result<(), ConsensusError> {
-    let blob_gas_used = header.blob_gas_used().ok_or(ConsensusError::BlobGasUsedMissing)?;
-    let excess_blob_gas = header.excess_blob_gas().ok_or(ConsensusError::ExcessBlobGasMissing)?;
+    header.blob_gas_used().ok_or(ConsensusError::BlobGasUsedMissing)?;
+    header.excess_blob_gas().ok_or(ConsensusError::ExcessBlobGasMissing)?;
 
     if header.parent_beacon_block_root().is_none() {
         return Err(ConsensusError::ParentBeaconBlockRootMissing)
     }
 
+    validate_4844_blob_gas_fields(header)
+}
+
+/// Validates that the EIP-4844 blob gas header fields exist and conform to the spec, without
+/// requiring the `parent_beacon_block_root`. This ensures that:
+///
+///  * `blob_gas_used` exists as a header field
+///  * `excess_blob_gas` exists as a header field
+///  * `blob_gas_used` is a multiple of `DATA_GAS_PER_BLOB`
+///  * `excess_blob_gas` is a multiple of `DATA_GAS_PER_BLOB`
+///
+/// This is used for chains that disable the EIP-4788 beacon root.
+pub fn validate_4844_blob_gas_fields<H: BlockHeader>(header: &H) -> Result<(), ConsensusError> {
+    let blob_gas_used = header.blob_gas_used().ok_or(ConsensusError::BlobGasUsedMissing)?;
+    let excess_blob_gas = header.excess_blob_gas().ok_or(ConsensusError::ExcessBlobGasMissing)?;
+
     if blob_gas_used % DATA_GAS_PER_BLOB != 0 {
         return Err(ConsensusError::BlobGasUsedNotMultipleOfBlobGasPerBlob {
             blob_gas_used,
@@ -337,7 +443,7 @@ mod tests {
     use super::*;
     use alloy_consensus::{BlockBody, Header, TxEip4844};
     use alloy_eips::eip4895::Withdrawals;
-    use alloy_primitives::{Address, Bytes, PrimitiveSignature as Signature, U256};
+    use alloy_primitives::{Address, Bytes, PrimitiveSignature as Signature, B256, U256};
     use rand::Rng;
     use reth_chainspec::ChainSpecBuilder;
     use reth_ethereum_primitives::{Transaction, TransactionSigned};
@@ -398,4 +504,59 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn cancun_block_blob_gas_used_policy() {
+        let chain_spec = ChainSpecBuilder::mainnet().cancun_activated().build();
+        let transaction = mock_blob_tx(1, 10);
+        let block = |header: Header| {
+            SealedBlock::seal_slow(alloy_consensus::Block {
+                header,
+                body: BlockBody {
+                    transactions: vec![transaction.clone()],
+                    ommers: vec![],
+                    withdrawals: Some(Withdrawals::default()),
+                },
+            })
+        };
+        let header = Header {
+            base_fee_per_gas: Some(1337),
+            withdrawals_root: Some(proofs::calculate_withdrawals_root(&[])),
+            blob_gas_used: Some(1),
+            transactions_root: proofs::calculate_transaction_root(&[transaction.clone()]),
+            ..Default::default()
+        };
+
+        // only the blob gas used comparison is skipped
+        let policy = BlockValidationPolicy::default().with_blob_gas_used(false);
+        let validate =
+            |header| validate_block_pre_execution_with_policy(&block(header), &chain_spec, policy);
+        assert_eq!(validate(header.clone()), Ok(()));
+        assert_eq!(
+            validate(Header { blob_gas_used: None, ..header.clone() }),
+            Err(ConsensusError::BlobGasUsedMissing)
+        );
+        assert!(matches!(
+            validate(Header { ommers_hash: B256::ZERO, ..header.clone() }),
+            Err(ConsensusError::BodyOmmersHashDiff(_))
+        ));
+        assert!(matches!(
+            validate(Header { transactions_root: B256::ZERO, ..header.clone() }),
+            Err(ConsensusError::BodyTransactionRootDiff(_))
+        ));
+        assert!(matches!(
+            validate(Header { withdrawals_root: Some(B256::ZERO), ..header.clone() }),
+            Err(ConsensusError::BodyWithdrawalsRootDiff(_))
+        ));
+
+        // disabling the other checks doesn't skip the blob gas used comparison
+        let policy = BlockValidationPolicy::default()
+            .with_gas_used(false)
+            .with_receipts_root(false)
+            .with_requests_hash(false);
+        assert!(matches!(
+            validate_block_pre_execution_with_policy(&block(header), &chain_spec, policy),
+            Err(ConsensusError::BlobGasUsedDiff(_))
+        ));
+    }
 }
@@ -0,0 +1,202 @@
+//! Builder for launching ephemeral test nodes in a single call.
+
+use crate::{
+    setup_engine, transaction::TransactionTestContext, wallet::Wallet, Adapter, NodeBuilderHelper,
+    NodeHelperType, PayloadAttributesBuilder, TmpDB, TmpNodeAdapter,
+};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{Address, TxKind, B256, U256};
+use alloy_rpc_types_engine::PayloadAttributes;
+use alloy_rpc_types_eth::TransactionRequest;
+use reth_engine_local::LocalPayloadAttributesBuilder;
+use reth_node_api::{BlockTy, NodeTypesWithDBAdapter, PayloadTypes};
+use reth_node_builder::{rpc::RethRpcAddOns, Node};
+use reth_payload_builder::EthPayloadBuilderAttributes;
+use reth_provider::{providers::BlockchainProvider, BlockReader};
+use reth_rpc_eth_api::helpers::{EthApiSpec, EthTransactions, TraceExt};
+use reth_tasks::TaskManager;
+use std::{fmt, sync::Arc};
+
+/// The payload builder attributes type of the node `N`.
+type BuilderAttributes<N> =
+    <<N as reth_node_api::NodeTypesWithEngine>::Engine as PayloadTypes>::PayloadBuilderAttributes;
+
+/// The eth API type of the node `N`.
+type TestNodeEthApi<N> =
+    <<N as Node<TmpNodeAdapter<N>>>::AddOns as RethRpcAddOns<Adapter<N>>>::EthApi;
+
+/// A launched test node.
+pub type TestNode<N> = NodeHelperType<N, BlockchainProvider<NodeTypesWithDBAdapter<N, TmpDB>>>;
+
+/// Builder for one or more ephemeral nodes backed by a temporary database.
+///
+/// Every node runs the engine, RPC server and transaction pool on unused ports. Nodes are peered
+/// with each other and their engine is initialized with the genesis block, so the chain can be
+/// advanced right away.
+///
+/// ```ignore
+/// let mut nodes = NodeTestBuilder::<EthereumNode>::new(chain_spec).launch().await?;
+/// let payloads = nodes.advance_blocks(10).await?;
+/// ```
+pub struct NodeTestBuilder<N, F = fn(u64) -> BuilderAttributes<N>>
+where
+    N: NodeBuilderHelper,
+    LocalPayloadAttributesBuilder<N::ChainSpec>:
+        PayloadAttributesBuilder<<N::Engine as PayloadTypes>::PayloadAttributes>,
+{
+    /// Chain specification of all nodes.
+    chain_spec: Arc<N::ChainSpec>,
+    /// Number of nodes to launch.
+    num_nodes: usize,
+    /// Whether to launch the nodes in dev mode.
+    is_dev: bool,
+    /// Generates the payload attributes for a given timestamp.
+    attributes_generator: F,
+}
+
+impl<N, F> fmt::Debug for NodeTestBuilder<N, F>
+where
+    N: NodeBuilderHelper,
+    LocalPayloadAttributesBuilder<N::ChainSpec>:
+        PayloadAttributesBuilder<<N::Engine as PayloadTypes>::PayloadAttributes>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeTestBuilder")
+            .field("num_nodes", &self.num_nodes)
+            .field("is_dev", &self.is_dev)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<N> NodeTestBuilder<N>
+where
+    N: NodeBuilderHelper,
+    LocalPayloadAttributesBuilder<N::ChainSpec>:
+        PayloadAttributesBuilder<<N::Engine as PayloadTypes>::PayloadAttributes>,
+{
+    /// Creates a new builder for a single, non-dev node with the given chain spec.
+    ///
+    /// Payload attributes default to [`default_payload_attributes`].
+    pub fn new(chain_spec: Arc<N::ChainSpec>) -> Self {
+        Self {
+            chain_spec,
+            num_nodes: 1,
+            is_dev: false,
+            attributes_generator: |timestamp| default_payload_attributes(timestamp).into(),
+        }
+    }
+}
+
+impl<N, F> NodeTestBuilder<N, F>
+where
+    N: NodeBuilderHelper,
+    F: Fn(u64) -> BuilderAttributes<N> + Send + Sync + Copy + 'static,
+    LocalPayloadAttributesBuilder<N::ChainSpec>:
+        PayloadAttributesBuilder<<N::Engine as PayloadTypes>::PayloadAttributes>,
+{
+    /// Sets the number of interconnected nodes to launch.
+    pub const fn with_nodes(mut self, num_nodes: usize) -> Self {
+        self.num_nodes = num_nodes;
+        self
+    }
+
+    /// Launches the nodes in dev mode.
+    pub const fn dev(mut self) -> Self {
+        self.is_dev = true;
+        self
+    }
+
+    /// Sets the function used to generate payload attributes from a timestamp.
+    pub fn with_attributes_generator<G>(self, attributes_generator: G) -> NodeTestBuilder<N, G>
+    where
+        G: Fn(u64) -> BuilderAttributes<N> + Send + Sync + Copy + 'static,
+    {
+        let Self { chain_spec, num_nodes, is_dev, .. } = self;
+        NodeTestBuilder { chain_spec, num_nodes, is_dev, attributes_generator }
+    }
+
+    /// Launches all nodes and returns handles to them.
+    pub async fn launch(self) -> eyre::Result<TestNodes<N>> {
+        let Self { chain_spec, num_nodes, is_dev, attributes_generator } = self;
+        let (nodes, tasks, wallet) =
+            setup_engine::<N>(num_nodes, chain_spec, is_dev, attributes_generator).await?;
+        Ok(TestNodes { nodes, tasks, wallet })
+    }
+}
+
+/// Handles to nodes launched by a [`NodeTestBuilder`].
+///
+/// The nodes are shut down once this is dropped.
+#[expect(missing_debug_implementations)]
+pub struct TestNodes<N>
+where
+    N: NodeBuilderHelper,
+    LocalPayloadAttributesBuilder<N::ChainSpec>:
+        PayloadAttributesBuilder<<N::Engine as PayloadTypes>::PayloadAttributes>,
+{
+    /// The launched nodes, in launch order.
+    pub nodes: Vec<TestNode<N>>,
+    /// Task manager owning all node tasks.
+    pub tasks: TaskManager,
+    /// Wallet funded by the test genesis, configured with the chain id of the nodes.
+    pub wallet: Wallet,
+}
+
+impl<N> TestNodes<N>
+where
+    N: NodeBuilderHelper,
+    LocalPayloadAttributesBuilder<N::ChainSpec>:
+        PayloadAttributesBuilder<<N::Engine as PayloadTypes>::PayloadAttributes>,
+{
+    /// Returns the first launched node.
+    pub fn primary(&mut self) -> &mut TestNode<N> {
+        self.nodes.first_mut().expect("at least one node is launched")
+    }
+
+    /// Builds `count` blocks on the first node and makes each of them canonical.
+    ///
+    /// Every block includes a transfer sent from [`Self::wallet`], so the payload builder has
+    /// something to build on.
+    pub async fn advance_blocks(
+        &mut self,
+        count: u64,
+    ) -> eyre::Result<Vec<<N::Engine as PayloadTypes>::BuiltPayload>>
+    where
+        TestNodeEthApi<N>:
+            EthApiSpec<Provider: BlockReader<Block = BlockTy<N>>> + EthTransactions + TraceExt,
+    {
+        let mut payloads = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let tx = TransactionRequest {
+                nonce: Some(self.wallet.inner_nonce),
+                value: Some(U256::from(1)),
+                to: Some(TxKind::Call(Address::ZERO)),
+                gas: Some(21_000),
+                max_fee_per_gas: Some(20e9 as u128),
+                max_priority_fee_per_gas: Some(20e9 as u128),
+                chain_id: Some(self.wallet.chain_id),
+                ..Default::default()
+            };
+            let raw_tx = TransactionTestContext::sign_tx(self.wallet.inner.clone(), tx).await;
+            self.wallet.inner_nonce += 1;
+
+            let node = self.primary();
+            node.rpc.inject_tx(raw_tx.encoded_2718().into()).await?;
+            payloads.push(node.advance_block().await?);
+        }
+        Ok(payloads)
+    }
+}
+
+/// Returns payload attributes with the given timestamp and empty withdrawals, zero prevrandao,
+/// fee recipient and parent beacon block root.
+pub fn default_payload_attributes(timestamp: u64) -> EthPayloadBuilderAttributes {
+    let attributes = PayloadAttributes {
+        timestamp,
+        prev_randao: B256::ZERO,
+        suggested_fee_recipient: Address::ZERO,
+        withdrawals: Some(vec![]),
+        parent_beacon_block_root: Some(B256::ZERO),
+    };
+    EthPayloadBuilderAttributes::new(B256::ZERO, attributes)
+}
@@ -1,5 +1,6 @@
 //! Utilities for end-to-end tests.
 
+pub use builder::{NodeTestBuilder, TestNodes};
 use node::NodeTestContext;
 use reth_chainspec::{ChainSpec, EthChainSpec};
 use reth_db::{test_utils::TempDatabase, DatabaseEnv};
@@ -20,6 +21,9 @@ use std::sync::Arc;
 use tracing::{span, Level};
 use wallet::Wallet;
 
+/// Builder for launching test nodes
+pub mod builder;
+
 /// Wrapper type to create test nodes
 pub mod node;
 pub mod testsuite;
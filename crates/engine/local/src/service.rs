@@ -122,6 +122,14 @@ where
 
         Self { handler, incoming_requests: from_engine }
     }
+
+    /// Returns a mutable reference to the request handler.
+    pub fn handler_mut(
+        &mut self,
+    ) -> &mut EngineApiRequestHandler<EngineApiRequest<N::Engine, N::Primitives>, N::Primitives>
+    {
+        &mut self.handler
+    }
 }
 
 impl<N> Stream for LocalEngineService<N>
@@ -0,0 +1,97 @@
+//! Types for reporting the progress of the backfill sync.
+
+use alloc::{string::String, sync::Arc};
+use alloy_primitives::BlockNumber;
+use core::{fmt, time::Duration};
+use tokio::sync::watch;
+
+/// The progress of a running backfill sync.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackfillSyncStatus {
+    /// The name of the running stage.
+    pub stage: String,
+    /// The block the stage has processed up to.
+    pub checkpoint: BlockNumber,
+    /// The block the pipeline syncs to, if known.
+    pub target: Option<BlockNumber>,
+    /// The processed share of the stage's work in percent, if known.
+    pub stage_progress: Option<f64>,
+    /// The estimated time until the stage is done, if known.
+    pub stage_eta: Option<Duration>,
+}
+
+impl fmt::Display for BackfillSyncStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "syncing {} {}", self.stage, self.checkpoint)?;
+        if let Some(target) = self.target {
+            write!(f, "/{target}")?;
+        }
+        if let Some(progress) = self.stage_progress {
+            write!(f, " {progress:.2}%")?;
+        }
+        if let Some(eta) = self.stage_eta {
+            let secs = eta.as_secs();
+            write!(f, " eta {}h{:02}m{:02}s", secs / 3600, secs % 3600 / 60, secs % 60)?;
+        }
+        Ok(())
+    }
+}
+
+/// A handle to the progress of the backfill sync.
+///
+/// The node's event handler updates it while the pipeline runs, and the engine API reports it to
+/// the consensus layer. Cloning the handle is cheap, all clones share the same status.
+#[derive(Debug, Clone)]
+pub struct BackfillProgress {
+    status: Arc<watch::Sender<Option<BackfillSyncStatus>>>,
+}
+
+impl BackfillProgress {
+    /// Creates a new handle without a running backfill sync.
+    pub fn new() -> Self {
+        Self { status: Arc::new(watch::Sender::new(None)) }
+    }
+
+    /// Sets the progress of the running backfill sync, or `None` if it's not running.
+    pub fn update(&self, status: Option<BackfillSyncStatus>) {
+        self.status.send_replace(status);
+    }
+
+    /// Returns the progress of the running backfill sync, if any.
+    pub fn status(&self) -> Option<BackfillSyncStatus> {
+        self.status.borrow().clone()
+    }
+
+    /// Returns a receiver that is notified whenever the progress is updated.
+    pub fn subscribe(&self) -> watch::Receiver<Option<BackfillSyncStatus>> {
+        self.status.subscribe()
+    }
+}
+
+impl Default for BackfillProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_status() {
+        let mut status = BackfillSyncStatus {
+            stage: "Execution".into(),
+            checkpoint: 1000,
+            target: None,
+            stage_progress: None,
+            stage_eta: None,
+        };
+        assert_eq!(status.to_string(), "syncing Execution 1000");
+
+        status.target = Some(2000);
+        status.stage_progress = Some(50.0);
+        status.stage_eta = Some(Duration::from_secs(3 * 3600 + 7 * 60 + 5));
+        assert_eq!(status.to_string(), "syncing Execution 1000/2000 50.00% eta 3h07m05s");
+    }
+}
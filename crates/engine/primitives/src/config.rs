@@ -1,5 +1,8 @@
 //! Engine tree configuration.
 
+use alloc::{format, string::String};
+use core::time::Duration;
+
 /// Triggers persistence when the number of canonical blocks in memory exceeds this threshold.
 pub const DEFAULT_PERSISTENCE_THRESHOLD: u64 = 2;
 
@@ -11,6 +14,19 @@ const DEFAULT_MAX_INVALID_HEADER_CACHE_LENGTH: u32 = 256;
 const DEFAULT_MAX_EXECUTE_BLOCK_BATCH_SIZE: usize = 4;
 const DEFAULT_CROSS_BLOCK_CACHE_SIZE: u64 = 4 * 1024 * 1024 * 1024;
 
+/// How long persisted buffered blocks are kept by default.
+pub const DEFAULT_BLOCK_BUFFER_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The default maximum size in bytes of the persisted buffered blocks.
+pub const DEFAULT_BLOCK_BUFFER_MAX_SIZE: u64 = 512 * 1024 * 1024;
+
+/// The default maximum number of accounts whose access frequencies are tracked to warm the
+/// execution cache after a restart.
+pub const DEFAULT_STATE_CACHE_WARMUP_ACCOUNTS: usize = 10_000;
+
+/// How long a forkchoice update waits for canonicalization checks by default.
+pub const DEFAULT_CANONICALIZATION_BUDGET: Duration = Duration::from_secs(1);
+
 /// Determines if the host has enough parallelism to run the payload processor.
 ///
 /// It requires at least 5 parallel threads:
@@ -42,6 +58,14 @@ pub struct TreeConfig {
     /// Number of pending blocks that cannot be executed due to missing parent and
     /// are kept in cache.
     block_buffer_limit: u32,
+    /// The directory the buffered blocks are persisted to, so they survive a restart. If `None`,
+    /// buffered blocks are only kept in memory.
+    #[cfg(feature = "std")]
+    block_buffer_dir: Option<std::path::PathBuf>,
+    /// Persisted buffered blocks older than this are discarded on startup.
+    block_buffer_max_age: Duration,
+    /// Maximum size in bytes of the persisted buffered blocks.
+    block_buffer_max_size: u64,
     /// Number of invalid headers to keep in cache.
     max_invalid_header_cache_length: u32,
     /// Maximum number of blocks to execute sequentially in a batch.
@@ -61,6 +85,18 @@ pub struct TreeConfig {
     cross_block_cache_size: u64,
     /// Whether the host has enough parallelism to run state root task.
     has_enough_parallelism: bool,
+    /// When a new head block of a forkchoice update is made canonical.
+    canonicalization_mode: CanonicalizationMode,
+    /// How long a forkchoice update waits for canonicalization checks to complete.
+    canonicalization_budget: Duration,
+    /// The file the access frequencies of accounts and storage slots are persisted to, to warm
+    /// the execution cache with the most frequently accessed state on startup. If `None`, access
+    /// frequencies are not tracked.
+    #[cfg(feature = "std")]
+    state_access_file: Option<std::path::PathBuf>,
+    /// Maximum number of accounts whose access frequencies are tracked and that are loaded into
+    /// the execution cache on startup.
+    state_cache_warmup_accounts: usize,
 }
 
 impl Default for TreeConfig {
@@ -69,6 +105,10 @@ impl Default for TreeConfig {
             persistence_threshold: DEFAULT_PERSISTENCE_THRESHOLD,
             memory_block_buffer_target: DEFAULT_MEMORY_BLOCK_BUFFER_TARGET,
             block_buffer_limit: DEFAULT_BLOCK_BUFFER_LIMIT,
+            #[cfg(feature = "std")]
+            block_buffer_dir: None,
+            block_buffer_max_age: DEFAULT_BLOCK_BUFFER_MAX_AGE,
+            block_buffer_max_size: DEFAULT_BLOCK_BUFFER_MAX_SIZE,
             max_invalid_header_cache_length: DEFAULT_MAX_INVALID_HEADER_CACHE_LENGTH,
             max_execute_block_batch_size: DEFAULT_MAX_EXECUTE_BLOCK_BATCH_SIZE,
             legacy_state_root: false,
@@ -76,6 +116,11 @@ impl Default for TreeConfig {
             use_caching_and_prewarming: false,
             cross_block_cache_size: DEFAULT_CROSS_BLOCK_CACHE_SIZE,
             has_enough_parallelism: has_enough_parallelism(),
+            canonicalization_mode: CanonicalizationMode::Immediate,
+            canonicalization_budget: DEFAULT_CANONICALIZATION_BUDGET,
+            #[cfg(feature = "std")]
+            state_access_file: None,
+            state_cache_warmup_accounts: DEFAULT_STATE_CACHE_WARMUP_ACCOUNTS,
         }
     }
 }
@@ -99,6 +144,10 @@ impl TreeConfig {
             persistence_threshold,
             memory_block_buffer_target,
             block_buffer_limit,
+            #[cfg(feature = "std")]
+            block_buffer_dir: None,
+            block_buffer_max_age: DEFAULT_BLOCK_BUFFER_MAX_AGE,
+            block_buffer_max_size: DEFAULT_BLOCK_BUFFER_MAX_SIZE,
             max_invalid_header_cache_length,
             max_execute_block_batch_size,
             legacy_state_root,
@@ -106,6 +155,11 @@ impl TreeConfig {
             use_caching_and_prewarming,
             cross_block_cache_size,
             has_enough_parallelism,
+            canonicalization_mode: CanonicalizationMode::Immediate,
+            canonicalization_budget: DEFAULT_CANONICALIZATION_BUDGET,
+            #[cfg(feature = "std")]
+            state_access_file: None,
+            state_cache_warmup_accounts: DEFAULT_STATE_CACHE_WARMUP_ACCOUNTS,
         }
     }
 
@@ -124,6 +178,22 @@ impl TreeConfig {
         self.block_buffer_limit
     }
 
+    /// Return the directory the buffered blocks are persisted to, if any.
+    #[cfg(feature = "std")]
+    pub fn block_buffer_dir(&self) -> Option<&std::path::Path> {
+        self.block_buffer_dir.as_deref()
+    }
+
+    /// Return the maximum age of persisted buffered blocks.
+    pub const fn block_buffer_max_age(&self) -> Duration {
+        self.block_buffer_max_age
+    }
+
+    /// Return the maximum size in bytes of the persisted buffered blocks.
+    pub const fn block_buffer_max_size(&self) -> u64 {
+        self.block_buffer_max_size
+    }
+
     /// Return the maximum invalid cache header length.
     pub const fn max_invalid_header_cache_length(&self) -> u32 {
         self.max_invalid_header_cache_length
@@ -156,6 +226,28 @@ impl TreeConfig {
         self.cross_block_cache_size
     }
 
+    /// Returns when a new head block of a forkchoice update is made canonical.
+    pub const fn canonicalization_mode(&self) -> CanonicalizationMode {
+        self.canonicalization_mode
+    }
+
+    /// Returns how long a forkchoice update waits for canonicalization checks to complete.
+    pub const fn canonicalization_budget(&self) -> Duration {
+        self.canonicalization_budget
+    }
+
+    /// Return the file the access frequencies of accounts and storage slots are persisted to, if
+    /// any.
+    #[cfg(feature = "std")]
+    pub fn state_access_file(&self) -> Option<&std::path::Path> {
+        self.state_access_file.as_deref()
+    }
+
+    /// Return the maximum number of accounts that are loaded into the execution cache on startup.
+    pub const fn state_cache_warmup_accounts(&self) -> usize {
+        self.state_cache_warmup_accounts
+    }
+
     /// Setter for persistence threshold.
     pub const fn with_persistence_threshold(mut self, persistence_threshold: u64) -> Self {
         self.persistence_threshold = persistence_threshold;
@@ -177,6 +269,25 @@ impl TreeConfig {
         self
     }
 
+    /// Setter for the directory the buffered blocks are persisted to.
+    #[cfg(feature = "std")]
+    pub fn with_block_buffer_dir(mut self, block_buffer_dir: Option<std::path::PathBuf>) -> Self {
+        self.block_buffer_dir = block_buffer_dir;
+        self
+    }
+
+    /// Setter for the maximum age of persisted buffered blocks.
+    pub const fn with_block_buffer_max_age(mut self, block_buffer_max_age: Duration) -> Self {
+        self.block_buffer_max_age = block_buffer_max_age;
+        self
+    }
+
+    /// Setter for the maximum size in bytes of the persisted buffered blocks.
+    pub const fn with_block_buffer_max_size(mut self, block_buffer_max_size: u64) -> Self {
+        self.block_buffer_max_size = block_buffer_max_size;
+        self
+    }
+
     /// Setter for maximum invalid header cache length.
     pub const fn with_max_invalid_header_cache_length(
         mut self,
@@ -229,8 +340,94 @@ impl TreeConfig {
         self
     }
 
+    /// Setter for the canonicalization mode.
+    pub const fn with_canonicalization_mode(
+        mut self,
+        canonicalization_mode: CanonicalizationMode,
+    ) -> Self {
+        self.canonicalization_mode = canonicalization_mode;
+        self
+    }
+
+    /// Setter for the canonicalization budget.
+    pub const fn with_canonicalization_budget(mut self, canonicalization_budget: Duration) -> Self {
+        self.canonicalization_budget = canonicalization_budget;
+        self
+    }
+
+    /// Setter for the file the access frequencies of accounts and storage slots are persisted to.
+    #[cfg(feature = "std")]
+    pub fn with_state_access_file(mut self, state_access_file: Option<std::path::PathBuf>) -> Self {
+        self.state_access_file = state_access_file;
+        self
+    }
+
+    /// Setter for the maximum number of accounts that are loaded into the execution cache on
+    /// startup.
+    pub const fn with_state_cache_warmup_accounts(
+        mut self,
+        state_cache_warmup_accounts: usize,
+    ) -> Self {
+        self.state_cache_warmup_accounts = state_cache_warmup_accounts;
+        self
+    }
+
     /// Whether or not to use state root task
     pub fn use_state_root_task(&self) -> bool {
         self.has_enough_parallelism && !self.legacy_state_root
     }
 }
+
+/// Determines when the new head block of a forkchoice update is made canonical.
+///
+/// Payloads are only reported `VALID` after their state root has been checked, so the only
+/// canonicalization check that can still be pending when a forkchoice update arrives is the
+/// persistence of previous blocks to disk. The canonicalization budget bounds how long a forkchoice
+/// update waits for it, e.g. to stay within the payload attestation deadline of a slot.
+///
+/// Only forkchoice updates to a new head block wait, and the engine keeps handling other messages
+/// while the response is deferred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CanonicalizationMode {
+    /// Make the head canonical right away, without waiting for persistence.
+    #[default]
+    Immediate,
+    /// Wait for persistence up to the canonicalization budget, then make the head canonical
+    /// whether or not persistence completed.
+    Latency,
+    /// Wait for persistence up to the canonicalization budget, and report `SYNCING` without making
+    /// the head canonical if it did not complete in time.
+    Correctness,
+}
+
+impl CanonicalizationMode {
+    /// Returns true if forkchoice updates wait for persistence before making a new head canonical.
+    pub const fn waits_for_persistence(&self) -> bool {
+        !matches!(self, Self::Immediate)
+    }
+}
+
+impl core::fmt::Display for CanonicalizationMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Immediate => f.write_str("immediate"),
+            Self::Latency => f.write_str("latency"),
+            Self::Correctness => f.write_str("correctness"),
+        }
+    }
+}
+
+impl core::str::FromStr for CanonicalizationMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "immediate" => Ok(Self::Immediate),
+            "latency" => Ok(Self::Latency),
+            "correctness" => Ok(Self::Correctness),
+            _ => Err(format!(
+                "invalid canonicalization mode: {s}, expected one of immediate, latency, correctness"
+            )),
+        }
+    }
+}
@@ -40,6 +40,15 @@ pub use event::*;
 mod invalid_block_hook;
 pub use invalid_block_hook::InvalidBlockHook;
 
+mod snapshot;
+pub use snapshot::*;
+
+mod sync_target;
+pub use sync_target::*;
+
+mod backfill_progress;
+pub use backfill_progress::*;
+
 pub mod config;
 pub use config::*;
 
@@ -1,6 +1,6 @@
 use crate::{
-    error::BeaconForkChoiceUpdateError, BeaconOnNewPayloadError, EngineApiMessageVersion,
-    EngineTypes, ExecutionPayload, ForkchoiceStatus,
+    error::BeaconForkChoiceUpdateError, BackfillProgress, BeaconOnNewPayloadError,
+    EngineApiMessageVersion, EngineTypes, ExecutionPayload, ForkchoiceStatus,
 };
 use alloy_rpc_types_engine::{
     ForkChoiceUpdateResult, ForkchoiceState, ForkchoiceUpdateError, ForkchoiceUpdated, PayloadId,
@@ -201,6 +201,8 @@ where
     Engine: EngineTypes,
 {
     to_engine: UnboundedSender<BeaconEngineMessage<Engine>>,
+    /// The progress of the backfill sync of the engine.
+    backfill_progress: BackfillProgress,
 }
 
 impl<Engine> BeaconConsensusEngineHandle<Engine>
@@ -208,8 +210,13 @@ where
     Engine: EngineTypes,
 {
     /// Creates a new beacon consensus engine handle.
-    pub const fn new(to_engine: UnboundedSender<BeaconEngineMessage<Engine>>) -> Self {
-        Self { to_engine }
+    pub fn new(to_engine: UnboundedSender<BeaconEngineMessage<Engine>>) -> Self {
+        Self { to_engine, backfill_progress: BackfillProgress::new() }
+    }
+
+    /// Returns the handle to the progress of the backfill sync of the engine.
+    pub const fn backfill_progress(&self) -> &BackfillProgress {
+        &self.backfill_progress
     }
 
     /// Sends a new payload message to the beacon consensus engine and waits for a response.
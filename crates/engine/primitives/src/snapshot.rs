@@ -0,0 +1,63 @@
+//! Types for capturing and restoring the in-memory state of the engine.
+
+use tokio::sync::{mpsc::UnboundedSender, oneshot};
+
+/// Identifier of a captured engine state snapshot.
+pub type EngineStateSnapshotId = u64;
+
+/// A request to capture or restore the in-memory state of the engine.
+#[derive(Debug)]
+pub enum EngineStateSnapshotRequest {
+    /// Capture the current in-memory state and respond with the id of the snapshot.
+    Capture(oneshot::Sender<EngineStateSnapshotId>),
+    /// Restore the in-memory state that was captured in the snapshot with the given id.
+    Restore(EngineStateSnapshotId, oneshot::Sender<Result<(), EngineStateSnapshotError>>),
+}
+
+/// Errors that can occur when capturing or restoring an engine state snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum EngineStateSnapshotError {
+    /// No snapshot with the given id was captured.
+    #[error("unknown engine state snapshot {0}")]
+    UnknownSnapshot(EngineStateSnapshotId),
+    /// Blocks were written to disk since the snapshot was captured, so the in-memory state no
+    /// longer sits on top of the persisted state.
+    #[error("blocks were persisted since engine state snapshot {0} was captured")]
+    PersistedSinceCapture(EngineStateSnapshotId),
+    /// Blocks are currently being written to disk.
+    #[error("persistence is in progress")]
+    PersistenceInProgress,
+    /// The engine dropped the request.
+    #[error("engine is unavailable")]
+    EngineUnavailable,
+}
+
+/// A handle to capture and restore snapshots of the in-memory state of the engine.
+///
+/// Snapshots only cover the state that is kept in memory, restoring a snapshot is therefore
+/// rejected once blocks of that state were persisted.
+#[derive(Debug, Clone)]
+pub struct EngineStateSnapshotHandle {
+    to_engine: UnboundedSender<EngineStateSnapshotRequest>,
+}
+
+impl EngineStateSnapshotHandle {
+    /// Creates a new engine state snapshot handle.
+    pub const fn new(to_engine: UnboundedSender<EngineStateSnapshotRequest>) -> Self {
+        Self { to_engine }
+    }
+
+    /// Captures the current in-memory state of the engine and returns the id of the snapshot.
+    pub async fn capture(&self) -> Result<EngineStateSnapshotId, EngineStateSnapshotError> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.to_engine.send(EngineStateSnapshotRequest::Capture(tx));
+        rx.await.map_err(|_| EngineStateSnapshotError::EngineUnavailable)
+    }
+
+    /// Restores the in-memory state of the engine that was captured in the given snapshot.
+    pub async fn restore(&self, id: EngineStateSnapshotId) -> Result<(), EngineStateSnapshotError> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.to_engine.send(EngineStateSnapshotRequest::Restore(id, tx));
+        rx.await.map_err(|_| EngineStateSnapshotError::EngineUnavailable)?
+    }
+}
@@ -0,0 +1,59 @@
+//! Types for moving the fixed block the node syncs to.
+
+use alloy_eips::{BlockHashOrNumber, BlockNumHash};
+use tokio::sync::{mpsc::UnboundedSender, oneshot};
+
+/// A request to move the fixed block the node syncs to.
+#[derive(Debug)]
+pub struct SyncTargetRequest {
+    /// The new block to sync to.
+    pub target: BlockHashOrNumber,
+    /// The sender for returning the number and hash of the new sync target.
+    pub tx: oneshot::Sender<Result<BlockNumHash, SyncTargetError>>,
+}
+
+/// Errors that can occur when moving the sync target.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SyncTargetError {
+    /// The node was not launched with a fixed sync target.
+    #[error("node is not running with a fixed sync target")]
+    Disabled,
+    /// The block could neither be found locally nor fetched from the network.
+    #[error("unknown sync target block {0}")]
+    UnknownBlock(BlockHashOrNumber),
+    /// The block is below the finalized block, the node can't unwind to it.
+    #[error("sync target block {target} is below the finalized block {finalized}")]
+    BelowFinalized {
+        /// The number of the sync target block.
+        target: u64,
+        /// The number of the finalized block.
+        finalized: u64,
+    },
+    /// The node dropped the request.
+    #[error("engine is unavailable")]
+    EngineUnavailable,
+}
+
+/// A handle to move the fixed block the node syncs to, see `--debug.sync-target`.
+#[derive(Debug, Clone)]
+pub struct SyncTargetHandle {
+    to_engine: UnboundedSender<SyncTargetRequest>,
+}
+
+impl SyncTargetHandle {
+    /// Creates a new sync target handle.
+    pub const fn new(to_engine: UnboundedSender<SyncTargetRequest>) -> Self {
+        Self { to_engine }
+    }
+
+    /// Moves the sync target to the given block and returns its number and hash once syncing
+    /// towards it started.
+    pub async fn set_target(
+        &self,
+        target: BlockHashOrNumber,
+    ) -> Result<BlockNumHash, SyncTargetError> {
+        let (tx, rx) = oneshot::channel();
+        let _ = self.to_engine.send(SyncTargetRequest { target, tx });
+        rx.await.map_err(|_| SyncTargetError::EngineUnavailable)?
+    }
+}
@@ -8,7 +8,9 @@ use crate::{
 use alloy_primitives::B256;
 use futures::{Stream, StreamExt};
 use reth_chain_state::ExecutedBlockWithTrieUpdates;
-use reth_engine_primitives::{BeaconConsensusEngineEvent, BeaconEngineMessage, EngineTypes};
+use reth_engine_primitives::{
+    BeaconConsensusEngineEvent, BeaconEngineMessage, EngineStateSnapshotRequest, EngineTypes,
+};
 use reth_ethereum_primitives::EthPrimitives;
 use reth_primitives_traits::{Block, NodePrimitives, RecoveredBlock};
 use std::{
@@ -246,6 +248,8 @@ pub enum EngineApiRequest<T: EngineTypes, N: NodePrimitives> {
     Beacon(BeaconEngineMessage<T>),
     /// Request to insert an already executed block, e.g. via payload building.
     InsertExecutedBlock(ExecutedBlockWithTrieUpdates<N>),
+    /// Request to capture or restore a snapshot of the in-memory state.
+    StateSnapshot(EngineStateSnapshotRequest),
 }
 
 impl<T: EngineTypes, N: NodePrimitives> Display for EngineApiRequest<T, N> {
@@ -255,6 +259,12 @@ impl<T: EngineTypes, N: NodePrimitives> Display for EngineApiRequest<T, N> {
             Self::InsertExecutedBlock(block) => {
                 write!(f, "InsertExecutedBlock({:?})", block.recovered_block().num_hash())
             }
+            Self::StateSnapshot(EngineStateSnapshotRequest::Capture(_)) => {
+                write!(f, "StateSnapshot(Capture)")
+            }
+            Self::StateSnapshot(EngineStateSnapshotRequest::Restore(id, _)) => {
+                write!(f, "StateSnapshot(Restore({id}))")
+            }
         }
     }
 }
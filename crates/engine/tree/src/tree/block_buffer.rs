@@ -1,8 +1,9 @@
-use crate::tree::metrics::BlockBufferMetrics;
+use crate::tree::{metrics::BlockBufferMetrics, BlockBufferStore};
 use alloy_consensus::BlockHeader;
 use alloy_primitives::{BlockHash, BlockNumber};
 use reth_primitives_traits::{Block, RecoveredBlock};
 use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use tracing::warn;
 
 /// Contains the tree of pending blocks that cannot be executed due to missing parent.
 /// It allows to store unconnected blocks for potential future inclusion.
@@ -15,6 +16,10 @@ use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 ///
 /// Note: Buffer is limited by number of blocks that it can contain and eviction of the block
 /// is done by last recently used block.
+///
+/// If a [`BlockBufferStore`] is set, the buffered blocks are persisted to disk as well, so they
+/// survive a restart. A clone of the buffer doesn't share the store, only the original buffer
+/// writes to it.
 #[derive(Debug)]
 pub struct BlockBuffer<B: Block> {
     /// All blocks in the buffer stored by their block hash.
@@ -33,6 +38,22 @@ pub struct BlockBuffer<B: Block> {
     pub(crate) max_blocks: usize,
     /// Various metrics for the block buffer.
     pub(crate) metrics: BlockBufferMetrics,
+    /// Optional store the buffered blocks are persisted to.
+    pub(crate) store: Option<BlockBufferStore>,
+}
+
+impl<B: Block> Clone for BlockBuffer<B> {
+    fn clone(&self) -> Self {
+        Self {
+            blocks: self.blocks.clone(),
+            parent_to_child: self.parent_to_child.clone(),
+            earliest_blocks: self.earliest_blocks.clone(),
+            block_queue: self.block_queue.clone(),
+            max_blocks: self.max_blocks,
+            metrics: self.metrics.clone(),
+            store: None,
+        }
+    }
 }
 
 impl<B: Block> BlockBuffer<B> {
@@ -45,9 +66,42 @@ impl<B: Block> BlockBuffer<B> {
             block_queue: VecDeque::default(),
             max_blocks: limit as usize,
             metrics: Default::default(),
+            store: None,
         }
     }
 
+    /// Sets the store the buffered blocks are persisted to, and buffers the blocks that were
+    /// persisted to it before.
+    ///
+    /// If more blocks were persisted than the buffer can hold, the blocks with the lowest numbers
+    /// are evicted and removed from the store.
+    pub fn set_store(&mut self, mut store: BlockBufferStore) {
+        let blocks = store.load().unwrap_or_else(|err| {
+            warn!(target: "engine::tree::block_buffer", %err, "Failed to load buffered blocks");
+            Vec::new()
+        });
+        self.store = Some(store);
+        for block in blocks {
+            self.insert_block_in_memory(block);
+        }
+        self.metrics.blocks.set(self.blocks.len() as f64);
+    }
+
+    /// Replaces the buffered blocks with the blocks of the given buffer, keeping the store of this
+    /// buffer and updating it to the new blocks.
+    pub(crate) fn replace_blocks(&mut self, buffer: Self) {
+        let store = self.store.take();
+        *self = buffer;
+        if let Some(mut store) = store {
+            store.retain(|hash| self.blocks.contains_key(hash));
+            for block in self.blocks.values() {
+                store.insert(block);
+            }
+            self.store = Some(store);
+        }
+        self.metrics.blocks.set(self.blocks.len() as f64);
+    }
+
     /// Return reference to the requested block.
     pub fn block(&self, hash: &BlockHash) -> Option<&RecoveredBlock<B>> {
         self.blocks.get(hash)
@@ -64,6 +118,16 @@ impl<B: Block> BlockBuffer<B> {
 
     /// Insert a correct block inside the buffer.
     pub fn insert_block(&mut self, block: RecoveredBlock<B>) {
+        if let Some(store) = &mut self.store {
+            store.insert(&block);
+        }
+        self.insert_block_in_memory(block);
+        self.metrics.blocks.set(self.blocks.len() as f64);
+    }
+
+    /// Inserts the block into the inner collections, evicting the oldest block if the buffer is
+    /// full.
+    fn insert_block_in_memory(&mut self, block: RecoveredBlock<B>) {
         let hash = block.hash();
 
         self.parent_to_child.entry(block.parent_hash()).or_default().insert(hash);
@@ -80,7 +144,6 @@ impl<B: Block> BlockBuffer<B> {
             }
         }
         self.block_queue.push_back(hash);
-        self.metrics.blocks.set(self.blocks.len() as f64);
     }
 
     /// Removes the given block from the buffer and also all the children of the block.
@@ -156,6 +219,9 @@ impl<B: Block> BlockBuffer<B> {
         self.remove_from_earliest_blocks(block.number(), hash);
         self.remove_from_parent(block.parent_hash(), hash);
         self.block_queue.retain(|h| h != hash);
+        if let Some(store) = &mut self.store {
+            store.remove(hash);
+        }
         Some(block)
     }
 
@@ -495,4 +561,42 @@ mod tests {
 
         assert_buffer_lengths(&buffer, 3);
     }
+
+    #[test]
+    fn persisted_blocks_survive_restart() {
+        let mut rng = generators::rng();
+        let dir = tempfile::tempdir().unwrap();
+        let open_store = || {
+            BlockBufferStore::open(dir.path(), std::time::Duration::from_secs(60), u64::MAX)
+                .unwrap()
+        };
+
+        let main_parent = BlockNumHash::new(9, rng.gen());
+        let block1 = create_block(&mut rng, 10, main_parent.hash);
+        let block2 = create_block(&mut rng, 11, block1.hash());
+        let block3 = create_block(&mut rng, 12, block2.hash());
+
+        let mut buffer = BlockBuffer::new(3);
+        buffer.set_store(open_store());
+        buffer.insert_block(block1.clone());
+        buffer.insert_block(block2.clone());
+        buffer.insert_block(block3.clone());
+        buffer.remove_old_blocks(block1.number);
+        drop(buffer);
+
+        // block1 and its descendants were removed from the store as well
+        let mut buffer = BlockBuffer::<reth_ethereum_primitives::Block>::new(3);
+        buffer.set_store(open_store());
+        assert_buffer_lengths(&buffer, 0);
+
+        buffer.insert_block(block2.clone());
+        buffer.insert_block(block3.clone());
+        drop(buffer);
+
+        let mut buffer = BlockBuffer::new(3);
+        buffer.set_store(open_store());
+        assert_buffer_lengths(&buffer, 2);
+        assert_eq!(buffer.remove_block_with_children(&block1.hash()), vec![block2, block3]);
+        assert_eq!(buffer.store.as_ref().unwrap().size(), 0);
+    }
 }
@@ -0,0 +1,203 @@
+use alloy_consensus::BlockHeader;
+use alloy_primitives::{BlockHash, BlockNumber};
+use alloy_rlp::Decodable;
+use reth_primitives_traits::{Block, RecoveredBlock, SealedBlock};
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::mpsc::{channel, Sender},
+    thread::JoinHandle,
+    time::Duration,
+};
+use tracing::{debug, trace, warn};
+
+/// The extension of the files the buffered blocks are persisted to.
+const BLOCK_FILE_EXTENSION: &str = "rlp";
+
+/// Persists the blocks of the [`BlockBuffer`](super::BlockBuffer) to a directory, so that the
+/// blocks that were received while the node was syncing don't need to be sent again by the CL
+/// after a restart.
+///
+/// Every block is written RLP encoded to its own `<number>-<hash>.rlp` file. The total size of the
+/// persisted blocks is limited: blocks that don't fit anymore are not persisted. Blocks older than
+/// the maximum age are discarded when the store is loaded.
+///
+/// The files are written and removed by a dedicated thread, so the engine doesn't wait on the
+/// disk. Dropping the store waits for the pending writes to finish.
+#[derive(Debug)]
+pub struct BlockBufferStore {
+    /// The directory the blocks are persisted to.
+    dir: PathBuf,
+    /// Persisted blocks older than this are discarded on load.
+    max_age: Duration,
+    /// Maximum total size in bytes of the persisted blocks.
+    max_size: u64,
+    /// The size in bytes of the file of every persisted block.
+    files: HashMap<BlockHash, (BlockNumber, u64)>,
+    /// Total size in bytes of the persisted blocks.
+    size: u64,
+    /// Sends the file operations to the writer thread.
+    writer: Option<Sender<FileOp>>,
+    /// The handle of the writer thread.
+    writer_handle: Option<JoinHandle<()>>,
+}
+
+impl BlockBufferStore {
+    /// Opens the store in the given directory, creating the directory if it doesn't exist.
+    pub fn open(dir: impl AsRef<Path>, max_age: Duration, max_size: u64) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        let (writer, ops) = channel();
+        let writer_handle = std::thread::Builder::new()
+            .name("Block Buffer Store".to_string())
+            .spawn(move || {
+                for op in ops {
+                    match op {
+                        FileOp::Write(path, data) => {
+                            if let Err(err) = fs::write(&path, data) {
+                                warn!(target: "engine::tree::block_buffer", %err, ?path, "Failed to persist buffered block");
+                            }
+                        }
+                        FileOp::Remove(path) => remove_file(&path),
+                    }
+                }
+            })?;
+
+        Ok(Self {
+            dir,
+            max_age,
+            max_size,
+            files: HashMap::new(),
+            size: 0,
+            writer: Some(writer),
+            writer_handle: Some(writer_handle),
+        })
+    }
+
+    /// Returns the total size in bytes of the persisted blocks.
+    pub const fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// Loads the persisted blocks, ordered by block number.
+    ///
+    /// Files that are older than the maximum age or that can't be decoded are removed. If the
+    /// persisted blocks exceed the maximum size, the blocks with the lowest numbers are removed.
+    pub fn load<B: Block>(&mut self) -> io::Result<Vec<RecoveredBlock<B>>> {
+        let mut blocks = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != BLOCK_FILE_EXTENSION) {
+                continue
+            }
+
+            let age = fs::metadata(&path)?.modified()?.elapsed().unwrap_or_default();
+            if age > self.max_age {
+                trace!(target: "engine::tree::block_buffer", ?path, ?age, "Removing expired buffered block");
+                remove_file(&path);
+                continue
+            }
+
+            let data = fs::read(&path)?;
+            match SealedBlock::<B>::decode(&mut data.as_slice()).map(SealedBlock::try_recover) {
+                Ok(Ok(block)) => blocks.push((block, data.len() as u64, path)),
+                _ => {
+                    warn!(target: "engine::tree::block_buffer", ?path, "Removing invalid buffered block");
+                    remove_file(&path);
+                }
+            }
+        }
+
+        // keep the highest blocks that fit
+        blocks.sort_unstable_by_key(|(block, _, _)| std::cmp::Reverse(block.number()));
+        let mut loaded = Vec::with_capacity(blocks.len());
+        for (block, len, path) in blocks {
+            if self.size + len > self.max_size {
+                remove_file(&path);
+                continue
+            }
+            self.size += len;
+            self.files.insert(block.hash(), (block.number(), len));
+            loaded.push(block);
+        }
+        loaded.reverse();
+
+        debug!(target: "engine::tree::block_buffer", blocks = loaded.len(), size = self.size, "Loaded buffered blocks");
+        Ok(loaded)
+    }
+
+    /// Persists the block, unless it's already persisted or doesn't fit anymore.
+    pub fn insert<B: Block>(&mut self, block: &RecoveredBlock<B>) {
+        let hash = block.hash();
+        if self.files.contains_key(&hash) {
+            return
+        }
+
+        let data = alloy_rlp::encode(block.sealed_block());
+        let len = data.len() as u64;
+        if self.size + len > self.max_size {
+            debug!(target: "engine::tree::block_buffer", %hash, size = self.size, "Block buffer store is full, not persisting block");
+            return
+        }
+
+        self.send(FileOp::Write(self.block_path(block.number(), hash), data));
+        self.size += len;
+        self.files.insert(hash, (block.number(), len));
+    }
+
+    /// Removes the persisted block, if any.
+    pub fn remove(&mut self, hash: &BlockHash) {
+        if let Some((number, len)) = self.files.remove(hash) {
+            self.size -= len;
+            self.send(FileOp::Remove(self.block_path(number, *hash)));
+        }
+    }
+
+    /// Removes the persisted blocks for which the predicate returns `false`.
+    pub fn retain(&mut self, mut f: impl FnMut(&BlockHash) -> bool) {
+        let removed = self.files.keys().filter(|hash| !f(hash)).copied().collect::<Vec<_>>();
+        for hash in removed {
+            self.remove(&hash);
+        }
+    }
+
+    /// Sends the file operation to the writer thread.
+    fn send(&self, op: FileOp) {
+        if let Some(writer) = &self.writer {
+            let _ = writer.send(op);
+        }
+    }
+
+    /// Returns the path of the file of the block.
+    fn block_path(&self, number: BlockNumber, hash: BlockHash) -> PathBuf {
+        self.dir.join(format!("{number}-{hash}.{BLOCK_FILE_EXTENSION}"))
+    }
+}
+
+impl Drop for BlockBufferStore {
+    fn drop(&mut self) {
+        // closing the channel stops the writer thread once the pending operations are done
+        self.writer.take();
+        if let Some(handle) = self.writer_handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// A file operation of the writer thread of the [`BlockBufferStore`].
+#[derive(Debug)]
+enum FileOp {
+    /// Writes the encoded block to the file.
+    Write(PathBuf, Vec<u8>),
+    /// Removes the file.
+    Remove(PathBuf),
+}
+
+/// Removes the file, logging a failure.
+fn remove_file(path: &Path) {
+    if let Err(err) = fs::remove_file(path) {
+        warn!(target: "engine::tree::block_buffer", %err, ?path, "Failed to remove buffered block");
+    }
+}
@@ -146,6 +146,62 @@ impl<S: StateProvider> StateProvider for CachedStateProvider<S> {
         Ok(final_res)
     }
 
+    fn storage_multi(
+        &self,
+        account: Address,
+        storage_keys: &[StorageKey],
+    ) -> ProviderResult<Vec<Option<StorageValue>>> {
+        let cached = storage_keys
+            .iter()
+            .map(|key| self.caches.get_storage(&account, key))
+            .collect::<Vec<_>>();
+
+        let missing = storage_keys
+            .iter()
+            .zip(&cached)
+            .filter_map(|(key, value)| value.is_none().then_some(*key))
+            .collect::<Vec<_>>();
+        self.metrics.storage_cache_hits.increment((storage_keys.len() - missing.len()) as f64);
+        self.metrics.storage_cache_misses.increment(missing.len() as f64);
+
+        let fetched = self.state_provider.storage_multi(account, &missing)?;
+        for (key, value) in missing.iter().zip(&fetched) {
+            self.caches.insert_storage(account, *key, *value);
+        }
+
+        let mut fetched = fetched.into_iter();
+        Ok(cached
+            .into_iter()
+            .map(|value| value.unwrap_or_else(|| fetched.next().flatten()))
+            .collect())
+    }
+
+    fn basic_accounts(&self, addresses: &[Address]) -> ProviderResult<Vec<Option<Account>>> {
+        let cached = addresses
+            .iter()
+            .map(|address| self.caches.account_cache.get(address))
+            .collect::<Vec<_>>();
+
+        let missing = addresses
+            .iter()
+            .zip(&cached)
+            .filter_map(|(address, account)| account.is_none().then_some(*address))
+            .collect::<Vec<_>>();
+        self.metrics.account_cache_hits.increment((addresses.len() - missing.len()) as f64);
+        self.metrics.account_cache_misses.increment(missing.len() as f64);
+
+        let fetched = self.state_provider.basic_accounts(&missing)?;
+        for (address, account) in missing.iter().zip(&fetched) {
+            self.caches.account_cache.insert(*address, *account);
+        }
+
+        let mut fetched = fetched.into_iter();
+        Ok(cached
+            .into_iter()
+            .map(|account| account.unwrap_or_else(|| fetched.next().flatten()))
+            .collect())
+    }
+
     fn bytecode_by_hash(&self, code_hash: &B256) -> ProviderResult<Option<Bytecode>> {
         if let Some(res) = self.caches.code_cache.get(code_hash) {
             self.metrics.code_cache_hits.increment(1);
@@ -307,6 +363,40 @@ impl ProviderCaches {
         self.storage_cache.iter().map(|addr| addr.len()).sum()
     }
 
+    /// Loads the accounts, their bytecode and the given storage slots from the state provider into
+    /// the caches.
+    ///
+    /// Returns the number of cached accounts and storage slots.
+    pub(crate) fn warm<S, I>(
+        &self,
+        state_provider: &S,
+        accounts: impl IntoIterator<Item = (Address, I)>,
+    ) -> ProviderResult<(usize, usize)>
+    where
+        S: StateProvider + ?Sized,
+        I: IntoIterator<Item = StorageKey>,
+    {
+        let (mut num_accounts, mut num_slots) = (0, 0);
+        for (address, slots) in accounts {
+            let account = state_provider.basic_account(&address)?;
+            if let Some(code_hash) = account.and_then(|account| account.bytecode_hash) {
+                if self.code_cache.get(&code_hash).is_none() {
+                    let code = state_provider.bytecode_by_hash(&code_hash)?;
+                    self.code_cache.insert(code_hash, code);
+                }
+            }
+            self.account_cache.insert(address, account);
+            num_accounts += 1;
+
+            for slot in slots {
+                let value = state_provider.storage(address, slot)?;
+                self.insert_storage(address, slot, value);
+                num_slots += 1;
+            }
+        }
+        Ok((num_accounts, num_slots))
+    }
+
     /// Inserts the [`BundleState`] entries into the cache.
     ///
     /// Returns an error if state can't be cached and the should be discarded.
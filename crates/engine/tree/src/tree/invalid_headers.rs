@@ -67,6 +67,25 @@ impl InvalidHeaderCache {
         }
     }
 
+    /// Returns the cached invalid headers with their invalid ancestors, from the least to the most
+    /// recently used.
+    pub(crate) fn entries(&self) -> Vec<(B256, BlockWithParent)> {
+        let mut entries: Vec<_> =
+            self.headers.iter().map(|(hash, entry)| (*hash, entry.header)).collect();
+        entries.reverse();
+        entries
+    }
+
+    /// Replaces the cached invalid headers with the given ones, ordered from the least to the most
+    /// recently used.
+    pub(crate) fn replace_entries(&mut self, entries: Vec<(B256, BlockWithParent)>) {
+        self.headers.clear();
+        for (hash, header) in entries {
+            self.insert_entry(hash, header);
+        }
+        self.metrics.count.set(self.headers.len() as f64);
+    }
+
     /// Inserts an invalid ancestor into the map.
     pub fn insert(&mut self, invalid_ancestor: BlockWithParent) {
         if self.get(&invalid_ancestor.block.hash).is_none() {
@@ -54,6 +54,10 @@ pub(crate) struct EngineMetrics {
     pub(crate) failed_new_payload_response_deliveries: Counter,
     /// Tracks the how often we failed to deliver a forkchoice update response.
     pub(crate) failed_forkchoice_updated_response_deliveries: Counter,
+    /// Histogram of how long forkchoice updates waited for canonicalization checks (in seconds)
+    pub(crate) canonicalization_wait_duration: Histogram,
+    /// The number of forkchoice updates whose canonicalization checks exceeded the budget.
+    pub(crate) canonicalization_budget_exceeded: Counter,
     // TODO add latency metrics
 }
 
@@ -82,7 +86,7 @@ impl BlockValidationMetrics {
 }
 
 /// Metrics for the blockchain tree block buffer
-#[derive(Metrics)]
+#[derive(Metrics, Clone)]
 #[metrics(scope = "blockchain_tree.block_buffer")]
 pub(crate) struct BlockBufferMetrics {
     /// Total blocks in the block buffer
@@ -4,7 +4,10 @@ use crate::{
     engine::{DownloadRequest, EngineApiEvent, EngineApiKind, EngineApiRequest, FromEngine},
     persistence::PersistenceHandle,
     tree::{
-        cached_state::CachedStateProvider, executor::WorkloadExecutor, metrics::EngineApiMetrics,
+        cached_state::CachedStateProvider,
+        executor::WorkloadExecutor,
+        metrics::EngineApiMetrics,
+        state_access::{StateAccessRecorder, StateAccessSketch},
     },
 };
 use alloy_consensus::BlockHeader;
@@ -26,12 +29,13 @@ use reth_chain_state::{
 use reth_consensus::{Consensus, FullConsensus};
 pub use reth_engine_primitives::InvalidBlockHook;
 use reth_engine_primitives::{
-    BeaconConsensusEngineEvent, BeaconEngineMessage, BeaconOnNewPayloadError, EngineTypes,
+    BeaconConsensusEngineEvent, BeaconEngineMessage, BeaconOnNewPayloadError, CanonicalizationMode,
+    EngineStateSnapshotError, EngineStateSnapshotId, EngineStateSnapshotRequest, EngineTypes,
     EngineValidator, ExecutionPayload, ForkchoiceStateTracker, OnForkChoiceUpdated,
 };
-use reth_errors::{ConsensusError, ProviderResult};
+use reth_errors::{ConsensusError, ProviderResult, RethResult};
 use reth_ethereum_primitives::EthPrimitives;
-use reth_evm::{execute::BlockExecutorProvider, ConfigureEvm};
+use reth_evm::{execute::BlockExecutorProvider, ConfigureEvm, OnStateHook};
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_payload_primitives::{EngineApiMessageVersion, PayloadBuilderAttributes};
 use reth_primitives_traits::{
@@ -56,7 +60,7 @@ use std::{
         mpsc::{Receiver, RecvError, RecvTimeoutError, Sender},
         Arc,
     },
-    time::Instant,
+    time::{Duration, Instant},
 };
 use tokio::sync::{
     mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
@@ -65,6 +69,7 @@ use tokio::sync::{
 use tracing::*;
 
 mod block_buffer;
+mod block_buffer_store;
 mod cached_state;
 pub mod error;
 mod invalid_block_hook;
@@ -72,17 +77,21 @@ mod invalid_headers;
 mod metrics;
 mod payload_processor;
 mod persistence_state;
+mod snapshot;
+mod state_access;
 // TODO(alexey): compare trie updates in `insert_block_inner`
 #[allow(unused)]
 mod trie_updates;
 
 use crate::tree::error::AdvancePersistenceError;
 pub use block_buffer::BlockBuffer;
+pub use block_buffer_store::BlockBufferStore;
 pub use invalid_block_hook::{InvalidBlockHooks, NoopInvalidBlockHook};
 pub use invalid_headers::InvalidHeaderCache;
 pub use payload_processor::*;
 pub use persistence_state::PersistenceState;
 pub use reth_engine_primitives::TreeConfig;
+use snapshot::{EngineStateSnapshot, EngineStateSnapshots};
 
 /// The largest gap for which the tree will be used for sync. See docs for `pipeline_run_threshold`
 /// for more information.
@@ -97,7 +106,7 @@ pub(crate) const MIN_BLOCKS_FOR_PIPELINE_RUN: u64 = EPOCH_SLOTS;
 ///
 /// - This only stores blocks that are connected to the canonical chain.
 /// - All executed blocks are valid and have been executed.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct TreeState<N: NodePrimitives = EthPrimitives> {
     /// __All__ unique executed blocks by block hash that are connected to the canonical chain.
     ///
@@ -555,6 +564,24 @@ pub enum TreeAction {
     },
 }
 
+/// A forkchoice update whose response is deferred until the persistence task in progress has
+/// completed, see [`CanonicalizationMode`].
+#[derive(Debug)]
+struct PendingForkchoiceUpdate<T: EngineTypes> {
+    /// The forkchoice state of the update.
+    state: ForkchoiceState,
+    /// The payload attributes of the update, if any.
+    payload_attrs: Option<T::PayloadAttributes>,
+    /// The engine API version of the update.
+    version: EngineApiMessageVersion,
+    /// The sender of the response.
+    tx: oneshot::Sender<RethResult<OnForkChoiceUpdated>>,
+    /// When the update was received.
+    received_at: Instant,
+    /// When the canonicalization budget of the update is exhausted.
+    deadline: Instant,
+}
+
 /// The engine API tree handler implementation.
 ///
 /// This type is responsible for processing engine API requests, maintaining the canonical state and
@@ -606,6 +633,14 @@ where
     engine_kind: EngineApiKind,
     /// The type responsible for processing new payloads
     payload_processor: PayloadProcessor<N, C>,
+    /// Snapshots of the in-memory state that were captured on request.
+    state_snapshots: EngineStateSnapshots<N>,
+    /// Records the accessed accounts and storage slots, if the execution cache is warmed on
+    /// startup.
+    state_access: Option<StateAccessRecorder>,
+    /// A forkchoice update that waits for the persistence task in progress before its head block
+    /// is made canonical, see [`CanonicalizationMode`].
+    pending_forkchoice_update: Option<PendingForkchoiceUpdate<T>>,
 }
 
 impl<N, P: Debug, E: Debug, T: EngineTypes + Debug, V: Debug, C: Debug> std::fmt::Debug
@@ -631,6 +666,7 @@ where
             .field("metrics", &self.metrics)
             .field("invalid_block_hook", &format!("{:p}", self.invalid_block_hook))
             .field("engine_kind", &self.engine_kind)
+            .field("pending_forkchoice_update", &self.pending_forkchoice_update)
             .finish()
     }
 }
@@ -695,9 +731,42 @@ where
             invalid_block_hook: Box::new(NoopInvalidBlockHook),
             engine_kind,
             payload_processor,
+            state_snapshots: Default::default(),
+            state_access: None,
+            pending_forkchoice_update: None,
         }
     }
 
+    /// Loads the persisted access frequencies of accounts and storage slots, spawns the warmup of
+    /// the execution cache for the head block with the most frequently accessed state, and starts
+    /// recording the state accesses of executed blocks.
+    ///
+    /// Reading the state from the database also brings its pages into the OS page cache.
+    fn init_state_access(&mut self, head: B256) {
+        let Some(path) = self.config.state_access_file() else { return };
+        let path = path.to_path_buf();
+        let max_accounts = self.config.state_cache_warmup_accounts();
+
+        let sketch = StateAccessSketch::load(&path, max_accounts).unwrap_or_else(|err| {
+            warn!(target: "engine::tree", %err, ?path, "Failed to load state access frequencies");
+            StateAccessSketch::new(max_accounts)
+        });
+
+        let hottest = sketch
+            .hottest()
+            .into_iter()
+            .map(|(address, accesses)| {
+                let slots = StateAccessSketch::hottest_slots(&accesses);
+                (address, slots.into_iter().map(|(slot, _)| slot).collect())
+            })
+            .collect::<Vec<_>>();
+        if !hottest.is_empty() {
+            self.payload_processor.spawn_cache_warmup(head, self.provider.clone(), hottest);
+        }
+
+        self.state_access = Some(StateAccessRecorder::new(sketch, path));
+    }
+
     /// Sets the invalid block hook.
     fn set_invalid_block_hook(&mut self, invalid_block_hook: Box<dyn InvalidBlockHook<N>>) {
         self.invalid_block_hook = invalid_block_hook;
@@ -733,11 +802,27 @@ where
         };
 
         let (tx, outgoing) = unbounded_channel();
-        let state = EngineApiTreeState::new(
+        let mut state = EngineApiTreeState::new(
             config.block_buffer_limit(),
             config.max_invalid_header_cache_length(),
             header.num_hash(),
         );
+        if let Some(dir) = config.block_buffer_dir() {
+            match BlockBufferStore::open(
+                dir,
+                config.block_buffer_max_age(),
+                config.block_buffer_max_size(),
+            ) {
+                Ok(store) => {
+                    state.buffer.set_store(store);
+                    // blocks that were persisted in the meantime are not needed anymore
+                    state.buffer.remove_old_blocks(best_block_number);
+                }
+                Err(err) => {
+                    warn!(target: "engine::tree", %err, ?dir, "Failed to open block buffer store")
+                }
+            }
+        }
 
         let mut task = Self::new(
             provider,
@@ -755,6 +840,7 @@ where
             evm_config,
         );
         task.set_invalid_block_hook(invalid_block_hook);
+        task.init_state_access(header.hash());
         let incoming = task.incoming_tx.clone();
         std::thread::Builder::new().name("Tree Task".to_string()).spawn(|| task.run()).unwrap();
         (incoming, outgoing)
@@ -787,6 +873,16 @@ where
                 }
             }
 
+            // a pending forkchoice update is handled before the next persistence task is started
+            if let Err(err) = self.try_finish_persistence() {
+                error!(target: "engine::tree", %err, "Advancing persistence failed");
+                return
+            }
+            if let Err(fatal) = self.on_pending_forkchoice_update(false) {
+                error!(target: "engine::tree", %fatal, "insert block fatal error");
+                return
+            }
+
             if let Err(err) = self.advance_persistence() {
                 error!(target: "engine::tree", %err, "Advancing persistence failed");
                 return
@@ -841,7 +937,7 @@ where
     ///
     /// This returns a [`PayloadStatus`] that represents the outcome of a processed new payload and
     /// returns an error if an internal error occurred.
-    #[instrument(level = "trace", skip_all, fields(block_hash = %payload.block_hash(), block_num = %payload.block_number(),), target = "engine::tree")]
+    #[instrument(level = "debug", skip_all, fields(block_hash = %payload.block_hash(), block_num = %payload.block_number(),), target = "engine::tree")]
     fn on_new_payload(
         &mut self,
         payload: T::ExecutionData,
@@ -1113,7 +1209,7 @@ where
     /// `engine_forkchoiceUpdated`](https://github.com/ethereum/execution-apis/blob/main/src/engine/paris.md#specification-1).
     ///
     /// Returns an error if an internal error occurred like a database error.
-    #[instrument(level = "trace", skip_all, fields(head = % state.head_block_hash, safe = % state.safe_block_hash,finalized = % state.finalized_block_hash), target = "engine::tree")]
+    #[instrument(level = "debug", skip_all, fields(head = % state.head_block_hash, safe = % state.safe_block_hash,finalized = % state.finalized_block_hash), target = "engine::tree")]
     fn on_forkchoice_updated(
         &mut self,
         state: ForkchoiceState,
@@ -1177,6 +1273,15 @@ where
         }
 
         // 2. ensure we can apply a new chain update for the head block
+        if self.config.canonicalization_mode() == CanonicalizationMode::Correctness &&
+            self.waits_for_persistence(state.head_block_hash)
+        {
+            debug!(target: "engine::tree", head = %state.head_block_hash, "persistence did not complete within canonicalization budget");
+            return Ok(TreeOutcome::new(OnForkChoiceUpdated::valid(PayloadStatus::from_status(
+                PayloadStatusEnum::Syncing,
+            ))))
+        }
+
         if let Some(chain_update) = self.on_new_head(state.head_block_hash)? {
             let tip = chain_update.tip().clone_sealed_header();
             self.on_canonical_chain_update(chain_update);
@@ -1261,8 +1366,13 @@ where
         &self,
     ) -> Result<Option<FromEngine<EngineApiRequest<T, N>, N::Block>>, RecvError> {
         if self.persistence_state.in_progress() {
-            // try to receive the next request with a timeout to not block indefinitely
-            match self.incoming.recv_timeout(std::time::Duration::from_millis(500)) {
+            // try to receive the next request with a timeout to not block indefinitely, or past the
+            // deadline of a pending forkchoice update
+            let mut timeout = Duration::from_millis(500);
+            if let Some(pending) = &self.pending_forkchoice_update {
+                timeout = timeout.min(pending.deadline.saturating_duration_since(Instant::now()));
+            }
+            match self.incoming.recv_timeout(timeout) {
                 Ok(msg) => Ok(Some(msg)),
                 Err(err) => match err {
                     RecvTimeoutError::Timeout => Ok(None),
@@ -1313,6 +1423,23 @@ where
     /// If we're currently awaiting a response this will try to receive the response (non-blocking)
     /// or send a new persistence action if necessary.
     fn advance_persistence(&mut self) -> Result<(), AdvancePersistenceError> {
+        self.try_finish_persistence()?;
+
+        if !self.persistence_state.in_progress() {
+            if let Some(new_tip_num) = self.persistence_state.remove_above_state.pop_front() {
+                self.remove_blocks(new_tip_num)
+            } else if self.should_persist() {
+                let blocks_to_persist = self.get_canonical_blocks_to_persist();
+                self.persist_blocks(blocks_to_persist);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the persistence task in progress has completed and updates the persistence state
+    /// if it did.
+    fn try_finish_persistence(&mut self) -> Result<(), AdvancePersistenceError> {
         if self.persistence_state.in_progress() {
             let (mut rx, start_time, current_action) = self
                 .persistence_state
@@ -1346,15 +1473,74 @@ where
             }
         }
 
-        if !self.persistence_state.in_progress() {
-            if let Some(new_tip_num) = self.persistence_state.remove_above_state.pop_front() {
-                self.remove_blocks(new_tip_num)
-            } else if self.should_persist() {
-                let blocks_to_persist = self.get_canonical_blocks_to_persist();
-                self.persist_blocks(blocks_to_persist);
+        Ok(())
+    }
+
+    /// Returns true if making the given head block canonical waits for the persistence task in
+    /// progress, according to the configured [`CanonicalizationMode`].
+    ///
+    /// Only new head blocks wait: heads that are unknown or already canonical are handled right
+    /// away.
+    fn waits_for_persistence(&self, head: B256) -> bool {
+        self.config.canonicalization_mode().waits_for_persistence() &&
+            self.persistence_state.in_progress() &&
+            self.state.tree_state.blocks_by_hash.contains_key(&head) &&
+            !self.state.tree_state.is_canonical(head)
+    }
+
+    /// Handles the pending forkchoice update, once the persistence task it waits for has completed
+    /// or the canonicalization budget is exhausted.
+    ///
+    /// If `force` is set, the pending forkchoice update is handled right away.
+    fn on_pending_forkchoice_update(&mut self, force: bool) -> Result<(), InsertBlockFatalError> {
+        let Some(pending) = &self.pending_forkchoice_update else { return Ok(()) };
+        let budget_exceeded = Instant::now() >= pending.deadline;
+        if !force && !budget_exceeded && self.persistence_state.in_progress() {
+            return Ok(())
+        }
+
+        let PendingForkchoiceUpdate { state, payload_attrs, version, tx, received_at, .. } =
+            self.pending_forkchoice_update.take().expect("is some");
+        self.metrics.engine.canonicalization_wait_duration.record(received_at.elapsed());
+        if budget_exceeded && self.persistence_state.in_progress() {
+            self.metrics.engine.canonicalization_budget_exceeded.increment(1);
+            if self.config.canonicalization_mode() == CanonicalizationMode::Latency {
+                warn!(target: "engine::tree", budget = ?self.config.canonicalization_budget(), "Persistence did not complete within canonicalization budget, making head canonical");
             }
         }
 
+        self.on_forkchoice_updated_message(state, payload_attrs, version, tx)
+    }
+
+    /// Handles a forkchoice update and sends the outcome to the given sender.
+    fn on_forkchoice_updated_message(
+        &mut self,
+        state: ForkchoiceState,
+        payload_attrs: Option<T::PayloadAttributes>,
+        version: EngineApiMessageVersion,
+        tx: oneshot::Sender<RethResult<OnForkChoiceUpdated>>,
+    ) -> Result<(), InsertBlockFatalError> {
+        let mut output = self.on_forkchoice_updated(state, payload_attrs, version);
+
+        if let Ok(res) = &mut output {
+            // track last received forkchoice state
+            self.state.forkchoice_state_tracker.set_latest(state, res.outcome.forkchoice_status());
+
+            // emit an event about the handled FCU
+            self.emit_event(BeaconConsensusEngineEvent::ForkchoiceUpdated(
+                state,
+                res.outcome.forkchoice_status(),
+            ));
+
+            // handle the event if any
+            self.on_maybe_tree_event(res.event.take())?;
+        }
+
+        if let Err(err) = tx.send(output.map(|o| o.outcome).map_err(Into::into)) {
+            self.metrics.engine.failed_forkchoice_updated_response_deliveries.increment(1);
+            error!(target: "engine::tree", "Failed to send event: {err:?}");
+        }
+
         Ok(())
     }
 
@@ -1395,6 +1581,9 @@ where
                             BeaconConsensusEngineEvent::CanonicalBlockAdded(block, now.elapsed()),
                         ));
                     }
+                    EngineApiRequest::StateSnapshot(request) => {
+                        self.on_state_snapshot_request(request);
+                    }
                     EngineApiRequest::Beacon(request) => {
                         match request {
                             BeaconEngineMessage::ForkchoiceUpdated {
@@ -1403,33 +1592,28 @@ where
                                 tx,
                                 version,
                             } => {
-                                let mut output =
-                                    self.on_forkchoice_updated(state, payload_attrs, version);
-
-                                if let Ok(res) = &mut output {
-                                    // track last received forkchoice state
-                                    self.state
-                                        .forkchoice_state_tracker
-                                        .set_latest(state, res.outcome.forkchoice_status());
-
-                                    // emit an event about the handled FCU
-                                    self.emit_event(BeaconConsensusEngineEvent::ForkchoiceUpdated(
+                                // a newer forkchoice update supersedes the pending one
+                                self.on_pending_forkchoice_update(true)?;
+
+                                if self.waits_for_persistence(state.head_block_hash) {
+                                    let received_at = Instant::now();
+                                    self.pending_forkchoice_update =
+                                        Some(PendingForkchoiceUpdate {
+                                            state,
+                                            payload_attrs,
+                                            version,
+                                            tx,
+                                            received_at,
+                                            deadline: received_at +
+                                                self.config.canonicalization_budget(),
+                                        });
+                                } else {
+                                    self.on_forkchoice_updated_message(
                                         state,
-                                        res.outcome.forkchoice_status(),
-                                    ));
-
-                                    // handle the event if any
-                                    self.on_maybe_tree_event(res.event.take())?;
-                                }
-
-                                if let Err(err) =
-                                    tx.send(output.map(|o| o.outcome).map_err(Into::into))
-                                {
-                                    self.metrics
-                                        .engine
-                                        .failed_forkchoice_updated_response_deliveries
-                                        .increment(1);
-                                    error!(target: "engine::tree", "Failed to send event: {err:?}");
+                                        payload_attrs,
+                                        version,
+                                        tx,
+                                    )?;
                                 }
                             }
                             BeaconEngineMessage::NewPayload { payload, tx } => {
@@ -1473,6 +1657,128 @@ where
         Ok(())
     }
 
+    /// Handles a request to capture or restore a snapshot of the in-memory state.
+    fn on_state_snapshot_request(&mut self, request: EngineStateSnapshotRequest) {
+        match request {
+            EngineStateSnapshotRequest::Capture(tx) => {
+                let id = self.capture_state_snapshot();
+                debug!(target: "engine::tree", id, "captured engine state snapshot");
+                let _ = tx.send(id);
+            }
+            EngineStateSnapshotRequest::Restore(id, tx) => {
+                let res = self.restore_state_snapshot(id);
+                debug!(target: "engine::tree", id, ?res, "restored engine state snapshot");
+                let _ = tx.send(res);
+            }
+        }
+    }
+
+    /// Captures the current in-memory state, including the executed and buffered blocks, and
+    /// returns the id of the snapshot.
+    fn capture_state_snapshot(&mut self) -> EngineStateSnapshotId {
+        let snapshot = EngineStateSnapshot {
+            tree_state: self.state.tree_state.clone(),
+            buffer: self.state.buffer.clone(),
+            invalid_headers: self.state.invalid_headers.entries(),
+            forkchoice_state_tracker: self.state.forkchoice_state_tracker.clone(),
+            canonical_head: self.canonical_in_memory_state.get_canonical_head(),
+            safe: self.canonical_in_memory_state.get_safe_header(),
+            finalized: self.canonical_in_memory_state.get_finalized_header(),
+            last_persisted_block: self.persistence_state.last_persisted_block,
+        };
+        self.state_snapshots.insert(snapshot)
+    }
+
+    /// Restores the in-memory state that was captured in the snapshot with the given id.
+    ///
+    /// This is rejected if blocks were persisted or removed from disk since the snapshot was
+    /// captured, because the restored blocks would no longer connect to the persisted state.
+    ///
+    /// A snapshot can only be restored once. If the restored canonical chain has new blocks, the
+    /// switch to it is notified as a reorg of the in-memory canonical chain.
+    fn restore_state_snapshot(
+        &mut self,
+        id: EngineStateSnapshotId,
+    ) -> Result<(), EngineStateSnapshotError> {
+        if self.persistence_state.in_progress() {
+            return Err(EngineStateSnapshotError::PersistenceInProgress)
+        }
+        let snapshot =
+            self.state_snapshots.get(id).ok_or(EngineStateSnapshotError::UnknownSnapshot(id))?;
+        if snapshot.last_persisted_block != self.persistence_state.last_persisted_block {
+            return Err(EngineStateSnapshotError::PersistedSinceCapture(id))
+        }
+
+        let EngineStateSnapshot {
+            tree_state,
+            buffer,
+            invalid_headers,
+            forkchoice_state_tracker,
+            canonical_head,
+            safe,
+            finalized,
+            ..
+        } = self.state_snapshots.remove(id).expect("snapshot exists");
+        let mut old = self
+            .state
+            .tree_state
+            .blocks_by_hash(self.state.tree_state.canonical_block_hash())
+            .map(|(_, blocks)| blocks)
+            .unwrap_or_default();
+        self.state.tree_state = tree_state;
+        self.state.buffer.replace_blocks(buffer);
+        self.state.invalid_headers.replace_entries(invalid_headers);
+        self.state.forkchoice_state_tracker = forkchoice_state_tracker;
+
+        // rebuild the canonical in-memory state from the restored canonical blocks
+        self.canonical_in_memory_state.clear_state();
+        let mut new = self
+            .state
+            .tree_state
+            .blocks_by_hash(canonical_head.hash())
+            .map(|(_, blocks)| blocks)
+            .unwrap_or_default();
+        new.reverse();
+        if !new.is_empty() {
+            self.canonical_in_memory_state
+                .update_chain(NewCanonicalChain::Commit { new: new.clone() });
+        }
+        self.metrics.tree.canonical_chain_height.set(canonical_head.number() as f64);
+        self.metrics.engine.executed_blocks.set(self.state.tree_state.block_count() as f64);
+        self.canonical_in_memory_state.set_canonical_head(canonical_head);
+        if let Some(safe) = safe {
+            self.canonical_in_memory_state.set_safe(safe);
+        }
+        if let Some(finalized) = finalized {
+            self.canonical_in_memory_state.set_finalized(finalized);
+        }
+
+        // notify the blocks that left and joined the canonical chain, a rollback to an ancestor
+        // of the previous head has no new blocks and is not notified
+        let shared = old
+            .iter()
+            .map(|block| block.recovered_block().hash())
+            .filter(|hash| new.iter().any(|block| block.recovered_block().hash() == *hash))
+            .collect::<HashSet<_>>();
+        new.retain(|block| !shared.contains(&block.recovered_block().hash()));
+        if !new.is_empty() {
+            old.retain(|block| !shared.contains(&block.recovered_block().hash()));
+            old.reverse();
+            let chain_update = NewCanonicalChain::Reorg {
+                new,
+                old: old.into_iter().map(|block| block.block).collect(),
+            };
+            if self.canonical_in_memory_state.has_trie_updates_subscribers() {
+                self.canonical_in_memory_state.notify_canon_state_with_trie_updates(
+                    chain_update.to_chain_notification_with_trie_updates(),
+                );
+            }
+            self.canonical_in_memory_state.notify_canon_state(chain_update.to_chain_notification());
+        }
+
+        Ok(())
+    }
+
     /// Invoked if the backfill sync has finished to target.
     ///
     /// At this point we consider the block synced to the backfill target.
@@ -1723,6 +2029,7 @@ where
             number: self.persistence_state.last_persisted_block.number,
             hash: self.persistence_state.last_persisted_block.hash,
         });
+        self.state_snapshots.retain_persisted(self.persistence_state.last_persisted_block);
         Ok(())
     }
 
@@ -2137,8 +2444,17 @@ where
         // update the tracked canonical head
         self.state.tree_state.set_canonical_head(chain_update.tip().num_hash());
 
+        if let Some(state_access) = &mut self.state_access {
+            state_access.maybe_save();
+        }
+
         let tip = chain_update.tip().clone_sealed_header();
         let notification = chain_update.to_chain_notification();
+        // the trie updates are only attached for subscribers that requested them
+        let notification_with_trie_updates = self
+            .canonical_in_memory_state
+            .has_trie_updates_subscribers()
+            .then(|| chain_update.to_chain_notification_with_trie_updates());
 
         // reinsert any missing reorged blocks
         if let NewCanonicalChain::Reorg { new, old } = &chain_update {
@@ -2174,6 +2490,9 @@ where
 
         // sends an event to all active listeners about the new canonical chain
         self.canonical_in_memory_state.notify_canon_state(notification);
+        if let Some(notification) = notification_with_trie_updates {
+            self.canonical_in_memory_state.notify_canon_state_with_trie_updates(notification);
+        }
 
         // emit event
         self.emit_event(BeaconConsensusEngineEvent::CanonicalChainCommitted(
@@ -2250,7 +2569,7 @@ where
     /// Returns an event with the appropriate action to take, such as:
     ///  - download more missing blocks
     ///  - try to canonicalize the target if the `block` is the tracked target (head) block.
-    #[instrument(level = "trace", skip_all, fields(block_hash = %block.hash(), block_num = %block.number(),), target = "engine::tree")]
+    #[instrument(level = "debug", skip_all, fields(block_hash = %block.hash(), block_num = %block.number(),), target = "engine::tree")]
     fn on_downloaded_block(
         &mut self,
         block: RecoveredBlock<N::Block>,
@@ -2418,11 +2737,13 @@ where
 
         let executor = self.executor_provider.executor(StateProviderDatabase::new(&state_provider));
         let execution_start = Instant::now();
-        let output = self.metrics.executor.execute_metered(
-            executor,
-            &block,
-            Box::new(handle.state_hook()),
-        )?;
+        let output = debug_span!(target: "engine::tree", "execute_block").in_scope(|| {
+            let state_hook: Box<dyn OnStateHook> = match &self.state_access {
+                Some(state_access) => Box::new(state_access.hook(handle.state_hook())),
+                None => Box::new(handle.state_hook()),
+            };
+            self.metrics.executor.execute_metered(executor, &block, state_hook)
+        })?;
         let execution_finish = Instant::now();
         let execution_time = execution_finish.duration_since(execution_start);
         trace!(target: "engine::tree", elapsed = ?execution_time, number=?block_num_hash.number, "Executed block");
@@ -3033,6 +3354,7 @@ mod tests {
     use super::*;
     use crate::persistence::PersistenceAction;
     use alloy_consensus::Header;
+    use alloy_eips::eip1898::BlockWithParent;
     use alloy_primitives::Bytes;
     use alloy_rlp::Decodable;
     use alloy_rpc_types_engine::{
@@ -3040,7 +3362,7 @@ mod tests {
         ExecutionPayloadV3,
     };
     use assert_matches::assert_matches;
-    use reth_chain_state::{test_utils::TestBlockBuilder, BlockState};
+    use reth_chain_state::{test_utils::TestBlockBuilder, BlockState, CanonStateNotification};
     use reth_chainspec::{ChainSpec, HOLESKY, MAINNET};
     use reth_engine_primitives::ForkchoiceStatus;
     use reth_ethereum_consensus::EthBeaconConsensus;
@@ -4248,6 +4570,159 @@ mod tests {
         test_harness.check_canon_head(fork_chain_last_hash);
     }
 
+    #[tokio::test]
+    async fn test_engine_tree_fcu_waits_for_persistence() {
+        let chain_spec = MAINNET.clone();
+        let mut test_harness = TestHarness::new(chain_spec.clone());
+        test_harness.tree.config = TreeConfig::default()
+            .with_legacy_state_root(true)
+            .with_has_enough_parallelism(true)
+            .with_canonicalization_mode(CanonicalizationMode::Correctness);
+
+        let main_chain: Vec<_> = test_harness.block_builder.get_executed_blocks(0..5).collect();
+        test_harness = test_harness.with_blocks(main_chain.clone());
+        let main_chain_last_hash = main_chain.last().unwrap().recovered_block().hash();
+
+        let fork_chain = test_harness.block_builder.create_fork(main_chain[2].recovered_block(), 3);
+        let fork_chain_last_hash = fork_chain.last().unwrap().hash();
+        for block in &fork_chain {
+            test_harness.insert_block(block.clone()).unwrap();
+        }
+
+        let (persistence_tx, persistence_rx) = oneshot::channel();
+        test_harness
+            .tree
+            .persistence_state
+            .start_save(main_chain[0].recovered_block().num_hash(), persistence_rx);
+
+        let send_fcu = |test_harness: &mut TestHarness, head| {
+            let (tx, rx) = oneshot::channel();
+            test_harness
+                .tree
+                .on_engine_message(FromEngine::Request(
+                    BeaconEngineMessage::ForkchoiceUpdated {
+                        state: test_harness.fcu_state(head),
+                        payload_attrs: None,
+                        tx,
+                        version: EngineApiMessageVersion::default(),
+                    }
+                    .into(),
+                ))
+                .unwrap();
+            rx
+        };
+
+        // the response is deferred while persistence is in progress
+        let mut rx = send_fcu(&mut test_harness, fork_chain_last_hash);
+        test_harness.tree.on_pending_forkchoice_update(false).unwrap();
+        assert_eq!(rx.try_recv().unwrap_err(), TryRecvError::Empty);
+
+        // persistence is still in progress after the budget
+        test_harness.tree.pending_forkchoice_update.as_mut().unwrap().deadline = Instant::now();
+        test_harness.tree.on_pending_forkchoice_update(false).unwrap();
+        let response = rx.await.unwrap().unwrap().await.unwrap();
+        assert!(response.payload_status.is_syncing());
+        test_harness.check_canon_head(main_chain_last_hash);
+
+        // a canonical head doesn't wait for persistence
+        let rx = send_fcu(&mut test_harness, main_chain[3].recovered_block().hash());
+        assert!(test_harness.tree.pending_forkchoice_update.is_none());
+        let response = rx.await.unwrap().unwrap().await.unwrap();
+        assert!(response.payload_status.is_valid());
+
+        // persistence completes within the budget
+        let rx = send_fcu(&mut test_harness, fork_chain_last_hash);
+        persistence_tx.send(None).unwrap();
+        test_harness.tree.try_finish_persistence().unwrap();
+        test_harness.tree.on_pending_forkchoice_update(false).unwrap();
+        let response = rx.await.unwrap().unwrap().await.unwrap();
+        assert!(response.payload_status.is_valid());
+        test_harness.check_canon_head(fork_chain_last_hash);
+    }
+
+    #[tokio::test]
+    async fn test_engine_tree_restore_state_snapshot() {
+        let chain_spec = MAINNET.clone();
+        let mut test_harness = TestHarness::new(chain_spec.clone());
+
+        let main_chain: Vec<_> = test_harness.block_builder.get_executed_blocks(0..5).collect();
+        test_harness = test_harness.with_blocks(main_chain.clone());
+        let main_chain_last_hash = main_chain.last().unwrap().recovered_block().hash();
+        test_harness.fcu_to(main_chain_last_hash, ForkchoiceStatus::Valid).await;
+
+        let (tx, rx) = oneshot::channel();
+        test_harness
+            .tree
+            .on_engine_message(FromEngine::Request(EngineApiRequest::StateSnapshot(
+                EngineStateSnapshotRequest::Capture(tx),
+            )))
+            .unwrap();
+        let id = rx.await.unwrap();
+
+        // reorg to a fork after capturing the snapshot
+        let fork_chain = test_harness.block_builder.create_fork(main_chain[2].recovered_block(), 3);
+        let fork_chain_last_hash = fork_chain.last().unwrap().hash();
+        for block in &fork_chain {
+            test_harness.insert_block(block.clone()).unwrap();
+        }
+        test_harness.send_fcu(fork_chain_last_hash, ForkchoiceStatus::Valid).await;
+        test_harness.check_canon_head(fork_chain_last_hash);
+        let invalid = BlockWithParent {
+            parent: fork_chain_last_hash,
+            block: BlockNumHash::new(6, B256::random()),
+        };
+        test_harness.tree.state.invalid_headers.insert(invalid);
+
+        assert_eq!(
+            test_harness.tree.restore_state_snapshot(id + 1),
+            Err(EngineStateSnapshotError::UnknownSnapshot(id + 1))
+        );
+        let mut notifications = test_harness.tree.canonical_in_memory_state.subscribe_canon_state();
+        test_harness.tree.restore_state_snapshot(id).unwrap();
+
+        // a snapshot is only restored once
+        assert_eq!(
+            test_harness.tree.restore_state_snapshot(id),
+            Err(EngineStateSnapshotError::UnknownSnapshot(id))
+        );
+        assert!(test_harness.tree.state.invalid_headers.get(&invalid.block.hash).is_none());
+
+        // the switch back to the main chain is notified as a reorg
+        match notifications.try_recv().unwrap() {
+            CanonStateNotification::Reorg { new, old } => {
+                assert_eq!(new.tip().hash(), main_chain_last_hash);
+                assert_eq!(new.len(), 2);
+                assert_eq!(old.tip().hash(), fork_chain_last_hash);
+                assert_eq!(old.len(), fork_chain.len());
+            }
+            notification => panic!("unexpected notification: {notification:?}"),
+        }
+
+        test_harness.check_canon_head(main_chain_last_hash);
+        assert_eq!(
+            test_harness.tree.canonical_in_memory_state.get_canonical_head().hash(),
+            main_chain_last_hash
+        );
+        assert!(test_harness
+            .tree
+            .state
+            .tree_state
+            .executed_block_by_hash(fork_chain_last_hash)
+            .is_none());
+        assert!(test_harness
+            .tree
+            .canonical_in_memory_state
+            .state_by_hash(main_chain_last_hash)
+            .is_some());
+
+        // the oldest snapshots are evicted
+        for _ in 0..=snapshot::MAX_ENGINE_STATE_SNAPSHOTS {
+            test_harness.tree.capture_state_snapshot();
+        }
+        assert_eq!(test_harness.tree.state_snapshots.len(), snapshot::MAX_ENGINE_STATE_SNAPSHOTS);
+        assert!(test_harness.tree.state_snapshots.get(id + 1).is_none());
+    }
+
     #[tokio::test]
     async fn test_engine_tree_live_sync_transition_required_blocks_requested() {
         reth_tracing::init_test_tracing();
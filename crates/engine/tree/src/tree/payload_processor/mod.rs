@@ -11,7 +11,7 @@ use crate::tree::{
 };
 use alloy_consensus::{transaction::Recovered, BlockHeader};
 use alloy_evm::block::StateChangeSource;
-use alloy_primitives::B256;
+use alloy_primitives::{Address, StorageKey, B256};
 use executor::WorkloadExecutor;
 use multiproof::*;
 use parking_lot::RwLock;
@@ -32,7 +32,9 @@ use std::{
         mpsc::{channel, Sender},
         Arc,
     },
+    time::Instant,
 };
+use tracing::{debug, info, warn};
 
 pub mod executor;
 pub mod multiproof;
@@ -230,6 +232,45 @@ where
         CacheTaskHandle { cache, to_prewarm_task: Some(to_prewarm_task), cache_metrics }
     }
 
+    /// Spawns a blocking task that warms a new cache for the block with the given hash with the
+    /// accounts and storage slots from the latest state of the provider, so that the first payload
+    /// on top of the block doesn't have to read its hot state from disk.
+    ///
+    /// The warmed cache is discarded if a payload was executed in the meantime.
+    pub(super) fn spawn_cache_warmup<P>(
+        &self,
+        block_hash: B256,
+        provider: P,
+        accounts: Vec<(Address, Vec<StorageKey>)>,
+    ) where
+        P: StateProviderFactory + 'static,
+    {
+        let execution_cache = self.execution_cache.clone();
+        let cross_block_cache_size = self.cross_block_cache_size;
+        self.executor.spawn_blocking(move || {
+            let start = Instant::now();
+            let cache = ProviderCacheBuilder::default().build_caches(cross_block_cache_size);
+            match provider.latest().and_then(|state_provider| cache.warm(&*state_provider, accounts))
+            {
+                Ok((accounts, slots)) => {
+                    let saved = execution_cache.save_cache_if_empty(SavedCache::new(
+                        block_hash,
+                        cache,
+                        CachedStateMetrics::zeroed(),
+                    ));
+                    if saved {
+                        info!(target: "engine::tree", accounts, slots, elapsed = ?start.elapsed(), "Warmed execution cache");
+                    } else {
+                        debug!(target: "engine::tree", "Discarded warmed execution cache, a payload was executed in the meantime");
+                    }
+                }
+                Err(err) => {
+                    warn!(target: "engine::tree", %err, "Failed to warm execution cache")
+                }
+            }
+        });
+    }
+
     /// Returns the cache for the given parent hash.
     ///
     /// If the given hash is different then what is recently cached, then this will create a new
@@ -375,6 +416,18 @@ impl ExecutionCache {
     pub(crate) fn save_cache(&self, cache: SavedCache) {
         self.inner.write().replace(cache);
     }
+
+    /// Stores the provider cache if no cache is stored yet.
+    ///
+    /// Returns whether the cache was stored.
+    pub(crate) fn save_cache_if_empty(&self, cache: SavedCache) -> bool {
+        let mut inner = self.inner.write();
+        if inner.is_some() {
+            return false
+        }
+        inner.replace(cache);
+        true
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,88 @@
+use crate::tree::{BlockBuffer, TreeState};
+use alloy_eips::{eip1898::BlockWithParent, BlockNumHash};
+use alloy_primitives::{map::HashMap, B256};
+use reth_engine_primitives::{EngineStateSnapshotId, ForkchoiceStateTracker};
+use reth_primitives_traits::{NodePrimitives, SealedHeader};
+
+/// The maximum number of snapshots that are kept, the oldest snapshot is evicted first.
+pub(crate) const MAX_ENGINE_STATE_SNAPSHOTS: usize = 16;
+
+/// A copy of the in-memory state of the engine, captured for later restoration.
+///
+/// The executed blocks of the tree are shared with the engine, only the buffered blocks are copied.
+#[derive(Debug)]
+pub(crate) struct EngineStateSnapshot<N: NodePrimitives> {
+    /// The executed blocks of the tree.
+    pub(crate) tree_state: TreeState<N>,
+    /// The buffered blocks that were not connected to the tree.
+    pub(crate) buffer: BlockBuffer<N::Block>,
+    /// The invalid headers with their invalid ancestors, from the least to the most recently used.
+    pub(crate) invalid_headers: Vec<(B256, BlockWithParent)>,
+    /// The forkchoice states received by the CL.
+    pub(crate) forkchoice_state_tracker: ForkchoiceStateTracker,
+    /// The canonical head.
+    pub(crate) canonical_head: SealedHeader<N::BlockHeader>,
+    /// The safe block header, if any.
+    pub(crate) safe: Option<SealedHeader<N::BlockHeader>>,
+    /// The finalized block header, if any.
+    pub(crate) finalized: Option<SealedHeader<N::BlockHeader>>,
+    /// The last block that was persisted when the snapshot was captured.
+    ///
+    /// The snapshot can only be restored on top of the same persisted state.
+    pub(crate) last_persisted_block: BlockNumHash,
+}
+
+/// The snapshots captured by the engine, keyed by their id.
+#[derive(Debug)]
+pub(crate) struct EngineStateSnapshots<N: NodePrimitives> {
+    /// All captured snapshots.
+    snapshots: HashMap<EngineStateSnapshotId, EngineStateSnapshot<N>>,
+    /// The id of the next captured snapshot.
+    next_id: EngineStateSnapshotId,
+}
+
+impl<N: NodePrimitives> EngineStateSnapshots<N> {
+    /// Stores the snapshot and returns its id.
+    ///
+    /// If [`MAX_ENGINE_STATE_SNAPSHOTS`] snapshots are stored, the oldest one is evicted.
+    pub(crate) fn insert(&mut self, snapshot: EngineStateSnapshot<N>) -> EngineStateSnapshotId {
+        if self.snapshots.len() >= MAX_ENGINE_STATE_SNAPSHOTS {
+            if let Some(oldest) = self.snapshots.keys().min().copied() {
+                self.snapshots.remove(&oldest);
+            }
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.snapshots.insert(id, snapshot);
+        id
+    }
+
+    /// Returns the snapshot with the given id.
+    pub(crate) fn get(&self, id: EngineStateSnapshotId) -> Option<&EngineStateSnapshot<N>> {
+        self.snapshots.get(&id)
+    }
+
+    /// Removes and returns the snapshot with the given id.
+    pub(crate) fn remove(&mut self, id: EngineStateSnapshotId) -> Option<EngineStateSnapshot<N>> {
+        self.snapshots.remove(&id)
+    }
+
+    /// Drops all snapshots that were not captured on top of the given persisted block, because
+    /// they can no longer be restored.
+    pub(crate) fn retain_persisted(&mut self, last_persisted_block: BlockNumHash) {
+        self.snapshots.retain(|_, snapshot| snapshot.last_persisted_block == last_persisted_block);
+    }
+
+    /// Returns the number of stored snapshots.
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+}
+
+impl<N: NodePrimitives> Default for EngineStateSnapshots<N> {
+    fn default() -> Self {
+        Self { snapshots: HashMap::default(), next_id: 0 }
+    }
+}
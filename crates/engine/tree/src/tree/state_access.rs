@@ -0,0 +1,372 @@
+//! Access frequencies of accounts and storage slots, persisted so that the execution cache can be
+//! warmed with the most frequently accessed state after a restart.
+
+use alloy_evm::block::StateChangeSource;
+use alloy_primitives::{Address, B256};
+use parking_lot::Mutex;
+use reth_evm::OnStateHook;
+use reth_revm::state::EvmState;
+use std::{
+    collections::HashMap,
+    fs,
+    hash::Hash,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+use tracing::{debug, warn};
+
+/// How often the access frequencies are persisted.
+const SAVE_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Maximum number of storage slots tracked per account.
+const MAX_SLOTS_PER_ACCOUNT: usize = 256;
+
+/// Version of the file format, written as the first byte of the file.
+const FILE_VERSION: u8 = 1;
+
+/// Counts how often accounts and their storage slots are accessed during block execution.
+///
+/// The number of tracked accounts and slots is bounded: once twice as many entries as allowed are
+/// tracked, all counts are halved, so entries that were hot a long time ago fade out, and only the
+/// most frequently accessed entries are kept.
+#[derive(Debug)]
+pub(crate) struct StateAccessSketch {
+    /// Access count and accessed storage slots of every tracked account.
+    accounts: HashMap<Address, AccountAccesses>,
+    /// Maximum number of tracked accounts.
+    max_accounts: usize,
+}
+
+/// Access counts of an account and its storage slots.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct AccountAccesses {
+    /// Number of transactions that accessed the account.
+    pub(crate) count: u32,
+    /// Number of transactions that accessed each storage slot of the account.
+    pub(crate) slots: HashMap<B256, u32>,
+}
+
+impl StateAccessSketch {
+    /// Creates an empty sketch that tracks at most `max_accounts` accounts.
+    pub(crate) fn new(max_accounts: usize) -> Self {
+        Self { accounts: HashMap::new(), max_accounts }
+    }
+
+    /// Adds the accesses that were recorded during the execution of a block.
+    pub(crate) fn merge(&mut self, block_accesses: BlockAccesses) {
+        for (address, block_account) in block_accesses.accounts {
+            let accesses = self.accounts.entry(address).or_default();
+            accesses.count = accesses.count.saturating_add(block_account.count);
+            for (slot, block_count) in block_account.slots {
+                let count = accesses.slots.entry(slot).or_default();
+                *count = count.saturating_add(block_count);
+            }
+            if accesses.slots.len() > 2 * MAX_SLOTS_PER_ACCOUNT {
+                decay(&mut accesses.slots, MAX_SLOTS_PER_ACCOUNT, |count| count);
+            }
+        }
+
+        if self.accounts.len() > 2 * self.max_accounts {
+            decay(&mut self.accounts, self.max_accounts, |accesses| &mut accesses.count);
+        }
+    }
+
+    /// Returns a copy of the sketch.
+    fn snapshot(&self) -> Self {
+        Self { accounts: self.accounts.clone(), max_accounts: self.max_accounts }
+    }
+
+    /// Returns the tracked accounts ordered by access count, most frequently accessed first, with
+    /// their storage slots ordered the same way.
+    pub(crate) fn hottest(&self) -> Vec<(Address, AccountAccesses)> {
+        let mut accounts = self
+            .accounts
+            .iter()
+            .map(|(address, accesses)| (*address, accesses))
+            .collect::<Vec<_>>();
+        accounts.sort_unstable_by(|(a, a_accesses), (b, b_accesses)| {
+            b_accesses.count.cmp(&a_accesses.count).then(a.cmp(b))
+        });
+        accounts.truncate(self.max_accounts);
+        accounts.into_iter().map(|(address, accesses)| (address, accesses.clone())).collect()
+    }
+
+    /// Returns the tracked storage slots of the account, most frequently accessed first.
+    pub(crate) fn hottest_slots(accesses: &AccountAccesses) -> Vec<(B256, u32)> {
+        let mut slots =
+            accesses.slots.iter().map(|(slot, count)| (*slot, *count)).collect::<Vec<_>>();
+        slots.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)));
+        slots.truncate(MAX_SLOTS_PER_ACCOUNT);
+        slots
+    }
+
+    /// Encodes the most frequently accessed accounts and storage slots.
+    ///
+    /// The encoding starts with the version byte, followed by every account as its address, its
+    /// access count and number of slots, and every slot as its key and access count. All integers
+    /// are little endian.
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        let mut buf = vec![FILE_VERSION];
+        for (address, accesses) in self.hottest() {
+            let slots = Self::hottest_slots(&accesses);
+            buf.extend_from_slice(address.as_slice());
+            buf.extend_from_slice(&accesses.count.to_le_bytes());
+            buf.extend_from_slice(&(slots.len() as u32).to_le_bytes());
+            for (slot, count) in slots {
+                buf.extend_from_slice(slot.as_slice());
+                buf.extend_from_slice(&count.to_le_bytes());
+            }
+        }
+        buf
+    }
+
+    /// Decodes a sketch that was encoded with [`Self::encode`], keeping at most `max_accounts`
+    /// accounts.
+    pub(crate) fn decode(mut buf: &[u8], max_accounts: usize) -> io::Result<Self> {
+        fn take<'a>(buf: &mut &'a [u8], len: usize) -> io::Result<&'a [u8]> {
+            if buf.len() < len {
+                return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "truncated state access"))
+            }
+            let (head, tail) = buf.split_at(len);
+            *buf = tail;
+            Ok(head)
+        }
+        fn take_u32(buf: &mut &[u8]) -> io::Result<u32> {
+            Ok(u32::from_le_bytes(take(buf, 4)?.try_into().expect("4 bytes")))
+        }
+
+        let version = take(&mut buf, 1)?[0];
+        if version != FILE_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported state access version {version}"),
+            ))
+        }
+
+        let mut sketch = Self::new(max_accounts);
+        while !buf.is_empty() && sketch.accounts.len() < max_accounts {
+            let address = Address::from_slice(take(&mut buf, 20)?);
+            let count = take_u32(&mut buf)?;
+            let num_slots = take_u32(&mut buf)?;
+            let mut slots = HashMap::with_capacity((num_slots as usize).min(MAX_SLOTS_PER_ACCOUNT));
+            for _ in 0..num_slots {
+                let slot = B256::from_slice(take(&mut buf, 32)?);
+                slots.insert(slot, take_u32(&mut buf)?);
+            }
+            sketch.accounts.insert(address, AccountAccesses { count, slots });
+        }
+        Ok(sketch)
+    }
+
+    /// Loads the sketch from the file, or returns an empty sketch if the file doesn't exist.
+    pub(crate) fn load(path: &Path, max_accounts: usize) -> io::Result<Self> {
+        match fs::read(path) {
+            Ok(buf) => Self::decode(&buf, max_accounts),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::new(max_accounts)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// The accounts and storage slots that were accessed during the execution of a block, counted
+/// without synchronization and merged into the shared [`StateAccessSketch`] once the block is
+/// executed.
+#[derive(Debug, Default)]
+pub(crate) struct BlockAccesses {
+    /// Access count and accessed storage slots of every accessed account.
+    accounts: HashMap<Address, AccountAccesses>,
+}
+
+impl BlockAccesses {
+    /// Records the accounts and storage slots that were accessed by a transaction or system call.
+    pub(crate) fn record(&mut self, state: &EvmState) {
+        for (address, account) in state {
+            let accesses = self.accounts.entry(*address).or_default();
+            accesses.count = accesses.count.saturating_add(1);
+            for slot in account.storage.keys() {
+                let count = accesses.slots.entry((*slot).into()).or_default();
+                *count = count.saturating_add(1);
+            }
+        }
+    }
+}
+
+/// Halves all counts and removes the entries that drop to zero. If more than `max` entries remain,
+/// only the `max` entries with the highest counts are kept.
+fn decay<K: Eq + Hash, V>(
+    entries: &mut HashMap<K, V>,
+    max: usize,
+    count_of: impl Fn(&mut V) -> &mut u32,
+) {
+    entries.retain(|_, value| {
+        let count = count_of(value);
+        *count /= 2;
+        *count > 0
+    });
+
+    if entries.len() > max {
+        let mut counts = entries.values_mut().map(|value| *count_of(value)).collect::<Vec<_>>();
+        let (_, threshold, _) = counts.select_nth_unstable_by(max, |a, b| b.cmp(a));
+        let threshold = *threshold;
+        entries.retain(|_, value| *count_of(value) > threshold);
+    }
+}
+
+/// Writes the file by writing a temporary file first and renaming it.
+fn write_atomic(path: &Path, buf: &[u8]) -> io::Result<()> {
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, buf)?;
+    fs::rename(tmp, path)
+}
+
+/// Records the state accesses of executed blocks into a shared [`StateAccessSketch`] and
+/// periodically persists the sketch.
+#[derive(Debug)]
+pub(crate) struct StateAccessRecorder {
+    /// The shared sketch.
+    sketch: Arc<Mutex<StateAccessSketch>>,
+    /// The file the sketch is persisted to.
+    path: PathBuf,
+    /// When the sketch was persisted the last time.
+    last_saved: Instant,
+    /// The thread that persists the sketch, if it was spawned.
+    save_thread: Option<JoinHandle<()>>,
+}
+
+impl StateAccessRecorder {
+    /// Creates a recorder that continues counting from the given sketch.
+    pub(crate) fn new(sketch: StateAccessSketch, path: PathBuf) -> Self {
+        Self {
+            sketch: Arc::new(Mutex::new(sketch)),
+            path,
+            last_saved: Instant::now(),
+            save_thread: None,
+        }
+    }
+
+    /// Wraps the state hook so that the accessed state is recorded before it's forwarded.
+    ///
+    /// The accesses of the block are merged into the shared sketch when the returned hook is
+    /// dropped.
+    pub(crate) fn hook<H: OnStateHook>(&self, hook: H) -> RecordingStateHook<H> {
+        RecordingStateHook { hook, accesses: BlockAccesses::default(), sketch: self.sketch.clone() }
+    }
+
+    /// Persists the sketch on a separate thread, if it wasn't persisted recently and the previous
+    /// save finished.
+    pub(crate) fn maybe_save(&mut self) {
+        if self.last_saved.elapsed() < SAVE_INTERVAL ||
+            self.save_thread.as_ref().is_some_and(|thread| !thread.is_finished())
+        {
+            return
+        }
+        self.last_saved = Instant::now();
+
+        // only the copy is taken under the lock, the encoding doesn't block the recording
+        let sketch = self.sketch.lock().snapshot();
+        let path = self.path.clone();
+        let spawned = std::thread::Builder::new().name("State Access Save".to_string()).spawn(
+            move || {
+                let buf = sketch.encode();
+                match write_atomic(&path, &buf) {
+                    Ok(()) => debug!(target: "engine::tree", ?path, "Saved state access frequencies"),
+                    Err(err) => {
+                        warn!(target: "engine::tree", %err, ?path, "Failed to save state access frequencies")
+                    }
+                }
+            },
+        );
+        match spawned {
+            Ok(thread) => self.save_thread = Some(thread),
+            Err(err) => {
+                warn!(target: "engine::tree", %err, "Failed to spawn state access save thread")
+            }
+        }
+    }
+}
+
+impl Drop for StateAccessRecorder {
+    fn drop(&mut self) {
+        // don't leave a partially written file behind
+        if let Some(thread) = self.save_thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A state hook that records the accessed state of a block before forwarding it to the inner
+/// hook.
+#[derive(Debug)]
+pub(crate) struct RecordingStateHook<H> {
+    /// The inner hook.
+    hook: H,
+    /// The accesses of the block.
+    accesses: BlockAccesses,
+    /// The shared sketch the accesses are merged into.
+    sketch: Arc<Mutex<StateAccessSketch>>,
+}
+
+impl<H: OnStateHook> OnStateHook for RecordingStateHook<H> {
+    fn on_state(&mut self, source: StateChangeSource, state: &EvmState) {
+        self.accesses.record(state);
+        self.hook.on_state(source, state);
+    }
+}
+
+impl<H> Drop for RecordingStateHook<H> {
+    fn drop(&mut self) {
+        self.sketch.lock().merge(std::mem::take(&mut self.accesses));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+    use revm_state::{Account, EvmStorageSlot};
+
+    fn block_accesses(accesses: &[(Address, &[u64])]) -> BlockAccesses {
+        let state = accesses
+            .iter()
+            .map(|(address, slots)| {
+                let mut account = Account::default();
+                for slot in *slots {
+                    account.storage.insert(U256::from(*slot), EvmStorageSlot::default());
+                }
+                (*address, account)
+            })
+            .collect();
+        let mut block_accesses = BlockAccesses::default();
+        block_accesses.record(&state);
+        block_accesses
+    }
+
+    #[test]
+    fn keeps_hottest_accounts() {
+        let (a, b, c) =
+            (Address::with_last_byte(1), Address::with_last_byte(2), Address::with_last_byte(3));
+        let mut sketch = StateAccessSketch::new(1);
+
+        sketch.merge(block_accesses(&[(a, &[1, 2]), (b, &[])]));
+        sketch.merge(block_accesses(&[(a, &[2])]));
+        assert_eq!(sketch.accounts.len(), 2);
+
+        // exceeding twice the maximum decays the counts and drops the cold accounts
+        sketch.merge(block_accesses(&[(c, &[])]));
+        assert_eq!(sketch.accounts.keys().collect::<Vec<_>>(), vec![&a]);
+
+        let hottest = sketch.hottest();
+        assert_eq!(hottest.len(), 1);
+        assert_eq!(hottest[0].1.count, 1);
+        assert_eq!(
+            StateAccessSketch::hottest_slots(&hottest[0].1),
+            vec![(B256::from(U256::from(2)), 2), (B256::from(U256::from(1)), 1)]
+        );
+
+        let decoded = StateAccessSketch::decode(&sketch.encode(), 1).unwrap();
+        assert_eq!(decoded.accounts, sketch.accounts);
+    }
+}
@@ -38,6 +38,9 @@ pub enum StoredEngineApiMessage<EngineT: EngineTypes> {
 pub struct EngineMessageStore {
     /// The path to the directory that stores the engine API messages.
     path: PathBuf,
+    /// The maximum number of messages to keep in the directory. If [`Some`], the oldest messages
+    /// are removed once the limit is exceeded.
+    max_messages: Option<usize>,
 }
 
 impl EngineMessageStore {
@@ -45,7 +48,14 @@ impl EngineMessageStore {
     ///
     /// The path is expected to be a directory, where individual message JSON files will be stored.
     pub const fn new(path: PathBuf) -> Self {
-        Self { path }
+        Self { path, max_messages: None }
+    }
+
+    /// Keeps at most the given number of the most recent messages, turning the store into a ring
+    /// buffer.
+    pub const fn with_max_messages(mut self, max_messages: Option<usize>) -> Self {
+        self.max_messages = max_messages;
+        self
     }
 
     /// Stores the received [`BeaconEngineMessage`] to disk, appending the `received_at` time to the
@@ -88,6 +98,19 @@ impl EngineMessageStore {
             // noop
             BeaconEngineMessage::TransitionConfigurationExchanged => (),
         };
+
+        if let Some(max_messages) = self.max_messages {
+            self.remove_oldest(max_messages)?;
+        }
+        Ok(())
+    }
+
+    /// Removes the oldest stored messages, so that at most `max_messages` remain.
+    fn remove_oldest(&self, max_messages: usize) -> eyre::Result<()> {
+        let messages = self.engine_messages_iter()?.collect::<Vec<_>>();
+        for path in messages.iter().take(messages.len().saturating_sub(max_messages)) {
+            fs::remove_file(path)?;
+        }
         Ok(())
     }
 
@@ -129,6 +152,12 @@ impl<S> EngineStoreStream<S> {
     pub const fn new(stream: S, path: PathBuf) -> Self {
         Self { stream, store: EngineMessageStore::new(path) }
     }
+
+    /// Keeps at most the given number of the most recent messages on disk.
+    pub const fn with_max_messages(mut self, max_messages: Option<usize>) -> Self {
+        self.store.max_messages = max_messages;
+        self
+    }
 }
 
 impl<S, Engine> Stream for EngineStoreStream<S>
@@ -149,3 +178,46 @@ where
         Poll::Ready(next)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rpc_types_engine::ForkchoiceState;
+    use reth_ethereum_engine_primitives::EthEngineTypes;
+    use reth_payload_primitives::EngineApiMessageVersion;
+    use std::time::Duration;
+
+    #[test]
+    fn keeps_most_recent_messages() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = EngineMessageStore::new(dir.path().to_path_buf()).with_max_messages(Some(2));
+
+        for i in 0..4u8 {
+            let (tx, _rx) = tokio::sync::oneshot::channel();
+            let message = BeaconEngineMessage::<EthEngineTypes>::ForkchoiceUpdated {
+                state: ForkchoiceState { head_block_hash: [i; 32].into(), ..Default::default() },
+                payload_attrs: None,
+                version: EngineApiMessageVersion::default(),
+                tx,
+            };
+            let received_at = SystemTime::UNIX_EPOCH + Duration::from_secs(i as u64);
+            store.on_message(&message, received_at).unwrap();
+        }
+
+        let messages = store
+            .engine_messages_iter()
+            .unwrap()
+            .map(|path| {
+                let message: StoredEngineApiMessage<EthEngineTypes> =
+                    serde_json::from_slice(&fs::read(path).unwrap()).unwrap();
+                match message {
+                    StoredEngineApiMessage::ForkchoiceUpdated { state, .. } => {
+                        state.head_block_hash[0]
+                    }
+                    StoredEngineApiMessage::NewPayload { .. } => unreachable!(),
+                }
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(messages, vec![2, 3]);
+    }
+}
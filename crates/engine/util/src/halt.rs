@@ -0,0 +1,59 @@
+//! Stream wrapper that keeps the consensus layer from advancing the chain.
+
+use alloy_rpc_types_engine::{PayloadStatus, PayloadStatusEnum};
+use futures::{Stream, StreamExt};
+use reth_engine_primitives::{
+    BeaconEngineMessage, EngineTypes, ExecutionPayload, OnForkChoiceUpdated,
+};
+use std::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+/// Engine API stream wrapper that answers all forkchoice updated and new payload messages with
+/// `SYNCING` instead of forwarding them, so that the chain only advances through backfill sync.
+#[derive(Debug)]
+#[pin_project::pin_project]
+pub struct EngineHalt<S> {
+    #[pin]
+    stream: S,
+}
+
+impl<S> EngineHalt<S> {
+    /// Creates new [`EngineHalt`] stream wrapper.
+    pub const fn new(stream: S) -> Self {
+        Self { stream }
+    }
+}
+
+impl<S, Engine> Stream for EngineHalt<S>
+where
+    S: Stream<Item = BeaconEngineMessage<Engine>>,
+    Engine: EngineTypes,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            let next = ready!(this.stream.poll_next_unpin(cx));
+            match next {
+                Some(BeaconEngineMessage::ForkchoiceUpdated { state, tx, .. }) => {
+                    tracing::trace!(target: "engine::stream::halt", ?state, "Ignoring FCU");
+                    let _ = tx.send(Ok(OnForkChoiceUpdated::syncing()));
+                }
+                Some(BeaconEngineMessage::NewPayload { payload, tx }) => {
+                    tracing::trace!(
+                        target: "engine::stream::halt",
+                        block_number = payload.block_number(),
+                        block_hash = %payload.block_hash(),
+                        "Ignoring new payload"
+                    );
+                    let _ = tx.send(Ok(PayloadStatus::from_status(PayloadStatusEnum::Syncing)));
+                }
+                next => return Poll::Ready(next),
+            }
+        }
+    }
+}
@@ -25,6 +25,9 @@ use skip_new_payload::EngineSkipNewPayload;
 pub mod reorg;
 use reorg::EngineReorg;
 
+pub mod halt;
+use halt::EngineHalt;
+
 /// The collection of stream extensions for engine API message stream.
 pub trait EngineMessageStreamExt<Engine: EngineTypes>:
     Stream<Item = BeaconEngineMessage<Engine>>
@@ -76,12 +79,37 @@ pub trait EngineMessageStreamExt<Engine: EngineTypes>:
         }
     }
 
+    /// Answers all [`BeaconEngineMessage::ForkchoiceUpdated`] and
+    /// [`BeaconEngineMessage::NewPayload`] messages with `SYNCING` instead of forwarding them.
+    fn halt(self) -> EngineHalt<Self>
+    where
+        Self: Sized,
+    {
+        EngineHalt::new(self)
+    }
+
+    /// If `halt` is true, returns the stream that answers all
+    /// [`BeaconEngineMessage::ForkchoiceUpdated`] and [`BeaconEngineMessage::NewPayload`]
+    /// messages with `SYNCING`. Otherwise, returns `Self`.
+    fn maybe_halt(self, halt: bool) -> Either<EngineHalt<Self>, Self>
+    where
+        Self: Sized,
+    {
+        if halt {
+            Either::Left(self.halt())
+        } else {
+            Either::Right(self)
+        }
+    }
+
     /// Stores engine messages at the specified location.
-    fn store_messages(self, path: PathBuf) -> EngineStoreStream<Self>
+    ///
+    /// If `max_messages` is [Some], only the given number of the most recent messages is kept.
+    fn store_messages(self, path: PathBuf, max_messages: Option<usize>) -> EngineStoreStream<Self>
     where
         Self: Sized,
     {
-        EngineStoreStream::new(self, path)
+        EngineStoreStream::new(self, path).with_max_messages(max_messages)
     }
 
     /// If the path is [Some], returns the stream that stores engine messages at the specified
@@ -89,12 +117,13 @@ pub trait EngineMessageStreamExt<Engine: EngineTypes>:
     fn maybe_store_messages(
         self,
         maybe_path: Option<PathBuf>,
+        max_messages: Option<usize>,
     ) -> Either<EngineStoreStream<Self>, Self>
     where
         Self: Sized,
     {
         if let Some(path) = maybe_path {
-            Either::Left(self.store_messages(path))
+            Either::Left(self.store_messages(path, max_messages))
         } else {
             Either::Right(self)
         }
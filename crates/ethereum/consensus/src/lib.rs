@@ -7,27 +7,33 @@
 )]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+use alloc::sync::Arc;
 use alloy_consensus::EMPTY_OMMER_ROOT_HASH;
 use alloy_eips::merge::ALLOWED_FUTURE_BLOCK_TIME_SECONDS;
 use alloy_primitives::U256;
+use core::fmt::Debug;
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_consensus::{Consensus, ConsensusError, FullConsensus, HeaderValidator};
 use reth_consensus_common::validation::{
-    validate_4844_header_standalone, validate_against_parent_4844,
+    validate_4844_blob_gas_fields, validate_4844_header_standalone, validate_against_parent_4844,
     validate_against_parent_eip1559_base_fee, validate_against_parent_hash_number,
-    validate_against_parent_timestamp, validate_block_pre_execution, validate_body_against_header,
-    validate_header_base_fee, validate_header_extra_data, validate_header_gas,
+    validate_against_parent_timestamp, validate_block_pre_execution_with_policy,
+    validate_body_against_header, validate_header_base_fee, validate_header_extra_data,
+    validate_header_gas,
 };
 use reth_execution_types::BlockExecutionResult;
 use reth_primitives_traits::{
     constants::{GAS_LIMIT_BOUND_DIVISOR, MINIMUM_GAS_LIMIT},
     Block, BlockHeader, NodePrimitives, RecoveredBlock, SealedBlock, SealedHeader,
 };
-use std::{fmt::Debug, sync::Arc, time::SystemTime};
 
 mod validation;
-pub use validation::validate_block_post_execution;
+pub use reth_consensus_common::validation::BlockValidationPolicy;
+pub use validation::{validate_block_post_execution, validate_block_post_execution_with_policy};
 
 /// Ethereum beacon consensus
 ///
@@ -36,12 +42,57 @@ pub use validation::validate_block_post_execution;
 pub struct EthBeaconConsensus<ChainSpec> {
     /// Configuration
     chain_spec: Arc<ChainSpec>,
+    /// Checks that are performed on blocks.
+    validation_policy: BlockValidationPolicy,
+    /// Returns the present unix timestamp in seconds, to reject pre-merge blocks from the future.
+    clock: Option<fn() -> u64>,
+}
+
+/// The default clock of [`EthBeaconConsensus`], the system clock if `std` is available.
+#[cfg(feature = "std")]
+const DEFAULT_CLOCK: Option<fn() -> u64> = Some(system_timestamp);
+
+/// Without `std` there is no system clock, it has to be set with
+/// [`EthBeaconConsensus::with_clock`], e.g. from the input of a zkVM guest.
+#[cfg(not(feature = "std"))]
+const DEFAULT_CLOCK: Option<fn() -> u64> = None;
+
+/// Returns the present unix timestamp in seconds of the system clock.
+#[cfg(feature = "std")]
+fn system_timestamp() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
 }
 
 impl<ChainSpec: EthChainSpec + EthereumHardforks> EthBeaconConsensus<ChainSpec> {
     /// Create a new instance of [`EthBeaconConsensus`]
     pub const fn new(chain_spec: Arc<ChainSpec>) -> Self {
-        Self { chain_spec }
+        Self { chain_spec, validation_policy: BlockValidationPolicy::new(), clock: DEFAULT_CLOCK }
+    }
+
+    /// Sets the clock that returns the present unix timestamp in seconds.
+    ///
+    /// The timestamps of pre-merge blocks are checked against it. Without `std` the check is only
+    /// performed if a clock is set.
+    pub const fn with_clock(mut self, clock: fn() -> u64) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Sets the [`BlockValidationPolicy`] that controls which block checks are performed.
+    pub const fn with_validation_policy(
+        mut self,
+        validation_policy: BlockValidationPolicy,
+    ) -> Self {
+        self.validation_policy = validation_policy;
+        self
+    }
+
+    /// Returns the [`BlockValidationPolicy`] of this consensus.
+    pub const fn validation_policy(&self) -> BlockValidationPolicy {
+        self.validation_policy
     }
 
     /// Checks the gas limit for consistency between parent and self headers.
@@ -104,7 +155,13 @@ where
         block: &RecoveredBlock<N::Block>,
         result: &BlockExecutionResult<N::Receipt>,
     ) -> Result<(), ConsensusError> {
-        validate_block_post_execution(block, &self.chain_spec, &result.receipts, &result.requests)
+        validate_block_post_execution_with_policy(
+            block,
+            &self.chain_spec,
+            &result.receipts,
+            &result.requests,
+            self.validation_policy,
+        )
     }
 }
 
@@ -124,7 +181,7 @@ where
     }
 
     fn validate_block_pre_execution(&self, block: &SealedBlock<B>) -> Result<(), Self::Error> {
-        validate_block_pre_execution(block, &self.chain_spec)
+        validate_block_pre_execution_with_policy(block, &self.chain_spec, self.validation_policy)
     }
 }
 
@@ -137,20 +194,26 @@ where
         validate_header_gas(header.header())?;
         validate_header_base_fee(header.header(), &self.chain_spec)?;
 
+        let features = self.chain_spec.execution_features();
+
         // EIP-4895: Beacon chain push withdrawals as operations
-        if self.chain_spec.is_shanghai_active_at_timestamp(header.timestamp()) &&
-            header.withdrawals_root().is_none()
-        {
+        let withdrawals_active = features.withdrawals &&
+            self.chain_spec.is_shanghai_active_at_timestamp(header.timestamp());
+        if withdrawals_active && header.withdrawals_root().is_none() {
             return Err(ConsensusError::WithdrawalsRootMissing)
-        } else if !self.chain_spec.is_shanghai_active_at_timestamp(header.timestamp()) &&
-            header.withdrawals_root().is_some()
-        {
+        } else if !withdrawals_active && header.withdrawals_root().is_some() {
             return Err(ConsensusError::WithdrawalsRootUnexpected)
         }
 
         // Ensures that EIP-4844 fields are valid once cancun is active.
         if self.chain_spec.is_cancun_active_at_timestamp(header.timestamp()) {
-            validate_4844_header_standalone(header.header())?;
+            if features.beacon_root {
+                validate_4844_header_standalone(header.header())?;
+            } else if header.parent_beacon_block_root().is_some() {
+                return Err(ConsensusError::ParentBeaconBlockRootUnexpected)
+            } else {
+                validate_4844_blob_gas_fields(header.header())?;
+            }
         } else if header.blob_gas_used().is_some() {
             return Err(ConsensusError::BlobGasUsedUnexpected)
         } else if header.excess_blob_gas().is_some() {
@@ -159,7 +222,7 @@ where
             return Err(ConsensusError::ParentBeaconBlockRootUnexpected)
         }
 
-        if self.chain_spec.is_prague_active_at_timestamp(header.timestamp()) {
+        if features.requests && self.chain_spec.is_prague_active_at_timestamp(header.timestamp()) {
             if header.requests_hash().is_none() {
                 return Err(ConsensusError::RequestsHashMissing)
             }
@@ -236,14 +299,15 @@ where
             // order, making those checks obsolete.
 
             // Check if timestamp is in the future. Clock can drift but this can be consensus issue.
-            let present_timestamp =
-                SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs();
-
-            if header.timestamp() > present_timestamp + ALLOWED_FUTURE_BLOCK_TIME_SECONDS {
-                return Err(ConsensusError::TimestampIsInFuture {
-                    timestamp: header.timestamp(),
-                    present_timestamp,
-                })
+            if let Some(clock) = self.clock {
+                let present_timestamp = clock();
+
+                if header.timestamp() > present_timestamp + ALLOWED_FUTURE_BLOCK_TIME_SECONDS {
+                    return Err(ConsensusError::TimestampIsInFuture {
+                        timestamp: header.timestamp(),
+                        present_timestamp,
+                    })
+                }
             }
 
             validate_header_extra_data(header)?;
@@ -1,8 +1,10 @@
+use alloc::vec::Vec;
 use alloy_consensus::{proofs::calculate_receipt_root, BlockHeader, TxReceipt};
 use alloy_eips::eip7685::Requests;
 use alloy_primitives::{Bloom, B256};
-use reth_chainspec::EthereumHardforks;
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_consensus::ConsensusError;
+use reth_consensus_common::validation::BlockValidationPolicy;
 use reth_primitives_traits::{
     receipt::gas_spent_by_transactions, Block, GotExpected, Receipt, RecoveredBlock,
 };
@@ -20,12 +22,37 @@ pub fn validate_block_post_execution<B, R, ChainSpec>(
 where
     B: Block,
     R: Receipt,
-    ChainSpec: EthereumHardforks,
+    ChainSpec: EthChainSpec + EthereumHardforks,
+{
+    validate_block_post_execution_with_policy(
+        block,
+        chain_spec,
+        receipts,
+        requests,
+        BlockValidationPolicy::default(),
+    )
+}
+
+/// Validate a block with regard to execution results, performing only the checks enabled by the
+/// given [`BlockValidationPolicy`].
+///
+/// See [`validate_block_post_execution`].
+pub fn validate_block_post_execution_with_policy<B, R, ChainSpec>(
+    block: &RecoveredBlock<B>,
+    chain_spec: &ChainSpec,
+    receipts: &[R],
+    requests: &Requests,
+    policy: BlockValidationPolicy,
+) -> Result<(), ConsensusError>
+where
+    B: Block,
+    R: Receipt,
+    ChainSpec: EthChainSpec + EthereumHardforks,
 {
     // Check if gas used matches the value set in header.
     let cumulative_gas_used =
         receipts.last().map(|receipt| receipt.cumulative_gas_used()).unwrap_or(0);
-    if block.header().gas_used() != cumulative_gas_used {
+    if policy.gas_used && block.header().gas_used() != cumulative_gas_used {
         return Err(ConsensusError::BlockGasUsed {
             gas: GotExpected { got: cumulative_gas_used, expected: block.header().gas_used() },
             gas_spent_by_tx: gas_spent_by_transactions(receipts),
@@ -36,7 +63,7 @@ where
     // operation as hashing that is required for state root got calculated in every
     // transaction This was replaced with is_success flag.
     // See more about EIP here: https://eips.ethereum.org/EIPS/eip-658
-    if chain_spec.is_byzantium_active_at_block(block.header().number()) {
+    if policy.receipts_root && chain_spec.is_byzantium_active_at_block(block.header().number()) {
         if let Err(error) =
             verify_receipts(block.header().receipts_root(), block.header().logs_bloom(), receipts)
         {
@@ -46,7 +73,10 @@ where
     }
 
     // Validate that the header requests hash matches the calculated requests hash
-    if chain_spec.is_prague_active_at_timestamp(block.header().timestamp()) {
+    if policy.requests_hash &&
+        chain_spec.execution_features().requests &&
+        chain_spec.is_prague_active_at_timestamp(block.header().timestamp())
+    {
         let Some(header_requests_hash) = block.header().requests_hash() else {
             return Err(ConsensusError::RequestsHashMissing)
         };
@@ -199,4 +229,105 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_validation_policy() {
+        let receipts = vec![Receipt { cumulative_gas_used: 21_000, ..Default::default() }];
+        let header = alloy_consensus::Header { number: 20_000_000, ..Default::default() };
+        let block = RecoveredBlock::new_unhashed(
+            reth_ethereum_primitives::Block { header, body: Default::default() },
+            vec![],
+        );
+        let chain_spec = reth_chainspec::ChainSpecBuilder::mainnet().byzantium_activated().build();
+
+        assert_eq!(
+            validate_block_post_execution(&block, &chain_spec, &receipts, &Requests::default()),
+            Err(ConsensusError::BlockGasUsed {
+                gas: GotExpected { got: 21_000, expected: 0 },
+                gas_spent_by_tx: gas_spent_by_transactions(&receipts),
+            })
+        );
+
+        let policy = BlockValidationPolicy::default().with_gas_used(false);
+        assert!(matches!(
+            validate_block_post_execution_with_policy(
+                &block,
+                &chain_spec,
+                &receipts,
+                &Requests::default(),
+                policy
+            ),
+            Err(ConsensusError::BodyReceiptRootDiff(_))
+        ));
+
+        let policy = policy.with_receipts_root(false);
+        assert_eq!(
+            validate_block_post_execution_with_policy(
+                &block,
+                &chain_spec,
+                &receipts,
+                &Requests::default(),
+                policy
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_requests_hash_policy() {
+        let receipts = vec![Receipt { cumulative_gas_used: 21_000, ..Default::default() }];
+        let header = alloy_consensus::Header {
+            gas_used: 21_000,
+            receipts_root: calculate_receipt_root(
+                &receipts.iter().map(TxReceipt::with_bloom_ref).collect::<Vec<_>>(),
+            ),
+            requests_hash: Some(B256::random()),
+            ..Default::default()
+        };
+        let chain_spec = reth_chainspec::ChainSpecBuilder::mainnet().prague_activated().build();
+        let validate = |header, policy| {
+            let block = RecoveredBlock::new_unhashed(
+                reth_ethereum_primitives::Block { header, body: Default::default() },
+                vec![],
+            );
+            validate_block_post_execution_with_policy(
+                &block,
+                &chain_spec,
+                &receipts,
+                &Requests::default(),
+                policy,
+            )
+        };
+
+        assert!(matches!(
+            validate(header.clone(), BlockValidationPolicy::default()),
+            Err(ConsensusError::BodyRequestsHashDiff(_))
+        ));
+
+        // only the requests hash comparison is skipped
+        let policy = BlockValidationPolicy::default().with_requests_hash(false);
+        assert_eq!(validate(header.clone(), policy), Ok(()));
+        assert!(matches!(
+            validate(alloy_consensus::Header { gas_used: 0, ..header.clone() }, policy),
+            Err(ConsensusError::BlockGasUsed { .. })
+        ));
+        assert!(matches!(
+            validate(
+                alloy_consensus::Header { receipts_root: B256::ZERO, ..header.clone() },
+                policy
+            ),
+            Err(ConsensusError::BodyReceiptRootDiff(_))
+        ));
+        assert!(matches!(
+            validate(
+                alloy_consensus::Header { logs_bloom: Bloom::random(), ..header.clone() },
+                policy
+            ),
+            Err(ConsensusError::BodyBloomLogDiff(_))
+        ));
+
+        // disabling the blob gas used comparison doesn't skip any check after execution
+        let policy = BlockValidationPolicy::default().with_blob_gas_used(false);
+        assert!(matches!(validate(header, policy), Err(ConsensusError::BodyRequestsHashDiff(_))));
+    }
 }
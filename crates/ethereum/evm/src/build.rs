@@ -5,6 +5,7 @@ use alloy_consensus::{
 use alloy_eips::merge::BEACON_NONCE;
 use alloy_evm::{block::BlockExecutorFactory, eth::EthBlockExecutionCtx};
 use alloy_primitives::Bytes;
+use core::fmt::Debug;
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_ethereum_primitives::{Receipt, TransactionSigned};
 use reth_evm::execute::{BlockAssembler, BlockAssemblerInput, BlockExecutionError};
@@ -18,13 +19,31 @@ pub struct EthBlockAssembler<ChainSpec> {
     pub chain_spec: Arc<ChainSpec>,
     /// Extra data to use for the blocks.
     pub extra_data: Bytes,
+    /// Source of the extra data of each block, overriding [`Self::extra_data`].
+    pub extra_data_source: Option<Arc<dyn ExtraDataSource>>,
 }
 
 impl<ChainSpec> EthBlockAssembler<ChainSpec> {
     /// Creates a new [`EthBlockAssembler`].
     pub fn new(chain_spec: Arc<ChainSpec>) -> Self {
-        Self { chain_spec, extra_data: Default::default() }
+        Self { chain_spec, extra_data: Default::default(), extra_data_source: None }
     }
+
+    /// Returns the extra data of the block with the given number and timestamp.
+    pub fn extra_data(&self, number: u64, timestamp: u64) -> Bytes {
+        self.extra_data_source
+            .as_ref()
+            .and_then(|source| source.extra_data(number, timestamp))
+            .unwrap_or_else(|| self.extra_data.clone())
+    }
+}
+
+/// Provides the extra data of assembled blocks, e.g. from a template that can be changed while the
+/// node is running.
+pub trait ExtraDataSource: Debug + Send + Sync {
+    /// Returns the extra data of the block with the given number and timestamp, or `None` to use
+    /// the configured [`EthBlockAssembler::extra_data`].
+    fn extra_data(&self, number: u64, timestamp: u64) -> Option<Bytes>;
 }
 
 impl<F, ChainSpec> BlockAssembler<F> for EthBlockAssembler<ChainSpec>
@@ -53,22 +72,21 @@ where
         } = input;
 
         let timestamp = evm_env.block_env.timestamp;
+        let features = self.chain_spec.execution_features();
 
         let transactions_root = proofs::calculate_transaction_root(&transactions);
         let receipts_root = Receipt::calculate_receipt_root_no_memo(receipts);
         let logs_bloom = logs_bloom(receipts.iter().flat_map(|r| r.logs()));
 
-        let withdrawals = self
-            .chain_spec
-            .is_shanghai_active_at_timestamp(timestamp)
-            .then(|| ctx.withdrawals.map(|w| w.into_owned()).unwrap_or_default());
+        let withdrawals = (features.withdrawals &&
+            self.chain_spec.is_shanghai_active_at_timestamp(timestamp))
+        .then(|| ctx.withdrawals.map(|w| w.into_owned()).unwrap_or_default());
 
         let withdrawals_root =
             withdrawals.as_deref().map(|w| proofs::calculate_withdrawals_root(w));
-        let requests_hash = self
-            .chain_spec
-            .is_prague_active_at_timestamp(timestamp)
-            .then(|| requests.requests_hash());
+        let requests_hash = (features.requests &&
+            self.chain_spec.is_prague_active_at_timestamp(timestamp))
+        .then(|| requests.requests_hash());
 
         let mut excess_blob_gas = None;
         let mut blob_gas_used = None;
@@ -105,8 +123,8 @@ where
             gas_limit: evm_env.block_env.gas_limit,
             difficulty: evm_env.block_env.difficulty,
             gas_used: *gas_used,
-            extra_data: self.extra_data.clone(),
-            parent_beacon_block_root: ctx.parent_beacon_block_root,
+            extra_data: self.extra_data(evm_env.block_env.number, timestamp),
+            parent_beacon_block_root: ctx.parent_beacon_block_root.filter(|_| features.beacon_root),
             blob_gas_used,
             excess_blob_gas,
             requests_hash,
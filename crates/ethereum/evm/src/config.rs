@@ -1,6 +1,7 @@
 use alloy_consensus::Header;
 use reth_chainspec::{ChainSpec, EthereumHardforks};
 use reth_ethereum_forks::EthereumHardfork;
+use reth_evm::GasSchedule;
 use revm::primitives::hardfork::SpecId;
 
 /// Map the latest active hardfork at the given header to a revm [`SpecId`].
@@ -89,12 +90,46 @@ pub fn revm_spec_by_timestamp_and_block_number(
     }
 }
 
+/// Returns the [`GasSchedule`] of the latest active hardfork at the given timestamp or block
+/// number.
+pub fn gas_schedule_by_timestamp_and_block_number(
+    chain_spec: &ChainSpec,
+    timestamp: u64,
+    block_number: u64,
+) -> GasSchedule {
+    GasSchedule::from_spec_id(revm_spec_by_timestamp_and_block_number(
+        chain_spec,
+        timestamp,
+        block_number,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::U256;
     use reth_chainspec::{ChainSpecBuilder, MAINNET};
 
+    #[test]
+    fn test_gas_schedule_by_timestamp_and_block_number() {
+        let spec = ChainSpecBuilder::mainnet()
+            .shanghai_activated()
+            .with_fork(EthereumHardfork::Prague, reth_chainspec::ForkCondition::Timestamp(10))
+            .build();
+        assert_eq!(gas_schedule_by_timestamp_and_block_number(&spec, 0, 0), GasSchedule::Shanghai);
+        assert_eq!(gas_schedule_by_timestamp_and_block_number(&spec, 10, 0), GasSchedule::Prague);
+
+        let mainnet = ChainSpecBuilder::mainnet().build();
+        assert_eq!(
+            gas_schedule_by_timestamp_and_block_number(&mainnet, 0, 0),
+            GasSchedule::Frontier
+        );
+        assert_eq!(
+            gas_schedule_by_timestamp_and_block_number(&mainnet, 0, 9_069_000),
+            GasSchedule::Istanbul
+        );
+    }
+
     #[test]
     fn test_revm_spec_by_timestamp() {
         assert_eq!(
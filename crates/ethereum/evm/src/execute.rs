@@ -1,9 +1,25 @@
 //! Ethereum block execution strategy.
 
 use crate::EthEvmConfig;
-use alloc::sync::Arc;
-use reth_chainspec::ChainSpec;
+use alloc::{boxed::Box, string::ToString, sync::Arc};
+use alloy_consensus::{transaction::Recovered, Transaction, TxReceipt};
+use alloy_eips::{eip2935::HISTORY_STORAGE_ADDRESS, eip4788::SYSTEM_ADDRESS, Encodable2718};
+use alloy_evm::{
+    block::{
+        BlockExecutionError, BlockExecutor, BlockExecutorFactory, BlockExecutorFor,
+        BlockValidationError, OnStateHook, StateChangePreBlockSource, StateChangeSource,
+    },
+    eth::{receipt_builder::ReceiptBuilder, spec::EthExecutorSpec, EthBlockExecutionCtx},
+    Database, Evm, EvmFactory, FromRecoveredTx,
+};
+use alloy_primitives::{Address, Log};
+use core::fmt::Debug;
+use reth_chainspec::{
+    ChainSpec, EthChainSpec, EthereumHardfork, EthereumHardforks, ExecutionFeatures, ForkCondition,
+};
 use reth_evm::execute::BasicBlockExecutorProvider;
+use reth_execution_types::BlockExecutionResult;
+use revm::{context::result::ExecutionResult, database::State, DatabaseCommit, Inspector};
 
 /// Helper type with backwards compatible methods to obtain Ethereum executor
 /// providers.
@@ -22,6 +38,231 @@ impl EthExecutorProvider {
     }
 }
 
+/// The specification of a chain, with the hardforks that introduced the disabled
+/// [`ExecutionFeatures`] reported as inactive.
+///
+/// The [`alloy_evm::eth::EthBlockExecutor`] only checks these hardforks to apply the system calls
+/// and post block changes of the features, the EVM rules of the hardforks are configured
+/// separately.
+#[derive(Debug, Clone)]
+pub struct ExecutionFeaturesSpec<Spec> {
+    spec: Spec,
+    features: ExecutionFeatures,
+}
+
+impl<Spec: EthChainSpec> ExecutionFeaturesSpec<Spec> {
+    /// Creates the specification with the [`ExecutionFeatures`] of the chain.
+    pub fn new(spec: Spec) -> Self {
+        let features = spec.execution_features();
+        Self { spec, features }
+    }
+}
+
+impl<Spec: EthereumHardforks> EthereumHardforks for ExecutionFeaturesSpec<Spec> {
+    fn ethereum_fork_activation(&self, fork: EthereumHardfork) -> ForkCondition {
+        let enabled = match fork {
+            EthereumHardfork::Shanghai => self.features.withdrawals,
+            EthereumHardfork::Cancun => self.features.beacon_root,
+            EthereumHardfork::Prague => self.features.requests,
+            _ => true,
+        };
+        if enabled {
+            self.spec.ethereum_fork_activation(fork)
+        } else {
+            ForkCondition::Never
+        }
+    }
+}
+
+impl<Spec: EthExecutorSpec> EthExecutorSpec for ExecutionFeaturesSpec<Spec> {
+    fn deposit_contract_address(&self) -> Option<Address> {
+        self.spec.deposit_contract_address()
+    }
+}
+
+/// Block executor for Ethereum.
+///
+/// Executes blocks with the [`alloy_evm::eth::EthBlockExecutor`], skipping the system calls and
+/// post block changes of the [`ExecutionFeatures`] that are disabled on the chain.
+///
+/// Disabling the requests hides Prague from the inner executor, so the EIP-2935 blockhashes
+/// contract call, which is not part of the requests, is applied by this executor in that case.
+pub struct EthBlockExecutor<'a, Evm, Spec, R: ReceiptBuilder> {
+    /// The executor of the enabled features.
+    inner: alloy_evm::eth::EthBlockExecutor<'a, Evm, ExecutionFeaturesSpec<Spec>, R>,
+    /// Reference to the specification object.
+    spec: Spec,
+    /// The state hook, until it is handed to the inner executor after the pre-execution changes.
+    hook: Option<Box<dyn OnStateHook>>,
+    /// Whether the pre-execution changes were applied.
+    pre_execution_applied: bool,
+}
+
+impl<Evm, Spec: Debug, R: ReceiptBuilder> Debug for EthBlockExecutor<'_, Evm, Spec, R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EthBlockExecutor")
+            .field("spec", &self.spec)
+            .field("pre_execution_applied", &self.pre_execution_applied)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, Evm, Spec, R> EthBlockExecutor<'a, Evm, Spec, R>
+where
+    Spec: EthChainSpec + Clone,
+    R: ReceiptBuilder,
+{
+    /// Creates a new [`EthBlockExecutor`].
+    pub fn new(evm: Evm, ctx: EthBlockExecutionCtx<'a>, spec: Spec, receipt_builder: R) -> Self {
+        Self {
+            inner: alloy_evm::eth::EthBlockExecutor::new(
+                evm,
+                ctx,
+                ExecutionFeaturesSpec::new(spec.clone()),
+                receipt_builder,
+            ),
+            spec,
+            hook: None,
+            pre_execution_applied: false,
+        }
+    }
+}
+
+impl<'db, DB, E, Spec, R> BlockExecutor for EthBlockExecutor<'_, E, Spec, R>
+where
+    DB: Database + 'db,
+    E: Evm<DB = &'db mut State<DB>, Tx: FromRecoveredTx<R::Transaction>>,
+    Spec: EthExecutorSpec + EthChainSpec,
+    R: ReceiptBuilder<Transaction: Transaction + Encodable2718, Receipt: TxReceipt<Log = Log>>,
+{
+    type Transaction = R::Transaction;
+    type Receipt = R::Receipt;
+    type Evm = E;
+
+    fn apply_pre_execution_changes(&mut self) -> Result<(), BlockExecutionError> {
+        let block_number = self.inner.evm_mut().block().number;
+        let timestamp = self.inner.evm_mut().block().timestamp;
+
+        // EIP-2935 blockhashes contract call, if the inner executor skips Prague
+        if !self.spec.execution_features().requests &&
+            self.spec.is_prague_active_at_timestamp(timestamp) &&
+            block_number != 0
+        {
+            let state_clear_flag = self.spec.is_spurious_dragon_active_at_block(block_number);
+            let parent_hash = self.inner.ctx.parent_hash;
+            let evm = self.inner.evm_mut();
+            evm.db_mut().set_state_clear_flag(state_clear_flag);
+
+            let mut res = evm
+                .transact_system_call(SYSTEM_ADDRESS, HISTORY_STORAGE_ADDRESS, parent_hash.0.into())
+                .map_err(|err| BlockValidationError::BlockHashContractCall {
+                    message: err.to_string(),
+                })?;
+            res.state.remove(&SYSTEM_ADDRESS);
+            res.state.remove(&evm.block().beneficiary);
+
+            if let Some(hook) = &mut self.hook {
+                hook.on_state(
+                    StateChangeSource::PreBlock(StateChangePreBlockSource::BlockHashesContract),
+                    &res.state,
+                );
+            }
+            evm.db_mut().commit(res.state);
+        }
+
+        self.pre_execution_applied = true;
+        self.inner.set_state_hook(self.hook.take());
+        self.inner.apply_pre_execution_changes()
+    }
+
+    fn execute_transaction_with_result_closure(
+        &mut self,
+        tx: Recovered<&R::Transaction>,
+        f: impl FnOnce(&ExecutionResult<<Self::Evm as Evm>::HaltReason>),
+    ) -> Result<u64, BlockExecutionError> {
+        self.inner.execute_transaction_with_result_closure(tx, f)
+    }
+
+    fn finish(self) -> Result<(Self::Evm, BlockExecutionResult<R::Receipt>), BlockExecutionError> {
+        self.inner.finish()
+    }
+
+    fn set_state_hook(&mut self, hook: Option<Box<dyn OnStateHook>>) {
+        if self.pre_execution_applied {
+            self.inner.set_state_hook(hook);
+        } else {
+            self.hook = hook;
+        }
+    }
+
+    fn evm_mut(&mut self) -> &mut Self::Evm {
+        self.inner.evm_mut()
+    }
+}
+
+/// Ethereum block executor factory, creating [`EthBlockExecutor`]s.
+#[derive(Debug, Clone, Default, Copy)]
+pub struct EthBlockExecutorFactory<R, Spec, EvmFactory> {
+    /// Receipt builder.
+    receipt_builder: R,
+    /// Chain specification.
+    spec: Spec,
+    /// EVM factory.
+    evm_factory: EvmFactory,
+}
+
+impl<R, Spec, EvmFactory> EthBlockExecutorFactory<R, Spec, EvmFactory> {
+    /// Creates a new [`EthBlockExecutorFactory`] with the given spec, [`EvmFactory`], and
+    /// [`ReceiptBuilder`].
+    pub const fn new(receipt_builder: R, spec: Spec, evm_factory: EvmFactory) -> Self {
+        Self { receipt_builder, spec, evm_factory }
+    }
+
+    /// Exposes the receipt builder.
+    pub const fn receipt_builder(&self) -> &R {
+        &self.receipt_builder
+    }
+
+    /// Exposes the chain specification.
+    pub const fn spec(&self) -> &Spec {
+        &self.spec
+    }
+
+    /// Exposes the EVM factory.
+    pub const fn evm_factory(&self) -> &EvmFactory {
+        &self.evm_factory
+    }
+}
+
+impl<R, Spec, EvmF> BlockExecutorFactory for EthBlockExecutorFactory<R, Spec, EvmF>
+where
+    R: ReceiptBuilder<Transaction: Transaction + Encodable2718, Receipt: TxReceipt<Log = Log>>,
+    Spec: EthExecutorSpec + EthChainSpec,
+    EvmF: EvmFactory<Tx: FromRecoveredTx<R::Transaction>>,
+    Self: 'static,
+{
+    type EvmFactory = EvmF;
+    type ExecutionCtx<'a> = EthBlockExecutionCtx<'a>;
+    type Transaction = R::Transaction;
+    type Receipt = R::Receipt;
+
+    fn evm_factory(&self) -> &Self::EvmFactory {
+        &self.evm_factory
+    }
+
+    fn create_executor<'a, DB, I>(
+        &'a self,
+        evm: EvmF::Evm<&'a mut State<DB>, I>,
+        ctx: Self::ExecutionCtx<'a>,
+    ) -> impl BlockExecutorFor<'a, Self, DB, I>
+    where
+        DB: Database + 'a,
+        I: Inspector<EvmF::Context<&'a mut State<DB>>> + 'a,
+    {
+        EthBlockExecutor::new(evm, ctx, &self.spec, &self.receipt_builder)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -880,4 +1121,67 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn disabled_execution_features() {
+        let db = create_database_with_block_hashes(1);
+
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::from(&*MAINNET)
+                .shanghai_activated()
+                .cancun_activated()
+                .prague_activated()
+                .execution_features(ExecutionFeatures {
+                    beacon_root: false,
+                    withdrawals: false,
+                    requests: false,
+                })
+                .build(),
+        );
+
+        // the header has none of the fields of the disabled features
+        let header = Header {
+            parent_hash: B256::random(),
+            timestamp: 1,
+            number: 2,
+            excess_blob_gas: Some(0),
+            ..Header::default()
+        };
+        let withdrawal_recipient = address!("0x1000000000000000000000000000000000000001");
+        let body = BlockBody {
+            withdrawals: Some(
+                vec![Withdrawal {
+                    index: 0,
+                    validator_index: 0,
+                    address: withdrawal_recipient,
+                    amount: 1,
+                }]
+                .into(),
+            ),
+            ..Default::default()
+        };
+
+        let provider = executor_provider(chain_spec);
+        let mut executor = provider.executor(db);
+
+        // neither the beacon roots nor the requests contracts are deployed
+        let result = executor
+            .execute_one(&RecoveredBlock::new_unhashed(Block { header, body }, vec![]))
+            .expect("the system calls of the disabled features should be skipped");
+        assert!(result.requests.is_empty());
+
+        // the withdrawals are not applied
+        assert!(executor
+            .with_state_mut(|state| state.basic(withdrawal_recipient).unwrap())
+            .is_none_or(|account| account.balance.is_zero()));
+
+        // the EIP-2935 blockhashes contract call is applied even though the requests are disabled
+        executor.with_state_mut(|state| state.basic(HISTORY_STORAGE_ADDRESS).unwrap());
+        assert_ne!(
+            executor.with_state_mut(|state| state
+                .storage(HISTORY_STORAGE_ADDRESS, U256::from(1))
+                .unwrap()),
+            U256::ZERO
+        );
+    }
 }
@@ -20,15 +20,14 @@ extern crate alloc;
 use alloc::{borrow::Cow, sync::Arc};
 use alloy_consensus::{BlockHeader, Header};
 pub use alloy_evm::EthEvm;
-use alloy_evm::{
-    eth::{EthBlockExecutionCtx, EthBlockExecutorFactory},
-    EthEvmFactory, FromRecoveredTx,
-};
+use alloy_evm::{eth::EthBlockExecutionCtx, EthEvmFactory, FromRecoveredTx};
 use alloy_primitives::{Bytes, U256};
 use core::{convert::Infallible, fmt::Debug};
 use reth_chainspec::{ChainSpec, EthChainSpec, MAINNET};
 use reth_ethereum_primitives::{Block, EthPrimitives, TransactionSigned};
-use reth_evm::{ConfigureEvm, EvmEnv, EvmFactory, NextBlockEnvAttributes, TransactionEnv};
+use reth_evm::{
+    ConfigureEvm, EvmEnv, EvmFactory, GasSchedule, NextBlockEnvAttributes, TransactionEnv,
+};
 use reth_primitives_traits::{SealedBlock, SealedHeader};
 use revm::{
     context::{BlockEnv, CfgEnv},
@@ -38,17 +37,23 @@ use revm::{
 
 mod config;
 use alloy_eips::eip1559::INITIAL_BASE_FEE;
-pub use config::{revm_spec, revm_spec_by_timestamp_and_block_number};
+pub use config::{
+    gas_schedule_by_timestamp_and_block_number, revm_spec, revm_spec_by_timestamp_and_block_number,
+};
 use reth_ethereum_forks::EthereumHardfork;
 
 pub mod execute;
+use execute::EthBlockExecutorFactory;
 
 mod build;
-pub use build::EthBlockAssembler;
+pub use build::{EthBlockAssembler, ExtraDataSource};
 
 mod receipt;
 pub use receipt::RethReceiptBuilder;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+
 /// Ethereum-related EVM configuration.
 #[derive(Debug, Clone)]
 pub struct EthEvmConfig<EvmFactory = EthEvmFactory> {
@@ -93,11 +98,24 @@ impl<EvmFactory> EthEvmConfig<EvmFactory> {
         self.executor_factory.spec()
     }
 
+    /// Returns the [`GasSchedule`] that is used to charge the intrinsic gas of transactions in the
+    /// block with the given timestamp and number.
+    pub fn gas_schedule(&self, timestamp: u64, block_number: u64) -> GasSchedule {
+        gas_schedule_by_timestamp_and_block_number(self.chain_spec(), timestamp, block_number)
+    }
+
     /// Sets the extra data for the block assembler.
     pub fn with_extra_data(mut self, extra_data: Bytes) -> Self {
         self.block_assembler.extra_data = extra_data;
         self
     }
+
+    /// Sets the source of the extra data of each block for the block assembler, overriding
+    /// [`Self::with_extra_data`].
+    pub fn with_extra_data_source(mut self, source: impl ExtraDataSource + 'static) -> Self {
+        self.block_assembler.extra_data_source = Some(Arc::new(source));
+        self
+    }
 }
 
 impl<EvmF> ConfigureEvm for EthEvmConfig<EvmF>
@@ -235,11 +253,13 @@ where
 mod tests {
     use super::*;
     use alloy_consensus::Header;
+    use alloy_eips::eip2930::{AccessList, AccessListItem};
     use alloy_genesis::Genesis;
+    use alloy_primitives::{Address, TxKind, B256};
     use reth_chainspec::{Chain, ChainSpec};
-    use reth_evm::{execute::ProviderError, EvmEnv};
+    use reth_evm::{execute::ProviderError, Evm, EvmEnv, EvmError};
     use revm::{
-        context::{BlockEnv, CfgEnv},
+        context::{BlockEnv, CfgEnv, TxEnv},
         database::CacheDB,
         database_interface::EmptyDBTyped,
         inspector::NoOpInspector,
@@ -404,4 +424,82 @@ mod tests {
         assert_eq!(evm.cfg, evm_env.cfg_env);
         assert_eq!(evm.tx, Default::default());
     }
+
+    /// The minimum gas limit of every [`GasSchedule`] matches the intrinsic gas charged by the
+    /// EVM of its spec.
+    #[test]
+    fn test_gas_schedule_conformance() {
+        let evm_config = EthEvmConfig::mainnet();
+        let access_list = AccessList(vec![AccessListItem {
+            address: Address::with_last_byte(1),
+            storage_keys: vec![B256::ZERO; 2],
+        }]);
+
+        for schedule in [
+            GasSchedule::Frontier,
+            GasSchedule::Homestead,
+            GasSchedule::Istanbul,
+            GasSchedule::Berlin,
+            GasSchedule::Shanghai,
+            GasSchedule::Prague,
+        ] {
+            let mut txs = vec![
+                // mostly zero calldata, which is charged the floor cost since Prague
+                TxEnv { data: Bytes::from_static(&[0, 0, 0, 1]), ..Default::default() },
+                TxEnv {
+                    kind: TxKind::Create,
+                    data: Bytes::from(vec![1; 100]),
+                    ..Default::default()
+                },
+            ];
+            if schedule >= GasSchedule::Berlin {
+                txs.push(TxEnv {
+                    tx_type: 1,
+                    access_list: access_list.clone(),
+                    ..Default::default()
+                });
+            }
+
+            for tx in txs {
+                let min_gas_limit = schedule.min_gas_limit(
+                    &tx.data,
+                    tx.kind.is_create(),
+                    tx.access_list.len() as u64,
+                    tx.access_list.iter().map(|item| item.storage_keys.len() as u64).sum(),
+                    0,
+                );
+                let transact = |gas_limit| {
+                    let evm_env = EvmEnv {
+                        cfg_env: CfgEnv::new().with_spec(schedule.spec_id()),
+                        ..Default::default()
+                    };
+                    let db = CacheDB::<EmptyDBTyped<ProviderError>>::default();
+                    evm_config.evm_with_env(db, evm_env).transact(TxEnv { gas_limit, ..tx.clone() })
+                };
+
+                let err = transact(min_gas_limit - 1).unwrap_err();
+                assert!(err.as_invalid_tx_err().is_some(), "{schedule:?}: {err:?}");
+                assert!(transact(min_gas_limit).is_ok(), "{schedule:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_extra_data_source() {
+        #[derive(Debug)]
+        struct EvenBlocks;
+
+        impl ExtraDataSource for EvenBlocks {
+            fn extra_data(&self, number: u64, timestamp: u64) -> Option<Bytes> {
+                (number % 2 == 0).then(|| Bytes::from(format!("{number}/{timestamp}")))
+            }
+        }
+
+        let evm_config = EthEvmConfig::mainnet().with_extra_data(Bytes::from_static(b"reth"));
+        assert_eq!(evm_config.block_assembler().extra_data(2, 12), Bytes::from_static(b"reth"));
+
+        let evm_config = evm_config.with_extra_data_source(EvenBlocks);
+        assert_eq!(evm_config.block_assembler().extra_data(2, 12), Bytes::from_static(b"2/12"));
+        assert_eq!(evm_config.block_assembler().extra_data(3, 24), Bytes::from_static(b"reth"));
+    }
 }
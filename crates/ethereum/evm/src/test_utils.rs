@@ -0,0 +1,463 @@
+//! Deterministic chain generation with real EVM activity.
+//!
+//! Unlike the generators in `reth_testing_utils::generators`, which produce blocks that are not
+//! valid if executed, [`EvmChainGenerator`] executes every generated block and fills in the gas
+//! used, receipts root, logs bloom and state root, so the resulting chain can be imported and
+//! validated like a real one.
+
+use crate::EthEvmConfig;
+use alloc::{collections::BTreeMap, sync::Arc, vec, vec::Vec};
+use alloy_consensus::{
+    proofs::{calculate_transaction_root, calculate_withdrawals_root},
+    BlockHeader, Header, TxEip1559, EMPTY_OMMER_ROOT_HASH,
+};
+use alloy_eips::eip1559::ETHEREUM_BLOCK_GAS_LIMIT_30M;
+use alloy_genesis::{Genesis, GenesisAccount};
+use alloy_primitives::{address, b256, bytes, logs_bloom, Address, Bytes, TxKind, B256, U256};
+use rand::{rngs::StdRng, seq::SliceRandom, Rng};
+use reth_chainspec::{Chain, ChainSpec, ChainSpecBuilder, EthChainSpec};
+use reth_ethereum_primitives::{Block, BlockBody, Receipt, Transaction};
+use reth_evm::execute::{
+    BasicBlockExecutorProvider, BlockExecutionError, BlockExecutorProvider, Executor,
+};
+use reth_primitives_traits::{
+    crypto::secp256k1::public_key_to_address, RecoveredBlock, SealedBlock, SealedHeader,
+};
+use reth_testing_utils::generators::{generate_keys, rng_with_seed, sign_tx_with_key_pair};
+use reth_trie_common::{
+    root::{state_root_unhashed, storage_root_unhashed},
+    TrieAccount,
+};
+use revm::{
+    database::{AccountState, BundleState, DbAccount, InMemoryDB, WrapDatabaseRef},
+    primitives::KECCAK_EMPTY,
+    state::{AccountInfo, Bytecode},
+};
+use secp256k1::Keypair;
+
+/// Address of the token contract deployed at genesis.
+pub const TOKEN_ADDRESS: Address = address!("0x0000000000000000000000000000000000007e57");
+
+/// Address of the storage writer contract deployed at genesis.
+pub const STORAGE_WRITER_ADDRESS: Address = address!("0x000000000000000000000000000000000005707e");
+
+/// Fee recipient of all generated blocks.
+pub const FEE_RECIPIENT: Address = address!("0x00000000000000000000000000000000000fee00");
+
+/// Signature of the `Transfer(address,address,uint256)` event emitted by the token contract.
+pub const TRANSFER_EVENT: B256 =
+    b256!("0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+/// Minimal token contract.
+///
+/// Takes the recipient and the amount as two abi encoded words, moves the amount from the
+/// caller's balance slot (the slot keyed by the caller address) to the recipient's balance slot
+/// and emits a `Transfer` event. Reverts if the caller's balance is insufficient.
+const TOKEN_CODE: Bytes = bytes!("0x6020353354818110604a570333556000358054602035019055602035600052600035337fddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef60206000a3005b600080fd");
+
+/// Contract that writes the current block number to `count` consecutive slots starting at
+/// `start`, both passed as abi encoded words.
+const STORAGE_WRITER_CODE: Bytes =
+    bytes!("0x6000356020355b8015601b574382556001900390600101906006565b00");
+
+/// Init code that deploys a contract which self-destructs to its caller.
+const SELFDESTRUCT_DEPLOYER_CODE: Bytes = bytes!("0x6133ff6000526002601ef3");
+
+/// Init code that self-destructs during contract creation.
+const EPHEMERAL_CREATE_CODE: Bytes = bytes!("0x33ff");
+
+/// Token balance of every generated account at genesis.
+const INITIAL_TOKEN_BALANCE: U256 = U256::from_limbs([0, 0, 1, 0]);
+
+/// Ether balance of every generated account at genesis.
+const INITIAL_BALANCE: U256 = U256::from_limbs([0, 0, 1, 0]);
+
+/// Timestamp difference between two consecutive blocks.
+const BLOCK_TIME: u64 = 12;
+
+/// A kind of transaction that [`EvmChainGenerator`] generates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratedTxKind {
+    /// Plain ether transfer between two accounts.
+    Transfer,
+    /// Token transfer that updates two balance slots and emits a log.
+    TokenTransfer,
+    /// Call that writes a number of storage slots.
+    StorageWrites,
+    /// Deployment of a contract that is kept in state.
+    Create,
+    /// Contract creation that self-destructs within the same transaction.
+    CreateSelfDestruct,
+}
+
+impl GeneratedTxKind {
+    /// All transaction kinds.
+    pub const ALL: [Self; 5] = [
+        Self::Transfer,
+        Self::TokenTransfer,
+        Self::StorageWrites,
+        Self::Create,
+        Self::CreateSelfDestruct,
+    ];
+}
+
+/// Configuration of an [`EvmChainGenerator`].
+#[derive(Debug, Clone)]
+pub struct EvmChainGeneratorConfig {
+    /// Seed for all randomness, the same seed always yields the same chain.
+    pub seed: Vec<u8>,
+    /// Number of funded accounts sending transactions.
+    pub accounts: usize,
+    /// Gas that the transactions of every block are allowed to use at most.
+    ///
+    /// The block gas limit is set to twice this value (but at least 30M), so the base fee never
+    /// increases.
+    pub target_gas_per_block: u64,
+    /// Number of storage slots written by a [`GeneratedTxKind::StorageWrites`] transaction.
+    pub slots_per_storage_write: u64,
+    /// Kinds of transactions to pick from, uniformly.
+    ///
+    /// If empty, the generated blocks contain no transactions.
+    pub tx_kinds: Vec<GeneratedTxKind>,
+}
+
+impl Default for EvmChainGeneratorConfig {
+    fn default() -> Self {
+        Self {
+            seed: b"reth".to_vec(),
+            accounts: 16,
+            target_gas_per_block: 3_000_000,
+            slots_per_storage_write: 10,
+            tx_kinds: GeneratedTxKind::ALL.to_vec(),
+        }
+    }
+}
+
+/// Generates a deterministic chain of Cancun blocks whose transactions perform real EVM work.
+///
+/// Generated blocks are executed on top of an in-memory state, so their gas used, receipts root,
+/// logs bloom and state root are correct.
+#[derive(Debug)]
+pub struct EvmChainGenerator {
+    /// Generator configuration.
+    config: EvmChainGeneratorConfig,
+    /// Source of all randomness.
+    rng: StdRng,
+    /// Chain spec of the generated chain, including the genesis allocation.
+    chain_spec: Arc<ChainSpec>,
+    /// Configuration used to execute generated blocks.
+    evm_config: EthEvmConfig,
+    /// Funded accounts and their next nonce.
+    accounts: Vec<(Keypair, Address, u64)>,
+    /// Full state after the latest generated block.
+    state: InMemoryDB,
+    /// Latest generated header.
+    head: SealedHeader,
+}
+
+impl EvmChainGenerator {
+    /// Creates a new generator and the genesis state for the given configuration.
+    pub fn new(config: EvmChainGeneratorConfig) -> Self {
+        let mut rng = rng_with_seed(&config.seed);
+        let keys = generate_keys(&mut rng, config.accounts.max(1));
+        let accounts = keys
+            .into_iter()
+            .map(|key| (key, public_key_to_address(key.public_key()), 0))
+            .collect::<Vec<_>>();
+
+        let mut alloc = BTreeMap::new();
+        let mut token_storage = BTreeMap::new();
+        for (_, address, _) in &accounts {
+            alloc.insert(*address, GenesisAccount::default().with_balance(INITIAL_BALANCE));
+            token_storage.insert(address.into_word(), B256::from(INITIAL_TOKEN_BALANCE));
+        }
+        alloc.insert(
+            TOKEN_ADDRESS,
+            GenesisAccount::default().with_code(Some(TOKEN_CODE)).with_storage(Some(token_storage)),
+        );
+        alloc.insert(
+            STORAGE_WRITER_ADDRESS,
+            GenesisAccount::default().with_code(Some(STORAGE_WRITER_CODE)),
+        );
+
+        let gas_limit =
+            config.target_gas_per_block.saturating_mul(2).max(ETHEREUM_BLOCK_GAS_LIMIT_30M);
+        let genesis = Genesis { gas_limit, ..Default::default() }.extend_accounts(alloc);
+        let chain_spec = Arc::new(
+            ChainSpecBuilder::default()
+                .chain(Chain::dev())
+                .genesis(genesis)
+                .cancun_activated()
+                .build(),
+        );
+
+        let mut state = InMemoryDB::default();
+        for (address, account) in &chain_spec.genesis().alloc {
+            let mut info = AccountInfo {
+                balance: account.balance,
+                nonce: account.nonce.unwrap_or_default(),
+                code_hash: KECCAK_EMPTY,
+                code: account.code.clone().map(Bytecode::new_raw),
+            };
+            state.insert_contract(&mut info);
+            let storage = account
+                .storage
+                .iter()
+                .flatten()
+                .map(|(slot, value)| (U256::from_be_bytes(slot.0), U256::from_be_bytes(value.0)))
+                .collect();
+            state
+                .cache
+                .accounts
+                .insert(*address, DbAccount { info, account_state: AccountState::None, storage });
+        }
+
+        let head = chain_spec.sealed_genesis_header();
+        state.cache.block_hashes.insert(U256::ZERO, head.hash());
+        let evm_config = EthEvmConfig::new(chain_spec.clone());
+
+        Self { config, rng, chain_spec, evm_config, accounts, state, head }
+    }
+
+    /// Returns the chain spec of the generated chain.
+    pub const fn chain_spec(&self) -> &Arc<ChainSpec> {
+        &self.chain_spec
+    }
+
+    /// Returns the latest generated header, or the genesis header if no block was generated yet.
+    pub const fn head(&self) -> &SealedHeader {
+        &self.head
+    }
+
+    /// Returns the full state after the latest generated block.
+    pub const fn state(&self) -> &InMemoryDB {
+        &self.state
+    }
+
+    /// Computes the state root of the full in-memory state.
+    pub fn state_root(&self) -> B256 {
+        state_root_of(&self.state)
+    }
+
+    /// Generates the given number of blocks on top of the current head.
+    pub fn generate_blocks(
+        &mut self,
+        count: u64,
+    ) -> Result<Vec<RecoveredBlock<Block>>, BlockExecutionError> {
+        (0..count).map(|_| self.generate_block()).collect()
+    }
+
+    /// Generates, executes and returns the next block.
+    pub fn generate_block(&mut self) -> Result<RecoveredBlock<Block>, BlockExecutionError> {
+        let parent = self.head.clone();
+        let timestamp = parent.timestamp() + BLOCK_TIME;
+        let base_fee = parent
+            .next_block_base_fee(self.chain_spec.base_fee_params_at_timestamp(timestamp))
+            .unwrap_or_default();
+
+        let mut transactions = Vec::new();
+        let mut senders = Vec::new();
+        let mut gas = 0;
+        loop {
+            let Some(&kind) = self.config.tx_kinds.choose(&mut self.rng) else { break };
+            let (to, input, gas_limit) = self.tx_call(kind);
+            if gas + gas_limit > self.config.target_gas_per_block {
+                break
+            }
+            gas += gas_limit;
+
+            let sender = self.rng.gen_range(0..self.accounts.len());
+            let (key, address, nonce) = &mut self.accounts[sender];
+            let tx = Transaction::Eip1559(TxEip1559 {
+                chain_id: self.chain_spec.chain().id(),
+                nonce: *nonce,
+                gas_limit,
+                max_fee_per_gas: base_fee as u128 * 2 + 1,
+                max_priority_fee_per_gas: 1,
+                to,
+                value: if kind == GeneratedTxKind::Transfer { U256::from(1) } else { U256::ZERO },
+                input,
+                ..Default::default()
+            });
+            *nonce += 1;
+            transactions.push(sign_tx_with_key_pair(*key, tx));
+            senders.push(*address);
+        }
+
+        let mut header = Header {
+            parent_hash: parent.hash(),
+            ommers_hash: EMPTY_OMMER_ROOT_HASH,
+            beneficiary: FEE_RECIPIENT,
+            transactions_root: calculate_transaction_root(&transactions),
+            number: parent.number() + 1,
+            gas_limit: parent.gas_limit(),
+            timestamp,
+            mix_hash: self.rng.gen(),
+            base_fee_per_gas: Some(base_fee),
+            withdrawals_root: Some(calculate_withdrawals_root(&[])),
+            blob_gas_used: Some(0),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(self.rng.gen()),
+            ..Default::default()
+        };
+        let body = BlockBody { transactions, ommers: Vec::new(), withdrawals: Some(vec![].into()) };
+        // the outputs of the execution are not inputs to it, so they can be filled in afterwards
+        let block = RecoveredBlock::new_sealed(
+            SealedBlock::from_sealed_parts(SealedHeader::seal_slow(header.clone()), body.clone()),
+            senders.clone(),
+        );
+        let output = BasicBlockExecutorProvider::new(self.evm_config.clone())
+            .executor(WrapDatabaseRef(&self.state))
+            .execute(&block)?;
+        apply_bundle(&mut self.state, &output.state);
+
+        header.gas_used = output.result.gas_used;
+        header.receipts_root = Receipt::calculate_receipt_root_no_memo(&output.result.receipts);
+        header.logs_bloom = logs_bloom(output.result.receipts.iter().flat_map(|r| &r.logs));
+        header.state_root = self.state_root();
+
+        let header = SealedHeader::seal_slow(header);
+        self.state.cache.block_hashes.insert(U256::from(header.number), header.hash());
+        self.head = header.clone();
+
+        Ok(RecoveredBlock::new_sealed(SealedBlock::from_sealed_parts(header, body), senders))
+    }
+
+    /// Returns the recipient, input and gas limit of a transaction of the given kind.
+    fn tx_call(&mut self, kind: GeneratedTxKind) -> (TxKind, Bytes, u64) {
+        match kind {
+            GeneratedTxKind::Transfer => {
+                let to = self.accounts[self.rng.gen_range(0..self.accounts.len())].1;
+                (TxKind::Call(to), Bytes::new(), 21_000)
+            }
+            GeneratedTxKind::TokenTransfer => {
+                let to = self.accounts[self.rng.gen_range(0..self.accounts.len())].1;
+                let amount = U256::from(self.rng.gen_range(1..1_000u64));
+                let input = [to.into_word().0, amount.to_be_bytes::<32>()].concat();
+                (TxKind::Call(TOKEN_ADDRESS), input.into(), 100_000)
+            }
+            GeneratedTxKind::StorageWrites => {
+                let slots = self.config.slots_per_storage_write;
+                let start = U256::from(self.rng.gen_range(0..10_000u64));
+                let input = [start.to_be_bytes::<32>(), U256::from(slots).to_be_bytes()].concat();
+                (TxKind::Call(STORAGE_WRITER_ADDRESS), input.into(), 50_000 + slots * 25_000)
+            }
+            GeneratedTxKind::Create => (TxKind::Create, SELFDESTRUCT_DEPLOYER_CODE, 100_000),
+            GeneratedTxKind::CreateSelfDestruct => (TxKind::Create, EPHEMERAL_CREATE_CODE, 100_000),
+        }
+    }
+}
+
+/// Applies the state changes of an executed block to the in-memory state.
+fn apply_bundle(state: &mut InMemoryDB, bundle: &BundleState) {
+    for (address, account) in bundle.state() {
+        let Some(mut info) = account.info.clone() else {
+            state.cache.accounts.remove(address);
+            continue
+        };
+        state.insert_contract(&mut info);
+
+        let db_account = state.cache.accounts.entry(*address).or_default();
+        if account.was_destroyed() {
+            db_account.storage.clear();
+        }
+        db_account.info = info;
+        db_account.account_state = AccountState::None;
+        for (slot, value) in &account.storage {
+            db_account.storage.insert(*slot, value.present_value);
+        }
+    }
+}
+
+/// Computes the state root of the full in-memory state.
+fn state_root_of(state: &InMemoryDB) -> B256 {
+    state_root_unhashed(state.cache.accounts.iter().map(|(address, account)| {
+        let storage_root = storage_root_unhashed(
+            account
+                .storage
+                .iter()
+                .filter(|(_, value)| !value.is_zero())
+                .map(|(slot, value)| (B256::from(*slot), *value)),
+        );
+        let trie_account = TrieAccount {
+            nonce: account.info.nonce,
+            balance: account.info.balance,
+            storage_root,
+            code_hash: account.info.code_hash,
+        };
+        (*address, trie_account)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxReceipt;
+
+    fn generator() -> EvmChainGenerator {
+        EvmChainGenerator::new(EvmChainGeneratorConfig {
+            target_gas_per_block: 2_000_000,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn genesis_state_root_matches_chain_spec() {
+        let generator = generator();
+        assert_eq!(generator.state_root(), generator.chain_spec().genesis_header().state_root);
+    }
+
+    #[test]
+    fn generated_chain_is_deterministic() {
+        let blocks_a = generator().generate_blocks(3).unwrap();
+        let blocks_b = generator().generate_blocks(3).unwrap();
+        assert_eq!(
+            blocks_a.iter().map(|b| b.hash()).collect::<Vec<_>>(),
+            blocks_b.iter().map(|b| b.hash()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn generated_blocks_reexecute_to_same_roots() {
+        let mut generator = generator();
+        let blocks = generator.generate_blocks(5).unwrap();
+
+        let mut state = EvmChainGenerator::new(generator.config.clone()).state;
+        let provider = BasicBlockExecutorProvider::new(generator.evm_config.clone());
+        let mut transfer_logs = 0;
+        for block in &blocks {
+            assert!(!block.body().transactions.is_empty());
+            let output = provider.executor(WrapDatabaseRef(&state)).execute(block).unwrap();
+            assert!(output.result.receipts.iter().all(|receipt| receipt.status()));
+            transfer_logs += output
+                .result
+                .receipts
+                .iter()
+                .flat_map(|receipt| &receipt.logs)
+                .filter(|log| log.topics().first() == Some(&TRANSFER_EVENT))
+                .count();
+
+            apply_bundle(&mut state, &output.state);
+            state.cache.block_hashes.insert(U256::from(block.number), block.hash());
+
+            assert_eq!(output.result.gas_used, block.gas_used);
+            assert_eq!(
+                Receipt::calculate_receipt_root_no_memo(&output.result.receipts),
+                block.receipts_root
+            );
+            assert_eq!(state_root_of(&state), block.state_root);
+        }
+        assert!(transfer_logs > 0);
+        assert_eq!(state_root_of(&state), generator.state_root());
+    }
+
+    #[test]
+    fn no_tx_kinds_generate_empty_blocks() {
+        let mut generator = EvmChainGenerator::new(EvmChainGeneratorConfig {
+            tx_kinds: Vec::new(),
+            ..Default::default()
+        });
+        let blocks = generator.generate_blocks(2).unwrap();
+        assert!(blocks.iter().all(|block| block.body().transactions.is_empty()));
+    }
+}
@@ -5,13 +5,14 @@ pub use alloy_rpc_types_engine::{
     ExecutionPayloadEnvelopeV2, ExecutionPayloadEnvelopeV3, ExecutionPayloadEnvelopeV4,
     ExecutionPayloadV1, PayloadAttributes as EthPayloadAttributes,
 };
-use reth_chainspec::ChainSpec;
+use reth_chainspec::{ChainSpec, EthChainSpec};
 use reth_engine_primitives::{EngineTypes, EngineValidator, PayloadValidator};
 use reth_ethereum_payload_builder::EthereumExecutionPayloadValidator;
 use reth_ethereum_primitives::Block;
 use reth_payload_primitives::{
     validate_execution_requests, validate_version_specific_fields, EngineApiMessageVersion,
     EngineObjectValidationError, NewPayloadError, PayloadOrAttributes,
+    VersionSpecificValidationError,
 };
 use reth_primitives_traits::RecoveredBlock;
 use std::sync::Arc;
@@ -70,6 +71,15 @@ where
         version: EngineApiMessageVersion,
         attributes: &EthPayloadAttributes,
     ) -> Result<(), EngineObjectValidationError> {
+        // withdrawals are not applied if they are disabled on the chain
+        if !self.chain_spec().execution_features().withdrawals &&
+            attributes.withdrawals.as_ref().is_some_and(|withdrawals| !withdrawals.is_empty())
+        {
+            return Err(EngineObjectValidationError::PayloadAttributes(
+                VersionSpecificValidationError::HasWithdrawalsPreShanghai,
+            ))
+        }
+
         validate_version_specific_fields(
             self.chain_spec(),
             version,
@@ -4,10 +4,13 @@ pub use crate::{payload::EthereumPayloadBuilder, EthereumEngineValidator};
 use crate::{EthEngineTypes, EthEvmConfig};
 use reth_chainspec::ChainSpec;
 use reth_consensus::{ConsensusError, FullConsensus};
-use reth_ethereum_consensus::EthBeaconConsensus;
+use reth_ethereum_consensus::{BlockValidationPolicy, EthBeaconConsensus};
 use reth_ethereum_engine_primitives::{
     EthBuiltPayload, EthPayloadAttributes, EthPayloadBuilderAttributes,
 };
+use reth_ethereum_payload_builder::{
+    EthereumBuilderConfig, EthereumBuilderSettings, Preconfirmations,
+};
 use reth_ethereum_primitives::{EthPrimitives, PooledTransaction};
 use reth_evm::{
     execute::BasicBlockExecutorProvider, ConfigureEvm, EvmFactory, EvmFactoryFor,
@@ -29,15 +32,18 @@ use reth_node_builder::{
     PayloadTypes,
 };
 use reth_provider::{providers::ProviderFactoryBuilder, CanonStateSubscriptions, EthStorage};
-use reth_rpc::{eth::core::EthApiFor, ValidationApi};
-use reth_rpc_api::{eth::FullEthApiServer, servers::BlockSubmissionValidationApiServer};
+use reth_rpc::{eth::core::EthApiFor, AdminBuilderApi, RethPayloadApi, ValidationApi};
+use reth_rpc_api::{
+    eth::FullEthApiServer,
+    servers::{AdminBuilderApiServer, BlockSubmissionValidationApiServer, RethPayloadApiServer},
+};
 use reth_rpc_builder::config::RethRpcServerConfig;
 use reth_rpc_eth_types::{error::FromEvmError, EthApiError, EthConfig, EthStateCache};
 use reth_rpc_server_types::RethRpcModule;
 use reth_tracing::tracing::{debug, info};
 use reth_transaction_pool::{
-    blobstore::DiskFileBlobStore, EthTransactionPool, PoolTransaction, TransactionPool,
-    TransactionValidationTaskExecutor,
+    blobstore::{DiskFileBlobStore, DiskFileBlobStoreConfig},
+    EthTransactionPool, PoolTransaction, TransactionPool, TransactionValidationTaskExecutor,
 };
 use reth_trie_db::MerklePatriciaTrie;
 use revm::context::TxEnv;
@@ -149,6 +155,10 @@ where
         .task_spawner(core_components.task_executor().clone())
         .gas_cap(config.rpc_gas_cap.into())
         .max_simulate_blocks(config.rpc_max_simulate_blocks)
+        .call_env_overrides(config.call_env_overrides)
+        .tx_lookup(config.tx_lookup)
+        .rpc_chain_id(config.rpc_chain_id)
+        .syncing_stage_detail(config.syncing_stage_detail)
         .eth_proof_window(config.eth_proof_window)
         .fee_history_cache_config(config.fee_history_cache)
         .proof_permits(config.proof_permits)
@@ -163,6 +173,35 @@ where
     EthApiFor<N>: FullEthApiServer<Provider = N::Provider, Pool = N::Pool>,
 {
     inner: RpcAddOns<N, EthereumEthApiBuilder, EthereumEngineValidatorBuilder>,
+    /// Payload builder settings that are exposed in the `admin` RPC namespace, if they are shared
+    /// with the payload builder.
+    builder_settings: Option<EthereumBuilderSettings>,
+    /// Preconfirmations that the `reth_preconfirmTransaction` endpoint reserves block gas for, if
+    /// they are shared with the payload builder.
+    preconfirmations: Option<Preconfirmations>,
+}
+
+impl<N: FullNodeComponents> EthereumAddOns<N>
+where
+    EthApiFor<N>: FullEthApiServer<Provider = N::Provider, Pool = N::Pool>,
+{
+    /// Configures the payload builder settings that can be changed via the `admin` RPC namespace.
+    ///
+    /// The settings must be shared with the payload builder, see
+    /// [`EthereumPayloadBuilder::with_settings`].
+    pub fn with_builder_settings(mut self, builder_settings: EthereumBuilderSettings) -> Self {
+        self.builder_settings = Some(builder_settings);
+        self
+    }
+
+    /// Configures the preconfirmations of the `reth_preconfirmTransaction` endpoint.
+    ///
+    /// The preconfirmations must be shared with the payload builder, see
+    /// [`EthereumPayloadBuilder::with_preconfirmations`].
+    pub fn with_preconfirmations(mut self, preconfirmations: Preconfirmations) -> Self {
+        self.preconfirmations = Some(preconfirmations);
+        self
+    }
 }
 
 impl<N: FullNodeComponents> Default for EthereumAddOns<N>
@@ -170,7 +209,11 @@ where
     EthApiFor<N>: FullEthApiServer<Provider = N::Provider, Pool = N::Pool>,
 {
     fn default() -> Self {
-        Self { inner: Default::default() }
+        Self {
+            inner: Default::default(),
+            builder_settings: Default::default(),
+            preconfirmations: Default::default(),
+        }
     }
 }
 
@@ -202,12 +245,98 @@ where
             Arc::new(EthereumEngineValidator::new(ctx.config.chain.clone())),
         );
 
+        // builds payloads like the payload builder of the node for `reth_buildPayload`
+        let payload_builder = {
+            let conf = &ctx.config.builder;
+            let settings = self.builder_settings.clone().unwrap_or_else(|| {
+                let settings = EthereumBuilderSettings::default();
+                settings.set_fee_recipients(conf.fee_recipients().to_vec());
+                settings
+            });
+            reth_ethereum_payload_builder::EthereumPayloadBuilder::new(
+                ctx.node.provider().clone(),
+                ctx.node.pool().clone(),
+                ctx.node.evm_config().clone(),
+                EthereumBuilderConfig::new()
+                    .with_gas_limit(conf.gas_limit())
+                    .with_settings(settings)
+                    .with_preconfirmations(self.preconfirmations.clone().unwrap_or_default()),
+            )
+        };
+
+        let admin_builder_api = self.builder_settings.map(AdminBuilderApi::new);
+        #[cfg(not(feature = "builder-api"))]
+        if ctx.config.rpc.auth_builder_api {
+            eyre::bail!(
+                "--authrpc.builder-api requires reth to be built with the `builder-api` feature"
+            );
+        }
+        #[cfg(feature = "builder-api")]
+        let builder_api = ctx.config.rpc.auth_builder_api.then(|| {
+            reth_rpc::BuilderApi::new(
+                ctx.node.payload_builder_handle().clone(),
+                Box::new(ctx.node.task_executor().clone()),
+            )
+        });
+
+        let preconf_key = ctx.config.rpc.preconf_secret_key()?;
+        #[cfg(not(feature = "preconf"))]
+        if preconf_key.is_some() {
+            eyre::bail!("--rpc.preconf-key requires reth to be built with the `preconf` feature");
+        }
+        #[cfg(feature = "preconf")]
+        let preconf = preconf_key
+            .map(|secret_key| {
+                let Some(preconfirmations) = self.preconfirmations else {
+                    eyre::bail!(
+                        "--rpc.preconf-key requires the preconfirmations to be shared with the payload builder"
+                    )
+                };
+                eyre::Ok((
+                    alloy_signer_local::PrivateKeySigner::from_slice(&secret_key.secret_bytes())?,
+                    ctx.node.payload_builder_handle().clone(),
+                    preconfirmations,
+                    ctx.node.task_executor().clone(),
+                ))
+            })
+            .transpose()?;
+
         self.inner
-            .launch_add_ons_with(ctx, move |modules, _| {
+            .launch_add_ons_with(ctx, move |modules, _auth_modules, registry| {
                 modules.merge_if_module_configured(
                     RethRpcModule::Flashbots,
                     validation_api.into_rpc(),
                 )?;
+                modules.merge_if_module_configured(
+                    RethRpcModule::Reth,
+                    RethPayloadApi::new(registry.eth_api().clone(), payload_builder).into_rpc(),
+                )?;
+                if let Some(admin_builder_api) = admin_builder_api {
+                    modules.merge_if_module_configured(
+                        RethRpcModule::Admin,
+                        admin_builder_api.into_rpc(),
+                    )?;
+                }
+                #[cfg(feature = "builder-api")]
+                if let Some(builder_api) = builder_api {
+                    use reth_rpc_api::servers::BuilderApiServer;
+
+                    _auth_modules.merge_auth_methods(builder_api.into_rpc())?;
+                }
+                #[cfg(feature = "preconf")]
+                if let Some((signer, payload_builder, preconfirmations, executor)) = preconf {
+                    use reth_rpc_api::servers::RethPreconfApiServer;
+
+                    info!(target: "reth::cli", signer = %signer.address(), "Enabling preconfirmations");
+                    let preconf_api = reth_rpc::RethPreconfApi::new(
+                        registry.eth_api().clone(),
+                        payload_builder,
+                        preconfirmations,
+                        signer,
+                        Box::new(executor),
+                    );
+                    modules.merge_if_module_configured(RethRpcModule::Reth, preconf_api.into_rpc())?;
+                }
 
                 Ok(())
             })
@@ -343,7 +472,12 @@ where
     async fn build_pool(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::Pool> {
         let data_dir = ctx.config().datadir();
         let pool_config = ctx.pool_config();
-        let blob_store = DiskFileBlobStore::open(data_dir.blobstore(), Default::default())?;
+        let mut blob_store_config = DiskFileBlobStoreConfig::default();
+        if let Some(location) = &ctx.config().txpool.blob_archive {
+            blob_store_config = blob_store_config.with_archive(location.open()?);
+            info!(target: "reth::cli", %location, "Blob archive enabled");
+        }
+        let blob_store = DiskFileBlobStore::open(data_dir.blobstore(), blob_store_config)?;
         let validator = TransactionValidationTaskExecutor::eth_builder(ctx.provider().clone())
             .with_head_timestamp(ctx.head().timestamp)
             .kzg_settings(ctx.kzg_settings()?)
@@ -427,7 +561,21 @@ where
 /// A basic ethereum consensus builder.
 #[derive(Debug, Default, Clone, Copy)]
 pub struct EthereumConsensusBuilder {
-    // TODO add closure to modify consensus
+    /// Checks that are performed on blocks.
+    validation_policy: BlockValidationPolicy,
+}
+
+impl EthereumConsensusBuilder {
+    /// Sets the [`BlockValidationPolicy`] of the consensus.
+    ///
+    /// This can be used for chains that deviate from the Ethereum mainnet block validation rules.
+    pub const fn with_validation_policy(
+        mut self,
+        validation_policy: BlockValidationPolicy,
+    ) -> Self {
+        self.validation_policy = validation_policy;
+        self
+    }
 }
 
 impl<Node> ConsensusBuilder<Node> for EthereumConsensusBuilder
@@ -437,7 +585,10 @@ where
     type Consensus = Arc<dyn FullConsensus<EthPrimitives, Error = ConsensusError>>;
 
     async fn build_consensus(self, ctx: &BuilderContext<Node>) -> eyre::Result<Self::Consensus> {
-        Ok(Arc::new(EthBeaconConsensus::new(ctx.chain_spec())))
+        Ok(Arc::new(
+            EthBeaconConsensus::new(ctx.chain_spec())
+                .with_validation_policy(self.validation_policy),
+        ))
     }
 }
 
@@ -4,7 +4,9 @@ use reth_chainspec::ChainSpec;
 use reth_ethereum_engine_primitives::{
     EthBuiltPayload, EthPayloadAttributes, EthPayloadBuilderAttributes,
 };
-use reth_ethereum_payload_builder::EthereumBuilderConfig;
+use reth_ethereum_payload_builder::{
+    EthereumBuilderConfig, EthereumBuilderSettings, Preconfirmations,
+};
 use reth_ethereum_primitives::EthPrimitives;
 use reth_evm::ConfigureEvm;
 use reth_evm_ethereum::EthEvmConfig;
@@ -17,9 +19,31 @@ use reth_transaction_pool::{PoolTransaction, TransactionPool};
 /// A basic ethereum payload service.
 #[derive(Clone, Default, Debug)]
 #[non_exhaustive]
-pub struct EthereumPayloadBuilder;
+pub struct EthereumPayloadBuilder {
+    /// Shareable fee recipient and extra data settings of the payload builder.
+    pub settings: EthereumBuilderSettings,
+    /// Shareable preconfirmations whose senders are included first.
+    pub preconfirmations: Preconfirmations,
+}
 
 impl EthereumPayloadBuilder {
+    /// Configures the payload builder with the given, shareable settings.
+    ///
+    /// The fee recipients and extra data template of the node config are applied to the settings
+    /// when the payload builder is built. The extra data template is only applied if the settings
+    /// are the extra data source of the EVM config, see
+    /// [`EthEvmConfig::with_extra_data_source`].
+    pub fn with_settings(mut self, settings: EthereumBuilderSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Configures the payload builder with the given, shareable preconfirmations.
+    pub fn with_preconfirmations(mut self, preconfirmations: Preconfirmations) -> Self {
+        self.preconfirmations = preconfirmations;
+        self
+    }
+
     /// A helper method initializing [`reth_ethereum_payload_builder::EthereumPayloadBuilder`] with
     /// the given EVM config.
     pub fn build<Types, Node, Evm, Pool>(
@@ -44,11 +68,21 @@ impl EthereumPayloadBuilder {
         >,
     {
         let conf = ctx.payload_builder_config();
+        if !conf.fee_recipients().is_empty() {
+            self.settings.set_fee_recipients(conf.fee_recipients().to_vec());
+        }
+        if let Some(template) = conf.extra_data_template() {
+            self.settings.set_extra_data_template(Some(template.clone()));
+        }
+
         Ok(reth_ethereum_payload_builder::EthereumPayloadBuilder::new(
             ctx.provider().clone(),
             pool,
             evm_config,
-            EthereumBuilderConfig::new().with_gas_limit(conf.gas_limit()),
+            EthereumBuilderConfig::new()
+                .with_gas_limit(conf.gas_limit())
+                .with_settings(self.settings)
+                .with_preconfirmations(self.preconfirmations),
         ))
     }
 }
@@ -74,6 +108,8 @@ where
         ctx: &BuilderContext<Node>,
         pool: Pool,
     ) -> eyre::Result<Self::PayloadBuilder> {
-        self.build(EthEvmConfig::new(ctx.chain_spec()), ctx, pool)
+        let evm_config =
+            EthEvmConfig::new(ctx.chain_spec()).with_extra_data_source(self.settings.clone());
+        self.build(evm_config, ctx, pool)
     }
 }
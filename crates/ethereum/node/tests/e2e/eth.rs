@@ -3,6 +3,7 @@ use alloy_genesis::Genesis;
 use reth_chainspec::{ChainSpecBuilder, MAINNET};
 use reth_e2e_test_utils::{
     node::NodeTestContext, setup, transaction::TransactionTestContext, wallet::Wallet,
+    NodeTestBuilder,
 };
 use reth_node_builder::{NodeBuilder, NodeHandle};
 use reth_node_core::{args::RpcServerArgs, node_config::NodeConfig};
@@ -127,3 +128,23 @@ async fn test_failed_run_eth_node_with_no_auth_engine_api_over_ipc_opts() -> eyr
 
     Ok(())
 }
+
+#[tokio::test]
+async fn can_launch_node_with_test_builder() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(serde_json::from_str(include_str!("../assets/genesis.json")).unwrap())
+            .cancun_activated()
+            .build(),
+    );
+    let mut nodes = NodeTestBuilder::<EthereumNode>::new(chain_spec).launch().await?;
+
+    let payloads = nodes.advance_blocks(3).await?;
+    assert_eq!(payloads.last().unwrap().block().number, 3);
+    assert_eq!(nodes.primary().block_hash(3), payloads.last().unwrap().block().hash());
+
+    Ok(())
+}
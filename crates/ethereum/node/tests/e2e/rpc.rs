@@ -6,13 +6,13 @@ use alloy_rpc_types_beacon::relay::{
     BidTrace, BuilderBlockValidationRequestV3, BuilderBlockValidationRequestV4,
     SignedBidSubmissionV3, SignedBidSubmissionV4,
 };
-use alloy_rpc_types_engine::{BlobsBundleV1, ExecutionPayloadV3};
-use alloy_rpc_types_eth::TransactionRequest;
+use alloy_rpc_types_engine::{BlobsBundleV1, ExecutionPayloadV3, PayloadAttributes};
+use alloy_rpc_types_eth::{Block, TransactionRequest};
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use reth_chainspec::{ChainSpecBuilder, MAINNET};
 use reth_e2e_test_utils::setup_engine;
 use reth_node_ethereum::EthereumNode;
-use reth_payload_primitives::BuiltPayload;
+use reth_payload_primitives::{BuiltPayload, SimulatedPayload, TransactionInclusionReason};
 use std::sync::Arc;
 
 alloy_sol_types::sol! {
@@ -266,3 +266,54 @@ async fn test_flashbots_validate_v4() -> eyre::Result<()> {
         .is_err());
     Ok(())
 }
+
+#[tokio::test]
+async fn test_reth_build_payload() -> eyre::Result<()> {
+    reth_tracing::init_test_tracing();
+
+    let chain_spec = Arc::new(
+        ChainSpecBuilder::default()
+            .chain(MAINNET.chain)
+            .genesis(serde_json::from_str(include_str!("../assets/genesis.json")).unwrap())
+            .cancun_activated()
+            .build(),
+    );
+
+    let (mut nodes, _tasks, wallet) =
+        setup_engine::<EthereumNode>(1, chain_spec.clone(), false, eth_payload_attributes).await?;
+    let node = nodes.pop().unwrap();
+    let provider = ProviderBuilder::new()
+        .wallet(EthereumWallet::new(wallet.gen().swap_remove(0)))
+        .on_http(node.rpc_url());
+
+    let tx_hash = *provider
+        .send_transaction(TransactionRequest::default().to(Address::ZERO))
+        .await?
+        .tx_hash();
+
+    let fee_recipient = Address::random();
+    let attributes = PayloadAttributes {
+        timestamp: chain_spec.genesis_timestamp() + 12,
+        prev_randao: B256::random(),
+        suggested_fee_recipient: fee_recipient,
+        withdrawals: Some(vec![]),
+        parent_beacon_block_root: Some(B256::ZERO),
+    };
+    let payload = provider
+        .raw_request::<_, SimulatedPayload<Block>>("reth_buildPayload".into(), (&attributes,))
+        .await?;
+
+    assert_eq!(payload.block.header.number, 1);
+    assert_eq!(payload.block.header.beneficiary, fee_recipient);
+    assert_eq!(payload.block.transactions.len(), 1);
+    assert_eq!(payload.transactions.len(), 1);
+    assert_eq!(payload.transactions[0].hash, tx_hash);
+    assert_eq!(payload.transactions[0].reason, TransactionInclusionReason::Included);
+    assert_eq!(payload.transactions[0].gas_used, 21_000);
+    assert_eq!(payload.total_fees, payload.transactions[0].fee);
+
+    // the simulated block is not inserted
+    assert_eq!(provider.get_block_number().await?, 0);
+
+    Ok(())
+}
@@ -1,11 +1,21 @@
+use crate::Preconfirmations;
 use alloy_eips::eip1559::ETHEREUM_BLOCK_GAS_LIMIT_30M;
+use alloy_primitives::{Address, Bytes, B256};
+use parking_lot::RwLock;
+use reth_evm_ethereum::ExtraDataSource;
+use reth_payload_primitives::{rotate_fee_recipient, ExtraDataTemplate, FeeRecipient};
 use reth_primitives_traits::constants::GAS_LIMIT_BOUND_DIVISOR;
+use std::{collections::HashSet, sync::Arc};
 
 /// Settings for the Ethereum builder.
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct EthereumBuilderConfig {
     /// Desired gas limit.
     pub desired_gas_limit: u64,
+    /// Fee recipient rotation of built blocks, if configured.
+    pub settings: Option<EthereumBuilderSettings>,
+    /// Transactions whose senders are included first, if configured.
+    pub preconfirmations: Option<Preconfirmations>,
 }
 
 impl Default for EthereumBuilderConfig {
@@ -17,7 +27,11 @@ impl Default for EthereumBuilderConfig {
 impl EthereumBuilderConfig {
     /// Create new payload builder config.
     pub const fn new() -> Self {
-        Self { desired_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT_30M }
+        Self {
+            desired_gas_limit: ETHEREUM_BLOCK_GAS_LIMIT_30M,
+            settings: None,
+            preconfirmations: None,
+        }
     }
 
     /// Set desired gas limit.
@@ -25,6 +39,18 @@ impl EthereumBuilderConfig {
         self.desired_gas_limit = desired_gas_limit;
         self
     }
+
+    /// Set the shareable settings whose fee recipient rotation is applied.
+    pub fn with_settings(mut self, settings: EthereumBuilderSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Set the shareable preconfirmations whose senders are included first.
+    pub fn with_preconfirmations(mut self, preconfirmations: Preconfirmations) -> Self {
+        self.preconfirmations = Some(preconfirmations);
+        self
+    }
 }
 
 impl EthereumBuilderConfig {
@@ -33,6 +59,83 @@ impl EthereumBuilderConfig {
     pub fn gas_limit(&self, parent_gas_limit: u64) -> u64 {
         calculate_block_gas_limit(parent_gas_limit, self.desired_gas_limit)
     }
+
+    /// Returns the fee recipient of the block with the given number, if a rotation is configured.
+    pub fn fee_recipient(&self, block_number: u64) -> Option<Address> {
+        self.settings.as_ref()?.fee_recipient(block_number)
+    }
+
+    /// Returns the senders of the transactions that were preconfirmed for the block built on top
+    /// of `parent_hash`, if any.
+    pub fn preconfirmed_senders(&self, parent_hash: B256) -> Option<HashSet<Address>> {
+        self.preconfirmations.as_ref()?.senders(parent_hash)
+    }
+}
+
+/// Contains the fee recipient rotation and the extra data template of the Ethereum builder.
+///
+/// This type is shareable and can be used to update the settings of a running payload builder. The
+/// extra data template is applied by the block assembler of the EVM config, see
+/// [`EthEvmConfig::with_extra_data_source`](reth_evm_ethereum::EthEvmConfig::with_extra_data_source).
+#[derive(Debug, Clone, Default)]
+pub struct EthereumBuilderSettings {
+    inner: Arc<RwLock<EthereumBuilderSettingsInner>>,
+}
+
+impl EthereumBuilderSettings {
+    /// Returns the configured fee recipients.
+    pub fn fee_recipients(&self) -> Vec<FeeRecipient> {
+        self.inner.read().fee_recipients.clone()
+    }
+
+    /// Sets the fee recipients that blocks are built for if the payload attributes don't suggest a
+    /// fee recipient, i.e. suggest the zero address. An empty list disables the rotation.
+    pub fn set_fee_recipients(&self, fee_recipients: Vec<FeeRecipient>) {
+        self.inner.write().fee_recipients = fee_recipients;
+    }
+
+    /// Returns the configured extra data template.
+    pub fn extra_data_template(&self) -> Option<ExtraDataTemplate> {
+        self.inner.read().extra_data.clone()
+    }
+
+    /// Sets the template of the extra data of built blocks.
+    pub fn set_extra_data_template(&self, template: Option<ExtraDataTemplate>) {
+        self.inner.write().extra_data = template;
+    }
+
+    /// Returns the fee recipient of the block with the given number, if a rotation is configured.
+    pub fn fee_recipient(&self, block_number: u64) -> Option<Address> {
+        rotate_fee_recipient(&self.inner.read().fee_recipients, block_number)
+    }
+
+    /// Returns the extra data of the block with the given number and timestamp, if a template is
+    /// configured.
+    pub fn extra_data(&self, block_number: u64, timestamp: u64) -> Option<Bytes> {
+        Some(self.inner.read().extra_data.as_ref()?.render(block_number, timestamp))
+    }
+}
+
+impl ExtraDataSource for EthereumBuilderSettings {
+    fn extra_data(&self, number: u64, timestamp: u64) -> Option<Bytes> {
+        Self::extra_data(self, number, timestamp)
+    }
+}
+
+impl PartialEq for EthereumBuilderSettings {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for EthereumBuilderSettings {}
+
+#[derive(Debug, Default)]
+struct EthereumBuilderSettingsInner {
+    /// Recipients of the fees of built blocks.
+    fee_recipients: Vec<FeeRecipient>,
+    /// Template of the extra data of built blocks.
+    extra_data: Option<ExtraDataTemplate>,
 }
 
 /// Calculate the gas limit for the next block based on parent and desired gas limits.
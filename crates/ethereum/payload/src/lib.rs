@@ -14,6 +14,7 @@ pub use validator::EthereumExecutionPayloadValidator;
 
 use alloy_consensus::{Transaction, Typed2718};
 use alloy_primitives::U256;
+use alloy_rpc_types_engine::PayloadAttributes;
 use reth_basic_payload_builder::{
     is_better_payload, BuildArguments, BuildOutcome, PayloadBuilder, PayloadConfig,
 };
@@ -24,16 +25,19 @@ use reth_evm::{
     execute::{BlockBuilder, BlockBuilderOutcome},
     ConfigureEvm, Evm, NextBlockEnvAttributes,
 };
-use reth_evm_ethereum::EthEvmConfig;
+use reth_evm_ethereum::{gas_schedule_by_timestamp_and_block_number, EthEvmConfig};
 use reth_payload_builder::{EthBuiltPayload, EthPayloadBuilderAttributes};
 use reth_payload_builder_primitives::PayloadBuilderError;
-use reth_payload_primitives::PayloadBuilderAttributes;
-use reth_primitives_traits::SignedTransaction;
+use reth_payload_primitives::{
+    PayloadBuilderAttributes, TransactionInclusion, TransactionInclusionReason,
+};
+use reth_primitives_traits::{SealedHeader, SignedTransaction};
 use reth_revm::{database::StateProviderDatabase, db::State};
 use reth_storage_api::StateProviderFactory;
 use reth_transaction_pool::{
-    error::InvalidPoolTransactionError, BestTransactions, BestTransactionsAttributes,
-    PoolTransaction, TransactionPool, ValidPoolTransaction,
+    error::InvalidPoolTransactionError, pool::BestTransactionsWithPrioritizedSenders,
+    BestTransactions, BestTransactionsAttributes, PoolTransaction, TransactionPool,
+    ValidPoolTransaction,
 };
 use revm::context_interface::Block as _;
 use std::sync::Arc;
@@ -41,6 +45,8 @@ use tracing::{debug, trace, warn};
 
 mod config;
 pub use config::*;
+mod preconf;
+pub use preconf::Preconfirmations;
 use reth_primitives_traits::transaction::error::InvalidTransactionError;
 use reth_transaction_pool::error::Eip4844PoolTransactionError;
 
@@ -73,6 +79,43 @@ impl<Pool, Client, EvmConfig> EthereumPayloadBuilder<Pool, Client, EvmConfig> {
     }
 }
 
+impl<Pool, Client, EvmConfig> EthereumPayloadBuilder<Pool, Client, EvmConfig>
+where
+    EvmConfig: ConfigureEvm<Primitives = EthPrimitives, NextBlockEnvCtx = NextBlockEnvAttributes>,
+    Client: StateProviderFactory + ChainSpecProvider<ChainSpec = ChainSpec> + Clone,
+    Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>>,
+{
+    /// Builds a payload on top of the given parent like [`PayloadBuilder::try_build`], but outside
+    /// of a payload job, so nothing is sent to the consensus layer.
+    ///
+    /// Returns the payload and the [`TransactionInclusion`] of every pool transaction that was
+    /// considered, in order.
+    pub fn simulate_payload(
+        &self,
+        parent_header: Arc<SealedHeader>,
+        attributes: PayloadAttributes,
+    ) -> Result<(EthBuiltPayload, Vec<TransactionInclusion>), PayloadBuilderError> {
+        let attributes = EthPayloadBuilderAttributes::new(parent_header.hash(), attributes);
+        let config = PayloadConfig::new(parent_header, attributes);
+        let args = BuildArguments::new(Default::default(), config, Default::default(), None);
+
+        let mut transactions = Vec::new();
+        let payload = build_ethereum_payload(
+            self.evm_config.clone(),
+            self.client.clone(),
+            self.pool.clone(),
+            self.builder_config.clone(),
+            args,
+            |attributes| self.pool.best_transactions_with_attributes(attributes),
+            |inclusion| transactions.push(inclusion),
+        )?
+        .into_payload()
+        .ok_or_else(|| PayloadBuilderError::MissingPayload)?;
+
+        Ok((payload, transactions))
+    }
+}
+
 // Default implementation of [PayloadBuilder] for unit type
 impl<Pool, Client, EvmConfig> PayloadBuilder for EthereumPayloadBuilder<Pool, Client, EvmConfig>
 where
@@ -135,10 +178,40 @@ where
     Client: StateProviderFactory + ChainSpecProvider<ChainSpec = ChainSpec>,
     Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>>,
     F: FnOnce(BestTransactionsAttributes) -> BestTransactionsIter<Pool>,
+{
+    build_ethereum_payload(evm_config, client, pool, builder_config, args, best_txs, |_| {})
+}
+
+/// Constructs an Ethereum transaction payload like [`default_ethereum_payload`] and reports the
+/// [`TransactionInclusion`] of every pool transaction that was considered.
+fn build_ethereum_payload<EvmConfig, Client, Pool, F, I>(
+    evm_config: EvmConfig,
+    client: Client,
+    pool: Pool,
+    builder_config: EthereumBuilderConfig,
+    args: BuildArguments<EthPayloadBuilderAttributes, EthBuiltPayload>,
+    best_txs: F,
+    mut on_transaction: I,
+) -> Result<BuildOutcome<EthBuiltPayload>, PayloadBuilderError>
+where
+    EvmConfig: ConfigureEvm<Primitives = EthPrimitives, NextBlockEnvCtx = NextBlockEnvAttributes>,
+    Client: StateProviderFactory + ChainSpecProvider<ChainSpec = ChainSpec>,
+    Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>>,
+    F: FnOnce(BestTransactionsAttributes) -> BestTransactionsIter<Pool>,
+    I: FnMut(TransactionInclusion),
 {
     let BuildArguments { mut cached_reads, config, cancel, best_payload } = args;
     let PayloadConfig { parent_header, attributes } = config;
 
+    let block_number = parent_header.number + 1;
+    // the rotation only applies if the consensus layer doesn't suggest a fee recipient
+    let mut suggested_fee_recipient = attributes.suggested_fee_recipient();
+    if suggested_fee_recipient.is_zero() {
+        if let Some(fee_recipient) = builder_config.fee_recipient(block_number) {
+            suggested_fee_recipient = fee_recipient;
+        }
+    }
+
     let state_provider = client.state_by_block_hash(parent_header.hash())?;
     let state = StateProviderDatabase::new(&state_provider);
     let mut db =
@@ -150,7 +223,7 @@ where
             &parent_header,
             NextBlockEnvAttributes {
                 timestamp: attributes.timestamp(),
-                suggested_fee_recipient: attributes.suggested_fee_recipient(),
+                suggested_fee_recipient,
                 prev_randao: attributes.prev_randao(),
                 gas_limit: builder_config.gas_limit(parent_header.gas_limit),
                 parent_beacon_block_root: attributes.parent_beacon_block_root(),
@@ -160,6 +233,8 @@ where
         .map_err(PayloadBuilderError::other)?;
 
     let chain_spec = client.chain_spec();
+    let gas_schedule =
+        gas_schedule_by_timestamp_and_block_number(&chain_spec, attributes.timestamp, block_number);
 
     debug!(target: "payload_builder", id=%attributes.id, parent_header = ?parent_header.hash(), parent_number = parent_header.number, "building new payload");
     let mut cumulative_gas_used = 0;
@@ -170,6 +245,15 @@ where
         base_fee,
         builder.evm_mut().block().blob_gasprice().map(|gasprice| gasprice as u64),
     ));
+    if let Some(senders) = builder_config.preconfirmed_senders(parent_header.hash()) {
+        // all transactions of the senders are included first, which keeps the nonce order of the
+        // preconfirmed transactions
+        best_txs = Box::new(BestTransactionsWithPrioritizedSenders::new(
+            senders,
+            block_gas_limit,
+            best_txs,
+        ));
+    }
     let mut total_fees = U256::ZERO;
 
     builder.apply_pre_execution_changes().map_err(|err| {
@@ -183,6 +267,10 @@ where
         blob_params.as_ref().map(|params| params.max_blob_count).unwrap_or_default();
 
     while let Some(pool_tx) = best_txs.next() {
+        let mut skip = |reason| {
+            on_transaction(TransactionInclusion::skipped(*pool_tx.hash(), pool_tx.sender(), reason))
+        };
+
         // ensure we still have capacity for this transaction
         if cumulative_gas_used + pool_tx.gas_limit() > block_gas_limit {
             // we can't fit this transaction into the block, so we need to mark it as invalid
@@ -192,6 +280,7 @@ where
                 &pool_tx,
                 InvalidPoolTransactionError::ExceedsGasLimit(pool_tx.gas_limit(), block_gas_limit),
             );
+            skip(TransactionInclusionReason::ExceedsBlockGasLimit);
             continue
         }
 
@@ -203,6 +292,15 @@ where
         // convert tx to a signed transaction
         let tx = pool_tx.to_consensus();
 
+        // the transaction may have been validated before a repricing of the intrinsic gas was
+        // activated, so it must still cover the intrinsic gas of the block's schedule
+        if pool_tx.gas_limit() < gas_schedule.tx_min_gas_limit(&*tx) {
+            trace!(target: "payload_builder", tx=?tx.hash(), ?gas_schedule, "skipping transaction below the intrinsic gas");
+            best_txs.mark_invalid(&pool_tx, InvalidPoolTransactionError::IntrinsicGasTooLow);
+            skip(TransactionInclusionReason::IntrinsicGasTooLow);
+            continue
+        }
+
         // There's only limited amount of blob space available per block, so we need to check if
         // the EIP-4844 can still fit in the block
         if let Some(blob_tx) = tx.as_eip4844() {
@@ -223,6 +321,7 @@ where
                         },
                     ),
                 );
+                skip(TransactionInclusionReason::ExceedsBlobLimit);
                 continue
             }
         }
@@ -235,6 +334,7 @@ where
                 if error.is_nonce_too_low() {
                     // if the nonce is too low, we can skip this transaction
                     trace!(target: "payload_builder", %error, ?tx, "skipping nonce too low transaction");
+                    skip(TransactionInclusionReason::NonceTooLow);
                 } else {
                     // if the transaction is invalid, we can skip it and all of its
                     // descendants
@@ -245,6 +345,7 @@ where
                             InvalidTransactionError::TxTypeNotSupported,
                         ),
                     );
+                    skip(TransactionInclusionReason::Invalid);
                 }
                 continue
             }
@@ -265,8 +366,15 @@ where
         // update add to total fees
         let miner_fee =
             tx.effective_tip_per_gas(base_fee).expect("fee is always valid; execution succeeded");
-        total_fees += U256::from(miner_fee) * U256::from(gas_used);
+        let fee = U256::from(miner_fee) * U256::from(gas_used);
+        total_fees += fee;
         cumulative_gas_used += gas_used;
+        on_transaction(TransactionInclusion::included(
+            *pool_tx.hash(),
+            pool_tx.sender(),
+            gas_used,
+            fee,
+        ));
     }
 
     // check if we have a better block
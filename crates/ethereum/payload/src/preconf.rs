@@ -0,0 +1,148 @@
+use alloy_primitives::{Address, TxHash, B256};
+use parking_lot::RwLock;
+use std::{collections::HashSet, sync::Arc};
+
+/// Transactions that were preconfirmed for inclusion in the block that is built on top of a
+/// parent block.
+///
+/// This type is shareable: the `reth_preconfirmTransaction` endpoint reserves block gas for the
+/// transactions it commits to, and the payload builder includes the transactions of their senders
+/// before all other transactions of the pool.
+#[derive(Debug, Clone, Default)]
+pub struct Preconfirmations {
+    inner: Arc<RwLock<PreconfirmationsInner>>,
+}
+
+impl Preconfirmations {
+    /// Reserves `gas_limit` gas of the block that is built on top of `parent_hash` for the given
+    /// transaction.
+    ///
+    /// Returns `false` if the transaction was already preconfirmed, or if the gas limits of the
+    /// preconfirmed transactions would exceed the `block_gas_limit`.
+    ///
+    /// Reservations for other parent blocks are dropped.
+    pub fn reserve(
+        &self,
+        parent_hash: B256,
+        tx_hash: TxHash,
+        sender: Address,
+        gas_limit: u64,
+        block_gas_limit: u64,
+    ) -> bool {
+        let mut inner = self.inner.write();
+        if inner.parent_hash != parent_hash {
+            *inner = PreconfirmationsInner { parent_hash, ..Default::default() };
+        }
+
+        if inner.transactions.contains(&tx_hash) ||
+            inner.gas.saturating_add(gas_limit) > block_gas_limit
+        {
+            return false
+        }
+
+        inner.gas += gas_limit;
+        inner.transactions.insert(tx_hash);
+        inner.senders.insert(sender);
+        true
+    }
+
+    /// Releases the gas that was reserved for the given transaction, e.g. because it couldn't be
+    /// submitted to the pool.
+    pub fn release(&self, parent_hash: B256, tx_hash: TxHash, gas_limit: u64) {
+        let mut inner = self.inner.write();
+        if inner.parent_hash == parent_hash && inner.transactions.remove(&tx_hash) {
+            inner.gas -= gas_limit;
+        }
+    }
+
+    /// Returns the gas that is reserved in the block that is built on top of `parent_hash`.
+    pub fn reserved_gas(&self, parent_hash: B256) -> u64 {
+        let inner = self.inner.read();
+        if inner.parent_hash == parent_hash {
+            inner.gas
+        } else {
+            0
+        }
+    }
+
+    /// Returns the senders of the transactions that were preconfirmed for the block that is built
+    /// on top of `parent_hash`, if any.
+    pub fn senders(&self, parent_hash: B256) -> Option<HashSet<Address>> {
+        let inner = self.inner.read();
+        (inner.parent_hash == parent_hash && !inner.senders.is_empty())
+            .then(|| inner.senders.clone())
+    }
+}
+
+impl PartialEq for Preconfirmations {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for Preconfirmations {}
+
+#[derive(Debug, Default)]
+struct PreconfirmationsInner {
+    /// The parent of the block the transactions are preconfirmed for.
+    parent_hash: B256,
+    /// Hashes of the preconfirmed transactions.
+    transactions: HashSet<TxHash>,
+    /// Senders of the preconfirmed transactions.
+    senders: HashSet<Address>,
+    /// Sum of the gas limits of the preconfirmed transactions.
+    gas: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLOCK_GAS_LIMIT: u64 = 50_000;
+
+    fn reserve(preconfirmations: &Preconfirmations, parent: u8, tx: u8, gas_limit: u64) -> bool {
+        preconfirmations.reserve(
+            B256::with_last_byte(parent),
+            TxHash::with_last_byte(tx),
+            Address::with_last_byte(parent),
+            gas_limit,
+            BLOCK_GAS_LIMIT,
+        )
+    }
+
+    #[test]
+    fn reserve_within_block_gas_limit() {
+        let preconfirmations = Preconfirmations::default();
+        let parent = B256::with_last_byte(1);
+
+        assert!(reserve(&preconfirmations, 1, 1, 21_000));
+        // the same transaction is only reserved once
+        assert!(!reserve(&preconfirmations, 1, 1, 21_000));
+        assert!(reserve(&preconfirmations, 1, 2, 21_000));
+        // the gas limits of the preconfirmed transactions would exceed the block gas limit
+        assert!(!reserve(&preconfirmations, 1, 3, 21_000));
+        assert_eq!(preconfirmations.reserved_gas(parent), 42_000);
+
+        preconfirmations.release(parent, TxHash::with_last_byte(2), 21_000);
+        assert_eq!(preconfirmations.reserved_gas(parent), 21_000);
+        assert!(reserve(&preconfirmations, 1, 3, 21_000));
+        assert_eq!(
+            preconfirmations.senders(parent),
+            Some(HashSet::from([Address::with_last_byte(1)]))
+        );
+    }
+
+    #[test]
+    fn reservations_are_dropped_on_new_parent() {
+        let preconfirmations = Preconfirmations::default();
+        assert!(reserve(&preconfirmations, 1, 1, 21_000));
+        assert_eq!(preconfirmations.senders(B256::with_last_byte(2)), None);
+
+        assert!(reserve(&preconfirmations, 2, 2, BLOCK_GAS_LIMIT));
+        assert_eq!(preconfirmations.reserved_gas(B256::with_last_byte(1)), 0);
+        assert_eq!(
+            preconfirmations.senders(B256::with_last_byte(2)),
+            Some(HashSet::from([Address::with_last_byte(2)]))
+        );
+    }
+}
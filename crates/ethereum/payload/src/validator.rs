@@ -1,8 +1,9 @@
 //! Validates execution payload wrt Ethereum consensus rules
 
 use alloy_consensus::Block;
+use alloy_eips::eip7685::EMPTY_REQUESTS_HASH;
 use alloy_rpc_types_engine::{ExecutionData, PayloadError};
-use reth_chainspec::EthereumHardforks;
+use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_payload_validator::{cancun, prague, shanghai};
 use reth_primitives_traits::{Block as _, SealedBlock, SignedTransaction};
 use std::sync::Arc;
@@ -27,7 +28,7 @@ impl<ChainSpec> EthereumExecutionPayloadValidator<ChainSpec> {
     }
 }
 
-impl<ChainSpec: EthereumHardforks> EthereumExecutionPayloadValidator<ChainSpec> {
+impl<ChainSpec: EthChainSpec + EthereumHardforks> EthereumExecutionPayloadValidator<ChainSpec> {
     /// Returns true if the Cancun hardfork is active at the given timestamp.
     #[inline]
     fn is_cancun_active_at_timestamp(&self, timestamp: u64) -> bool {
@@ -69,6 +70,11 @@ impl<ChainSpec: EthereumHardforks> EthereumExecutionPayloadValidator<ChainSpec>
     ///
     /// This validates versioned hashes according to the Engine API Cancun spec:
     /// <https://github.com/ethereum/execution-apis/blob/fe8e13c288c592ec154ce25c534e26cb7ce0530d/src/engine/cancun.md#specification>
+    ///
+    /// The header fields of the [`ExecutionFeatures`](reth_chainspec::ExecutionFeatures) that are
+    /// disabled on the chain are not part of the block, even though the payload of the fork carries
+    /// them: the withdrawals and the execution requests of the payload must be empty, and the
+    /// parent beacon block root is ignored.
     pub fn ensure_well_formed_payload<T: SignedTransaction>(
         &self,
         payload: ExecutionData,
@@ -78,7 +84,28 @@ impl<ChainSpec: EthereumHardforks> EthereumExecutionPayloadValidator<ChainSpec>
         let expected_hash = payload.block_hash();
 
         // First parse the block
-        let sealed_block = payload.try_into_block_with_sidecar(&sidecar)?.seal_slow();
+        let mut block = payload.try_into_block_with_sidecar(&sidecar)?;
+
+        // Remove the fields of the disabled features, these are not part of the block hash
+        let features = self.chain_spec().execution_features();
+        if !features.withdrawals && block.body.withdrawals.as_ref().is_some_and(|w| w.is_empty()) {
+            block.body.withdrawals = None;
+            block.header.withdrawals_root = None;
+        }
+        if !features.beacon_root {
+            block.header.parent_beacon_block_root = None;
+        }
+        if !features.requests {
+            if sidecar
+                .prague()
+                .is_some_and(|prague| prague.requests.requests_hash() != EMPTY_REQUESTS_HASH)
+            {
+                return Err(PayloadError::PrePragueBlockRequests)
+            }
+            block.header.requests_hash = None;
+        }
+
+        let sealed_block = block.seal_slow();
 
         // Ensure the hash included in the payload matches the block hash
         if expected_hash != sealed_block.hash() {
@@ -90,7 +117,7 @@ impl<ChainSpec: EthereumHardforks> EthereumExecutionPayloadValidator<ChainSpec>
 
         shanghai::ensure_well_formed_fields(
             sealed_block.body(),
-            self.is_shanghai_active_at_timestamp(sealed_block.timestamp),
+            features.withdrawals && self.is_shanghai_active_at_timestamp(sealed_block.timestamp),
         )?;
 
         cancun::ensure_well_formed_fields(
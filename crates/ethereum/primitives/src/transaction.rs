@@ -896,6 +896,12 @@ impl SignedTransaction for TransactionSigned {
         let signature_hash = keccak256(buf);
         recover_signer_unchecked(&self.signature, signature_hash)
     }
+
+    fn signature_hash_with_buf(&self, buf: &mut Vec<u8>) -> Option<B256> {
+        buf.clear();
+        self.encode_for_signing(buf);
+        Some(keccak256(buf))
+    }
 }
 
 impl TryFrom<TransactionSigned> for PooledTransaction {
@@ -1,7 +1,7 @@
 //! Contains [Chain], a chain of blocks and their final state.
 
 use crate::ExecutionOutcome;
-use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+use alloc::{borrow::Cow, collections::BTreeMap, sync::Arc, vec::Vec};
 use alloy_consensus::{transaction::Recovered, BlockHeader};
 use alloy_eips::{eip1898::ForkBlock, eip2718::Encodable2718, BlockNumHash};
 use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash};
@@ -39,6 +39,12 @@ pub struct Chain<N: NodePrimitives = reth_ethereum_primitives::EthPrimitives> {
     /// NOTE: Currently, trie updates are present only for
     /// single-block chains that extend the canonical chain.
     trie_updates: Option<TrieUpdates>,
+    /// Trie updates of the individual blocks of the chain, by block number.
+    ///
+    /// These are only attached to canonical state notifications of subscribers that requested
+    /// them.
+    #[cfg_attr(feature = "serde", serde(default))]
+    block_trie_updates: BTreeMap<BlockNumber, Arc<TrieUpdates>>,
 }
 
 impl<N: NodePrimitives> Default for Chain<N> {
@@ -47,6 +53,7 @@ impl<N: NodePrimitives> Default for Chain<N> {
             blocks: Default::default(),
             execution_outcome: Default::default(),
             trie_updates: Default::default(),
+            block_trie_updates: Default::default(),
         }
     }
 }
@@ -66,7 +73,7 @@ impl<N: NodePrimitives> Chain<N> {
             blocks.into_iter().map(|b| (b.header().number(), b)).collect::<BTreeMap<_, _>>();
         debug_assert!(!blocks.is_empty(), "Chain should have at least one block");
 
-        Self { blocks, execution_outcome, trie_updates }
+        Self { blocks, execution_outcome, trie_updates, block_trie_updates: Default::default() }
     }
 
     /// Create new Chain from a single block and its state.
@@ -103,6 +110,26 @@ impl<N: NodePrimitives> Chain<N> {
         self.trie_updates.take();
     }
 
+    /// Returns the trie updates of the block with the given number, if they're attached.
+    pub fn block_trie_updates(&self, block_number: BlockNumber) -> Option<&Arc<TrieUpdates>> {
+        self.block_trie_updates.get(&block_number)
+    }
+
+    /// Returns the attached trie updates of the individual blocks, by block number.
+    pub const fn blocks_trie_updates(&self) -> &BTreeMap<BlockNumber, Arc<TrieUpdates>> {
+        &self.block_trie_updates
+    }
+
+    /// Attaches the trie updates of the block with the given number.
+    pub fn set_block_trie_updates(&mut self, block_number: BlockNumber, trie: Arc<TrieUpdates>) {
+        self.block_trie_updates.insert(block_number, trie);
+    }
+
+    /// Removes the attached trie updates of all blocks.
+    pub fn clear_block_trie_updates(&mut self) {
+        self.block_trie_updates.clear();
+    }
+
     /// Get execution outcome of this chain
     pub const fn execution_outcome(&self) -> &ExecutionOutcome<N::Receipt> {
         &self.execution_outcome
@@ -258,14 +285,18 @@ impl<N: NodePrimitives> Chain<N> {
 
     /// Append a single block with state to the chain.
     /// This method assumes that blocks attachment to the chain has already been validated.
+    ///
+    /// Returns the passed execution outcome in [`Result::Err`] variant if it doesn't start at the
+    /// block after the execution outcome of the chain, see [`ExecutionOutcome::merge`].
     pub fn append_block(
         &mut self,
         block: RecoveredBlock<N::Block>,
         execution_outcome: ExecutionOutcome<N::Receipt>,
-    ) {
+    ) -> Result<(), ExecutionOutcome<N::Receipt>> {
+        self.execution_outcome.merge(execution_outcome)?;
         self.blocks.insert(block.header().number(), block);
-        self.execution_outcome.extend(execution_outcome);
         self.trie_updates.take(); // reset
+        Ok(())
     }
 
     /// Merge two chains by appending the given chain into the current one.
@@ -285,6 +316,7 @@ impl<N: NodePrimitives> Chain<N> {
         self.blocks.extend(other.blocks);
         self.execution_outcome.extend(other.execution_outcome);
         self.trie_updates.take(); // reset
+        self.block_trie_updates.extend(other.block_trie_updates);
 
         Ok(())
     }
@@ -339,6 +371,7 @@ impl<N: NodePrimitives> Chain<N> {
 
         let split_at = block_number + 1;
         let higher_number_blocks = self.blocks.split_off(&split_at);
+        let higher_number_block_trie_updates = self.block_trie_updates.split_off(&split_at);
 
         let execution_outcome = core::mem::take(&mut self.execution_outcome);
         let (canonical_block_exec_outcome, pending_block_exec_outcome) =
@@ -351,11 +384,13 @@ impl<N: NodePrimitives> Chain<N> {
                 execution_outcome: canonical_block_exec_outcome.expect("split in range"),
                 blocks: self.blocks,
                 trie_updates: None,
+                block_trie_updates: self.block_trie_updates,
             },
             pending: Self {
                 execution_outcome: pending_block_exec_outcome,
                 blocks: higher_number_blocks,
                 trie_updates: None,
+                block_trie_updates: higher_number_block_trie_updates,
             },
         }
     }
@@ -526,7 +561,7 @@ pub enum ChainSplit<N: NodePrimitives = reth_ethereum_primitives::EthPrimitives>
 #[cfg(feature = "serde-bincode-compat")]
 pub(super) mod serde_bincode_compat {
     use crate::{serde_bincode_compat, ExecutionOutcome};
-    use alloc::borrow::Cow;
+    use alloc::{borrow::Cow, collections::BTreeMap, sync::Arc};
     use alloy_primitives::BlockNumber;
     use reth_ethereum_primitives::EthPrimitives;
     use reth_primitives_traits::{
@@ -536,7 +571,6 @@ pub(super) mod serde_bincode_compat {
     use reth_trie_common::serde_bincode_compat::updates::TrieUpdates;
     use serde::{ser::SerializeMap, Deserialize, Deserializer, Serialize, Serializer};
     use serde_with::{DeserializeAs, SerializeAs};
-    use std::collections::BTreeMap;
 
     /// Bincode-compatible [`super::Chain`] serde implementation.
     ///
@@ -563,6 +597,8 @@ pub(super) mod serde_bincode_compat {
         blocks: RecoveredBlocks<'a, N::Block>,
         execution_outcome: serde_bincode_compat::ExecutionOutcome<'a, N::Receipt>,
         trie_updates: Option<TrieUpdates<'a>>,
+        #[serde(default)]
+        block_trie_updates: BTreeMap<BlockNumber, TrieUpdates<'a>>,
     }
 
     #[derive(Debug)]
@@ -616,6 +652,11 @@ pub(super) mod serde_bincode_compat {
                 blocks: RecoveredBlocks(Cow::Borrowed(&value.blocks)),
                 execution_outcome: value.execution_outcome.as_repr(),
                 trie_updates: value.trie_updates.as_ref().map(Into::into),
+                block_trie_updates: value
+                    .block_trie_updates
+                    .iter()
+                    .map(|(block_number, trie)| (*block_number, trie.as_ref().into()))
+                    .collect(),
             }
         }
     }
@@ -631,6 +672,11 @@ pub(super) mod serde_bincode_compat {
                 blocks: value.blocks.0.into_owned(),
                 execution_outcome: ExecutionOutcome::from_repr(value.execution_outcome),
                 trie_updates: value.trie_updates.map(Into::into),
+                block_trie_updates: value
+                    .block_trie_updates
+                    .into_iter()
+                    .map(|(block_number, trie)| (block_number, Arc::new(trie.into())))
+                    .collect(),
             }
         }
     }
@@ -666,9 +712,12 @@ pub(super) mod serde_bincode_compat {
     #[cfg(test)]
     mod tests {
         use super::super::{serde_bincode_compat, Chain};
+        use alloc::sync::Arc;
+        use alloy_consensus::BlockHeader;
         use arbitrary::Arbitrary;
         use rand::Rng;
         use reth_primitives_traits::RecoveredBlock;
+        use reth_trie_common::{updates::TrieUpdates, Nibbles};
         use serde::{Deserialize, Serialize};
         use serde_with::serde_as;
 
@@ -683,7 +732,7 @@ pub(super) mod serde_bincode_compat {
 
             let mut bytes = [0u8; 1024];
             rand::thread_rng().fill(bytes.as_mut_slice());
-            let data = Data {
+            let mut data = Data {
                 chain: Chain::new(
                     vec![RecoveredBlock::arbitrary(&mut arbitrary::Unstructured::new(&bytes))
                         .unwrap()],
@@ -691,6 +740,12 @@ pub(super) mod serde_bincode_compat {
                     None,
                 ),
             };
+            let trie = TrieUpdates {
+                removed_nodes: [Nibbles::from_nibbles([0x1, 0x2])].into_iter().collect(),
+                ..Default::default()
+            };
+            let tip = data.chain.tip().number();
+            data.chain.set_block_trie_updates(tip, Arc::new(trie));
 
             let encoded = bincode::serialize(&data).unwrap();
             let decoded: Data = bincode::deserialize(&encoded).unwrap();
@@ -798,12 +853,14 @@ mod tests {
             execution_outcome: split1_execution_outcome.unwrap(),
             blocks: BTreeMap::from([(1, block1.clone())]),
             trie_updates: None,
+            block_trie_updates: BTreeMap::new(),
         };
 
         let chain_split2 = Chain {
             execution_outcome: split2_execution_outcome,
             blocks: BTreeMap::from([(2, block2.clone())]),
             trie_updates: None,
+            block_trie_updates: BTreeMap::new(),
         };
 
         // return tip state
@@ -1,11 +1,16 @@
 use crate::{BlockExecutionOutput, BlockExecutionResult};
-use alloc::{vec, vec::Vec};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use alloy_consensus::constants::KECCAK_EMPTY;
 use alloy_eips::eip7685::Requests;
 use alloy_primitives::{logs_bloom, map::HashMap, Address, BlockNumber, Bloom, Log, B256, U256};
+use core::ops::RangeInclusive;
 use reth_primitives_traits::{Account, Bytecode, Receipt, StorageEntry};
 use reth_trie_common::{HashedPostState, KeyHasher};
 use revm::state::AccountInfo;
-use revm_database::{states::BundleState, BundleAccount};
+use revm_database::{
+    states::{reverts::AccountInfoRevert, BundleState},
+    BundleAccount,
+};
 
 /// Type used to initialize revms bundle state.
 pub type BundleStateInit =
@@ -35,6 +40,24 @@ impl ChangedAccount {
     }
 }
 
+/// The changes that revert an account to its state before a range of blocks.
+///
+/// See [`ExecutionOutcome::account_reverts`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountRangeRevert {
+    /// The account before the range, `Some(None)` if it didn't exist.
+    ///
+    /// `None` if only the storage of the account changed in the range.
+    pub info: Option<Option<Account>>,
+    /// The values before the range of all storage slots changed in the range.
+    pub storage: BTreeMap<B256, U256>,
+    /// Whether the storage of the account was wiped in the range.
+    ///
+    /// The whole storage of the account before the range then needs to be restored, not only the
+    /// slots in `storage`.
+    pub wipe_storage: bool,
+}
+
 /// Represents the outcome of block execution, including post-execution changes and reverts.
 ///
 /// The `ExecutionOutcome` structure aggregates the state changes over an arbitrary number of
@@ -316,6 +339,20 @@ impl<T> ExecutionOutcome<T> {
         (Some(lower_state), higher_state)
     }
 
+    /// Splits the block range state at a given block number, see [`Self::split_at`].
+    ///
+    /// Returns the outcome in [`Result::Err`] variant if the block number is not in the block
+    /// range of the outcome.
+    pub fn try_split_at(self, at: BlockNumber) -> Result<(Option<Self>, Self), Self>
+    where
+        T: Clone,
+    {
+        if self.block_number_to_index(at).is_none() {
+            return Err(self)
+        }
+        Ok(self.split_at(at))
+    }
+
     /// Extend one state from another
     ///
     /// For state this is very sensitive operation and should be used only when
@@ -327,6 +364,57 @@ impl<T> ExecutionOutcome<T> {
         self.requests.extend(other.requests);
     }
 
+    /// Appends the outcome of the blocks right after this outcome.
+    ///
+    /// Unlike [`Self::extend`], this checks that `other` starts at the block after
+    /// [`Self::last_block`]. If this outcome has no blocks yet, it takes over the first block of
+    /// `other`.
+    ///
+    /// Returns the passed `other` outcome in [`Result::Err`] variant if the outcomes could not be
+    /// connected.
+    pub fn merge(&mut self, other: Self) -> Result<(), Self> {
+        if self.is_empty() {
+            self.first_block = other.first_block;
+        } else if other.first_block != self.last_block() + 1 {
+            return Err(other)
+        }
+        self.extend(other);
+        Ok(())
+    }
+
+    /// Returns the changes that revert all accounts changed in the given block range to their
+    /// state before the first block of the range.
+    ///
+    /// Returns `None` if the range is empty, or not covered by the outcome and its reverts.
+    pub fn account_reverts(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> Option<BTreeMap<Address, AccountRangeRevert>> {
+        let start = self.block_number_to_index(*range.start())?;
+        let end = self.block_number_to_index(*range.end())?;
+        if start > end {
+            return None
+        }
+
+        let mut reverts = BTreeMap::<Address, AccountRangeRevert>::new();
+        // walk the blocks backwards, so that the revert of the earliest block wins
+        for block_reverts in self.bundle.reverts.get(start..=end)?.iter().rev() {
+            for (address, revert) in block_reverts {
+                let account = reverts.entry(*address).or_default();
+                match &revert.account {
+                    AccountInfoRevert::DoNothing => {}
+                    AccountInfoRevert::DeleteIt => account.info = Some(None),
+                    AccountInfoRevert::RevertTo(info) => account.info = Some(Some(info.into())),
+                }
+                for (slot, previous) in &revert.storage {
+                    account.storage.insert(B256::from(*slot), previous.to_previous_value());
+                }
+                account.wipe_storage |= revert.wipe_storage;
+            }
+        }
+        Some(reverts)
+    }
+
     /// Prepends present the state with the given `BundleState`.
     /// It adds changes from the given state but does not override any existing changes.
     ///
@@ -365,6 +453,38 @@ impl<T> ExecutionOutcome<T> {
             |(address, acc)| ChangedAccount { address, nonce: acc.nonce, balance: acc.balance },
         )
     }
+
+    /// Returns the accounts whose code changed in each block, together with their code hash from
+    /// before the block.
+    ///
+    /// This covers contract deployments, selfdestructs and changes of EIP-7702 delegations. A code
+    /// hash of `None` means that the account didn't exist or had no code.
+    pub fn code_changes(&self) -> Vec<Vec<(Address, Option<B256>)>> {
+        let code_hash = |info: Option<&AccountInfo>| {
+            info.map(|info| info.code_hash).filter(|code_hash| *code_hash != KECCAK_EMPTY)
+        };
+
+        // Code hashes after the block that is processed, starting from the state after the last
+        // block and moving backwards.
+        let mut code_hashes_after = HashMap::<Address, Option<B256>>::default();
+        let mut changes = vec![Vec::new(); self.bundle.reverts.len()];
+        for (block_changes, reverts) in changes.iter_mut().zip(self.bundle.reverts.iter()).rev() {
+            for (address, revert) in reverts {
+                let before = match &revert.account {
+                    AccountInfoRevert::DoNothing => continue,
+                    AccountInfoRevert::DeleteIt => None,
+                    AccountInfoRevert::RevertTo(info) => code_hash(Some(info)),
+                };
+                let after = code_hashes_after.insert(*address, before).unwrap_or_else(|| {
+                    code_hash(self.bundle.account(address).and_then(|acc| acc.info.as_ref()))
+                });
+                if before != after {
+                    block_changes.push((*address, before));
+                }
+            }
+        }
+        changes
+    }
 }
 
 impl<T: Receipt<Log = Log>> ExecutionOutcome<T> {
@@ -984,4 +1104,157 @@ mod tests {
             balance: U256::from(200)
         }));
     }
+
+    #[test]
+    fn test_code_changes() {
+        let contract = Address::new([1; 20]);
+        let eoa = Address::new([2; 20]);
+        let code_hash = B256::new([3; 32]);
+        let contract_info = AccountInfo { code_hash, ..Default::default() };
+        let eoa_info = AccountInfo { balance: U256::from(1), ..Default::default() };
+
+        // The contract is deployed in the first block and selfdestructed in the second block, the
+        // balance of the EOA changes in both blocks.
+        let bundle = BundleState::new(
+            vec![
+                (contract, None, None, HashMap::default()),
+                (eoa, Some(AccountInfo::default()), Some(eoa_info.clone()), HashMap::default()),
+            ],
+            vec![
+                vec![
+                    (contract, Some(None), vec![]),
+                    (eoa, Some(Some(AccountInfo::default())), vec![]),
+                ],
+                vec![
+                    (contract, Some(Some(contract_info)), vec![]),
+                    (eoa, Some(Some(eoa_info)), vec![]),
+                ],
+            ],
+            vec![],
+        );
+        let execution_outcome: ExecutionOutcome = ExecutionOutcome {
+            bundle,
+            receipts: vec![vec![], vec![]],
+            first_block: 0,
+            requests: vec![],
+        };
+
+        assert_eq!(
+            execution_outcome.code_changes(),
+            vec![vec![(contract, None)], vec![(contract, Some(code_hash))]]
+        );
+    }
+
+    #[test]
+    fn test_try_split_at() {
+        let execution_outcome: ExecutionOutcome = ExecutionOutcome {
+            receipts: vec![vec![], vec![], vec![]],
+            first_block: 10,
+            ..Default::default()
+        };
+
+        let (lower, higher) = execution_outcome.clone().try_split_at(11).unwrap();
+        assert_eq!(lower.map(|lower| (lower.first_block, lower.len())), Some((10, 1)));
+        assert_eq!((higher.first_block, higher.len()), (11, 2));
+
+        assert_eq!(execution_outcome.clone().try_split_at(13), Err(execution_outcome.clone()));
+        assert_eq!(execution_outcome.clone().try_split_at(9), Err(execution_outcome));
+    }
+
+    #[test]
+    fn test_merge() {
+        let outcome = |first_block, blocks| ExecutionOutcome::<()> {
+            receipts: vec![vec![]; blocks],
+            first_block,
+            ..Default::default()
+        };
+
+        // an empty outcome takes over the first block
+        let mut merged = ExecutionOutcome::default();
+        merged.merge(outcome(10, 2)).unwrap();
+        assert_eq!((merged.first_block, merged.len()), (10, 2));
+
+        merged.merge(outcome(12, 1)).unwrap();
+        assert_eq!((merged.first_block, merged.last_block()), (10, 12));
+
+        // gaps and overlaps are rejected
+        assert_eq!(merged.merge(outcome(14, 1)), Err(outcome(14, 1)));
+        assert_eq!(merged.merge(outcome(12, 1)), Err(outcome(12, 1)));
+        assert_eq!(merged.len(), 3);
+    }
+
+    #[test]
+    fn test_account_reverts() {
+        let address = Address::new([1; 20]);
+        let created = Address::new([2; 20]);
+        let info = |balance| AccountInfo { balance: U256::from(balance), ..Default::default() };
+
+        // `address` changes its balance in blocks 1 and 3 and a storage slot in blocks 2 and 3,
+        // `created` is created in block 2.
+        let bundle = BundleState::new(
+            vec![
+                (
+                    address,
+                    Some(info(1)),
+                    Some(info(4)),
+                    HashMap::from_iter([(U256::from(7), (U256::from(1), U256::from(3)))]),
+                ),
+                (created, None, Some(info(5)), HashMap::default()),
+            ],
+            vec![
+                vec![(address, Some(Some(info(1))), vec![])],
+                vec![
+                    (address, None, vec![(U256::from(7), U256::from(1))]),
+                    (created, Some(None), vec![]),
+                ],
+                vec![(address, Some(Some(info(2))), vec![(U256::from(7), U256::from(2))])],
+            ],
+            vec![],
+        );
+        let execution_outcome: ExecutionOutcome = ExecutionOutcome {
+            bundle,
+            receipts: vec![vec![], vec![], vec![]],
+            first_block: 1,
+            requests: vec![],
+        };
+
+        let storage = |value| BTreeMap::from([(B256::from(U256::from(7)), U256::from(value))]);
+
+        let reverts = execution_outcome.account_reverts(1..=3).unwrap();
+        assert_eq!(
+            reverts,
+            BTreeMap::from([
+                (
+                    address,
+                    AccountRangeRevert {
+                        info: Some(Some((&info(1)).into())),
+                        storage: storage(1),
+                        wipe_storage: false,
+                    }
+                ),
+                (created, AccountRangeRevert { info: Some(None), ..Default::default() }),
+            ])
+        );
+
+        let reverts = execution_outcome.account_reverts(3..=3).unwrap();
+        assert_eq!(
+            reverts,
+            BTreeMap::from([(
+                address,
+                AccountRangeRevert {
+                    info: Some(Some((&info(2)).into())),
+                    storage: storage(2),
+                    wipe_storage: false,
+                }
+            )])
+        );
+
+        // only the storage of the account changed in block 2
+        let reverts = execution_outcome.account_reverts(2..=2).unwrap();
+        assert_eq!(reverts[&address].info, None);
+
+        assert_eq!(execution_outcome.account_reverts(0..=2), None);
+        assert_eq!(execution_outcome.account_reverts(3..=2), None);
+        assert_eq!(execution_outcome.account_reverts(2..=4), None);
+    }
 }
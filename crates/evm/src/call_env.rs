@@ -0,0 +1,123 @@
+//! Adjustments of the EVM environment used for RPC calls.
+
+use crate::EvmEnv;
+
+/// Adjustments applied to the [`EvmEnv`] that is used to execute RPC calls, such as `eth_call` and
+/// `eth_estimateGas`.
+///
+/// By default, this mirrors geth's `SkipAccountChecks` behaviour for calls: the EIP-3607 sender
+/// check and the base fee checks are disabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CallEnvOverrides {
+    /// Whether the EIP-3607 check, which rejects transactions sent from accounts with deployed
+    /// code, is disabled.
+    ///
+    /// Defaults to `true`, because `eth_call` is commonly used with contract senders.
+    pub disable_eip3607: bool,
+    /// Whether the base fee checks are disabled.
+    ///
+    /// Defaults to `true`.
+    pub disable_base_fee: bool,
+    /// Base fee to use for the block environment instead of the one of the block the call is
+    /// executed on.
+    pub base_fee: Option<u64>,
+    /// Whether calls with a gas price of zero are executed with a base fee of zero, so that they
+    /// pass the base fee checks.
+    ///
+    /// Only relevant if base fee checks are enabled.
+    pub allow_zero_gas_price: bool,
+}
+
+impl Default for CallEnvOverrides {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CallEnvOverrides {
+    /// Creates new overrides with the default settings.
+    pub const fn new() -> Self {
+        Self {
+            disable_eip3607: true,
+            disable_base_fee: true,
+            base_fee: None,
+            allow_zero_gas_price: false,
+        }
+    }
+
+    /// Sets whether the EIP-3607 check is disabled.
+    pub const fn with_disable_eip3607(mut self, disable_eip3607: bool) -> Self {
+        self.disable_eip3607 = disable_eip3607;
+        self
+    }
+
+    /// Sets whether the base fee checks are disabled.
+    pub const fn with_disable_base_fee(mut self, disable_base_fee: bool) -> Self {
+        self.disable_base_fee = disable_base_fee;
+        self
+    }
+
+    /// Sets the base fee to use for the block environment.
+    pub const fn with_base_fee(mut self, base_fee: Option<u64>) -> Self {
+        self.base_fee = base_fee;
+        self
+    }
+
+    /// Sets whether calls with a gas price of zero are executed with a base fee of zero.
+    pub const fn with_allow_zero_gas_price(mut self, allow_zero_gas_price: bool) -> Self {
+        self.allow_zero_gas_price = allow_zero_gas_price;
+        self
+    }
+
+    /// Applies the overrides to the given [`EvmEnv`].
+    pub fn apply<Spec>(&self, evm_env: &mut EvmEnv<Spec>) {
+        evm_env.cfg_env.disable_eip3607 = self.disable_eip3607;
+        evm_env.cfg_env.disable_base_fee = self.disable_base_fee;
+        if let Some(base_fee) = self.base_fee {
+            evm_env.block_env.basefee = base_fee;
+        }
+    }
+
+    /// Adjusts the [`EvmEnv`] for a call with the given gas price.
+    ///
+    /// If [`Self::allow_zero_gas_price`] is set, this zeroes the base fee for calls without a gas
+    /// price.
+    pub fn apply_gas_price<Spec>(&self, evm_env: &mut EvmEnv<Spec>, gas_price: u128) {
+        if self.allow_zero_gas_price && gas_price == 0 {
+            evm_env.block_env.basefee = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use revm::primitives::hardfork::SpecId;
+
+    #[test]
+    fn apply_call_env_overrides() {
+        let mut evm_env = EvmEnv::<SpecId>::default();
+        evm_env.block_env.basefee = 7;
+
+        CallEnvOverrides::default().apply(&mut evm_env);
+        assert!(evm_env.cfg_env.disable_eip3607);
+        assert!(evm_env.cfg_env.disable_base_fee);
+        assert_eq!(evm_env.block_env.basefee, 7);
+
+        let overrides = CallEnvOverrides::default()
+            .with_disable_eip3607(false)
+            .with_disable_base_fee(false)
+            .with_base_fee(Some(10))
+            .with_allow_zero_gas_price(true);
+        overrides.apply(&mut evm_env);
+        assert!(!evm_env.cfg_env.disable_eip3607);
+        assert!(!evm_env.cfg_env.disable_base_fee);
+        assert_eq!(evm_env.block_env.basefee, 10);
+
+        overrides.apply_gas_price(&mut evm_env, 1);
+        assert_eq!(evm_env.block_env.basefee, 10);
+        overrides.apply_gas_price(&mut evm_env, 0);
+        assert_eq!(evm_env.block_env.basefee, 0);
+    }
+}
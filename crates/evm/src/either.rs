@@ -96,6 +96,16 @@ where
         }
     }
 
+    fn with_state_mut<F, T>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&mut revm_database::State<DB>) -> T,
+    {
+        match self {
+            Self::Left(a) => a.with_state_mut(f),
+            Self::Right(b) => b.with_state_mut(f),
+        }
+    }
+
     fn size_hint(&self) -> usize {
         match self {
             Self::Left(a) => a.size_hint(),
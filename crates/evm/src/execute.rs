@@ -119,6 +119,14 @@ pub trait Executor<DB: Database>: Sized {
     /// Consumes the executor and returns the [`State`] containing all state changes.
     fn into_state(self) -> State<DB>;
 
+    /// Applies the given closure to the [`State`] of the executor.
+    ///
+    /// This can be used to take data out of the accumulated state between blocks, e.g. the reverts
+    /// of the already executed blocks.
+    fn with_state_mut<F, T>(&mut self, f: F) -> T
+    where
+        F: FnOnce(&mut State<DB>) -> T;
+
     /// The size hint of the batch's tracked state size.
     ///
     /// This is used to optimize DB commits depending on the size of the state.
@@ -469,6 +477,13 @@ where
         self.db
     }
 
+    fn with_state_mut<T, R>(&mut self, f: T) -> R
+    where
+        T: FnOnce(&mut State<DB>) -> R,
+    {
+        f(&mut self.db)
+    }
+
     fn size_hint(&self) -> usize {
         self.db.bundle_state.size_hint()
     }
@@ -530,6 +545,13 @@ mod tests {
             unreachable!()
         }
 
+        fn with_state_mut<F, T>(&mut self, _f: F) -> T
+        where
+            F: FnOnce(&mut State<DB>) -> T,
+        {
+            unreachable!()
+        }
+
         fn size_hint(&self) -> usize {
             0
         }
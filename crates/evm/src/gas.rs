@@ -0,0 +1,197 @@
+//! Versioned schedule of the intrinsic gas of transactions.
+
+use alloy_consensus::Transaction;
+use revm::{
+    interpreter::gas::{calculate_initial_tx_gas, InitialAndFloorGas},
+    primitives::hardfork::SpecId,
+};
+
+/// The rules used to charge the intrinsic gas of a transaction, versioned by the hardfork that
+/// last changed them.
+///
+/// The schedule of a block is derived from its [`SpecId`], so a repricing is activated for a chain
+/// by activating the hardfork that introduces it in the chain spec. Intrinsic gas computed with a
+/// schedule always matches the gas charged by the EVM for the same spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum GasSchedule {
+    /// The initial schedule: 68 gas per non-zero calldata byte.
+    Frontier,
+    /// Contract creations cost 53000 gas (EIP-2).
+    Homestead,
+    /// Non-zero calldata bytes cost 16 gas (EIP-2028).
+    Istanbul,
+    /// Access lists are charged per address and storage key (EIP-2930).
+    Berlin,
+    /// Initcode is charged per word (EIP-3860).
+    Shanghai,
+    /// Calldata is charged at least a floor cost per token (EIP-7623) and authorizations are
+    /// charged per entry (EIP-7702).
+    Prague,
+}
+
+impl GasSchedule {
+    /// The most recent schedule.
+    pub const LATEST: Self = Self::Prague;
+
+    /// Returns the schedule that is used by the given spec.
+    pub fn from_spec_id(spec_id: SpecId) -> Self {
+        if spec_id.is_enabled_in(SpecId::PRAGUE) {
+            Self::Prague
+        } else if spec_id.is_enabled_in(SpecId::SHANGHAI) {
+            Self::Shanghai
+        } else if spec_id.is_enabled_in(SpecId::BERLIN) {
+            Self::Berlin
+        } else if spec_id.is_enabled_in(SpecId::ISTANBUL) {
+            Self::Istanbul
+        } else if spec_id.is_enabled_in(SpecId::HOMESTEAD) {
+            Self::Homestead
+        } else {
+            Self::Frontier
+        }
+    }
+
+    /// Returns the first spec that uses this schedule.
+    pub const fn spec_id(self) -> SpecId {
+        match self {
+            Self::Frontier => SpecId::FRONTIER,
+            Self::Homestead => SpecId::HOMESTEAD,
+            Self::Istanbul => SpecId::ISTANBUL,
+            Self::Berlin => SpecId::BERLIN,
+            Self::Shanghai => SpecId::SHANGHAI,
+            Self::Prague => SpecId::PRAGUE,
+        }
+    }
+
+    /// Returns `true` if transactions are charged at least the calldata floor cost of EIP-7623.
+    pub fn has_calldata_floor(self) -> bool {
+        self >= Self::Prague
+    }
+
+    /// Computes the intrinsic gas of a transaction, and the minimum gas it is charged because of
+    /// its calldata.
+    pub fn intrinsic_gas(
+        self,
+        input: &[u8],
+        is_create: bool,
+        access_list_accounts: u64,
+        access_list_storage_keys: u64,
+        authorizations: u64,
+    ) -> InitialAndFloorGas {
+        calculate_initial_tx_gas(
+            self.spec_id(),
+            input,
+            is_create,
+            access_list_accounts,
+            access_list_storage_keys,
+            authorizations,
+        )
+    }
+
+    /// Returns the minimum gas limit of a transaction, the larger of its intrinsic gas and its
+    /// calldata floor cost.
+    pub fn min_gas_limit(
+        self,
+        input: &[u8],
+        is_create: bool,
+        access_list_accounts: u64,
+        access_list_storage_keys: u64,
+        authorizations: u64,
+    ) -> u64 {
+        let gas = self.intrinsic_gas(
+            input,
+            is_create,
+            access_list_accounts,
+            access_list_storage_keys,
+            authorizations,
+        );
+        gas.initial_gas.max(gas.floor_gas)
+    }
+
+    /// Returns the minimum gas limit of the given transaction, see [`Self::min_gas_limit`].
+    pub fn tx_min_gas_limit(self, tx: &impl Transaction) -> u64 {
+        let access_list = tx.access_list();
+        self.min_gas_limit(
+            tx.input(),
+            tx.is_create(),
+            access_list.map(|list| list.len()).unwrap_or_default() as u64,
+            access_list
+                .map(|list| list.iter().map(|item| item.storage_keys.len()).sum::<usize>())
+                .unwrap_or_default() as u64,
+            tx.authorization_list().map(|list| list.len()).unwrap_or_default() as u64,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The minimum gas limit of a call with a single non-zero calldata byte.
+    fn one_byte_call(schedule: GasSchedule) -> u64 {
+        schedule.min_gas_limit(&[1], false, 0, 0, 0)
+    }
+
+    #[test]
+    fn from_spec_id() {
+        assert_eq!(GasSchedule::from_spec_id(SpecId::FRONTIER), GasSchedule::Frontier);
+        assert_eq!(GasSchedule::from_spec_id(SpecId::BYZANTIUM), GasSchedule::Homestead);
+        assert_eq!(GasSchedule::from_spec_id(SpecId::ISTANBUL), GasSchedule::Istanbul);
+        assert_eq!(GasSchedule::from_spec_id(SpecId::LONDON), GasSchedule::Berlin);
+        assert_eq!(GasSchedule::from_spec_id(SpecId::CANCUN), GasSchedule::Shanghai);
+        assert_eq!(GasSchedule::from_spec_id(SpecId::PRAGUE), GasSchedule::Prague);
+        assert_eq!(GasSchedule::from_spec_id(SpecId::OSAKA), GasSchedule::LATEST);
+
+        for schedule in [
+            GasSchedule::Frontier,
+            GasSchedule::Homestead,
+            GasSchedule::Istanbul,
+            GasSchedule::Berlin,
+            GasSchedule::Shanghai,
+            GasSchedule::Prague,
+        ] {
+            assert_eq!(GasSchedule::from_spec_id(schedule.spec_id()), schedule);
+        }
+    }
+
+    #[test]
+    fn frontier() {
+        assert_eq!(one_byte_call(GasSchedule::Frontier), 21_000 + 68);
+        assert_eq!(GasSchedule::Frontier.min_gas_limit(&[], true, 0, 0, 0), 21_000);
+    }
+
+    #[test]
+    fn homestead() {
+        assert_eq!(GasSchedule::Homestead.min_gas_limit(&[], true, 0, 0, 0), 53_000);
+    }
+
+    #[test]
+    fn istanbul() {
+        assert_eq!(one_byte_call(GasSchedule::Istanbul), 21_000 + 16);
+        assert_eq!(GasSchedule::Istanbul.min_gas_limit(&[0], false, 0, 0, 0), 21_000 + 4);
+    }
+
+    #[test]
+    fn berlin() {
+        assert_eq!(GasSchedule::Berlin.min_gas_limit(&[], false, 1, 2, 0), 21_000 + 2_400 + 3_800);
+    }
+
+    #[test]
+    fn shanghai() {
+        // two words of initcode
+        assert_eq!(
+            GasSchedule::Shanghai.min_gas_limit(&[0; 33], true, 0, 0, 0),
+            53_000 + 33 * 4 + 2 * 2
+        );
+        assert!(!GasSchedule::Shanghai.has_calldata_floor());
+    }
+
+    #[test]
+    fn prague() {
+        assert!(GasSchedule::Prague.has_calldata_floor());
+        // the floor cost of 10 gas per token exceeds the standard cost of 16 gas per byte
+        assert_eq!(one_byte_call(GasSchedule::Prague), 21_000 + 40);
+        assert_eq!(one_byte_call(GasSchedule::Shanghai), 21_000 + 16);
+        assert_eq!(GasSchedule::Prague.min_gas_limit(&[], false, 0, 0, 1), 21_000 + 25_000);
+    }
+}
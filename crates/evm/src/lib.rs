@@ -30,7 +30,11 @@ use reth_primitives_traits::{
 use revm::context::TxEnv;
 use revm_database::State;
 
+pub mod call_env;
+pub use call_env::CallEnvOverrides;
 pub mod either;
+pub mod gas;
+pub use gas::GasSchedule;
 /// EVM environment configuration.
 pub mod execute;
 
@@ -149,6 +153,18 @@ pub trait ConfigureEvm: Send + Sync + Unpin + Clone {
         attributes: Self::NextBlockEnvCtx,
     ) -> ExecutionCtxFor<'_, Self>;
 
+    /// Applies the [`CallEnvOverrides`] to an [`EvmEnv`] that is used to execute RPC calls, such
+    /// as `eth_call` and `eth_estimateGas`.
+    ///
+    /// Chains that need to adjust the call environment beyond the overrides can customize this.
+    fn apply_call_env_overrides(
+        &self,
+        evm_env: &mut EvmEnvFor<Self>,
+        overrides: &CallEnvOverrides,
+    ) {
+        overrides.apply(evm_env);
+    }
+
     /// Returns a [`TxEnv`] from a transaction and [`Address`].
     fn tx_env(&self, transaction: impl IntoTxEnv<TxEnvFor<Self>>) -> TxEnvFor<Self> {
         transaction.into_tx_env()
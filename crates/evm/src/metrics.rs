@@ -194,6 +194,13 @@ mod tests {
             State::builder().with_database(Default::default()).build()
         }
 
+        fn with_state_mut<F, T>(&mut self, _f: F) -> T
+        where
+            F: FnOnce(&mut revm_database::State<DB>) -> T,
+        {
+            unreachable!()
+        }
+
         fn size_hint(&self) -> usize {
             0
         }
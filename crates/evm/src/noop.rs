@@ -55,6 +55,13 @@ impl<DB: Database, P: NodePrimitives> Executor<DB> for NoopBlockExecutorProvider
         unreachable!()
     }
 
+    fn with_state_mut<F, T>(&mut self, _f: F) -> T
+    where
+        F: FnOnce(&mut revm_database::State<DB>) -> T,
+    {
+        unreachable!()
+    }
+
     fn size_hint(&self) -> usize {
         0
     }
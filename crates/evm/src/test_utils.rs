@@ -117,6 +117,13 @@ impl<DB: Database> Executor<DB> for MockExecutorProvider {
         unreachable!()
     }
 
+    fn with_state_mut<F, T>(&mut self, _f: F) -> T
+    where
+        F: FnOnce(&mut revm_database::State<DB>) -> T,
+    {
+        unreachable!()
+    }
+
     fn size_hint(&self) -> usize {
         0
     }
@@ -130,12 +137,4 @@ impl<Factory, DB> BasicBlockExecutor<Factory, DB> {
     {
         f(&self.db)
     }
-
-    /// Provides safe write access to the state
-    pub fn with_state_mut<F, R>(&mut self, f: F) -> R
-    where
-        F: FnOnce(&mut State<DB>) -> R,
-    {
-        f(&mut self.db)
-    }
 }
@@ -0,0 +1,327 @@
+//! An `ExEx` that cross-checks the blocks of the node against a reference RPC node.
+//!
+//! For every committed block, the receipts computed by the local execution are compared with the
+//! receipts of the block with the same hash returned by `eth_getBlockReceipts` of the reference
+//! node. If the reference node doesn't have the block, the block hash is compared with the block of
+//! the same number once the reference node finalized it, so blocks that are reorged out on either
+//! of the nodes are not reported.
+//!
+//! A divergence is logged as an error and counted in the `exex.consistency.divergent_blocks`
+//! metric, giving operators an early warning of a consensus bug in either of the nodes.
+//!
+//! ```ignore
+//! builder.install_exex("consistency-check", |ctx| async move {
+//!     Ok(ConsistencyCheckExEx::connect(ctx, "http://reference-node:8545").await?.run())
+//! })
+//! ```
+
+use crate::ExExContext;
+use alloy_consensus::{BlockHeader, TxReceipt};
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_primitives::{BlockNumber, Log, B256};
+use alloy_provider::{network::AnyNetwork, Provider, ProviderBuilder};
+use futures::TryStreamExt;
+use reth_metrics::{metrics::Counter, Metrics};
+use reth_node_api::FullNodeComponents;
+use reth_provider::BlockHashReader;
+use std::{fmt::Debug, sync::Arc, time::Duration};
+use tokio::sync::mpsc::{self, error::TrySendError, Receiver};
+use tracing::{debug, error, warn};
+
+/// The number of times the receipts of a block that the reference node doesn't have yet are
+/// requested again.
+const REFERENCE_RETRIES: usize = 10;
+
+/// The delay between the requests of a block that the reference node doesn't have yet.
+const REFERENCE_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// The number of committed blocks that are queued for the check.
+///
+/// Blocks are skipped if the reference node can't keep up, so it doesn't hold back the node.
+const QUEUED_BLOCKS: usize = 1024;
+
+/// Metrics of the [`ConsistencyCheckExEx`].
+#[derive(Metrics, Clone)]
+#[metrics(scope = "exex.consistency")]
+struct ConsistencyCheckMetrics {
+    /// The total number of blocks that were compared with the reference node.
+    checked_blocks: Counter,
+    /// The total number of blocks that differ from the block of the reference node.
+    divergent_blocks: Counter,
+    /// The total number of blocks that could not be fetched from the reference node.
+    failed_checks: Counter,
+    /// The total number of blocks that were not checked because the queue was full.
+    skipped_blocks: Counter,
+}
+
+/// The fields of a receipt that are compared with the reference node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptSummary {
+    /// Whether the transaction succeeded.
+    pub success: bool,
+    /// The gas used by the block up to and including the transaction.
+    pub cumulative_gas_used: u64,
+    /// The logs emitted by the transaction.
+    pub logs: Vec<Log>,
+}
+
+impl ReceiptSummary {
+    /// Creates the summary of the given receipt.
+    pub fn new(receipt: &impl TxReceipt<Log = Log>) -> Self {
+        Self {
+            success: receipt.status(),
+            cumulative_gas_used: receipt.cumulative_gas_used(),
+            logs: receipt.logs().to_vec(),
+        }
+    }
+}
+
+/// A committed block and its receipts, as computed by the local execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockSummary {
+    /// The block number.
+    pub number: BlockNumber,
+    /// The block hash.
+    pub hash: B256,
+    /// The receipts of the block.
+    pub receipts: Vec<ReceiptSummary>,
+}
+
+impl BlockSummary {
+    /// Returns the index of the first receipt that differs from the receipts of the reference
+    /// node, or the number of receipts that both have if they have a different number of receipts.
+    pub fn divergent_receipt(&self, reference: &[ReceiptSummary]) -> Option<usize> {
+        self.receipts
+            .iter()
+            .zip(reference)
+            .position(|(local, reference)| local != reference)
+            .or_else(|| {
+                (self.receipts.len() != reference.len())
+                    .then(|| self.receipts.len().min(reference.len()))
+            })
+    }
+}
+
+/// An `ExEx` that compares the committed blocks with the blocks of a reference RPC node.
+///
+/// The blocks are compared on a separate task, so a slow or unavailable reference node doesn't
+/// hold back the node.
+pub struct ConsistencyCheckExEx<Node: FullNodeComponents> {
+    ctx: ExExContext<Node>,
+    reference: Arc<dyn Provider<AnyNetwork>>,
+    reference_url: String,
+}
+
+impl<Node: FullNodeComponents> Debug for ConsistencyCheckExEx<Node> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConsistencyCheckExEx")
+            .field("reference_url", &self.reference_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Node: FullNodeComponents> ConsistencyCheckExEx<Node> {
+    /// Creates the `ExEx` that compares the blocks with the reference node at the given URL.
+    pub async fn connect(ctx: ExExContext<Node>, reference_url: &str) -> eyre::Result<Self> {
+        let reference = ProviderBuilder::new()
+            .disable_recommended_fillers()
+            .network::<AnyNetwork>()
+            .connect(reference_url)
+            .await?;
+        Ok(Self { ctx, reference: Arc::new(reference), reference_url: reference_url.to_string() })
+    }
+
+    /// Runs the `ExEx` until the notification stream ends.
+    pub async fn run(mut self) -> eyre::Result<()> {
+        let metrics = ConsistencyCheckMetrics::default();
+        let (tx, rx) = mpsc::channel(QUEUED_BLOCKS);
+        self.ctx.task_executor().spawn(Box::pin(check_blocks(
+            self.reference.clone(),
+            self.ctx.provider().clone(),
+            rx,
+            metrics.clone(),
+        )));
+
+        while let Some(notification) = self.ctx.notifications.try_next().await? {
+            // reverted blocks are not checked again, the blocks of the new chain are checked
+            // once they are committed
+            if let Some(committed) = notification.committed_chain() {
+                for (block, receipts) in committed.blocks_and_receipts() {
+                    let summary = BlockSummary {
+                        number: block.number(),
+                        hash: block.hash(),
+                        receipts: receipts.iter().map(ReceiptSummary::new).collect(),
+                    };
+                    match tx.try_send(summary) {
+                        Ok(()) => {}
+                        Err(TrySendError::Full(summary)) => {
+                            debug!(target: "exex::consistency", number = summary.number, "Skipping block, the check is lagging behind");
+                            metrics.skipped_blocks.increment(1);
+                        }
+                        Err(TrySendError::Closed(_)) => {
+                            eyre::bail!("consistency check task terminated")
+                        }
+                    }
+                }
+                self.ctx.send_finished_height(committed.tip().num_hash())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Compares the received blocks with the blocks of the reference node.
+async fn check_blocks<P: BlockHashReader>(
+    reference: Arc<dyn Provider<AnyNetwork>>,
+    provider: P,
+    mut blocks: Receiver<BlockSummary>,
+    metrics: ConsistencyCheckMetrics,
+) {
+    while let Some(local) = blocks.recv().await {
+        match check_block(&*reference, &provider, &local).await {
+            Ok(CheckOutcome::Matches) => {
+                metrics.checked_blocks.increment(1);
+                debug!(target: "exex::consistency", number = local.number, "Block matches the reference node");
+            }
+            Ok(CheckOutcome::Reorged) => {
+                debug!(target: "exex::consistency", number = local.number, hash = %local.hash, "Block was reorged out before it was checked");
+            }
+            Ok(CheckOutcome::DivergentReceipt { index, reference: reference_receipt }) => {
+                metrics.checked_blocks.increment(1);
+                metrics.divergent_blocks.increment(1);
+                error!(
+                    target: "exex::consistency",
+                    number = local.number,
+                    hash = %local.hash,
+                    index,
+                    local = ?local.receipts.get(index),
+                    reference = ?reference_receipt,
+                    "Receipt diverges from the reference node"
+                );
+            }
+            Ok(CheckOutcome::DivergentBlock { reference_hash }) => {
+                metrics.checked_blocks.increment(1);
+                metrics.divergent_blocks.increment(1);
+                error!(
+                    target: "exex::consistency",
+                    number = local.number,
+                    hash = %local.hash,
+                    %reference_hash,
+                    "Block diverges from the finalized block of the reference node"
+                );
+            }
+            Ok(CheckOutcome::Unavailable) => {
+                metrics.failed_checks.increment(1);
+                warn!(target: "exex::consistency", number = local.number, hash = %local.hash, "Reference node doesn't have the block");
+            }
+            Err(err) => {
+                metrics.failed_checks.increment(1);
+                warn!(target: "exex::consistency", number = local.number, %err, "Failed to check block against the reference node");
+            }
+        }
+    }
+}
+
+/// The outcome of the check of a block.
+#[derive(Debug)]
+enum CheckOutcome {
+    /// The receipts match the receipts of the reference node.
+    Matches,
+    /// The block is no longer canonical on the node.
+    Reorged,
+    /// The receipt at the index differs from the receipt of the reference node.
+    DivergentReceipt { index: usize, reference: Option<ReceiptSummary> },
+    /// The reference node finalized another block at the number of the block.
+    DivergentBlock { reference_hash: B256 },
+    /// The reference node doesn't have the block and didn't finalize a block at its number yet.
+    Unavailable,
+}
+
+/// Compares the block with the block of the same hash of the reference node, waiting for the
+/// reference node to catch up if it doesn't have the block yet.
+async fn check_block<P: BlockHashReader>(
+    reference: &dyn Provider<AnyNetwork>,
+    provider: &P,
+    local: &BlockSummary,
+) -> eyre::Result<CheckOutcome> {
+    for _ in 0..REFERENCE_RETRIES {
+        if provider.block_hash(local.number)? != Some(local.hash) {
+            return Ok(CheckOutcome::Reorged)
+        }
+
+        if let Some(receipts) = reference.get_block_receipts(BlockId::hash(local.hash)).await? {
+            let receipts = receipts
+                .iter()
+                .map(|receipt| {
+                    let receipt = &receipt.inner.inner;
+                    ReceiptSummary {
+                        success: receipt.status(),
+                        cumulative_gas_used: receipt.cumulative_gas_used(),
+                        logs: receipt.logs().iter().map(|log| log.inner.clone()).collect(),
+                    }
+                })
+                .collect::<Vec<_>>();
+            return Ok(match local.divergent_receipt(&receipts) {
+                Some(index) => CheckOutcome::DivergentReceipt {
+                    index,
+                    reference: receipts.get(index).cloned(),
+                },
+                None => CheckOutcome::Matches,
+            })
+        }
+
+        // the reference node doesn't have the block, either because it's behind or because it's
+        // on another chain, which is only a divergence if it finalized that chain
+        let finalized = reference.get_block_by_number(BlockNumberOrTag::Finalized).await?;
+        if finalized.is_some_and(|finalized| finalized.header.number >= local.number) {
+            if let Some(block) = reference.get_block_by_number(local.number.into()).await? {
+                if block.header.hash != local.hash {
+                    return Ok(CheckOutcome::DivergentBlock { reference_hash: block.header.hash })
+                }
+            }
+        }
+
+        tokio::time::sleep(REFERENCE_RETRY_DELAY).await;
+    }
+
+    Ok(CheckOutcome::Unavailable)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, Bytes, LogData};
+
+    fn receipt(cumulative_gas_used: u64) -> ReceiptSummary {
+        ReceiptSummary {
+            success: true,
+            cumulative_gas_used,
+            logs: vec![Log {
+                address: Address::with_last_byte(1),
+                data: LogData::new_unchecked(vec![B256::with_last_byte(2)], Bytes::new()),
+            }],
+        }
+    }
+
+    #[test]
+    fn divergent_receipt() {
+        let local = BlockSummary {
+            number: 1,
+            hash: B256::with_last_byte(1),
+            receipts: vec![receipt(21_000), receipt(42_000)],
+        };
+        assert_eq!(local.divergent_receipt(&local.receipts), None);
+
+        let mut reference = local.receipts.clone();
+        reference[1].success = false;
+        assert_eq!(local.divergent_receipt(&reference), Some(1));
+
+        let mut reference = local.receipts.clone();
+        reference[0].logs.clear();
+        assert_eq!(local.divergent_receipt(&reference), Some(0));
+
+        assert_eq!(local.divergent_receipt(&local.receipts[..1]), Some(1));
+        assert_eq!(local.divergent_receipt(&[]), Some(0));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{ExExContextDyn, ExExEvent, ExExNotifications, ExExNotificationsStream};
 use alloy_eips::BlockNumHash;
-use reth_exex_types::ExExHead;
+use reth_exex_types::{ExExHead, ExExPruneTargets};
 use reth_node_api::{
     FullNodeComponents, NodePrimitives, NodeTypes, NodeTypesWithEngine, PrimitivesTy,
 };
@@ -36,6 +36,11 @@ pub struct ExExContext<Node: FullNodeComponents> {
     /// Once an [`ExExNotification`](crate::ExExNotification) is sent over the channel, it is
     /// considered delivered by the node.
     pub notifications: ExExNotifications<Node::Provider, Node::Executor>,
+    /// Prune targets of data owned by `ExEx`'s.
+    ///
+    /// An `ExEx` that stores data in its own database can register a prune target to be notified
+    /// when it is safe to prune it, in coordination with the pruner of the node.
+    pub prune_targets: ExExPruneTargets,
 
     /// Node components
     pub components: Node,
@@ -54,6 +59,7 @@ where
             .field("reth_config", &self.reth_config)
             .field("events", &self.events)
             .field("notifications", &self.notifications)
+            .field("prune_targets", &self.prune_targets)
             .field("components", &"...")
             .finish()
     }
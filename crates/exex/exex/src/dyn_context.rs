@@ -3,6 +3,7 @@
 
 use alloy_eips::BlockNumHash;
 use reth_chainspec::EthChainSpec;
+use reth_exex_types::ExExPruneTargets;
 use reth_node_api::{FullNodeComponents, HeaderTy, NodePrimitives, NodeTypes, PrimitivesTy};
 use reth_node_core::node_config::NodeConfig;
 use reth_primitives::EthPrimitives;
@@ -36,6 +37,11 @@ pub struct ExExContextDyn<N: NodePrimitives = EthPrimitives> {
     /// Once an [`ExExNotification`](crate::ExExNotification) is sent over the channel, it is
     /// considered delivered by the node.
     pub notifications: Box<dyn ExExNotificationsStream<N>>,
+    /// Prune targets of data owned by `ExEx`'s.
+    ///
+    /// An `ExEx` that stores data in its own database can register a prune target to be notified
+    /// when it is safe to prune it, in coordination with the pruner of the node.
+    pub prune_targets: ExExPruneTargets,
 }
 
 impl<N: NodePrimitives> Debug for ExExContextDyn<N> {
@@ -46,6 +52,7 @@ impl<N: NodePrimitives> Debug for ExExContextDyn<N> {
             .field("reth_config", &self.reth_config)
             .field("events", &self.events)
             .field("notifications", &"...")
+            .field("prune_targets", &self.prune_targets)
             .finish()
     }
 }
@@ -68,6 +75,7 @@ where
             reth_config: ctx.reth_config,
             events: ctx.events,
             notifications,
+            prune_targets: ctx.prune_targets,
         }
     }
 }
@@ -109,6 +109,12 @@ pub use notifications::*;
 mod wal;
 pub use wal::*;
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "consistency-check")]
+pub mod consistency;
+
 // Re-export exex types
 #[doc(inline)]
 pub use reth_exex_types::*;
@@ -1,8 +1,10 @@
 use crate::{
-    wal::Wal, ExExEvent, ExExNotification, ExExNotifications, FinishedExExHeight, WalHandle,
+    wal::Wal, ExExEvent, ExExManagerStatus, ExExNotification, ExExNotifications, ExExStatus,
+    ExExStatusHandle, FinishedExExHeight, WalHandle,
 };
 use alloy_consensus::BlockHeader;
 use alloy_eips::BlockNumHash;
+use alloy_primitives::BlockNumber;
 use futures::StreamExt;
 use itertools::Itertools;
 use metrics::Gauge;
@@ -14,7 +16,7 @@ use reth_primitives::{EthPrimitives, SealedHeader};
 use reth_provider::HeaderProvider;
 use reth_tracing::tracing::{debug, warn};
 use std::{
-    collections::VecDeque,
+    collections::{HashMap, VecDeque},
     fmt::Debug,
     future::{poll_fn, Future},
     ops::Not,
@@ -86,6 +88,9 @@ pub struct ExExHandle<N: NodePrimitives = EthPrimitives> {
     ///
     /// If this is `None`, the `ExEx` has not emitted a `FinishedHeight` event.
     finished_height: Option<BlockNumHash>,
+    /// The WAL file IDs of the notifications that were spilled to the WAL while the `ExEx` was
+    /// paused, oldest first.
+    spilled: VecDeque<u32>,
 }
 
 impl<N: NodePrimitives> ExExHandle<N> {
@@ -113,6 +118,7 @@ impl<N: NodePrimitives> ExExHandle<N> {
                 receiver: event_rx,
                 next_notification_id: 0,
                 finished_height: None,
+                spilled: VecDeque::new(),
             },
             event_tx,
             notifications,
@@ -182,6 +188,30 @@ impl<N: NodePrimitives> ExExHandle<N> {
             Err(err) => Poll::Ready(Err(err)),
         }
     }
+
+    /// Sends the notifications that were spilled to the WAL while the `ExEx` was paused, oldest
+    /// first.
+    ///
+    /// Returns `Poll::Ready(Ok(()))` once all of them were sent.
+    fn poll_send_spilled(&mut self, cx: &mut Context<'_>, wal: &Wal<N>) -> Poll<eyre::Result<()>> {
+        while let Some(&file_id) = self.spilled.front() {
+            ready!(self.sender.poll_reserve(cx))?;
+
+            if let Some(notification) = wal.read_notification(file_id)? {
+                debug!(target: "exex::manager", exex_id = %self.id, %file_id, "Sending spilled notification");
+                self.sender.send_item(notification)?;
+                self.metrics.notifications_sent_total.increment(1);
+            } else {
+                // The WAL is only finalized below the finished height of all ExExes, so this
+                // notification contains no blocks the ExEx didn't finish yet.
+                debug!(target: "exex::manager", exex_id = %self.id, %file_id, "Spilled notification was finalized, skipping");
+                self.sender.abort_send();
+            }
+            self.spilled.pop_front();
+        }
+
+        Poll::Ready(Ok(()))
+    }
 }
 
 /// Metrics for the `ExEx` manager.
@@ -229,6 +259,8 @@ pub struct ExExManager<P, N: NodePrimitives> {
     /// The first element of the tuple is a monotonically increasing ID unique to the notification
     /// (the second element of the tuple).
     buffer: VecDeque<(usize, ExExNotification<N>)>,
+    /// The WAL file IDs of the notifications in the buffer that were committed to the WAL.
+    wal_file_ids: HashMap<usize, u32>,
     /// Max size of the internal state notifications buffer.
     max_capacity: usize,
     /// Current state notifications buffer capacity.
@@ -249,6 +281,10 @@ pub struct ExExManager<P, N: NodePrimitives> {
 
     /// A handle to the `ExEx` manager.
     handle: ExExManagerHandle<N>,
+    /// Shared status of the manager, also used to pause and resume `ExEx`'s.
+    status: ExExStatusHandle,
+    /// The tip of the latest notification pushed into the buffer.
+    tip: Option<BlockNumber>,
     /// Metrics for the `ExEx` manager.
     metrics: ExExManagerMetrics,
 }
@@ -297,6 +333,7 @@ where
             min_id: 0,
             next_id: 0,
             buffer: VecDeque::with_capacity(max_capacity),
+            wal_file_ids: HashMap::new(),
             max_capacity,
             current_capacity: Arc::clone(&current_capacity),
 
@@ -314,15 +351,28 @@ where
                 current_capacity,
                 finished_height: finished_height_rx,
             },
+            status: ExExStatusHandle::default(),
+            tip: None,
             metrics,
         }
     }
 
+    /// Sets the handle the manager publishes its status to.
+    pub fn with_status_handle(mut self, status: ExExStatusHandle) -> Self {
+        self.status = status;
+        self
+    }
+
     /// Returns the handle to the manager.
     pub fn handle(&self) -> ExExManagerHandle<N> {
         self.handle.clone()
     }
 
+    /// Returns the handle to the status of the manager.
+    pub fn status_handle(&self) -> ExExStatusHandle {
+        self.status.clone()
+    }
+
     /// Updates the current buffer capacity and notifies all `is_ready` watchers of the manager's
     /// readiness to receive notifications.
     fn update_capacity(&self) {
@@ -339,10 +389,45 @@ where
     /// Pushes a new notification into the managers internal buffer, assigning the notification a
     /// unique ID.
     fn push_notification(&mut self, notification: ExExNotification<N>) {
+        self.tip = match (notification.committed_chain(), notification.reverted_chain()) {
+            (Some(committed), _) => Some(committed.tip().number()),
+            (None, Some(reverted)) => Some(reverted.first().number().saturating_sub(1)),
+            (None, None) => self.tip,
+        };
+
         let next_id = self.next_id;
         self.buffer.push_back((next_id, notification));
         self.next_id += 1;
     }
+
+    /// Publishes the current status of the manager and all `ExEx`'s to the status handle.
+    fn update_status(&self, cx: &Context<'_>) {
+        let exexs = self
+            .exex_handles
+            .iter()
+            .map(|exex| ExExStatus {
+                id: exex.id.clone(),
+                finished_height: exex.finished_height,
+                lag: self
+                    .tip
+                    .zip(exex.finished_height)
+                    .map(|(tip, finished)| tip.saturating_sub(finished.number)),
+                pending_notifications: self.next_id.saturating_sub(exex.next_notification_id) +
+                    exex.spilled.len(),
+                paused: false,
+            })
+            .collect();
+
+        self.status.update(
+            ExExManagerStatus {
+                tip: self.tip,
+                buffer_size: self.buffer.len(),
+                buffer_capacity: self.max_capacity,
+                exexs,
+            },
+            cx.waker(),
+        );
+    }
 }
 
 impl<P, N> ExExManager<P, N>
@@ -432,11 +517,13 @@ where
     /// 2. Finalize the WAL with the finalized header, if necessary.
     /// 3. Drain [`ExExManagerHandle`] notifications, push them to the internal buffer and update
     ///    the internal buffer capacity.
-    /// 5. Send notifications from the internal buffer to those ExExes that are ready to receive new
-    ///    notifications.
+    /// 4. Spill the notifications of paused ExExes to the WAL. Send the spilled notifications of
+    ///    resumed ExExes, and afterwards notifications from the internal buffer to those ExExes
+    ///    that are ready to receive new notifications.
     /// 5. Remove notifications from the internal buffer that have been sent to **all** ExExes and
     ///    update the internal buffer capacity.
     /// 6. Update the channel with the lowest [`FinishedExExHeight`] among all ExExes.
+    /// 7. Publish the status of the manager and all ExExes.
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         let this = self.get_mut();
 
@@ -473,7 +560,8 @@ where
                 match source {
                     ExExNotificationSource::BlockchainTree => {
                         debug!(target: "exex::manager", ?committed_tip, ?reverted_tip, "Committing notification to WAL");
-                        this.wal.commit(&notification)?;
+                        let file_id = this.wal.commit_with_file_id(&notification)?;
+                        this.wal_file_ids.insert(this.next_id, file_id);
                     }
                     ExExNotificationSource::Pipeline => {
                         debug!(target: "exex::manager", ?committed_tip, ?reverted_tip, "Notification was sent from pipeline, skipping WAL commit");
@@ -500,10 +588,36 @@ where
                 .next_notification_id
                 .checked_sub(this.min_id)
                 .expect("exex expected notification ID outside the manager's range");
-            if let Some(notification) = this.buffer.get(notification_index) {
-                if let Poll::Ready(Err(err)) = exex.send(cx, notification) {
+            if this.status.is_paused(&exex.id) {
+                // Spill the pending notifications to the WAL, so that the paused ExEx doesn't
+                // hold back the buffer. Pipeline notifications are only committed to the WAL here.
+                for (id, notification) in this.buffer.range(notification_index..) {
+                    let file_id = match this.wal_file_ids.get(id) {
+                        Some(file_id) => *file_id,
+                        None => {
+                            let file_id = this.wal.commit_with_file_id(notification)?;
+                            this.wal_file_ids.insert(*id, file_id);
+                            file_id
+                        }
+                    };
+                    debug!(target: "exex::manager", exex_id = %exex.id, notification_id = %id, %file_id, "Spilling notification of paused ExEx");
+                    exex.spilled.push_back(file_id);
+                    exex.next_notification_id = id + 1;
+                }
+            } else {
+                let spilled_sent = match exex.poll_send_spilled(cx, &this.wal) {
+                    Poll::Ready(Ok(())) => true,
                     // The channel was closed, which is irrecoverable for the manager
-                    return Poll::Ready(Err(err.into()))
+                    Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                    Poll::Pending => false,
+                };
+                if let Some(notification) =
+                    this.buffer.get(notification_index).filter(|_| spilled_sent)
+                {
+                    if let Poll::Ready(Err(err)) = exex.send(cx, notification) {
+                        // The channel was closed, which is irrecoverable for the manager
+                        return Poll::Ready(Err(err.into()))
+                    }
                 }
             }
             min_id = min_id.min(exex.next_notification_id);
@@ -513,6 +627,7 @@ where
         // Remove processed buffered notifications
         debug!(target: "exex::manager", %min_id, "Updating lowest notification id in buffer");
         this.buffer.retain(|&(id, _)| id >= min_id);
+        this.wal_file_ids.retain(|&id, _| id >= min_id);
         this.min_id = min_id;
 
         // Update capacity
@@ -526,6 +641,9 @@ where
             let _ = this.finished_height.send(FinishedExExHeight::Height(finished_height));
         }
 
+        // Publish the status
+        this.update_status(cx);
+
         Poll::Pending
     }
 }
@@ -1094,6 +1212,69 @@ mod tests {
         assert_eq!(pinned_manager.buffer.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_paused_exex() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let wal = Wal::new(temp_dir.path()).unwrap();
+
+        let provider_factory = create_test_provider_factory();
+
+        let (exex_handle, _, _notifications) = ExExHandle::new(
+            "test_exex".to_string(),
+            Default::default(),
+            (),
+            MockExecutorProvider::default(),
+            wal.handle(),
+        );
+
+        let exex_manager = ExExManager::new(
+            provider_factory,
+            vec![exex_handle],
+            10,
+            Wal::new(temp_dir.path()).unwrap(),
+            empty_finalized_header_stream(),
+        );
+        let status = exex_manager.status_handle();
+
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        let mut pinned_manager = std::pin::pin!(exex_manager);
+
+        // The ExEx is unknown until the manager published its status
+        assert_eq!(status.set_paused("test_exex", true), None);
+        assert!(pinned_manager.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(status.set_paused("test_exex", true), Some(true));
+
+        let mut block: RecoveredBlock<reth_primitives::Block> = Default::default();
+        block.set_block_number(10);
+        let notification = ExExNotification::ChainCommitted {
+            new: Arc::new(Chain::new(vec![block], Default::default(), Default::default())),
+        };
+        pinned_manager
+            .handle
+            .exex_tx
+            .send((ExExNotificationSource::Pipeline, notification))
+            .unwrap();
+
+        // The notification is spilled to the WAL while the ExEx is paused, freeing the buffer
+        assert!(pinned_manager.as_mut().poll(&mut cx).is_pending());
+        let manager_status = status.status();
+        assert_eq!(manager_status.tip, Some(10));
+        assert_eq!(manager_status.buffer_size, 0);
+        assert_eq!(manager_status.exexs[0].pending_notifications, 1);
+        assert!(manager_status.exexs[0].paused);
+        assert_eq!(pinned_manager.wal.num_blocks(), 1);
+        assert_eq!(pinned_manager.exex_handles[0].spilled.len(), 1);
+
+        // Once resumed, the notification is sent from the WAL
+        assert_eq!(status.set_paused("test_exex", false), Some(true));
+        assert!(pinned_manager.as_mut().poll(&mut cx).is_pending());
+        let manager_status = status.status();
+        assert_eq!(manager_status.buffer_size, 0);
+        assert_eq!(manager_status.exexs[0].pending_notifications, 0);
+        assert!(!manager_status.exexs[0].paused);
+        assert!(pinned_manager.exex_handles[0].spilled.is_empty());
+    }
+
     #[tokio::test]
     async fn exex_handle_new() {
         let provider_factory = create_test_provider_factory();
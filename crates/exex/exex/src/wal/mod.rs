@@ -56,9 +56,23 @@ where
 
     /// Commits the notification to WAL.
     pub fn commit(&self, notification: &ExExNotification<N>) -> WalResult<()> {
+        self.inner.commit(notification).map(|_| ())
+    }
+
+    /// Commits the notification to WAL, returning the ID of the file it was written to.
+    pub(crate) fn commit_with_file_id(&self, notification: &ExExNotification<N>) -> WalResult<u32> {
         self.inner.commit(notification)
     }
 
+    /// Reads the notification from the file with the given ID, returning `None` if it was
+    /// finalized already.
+    pub(crate) fn read_notification(&self, file_id: u32) -> WalResult<Option<ExExNotification<N>>> {
+        self.inner
+            .storage
+            .read_notification(file_id)
+            .map(|entry| entry.map(|(notification, _)| notification))
+    }
+
     /// Finalizes the WAL up to the given canonical block, inclusive.
     ///
     /// The caller should check that all ExExes are on the canonical chain and will not need any
@@ -147,7 +161,7 @@ where
         reverted_block_range = ?notification.reverted_chain().as_ref().map(|chain| chain.range()),
         committed_block_range = ?notification.committed_chain().as_ref().map(|chain| chain.range())
     ))]
-    fn commit(&self, notification: &ExExNotification<N>) -> WalResult<()> {
+    fn commit(&self, notification: &ExExNotification<N>) -> WalResult<u32> {
         let mut block_cache = self.block_cache.write();
 
         let file_id = self.next_file_id.fetch_add(1, Ordering::Relaxed);
@@ -158,7 +172,7 @@ where
 
         self.update_metrics(&block_cache, size as i64);
 
-        Ok(())
+        Ok(file_id)
     }
 
     #[instrument(skip(self))]
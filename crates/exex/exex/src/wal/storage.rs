@@ -193,8 +193,12 @@ mod tests {
         let old_block = random_block(&mut rng, 0, Default::default()).try_recover()?;
         let new_block = random_block(&mut rng, 0, Default::default()).try_recover()?;
 
+        // the attached trie updates of the blocks are persisted as well
+        let mut new = Chain::new(vec![new_block], Default::default(), None);
+        new.set_block_trie_updates(0, Default::default());
+
         let notification = ExExNotification::ChainReorged {
-            new: Arc::new(Chain::new(vec![new_block], Default::default(), None)),
+            new: Arc::new(new),
             old: Arc::new(Chain::new(vec![old_block], Default::default(), None)),
         };
 
@@ -0,0 +1,86 @@
+use crate::wasm::WasmPluginError;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// The manifest of a WASM plugin.
+///
+/// Manifests are TOML files:
+///
+/// ```toml
+/// name = "my-plugin"
+/// # relative to the directory of the manifest
+/// module = "my_plugin.wasm"
+///
+/// [limits]
+/// fuel_per_call = 10000000
+/// max_memory_bytes = 16777216
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PluginManifest {
+    /// The name of the plugin, used in logs.
+    pub name: String,
+    /// The path to the WASM module of the plugin.
+    pub module: PathBuf,
+    /// The resource limits of the plugin.
+    #[serde(default)]
+    pub limits: PluginLimits,
+}
+
+impl PluginManifest {
+    /// Reads the manifest at the given path.
+    ///
+    /// A relative module path is resolved against the directory of the manifest.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, WasmPluginError> {
+        let path = path.as_ref();
+        let mut manifest: Self = toml::from_str(&reth_fs_util::read_to_string(path)?)?;
+        if manifest.module.is_relative() {
+            if let Some(dir) = path.parent() {
+                manifest.module = dir.join(&manifest.module);
+            }
+        }
+        Ok(manifest)
+    }
+}
+
+/// The resources a plugin may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PluginLimits {
+    /// The fuel available to each call into the plugin, roughly the number of executed
+    /// instructions.
+    pub fuel_per_call: u64,
+    /// The maximum size of the linear memory of the plugin.
+    pub max_memory_bytes: usize,
+}
+
+impl Default for PluginLimits {
+    fn default() -> Self {
+        Self { fuel_per_call: 100_000_000, max_memory_bytes: 64 * 1024 * 1024 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("plugin.toml");
+        std::fs::write(
+            &path,
+            "name = \"indexer\"\nmodule = \"indexer.wasm\"\n\n[limits]\nfuel_per_call = 1000\n",
+        )
+        .unwrap();
+
+        let manifest = PluginManifest::from_path(&path).unwrap();
+        assert_eq!(manifest.name, "indexer");
+        assert_eq!(manifest.module, dir.path().join("indexer.wasm"));
+        assert_eq!(manifest.limits, PluginLimits { fuel_per_call: 1000, ..Default::default() });
+
+        std::fs::write(&path, "name = \"indexer\"\nmodule = \"/indexer.wasm\"\nversion = 1\n")
+            .unwrap();
+        assert!(PluginManifest::from_path(&path).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+//! An `ExEx` that runs user plugins compiled to WASM.
+//!
+//! Plugins extend the node without recompiling it. Each plugin is described by a
+//! [`PluginManifest`] and must implement the following ABI, version [`ABI_VERSION`]:
+//!
+//! - `memory`: the exported linear memory of the plugin.
+//! - `reth_abi_version() -> i32`: returns the version of the ABI implemented by the plugin.
+//! - `reth_alloc(len: i32) -> i32`: returns a pointer to `len` bytes that the host writes the input
+//!   of the next handler call to.
+//! - `on_block_committed(ptr: i32, len: i32) -> i32`: called for every committed block, in
+//!   ascending order. The input is the block number (`u64`, little endian), the block hash and the
+//!   parent hash.
+//! - `on_reorg(ptr: i32, len: i32) -> i32`: called when blocks are reverted, before the blocks of
+//!   the new chain are committed. The input is the number of the first and of the last reverted
+//!   block (`u64`, little endian) and the hash of the last reverted block.
+//!
+//! Handlers return `0` on success. The only function the host provides to plugins is
+//! `env.reth_log(ptr: i32, len: i32)`, which logs a UTF-8 message.
+//!
+//! Plugins run in parallel on the blocking thread pool, every plugin handles the notifications in
+//! order. A plugin that fails, traps or exceeds its [`PluginLimits`] is disabled, the node and the
+//! other plugins are not affected.
+
+mod manifest;
+pub use manifest::*;
+
+mod plugin;
+pub use plugin::*;
+
+use crate::ExExContext;
+use alloy_consensus::BlockHeader;
+use futures::TryStreamExt;
+use reth_fs_util::FsPathError;
+use reth_node_api::FullNodeComponents;
+use std::{fmt::Debug, path::Path, sync::Arc};
+use tracing::error;
+
+/// The version of the plugin ABI implemented by the host.
+pub const ABI_VERSION: i32 = 1;
+
+/// An error of a WASM plugin.
+#[derive(Debug, thiserror::Error)]
+pub enum WasmPluginError {
+    /// The manifest or the module could not be read.
+    #[error(transparent)]
+    Io(#[from] FsPathError),
+    /// The manifest is invalid.
+    #[error("invalid plugin manifest: {0}")]
+    Manifest(#[from] toml::de::Error),
+    /// The module is invalid, doesn't implement the ABI or trapped.
+    #[error(transparent)]
+    Wasm(#[from] wasmi::Error),
+    /// The plugin implements a different version of the ABI.
+    #[error("unsupported plugin ABI version {0}, expected {ABI_VERSION}")]
+    UnsupportedAbiVersion(i32),
+    /// The plugin doesn't export its memory.
+    #[error("plugin doesn't export its memory")]
+    MissingMemory,
+    /// The buffer returned by `reth_alloc` is out of bounds.
+    #[error("invalid input buffer at {0}")]
+    InvalidBuffer(i32),
+    /// A handler returned an error code.
+    #[error("{handler} failed with code {code}")]
+    HandlerFailed {
+        /// The name of the handler.
+        handler: &'static str,
+        /// The returned error code.
+        code: i32,
+    },
+}
+
+/// An `ExEx` that forwards the notifications of the node to WASM plugins.
+pub struct WasmExEx<Node: FullNodeComponents> {
+    ctx: ExExContext<Node>,
+    plugins: Vec<WasmPlugin>,
+}
+
+impl<Node: FullNodeComponents> Debug for WasmExEx<Node> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmExEx").field("plugins", &self.plugins).finish_non_exhaustive()
+    }
+}
+
+impl<Node: FullNodeComponents> WasmExEx<Node> {
+    /// Creates the `ExEx` with the given plugins.
+    pub const fn new(ctx: ExExContext<Node>, plugins: Vec<WasmPlugin>) -> Self {
+        Self { ctx, plugins }
+    }
+
+    /// Creates the `ExEx` with the plugins of the given manifests.
+    pub fn load(
+        ctx: ExExContext<Node>,
+        manifests: impl IntoIterator<Item = impl AsRef<Path>>,
+    ) -> Result<Self, WasmPluginError> {
+        let plugins = manifests
+            .into_iter()
+            .map(|path| WasmPlugin::load(&PluginManifest::from_path(path)?))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(ctx, plugins))
+    }
+
+    /// Runs the `ExEx` until the notification stream ends.
+    pub async fn run(mut self) -> eyre::Result<()> {
+        while let Some(notification) = self.ctx.notifications.try_next().await? {
+            let mut events = Vec::new();
+            if let Some(reverted) = notification.reverted_chain() {
+                events.push(PluginEvent::Reorg {
+                    reverted: reverted.range(),
+                    reverted_tip: reverted.tip().hash(),
+                });
+            }
+            if let Some(committed) = notification.committed_chain() {
+                events.extend(committed.blocks_iter().map(|block| PluginEvent::BlockCommitted {
+                    block: block.num_hash(),
+                    parent_hash: block.header().parent_hash(),
+                }));
+            }
+
+            self.dispatch(events).await?;
+
+            if let Some(committed) = notification.committed_chain() {
+                self.ctx.send_finished_height(committed.tip().num_hash())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Calls every plugin with the events on the blocking thread pool and disables the plugins
+    /// that failed.
+    ///
+    /// The plugins run in parallel, so a slow plugin doesn't delay the others.
+    async fn dispatch(&mut self, events: Vec<PluginEvent>) -> eyre::Result<()> {
+        if events.is_empty() {
+            return Ok(())
+        }
+
+        let events = Arc::new(events);
+        let tasks = std::mem::take(&mut self.plugins).into_iter().map(|mut plugin| {
+            let events = Arc::clone(&events);
+            tokio::task::spawn_blocking(move || {
+                let result = events.iter().try_for_each(|event| plugin.handle(event));
+                (plugin, result)
+            })
+        });
+
+        for task in futures::future::join_all(tasks).await {
+            match task? {
+                (plugin, Ok(())) => self.plugins.push(plugin),
+                (plugin, Err(err)) => {
+                    error!(target: "exex::wasm", plugin = %plugin.name(), %err, "Disabling plugin");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
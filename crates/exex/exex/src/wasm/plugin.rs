@@ -0,0 +1,293 @@
+use crate::wasm::{PluginManifest, WasmPluginError, ABI_VERSION};
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{BlockNumber, B256};
+use std::ops::RangeInclusive;
+use wasmi::{
+    Caller, Config, Engine, Extern, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder,
+    TypedFunc,
+};
+
+/// A notification that is forwarded to the plugins.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginEvent {
+    /// A block was committed, see [`WasmPlugin::on_block_committed`].
+    BlockCommitted {
+        /// The number and hash of the block.
+        block: BlockNumHash,
+        /// The hash of the parent block.
+        parent_hash: B256,
+    },
+    /// Blocks were reverted, see [`WasmPlugin::on_reorg`].
+    Reorg {
+        /// The range of the reverted blocks.
+        reverted: RangeInclusive<BlockNumber>,
+        /// The hash of the last reverted block.
+        reverted_tip: B256,
+    },
+}
+
+/// The state of a plugin instance.
+#[derive(Debug)]
+struct PluginState {
+    name: String,
+    limits: StoreLimits,
+}
+
+/// A loaded WASM plugin.
+///
+/// The plugin runs in a sandbox: the only host function it may import is `env.reth_log`, its
+/// memory is limited and every call into it is metered with a fixed amount of fuel.
+#[derive(Debug)]
+pub struct WasmPlugin {
+    store: Store<PluginState>,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    on_block_committed: TypedFunc<(i32, i32), i32>,
+    on_reorg: TypedFunc<(i32, i32), i32>,
+    fuel_per_call: u64,
+}
+
+impl WasmPlugin {
+    /// Loads the module of the manifest and instantiates the plugin.
+    pub fn load(manifest: &PluginManifest) -> Result<Self, WasmPluginError> {
+        Self::new(manifest, &reth_fs_util::read(&manifest.module)?)
+    }
+
+    /// Instantiates the plugin from the given module.
+    pub fn new(manifest: &PluginManifest, wasm: &[u8]) -> Result<Self, WasmPluginError> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, wasm)?;
+
+        let limits =
+            StoreLimitsBuilder::new().memory_size(manifest.limits.max_memory_bytes).build();
+        let mut store = Store::new(&engine, PluginState { name: manifest.name.clone(), limits });
+        store.limiter(|state| &mut state.limits);
+        store.set_fuel(manifest.limits.fuel_per_call)?;
+
+        let mut linker = Linker::new(&engine);
+        linker.func_wrap("env", "reth_log", log).map_err(wasmi::Error::from)?;
+        let instance = linker.instantiate(&mut store, &module)?.start(&mut store)?;
+
+        let version =
+            instance.get_typed_func::<(), i32>(&store, "reth_abi_version")?.call(&mut store, ())?;
+        if version != ABI_VERSION {
+            return Err(WasmPluginError::UnsupportedAbiVersion(version))
+        }
+
+        let memory = instance.get_memory(&store, "memory").ok_or(WasmPluginError::MissingMemory)?;
+        Ok(Self {
+            alloc: instance.get_typed_func(&store, "reth_alloc")?,
+            on_block_committed: instance.get_typed_func(&store, "on_block_committed")?,
+            on_reorg: instance.get_typed_func(&store, "on_reorg")?,
+            store,
+            memory,
+            fuel_per_call: manifest.limits.fuel_per_call,
+        })
+    }
+
+    /// Returns the name of the plugin.
+    pub fn name(&self) -> &str {
+        &self.store.data().name
+    }
+
+    /// Calls the handler of the given event.
+    pub fn handle(&mut self, event: &PluginEvent) -> Result<(), WasmPluginError> {
+        match event {
+            PluginEvent::BlockCommitted { block, parent_hash } => {
+                self.on_block_committed(*block, *parent_hash)
+            }
+            PluginEvent::Reorg { reverted, reverted_tip } => {
+                self.on_reorg(reverted.clone(), *reverted_tip)
+            }
+        }
+    }
+
+    /// Calls `on_block_committed` with the number, hash and parent hash of the block.
+    pub fn on_block_committed(
+        &mut self,
+        block: BlockNumHash,
+        parent_hash: B256,
+    ) -> Result<(), WasmPluginError> {
+        let mut input = Vec::with_capacity(72);
+        input.extend_from_slice(&block.number.to_le_bytes());
+        input.extend_from_slice(block.hash.as_slice());
+        input.extend_from_slice(parent_hash.as_slice());
+        self.call("on_block_committed", self.on_block_committed, &input)
+    }
+
+    /// Calls `on_reorg` with the range of the reverted blocks and the hash of the last reverted
+    /// block.
+    pub fn on_reorg(
+        &mut self,
+        reverted: RangeInclusive<BlockNumber>,
+        reverted_tip: B256,
+    ) -> Result<(), WasmPluginError> {
+        let mut input = Vec::with_capacity(48);
+        input.extend_from_slice(&reverted.start().to_le_bytes());
+        input.extend_from_slice(&reverted.end().to_le_bytes());
+        input.extend_from_slice(reverted_tip.as_slice());
+        self.call("on_reorg", self.on_reorg, &input)
+    }
+
+    /// Copies the input into a buffer allocated by the plugin and calls the handler with it.
+    fn call(
+        &mut self,
+        handler: &'static str,
+        func: TypedFunc<(i32, i32), i32>,
+        input: &[u8],
+    ) -> Result<(), WasmPluginError> {
+        self.store.set_fuel(self.fuel_per_call)?;
+
+        let len = input.len() as i32;
+        let ptr = self.alloc.call(&mut self.store, len)?;
+        self.memory
+            .write(&mut self.store, ptr as u32 as usize, input)
+            .map_err(|_| WasmPluginError::InvalidBuffer(ptr))?;
+
+        match func.call(&mut self.store, (ptr, len))? {
+            0 => Ok(()),
+            code => Err(WasmPluginError::HandlerFailed { handler, code }),
+        }
+    }
+}
+
+/// The maximum length of a message logged by a plugin, longer messages are truncated.
+const MAX_LOG_MESSAGE_LEN: usize = 4096;
+
+/// The `env.reth_log` host function: logs the UTF-8 message at the given location of the memory
+/// of the plugin.
+///
+/// Messages that are not within the memory of the plugin are ignored.
+fn log(caller: Caller<'_, PluginState>, ptr: i32, len: i32) {
+    let Some(Extern::Memory(memory)) = caller.get_export("memory") else { return };
+    let start = ptr as u32 as usize;
+    let Some(message) =
+        start.checked_add(len as u32 as usize).and_then(|end| memory.data(&caller).get(start..end))
+    else {
+        return
+    };
+    tracing::info!(
+        target: "exex::wasm",
+        plugin = %caller.data().name,
+        "{}",
+        String::from_utf8_lossy(&message[..message.len().min(MAX_LOG_MESSAGE_LEN)])
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wasm::PluginLimits;
+
+    /// A plugin that stores the number of the last committed block at address 0 and fails to
+    /// handle reorgs of more than one block.
+    const PLUGIN: &str = r#"
+        (module
+            (import "env" "reth_log" (func $log (param i32 i32)))
+            (memory (export "memory") 1)
+            (data (i32.const 16) "reorg")
+            (func (export "reth_abi_version") (result i32) (i32.const 1))
+            (func (export "reth_alloc") (param i32) (result i32) (i32.const 1024))
+            (func (export "on_block_committed") (param i32 i32) (result i32)
+                (i64.store (i32.const 0) (i64.load (local.get 0)))
+                (i32.const 0))
+            (func (export "on_reorg") (param i32 i32) (result i32)
+                (call $log (i32.const 16) (i32.const 5))
+                (i64.ne
+                    (i64.load (local.get 0))
+                    (i64.load (i32.add (local.get 0) (i32.const 8)))))
+        )
+    "#;
+
+    fn manifest(limits: PluginLimits) -> PluginManifest {
+        PluginManifest { name: "test".to_string(), module: Default::default(), limits }
+    }
+
+    fn last_committed(plugin: &WasmPlugin) -> u64 {
+        let mut number = [0; 8];
+        plugin.memory.read(&plugin.store, 0, &mut number).unwrap();
+        u64::from_le_bytes(number)
+    }
+
+    #[test]
+    fn calls_handlers() {
+        let wasm = wat::parse_str(PLUGIN).unwrap();
+        let mut plugin = WasmPlugin::new(&manifest(Default::default()), &wasm).unwrap();
+        assert_eq!(plugin.name(), "test");
+
+        plugin
+            .on_block_committed(BlockNumHash::new(7, B256::with_last_byte(7)), B256::ZERO)
+            .unwrap();
+        assert_eq!(last_committed(&plugin), 7);
+
+        plugin.on_reorg(7..=7, B256::with_last_byte(7)).unwrap();
+        assert!(matches!(
+            plugin.on_reorg(6..=7, B256::with_last_byte(7)),
+            Err(WasmPluginError::HandlerFailed { handler: "on_reorg", code: 1 })
+        ));
+    }
+
+    #[test]
+    fn enforces_limits() {
+        let wasm = wat::parse_str(PLUGIN).unwrap();
+
+        // the memory of the plugin exceeds the limit
+        let limits = PluginLimits { max_memory_bytes: 1024, ..Default::default() };
+        assert!(WasmPlugin::new(&manifest(limits), &wasm).is_err());
+
+        // the fuel is refilled for each call
+        let limits = PluginLimits { fuel_per_call: 100, ..Default::default() };
+        let mut plugin = WasmPlugin::new(&manifest(limits), &wasm).unwrap();
+        for number in 0..10 {
+            plugin.on_block_committed(BlockNumHash::new(number, B256::ZERO), B256::ZERO).unwrap();
+        }
+
+        let limits = PluginLimits { fuel_per_call: 5, ..Default::default() };
+        let mut plugin = WasmPlugin::new(&manifest(limits), &wasm).unwrap();
+        assert!(matches!(
+            plugin.on_block_committed(BlockNumHash::new(1, B256::ZERO), B256::ZERO),
+            Err(WasmPluginError::Wasm(_))
+        ));
+    }
+
+    #[test]
+    fn ignores_out_of_bounds_log() {
+        let wasm = wat::parse_str(
+            r#"
+            (module
+                (import "env" "reth_log" (func $log (param i32 i32)))
+                (memory (export "memory") 1)
+                (func (export "reth_abi_version") (result i32) (i32.const 1))
+                (func (export "reth_alloc") (param i32) (result i32) (i32.const 1024))
+                (func (export "on_block_committed") (param i32 i32) (result i32)
+                    (call $log (i32.const 16) (i32.const -1))
+                    (i32.const 0))
+                (func (export "on_reorg") (param i32 i32) (result i32) (i32.const 0))
+            )
+        "#,
+        )
+        .unwrap();
+        let mut plugin = WasmPlugin::new(&manifest(Default::default()), &wasm).unwrap();
+        plugin.on_block_committed(BlockNumHash::new(1, B256::ZERO), B256::ZERO).unwrap();
+    }
+
+    #[test]
+    fn rejects_unknown_imports() {
+        let wasm = wat::parse_str(
+            r#"(module (import "wasi_snapshot_preview1" "fd_write" (func (param i32 i32 i32 i32) (result i32))))"#,
+        )
+        .unwrap();
+        assert!(WasmPlugin::new(&manifest(Default::default()), &wasm).is_err());
+
+        let wasm = wat::parse_str(
+            r#"(module (func (export "reth_abi_version") (result i32) (i32.const 2)))"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            WasmPlugin::new(&manifest(Default::default()), &wasm),
+            Err(WasmPluginError::UnsupportedAbiVersion(2))
+        ));
+    }
+}
@@ -26,7 +26,9 @@ use reth_db::{
 use reth_db_common::init::init_genesis;
 use reth_evm::test_utils::MockExecutorProvider;
 use reth_execution_types::Chain;
-use reth_exex::{ExExContext, ExExEvent, ExExNotification, ExExNotifications, Wal};
+use reth_exex::{
+    ExExContext, ExExEvent, ExExNotification, ExExNotifications, ExExPruneTargets, Wal,
+};
 use reth_network::{config::SecretKey, NetworkConfigBuilder, NetworkManager};
 use reth_node_api::{
     FullNodeTypes, FullNodeTypesAdapter, NodePrimitives, NodeTypes, NodeTypesWithDBAdapter,
@@ -194,6 +196,8 @@ pub struct TestExExHandle {
     pub events_rx: UnboundedReceiver<ExExEvent>,
     /// Channel for sending notifications to the Execution Extension
     pub notifications_tx: Sender<ExExNotification>,
+    /// Prune targets registered by the Execution Extension
+    pub prune_targets: ExExPruneTargets,
     /// Node task manager
     pub tasks: TaskManager,
     /// WAL temp directory handle
@@ -323,12 +327,15 @@ pub async fn test_exex_context_with_chain_spec(
         wal.handle(),
     );
 
+    let prune_targets = ExExPruneTargets::default();
+
     let ctx = ExExContext {
         head,
         config: NodeConfig::test(),
         reth_config: reth_config::Config::default(),
         events: events_tx,
         notifications,
+        prune_targets: prune_targets.clone(),
         components,
     };
 
@@ -339,6 +346,7 @@ pub async fn test_exex_context_with_chain_spec(
             provider_factory,
             events_rx,
             notifications_tx,
+            prune_targets,
             tasks,
             _wal_directory: wal_directory,
         },
@@ -11,10 +11,14 @@
 mod finished_height;
 mod head;
 mod notification;
+mod prune;
+mod status;
 
 pub use finished_height::FinishedExExHeight;
 pub use head::ExExHead;
 pub use notification::ExExNotification;
+pub use prune::{ExExPruneSignal, ExExPruneTargetHandle, ExExPruneTargets};
+pub use status::{ExExManagerStatus, ExExStatus, ExExStatusHandle};
 
 /// Bincode-compatible serde implementations for commonly used ExEx types.
 ///
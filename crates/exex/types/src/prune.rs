@@ -0,0 +1,169 @@
+use alloy_primitives::BlockNumber;
+use parking_lot::Mutex;
+use reth_prune_types::PruneMode;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+/// Signal sent by the pruner of the node to an `ExEx` that registered a prune target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExExPruneSignal {
+    /// The tip block number the pruner of the node ran with.
+    ///
+    /// The tip never exceeds the finished height of all `ExEx`'s and stays below the lowest block
+    /// retained by any prune target, so the data of the node may be pruned up to the prune
+    /// configuration of the node relative to this block.
+    pub tip_block_number: BlockNumber,
+    /// The `ExEx` may prune its own data for all blocks `< prune_before`, according to the
+    /// registered [`PruneMode`].
+    pub prune_before: BlockNumber,
+}
+
+/// A prune target registered by an `ExEx`.
+#[derive(Debug)]
+struct ExExPruneTarget {
+    /// How the `ExEx` wants its own data to be pruned.
+    mode: PruneMode,
+    /// Sender of the prune signals.
+    sender: watch::Sender<Option<ExExPruneSignal>>,
+    /// The lowest block of which the `ExEx` requires the data of the node, see
+    /// [`ExExPruneTargetHandle::retain_from`].
+    retain_from: watch::Receiver<Option<BlockNumber>>,
+}
+
+/// Handle of a prune target registered with [`ExExPruneTargets::register`].
+///
+/// The target is unregistered once the handle and all receivers of its prune signals are dropped.
+#[derive(Debug)]
+pub struct ExExPruneTargetHandle {
+    /// Receiver of the prune signals.
+    signals: watch::Receiver<Option<ExExPruneSignal>>,
+    /// Sender of the lowest block of which the `ExEx` requires the data of the node.
+    retain_from: watch::Sender<Option<BlockNumber>>,
+}
+
+impl ExExPruneTargetHandle {
+    /// Returns a receiver of the prune signals.
+    ///
+    /// The receiver holds `None` until the pruner of the node runs for the first time.
+    pub fn signals(&self) -> watch::Receiver<Option<ExExPruneSignal>> {
+        self.signals.clone()
+    }
+
+    /// Returns the latest prune signal, if the pruner of the node ran already.
+    pub fn latest_signal(&self) -> Option<ExExPruneSignal> {
+        *self.signals.borrow()
+    }
+
+    /// Requires the pruner of the node to keep its data of all blocks `>= block_number`, e.g.
+    /// because the `ExEx` still has to process them. `None` lifts the requirement.
+    ///
+    /// The pruner of the node never runs with a tip at or above the lowest retained block of all
+    /// prune targets.
+    pub fn retain_from(&self, block_number: Option<BlockNumber>) {
+        self.retain_from.send_replace(block_number);
+    }
+}
+
+/// Prune targets of data owned by `ExEx`'s, e.g. databases in their own data directories.
+///
+/// `ExEx`'s register a target with [`ExExPruneTargets::register`] and receive an
+/// [`ExExPruneSignal`] after every run of the pruner of the node, so that pruning of all data
+/// directories is coordinated and honors the lowest finished `ExEx` height. In turn, the pruner of
+/// the node keeps its data of all blocks retained with [`ExExPruneTargetHandle::retain_from`].
+#[derive(Debug, Clone, Default)]
+pub struct ExExPruneTargets {
+    targets: Arc<Mutex<Vec<ExExPruneTarget>>>,
+}
+
+impl ExExPruneTargets {
+    /// Registers a new prune target and returns its handle.
+    pub fn register(&self, mode: PruneMode) -> ExExPruneTargetHandle {
+        let (sender, signals) = watch::channel(None);
+        let (retain_from_tx, retain_from) = watch::channel(None);
+        self.targets.lock().push(ExExPruneTarget { mode, sender, retain_from });
+        ExExPruneTargetHandle { signals, retain_from: retain_from_tx }
+    }
+
+    /// Returns the number of registered prune targets.
+    pub fn len(&self) -> usize {
+        self.targets.lock().len()
+    }
+
+    /// Returns `true` if no prune targets are registered.
+    pub fn is_empty(&self) -> bool {
+        self.targets.lock().is_empty()
+    }
+
+    /// Returns the lowest block of which the prune targets require the data of the node, if any.
+    pub fn lowest_retained_block(&self) -> Option<BlockNumber> {
+        self.targets
+            .lock()
+            .iter()
+            .filter(|target| !target.sender.is_closed())
+            .filter_map(|target| *target.retain_from.borrow())
+            .min()
+    }
+
+    /// Notifies all registered prune targets that the pruner of the node ran with the given tip.
+    ///
+    /// Targets whose receivers were dropped are unregistered.
+    pub fn notify(&self, tip_block_number: BlockNumber) {
+        self.targets.lock().retain(|target| {
+            let prune_before = match target.mode {
+                PruneMode::Full => tip_block_number + 1,
+                PruneMode::Distance(distance) => tip_block_number.saturating_sub(distance),
+                PruneMode::Before(block_number) => block_number.min(tip_block_number + 1),
+            };
+            target.sender.send(Some(ExExPruneSignal { tip_block_number, prune_before })).is_ok()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn notify_prune_targets() {
+        let targets = ExExPruneTargets::default();
+        let full = targets.register(PruneMode::Full);
+        let distance = targets.register(PruneMode::Distance(64));
+        let before = targets.register(PruneMode::Before(10));
+        assert_eq!(full.latest_signal(), None);
+
+        targets.notify(100);
+        assert_eq!(
+            full.latest_signal(),
+            Some(ExExPruneSignal { tip_block_number: 100, prune_before: 101 })
+        );
+        assert_eq!(distance.latest_signal().unwrap().prune_before, 36);
+        assert_eq!(before.latest_signal().unwrap().prune_before, 10);
+
+        targets.notify(5);
+        assert_eq!(distance.latest_signal().unwrap().prune_before, 0);
+        assert_eq!(before.latest_signal().unwrap().prune_before, 6);
+
+        drop(full);
+        targets.notify(101);
+        assert_eq!(targets.len(), 2);
+    }
+
+    #[test]
+    fn lowest_retained_block() {
+        let targets = ExExPruneTargets::default();
+        let first = targets.register(PruneMode::Full);
+        let second = targets.register(PruneMode::Full);
+        assert_eq!(targets.lowest_retained_block(), None);
+
+        first.retain_from(Some(20));
+        second.retain_from(Some(10));
+        assert_eq!(targets.lowest_retained_block(), Some(10));
+
+        second.retain_from(None);
+        assert_eq!(targets.lowest_retained_block(), Some(20));
+
+        // dropped targets don't retain any blocks
+        drop(first);
+        assert_eq!(targets.lowest_retained_block(), None);
+    }
+}
@@ -0,0 +1,144 @@
+use alloy_eips::BlockNumHash;
+use alloy_primitives::BlockNumber;
+use parking_lot::Mutex;
+use std::{collections::HashSet, sync::Arc, task::Waker};
+
+/// The status of an installed `ExEx`, as seen by the `ExEx` manager.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ExExStatus {
+    /// The ID of the `ExEx`.
+    pub id: String,
+    /// The last block the `ExEx` reported as finished, `None` if it didn't report any yet.
+    pub finished_height: Option<BlockNumHash>,
+    /// The number of blocks the finished height of the `ExEx` is behind the tip of the latest
+    /// notification, `None` if either is unknown.
+    pub lag: Option<u64>,
+    /// The number of notifications that were not sent to the `ExEx` yet, including the ones that
+    /// were spilled to the WAL while it was paused.
+    pub pending_notifications: usize,
+    /// Whether sending notifications to the `ExEx` is paused.
+    pub paused: bool,
+}
+
+/// The status of the `ExEx` manager and all installed `ExEx`'s.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ExExManagerStatus {
+    /// The tip of the latest notification the manager received, `None` if it didn't receive any
+    /// yet.
+    pub tip: Option<BlockNumber>,
+    /// The number of notifications in the buffer of the manager.
+    pub buffer_size: usize,
+    /// The maximum number of notifications in the buffer of the manager, the node waits for the
+    /// `ExEx`'s once it's full.
+    pub buffer_capacity: usize,
+    /// The status of every installed `ExEx`.
+    pub exexs: Vec<ExExStatus>,
+}
+
+#[derive(Debug, Default)]
+struct ExExStatusInner {
+    /// The latest status published by the manager.
+    status: ExExManagerStatus,
+    /// The IDs of the `ExEx`'s that notifications are not sent to.
+    paused: HashSet<String>,
+    /// Waker of the manager, woken when an `ExEx` is paused or resumed.
+    waker: Option<Waker>,
+}
+
+/// Shared handle to the status of the `ExEx` manager, that also allows pausing and resuming the
+/// delivery of notifications to individual `ExEx`'s.
+///
+/// While an `ExEx` is paused, its notifications are spilled to the WAL instead of being kept in the
+/// buffer of the manager, so the node doesn't wait for it. Once resumed, the spilled notifications
+/// are sent before any new ones.
+#[derive(Debug, Clone, Default)]
+pub struct ExExStatusHandle {
+    inner: Arc<Mutex<ExExStatusInner>>,
+}
+
+impl ExExStatusHandle {
+    /// Returns the latest status of the manager.
+    pub fn status(&self) -> ExExManagerStatus {
+        self.inner.lock().status.clone()
+    }
+
+    /// Publishes the status of the manager and registers the waker of the manager.
+    ///
+    /// The paused flags of the `ExEx`'s are taken from the handle.
+    pub fn update(&self, mut status: ExExManagerStatus, waker: &Waker) {
+        let mut inner = self.inner.lock();
+        for exex in &mut status.exexs {
+            exex.paused = inner.paused.contains(&exex.id);
+        }
+        inner.status = status;
+        if !inner.waker.as_ref().is_some_and(|w| w.will_wake(waker)) {
+            inner.waker = Some(waker.clone());
+        }
+    }
+
+    /// Returns `true` if sending notifications to the `ExEx` is paused.
+    pub fn is_paused(&self, id: &str) -> bool {
+        self.inner.lock().paused.contains(id)
+    }
+
+    /// Pauses or resumes sending notifications to the `ExEx`.
+    ///
+    /// Returns `None` if no `ExEx` with the ID is installed, otherwise whether the `ExEx` was
+    /// paused or resumed, i.e. `false` if it already was.
+    pub fn set_paused(&self, id: &str, paused: bool) -> Option<bool> {
+        let mut inner = self.inner.lock();
+        let inner = &mut *inner;
+        let exex = inner.status.exexs.iter_mut().find(|exex| exex.id == id)?;
+        let changed =
+            if paused { inner.paused.insert(id.to_string()) } else { inner.paused.remove(id) };
+        exex.paused = paused;
+        if changed {
+            if let Some(waker) = &inner.waker {
+                waker.wake_by_ref();
+            }
+        }
+        Some(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_and_resume() {
+        let handle = ExExStatusHandle::default();
+        let exex = |id: &str| ExExStatus {
+            id: id.to_string(),
+            finished_height: None,
+            lag: None,
+            pending_notifications: 0,
+            paused: false,
+        };
+        handle.update(
+            ExExManagerStatus { exexs: vec![exex("a"), exex("b")], ..Default::default() },
+            Waker::noop(),
+        );
+
+        assert_eq!(handle.set_paused("c", true), None);
+        assert_eq!(handle.set_paused("a", true), Some(true));
+        assert_eq!(handle.set_paused("a", true), Some(false));
+        assert!(handle.is_paused("a"));
+        assert!(handle.status().exexs[0].paused);
+
+        // the paused flag survives status updates of the manager
+        handle.update(
+            ExExManagerStatus { exexs: vec![exex("a"), exex("b")], ..Default::default() },
+            Waker::noop(),
+        );
+        assert!(handle.status().exexs[0].paused);
+        assert!(!handle.status().exexs[1].paused);
+
+        assert_eq!(handle.set_paused("a", false), Some(true));
+        assert!(!handle.is_paused("a"));
+    }
+}
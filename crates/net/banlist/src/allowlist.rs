@@ -0,0 +1,273 @@
+//! Support for restricting the peers to an allowlist.
+
+use crate::PeerId;
+use parking_lot::{Mutex, RwLock};
+use std::{
+    collections::HashSet,
+    fmt,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Waker},
+};
+
+/// A range of IP addresses in CIDR notation, e.g. `10.0.0.0/8`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpNetwork {
+    /// The first address of the range.
+    addr: IpAddr,
+    /// The number of leading bits that addresses of the range share.
+    prefix_len: u8,
+}
+
+impl IpNetwork {
+    /// Creates the range of addresses that share the first `prefix_len` bits with the given
+    /// address.
+    ///
+    /// Returns `None` if the prefix length exceeds the length of the address.
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Option<Self> {
+        let addr = match addr {
+            IpAddr::V4(addr) => IpAddr::V4(Ipv4Addr::from(u32::from(addr) & v4_mask(prefix_len)?)),
+            IpAddr::V6(addr) => IpAddr::V6(Ipv6Addr::from(u128::from(addr) & v6_mask(prefix_len)?)),
+        };
+        Some(Self { addr, prefix_len })
+    }
+
+    /// Returns the first address of the range.
+    pub const fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Returns the number of leading bits that addresses of the range share.
+    pub const fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns true if the address is in the range.
+    ///
+    /// IPv4-mapped IPv6 addresses are treated as IPv4 addresses.
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.addr, ip.to_canonical()) {
+            (IpAddr::V4(addr), IpAddr::V4(ip)) => {
+                let mask = v4_mask(self.prefix_len).unwrap_or_default();
+                u32::from(ip) & mask == u32::from(addr)
+            }
+            (IpAddr::V6(addr), IpAddr::V6(ip)) => {
+                let mask = v6_mask(self.prefix_len).unwrap_or_default();
+                u128::from(ip) & mask == u128::from(addr)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Returns the mask of the first `prefix_len` bits of an IPv4 address.
+fn v4_mask(prefix_len: u8) -> Option<u32> {
+    (prefix_len <= 32).then(|| u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or_default())
+}
+
+/// Returns the mask of the first `prefix_len` bits of an IPv6 address.
+fn v6_mask(prefix_len: u8) -> Option<u128> {
+    (prefix_len <= 128).then(|| u128::MAX.checked_shl(128 - prefix_len as u32).unwrap_or_default())
+}
+
+impl From<IpAddr> for IpNetwork {
+    fn from(addr: IpAddr) -> Self {
+        let prefix_len = if addr.is_ipv4() { 32 } else { 128 };
+        Self { addr, prefix_len }
+    }
+}
+
+impl fmt::Display for IpNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+/// Error when parsing an [`IpNetwork`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpNetworkParseError(String);
+
+impl fmt::Display for IpNetworkParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid IP network: {}", self.0)
+    }
+}
+
+impl core::error::Error for IpNetworkParseError {}
+
+impl FromStr for IpNetwork {
+    type Err = IpNetworkParseError;
+
+    /// Parses a range in CIDR notation, or a single address.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || IpNetworkParseError(s.to_string());
+        let Some((addr, prefix_len)) = s.split_once('/') else {
+            return s.parse::<IpAddr>().map(Into::into).map_err(|_| err())
+        };
+        let addr = addr.parse::<IpAddr>().map_err(|_| err())?;
+        let prefix_len = prefix_len.parse::<u8>().map_err(|_| err())?;
+        Self::new(addr, prefix_len).ok_or_else(err)
+    }
+}
+
+/// Restricts the peers to the allowed peer ids and IP ranges.
+///
+/// A peer is allowed if either its id is listed _or_ its IP is in one of the listed ranges.
+///
+/// Cloning this is cheap, and all clones share the same entries, so they can be replaced at
+/// runtime, e.g. when the file they were loaded from changes.
+#[derive(Debug, Clone, Default)]
+pub struct PeerAllowlist {
+    inner: Arc<AllowlistInner>,
+}
+
+impl PartialEq for PeerAllowlist {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner) ||
+            *self.inner.entries.read() == *other.inner.entries.read()
+    }
+}
+
+impl Eq for PeerAllowlist {}
+
+/// The shared state of a [`PeerAllowlist`].
+#[derive(Debug, Default)]
+struct AllowlistInner {
+    /// The allowed peers.
+    entries: RwLock<AllowlistEntries>,
+    /// Incremented whenever the entries are replaced.
+    version: AtomicU64,
+    /// Woken when the entries are replaced, see [`PeerAllowlist::poll_changed`].
+    waker: Mutex<Option<Waker>>,
+}
+
+/// The entries of a [`PeerAllowlist`].
+#[derive(Debug, Default, PartialEq, Eq)]
+struct AllowlistEntries {
+    /// The allowed peer ids.
+    peers: HashSet<PeerId>,
+    /// The allowed IP ranges.
+    networks: Vec<IpNetwork>,
+}
+
+impl PeerAllowlist {
+    /// Creates a new allowlist with the given peer ids and IP ranges.
+    pub fn new(
+        peers: impl IntoIterator<Item = PeerId>,
+        networks: impl IntoIterator<Item = IpNetwork>,
+    ) -> Self {
+        let allowlist = Self::default();
+        allowlist.set(peers, networks);
+        allowlist
+    }
+
+    /// Replaces the allowed peer ids and IP ranges.
+    pub fn set(
+        &self,
+        peers: impl IntoIterator<Item = PeerId>,
+        networks: impl IntoIterator<Item = IpNetwork>,
+    ) {
+        *self.inner.entries.write() = AllowlistEntries {
+            peers: peers.into_iter().collect(),
+            networks: networks.into_iter().collect(),
+        };
+        self.inner.version.fetch_add(1, Ordering::Relaxed);
+        if let Some(waker) = self.inner.waker.lock().take() {
+            waker.wake();
+        }
+    }
+
+    /// Returns true if the entries were replaced since the given version was observed, and
+    /// updates it to the current version.
+    ///
+    /// Otherwise, the waker of the context is woken the next time the entries are replaced.
+    pub fn poll_changed(&self, cx: &Context<'_>, version: &mut u64) -> bool {
+        *self.inner.waker.lock() = Some(cx.waker().clone());
+        let current = self.inner.version.load(Ordering::Relaxed);
+        let changed = current != *version;
+        *version = current;
+        changed
+    }
+
+    /// Returns true if either the given peer id _or_ ip address is allowed.
+    pub fn is_allowed(&self, peer_id: &PeerId, ip: &IpAddr) -> bool {
+        let entries = self.inner.entries.read();
+        entries.peers.contains(peer_id) ||
+            entries.networks.iter().any(|network| network.contains(ip))
+    }
+
+    /// Returns true if connections from the ip address can be rejected before the peer id is
+    /// known, i.e. if only IP ranges are allowed and the ip address isn't in any of them.
+    pub fn rejects_ip(&self, ip: &IpAddr) -> bool {
+        let entries = self.inner.entries.read();
+        entries.peers.is_empty() && !entries.networks.iter().any(|network| network.contains(ip))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ip_network() {
+        let network: IpNetwork = "10.1.2.3/8".parse().unwrap();
+        assert_eq!(network.addr(), IpAddr::from([10, 0, 0, 0]));
+        assert_eq!(network.prefix_len(), 8);
+        assert_eq!(network.to_string(), "10.0.0.0/8");
+
+        let network: IpNetwork = "192.168.0.1".parse().unwrap();
+        assert_eq!(network.to_string(), "192.168.0.1/32");
+
+        let network: IpNetwork = "fd00::/8".parse().unwrap();
+        assert_eq!(network.prefix_len(), 8);
+
+        assert!("10.0.0.0/33".parse::<IpNetwork>().is_err());
+        assert!("10.0.0/8".parse::<IpNetwork>().is_err());
+    }
+
+    #[test]
+    fn ip_network_contains() {
+        let network: IpNetwork = "10.0.0.0/8".parse().unwrap();
+        assert!(network.contains(&IpAddr::from([10, 255, 0, 1])));
+        assert!(!network.contains(&IpAddr::from([11, 0, 0, 1])));
+        assert!(network.contains(&IpAddr::from(Ipv4Addr::new(10, 0, 0, 1).to_ipv6_mapped())));
+
+        let all: IpNetwork = "0.0.0.0/0".parse().unwrap();
+        assert!(all.contains(&IpAddr::from([1, 1, 1, 1])));
+        assert!(!all.contains(&IpAddr::from(Ipv6Addr::LOCALHOST)));
+    }
+
+    #[test]
+    fn allowlist() {
+        let peer = PeerId::random();
+        let public_ip = IpAddr::from([1, 1, 1, 1]);
+        let private_ip = IpAddr::from([10, 0, 0, 1]);
+
+        let allowlist = PeerAllowlist::new([], ["10.0.0.0/8".parse().unwrap()]);
+        assert!(allowlist.is_allowed(&peer, &private_ip));
+        assert!(!allowlist.is_allowed(&peer, &public_ip));
+        assert!(allowlist.rejects_ip(&public_ip));
+
+        allowlist.set([peer], []);
+        assert!(allowlist.is_allowed(&peer, &public_ip));
+        assert!(!allowlist.is_allowed(&PeerId::random(), &private_ip));
+        // the peer id is only known after the handshake
+        assert!(!allowlist.rejects_ip(&public_ip));
+    }
+
+    #[test]
+    fn allowlist_changed() {
+        let allowlist = PeerAllowlist::default();
+        let cx = Context::from_waker(Waker::noop());
+        let mut version = 0;
+        assert!(!allowlist.poll_changed(&cx, &mut version));
+
+        allowlist.clone().set([PeerId::random()], []);
+        assert!(allowlist.poll_changed(&cx, &mut version));
+        assert!(!allowlist.poll_changed(&cx, &mut version));
+    }
+}
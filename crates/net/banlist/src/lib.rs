@@ -10,6 +10,9 @@
 
 type PeerId = alloy_primitives::B512;
 
+mod allowlist;
+pub use allowlist::{IpNetwork, IpNetworkParseError, PeerAllowlist};
+
 use std::{collections::HashMap, net::IpAddr, time::Instant};
 
 /// Determines whether or not the IP is globally routable.
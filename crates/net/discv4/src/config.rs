@@ -5,7 +5,7 @@
 
 use alloy_primitives::bytes::Bytes;
 use alloy_rlp::Encodable;
-use reth_net_banlist::BanList;
+use reth_net_banlist::{BanList, PeerAllowlist};
 use reth_net_nat::{NatResolver, ResolveNatInterval};
 use reth_network_peers::NodeRecord;
 use std::{
@@ -41,6 +41,9 @@ pub struct Discv4Config {
     /// Provides a way to ban peers and ips.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub ban_list: BanList,
+    /// If set, only the allowed peers are discovered, packets from other peers are dropped.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub allowlist: Option<PeerAllowlist>,
     /// Set the default duration for which nodes are banned for. This timeouts are checked every 5
     /// minutes, so the precision will be to the nearest 5 minutes. If set to `None`, bans from
     /// the filter will last indefinitely. Default is 1 hour.
@@ -126,6 +129,7 @@ impl Default for Discv4Config {
 
             lookup_interval: Duration::from_secs(20),
             ban_list: Default::default(),
+            allowlist: None,
             ban_duration: Some(Duration::from_secs(60 * 60)), // 1 hour
             bootstrap_nodes: Default::default(),
             enable_dht_random_walk: true,
@@ -264,6 +268,12 @@ impl Discv4ConfigBuilder {
         self
     }
 
+    /// Restricts discovery to the peers of the allowlist.
+    pub fn allowlist(&mut self, allowlist: PeerAllowlist) -> &mut Self {
+        self.config.allowlist = Some(allowlist);
+        self
+    }
+
     /// Sets the lookup interval duration.
     pub fn lookup_interval(&mut self, lookup_interval: Duration) -> &mut Self {
         self.config.lookup_interval = lookup_interval;
@@ -831,6 +831,11 @@ impl Discv4Service {
         });
     }
 
+    /// Returns true if the peer isn't excluded by the configured allowlist.
+    fn is_allowed(&self, node_id: &PeerId, ip: &IpAddr) -> bool {
+        self.config.allowlist.as_ref().is_none_or(|allowlist| allowlist.is_allowed(node_id, ip))
+    }
+
     /// Adds the ip to the ban list indefinitely
     pub fn ban_ip(&mut self, ip: IpAddr) {
         self.config.ban_list.ban_ip(ip);
@@ -1434,6 +1439,10 @@ impl Discv4Service {
                 trace!(target: "discv4", peer_id=?node.id, ip=?node.address, "ignoring banned record");
                 continue
             }
+            if !self.is_allowed(&node.id, &node.address) {
+                trace!(target: "discv4", peer_id=?node.id, ip=?node.address, "ignoring record not in allowlist");
+                continue
+            }
 
             ctx.add_node(node);
         }
@@ -1772,6 +1781,11 @@ impl Discv4Service {
                     IngressEvent::BadPacket(from, err, data) => {
                         trace!(target: "discv4", ?from, %err, packet=?hex::encode(&data), "bad packet");
                     }
+                    IngressEvent::Packet(remote_addr, Packet { node_id, .. })
+                        if !self.is_allowed(&node_id, &remote_addr.ip()) =>
+                    {
+                        trace!(target: "discv4", ?node_id, from=?remote_addr, "dropping packet from peer not in allowlist");
+                    }
                     IngressEvent::Packet(remote_addr, Packet { msg, node_id, hash }) => {
                         trace!(target: "discv4", r#type=?msg.msg_type(), from=?remote_addr,"received packet");
                         let event = match msg {
@@ -13,6 +13,7 @@ use discv5::{
     ListenConfig,
 };
 use reth_ethereum_forks::{EnrForkIdEntry, ForkId};
+use reth_net_banlist::PeerAllowlist;
 use reth_network_peers::NodeRecord;
 use tracing::warn;
 
@@ -85,6 +86,8 @@ pub struct ConfigBuilder {
     /// Custom filter rules to apply to a discovered peer in order to determine if it should be
     /// passed up to rlpx or dropped.
     discovered_peer_filter: Option<MustNotIncludeKeys>,
+    /// If set, only the allowed peers are kept in the kbuckets and passed up to rlpx.
+    allowlist: Option<PeerAllowlist>,
 }
 
 impl ConfigBuilder {
@@ -100,6 +103,7 @@ impl ConfigBuilder {
             bootstrap_lookup_interval,
             bootstrap_lookup_countdown,
             discovered_peer_filter,
+            allowlist,
         } = discv5_config;
 
         Self {
@@ -112,6 +116,7 @@ impl ConfigBuilder {
             bootstrap_lookup_interval: Some(bootstrap_lookup_interval),
             bootstrap_lookup_countdown: Some(bootstrap_lookup_countdown),
             discovered_peer_filter: Some(discovered_peer_filter),
+            allowlist,
         }
     }
 
@@ -214,6 +219,12 @@ impl ConfigBuilder {
         self
     }
 
+    /// Restricts discovery to the peers of the allowlist.
+    pub fn allowlist(mut self, allowlist: PeerAllowlist) -> Self {
+        self.allowlist = Some(allowlist);
+        self
+    }
+
     /// Returns a new [`Config`].
     pub fn build(self) -> Config {
         let Self {
@@ -226,6 +237,7 @@ impl ConfigBuilder {
             bootstrap_lookup_interval,
             bootstrap_lookup_countdown,
             discovered_peer_filter,
+            allowlist,
         } = self;
 
         let mut discv5_config = discv5_config.unwrap_or_else(|| {
@@ -256,6 +268,7 @@ impl ConfigBuilder {
             bootstrap_lookup_interval,
             bootstrap_lookup_countdown,
             discovered_peer_filter,
+            allowlist,
         }
     }
 }
@@ -289,6 +302,8 @@ pub struct Config {
     /// Custom filter rules to apply to a discovered peer in order to determine if it should be
     /// passed up to rlpx or dropped.
     pub(super) discovered_peer_filter: MustNotIncludeKeys,
+    /// If set, only the allowed peers are kept in the kbuckets and passed up to rlpx.
+    pub(super) allowlist: Option<PeerAllowlist>,
 }
 
 impl Config {
@@ -305,6 +320,7 @@ impl Config {
             bootstrap_lookup_interval: None,
             bootstrap_lookup_countdown: None,
             discovered_peer_filter: None,
+            allowlist: None,
         }
     }
 
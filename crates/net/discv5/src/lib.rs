@@ -24,6 +24,7 @@ use futures::future::join_all;
 use itertools::Itertools;
 use rand::{Rng, RngCore};
 use reth_ethereum_forks::{EnrForkIdEntry, ForkId};
+use reth_net_banlist::PeerAllowlist;
 use reth_network_peers::{NodeRecord, PeerId};
 use secp256k1::SecretKey;
 use tokio::{sync::mpsc, task};
@@ -73,6 +74,9 @@ pub struct Discv5 {
     fork_key: Option<&'static [u8]>,
     /// Filter applied to a discovered peers before passing it up to app.
     discovered_peer_filter: MustNotIncludeKeys,
+    /// If set, peers that are not allowed are removed from the kbuckets instead of being passed
+    /// up to app.
+    allowlist: Option<PeerAllowlist>,
     /// Metrics for underlying [`discv5::Discv5`] node and filtered discovered peers.
     metrics: Discv5Metrics,
 }
@@ -182,6 +186,7 @@ impl Discv5 {
             bootstrap_lookup_interval,
             bootstrap_lookup_countdown,
             discovered_peer_filter,
+            allowlist,
             ..
         } = discv5_config;
 
@@ -217,7 +222,7 @@ impl Discv5 {
         );
 
         Ok((
-            Self { discv5, rlpx_ip_mode, fork_key, discovered_peer_filter, metrics },
+            Self { discv5, rlpx_ip_mode, fork_key, discovered_peer_filter, allowlist, metrics },
             discv5_updates,
             bc_enr,
         ))
@@ -307,6 +312,21 @@ impl Discv5 {
                 return None
             }
         };
+        if self
+            .allowlist
+            .as_ref()
+            .is_some_and(|allowlist| !allowlist.is_allowed(&node_record.id, &node_record.address))
+        {
+            trace!(target: "net::discv5",
+                ?enr,
+                "removing discovered peer not in allowlist"
+            );
+
+            // stop talking to the peer on the discovery network as well
+            self.discv5.remove_node(&enr.node_id());
+
+            return None
+        }
         if let FilterOutcome::Ignore { reason } = self.filter_discovered_peer(enr) {
             trace!(target: "net::discv5",
                 ?enr,
@@ -673,6 +693,7 @@ mod test {
             rlpx_ip_mode: IpMode::Ip4,
             fork_key: None,
             discovered_peer_filter: MustNotIncludeKeys::default(),
+            allowlist: None,
             metrics: Discv5Metrics::default(),
         }
     }
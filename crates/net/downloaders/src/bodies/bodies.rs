@@ -12,6 +12,7 @@ use reth_network_p2p::{
         downloader::{BodyDownloader, BodyDownloaderResult},
         response::BlockResponse,
     },
+    checkpoints::CanonicalCheckpoints,
     error::{DownloadError, DownloadResult},
 };
 use reth_primitives::SealedHeader;
@@ -512,6 +513,8 @@ pub struct BodiesDownloaderBuilder {
     pub max_buffered_blocks_size_bytes: usize,
     /// The maximum number of requests to send concurrently.
     pub concurrent_requests_range: RangeInclusive<usize>,
+    /// Hashes of known canonical blocks.
+    pub checkpoints: CanonicalCheckpoints,
 }
 
 impl BodiesDownloaderBuilder {
@@ -536,6 +539,7 @@ impl Default for BodiesDownloaderBuilder {
             stream_batch_size: 1_000,
             max_buffered_blocks_size_bytes: 2 * 1024 * 1024 * 1024, // ~2GB
             concurrent_requests_range: 5..=100,
+            checkpoints: Default::default(),
         }
     }
 }
@@ -571,6 +575,14 @@ impl BodiesDownloaderBuilder {
         self
     }
 
+    /// Set the hashes of known canonical blocks on the downloader.
+    ///
+    /// Peers that respond with invalid bodies for blocks up to the highest checkpoint are banned.
+    pub fn with_checkpoints(mut self, checkpoints: CanonicalCheckpoints) -> Self {
+        self.checkpoints = checkpoints;
+        self
+    }
+
     /// Consume self and return the concurrent downloader.
     pub fn build<B, C, Provider>(
         self,
@@ -588,9 +600,10 @@ impl BodiesDownloaderBuilder {
             stream_batch_size,
             concurrent_requests_range,
             max_buffered_blocks_size_bytes,
+            checkpoints,
         } = self;
         let metrics = BodyDownloaderMetrics::default();
-        let in_progress_queue = BodiesRequestQueue::new(metrics.clone());
+        let in_progress_queue = BodiesRequestQueue::new(metrics.clone(), checkpoints);
         BodiesDownloader {
             client: Arc::new(client),
             consensus,
@@ -7,6 +7,7 @@ use futures_util::StreamExt;
 use reth_consensus::{Consensus, ConsensusError};
 use reth_network_p2p::{
     bodies::{client::BodiesClient, response::BlockResponse},
+    checkpoints::CanonicalCheckpoints,
     error::DownloadResult,
 };
 use reth_primitives::SealedHeader;
@@ -27,6 +28,8 @@ pub(crate) struct BodiesRequestQueue<B: Block, C: BodiesClient<Body = B::Body>>
     metrics: BodyDownloaderMetrics,
     /// Last requested block number.
     pub(crate) last_requested_block_number: Option<BlockNumber>,
+    /// Hashes of known canonical blocks.
+    checkpoints: CanonicalCheckpoints,
 }
 
 impl<B, C> BodiesRequestQueue<B, C>
@@ -35,8 +38,8 @@ where
     C: BodiesClient<Body = B::Body> + 'static,
 {
     /// Create new instance of request queue.
-    pub(crate) fn new(metrics: BodyDownloaderMetrics) -> Self {
-        Self { metrics, inner: Default::default(), last_requested_block_number: None }
+    pub(crate) fn new(metrics: BodyDownloaderMetrics, checkpoints: CanonicalCheckpoints) -> Self {
+        Self { metrics, inner: Default::default(), last_requested_block_number: None, checkpoints }
     }
 
     /// Returns `true` if the queue is empty.
@@ -73,7 +76,9 @@ where
 
         // Create request and push into the queue.
         self.inner.push(
-            BodiesRequestFuture::new(client, consensus, self.metrics.clone()).with_headers(request),
+            BodiesRequestFuture::new(client, consensus, self.metrics.clone())
+                .with_checkpoints(self.checkpoints.clone())
+                .with_headers(request),
         )
     }
 }
@@ -5,6 +5,7 @@ use futures::{Future, FutureExt};
 use reth_consensus::{Consensus, ConsensusError};
 use reth_network_p2p::{
     bodies::{client::BodiesClient, response::BlockResponse},
+    checkpoints::CanonicalCheckpoints,
     error::{DownloadError, DownloadResult},
     priority::Priority,
 };
@@ -52,6 +53,8 @@ pub(crate) struct BodiesRequestFuture<B: Block, C: BodiesClient<Body = B::Body>>
     fut: Option<C::Output>,
     /// Tracks how many bodies we requested in the last request.
     last_request_len: Option<usize>,
+    /// Hashes of known canonical blocks.
+    checkpoints: CanonicalCheckpoints,
 }
 
 impl<B, C> BodiesRequestFuture<B, C>
@@ -74,9 +77,19 @@ where
             buffer: Default::default(),
             last_request_len: None,
             fut: None,
+            checkpoints: Default::default(),
         }
     }
 
+    /// Sets the hashes of known canonical blocks.
+    ///
+    /// Peers that respond with invalid bodies for blocks up to the highest checkpoint are banned,
+    /// because the headers of these blocks are known to be canonical.
+    pub(crate) fn with_checkpoints(mut self, checkpoints: CanonicalCheckpoints) -> Self {
+        self.checkpoints = checkpoints;
+        self
+    }
+
     pub(crate) fn with_headers(mut self, headers: Vec<SealedHeader<B::Header>>) -> Self {
         self.buffer.reserve_exact(headers.len());
         self.pending_headers = VecDeque::from(headers);
@@ -92,7 +105,11 @@ where
         self.metrics.increment_errors(&error);
         tracing::debug!(target: "downloaders::bodies", ?peer_id, %error, "Error requesting bodies");
         if let Some(peer_id) = peer_id {
-            self.client.report_bad_message(peer_id);
+            if self.is_non_canonical(&error) {
+                self.client.report_non_canonical(peer_id);
+            } else {
+                self.client.report_bad_message(peer_id);
+            }
         }
         self.submit_request(
             self.next_request().expect("existing hashes to resubmit"),
@@ -100,6 +117,16 @@ where
         );
     }
 
+    /// Returns `true` if the error was caused by a body that doesn't belong to a block that is
+    /// known to be canonical.
+    fn is_non_canonical(&self, error: &DownloadError) -> bool {
+        matches!(
+            error,
+            DownloadError::BodyValidation { number, .. }
+                if self.checkpoints.highest().is_some_and(|highest| *number <= highest.number)
+        )
+    }
+
     /// Retrieve header hashes for the next request.
     fn next_request(&self) -> Option<Vec<B256>> {
         let mut hashes =
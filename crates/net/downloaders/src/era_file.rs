@@ -0,0 +1,190 @@
+use crate::file_client::{FileClient, FileClientError};
+use alloy_consensus::BlockHeader;
+use alloy_primitives::BlockNumber;
+use alloy_rlp::Decodable;
+use reth_primitives_traits::{Block, FullBlock};
+use std::{io::Read, path::Path};
+use tokio::io::{AsyncRead, AsyncReadExt, BufReader};
+use tracing::trace;
+
+/// The file extension of era1 files.
+pub const ERA1_FILE_EXTENSION: &str = "era1";
+
+/// Type of the version entry that starts an era1 file.
+const VERSION: [u8; 2] = [0x65, 0x32];
+
+/// Type of the entry of a snappy compressed, RLP encoded block header.
+const COMPRESSED_HEADER: [u8; 2] = [0x03, 0x00];
+
+/// Type of the entry of a snappy compressed, RLP encoded block body.
+const COMPRESSED_BODY: [u8; 2] = [0x04, 0x00];
+
+/// Length of the header of an e2store entry: type, length and reserved bytes.
+const ENTRY_HEADER_LEN: usize = 8;
+
+impl<B: FullBlock> FileClient<B> {
+    /// Creates a file client with the blocks of an [era1] file.
+    ///
+    /// An era1 file is an e2store file with the headers, bodies, receipts and total difficulties
+    /// of up to 8192 pre-merge blocks. Only the headers and bodies are read, blocks up to and
+    /// including `skip_until` are skipped, e.g. because they're already synced.
+    ///
+    /// The file is read entry by entry, only the decoded blocks are kept in memory.
+    ///
+    /// [era1]: https://github.com/ethereum/go-ethereum/blob/master/internal/era/era.go
+    pub async fn from_era1_file(
+        path: impl AsRef<Path>,
+        skip_until: Option<BlockNumber>,
+    ) -> Result<Self, FileClientError> {
+        let file = tokio::fs::File::open(path).await?;
+        let blocks = read_era1_blocks::<B>(BufReader::new(file))
+            .await?
+            .into_iter()
+            .filter(|block| skip_until.is_none_or(|number| block.header().number() > number));
+        Ok(Self::from_blocks(blocks))
+    }
+}
+
+/// Reads the blocks of an era1 file.
+async fn read_era1_blocks<B: Block>(
+    mut reader: impl AsyncRead + Unpin,
+) -> Result<Vec<B>, FileClientError> {
+    let mut headers = Vec::new();
+    let mut bodies = Vec::new();
+    let mut is_first = true;
+    let mut data = Vec::new();
+
+    while let Some(entry_type) = read_entry(&mut reader, &mut data).await? {
+        if std::mem::take(&mut is_first) && entry_type != VERSION {
+            return Err(FileClientError::Custom("era1 file does not start with a version entry"))
+        }
+
+        match entry_type {
+            COMPRESSED_HEADER => {
+                let rlp = decompress_snappy_frames(&data)?;
+                let header = B::Header::decode(&mut &rlp[..])
+                    .map_err(|err| FileClientError::Rlp(err, rlp.clone()))?;
+                headers.push(header);
+            }
+            COMPRESSED_BODY => {
+                let rlp = decompress_snappy_frames(&data)?;
+                let body = B::Body::decode(&mut &rlp[..])
+                    .map_err(|err| FileClientError::Rlp(err, rlp.clone()))?;
+                bodies.push(body);
+            }
+            // receipts, total difficulties, the accumulator and the block index are not needed
+            _ => {}
+        }
+    }
+
+    if headers.len() != bodies.len() {
+        return Err(FileClientError::Custom(
+            "era1 file has a different number of headers and bodies",
+        ))
+    }
+    trace!(target: "downloaders::file", blocks = headers.len(), "Decoded era1 file");
+
+    Ok(headers.into_iter().zip(bodies).map(|(header, body)| B::new(header, body)).collect())
+}
+
+/// Reads the next e2store entry into `data` and returns its type, or `None` at the end of the
+/// file.
+///
+/// The buffer grows with the data that is actually read, so a corrupted length doesn't allocate
+/// more memory than the file holds.
+async fn read_entry(
+    reader: &mut (impl AsyncRead + Unpin),
+    data: &mut Vec<u8>,
+) -> Result<Option<[u8; 2]>, FileClientError> {
+    let mut header = [0; ENTRY_HEADER_LEN];
+    if reader.read(&mut header[..1]).await? == 0 {
+        return Ok(None)
+    }
+    reader
+        .read_exact(&mut header[1..])
+        .await
+        .map_err(|_| FileClientError::Custom("truncated e2store entry header"))?;
+
+    let entry_type = [header[0], header[1]];
+    let len = u32::from_le_bytes([header[2], header[3], header[4], header[5]]) as u64;
+    data.clear();
+    (&mut *reader).take(len).read_to_end(data).await?;
+    if (data.len() as u64) < len {
+        return Err(FileClientError::Custom("truncated e2store entry"))
+    }
+    Ok(Some(entry_type))
+}
+
+/// Decompresses data in the [snappy framing format], verifying the checksums of the chunks.
+///
+/// [snappy framing format]: https://github.com/google/snappy/blob/main/framing_format.txt
+fn decompress_snappy_frames(data: &[u8]) -> Result<Vec<u8>, FileClientError> {
+    let mut out = Vec::new();
+    snap::read::FrameDecoder::new(data).read_to_end(&mut out)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rlp::Encodable;
+    use reth_primitives::{Block, BlockBody, Header};
+    use std::io::Write;
+
+    fn snappy_frames(data: &[u8]) -> Vec<u8> {
+        let mut encoder = snap::write::FrameEncoder::new(Vec::new());
+        encoder.write_all(data).unwrap();
+        encoder.into_inner().unwrap()
+    }
+
+    fn entry(entry_type: [u8; 2], data: &[u8]) -> Vec<u8> {
+        let mut out = entry_type.to_vec();
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(&[0, 0]);
+        out.extend_from_slice(data);
+        out
+    }
+
+    #[test]
+    fn decompress_corrupted_frames() {
+        let data = [1u8; 100];
+        let mut frames = snappy_frames(&data);
+        assert_eq!(decompress_snappy_frames(&frames).unwrap(), data);
+
+        // the checksum doesn't match anymore
+        *frames.last_mut().unwrap() ^= 1;
+        assert!(decompress_snappy_frames(&frames).is_err());
+    }
+
+    #[tokio::test]
+    async fn decode_era1() {
+        let blocks = (1..=3)
+            .map(|number| Block {
+                header: Header { number, ..Default::default() },
+                body: BlockBody::default(),
+            })
+            .collect::<Vec<_>>();
+
+        let mut content = entry(VERSION, &[]);
+        for block in &blocks {
+            let mut header = Vec::new();
+            block.header.encode(&mut header);
+            let mut body = Vec::new();
+            block.body.encode(&mut body);
+            content.extend(entry(COMPRESSED_HEADER, &snappy_frames(&header)));
+            content.extend(entry(COMPRESSED_BODY, &snappy_frames(&body)));
+            content.extend(entry([0x06, 0x00], &[0; 32]));
+        }
+
+        assert_eq!(read_era1_blocks::<Block>(&content[..]).await.unwrap(), blocks);
+        assert!(read_era1_blocks::<Block>(&content[ENTRY_HEADER_LEN..]).await.is_err());
+        assert!(read_era1_blocks::<Block>(&content[..content.len() - 1]).await.is_err());
+
+        // an entry claiming more data than the file holds
+        let mut truncated = entry(VERSION, &[]);
+        truncated.extend(entry([0x06, 0x00], &[]));
+        truncated[ENTRY_HEADER_LEN + 2..ENTRY_HEADER_LEN + 6]
+            .copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(read_era1_blocks::<Block>(&truncated[..]).await.is_err());
+    }
+}
@@ -108,6 +108,24 @@ impl<B: FullBlock> FileClient<B> {
             .file_client)
     }
 
+    /// Creates a file client with the given blocks.
+    ///
+    /// The blocks are not validated.
+    pub fn from_blocks(blocks: impl IntoIterator<Item = B>) -> Self {
+        let mut headers = HashMap::default();
+        let mut hash_to_number = HashMap::default();
+        let mut bodies = HashMap::default();
+        for block in blocks {
+            let (header, body) = block.split();
+            let block_hash = header.hash_slow();
+            let block_number = header.number();
+            headers.insert(block_number, header);
+            hash_to_number.insert(block_hash, block_number);
+            bodies.insert(block_hash, body);
+        }
+        Self { headers, hash_to_number, bodies }
+    }
+
     /// Get the tip hash of the chain.
     pub fn tip(&self) -> Option<B256> {
         self.headers.get(&self.max_block()?).map(|h| h.hash_slow())
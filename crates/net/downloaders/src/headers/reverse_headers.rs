@@ -9,8 +9,9 @@ use futures::{stream::Stream, FutureExt};
 use futures_util::{stream::FuturesUnordered, StreamExt};
 use rayon::prelude::*;
 use reth_config::config::HeadersConfig;
-use reth_consensus::HeaderValidator;
+use reth_consensus::{ConsensusError, HeaderValidator};
 use reth_network_p2p::{
+    checkpoints::CanonicalCheckpoints,
     error::{DownloadError, DownloadResult, PeerRequestResult},
     headers::{
         client::{HeadersClient, HeadersRequest},
@@ -104,6 +105,8 @@ pub struct ReverseHeadersDownloader<H: HeadersClient> {
     ///
     /// Note: headers are sorted from high to low
     queued_validated_headers: Vec<SealedHeader<H::Header>>,
+    /// Hashes of known canonical blocks that responses are verified against.
+    checkpoints: CanonicalCheckpoints,
     /// Header downloader metrics.
     metrics: HeaderDownloaderMetrics,
 }
@@ -256,14 +259,12 @@ where
     fn process_next_headers(
         &mut self,
         request: HeadersRequest,
-        headers: Vec<H::Header>,
+        headers: Vec<SealedHeader<H::Header>>,
         peer_id: PeerId,
     ) -> Result<(), ReverseHeadersDownloaderError<H::Header>> {
         let mut validated = Vec::with_capacity(headers.len());
 
-        let sealed_headers =
-            headers.into_par_iter().map(SealedHeader::seal_slow).collect::<Vec<_>>();
-        for parent in sealed_headers {
+        for parent in headers {
             // Validate that the header is the parent header of the last validated header.
             if let Some(validated_header) =
                 validated.last().or_else(|| self.lowest_validated_header())
@@ -494,31 +495,42 @@ where
                 headers.sort_unstable_by_key(|h| Reverse(h.number()));
 
                 // validate the response
-                let highest = &headers[0];
+                let highest = headers[0].number();
 
-                trace!(target: "downloaders::headers", requested_block_number, highest=?highest.number(), "Validating non-empty headers response");
+                trace!(target: "downloaders::headers", requested_block_number, highest, "Validating non-empty headers response");
 
-                if highest.number() != requested_block_number {
+                if highest != requested_block_number {
                     return Err(HeadersResponseError {
                         request,
                         peer_id: Some(peer_id),
                         error: DownloadError::HeadersResponseStartBlockMismatch(GotExpected {
-                            got: highest.number(),
+                            got: highest,
                             expected: requested_block_number,
                         }),
                     }
                     .into())
                 }
 
+                let headers =
+                    headers.into_par_iter().map(SealedHeader::seal_slow).collect::<Vec<_>>();
+
+                // reject responses that contradict the known canonical chain right away, instead
+                // of once they can be attached to the validated headers
+                if let Some(error) = self.verify_checkpoints(&headers) {
+                    return Err(
+                        HeadersResponseError { request, peer_id: Some(peer_id), error }.into()
+                    )
+                }
+
                 // check if the response is the next expected
-                if highest.number() == self.next_chain_tip_block_number {
+                if highest == self.next_chain_tip_block_number {
                     // is next response, validate it
                     self.process_next_headers(request, headers, peer_id)?;
                     // try to validate all buffered responses blocked by this successful response
                     self.try_validate_buffered()
                         .map(Err::<(), ReverseHeadersDownloaderError<H::Header>>)
                         .transpose()?;
-                } else if highest.number() > self.existing_local_block_number() {
+                } else if highest > self.existing_local_block_number() {
                     self.metrics.buffered_responses.increment(1.);
                     // can't validate yet
                     self.buffered_responses.push(OrderedHeadersResponse {
@@ -539,11 +551,31 @@ where
         }
     }
 
+    /// Verifies the headers against the known canonical hashes of their block numbers.
+    ///
+    /// Returns an error for the first header that contradicts its checkpoint.
+    fn verify_checkpoints(&self, headers: &[SealedHeader<H::Header>]) -> Option<DownloadError> {
+        if self.checkpoints.is_empty() {
+            return None
+        }
+        headers.iter().find_map(|header| {
+            let expected = self.checkpoints.get(header.number())?;
+            (header.hash() != expected).then(|| DownloadError::NonCanonicalHeader {
+                number: header.number(),
+                hash: GotExpected { got: header.hash(), expected }.into(),
+            })
+        })
+    }
+
     fn penalize_peer(&self, peer_id: Option<PeerId>, error: &DownloadError) {
         // Penalize the peer for bad response
         if let Some(peer_id) = peer_id {
             trace!(target: "downloaders::headers", ?peer_id, %error, "Penalizing peer");
-            self.client.report_bad_message(peer_id);
+            if matches!(error, DownloadError::NonCanonicalHeader { .. }) {
+                self.client.report_non_canonical(peer_id);
+            } else {
+                self.client.report_bad_message(peer_id);
+            }
         }
     }
 
@@ -614,11 +646,26 @@ where
     }
 
     /// Validate whether the header is valid in relation to it's parent
+    ///
+    /// If the header is known to be canonical, it's only checked that the parent is the header's
+    /// parent, which makes the parent canonical as well.
     fn validate(
         &self,
         header: &SealedHeader<H::Header>,
         parent: &SealedHeader<H::Header>,
     ) -> DownloadResult<()> {
+        if self.checkpoints.is_canonical(header.number(), header.hash()) {
+            if header.parent_hash() != parent.hash() {
+                return Err(DownloadError::HeaderValidation {
+                    hash: header.hash(),
+                    number: header.number(),
+                    error: Box::new(ConsensusError::ParentHashMismatch(
+                        GotExpected { got: header.parent_hash(), expected: parent.hash() }.into(),
+                    )),
+                })
+            }
+            return Ok(())
+        }
         validate_header_download(&self.consensus, header, parent)
     }
 
@@ -962,7 +1009,7 @@ impl<H> HeadersRequestOutcome<H> {
 /// Wrapper type to order responses
 #[derive(Debug)]
 struct OrderedHeadersResponse<H> {
-    headers: Vec<H>,
+    headers: Vec<SealedHeader<H>>,
     request: HeadersRequest,
     peer_id: PeerId,
 }
@@ -1117,6 +1164,8 @@ pub struct ReverseHeadersDownloaderBuilder {
     max_concurrent_requests: usize,
     /// How many responses to buffer
     max_buffered_responses: usize,
+    /// Hashes of known canonical blocks
+    checkpoints: CanonicalCheckpoints,
 }
 
 impl ReverseHeadersDownloaderBuilder {
@@ -1142,6 +1191,7 @@ impl Default for ReverseHeadersDownloaderBuilder {
             max_concurrent_requests: 100,
             min_concurrent_requests: 5,
             max_buffered_responses: 100,
+            checkpoints: Default::default(),
         }
     }
 }
@@ -1194,6 +1244,16 @@ impl ReverseHeadersDownloaderBuilder {
         self
     }
 
+    /// Set the hashes of known canonical blocks.
+    ///
+    /// Responses are verified against them as soon as they arrive, and peers serving headers that
+    /// contradict them are banned. Headers that are known to be canonical skip consensus
+    /// validation.
+    pub fn checkpoints(mut self, checkpoints: CanonicalCheckpoints) -> Self {
+        self.checkpoints = checkpoints;
+        self
+    }
+
     /// Build [`ReverseHeadersDownloader`] with provided consensus
     /// and header client implementations
     pub fn build<H>(
@@ -1210,6 +1270,7 @@ impl ReverseHeadersDownloaderBuilder {
             min_concurrent_requests,
             max_concurrent_requests,
             max_buffered_responses,
+            checkpoints,
         } = self;
         ReverseHeadersDownloader {
             consensus,
@@ -1230,6 +1291,7 @@ impl ReverseHeadersDownloaderBuilder {
             in_progress_queue: Default::default(),
             buffered_responses: Default::default(),
             queued_validated_headers: Default::default(),
+            checkpoints,
             metrics: Default::default(),
         }
     }
@@ -33,6 +33,11 @@ pub mod file_client;
 /// files, efficiently buffering receipts for retrieval.
 pub mod receipt_file_client;
 
+/// Module reading blocks from era1 archives.
+///
+/// Extends [`FileClient`](file_client::FileClient) to read the blocks of an era1 file.
+pub mod era_file;
+
 /// Module with a codec for reading and encoding block bodies in files.
 ///
 /// Enables decoding and encoding `Block` types within file contexts.
@@ -106,6 +106,8 @@ where
 pub struct EthStream<S, N = EthNetworkPrimitives> {
     /// Negotiated eth version.
     version: EthVersion,
+    /// Maximum size of a message accepted from the peer.
+    max_message_size: usize,
     #[pin]
     inner: S,
 
@@ -117,7 +119,31 @@ impl<S, N> EthStream<S, N> {
     /// to manually handshake a peer.
     #[inline]
     pub const fn new(version: EthVersion, inner: S) -> Self {
-        Self { version, inner, _pd: std::marker::PhantomData }
+        Self { version, max_message_size: MAX_MESSAGE_SIZE, inner, _pd: std::marker::PhantomData }
+    }
+
+    /// Sets the maximum size of a message accepted from the peer, [`MAX_MESSAGE_SIZE`] by default.
+    ///
+    /// Larger messages are rejected with [`EthStreamError::MessageTooBig`] before they are
+    /// decoded.
+    #[inline]
+    pub const fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Sets the maximum size of a message accepted from the peer.
+    ///
+    /// See also [`EthStream::with_max_message_size`].
+    #[inline]
+    pub fn set_max_message_size(&mut self, max_message_size: usize) {
+        self.max_message_size = max_message_size;
+    }
+
+    /// Returns the maximum size of a message accepted from the peer.
+    #[inline]
+    pub const fn max_message_size(&self) -> usize {
+        self.max_message_size
     }
 
     /// Returns the eth version.
@@ -191,7 +217,7 @@ where
             None => return Poll::Ready(None),
         };
 
-        if bytes.len() > MAX_MESSAGE_SIZE {
+        if bytes.len() > *this.max_message_size {
             return Poll::Ready(Some(Err(EthStreamError::MessageTooBig(bytes.len()))))
         }
 
@@ -0,0 +1,248 @@
+//! Fuzzing of the `eth-wire` message decoding and session handling with malformed input.
+//!
+//! The `#[test]` functions call the fuzz targets with a seed corpus of malformed messages. Running
+//! them with `TEST_FUZZ_WRITE=1` records the seeds as the corpus for `cargo test-fuzz`.
+
+use alloy_primitives::{B256, U256};
+use bytes::{Bytes, BytesMut};
+use futures::{Sink, Stream, StreamExt};
+use reth_eth_wire::{
+    errors::EthStreamError, CanDisconnect, DisconnectReason, EthMessage, EthVersion,
+    ProtocolMessage, Status, UnauthedEthStream,
+};
+use reth_ethereum_forks::{ForkFilter, Head};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Maximum message size of the fuzzed sessions, small enough for the fuzzer to exceed it.
+const SESSION_MAX_MESSAGE_SIZE: usize = 1024;
+
+/// Decodes the bytes as a message of the given `eth` version.
+///
+/// Decoding must never panic, and a successfully decoded message must round-trip.
+fn decode_protocol_message(version: u8, data: &[u8]) {
+    let Ok(version) = EthVersion::try_from(version) else { return };
+    let Ok(message) = ProtocolMessage::<reth_eth_wire::EthNetworkPrimitives>::decode_message(
+        version,
+        &mut &data[..],
+    ) else {
+        return
+    };
+
+    let encoded = alloy_rlp::encode(&message);
+    let decoded = ProtocolMessage::decode_message(version, &mut &encoded[..]).unwrap();
+    assert_eq!(message, decoded);
+}
+
+/// Performs the `eth` handshake with a peer that sends the given status message, and then reads
+/// the given messages from the established session.
+///
+/// The session must never panic, and must reject messages that exceed its maximum message size.
+fn eth_session(status: &[u8], messages: Vec<Vec<u8>>) {
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    runtime.block_on(async move {
+        let (local_status, fork_filter) = local_status();
+        let peer = MockPeer {
+            incoming: std::iter::once(status.to_vec()).chain(messages).map(Bytes::from).collect(),
+        };
+
+        let Ok((stream, _)) = UnauthedEthStream::new(peer)
+            .handshake_without_timeout::<reth_eth_wire::EthNetworkPrimitives>(
+                local_status,
+                fork_filter,
+            )
+            .await
+        else {
+            return
+        };
+        let mut stream = stream.with_max_message_size(SESSION_MAX_MESSAGE_SIZE);
+
+        while let Some(remaining) = stream.inner().incoming.front().map(Bytes::len) {
+            match stream.next().await {
+                Some(Ok(message)) => {
+                    assert!(remaining <= SESSION_MAX_MESSAGE_SIZE);
+                    assert!(!matches!(message, EthMessage::Status(_)));
+                }
+                Some(Err(EthStreamError::MessageTooBig(size))) => {
+                    assert!(size > SESSION_MAX_MESSAGE_SIZE)
+                }
+                Some(Err(_)) => {}
+                None => unreachable!("mock peer has pending messages"),
+            }
+        }
+    })
+}
+
+/// Returns the status and fork filter of the local node.
+fn local_status() -> (Status, ForkFilter) {
+    let genesis = B256::with_last_byte(1);
+    let fork_filter = ForkFilter::new(Head::default(), genesis, 0, Vec::new());
+    let status = Status {
+        version: EthVersion::Eth68,
+        chain: alloy_chains::NamedChain::Mainnet.into(),
+        total_difficulty: U256::ZERO,
+        blockhash: B256::ZERO,
+        genesis,
+        forkid: fork_filter.current(),
+    };
+    (status, fork_filter)
+}
+
+/// A peer that sends the queued messages and ignores all messages sent to it.
+#[derive(Debug)]
+struct MockPeer {
+    incoming: VecDeque<Bytes>,
+}
+
+impl Stream for MockPeer {
+    type Item = io::Result<BytesMut>;
+
+    fn poll_next(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.incoming.pop_front().map(|msg| Ok(BytesMut::from(&msg[..]))))
+    }
+}
+
+impl Sink<Bytes> for MockPeer {
+    type Error = io::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, _item: Bytes) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl CanDisconnect<Bytes> for MockPeer {
+    fn disconnect(
+        &mut self,
+        _reason: DisconnectReason,
+    ) -> Pin<Box<dyn Future<Output = Result<(), io::Error>> + Send + '_>> {
+        Box::pin(async { Ok(()) })
+    }
+}
+
+#[cfg(test)]
+#[allow(missing_docs)]
+pub mod fuzz_decode {
+    use super::*;
+    use alloy_eips::BlockHashOrNumber;
+    use reth_eth_wire::{message::RequestPair, EthMessageID, GetBlockHeaders, HeadersDirection};
+    use test_fuzz::test_fuzz;
+
+    /// Fuzzes the decoding of `eth` messages.
+    #[test_fuzz]
+    fn fuzz_decode_protocol_message(version: u8, data: Vec<u8>) {
+        decode_protocol_message(version, &data)
+    }
+
+    /// Fuzzes the `eth` handshake and the established session.
+    #[test_fuzz]
+    fn fuzz_eth_session(status: Vec<u8>, messages: Vec<Vec<u8>>) {
+        eth_session(&status, messages)
+    }
+
+    /// Returns the encoding of the given message.
+    fn encode(message: EthMessage) -> Vec<u8> {
+        alloy_rlp::encode(ProtocolMessage::from(message))
+    }
+
+    /// Returns the encoding of a valid `GetBlockHeaders` request.
+    fn get_block_headers() -> Vec<u8> {
+        encode(EthMessage::GetBlockHeaders(RequestPair {
+            request_id: 1,
+            message: GetBlockHeaders {
+                start_block: BlockHashOrNumber::Number(1),
+                limit: 10,
+                skip: 0,
+                direction: HeadersDirection::Rising,
+            },
+        }))
+    }
+
+    /// Malformed `eth` messages: empty, unknown message ids, truncated payloads and list headers
+    /// announcing more bytes than present.
+    fn malformed_messages() -> Vec<Vec<u8>> {
+        let valid = get_block_headers();
+        vec![
+            vec![],
+            vec![0xff],
+            vec![EthMessageID::GetBlockHeaders as u8],
+            vec![EthMessageID::GetBlockHeaders as u8, 0xc0],
+            vec![EthMessageID::GetBlockHeaders as u8, 0xf9, 0xff, 0xff],
+            vec![EthMessageID::BlockHeaders as u8, 0xfb, 0xff, 0xff, 0xff, 0xff, 0x01],
+            vec![EthMessageID::Transactions as u8, 0xc1, 0xc0],
+            vec![EthMessageID::NewPooledTransactionHashes as u8, 0xc3, 0x80, 0xc0, 0xc0],
+            valid[..valid.len() - 1].to_vec(),
+            [valid.as_slice(), &[0x00]].concat(),
+        ]
+    }
+
+    #[test]
+    fn decode_malformed_messages() {
+        for version in [EthVersion::Eth66, EthVersion::Eth67, EthVersion::Eth68, EthVersion::Eth69]
+        {
+            for message in malformed_messages() {
+                fuzz_decode_protocol_message(version as u8, message);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_valid_message() {
+        fuzz_decode_protocol_message(EthVersion::Eth68 as u8, get_block_headers());
+    }
+
+    #[test]
+    fn decode_unknown_version() {
+        fuzz_decode_protocol_message(0, get_block_headers());
+        fuzz_decode_protocol_message(u8::MAX, get_block_headers());
+    }
+
+    #[test]
+    fn session_malformed_status() {
+        let (status, _) = local_status();
+        let valid = encode(EthMessage::Status(status));
+        for status in malformed_messages().into_iter().chain([valid[..valid.len() / 2].to_vec()]) {
+            fuzz_eth_session(status, vec![get_block_headers()]);
+        }
+    }
+
+    #[test]
+    fn session_malformed_messages() {
+        let (status, _) = local_status();
+        fuzz_eth_session(encode(EthMessage::Status(status)), malformed_messages());
+    }
+
+    #[test]
+    fn session_status_after_handshake() {
+        let (status, _) = local_status();
+        let status = encode(EthMessage::Status(status));
+        fuzz_eth_session(status.clone(), vec![status, get_block_headers()]);
+    }
+
+    #[test]
+    fn session_oversized_message() {
+        let (status, _) = local_status();
+        let mut oversized = get_block_headers();
+        oversized.resize(SESSION_MAX_MESSAGE_SIZE + 1, 0);
+        fuzz_eth_session(
+            encode(EthMessage::Status(status)),
+            vec![oversized, vec![0; SESSION_MAX_MESSAGE_SIZE + 1], get_block_headers()],
+        );
+    }
+}
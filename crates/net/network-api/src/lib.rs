@@ -21,6 +21,7 @@ pub mod events;
 pub mod noop;
 
 pub mod test_utils;
+pub mod transactions;
 use test_utils::PeersHandleProvider;
 
 pub use alloy_rpc_types_admin::EthProtocolInfo;
@@ -34,6 +35,9 @@ pub use events::{
     DiscoveredEvent, DiscoveryEvent, NetworkEvent, NetworkEventListenerProvider, PeerRequest,
     PeerRequestSender,
 };
+pub use transactions::{
+    TransactionArrival, TransactionArrivalKind, TransactionPropagated, TransactionPropagation,
+};
 
 use std::{future::Future, net::SocketAddr, sync::Arc, time::Instant};
 
@@ -87,6 +91,18 @@ pub trait NetworkInfo: Send + Sync {
 
     /// Returns `true` when the node is undergoing the very first Pipeline sync.
     fn is_initially_syncing(&self) -> bool;
+
+    /// Returns how the transaction with the given hash arrived at the node and to which peers it
+    /// was propagated.
+    ///
+    /// Returns `None` if the transaction is not tracked.
+    fn transaction_propagation(
+        &self,
+        hash: alloy_primitives::TxHash,
+    ) -> impl Future<Output = Result<Option<TransactionPropagation>, NetworkError>> + Send {
+        let _ = hash;
+        futures::future::ready(Ok(None))
+    }
 }
 
 /// Provides general purpose information about Peers in the network.
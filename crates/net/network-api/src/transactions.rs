@@ -0,0 +1,79 @@
+//! Types for observing how transactions travel through the p2p network.
+
+use crate::PeerId;
+use alloy_primitives::TxHash;
+
+/// Records how a transaction arrived at the local node and to which peers it was propagated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TransactionPropagation {
+    /// The hash of the transaction.
+    pub hash: TxHash,
+    /// Unix timestamp in milliseconds at which the transaction was first seen on the network.
+    ///
+    /// For transactions that were never received from a peer, this is the time of the first
+    /// propagation.
+    pub first_seen: u64,
+    /// The peer the transaction was first received from.
+    ///
+    /// This is `None` if the transaction was not received over the network, e.g. because it was
+    /// submitted to the local node.
+    pub origin: Option<PeerId>,
+    /// The peers the transaction was received from, in order of arrival.
+    pub received_from: Vec<TransactionArrival>,
+    /// The peers the transaction was announced or sent to, in order of propagation.
+    pub propagated_to: Vec<TransactionPropagated>,
+}
+
+impl TransactionPropagation {
+    /// Creates a new record for a transaction that was first seen at the given time.
+    pub const fn new(hash: TxHash, first_seen: u64) -> Self {
+        Self {
+            hash,
+            first_seen,
+            origin: None,
+            received_from: Vec::new(),
+            propagated_to: Vec::new(),
+        }
+    }
+}
+
+/// The arrival of a transaction from a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TransactionArrival {
+    /// The peer that sent or announced the transaction.
+    pub peer_id: PeerId,
+    /// Unix timestamp in milliseconds at which the transaction arrived from the peer.
+    pub timestamp: u64,
+    /// How the transaction arrived.
+    pub kind: TransactionArrivalKind,
+}
+
+/// How a transaction arrived from a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum TransactionArrivalKind {
+    /// The peer announced the hash of the transaction.
+    Announcement,
+    /// The peer broadcast the full transaction.
+    Broadcast,
+    /// The peer sent the full transaction in response to our request.
+    Response,
+}
+
+/// The propagation of a transaction to a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TransactionPropagated {
+    /// The peer the transaction was propagated to.
+    pub peer_id: PeerId,
+    /// Unix timestamp in milliseconds at which the transaction was propagated to the peer.
+    pub timestamp: u64,
+    /// Whether the full transaction was sent, instead of only announcing its hash.
+    pub full: bool,
+}
@@ -7,7 +7,7 @@ use std::{
     time::Duration,
 };
 
-use reth_net_banlist::BanList;
+use reth_net_banlist::{BanList, PeerAllowlist};
 use reth_network_peers::{NodeRecord, TrustedPeer};
 use tracing::info;
 
@@ -150,6 +150,9 @@ pub struct PeersConfig {
     /// Restrictions on `PeerIds` and Ips.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub ban_list: BanList,
+    /// If set, only the allowed peers are connected to or accepted from.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub allowlist: Option<PeerAllowlist>,
     /// Restrictions on connections.
     pub connection_info: ConnectionsConfig,
     /// How to weigh reputation changes.
@@ -172,6 +175,7 @@ impl Default for PeersConfig {
             connection_info: Default::default(),
             reputation_weights: Default::default(),
             ban_list: Default::default(),
+            allowlist: None,
             // Ban peers for 12h
             ban_duration: Duration::from_secs(60 * 60 * 12),
             backoff_durations: Default::default(),
@@ -191,6 +195,12 @@ impl PeersConfig {
         self
     }
 
+    /// Only connect to or accept from the peers of the allowlist.
+    pub fn with_allowlist(mut self, allowlist: PeerAllowlist) -> Self {
+        self.allowlist = Some(allowlist);
+        self
+    }
+
     /// Configure how long to ban bad peers
     pub const fn with_ban_duration(mut self, ban_duration: Duration) -> Self {
         self.ban_duration = ban_duration;
@@ -16,6 +16,11 @@ pub const PENDING_SESSION_TIMEOUT: Duration = Duration::from_secs(20);
 /// This is the time a peer has to answer a response.
 pub const PROTOCOL_BREACH_REQUEST_TIMEOUT: Duration = Duration::from_secs(2 * 60);
 
+/// Default maximum size of an `eth` message accepted from a peer.
+///
+/// This matches the `eth-wire` `MAX_MESSAGE_SIZE` limit.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 10 * 1024 * 1024;
+
 /// The default maximum number of peers.
 const DEFAULT_MAX_PEERS: usize =
     DEFAULT_MAX_COUNT_PEERS_OUTBOUND as usize + DEFAULT_MAX_COUNT_PEERS_INBOUND as usize;
@@ -50,6 +55,11 @@ pub struct SessionsConfig {
     pub protocol_breach_request_timeout: Duration,
     /// The timeout after which a pending session attempt is considered failed.
     pub pending_session_timeout: Duration,
+    /// Maximum size of an `eth` message accepted from a peer, in bytes.
+    ///
+    /// Larger messages are rejected before they are decoded and the session is dropped. Messages
+    /// are additionally bounded by the maximum `p2p` payload size of 16MiB.
+    pub max_message_size: usize,
 }
 
 impl Default for SessionsConfig {
@@ -69,6 +79,7 @@ impl Default for SessionsConfig {
             initial_internal_request_timeout: INITIAL_REQUEST_TIMEOUT,
             protocol_breach_request_timeout: PROTOCOL_BREACH_REQUEST_TIMEOUT,
             pending_session_timeout: PENDING_SESSION_TIMEOUT,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
         }
     }
 }
@@ -84,6 +95,12 @@ impl SessionsConfig {
         self
     }
 
+    /// Sets the maximum size of an `eth` message accepted from a peer.
+    pub const fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
     /// Helper function to set the buffer size for the bounded communication channel between the
     /// manager and its sessions for events emitted by the sessions.
     ///
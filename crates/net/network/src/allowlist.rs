@@ -0,0 +1,200 @@
+//! Loading the peer allowlist from a file.
+//!
+//! The file lists one entry per line, everything after a `#` is ignored:
+//!
+//! ```text
+//! # a node by its enode URL, ENR or node id
+//! enode://6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0@10.3.58.6:30303
+//! enr:-IS4QHCYrYZbAKWCBRlAy5zzaDZXJBGkcnh4MHcBFZntXNFrdvJjX04jRzjzCBOonrkTfj499SZuOh8R33Ls8RRcy5wBgmlkgnY0gmlwhH8AAAGJc2VjcDI1NmsxoQPKY0yuDUmstAHYpMa2_oxVtw0RW_QAdpzBQA8yWM0xOIN1ZHCCdl8
+//! 6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0
+//! # all nodes in an IP range
+//! 10.0.0.0/8
+//! ```
+//!
+//! Only the node ids of enode URLs and ENRs are allowed, not their addresses.
+
+use enr::Enr;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use reth_net_banlist::{IpNetwork, PeerAllowlist};
+use reth_network_peers::{pk2id, AnyNode, PeerId};
+use secp256k1::SecretKey;
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+use tracing::{info, warn};
+
+/// Errors that can occur when loading or watching the allowlist file.
+#[derive(Debug, thiserror::Error)]
+pub enum AllowlistError {
+    /// Failed to read the allowlist file.
+    #[error("failed to read peer allowlist from {path:?}: {source}")]
+    Read {
+        /// Path of the allowlist file.
+        path: PathBuf,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// An entry of the allowlist file is invalid.
+    #[error("invalid entry in peer allowlist {path:?} at line {line}: {entry}")]
+    Parse {
+        /// Path of the allowlist file.
+        path: PathBuf,
+        /// The line of the invalid entry.
+        line: usize,
+        /// The invalid entry.
+        entry: String,
+    },
+    /// Failed to watch the allowlist file.
+    #[error(transparent)]
+    Watch(#[from] notify::Error),
+}
+
+/// A [`PeerAllowlist`] loaded from a file.
+#[derive(Debug, Clone)]
+pub struct AllowlistFile {
+    /// Path of the allowlist file.
+    path: PathBuf,
+    /// The allowlist, shared with the network.
+    allowlist: PeerAllowlist,
+}
+
+impl AllowlistFile {
+    /// Loads the allowlist from the file at the given path.
+    pub fn load(path: impl Into<PathBuf>) -> Result<Self, AllowlistError> {
+        let path = path.into();
+        let (peers, networks) = read_allowlist(&path)?;
+        Ok(Self { allowlist: PeerAllowlist::new(peers, networks), path })
+    }
+
+    /// Returns the allowlist, which is updated when the file is reloaded.
+    pub const fn allowlist(&self) -> &PeerAllowlist {
+        &self.allowlist
+    }
+
+    /// Reloads the allowlist from the file.
+    ///
+    /// The active allowlist is kept if the file can't be loaded.
+    pub fn reload(&self) -> Result<(), AllowlistError> {
+        let (peers, networks) = read_allowlist(&self.path)?;
+        self.allowlist.set(peers, networks);
+        Ok(())
+    }
+
+    /// Spawns a thread that reloads the allowlist whenever the file changes.
+    ///
+    /// Peers that are no longer allowed are disconnected.
+    pub fn watch(&self) -> Result<(), AllowlistError> {
+        // Watch the parent directory, because editors commonly replace the file instead of
+        // modifying it.
+        let directory = match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut watcher = RecommendedWatcher::new(
+            move |res| {
+                let _ = tx.send(res);
+            },
+            notify::Config::default(),
+        )?;
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+
+        let file = self.clone();
+        std::thread::spawn(move || {
+            // Keep the watcher alive for as long as the thread runs.
+            let _watcher = watcher;
+            let file_name = file.path.file_name();
+
+            while let Ok(res) = rx.recv() {
+                let event = match res {
+                    Ok(event) => event,
+                    Err(err) => {
+                        warn!(target: "net", %err, "Failed to watch peer allowlist file");
+                        continue
+                    }
+                };
+
+                if !matches!(
+                    event.kind,
+                    notify::EventKind::Modify(_) | notify::EventKind::Create(_)
+                ) || !event.paths.iter().any(|changed| changed.file_name() == file_name)
+                {
+                    continue
+                }
+
+                match file.reload() {
+                    Ok(()) => info!(target: "net", path=?file.path, "Reloaded peer allowlist"),
+                    Err(err) => {
+                        warn!(target: "net", %err, "Failed to reload peer allowlist, keeping the active one")
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Reads the allowed peer ids and IP ranges from the file.
+fn read_allowlist(path: &Path) -> Result<(Vec<PeerId>, Vec<IpNetwork>), AllowlistError> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|source| AllowlistError::Read { path: path.to_path_buf(), source })?;
+    parse_allowlist(&contents).map_err(|(line, entry)| AllowlistError::Parse {
+        path: path.to_path_buf(),
+        line,
+        entry,
+    })
+}
+
+/// Parses the allowed peer ids and IP ranges, or returns the line number and invalid entry.
+fn parse_allowlist(contents: &str) -> Result<(Vec<PeerId>, Vec<IpNetwork>), (usize, String)> {
+    let mut peers = Vec::new();
+    let mut networks = Vec::new();
+
+    for (idx, line) in contents.lines().enumerate() {
+        let entry = line.split('#').next().unwrap_or_default().trim();
+        if entry.is_empty() {
+            continue
+        }
+        let invalid = || (idx + 1, entry.to_string());
+
+        if entry.starts_with("enr:") {
+            let enr = Enr::<SecretKey>::from_str(entry).map_err(|_| invalid())?;
+            peers.push(pk2id(&enr.public_key()));
+        } else if entry.starts_with("enode://") {
+            peers.push(AnyNode::from_str(entry).map_err(|_| invalid())?.peer_id());
+        } else if let Ok(network) = entry.parse::<IpNetwork>() {
+            networks.push(network);
+        } else {
+            peers.push(PeerId::from_str(entry).map_err(|_| invalid())?);
+        }
+    }
+
+    Ok((peers, networks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_entries() {
+        let contents = "
+            # bootnode
+            enode://6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0@10.3.58.6:30303
+            enr:-IS4QHCYrYZbAKWCBRlAy5zzaDZXJBGkcnh4MHcBFZntXNFrdvJjX04jRzjzCBOonrkTfj499SZuOh8R33Ls8RRcy5wBgmlkgnY0gmlwhH8AAAGJc2VjcDI1NmsxoQPKY0yuDUmstAHYpMa2_oxVtw0RW_QAdpzBQA8yWM0xOIN1ZHCCdl8
+            0x6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0
+
+            10.0.0.0/8 # private network
+            fd00::/8
+        ";
+        let (peers, networks) = parse_allowlist(contents).unwrap();
+        assert_eq!(peers.len(), 3);
+        assert_eq!(peers[0], peers[2]);
+        assert_eq!(networks, vec!["10.0.0.0/8".parse().unwrap(), "fd00::/8".parse().unwrap()]);
+
+        assert_eq!(parse_allowlist("10.0.0.0/8\nnot a node"), Err((2, "not a node".to_string())));
+    }
+}
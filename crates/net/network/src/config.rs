@@ -15,12 +15,13 @@ use reth_eth_wire::{
     EthNetworkPrimitives, HelloMessage, HelloMessageWithProtocols, NetworkPrimitives, Status,
 };
 use reth_ethereum_forks::{ForkFilter, Head};
+use reth_net_banlist::PeerAllowlist;
 use reth_network_peers::{mainnet_nodes, pk2id, sepolia_nodes, PeerId, TrustedPeer};
 use reth_network_types::{PeersConfig, SessionsConfig};
 use reth_storage_api::{noop::NoopProvider, BlockNumReader, BlockReader, HeaderProvider};
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
 use secp256k1::SECP256K1;
-use std::{collections::HashSet, net::SocketAddr, sync::Arc};
+use std::{collections::HashSet, net::SocketAddr, sync::Arc, time::Duration};
 
 // re-export for convenience
 use crate::protocol::{IntoRlpxSubProtocol, RlpxSubProtocols};
@@ -43,6 +44,8 @@ pub struct NetworkConfig<C, N: NetworkPrimitives = EthNetworkPrimitives> {
     pub secret_key: SecretKey,
     /// All boot nodes to start network discovery with.
     pub boot_nodes: HashSet<TrustedPeer>,
+    /// The interval at which boot nodes given by DNS name are resolved again, if any.
+    pub boot_nodes_resolution_interval: Option<Duration>,
     /// How to set up discovery over DNS.
     pub dns_discovery_config: Option<DnsDiscoveryConfig>,
     /// Address to use for discovery v4.
@@ -70,6 +73,8 @@ pub struct NetworkConfig<C, N: NetworkPrimitives = EthNetworkPrimitives> {
     pub block_import: Box<dyn BlockImport<N::Block>>,
     /// The default mode of the network.
     pub network_mode: NetworkMode,
+    /// How much historical chain data is served to peers.
+    pub serve_mode: ServeMode,
     /// The executor to use for spawning tasks.
     pub executor: Box<dyn TaskSpawner>,
     /// The `Status` message to send to peers at the beginning.
@@ -187,16 +192,22 @@ pub struct NetworkConfigBuilder<N: NetworkPrimitives = EthNetworkPrimitives> {
     discovery_v5_builder: Option<reth_discv5::ConfigBuilder>,
     /// All boot nodes to start network discovery with.
     boot_nodes: HashSet<TrustedPeer>,
+    /// The interval at which boot nodes given by DNS name are resolved again, if any.
+    boot_nodes_resolution_interval: Option<Duration>,
     /// Address to use for discovery
     discovery_addr: Option<SocketAddr>,
     /// Listener for incoming connections
     listener_addr: Option<SocketAddr>,
     /// How to instantiate peer manager.
     peers_config: Option<PeersConfig>,
+    /// If set, only these peers are discovered, connected to or accepted from.
+    allowlist: Option<PeerAllowlist>,
     /// How to configure the sessions manager
     sessions_config: Option<SessionsConfig>,
     /// The default mode of the network.
     network_mode: NetworkMode,
+    /// How much historical chain data is served to peers.
+    serve_mode: ServeMode,
     /// The executor to use for spawning tasks.
     executor: Option<Box<dyn TaskSpawner>>,
     /// Sets the hello message for the p2p handshake in `RLPx`
@@ -242,11 +253,14 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
             discovery_v4_builder: Some(Default::default()),
             discovery_v5_builder: None,
             boot_nodes: Default::default(),
+            boot_nodes_resolution_interval: None,
             discovery_addr: None,
             listener_addr: None,
             peers_config: None,
+            allowlist: None,
             sessions_config: None,
             network_mode: Default::default(),
+            serve_mode: Default::default(),
             executor: None,
             hello_message: None,
             extra_protocols: Default::default(),
@@ -283,6 +297,15 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
         self
     }
 
+    /// Sets the [`ServeMode`].
+    ///
+    /// A restricted mode is also advertised in the local ENR of the discovery services under the
+    /// [`ServeMode::ENR_KEY`] key.
+    pub const fn serve_mode(mut self, serve_mode: ServeMode) -> Self {
+        self.serve_mode = serve_mode;
+        self
+    }
+
     /// Configures the network to use proof-of-work.
     ///
     /// This effectively allows block propagation in the `eth` sub-protocol, which has been
@@ -325,6 +348,13 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
         self
     }
 
+    /// Restricts the network to the peers of the allowlist, which is enforced by discovery v4 and
+    /// v5 and when establishing sessions.
+    pub fn allowlist(mut self, allowlist: PeerAllowlist) -> Self {
+        self.allowlist = Some(allowlist);
+        self
+    }
+
     /// Sets the executor to use for spawning tasks.
     ///
     /// If `None`, then [`tokio::spawn`] is used for spawning tasks.
@@ -452,6 +482,15 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
         self
     }
 
+    /// Sets the interval at which boot nodes given by DNS name are resolved again.
+    ///
+    /// If the address of such a boot node changed, it's added to discovery v4 again. By default,
+    /// boot nodes are only resolved once on startup.
+    pub const fn boot_nodes_resolution_interval(mut self, interval: Duration) -> Self {
+        self.boot_nodes_resolution_interval = Some(interval);
+        self
+    }
+
     /// Returns an iterator over all configured boot nodes.
     pub fn boot_nodes_iter(&self) -> impl Iterator<Item = &TrustedPeer> + '_ {
         self.boot_nodes.iter()
@@ -579,14 +618,17 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
         let Self {
             secret_key,
             mut dns_discovery_config,
-            discovery_v4_builder,
+            mut discovery_v4_builder,
             mut discovery_v5_builder,
             boot_nodes,
+            boot_nodes_resolution_interval,
             discovery_addr,
             listener_addr,
-            peers_config,
+            mut peers_config,
+            allowlist,
             sessions_config,
             network_mode,
+            serve_mode,
             executor,
             hello_message,
             extra_protocols,
@@ -604,9 +646,32 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
                 builder = builder.fork(network_stack_id, fork_id)
             }
 
+            if !serve_mode.is_full() {
+                builder = builder.add_enr_kv_pair(
+                    ServeMode::ENR_KEY,
+                    alloy_rlp::encode(serve_mode.as_u8()).into(),
+                );
+            }
+
             builder
         });
 
+        // advertise a restricted serve mode to peers
+        if !serve_mode.is_full() {
+            if let Some(builder) = discovery_v4_builder.as_mut() {
+                builder.add_eip868_pair(ServeMode::ENR_KEY, serve_mode.as_u8());
+            }
+        }
+
+        if let Some(allowlist) = allowlist {
+            if let Some(builder) = discovery_v4_builder.as_mut() {
+                builder.allowlist(allowlist.clone());
+            }
+            discovery_v5_builder =
+                discovery_v5_builder.map(|builder| builder.allowlist(allowlist.clone()));
+            peers_config = Some(peers_config.unwrap_or_default().with_allowlist(allowlist));
+        }
+
         let listener_addr = listener_addr.unwrap_or(DEFAULT_DISCOVERY_ADDRESS);
 
         let mut hello_message =
@@ -645,6 +710,7 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
             client,
             secret_key,
             boot_nodes,
+            boot_nodes_resolution_interval,
             dns_discovery_config,
             discovery_v4_config: discovery_v4_builder.map(|builder| builder.build()),
             discovery_v5_config: discovery_v5_builder.map(|builder| builder.build()),
@@ -655,6 +721,7 @@ impl<N: NetworkPrimitives> NetworkConfigBuilder<N> {
             chain_id,
             block_import: block_import.unwrap_or_else(|| Box::<ProofOfStakeBlockImport>::default()),
             network_mode,
+            serve_mode,
             executor: executor.unwrap_or_else(|| Box::<TokioTaskExecutor>::default()),
             status,
             hello_message,
@@ -692,6 +759,77 @@ impl NetworkMode {
     }
 }
 
+/// Determines how much historical chain data the node serves to its peers.
+///
+/// Requests for data that is not served are answered with empty responses, which peers treat as
+/// if the data is unavailable.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum ServeMode {
+    /// Don't serve any headers, bodies, receipts or node data.
+    None,
+    /// Only serve block headers.
+    Headers,
+    /// Serve all available data.
+    #[default]
+    Full,
+}
+
+// === impl ServeMode ===
+
+impl ServeMode {
+    /// The key under which a restricted serve mode is advertised in the local ENR.
+    pub const ENR_KEY: &'static [u8] = b"serve";
+
+    /// Returns true if all available data is served.
+    pub const fn is_full(&self) -> bool {
+        matches!(self, Self::Full)
+    }
+
+    /// Returns true if block headers are served.
+    pub const fn serves_headers(&self) -> bool {
+        matches!(self, Self::Headers | Self::Full)
+    }
+
+    /// Returns true if block bodies, receipts and node data are served.
+    pub const fn serves_bodies(&self) -> bool {
+        self.is_full()
+    }
+
+    /// Returns the value that is advertised in the local ENR.
+    pub const fn as_u8(&self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Headers => 1,
+            Self::Full => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for ServeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Headers => f.write_str("headers"),
+            Self::Full => f.write_str("full"),
+        }
+    }
+}
+
+impl std::str::FromStr for ServeMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "headers" => Ok(Self::Headers),
+            "full" => Ok(Self::Full),
+            _ => Err(format!("invalid serve mode: {s}, expected one of none, headers, full")),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -745,4 +883,27 @@ mod tests {
         assert_eq!(status.forkid.hash, genesis_fork_hash);
         assert_eq!(fork_filter.current().hash, genesis_fork_hash);
     }
+
+    #[test]
+    fn test_serve_mode_enr_pair() {
+        let config = builder().serve_mode(ServeMode::Headers).build(NoopProvider::default());
+        let discv4 = config.discovery_v4_config.unwrap();
+        assert_eq!(
+            discv4.additional_eip868_rlp_pairs.get(ServeMode::ENR_KEY),
+            Some(&alloy_rlp::encode(1u8).into())
+        );
+
+        // the default mode is not advertised
+        let config = builder().build(NoopProvider::default());
+        let discv4 = config.discovery_v4_config.unwrap();
+        assert!(!discv4.additional_eip868_rlp_pairs.contains_key(ServeMode::ENR_KEY));
+    }
+
+    #[test]
+    fn test_serve_mode_from_str() {
+        assert_eq!("none".parse::<ServeMode>().unwrap(), ServeMode::None);
+        assert_eq!("headers".parse::<ServeMode>().unwrap(), ServeMode::Headers);
+        assert_eq!("full".parse::<ServeMode>().unwrap(), ServeMode::Full);
+        assert!("bodies".parse::<ServeMode>().is_err());
+    }
 }
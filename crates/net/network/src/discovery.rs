@@ -13,7 +13,7 @@ use reth_dns_discovery::{
 };
 use reth_ethereum_forks::{EnrForkIdEntry, ForkId};
 use reth_network_api::{DiscoveredEvent, DiscoveryEvent};
-use reth_network_peers::{NodeRecord, PeerId};
+use reth_network_peers::{NodeRecord, PeerId, TrustedPeer};
 use reth_network_types::PeerAddr;
 use secp256k1::SecretKey;
 use std::{
@@ -22,16 +22,48 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{ready, Context, Poll},
+    time::Duration,
 };
 use tokio::{sync::mpsc, task::JoinHandle};
 use tokio_stream::{wrappers::ReceiverStream, Stream};
-use tracing::trace;
+use tracing::{debug, trace};
 
 /// Default max capacity for cache of discovered peers.
 ///
 /// Default is 10 000 peers.
 pub const DEFAULT_MAX_CAPACITY_DISCOVERED_PEERS_CACHE: u32 = 10_000;
 
+/// Resolves the boot nodes given by DNS name at every interval, and re-adds a boot node to discv4
+/// if its address changed.
+///
+/// This keeps boot nodes reachable whose DNS records are updated while the node is running, e.g.
+/// on custom networks.
+pub(crate) async fn resolve_boot_nodes_periodically(
+    discv4: Discv4,
+    mut boot_nodes: Vec<(TrustedPeer, NodeRecord)>,
+    interval: Duration,
+) {
+    let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+    loop {
+        interval.tick().await;
+        for (peer, record) in &mut boot_nodes {
+            match peer.resolve().await {
+                Ok(resolved) if resolved != *record => {
+                    debug!(target: "net::discovery", %peer, previous=%record.address, address=%resolved.address, "Boot node address changed");
+                    // the node is only added if it's not in the table already
+                    discv4.remove_peer(resolved.id);
+                    discv4.add_node(resolved);
+                    *record = resolved;
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    debug!(target: "net::discovery", %peer, %err, "Failed to resolve boot node");
+                }
+            }
+        }
+    }
+}
+
 /// An abstraction over the configured discovery protocol.
 ///
 /// Listens for new discovered nodes and emits events for discovered nodes and their
@@ -43,6 +43,12 @@ impl<N: NetworkPrimitives> DownloadClient for FetchClient<N> {
         self.peers_handle.reputation_change(peer_id, ReputationChangeKind::BadMessage);
     }
 
+    fn report_non_canonical(&self, peer_id: PeerId) {
+        // serving data that contradicts the known canonical chain is never honest, so the peer is
+        // banned right away
+        self.peers_handle.reputation_change(peer_id, ReputationChangeKind::BadProtocol);
+    }
+
     fn num_connected_peers(&self) -> usize {
         self.num_active_peers.load(Ordering::Relaxed)
     }
@@ -6,6 +6,7 @@ pub use client::FetchClient;
 
 use crate::message::BlockRequest;
 use alloy_primitives::B256;
+use alloy_rlp::Encodable;
 use futures::StreamExt;
 use reth_eth_wire::{EthNetworkPrimitives, GetBlockBodies, GetBlockHeaders, NetworkPrimitives};
 use reth_network_api::test_utils::PeersHandle;
@@ -23,6 +24,7 @@ use std::{
         Arc,
     },
     task::{Context, Poll},
+    time::{Duration, Instant},
 };
 use tokio::sync::{mpsc, mpsc::UnboundedSender, oneshot};
 use tokio_stream::wrappers::UnboundedReceiverStream;
@@ -30,6 +32,13 @@ use tokio_stream::wrappers::UnboundedReceiverStream;
 type InflightHeadersRequest<H> = Request<HeadersRequest, PeerRequestResult<Vec<H>>>;
 type InflightBodiesRequest<B> = Request<Vec<B256>, PeerRequestResult<Vec<B>>>;
 
+/// The weight of the latest response in the smoothed throughput and error rate of a peer's
+/// `GetBlockBodies` responses.
+const BODIES_STATS_WEIGHT: f64 = 0.25;
+
+/// The minimum number of bodies requested from a peer, regardless of its throughput.
+const MIN_BODIES_REQUEST_LEN: usize = 8;
+
 /// Manages data fetching operations.
 ///
 /// This type is hooked into the staged sync pipeline and delegates download request to available
@@ -89,6 +98,7 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
                 best_number,
                 timeout,
                 last_response_likely_bad: false,
+                bodies: Default::default(),
             },
         );
     }
@@ -156,6 +166,43 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
         Some(*best_peer.0)
     }
 
+    /// Returns the idle peer that's expected to serve a `GetBlockBodies` request the fastest.
+    ///
+    /// Peers are ranked by the measured throughput of their previous bodies responses, discounted
+    /// by their error rate, so slow and unreliable peers are only used if no better peer is idle.
+    /// Peers without measurements are preferred, so that every peer is measured once.
+    fn next_best_bodies_peer(&self) -> Option<PeerId> {
+        self.peers
+            .iter()
+            .filter(|(_, peer)| peer.state.is_idle())
+            .max_by(|(_, a), (_, b)| {
+                // a peer that sent a bad response last time ranks lower than any other peer
+                b.last_response_likely_bad
+                    .cmp(&a.last_response_likely_bad)
+                    .then_with(|| a.bodies.rank().total_cmp(&b.bodies.rank()))
+                    .then_with(|| b.timeout().cmp(&a.timeout()))
+            })
+            .map(|(peer_id, _)| *peer_id)
+    }
+
+    /// Returns the number of bodies to request from the peer out of `len` requested bodies.
+    ///
+    /// The request size is scaled down by the ratio of the peer's score to the best score of all
+    /// peers, so a slow peer doesn't hold back a large batch. The remaining bodies are requested
+    /// again by the downloader.
+    fn bodies_request_limit(&self, peer_id: &PeerId, len: usize) -> usize {
+        let Some(score) = self.peers.get(peer_id).and_then(|peer| peer.bodies.score()) else {
+            return len
+        };
+        let best = self.peers.values().filter_map(|peer| peer.bodies.score()).fold(0.0, f64::max);
+        if best <= 0.0 {
+            return len
+        }
+
+        let limit = (len as f64 * score / best).ceil() as usize;
+        limit.clamp(MIN_BODIES_REQUEST_LEN.min(len), len)
+    }
+
     /// Returns the next action to return
     fn poll_action(&mut self) -> PollAction {
         // we only check and not pop here since we don't know yet whether a peer is available.
@@ -163,7 +210,12 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
             return PollAction::NoRequests
         }
 
-        let Some(peer_id) = self.next_best_peer() else { return PollAction::NoPeersAvailable };
+        let next_peer = if self.queued_requests.front().is_some_and(DownloadRequest::is_bodies) {
+            self.next_best_bodies_peer()
+        } else {
+            self.next_best_peer()
+        };
+        let Some(peer_id) = next_peer else { return PollAction::NoPeersAvailable };
 
         let request = self.queued_requests.pop_front().expect("not empty");
         let request = self.prepare_block_request(peer_id, request);
@@ -219,6 +271,9 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
         // update the peer's state
         if let Some(peer) = self.peers.get_mut(&peer_id) {
             peer.state = req.peer_state();
+            if req.is_bodies() {
+                peer.bodies.inflight_since = Some(Instant::now());
+            }
         }
 
         match req {
@@ -233,7 +288,8 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
                     direction,
                 })
             }
-            DownloadRequest::GetBlockBodies { request, response, .. } => {
+            DownloadRequest::GetBlockBodies { mut request, response, .. } => {
+                request.truncate(self.bodies_request_limit(&peer_id, request.len()));
                 let inflight = Request { request: request.clone(), response };
                 self.inflight_bodies_requests.insert(peer_id, inflight);
                 BlockRequest::GetBlockBodies(GetBlockBodies(request))
@@ -297,19 +353,33 @@ impl<N: NetworkPrimitives> StateFetcher<N> {
         res: RequestResult<Vec<N::BlockBody>>,
     ) -> Option<BlockResponseOutcome> {
         let is_likely_bad_response = res.as_ref().map_or(true, |bodies| bodies.is_empty());
+        let response_size =
+            res.as_ref().map_or(0, |bodies| bodies.iter().map(|body| body.length()).sum::<usize>());
 
         if let Some(resp) = self.inflight_bodies_requests.remove(&peer_id) {
             let _ = resp.response.send(res.map(|b| (peer_id, b).into()));
         }
-        if let Some(peer) = self.peers.get_mut(&peer_id) {
-            // update the peer's response state
-            peer.last_response_likely_bad = is_likely_bad_response;
+        let Some(peer) = self.peers.get_mut(&peer_id) else { return None };
+
+        // update the peer's response state
+        peer.last_response_likely_bad = is_likely_bad_response;
+        if is_likely_bad_response {
+            peer.bodies.on_error();
+        } else {
+            peer.bodies.on_response(response_size);
+        }
 
-            if peer.state.on_request_finished() && !is_likely_bad_response {
-                return self.followup_request(peer_id)
-            }
+        if !peer.state.on_request_finished() || is_likely_bad_response {
+            return None
+        }
+
+        // leave the next bodies request to a faster peer if one is idle
+        if self.queued_requests.front().is_some_and(DownloadRequest::is_bodies) &&
+            self.next_best_bodies_peer() != Some(peer_id)
+        {
+            return None
         }
-        None
+        self.followup_request(peer_id)
     }
 
     /// Returns a new [`FetchClient`] that can send requests to this type.
@@ -347,6 +417,8 @@ struct Peer {
     /// downloaded), but we still want to avoid requesting from the same peer again if it has the
     /// lowest timeout.
     last_response_likely_bad: bool,
+    /// Tracks the throughput and error rate of the peer's `GetBlockBodies` responses.
+    bodies: BodiesStats,
 }
 
 impl Peer {
@@ -355,6 +427,47 @@ impl Peer {
     }
 }
 
+/// Measurements of a peer's responses to `GetBlockBodies` requests.
+#[derive(Debug, Default)]
+struct BodiesStats {
+    /// When the inflight `GetBlockBodies` request was sent, if any.
+    inflight_since: Option<Instant>,
+    /// Smoothed throughput of the responses in bytes per second, `None` until the first response.
+    throughput: Option<f64>,
+    /// Smoothed share of the requests that failed or were answered without bodies.
+    error_rate: f64,
+}
+
+impl BodiesStats {
+    /// Records a response of the given size in bytes to the inflight request.
+    fn on_response(&mut self, size: usize) {
+        let elapsed = self.inflight_since.take().map_or(Duration::ZERO, |since| since.elapsed());
+        // avoid infinite throughput for responses that arrive within the timer resolution
+        let throughput = size as f64 / elapsed.as_secs_f64().max(0.001);
+        self.throughput = Some(self.throughput.map_or(throughput, |smoothed| {
+            smoothed + BODIES_STATS_WEIGHT * (throughput - smoothed)
+        }));
+        self.error_rate -= BODIES_STATS_WEIGHT * self.error_rate;
+    }
+
+    /// Records a failed or empty response to the inflight request.
+    fn on_error(&mut self) {
+        self.inflight_since = None;
+        self.error_rate += BODIES_STATS_WEIGHT * (1.0 - self.error_rate);
+    }
+
+    /// Returns the expected throughput of the peer, i.e. its throughput discounted by its error
+    /// rate, or `None` if no response was measured yet.
+    fn score(&self) -> Option<f64> {
+        self.throughput.map(|throughput| throughput * (1.0 - self.error_rate))
+    }
+
+    /// Returns the rank of the peer for the next request, unmeasured peers rank highest.
+    fn rank(&self) -> f64 {
+        self.score().unwrap_or(f64::INFINITY)
+    }
+}
+
 /// Tracks the state of an individual peer
 #[derive(Debug)]
 enum PeerState {
@@ -442,6 +555,11 @@ impl<N: NetworkPrimitives> DownloadRequest<N> {
     const fn is_normal_priority(&self) -> bool {
         self.get_priority().is_normal()
     }
+
+    /// Returns `true` if this is a `GetBlockBodies` request.
+    const fn is_bodies(&self) -> bool {
+        matches!(self, Self::GetBlockBodies { .. })
+    }
 }
 
 /// An action the syncer can emit.
@@ -545,6 +663,42 @@ mod tests {
         assert_eq!(fetcher.next_best_peer(), Some(peer2));
     }
 
+    #[tokio::test]
+    async fn test_bodies_peer_throughput() {
+        let manager = PeersManager::new(PeersConfig::default());
+        let mut fetcher =
+            StateFetcher::<EthNetworkPrimitives>::new(manager.handle(), Default::default());
+        let fast = B512::random();
+        let slow = B512::random();
+        let unmeasured = B512::random();
+        fetcher.new_active_peer(fast, B256::random(), 1, Arc::new(AtomicU64::new(100)));
+        fetcher.new_active_peer(slow, B256::random(), 1, Arc::new(AtomicU64::new(10)));
+        fetcher.new_active_peer(unmeasured, B256::random(), 1, Arc::new(AtomicU64::new(100)));
+
+        fetcher.peers.get_mut(&fast).unwrap().bodies.throughput = Some(4_000_000.0);
+        fetcher.peers.get_mut(&slow).unwrap().bodies.throughput = Some(500_000.0);
+
+        // unmeasured peers are tried first, then the fastest one despite its higher timeout
+        assert_eq!(fetcher.next_best_bodies_peer(), Some(unmeasured));
+        fetcher.on_pending_disconnect(&unmeasured);
+        assert_eq!(fetcher.next_best_bodies_peer(), Some(fast));
+        // headers are still requested from the peer with the lowest timeout
+        assert_eq!(fetcher.next_best_peer(), Some(slow));
+
+        // the slow peer only gets a share of the request proportional to its throughput
+        assert_eq!(fetcher.bodies_request_limit(&fast, 128), 128);
+        assert_eq!(fetcher.bodies_request_limit(&slow, 128), 16);
+        assert_eq!(fetcher.bodies_request_limit(&slow, 16), 8);
+        assert_eq!(fetcher.bodies_request_limit(&slow, 4), 4);
+        assert_eq!(fetcher.bodies_request_limit(&unmeasured, 128), 128);
+
+        // errors demote the fast peer below the slow one
+        for _ in 0..10 {
+            fetcher.peers.get_mut(&fast).unwrap().bodies.on_error();
+        }
+        assert_eq!(fetcher.next_best_bodies_peer(), Some(slow));
+    }
+
     #[tokio::test]
     async fn test_on_block_headers_response() {
         let manager = PeersManager::new(PeersConfig::default());
@@ -121,6 +121,7 @@
 /// Common helpers for network testing.
 pub mod test_utils;
 
+pub mod allowlist;
 pub mod cache;
 pub mod config;
 pub mod error;
@@ -158,8 +159,9 @@ pub use session::{
     SessionEvent, SessionId, SessionManager,
 };
 
+pub use allowlist::{AllowlistError, AllowlistFile};
 pub use builder::NetworkBuilder;
-pub use config::{NetworkConfig, NetworkConfigBuilder};
+pub use config::{NetworkConfig, NetworkConfigBuilder, ServeMode};
 pub use discovery::Discovery;
 pub use fetch::FetchClient;
 pub use flattened_response::FlattenedResponse;
@@ -172,5 +174,8 @@ pub use transactions::{FilterAnnouncement, MessageFilter, ValidateTx68};
 /// re-export p2p interfaces
 pub use reth_network_p2p as p2p;
 
+/// re-export dns discovery types
+pub use reth_dns_discovery as dns;
+
 /// re-export types crate
 pub use reth_eth_wire_types as types;
@@ -17,8 +17,8 @@
 
 use crate::{
     budget::{DEFAULT_BUDGET_TRY_DRAIN_NETWORK_HANDLE_CHANNEL, DEFAULT_BUDGET_TRY_DRAIN_SWARM},
-    config::NetworkConfig,
-    discovery::Discovery,
+    config::{NetworkConfig, ServeMode},
+    discovery::{resolve_boot_nodes_periodically, Discovery},
     error::{NetworkError, ServiceKind},
     eth_requests::IncomingEthRequest,
     import::{BlockImport, BlockImportOutcome, BlockValidation},
@@ -37,7 +37,10 @@ use crate::{
 };
 use futures::{Future, StreamExt};
 use parking_lot::Mutex;
-use reth_eth_wire::{DisconnectReason, EthNetworkPrimitives, NetworkPrimitives};
+use reth_eth_wire::{
+    BlockBodies, BlockHeaders, DisconnectReason, EthNetworkPrimitives, NetworkPrimitives, NodeData,
+    Receipts,
+};
 use reth_fs_util::{self as fs, FsPathError};
 use reth_metrics::common::mpsc::UnboundedMeteredSender;
 use reth_network_api::{
@@ -128,6 +131,11 @@ pub struct NetworkManager<N: NetworkPrimitives = EthNetworkPrimitives> {
     /// requests. This channel size is set at
     /// [`ETH_REQUEST_CHANNEL_CAPACITY`](crate::builder::ETH_REQUEST_CHANNEL_CAPACITY)
     to_eth_request_handler: Option<mpsc::Sender<IncomingEthRequest<N>>>,
+    /// How much historical chain data is served to peers.
+    ///
+    /// Requests for data that is not served are answered with empty responses and never reach the
+    /// [`EthRequestHandler`](crate::eth_requests::EthRequestHandler).
+    serve_mode: ServeMode,
     /// Tracks the number of active session (connected peers).
     ///
     /// This is updated via internal events and shared via `Arc` with the [`NetworkHandle`]
@@ -238,7 +246,9 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             chain_id,
             block_import,
             network_mode,
+            serve_mode,
             boot_nodes,
+            boot_nodes_resolution_interval,
             executor,
             hello_message,
             status,
@@ -271,6 +281,14 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             disc_config.add_eip868_pair("eth", status.forkid);
         }
 
+        // boot nodes given by DNS name, paired with the record they resolved to
+        let named_boot_nodes = boot_nodes
+            .iter()
+            .zip(&resolved_boot_nodes)
+            .filter(|(peer, _)| matches!(peer.host, url::Host::Domain(_)))
+            .map(|(peer, record)| (peer.clone(), *record))
+            .collect::<Vec<_>>();
+
         if let Some(discv5) = discovery_v5_config.as_mut() {
             // merge configured boot nodes
             discv5.extend_unsigned_boot_nodes(resolved_boot_nodes)
@@ -290,6 +308,16 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
         let discv4 = discovery.discv4();
         let discv5 = discovery.discv5();
 
+        if let (Some(discv4), Some(interval)) = (discv4.clone(), boot_nodes_resolution_interval) {
+            if !named_boot_nodes.is_empty() {
+                executor.spawn(Box::pin(resolve_boot_nodes_periodically(
+                    discv4,
+                    named_boot_nodes,
+                    interval,
+                )));
+            }
+        }
+
         let num_active_peers = Arc::new(AtomicUsize::new(0));
 
         let sessions = SessionManager::new(
@@ -340,6 +368,7 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
             event_sender,
             to_transactions_manager: None,
             to_eth_request_handler: None,
+            serve_mode,
             num_active_peers,
             metrics: Default::default(),
             disconnect_metrics: Default::default(),
@@ -480,6 +509,12 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
 
     /// Handle an incoming request from the peer
     fn on_eth_request(&self, peer_id: PeerId, req: PeerRequest<N>) {
+        let Some(req) = self.reject_unserved_eth_request(req) else {
+            trace!(target: "net", ?peer_id, serve_mode=%self.serve_mode, "Rejected eth request");
+            self.metrics.total_rejected_eth_requests.increment(1);
+            return
+        };
+
         match req {
             PeerRequest::GetBlockHeaders { request, response } => {
                 self.delegate_eth_request(IncomingEthRequest::GetBlockHeaders {
@@ -519,6 +554,27 @@ impl<N: NetworkPrimitives> NetworkManager<N> {
         }
     }
 
+    /// Answers the request with an empty response if the requested data is not served according
+    /// to the [`ServeMode`], otherwise returns the request.
+    fn reject_unserved_eth_request(&self, req: PeerRequest<N>) -> Option<PeerRequest<N>> {
+        match req {
+            PeerRequest::GetBlockHeaders { response, .. } if !self.serve_mode.serves_headers() => {
+                let _ = response.send(Ok(BlockHeaders(Vec::new())));
+            }
+            PeerRequest::GetBlockBodies { response, .. } if !self.serve_mode.serves_bodies() => {
+                let _ = response.send(Ok(BlockBodies(Vec::new())));
+            }
+            PeerRequest::GetNodeData { response, .. } if !self.serve_mode.serves_bodies() => {
+                let _ = response.send(Ok(NodeData(Vec::new())));
+            }
+            PeerRequest::GetReceipts { response, .. } if !self.serve_mode.serves_bodies() => {
+                let _ = response.send(Ok(Receipts(Vec::new())));
+            }
+            req => return Some(req),
+        }
+        None
+    }
+
     /// Invoked after a `NewBlock` message from the peer was validated
     fn on_block_import_result(&mut self, outcome: BlockImportOutcome<N::Block>) {
         let BlockImportOutcome { peer, result } = outcome;
@@ -2,7 +2,7 @@ use crate::{
     config::NetworkMode, message::PeerMessage, protocol::RlpxSubProtocol,
     swarm::NetworkConnectionState, transactions::TransactionsHandle, FetchClient,
 };
-use alloy_primitives::B256;
+use alloy_primitives::{TxHash, B256};
 use enr::Enr;
 use futures::StreamExt;
 use parking_lot::Mutex;
@@ -18,7 +18,7 @@ use reth_network_api::{
     test_utils::{PeersHandle, PeersHandleProvider},
     BlockDownloaderProvider, DiscoveryEvent, NetworkError, NetworkEvent,
     NetworkEventListenerProvider, NetworkInfo, NetworkStatus, PeerInfo, PeerRequest, Peers,
-    PeersInfo,
+    PeersInfo, TransactionPropagation,
 };
 use reth_network_p2p::sync::{NetworkSyncUpdater, SyncState, SyncStateProvider};
 use reth_network_peers::{NodeRecord, PeerId};
@@ -386,6 +386,14 @@ impl<N: NetworkPrimitives> NetworkInfo for NetworkHandle<N> {
     fn is_initially_syncing(&self) -> bool {
         SyncStateProvider::is_initially_syncing(self)
     }
+
+    async fn transaction_propagation(
+        &self,
+        hash: TxHash,
+    ) -> Result<Option<TransactionPropagation>, NetworkError> {
+        let Some(handle) = self.transactions_handle().await else { return Ok(None) };
+        handle.transaction_propagation(hash).await.map_err(Into::into)
+    }
 }
 
 impl<N: NetworkPrimitives> SyncStateProvider for NetworkHandle<N> {
@@ -8,7 +8,7 @@ use crate::{
 use futures::StreamExt;
 use reth_eth_wire::{errors::EthStreamError, DisconnectReason};
 use reth_ethereum_forks::ForkId;
-use reth_net_banlist::BanList;
+use reth_net_banlist::{BanList, PeerAllowlist};
 use reth_network_api::test_utils::{PeerCommand, PeersHandle};
 use reth_network_peers::{NodeRecord, PeerId};
 use reth_network_types::{
@@ -64,6 +64,10 @@ pub struct PeersManager {
     connection_info: ConnectionInfo,
     /// Tracks unwanted ips/peer ids.
     ban_list: BanList,
+    /// If set, only these peers (and trusted peers) are connected to or accepted from.
+    allowlist: Option<PeerAllowlist>,
+    /// The version of the allowlist the peers were last checked against.
+    allowlist_version: u64,
     /// Tracks currently backed off peers.
     backed_off_peers: HashMap<PeerId, std::time::Instant>,
     /// Interval at which to check for peers to unban and release from the backoff map.
@@ -94,6 +98,7 @@ impl PeersManager {
             connection_info,
             reputation_weights,
             ban_list,
+            allowlist,
             ban_duration,
             backoff_durations,
             trusted_nodes,
@@ -142,6 +147,8 @@ impl PeersManager {
             release_interval: tokio::time::interval_at(now + unban_interval, unban_interval),
             connection_info: ConnectionInfo::new(connection_info),
             ban_list,
+            allowlist,
+            allowlist_version: 0,
             backed_off_peers: Default::default(),
             ban_duration,
             backoff_durations,
@@ -227,7 +234,7 @@ impl PeersManager {
 
     /// Invoked when a new _incoming_ tcp connection is accepted.
     ///
-    /// returns an error if the inbound ip address is on the ban list
+    /// returns an error if the inbound ip address is on the ban list, or not on the allowlist
     pub(crate) fn on_incoming_pending_session(
         &mut self,
         addr: IpAddr,
@@ -236,6 +243,14 @@ impl PeersManager {
             return Err(InboundConnectionError::IpBanned)
         }
 
+        // peer ids are only known after the handshake, so this can only reject the ip if neither
+        // peer ids nor trusted peers are allowed
+        if self.trusted_peer_ids.is_empty() &&
+            self.allowlist.as_ref().is_some_and(|allowlist| allowlist.rejects_ip(&addr))
+        {
+            return Err(InboundConnectionError::IpNotAllowed)
+        }
+
         // check if we even have slots for a new incoming connection
         if !self.connection_info.has_in_capacity() {
             if self.trusted_peer_ids.is_empty() {
@@ -326,6 +341,12 @@ impl PeersManager {
             return
         }
 
+        if !self.is_allowed(&peer_id, &addr.ip()) {
+            trace!(target: "net::peers", ?peer_id, ?addr, "disconnecting incoming peer not in allowlist");
+            self.queued_actions.push_back(PeerAction::DisconnectUntrustedIncoming { peer_id });
+            return
+        }
+
         // start a new tick, so the peer is not immediately rewarded for the time since last tick
         self.tick();
 
@@ -707,6 +728,30 @@ impl PeersManager {
         self.add_peer_kind(peer_id, PeerKind::Trusted, addr, None)
     }
 
+    /// Returns true if the peer is trusted or isn't excluded by the configured allowlist.
+    fn is_allowed(&self, peer_id: &PeerId, ip: &IpAddr) -> bool {
+        self.trusted_peer_ids.contains(peer_id) ||
+            self.allowlist.as_ref().is_none_or(|allowlist| allowlist.is_allowed(peer_id, ip))
+    }
+
+    /// Removes all peers that are no longer allowed, e.g. after the allowlist was reloaded, and
+    /// disconnects them.
+    fn evict_disallowed_peers(&mut self) {
+        if self.allowlist.is_none() {
+            return
+        }
+        let disallowed = self
+            .peers
+            .iter()
+            .filter(|(peer_id, peer)| !self.is_allowed(peer_id, &peer.addr.tcp().ip()))
+            .map(|(peer_id, _)| *peer_id)
+            .collect::<Vec<_>>();
+        for peer_id in disallowed {
+            trace!(target: "net::peers", ?peer_id, "removing peer not in allowlist");
+            self.remove_peer(peer_id);
+        }
+    }
+
     /// Called for a newly discovered peer.
     ///
     /// If the peer already exists, then the address, kind and `fork_id` will be updated.
@@ -720,6 +765,10 @@ impl PeersManager {
         if self.ban_list.is_banned(&peer_id, &addr.tcp().ip()) {
             return
         }
+        if !kind.is_trusted() && !self.is_allowed(&peer_id, &addr.tcp().ip()) {
+            trace!(target: "net::peers", ?peer_id, addr=?addr.tcp(), "ignoring peer not in allowlist");
+            return
+        }
 
         match self.peers.entry(peer_id) {
             Entry::Occupied(mut entry) => {
@@ -801,6 +850,10 @@ impl PeersManager {
         if self.ban_list.is_banned(&peer_id, &addr.tcp().ip()) {
             return
         }
+        if !kind.is_trusted() && !self.is_allowed(&peer_id, &addr.tcp().ip()) {
+            trace!(target: "net::peers", ?peer_id, addr=?addr.tcp(), "ignoring peer not in allowlist");
+            return
+        }
 
         match self.peers.entry(peer_id) {
             Entry::Occupied(mut entry) => {
@@ -962,6 +1015,16 @@ impl PeersManager {
                 }
             }
 
+            // disconnect the peers that are no longer allowed once the allowlist was reloaded
+            if self
+                .allowlist
+                .as_ref()
+                .is_some_and(|allowlist| allowlist.poll_changed(cx, &mut self.allowlist_version))
+            {
+                self.evict_disallowed_peers();
+                continue
+            }
+
             if self.release_interval.poll_tick(cx).is_ready() {
                 let now = std::time::Instant::now();
                 let (_, unbanned_peers) = self.ban_list.evict(now);
@@ -1145,6 +1208,8 @@ pub enum PeerAction {
 pub enum InboundConnectionError {
     /// The remote's ip address is banned
     IpBanned,
+    /// The remote's ip address is not on the allowlist
+    IpNotAllowed,
     /// No capacity for new inbound connections
     ExceedsCapacity,
 }
@@ -1162,7 +1227,7 @@ mod tests {
         errors::{EthHandshakeError, EthStreamError, P2PHandshakeError, P2PStreamError},
         DisconnectReason,
     };
-    use reth_net_banlist::BanList;
+    use reth_net_banlist::{BanList, PeerAllowlist};
     use reth_network_api::Direction;
     use reth_network_peers::{PeerId, TrustedPeer};
     use reth_network_types::{
@@ -2444,6 +2509,52 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_allowlist() {
+        let allowed_peer = PeerId::random();
+        let allowlist = PeerAllowlist::new([], ["10.0.0.0/8".parse().unwrap()]);
+        let config = PeersConfig::test().with_allowlist(allowlist.clone());
+        let mut peers = PeersManager::new(config);
+
+        let public_ip = IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1));
+        assert_eq!(
+            peers.on_incoming_pending_session(public_ip).unwrap_err(),
+            InboundConnectionError::IpNotAllowed
+        );
+
+        let private_sock = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), 30303);
+        assert!(peers.on_incoming_pending_session(private_sock.ip()).is_ok());
+        peers.on_incoming_session_established(allowed_peer, private_sock);
+        assert!(peers.peers.contains_key(&allowed_peer));
+
+        // peers outside the allowlist are not added
+        let other_peer = PeerId::random();
+        peers.add_peer(other_peer, PeerAddr::from_tcp(SocketAddr::new(public_ip, 30303)), None);
+        assert!(!peers.peers.contains_key(&other_peer));
+
+        // observe the initial allowlist
+        peers.queued_actions.clear();
+        poll_fn(|cx| {
+            assert!(peers.poll(cx).is_pending());
+            Poll::Ready(())
+        })
+        .await;
+
+        // right after a reload, the peer is no longer allowed and disconnected
+        allowlist.set([other_peer], []);
+        assert!(matches!(
+            event!(peers),
+            PeerAction::PeerRemoved(peer_id) if peer_id == allowed_peer
+        ));
+        assert!(matches!(
+            event!(peers),
+            PeerAction::Disconnect { peer_id, .. } if peer_id == allowed_peer
+        ));
+
+        // with allowed peer ids, incoming connections are checked after the handshake
+        assert!(peers.on_incoming_pending_session(public_ip).is_ok());
+    }
+
     #[tokio::test]
     async fn test_incoming_rate_limit() {
         let config = PeersConfig {
@@ -847,7 +847,7 @@ mod tests {
     use reth_eth_wire::{
         handshake::EthHandshake, EthNetworkPrimitives, EthStream, GetBlockBodies,
         HelloMessageWithProtocols, P2PStream, Status, StatusBuilder, UnauthedEthStream,
-        UnauthedP2PStream,
+        UnauthedP2PStream, MAX_MESSAGE_SIZE,
     };
     use reth_network_peers::pk2id;
     use reth_network_types::session::config::PROTOCOL_BREACH_REQUEST_TIMEOUT;
@@ -931,6 +931,7 @@ mod tests {
                 self.status,
                 self.fork_filter.clone(),
                 Default::default(),
+                MAX_MESSAGE_SIZE,
             ));
 
             let mut stream = ReceiverStream::new(pending_sessions_rx);
@@ -115,6 +115,8 @@ pub struct SessionManager<N: NetworkPrimitives> {
     metrics: SessionManagerMetrics,
     /// The [`EthRlpxHandshake`] is used to perform the initial handshake with the peer.
     handshake: Arc<dyn EthRlpxHandshake>,
+    /// Maximum size of an `eth` message accepted from a peer.
+    max_message_size: usize,
 }
 
 // === impl SessionManager ===
@@ -158,6 +160,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
             disconnections_counter: Default::default(),
             metrics: Default::default(),
             handshake,
+            max_message_size: config.max_message_size,
         }
     }
 
@@ -271,6 +274,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                 status,
                 fork_filter,
                 extra_handlers,
+                self.max_message_size,
             ),
         ));
 
@@ -313,6 +317,7 @@ impl<N: NetworkPrimitives> SessionManager<N> {
                     status,
                     fork_filter,
                     extra_handlers,
+                    self.max_message_size,
                 ),
             ));
 
@@ -831,6 +836,7 @@ pub(crate) async fn start_pending_incoming_session<N: NetworkPrimitives>(
     status: Status,
     fork_filter: ForkFilter,
     extra_handlers: RlpxSubProtocolHandlers,
+    max_message_size: usize,
 ) {
     authenticate(
         handshake,
@@ -845,6 +851,7 @@ pub(crate) async fn start_pending_incoming_session<N: NetworkPrimitives>(
         status,
         fork_filter,
         extra_handlers,
+        max_message_size,
     )
     .await
 }
@@ -864,6 +871,7 @@ async fn start_pending_outbound_session<N: NetworkPrimitives>(
     status: Status,
     fork_filter: ForkFilter,
     extra_handlers: RlpxSubProtocolHandlers,
+    max_message_size: usize,
 ) {
     let stream = match TcpStream::connect(remote_addr).await {
         Ok(stream) => {
@@ -897,6 +905,7 @@ async fn start_pending_outbound_session<N: NetworkPrimitives>(
         status,
         fork_filter,
         extra_handlers,
+        max_message_size,
     )
     .await
 }
@@ -916,6 +925,7 @@ async fn authenticate<N: NetworkPrimitives>(
     status: Status,
     fork_filter: ForkFilter,
     extra_handlers: RlpxSubProtocolHandlers,
+    max_message_size: usize,
 ) {
     let local_addr = stream.local_addr().ok();
     let stream = match get_ecies_stream(stream, secret_key, direction).await {
@@ -946,6 +956,7 @@ async fn authenticate<N: NetworkPrimitives>(
         status,
         fork_filter,
         extra_handlers,
+        max_message_size,
     )
     .boxed();
 
@@ -999,6 +1010,7 @@ async fn authenticate_stream<N: NetworkPrimitives>(
     mut status: Status,
     fork_filter: ForkFilter,
     mut extra_handlers: RlpxSubProtocolHandlers,
+    max_message_size: usize,
 ) -> PendingSessionEvent<N> {
     // Add extra protocols to the hello message
     extra_handlers.retain(|handler| hello.try_add_protocol(handler.protocol()).is_ok());
@@ -1068,7 +1080,8 @@ async fn authenticate_stream<N: NetworkPrimitives>(
             .await
         {
             Ok(their_status) => {
-                let eth_stream = EthStream::new(status.version, p2p_stream);
+                let eth_stream = EthStream::new(status.version, p2p_stream)
+                    .with_max_message_size(max_message_size);
                 (eth_stream.into(), their_status)
             }
             Err(err) => {
@@ -1096,7 +1109,7 @@ async fn authenticate_stream<N: NetworkPrimitives>(
                 .ok();
         }
 
-        let (multiplex_stream, their_status) =
+        let (mut multiplex_stream, their_status) =
             match multiplex_stream.into_eth_satellite_stream(status, fork_filter).await {
                 Ok((multiplex_stream, their_status)) => (multiplex_stream, their_status),
                 Err(err) => {
@@ -1108,6 +1121,7 @@ async fn authenticate_stream<N: NetworkPrimitives>(
                     }
                 }
             };
+        multiplex_stream.primary_mut().set_max_message_size(max_message_size);
 
         (multiplex_stream.into(), their_status)
     };
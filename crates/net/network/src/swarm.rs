@@ -195,6 +195,9 @@ impl<N: NetworkPrimitives> Swarm<N> {
                         InboundConnectionError::IpBanned => {
                             trace!(target: "net", ?remote_addr, "The incoming ip address is in the ban list");
                         }
+                        InboundConnectionError::IpNotAllowed => {
+                            trace!(target: "net", ?remote_addr, "The incoming ip address is not in the allowlist");
+                        }
                         InboundConnectionError::ExceedsCapacity => {
                             trace!(target: "net", ?remote_addr, "No capacity for incoming connection");
                             self.sessions.try_disconnect_incoming_connection(
@@ -53,6 +53,18 @@ pub mod tx_manager {
     ///
     /// Default is 100 KiB, i.e. 3 200 transaction hashes.
     pub const DEFAULT_MAX_COUNT_BAD_IMPORTS: u32 = 100 * 1024 / 32;
+
+    /// Default limit for number of transactions to keep propagation records for.
+    ///
+    /// Default is equivalent to the number of hashes in one full announcement, which is spec'd at
+    /// 4096 hashes, so 4096 transactions.
+    pub const DEFAULT_MAX_COUNT_TRACKED_PROPAGATIONS: u32 =
+        SOFT_LIMIT_COUNT_HASHES_IN_NEW_POOLED_TRANSACTIONS_BROADCAST_MESSAGE as u32;
+
+    /// Default limit for number of peers to record per direction in a single propagation record.
+    ///
+    /// Default is 128 peers.
+    pub const DEFAULT_MAX_COUNT_PEERS_PER_PROPAGATION: usize = 128;
 }
 
 /// Constants used by [`TransactionFetcher`](super::TransactionFetcher).
@@ -6,6 +6,7 @@ pub mod config;
 pub mod constants;
 /// Component responsible for fetching transactions from [`NewPooledTransactionHashes`].
 pub mod fetcher;
+mod propagation;
 pub mod validation;
 
 pub use self::constants::{
@@ -16,6 +17,7 @@ pub use config::{TransactionFetcherConfig, TransactionPropagationMode, Transacti
 pub use validation::*;
 
 pub(crate) use fetcher::{FetchEvent, TransactionFetcher};
+use propagation::PropagationTracker;
 
 use self::constants::{tx_manager::*, DEFAULT_SOFT_LIMIT_BYTE_SIZE_TRANSACTIONS_BROADCAST_MESSAGE};
 use crate::{
@@ -42,6 +44,7 @@ use reth_metrics::common::mpsc::UnboundedMeteredReceiver;
 use reth_network_api::{
     events::{PeerEvent, SessionInfo},
     NetworkEvent, NetworkEventListenerProvider, PeerRequest, PeerRequestSender, Peers,
+    TransactionArrivalKind, TransactionPropagation,
 };
 use reth_network_p2p::{
     error::{RequestError, RequestResult},
@@ -195,6 +198,18 @@ impl<N: NetworkPrimitives> TransactionsHandle<N> {
         Ok(res.into_values().next().unwrap_or_default())
     }
 
+    /// Requests the record of how the transaction arrived and to which peers it was propagated.
+    ///
+    /// Returns `None` if the transaction is not tracked.
+    pub async fn transaction_propagation(
+        &self,
+        hash: TxHash,
+    ) -> Result<Option<TransactionPropagation>, RecvError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(TransactionsCommand::GetTransactionPropagation { hash, tx });
+        rx.await
+    }
+
     /// Requests the transactions directly from the given peer.
     ///
     /// Returns `None` if the peer is not connected.
@@ -266,6 +281,8 @@ pub struct TransactionsManager<Pool, N: NetworkPrimitives = EthNetworkPrimitives
     pending_pool_imports_info: PendingPoolImportsInfo,
     /// Bad imports.
     bad_imports: LruCache<TxHash>,
+    /// Records of where recently seen transactions came from and where they were propagated to.
+    propagations: PropagationTracker,
     /// All the connected peers.
     peers: HashMap<PeerId, PeerMetadata<N>>,
     /// Send half for the command channel.
@@ -332,6 +349,7 @@ impl<Pool: TransactionPool, N: NetworkPrimitives> TransactionsManager<Pool, N> {
                 DEFAULT_MAX_COUNT_PENDING_POOL_IMPORTS,
             ),
             bad_imports: LruCache::new(DEFAULT_MAX_COUNT_BAD_IMPORTS),
+            propagations: PropagationTracker::new(DEFAULT_MAX_COUNT_TRACKED_PROPAGATIONS),
             peers: Default::default(),
             command_tx,
             command_rx: UnboundedReceiverStream::new(command_rx),
@@ -516,6 +534,12 @@ where
         };
         let client = peer.client_version.clone();
 
+        self.propagations.on_received(
+            msg.iter_hashes().copied(),
+            peer_id,
+            TransactionArrivalKind::Announcement,
+        );
+
         // keep track of the transactions the peer knows
         let mut count_txns_already_seen_by_peer = 0;
         for tx in msg.iter_hashes().copied() {
@@ -798,6 +822,7 @@ where
 
         // Update propagated transactions metrics
         self.metrics.propagated_transactions.increment(propagated.0.len() as u64);
+        self.propagations.on_propagated(&propagated);
 
         Some(propagated)
     }
@@ -862,6 +887,7 @@ where
 
             // Update propagated transactions metrics
             self.metrics.propagated_transactions.increment(propagated.0.len() as u64);
+            self.propagations.on_propagated(&propagated);
 
             propagated
         };
@@ -960,6 +986,7 @@ where
 
         // Update propagated transactions metrics
         self.metrics.propagated_transactions.increment(propagated.0.len() as u64);
+        self.propagations.on_propagated(&propagated);
 
         propagated
     }
@@ -1047,6 +1074,9 @@ where
                 let sender = self.peers.get(&peer_id).map(|peer| peer.request_tx.clone());
                 peer_request_sender.send(sender).ok();
             }
+            TransactionsCommand::GetTransactionPropagation { hash, tx } => {
+                tx.send(self.propagations.get(&hash)).ok();
+            }
         }
     }
 
@@ -1186,6 +1216,11 @@ where
         // mark the transactions as received
         self.transaction_fetcher
             .remove_hashes_from_transaction_fetcher(transactions.iter().map(|tx| *tx.tx_hash()));
+        self.propagations.on_received(
+            transactions.iter().map(|tx| *tx.tx_hash()),
+            peer_id,
+            source.arrival_kind(),
+        );
 
         // track that the peer knows these transaction, but only if this is a new broadcast.
         // If we received the transactions as the response to our `GetPooledTransactions``
@@ -1775,6 +1810,14 @@ impl TransactionSource {
     const fn is_broadcast(&self) -> bool {
         matches!(self, Self::Broadcast)
     }
+
+    /// Returns the [`TransactionArrivalKind`] recorded for transactions of this source.
+    const fn arrival_kind(&self) -> TransactionArrivalKind {
+        match self {
+            Self::Broadcast => TransactionArrivalKind::Broadcast,
+            Self::Response => TransactionArrivalKind::Response,
+        }
+    }
 }
 
 /// Tracks a single peer in the context of [`TransactionsManager`].
@@ -1834,6 +1877,8 @@ enum TransactionsCommand<N: NetworkPrimitives = EthNetworkPrimitives> {
         peer_id: PeerId,
         peer_request_sender: oneshot::Sender<Option<PeerRequestSender<PeerRequest<N>>>>,
     },
+    /// Requests the propagation record of a transaction from the [`TransactionsManager`].
+    GetTransactionPropagation { hash: TxHash, tx: oneshot::Sender<Option<TransactionPropagation>> },
 }
 
 /// All events related to transactions emitted by the network.
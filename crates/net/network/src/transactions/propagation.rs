@@ -0,0 +1,132 @@
+//! Tracking of where transactions came from and to which peers they were propagated.
+
+use super::constants::tx_manager::DEFAULT_MAX_COUNT_PEERS_PER_PROPAGATION;
+use crate::cache::LruMap;
+use alloy_primitives::TxHash;
+use reth_network_api::{
+    TransactionArrival, TransactionArrivalKind, TransactionPropagated, TransactionPropagation,
+};
+use reth_network_peers::PeerId;
+use reth_transaction_pool::PropagatedTransactions;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Keeps [`TransactionPropagation`] records of the most recently seen transactions.
+#[derive(Debug)]
+pub(crate) struct PropagationTracker {
+    /// Propagation records by transaction hash.
+    records: LruMap<TxHash, TransactionPropagation>,
+}
+
+impl PropagationTracker {
+    /// Returns a new tracker that keeps records of at most `max_tracked` transactions.
+    pub(crate) fn new(max_tracked: u32) -> Self {
+        Self { records: LruMap::new(max_tracked) }
+    }
+
+    /// Records that the given transactions arrived from the peer.
+    ///
+    /// Only the first arrival of a transaction from a specific peer is recorded.
+    pub(crate) fn on_received(
+        &mut self,
+        hashes: impl IntoIterator<Item = TxHash>,
+        peer_id: PeerId,
+        kind: TransactionArrivalKind,
+    ) {
+        let timestamp = unix_timestamp_millis();
+        for hash in hashes {
+            let arrival = TransactionArrival { peer_id, timestamp, kind };
+            if let Some(record) = self.records.get(&hash) {
+                if record.received_from.len() < DEFAULT_MAX_COUNT_PEERS_PER_PROPAGATION &&
+                    !record.received_from.iter().any(|arrival| arrival.peer_id == peer_id)
+                {
+                    record.received_from.push(arrival);
+                }
+                continue
+            }
+
+            let mut record = TransactionPropagation::new(hash, timestamp);
+            record.origin = Some(peer_id);
+            record.received_from.push(arrival);
+            self.records.insert(hash, record);
+        }
+    }
+
+    /// Records the peers the transactions were propagated to.
+    pub(crate) fn on_propagated(&mut self, propagated: &PropagatedTransactions) {
+        let timestamp = unix_timestamp_millis();
+        for (hash, kinds) in &propagated.0 {
+            let Some(record) =
+                self.records.get_or_insert(*hash, || TransactionPropagation::new(*hash, timestamp))
+            else {
+                continue
+            };
+            for kind in kinds {
+                if record.propagated_to.len() >= DEFAULT_MAX_COUNT_PEERS_PER_PROPAGATION {
+                    break
+                }
+                record.propagated_to.push(TransactionPropagated {
+                    peer_id: *kind.peer(),
+                    timestamp,
+                    full: kind.is_full(),
+                });
+            }
+        }
+    }
+
+    /// Returns the propagation record of the transaction, if tracked.
+    pub(crate) fn get(&self, hash: &TxHash) -> Option<TransactionPropagation> {
+        self.records.peek(hash).cloned()
+    }
+}
+
+/// Returns the current unix timestamp in milliseconds.
+fn unix_timestamp_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_transaction_pool::PropagateKind;
+
+    #[test]
+    fn track_arrivals_and_propagation() {
+        let mut tracker = PropagationTracker::new(2);
+        let (origin, other, target) = (PeerId::random(), PeerId::random(), PeerId::random());
+        let (received, local, evicted) = (TxHash::random(), TxHash::random(), TxHash::random());
+
+        tracker.on_received([received], origin, TransactionArrivalKind::Announcement);
+        tracker.on_received([received], other, TransactionArrivalKind::Broadcast);
+        tracker.on_received([received], origin, TransactionArrivalKind::Response);
+
+        let mut propagated = PropagatedTransactions::default();
+        propagated.0.insert(received, vec![PropagateKind::Full(target)]);
+        propagated.0.insert(local, vec![PropagateKind::Hash(target)]);
+        tracker.on_propagated(&propagated);
+
+        let record = tracker.get(&received).unwrap();
+        assert_eq!(record.origin, Some(origin));
+        assert_eq!(
+            record.received_from.iter().map(|arrival| arrival.peer_id).collect::<Vec<_>>(),
+            vec![origin, other]
+        );
+        assert_eq!(record.received_from[0].kind, TransactionArrivalKind::Announcement);
+        assert_eq!(record.first_seen, record.received_from[0].timestamp);
+        assert_eq!(record.propagated_to.len(), 1);
+        assert!(record.propagated_to[0].full);
+
+        let record = tracker.get(&local).unwrap();
+        assert_eq!(record.origin, None);
+        assert!(record.received_from.is_empty());
+        assert_eq!(record.propagated_to[0].peer_id, target);
+        assert!(!record.propagated_to[0].full);
+
+        // seeing a transaction again marks it as recently seen, so the least recently seen
+        // transaction is evicted
+        tracker.on_received([received], origin, TransactionArrivalKind::Broadcast);
+        tracker.on_received([evicted], origin, TransactionArrivalKind::Broadcast);
+        assert!(tracker.get(&evicted).is_some());
+        assert!(tracker.get(&received).is_some());
+        assert!(tracker.get(&local).is_none());
+    }
+}
@@ -0,0 +1,103 @@
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{BlockNumber, B256};
+use std::{collections::BTreeMap, io, path::Path, sync::Arc};
+
+/// Hashes of blocks that are known to be canonical, e.g. because they're finalized.
+///
+/// Downloaders verify responses for these block numbers against the known hashes, so that peers
+/// serving non-canonical data are detected as soon as they respond, and skip validating headers
+/// that are known to be canonical.
+///
+/// The checkpoints can be loaded from a file with one `<block number> <block hash>` line per block.
+/// This can be a sparse list of checkpoints, or an accumulator of all canonical hashes up to a
+/// finalized block.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CanonicalCheckpoints {
+    hashes: Arc<BTreeMap<BlockNumber, B256>>,
+}
+
+impl CanonicalCheckpoints {
+    /// Creates the checkpoints from the given blocks.
+    pub fn new(blocks: impl IntoIterator<Item = BlockNumHash>) -> Self {
+        Self {
+            hashes: Arc::new(blocks.into_iter().map(|block| (block.number, block.hash)).collect()),
+        }
+    }
+
+    /// Loads the checkpoints from a file with one `<block number> <block hash>` line per block.
+    ///
+    /// Empty lines and lines starting with `#` are ignored.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+
+        let mut hashes = BTreeMap::new();
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue
+            }
+            let checkpoint = line
+                .split_once(char::is_whitespace)
+                .and_then(|(number, hash)| Some((number.parse().ok()?, hash.trim().parse().ok()?)));
+            let Some((number, hash)) = checkpoint else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("malformed canonical checkpoint in {}: {line}", path.display()),
+                ))
+            };
+            hashes.insert(number, hash);
+        }
+
+        Ok(Self { hashes: Arc::new(hashes) })
+    }
+
+    /// Returns the known canonical hash of the block number, if any.
+    pub fn get(&self, number: BlockNumber) -> Option<B256> {
+        self.hashes.get(&number).copied()
+    }
+
+    /// Returns `true` if the hash is the known canonical hash of the block number.
+    pub fn is_canonical(&self, number: BlockNumber, hash: B256) -> bool {
+        self.get(number) == Some(hash)
+    }
+
+    /// Returns the highest checkpoint, if any.
+    ///
+    /// All blocks up to this one are known to be final.
+    pub fn highest(&self) -> Option<BlockNumHash> {
+        self.hashes.last_key_value().map(|(number, hash)| BlockNumHash::new(*number, *hash))
+    }
+
+    /// Returns the number of checkpoints.
+    pub fn len(&self) -> usize {
+        self.hashes.len()
+    }
+
+    /// Returns `true` if there are no checkpoints.
+    pub fn is_empty(&self) -> bool {
+        self.hashes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_checkpoints() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("checkpoints");
+
+        let hash = B256::with_last_byte(1);
+        std::fs::write(&path, format!("# mainnet\n\n100 {hash}\n200\t{}\n", B256::ZERO)).unwrap();
+        let checkpoints = CanonicalCheckpoints::load(&path).unwrap();
+        assert_eq!(checkpoints.len(), 2);
+        assert!(checkpoints.is_canonical(100, hash));
+        assert!(!checkpoints.is_canonical(100, B256::ZERO));
+        assert_eq!(checkpoints.get(150), None);
+        assert_eq!(checkpoints.highest(), Some(BlockNumHash::new(200, B256::ZERO)));
+
+        std::fs::write(&path, "100 0x01\n").unwrap();
+        assert!(CanonicalCheckpoints::load(&path).is_err());
+    }
+}
@@ -8,6 +8,13 @@ pub trait DownloadClient: Send + Sync + Debug {
     /// that violates validation rules
     fn report_bad_message(&self, peer_id: PeerId);
 
+    /// Penalize the peer for responding with data that contradicts the known canonical chain.
+    ///
+    /// This is a stronger signal than a bad message and by default treated the same way.
+    fn report_non_canonical(&self, peer_id: PeerId) {
+        self.report_bad_message(peer_id)
+    }
+
     /// Returns how many peers the network is currently connected to.
     fn num_connected_peers(&self) -> usize;
 }
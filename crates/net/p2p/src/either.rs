@@ -21,6 +21,12 @@ where
             Self::Right(b) => b.report_bad_message(peer_id),
         }
     }
+    fn report_non_canonical(&self, peer_id: reth_network_peers::PeerId) {
+        match self {
+            Self::Left(a) => a.report_non_canonical(peer_id),
+            Self::Right(b) => b.report_non_canonical(peer_id),
+        }
+    }
     fn num_connected_peers(&self) -> usize {
         match self {
             Self::Left(a) => a.num_connected_peers(),
@@ -157,6 +157,14 @@ pub enum DownloadError {
     /// Received headers with less than expected items.
     #[display("received less headers than expected: {_0}")]
     HeadersResponseTooShort(GotExpected<u64>),
+    /// Received a header that contradicts the known canonical hash of its block number.
+    #[display("received non-canonical header for block number {number}: {hash}")]
+    NonCanonicalHeader {
+        /// Number of the non-canonical header
+        number: u64,
+        /// The hash of the received header and the known canonical hash
+        hash: GotExpectedBoxed<B256>,
+    },
 
     /* ==================== BODIES ERRORS ==================== */
     /// Block validation failed
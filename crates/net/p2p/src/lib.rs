@@ -14,6 +14,9 @@
 /// Shared abstractions for downloader implementations.
 pub mod download;
 
+/// Hashes of known canonical blocks that downloaded data is verified against.
+pub mod checkpoints;
+
 /// Traits for implementing P2P block body clients.
 pub mod bodies;
 
@@ -46,6 +49,7 @@ pub mod sync;
 pub mod test_utils;
 
 pub use bodies::client::BodiesClient;
+pub use checkpoints::CanonicalCheckpoints;
 pub use headers::client::HeadersClient;
 use reth_primitives_traits::Block;
 
@@ -5,8 +5,12 @@ use alloy_rpc_types_engine::JwtSecret;
 use reth_basic_payload_builder::PayloadBuilder;
 use reth_consensus::{ConsensusError, FullConsensus};
 use reth_db_api::{database_metrics::DatabaseMetrics, Database};
-use reth_engine_primitives::{BeaconConsensusEngineEvent, BeaconConsensusEngineHandle};
+use reth_engine_primitives::{
+    BeaconConsensusEngineEvent, BeaconConsensusEngineHandle, EngineStateSnapshotHandle,
+    SyncTargetHandle,
+};
 use reth_evm::{execute::BlockExecutorProvider, ConfigureEvm};
+use reth_exex_types::ExExStatusHandle;
 use reth_network_api::FullNetwork;
 use reth_node_core::node_config::NodeConfig;
 use reth_node_types::{NodeTypes, NodeTypesWithDBAdapter, NodeTypesWithEngine, TxTy};
@@ -122,6 +126,12 @@ pub struct AddOnsContext<'a, N: FullNodeComponents> {
         BeaconConsensusEngineHandle<<N::Types as NodeTypesWithEngine>::Engine>,
     /// Notification channel for engine API events
     pub engine_events: EventSender<BeaconConsensusEngineEvent<<N::Types as NodeTypes>::Primitives>>,
+    /// Handle to capture and restore snapshots of the in-memory engine state.
+    pub engine_state_snapshots: EngineStateSnapshotHandle,
+    /// Handle to move the fixed block the node syncs to.
+    pub sync_target: SyncTargetHandle,
+    /// Handle to the status of the installed `ExEx`'s.
+    pub exex_status: ExExStatusHandle,
     /// JWT secret for the node.
     pub jwt_secret: JwtSecret,
 }
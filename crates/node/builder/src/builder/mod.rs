@@ -685,6 +685,11 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
     ///
     /// Spawns the configured network and associated tasks and returns the [`NetworkHandle`]
     /// connected to that network.
+    ///
+    /// If networking is disabled with `--no-network`, only the isolated network manager is
+    /// spawned to back the [`NetworkHandle`]: without peers there are no transactions to exchange
+    /// and no requests to serve, so the transactions task and the eth request handler are not
+    /// spawned.
     pub fn start_network_with<Pool, N>(
         &self,
         builder: NetworkBuilder<(), (), N>,
@@ -702,13 +707,19 @@ impl<Node: FullNodeTypes> BuilderContext<Node> {
             + 'static,
         Node::Provider: BlockReaderFor<N>,
     {
-        let (handle, network, txpool, eth) = builder
-            .transactions(pool, tx_config)
-            .request_handler(self.provider().clone())
-            .split_with_handle();
+        let (handle, network) = if self.config().network.no_network {
+            let (handle, network, _, _) = builder.split_with_handle();
+            (handle, network)
+        } else {
+            let (handle, network, txpool, eth) = builder
+                .transactions(pool, tx_config)
+                .request_handler(self.provider().clone())
+                .split_with_handle();
 
-        self.executor.spawn_critical("p2p txpool", txpool);
-        self.executor.spawn_critical("p2p eth request handler", eth);
+            self.executor.spawn_critical("p2p txpool", txpool);
+            self.executor.spawn_critical("p2p eth request handler", eth);
+            (handle, network)
+        };
 
         let default_peers_path = self.config().datadir().known_peers();
         let known_peers_file = self.config().network.persistent_peers_file(default_peers_path);
@@ -793,9 +804,17 @@ impl<Node: FullNodeTypes<Types: NodeTypes<ChainSpec: Hardforks>>> BuilderContext
                 default_peers_path,
             )
             .with_task_executor(Box::new(self.executor.clone()))
-            .set_head(self.head);
-
-        Ok(builder)
+            .set_head(self.head)
+            // there are no transactions to gossip if the pool is disabled
+            .apply(|builder| {
+                if self.config().txpool.disabled {
+                    builder.disable_tx_gossip(true)
+                } else {
+                    builder
+                }
+            });
+
+        Ok(self.config().network.apply_allowlist(builder)?)
     }
 }
 
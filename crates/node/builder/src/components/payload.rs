@@ -86,10 +86,13 @@ where
 
         let conf = ctx.config().builder.clone();
 
-        let payload_job_config = BasicPayloadJobGeneratorConfig::default()
+        let mut payload_job_config = BasicPayloadJobGeneratorConfig::default()
             .interval(conf.interval)
             .deadline(conf.deadline)
             .max_payload_tasks(conf.max_payload_tasks);
+        if let Some(max_build_time) = conf.max_build_time {
+            payload_job_config = payload_job_config.max_build_time(max_build_time);
+        }
 
         let payload_generator = BasicPayloadJobGenerator::with_builder(
             ctx.provider().clone(),
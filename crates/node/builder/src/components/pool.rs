@@ -2,8 +2,12 @@
 
 use alloy_primitives::Address;
 use reth_node_api::TxTy;
-use reth_transaction_pool::{PoolConfig, PoolTransaction, SubPoolLimit, TransactionPool};
-use std::{collections::HashSet, future::Future};
+use reth_transaction_pool::{
+    noop::NoopTransactionPool, EthPoolTransaction, EthPooledTransaction, PoolConfig,
+    PoolTransaction, SubPoolLimit, TransactionPool,
+};
+use std::{collections::HashSet, future::Future, marker::PhantomData};
+use tracing::info;
 
 use crate::{BuilderContext, FullNodeTypes};
 
@@ -40,6 +44,34 @@ where
     }
 }
 
+/// A [`PoolBuilder`] that builds a [`NoopTransactionPool`].
+///
+/// This can be used in place of the node's pool builder to run the node without a transaction
+/// pool, for example if the pool is disabled with `--no-txpool`.
+#[derive(Debug)]
+pub struct NoopPoolBuilder<T = EthPooledTransaction> {
+    _marker: PhantomData<T>,
+}
+
+impl<T> Default for NoopPoolBuilder<T> {
+    fn default() -> Self {
+        Self { _marker: PhantomData }
+    }
+}
+
+impl<Node, T> PoolBuilder<Node> for NoopPoolBuilder<T>
+where
+    Node: FullNodeTypes,
+    T: EthPoolTransaction<Consensus = TxTy<Node::Types>> + Unpin,
+{
+    type Pool = NoopTransactionPool<T>;
+
+    async fn build_pool(self, _ctx: &BuilderContext<Node>) -> eyre::Result<Self::Pool> {
+        info!(target: "reth::cli", "Transaction pool disabled");
+        Ok(NoopTransactionPool::new())
+    }
+}
+
 /// Convenience type to override cli or default pool configuration during build.
 #[derive(Debug, Clone, Default)]
 pub struct PoolBuilderConfigOverrides {
@@ -51,6 +83,8 @@ pub struct PoolBuilderConfigOverrides {
     pub queued_limit: Option<SubPoolLimit>,
     /// Max number of transactions in the blob sub-pool
     pub blob_limit: Option<SubPoolLimit>,
+    /// Max number of blob transactions in the pending sub-pool
+    pub pending_blob_limit: Option<SubPoolLimit>,
     /// Max number of executable transaction slots guaranteed per account
     pub max_account_slots: Option<usize>,
     /// Minimum base fee required by the protocol.
@@ -69,6 +103,7 @@ impl PoolBuilderConfigOverrides {
             basefee_limit,
             queued_limit,
             blob_limit,
+            pending_blob_limit,
             max_account_slots,
             minimal_protocol_basefee,
             local_addresses,
@@ -87,6 +122,9 @@ impl PoolBuilderConfigOverrides {
         if let Some(blob_limit) = blob_limit {
             config.blob_limit = blob_limit;
         }
+        if let Some(pending_blob_limit) = pending_blob_limit {
+            config.pending_blob_limit = pending_blob_limit;
+        }
         if let Some(max_account_slots) = max_account_slots {
             config.max_account_slots = max_account_slots;
         }
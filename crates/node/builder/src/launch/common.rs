@@ -24,7 +24,7 @@ use reth_invalid_block_hooks::InvalidBlockWitnessHook;
 use reth_network_p2p::headers::client::HeadersClient;
 use reth_node_api::{FullNodeTypes, NodeTypes, NodeTypesWithDB, NodeTypesWithDBAdapter};
 use reth_node_core::{
-    args::InvalidBlockHookType,
+    args::{pipeline_profile_prune_config, InvalidBlockHookType},
     dirs::{ChainPath, DataDirPath},
     node_config::NodeConfig,
     primitives::BlockHeader,
@@ -106,6 +106,10 @@ impl LaunchContext {
 
         let mut toml_config = reth_config::Config::from_path(&config_path)
             .wrap_err_with(|| format!("Could not load config file {config_path:?}"))?;
+        if let Err(err) = toml_config.validate() {
+            warn!(target: "reth::cli", path = ?config_path, %err, "Invalid value in config file");
+        }
+        Self::warn_unknown_config_keys(&config_path);
 
         Self::save_pruning_config_if_full_node(&mut toml_config, config, &config_path)?;
 
@@ -113,10 +117,29 @@ impl LaunchContext {
 
         // Update the config with the command line arguments
         toml_config.peers.trusted_nodes_only = config.network.trusted_only;
+        config.pipeline.apply(&mut toml_config.stages);
+
+        let profile = toml_config.stages.profile;
+        if !profile.is_supported_by_node() {
+            eyre::bail!(
+                "The `{profile}` pipeline profile can't be used to run a node, use `archive` or `full`"
+            );
+        }
 
         Ok(toml_config)
     }
 
+    /// Warns about keys in the config file that are not part of the config schema and are
+    /// therefore ignored.
+    fn warn_unknown_config_keys(config_path: &std::path::Path) {
+        let Ok(contents) = std::fs::read_to_string(config_path) else { return };
+        if let Ok(unknown_keys) = reth_config::Config::unknown_keys(&contents) {
+            for key in unknown_keys {
+                warn!(target: "reth::cli", path = ?config_path, %key, "Unknown key in config file, ignoring");
+            }
+        }
+    }
+
     /// Save prune config to the toml file if node is a full node.
     fn save_pruning_config_if_full_node<ChainSpec: EthChainSpec>(
         reth_config: &mut reth_config::Config,
@@ -332,14 +355,25 @@ impl<R, ChainSpec: EthChainSpec> LaunchContextWith<Attached<WithConfigs<ChainSpe
     /// Returns the configured [`PruneConfig`]
     /// Any configuration set in CLI will take precedence over those set in toml
     pub fn prune_config(&self) -> Option<PruneConfig> {
-        let Some(mut node_prune_config) = self.node_config().prune_config() else {
+        let mut prune_config = match self.node_config().prune_config() {
+            // Use the CLI configuration and merge with toml config.
+            Some(mut node_prune_config) => {
+                node_prune_config.merge(self.toml_config().prune.clone());
+                Some(node_prune_config)
+            }
             // No CLI config is set, use the toml config.
-            return self.toml_config().prune.clone();
+            None => self.toml_config().prune.clone(),
         };
 
-        // Otherwise, use the CLI configuration and merge with toml config.
-        node_prune_config.merge(self.toml_config().prune.clone());
-        Some(node_prune_config)
+        // Fill in the segments that are not configured with the defaults of the pipeline profile.
+        let chain_spec = self.chain_spec();
+        let profile_prune_config =
+            pipeline_profile_prune_config(self.toml_config().stages.profile, chain_spec.as_ref());
+        match &mut prune_config {
+            Some(prune_config) => prune_config.merge(profile_prune_config),
+            None => prune_config = profile_prune_config,
+        }
+        prune_config
     }
 
     /// Returns the configured [`PruneModes`], returning the default if no config was available.
@@ -376,9 +410,9 @@ where
     DB: Database + Clone + 'static,
     ChainSpec: EthChainSpec + EthereumHardforks + 'static,
 {
-    /// Returns the [`ProviderFactory`] for the attached storage after executing a consistent check
-    /// between the database and static files. **It may execute a pipeline unwind if it fails this
-    /// check.**
+    /// Returns the [`ProviderFactory`] for the attached storage after executing a consistency check
+    /// of the storage, see [`ProviderFactory::check_consistency`]. **It may execute a pipeline
+    /// unwind if it fails this check.**
     pub async fn create_provider_factory<N>(&self) -> eyre::Result<ProviderFactory<N>>
     where
         N: ProviderNodeTypes<DB = DB, ChainSpec = ChainSpec>,
@@ -389,20 +423,23 @@ where
             StaticFileProvider::read_write(self.data_dir().static_files())?,
         )
         .with_prune_modes(self.prune_modes())
-        .with_static_files_metrics();
+        .with_static_files_metrics()
+        .with_static_files_task_spawner(Box::new(self.task_executor().clone()));
 
         let has_receipt_pruning =
             self.toml_config().prune.as_ref().is_some_and(|a| a.has_receipts_pruning());
 
-        // Check for consistency between database and static files. If it fails, it unwinds to
-        // the first block that's consistent between database and static files.
-        if let Some(unwind_target) = factory
-            .static_file_provider()
-            .check_consistency(&factory.provider()?, has_receipt_pruning)?
-        {
+        // Check for consistency between stage checkpoints, database, static files and prune
+        // checkpoints. If it fails, it unwinds to the first block that's consistent across all of
+        // them.
+        if let Some(unwind_target) = factory.check_consistency(has_receipt_pruning)? {
             // Highly unlikely to happen, and given its destructive nature, it's better to panic
             // instead.
-            assert_ne!(unwind_target, PipelineTarget::Unwind(0), "A static file <> database inconsistency was found that would trigger an unwind to block 0");
+            assert_ne!(
+                unwind_target,
+                PipelineTarget::Unwind(0),
+                "A storage inconsistency was found that would trigger an unwind to block 0"
+            );
 
             info!(target: "reth::cli", unwind_target = %unwind_target, "Executing an unwind after a failed storage consistency check.");
 
@@ -519,6 +556,10 @@ where
                             }
                         }
                     })
+                    .with_hook({
+                        let executor = self.task_executor().clone();
+                        move || executor.report_runtime_metrics()
+                    })
                     .build(),
             );
 
@@ -554,7 +595,8 @@ where
 
         debug!(target: "reth::cli", "Spawning stages metrics listener task");
         let sync_metrics_listener = reth_stages::MetricsListener::new(metrics_receiver);
-        self.task_executor().spawn_critical("stages metrics listener task", sync_metrics_listener);
+        self.task_executor()
+            .spawn_critical_background("stages metrics listener task", sync_metrics_listener);
 
         LaunchContextWith {
             inner: self.inner,
@@ -734,6 +776,49 @@ where
         self.provider_factory().static_file_provider()
     }
 
+    /// Spawns a task that periodically verifies the sealed static files against their recorded
+    /// checksums, if configured.
+    pub fn spawn_static_file_verification(&self) {
+        let Some(verify_interval) = self.node_config().static_files.verify_interval else { return };
+        let static_file_provider = self.static_file_provider();
+
+        self.task_executor().spawn_background(Box::pin(async move {
+            let mut interval = tokio::time::interval(verify_interval);
+            // the first tick completes immediately, verification is skipped at startup
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+
+                let provider = static_file_provider.clone();
+                let verifications =
+                    match tokio::task::spawn_blocking(move || provider.verify_static_files()).await
+                    {
+                        Ok(Ok(verifications)) => verifications,
+                        Ok(Err(err)) => {
+                            warn!(target: "reth::cli", %err, "Failed to verify static files");
+                            continue
+                        }
+                        Err(_) => return,
+                    };
+
+                let mut damaged = 0;
+                for verification in verifications.iter().filter(|v| !v.is_ok()) {
+                    damaged += 1;
+                    error!(
+                        target: "reth::cli",
+                        segment = %verification.segment,
+                        file = %verification.file_name,
+                        outcome = ?verification.outcome,
+                        "Static file is damaged, run `reth static-files verify` for recovery options"
+                    );
+                }
+                if damaged == 0 {
+                    info!(target: "reth::cli", verified = verifications.len(), "Verified static files");
+                }
+            }
+        }));
+    }
+
     /// Creates a new [`StaticFileProducer`] with the attached database.
     pub fn static_file_producer(
         &self,
@@ -827,7 +912,11 @@ where
 
         // Skip the first stage as we've already retrieved it and comparing all other checkpoints
         // against it.
-        for stage_id in StageId::ALL.iter().skip(1) {
+        // Stages that don't run with the pipeline profile are not expected to keep up.
+        let profile = self.toml_config().stages.profile;
+        for stage_id in
+            StageId::ALL.iter().skip(1).filter(|stage_id| profile.is_stage_enabled(**stage_id))
+        {
             let stage_checkpoint = self
                 .blockchain_db()
                 .get_stage_checkpoint(*stage_id)?
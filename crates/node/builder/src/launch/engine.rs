@@ -1,8 +1,10 @@
 //! Engine node related functionality.
 
 use alloy_consensus::BlockHeader;
+use alloy_eips::BlockNumHash;
 use futures::{future::Either, stream, stream_select, StreamExt};
 use reth_chainspec::EthChainSpec;
+use reth_cli_util::sd_notify;
 use reth_db_api::{database_metrics::DatabaseMetrics, Database};
 use reth_engine_local::{LocalEngineService, LocalPayloadAttributesBuilder};
 use reth_engine_service::service::{ChainEvent, EngineService};
@@ -14,21 +16,32 @@ use reth_engine_util::EngineMessageStreamExt;
 use reth_exex::ExExManagerHandle;
 use reth_network::{NetworkSyncUpdater, SyncState};
 use reth_network_api::BlockDownloaderProvider;
+use reth_network_p2p::CanonicalCheckpoints;
 use reth_node_api::{
-    BeaconConsensusEngineHandle, BuiltPayload, FullNodeTypes, NodeTypesWithDBAdapter,
-    NodeTypesWithEngine, PayloadAttributesBuilder, PayloadTypes,
+    BeaconConsensusEngineHandle, BuiltPayload, EngineStateSnapshotHandle, FullNodeTypes,
+    NodeTypesWithDBAdapter, NodeTypesWithEngine, PayloadAttributesBuilder, PayloadTypes,
+    SyncTargetError, SyncTargetHandle,
 };
 use reth_node_core::{
     dirs::{ChainPath, DataDirPath},
     exit::NodeExitFuture,
     primitives::Head,
+    utils::get_single_header,
+};
+use reth_node_events::{
+    alerts::{AlertMonitor, AlertsConfig},
+    cl::ConsensusLayerHealthEvents,
+    node,
 };
-use reth_node_events::{cl::ConsensusLayerHealthEvents, node};
 use reth_primitives::EthereumHardforks;
-use reth_provider::providers::{BlockchainProvider, NodeTypesForProvider};
+use reth_provider::{
+    providers::{BlockchainProvider, NodeTypesForProvider},
+    BlockIdReader, BlockNumReader, CanonStateSubscriptionOptions,
+};
+use reth_stages::PipelineTarget;
 use reth_tasks::TaskExecutor;
 use reth_tokio_util::EventSender;
-use reth_tracing::tracing::{debug, error, info};
+use reth_tracing::tracing::{debug, error, info, warn};
 use std::sync::Arc;
 use tokio::sync::{mpsc::unbounded_channel, oneshot};
 use tokio_stream::wrappers::UnboundedReceiverStream;
@@ -95,6 +108,17 @@ where
         } = target;
         let NodeHooks { on_component_initialized, on_node_started, .. } = hooks;
 
+        let engine_tree_config = if config.engine.persist_block_buffer {
+            engine_tree_config.with_block_buffer_dir(Some(ctx.data_dir.block_buffer()))
+        } else {
+            engine_tree_config
+        };
+        let engine_tree_config = if config.engine.warm_state_cache {
+            engine_tree_config.with_state_access_file(Some(ctx.data_dir.state_access()))
+        } else {
+            engine_tree_config
+        };
+
         // setup the launch context
         let ctx = ctx
             .with_configured_globals()
@@ -128,14 +152,20 @@ where
             .with_components(components_builder, on_component_initialized).await?;
 
         // spawn exexs
-        let exex_manager_handle = ExExLauncher::new(
+        let mut exex_canon_state_options = CanonStateSubscriptionOptions::default();
+        if ctx.node_config().engine.exex_trie_updates {
+            exex_canon_state_options = exex_canon_state_options.with_trie_updates();
+        }
+        let exex_launcher = ExExLauncher::new(
             ctx.head(),
             ctx.node_adapter().clone(),
             installed_exex,
             ctx.configs().clone(),
         )
-        .launch()
-        .await?;
+        .with_canon_state_options(exex_canon_state_options);
+        let exex_prune_targets = exex_launcher.prune_targets();
+        let exex_status = exex_launcher.status_handle();
+        let exex_manager_handle = exex_launcher.launch().await?;
 
         // create pipeline
         let network_client = ctx.components().network().fetch_client().await?;
@@ -145,12 +175,46 @@ where
 
         let max_block = ctx.max_block(network_client.clone()).await?;
 
+        // resolve the fixed block to sync to, if configured
+        let mut sync_target = match node_config.debug.sync_target {
+            Some(target) if ctx.is_dev() => {
+                warn!(target: "reth::cli", ?target, "Ignoring --debug.sync-target in dev mode");
+                None
+            }
+            Some(target) => {
+                let target = node_config
+                    .lookup_or_fetch_sync_target(
+                        ctx.provider_factory().clone(),
+                        network_client.clone(),
+                        target,
+                    )
+                    .await?;
+                check_sync_target(ctx.blockchain_db().finalized_block_number()?, target)?;
+                Some(target)
+            }
+            None => None,
+        };
+
         let static_file_producer = ctx.static_file_producer();
         let static_file_producer_events = static_file_producer.lock().events();
         info!(target: "reth::cli", "StaticFileProducer initialized");
 
         let consensus = Arc::new(ctx.components().consensus().clone());
 
+        let checkpoints = match &node_config.debug.canonical_checkpoints {
+            Some(path) => {
+                let checkpoints = CanonicalCheckpoints::load(path).map_err(|err| {
+                    eyre::eyre!(
+                        "failed to load canonical checkpoints from {}: {err}",
+                        path.display()
+                    )
+                })?;
+                info!(target: "reth::cli", path = %path.display(), count = checkpoints.len(), highest = ?checkpoints.highest(), "Loaded canonical checkpoints");
+                checkpoints
+            }
+            None => Default::default(),
+        };
+
         // Configure the pipeline
         let pipeline_exex_handle =
             exex_manager_handle.clone().unwrap_or_else(ExExManagerHandle::empty);
@@ -166,6 +230,7 @@ where
             static_file_producer,
             ctx.components().block_executor().clone(),
             pipeline_exex_handle,
+            checkpoints,
         )?;
 
         // The new engine writes directly to static files. This ensures that they're up to the tip.
@@ -175,8 +240,9 @@ where
 
         let mut pruner_builder = ctx.pruner_builder();
         if let Some(exex_manager_handle) = &exex_manager_handle {
-            pruner_builder =
-                pruner_builder.finished_exex_height(exex_manager_handle.finished_height());
+            pruner_builder = pruner_builder
+                .finished_exex_height(exex_manager_handle.finished_height())
+                .exex_prune_targets(exex_prune_targets);
         }
         let pruner = pruner_builder.build_with_provider_factory(ctx.provider_factory().clone());
         let pruner_events = pruner.events();
@@ -185,6 +251,8 @@ where
         let event_sender = EventSender::default();
 
         let beacon_engine_handle = BeaconConsensusEngineHandle::new(consensus_engine_tx.clone());
+        let (engine_state_snapshot_tx, mut engine_state_snapshot_rx) = unbounded_channel();
+        let (sync_target_tx, mut sync_target_rx) = unbounded_channel();
 
         // extract the jwt secret from the args if possible
         let jwt_secret = ctx.auth_jwt_secret()?;
@@ -195,10 +263,15 @@ where
             beacon_engine_handle: beacon_engine_handle.clone(),
             jwt_secret,
             engine_events: event_sender.clone(),
+            engine_state_snapshots: EngineStateSnapshotHandle::new(engine_state_snapshot_tx),
+            sync_target: SyncTargetHandle::new(sync_target_tx),
+            exex_status,
         };
         let engine_payload_validator = add_ons.engine_validator(&add_ons_ctx).await?;
 
         let consensus_engine_stream = UnboundedReceiverStream::from(consensus_engine_rx)
+            // With a fixed sync target the chain is only moved by backfill sync.
+            .maybe_halt(sync_target.is_some())
             .maybe_skip_fcu(node_config.debug.skip_fcu)
             .maybe_skip_new_payload(node_config.debug.skip_new_payload)
             .maybe_reorg(
@@ -208,10 +281,13 @@ where
                 node_config.debug.reorg_frequency,
                 node_config.debug.reorg_depth,
             )
-            // Store messages _after_ skipping so that `replay-engine` command
+            // Store messages _after_ skipping so that `dump-engine-messages` command
             // would replay only the messages that were observed by the engine
             // during this run.
-            .maybe_store_messages(node_config.debug.engine_api_store.clone());
+            .maybe_store_messages(
+                node_config.debug.engine_api_store.clone(),
+                node_config.debug.engine_api_store_max_messages,
+            );
 
         let mut engine_service = if ctx.is_dev() {
             let eth_service = LocalEngineService::new(
@@ -261,7 +337,7 @@ where
         let events = stream_select!(
             event_sender.new_listener().map(Into::into),
             pipeline_events.map(Into::into),
-            if ctx.node_config().debug.tip.is_none() && !ctx.is_dev() {
+            if ctx.node_config().debug.tip.is_none() && sync_target.is_none() && !ctx.is_dev() {
                 Either::Left(
                     ConsensusLayerHealthEvents::new(Box::new(ctx.blockchain_db().clone()))
                         .map(Into::into),
@@ -275,18 +351,55 @@ where
 
         ctx.task_executor().spawn_critical(
             "events task",
-            node::handle_events(
+            node::handle_events_with_progress(
                 Some(Box::new(ctx.components().network().clone())),
                 Some(ctx.head().number),
                 events,
+                ctx.node_config().engine.sync_status_interval,
+                ctx.node_config()
+                    .engine
+                    .backfill_progress
+                    .then(|| beacon_engine_handle.backfill_progress().clone()),
             ),
         );
 
+        if ctx.node_config().alerts.enabled {
+            let alerts = &ctx.node_config().alerts;
+            let monitor = AlertMonitor::new(
+                AlertsConfig {
+                    forkchoice_update_timeout: alerts.fcu_timeout,
+                    invalid_payloads: alerts.invalid_payloads,
+                    invalid_payloads_window: alerts.invalid_payloads_window,
+                    min_peers: alerts.min_peers,
+                    disk_path: ctx.data_dir().data_dir().to_path_buf(),
+                    min_free_disk_space: alerts.min_free_disk_space.0 as u64,
+                    repeat_interval: alerts.repeat_interval,
+                    webhooks: alerts.webhooks.clone(),
+                },
+                Some(Box::new(ctx.components().network().clone())),
+            );
+            ctx.task_executor().spawn(Box::pin(monitor.run(event_sender.new_listener())));
+            info!(target: "reth::cli", "Alerts enabled");
+        }
+
+        ctx.spawn_static_file_verification();
+
         let RpcHandle { rpc_server_handles, rpc_registry, engine_events, beacon_engine_handle } =
             add_ons.launch_add_ons(add_ons_ctx).await?;
 
         // Run consensus engine to completion
-        let initial_target = ctx.initial_backfill_target()?;
+        let initial_target = if let Some(sync_target) = sync_target {
+            let head = ctx.head();
+            sync_target_backfill(BlockNumHash::new(head.number, head.hash), sync_target)
+        } else {
+            ctx.initial_backfill_target()?.map(PipelineTarget::Sync)
+        };
+        let (resolved_sync_target_tx, mut resolved_sync_target_rx) = unbounded_channel();
+        let sync_target_provider = ctx.provider_factory().clone();
+        let sync_target_client = network_client.clone();
+        let blockchain_db = ctx.blockchain_db().clone();
+        let task_executor = ctx.task_executor().clone();
+        let config = ctx.node_config().clone();
         let network_handle = ctx.components().network().clone();
         let mut built_payloads = ctx
             .components()
@@ -303,7 +416,7 @@ where
         info!(target: "reth::cli", "Starting consensus engine");
         ctx.task_executor().spawn_critical("consensus engine", async move {
             if let Some(initial_target) = initial_target {
-                debug!(target: "reth::cli", %initial_target,  "start backfill sync");
+                debug!(target: "reth::cli", ?initial_target,  "start backfill sync");
                 if let Either::Right(eth_service) = &mut engine_service {
                     eth_service.orchestrator_mut().start_backfill_sync(initial_target);
                 }
@@ -322,6 +435,79 @@ where
                             }
                         }
                     }
+                    Some(request) = engine_state_snapshot_rx.recv() => {
+                        match &mut engine_service {
+                            Either::Left(eth_service) => {
+                                eth_service.handler_mut().on_event(EngineApiRequest::StateSnapshot(request).into());
+                            }
+                            Either::Right(eth_service) => {
+                                eth_service.orchestrator_mut().handler_mut().handler_mut().on_event(EngineApiRequest::StateSnapshot(request).into());
+                            }
+                        }
+                    }
+                    Some(request) = sync_target_rx.recv() => {
+                        if sync_target.is_none() {
+                            let _ = request.tx.send(Err(SyncTargetError::Disabled));
+                            continue
+                        }
+
+                        // the target may need to be fetched from the network, which must not
+                        // block the engine
+                        let provider = sync_target_provider.clone();
+                        let client = sync_target_client.clone();
+                        let config = config.clone();
+                        let resolved_tx = resolved_sync_target_tx.clone();
+                        task_executor.spawn(Box::pin(async move {
+                            let target = request.target;
+                            let num_hash = match config.lookup_sync_target(provider, target) {
+                                Ok(Some(num_hash)) => Some(num_hash),
+                                Ok(None) => get_single_header(client, target)
+                                    .await
+                                    .ok()
+                                    .map(|header| header.num_hash()),
+                                Err(err) => {
+                                    warn!(target: "reth::cli", %err, ?target, "Failed to look up sync target");
+                                    None
+                                }
+                            };
+                            match num_hash {
+                                Some(num_hash) => {
+                                    let _ = resolved_tx.send((num_hash, request.tx));
+                                }
+                                None => {
+                                    let err = SyncTargetError::UnknownBlock(target);
+                                    let _ = request.tx.send(Err(err));
+                                }
+                            }
+                        }));
+                    }
+                    Some((target, tx)) = resolved_sync_target_rx.recv() => {
+                        let (head, finalized) = match blockchain_db
+                            .chain_info()
+                            .and_then(|info| Ok((info, blockchain_db.finalized_block_number()?)))
+                        {
+                            Ok((info, finalized)) => {
+                                (BlockNumHash::new(info.best_number, info.best_hash), finalized)
+                            }
+                            Err(err) => {
+                                error!(target: "reth::cli", %err, "Failed to read the canonical head");
+                                let _ = tx.send(Err(SyncTargetError::EngineUnavailable));
+                                continue
+                            }
+                        };
+                        if let Err(err) = check_sync_target(finalized, target) {
+                            let _ = tx.send(Err(err));
+                            continue
+                        }
+                        info!(target: "reth::cli", number = target.number, hash = %target.hash, "Moving sync target");
+                        sync_target = Some(target);
+                        if let Some(backfill) = sync_target_backfill(head, target) {
+                            if let Either::Right(eth_service) = &mut engine_service {
+                                eth_service.orchestrator_mut().start_backfill_sync(backfill);
+                            }
+                        }
+                        let _ = tx.send(Ok(target));
+                    }
                     event = engine_service.next() => {
                         let Some(event) = event else { break };
                         debug!(target: "reth::cli", "Event: {event}");
@@ -332,6 +518,20 @@ where
                                     break
                                 }
 
+                                if let Some(target) = sync_target {
+                                    match blockchain_db.chain_info() {
+                                        Ok(info) if info.best_hash == target.hash => {
+                                            info!(target: "reth::cli", number = target.number, hash = %target.hash, "Reached sync target, halting sync");
+                                        }
+                                        Ok(info) => {
+                                            warn!(target: "reth::cli", number = target.number, hash = %target.hash, head_number = info.best_number, head_hash = %info.best_hash, "Backfill sync finished without reaching the sync target");
+                                        }
+                                        Err(err) => {
+                                            error!(target: "reth::cli", %err, "Failed to read the canonical head");
+                                        }
+                                    }
+                                }
+
                                 network_handle.update_sync_state(SyncState::Idle);
                             }
                             ChainEvent::BackfillSyncStarted => {
@@ -383,6 +583,23 @@ where
         // Notify on node started
         on_node_started.on_event(FullNode::clone(&full_node))?;
 
+        // Notify the service manager that the node is ready, and keep its watchdog alive
+        if let Err(err) = sd_notify::ready() {
+            warn!(target: "reth::cli", %err, "Failed to notify service manager");
+        }
+        if let Some(interval) = sd_notify::watchdog_interval() {
+            info!(target: "reth::cli", ?interval, "Service manager watchdog enabled");
+            ctx.task_executor().spawn(Box::pin(async move {
+                let mut interval = tokio::time::interval(interval);
+                loop {
+                    interval.tick().await;
+                    if let Err(err) = sd_notify::watchdog() {
+                        warn!(target: "reth::cli", %err, "Failed to notify service manager watchdog");
+                    }
+                }
+            }));
+        }
+
         let handle = NodeHandle {
             node_exit_future: NodeExitFuture::new(
                 async { rx.await? },
@@ -394,3 +611,26 @@ where
         Ok(handle)
     }
 }
+
+/// Returns an error if the fixed sync `target` is below the `finalized` block, because the node
+/// can't unwind the finalized chain.
+fn check_sync_target(finalized: Option<u64>, target: BlockNumHash) -> Result<(), SyncTargetError> {
+    match finalized {
+        Some(finalized) if target.number < finalized => {
+            Err(SyncTargetError::BelowFinalized { target: target.number, finalized })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the backfill target that moves the canonical chain from `head` to the fixed sync
+/// `target`, or `None` if the target already is the head.
+fn sync_target_backfill(head: BlockNumHash, target: BlockNumHash) -> Option<PipelineTarget> {
+    if target == head {
+        None
+    } else if target.number < head.number {
+        Some(PipelineTarget::Unwind(target.number))
+    } else {
+        Some(PipelineTarget::Sync(target.hash))
+    }
+}
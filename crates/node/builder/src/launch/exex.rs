@@ -2,11 +2,11 @@
 
 use alloy_eips::BlockNumHash;
 use futures::future;
-use reth_chain_state::ForkChoiceSubscriptions;
+use reth_chain_state::{CanonStateSubscriptionOptions, ForkChoiceSubscriptions};
 use reth_chainspec::EthChainSpec;
 use reth_exex::{
-    ExExContext, ExExHandle, ExExManager, ExExManagerHandle, ExExNotificationSource, Wal,
-    DEFAULT_EXEX_MANAGER_CAPACITY,
+    ExExContext, ExExHandle, ExExManager, ExExManagerHandle, ExExNotificationSource,
+    ExExPruneTargets, ExExStatusHandle, Wal, DEFAULT_EXEX_MANAGER_CAPACITY,
 };
 use reth_node_api::{FullNodeComponents, NodeTypes, PrimitivesTy};
 use reth_primitives::Head;
@@ -23,17 +23,51 @@ pub struct ExExLauncher<Node: FullNodeComponents> {
     extensions: Vec<(String, Box<dyn BoxedLaunchExEx<Node>>)>,
     components: Node,
     config_container: WithConfigs<<Node::Types as NodeTypes>::ChainSpec>,
+    prune_targets: ExExPruneTargets,
+    status: ExExStatusHandle,
+    canon_state_options: CanonStateSubscriptionOptions,
 }
 
 impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
     /// Create a new `ExExLauncher` with the given extensions.
-    pub const fn new(
+    pub fn new(
         head: Head,
         components: Node,
         extensions: Vec<(String, Box<dyn BoxedLaunchExEx<Node>>)>,
         config_container: WithConfigs<<Node::Types as NodeTypes>::ChainSpec>,
     ) -> Self {
-        Self { head, extensions, components, config_container }
+        Self {
+            head,
+            extensions,
+            components,
+            config_container,
+            prune_targets: ExExPruneTargets::default(),
+            status: ExExStatusHandle::default(),
+            canon_state_options: CanonStateSubscriptionOptions::default(),
+        }
+    }
+
+    /// Sets the options of the subscription to the canonical state notifications that are sent
+    /// to the extensions, e.g. to attach the trie updates of the committed blocks.
+    pub const fn with_canon_state_options(
+        mut self,
+        options: CanonStateSubscriptionOptions,
+    ) -> Self {
+        self.canon_state_options = options;
+        self
+    }
+
+    /// Returns the prune targets that the extensions register for the data they own.
+    ///
+    /// These should be passed to the pruner, so that the extensions are notified after every run.
+    pub fn prune_targets(&self) -> ExExPruneTargets {
+        self.prune_targets.clone()
+    }
+
+    /// Returns the handle to the status of the exex manager, which also allows pausing and
+    /// resuming individual extensions.
+    pub fn status_handle(&self) -> ExExStatusHandle {
+        self.status.clone()
     }
 
     /// Launches all execution extensions.
@@ -43,7 +77,15 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
     pub async fn launch(
         self,
     ) -> eyre::Result<Option<ExExManagerHandle<PrimitivesTy<Node::Types>>>> {
-        let Self { head, extensions, components, config_container } = self;
+        let Self {
+            head,
+            extensions,
+            components,
+            config_container,
+            prune_targets,
+            status,
+            canon_state_options,
+        } = self;
         let head = BlockNumHash::new(head.number, head.hash);
 
         if extensions.is_empty() {
@@ -83,6 +125,7 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
                 components: components.clone(),
                 events,
                 notifications,
+                prune_targets: prune_targets.clone(),
             };
 
             let executor = components.task_executor().clone();
@@ -93,8 +136,8 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
                 // init the exex
                 let exex = exex.launch(context).instrument(span.clone()).await.unwrap();
 
-                // spawn it as a crit task
-                executor.spawn_critical(
+                // spawn it as a crit task on the background lane
+                executor.spawn_critical_background(
                     "exex",
                     async move {
                         info!(target: "reth::cli", "ExEx started");
@@ -118,14 +161,16 @@ impl<Node: FullNodeComponents + Clone> ExExLauncher<Node> {
             DEFAULT_EXEX_MANAGER_CAPACITY,
             exex_wal,
             components.provider().finalized_block_stream(),
-        );
+        )
+        .with_status_handle(status);
         let exex_manager_handle = exex_manager.handle();
         components.task_executor().spawn_critical("exex manager", async move {
             exex_manager.await.expect("exex manager crashed");
         });
 
         // send notifications from the blockchain tree to exex manager
-        let mut canon_state_notifications = components.provider().subscribe_to_canonical_state();
+        let mut canon_state_notifications =
+            components.provider().subscribe_to_canonical_state_with(canon_state_options);
         let mut handle = exex_manager_handle.clone();
         components.task_executor().spawn_critical(
             "exex manager blockchain tree notifications",
@@ -152,6 +197,8 @@ impl<Node: FullNodeComponents> Debug for ExExLauncher<Node> {
             .field("extensions", &self.extensions.iter().map(|(id, _)| id).collect::<Vec<_>>())
             .field("components", &"...")
             .field("config_container", &self.config_container)
+            .field("prune_targets", &self.prune_targets)
+            .field("status", &self.status)
             .finish()
     }
 }
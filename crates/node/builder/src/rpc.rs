@@ -5,7 +5,7 @@ use alloy_rpc_types::engine::ClientVersionV1;
 use alloy_rpc_types_engine::ExecutionData;
 use futures::TryFutureExt;
 use reth_chain_state::CanonStateSubscriptions;
-use reth_chainspec::{ChainSpecProvider, EthereumHardforks};
+use reth_chainspec::{ChainSpecProvider, EthChainSpec, EthereumHardforks};
 use reth_node_api::{
     AddOnsContext, BlockTy, EngineTypes, EngineValidator, FullNodeComponents, FullNodeTypes,
     NodeAddOns, NodeTypes, NodeTypesWithEngine, ReceiptTy,
@@ -14,13 +14,31 @@ use reth_node_core::{
     node_config::NodeConfig,
     version::{CARGO_PKG_VERSION, CLIENT_CODE, NAME_CLIENT, VERGEN_GIT_SHA},
 };
+use reth_node_tx_watcher::{TxWatcher, TxWatcherConfig};
 use reth_payload_builder::{PayloadBuilderHandle, PayloadStore};
-use reth_rpc::eth::{EthApiTypes, FullEthApiServer};
-use reth_rpc_api::{eth::helpers::AddDevSigners, IntoEngineApiRpcModule};
+use reth_rpc::{
+    eth::{
+        EthApiTypes, FullEthApiServer, HardwareSigner, HardwareWallet, RemoteSigner, SignerPolicies,
+    },
+    AdminSyncTargetApi, ContractCreationApi, ContractIndexer, DebugTraceFileApi, ExExApi,
+    ExecutionRequestIndexer, ExecutionRequestsApi, HeaderAccumulatorUpdater, HeaderProofApi,
+    ProposerPaymentTracker, ProposerPaymentsApi, RethBlockStreamApi, StorageBreakdownApi,
+    TxWatcherApi,
+};
+#[cfg(feature = "test-utils")]
+use reth_rpc_api::DebugEngineStateApiServer;
+use reth_rpc_api::{
+    eth::helpers::{AddDevSigners, EthSigner, EthTransactions},
+    AdminExExApiServer, AdminSyncTargetApiServer, DebugTraceFileApiServer,
+    EthContractCreationApiServer, IntoEngineApiRpcModule, RethBlockStreamApiServer,
+    RethExExApiServer, RethExecutionRequestsApiServer, RethHeaderProofApiServer,
+    RethProposerPaymentsApiServer, RethStorageApiServer, RethTxWatcherApiServer,
+};
 use reth_rpc_builder::{
     auth::{AuthRpcModule, AuthServerHandle},
     config::RethRpcServerConfig,
-    RpcModuleBuilder, RpcRegistryInner, RpcServerHandle, TransportRpcModules,
+    RethRpcModule, RpcModuleBuilder, RpcModuleConfig, RpcRegistryInner, RpcServerHandle,
+    TransportRpcModules,
 };
 use reth_rpc_engine_api::{capabilities::EngineCapabilities, EngineApi};
 use reth_rpc_eth_types::{cache::cache_new_blocks_task, EthConfig, EthStateCache};
@@ -442,24 +460,46 @@ where
 {
     /// Launches the RPC servers with the given context and an additional hook for extending
     /// modules.
+    ///
+    /// The hook also receives the [`RpcRegistry`], to build additional modules on top of the
+    /// configured APIs.
     pub async fn launch_add_ons_with<F>(
         self,
         ctx: AddOnsContext<'_, N>,
         ext: F,
     ) -> eyre::Result<RpcHandle<N, EthB::EthApi>>
     where
-        F: FnOnce(&mut TransportRpcModules, &mut AuthRpcModule) -> eyre::Result<()>,
+        F: FnOnce(
+            &mut TransportRpcModules,
+            &mut AuthRpcModule,
+            &mut RpcRegistry<N, EthB::EthApi>,
+        ) -> eyre::Result<()>,
     {
         let Self { eth_api_builder, engine_api_builder, hooks, .. } = self;
 
         let engine_api = engine_api_builder.build_engine_api(&ctx).await?;
-        let AddOnsContext { node, config, beacon_engine_handle, jwt_secret, engine_events } = ctx;
+        #[cfg(feature = "test-utils")]
+        let engine_state_snapshots = ctx.engine_state_snapshots.clone();
+        let AddOnsContext {
+            node,
+            config,
+            beacon_engine_handle,
+            jwt_secret,
+            engine_events,
+            sync_target,
+            exex_status,
+            ..
+        } = ctx;
 
         info!(target: "reth::cli", "Engine API handler initialized");
 
+        let mut eth_config = config.rpc.eth_config_for_chain(&*config.chain);
+        eth_config.tx_lookup.prune_mode =
+            config.prune_config().and_then(|prune| prune.segments.transaction_lookup);
+
         let cache = EthStateCache::spawn_with(
             node.provider().clone(),
-            config.rpc.eth_config().cache,
+            eth_config.cache,
             node.task_executor().clone(),
         );
 
@@ -472,10 +512,13 @@ where
             }),
         );
 
-        let eth_api = eth_api_builder.build_eth_api(&node, config.rpc.eth_config(), cache);
+        let eth_api = eth_api_builder.build_eth_api(&node, eth_config, cache);
 
         let auth_config = config.rpc.auth_server_config(jwt_secret)?;
-        let module_config = config.rpc.transport_rpc_module_config();
+        let module_config = config.rpc.transport_rpc_module_config().with_config(
+            RpcModuleConfig::new(eth_config, config.rpc.flashbots_config())
+                .with_database_read_metrics(config.database_read_metrics.clone()),
+        );
         debug!(target: "reth::cli", http=?module_config.http(), ws=?module_config.ws(), "Using RPC module config");
 
         let (mut modules, mut auth_module, registry) = RpcModuleBuilder::default()
@@ -493,6 +536,36 @@ where
             registry.eth_api().with_dev_accounts();
         }
 
+        if let Some(endpoint) = &config.rpc.rpc_remote_signer {
+            let policies = config
+                .rpc
+                .rpc_remote_signer_policies
+                .as_deref()
+                .map(reth_fs_util::read_json_file::<SignerPolicies>)
+                .transpose()?
+                .unwrap_or_default();
+            let api = config.rpc.rpc_remote_signer_api;
+            let signer: Box<dyn EthSigner<_>> =
+                Box::new(RemoteSigner::connect(endpoint, api, policies).await?);
+            info!(target: "reth::cli", %endpoint, accounts = ?signer.accounts(), "Connected to remote signer");
+            registry.eth_api().signers().write().push(signer);
+        }
+
+        if let Some(wallet) = &config.rpc.rpc_hardware_wallet {
+            let wallet = wallet.parse::<HardwareWallet>().map_err(|err| eyre::eyre!(err))?;
+            info!(target: "reth::cli", %wallet, "Connecting to hardware wallet");
+            let signer: Box<dyn EthSigner<_>> = Box::new(
+                HardwareSigner::connect(
+                    wallet,
+                    config.rpc.rpc_hardware_wallet_index,
+                    config.chain.chain().id(),
+                )
+                .await?,
+            );
+            info!(target: "reth::cli", %wallet, accounts = ?signer.accounts(), "Connected to hardware wallet");
+            registry.eth_api().signers().write().push(signer);
+        }
+
         let mut registry = RpcRegistry { registry };
         let ctx = RpcContext {
             node: node.clone(),
@@ -504,10 +577,145 @@ where
 
         let RpcHooks { on_rpc_started, extend_rpc_modules } = hooks;
 
-        ext(ctx.modules, ctx.auth_module)?;
+        if config.tx_watcher.enabled {
+            let tx_watcher = TxWatcher::new(
+                node.provider().canonical_state_stream(),
+                TxWatcherConfig {
+                    transactions: config.tx_watcher.transactions.clone(),
+                    senders: config.tx_watcher.senders.clone(),
+                    confirmations: config.tx_watcher.confirmations,
+                    webhooks: config.tx_watcher.webhooks.clone(),
+                },
+            );
+            ctx.modules.merge_if_module_configured(
+                RethRpcModule::Reth,
+                TxWatcherApi::new(tx_watcher.handle(), Box::new(node.task_executor().clone()))
+                    .into_rpc(),
+            )?;
+            node.task_executor().spawn(Box::pin(tx_watcher.run()));
+            info!(target: "reth::cli", "Transaction watcher started");
+        }
+
+        if config.contract_index.enabled {
+            let indexer = ContractIndexer::new(
+                node.provider().clone(),
+                ctx.registry.eth_api().clone(),
+                config.contract_index.prune_mode(),
+            );
+            ctx.modules.merge_if_module_configured(
+                RethRpcModule::Eth,
+                ContractCreationApi::new(node.provider().clone()).into_rpc(),
+            )?;
+            node.task_executor()
+                .spawn(Box::pin(indexer.run(node.provider().canonical_state_stream())));
+            info!(target: "reth::cli", "Contract creation index started");
+        }
+
+        if config.header_accumulator.enabled {
+            let updater = HeaderAccumulatorUpdater::new(node.provider().clone());
+            ctx.modules.merge_if_module_configured(
+                RethRpcModule::Reth,
+                HeaderProofApi::new(node.provider().clone()).into_rpc(),
+            )?;
+            // appending the hashes of all canonical blocks on first start takes a while
+            node.task_executor()
+                .spawn_blocking(Box::pin(updater.run(node.provider().canonical_state_stream())));
+            info!(target: "reth::cli", "Header accumulator started");
+        }
+
+        if config.request_index.enabled {
+            let indexer = ExecutionRequestIndexer::new(
+                node.provider().clone(),
+                node.block_executor().clone(),
+                ctx.registry.eth_api().clone(),
+            );
+            ctx.modules.merge_if_module_configured(
+                RethRpcModule::Reth,
+                ExecutionRequestsApi::new(node.provider().clone()).into_rpc(),
+            )?;
+            node.task_executor()
+                .spawn(Box::pin(indexer.run(node.provider().canonical_state_stream())));
+            info!(target: "reth::cli", "Execution request index started");
+        }
+
+        if config.proposer_payments.enabled {
+            let tracker = ProposerPaymentTracker::new(
+                node.provider().clone(),
+                node.block_executor().clone(),
+                ctx.registry.eth_api().clone(),
+                config.proposer_payments.max_blocks,
+            );
+            ctx.modules.merge_if_module_configured(
+                RethRpcModule::Reth,
+                ProposerPaymentsApi::new(tracker.payments()).into_rpc(),
+            )?;
+            let attributes =
+                node.payload_builder_handle().subscribe().await?.into_attributes_stream();
+            node.task_executor()
+                .spawn(Box::pin(tracker.run(attributes, node.provider().canonical_state_stream())));
+            info!(target: "reth::cli", "Proposer payment verification started");
+        }
+
+        if config.debug.sync_target_rpc {
+            ctx.modules.merge_if_module_configured(
+                RethRpcModule::Admin,
+                AdminSyncTargetApi::new(sync_target).into_rpc(),
+            )?;
+        }
+
+        let exex_api = ExExApi::new(exex_status);
+        ctx.modules.merge_if_module_configured(
+            RethRpcModule::Reth,
+            RethExExApiServer::into_rpc(exex_api.clone()),
+        )?;
+        // pausing an ExEx changes what the node keeps in the WAL, so `all` doesn't include it
+        ctx.modules.merge_if_module_selected(
+            RethRpcModule::Admin,
+            AdminExExApiServer::into_rpc(exex_api),
+        )?;
+        ctx.modules.merge_if_module_configured(
+            RethRpcModule::Reth,
+            StorageBreakdownApi::new(
+                node.provider().clone(),
+                Box::new(node.task_executor().clone()),
+            )
+            .into_rpc(),
+        )?;
+        ctx.modules.merge_if_module_configured(
+            RethRpcModule::Reth,
+            RethBlockStreamApi::new(
+                ctx.registry.eth_api().clone(),
+                Box::new(node.task_executor().clone()),
+            )
+            .into_rpc(),
+        )?;
+
+        let trace_dir =
+            config.rpc.rpc_trace_dir.clone().unwrap_or_else(|| config.datadir().traces());
+        ctx.modules.merge_if_module_configured(
+            RethRpcModule::Debug,
+            DebugTraceFileApi::new(
+                ctx.registry.eth_api().clone(),
+                trace_dir,
+                config.rpc.rpc_trace_dir_max_size * 1024 * 1024,
+            )
+            .into_rpc(),
+        )?;
+
+        #[cfg(feature = "test-utils")]
+        ctx.modules.merge_if_module_configured(
+            RethRpcModule::Debug,
+            reth_rpc::DebugEngineStateApi::new(engine_state_snapshots).into_rpc(),
+        )?;
+
+        ext(ctx.modules, ctx.auth_module, ctx.registry)?;
         extend_rpc_modules.extend_rpc_modules(ctx)?;
 
-        let server_config = config.rpc.rpc_server_config();
+        let api_keys = config.rpc.rpc_api_keys()?;
+        if let Some(api_keys) = &api_keys {
+            api_keys.watch()?;
+        }
+        let server_config = config.rpc.rpc_server_config().with_api_keys(api_keys);
         let cloned_modules = modules.clone();
         let launch_rpc = server_config.start(&cloned_modules).map_ok(|handle| {
             if let Some(path) = handle.ipc_endpoint() {
@@ -567,7 +775,7 @@ where
     type Handle = RpcHandle<N, EthB::EthApi>;
 
     async fn launch_add_ons(self, ctx: AddOnsContext<'_, N>) -> eyre::Result<Self::Handle> {
-        self.launch_add_ons_with(ctx, |_, _| Ok(())).await
+        self.launch_add_ons_with(ctx, |_, _, _| Ok(())).await
     }
 }
 
@@ -719,7 +927,7 @@ where
             version: CARGO_PKG_VERSION.to_string(),
             commit: VERGEN_GIT_SHA.to_string(),
         };
-        Ok(EngineApi::new(
+        let mut engine_api = EngineApi::new(
             ctx.node.provider().clone(),
             ctx.config.chain.clone(),
             ctx.beacon_engine_handle.clone(),
@@ -729,6 +937,11 @@ where
             client,
             EngineCapabilities::default(),
             engine_validator,
-        ))
+        );
+        if ctx.config.engine.backfill_progress {
+            engine_api = engine_api
+                .with_backfill_progress(ctx.beacon_engine_handle.backfill_progress().clone());
+        }
+        Ok(engine_api)
     }
 }
@@ -14,10 +14,15 @@ use reth_evm::execute::BlockExecutorProvider;
 use reth_exex::ExExManagerHandle;
 use reth_network_p2p::{
     bodies::downloader::BodyDownloader, headers::downloader::HeaderDownloader, BlockClient,
+    CanonicalCheckpoints,
 };
 use reth_node_api::HeaderTy;
 use reth_provider::{providers::ProviderNodeTypes, ProviderFactory};
-use reth_stages::{prelude::DefaultStages, stages::ExecutionStage, Pipeline, StageSet};
+use reth_stages::{
+    prelude::DefaultStages,
+    stages::{BlockPrefetcher, ExecutionStage, ExecutionVerificationSampling},
+    Pipeline, StageSet,
+};
 use reth_static_file::StaticFileProducer;
 use reth_tasks::TaskExecutor;
 use reth_tracing::tracing::debug;
@@ -37,6 +42,7 @@ pub fn build_networked_pipeline<N, Client, Executor>(
     static_file_producer: StaticFileProducer<ProviderFactory<N>>,
     executor: Executor,
     exex_manager_handle: ExExManagerHandle<N::Primitives>,
+    checkpoints: CanonicalCheckpoints,
 ) -> eyre::Result<Pipeline<N>>
 where
     N: ProviderNodeTypes,
@@ -45,10 +51,12 @@ where
 {
     // building network downloaders using the fetch client
     let header_downloader = ReverseHeadersDownloaderBuilder::new(config.headers)
+        .checkpoints(checkpoints.clone())
         .build(client.clone(), consensus.clone().as_header_validator())
         .into_task_with(task_executor);
 
     let body_downloader = BodiesDownloaderBuilder::new(config.bodies)
+        .with_checkpoints(checkpoints)
         .build(client, consensus.clone().as_consensus(), provider_factory.clone())
         .into_task_with(task_executor);
 
@@ -101,6 +109,25 @@ where
 
     let prune_modes = prune_config.map(|prune| prune.segments).unwrap_or_default();
 
+    let mut execution_stage = ExecutionStage::new(
+        executor.clone(),
+        consensus.clone(),
+        stage_config.execution.into(),
+        stage_config.execution_external_clean_threshold(),
+        exex_manager_handle,
+    )
+    .with_verification_sampling(ExecutionVerificationSampling {
+        interval: stage_config.execution.verification_interval,
+        tip_distance: stage_config.execution.verification_tip_distance,
+    });
+    if stage_config.execution.prefetch_blocks > 0 {
+        execution_stage = execution_stage.with_block_prefetcher(BlockPrefetcher::new(
+            provider_factory.clone(),
+            stage_config.execution.prefetch_blocks,
+            stage_config.execution.prefetch_threads,
+        ));
+    }
+
     let pipeline = builder
         .with_tip_sender(tip_tx)
         .with_metrics_tx(metrics_tx)
@@ -108,20 +135,16 @@ where
             DefaultStages::new(
                 provider_factory.clone(),
                 tip_rx,
-                Arc::clone(&consensus),
+                consensus,
                 header_downloader,
                 body_downloader,
-                executor.clone(),
+                executor,
                 stage_config.clone(),
                 prune_modes,
             )
-            .set(ExecutionStage::new(
-                executor,
-                consensus,
-                stage_config.execution.into(),
-                stage_config.execution_external_clean_threshold(),
-                exex_manager_handle,
-            )),
+            .set(execution_stage)
+            .builder()
+            .disable_all(stage_config.profile.disabled_stages()),
         )
         .build(provider_factory, static_file_producer);
 
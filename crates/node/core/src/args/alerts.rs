@@ -0,0 +1,106 @@
+//! clap [Args](clap::Args) for node alerts
+
+use crate::args::ByteSize;
+use clap::Args;
+use humantime::parse_duration;
+use std::time::Duration;
+use url::Url;
+
+/// Parameters for alerting on node conditions.
+#[derive(Debug, Clone, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Alerts")]
+pub struct AlertArgs {
+    /// Enable alerts on node conditions that need the operator's attention.
+    ///
+    /// Alerts are logged and posted to the configured webhooks. A zero threshold disables the
+    /// respective condition.
+    #[arg(id = "alerts", long = "alerts", default_value_t = false)]
+    pub enabled: bool,
+
+    /// URLs that every alert is posted to as JSON.
+    #[arg(
+        id = "alerts.webhooks",
+        long = "alerts.webhooks",
+        value_name = "URL",
+        value_delimiter = ','
+    )]
+    pub webhooks: Vec<Url>,
+
+    /// Period without forkchoice updates after which an alert is raised.
+    #[arg(long = "alerts.fcu-timeout", value_name = "DURATION", value_parser = parse_duration, default_value = "2m")]
+    pub fcu_timeout: Duration,
+
+    /// Number of invalid payloads within `--alerts.invalid-payloads-window` that raises an alert.
+    #[arg(long = "alerts.invalid-payloads", value_name = "COUNT", default_value_t = 3)]
+    pub invalid_payloads: usize,
+
+    /// Period in which invalid payloads are counted.
+    #[arg(long = "alerts.invalid-payloads-window", value_name = "DURATION", value_parser = parse_duration, default_value = "10m")]
+    pub invalid_payloads_window: Duration,
+
+    /// Number of connected peers below which an alert is raised.
+    #[arg(long = "alerts.min-peers", value_name = "COUNT", default_value_t = 3)]
+    pub min_peers: usize,
+
+    /// Free disk space of the data directory below which an alert is raised.
+    #[arg(long = "alerts.min-free-disk-space", value_name = "SIZE", default_value = "50GB")]
+    pub min_free_disk_space: ByteSize,
+
+    /// Period in which an alert whose condition persists is not raised again.
+    #[arg(long = "alerts.repeat-interval", value_name = "DURATION", value_parser = parse_duration, default_value = "30m")]
+    pub repeat_interval: Duration,
+}
+
+impl Default for AlertArgs {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            webhooks: Vec::new(),
+            fcu_timeout: Duration::from_secs(2 * 60),
+            invalid_payloads: 3,
+            invalid_payloads_window: Duration::from_secs(10 * 60),
+            min_peers: 3,
+            min_free_disk_space: ByteSize(50 * 1024 * 1024 * 1024),
+            repeat_interval: Duration::from_secs(30 * 60),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_default_alert_args() {
+        let default_args = AlertArgs::default();
+        let args = CommandParser::<AlertArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, default_args);
+    }
+
+    #[test]
+    fn test_parse_alert_args() {
+        let args = CommandParser::<AlertArgs>::parse_from([
+            "reth",
+            "--alerts",
+            "--alerts.fcu-timeout",
+            "30s",
+            "--alerts.min-peers",
+            "0",
+            "--alerts.min-free-disk-space",
+            "10GB",
+        ])
+        .args;
+        assert!(args.enabled);
+        assert_eq!(args.fcu_timeout, Duration::from_secs(30));
+        assert_eq!(args.min_peers, 0);
+        assert_eq!(args.min_free_disk_space, ByteSize(10 * 1024 * 1024 * 1024));
+    }
+}
@@ -0,0 +1,92 @@
+//! clap [Args](clap::Args) for the contract creation index
+
+use alloy_primitives::BlockNumber;
+use clap::Args;
+use reth_prune_types::PruneMode;
+
+/// Parameters for the contract creation index.
+#[derive(Debug, Clone, Copy, Default, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Contract creation index")]
+pub struct ContractIndexArgs {
+    /// Enable the contract creation index, which records the block, transaction and creator of
+    /// every contract created in canonical blocks and serves them via `eth_getContractCreation`.
+    ///
+    /// The index catches up with the canonical chain from the last indexed block, or from the
+    /// first block kept by the prune configuration of the index if it's empty. Blocks whose state
+    /// was pruned by the node can't be traced, so they can't be indexed.
+    #[arg(id = "contract_index_enabled", long = "contract-index", default_value_t = false)]
+    pub enabled: bool,
+
+    /// Prune contract creations before the `head-N` block number. In other words, keep the
+    /// contract creations of the last N + 1 blocks.
+    #[arg(
+        long = "contract-index.prune.distance",
+        value_name = "BLOCKS",
+        conflicts_with = "prune_before"
+    )]
+    pub prune_distance: Option<u64>,
+
+    /// Prune contract creations before the specified block number. The specified block number is
+    /// not pruned.
+    #[arg(
+        long = "contract-index.prune.before",
+        value_name = "BLOCK_NUMBER",
+        conflicts_with = "prune_distance"
+    )]
+    pub prune_before: Option<BlockNumber>,
+}
+
+impl ContractIndexArgs {
+    /// Returns the prune mode of the index, if pruning is configured.
+    pub const fn prune_mode(&self) -> Option<PruneMode> {
+        if let Some(distance) = self.prune_distance {
+            Some(PruneMode::Distance(distance))
+        } else if let Some(block_number) = self.prune_before {
+            Some(PruneMode::Before(block_number))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_default_contract_index_args() {
+        let args = CommandParser::<ContractIndexArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, ContractIndexArgs::default());
+        assert_eq!(args.prune_mode(), None);
+    }
+
+    #[test]
+    fn test_parse_contract_index_args() {
+        let args = CommandParser::<ContractIndexArgs>::parse_from([
+            "reth",
+            "--contract-index",
+            "--contract-index.prune.distance",
+            "10064",
+        ])
+        .args;
+        assert!(args.enabled);
+        assert_eq!(args.prune_mode(), Some(PruneMode::Distance(10064)));
+
+        assert!(CommandParser::<ContractIndexArgs>::try_parse_from([
+            "reth",
+            "--contract-index.prune.distance",
+            "10064",
+            "--contract-index.prune.before",
+            "100",
+        ])
+        .is_err());
+    }
+}
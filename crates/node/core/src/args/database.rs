@@ -8,7 +8,12 @@ use clap::{
     error::ErrorKind,
     Arg, Args, Command, Error,
 };
-use reth_db::{mdbx::MaxReadTransactionDuration, ClientVersion};
+use reth_config::{DatabaseConfig, DatabaseSyncMode};
+use reth_db::{
+    mdbx::{MaxReadTransactionDuration, SyncMode},
+    read_metrics::ReadMetricsConfig,
+    ClientVersion,
+};
 use reth_storage_errors::db::LogLevel;
 
 /// Parameters for database configuration
@@ -28,9 +33,28 @@ pub struct DatabaseArgs {
     /// Database growth step (e.g., 4GB, 4KB)
     #[arg(long = "db.growth-step", value_parser = parse_byte_size)]
     pub growth_step: Option<usize>,
+    /// How commits are flushed to disk: `durable`, `no-meta-sync` or `safe-no-sync`. The
+    /// non-durable modes are faster, but a system crash may undo the latest committed blocks.
+    #[arg(long = "db.sync-mode", value_name = "MODE")]
+    pub sync_mode: Option<DatabaseSyncMode>,
+    /// Disable OS readahead of the database file. Readahead speeds up linear scans, e.g. during
+    /// initial sync, but slows down random access. Readahead is disabled unless it is enabled with
+    /// `no_readahead = false` in the `[database]` section of the config.
+    #[arg(long = "db.no-readahead")]
+    pub no_readahead: bool,
     /// Read transaction timeout in seconds, 0 means no timeout.
     #[arg(long = "db.read-transaction-timeout")]
     pub read_transaction_timeout: Option<u64>,
+    /// Record per-table counters and duration histograms of database reads. Can also be toggled
+    /// at runtime with `admin_setDatabaseReadMetrics`.
+    #[arg(long = "db.read-metrics")]
+    pub read_metrics: bool,
+    /// Log database reads that take longer than the given duration (e.g., 50ms).
+    #[arg(long = "db.slow-read-threshold", value_name = "DURATION", value_parser = humantime::parse_duration)]
+    pub slow_read_threshold: Option<Duration>,
+    /// Whether OS readahead is disabled, as set in the `[database]` section of the reth config.
+    #[arg(skip)]
+    config_no_readahead: Option<bool>,
 }
 
 impl DatabaseArgs {
@@ -57,6 +81,23 @@ impl DatabaseArgs {
             .with_max_read_transaction_duration(max_read_transaction_duration)
             .with_geometry_max_size(self.max_size)
             .with_growth_step(self.growth_step)
+            .with_sync_mode(self.sync_mode.map(|sync_mode| match sync_mode {
+                DatabaseSyncMode::Durable => SyncMode::Durable,
+                DatabaseSyncMode::NoMetaSync => SyncMode::NoMetaSync,
+                DatabaseSyncMode::SafeNoSync => SyncMode::SafeNoSync,
+            }))
+            .with_no_readahead(self.no_readahead.then_some(true).or(self.config_no_readahead))
+            .with_read_metrics(ReadMetricsConfig::new(self.read_metrics, self.slow_read_threshold))
+    }
+
+    /// Fills the arguments that are not set on the command line with the values of the `[database]`
+    /// section of the reth config.
+    pub fn with_config(mut self, config: &DatabaseConfig) -> Self {
+        self.max_size = self.max_size.or(config.max_size);
+        self.growth_step = self.growth_step.or(config.growth_step);
+        self.sync_mode = self.sync_mode.or(config.sync_mode);
+        self.config_no_readahead = config.no_readahead;
+        self
     }
 }
 
@@ -191,6 +232,56 @@ mod tests {
         assert_eq!(args, default_args);
     }
 
+    #[test]
+    fn test_command_parser_with_sync_mode_and_config() {
+        let cmd = CommandParser::<DatabaseArgs>::try_parse_from([
+            "reth",
+            "--db.sync-mode",
+            "safe-no-sync",
+            "--db.growth-step",
+            "8GB",
+        ])
+        .unwrap();
+        assert_eq!(cmd.args.sync_mode, Some(DatabaseSyncMode::SafeNoSync));
+
+        let config = DatabaseConfig {
+            max_size: Some(TERABYTE),
+            growth_step: Some(GIGABYTE),
+            sync_mode: Some(DatabaseSyncMode::NoMetaSync),
+            no_readahead: Some(false),
+        };
+        let args = cmd.args.with_config(&config);
+        assert_eq!(args.max_size, Some(TERABYTE));
+        assert_eq!(args.growth_step, Some(8 * GIGABYTE));
+        assert_eq!(args.sync_mode, Some(DatabaseSyncMode::SafeNoSync));
+        assert!(!args.no_readahead);
+        assert_eq!(args.config_no_readahead, Some(false));
+
+        let cmd =
+            CommandParser::<DatabaseArgs>::try_parse_from(["reth", "--db.no-readahead"]).unwrap();
+        assert!(cmd.args.with_config(&config).no_readahead);
+
+        assert!(CommandParser::<DatabaseArgs>::try_parse_from([
+            "reth",
+            "--db.sync-mode",
+            "utterly-no-sync"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_command_parser_with_read_metrics() {
+        let cmd = CommandParser::<DatabaseArgs>::try_parse_from([
+            "reth",
+            "--db.read-metrics",
+            "--db.slow-read-threshold",
+            "50ms",
+        ])
+        .unwrap();
+        assert!(cmd.args.read_metrics);
+        assert_eq!(cmd.args.slow_read_threshold, Some(Duration::from_millis(50)));
+    }
+
     #[test]
     fn test_command_parser_with_valid_max_size() {
         let cmd = CommandParser::<DatabaseArgs>::try_parse_from([
@@ -3,7 +3,11 @@
 use crate::dirs::{ChainPath, DataDirPath, MaybePlatformPath};
 use clap::Args;
 use reth_chainspec::Chain;
-use std::path::PathBuf;
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+use tempfile::TempDir;
 
 /// Parameters for datadir configuration
 #[derive(Debug, Args, PartialEq, Eq, Default, Clone)]
@@ -16,6 +20,9 @@ pub struct DatadirArgs {
     /// - Linux: `$XDG_DATA_HOME/reth/` or `$HOME/.local/share/reth/`
     /// - Windows: `{FOLDERID_RoamingAppData}/reth/`
     /// - macOS: `$HOME/Library/Application Support/reth/`
+    ///
+    /// `--datadir memory` uses an ephemeral datadir in memory-backed storage that is removed
+    /// when the command exits.
     #[arg(long, value_name = "DATA_DIR", verbatim_doc_comment, default_value_t)]
     pub datadir: MaybePlatformPath<DataDirPath>,
 
@@ -29,7 +36,32 @@ pub struct DatadirArgs {
     pub static_files_path: Option<PathBuf>,
 }
 
+/// Value of `--datadir` that selects an ephemeral datadir in memory-backed storage.
+pub const MEMORY_DATADIR: &str = "memory";
+
 impl DatadirArgs {
+    /// Returns `true` if the datadir is [`MEMORY_DATADIR`].
+    pub fn is_memory(&self) -> bool {
+        self.datadir.as_ref() == Some(Path::new(MEMORY_DATADIR))
+    }
+
+    /// Replaces [`MEMORY_DATADIR`] with a new ephemeral datadir in memory-backed storage: in
+    /// `/dev/shm` if it's available, or in the temporary directory of the OS otherwise.
+    ///
+    /// Returns the ephemeral datadir, which is removed when it's dropped, or `None` if the datadir
+    /// is not [`MEMORY_DATADIR`].
+    pub fn create_memory_datadir(&mut self) -> io::Result<Option<TempDir>> {
+        if !self.is_memory() {
+            return Ok(None)
+        }
+
+        let dir =
+            tempfile::Builder::new().prefix("reth-memory-").tempdir_in(memory_datadir_root())?;
+        tracing::info!(target: "reth::cli", path = ?dir.path(), "Using ephemeral datadir");
+        self.datadir = dir.path().to_path_buf().into();
+        Ok(Some(dir))
+    }
+
     /// Resolves the final datadir path.
     pub fn resolve_datadir(self, chain: Chain) -> ChainPath<DataDirPath> {
         let datadir = self.datadir.clone();
@@ -37,6 +69,16 @@ impl DatadirArgs {
     }
 }
 
+/// Returns the directory the ephemeral datadir of [`MEMORY_DATADIR`] is created in.
+fn memory_datadir_root() -> PathBuf {
+    let shm = Path::new("/dev/shm");
+    if shm.is_dir() {
+        shm.to_path_buf()
+    } else {
+        std::env::temp_dir()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,5 +96,34 @@ mod tests {
         let default_args = DatadirArgs::default();
         let args = CommandParser::<DatadirArgs>::parse_from(["reth"]).args;
         assert_eq!(args, default_args);
+        assert!(!args.is_memory());
+    }
+
+    #[test]
+    fn test_parse_memory_datadir() {
+        let args = CommandParser::<DatadirArgs>::parse_from(["reth", "--datadir", "memory"]).args;
+        assert!(args.is_memory());
+
+        let args =
+            CommandParser::<DatadirArgs>::parse_from(["reth", "--datadir", "/data/memory"]).args;
+        assert!(!args.is_memory());
+    }
+
+    #[test]
+    fn test_create_memory_datadir() {
+        let mut args =
+            CommandParser::<DatadirArgs>::parse_from(["reth", "--datadir", "memory"]).args;
+        let dir = args.create_memory_datadir().unwrap().unwrap();
+        assert!(!args.is_memory());
+        assert_eq!(args.datadir.as_ref(), Some(dir.path()));
+
+        let path = dir.path().to_path_buf();
+        assert!(path.is_dir());
+        drop(dir);
+        assert!(!path.exists());
+
+        let mut args = DatadirArgs::default();
+        assert!(args.create_memory_datadir().unwrap().is_none());
+        assert_eq!(args, DatadirArgs::default());
     }
 }
@@ -1,10 +1,12 @@
 //! clap [Args](clap::Args) for debugging purposes
 
+use alloy_eips::BlockHashOrNumber;
 use alloy_primitives::B256;
 use clap::{
     builder::{PossibleValue, TypedValueParser},
     Arg, Args, Command,
 };
+use reth_cli_util::hash_or_num_value_parser;
 use std::{collections::HashSet, ffi::OsStr, fmt, path::PathBuf, str::FromStr};
 use strum::{AsRefStr, EnumIter, IntoStaticStr, ParseError, VariantArray, VariantNames};
 
@@ -26,6 +28,33 @@ pub struct DebugArgs {
     #[arg(long = "debug.max-block", help_heading = "Debug")]
     pub max_block: Option<u64>,
 
+    /// Syncs the pipeline to exactly the specified block number or hash and then halts.
+    ///
+    /// Once the target is reached, the node ignores the consensus layer and only serves RPC
+    /// reads. Targets below the finalized block are rejected. Ignored in dev mode.
+    #[arg(
+        long = "debug.sync-target",
+        help_heading = "Debug",
+        value_name = "BLOCK_OR_HASH",
+        value_parser = hash_or_num_value_parser,
+        conflicts_with_all = &["tip", "max_block", "terminate", "etherscan", "rpc_consensus_ws"]
+    )]
+    pub sync_target: Option<BlockHashOrNumber>,
+
+    /// Enables `admin_setSyncTarget`, which moves the block set with `--debug.sync-target` at
+    /// runtime.
+    #[arg(long = "debug.sync-target-rpc", help_heading = "Debug", requires = "sync_target")]
+    pub sync_target_rpc: bool,
+
+    /// A file with the hashes of known canonical blocks, one `<block number> <block hash>` line
+    /// per block.
+    ///
+    /// Downloaded headers are verified against these hashes as soon as they arrive, and peers
+    /// serving headers or bodies that contradict them are banned. Headers that are known to be
+    /// canonical skip consensus validation.
+    #[arg(long = "debug.canonical-checkpoints", help_heading = "Debug", value_name = "PATH")]
+    pub canonical_checkpoints: Option<PathBuf>,
+
     /// Runs a fake consensus client that advances the chain using recent block hashes
     /// on Etherscan. If specified, requires an `ETHERSCAN_API_KEY` environment variable.
     #[arg(
@@ -68,6 +97,16 @@ pub struct DebugArgs {
     #[arg(long = "debug.engine-api-store", help_heading = "Debug", value_name = "PATH")]
     pub engine_api_store: Option<PathBuf>,
 
+    /// The maximum number of engine API messages to keep in the engine API store.
+    /// If specified, the oldest messages are removed once the limit is exceeded.
+    #[arg(
+        long = "debug.engine-api-store-max-messages",
+        help_heading = "Debug",
+        value_name = "COUNT",
+        requires = "engine_api_store"
+    )]
+    pub engine_api_store_max_messages: Option<usize>,
+
     /// Determines which type of invalid block hook to install
     ///
     /// Example: `witness,prestate`
@@ -95,6 +134,9 @@ impl Default for DebugArgs {
             terminate: false,
             tip: None,
             max_block: None,
+            sync_target: None,
+            sync_target_rpc: false,
+            canonical_checkpoints: None,
             etherscan: None,
             rpc_consensus_ws: None,
             skip_fcu: None,
@@ -102,6 +144,7 @@ impl Default for DebugArgs {
             reorg_frequency: None,
             reorg_depth: None,
             engine_api_store: None,
+            engine_api_store_max_messages: None,
             invalid_block_hook: Some(InvalidBlockSelection::default()),
             healthy_node_rpc_url: None,
         }
@@ -334,6 +377,43 @@ mod tests {
         assert_eq!(args, default_args);
     }
 
+    #[test]
+    fn test_parse_sync_target_args() {
+        let args =
+            CommandParser::<DebugArgs>::parse_from(["reth", "--debug.sync-target", "1000"]).args;
+        assert_eq!(args.sync_target, Some(BlockHashOrNumber::Number(1000)));
+
+        let hash = B256::with_last_byte(1);
+        let args = CommandParser::<DebugArgs>::parse_from([
+            "reth",
+            "--debug.sync-target",
+            &hash.to_string(),
+        ])
+        .args;
+        assert_eq!(args.sync_target, Some(BlockHashOrNumber::Hash(hash)));
+
+        let res = CommandParser::<DebugArgs>::try_parse_from([
+            "reth",
+            "--debug.sync-target",
+            "1000",
+            "--debug.max-block",
+            "1000",
+        ]);
+        assert!(res.is_err());
+
+        let args = CommandParser::<DebugArgs>::parse_from([
+            "reth",
+            "--debug.sync-target",
+            "1000",
+            "--debug.sync-target-rpc",
+        ])
+        .args;
+        assert!(args.sync_target_rpc);
+
+        let res = CommandParser::<DebugArgs>::try_parse_from(["reth", "--debug.sync-target-rpc"]);
+        assert!(res.is_err());
+    }
+
     #[test]
     fn test_parse_invalid_block_args() {
         let expected_args = DebugArgs {
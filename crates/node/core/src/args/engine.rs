@@ -1,13 +1,20 @@
 //! clap [Args](clap::Args) for engine purposes
 
 use clap::Args;
-use reth_engine_primitives::TreeConfig;
+use reth_engine_primitives::{
+    CanonicalizationMode, TreeConfig, DEFAULT_BLOCK_BUFFER_MAX_AGE,
+    DEFAULT_CANONICALIZATION_BUDGET, DEFAULT_STATE_CACHE_WARMUP_ACCOUNTS,
+};
+use std::time::Duration;
 
 use crate::node_config::{
-    DEFAULT_CROSS_BLOCK_CACHE_SIZE_MB, DEFAULT_MEMORY_BLOCK_BUFFER_TARGET,
-    DEFAULT_PERSISTENCE_THRESHOLD,
+    DEFAULT_BLOCK_BUFFER_MAX_SIZE_MB, DEFAULT_CROSS_BLOCK_CACHE_SIZE_MB,
+    DEFAULT_MEMORY_BLOCK_BUFFER_TARGET, DEFAULT_PERSISTENCE_THRESHOLD,
 };
 
+/// Default interval at which the sync status is logged and reported.
+const DEFAULT_SYNC_STATUS_INTERVAL: Duration = Duration::from_secs(25);
+
 /// Parameters for configuring the engine driver.
 #[derive(Debug, Clone, Args, PartialEq, Eq)]
 #[command(next_help_heading = "Engine")]
@@ -36,6 +43,90 @@ pub struct EngineArgs {
     /// state root calculation.
     #[arg(long = "engine.state-root-task-compare-updates")]
     pub state_root_task_compare_updates: bool,
+
+    /// When the new head block of a forkchoice update is made canonical: `immediate`, `latency`
+    /// or `correctness`.
+    ///
+    /// With `immediate` the head is made canonical right away. The other modes first wait for
+    /// blocks that are being persisted, up to `--engine.canonicalization-budget`. If persistence
+    /// does not complete in time, `latency` makes the head canonical anyway and `correctness`
+    /// reports `SYNCING` instead.
+    #[arg(long = "engine.canonicalization", value_name = "MODE", default_value_t = CanonicalizationMode::Immediate)]
+    pub canonicalization_mode: CanonicalizationMode,
+
+    /// How long a forkchoice update waits for canonicalization checks, e.g. the time left until
+    /// the payload attestation deadline.
+    ///
+    /// Parses strings using [`humantime::parse_duration`]
+    /// --engine.canonicalization-budget 1s
+    #[arg(
+        long = "engine.canonicalization-budget",
+        value_name = "DURATION",
+        default_value = "1s",
+        value_parser = humantime::parse_duration,
+        verbatim_doc_comment
+    )]
+    pub canonicalization_budget: Duration,
+
+    /// Persist the blocks that are buffered while the node is syncing to the data directory, so
+    /// they don't have to be sent again by the CL after a restart.
+    #[arg(long = "engine.persist-block-buffer")]
+    pub persist_block_buffer: bool,
+
+    /// Maximum age of persisted buffered blocks, older blocks are discarded on startup.
+    ///
+    /// Parses strings using [`humantime::parse_duration`]
+    /// --engine.block-buffer-max-age 24h
+    #[arg(
+        long = "engine.block-buffer-max-age",
+        value_name = "DURATION",
+        default_value = "24h",
+        value_parser = humantime::parse_duration,
+        verbatim_doc_comment
+    )]
+    pub block_buffer_max_age: Duration,
+
+    /// Maximum size of the persisted buffered blocks in megabytes
+    #[arg(long = "engine.block-buffer-max-size", default_value_t = DEFAULT_BLOCK_BUFFER_MAX_SIZE_MB)]
+    pub block_buffer_max_size: u64,
+
+    /// Attach the trie updates of the committed blocks to the canonical state notifications sent
+    /// to ExExes, so they don't have to recompute the trie changes.
+    #[arg(long = "engine.exex-trie-updates")]
+    pub exex_trie_updates: bool,
+
+    /// Interval at which the sync status is logged and the progress of the backfill sync is
+    /// reported to the consensus layer.
+    ///
+    /// Parses strings using [`humantime::parse_duration`]
+    /// --engine.sync-status-interval 25s
+    #[arg(
+        long = "engine.sync-status-interval",
+        value_name = "DURATION",
+        default_value = "25s",
+        value_parser = humantime::parse_duration,
+        verbatim_doc_comment
+    )]
+    pub sync_status_interval: Duration,
+
+    /// Append the progress of the backfill sync to the client name returned by
+    /// `engine_getClientVersionV1`, so the consensus layer can display it.
+    #[arg(long = "engine.backfill-progress")]
+    pub backfill_progress: bool,
+
+    /// Track how often accounts and storage slots are accessed during block execution, persist
+    /// the access frequencies to the data directory, and warm the execution cache with the most
+    /// frequently accessed state on startup.
+    ///
+    /// This avoids the slower execution of the first payloads after a restart, at the cost of a
+    /// longer startup.
+    #[arg(long = "engine.warm-state-cache")]
+    pub warm_state_cache: bool,
+
+    /// Maximum number of the most frequently accessed accounts that are loaded into the execution
+    /// cache on startup
+    #[arg(long = "engine.state-cache-warmup-accounts", default_value_t = DEFAULT_STATE_CACHE_WARMUP_ACCOUNTS)]
+    pub state_cache_warmup_accounts: usize,
 }
 
 impl Default for EngineArgs {
@@ -47,6 +138,16 @@ impl Default for EngineArgs {
             state_root_task_compare_updates: false,
             caching_and_prewarming_enabled: false,
             cross_block_cache_size: DEFAULT_CROSS_BLOCK_CACHE_SIZE_MB,
+            canonicalization_mode: CanonicalizationMode::Immediate,
+            canonicalization_budget: DEFAULT_CANONICALIZATION_BUDGET,
+            persist_block_buffer: false,
+            block_buffer_max_age: DEFAULT_BLOCK_BUFFER_MAX_AGE,
+            block_buffer_max_size: DEFAULT_BLOCK_BUFFER_MAX_SIZE_MB,
+            exex_trie_updates: false,
+            sync_status_interval: DEFAULT_SYNC_STATUS_INTERVAL,
+            backfill_progress: false,
+            warm_state_cache: false,
+            state_cache_warmup_accounts: DEFAULT_STATE_CACHE_WARMUP_ACCOUNTS,
         }
     }
 }
@@ -61,6 +162,11 @@ impl EngineArgs {
             .with_caching_and_prewarming(self.caching_and_prewarming_enabled)
             .with_always_compare_trie_updates(self.state_root_task_compare_updates)
             .with_cross_block_cache_size(self.cross_block_cache_size * 1024 * 1024)
+            .with_canonicalization_mode(self.canonicalization_mode)
+            .with_canonicalization_budget(self.canonicalization_budget)
+            .with_block_buffer_max_age(self.block_buffer_max_age)
+            .with_block_buffer_max_size(self.block_buffer_max_size * 1024 * 1024)
+            .with_state_cache_warmup_accounts(self.state_cache_warmup_accounts)
     }
 }
 
@@ -82,4 +188,69 @@ mod tests {
         let args = CommandParser::<EngineArgs>::parse_from(["reth"]).args;
         assert_eq!(args, default_args);
     }
+
+    #[test]
+    fn test_parse_canonicalization_args() {
+        let args = CommandParser::<EngineArgs>::parse_from([
+            "reth",
+            "--engine.canonicalization",
+            "correctness",
+            "--engine.canonicalization-budget",
+            "500ms",
+        ])
+        .args;
+        assert_eq!(args.canonicalization_mode, CanonicalizationMode::Correctness);
+        assert_eq!(args.canonicalization_budget, Duration::from_millis(500));
+
+        assert!(CommandParser::<EngineArgs>::try_parse_from([
+            "reth",
+            "--engine.canonicalization",
+            "eventually"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_block_buffer_args() {
+        let args = CommandParser::<EngineArgs>::parse_from([
+            "reth",
+            "--engine.persist-block-buffer",
+            "--engine.block-buffer-max-age",
+            "2h",
+            "--engine.block-buffer-max-size",
+            "128",
+        ])
+        .args;
+        assert!(args.persist_block_buffer);
+
+        let config = args.tree_config();
+        assert_eq!(config.block_buffer_max_age(), Duration::from_secs(2 * 60 * 60));
+        assert_eq!(config.block_buffer_max_size(), 128 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_parse_state_cache_warmup_args() {
+        let args = CommandParser::<EngineArgs>::parse_from([
+            "reth",
+            "--engine.warm-state-cache",
+            "--engine.state-cache-warmup-accounts",
+            "500",
+        ])
+        .args;
+        assert!(args.warm_state_cache);
+        assert_eq!(args.tree_config().state_cache_warmup_accounts(), 500);
+    }
+
+    #[test]
+    fn test_parse_sync_status_args() {
+        let args = CommandParser::<EngineArgs>::parse_from([
+            "reth",
+            "--engine.sync-status-interval",
+            "1m",
+            "--engine.backfill-progress",
+        ])
+        .args;
+        assert_eq!(args.sync_status_interval, Duration::from_secs(60));
+        assert!(args.backfill_progress);
+    }
 }
@@ -0,0 +1,39 @@
+//! clap [Args](clap::Args) for the header accumulator
+
+use clap::Args;
+
+/// Parameters for the accumulator of canonical block hashes.
+#[derive(Debug, Clone, Copy, Default, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Header accumulator")]
+pub struct HeaderAccumulatorArgs {
+    /// Enable the header accumulator, a Merkle Mountain Range of all canonical block hashes that
+    /// serves inclusion proofs of historical headers via `reth_getHeaderProof`.
+    ///
+    /// On start, the hashes of all canonical blocks that are not accumulated yet are appended.
+    #[arg(id = "header_accumulator_enabled", long = "header-accumulator", default_value_t = false)]
+    pub enabled: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_header_accumulator_args() {
+        let args = CommandParser::<HeaderAccumulatorArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, HeaderAccumulatorArgs::default());
+
+        let args =
+            CommandParser::<HeaderAccumulatorArgs>::parse_from(["reth", "--header-accumulator"])
+                .args;
+        assert!(args.enabled);
+    }
+}
@@ -57,6 +57,35 @@ pub struct LogArgs {
     )]
     pub journald_filter: String,
 
+    /// Export spans to an OpenTelemetry collector at the given OTLP/HTTP traces endpoint, e.g.
+    /// `http://localhost:4318/v1/traces`.
+    ///
+    /// Requires reth to be built with the `otlp` feature.
+    #[arg(long = "log.otlp", value_name = "URL", global = true)]
+    pub otlp: Option<String>,
+
+    /// The filter to use for spans exported over OTLP.
+    #[arg(long = "log.otlp.filter", value_name = "FILTER", global = true, default_value = "debug")]
+    pub otlp_filter: String,
+
+    /// The fraction of traces exported over OTLP, between 0.0 and 1.0.
+    #[arg(
+        long = "log.otlp.sample-ratio",
+        value_name = "RATIO",
+        global = true,
+        default_value_t = 1.0
+    )]
+    pub otlp_sample_ratio: f64,
+
+    /// The service name reported with spans exported over OTLP.
+    #[arg(
+        long = "log.otlp.service-name",
+        value_name = "NAME",
+        global = true,
+        default_value = "reth"
+    )]
+    pub otlp_service_name: String,
+
     /// Sets whether or not the formatter emits ANSI terminal escape codes for colors and other
     /// text formatting.
     #[arg(
@@ -110,6 +139,15 @@ impl LogArgs {
             tracer = tracer.with_file(file, info);
         }
 
+        if let Some(endpoint) = &self.otlp {
+            tracer = tracer.with_otlp(OtlpConfig::new(
+                endpoint.clone(),
+                self.otlp_service_name.clone(),
+                self.otlp_sample_ratio,
+                self.otlp_filter.clone(),
+            ));
+        }
+
         let guard = tracer.init()?;
         Ok(guard)
     }
@@ -18,7 +18,7 @@ pub use debug::{DebugArgs, InvalidBlockHookType, InvalidBlockSelection};
 
 /// DatabaseArgs struct for configuring the database
 mod database;
-pub use database::DatabaseArgs;
+pub use database::{ByteSize, DatabaseArgs};
 
 /// LogArgs struct for configuring the logger
 mod log;
@@ -46,11 +46,11 @@ pub use dev::DevArgs;
 
 /// PruneArgs for configuring the pruning and full node
 mod pruning;
-pub use pruning::PruningArgs;
+pub use pruning::{pipeline_profile_prune_config, PruningArgs};
 
 /// DatadirArgs for configuring data storage paths
 mod datadir_args;
-pub use datadir_args::DatadirArgs;
+pub use datadir_args::{DatadirArgs, MEMORY_DATADIR};
 
 /// BenchmarkArgs struct for configuring the benchmark to run
 mod benchmark_args;
@@ -64,5 +64,41 @@ pub use engine::EngineArgs;
 mod ress_args;
 pub use ress_args::RessArgs;
 
+/// `TxWatcherArgs` for configuring the transaction watcher
+mod tx_watcher;
+pub use tx_watcher::TxWatcherArgs;
+
+/// `ContractIndexArgs` for configuring the contract creation index
+mod contract_index;
+pub use contract_index::ContractIndexArgs;
+
+/// `HeaderAccumulatorArgs` for configuring the header accumulator
+mod header_accumulator;
+pub use header_accumulator::HeaderAccumulatorArgs;
+
+/// `RequestIndexArgs` for configuring the execution layer request index
+mod request_index;
+pub use request_index::RequestIndexArgs;
+
+/// `ProposerPaymentsArgs` for configuring the verification of proposer payments
+mod proposer_payments;
+pub use proposer_payments::ProposerPaymentsArgs;
+
+/// `AlertArgs` for configuring node alerts
+mod alerts;
+pub use alerts::AlertArgs;
+
+/// `StaticFilesArgs` for configuring the verification of static files
+mod static_files;
+pub use static_files::StaticFilesArgs;
+
+/// `PipelineArgs` for configuring the pipeline profile
+mod pipeline;
+pub use pipeline::PipelineArgs;
+
+/// `WasmPluginArgs` for loading WASM plugins
+mod wasm_plugins;
+pub use wasm_plugins::WasmPluginArgs;
+
 mod error;
 pub mod types;
@@ -4,6 +4,7 @@ use std::{
     net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
     ops::Not,
     path::PathBuf,
+    time::Duration,
 };
 
 use clap::Args;
@@ -16,6 +17,7 @@ use reth_discv5::{
 };
 use reth_net_nat::{NatResolver, DEFAULT_NET_IF_NAME};
 use reth_network::{
+    dns::{tree::LinkEntry, DnsDiscoveryConfig},
     transactions::{
         constants::{
             tx_fetcher::{
@@ -30,14 +32,18 @@ use reth_network::{
         DEFAULT_SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESP_ON_PACK_GET_POOLED_TRANSACTIONS_REQ,
         SOFT_LIMIT_BYTE_SIZE_POOLED_TRANSACTIONS_RESPONSE,
     },
-    HelloMessageWithProtocols, NetworkConfigBuilder, NetworkPrimitives, SessionsConfig,
+    AllowlistError, AllowlistFile, HelloMessageWithProtocols, NetworkConfigBuilder,
+    NetworkPrimitives, PeersConfig, ServeMode, SessionsConfig,
 };
 use reth_network_peers::{mainnet_nodes, TrustedPeer};
 use secp256k1::SecretKey;
-use tracing::error;
+use tracing::{error, info};
 
 use crate::version::P2P_CLIENT_VERSION;
 
+/// The default interval at which bootnodes given by DNS name are resolved again.
+const DEFAULT_BOOTNODES_RESOLVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
 /// Parameters for configuring the network more granularity via CLI
 #[derive(Debug, Clone, Args, PartialEq, Eq)]
 #[command(next_help_heading = "Networking")]
@@ -46,6 +52,13 @@ pub struct NetworkArgs {
     #[command(flatten)]
     pub discovery: DiscoveryArgs,
 
+    /// Whether P2P networking is disabled.
+    ///
+    /// This is set by the `--no-network` flag of the `node` command, which is the only command
+    /// that accepts it.
+    #[arg(skip)]
+    pub no_network: bool,
+
     #[allow(clippy::doc_markdown)]
     /// Comma separated enode URLs of trusted peers for P2P connections.
     ///
@@ -57,6 +70,13 @@ pub struct NetworkArgs {
     #[arg(long)]
     pub trusted_only: bool,
 
+    /// Path to a peer allowlist file for private networks.
+    ///
+    /// Only the listed peers are discovered, connected to or accepted from. The file lists one
+    /// enode URL, ENR, node id or CIDR range of IPs per line, and is reloaded on changes.
+    #[arg(long, value_name = "FILE")]
+    pub allowlist: Option<PathBuf>,
+
     /// Comma separated enode URLs for P2P discovery bootstrap.
     ///
     /// Will fall back to a network-specific default if not specified.
@@ -154,6 +174,13 @@ pub struct NetworkArgs {
     /// If flag is set, but no value is passed, the default interface for docker `eth0` is tried.
     #[arg(long = "net-if.experimental", conflicts_with = "addr", value_name = "IF_NAME")]
     pub net_if: Option<String>,
+
+    /// How much historical chain data is served to peers: `none`, `headers` or `full`.
+    ///
+    /// Requests for data that is not served are answered with empty responses. A restricted mode
+    /// is advertised in the node record.
+    #[arg(long = "p2p.serve", value_name = "MODE", default_value_t = ServeMode::Full)]
+    pub serve_mode: ServeMode,
 }
 
 impl NetworkArgs {
@@ -181,9 +208,50 @@ impl NetworkArgs {
     /// Returns the resolved bootnodes if any are provided.
     pub fn resolved_bootnodes(&self) -> Option<Vec<NodeRecord>> {
         self.bootnodes.clone().map(|bootnodes| {
-            bootnodes.into_iter().filter_map(|node| node.resolve_blocking().ok()).collect()
+            resolve_bootnodes(bootnodes).into_iter().map(|(_, record)| record).collect()
+        })
+    }
+
+    /// Returns the resolved bootnodes provided by `--bootnodes`, or else by the discovery section
+    /// of the config, if any.
+    ///
+    /// These replace the bootnodes of the chain.
+    pub fn resolved_bootnodes_with_config(&self, config: &Config) -> Option<Vec<NodeRecord>> {
+        self.bootnodes.clone().or_else(|| config.discovery.bootnodes.clone()).map(|bootnodes| {
+            resolve_bootnodes(bootnodes).into_iter().map(|(_, record)| record).collect()
         })
     }
+
+    /// Returns the DNS discovery config with the DNS networks and resolve interval of the
+    /// discovery section of the config, if any are set.
+    ///
+    /// Invalid DNS network links are skipped.
+    fn dns_discovery_config(&self, config: &Config) -> Option<DnsDiscoveryConfig> {
+        let discovery = &config.discovery;
+        if discovery.dns_networks.is_none() && discovery.resolve_interval.is_none() {
+            return None
+        }
+
+        let mut dns_config = DnsDiscoveryConfig::default();
+        if let Some(interval) = discovery.resolve_interval {
+            dns_config.recheck_interval = interval;
+        }
+        if let Some(networks) = &discovery.dns_networks {
+            let links = networks
+                .iter()
+                .filter_map(|link| match link.parse::<LinkEntry>() {
+                    Ok(link) => Some(link),
+                    Err(err) => {
+                        error!(target: "reth::cli", %link, %err, "Invalid DNS discovery network");
+                        None
+                    }
+                })
+                .collect();
+            dns_config.bootstrap_dns_networks = Some(links);
+        }
+        Some(dns_config)
+    }
+
     /// Configures and returns a `TransactionsManagerConfig` based on the current settings.
     pub fn transactions_manager_config(&self) -> TransactionsManagerConfig {
         TransactionsManagerConfig {
@@ -218,9 +286,16 @@ impl NetworkArgs {
         default_peers_file: PathBuf,
     ) -> NetworkConfigBuilder<N> {
         let addr = self.resolved_addr();
-        let chain_bootnodes = self
-            .resolved_bootnodes()
-            .unwrap_or_else(|| chain_spec.bootnodes().unwrap_or_else(mainnet_nodes));
+        // bootnodes of the cli or config replace the bootnodes of the chain, nodes given by DNS
+        // name are kept so that they can be resolved again
+        let (boot_nodes, chain_bootnodes): (Vec<TrustedPeer>, Vec<NodeRecord>) =
+            match self.bootnodes.clone().or_else(|| config.discovery.bootnodes.clone()) {
+                Some(bootnodes) => resolve_bootnodes(bootnodes).into_iter().unzip(),
+                None => {
+                    let records = chain_spec.bootnodes().unwrap_or_else(mainnet_nodes);
+                    (records.iter().copied().map(Into::into).collect(), records)
+                }
+            };
         let peers_file = self.peers_file.clone().unwrap_or(default_peers_file);
 
         // Configure peer connections
@@ -240,8 +315,12 @@ impl NetworkArgs {
                 SessionsConfig::default().with_upscaled_event_buffer(peers_config.max_peers()),
             )
             .peer_config(peers_config)
-            .boot_nodes(chain_bootnodes.clone())
+            .boot_nodes(boot_nodes)
+            .boot_nodes_resolution_interval(
+                config.discovery.resolve_interval.unwrap_or(DEFAULT_BOOTNODES_RESOLVE_INTERVAL),
+            )
             .transactions_manager_config(self.transactions_manager_config())
+            .serve_mode(self.serve_mode)
             // Configure node identity
             .apply(|builder| {
                 let peer_id = builder.get_peer_id();
@@ -251,6 +330,11 @@ impl NetworkArgs {
                         .build(),
                 )
             })
+            // configure DNS discovery before it may be disabled by the discovery settings
+            .apply(|builder| match self.dns_discovery_config(config) {
+                Some(dns_config) => builder.dns_discovery(dns_config),
+                None => builder,
+            })
             // apply discovery settings
             .apply(|builder| {
                 let rlpx_socket = (addr, self.port).into();
@@ -265,6 +349,32 @@ impl NetworkArgs {
                 // set discovery port based on instance number
                 self.discovery.port,
             ))
+            // isolate the node from all peers if networking is disabled
+            .apply(|builder| {
+                if !self.no_network {
+                    return builder
+                }
+                builder
+                    .disable_discovery()
+                    .disable_nat()
+                    .boot_nodes(Vec::<TrustedPeer>::new())
+                    .peer_config(PeersConfig::default().with_max_inbound(0).with_max_outbound(0))
+                    .listener_addr(SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0))
+                    .disable_tx_gossip(true)
+            })
+    }
+
+    /// Restricts the network to the peer allowlist of `--allowlist`, if set. The allowlist is
+    /// reloaded whenever the file changes.
+    pub fn apply_allowlist<N: NetworkPrimitives>(
+        &self,
+        builder: NetworkConfigBuilder<N>,
+    ) -> Result<NetworkConfigBuilder<N>, AllowlistError> {
+        let Some(path) = &self.allowlist else { return Ok(builder) };
+        let allowlist = AllowlistFile::load(path)?;
+        allowlist.watch()?;
+        info!(target: "reth::cli", ?path, "Restricting peers to allowlist");
+        Ok(builder.allowlist(allowlist.allowlist().clone()))
     }
 
     /// If `no_persist_peers` is false then this returns the path to the persistent peers file path.
@@ -311,8 +421,10 @@ impl Default for NetworkArgs {
     fn default() -> Self {
         Self {
             discovery: DiscoveryArgs::default(),
+            no_network: false,
             trusted_peers: vec![],
             trusted_only: false,
+            allowlist: None,
             bootnodes: None,
             dns_retries: 0,
             peers_file: None,
@@ -333,6 +445,7 @@ impl Default for NetworkArgs {
             max_seen_tx_history: DEFAULT_MAX_COUNT_TRANSACTIONS_SEEN_BY_PEER,
             max_capacity_cache_txns_pending_fetch: DEFAULT_MAX_CAPACITY_CACHE_PENDING_FETCH,
             net_if: None,
+            serve_mode: ServeMode::Full,
         }
     }
 }
@@ -521,6 +634,17 @@ impl Default for DiscoveryArgs {
     }
 }
 
+/// Resolves the bootnodes, skipping those that can't be resolved.
+fn resolve_bootnodes(bootnodes: Vec<TrustedPeer>) -> Vec<(TrustedPeer, NodeRecord)> {
+    bootnodes
+        .into_iter()
+        .filter_map(|node| {
+            let record = node.resolve_blocking().ok()?;
+            Some((node, record))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -542,6 +666,19 @@ mod tests {
         assert_eq!(args.nat, NatResolver::ExternalIp("0.0.0.0".parse().unwrap()));
     }
 
+    #[test]
+    fn parse_serve_mode_args() {
+        let args = CommandParser::<NetworkArgs>::parse_from(["reth"]).args;
+        assert_eq!(args.serve_mode, ServeMode::Full);
+
+        let args =
+            CommandParser::<NetworkArgs>::parse_from(["reth", "--p2p.serve", "headers"]).args;
+        assert_eq!(args.serve_mode, ServeMode::Headers);
+
+        assert!(CommandParser::<NetworkArgs>::try_parse_from(["reth", "--p2p.serve", "bodies"])
+            .is_err());
+    }
+
     #[test]
     fn parse_peer_args() {
         let args =
@@ -6,6 +6,7 @@ use clap::{
     Arg, Args, Command,
 };
 use reth_cli_util::{parse_duration_from_secs, parse_duration_from_secs_or_ms};
+use reth_payload_primitives::{ExtraDataTemplate, FeeRecipient};
 use std::{borrow::Cow, ffi::OsStr, time::Duration};
 
 /// Parameters for configuring the Payload Builder
@@ -35,6 +36,30 @@ pub struct PayloadBuilderArgs {
     /// Maximum number of tasks to spawn for building a payload.
     #[arg(long = "builder.max-tasks", default_value = "3", value_parser = RangedU64ValueParser::<usize>::new().range(1..))]
     pub max_payload_tasks: usize,
+
+    /// The maximum time a payload job spends building payloads, summed over all its build
+    /// attempts.
+    ///
+    /// Once exhausted, the in-progress build is cancelled and the best payload built so far is
+    /// kept. Unlimited by default.
+    ///
+    /// Duration is specified in seconds or in milliseconds if the value ends with `ms`.
+    #[arg(long = "builder.max-build-time", value_parser = parse_duration_from_secs_or_ms, value_name = "DURATION")]
+    pub max_build_time: Option<Duration>,
+
+    /// Fee recipients of built blocks if the consensus layer doesn't suggest a fee recipient, i.e.
+    /// suggests the zero address.
+    ///
+    /// Recipients are rotated by block number, each receiving `WEIGHT` consecutive blocks per
+    /// round. The weight defaults to 1.
+    #[arg(long = "builder.fee-recipients", value_name = "ADDRESS[:WEIGHT]", value_delimiter = ',')]
+    pub fee_recipients: Vec<FeeRecipient>,
+
+    /// Template of the extra data of built blocks, overriding `--builder.extradata`.
+    ///
+    /// `{number}` and `{timestamp}` are replaced with the number and timestamp of the block.
+    #[arg(long = "builder.extradata-template", value_name = "TEMPLATE")]
+    pub extra_data_template: Option<ExtraDataTemplate>,
 }
 
 impl Default for PayloadBuilderArgs {
@@ -45,6 +70,9 @@ impl Default for PayloadBuilderArgs {
             interval: Duration::from_secs(1),
             deadline: SLOT_DURATION,
             max_payload_tasks: 3,
+            max_build_time: None,
+            fee_recipients: Vec::new(),
+            extra_data_template: None,
         }
     }
 }
@@ -69,6 +97,18 @@ impl PayloadBuilderConfig for PayloadBuilderArgs {
     fn max_payload_tasks(&self) -> usize {
         self.max_payload_tasks
     }
+
+    fn max_build_time(&self) -> Option<Duration> {
+        self.max_build_time
+    }
+
+    fn fee_recipients(&self) -> &[FeeRecipient] {
+        &self.fee_recipients
+    }
+
+    fn extra_data_template(&self) -> Option<&ExtraDataTemplate> {
+        self.extra_data_template.as_ref()
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -101,6 +141,7 @@ impl TypedValueParser for ExtraDataValueParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_primitives::Address;
     use clap::Parser;
 
     /// A helper type to parse Args more easily
@@ -151,6 +192,36 @@ mod tests {
         assert!(args.is_err());
     }
 
+    #[test]
+    fn test_fee_recipients_and_extra_data_template() {
+        let args = CommandParser::<PayloadBuilderArgs>::parse_from([
+            "reth",
+            "--builder.fee-recipients",
+            "0x0000000000000000000000000000000000000001:2,0x0000000000000000000000000000000000000002",
+            "--builder.extradata-template",
+            "reth/{number}",
+        ])
+        .args;
+        assert_eq!(
+            args.fee_recipients,
+            vec![
+                FeeRecipient::new(Address::with_last_byte(1)).with_weight(2),
+                FeeRecipient::new(Address::with_last_byte(2)),
+            ]
+        );
+        assert_eq!(
+            args.extra_data_template,
+            Some(ExtraDataTemplate::new("reth/{number}").unwrap())
+        );
+
+        assert!(CommandParser::<PayloadBuilderArgs>::try_parse_from([
+            "reth",
+            "--builder.extradata-template",
+            "reth/{number}/{timestamp}/overflow",
+        ])
+        .is_err());
+    }
+
     #[test]
     fn payload_builder_args_default_sanity_check() {
         let default_args = PayloadBuilderArgs::default();
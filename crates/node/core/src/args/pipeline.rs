@@ -0,0 +1,70 @@
+//! clap [Args](clap::Args) for the pipeline
+
+use clap::Args;
+use reth_config::{config::StageConfig, PipelineProfile};
+
+/// Parameters for the pipeline.
+#[derive(Debug, Clone, Copy, Default, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Pipeline")]
+pub struct PipelineArgs {
+    /// The stages the pipeline runs and the indices it builds: `archive`, `full`,
+    /// `chain-data-only` or `execution-only`.
+    ///
+    /// `full` uses the pruning defaults of a full node. `chain-data-only` syncs headers, bodies
+    /// and the transaction lookup index without executing the blocks. `execution-only`
+    /// executes the blocks without building the transaction lookup and history indices.
+    /// Explicitly configured prune segments take precedence over the defaults of the profile.
+    ///
+    /// A node can't follow the chain with `chain-data-only` or `execution-only`, so it refuses
+    /// to start with them.
+    #[arg(long = "pipeline.profile", value_name = "PROFILE")]
+    pub profile: Option<PipelineProfile>,
+}
+
+impl PipelineArgs {
+    /// Overrides the `[stages]` section of the reth config with the arguments that are set on the
+    /// command line.
+    pub const fn apply(&self, config: &mut StageConfig) {
+        if let Some(profile) = self.profile {
+            config.profile = profile;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_pipeline_args() {
+        let args = CommandParser::<PipelineArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, PipelineArgs::default());
+
+        let args = CommandParser::<PipelineArgs>::parse_from([
+            "reth",
+            "--pipeline.profile",
+            "chain-data-only",
+        ])
+        .args;
+        assert_eq!(args.profile, Some(PipelineProfile::ChainDataOnly));
+
+        let mut config = StageConfig::default();
+        args.apply(&mut config);
+        assert_eq!(config.profile, PipelineProfile::ChainDataOnly);
+
+        assert!(CommandParser::<PipelineArgs>::try_parse_from([
+            "reth",
+            "--pipeline.profile",
+            "light"
+        ])
+        .is_err());
+    }
+}
@@ -0,0 +1,56 @@
+//! clap [Args](clap::Args) for the verification of proposer payments
+
+use clap::Args;
+
+/// Default number of locally proposed blocks whose payments are kept.
+const DEFAULT_MAX_BLOCKS: usize = 1024;
+
+/// Parameters for the verification of proposer payments.
+#[derive(Debug, Clone, Copy, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Proposer payments")]
+pub struct ProposerPaymentsArgs {
+    /// Verify that the fee recipient of every block proposed through this node receives the
+    /// priority fees of the block, or the payment of the external builder that built it.
+    ///
+    /// The payments are exposed via metrics and `reth_getProposerPayments`.
+    #[arg(id = "proposer_payments_enabled", long = "proposer-payments", default_value_t = false)]
+    pub enabled: bool,
+
+    /// Number of locally proposed blocks whose payments are kept.
+    #[arg(long = "proposer-payments.max-blocks", value_name = "COUNT", default_value_t = DEFAULT_MAX_BLOCKS)]
+    pub max_blocks: usize,
+}
+
+impl Default for ProposerPaymentsArgs {
+    fn default() -> Self {
+        Self { enabled: false, max_blocks: DEFAULT_MAX_BLOCKS }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_proposer_payments_args() {
+        let args = CommandParser::<ProposerPaymentsArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, ProposerPaymentsArgs::default());
+
+        let args = CommandParser::<ProposerPaymentsArgs>::parse_from([
+            "reth",
+            "--proposer-payments",
+            "--proposer-payments.max-blocks",
+            "64",
+        ])
+        .args;
+        assert_eq!(args, ProposerPaymentsArgs { enabled: true, max_blocks: 64 });
+    }
+}
@@ -4,7 +4,7 @@ use crate::args::error::ReceiptsLogError;
 use alloy_primitives::{Address, BlockNumber};
 use clap::{builder::RangedU64ValueParser, Args};
 use reth_chainspec::EthChainSpec;
-use reth_config::config::PruneConfig;
+use reth_config::{config::PruneConfig, PipelineProfile};
 use reth_prune_types::{PruneMode, PruneModes, ReceiptsLogPruneConfig, MINIMUM_PRUNING_DISTANCE};
 use std::collections::BTreeMap;
 
@@ -98,28 +98,7 @@ impl PruningArgs {
 
         // If --full is set, use full node defaults.
         if self.full {
-            config = PruneConfig {
-                block_interval: config.block_interval,
-                segments: PruneModes {
-                    sender_recovery: Some(PruneMode::Full),
-                    transaction_lookup: None,
-                    // prune all receipts if chain doesn't have deposit contract specified in chain
-                    // spec
-                    receipts: chain_spec
-                        .deposit_contract()
-                        .map(|contract| PruneMode::Before(contract.block))
-                        .or(Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE))),
-                    account_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
-                    storage_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
-                    receipts_log_filter: ReceiptsLogPruneConfig(
-                        chain_spec
-                            .deposit_contract()
-                            .map(|contract| (contract.address, PruneMode::Before(contract.block)))
-                            .into_iter()
-                            .collect(),
-                    ),
-                },
-            }
+            config = full_node_prune_config(chain_spec);
         }
 
         // Override with any explicitly set prune.* flags.
@@ -205,6 +184,50 @@ impl PruningArgs {
     }
 }
 
+/// Returns the pruning configuration of a full node, only the most recent
+/// [`MINIMUM_PRUNING_DISTANCE`] block states are stored.
+fn full_node_prune_config(chain_spec: &impl EthChainSpec) -> PruneConfig {
+    PruneConfig {
+        segments: PruneModes {
+            sender_recovery: Some(PruneMode::Full),
+            transaction_lookup: None,
+            // prune all receipts if chain doesn't have deposit contract specified in chain
+            // spec
+            receipts: chain_spec
+                .deposit_contract()
+                .map(|contract| PruneMode::Before(contract.block))
+                .or(Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE))),
+            account_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
+            storage_history: Some(PruneMode::Distance(MINIMUM_PRUNING_DISTANCE)),
+            receipts_log_filter: ReceiptsLogPruneConfig(
+                chain_spec
+                    .deposit_contract()
+                    .map(|contract| (contract.address, PruneMode::Before(contract.block)))
+                    .into_iter()
+                    .collect(),
+            ),
+        },
+        ..Default::default()
+    }
+}
+
+/// Returns the default pruning configuration of the pipeline profile.
+///
+/// Segments that are configured explicitly take precedence over these defaults.
+pub fn pipeline_profile_prune_config(
+    profile: PipelineProfile,
+    chain_spec: &impl EthChainSpec,
+) -> Option<PruneConfig> {
+    match profile {
+        PipelineProfile::Full => Some(full_node_prune_config(chain_spec)),
+        // the node doesn't run with the profiles that skip stages, see
+        // `PipelineProfile::is_supported_by_node`
+        PipelineProfile::Archive |
+        PipelineProfile::ChainDataOnly |
+        PipelineProfile::ExecutionOnly => None,
+    }
+}
+
 pub(crate) fn parse_receipts_log_filter(
     value: &str,
 ) -> Result<ReceiptsLogPruneConfig, ReceiptsLogError> {
@@ -267,6 +290,18 @@ mod tests {
         assert_eq!(args, default_args);
     }
 
+    #[test]
+    fn pipeline_profile_prune_defaults() {
+        let chain_spec = reth_chainspec::MAINNET.as_ref();
+        assert_eq!(pipeline_profile_prune_config(PipelineProfile::Archive, chain_spec), None);
+
+        let full_args = PruningArgs { full: true, ..Default::default() };
+        assert_eq!(
+            pipeline_profile_prune_config(PipelineProfile::Full, chain_spec),
+            full_args.prune_config(chain_spec)
+        );
+    }
+
     #[test]
     fn test_parse_receipts_log_filter() {
         let filter1 = "0x0000000000000000000000000000000000000001:full";
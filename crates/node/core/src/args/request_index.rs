@@ -0,0 +1,19 @@
+//! clap [Args](clap::Args) for the execution layer request index
+
+use clap::Args;
+
+/// Parameters for the execution layer request index.
+#[derive(Debug, Clone, Copy, Default, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Execution request index")]
+pub struct RequestIndexArgs {
+    /// Enable the execution layer request index, which records the deposit, withdrawal and
+    /// consolidation requests of canonical blocks and serves them via `reth_getRequestsByBlock`
+    /// and `reth_getRequestsByValidator`.
+    ///
+    /// The index catches up with the canonical chain from the last indexed block, or from the
+    /// Prague activation if it's empty. Blocks that are not indexed as they become canonical are
+    /// executed again, which requires their state, so blocks whose state was pruned by the node
+    /// can't be indexed.
+    #[arg(id = "request_index_enabled", long = "request-index", default_value_t = false)]
+    pub enabled: bool,
+}
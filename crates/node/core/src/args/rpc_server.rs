@@ -5,6 +5,7 @@ use std::{
     ffi::OsStr,
     net::{IpAddr, Ipv4Addr},
     path::PathBuf,
+    time::Duration,
 };
 
 use alloy_primitives::Address;
@@ -14,7 +15,10 @@ use clap::{
     Arg, Args, Command,
 };
 use rand::Rng;
-use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
+use reth_cli_util::load_secret_key::{get_secret_key, SecretKeyError};
+use reth_rpc_eth_types::TxLookupFallback;
+use reth_rpc_server_types::{constants, RemoteSignerApi, RethRpcModule, RpcModuleSelection};
+use secp256k1::SecretKey;
 
 use crate::args::{
     types::{MaxU32, ZeroAsNoneU64},
@@ -112,6 +116,13 @@ pub struct RpcServerArgs {
     #[arg(long = "auth-ipc.path", default_value_t = constants::DEFAULT_ENGINE_API_IPC_ENDPOINT.to_string())]
     pub auth_ipc_path: String,
 
+    /// Serve the builder API (`builder_getHeader` and `builder_getPayload`) on the authenticated
+    /// engine-API RPC server, so that relays can source blocks from the local payload builder.
+    ///
+    /// Requires reth to be built with the `builder-api` feature.
+    #[arg(long = "authrpc.builder-api")]
+    pub auth_builder_api: bool,
+
     /// Hex encoded JWT secret to authenticate the regular RPC server(s), see `--http.api` and
     /// `--ws.api`.
     ///
@@ -128,6 +139,14 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.max-response-size", alias = "rpc-max-response-size", visible_alias = "rpc.returndata.limit", default_value_t = RPC_DEFAULT_MAX_RESPONSE_SIZE_MB.into())]
     pub rpc_max_response_size: MaxU32,
 
+    /// Set the approximate memory budget of a single RPC request for HTTP, WS and IPC in
+    /// megabytes.
+    ///
+    /// Requests that allocate more, e.g. for the traces of `debug_` and `trace_` calls, are
+    /// aborted with an error. Disabled by default.
+    #[arg(long = "rpc.max-request-memory", value_name = "MB")]
+    pub rpc_max_request_memory: Option<u32>,
+
     /// Set the maximum concurrent subscriptions per connection.
     #[arg(long = "rpc.max-subscriptions-per-connection", alias = "rpc-max-subscriptions-per-connection", default_value_t = RPC_DEFAULT_MAX_SUBS_PER_CONN.into())]
     pub rpc_max_subscriptions_per_connection: MaxU32,
@@ -145,6 +164,36 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.max-tracing-requests", alias = "rpc-max-tracing-requests", value_name = "COUNT", default_value_t = constants::default_max_tracing_requests())]
     pub rpc_max_tracing_requests: usize,
 
+    /// Maximum number of `debug_` and `trace_` calls to the HTTP, WS and IPC servers that are
+    /// processed concurrently.
+    ///
+    /// Enables the tracing queue: further calls wait in a bounded queue, so tracing load can't
+    /// starve the `eth_` namespace. Calls that are rejected by the queue report their position in
+    /// the error. Disabled by default.
+    #[arg(long = "rpc.tracing-queue.max-concurrent", value_name = "COUNT")]
+    pub rpc_tracing_queue_max_concurrent: Option<usize>,
+
+    /// Maximum number of calls waiting in the tracing queue, further calls are rejected.
+    #[arg(long = "rpc.tracing-queue.max-queued", value_name = "COUNT", default_value_t = constants::DEFAULT_TRACING_QUEUE_MAX_QUEUED)]
+    pub rpc_tracing_queue_max_queued: usize,
+
+    /// Maximum number of tracing calls of a single client that are processed concurrently.
+    ///
+    /// Clients are identified by their API key, see `--rpc.api-keys`. Calls without an API key
+    /// count as a single client. Waiting calls of clients with fewer running calls are processed
+    /// first.
+    #[arg(long = "rpc.tracing-queue.max-per-client", value_name = "COUNT")]
+    pub rpc_tracing_queue_max_per_client: Option<usize>,
+
+    /// Maximum time a call waits in the tracing queue before it's rejected.
+    #[arg(
+        long = "rpc.tracing-queue.timeout",
+        value_name = "DURATION",
+        default_value = "30s",
+        value_parser = humantime::parse_duration
+    )]
+    pub rpc_tracing_queue_timeout: Duration,
+
     /// Maximum number of blocks that could be scanned per filter request. (0 = entire chain)
     #[arg(long = "rpc.max-blocks-per-filter", alias = "rpc-max-blocks-per-filter", value_name = "COUNT", default_value_t = ZeroAsNoneU64::new(constants::DEFAULT_MAX_BLOCKS_PER_FILTER))]
     pub rpc_max_blocks_per_filter: ZeroAsNoneU64,
@@ -171,6 +220,25 @@ pub struct RpcServerArgs {
     )]
     pub rpc_max_simulate_blocks: u64,
 
+    /// Enforce the EIP-3607 check in `eth_call` and `eth_estimateGas`, which rejects calls from
+    /// senders with deployed code.
+    #[arg(long = "rpc.call-enforce-eip3607")]
+    pub rpc_call_enforce_eip3607: bool,
+
+    /// Enforce the base fee checks in `eth_call` and `eth_estimateGas`.
+    #[arg(long = "rpc.call-enforce-base-fee")]
+    pub rpc_call_enforce_base_fee: bool,
+
+    /// Base fee to use for `eth_call` and `eth_estimateGas` instead of the base fee of the block
+    /// the call is executed on.
+    #[arg(long = "rpc.call-base-fee", value_name = "WEI")]
+    pub rpc_call_base_fee: Option<u64>,
+
+    /// Execute `eth_call` and `eth_estimateGas` requests with a gas price of zero with a base fee
+    /// of zero, so that they pass the enforced base fee checks.
+    #[arg(long = "rpc.call-allow-zero-gas-price", requires = "rpc_call_enforce_base_fee")]
+    pub rpc_call_allow_zero_gas_price: bool,
+
     /// The maximum proof window for historical proof generation.
     /// This value allows for generating historical proofs up to
     /// configured number of blocks from current tip (up to `tip - window`).
@@ -185,11 +253,119 @@ pub struct RpcServerArgs {
     #[arg(long = "rpc.proof-permits", alias = "rpc-proof-permits", value_name = "COUNT", default_value_t = constants::DEFAULT_PROOF_PERMITS)]
     pub rpc_proof_permits: usize,
 
+    /// Report reth specific failures, e.g. pruned state or blocks that are not synced yet, with
+    /// structured error codes and data payloads instead of the generic JSON-RPC error codes.
+    #[arg(long = "rpc.structured-errors")]
+    pub rpc_structured_errors: bool,
+
+    /// How transactions that are not in the transaction hash index, because it was pruned with
+    /// `--prune.transactionlookup.*`, are looked up by hash: `disabled` reports them as not
+    /// found and `scan` scans a bounded number of blocks below the horizon of the index.
+    #[arg(long = "rpc.tx-lookup-fallback", value_name = "MODE", default_value_t = TxLookupFallback::Disabled)]
+    pub rpc_tx_lookup_fallback: TxLookupFallback,
+
+    /// Maximum number of blocks below the horizon of the transaction hash index that are scanned
+    /// for a transaction with `--rpc.tx-lookup-fallback scan`, at most 10000.
+    #[arg(
+        long = "rpc.tx-lookup-max-scan-blocks",
+        value_name = "BLOCKS",
+        default_value_t = constants::DEFAULT_TX_LOOKUP_MAX_SCAN_BLOCKS,
+        value_parser = clap::value_parser!(u64).range(..=constants::MAX_TX_LOOKUP_SCAN_BLOCKS)
+    )]
+    pub rpc_tx_lookup_max_scan_blocks: u64,
+
+    /// Number of blocks below the tip after which a block is assumed to be final.
+    ///
+    /// Used by log filters and the RPC cache. Defaults to the max reorg depth of the chain.
+    #[arg(long = "rpc.max-reorg-depth", value_name = "BLOCKS")]
+    pub rpc_max_reorg_depth: Option<u64>,
+
+    /// Chain id reported by `eth_chainId` and `net_version` instead of the chain id of the
+    /// executed chain, e.g. for dev nodes forked from another chain.
+    ///
+    /// Raw transactions signed for this chain id are rejected, they have to be signed for the
+    /// executed chain. Transactions signed by the node always use the executed chain id.
+    #[arg(long = "rpc.chain-id", value_name = "CHAIN_ID")]
+    pub rpc_chain_id: Option<u64>,
+
+    /// Include the detail of the stage the pipeline is running in `eth_syncingDetail`: the current
+    /// stage, its checkpoint, the entities processed and total, and the estimated time until the
+    /// stage is done.
+    ///
+    /// The standard fields of `eth_syncing` are kept, the stage detail is added next to them.
+    /// `eth_syncing` itself always returns the standard response.
+    #[arg(long = "rpc.syncing-stage-detail")]
+    pub rpc_syncing_stage_detail: bool,
+
+    /// Directory `debug_traceTransactionToFile` writes traces to.
+    ///
+    /// Defaults to `<DIR>/<CHAIN_ID>/traces`.
+    #[arg(long = "rpc.trace-dir", value_name = "PATH")]
+    pub rpc_trace_dir: Option<PathBuf>,
+
+    /// Maximum total size of the traces in the trace directory in megabytes.
+    ///
+    /// The oldest traces are removed once a new trace exceeds it, a trace that doesn't fit on its
+    /// own is rejected.
+    #[arg(long = "rpc.trace-dir-max-size", value_name = "MB", default_value_t = constants::DEFAULT_TRACE_DIR_MAX_SIZE_MB)]
+    pub rpc_trace_dir_max_size: u64,
+
+    /// Path to a JSON file with the API keys accepted by the HTTP and WS servers, including
+    /// their rate limits and allowed methods. The file is reloaded when it changes.
+    #[arg(long = "rpc.api-keys", value_name = "PATH")]
+    pub rpc_api_keys: Option<PathBuf>,
+
     /// Path to file containing disallowed addresses, json-encoded list of strings. Block
     /// validation API will reject blocks containing transactions from these addresses.
     #[arg(long = "builder.disallow", value_name = "PATH", value_parser = reth_cli_util::parsers::read_json_from_file::<HashSet<Address>>)]
     pub builder_disallow: Option<HashSet<Address>>,
 
+    /// Endpoint of a remote signer whose accounts back `eth_accounts`, `eth_sign` and
+    /// `eth_sendTransaction`: an HTTP URL or the path to an IPC socket.
+    #[arg(long = "rpc.remote-signer", value_name = "URL_OR_PATH")]
+    pub rpc_remote_signer: Option<String>,
+
+    /// The API of the remote signer.
+    #[arg(
+        long = "rpc.remote-signer.api",
+        value_name = "API",
+        default_value_t = RemoteSignerApi::Web3Signer,
+        requires = "rpc_remote_signer"
+    )]
+    pub rpc_remote_signer_api: RemoteSignerApi,
+
+    /// Path to a JSON file with the signing policies of the remote signer accounts: allowed
+    /// recipients, contract creation, value and gas limits, and message signing.
+    #[arg(
+        long = "rpc.remote-signer.policies",
+        value_name = "PATH",
+        requires = "rpc_remote_signer"
+    )]
+    pub rpc_remote_signer_policies: Option<PathBuf>,
+
+    /// Hardware wallet whose account backs `eth_accounts`, `eth_sign` and `eth_sendTransaction`.
+    ///
+    /// Every signature has to be confirmed on the device. Requires reth to be built with the
+    /// `ledger` or `trezor` feature.
+    #[arg(long = "rpc.hardware-wallet", value_name = "WALLET", value_parser = ["ledger", "trezor"])]
+    pub rpc_hardware_wallet: Option<String>,
+
+    /// Index of the hardware wallet account in the Ledger Live or Trezor Live derivation path.
+    #[arg(
+        long = "rpc.hardware-wallet.index",
+        value_name = "INDEX",
+        default_value_t = 0,
+        requires = "rpc_hardware_wallet"
+    )]
+    pub rpc_hardware_wallet_index: usize,
+
+    /// Path to the secret key that signs the commitments of `reth_preconfirmTransaction`. A new
+    /// key is generated if the file doesn't exist.
+    ///
+    /// Requires reth to be built with the `preconf` feature.
+    #[arg(long = "rpc.preconf-key", value_name = "PATH")]
+    pub rpc_preconf_key: Option<PathBuf>,
+
     /// State cache configuration.
     #[command(flatten)]
     pub rpc_state_cache: RpcStateCacheArgs,
@@ -200,6 +376,11 @@ pub struct RpcServerArgs {
 }
 
 impl RpcServerArgs {
+    /// Returns the secret key that signs transaction preconfirmations, if configured.
+    pub fn preconf_secret_key(&self) -> Result<Option<SecretKey>, SecretKeyError> {
+        self.rpc_preconf_key.as_deref().map(get_secret_key).transpose()
+    }
+
     /// Enables the HTTP-RPC server.
     pub const fn with_http(mut self) -> Self {
         self.http = true;
@@ -317,21 +498,46 @@ impl Default for RpcServerArgs {
             auth_jwtsecret: None,
             auth_ipc: false,
             auth_ipc_path: constants::DEFAULT_ENGINE_API_IPC_ENDPOINT.to_string(),
+            auth_builder_api: false,
             rpc_jwtsecret: None,
             rpc_max_request_size: RPC_DEFAULT_MAX_REQUEST_SIZE_MB.into(),
             rpc_max_response_size: RPC_DEFAULT_MAX_RESPONSE_SIZE_MB.into(),
+            rpc_max_request_memory: None,
             rpc_max_subscriptions_per_connection: RPC_DEFAULT_MAX_SUBS_PER_CONN.into(),
             rpc_max_connections: RPC_DEFAULT_MAX_CONNECTIONS.into(),
             rpc_max_tracing_requests: constants::default_max_tracing_requests(),
+            rpc_tracing_queue_max_concurrent: None,
+            rpc_tracing_queue_max_queued: constants::DEFAULT_TRACING_QUEUE_MAX_QUEUED,
+            rpc_tracing_queue_max_per_client: None,
+            rpc_tracing_queue_timeout: Duration::from_secs(30),
             rpc_max_blocks_per_filter: constants::DEFAULT_MAX_BLOCKS_PER_FILTER.into(),
             rpc_max_logs_per_response: (constants::DEFAULT_MAX_LOGS_PER_RESPONSE as u64).into(),
             rpc_gas_cap: constants::gas_oracle::RPC_DEFAULT_GAS_CAP,
             rpc_max_simulate_blocks: constants::DEFAULT_MAX_SIMULATE_BLOCKS,
+            rpc_call_enforce_eip3607: false,
+            rpc_call_enforce_base_fee: false,
+            rpc_call_base_fee: None,
+            rpc_call_allow_zero_gas_price: false,
             rpc_eth_proof_window: constants::DEFAULT_ETH_PROOF_WINDOW,
             gas_price_oracle: GasPriceOracleArgs::default(),
             rpc_state_cache: RpcStateCacheArgs::default(),
             rpc_proof_permits: constants::DEFAULT_PROOF_PERMITS,
+            rpc_structured_errors: false,
+            rpc_tx_lookup_fallback: TxLookupFallback::Disabled,
+            rpc_tx_lookup_max_scan_blocks: constants::DEFAULT_TX_LOOKUP_MAX_SCAN_BLOCKS,
+            rpc_max_reorg_depth: None,
+            rpc_chain_id: None,
+            rpc_syncing_stage_detail: false,
+            rpc_trace_dir: None,
+            rpc_trace_dir_max_size: constants::DEFAULT_TRACE_DIR_MAX_SIZE_MB,
+            rpc_api_keys: None,
             builder_disallow: Default::default(),
+            rpc_remote_signer: None,
+            rpc_remote_signer_api: RemoteSignerApi::default(),
+            rpc_remote_signer_policies: None,
+            rpc_hardware_wallet: None,
+            rpc_hardware_wallet_index: 0,
+            rpc_preconf_key: None,
         }
     }
 }
@@ -412,6 +618,24 @@ mod tests {
         assert_eq!(apis, expected);
     }
 
+    #[test]
+    fn test_rpc_server_tracing_queue_args() {
+        let args = CommandParser::<RpcServerArgs>::parse_from([
+            "reth",
+            "--rpc.tracing-queue.max-concurrent",
+            "4",
+            "--rpc.tracing-queue.max-per-client",
+            "2",
+            "--rpc.tracing-queue.timeout",
+            "5s",
+        ])
+        .args;
+        assert_eq!(args.rpc_tracing_queue_max_concurrent, Some(4));
+        assert_eq!(args.rpc_tracing_queue_max_queued, constants::DEFAULT_TRACING_QUEUE_MAX_QUEUED);
+        assert_eq!(args.rpc_tracing_queue_max_per_client, Some(2));
+        assert_eq!(args.rpc_tracing_queue_timeout, Duration::from_secs(5));
+    }
+
     #[test]
     fn rpc_server_args_default_sanity_test() {
         let default_args = RpcServerArgs::default();
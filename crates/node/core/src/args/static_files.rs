@@ -0,0 +1,45 @@
+//! clap [Args](clap::Args) for static files
+
+use clap::Args;
+use humantime::parse_duration;
+use std::time::Duration;
+
+/// Parameters for the static files.
+#[derive(Debug, Clone, Copy, Default, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Static Files")]
+pub struct StaticFilesArgs {
+    /// Interval at which the sealed static files are verified against the checksums recorded when
+    /// they were written, e.g. `1d`.
+    ///
+    /// Verification reads every sealed static file, so the interval should be long. Corrupt static
+    /// files are logged as errors. Disabled by default.
+    #[arg(long = "static-files.verify-interval", value_name = "DURATION", value_parser = parse_duration)]
+    pub verify_interval: Option<Duration>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_static_files_args() {
+        let args = CommandParser::<StaticFilesArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, StaticFilesArgs::default());
+
+        let args = CommandParser::<StaticFilesArgs>::parse_from([
+            "reth",
+            "--static-files.verify-interval",
+            "1d",
+        ])
+        .args;
+        assert_eq!(args.verify_interval, Some(Duration::from_secs(24 * 60 * 60)));
+    }
+}
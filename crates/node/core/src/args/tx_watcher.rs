@@ -0,0 +1,98 @@
+//! clap [Args](clap::Args) for the transaction watcher
+
+use alloy_primitives::{Address, TxHash};
+use clap::Args;
+use url::Url;
+
+/// The default number of canonical blocks after which a watched transaction is confirmed.
+const CONFIRMATIONS_DEFAULT: u64 = 12;
+
+/// Parameters for the transaction watcher.
+#[derive(Debug, Clone, Args, PartialEq, Eq)]
+#[command(next_help_heading = "Transaction watcher")]
+pub struct TxWatcherArgs {
+    /// Enable the transaction watcher, which reports inclusion, confirmation and reorgs of
+    /// watched transactions to webhooks and `reth_transactionEvents` subscribers.
+    #[arg(id = "tx_watcher_enabled", long = "tx-watcher", default_value_t = false)]
+    pub enabled: bool,
+
+    /// Transaction hashes to watch from startup.
+    #[arg(long = "tx-watcher.transactions", value_name = "HASH", value_delimiter = ',')]
+    pub transactions: Vec<TxHash>,
+
+    /// Senders whose transactions are watched from startup.
+    #[arg(long = "tx-watcher.senders", value_name = "ADDRESS", value_delimiter = ',')]
+    pub senders: Vec<Address>,
+
+    /// URLs that every transaction event is posted to as JSON.
+    #[arg(long = "tx-watcher.webhooks", value_name = "URL", value_delimiter = ',')]
+    pub webhooks: Vec<Url>,
+
+    /// Number of canonical blocks, including the block of the transaction, after which a
+    /// watched transaction is confirmed.
+    #[arg(
+        long = "tx-watcher.confirmations",
+        value_name = "BLOCKS",
+        default_value_t = CONFIRMATIONS_DEFAULT,
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub confirmations: u64,
+}
+
+impl Default for TxWatcherArgs {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            transactions: Vec::new(),
+            senders: Vec::new(),
+            webhooks: Vec::new(),
+            confirmations: CONFIRMATIONS_DEFAULT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_default_tx_watcher_args() {
+        let default_args = TxWatcherArgs::default();
+        let args = CommandParser::<TxWatcherArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, default_args);
+    }
+
+    #[test]
+    fn test_parse_tx_watcher_args() {
+        let args = CommandParser::<TxWatcherArgs>::parse_from([
+            "reth",
+            "--tx-watcher",
+            "--tx-watcher.senders",
+            "0x0000000000000000000000000000000000000001,0x0000000000000000000000000000000000000002",
+            "--tx-watcher.webhooks",
+            "http://localhost:8080/events",
+            "--tx-watcher.confirmations",
+            "6",
+        ])
+        .args;
+        assert!(args.enabled);
+        assert_eq!(args.senders, vec![Address::with_last_byte(1), Address::with_last_byte(2)]);
+        assert_eq!(args.webhooks, vec!["http://localhost:8080/events".parse::<Url>().unwrap()]);
+        assert_eq!(args.confirmations, 6);
+
+        assert!(CommandParser::<TxWatcherArgs>::try_parse_from([
+            "reth",
+            "--tx-watcher.confirmations",
+            "0"
+        ])
+        .is_err());
+    }
+}
@@ -6,14 +6,15 @@ use alloy_primitives::Address;
 use clap::Args;
 use reth_cli_util::parse_duration_from_secs_or_ms;
 use reth_transaction_pool::{
-    blobstore::disk::DEFAULT_MAX_CACHED_BLOBS,
+    blobstore::{disk::DEFAULT_MAX_CACHED_BLOBS, BlobArchiveLocation},
     maintain::MAX_QUEUED_TRANSACTION_LIFETIME,
     pool::{NEW_TX_LISTENER_BUFFER_SIZE, PENDING_TX_LISTENER_BUFFER_SIZE},
     validate::DEFAULT_MAX_TX_INPUT_BYTES,
     LocalTransactionConfig, PoolConfig, PriceBumpConfig, SubPoolLimit, DEFAULT_PRICE_BUMP,
     DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS, MAX_NEW_PENDING_TXS_NOTIFICATIONS,
     REPLACE_BLOB_PRICE_BUMP, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
-    TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT, TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
+    TXPOOL_PENDING_BLOB_MAX_TXS_DEFAULT, TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
+    TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
 };
 use std::time::Duration;
 
@@ -21,6 +22,13 @@ use std::time::Duration;
 #[derive(Debug, Clone, Args, PartialEq, Eq)]
 #[command(next_help_heading = "TxPool")]
 pub struct TxPoolArgs {
+    /// Disables the transaction pool.
+    ///
+    /// All transactions submitted to the node are rejected, and payloads are built without
+    /// transactions from the pool.
+    #[arg(long = "no-txpool")]
+    pub disabled: bool,
+
     /// Max number of transaction in the pending sub-pool.
     #[arg(long = "txpool.pending-max-count", alias = "txpool.pending_max_count", default_value_t = TXPOOL_SUBPOOL_MAX_TXS_DEFAULT)]
     pub pending_max_count: usize,
@@ -49,6 +57,14 @@ pub struct TxPoolArgs {
     #[arg(long = "txpool.blobpool-max-size", alias = "txpool.blobpool_max_size", default_value_t = TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT)]
     pub blobpool_max_size: usize,
 
+    /// Max number of blob transactions in the pending sub-pool, evicted before any regular
+    /// pending transaction
+    #[arg(long = "txpool.pending-blob-max-count", default_value_t = TXPOOL_PENDING_BLOB_MAX_TXS_DEFAULT)]
+    pub pending_blob_max_count: usize,
+    /// Max size of the blob transactions in the pending sub-pool in megabytes.
+    #[arg(long = "txpool.pending-blob-max-size", default_value_t = TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT)]
+    pub pending_blob_max_size: usize,
+
     /// Max number of executable transaction slots guaranteed per account
     #[arg(long = "txpool.max-account-slots", alias = "txpool.max_account_slots", default_value_t = TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER)]
     pub max_account_slots: usize,
@@ -77,6 +93,16 @@ pub struct TxPoolArgs {
     #[arg(long = "txpool.max-cached-entries", alias = "txpool.max_cached_entries", default_value_t = DEFAULT_MAX_CACHED_BLOBS)]
     pub max_cached_entries: u32,
 
+    /// Exports blob sidecars to the given archive before they are removed from the blob store.
+    ///
+    /// Either a local directory, the `http(s)://` URL of an S3 compatible endpoint, or an
+    /// `s3://<bucket>/<prefix>` URL. Requests to an endpoint are signed with the AWS credentials
+    /// of the `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`, `AWS_SESSION_TOKEN` and
+    /// `AWS_REGION` environment variables, if set. Blobs left over from the previous run are
+    /// archived too. Archived blobs can be read with `reth blob get`.
+    #[arg(long = "txpool.blob-archive", value_name = "DIR_OR_URL")]
+    pub blob_archive: Option<BlobArchiveLocation>,
+
     /// Flag to disable local transaction exemptions.
     #[arg(long = "txpool.nolocals")]
     pub no_locals: bool,
@@ -111,6 +137,7 @@ pub struct TxPoolArgs {
 impl Default for TxPoolArgs {
     fn default() -> Self {
         Self {
+            disabled: false,
             pending_max_count: TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
             pending_max_size: TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
             basefee_max_count: TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
@@ -119,6 +146,8 @@ impl Default for TxPoolArgs {
             queued_max_size: TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
             blobpool_max_count: TXPOOL_SUBPOOL_MAX_TXS_DEFAULT,
             blobpool_max_size: TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
+            pending_blob_max_count: TXPOOL_PENDING_BLOB_MAX_TXS_DEFAULT,
+            pending_blob_max_size: TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT,
             max_account_slots: TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
             price_bump: DEFAULT_PRICE_BUMP,
             minimal_protocol_basefee: MIN_PROTOCOL_BASE_FEE,
@@ -126,6 +155,7 @@ impl Default for TxPoolArgs {
             blob_transaction_price_bump: REPLACE_BLOB_PRICE_BUMP,
             max_tx_input_bytes: DEFAULT_MAX_TX_INPUT_BYTES,
             max_cached_entries: DEFAULT_MAX_CACHED_BLOBS,
+            blob_archive: None,
             no_locals: false,
             locals: Default::default(),
             no_local_transactions_propagation: false,
@@ -163,6 +193,10 @@ impl RethTransactionPoolConfig for TxPoolArgs {
                 max_txs: self.blobpool_max_count,
                 max_size: self.blobpool_max_size.saturating_mul(1024 * 1024),
             },
+            pending_blob_limit: SubPoolLimit {
+                max_txs: self.pending_blob_max_count,
+                max_size: self.pending_blob_max_size.saturating_mul(1024 * 1024),
+            },
             max_account_slots: self.max_account_slots,
             price_bumps: PriceBumpConfig {
                 default_price_bump: self.price_bump,
@@ -197,6 +231,12 @@ mod tests {
         assert_eq!(args, default_args);
     }
 
+    #[test]
+    fn txpool_parse_disabled() {
+        let args = CommandParser::<TxPoolArgs>::parse_from(["reth", "--no-txpool"]).args;
+        assert!(args.disabled);
+    }
+
     #[test]
     fn txpool_parse_locals() {
         let args = CommandParser::<TxPoolArgs>::parse_from([
@@ -0,0 +1,46 @@
+//! WASM plugin arguments
+
+use clap::Args;
+use std::path::PathBuf;
+
+/// Parameters for loading WASM plugins.
+///
+/// Plugins are only run if the node was built with the `wasm-plugins` feature.
+#[derive(Debug, Clone, Default, Args, PartialEq, Eq)]
+#[command(next_help_heading = "WASM plugins")]
+pub struct WasmPluginArgs {
+    /// Path to the manifest of a WASM plugin to load.
+    ///
+    /// Can be specified multiple times.
+    #[arg(long = "wasm.plugin", value_name = "MANIFEST")]
+    pub plugins: Vec<PathBuf>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    /// A helper type to parse Args more easily
+    #[derive(Parser)]
+    struct CommandParser<T: Args> {
+        #[command(flatten)]
+        args: T,
+    }
+
+    #[test]
+    fn test_parse_wasm_plugin_args() {
+        let args = CommandParser::<WasmPluginArgs>::parse_from(["reth"]).args;
+        assert_eq!(args, WasmPluginArgs::default());
+
+        let args = CommandParser::<WasmPluginArgs>::parse_from([
+            "reth",
+            "--wasm.plugin",
+            "indexer.toml",
+            "--wasm.plugin",
+            "alerts.toml",
+        ])
+        .args;
+        assert_eq!(args.plugins, vec![PathBuf::from("indexer.toml"), PathBuf::from("alerts.toml")]);
+    }
+}
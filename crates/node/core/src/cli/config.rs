@@ -2,6 +2,7 @@
 
 use alloy_primitives::Bytes;
 use reth_network::{protocol::IntoRlpxSubProtocol, NetworkPrimitives};
+use reth_payload_primitives::{ExtraDataTemplate, FeeRecipient};
 use reth_transaction_pool::PoolConfig;
 use std::{borrow::Cow, time::Duration};
 
@@ -29,6 +30,22 @@ pub trait PayloadBuilderConfig {
 
     /// Maximum number of tasks to spawn for building a payload.
     fn max_payload_tasks(&self) -> usize;
+
+    /// The maximum time a payload job spends building payloads, summed over all its build
+    /// attempts.
+    fn max_build_time(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Fee recipients that built blocks rotate through if no fee recipient is suggested.
+    fn fee_recipients(&self) -> &[FeeRecipient] {
+        &[]
+    }
+
+    /// Template of the extra data of built blocks, overriding [`Self::extra_data`].
+    fn extra_data_template(&self) -> Option<&ExtraDataTemplate> {
+        None
+    }
 }
 
 /// A trait that represents the configured network and can be used to apply additional configuration
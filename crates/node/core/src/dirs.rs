@@ -351,6 +351,28 @@ impl<D> ChainPath<D> {
         self.data_dir().join("invalid_block_hooks")
     }
 
+    /// Returns the path to the directory the engine's buffered blocks are persisted to.
+    ///
+    /// `<DIR>/<CHAIN_ID>/block_buffer`
+    pub fn block_buffer(&self) -> PathBuf {
+        self.data_dir().join("block_buffer")
+    }
+
+    /// Returns the path to the file the engine persists the access frequencies of accounts and
+    /// storage slots to.
+    ///
+    /// `<DIR>/<CHAIN_ID>/state-access.bin`
+    pub fn state_access(&self) -> PathBuf {
+        self.data_dir().join("state-access.bin")
+    }
+
+    /// Returns the path to the directory `debug_traceTransactionToFile` writes traces to.
+    ///
+    /// `<DIR>/<CHAIN_ID>/traces`
+    pub fn traces(&self) -> PathBuf {
+        self.data_dir().join("traces")
+    }
+
     /// Returns the path to the ExEx WAL directory for this chain.
     pub fn exex_wal(&self) -> PathBuf {
         self.data_dir().join("exex/wal")
@@ -2,18 +2,20 @@
 
 use crate::{
     args::{
-        DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EngineArgs, NetworkArgs, PayloadBuilderArgs,
-        PruningArgs, RpcServerArgs, TxPoolArgs,
+        AlertArgs, ContractIndexArgs, DatabaseArgs, DatadirArgs, DebugArgs, DevArgs, EngineArgs,
+        HeaderAccumulatorArgs, NetworkArgs, PayloadBuilderArgs, PipelineArgs, ProposerPaymentsArgs,
+        PruningArgs, RequestIndexArgs, RpcServerArgs, StaticFilesArgs, TxPoolArgs, TxWatcherArgs,
     },
     dirs::{ChainPath, DataDirPath},
     utils::get_single_header,
 };
 use alloy_consensus::BlockHeader;
-use alloy_eips::BlockHashOrNumber;
+use alloy_eips::{BlockHashOrNumber, BlockNumHash};
 use alloy_primitives::{BlockNumber, B256};
 use eyre::eyre;
 use reth_chainspec::{ChainSpec, EthChainSpec, MAINNET};
 use reth_config::config::PruneConfig;
+use reth_db::read_metrics::ReadMetricsConfig;
 use reth_ethereum_forks::Head;
 use reth_network_p2p::headers::client::HeadersClient;
 use reth_primitives_traits::SealedHeader;
@@ -39,6 +41,9 @@ pub const DEFAULT_PERSISTENCE_THRESHOLD: u64 = 2;
 /// Default size of cross-block cache in megabytes.
 pub const DEFAULT_CROSS_BLOCK_CACHE_SIZE_MB: u64 = 4 * 1024;
 
+/// Default maximum size of the persisted buffered blocks in megabytes.
+pub const DEFAULT_BLOCK_BUFFER_MAX_SIZE_MB: u64 = 512;
+
 /// This includes all necessary configuration to launch the node.
 /// The individual configuration options can be overwritten before launching the node.
 ///
@@ -144,6 +149,34 @@ pub struct NodeConfig<ChainSpec> {
 
     /// All engine related arguments
     pub engine: EngineArgs,
+
+    /// All transaction watcher related arguments with --tx-watcher prefix
+    pub tx_watcher: TxWatcherArgs,
+
+    /// All contract creation index related arguments with --contract-index prefix
+    pub contract_index: ContractIndexArgs,
+
+    /// All header accumulator related arguments with --header-accumulator prefix
+    pub header_accumulator: HeaderAccumulatorArgs,
+
+    /// All execution layer request index related arguments with --request-index prefix
+    pub request_index: RequestIndexArgs,
+
+    /// All proposer payment related arguments with --proposer-payments prefix
+    pub proposer_payments: ProposerPaymentsArgs,
+
+    /// All alert related arguments with --alerts prefix
+    pub alerts: AlertArgs,
+
+    /// All static file related arguments with --static-files prefix
+    pub static_files: StaticFilesArgs,
+
+    /// All pipeline related arguments with --pipeline prefix
+    pub pipeline: PipelineArgs,
+
+    /// Instrumentation settings of reads of the opened database, which can be changed while the
+    /// node is running.
+    pub database_read_metrics: Option<ReadMetricsConfig>,
 }
 
 impl NodeConfig<ChainSpec> {
@@ -173,6 +206,15 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             pruning: PruningArgs::default(),
             datadir: DatadirArgs::default(),
             engine: EngineArgs::default(),
+            tx_watcher: TxWatcherArgs::default(),
+            contract_index: ContractIndexArgs::default(),
+            header_accumulator: HeaderAccumulatorArgs::default(),
+            request_index: RequestIndexArgs::default(),
+            proposer_payments: ProposerPaymentsArgs::default(),
+            alerts: AlertArgs::default(),
+            static_files: StaticFilesArgs::default(),
+            pipeline: PipelineArgs::default(),
+            database_read_metrics: None,
         }
     }
 
@@ -273,6 +315,63 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
         self
     }
 
+    /// Set the transaction watcher args for the node
+    pub fn with_tx_watcher(mut self, tx_watcher: TxWatcherArgs) -> Self {
+        self.tx_watcher = tx_watcher;
+        self
+    }
+
+    /// Set the contract creation index args for the node
+    pub const fn with_contract_index(mut self, contract_index: ContractIndexArgs) -> Self {
+        self.contract_index = contract_index;
+        self
+    }
+
+    /// Set the header accumulator args for the node
+    pub const fn with_header_accumulator(
+        mut self,
+        header_accumulator: HeaderAccumulatorArgs,
+    ) -> Self {
+        self.header_accumulator = header_accumulator;
+        self
+    }
+
+    /// Set the execution layer request index args for the node
+    pub const fn with_request_index(mut self, request_index: RequestIndexArgs) -> Self {
+        self.request_index = request_index;
+        self
+    }
+
+    /// Set the proposer payment args for the node
+    pub const fn with_proposer_payments(mut self, proposer_payments: ProposerPaymentsArgs) -> Self {
+        self.proposer_payments = proposer_payments;
+        self
+    }
+
+    /// Set the alert args for the node
+    pub fn with_alerts(mut self, alerts: AlertArgs) -> Self {
+        self.alerts = alerts;
+        self
+    }
+
+    /// Set the static file args for the node
+    pub const fn with_static_files(mut self, static_files: StaticFilesArgs) -> Self {
+        self.static_files = static_files;
+        self
+    }
+
+    /// Set the pipeline args for the node
+    pub const fn with_pipeline(mut self, pipeline: PipelineArgs) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    /// Set the instrumentation settings of reads of the opened database
+    pub fn with_database_read_metrics(mut self, database_read_metrics: ReadMetricsConfig) -> Self {
+        self.database_read_metrics = Some(database_read_metrics);
+        self
+    }
+
     /// Returns pruning configuration.
     pub fn prune_config(&self) -> Option<PruneConfig>
     where
@@ -364,6 +463,49 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
         Ok(self.fetch_tip_from_network(client, tip.into()).await.number())
     }
 
+    /// Attempt to look up the block number and hash of the sync target in the database.
+    pub fn lookup_sync_target<Provider>(
+        &self,
+        provider: Provider,
+        target: BlockHashOrNumber,
+    ) -> ProviderResult<Option<BlockNumHash>>
+    where
+        Provider: HeaderProvider,
+    {
+        let num_hash = match target {
+            BlockHashOrNumber::Hash(hash) => {
+                provider.header(&hash)?.map(|header| BlockNumHash::new(header.number(), hash))
+            }
+            BlockHashOrNumber::Number(number) => {
+                provider.sealed_header(number)?.map(|header| header.num_hash())
+            }
+        };
+
+        Ok(num_hash)
+    }
+
+    /// Attempt to look up the block number and hash of the sync target in the database.
+    /// If it doesn't exist, download the header and return its number and hash.
+    ///
+    /// NOTE: The download is attempted with infinite retries.
+    pub async fn lookup_or_fetch_sync_target<Provider, Client>(
+        &self,
+        provider: Provider,
+        client: Client,
+        target: BlockHashOrNumber,
+    ) -> ProviderResult<BlockNumHash>
+    where
+        Provider: HeaderProvider,
+        Client: HeadersClient<Header: reth_primitives_traits::BlockHeader>,
+    {
+        if let Some(num_hash) = self.lookup_sync_target(provider, target)? {
+            info!(target: "reth::cli", ?target, "Successfully looked up sync target in the database");
+            return Ok(num_hash)
+        }
+
+        Ok(self.fetch_tip_from_network(client, target).await.num_hash())
+    }
+
     /// Attempt to look up the block with the given number and return the header.
     ///
     /// NOTE: The download is attempted with infinite retries.
@@ -464,6 +606,15 @@ impl<ChainSpec> NodeConfig<ChainSpec> {
             dev: self.dev,
             pruning: self.pruning,
             engine: self.engine,
+            tx_watcher: self.tx_watcher,
+            contract_index: self.contract_index,
+            header_accumulator: self.header_accumulator,
+            request_index: self.request_index,
+            proposer_payments: self.proposer_payments,
+            alerts: self.alerts,
+            static_files: self.static_files,
+            pipeline: self.pipeline,
+            database_read_metrics: self.database_read_metrics,
         }
     }
 }
@@ -491,6 +642,15 @@ impl<ChainSpec> Clone for NodeConfig<ChainSpec> {
             pruning: self.pruning.clone(),
             datadir: self.datadir.clone(),
             engine: self.engine.clone(),
+            tx_watcher: self.tx_watcher.clone(),
+            contract_index: self.contract_index,
+            header_accumulator: self.header_accumulator,
+            request_index: self.request_index,
+            proposer_payments: self.proposer_payments,
+            alerts: self.alerts.clone(),
+            static_files: self.static_files,
+            pipeline: self.pipeline,
+            database_read_metrics: self.database_read_metrics.clone(),
         }
     }
 }
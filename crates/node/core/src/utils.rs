@@ -23,11 +23,21 @@ pub fn parse_path(value: &str) -> Result<PathBuf, shellexpand::LookupError<VarEr
     shellexpand::full(value).map(|path| PathBuf::from(path.into_owned()))
 }
 
+/// Reads the JWT secret from the specified path.
+///
+/// If the file is a [`Keystore`](reth_cli_util::keystore::Keystore), the secret is decrypted with
+/// the password returned by [`keystore_password`](reth_cli_util::keystore::keystore_password).
+pub fn read_jwt_secret(path: &Path) -> Result<JwtSecret, JwtError> {
+    let secret = reth_cli_util::keystore::read_secret(path)
+        .map_err(|err| JwtError::Read { source: std::io::Error::other(err), path: path.into() })?;
+    JwtSecret::from_hex(secret)
+}
+
 /// Attempts to retrieve or create a JWT secret from the specified path.
 pub fn get_or_create_jwt_secret_from_path(path: &Path) -> Result<JwtSecret, JwtError> {
     if path.exists() {
         debug!(target: "reth::cli", ?path, "Reading JWT auth secret file");
-        JwtSecret::from_file(path)
+        read_jwt_secret(path)
     } else {
         info!(target: "reth::cli", ?path, "Creating JWT auth secret file");
         JwtSecret::try_create_random(path)
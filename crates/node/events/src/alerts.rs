@@ -0,0 +1,369 @@
+//! Alerts on node conditions that need the operator's attention.
+
+use futures::{Stream, StreamExt};
+use reqwest::Url;
+use reth_engine_primitives::BeaconConsensusEngineEvent;
+use reth_network_api::PeersInfo;
+use reth_primitives_traits::NodePrimitives;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use sysinfo::Disks;
+use tokio::{sync::mpsc, time::Instant};
+use tracing::{info, warn};
+
+/// Interval of checking the alert conditions.
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A condition the [`AlertMonitor`] raises an [`Alert`] for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, derive_more::Display)]
+#[serde(rename_all = "camelCase")]
+pub enum AlertKind {
+    /// No forkchoice update was received for a while.
+    #[display("no forkchoice update")]
+    NoForkchoiceUpdate,
+    /// Several invalid payloads were received in a short period.
+    #[display("invalid payloads")]
+    InvalidPayloads,
+    /// The number of connected peers is low.
+    #[display("low peer count")]
+    LowPeerCount,
+    /// The disk of the data directory is nearly full.
+    #[display("low disk space")]
+    LowDiskSpace,
+}
+
+/// An alert raised by the [`AlertMonitor`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Alert {
+    /// The condition that raised the alert.
+    pub kind: AlertKind,
+    /// A human readable description of the condition.
+    pub message: String,
+}
+
+/// Configuration of the [`AlertMonitor`].
+///
+/// Conditions with a zero threshold are not checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertsConfig {
+    /// Period without forkchoice updates after which an alert is raised.
+    pub forkchoice_update_timeout: Duration,
+    /// Number of invalid payloads within [`Self::invalid_payloads_window`] that raises an alert.
+    pub invalid_payloads: usize,
+    /// Period in which invalid payloads are counted.
+    pub invalid_payloads_window: Duration,
+    /// Number of connected peers below which an alert is raised.
+    pub min_peers: usize,
+    /// Path on the disk that is checked for free space.
+    pub disk_path: PathBuf,
+    /// Free disk space in bytes below which an alert is raised.
+    pub min_free_disk_space: u64,
+    /// Period in which an alert whose condition persists is not raised again.
+    pub repeat_interval: Duration,
+    /// URLs that every alert is posted to as JSON.
+    pub webhooks: Vec<Url>,
+}
+
+/// Monitors the node and raises an [`Alert`] when one of the configured conditions is met.
+///
+/// Alerts are logged and posted to the configured webhooks. An alert is raised again only after
+/// [`AlertsConfig::repeat_interval`] if its condition persists, and is logged as resolved once the
+/// condition clears.
+pub struct AlertMonitor {
+    config: AlertsConfig,
+    peers_info: Option<Box<dyn PeersInfo>>,
+    /// When the last forkchoice update was received, or the monitor was created.
+    last_forkchoice_update: Instant,
+    /// When the invalid payloads within the window were received.
+    invalid_payloads: VecDeque<Instant>,
+    /// When each alert whose condition persists was last raised.
+    raised: HashMap<AlertKind, Instant>,
+}
+
+impl fmt::Debug for AlertMonitor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AlertMonitor")
+            .field("config", &self.config)
+            .field("last_forkchoice_update", &self.last_forkchoice_update)
+            .field("raised", &self.raised)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AlertMonitor {
+    /// Creates a new [`AlertMonitor`] with the given configuration.
+    pub fn new(config: AlertsConfig, peers_info: Option<Box<dyn PeersInfo>>) -> Self {
+        Self {
+            config,
+            peers_info,
+            last_forkchoice_update: Instant::now(),
+            invalid_payloads: VecDeque::new(),
+            raised: HashMap::new(),
+        }
+    }
+
+    /// Runs the monitor until the engine event stream ends.
+    pub async fn run<N: NodePrimitives>(
+        mut self,
+        engine_events: impl Stream<Item = BeaconConsensusEngineEvent<N>> + Unpin,
+    ) {
+        let webhooks = std::mem::take(&mut self.config.webhooks);
+        let (to_webhooks, alerts) = mpsc::unbounded_channel();
+        let has_webhooks = !webhooks.is_empty();
+
+        let monitor = async move {
+            let mut engine_events = engine_events.fuse();
+            let mut interval = tokio::time::interval(CHECK_INTERVAL);
+            loop {
+                let alerts = tokio::select! {
+                    _ = interval.tick() => {
+                        let free_disk_space = (self.config.min_free_disk_space > 0)
+                            .then(|| free_disk_space(&self.config.disk_path))
+                            .flatten();
+                        self.check(Instant::now(), free_disk_space)
+                    }
+                    event = engine_events.next() => match event {
+                        Some(event) => self.on_engine_event(&event, Instant::now()).into_iter().collect(),
+                        None => break,
+                    },
+                };
+
+                for alert in alerts {
+                    warn!(target: "reth::alerts", kind = %alert.kind, "{}", alert.message);
+                    if has_webhooks {
+                        let _ = to_webhooks.send(alert);
+                    }
+                }
+            }
+        };
+
+        tokio::join!(monitor, deliver_webhooks(webhooks, alerts));
+    }
+
+    /// Records forkchoice updates and invalid payloads.
+    ///
+    /// Returns an alert if the number of recent invalid payloads reached the threshold.
+    fn on_engine_event<N: NodePrimitives>(
+        &mut self,
+        event: &BeaconConsensusEngineEvent<N>,
+        now: Instant,
+    ) -> Option<Alert> {
+        match event {
+            BeaconConsensusEngineEvent::ForkchoiceUpdated(..) => {
+                self.last_forkchoice_update = now;
+                None
+            }
+            BeaconConsensusEngineEvent::InvalidBlock(_) => {
+                self.invalid_payloads.push_back(now);
+                let condition = self.invalid_payloads_condition(now);
+                self.evaluate(AlertKind::InvalidPayloads, condition, now)
+            }
+            _ => None,
+        }
+    }
+
+    /// Checks all conditions and returns the alerts that are raised.
+    fn check(&mut self, now: Instant, free_disk_space: Option<u64>) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+
+        let since_forkchoice_update = now.duration_since(self.last_forkchoice_update);
+        let condition = (!self.config.forkchoice_update_timeout.is_zero() &&
+            since_forkchoice_update > self.config.forkchoice_update_timeout)
+            .then(|| {
+                format!(
+                    "No forkchoice update received for {}",
+                    humantime::format_duration(Duration::from_secs(
+                        since_forkchoice_update.as_secs()
+                    ))
+                )
+            });
+        alerts.extend(self.evaluate(AlertKind::NoForkchoiceUpdate, condition, now));
+
+        let condition = self.invalid_payloads_condition(now);
+        alerts.extend(self.evaluate(AlertKind::InvalidPayloads, condition, now));
+
+        let peers = self.peers_info.as_ref().map(|info| info.num_connected_peers());
+        let condition = peers.filter(|peers| *peers < self.config.min_peers).map(|peers| {
+            format!("Connected to {peers} peers, below the minimum of {}", self.config.min_peers)
+        });
+        alerts.extend(self.evaluate(AlertKind::LowPeerCount, condition, now));
+
+        let condition =
+            free_disk_space.filter(|free| *free < self.config.min_free_disk_space).map(|free| {
+                format!(
+                    "{} MB of disk space left at {}, below the minimum of {} MB",
+                    free / 1024 / 1024,
+                    self.config.disk_path.display(),
+                    self.config.min_free_disk_space / 1024 / 1024
+                )
+            });
+        alerts.extend(self.evaluate(AlertKind::LowDiskSpace, condition, now));
+
+        alerts
+    }
+
+    /// Drops invalid payloads outside of the window and returns the alert message if the
+    /// threshold is reached.
+    fn invalid_payloads_condition(&mut self, now: Instant) -> Option<String> {
+        while self.invalid_payloads.front().is_some_and(|received| {
+            now.duration_since(*received) > self.config.invalid_payloads_window
+        }) {
+            self.invalid_payloads.pop_front();
+        }
+
+        (self.config.invalid_payloads > 0 &&
+            self.invalid_payloads.len() >= self.config.invalid_payloads)
+            .then(|| {
+                format!(
+                    "Received {} invalid payloads within {}",
+                    self.invalid_payloads.len(),
+                    humantime::format_duration(self.config.invalid_payloads_window)
+                )
+            })
+    }
+
+    /// Returns an alert if the condition is met and the alert wasn't raised recently.
+    fn evaluate(
+        &mut self,
+        kind: AlertKind,
+        condition: Option<String>,
+        now: Instant,
+    ) -> Option<Alert> {
+        let Some(message) = condition else {
+            if self.raised.remove(&kind).is_some() {
+                info!(target: "reth::alerts", %kind, "Alert resolved");
+            }
+            return None
+        };
+
+        if self
+            .raised
+            .get(&kind)
+            .is_some_and(|raised| now.duration_since(*raised) < self.config.repeat_interval)
+        {
+            return None
+        }
+
+        self.raised.insert(kind, now);
+        Some(Alert { kind, message })
+    }
+}
+
+/// Returns the available space of the disk that contains the path.
+fn free_disk_space(path: &Path) -> Option<u64> {
+    let path = path.canonicalize().ok()?;
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .list()
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .map(|disk| disk.available_space())
+}
+
+/// Posts every received alert to all webhooks, in order.
+async fn deliver_webhooks(webhooks: Vec<Url>, mut alerts: mpsc::UnboundedReceiver<Alert>) {
+    let client = reqwest::Client::new();
+    while let Some(alert) = alerts.recv().await {
+        for url in &webhooks {
+            let res = client
+                .post(url.clone())
+                .json(&alert)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+            if let Err(err) = res {
+                warn!(target: "reth::alerts", %url, kind = %alert.kind, %err, "Failed to deliver alert");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_engine_primitives::ForkchoiceStatus;
+    use reth_ethereum_primitives::EthPrimitives;
+    use reth_primitives_traits::SealedBlock;
+
+    fn config() -> AlertsConfig {
+        AlertsConfig {
+            forkchoice_update_timeout: Duration::from_secs(120),
+            invalid_payloads: 2,
+            invalid_payloads_window: Duration::from_secs(60),
+            min_peers: 0,
+            disk_path: PathBuf::new(),
+            min_free_disk_space: 1024 * 1024 * 1024,
+            repeat_interval: Duration::from_secs(600),
+            webhooks: Vec::new(),
+        }
+    }
+
+    fn kinds(alerts: &[Alert]) -> Vec<AlertKind> {
+        alerts.iter().map(|alert| alert.kind).collect()
+    }
+
+    #[tokio::test]
+    async fn deduplicates_alerts() {
+        let mut monitor = AlertMonitor::new(config(), None);
+        let start = monitor.last_forkchoice_update;
+
+        assert!(monitor.check(start + Duration::from_secs(60), None).is_empty());
+
+        let now = start + Duration::from_secs(121);
+        assert_eq!(kinds(&monitor.check(now, None)), vec![AlertKind::NoForkchoiceUpdate]);
+        assert!(monitor.check(now + Duration::from_secs(10), None).is_empty());
+        assert_eq!(
+            kinds(&monitor.check(now + Duration::from_secs(600), None)),
+            vec![AlertKind::NoForkchoiceUpdate]
+        );
+
+        // the condition clears and the alert is raised again right away once it reappears
+        let now = now + Duration::from_secs(610);
+        monitor.on_engine_event::<EthPrimitives>(
+            &BeaconConsensusEngineEvent::ForkchoiceUpdated(
+                Default::default(),
+                ForkchoiceStatus::Valid,
+            ),
+            now,
+        );
+        assert!(monitor.check(now, None).is_empty());
+        assert_eq!(
+            kinds(&monitor.check(now + Duration::from_secs(121), None)),
+            vec![AlertKind::NoForkchoiceUpdate]
+        );
+    }
+
+    #[tokio::test]
+    async fn alerts_on_repeated_invalid_payloads() {
+        let mut monitor = AlertMonitor::new(config(), None);
+        let start = monitor.last_forkchoice_update;
+        let invalid = BeaconConsensusEngineEvent::<EthPrimitives>::InvalidBlock(Box::new(
+            SealedBlock::default(),
+        ));
+
+        assert_eq!(monitor.on_engine_event(&invalid, start), None);
+        // outside of the window
+        assert_eq!(monitor.on_engine_event(&invalid, start + Duration::from_secs(61)), None);
+        let alert = monitor.on_engine_event(&invalid, start + Duration::from_secs(62)).unwrap();
+        assert_eq!(alert.kind, AlertKind::InvalidPayloads);
+        assert_eq!(monitor.on_engine_event(&invalid, start + Duration::from_secs(63)), None);
+    }
+
+    #[tokio::test]
+    async fn alerts_on_low_disk_space() {
+        let mut monitor = AlertMonitor::new(config(), None);
+        let start = monitor.last_forkchoice_update;
+
+        assert!(monitor.check(start, Some(2 * 1024 * 1024 * 1024)).is_empty());
+        assert_eq!(
+            kinds(&monitor.check(start, Some(512 * 1024 * 1024))),
+            vec![AlertKind::LowDiskSpace]
+        );
+    }
+}
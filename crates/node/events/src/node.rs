@@ -6,7 +6,8 @@ use alloy_primitives::{BlockNumber, B256};
 use alloy_rpc_types_engine::ForkchoiceState;
 use futures::Stream;
 use reth_engine_primitives::{
-    BeaconConsensusEngineEvent, ConsensusEngineLiveSyncProgress, ForkchoiceStatus,
+    BackfillProgress, BackfillSyncStatus, BeaconConsensusEngineEvent,
+    ConsensusEngineLiveSyncProgress, ForkchoiceStatus,
 };
 use reth_network_api::PeersInfo;
 use reth_primitives_traits::{format_gas, format_gas_throughput, BlockBody, NodePrimitives};
@@ -23,8 +24,8 @@ use std::{
 use tokio::time::Interval;
 use tracing::{debug, info, warn};
 
-/// Interval of reporting node state.
-const INFO_MESSAGE_INTERVAL: Duration = Duration::from_secs(25);
+/// Default interval of reporting node state.
+pub const INFO_MESSAGE_INTERVAL: Duration = Duration::from_secs(25);
 
 /// The current high-level state of the node, including the node's database environment, network
 /// connections, current processing stage, and the latest block information. It provides
@@ -67,6 +68,21 @@ impl NodeState {
         self.peers_info.as_ref().map(|info| info.num_connected_peers()).unwrap_or_default()
     }
 
+    /// Returns the progress of the running backfill sync, if any.
+    fn backfill_status(&self) -> Option<BackfillSyncStatus> {
+        let CurrentStage { stage_id, eta, checkpoint, entities_checkpoint, target } =
+            self.current_stage.as_ref()?;
+        Some(BackfillSyncStatus {
+            stage: stage_id.to_string(),
+            checkpoint: checkpoint.block_number,
+            target: *target,
+            stage_progress: entities_checkpoint
+                .filter(|entities| entities.total > 0)
+                .map(|entities| 100.0 * entities.processed as f64 / entities.total as f64),
+            stage_eta: eta.remaining_for_stage(*stage_id),
+        })
+    }
+
     fn build_current_stage(
         &self,
         stage_id: StageId,
@@ -384,14 +400,35 @@ pub async fn handle_events<E, N: NodePrimitives>(
     events: E,
 ) where
     E: Stream<Item = NodeEvent<N>> + Unpin,
+{
+    handle_events_with_progress(
+        peers_info,
+        latest_block_number,
+        events,
+        INFO_MESSAGE_INTERVAL,
+        None,
+    )
+    .await
+}
+
+/// Like [`handle_events`], but displays the status at the given interval, and publishes the
+/// progress of the backfill sync to the given handle at the same interval.
+pub async fn handle_events_with_progress<E, N: NodePrimitives>(
+    peers_info: Option<Box<dyn PeersInfo>>,
+    latest_block_number: Option<BlockNumber>,
+    events: E,
+    status_interval: Duration,
+    backfill_progress: Option<BackfillProgress>,
+) where
+    E: Stream<Item = NodeEvent<N>> + Unpin,
 {
     let state = NodeState::new(peers_info, latest_block_number);
 
     let start = tokio::time::Instant::now() + Duration::from_secs(3);
-    let mut info_interval = tokio::time::interval_at(start, INFO_MESSAGE_INTERVAL);
+    let mut info_interval = tokio::time::interval_at(start, status_interval);
     info_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
 
-    let handler = EventHandler { state, events, info_interval };
+    let handler = EventHandler { state, events, info_interval, backfill_progress };
     handler.await
 }
 
@@ -403,6 +440,8 @@ struct EventHandler<E> {
     events: E,
     #[pin]
     info_interval: Interval,
+    /// Handle that the progress of the backfill sync is published to.
+    backfill_progress: Option<BackfillProgress>,
 }
 
 impl<E, N: NodePrimitives> Future for EventHandler<E>
@@ -415,6 +454,10 @@ where
         let mut this = self.project();
 
         while this.info_interval.poll_tick(cx).is_ready() {
+            if let Some(backfill_progress) = this.backfill_progress.as_ref() {
+                backfill_progress.update(this.state.backfill_status());
+            }
+
             if let Some(CurrentStage { stage_id, eta, checkpoint, entities_checkpoint, target }) =
                 &this.state.current_stage
             {
@@ -565,19 +608,29 @@ impl Eta {
         self.eta.zip(self.last_checkpoint_time).is_some()
     }
 
+    /// Returns `true` if the ETA is available and the progress of the stage is predictable, see
+    /// [`Self::fmt_for_stage`].
+    fn is_available_for_stage(&self, stage: StageId) -> bool {
+        self.is_available() &&
+            !matches!(stage, StageId::Headers | StageId::Bodies | StageId::Execution)
+    }
+
+    /// Returns the remaining time for a given stage, if the ETA is available for it.
+    fn remaining_for_stage(&self, stage: StageId) -> Option<Duration> {
+        if !self.is_available_for_stage(stage) {
+            return None
+        }
+        let (eta, last_checkpoint_time) = self.eta.zip(self.last_checkpoint_time)?;
+        eta.checked_sub(last_checkpoint_time.elapsed())
+    }
+
     /// Format ETA for a given stage.
     ///
     /// NOTE: Currently ETA is enabled only for the stages that have predictable progress.
     /// It's not the case for network-dependent ([`StageId::Headers`] and [`StageId::Bodies`]) and
     /// [`StageId::Execution`] stages.
     fn fmt_for_stage(&self, stage: StageId) -> Option<String> {
-        if !self.is_available() ||
-            matches!(stage, StageId::Headers | StageId::Bodies | StageId::Execution)
-        {
-            None
-        } else {
-            Some(self.to_string())
-        }
+        self.is_available_for_stage(stage).then(|| self.to_string())
     }
 }
 
@@ -84,7 +84,7 @@ impl MetricServer {
             .await
             .wrap_err("Could not bind to address")?;
 
-        task_executor.spawn_with_graceful_shutdown_signal(|mut signal| async move {
+        task_executor.spawn_background_with_graceful_shutdown_signal(|mut signal| async move {
             loop {
                 let io = tokio::select! {
                     _ = &mut signal => break,
@@ -137,6 +137,18 @@ fn describe_db_metrics() {
         "db.timed_out_not_aborted_transactions",
         "Number of timed out transactions that were not aborted by the user yet"
     );
+    describe_gauge!("db.reader_slots_used", "The number of used reader slots of the database");
+    describe_gauge!("db.reader_slots_max", "The maximum number of reader slots of the database");
+    describe_gauge!("db.active_read_transactions", "The number of open read transactions");
+    describe_gauge!(
+        "db.long_read_transactions",
+        "The number of read transactions that have been open for longer than a minute"
+    );
+    describe_gauge!(
+        "db.oldest_read_transaction_duration",
+        Unit::Seconds,
+        "For how long the oldest open read transaction has been open"
+    );
 }
 
 fn describe_static_file_metrics() {
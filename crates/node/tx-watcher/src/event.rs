@@ -0,0 +1,31 @@
+use alloy_primitives::{Address, BlockHash, TxHash};
+use serde::{Deserialize, Serialize};
+
+/// What happened to a watched transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionEventKind {
+    /// The transaction was included in a canonical block.
+    Included,
+    /// The block that includes the transaction reached the configured confirmation depth.
+    Confirmed,
+    /// The block that included the transaction was reorged out of the canonical chain.
+    Reorged,
+}
+
+/// An event of a watched transaction, reported by the [`TxWatcher`](crate::TxWatcher).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionEvent {
+    /// What happened to the transaction.
+    pub kind: TransactionEventKind,
+    /// Hash of the transaction.
+    pub hash: TxHash,
+    /// Sender of the transaction.
+    pub sender: Address,
+    /// Number of the block that includes, or included, the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: u64,
+    /// Hash of the block that includes, or included, the transaction.
+    pub block_hash: BlockHash,
+}
@@ -0,0 +1,25 @@
+//! Watches registered transactions and senders on the canonical chain.
+//!
+//! The [`TxWatcher`] consumes [`CanonStateNotification`](reth_chain_state::CanonStateNotification)s
+//! and reports a [`TransactionEvent`] whenever a watched transaction is included in a canonical
+//! block, reaches the configured confirmation depth, or is reorged out of the canonical chain.
+//! Events are posted to the configured webhooks and broadcast to subscribers of the
+//! [`TxWatcherHandle`].
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod event;
+pub use event::{TransactionEvent, TransactionEventKind};
+
+mod tracker;
+
+mod watcher;
+pub use watcher::{TxWatcher, TxWatcherConfig, TxWatcherHandle};
+
+pub use reqwest::Url;
@@ -0,0 +1,231 @@
+//! Tracks watched transactions on the canonical chain.
+
+use crate::{TransactionEvent, TransactionEventKind};
+use alloy_consensus::BlockHeader;
+use alloy_primitives::{
+    map::{HashMap, HashSet},
+    Address, BlockHash, TxHash,
+};
+use reth_chain_state::CanonStateNotification;
+use reth_primitives_traits::{BlockBody, NodePrimitives, SignedTransaction};
+
+/// Transactions and senders that are watched.
+#[derive(Debug, Default)]
+pub(crate) struct WatchList {
+    /// Watched transaction hashes.
+    pub(crate) transactions: HashSet<TxHash>,
+    /// Senders whose transactions are all watched.
+    pub(crate) senders: HashSet<Address>,
+}
+
+impl WatchList {
+    /// Returns `true` if the transaction, or its sender, is watched.
+    fn contains(&self, hash: &TxHash, sender: &Address) -> bool {
+        self.transactions.contains(hash) || self.senders.contains(sender)
+    }
+}
+
+/// A watched transaction in a canonical block that isn't confirmed yet.
+#[derive(Debug, Clone, Copy)]
+struct Inclusion {
+    sender: Address,
+    block_number: u64,
+    block_hash: BlockHash,
+}
+
+impl Inclusion {
+    const fn event(&self, kind: TransactionEventKind, hash: TxHash) -> TransactionEvent {
+        TransactionEvent {
+            kind,
+            hash,
+            sender: self.sender,
+            block_number: self.block_number,
+            block_hash: self.block_hash,
+        }
+    }
+}
+
+/// Follows the canonical chain and keeps track of watched transactions until they are confirmed.
+#[derive(Debug)]
+pub(crate) struct InclusionTracker {
+    /// Number of canonical blocks, including the block of the transaction, after which a
+    /// transaction is confirmed.
+    confirmations: u64,
+    /// Included transactions that aren't confirmed yet.
+    included: HashMap<TxHash, Inclusion>,
+}
+
+impl InclusionTracker {
+    /// Creates a new tracker with the given confirmation depth.
+    pub(crate) fn new(confirmations: u64) -> Self {
+        Self { confirmations: confirmations.max(1), included: HashMap::default() }
+    }
+
+    /// Applies the notification and returns the events of watched transactions, in order.
+    ///
+    /// Confirmed transaction hashes are removed from the watch list.
+    pub(crate) fn on_canon_state<N: NodePrimitives>(
+        &mut self,
+        watch_list: &mut WatchList,
+        notification: &CanonStateNotification<N>,
+    ) -> Vec<TransactionEvent> {
+        let mut events = Vec::new();
+
+        if let Some(reverted) = notification.reverted() {
+            for block in reverted.blocks().values().rev() {
+                for tx in block.body().transactions() {
+                    if let Some(inclusion) = self.included.remove(tx.tx_hash()) {
+                        events.push(inclusion.event(TransactionEventKind::Reorged, *tx.tx_hash()));
+                    }
+                }
+            }
+        }
+
+        let committed = notification.committed();
+        if committed.is_empty() {
+            return events
+        }
+
+        for block in committed.blocks_iter() {
+            for (sender, tx) in block.transactions_with_sender() {
+                let hash = *tx.tx_hash();
+                if watch_list.contains(&hash, sender) {
+                    let inclusion = Inclusion {
+                        sender: *sender,
+                        block_number: block.number(),
+                        block_hash: block.hash(),
+                    };
+                    events.push(inclusion.event(TransactionEventKind::Included, hash));
+                    self.included.insert(hash, inclusion);
+                }
+            }
+        }
+
+        let tip = committed.tip().number();
+        let mut confirmed = self
+            .included
+            .iter()
+            .filter(|(_, inclusion)| {
+                tip.saturating_sub(inclusion.block_number) + 1 >= self.confirmations
+            })
+            .map(|(hash, inclusion)| (*hash, *inclusion))
+            .collect::<Vec<_>>();
+        confirmed.sort_by_key(|(_, inclusion)| inclusion.block_number);
+
+        for (hash, inclusion) in confirmed {
+            self.included.remove(&hash);
+            watch_list.transactions.remove(&hash);
+            events.push(inclusion.event(TransactionEventKind::Confirmed, hash));
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use reth_chain_state::test_utils::TestBlockBuilder;
+    use reth_ethereum_primitives::{Block, EthPrimitives};
+    use reth_execution_types::{Chain, ExecutionOutcome};
+    use reth_primitives_traits::RecoveredBlock;
+    use std::sync::Arc;
+
+    fn chain(blocks: Vec<RecoveredBlock<Block>>) -> Arc<Chain<EthPrimitives>> {
+        Arc::new(Chain::new(blocks, ExecutionOutcome::default(), None))
+    }
+
+    /// Generates a block with at least one transaction.
+    fn block_with_transactions(
+        builder: &mut TestBlockBuilder<EthPrimitives>,
+        number: u64,
+        parent_hash: B256,
+    ) -> RecoveredBlock<Block> {
+        loop {
+            let block = builder.generate_random_block(number, parent_hash);
+            if !block.body().transactions.is_empty() {
+                return block
+            }
+        }
+    }
+
+    fn kinds(events: &[TransactionEvent]) -> Vec<TransactionEventKind> {
+        events.iter().map(|event| event.kind).collect()
+    }
+
+    #[test]
+    fn reports_inclusion_and_confirmation() {
+        let mut builder = TestBlockBuilder::<EthPrimitives>::default();
+        let first = block_with_transactions(&mut builder, 1, B256::ZERO);
+        let second = builder.generate_random_block(2, first.hash());
+        let hash = *first.body().transactions[0].tx_hash();
+
+        let mut watch_list = WatchList::default();
+        watch_list.transactions.insert(hash);
+        let mut tracker = InclusionTracker::new(2);
+
+        let events = tracker.on_canon_state(
+            &mut watch_list,
+            &CanonStateNotification::Commit { new: chain(vec![first.clone()]) },
+        );
+        assert_eq!(kinds(&events), vec![TransactionEventKind::Included]);
+        assert_eq!(events[0].hash, hash);
+        assert_eq!(events[0].sender, builder.signer);
+        assert_eq!(events[0].block_hash, first.hash());
+
+        let events = tracker.on_canon_state(
+            &mut watch_list,
+            &CanonStateNotification::Commit { new: chain(vec![second]) },
+        );
+        assert_eq!(kinds(&events), vec![TransactionEventKind::Confirmed]);
+        assert_eq!(events[0].block_number, 1);
+        assert!(watch_list.transactions.is_empty());
+    }
+
+    #[test]
+    fn reports_reorged_sender_transactions() {
+        let mut builder = TestBlockBuilder::<EthPrimitives>::default();
+        let old = block_with_transactions(&mut builder, 1, B256::ZERO);
+        let new = block_with_transactions(&mut builder, 1, B256::ZERO);
+
+        let mut watch_list = WatchList::default();
+        watch_list.senders.insert(builder.signer);
+        let mut tracker = InclusionTracker::new(3);
+
+        let events = tracker.on_canon_state(
+            &mut watch_list,
+            &CanonStateNotification::Commit { new: chain(vec![old.clone()]) },
+        );
+        assert_eq!(events.len(), old.body().transactions.len());
+        assert!(events.iter().all(|event| event.kind == TransactionEventKind::Included));
+
+        let events = tracker.on_canon_state(
+            &mut watch_list,
+            &CanonStateNotification::Reorg {
+                old: chain(vec![old.clone()]),
+                new: chain(vec![new.clone()]),
+            },
+        );
+        let (reorged, included): (Vec<_>, Vec<_>) =
+            events.iter().partition(|event| event.kind == TransactionEventKind::Reorged);
+        assert_eq!(reorged.len(), old.body().transactions.len());
+        assert!(reorged.iter().all(|event| event.block_hash == old.hash()));
+        assert_eq!(included.len(), new.body().transactions.len());
+        assert!(included.iter().all(|event| event.block_hash == new.hash()));
+    }
+
+    #[test]
+    fn serializes_event() {
+        let event = TransactionEvent {
+            kind: TransactionEventKind::Included,
+            hash: TxHash::ZERO,
+            sender: Address::ZERO,
+            block_number: 16,
+            block_hash: BlockHash::ZERO,
+        };
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["kind"], "included");
+        assert_eq!(json["blockNumber"], "0x10");
+    }
+}
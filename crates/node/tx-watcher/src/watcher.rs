@@ -0,0 +1,174 @@
+//! The transaction watcher service and its handle.
+
+use crate::{
+    tracker::{InclusionTracker, WatchList},
+    TransactionEvent,
+};
+use alloy_primitives::{Address, TxHash};
+use futures::{future::join_all, Stream, StreamExt};
+use parking_lot::RwLock;
+use reqwest::Url;
+use reth_chain_state::CanonStateNotification;
+use reth_primitives_traits::NodePrimitives;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{broadcast, mpsc};
+use tracing::{debug, trace, warn};
+
+/// The number of events buffered for each subscriber of the [`TxWatcherHandle`].
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// The number of events buffered for delivery to the webhooks. Events are dropped while the buffer
+/// is full.
+const WEBHOOK_CHANNEL_CAPACITY: usize = 1024;
+
+/// Timeout of a single webhook request.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Configuration of the [`TxWatcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxWatcherConfig {
+    /// Transactions that are watched from startup.
+    pub transactions: Vec<TxHash>,
+    /// Senders whose transactions are watched from startup.
+    pub senders: Vec<Address>,
+    /// Number of canonical blocks, including the block of the transaction, after which a
+    /// transaction is confirmed.
+    pub confirmations: u64,
+    /// URLs that every event is posted to as JSON.
+    pub webhooks: Vec<Url>,
+}
+
+/// A handle to a [`TxWatcher`], to change the watched transactions and senders at runtime and to
+/// subscribe to events.
+#[derive(Debug, Clone)]
+pub struct TxWatcherHandle {
+    watch_list: Arc<RwLock<WatchList>>,
+    events: broadcast::Sender<TransactionEvent>,
+}
+
+impl TxWatcherHandle {
+    /// Starts watching the transaction. Returns `false` if it was already watched.
+    pub fn watch_transaction(&self, hash: TxHash) -> bool {
+        self.watch_list.write().transactions.insert(hash)
+    }
+
+    /// Stops watching the transaction. Returns `false` if it wasn't watched.
+    pub fn unwatch_transaction(&self, hash: TxHash) -> bool {
+        self.watch_list.write().transactions.remove(&hash)
+    }
+
+    /// Starts watching all transactions of the sender. Returns `false` if it was already watched.
+    pub fn watch_sender(&self, sender: Address) -> bool {
+        self.watch_list.write().senders.insert(sender)
+    }
+
+    /// Stops watching the transactions of the sender. Returns `false` if it wasn't watched.
+    pub fn unwatch_sender(&self, sender: Address) -> bool {
+        self.watch_list.write().senders.remove(&sender)
+    }
+
+    /// Returns a new receiver of all events reported from now on.
+    pub fn subscribe(&self) -> broadcast::Receiver<TransactionEvent> {
+        self.events.subscribe()
+    }
+}
+
+/// Watches the canonical chain for inclusion, confirmation and reorgs of watched transactions.
+///
+/// Events are broadcast to the subscribers of the [`TxWatcherHandle`] and posted, in order, to
+/// every configured webhook. If the webhooks can't keep up, events are dropped for them instead of
+/// stalling the watcher. Transactions that are reorged out after they were confirmed are not
+/// reported.
+#[derive(Debug)]
+pub struct TxWatcher<St> {
+    /// Stream of canonical state notifications.
+    notifications: St,
+    /// Inclusions of watched transactions that aren't confirmed yet.
+    tracker: InclusionTracker,
+    /// Handle that shares the watch list and the event channel.
+    handle: TxWatcherHandle,
+    /// URLs that every event is posted to.
+    webhooks: Vec<Url>,
+}
+
+impl<St> TxWatcher<St> {
+    /// Creates a new watcher that follows the given canonical state notifications.
+    pub fn new(notifications: St, config: TxWatcherConfig) -> Self {
+        let TxWatcherConfig { transactions, senders, confirmations, webhooks } = config;
+        let watch_list = WatchList {
+            transactions: transactions.into_iter().collect(),
+            senders: senders.into_iter().collect(),
+        };
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self {
+            notifications,
+            tracker: InclusionTracker::new(confirmations),
+            handle: TxWatcherHandle { watch_list: Arc::new(RwLock::new(watch_list)), events },
+            webhooks,
+        }
+    }
+
+    /// Returns a handle to the watcher.
+    pub fn handle(&self) -> TxWatcherHandle {
+        self.handle.clone()
+    }
+}
+
+impl<St, N> TxWatcher<St>
+where
+    St: Stream<Item = CanonStateNotification<N>> + Unpin,
+    N: NodePrimitives,
+{
+    /// Runs the watcher until the notification stream ends.
+    pub async fn run(self) {
+        let Self { mut notifications, mut tracker, handle, webhooks } = self;
+
+        let has_webhooks = !webhooks.is_empty();
+        let (to_webhooks, webhook_events) = mpsc::channel(WEBHOOK_CHANNEL_CAPACITY);
+        let watch = async move {
+            while let Some(notification) = notifications.next().await {
+                let events = tracker.on_canon_state(&mut handle.watch_list.write(), &notification);
+                for event in events {
+                    trace!(target: "tx_watcher", ?event, "Watched transaction event");
+                    let _ = handle.events.send(event.clone());
+                    if has_webhooks {
+                        if let Err(mpsc::error::TrySendError::Full(event)) =
+                            to_webhooks.try_send(event)
+                        {
+                            warn!(target: "tx_watcher", hash = %event.hash, "Webhook queue is full, dropping transaction event");
+                        }
+                    }
+                }
+            }
+            debug!(target: "tx_watcher", "Canonical state notification stream ended");
+        };
+
+        tokio::join!(watch, deliver_webhooks(webhooks, webhook_events));
+    }
+}
+
+/// Posts every received event to all webhooks concurrently. The next event is posted once the
+/// previous one was delivered to all webhooks, so every webhook receives the events in order.
+async fn deliver_webhooks(webhooks: Vec<Url>, mut events: mpsc::Receiver<TransactionEvent>) {
+    let client = match reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(err) => {
+            warn!(target: "tx_watcher", %err, "Failed to build webhook client, transaction events are not delivered");
+            return
+        }
+    };
+    while let Some(event) = events.recv().await {
+        let event = &event;
+        join_all(webhooks.iter().map(|url| {
+            let request = client.post(url.clone()).json(event);
+            async move {
+                let res =
+                    request.send().await.and_then(|response| response.error_for_status());
+                if let Err(err) = res {
+                    warn!(target: "tx_watcher", %url, hash = %event.hash, %err, "Failed to deliver transaction event");
+                }
+            }
+        }))
+        .await;
+    }
+}
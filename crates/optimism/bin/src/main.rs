@@ -1,8 +1,9 @@
 #![allow(missing_docs, rustdoc::missing_crate_level_docs)]
 
 use clap::Parser;
+use reth_node_builder::NoopPoolBuilder;
 use reth_optimism_cli::{chainspec::OpChainSpecParser, Cli};
-use reth_optimism_node::{args::RollupArgs, OpNode};
+use reth_optimism_node::{args::RollupArgs, txpool::OpPooledTransaction, OpNode};
 use tracing::info;
 
 #[global_allocator]
@@ -19,9 +20,22 @@ fn main() {
     if let Err(err) =
         Cli::<OpChainSpecParser, RollupArgs>::parse().run(async move |builder, rollup_args| {
             info!(target: "reth::cli", "Launching node");
-            let handle =
-                builder.node(OpNode::new(rollup_args)).launch_with_debug_capabilities().await?;
-            handle.node_exit_future.await
+            let node = OpNode::new(rollup_args);
+            if builder.config().txpool.disabled {
+                // the node runs with the regular components, except for the pool
+                let handle = builder
+                    .with_types::<OpNode>()
+                    .with_components(
+                        node.components().pool(NoopPoolBuilder::<OpPooledTransaction>::default()),
+                    )
+                    .with_add_ons(node.add_ons_builder().build())
+                    .launch_with_debug_capabilities()
+                    .await?;
+                handle.node_exit_future.await
+            } else {
+                let handle = builder.node(node).launch_with_debug_capabilities().await?;
+                handle.node_exit_future.await
+            }
         })
     {
         eprintln!("Error: {err:?}");
@@ -17,6 +17,7 @@ pub struct SuperChainRegistryManager {
 impl SuperChainRegistryManager {
     const DICT_URL: &'static str = "https://raw.githubusercontent.com/ethereum-optimism/superchain-registry/main/superchain/extra/dictionary";
     const GENESIS_BASE_URL: &'static str = "https://raw.githubusercontent.com/ethereum-optimism/superchain-registry/main/superchain/extra/genesis";
+    const CONFIGS_BASE_URL: &'static str = "https://raw.githubusercontent.com/ethereum-optimism/superchain-registry/main/superchain/configs";
 
     /// Create a new registry manager with the given base path
     pub fn new(base_path: impl Into<PathBuf>) -> Result<Self> {
@@ -35,6 +36,11 @@ impl SuperChainRegistryManager {
         self.base_path.join(network_type).join(format!("{}.json.zst", network))
     }
 
+    /// Get the path to the config file for the given network (`mainnet`, `base`).
+    pub fn config_path(&self, network_type: &str, network: &str) -> PathBuf {
+        self.base_path.join("configs").join(network_type).join(format!("{}.toml", network))
+    }
+
     /// Read file from the given path
     fn read_file(&self, path: &Path) -> Result<Vec<u8>> {
         fs::read(path)
@@ -79,24 +85,35 @@ impl SuperChainRegistryManager {
         let dict_bytes = self.update_dictionary()?;
         trace!(target: "reth::cli", bytes = ?dict_bytes.len(),"Got dictionary");
 
-        let dictionary = DecoderDictionary::copy(&dict_bytes);
-
         let url = format!("{}/{}/{}.json.zst", Self::GENESIS_BASE_URL, network_type, network);
         let path = self.genesis_path(network_type, network);
 
         let compressed_bytes = self.download_file(&url, &path)?;
         trace!(target: "reth::cli", bytes = ?compressed_bytes.len(),"Got genesis file");
 
-        let decoder = Decoder::with_prepared_dictionary(&compressed_bytes[..], &dictionary)
-            .context("Failed to create decoder with dictionary")?;
+        decode_genesis(&compressed_bytes, &dict_bytes)
+            .with_context(|| format!("Failed to decode genesis: {path:?}"))
+    }
 
-        let json: Value = serde_json::from_reader(decoder)
-            .with_context(|| format!("Failed to parse JSON: {path:?}"))?;
+    /// Get the TOML config of a network, downloading it if necessary
+    pub fn get_config(&self, network_type: &str, network: &str) -> eyre::Result<String> {
+        let url = format!("{}/{}/{}.toml", Self::CONFIGS_BASE_URL, network_type, network);
+        let path = self.config_path(network_type, network);
 
-        Ok(json)
+        let bytes = self.download_file(&url, &path)?;
+        String::from_utf8(bytes).with_context(|| format!("Invalid config: {path:?}"))
     }
 }
 
+/// Decodes a genesis file of the registry, that is compressed with the registry's zstd dictionary.
+pub fn decode_genesis(compressed: &[u8], dictionary: &[u8]) -> eyre::Result<Value> {
+    let dictionary = DecoderDictionary::copy(dictionary);
+    let decoder = Decoder::with_prepared_dictionary(compressed, &dictionary)
+        .context("Failed to create decoder with dictionary")?;
+
+    serde_json::from_reader(decoder).context("Failed to parse JSON")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
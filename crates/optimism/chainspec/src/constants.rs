@@ -2,6 +2,12 @@
 
 use alloy_primitives::hex;
 
+/// Number of blocks below the tip after which a block is assumed to be final on OP stack chains.
+///
+/// L2 blocks are final once the L1 blocks that contain their batches are final, which takes about
+/// 30 minutes with 2 second block times.
+pub const OP_MAX_REORG_DEPTH: u64 = 900;
+
 //------------------------------- BASE MAINNET -------------------------------//
 
 /// Max gas limit on Base: <https://basescan.org/block/17208876>
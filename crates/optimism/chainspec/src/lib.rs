@@ -249,6 +249,10 @@ impl EthChainSpec for OpChainSpec {
     fn final_paris_total_difficulty(&self) -> Option<U256> {
         self.inner.final_paris_total_difficulty()
     }
+
+    fn max_reorg_depth(&self) -> u64 {
+        constants::OP_MAX_REORG_DEPTH
+    }
 }
 
 impl Hardforks for OpChainSpec {
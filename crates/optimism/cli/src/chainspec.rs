@@ -1,8 +1,12 @@
+use crate::superchain::{
+    download_chain_spec, find_registry_config, load_chain_spec, SUPERCHAIN_REGISTRY_ENV,
+};
 use reth_cli::chainspec::{parse_genesis, ChainSpecParser};
+use reth_node_core::dirs::cache_dir;
 use reth_optimism_chainspec::{
     OpChainSpec, BASE_MAINNET, BASE_SEPOLIA, OP_DEV, OP_MAINNET, OP_SEPOLIA,
 };
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 /// Optimism chain specification parser.
 #[derive(Debug, Clone, Default)]
@@ -15,8 +19,11 @@ impl ChainSpecParser for OpChainSpecParser {
     const SUPPORTED_CHAINS: &'static [&'static str] = &[
         "dev",
         "optimism",
+        "op",
         "optimism_sepolia",
         "optimism-sepolia",
+        "op_sepolia",
+        "op-sepolia",
         "base",
         "base_sepolia",
         "base-sepolia",
@@ -29,19 +36,44 @@ impl ChainSpecParser for OpChainSpecParser {
 
 /// Clap value parser for [`OpChainSpec`]s.
 ///
-/// The value parser matches either a known chain, the path
-/// to a json file, or a json formatted string in-memory. The json needs to be a Genesis struct.
+/// The value parser matches either a known chain, the path to a superchain registry chain config
+/// (`.toml`), the name of a chain in the superchain registry checked out at
+/// [`SUPERCHAIN_REGISTRY_ENV`], the path to a json file, a json formatted string in-memory or the
+/// name of a chain in the superchain registry, which is downloaded and cached. The json needs to be
+/// a Genesis struct.
 pub fn chain_value_parser(s: &str) -> eyre::Result<Arc<OpChainSpec>, eyre::Error> {
     Ok(match s {
         "dev" => OP_DEV.clone(),
-        "optimism" => OP_MAINNET.clone(),
-        "optimism_sepolia" | "optimism-sepolia" => OP_SEPOLIA.clone(),
+        "optimism" | "op" => OP_MAINNET.clone(),
+        "optimism_sepolia" | "optimism-sepolia" | "op_sepolia" | "op-sepolia" => OP_SEPOLIA.clone(),
         "base" => BASE_MAINNET.clone(),
         "base_sepolia" | "base-sepolia" => BASE_SEPOLIA.clone(),
-        _ => Arc::new(parse_genesis(s)?.into()),
+        _ if s.ends_with(".toml") => Arc::new(load_chain_spec(Path::new(s))?),
+        _ => {
+            let registry_config = std::env::var_os(SUPERCHAIN_REGISTRY_ENV)
+                .and_then(|registry| find_registry_config(Path::new(&registry), s));
+            match registry_config {
+                Some(config) => Arc::new(load_chain_spec(&config)?),
+                None => match parse_genesis(s) {
+                    Ok(genesis) => Arc::new(genesis.into()),
+                    // neither a genesis file nor json, so it's looked up in the registry
+                    Err(err) if is_registry_name(s) => {
+                        let cache_dir = cache_dir().ok_or(err)?.join("superchain-registry");
+                        Arc::new(download_chain_spec(&cache_dir, s)?)
+                    }
+                    Err(err) => return Err(err),
+                },
+            }
+        }
     })
 }
 
+/// Returns whether the value is the name of a chain in the superchain registry, e.g. `unichain`
+/// or `sepolia/unichain`.
+fn is_registry_name(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '/'))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
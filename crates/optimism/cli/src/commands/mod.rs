@@ -4,9 +4,9 @@ use import::ImportOpCommand;
 use import_receipts::ImportReceiptsOpCommand;
 use reth_cli::chainspec::ChainSpecParser;
 use reth_cli_commands::{
-    config_cmd, db, dump_genesis, init_cmd,
+    bench, config_cmd, db, dump_genesis, init_cmd, keys,
     node::{self, NoArgs},
-    p2p, prune, recover, stage,
+    p2p, prune, recover, stage, static_files,
 };
 use std::fmt;
 
@@ -48,15 +48,24 @@ pub enum Commands<Spec: ChainSpecParser = OpChainSpecParser, Ext: clap::Args + f
     /// P2P Debugging utilities
     #[command(name = "p2p")]
     P2P(p2p::Command<Spec>),
-    /// Write config to stdout
+    /// Write config to stdout or validate it
     #[command(name = "config")]
     Config(config_cmd::Command),
+    /// Manage the encrypted p2p secret key and JWT secret
+    #[command(name = "keys")]
+    Keys(keys::Command),
     /// Scripts for node recovery
     #[command(name = "recover")]
     Recover(recover::Command<Spec>),
     /// Prune according to the configuration without any limits
     #[command(name = "prune")]
     Prune(prune::PruneCommand<Spec>),
+    /// Verify the integrity of the static files
+    #[command(name = "static-files")]
+    StaticFiles(static_files::Command<Spec>),
+    /// Benchmark the node on its local database
+    #[command(name = "bench")]
+    Bench(bench::Command<Spec>),
     /// Generate Test Vectors
     #[cfg(feature = "dev")]
     #[command(name = "test-vectors")]
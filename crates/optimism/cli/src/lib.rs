@@ -24,6 +24,8 @@ pub mod commands;
 /// reth's needs for importing. However, this would require patching the diff in <https://github.com/testinprod-io/op-geth/pull/1> to export the `Receipt` and not `OpGethReceipt` type (originally
 /// made for op-erigon's import needs).
 pub mod receipt_file_codec;
+/// Loading of OP-stack chain specs from the [superchain registry](https://github.com/ethereum-optimism/superchain-registry).
+pub mod superchain;
 
 /// OVM block, same as EVM block at bedrock, except for signature of deposit transaction
 /// not having a signature back then.
@@ -153,7 +155,7 @@ where
         // Install the prometheus recorder to be sure to record all metrics
         let _ = install_prometheus_recorder();
 
-        match self.command {
+        let result = match self.command {
             Commands::Node(command) => {
                 runner.run_command_until_exit(|ctx| command.execute(ctx, launcher))
             }
@@ -180,13 +182,26 @@ where
                 runner.run_until_ctrl_c(command.execute::<OpNetworkPrimitives>())
             }
             Commands::Config(command) => runner.run_until_ctrl_c(command.execute()),
+            Commands::Keys(command) => runner.run_until_ctrl_c(command.execute()),
             Commands::Recover(command) => {
                 runner.run_command_until_exit(|ctx| command.execute::<OpNode>(ctx))
             }
             Commands::Prune(command) => runner.run_until_ctrl_c(command.execute::<OpNode>()),
+            Commands::StaticFiles(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<OpNode>())
+            }
+            Commands::Bench(command) => {
+                runner.run_blocking_until_ctrl_c(command.execute::<OpNode, _, _>(|spec| {
+                    (OpExecutorProvider::optimism(spec.clone()), OpBeaconConsensus::new(spec))
+                }))
+            }
             #[cfg(feature = "dev")]
             Commands::TestVectors(command) => runner.run_until_ctrl_c(command.execute()),
-        }
+        };
+
+        // flush spans that haven't been exported yet
+        reth_tracing::shutdown_otlp();
+        result
     }
 
     /// Initializes tracing with the configured options.
@@ -0,0 +1,391 @@
+//! A chain is described by its config at `superchain/configs/<superchain>/<name>.toml` and its
+//! genesis, which is read from `<name>.json` next to the config or from
+//! `superchain/extra/genesis/<superchain>/<name>.json`, or `<name>.json.zst` compressed with the
+//! zstd dictionary of the registry at `superchain/extra/dictionary`.
+//!
+//! Chains that aren't found in a local checkout of the registry are downloaded from the registry
+//! and cached, see [`SuperChainRegistryManager`].
+
+use alloy_genesis::Genesis;
+use alloy_primitives::{Address, B256};
+use eyre::{eyre, WrapErr};
+use reth_chainspec::EthChainSpec;
+use reth_optimism_chain_registry::{decode_genesis, SuperChainRegistryManager};
+use reth_optimism_chainspec::OpChainSpec;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable with the path to a checkout of the superchain registry, which is searched
+/// for chain names that are not built in.
+pub const SUPERCHAIN_REGISTRY_ENV: &str = "RETH_SUPERCHAIN_REGISTRY";
+
+/// The superchains that are searched for a chain name without a superchain, mainnet chains are
+/// preferred over testnet chains with the same name.
+const SUPERCHAINS: &[&str] = &["mainnet", "sepolia"];
+
+/// The config of a chain in the superchain registry.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SuperchainChainConfig {
+    /// The name of the chain.
+    pub name: String,
+    /// The chain id of the chain.
+    pub chain_id: u64,
+    /// The L1 address that batches are sent to.
+    #[serde(default)]
+    pub batch_inbox_addr: Option<Address>,
+    /// The activation times of the OP hardforks.
+    #[serde(default)]
+    pub hardforks: SuperchainHardforks,
+    /// The EIP-1559 parameters of the chain.
+    #[serde(default)]
+    pub optimism: Option<SuperchainEip1559Params>,
+    /// The L1 and L2 blocks the rollup starts at.
+    pub genesis: SuperchainGenesis,
+    /// The L1 system contracts of the chain, by name.
+    #[serde(default)]
+    pub addresses: BTreeMap<String, Address>,
+    /// The privileged accounts of the chain, by role.
+    #[serde(default)]
+    pub roles: BTreeMap<String, Address>,
+}
+
+/// The activation times of the OP hardforks of a chain in the superchain registry.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct SuperchainHardforks {
+    /// Regolith activation time, at genesis if not set.
+    pub regolith_time: Option<u64>,
+    /// Canyon activation time, which also activates Shanghai.
+    pub canyon_time: Option<u64>,
+    /// Ecotone activation time, which also activates Cancun.
+    pub ecotone_time: Option<u64>,
+    /// Fjord activation time.
+    pub fjord_time: Option<u64>,
+    /// Granite activation time.
+    pub granite_time: Option<u64>,
+    /// Holocene activation time.
+    pub holocene_time: Option<u64>,
+    /// Isthmus activation time, which also activates Prague.
+    pub isthmus_time: Option<u64>,
+}
+
+/// The EIP-1559 parameters of a chain in the superchain registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct SuperchainEip1559Params {
+    /// The elasticity multiplier.
+    pub eip1559_elasticity: u64,
+    /// The base fee max change denominator.
+    pub eip1559_denominator: u64,
+    /// The base fee max change denominator after Canyon.
+    pub eip1559_denominator_canyon: Option<u64>,
+}
+
+/// The genesis of a rollup in the superchain registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct SuperchainGenesis {
+    /// The timestamp of the L2 genesis block.
+    pub l2_time: u64,
+    /// The L1 block the rollup starts at.
+    pub l1: SuperchainBlockId,
+    /// The L2 genesis block.
+    pub l2: SuperchainBlockId,
+}
+
+/// A block referenced by the superchain registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct SuperchainBlockId {
+    /// The block hash.
+    pub hash: B256,
+    /// The block number.
+    pub number: u64,
+}
+
+impl SuperchainChainConfig {
+    /// Reads the config from a TOML file.
+    pub fn from_file(path: &Path) -> eyre::Result<Self> {
+        let raw = fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read superchain config {}", path.display()))?;
+        toml::from_str(&raw)
+            .wrap_err_with(|| format!("failed to parse superchain config {}", path.display()))
+    }
+
+    /// Sets the chain id, the hardfork activations and the EIP-1559 parameters of the config in
+    /// the genesis.
+    pub fn apply_to_genesis(&self, genesis: &mut Genesis) {
+        let hardforks = &self.hardforks;
+        genesis.config.chain_id = self.chain_id;
+        genesis.config.shanghai_time = hardforks.canyon_time;
+        genesis.config.cancun_time = hardforks.ecotone_time;
+        genesis.config.prague_time = hardforks.isthmus_time;
+
+        let extra_fields = &mut genesis.config.extra_fields;
+        extra_fields.insert("bedrockBlock".to_string(), self.genesis.l2.number.into());
+        extra_fields
+            .insert("regolithTime".to_string(), hardforks.regolith_time.unwrap_or_default().into());
+        for (name, time) in [
+            ("canyonTime", hardforks.canyon_time),
+            ("ecotoneTime", hardforks.ecotone_time),
+            ("fjordTime", hardforks.fjord_time),
+            ("graniteTime", hardforks.granite_time),
+            ("holoceneTime", hardforks.holocene_time),
+            ("isthmusTime", hardforks.isthmus_time),
+        ] {
+            match time {
+                Some(time) => extra_fields.insert(name.to_string(), time.into()),
+                None => extra_fields.remove(name),
+            };
+        }
+
+        if let Some(params) = &self.optimism {
+            let mut optimism = serde_json::json!({
+                "eip1559Elasticity": params.eip1559_elasticity,
+                "eip1559Denominator": params.eip1559_denominator,
+            });
+            if let Some(canyon_denominator) = params.eip1559_denominator_canyon {
+                optimism["eip1559DenominatorCanyon"] = canyon_denominator.into();
+            }
+            extra_fields.insert("optimism".to_string(), optimism);
+        }
+    }
+}
+
+/// Loads the chain spec of the chain with the given registry config file.
+///
+/// Fails if the hash of the resulting genesis block doesn't match the registry.
+pub fn load_chain_spec(config_path: &Path) -> eyre::Result<OpChainSpec> {
+    let config = SuperchainChainConfig::from_file(config_path)?;
+
+    let genesis_path = genesis_path(config_path)
+        .ok_or_else(|| eyre!("no genesis found for superchain config {}", config_path.display()))?;
+    let genesis = read_genesis(&genesis_path)
+        .wrap_err_with(|| format!("failed to read genesis {}", genesis_path.display()))?;
+
+    chain_spec_from_registry(&config, genesis)
+}
+
+/// Downloads the config and the genesis of the chain with the given name from the superchain
+/// registry, caching them in the given directory, and loads its chain spec.
+///
+/// The name is either `<superchain>/<name>`, e.g. `sepolia/unichain`, or just the name of the
+/// chain, in which case the mainnet and the sepolia superchain are searched.
+pub fn download_chain_spec(cache_dir: &Path, name: &str) -> eyre::Result<OpChainSpec> {
+    let registry = SuperChainRegistryManager::new(cache_dir)?;
+    let (superchains, name) = match name.split_once('/') {
+        Some((superchain, name)) => (vec![superchain], name),
+        None => (SUPERCHAINS.to_vec(), name),
+    };
+
+    for superchain in superchains {
+        let Ok(config) = registry.get_config(superchain, name) else { continue };
+        let config: SuperchainChainConfig = toml::from_str(&config)
+            .wrap_err_with(|| format!("failed to parse superchain config {superchain}/{name}"))?;
+        let genesis = serde_json::from_value(registry.get_genesis(superchain, name)?)
+            .wrap_err_with(|| format!("failed to parse genesis {superchain}/{name}"))?;
+        return chain_spec_from_registry(&config, genesis)
+    }
+
+    eyre::bail!("chain {name} not found in the superchain registry")
+}
+
+/// Reads a genesis file, decompressing it with the dictionary of the registry if it's a
+/// `.json.zst` file.
+fn read_genesis(path: &Path) -> eyre::Result<Genesis> {
+    if path.extension().is_some_and(|extension| extension == "zst") {
+        // superchain/extra/genesis/<superchain>/<name>.json.zst -> superchain/extra/dictionary
+        let dictionary_path = path
+            .parent()
+            .and_then(Path::parent)
+            .map(|genesis_dir| genesis_dir.with_file_name("dictionary"))
+            .ok_or_else(|| eyre!("no dictionary for compressed genesis"))?;
+        let dictionary = fs::read(&dictionary_path)
+            .wrap_err_with(|| format!("failed to read dictionary {}", dictionary_path.display()))?;
+        return Ok(serde_json::from_value(decode_genesis(&fs::read(path)?, &dictionary)?)?)
+    }
+
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+/// Creates the chain spec of a chain of the registry, from its config and its genesis.
+///
+/// Fails if the hash of the resulting genesis block doesn't match the registry.
+fn chain_spec_from_registry(
+    config: &SuperchainChainConfig,
+    mut genesis: Genesis,
+) -> eyre::Result<OpChainSpec> {
+    config.apply_to_genesis(&mut genesis);
+    let chain_spec = OpChainSpec::from(genesis);
+
+    let genesis_hash = chain_spec.genesis_hash();
+    if genesis_hash != config.genesis.l2.hash {
+        eyre::bail!(
+            "genesis hash mismatch for {}: got {genesis_hash}, expected {}",
+            config.name,
+            config.genesis.l2.hash
+        )
+    }
+
+    Ok(chain_spec)
+}
+
+/// Returns the path of the genesis of the chain with the given registry config file, if it exists.
+fn genesis_path(config_path: &Path) -> Option<PathBuf> {
+    let sibling = config_path.with_extension("json");
+    if sibling.is_file() {
+        return Some(sibling)
+    }
+
+    // superchain/configs/<superchain>/<name>.toml ->
+    // superchain/extra/genesis/<superchain>/<name>.json(.zst)
+    let superchain = config_path.parent()?;
+    let superchain_dir = superchain.parent()?.parent()?;
+    let path = superchain_dir
+        .join("extra")
+        .join("genesis")
+        .join(superchain.file_name()?)
+        .join(config_path.file_name()?)
+        .with_extension("json");
+    let compressed = path.with_extension("json.zst");
+    [path, compressed].into_iter().find(|path| path.is_file())
+}
+
+/// Finds the config of the chain with the given name in the registry checkout.
+///
+/// The name is either `<superchain>/<name>`, e.g. `sepolia/unichain`, or just the name of the
+/// chain, in which case all superchains are searched.
+pub fn find_registry_config(registry: &Path, name: &str) -> Option<PathBuf> {
+    let configs = registry.join("superchain").join("configs");
+    if name.contains('/') {
+        let path = configs.join(name).with_extension("toml");
+        return path.is_file().then_some(path)
+    }
+
+    let mut superchains = fs::read_dir(configs)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect::<Vec<_>>();
+    // prefer mainnet chains over testnet chains with the same name
+    superchains.sort_by_key(|path| !path.ends_with("mainnet"));
+    superchains
+        .into_iter()
+        .map(|superchain| superchain.join(name).with_extension("toml"))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_chainspec::{BaseFeeParams, EthereumHardforks};
+    use reth_optimism_forks::{OpHardfork, OpHardforks};
+
+    const CONFIG: &str = r#"
+name = "Example Chain"
+chain_id = 424242
+batch_inbox_addr = "0xff00000000000000000000000000000000424242"
+
+[hardforks]
+canyon_time = 0
+delta_time = 0
+ecotone_time = 0
+fjord_time = 0
+granite_time = 100
+holocene_time = 200
+
+[optimism]
+eip1559_elasticity = 6
+eip1559_denominator = 50
+eip1559_denominator_canyon = 250
+
+[genesis]
+l2_time = 1700000000
+[genesis.l1]
+hash = "0x0000000000000000000000000000000000000000000000000000000000000001"
+number = 100
+[genesis.l2]
+hash = "0x0000000000000000000000000000000000000000000000000000000000000002"
+number = 0
+
+[addresses]
+SystemConfigProxy = "0x0000000000000000000000000000000000000003"
+"#;
+
+    const GENESIS: &str = r#"{
+        "config": {
+            "chainId": 1,
+            "homesteadBlock": 0,
+            "eip150Block": 0,
+            "eip155Block": 0,
+            "byzantiumBlock": 0,
+            "constantinopleBlock": 0,
+            "petersburgBlock": 0,
+            "istanbulBlock": 0,
+            "berlinBlock": 0,
+            "londonBlock": 0
+        },
+        "timestamp": "0x6553f100",
+        "gasLimit": "0x1c9c380",
+        "difficulty": "0x0",
+        "alloc": {}
+    }"#;
+
+    #[test]
+    fn apply_registry_config() {
+        let config: SuperchainChainConfig = toml::from_str(CONFIG).unwrap();
+        assert_eq!(config.addresses.get("SystemConfigProxy"), Some(&Address::with_last_byte(3)));
+
+        let mut genesis: Genesis = serde_json::from_str(GENESIS).unwrap();
+        config.apply_to_genesis(&mut genesis);
+        let chain_spec = OpChainSpec::from(genesis);
+
+        assert_eq!(chain_spec.chain().id(), 424242);
+        assert!(chain_spec.is_fork_active_at_timestamp(OpHardfork::Fjord, 0));
+        assert!(!chain_spec.is_fork_active_at_timestamp(OpHardfork::Granite, 99));
+        assert!(chain_spec.is_fork_active_at_timestamp(OpHardfork::Holocene, 200));
+        assert!(chain_spec.is_cancun_active_at_timestamp(0));
+        assert_eq!(chain_spec.base_fee_params_at_timestamp(0), BaseFeeParams::new(250, 6));
+    }
+
+    #[test]
+    fn load_from_registry_layout() {
+        let registry = tempfile::tempdir().unwrap();
+        let configs = registry.path().join("superchain/configs/mainnet");
+        let genesis = registry.path().join("superchain/extra/genesis/mainnet");
+        fs::create_dir_all(&configs).unwrap();
+        fs::create_dir_all(&genesis).unwrap();
+        fs::write(genesis.join("example.json"), GENESIS).unwrap();
+
+        // compute the genesis hash the registry would contain
+        let mut expected: Genesis = serde_json::from_str(GENESIS).unwrap();
+        let config: SuperchainChainConfig = toml::from_str(CONFIG).unwrap();
+        config.apply_to_genesis(&mut expected);
+        let genesis_hash = OpChainSpec::from(expected).genesis_hash();
+        let config = CONFIG.replace(
+            "0x0000000000000000000000000000000000000000000000000000000000000002",
+            &genesis_hash.to_string(),
+        );
+        fs::write(configs.join("example.toml"), config).unwrap();
+
+        let path = find_registry_config(registry.path(), "example").unwrap();
+        assert_eq!(path, find_registry_config(registry.path(), "mainnet/example").unwrap());
+        assert!(find_registry_config(registry.path(), "unknown").is_none());
+
+        let chain_spec = load_chain_spec(&path).unwrap();
+        assert_eq!(chain_spec.genesis_hash(), genesis_hash);
+
+        // the genesis can be compressed with the dictionary of the registry
+        let dictionary = b"\"config\": {\"chainId\": 1, \"homesteadBlock\": 0, \"alloc\": {}";
+        let compressed = zstd::bulk::Compressor::with_dictionary(0, dictionary)
+            .unwrap()
+            .compress(GENESIS.as_bytes());
+        fs::write(registry.path().join("superchain/extra/dictionary"), dictionary).unwrap();
+        fs::write(genesis.join("example.json.zst"), compressed.unwrap()).unwrap();
+        fs::remove_file(genesis.join("example.json")).unwrap();
+        assert_eq!(load_chain_spec(&path).unwrap().genesis_hash(), genesis_hash);
+
+        // a genesis that doesn't match the registry is rejected
+        fs::write(configs.join("example.toml"), CONFIG).unwrap();
+        assert!(load_chain_spec(&path).is_err());
+    }
+}
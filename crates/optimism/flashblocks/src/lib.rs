@@ -0,0 +1,27 @@
+//! Support for flashblocks, partial blocks that the sequencer of an OP-stack chain streams while
+//! it builds a payload.
+//!
+//! The [`FlashBlockService`] receives the flashblocks over `WebSocket` and builds the pending
+//! block after every flashblock, so that `pending` RPC queries can be served with sub-block
+//! latency.
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
+    html_favicon_url = "https://avatars0.githubusercontent.com/u/97369466?s=256",
+    issue_tracker_base_url = "https://github.com/paradigmxyz/reth/issues/"
+)]
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
+
+mod payload;
+pub use payload::{ExecutionPayloadBaseV1, ExecutionPayloadFlashblockDeltaV1, FlashBlock};
+
+mod sequence;
+pub use sequence::FlashBlockSequence;
+
+mod service;
+pub use service::{
+    FlashBlockService, PendingFlashBlock, PendingFlashBlockReceiver, PendingFlashBlockSender,
+};
+
+pub use url::Url;
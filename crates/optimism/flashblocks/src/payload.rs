@@ -0,0 +1,75 @@
+//! Flashblock payload types, as streamed by the sequencer.
+
+use alloy_eips::eip4895::Withdrawal;
+use alloy_primitives::{Address, Bloom, Bytes, B256, U256};
+use alloy_rpc_types_engine::PayloadId;
+use serde::{Deserialize, Serialize};
+
+/// A flashblock, a partial block streamed by the sequencer while it builds the payload.
+///
+/// The first flashblock of a payload carries the [`ExecutionPayloadBaseV1`], every flashblock
+/// carries the transactions that were added to the payload since the previous one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlashBlock {
+    /// The id of the payload that is built.
+    pub payload_id: PayloadId,
+    /// The index of the flashblock within the payload, starting at 0.
+    pub index: u64,
+    /// Fields of the payload that don't change while it is built, only set for index 0.
+    pub base: Option<ExecutionPayloadBaseV1>,
+    /// The changes to the payload since the previous flashblock.
+    pub diff: ExecutionPayloadFlashblockDeltaV1,
+    /// Builder specific metadata, which is not interpreted.
+    pub metadata: serde_json::Value,
+}
+
+/// Fields of the payload that are known when building starts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionPayloadBaseV1 {
+    /// The parent beacon block root.
+    pub parent_beacon_block_root: B256,
+    /// The hash of the parent block.
+    pub parent_hash: B256,
+    /// The fee recipient.
+    pub fee_recipient: Address,
+    /// The previous randao value.
+    pub prev_randao: B256,
+    /// The block number.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: u64,
+    /// The gas limit.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_limit: u64,
+    /// The timestamp.
+    #[serde(with = "alloy_serde::quantity")]
+    pub timestamp: u64,
+    /// The extra data.
+    pub extra_data: Bytes,
+    /// The base fee per gas.
+    pub base_fee_per_gas: U256,
+}
+
+/// The changes to the payload since the previous flashblock.
+///
+/// The roots, bloom, gas used and block hash are those of the payload including all flashblocks
+/// up to this one, the transactions and withdrawals are only the new ones.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionPayloadFlashblockDeltaV1 {
+    /// The state root.
+    pub state_root: B256,
+    /// The receipts root.
+    pub receipts_root: B256,
+    /// The logs bloom.
+    pub logs_bloom: Bloom,
+    /// The gas used.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+    /// The block hash.
+    pub block_hash: B256,
+    /// The EIP-2718 encoded transactions that were added.
+    pub transactions: Vec<Bytes>,
+    /// The withdrawals that were added.
+    pub withdrawals: Vec<Withdrawal>,
+    /// The L2 withdrawals root, set since Isthmus.
+    pub withdrawals_root: B256,
+}
@@ -0,0 +1,188 @@
+//! Accumulates the flashblocks of a payload.
+
+use crate::{ExecutionPayloadBaseV1, FlashBlock};
+use alloy_eips::eip7685::Requests;
+use alloy_rpc_types_engine::{ExecutionPayloadV1, ExecutionPayloadV2, ExecutionPayloadV3};
+use op_alloy_rpc_types_engine::{OpExecutionData, OpExecutionPayloadV4};
+
+/// The flashblocks of the payload that is currently built by the sequencer, in order.
+#[derive(Debug, Default)]
+pub struct FlashBlockSequence {
+    flashblocks: Vec<FlashBlock>,
+}
+
+impl FlashBlockSequence {
+    /// Adds the flashblock to the sequence. Returns `false` if it was ignored.
+    ///
+    /// A flashblock with index 0 starts a new sequence. Any other flashblock is only added if it
+    /// directly follows the last flashblock of the current payload.
+    pub fn insert(&mut self, flashblock: FlashBlock) -> bool {
+        if flashblock.index == 0 {
+            if flashblock.base.is_none() {
+                return false
+            }
+            self.flashblocks.clear();
+            self.flashblocks.push(flashblock);
+            return true
+        }
+
+        match self.flashblocks.last() {
+            Some(last)
+                if last.payload_id == flashblock.payload_id &&
+                    last.index + 1 == flashblock.index =>
+            {
+                self.flashblocks.push(flashblock);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the base of the payload, if the sequence isn't empty.
+    pub fn base(&self) -> Option<&ExecutionPayloadBaseV1> {
+        self.flashblocks.first().and_then(|flashblock| flashblock.base.as_ref())
+    }
+
+    /// Returns the index of the last flashblock, if the sequence isn't empty.
+    pub fn last_index(&self) -> Option<u64> {
+        self.flashblocks.last().map(|flashblock| flashblock.index)
+    }
+
+    /// Returns the payload that contains all flashblocks of the sequence, if it isn't empty.
+    ///
+    /// The payload is a V4 payload if Isthmus is active, and a V3 payload otherwise.
+    pub fn to_execution_data(&self, is_isthmus: bool) -> Option<OpExecutionData> {
+        let base = self.base()?;
+        let diff = &self.flashblocks.last()?.diff;
+
+        let payload = ExecutionPayloadV3 {
+            payload_inner: ExecutionPayloadV2 {
+                payload_inner: ExecutionPayloadV1 {
+                    parent_hash: base.parent_hash,
+                    fee_recipient: base.fee_recipient,
+                    state_root: diff.state_root,
+                    receipts_root: diff.receipts_root,
+                    logs_bloom: diff.logs_bloom,
+                    prev_randao: base.prev_randao,
+                    block_number: base.block_number,
+                    gas_limit: base.gas_limit,
+                    gas_used: diff.gas_used,
+                    timestamp: base.timestamp,
+                    extra_data: base.extra_data.clone(),
+                    base_fee_per_gas: base.base_fee_per_gas,
+                    block_hash: diff.block_hash,
+                    transactions: self
+                        .flashblocks
+                        .iter()
+                        .flat_map(|flashblock| flashblock.diff.transactions.iter().cloned())
+                        .collect(),
+                },
+                withdrawals: self
+                    .flashblocks
+                    .iter()
+                    .flat_map(|flashblock| flashblock.diff.withdrawals.iter().copied())
+                    .collect(),
+            },
+            blob_gas_used: 0,
+            excess_blob_gas: 0,
+        };
+
+        Some(if is_isthmus {
+            OpExecutionData::v4(
+                OpExecutionPayloadV4::from_v3_with_withdrawals_root(payload, diff.withdrawals_root),
+                Vec::new(),
+                base.parent_beacon_block_root,
+                Requests::default(),
+            )
+        } else {
+            OpExecutionData::v3(payload, Vec::new(), base.parent_beacon_block_root)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExecutionPayloadFlashblockDeltaV1;
+    use alloy_primitives::{Address, Bloom, Bytes, B256, U256};
+    use alloy_rpc_types_engine::PayloadId;
+    use reth_optimism_primitives::OpTransactionSigned;
+
+    fn flashblock(payload_id: u64, index: u64, transactions: Vec<Bytes>) -> FlashBlock {
+        FlashBlock {
+            payload_id: PayloadId::new(payload_id.to_be_bytes()),
+            index,
+            base: (index == 0).then(|| ExecutionPayloadBaseV1 {
+                parent_beacon_block_root: B256::with_last_byte(1),
+                parent_hash: B256::with_last_byte(2),
+                fee_recipient: Address::with_last_byte(3),
+                prev_randao: B256::with_last_byte(4),
+                block_number: 10,
+                gas_limit: 30_000_000,
+                timestamp: 1_700_000_000,
+                extra_data: Bytes::new(),
+                base_fee_per_gas: U256::from(1_000),
+            }),
+            diff: ExecutionPayloadFlashblockDeltaV1 {
+                state_root: B256::with_last_byte(5),
+                receipts_root: B256::with_last_byte(6),
+                logs_bloom: Bloom::default(),
+                gas_used: index * 21_000,
+                block_hash: B256::with_last_byte(index as u8),
+                transactions,
+                withdrawals: Vec::new(),
+                withdrawals_root: B256::with_last_byte(7),
+            },
+            metadata: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn inserts_consecutive_flashblocks() {
+        let mut sequence = FlashBlockSequence::default();
+
+        // a sequence starts with index 0
+        assert!(!sequence.insert(flashblock(1, 1, Vec::new())));
+        assert!(sequence.insert(flashblock(1, 0, Vec::new())));
+        assert!(sequence.insert(flashblock(1, 1, Vec::new())));
+        // gaps and other payloads are ignored
+        assert!(!sequence.insert(flashblock(1, 3, Vec::new())));
+        assert!(!sequence.insert(flashblock(2, 2, Vec::new())));
+        assert_eq!(sequence.last_index(), Some(1));
+
+        // index 0 starts the next payload
+        assert!(sequence.insert(flashblock(2, 0, Vec::new())));
+        assert_eq!(sequence.last_index(), Some(0));
+    }
+
+    #[test]
+    fn converts_to_block() {
+        let mut sequence = FlashBlockSequence::default();
+        sequence.insert(flashblock(1, 0, Vec::new()));
+        sequence.insert(flashblock(1, 1, Vec::new()));
+
+        let data = sequence.to_execution_data(true).unwrap();
+        let block =
+            data.payload.try_into_block_with_sidecar::<OpTransactionSigned>(&data.sidecar).unwrap();
+        assert_eq!(block.header.number, 10);
+        assert_eq!(block.header.parent_hash, B256::with_last_byte(2));
+        assert_eq!(block.header.gas_used, 21_000);
+        assert_eq!(block.header.withdrawals_root, Some(B256::with_last_byte(7)));
+        assert_eq!(block.header.parent_beacon_block_root, Some(B256::with_last_byte(1)));
+        assert!(block.header.requests_hash.is_some());
+
+        let data = sequence.to_execution_data(false).unwrap();
+        let block =
+            data.payload.try_into_block_with_sidecar::<OpTransactionSigned>(&data.sidecar).unwrap();
+        assert_eq!(block.header.requests_hash, None);
+    }
+
+    #[test]
+    fn deserializes_flashblock() {
+        let flashblock = flashblock(1, 0, vec![Bytes::from_static(&[0x7e])]);
+        let json = serde_json::to_value(&flashblock).unwrap();
+        assert_eq!(json["base"]["block_number"], "0xa");
+        assert_eq!(json["diff"]["gas_used"], "0x0");
+        assert_eq!(serde_json::from_value::<FlashBlock>(json).unwrap(), flashblock);
+    }
+}
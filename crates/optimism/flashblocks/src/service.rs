@@ -0,0 +1,242 @@
+//! Service that streams flashblocks from the sequencer and builds the pending block from them.
+
+use crate::{FlashBlock, FlashBlockSequence};
+use alloy_primitives::Address;
+use alloy_rpc_types_engine::PayloadId;
+use futures_util::StreamExt;
+use reth_chainspec::ChainSpecProvider;
+use reth_evm::{execute::BlockExecutor, ConfigureEvm};
+use reth_optimism_forks::OpHardforks;
+use reth_optimism_primitives::{OpBlock, OpPrimitives, OpReceipt, OpTransactionSigned};
+use reth_primitives_traits::{RecoveredBlock, SealedBlock, SignedTransaction};
+use reth_revm::{database::StateProviderDatabase, db::State};
+use reth_storage_api::{StateProviderBox, StateProviderFactory};
+use std::{fmt, sync::Arc, time::Duration};
+use tokio::sync::watch;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tracing::{debug, info, trace, warn};
+use url::Url;
+
+/// Delay before reconnecting to the flashblocks endpoint after the connection was lost.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// The pending block built from the flashblocks of the sequencer.
+#[derive(Debug, Clone)]
+pub struct PendingFlashBlock {
+    /// The block that contains all flashblocks received so far.
+    pub block: RecoveredBlock<OpBlock>,
+    /// The receipts of the block.
+    pub receipts: Vec<OpReceipt>,
+    /// The index of the last flashblock that is included.
+    pub index: u64,
+}
+
+/// Sender of the latest [`PendingFlashBlock`].
+pub type PendingFlashBlockSender = watch::Sender<Option<Arc<PendingFlashBlock>>>;
+
+/// Receiver of the latest [`PendingFlashBlock`].
+pub type PendingFlashBlockReceiver = watch::Receiver<Option<Arc<PendingFlashBlock>>>;
+
+/// Execution of the payload that is currently built, which is continued with the transactions of
+/// every flashblock.
+struct PayloadExecution {
+    /// The payload that is executed.
+    payload_id: PayloadId,
+    /// State of the parent block with the changes of all executed transactions.
+    state: State<StateProviderDatabase<StateProviderBox>>,
+    /// Senders of the executed transactions.
+    senders: Vec<Address>,
+    /// Receipts of the executed transactions.
+    receipts: Vec<OpReceipt>,
+    /// Gas used by the executed transactions.
+    gas_used: u64,
+}
+
+impl fmt::Debug for PayloadExecution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PayloadExecution")
+            .field("payload_id", &self.payload_id)
+            .field("transactions", &self.senders.len())
+            .field("gas_used", &self.gas_used)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Receives flashblocks from the sequencer over `WebSocket` and builds the pending block from
+/// them.
+///
+/// The block itself is reconstructed from the flashblocks, so its hash matches the payload built
+/// by the sequencer. The receipts are obtained by executing the transactions on top of the state
+/// of its parent. The execution of a payload is kept between its flashblocks, so that only the
+/// transactions of a new flashblock are executed.
+#[derive(Debug)]
+pub struct FlashBlockService<Provider, EvmConfig> {
+    /// The `WebSocket` endpoint that streams the flashblocks.
+    url: Url,
+    /// Provider of the state of the parent block.
+    provider: Provider,
+    /// Configuration of the EVM that executes the pending block.
+    evm_config: EvmConfig,
+    /// Flashblocks of the payload that is currently built.
+    sequence: FlashBlockSequence,
+    /// Execution of the payload that is currently built, if it succeeded so far.
+    execution: Option<PayloadExecution>,
+    /// Sender of the latest pending block.
+    pending: PendingFlashBlockSender,
+}
+
+impl<Provider, EvmConfig> FlashBlockService<Provider, EvmConfig>
+where
+    Provider: StateProviderFactory + ChainSpecProvider<ChainSpec: OpHardforks> + Clone + 'static,
+    EvmConfig: ConfigureEvm<Primitives = OpPrimitives> + 'static,
+{
+    /// Creates a new service that streams flashblocks from the given `WebSocket` endpoint and
+    /// sends the pending blocks it builds to the given sender.
+    pub fn new(
+        url: Url,
+        provider: Provider,
+        evm_config: EvmConfig,
+        pending: PendingFlashBlockSender,
+    ) -> Self {
+        Self {
+            url,
+            provider,
+            evm_config,
+            sequence: FlashBlockSequence::default(),
+            execution: None,
+            pending,
+        }
+    }
+
+    /// Returns a receiver of the latest pending block.
+    pub fn subscribe(&self) -> PendingFlashBlockReceiver {
+        self.pending.subscribe()
+    }
+
+    /// Runs the service, reconnecting whenever the connection is lost.
+    pub async fn run(mut self) {
+        loop {
+            match connect_async(self.url.as_str()).await {
+                Ok((mut stream, _)) => {
+                    info!(target: "flashblocks", url = %self.url, "Connected to flashblocks");
+                    while let Some(message) = stream.next().await {
+                        let payload = match message {
+                            Ok(Message::Text(text)) => text.as_bytes().to_vec(),
+                            Ok(Message::Binary(bytes)) => bytes.to_vec(),
+                            Ok(Message::Close(_)) => break,
+                            Ok(_) => continue,
+                            Err(err) => {
+                                warn!(target: "flashblocks", %err, "Flashblocks connection failed");
+                                break
+                            }
+                        };
+                        match serde_json::from_slice::<FlashBlock>(&payload) {
+                            Ok(flashblock) => self.on_flashblock(flashblock).await,
+                            Err(err) => {
+                                debug!(target: "flashblocks", %err, "Failed to decode flashblock")
+                            }
+                        }
+                    }
+                }
+                Err(err) => {
+                    warn!(target: "flashblocks", url = %self.url, %err, "Failed to connect to flashblocks")
+                }
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    /// Adds the flashblock to the current sequence and builds the pending block.
+    async fn on_flashblock(&mut self, flashblock: FlashBlock) {
+        trace!(target: "flashblocks", payload_id = %flashblock.payload_id, index = flashblock.index, "Received flashblock");
+        let index = flashblock.index;
+        let payload_id = flashblock.payload_id;
+        if !self.sequence.insert(flashblock) {
+            debug!(target: "flashblocks", index, "Ignoring flashblock that doesn't continue the current payload");
+            return
+        }
+
+        let Some(base) = self.sequence.base() else { return };
+        let is_isthmus = self.provider.chain_spec().is_isthmus_active_at_timestamp(base.timestamp);
+        let Some(data) = self.sequence.to_execution_data(is_isthmus) else { return };
+
+        // the execution of a previous payload can't be continued
+        let execution =
+            self.execution.take().filter(|execution| execution.payload_id == payload_id);
+        let provider = self.provider.clone();
+        let evm_config = self.evm_config.clone();
+        let built = tokio::task::spawn_blocking(move || {
+            let block =
+                data.payload.try_into_block_with_sidecar::<OpTransactionSigned>(&data.sidecar)?;
+            let block = SealedBlock::seal_slow(block);
+            let mut execution = match execution {
+                Some(execution) => execution,
+                None => PayloadExecution {
+                    payload_id,
+                    state: State::builder()
+                        .with_database(StateProviderDatabase::new(
+                            provider.history_by_block_hash(block.parent_hash)?,
+                        ))
+                        .build(),
+                    senders: Vec::new(),
+                    receipts: Vec::new(),
+                    gas_used: 0,
+                },
+            };
+            let block = execute_transactions(&evm_config, &mut execution, block)?;
+            let pending = PendingFlashBlock { block, receipts: execution.receipts.clone(), index };
+            eyre::Ok((pending, execution))
+        })
+        .await;
+
+        match built {
+            Ok(Ok((pending, execution))) => {
+                debug!(target: "flashblocks", number = pending.block.number, hash = %pending.block.hash(), index, "Built pending block from flashblocks");
+                self.execution = Some(execution);
+                self.pending.send_replace(Some(Arc::new(pending)));
+            }
+            Ok(Err(err)) => {
+                debug!(target: "flashblocks", index, %err, "Failed to build pending block from flashblocks")
+            }
+            Err(err) => warn!(target: "flashblocks", %err, "Building pending block panicked"),
+        }
+    }
+}
+
+/// Executes the transactions of the block that weren't executed yet, and returns the block with
+/// the senders of all its transactions.
+///
+/// The pre-execution changes are applied before the first transaction of the payload. The
+/// cumulative gas used of the new receipts continues from the transactions that were already
+/// executed.
+fn execute_transactions<EvmConfig>(
+    evm_config: &EvmConfig,
+    execution: &mut PayloadExecution,
+    block: SealedBlock<OpBlock>,
+) -> eyre::Result<RecoveredBlock<OpBlock>>
+where
+    EvmConfig: ConfigureEvm<Primitives = OpPrimitives>,
+{
+    let executed = execution.senders.len();
+    for tx in block.body().transactions.iter().skip(executed) {
+        execution.senders.push(tx.try_recover()?);
+    }
+    let block = RecoveredBlock::new_sealed(block, execution.senders.clone());
+
+    let mut executor = evm_config.executor_for_block(&mut execution.state, block.sealed_block());
+    if executed == 0 {
+        executor.apply_pre_execution_changes()?;
+    }
+    for tx in block.transactions_recovered().skip(executed) {
+        executor.execute_transaction(tx)?;
+    }
+    let (_, result) = executor.finish()?;
+
+    for mut receipt in result.receipts {
+        receipt.as_receipt_mut().cumulative_gas_used += execution.gas_used;
+        execution.receipts.push(receipt);
+    }
+    execution.gas_used += result.gas_used;
+
+    Ok(block)
+}
@@ -2,6 +2,8 @@
 
 //! clap [Args](clap::Args) for optimism rollup configuration
 
+use reth_optimism_flashblocks::Url;
+
 /// Parameters for rollup configuration
 #[derive(Debug, Clone, PartialEq, Eq, clap::Args)]
 #[command(next_help_heading = "Rollup")]
@@ -37,6 +39,13 @@ pub struct RollupArgs {
     /// Enable transaction conditional support on sequencer
     #[arg(long = "rollup.enable-tx-conditional", default_value = "false")]
     pub enable_tx_conditional: bool,
+
+    /// `WebSocket` endpoint of the sequencer that streams flashblocks.
+    ///
+    /// If set, the pending block is built from the flashblocks, which allows `pending` queries to
+    /// be served with sub-block latency.
+    #[arg(long = "rollup.flashblocks-url", value_name = "WS_URL")]
+    pub flashblocks_url: Option<Url>,
 }
 
 #[allow(clippy::derivable_impls)]
@@ -49,6 +58,7 @@ impl Default for RollupArgs {
             compute_pending_block: false,
             discovery_v4: false,
             enable_tx_conditional: false,
+            flashblocks_url: None,
         }
     }
 }
@@ -128,6 +138,21 @@ mod tests {
         assert_eq!(args, expected_args);
     }
 
+    #[test]
+    fn test_parse_optimism_flashblocks_url() {
+        let expected_args = RollupArgs {
+            flashblocks_url: Some("wss://host:8545".parse().unwrap()),
+            ..Default::default()
+        };
+        let args = CommandParser::<RollupArgs>::parse_from([
+            "reth",
+            "--rollup.flashblocks-url",
+            "wss://host:8545",
+        ])
+        .args;
+        assert_eq!(args, expected_args);
+    }
+
     #[test]
     fn test_parse_optimism_many_args() {
         let expected_args = RollupArgs {
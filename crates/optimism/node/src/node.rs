@@ -28,6 +28,7 @@ use reth_node_builder::{
 use reth_optimism_chainspec::OpChainSpec;
 use reth_optimism_consensus::OpBeaconConsensus;
 use reth_optimism_evm::{OpEvmConfig, OpNextBlockEnvAttributes};
+use reth_optimism_flashblocks::{FlashBlockService, PendingFlashBlockSender, Url};
 use reth_optimism_forks::OpHardforks;
 use reth_optimism_payload_builder::{
     builder::OpPayloadTransactions,
@@ -123,6 +124,15 @@ impl OpNode {
             .consensus(OpConsensusBuilder::default())
     }
 
+    /// Returns an [`OpAddOnsBuilder`] configured with the rollup args of the node.
+    pub fn add_ons_builder(&self) -> OpAddOnsBuilder {
+        OpAddOnsBuilder::default()
+            .with_sequencer(self.args.sequencer_http.clone())
+            .with_flashblocks(self.args.flashblocks_url.clone())
+            .with_da_config(self.da_config.clone())
+            .with_enable_tx_conditional(self.args.enable_tx_conditional)
+    }
+
     /// Instantiates the [`ProviderFactoryBuilder`] for an opstack node.
     ///
     /// # Open a Providerfactory in read-only mode from a datadir
@@ -186,11 +196,7 @@ where
     }
 
     fn add_ons(&self) -> Self::AddOns {
-        Self::AddOns::builder()
-            .with_sequencer(self.args.sequencer_http.clone())
-            .with_da_config(self.da_config.clone())
-            .with_enable_tx_conditional(self.args.enable_tx_conditional)
-            .build()
+        self.add_ons_builder().build()
     }
 }
 
@@ -245,6 +251,9 @@ where
     pub sequencer_client: Option<SequencerClient>,
     /// Enable transaction conditionals.
     enable_tx_conditional: bool,
+    /// `WebSocket` endpoint of the sequencer that streams flashblocks, and the sender of the
+    /// pending block that is built from them.
+    flashblocks: Option<(Url, PendingFlashBlockSender)>,
 }
 
 impl<N> Default for OpAddOns<N>
@@ -289,7 +298,18 @@ where
         self,
         ctx: reth_node_api::AddOnsContext<'_, N>,
     ) -> eyre::Result<Self::Handle> {
-        let Self { rpc_add_ons, da_config, sequencer_client, enable_tx_conditional } = self;
+        let Self { rpc_add_ons, da_config, sequencer_client, enable_tx_conditional, flashblocks } =
+            self;
+
+        if let Some((url, pending)) = flashblocks {
+            let service = FlashBlockService::new(
+                url,
+                ctx.node.provider().clone(),
+                ctx.node.evm_config().clone(),
+                pending,
+            );
+            ctx.node.task_executor().spawn(Box::pin(service.run()));
+        }
 
         let builder = reth_optimism_payload_builder::OpPayloadBuilder::new(
             ctx.node.pool().clone(),
@@ -310,7 +330,7 @@ where
             ctx.node.provider().clone(),
         );
         rpc_add_ons
-            .launch_add_ons_with(ctx, move |modules, auth_modules| {
+            .launch_add_ons_with(ctx, move |modules, auth_modules, _| {
                 debug!(target: "reth::cli", "Installing debug payload witness rpc endpoint");
                 modules.merge_if_module_configured(RethRpcModule::Debug, debug_ext.into_rpc())?;
 
@@ -391,6 +411,8 @@ pub struct OpAddOnsBuilder {
     da_config: Option<OpDAConfig>,
     /// Enable transaction conditionals.
     enable_tx_conditional: bool,
+    /// `WebSocket` endpoint of the sequencer that streams flashblocks.
+    flashblocks_url: Option<Url>,
 }
 
 impl OpAddOnsBuilder {
@@ -400,6 +422,13 @@ impl OpAddOnsBuilder {
         self
     }
 
+    /// With a `WebSocket` endpoint of the sequencer that streams flashblocks, from which the
+    /// pending block is built.
+    pub fn with_flashblocks(mut self, flashblocks_url: Option<Url>) -> Self {
+        self.flashblocks_url = flashblocks_url;
+        self
+    }
+
     /// Configure the data availability configuration for the OP builder.
     pub fn with_da_config(mut self, da_config: OpDAConfig) -> Self {
         self.da_config = Some(da_config);
@@ -420,18 +449,23 @@ impl OpAddOnsBuilder {
         N: FullNodeComponents<Types: NodeTypes<Primitives = OpPrimitives>>,
         OpEthApiBuilder: EthApiBuilder<N>,
     {
-        let Self { sequencer_client, da_config, enable_tx_conditional } = self;
+        let Self { sequencer_client, da_config, enable_tx_conditional, flashblocks_url } = self;
+
+        let flashblocks = flashblocks_url.map(|url| (url, PendingFlashBlockSender::new(None)));
 
         let sequencer_client_clone = sequencer_client.clone();
         OpAddOns {
             rpc_add_ons: RpcAddOns::new(
-                OpEthApiBuilder::default().with_sequencer(sequencer_client_clone),
+                OpEthApiBuilder::default()
+                    .with_sequencer(sequencer_client_clone)
+                    .with_flashblocks(flashblocks.as_ref().map(|(_, pending)| pending.subscribe())),
                 Default::default(),
                 Default::default(),
             ),
             da_config: da_config.unwrap_or_default(),
             sequencer_client,
             enable_tx_conditional,
+            flashblocks,
         }
     }
 }
@@ -587,7 +621,7 @@ where
             }
         }
 
-        Ok(transaction_pool)
+        Ok(Either::Left(transaction_pool))
     }
 }
 
@@ -724,13 +758,13 @@ impl OpNetworkBuilder {
                 if disable_discovery_v4 || args.discovery.disable_discovery {
                     builder = builder.disable_discv4_discovery();
                 }
-                if !args.discovery.disable_discovery {
+                if !args.discovery.disable_discovery && !args.no_network {
                     builder = builder.discovery_v5(
                         args.discovery.discovery_v5_builder(
                             rlpx_socket,
                             ctx.config()
                                 .network
-                                .resolved_bootnodes()
+                                .resolved_bootnodes_with_config(ctx.reth_config())
                                 .or_else(|| ctx.chain_spec().bootnodes())
                                 .unwrap_or_default(),
                         ),
@@ -745,7 +779,7 @@ impl OpNetworkBuilder {
         // When `sequencer_endpoint` is configured, the node will forward all transactions to a
         // Sequencer node for execution and inclusion on L1, and disable its own txpool
         // gossip to prevent other parties in the network from learning about them.
-        network_config.tx_gossip_disabled = disable_txpool_gossip;
+        network_config.tx_gossip_disabled |= disable_txpool_gossip;
 
         Ok(network_config)
     }
@@ -49,7 +49,7 @@ where
             version: CARGO_PKG_VERSION.to_string(),
             commit: VERGEN_GIT_SHA.to_string(),
         };
-        let inner = EngineApi::new(
+        let mut inner = EngineApi::new(
             ctx.node.provider().clone(),
             ctx.config.chain.clone(),
             ctx.beacon_engine_handle.clone(),
@@ -60,6 +60,10 @@ where
             EngineCapabilities::new(OP_ENGINE_CAPABILITIES.iter().copied()),
             engine_validator,
         );
+        if ctx.config.engine.backfill_progress {
+            inner =
+                inner.with_backfill_progress(ctx.beacon_engine_handle.backfill_progress().clone());
+        }
 
         Ok(OpEngineApi::new(inner))
     }
@@ -143,6 +143,18 @@ impl SignedTransaction for OpTransactionSigned {
         recover_signer_unchecked(&self.signature, keccak256(buf))
     }
 
+    fn signature_hash_with_buf(&self, buf: &mut Vec<u8>) -> Option<B256> {
+        buf.clear();
+        match &self.transaction {
+            OpTypedTransaction::Deposit(_) => return None,
+            OpTypedTransaction::Legacy(tx) => tx.encode_for_signing(buf),
+            OpTypedTransaction::Eip2930(tx) => tx.encode_for_signing(buf),
+            OpTypedTransaction::Eip1559(tx) => tx.encode_for_signing(buf),
+            OpTypedTransaction::Eip7702(tx) => tx.encode_for_signing(buf),
+        };
+        Some(keccak256(buf))
+    }
+
     fn recalculate_hash(&self) -> B256 {
         keccak256(self.encoded_2718())
     }
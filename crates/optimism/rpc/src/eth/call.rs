@@ -4,7 +4,9 @@ use alloy_consensus::TxType;
 use alloy_primitives::{Bytes, TxKind, U256};
 use alloy_rpc_types_eth::transaction::TransactionRequest;
 use op_revm::OpTransaction;
-use reth_evm::{execute::BlockExecutorFactory, ConfigureEvm, EvmEnv, EvmFactory, SpecFor};
+use reth_evm::{
+    execute::BlockExecutorFactory, CallEnvOverrides, ConfigureEvm, EvmEnv, EvmFactory, SpecFor,
+};
 use reth_node_api::NodePrimitives;
 use reth_provider::{ProviderHeader, ProviderTx};
 use reth_rpc_eth_api::{
@@ -56,6 +58,11 @@ where
         self.inner.eth_api.max_simulate_blocks()
     }
 
+    #[inline]
+    fn call_env_overrides(&self) -> CallEnvOverrides {
+        self.inner.eth_api.call_env_overrides()
+    }
+
     fn create_txn_env(
         &self,
         evm_env: &EvmEnv<SpecFor<Self::Evm>>,
@@ -10,13 +10,14 @@ mod pending_block;
 
 pub use receipt::{OpReceiptBuilder, OpReceiptFieldsBuilder};
 
-use alloy_primitives::U256;
+use alloy_primitives::{U256, U64};
 use op_alloy_network::Optimism;
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_evm::ConfigureEvm;
 use reth_network_api::NetworkInfo;
 use reth_node_api::{BlockTy, FullNodeComponents, NodePrimitives, ReceiptTy};
 use reth_node_builder::rpc::EthApiBuilder;
+use reth_optimism_flashblocks::{PendingFlashBlock, PendingFlashBlockReceiver};
 use reth_optimism_primitives::OpPrimitives;
 use reth_provider::{
     BlockNumReader, BlockReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
@@ -31,7 +32,9 @@ use reth_rpc_eth_api::{
     },
     EthApiTypes, FromEvmError, FullEthApiServer, RpcNodeCore, RpcNodeCoreExt,
 };
-use reth_rpc_eth_types::{EthConfig, EthStateCache, FeeHistoryCache, GasPriceOracle};
+use reth_rpc_eth_types::{
+    EthConfig, EthStateCache, FeeHistoryCache, GasPriceOracle, SyncStageProgress,
+};
 use reth_tasks::{
     pool::{BlockingTaskGuard, BlockingTaskPool},
     TaskSpawner,
@@ -89,6 +92,12 @@ where
         self.inner.sequencer_client()
     }
 
+    /// Returns the latest pending block built from the flashblocks of the sequencer, if
+    /// flashblocks are configured and one was built.
+    pub fn pending_flashblock(&self) -> Option<Arc<PendingFlashBlock>> {
+        self.inner.pending_flashblock()
+    }
+
     /// Build a [`OpEthApi`] using [`OpEthApiBuilder`].
     pub const fn builder() -> OpEthApiBuilder {
         OpEthApiBuilder::new()
@@ -176,6 +185,20 @@ where
     fn signers(&self) -> &parking_lot::RwLock<Vec<Box<dyn EthSigner<ProviderTx<Self::Provider>>>>> {
         self.inner.eth_api.signers()
     }
+
+    #[inline]
+    fn chain_id(&self) -> U64 {
+        self.inner
+            .eth_api
+            .rpc_chain_id()
+            .map(U64::from)
+            .unwrap_or_else(|| self.execution_chain_id())
+    }
+
+    #[inline]
+    fn sync_stage_progress(&self) -> Option<&SyncStageProgress> {
+        self.inner.eth_api.sync_stage_progress()
+    }
 }
 
 impl<N> SpawnBlocking for OpEthApi<N>
@@ -284,6 +307,8 @@ struct OpEthApiInner<N: OpNodeCore> {
     /// Sequencer client, configured to forward submitted transactions to sequencer of given OP
     /// network.
     sequencer_client: Option<SequencerClient>,
+    /// Receiver of the pending block built from the flashblocks of the sequencer, if configured.
+    flashblocks: Option<PendingFlashBlockReceiver>,
 }
 
 impl<N: OpNodeCore> OpEthApiInner<N> {
@@ -296,6 +321,11 @@ impl<N: OpNodeCore> OpEthApiInner<N> {
     const fn sequencer_client(&self) -> Option<&SequencerClient> {
         self.sequencer_client.as_ref()
     }
+
+    /// Returns the latest pending block built from flashblocks, if any.
+    fn pending_flashblock(&self) -> Option<Arc<PendingFlashBlock>> {
+        self.flashblocks.as_ref().and_then(|flashblocks| flashblocks.borrow().clone())
+    }
 }
 
 /// Builds [`OpEthApi`] for Optimism.
@@ -304,12 +334,15 @@ pub struct OpEthApiBuilder {
     /// Sequencer client, configured to forward submitted transactions to sequencer of given OP
     /// network.
     sequencer_client: Option<SequencerClient>,
+    /// Receiver of the pending block built from the flashblocks of the sequencer, used to serve
+    /// `pending` queries.
+    flashblocks: Option<PendingFlashBlockReceiver>,
 }
 
 impl OpEthApiBuilder {
     /// Creates a [`OpEthApiBuilder`] instance from core components.
     pub const fn new() -> Self {
-        Self { sequencer_client: None }
+        Self { sequencer_client: None, flashblocks: None }
     }
 
     /// With a [`SequencerClient`].
@@ -317,6 +350,12 @@ impl OpEthApiBuilder {
         self.sequencer_client = sequencer_client;
         self
     }
+
+    /// With a receiver of the pending block built from the flashblocks of the sequencer.
+    pub fn with_flashblocks(mut self, flashblocks: Option<PendingFlashBlockReceiver>) -> Self {
+        self.flashblocks = flashblocks;
+        self
+    }
 }
 
 impl<N> EthApiBuilder<N> for OpEthApiBuilder
@@ -332,7 +371,7 @@ where
         config: EthConfig,
         cache: EthStateCache<BlockTy<N::Types>, ReceiptTy<N::Types>>,
     ) -> Self::EthApi {
-        let Self { sequencer_client } = self;
+        let Self { sequencer_client, flashblocks } = self;
         let eth_api = reth_rpc::EthApiBuilder::new(
             core_components.provider().clone(),
             core_components.pool().clone(),
@@ -343,11 +382,15 @@ where
         .task_spawner(core_components.task_executor().clone())
         .gas_cap(config.rpc_gas_cap.into())
         .max_simulate_blocks(config.rpc_max_simulate_blocks)
+        .call_env_overrides(config.call_env_overrides)
+        .tx_lookup(config.tx_lookup)
+        .rpc_chain_id(config.rpc_chain_id)
+        .syncing_stage_detail(config.syncing_stage_detail)
         .eth_proof_window(config.eth_proof_window)
         .fee_history_cache_config(config.fee_history_cache)
         .proof_permits(config.proof_permits)
         .build_inner();
 
-        OpEthApi { inner: Arc::new(OpEthApiInner { eth_api, sequencer_client }) }
+        OpEthApi { inner: Arc::new(OpEthApiInner { eth_api, sequencer_client, flashblocks }) }
     }
 }
@@ -91,6 +91,15 @@ where
             .latest_header()
             .map_err(Self::Error::from_eth_err)?
             .ok_or(EthApiError::HeaderNotFound(BlockNumberOrTag::Latest.into()))?;
+
+        // serve the block built from the flashblocks of the sequencer, if it extends the latest
+        // block
+        if let Some(pending) = self.inner.pending_flashblock() {
+            if pending.block.parent_hash() == latest.hash() {
+                return Ok(Some((pending.block.clone(), pending.receipts.clone())))
+            }
+        }
+
         let block_id = latest.hash().into();
         let block = self
             .provider()
@@ -10,7 +10,8 @@ use reth_optimism_chainspec::OpChainSpec;
 use reth_optimism_evm::RethL1BlockInfo;
 use reth_optimism_forks::OpHardforks;
 use reth_optimism_primitives::{OpReceipt, OpTransactionSigned};
-use reth_provider::{ChainSpecProvider, ReceiptProvider, TransactionsProvider};
+use reth_primitives_traits::RecoveredBlock;
+use reth_provider::{ChainSpecProvider, ProviderBlock, ReceiptProvider, TransactionsProvider};
 use reth_rpc_eth_api::{helpers::LoadReceipt, FromEthApiError, RpcReceipt};
 use reth_rpc_eth_types::{receipt::build_receipt, EthApiError};
 
@@ -40,15 +41,26 @@ where
                 meta.block_hash.into(),
             )))?;
 
+        self.build_transaction_receipt_with_block(&tx, meta, &receipt, &block, &receipts).await
+    }
+
+    async fn build_transaction_receipt_with_block(
+        &self,
+        tx: &OpTransactionSigned,
+        meta: TransactionMeta,
+        receipt: &OpReceipt,
+        block: &RecoveredBlock<ProviderBlock<Self::Provider>>,
+        all_receipts: &[OpReceipt],
+    ) -> Result<RpcReceipt<Self::NetworkTypes>, Self::Error> {
         let mut l1_block_info =
             reth_optimism_evm::extract_l1_info(block.body()).map_err(OpEthApiError::from)?;
 
         Ok(OpReceiptBuilder::new(
             &self.inner.eth_api.provider().chain_spec(),
-            &tx,
+            tx,
             meta,
-            &receipt,
-            &receipts,
+            receipt,
+            all_receipts,
             &mut l1_block_info,
         )?
         .build())
@@ -28,13 +28,13 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 use tokio::{
     sync::{oneshot, Semaphore},
     time::{Interval, Sleep},
 };
-use tracing::{debug, trace, warn};
+use tracing::{debug, debug_span, trace, warn};
 
 mod better_payload_emitter;
 mod metrics;
@@ -175,6 +175,9 @@ where
             pending_block: None,
             cached_reads,
             payload_task_guard: self.payload_task_guard.clone(),
+            build_time: Duration::ZERO,
+            max_build_time: self.config.max_build_time,
+            build_time_limit: None,
             metrics: Default::default(),
             builder: self.builder.clone(),
         };
@@ -248,6 +251,10 @@ pub struct BasicPayloadJobGeneratorConfig {
     deadline: Duration,
     /// Maximum number of tasks to spawn for building a payload.
     max_payload_tasks: usize,
+    /// The maximum time a job spends building payloads, summed over all its build attempts.
+    ///
+    /// Unlimited by default.
+    max_build_time: Option<Duration>,
 }
 
 // === impl BasicPayloadJobGeneratorConfig ===
@@ -275,6 +282,15 @@ impl BasicPayloadJobGeneratorConfig {
         self.max_payload_tasks = max_payload_tasks;
         self
     }
+
+    /// Sets the maximum time a job spends building payloads, summed over all its build attempts.
+    ///
+    /// Once a job exhausts it, its in-progress build is cancelled and the job keeps the best
+    /// payload built so far without building further payloads.
+    pub const fn max_build_time(mut self, max_build_time: Duration) -> Self {
+        self.max_build_time = Some(max_build_time);
+        self
+    }
 }
 
 impl Default for BasicPayloadJobGeneratorConfig {
@@ -284,6 +300,7 @@ impl Default for BasicPayloadJobGeneratorConfig {
             // 12s slot time
             deadline: SLOT_DURATION,
             max_payload_tasks: 3,
+            max_build_time: None,
         }
     }
 }
@@ -298,6 +315,11 @@ impl Default for BasicPayloadJobGeneratorConfig {
 /// [`BuildOutcome::Freeze`]. Once a frozen payload is returned, no additional payloads will be
 /// built and this future will wait to be resolved: [`PayloadJob::resolve`] or terminated if the
 /// deadline is reached..
+///
+/// The same applies once the job exhausted its build time, see
+/// [`BasicPayloadJobGeneratorConfig::max_build_time`].
+///
+/// Dropping the job cancels the in-progress build, e.g. when the job is superseded by a newer one.
 #[derive(Debug)]
 pub struct BasicPayloadJob<Tasks, Builder>
 where
@@ -322,6 +344,12 @@ where
     /// This is used to avoid reading the same state over and over again when new attempts are
     /// triggered, because during the building process we'll repeatedly execute the transactions.
     cached_reads: Option<CachedReads>,
+    /// The time spent on the build attempts of this job so far.
+    build_time: Duration,
+    /// The maximum time to spend on build attempts.
+    max_build_time: Option<Duration>,
+    /// Fires when the in-progress build attempt exhausts the remaining build time of the job.
+    build_time_limit: Option<Pin<Box<Sleep>>>,
     /// metrics for this type
     metrics: PayloadBuilderMetrics,
     /// The type responsible for building payloads.
@@ -349,16 +377,56 @@ where
         self.metrics.inc_initiated_payload_builds();
         let cached_reads = self.cached_reads.take().unwrap_or_default();
         let builder = self.builder.clone();
+        let span =
+            debug_span!(target: "payload_builder", "build_payload", id = %self.config.payload_id());
         self.executor.spawn_blocking(Box::pin(async move {
             // acquire the permit for executing the task
             let _permit = guard.acquire().await;
+            if cancel.is_cancelled() {
+                // the job was dropped while waiting for the permit
+                let _ = tx.send(Ok(BuildOutcome::Cancelled));
+                return
+            }
             let args =
                 BuildArguments { cached_reads, config: payload_config, cancel, best_payload };
-            let result = builder.try_build(args);
+            let result = span.in_scope(|| builder.try_build(args));
             let _ = tx.send(result);
         }));
 
-        self.pending_block = Some(PendingPayload { _cancel, payload: rx });
+        let pending = PendingPayload::new(_cancel, rx);
+        if let Some(max_build_time) = self.max_build_time {
+            let remaining = max_build_time.saturating_sub(self.build_time);
+            self.build_time_limit = Some(Box::pin(tokio::time::sleep(remaining)));
+        }
+        self.pending_block = Some(pending);
+    }
+
+    /// Returns `true` if the job spent its maximum build time.
+    fn is_build_time_exhausted(&self) -> bool {
+        self.max_build_time.is_some_and(|max_build_time| self.build_time >= max_build_time)
+    }
+
+    /// Accounts the time spent on a finished or cancelled build attempt.
+    fn record_build_time(&mut self, elapsed: Duration) {
+        let was_exhausted = self.is_build_time_exhausted();
+        self.build_time += elapsed;
+        self.build_time_limit = None;
+        if !was_exhausted && self.is_build_time_exhausted() {
+            debug!(target: "payload_builder", id = %self.config.payload_id(), build_time = ?self.build_time, "payload job exhausted its build time, no further payloads will be built");
+            self.metrics.inc_build_time_exhausted_jobs();
+        }
+    }
+}
+
+impl<Tasks, Builder> Drop for BasicPayloadJob<Tasks, Builder>
+where
+    Builder: PayloadBuilder,
+{
+    fn drop(&mut self) {
+        if self.pending_block.is_some() {
+            // dropping the pending block cancels the in-progress build
+            self.metrics.inc_cancelled_payload_builds();
+        }
     }
 }
 
@@ -377,21 +445,42 @@ where
         // check if the deadline is reached
         if this.deadline.as_mut().poll(cx).is_ready() {
             trace!(target: "payload_builder", "payload building deadline reached");
+            this.metrics.inc_expired_jobs();
             return Poll::Ready(Ok(()))
         }
 
+        // cancel the in-progress build if it exhausted the build time of the job
+        if let Some(limit) = this.build_time_limit.as_mut() {
+            if limit.as_mut().poll(cx).is_ready() {
+                if let Some(pending) = this.pending_block.take() {
+                    let remaining =
+                        this.max_build_time.unwrap_or_default().saturating_sub(this.build_time);
+                    this.metrics.inc_cancelled_payload_builds();
+                    this.record_build_time(pending.started_at.elapsed().max(remaining));
+                }
+                this.build_time_limit = None;
+            }
+        }
+
         // check if the interval is reached
         while this.interval.poll_tick(cx).is_ready() {
             // start a new job if there is no pending block, we haven't reached the deadline,
-            // and the payload isn't frozen
-            if this.pending_block.is_none() && !this.best_payload.is_frozen() {
+            // the payload isn't frozen and there's build time left
+            if this.pending_block.is_none() &&
+                !this.best_payload.is_frozen() &&
+                !this.is_build_time_exhausted()
+            {
                 this.spawn_build_job();
             }
         }
 
         // poll the pending block
         if let Some(mut fut) = this.pending_block.take() {
-            match fut.poll_unpin(cx) {
+            let poll = fut.poll_unpin(cx);
+            if poll.is_ready() {
+                this.record_build_time(fut.started_at.elapsed());
+            }
+            match poll {
                 Poll::Ready(Ok(outcome)) => match outcome {
                     BuildOutcome::Better { payload, cached_reads } => {
                         this.cached_reads = Some(cached_reads);
@@ -630,17 +719,19 @@ where
 pub struct PendingPayload<P> {
     /// The marker to cancel the job on drop
     _cancel: CancelOnDrop,
+    /// When the build job was started.
+    started_at: Instant,
     /// The channel to send the result to.
     payload: oneshot::Receiver<Result<BuildOutcome<P>, PayloadBuilderError>>,
 }
 
 impl<P> PendingPayload<P> {
     /// Constructs a `PendingPayload` future.
-    pub const fn new(
+    pub fn new(
         cancel: CancelOnDrop,
         payload: oneshot::Receiver<Result<BuildOutcome<P>, PayloadBuilderError>>,
     ) -> Self {
-        Self { _cancel: cancel, payload }
+        Self { _cancel: cancel, started_at: Instant::now(), payload }
     }
 }
 
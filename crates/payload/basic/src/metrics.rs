@@ -12,6 +12,13 @@ pub(crate) struct PayloadBuilderMetrics {
     pub(crate) initiated_payload_builds: Counter,
     /// Total number of failed payload build attempts.
     pub(crate) failed_payload_builds: Counter,
+    /// Total number of in-progress payload build attempts that were cancelled, because their job
+    /// was dropped or exhausted its build time.
+    pub(crate) cancelled_payload_builds: Counter,
+    /// Total number of payload jobs that reached their deadline before they were resolved.
+    pub(crate) expired_payload_jobs: Counter,
+    /// Total number of payload jobs that exhausted their maximum build time.
+    pub(crate) build_time_exhausted_payload_jobs: Counter,
 }
 
 impl PayloadBuilderMetrics {
@@ -26,4 +33,16 @@ impl PayloadBuilderMetrics {
     pub(crate) fn inc_failed_payload_builds(&self) {
         self.failed_payload_builds.increment(1);
     }
+
+    pub(crate) fn inc_cancelled_payload_builds(&self) {
+        self.cancelled_payload_builds.increment(1);
+    }
+
+    pub(crate) fn inc_expired_jobs(&self) {
+        self.expired_payload_jobs.increment(1);
+    }
+
+    pub(crate) fn inc_build_time_exhausted_jobs(&self) {
+        self.build_time_exhausted_payload_jobs.increment(1);
+    }
 }
@@ -20,6 +20,7 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::{Duration, Instant},
 };
 use tokio::sync::{
     broadcast, mpsc,
@@ -208,6 +209,11 @@ where
     generator: Gen,
     /// All active payload jobs.
     payload_jobs: Vec<(Gen::Job, PayloadId)>,
+    /// Jobs that were superseded by a newer job, with the instant their payload id expires.
+    ///
+    /// These are no longer polled, so they stop building, but their best payload can still be
+    /// retrieved until the id expires.
+    superseded_jobs: Vec<(Gen::Job, PayloadId, Instant)>,
     /// Copy of the sender half, so new [`PayloadBuilderHandle`] can be created on demand.
     service_tx: mpsc::UnboundedSender<PayloadServiceCommand<T>>,
     /// Receiver half of the command channel.
@@ -222,6 +228,11 @@ where
 
 const PAYLOAD_EVENTS_BUFFER_SIZE: usize = 20;
 
+/// How long the payload of a superseded job remains resolvable.
+///
+/// This matches the default payload job deadline of one slot.
+const SUPERSEDED_PAYLOAD_TTL: Duration = Duration::from_secs(12);
+
 // === impl PayloadBuilderService ===
 
 impl<Gen, St, T> PayloadBuilderService<Gen, St, T>
@@ -244,6 +255,7 @@ where
         let service = Self {
             generator,
             payload_jobs: Vec::new(),
+            superseded_jobs: Vec::new(),
             service_tx,
             command_rx: UnboundedReceiverStream::new(command_rx),
             metrics: Default::default(),
@@ -265,13 +277,52 @@ where
         self.payload_jobs.iter().any(|(_, job_id)| *job_id == id)
     }
 
+    /// Stops the jobs that are superseded by a new job with the given attributes.
+    ///
+    /// A job is superseded if it builds on another parent, or for an earlier timestamp, because
+    /// the consensus layer moved on. Superseded jobs are no longer polled, which stops further
+    /// building, but the consensus layer may still request their payload with the id it was
+    /// given, so they are kept until [`SUPERSEDED_PAYLOAD_TTL`] elapsed.
+    fn cancel_superseded_jobs(&mut self, attr: &<Gen::Job as PayloadJob>::PayloadAttributes) {
+        let (parent, timestamp) = (attr.parent(), attr.timestamp());
+        let expires_at = Instant::now() + SUPERSEDED_PAYLOAD_TTL;
+        for idx in (0..self.payload_jobs.len()).rev() {
+            let superseded = self.payload_jobs[idx].0.payload_attributes().is_ok_and(|job_attr| {
+                job_attr.parent() != parent || job_attr.timestamp() < timestamp
+            });
+            if superseded {
+                let (job, id) = self.payload_jobs.swap_remove(idx);
+                debug!(%id, %parent, "Stopping superseded payload job");
+                self.metrics.inc_superseded_jobs();
+                self.superseded_jobs.push((job, id, expires_at));
+            }
+        }
+        self.metrics.set_active_jobs(self.payload_jobs.len());
+    }
+
+    /// Drops the superseded jobs whose payload id expired.
+    fn remove_expired_jobs(&mut self) {
+        let now = Instant::now();
+        self.superseded_jobs.retain(|(_, id, expires_at)| {
+            let expired = *expires_at <= now;
+            if expired {
+                trace!(%id, "removed expired superseded payload job");
+            }
+            !expired
+        });
+    }
+
+    /// Returns the active or superseded job for the given identifier.
+    fn find_job(&self, id: PayloadId) -> Option<&Gen::Job> {
+        if let Some((job, _)) = self.payload_jobs.iter().find(|(_, job_id)| *job_id == id) {
+            return Some(job)
+        }
+        self.superseded_jobs.iter().find(|(_, job_id, _)| *job_id == id).map(|(job, ..)| job)
+    }
+
     /// Returns the best payload for the given identifier that has been built so far.
     fn best_payload(&self, id: PayloadId) -> Option<Result<T::BuiltPayload, PayloadBuilderError>> {
-        let res = self
-            .payload_jobs
-            .iter()
-            .find(|(_, job_id)| *job_id == id)
-            .map(|(j, _)| j.best_payload().map(|p| p.into()));
+        let res = self.find_job(id).map(|j| j.best_payload().map(|p| p.into()));
         if let Some(Ok(ref best)) = res {
             self.metrics.set_best_revenue(best.block().number(), f64::from(best.fees()));
         }
@@ -288,11 +339,23 @@ where
     ) -> Option<PayloadFuture<T::BuiltPayload>> {
         trace!(%id, "resolving payload job");
 
-        let job = self.payload_jobs.iter().position(|(_, job_id)| *job_id == id)?;
-        let (fut, keep_alive) = self.payload_jobs[job].0.resolve_kind(kind);
+        let (fut, keep_alive) =
+            if let Some(job) = self.payload_jobs.iter().position(|(_, job_id)| *job_id == id) {
+                let (fut, keep_alive) = self.payload_jobs[job].0.resolve_kind(kind);
+                if keep_alive == KeepPayloadJobAlive::No {
+                    self.payload_jobs.swap_remove(job);
+                }
+                (fut, keep_alive)
+            } else {
+                let job = self.superseded_jobs.iter().position(|(_, job_id, _)| *job_id == id)?;
+                let (fut, keep_alive) = self.superseded_jobs[job].0.resolve_kind(kind);
+                if keep_alive == KeepPayloadJobAlive::No {
+                    self.superseded_jobs.swap_remove(job);
+                }
+                (fut, keep_alive)
+            };
 
         if keep_alive == KeepPayloadJobAlive::No {
-            let (_, id) = self.payload_jobs.swap_remove(job);
             trace!(%id, "terminated resolved job");
         }
 
@@ -328,11 +391,7 @@ where
         &self,
         id: PayloadId,
     ) -> Option<Result<<Gen::Job as PayloadJob>::PayloadAttributes, PayloadBuilderError>> {
-        let attributes = self
-            .payload_jobs
-            .iter()
-            .find(|(_, job_id)| *job_id == id)
-            .map(|(j, _)| j.payload_attributes());
+        let attributes = self.find_job(id).map(|j| j.payload_attributes());
 
         if attributes.is_none() {
             trace!(%id, "no matching payload job found to get attributes for");
@@ -386,6 +445,8 @@ where
                 }
             }
 
+            this.remove_expired_jobs();
+
             // marker for exit condition
             let mut new_job = false;
 
@@ -406,6 +467,10 @@ where
                                     info!(%id, %parent, "New payload job created");
                                     this.metrics.inc_initiated_jobs();
                                     new_job = true;
+                                    // the consensus layer asked for this payload again, so it
+                                    // is no longer superseded
+                                    this.superseded_jobs.retain(|(_, job_id, _)| *job_id != id);
+                                    this.cancel_superseded_jobs(&attr);
                                     this.payload_jobs.push((job, id));
                                     this.payload_events.send(Events::Attributes(attr.clone())).ok();
                                 }
@@ -488,3 +553,70 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_payload_service;
+    use alloy_primitives::{Address, B256};
+    use alloy_rpc_types::engine::PayloadAttributes;
+    use reth_ethereum_engine_primitives::{EthPayloadBuilderAttributes, EthPayloadTypes};
+
+    fn attributes(parent: B256, timestamp: u64) -> EthPayloadBuilderAttributes {
+        EthPayloadBuilderAttributes::new(
+            parent,
+            PayloadAttributes {
+                timestamp,
+                prev_randao: B256::ZERO,
+                suggested_fee_recipient: Address::ZERO,
+                withdrawals: None,
+                parent_beacon_block_root: None,
+            },
+        )
+    }
+
+    #[test]
+    fn superseded_payload_remains_resolvable() {
+        let (mut service, handle) = test_payload_service::<EthPayloadTypes>();
+
+        let old = attributes(B256::with_last_byte(1), 1);
+        let old_id = old.payload_id();
+        let _ = handle.send_new_payload(old);
+        assert!((&mut service).now_or_never().is_none());
+
+        let new = attributes(B256::with_last_byte(2), 2);
+        let new_id = new.payload_id();
+        let _ = handle.send_new_payload(new);
+        assert!((&mut service).now_or_never().is_none());
+
+        // the old job is no longer built, but its payload can still be retrieved
+        assert_eq!(service.payload_jobs.len(), 1);
+        assert_eq!(service.payload_jobs[0].1, new_id);
+        assert!(matches!(service.best_payload(old_id), Some(Ok(_))));
+        assert!(matches!(service.payload_attributes(old_id), Some(Ok(_))));
+
+        assert!(service.resolve(old_id, PayloadKind::Earliest).is_some());
+        assert!(service.superseded_jobs.is_empty());
+        assert!(service.best_payload(old_id).is_none());
+    }
+
+    #[test]
+    fn superseded_payload_expires() {
+        let (mut service, handle) = test_payload_service::<EthPayloadTypes>();
+
+        let parent = B256::with_last_byte(1);
+        let old = attributes(parent, 1);
+        let old_id = old.payload_id();
+        let _ = handle.send_new_payload(old);
+        let _ = handle.send_new_payload(attributes(parent, 2));
+        assert!((&mut service).now_or_never().is_none());
+
+        assert_eq!(service.superseded_jobs.len(), 1);
+        assert!(service.best_payload(old_id).is_some());
+
+        service.superseded_jobs[0].2 = Instant::now();
+        service.remove_expired_jobs();
+        assert!(service.best_payload(old_id).is_none());
+        assert!(service.resolve(old_id, PayloadKind::Earliest).is_none());
+    }
+}
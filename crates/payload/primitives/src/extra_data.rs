@@ -0,0 +1,112 @@
+//! Templated extra data for built payloads.
+
+use alloc::string::String;
+use alloy_consensus::constants::MAXIMUM_EXTRA_DATA_SIZE;
+use alloy_primitives::Bytes;
+use core::{fmt, str::FromStr};
+use serde::{Deserialize, Serialize};
+
+/// Placeholder that is replaced with the block number.
+const NUMBER_PLACEHOLDER: &str = "{number}";
+
+/// Placeholder that is replaced with the block timestamp.
+const TIMESTAMP_PLACEHOLDER: &str = "{timestamp}";
+
+/// The largest value with the number of digits that block numbers and timestamps are assumed to
+/// have when checking the length of a template.
+const MAX_RENDERED_VALUE: u64 = 9_999_999_999;
+
+/// Extra data of built payloads, with `{number}` and `{timestamp}` placeholders that are replaced
+/// with the values of the block.
+///
+/// Templates are rejected if the rendered extra data could exceed [`MAXIMUM_EXTRA_DATA_SIZE`]
+/// bytes for block numbers and timestamps of up to ten digits. Longer renders are truncated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ExtraDataTemplate(String);
+
+impl ExtraDataTemplate {
+    /// Creates a new template, returning an error if the rendered extra data is too long.
+    pub fn new(template: impl Into<String>) -> Result<Self, ExtraDataTooLong> {
+        let this = Self(template.into());
+        let len = this.render_untruncated(MAX_RENDERED_VALUE, MAX_RENDERED_VALUE).len();
+        if len > MAXIMUM_EXTRA_DATA_SIZE {
+            return Err(ExtraDataTooLong { len })
+        }
+        Ok(this)
+    }
+
+    /// Returns the template.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Renders the extra data of the block with the given number and timestamp, truncated to
+    /// [`MAXIMUM_EXTRA_DATA_SIZE`] bytes.
+    pub fn render(&self, number: u64, timestamp: u64) -> Bytes {
+        let mut extra_data = self.render_untruncated(number, timestamp).into_bytes();
+        extra_data.truncate(MAXIMUM_EXTRA_DATA_SIZE);
+        extra_data.into()
+    }
+
+    fn render_untruncated(&self, number: u64, timestamp: u64) -> String {
+        self.0
+            .replace(NUMBER_PLACEHOLDER, &alloc::format!("{number}"))
+            .replace(TIMESTAMP_PLACEHOLDER, &alloc::format!("{timestamp}"))
+    }
+}
+
+impl fmt::Display for ExtraDataTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for ExtraDataTemplate {
+    type Err = ExtraDataTooLong;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for ExtraDataTemplate {
+    type Error = ExtraDataTooLong;
+
+    fn try_from(template: String) -> Result<Self, Self::Error> {
+        Self::new(template)
+    }
+}
+
+impl From<ExtraDataTemplate> for String {
+    fn from(template: ExtraDataTemplate) -> Self {
+        template.0
+    }
+}
+
+/// Error returned if an [`ExtraDataTemplate`] can render to more than
+/// [`MAXIMUM_EXTRA_DATA_SIZE`] bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error(
+    "extra data template renders up to {len} bytes, at most {MAXIMUM_EXTRA_DATA_SIZE} are allowed"
+)]
+pub struct ExtraDataTooLong {
+    /// The maximum length of the rendered extra data.
+    pub len: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_extra_data_template() {
+        let template = ExtraDataTemplate::new("reth/{number}/{timestamp}").unwrap();
+        assert_eq!(template.render(7, 12), Bytes::from_static(b"reth/7/12"));
+
+        assert_eq!(
+            ExtraDataTemplate::new("reth/{number}/{timestamp}/overflow"),
+            Err(ExtraDataTooLong { len: 35 })
+        );
+    }
+}
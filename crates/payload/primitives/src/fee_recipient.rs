@@ -0,0 +1,129 @@
+//! Fee recipient rotation for built payloads.
+
+use alloc::string::ToString;
+use alloy_primitives::Address;
+use core::{fmt, str::FromStr};
+use serde::{Deserialize, Serialize};
+
+/// A fee recipient of built payloads, together with its share of the blocks in a rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeRecipient {
+    /// The address that receives the fees of the block.
+    pub address: Address,
+    /// The number of consecutive blocks that are built for this recipient in each round of the
+    /// rotation.
+    #[serde(default = "default_weight")]
+    pub weight: u64,
+}
+
+const fn default_weight() -> u64 {
+    1
+}
+
+impl FeeRecipient {
+    /// Creates a new fee recipient with a weight of 1.
+    pub const fn new(address: Address) -> Self {
+        Self { address, weight: 1 }
+    }
+
+    /// Sets the weight of the recipient.
+    pub const fn with_weight(mut self, weight: u64) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+impl fmt::Display for FeeRecipient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.address, self.weight)
+    }
+}
+
+impl FromStr for FeeRecipient {
+    type Err = ParseFeeRecipientError;
+
+    /// Parses `<ADDRESS>` or `<ADDRESS>:<WEIGHT>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (address, weight) = match s.split_once(':') {
+            Some((address, weight)) => (
+                address,
+                weight
+                    .parse()
+                    .map_err(|_| ParseFeeRecipientError::InvalidWeight(weight.to_string()))?,
+            ),
+            None => (s, default_weight()),
+        };
+        let address = address
+            .parse()
+            .map_err(|_| ParseFeeRecipientError::InvalidAddress(address.to_string()))?;
+        if weight == 0 {
+            return Err(ParseFeeRecipientError::ZeroWeight)
+        }
+        Ok(Self { address, weight })
+    }
+}
+
+/// Error returned when parsing a [`FeeRecipient`] fails.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseFeeRecipientError {
+    /// The address is not a valid hex encoded address.
+    #[error("invalid fee recipient address: {0}")]
+    InvalidAddress(alloc::string::String),
+    /// The weight is not a valid integer.
+    #[error("invalid fee recipient weight: {0}")]
+    InvalidWeight(alloc::string::String),
+    /// The weight is zero.
+    #[error("fee recipient weight must not be zero")]
+    ZeroWeight,
+}
+
+/// Returns the fee recipient of the block with the given number.
+///
+/// Recipients are rotated in order, each of them receiving `weight` consecutive blocks per round.
+/// Returns `None` if there are no recipients with a non-zero weight.
+pub fn rotate_fee_recipient(recipients: &[FeeRecipient], block_number: u64) -> Option<Address> {
+    let total = recipients.iter().map(|recipient| recipient.weight).sum::<u64>();
+    if total == 0 {
+        return None
+    }
+
+    let mut slot = block_number % total;
+    for recipient in recipients {
+        if slot < recipient.weight {
+            return Some(recipient.address)
+        }
+        slot -= recipient.weight;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fee_recipient() {
+        let address = Address::with_last_byte(1);
+        assert_eq!(address.to_string().parse::<FeeRecipient>(), Ok(FeeRecipient::new(address)));
+        assert_eq!(
+            alloc::format!("{address}:3").parse::<FeeRecipient>(),
+            Ok(FeeRecipient::new(address).with_weight(3))
+        );
+        assert_eq!(
+            alloc::format!("{address}:0").parse::<FeeRecipient>(),
+            Err(ParseFeeRecipientError::ZeroWeight)
+        );
+        assert!("0x01:1".parse::<FeeRecipient>().is_err());
+    }
+
+    #[test]
+    fn weighted_rotation() {
+        let a = Address::with_last_byte(1);
+        let b = Address::with_last_byte(2);
+        let recipients = [FeeRecipient::new(a).with_weight(2), FeeRecipient::new(b)];
+
+        let rotation = (0..6).map(|n| rotate_fee_recipient(&recipients, n)).collect::<Vec<_>>();
+        assert_eq!(rotation, [a, a, b, a, a, b].map(Some));
+        assert_eq!(rotate_fee_recipient(&[], 1), None);
+    }
+}
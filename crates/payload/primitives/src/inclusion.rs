@@ -0,0 +1,67 @@
+use alloc::vec::Vec;
+use alloy_primitives::{Address, TxHash, U256};
+use serde::{Deserialize, Serialize};
+
+/// Why a pool transaction was or wasn't included in a built payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionInclusionReason {
+    /// The transaction was executed and included in the payload.
+    Included,
+    /// The gas limit of the transaction exceeds the remaining gas of the block.
+    ExceedsBlockGasLimit,
+    /// The gas limit of the transaction doesn't cover the intrinsic gas of the block's schedule.
+    IntrinsicGasTooLow,
+    /// The blobs of the transaction exceed the remaining blob capacity of the block.
+    ExceedsBlobLimit,
+    /// The nonce of the transaction was already used by a previously included transaction.
+    NonceTooLow,
+    /// The transaction failed validation against the state of the block.
+    Invalid,
+}
+
+/// Outcome of a pool transaction that was considered for a payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionInclusion {
+    /// Hash of the transaction.
+    pub hash: TxHash,
+    /// Sender of the transaction.
+    pub sender: Address,
+    /// Whether and why the transaction was included.
+    pub reason: TransactionInclusionReason,
+    /// Gas used by the transaction, zero if it was not included.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+    /// Priority fee paid to the fee recipient, zero if it was not included.
+    pub fee: U256,
+}
+
+impl TransactionInclusion {
+    /// Creates the outcome of a transaction that was included.
+    pub const fn included(hash: TxHash, sender: Address, gas_used: u64, fee: U256) -> Self {
+        Self { hash, sender, reason: TransactionInclusionReason::Included, gas_used, fee }
+    }
+
+    /// Creates the outcome of a transaction that was not included for the given reason.
+    pub const fn skipped(
+        hash: TxHash,
+        sender: Address,
+        reason: TransactionInclusionReason,
+    ) -> Self {
+        Self { hash, sender, reason, gas_used: 0, fee: U256::ZERO }
+    }
+}
+
+/// Result of a simulated payload build: a block that was built from the pool without being
+/// inserted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedPayload<B> {
+    /// The would-be block.
+    pub block: B,
+    /// Outcome of every pool transaction that was considered, in order.
+    pub transactions: Vec<TransactionInclusion>,
+    /// Sum of the priority fees paid to the fee recipient.
+    pub total_fees: U256,
+}
@@ -31,6 +31,15 @@ pub use traits::{
 mod payload;
 pub use payload::{ExecutionPayload, PayloadOrAttributes};
 
+mod fee_recipient;
+pub use fee_recipient::{rotate_fee_recipient, FeeRecipient, ParseFeeRecipientError};
+
+mod extra_data;
+pub use extra_data::{ExtraDataTemplate, ExtraDataTooLong};
+
+mod inclusion;
+pub use inclusion::{SimulatedPayload, TransactionInclusion, TransactionInclusionReason};
+
 /// The types that are used by the engine API.
 pub trait PayloadTypes: Send + Sync + Unpin + core::fmt::Debug + Clone + 'static {
     /// The built payload type.
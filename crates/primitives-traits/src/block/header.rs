@@ -1,7 +1,7 @@
 //! Block header data primitive.
 
 use crate::{InMemorySize, MaybeCompact, MaybeSerde, MaybeSerdeBincodeCompat};
-use alloy_primitives::Sealable;
+use alloy_primitives::{BlockNumber, Sealable};
 use core::{fmt, hash::Hash};
 
 /// Re-exported alias
@@ -37,3 +37,19 @@ pub trait BlockHeader:
 }
 
 impl BlockHeader for alloy_consensus::Header {}
+
+/// Mutable access to the header fields that node tooling sets when it creates headers, e.g. the
+/// empty headers it backfills the chain with.
+///
+/// Custom header types implement this to support those tools, all other operations only require
+/// [`BlockHeader`].
+pub trait BlockHeaderMut: BlockHeader {
+    /// Sets the block number.
+    fn set_number(&mut self, number: BlockNumber);
+}
+
+impl BlockHeaderMut for alloy_consensus::Header {
+    fn set_number(&mut self, number: BlockNumber) {
+        self.number = number;
+    }
+}
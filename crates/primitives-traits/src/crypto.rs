@@ -23,6 +23,7 @@ pub mod secp256k1 {
     use super::impl_secp256k1 as imp;
 
     use crate::transaction::signed::RecoveryError;
+    use alloc::vec::Vec;
     pub use imp::{public_key_to_address, sign_message};
 
     /// Recover signer from message hash, _without ensuring that the signature has a low `s`
@@ -35,15 +36,40 @@ pub mod secp256k1 {
         signature: &Signature,
         hash: B256,
     ) -> Result<Address, RecoveryError> {
+        // NOTE: we are removing error from underlying crypto library as it will restrain primitive
+        // errors and we care only if recovery is passing or not.
+        imp::recover_signer_unchecked(&to_bytes(signature), &hash.0).map_err(|_| RecoveryError)
+    }
+
+    /// Recovers the signers of a batch of signatures and the message hashes they sign, _without
+    /// ensuring that the signatures have a low `s` value_.
+    ///
+    /// All signatures are serialized before the first one is recovered, so that recovering the
+    /// batch only runs the curve operations back to back. The results are in the order of the
+    /// batch.
+    pub fn recover_signers_unchecked<'a>(
+        batch: impl IntoIterator<Item = (&'a Signature, B256)>,
+    ) -> Vec<Result<Address, RecoveryError>> {
+        let batch = batch
+            .into_iter()
+            .map(|(signature, hash)| (to_bytes(signature), hash))
+            .collect::<Vec<_>>();
+        batch
+            .iter()
+            .map(|(sig, hash)| {
+                imp::recover_signer_unchecked(sig, &hash.0).map_err(|_| RecoveryError)
+            })
+            .collect()
+    }
+
+    /// Serializes the signature into the 65 bytes of `r`, `s` and `v`.
+    fn to_bytes(signature: &Signature) -> [u8; 65] {
         let mut sig: [u8; 65] = [0; 65];
 
         sig[0..32].copy_from_slice(&signature.r().to_be_bytes::<32>());
         sig[32..64].copy_from_slice(&signature.s().to_be_bytes::<32>());
         sig[64] = signature.v() as u8;
-
-        // NOTE: we are removing error from underlying crypto library as it will restrain primitive
-        // errors and we care only if recovery is passing or not.
-        imp::recover_signer_unchecked(&sig, &hash.0).map_err(|_| RecoveryError)
+        sig
     }
 
     /// Recover signer address from message hash. This ensures that the signature S value is
@@ -251,4 +277,28 @@ mod tests {
 
         assert_eq!(secp256k1_recovered, k256_recovered);
     }
+
+    #[test]
+    fn recover_signers_unchecked_batch() {
+        use super::secp256k1::{recover_signer_unchecked, recover_signers_unchecked, sign_message};
+        use crate::transaction::signature::Signature;
+        use alloy_primitives::U256;
+
+        let secret = B256::with_last_byte(1);
+        let hashes = [keccak256(b"hello"), keccak256(b"world")];
+        let signatures = hashes.map(|hash| sign_message(secret, hash).expect("sign message"));
+        let invalid = Signature::new(U256::ZERO, U256::ZERO, false);
+
+        let recovered =
+            recover_signers_unchecked(signatures.iter().zip(hashes).chain([(&invalid, hashes[0])]));
+        assert_eq!(recovered.len(), 3);
+        for ((signature, hash), recovered) in signatures.iter().zip(hashes).zip(&recovered) {
+            assert_eq!(
+                recovered.as_ref().ok(),
+                recover_signer_unchecked(signature, hash).ok().as_ref()
+            );
+        }
+        assert_eq!(recovered[0].as_ref().ok(), recovered[1].as_ref().ok());
+        assert!(recovered[2].is_err());
+    }
 }
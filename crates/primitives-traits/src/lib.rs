@@ -99,7 +99,7 @@ pub use transaction::{
 pub mod block;
 pub use block::{
     body::{BlockBody, FullBlockBody},
-    header::{AlloyBlockHeader, BlockHeader, FullBlockHeader},
+    header::{AlloyBlockHeader, BlockHeader, BlockHeaderMut, FullBlockHeader},
     Block, FullBlock, RecoveredBlock, SealedBlock,
 };
 
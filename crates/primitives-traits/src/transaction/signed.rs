@@ -94,6 +94,14 @@ pub trait SignedTransaction:
         buf: &mut Vec<u8>,
     ) -> Result<Address, RecoveryError>;
 
+    /// Returns the hash that the signature of the transaction signs, using the given buffer to
+    /// encode the transaction.
+    ///
+    /// Returns `None` if the signer is not recovered from the signature, e.g. for deposit
+    /// transactions. The hashes are used to recover the signers of many transactions at once with
+    /// [`recover_signers_unchecked`](crate::crypto::secp256k1::recover_signers_unchecked).
+    fn signature_hash_with_buf(&self, buf: &mut Vec<u8>) -> Option<B256>;
+
     /// Calculate transaction hash, eip2728 transaction does not contain rlp header and start with
     /// tx type.
     fn recalculate_hash(&self) -> B256 {
@@ -166,6 +174,12 @@ impl SignedTransaction for PooledTransaction {
         &self,
         buf: &mut Vec<u8>,
     ) -> Result<Address, RecoveryError> {
+        let signature_hash = self.signature_hash_with_buf(buf).ok_or(RecoveryError)?;
+        recover_signer_unchecked(self.signature(), signature_hash)
+    }
+
+    fn signature_hash_with_buf(&self, buf: &mut Vec<u8>) -> Option<B256> {
+        buf.clear();
         match self {
             Self::Legacy(tx) => tx.tx().encode_for_signing(buf),
             Self::Eip2930(tx) => tx.tx().encode_for_signing(buf),
@@ -173,8 +187,7 @@ impl SignedTransaction for PooledTransaction {
             Self::Eip7702(tx) => tx.tx().encode_for_signing(buf),
             Self::Eip4844(tx) => tx.tx().encode_for_signing(buf),
         }
-        let signature_hash = keccak256(buf);
-        recover_signer_unchecked(self.signature(), signature_hash)
+        Some(keccak256(buf))
     }
 }
 
@@ -207,14 +220,19 @@ impl SignedTransaction for op_alloy_consensus::OpPooledTransaction {
         &self,
         buf: &mut Vec<u8>,
     ) -> Result<Address, RecoveryError> {
+        let signature_hash = self.signature_hash_with_buf(buf).ok_or(RecoveryError)?;
+        recover_signer_unchecked(self.signature(), signature_hash)
+    }
+
+    fn signature_hash_with_buf(&self, buf: &mut Vec<u8>) -> Option<B256> {
+        buf.clear();
         match self {
             Self::Legacy(tx) => tx.tx().encode_for_signing(buf),
             Self::Eip2930(tx) => tx.tx().encode_for_signing(buf),
             Self::Eip1559(tx) => tx.tx().encode_for_signing(buf),
             Self::Eip7702(tx) => tx.tx().encode_for_signing(buf),
         }
-        let signature_hash = keccak256(buf);
-        recover_signer_unchecked(self.signature(), signature_hash)
+        Some(keccak256(buf))
     }
 }
 
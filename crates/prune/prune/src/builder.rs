@@ -3,7 +3,7 @@ use alloy_eips::eip2718::Encodable2718;
 use reth_chainspec::MAINNET_PRUNE_DELETE_LIMIT;
 use reth_config::PruneConfig;
 use reth_db_api::{table::Value, transaction::DbTxMut};
-use reth_exex_types::FinishedExExHeight;
+use reth_exex_types::{ExExPruneTargets, FinishedExExHeight};
 use reth_primitives_traits::NodePrimitives;
 use reth_provider::{
     providers::StaticFileProvider, BlockReader, DBProvider, DatabaseProviderFactory,
@@ -26,6 +26,8 @@ pub struct PrunerBuilder {
     timeout: Option<Duration>,
     /// The finished height of all `ExEx`'s.
     finished_exex_height: watch::Receiver<FinishedExExHeight>,
+    /// Prune targets of data owned by `ExEx`'s.
+    exex_prune_targets: ExExPruneTargets,
 }
 
 impl PrunerBuilder {
@@ -75,6 +77,12 @@ impl PrunerBuilder {
         self
     }
 
+    /// Sets the prune targets of data owned by `ExEx`'s, which are notified after every run.
+    pub fn exex_prune_targets(mut self, exex_prune_targets: ExExPruneTargets) -> Self {
+        self.exex_prune_targets = exex_prune_targets;
+        self
+    }
+
     /// Builds a [Pruner] from the current configuration with the given provider factory.
     pub fn build_with_provider_factory<PF>(self, provider_factory: PF) -> Pruner<PF::ProviderRW, PF>
     where
@@ -82,7 +90,7 @@ impl PrunerBuilder {
                 ProviderRW: PruneCheckpointWriter
                                 + BlockReader<Transaction: Encodable2718>
                                 + StaticFileProviderFactory<
-                    Primitives: NodePrimitives<SignedTx: Value, Receipt: Value>,
+                    Primitives: NodePrimitives<SignedTx: Value, Receipt: Value, BlockHeader: Value>,
                 >,
             > + StaticFileProviderFactory<
                 Primitives = <PF::ProviderRW as NodePrimitivesProvider>::Primitives,
@@ -99,6 +107,7 @@ impl PrunerBuilder {
             self.timeout,
             self.finished_exex_height,
         )
+        .with_exex_prune_targets(self.exex_prune_targets)
     }
 
     /// Builds a [Pruner] from the current configuration with the given static file provider.
@@ -107,8 +116,9 @@ impl PrunerBuilder {
         static_file_provider: StaticFileProvider<Provider::Primitives>,
     ) -> Pruner<Provider, ()>
     where
-        Provider: StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value, Receipt: Value>>
-            + DBProvider<Tx: DbTxMut>
+        Provider: StaticFileProviderFactory<
+                Primitives: NodePrimitives<SignedTx: Value, Receipt: Value, BlockHeader: Value>,
+            > + DBProvider<Tx: DbTxMut>
             + BlockReader<Transaction: Encodable2718>
             + PruneCheckpointWriter,
     {
@@ -121,6 +131,7 @@ impl PrunerBuilder {
             self.timeout,
             self.finished_exex_height,
         )
+        .with_exex_prune_targets(self.exex_prune_targets)
     }
 }
 
@@ -132,6 +143,7 @@ impl Default for PrunerBuilder {
             delete_limit: MAINNET_PRUNE_DELETE_LIMIT,
             timeout: None,
             finished_exex_height: watch::channel(FinishedExExHeight::NoExExs).1,
+            exex_prune_targets: ExExPruneTargets::default(),
         }
     }
 }
@@ -5,7 +5,7 @@ use crate::{
     Metrics, PruneLimiter, PrunerError, PrunerEvent,
 };
 use alloy_primitives::BlockNumber;
-use reth_exex_types::FinishedExExHeight;
+use reth_exex_types::{ExExPruneTargets, FinishedExExHeight};
 use reth_provider::{
     DBProvider, DatabaseProviderFactory, PruneCheckpointReader, PruneCheckpointWriter,
 };
@@ -43,6 +43,8 @@ pub struct Pruner<Provider, PF> {
     timeout: Option<Duration>,
     /// The finished height of all `ExEx`'s.
     finished_exex_height: watch::Receiver<FinishedExExHeight>,
+    /// Prune targets of data owned by `ExEx`'s, notified after every run.
+    exex_prune_targets: ExExPruneTargets,
     #[doc(hidden)]
     metrics: Metrics,
     event_sender: EventSender<PrunerEvent>,
@@ -65,6 +67,7 @@ impl<Provider> Pruner<Provider, ()> {
             delete_limit,
             timeout,
             finished_exex_height,
+            exex_prune_targets: ExExPruneTargets::default(),
             metrics: Metrics::default(),
             event_sender: Default::default(),
         }
@@ -92,12 +95,22 @@ where
             delete_limit,
             timeout,
             finished_exex_height,
+            exex_prune_targets: ExExPruneTargets::default(),
             metrics: Metrics::default(),
             event_sender: Default::default(),
         }
     }
 }
 
+impl<Provider, PF> Pruner<Provider, PF> {
+    /// Sets the prune targets of data owned by `ExEx`'s, which are notified with the tip block
+    /// number after every run.
+    pub fn with_exex_prune_targets(mut self, exex_prune_targets: ExExPruneTargets) -> Self {
+        self.exex_prune_targets = exex_prune_targets;
+        self
+    }
+}
+
 impl<Provider, S> Pruner<Provider, S>
 where
     Provider: PruneCheckpointReader + PruneCheckpointWriter,
@@ -118,7 +131,9 @@ where
         tip_block_number: BlockNumber,
     ) -> PrunerResult {
         let Some(tip_block_number) =
-            self.adjust_tip_block_number_to_finished_exex_height(tip_block_number)
+            self.adjust_tip_block_number_to_finished_exex_height(tip_block_number).map(
+                |tip_block_number| self.adjust_tip_block_number_to_exex_retention(tip_block_number),
+            )
         else {
             return Ok(PruneProgress::Finished.into())
         };
@@ -164,6 +179,7 @@ where
         );
 
         self.event_sender.notify(PrunerEvent::Finished { tip_block_number, elapsed, stats });
+        self.exex_prune_targets.notify(tip_block_number);
 
         Ok(output)
     }
@@ -268,7 +284,9 @@ where
     /// number.
     pub fn is_pruning_needed(&self, tip_block_number: BlockNumber) -> bool {
         let Some(tip_block_number) =
-            self.adjust_tip_block_number_to_finished_exex_height(tip_block_number)
+            self.adjust_tip_block_number_to_finished_exex_height(tip_block_number).map(
+                |tip_block_number| self.adjust_tip_block_number_to_exex_retention(tip_block_number),
+            )
         else {
             return false
         };
@@ -314,6 +332,21 @@ where
             }
         }
     }
+
+    /// Adjusts the tip block number to stay below the lowest block retained by the `ExEx` prune
+    /// targets. This is needed to not prune data that the `ExEx`'s still require.
+    fn adjust_tip_block_number_to_exex_retention(
+        &self,
+        tip_block_number: BlockNumber,
+    ) -> BlockNumber {
+        match self.exex_prune_targets.lowest_retained_block() {
+            Some(retained_block) if retained_block <= tip_block_number => {
+                debug!(target: "pruner", %tip_block_number, %retained_block, "Adjusting tip block number to the lowest block retained by ExExs");
+                retained_block.saturating_sub(1)
+            }
+            _ => tip_block_number,
+        }
+    }
 }
 
 impl<PF> Pruner<PF::ProviderRW, PF>
@@ -336,8 +369,9 @@ where
 #[cfg(test)]
 mod tests {
     use crate::Pruner;
-    use reth_exex_types::FinishedExExHeight;
+    use reth_exex_types::{ExExPruneSignal, ExExPruneTargets, FinishedExExHeight};
     use reth_provider::test_utils::create_test_provider_factory;
+    use reth_prune_types::PruneMode;
 
     #[test]
     fn is_pruning_needed() {
@@ -378,4 +412,39 @@ mod tests {
         finished_exex_height_tx.send(FinishedExExHeight::Height(third_block_number)).unwrap();
         assert!(pruner.is_pruning_needed(third_block_number));
     }
+
+    #[test]
+    fn notifies_exex_prune_targets() {
+        let provider_factory = create_test_provider_factory();
+
+        let (finished_exex_height_tx, finished_exex_height_rx) =
+            tokio::sync::watch::channel(FinishedExExHeight::NotReady);
+
+        let exex_prune_targets = ExExPruneTargets::default();
+        let prune_signals = exex_prune_targets.register(PruneMode::Distance(10));
+
+        let mut pruner =
+            Pruner::new_with_factory(provider_factory, vec![], 5, 0, None, finished_exex_height_rx)
+                .with_exex_prune_targets(exex_prune_targets);
+
+        // Not all ExExs have emitted a `FinishedHeight` event yet
+        pruner.run(100).unwrap();
+        assert_eq!(prune_signals.latest_signal(), None);
+
+        // Tip block number is adjusted to the finished ExEx height
+        finished_exex_height_tx.send(FinishedExExHeight::Height(50)).unwrap();
+        pruner.run(100).unwrap();
+        assert_eq!(
+            prune_signals.latest_signal(),
+            Some(ExExPruneSignal { tip_block_number: 50, prune_before: 40 })
+        );
+
+        // Tip block number is adjusted to stay below the retained block
+        prune_signals.retain_from(Some(30));
+        pruner.run(100).unwrap();
+        assert_eq!(
+            prune_signals.latest_signal(),
+            Some(ExExPruneSignal { tip_block_number: 29, prune_before: 19 })
+        );
+    }
 }
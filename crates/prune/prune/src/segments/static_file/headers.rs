@@ -7,10 +7,14 @@ use alloy_primitives::BlockNumber;
 use itertools::Itertools;
 use reth_db_api::{
     cursor::{DbCursorRO, RangeWalker},
+    table::Value,
     tables,
     transaction::DbTxMut,
 };
-use reth_provider::{providers::StaticFileProvider, DBProvider, StaticFileProviderFactory};
+use reth_primitives_traits::HeaderTy;
+use reth_provider::{
+    providers::StaticFileProvider, DBProvider, NodePrimitivesProvider, StaticFileProviderFactory,
+};
 use reth_prune_types::{
     PruneMode, PrunePurpose, PruneSegment, SegmentOutput, SegmentOutputCheckpoint,
 };
@@ -32,8 +36,10 @@ impl<N> Headers<N> {
     }
 }
 
-impl<Provider: StaticFileProviderFactory + DBProvider<Tx: DbTxMut>> Segment<Provider>
-    for Headers<Provider::Primitives>
+impl<Provider> Segment<Provider> for Headers<Provider::Primitives>
+where
+    Provider: StaticFileProviderFactory + DBProvider<Tx: DbTxMut>,
+    HeaderTy<Provider::Primitives>: Value,
 {
     fn segment(&self) -> PruneSegment {
         PruneSegment::Headers
@@ -63,7 +69,8 @@ impl<Provider: StaticFileProviderFactory + DBProvider<Tx: DbTxMut>> Segment<Prov
 
         let range = last_pruned_block.map_or(0, |block| block + 1)..=block_range_end;
 
-        let mut headers_cursor = provider.tx_ref().cursor_write::<tables::Headers>()?;
+        let mut headers_cursor =
+            provider.tx_ref().cursor_write::<tables::Headers<HeaderTy<Provider::Primitives>>>()?;
         let mut header_tds_cursor =
             provider.tx_ref().cursor_write::<tables::HeaderTerminalDifficulties>()?;
         let mut canonical_headers_cursor =
@@ -108,11 +115,12 @@ type Walker<'a, Provider, T> =
 #[allow(missing_debug_implementations)]
 struct HeaderTablesIter<'a, Provider>
 where
-    Provider: DBProvider<Tx: DbTxMut>,
+    Provider: DBProvider<Tx: DbTxMut> + NodePrimitivesProvider,
+    HeaderTy<Provider::Primitives>: Value,
 {
     provider: &'a Provider,
     limiter: &'a mut PruneLimiter,
-    headers_walker: Walker<'a, Provider, tables::Headers>,
+    headers_walker: Walker<'a, Provider, tables::Headers<HeaderTy<Provider::Primitives>>>,
     header_tds_walker: Walker<'a, Provider, tables::HeaderTerminalDifficulties>,
     canonical_headers_walker: Walker<'a, Provider, tables::CanonicalHeaders>,
 }
@@ -124,12 +132,13 @@ struct HeaderTablesIterItem {
 
 impl<'a, Provider> HeaderTablesIter<'a, Provider>
 where
-    Provider: DBProvider<Tx: DbTxMut>,
+    Provider: DBProvider<Tx: DbTxMut> + NodePrimitivesProvider,
+    HeaderTy<Provider::Primitives>: Value,
 {
     fn new(
         provider: &'a Provider,
         limiter: &'a mut PruneLimiter,
-        headers_walker: Walker<'a, Provider, tables::Headers>,
+        headers_walker: Walker<'a, Provider, tables::Headers<HeaderTy<Provider::Primitives>>>,
         header_tds_walker: Walker<'a, Provider, tables::HeaderTerminalDifficulties>,
         canonical_headers_walker: Walker<'a, Provider, tables::CanonicalHeaders>,
     ) -> Self {
@@ -139,7 +148,8 @@ where
 
 impl<Provider> Iterator for HeaderTablesIter<'_, Provider>
 where
-    Provider: DBProvider<Tx: DbTxMut>,
+    Provider: DBProvider<Tx: DbTxMut> + NodePrimitivesProvider,
+    HeaderTy<Provider::Primitives>: Value,
 {
     type Item = Result<HeaderTablesIterItem, PrunerError>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -1,7 +1,7 @@
 use crate::{
     db_ext::DbTxPruneExt,
     segments::{user::history::prune_history_indices, PruneInput, Segment},
-    PrunerError,
+    PruneLimiter, PrunerError,
 };
 use itertools::Itertools;
 use reth_db_api::{models::ShardedKey, tables, transaction::DbTxMut};
@@ -16,6 +16,9 @@ use tracing::{instrument, trace};
 ///
 /// Account History consists of two tables: [`tables::AccountChangeSets`] and
 /// [`tables::AccountsHistory`]. We want to prune them to the same block number.
+///
+/// The code changes in [`tables::CodeChangeSets`] and [`tables::CodeHistory`] are pruned to the
+/// same block number as well, but they are rare and don't count towards the limit.
 const ACCOUNT_HISTORY_TABLES_TO_PRUNE: usize = 2;
 
 #[derive(Debug)]
@@ -54,7 +57,7 @@ where
                 return Ok(SegmentOutput::done())
             }
         };
-        let range_end = *range.end();
+        let (range_start, range_end) = (*range.start(), *range.end());
 
         let mut limiter = if let Some(limit) = input.limiter.deleted_entries_limit() {
             input.limiter.set_deleted_entries_limit(limit / ACCOUNT_HISTORY_TABLES_TO_PRUNE)
@@ -111,11 +114,34 @@ where
         )?;
         trace!(target: "pruner", ?outcomes, %done, "Pruned account history (indices)");
 
+        let mut highest_deleted_code_accounts = FxHashMap::default();
+        let (pruned_code_changesets, _) =
+            provider.tx_ref().prune_table_with_range::<tables::CodeChangeSets>(
+                range_start..=last_changeset_pruned_block,
+                &mut PruneLimiter::default(),
+                |_| false,
+                |(block_number, change)| {
+                    highest_deleted_code_accounts.insert(change.address, block_number);
+                },
+            )?;
+        let code_outcomes = prune_history_indices::<Provider, tables::CodeHistory, _>(
+            provider,
+            highest_deleted_code_accounts
+                .into_iter()
+                .sorted_unstable()
+                .map(|(address, block_number)| ShardedKey::new(address, block_number)),
+            |a, b| a.key == b.key,
+        )?;
+        trace!(target: "pruner", pruned = %pruned_code_changesets, ?code_outcomes, "Pruned account history (code changes)");
+
         let progress = limiter.progress(done);
 
         Ok(SegmentOutput {
             progress,
-            pruned: pruned_changesets + outcomes.deleted,
+            pruned: pruned_changesets +
+                outcomes.deleted +
+                pruned_code_changesets +
+                code_outcomes.deleted,
             checkpoint: Some(SegmentOutputCheckpoint {
                 block_number: Some(last_changeset_pruned_block),
                 tx_number: None,
@@ -130,9 +156,14 @@ mod tests {
         user::account_history::ACCOUNT_HISTORY_TABLES_TO_PRUNE, AccountHistory, PruneInput,
         PruneLimiter, Segment, SegmentOutput,
     };
-    use alloy_primitives::{BlockNumber, B256};
+    use alloy_primitives::{Address, BlockNumber, B256};
     use assert_matches::assert_matches;
-    use reth_db_api::{tables, BlockNumberList};
+    use reth_db_api::{
+        models::{CodeBeforeTx, ShardedKey},
+        tables,
+        transaction::DbTxMut,
+        BlockNumberList,
+    };
     use reth_provider::{DatabaseProviderFactory, PruneCheckpointReader};
     use reth_prune_types::{
         PruneCheckpoint, PruneInterruptReason, PruneMode, PruneProgress, PruneSegment,
@@ -303,4 +334,43 @@ mod tests {
         test_prune(998, 2, (PruneProgress::Finished, 998));
         test_prune(1400, 3, (PruneProgress::Finished, 804));
     }
+
+    #[test]
+    fn prune_code_changes() {
+        let db = TestStageDB::default();
+        let address = Address::with_last_byte(1);
+
+        // deployed at block 3, selfdestructed at block 7, redeployed at block 10
+        db.commit(|tx| {
+            for block_number in [3, 7, 10] {
+                tx.put::<tables::CodeChangeSets>(
+                    block_number,
+                    CodeBeforeTx { address, code_hash: None },
+                )?;
+            }
+            tx.put::<tables::CodeHistory>(
+                ShardedKey::last(address),
+                BlockNumberList::new([3, 7, 10]).unwrap(),
+            )?;
+            Ok(())
+        })
+        .unwrap();
+
+        let input =
+            PruneInput { previous_checkpoint: None, to_block: 7, limiter: PruneLimiter::default() };
+        let provider = db.factory.database_provider_rw().unwrap();
+        let result = AccountHistory::new(PruneMode::Before(8)).prune(&provider, input).unwrap();
+        provider.commit().expect("commit");
+
+        assert!(result.progress.is_finished());
+        assert_eq!(result.pruned, 2);
+        assert_eq!(
+            db.table::<tables::CodeChangeSets>().unwrap(),
+            vec![(10, CodeBeforeTx { address, code_hash: None })]
+        );
+        assert_eq!(
+            db.table::<tables::CodeHistory>().unwrap(),
+            vec![(ShardedKey::last(address), BlockNumberList::new([10]).unwrap())]
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use crate::primitives::alloy_primitives::{BlockNumber, StorageKey, StorageValue};
+use alloc::vec::Vec;
 use alloy_primitives::{Address, B256, U256};
 use core::ops::{Deref, DerefMut};
 use reth_primitives_traits::Account;
@@ -31,6 +32,20 @@ pub trait EvmStateProvider: Send + Sync {
         account: Address,
         storage_key: StorageKey,
     ) -> ProviderResult<Option<StorageValue>>;
+
+    /// Get multiple storage slots of the given account, in the order of the given keys.
+    fn storage_multi(
+        &self,
+        account: Address,
+        storage_keys: &[StorageKey],
+    ) -> ProviderResult<Vec<Option<StorageValue>>> {
+        storage_keys.iter().map(|key| self.storage(account, *key)).collect()
+    }
+
+    /// Get basic account information of multiple accounts, in the order of the given addresses.
+    fn basic_accounts(&self, addresses: &[Address]) -> ProviderResult<Vec<Option<Account>>> {
+        addresses.iter().map(|address| self.basic_account(address)).collect()
+    }
 }
 
 // Blanket implementation of EvmStateProvider for any type that implements StateProvider.
@@ -57,6 +72,18 @@ impl<T: StateProvider> EvmStateProvider for T {
     ) -> ProviderResult<Option<StorageValue>> {
         <T as StateProvider>::storage(self, account, storage_key)
     }
+
+    fn storage_multi(
+        &self,
+        account: Address,
+        storage_keys: &[StorageKey],
+    ) -> ProviderResult<Vec<Option<StorageValue>>> {
+        <T as StateProvider>::storage_multi(self, account, storage_keys)
+    }
+
+    fn basic_accounts(&self, addresses: &[Address]) -> ProviderResult<Vec<Option<Account>>> {
+        <T as StateProvider>::basic_accounts(self, addresses)
+    }
 }
 
 /// A [Database] and [`DatabaseRef`] implementation that uses [`EvmStateProvider`] as the underlying
@@ -76,6 +103,35 @@ impl<DB> StateProviderDatabase<DB> {
     }
 }
 
+impl<DB: EvmStateProvider> StateProviderDatabase<DB> {
+    /// Retrieves basic account information for multiple accounts at once.
+    ///
+    /// Returns the accounts in the order of the given addresses.
+    pub fn basic_accounts_ref(
+        &self,
+        addresses: &[Address],
+    ) -> ProviderResult<Vec<Option<AccountInfo>>> {
+        Ok(self.0.basic_accounts(addresses)?.into_iter().map(|acc| acc.map(Into::into)).collect())
+    }
+
+    /// Retrieves multiple storage values of the given account at once.
+    ///
+    /// Returns the values in the order of the given indices, or the default value if not found.
+    pub fn storage_multi_ref(
+        &self,
+        address: Address,
+        indices: &[U256],
+    ) -> ProviderResult<Vec<U256>> {
+        let keys = indices.iter().map(|index| B256::new(index.to_be_bytes())).collect::<Vec<_>>();
+        Ok(self
+            .0
+            .storage_multi(address, &keys)?
+            .into_iter()
+            .map(Option::unwrap_or_default)
+            .collect())
+    }
+}
+
 impl<DB> AsRef<DB> for StateProviderDatabase<DB> {
     fn as_ref(&self) -> &DB {
         self
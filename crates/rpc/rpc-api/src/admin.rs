@@ -1,6 +1,8 @@
+use alloy_eips::BlockId;
 use alloy_rpc_types_admin::{NodeInfo, PeerInfo};
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use reth_network_peers::{AnyNode, NodeRecord};
+use reth_payload_primitives::{ExtraDataTemplate, FeeRecipient};
 
 /// Admin namespace rpc interface that gives access to several non-standard RPC methods.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
@@ -45,4 +47,77 @@ pub trait AdminApi {
     /// Returns the ENR of the node.
     #[method(name = "nodeInfo")]
     async fn node_info(&self) -> RpcResult<NodeInfo>;
+
+    /// Enables or disables the per-table read metrics of the database, and sets the duration in
+    /// milliseconds above which database reads are logged.
+    ///
+    /// A `null` threshold disables the logging of slow reads. Fails if the settings of the
+    /// database can't be changed by the node.
+    #[method(name = "setDatabaseReadMetrics")]
+    fn set_database_read_metrics(
+        &self,
+        enabled: bool,
+        slow_read_threshold_ms: Option<u64>,
+    ) -> RpcResult<bool>;
+}
+
+/// Admin namespace rpc interface that controls the fee recipients and extra data of the blocks
+/// built by the payload builder.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "admin"))]
+pub trait AdminBuilderApi {
+    /// Returns the fee recipients that built blocks rotate through.
+    #[method(name = "builderFeeRecipients")]
+    fn builder_fee_recipients(&self) -> RpcResult<Vec<FeeRecipient>>;
+
+    /// Sets the fee recipients that built blocks rotate through, each receiving `weight`
+    /// consecutive blocks per round.
+    ///
+    /// The rotation only applies to blocks for which the consensus layer doesn't suggest a fee
+    /// recipient, i.e. suggests the zero address. An empty list disables the rotation.
+    #[method(name = "setBuilderFeeRecipients")]
+    fn set_builder_fee_recipients(&self, fee_recipients: Vec<FeeRecipient>) -> RpcResult<bool>;
+
+    /// Returns the template of the extra data of built blocks.
+    #[method(name = "builderExtraData")]
+    fn builder_extra_data(&self) -> RpcResult<Option<ExtraDataTemplate>>;
+
+    /// Sets the template of the extra data of built blocks. `{number}` and `{timestamp}` are
+    /// replaced with the values of the block.
+    ///
+    /// `null` restores the configured extra data.
+    #[method(name = "setBuilderExtraData")]
+    fn set_builder_extra_data(&self, template: Option<ExtraDataTemplate>) -> RpcResult<bool>;
+}
+
+/// Admin namespace rpc interface that moves the fixed block the node syncs to when it was launched
+/// with `--debug.sync-target`.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "admin"))]
+pub trait AdminSyncTargetApi {
+    /// Moves the sync target to the given block number or hash. The node syncs to, or unwinds to,
+    /// that block and halts again.
+    ///
+    /// Returns true once syncing towards the new target started.
+    #[method(name = "setSyncTarget")]
+    async fn set_sync_target(&self, target: BlockId) -> RpcResult<bool>;
+}
+
+/// Admin namespace rpc interface to pause and resume the delivery of notifications to installed
+/// execution extensions.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "admin"))]
+pub trait AdminExExApi {
+    /// Stops sending notifications to the `ExEx` with the given ID. Notifications are buffered
+    /// until the `ExEx` is resumed.
+    ///
+    /// Returns false if the `ExEx` was already paused.
+    #[method(name = "pauseExEx")]
+    fn pause_exex(&self, id: String) -> RpcResult<bool>;
+
+    /// Resumes sending notifications to the `ExEx` with the given ID.
+    ///
+    /// Returns false if the `ExEx` was not paused.
+    #[method(name = "resumeExEx")]
+    fn resume_exex(&self, id: String) -> RpcResult<bool>;
 }
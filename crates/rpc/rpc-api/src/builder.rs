@@ -0,0 +1,28 @@
+//! Builder API for external proposers and relays.
+
+use alloy_primitives::B256;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_eth_types::builder_bid::{BuilderApiResponse, BuilderPayload, SignedBuilderBid};
+
+/// Builder API that exposes the locally built payloads in the format of the
+/// [builder-specs](https://ethereum.github.io/builder-specs/).
+///
+/// This is served on the authenticated engine API server.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "builder"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "builder"))]
+pub trait BuilderApi {
+    /// Returns the bid for the best payload that is currently being built on top of the given
+    /// parent block, or `None` if there is no such payload.
+    ///
+    /// The bid is not signed, the `pubkey` and `signature` fields are empty.
+    #[method(name = "getHeader")]
+    async fn get_header(
+        &self,
+        parent_hash: B256,
+    ) -> RpcResult<Option<BuilderApiResponse<SignedBuilderBid>>>;
+
+    /// Returns the full payload of a bid previously returned by `builder_getHeader`, with the
+    /// blobs bundle since Deneb.
+    #[method(name = "getPayload")]
+    async fn get_payload(&self, block_hash: B256) -> RpcResult<BuilderApiResponse<BuilderPayload>>;
+}
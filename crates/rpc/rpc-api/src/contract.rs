@@ -0,0 +1,15 @@
+use alloy_primitives::Address;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_db_models::ContractCreation;
+
+/// Eth rpc interface for the contract creation index.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "eth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "eth"))]
+pub trait EthContractCreationApi {
+    /// Returns the block, the transaction and the creator of the first creation of the contract.
+    ///
+    /// Returns `None` if the address is not a contract, if it was created before the index was
+    /// enabled, or if its creation was pruned from the index.
+    #[method(name = "getContractCreation")]
+    async fn get_contract_creation(&self, address: Address) -> RpcResult<Option<ContractCreation>>;
+}
@@ -7,6 +7,7 @@ use alloy_rpc_types_trace::geth::{
     BlockTraceResult, GethDebugTracingCallOptions, GethDebugTracingOptions, GethTrace, TraceResult,
 };
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_eth_types::{TraceFile, TraceFileOptions};
 
 /// Debug rpc interface.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "debug"))]
@@ -434,3 +435,39 @@ pub trait DebugExecutionWitnessApi<Attributes> {
         attributes: Attributes,
     ) -> RpcResult<ExecutionWitness>;
 }
+
+/// An extension to the `debug_` namespace to write opcode traces to files on the node, which is
+/// needed for traces that are too large to be returned inline.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "debug"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "debug"))]
+pub trait DebugTraceFileApi {
+    /// Replays the transaction and writes its full opcode trace to a file in the trace directory
+    /// of the node, in the geth struct logger format or as collapsed stacks for flamegraphs.
+    ///
+    /// Returns the path and size of the file instead of the trace.
+    #[method(name = "traceTransactionToFile")]
+    async fn debug_trace_transaction_to_file(
+        &self,
+        tx_hash: B256,
+        opts: Option<TraceFileOptions>,
+    ) -> RpcResult<TraceFile>;
+}
+
+/// An extension to the `debug_` namespace to capture and restore the in-memory state of the
+/// engine, which is used to deterministically rewind the chain in tests.
+#[cfg(feature = "engine-snapshots")]
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "debug"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "debug"))]
+pub trait DebugEngineStateApi {
+    /// Captures the executed and buffered blocks that the engine keeps in memory and returns the
+    /// id of the snapshot.
+    #[method(name = "captureEngineState")]
+    async fn capture_engine_state(&self) -> RpcResult<u64>;
+
+    /// Restores the in-memory state of the engine that was captured in the given snapshot.
+    ///
+    /// This fails if blocks were persisted since the snapshot was captured. A snapshot can only be
+    /// restored once.
+    #[method(name = "restoreEngineState")]
+    async fn restore_engine_state(&self, id: u64) -> RpcResult<()>;
+}
@@ -5,6 +5,8 @@
 //! ## Feature Flags
 //!
 //! - `client`: Enables JSON-RPC client support.
+//! - `preconf`: Enables the `reth_preconfirmTransaction` endpoint.
+//! - `engine-snapshots`: Enables the `debug_` endpoints to capture and restore the engine state.
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
@@ -14,8 +16,13 @@
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
+// only referenced by the generated subscription client
+use reth_node_tx_watcher as _;
+
 mod admin;
 mod anvil;
+mod builder;
+mod contract;
 mod debug;
 mod engine;
 mod ganache;
@@ -24,6 +31,7 @@ mod mev;
 mod miner;
 mod net;
 mod otterscan;
+mod personal;
 mod reth;
 mod rpc;
 mod trace;
@@ -36,15 +44,29 @@ pub use servers::*;
 
 /// Aggregates all server traits.
 pub mod servers {
+    #[cfg(feature = "engine-snapshots")]
+    pub use crate::debug::DebugEngineStateApiServer;
+    #[cfg(feature = "preconf")]
+    pub use crate::reth::RethPreconfApiServer;
     pub use crate::{
-        admin::AdminApiServer,
-        debug::{DebugApiServer, DebugExecutionWitnessApiServer},
+        admin::{
+            AdminApiServer, AdminBuilderApiServer, AdminExExApiServer, AdminSyncTargetApiServer,
+        },
+        builder::BuilderApiServer,
+        contract::EthContractCreationApiServer,
+        debug::{DebugApiServer, DebugExecutionWitnessApiServer, DebugTraceFileApiServer},
         engine::{EngineApiServer, EngineEthApiServer, IntoEngineApiRpcModule},
         mev::{MevFullApiServer, MevSimApiServer},
         miner::MinerApiServer,
         net::NetApiServer,
         otterscan::OtterscanServer,
-        reth::RethApiServer,
+        personal::PersonalApiServer,
+        reth::{
+            RethApiServer, RethBlockStreamApiServer, RethEvmApiServer, RethExExApiServer,
+            RethExecutionRequestsApiServer, RethHeaderProofApiServer, RethPayloadApiServer,
+            RethProposerPaymentsApiServer, RethSimulateApiServer, RethStorageApiServer,
+            RethTxWatcherApiServer,
+        },
         rpc::RpcApiServer,
         trace::TraceApiServer,
         txpool::TxPoolApiServer,
@@ -52,8 +74,9 @@ pub mod servers {
         web3::Web3ApiServer,
     };
     pub use reth_rpc_eth_api::{
-        self as eth, EthApiServer, EthBundleApiServer, EthCallBundleApiServer, EthFilterApiServer,
-        EthPubSubApiServer, L2EthApiExtServer,
+        self as eth, EthApiServer, EthBlockResponseApiServer, EthBundleApiServer,
+        EthCallBundleApiServer, EthFilterApiServer, EthPubSubApiServer, EthPubSubExtApiServer,
+        L2EthApiExtServer,
     };
 }
 
@@ -64,10 +87,18 @@ pub use clients::*;
 /// Aggregates all client traits.
 #[cfg(feature = "client")]
 pub mod clients {
+    #[cfg(feature = "engine-snapshots")]
+    pub use crate::debug::DebugEngineStateApiClient;
+    #[cfg(feature = "preconf")]
+    pub use crate::reth::RethPreconfApiClient;
     pub use crate::{
-        admin::AdminApiClient,
+        admin::{
+            AdminApiClient, AdminBuilderApiClient, AdminExExApiClient, AdminSyncTargetApiClient,
+        },
         anvil::AnvilApiClient,
-        debug::{DebugApiClient, DebugExecutionWitnessApiClient},
+        builder::BuilderApiClient,
+        contract::EthContractCreationApiClient,
+        debug::{DebugApiClient, DebugExecutionWitnessApiClient, DebugTraceFileApiClient},
         engine::{EngineApiClient, EngineEthApiClient},
         ganache::GanacheApiClient,
         hardhat::HardhatApiClient,
@@ -75,7 +106,13 @@ pub mod clients {
         miner::MinerApiClient,
         net::NetApiClient,
         otterscan::OtterscanClient,
-        reth::RethApiClient,
+        personal::PersonalApiClient,
+        reth::{
+            RethApiClient, RethBlockStreamApiClient, RethEvmApiClient, RethExExApiClient,
+            RethExecutionRequestsApiClient, RethHeaderProofApiClient, RethPayloadApiClient,
+            RethProposerPaymentsApiClient, RethSimulateApiClient, RethStorageApiClient,
+            RethTxWatcherApiClient,
+        },
         rpc::RpcApiServer,
         trace::TraceApiClient,
         txpool::TxPoolApiClient,
@@ -83,7 +120,7 @@ pub mod clients {
         web3::Web3ApiClient,
     };
     pub use reth_rpc_eth_api::{
-        EthApiClient, EthBundleApiClient, EthCallBundleApiClient, EthFilterApiClient,
-        L2EthApiExtServer,
+        EthApiClient, EthBlockResponseApiClient, EthBundleApiClient, EthCallBundleApiClient,
+        EthFilterApiClient, L2EthApiExtServer,
     };
 }
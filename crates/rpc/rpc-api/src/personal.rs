@@ -0,0 +1,74 @@
+use alloy_primitives::{Address, Bytes, B256};
+use alloy_rpc_types_eth::TransactionRequest;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+/// Personal rpc interface.
+///
+/// The accounts of the node are held by its signers and are always unlocked, so passwords are
+/// accepted for compatibility but ignored.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "personal"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "personal"))]
+pub trait PersonalApi {
+    /// Returns the addresses of all accounts of the node.
+    #[method(name = "listAccounts")]
+    async fn list_accounts(&self) -> RpcResult<Vec<Address>>;
+
+    /// Generates a new account and returns its address.
+    ///
+    /// The key is only kept in memory and is lost when the node restarts.
+    #[method(name = "newAccount")]
+    async fn new_account(&self, password: Option<String>) -> RpcResult<Address>;
+
+    /// Imports the given hex encoded private key and returns the address of the account.
+    ///
+    /// The key is only kept in memory and is lost when the node restarts.
+    #[method(name = "importRawKey")]
+    async fn import_raw_key(
+        &self,
+        private_key: String,
+        password: Option<String>,
+    ) -> RpcResult<Address>;
+
+    /// Unlocks the account, returns `true` if the account belongs to the node.
+    #[method(name = "unlockAccount")]
+    async fn unlock_account(
+        &self,
+        address: Address,
+        password: Option<String>,
+        duration: Option<u64>,
+    ) -> RpcResult<bool>;
+
+    /// Accounts of the node can't be locked, always returns `false`.
+    #[method(name = "lockAccount")]
+    async fn lock_account(&self, address: Address) -> RpcResult<bool>;
+
+    /// Returns an Ethereum specific signature of the message, like `eth_sign` with swapped
+    /// arguments.
+    #[method(name = "sign")]
+    async fn sign(
+        &self,
+        message: Bytes,
+        address: Address,
+        password: Option<String>,
+    ) -> RpcResult<Bytes>;
+
+    /// Returns the address of the account that created the signature with `personal_sign`.
+    #[method(name = "ecRecover")]
+    async fn ec_recover(&self, message: Bytes, signature: Bytes) -> RpcResult<Address>;
+
+    /// Signs the transaction and returns it EIP-2718 encoded, like `eth_signTransaction`.
+    #[method(name = "signTransaction")]
+    async fn sign_transaction(
+        &self,
+        request: TransactionRequest,
+        password: Option<String>,
+    ) -> RpcResult<Bytes>;
+
+    /// Signs the transaction and submits it to the pool, like `eth_sendTransaction`.
+    #[method(name = "sendTransaction")]
+    async fn send_transaction(
+        &self,
+        request: TransactionRequest,
+        password: Option<String>,
+    ) -> RpcResult<B256>;
+}
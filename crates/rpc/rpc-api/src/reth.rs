@@ -1,6 +1,15 @@
-use alloy_eips::BlockId;
-use alloy_primitives::{Address, U256};
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_json_rpc::RpcObject;
+use alloy_primitives::{Address, TxHash, U256};
+use alloy_rpc_types_engine::PayloadAttributes;
+use alloy_rpc_types_eth::Filter;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_network_api::TransactionPropagation;
+use reth_payload_primitives::SimulatedPayload;
+use reth_rpc_eth_types::{
+    logs_utils::LogStreamResumeToken, BlockEvmEnv, BlockRewards, BlockSummary, BlockWithReceipts,
+    CodeChange, ProposerPayment, SimulatedTransactionInput, TransactionSimulation,
+};
 use std::collections::HashMap;
 
 /// Reth API namespace for reth-specific methods
@@ -13,4 +22,259 @@ pub trait RethApi {
         &self,
         block_id: BlockId,
     ) -> RpcResult<HashMap<Address, U256>>;
+
+    /// Returns when and from which peers the transaction arrived at the node, and to which peers
+    /// it was announced or sent.
+    ///
+    /// Returns `None` if the transaction was not seen recently.
+    #[method(name = "getTransactionPropagation")]
+    async fn reth_get_transaction_propagation(
+        &self,
+        hash: TxHash,
+    ) -> RpcResult<Option<TransactionPropagation>>;
+
+    /// Returns the headers of up to `count` consecutive blocks, starting at `from`.
+    ///
+    /// This is a light alternative to `eth_getBlockByNumber` for consumers that only need the
+    /// headers. If `fields` is set, only these fields of the headers are returned.
+    #[method(name = "getBlockHeaders")]
+    async fn reth_get_block_headers(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+        fields: Option<Vec<String>>,
+    ) -> RpcResult<Vec<serde_json::Value>>;
+
+    /// Returns the burned base fees, the priority fees paid to the fee recipient and the
+    /// pre-merge block rewards of up to `count` consecutive blocks, starting at `from`.
+    #[method(name = "getBlockRewards")]
+    async fn reth_get_block_rewards(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+    ) -> RpcResult<Vec<BlockRewards>>;
+
+    /// Returns summaries of up to `count` consecutive blocks, starting at `from`: their
+    /// transaction count, gas usage, base fee, blob gas and fee recipient.
+    ///
+    /// The summaries are read from the headers and block body indices, without loading the
+    /// transactions. If `include_size` is set, the size of each block is returned as well, which
+    /// requires reading the transactions and lowers the maximum `count` from 10000 to 1000 blocks.
+    #[method(name = "getBlockSummaries")]
+    async fn reth_get_block_summaries(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+        include_size: Option<bool>,
+    ) -> RpcResult<Vec<BlockSummary>>;
+
+    /// Returns all recorded code changes of the account, oldest first: contract deployments,
+    /// selfdestructs and EIP-7702 delegation changes.
+    #[method(name = "getCodeHistory")]
+    async fn reth_get_code_history(&self, address: Address) -> RpcResult<Vec<CodeChange>>;
+
+    /// Streams all historical logs matching the filter in chunks, followed by live logs of newly
+    /// canonical blocks on the same subscription.
+    ///
+    /// If a resume token of a previous stream is provided, streaming continues right after the
+    /// block it points to, regardless of the filter's `fromBlock`.
+    #[subscription(
+        name = "streamLogs",
+        unsubscribe = "streamLogs_unsubscribe",
+        item = reth_rpc_eth_types::logs_utils::LogStreamChunk
+    )]
+    async fn reth_stream_logs(
+        &self,
+        filter: Filter,
+        resume_token: Option<LogStreamResumeToken>,
+    ) -> jsonrpsee::core::SubscriptionResult;
+}
+
+/// Reth API namespace for streaming canonical blocks with their receipts.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethBlockStreamApi<B: RpcObject, R: RpcObject> {
+    /// Streams every newly canonical block together with its receipts and the senders of its
+    /// transactions in a single message.
+    ///
+    /// Unlike following `newHeads` with `eth_getBlockReceipts`, the receipts are taken from the
+    /// same canonical state notification as the block, so they are always available. Blocks that
+    /// were streamed and are reorged out are streamed again with `removed` set.
+    #[subscription(
+        name = "newBlockWithReceipts",
+        unsubscribe = "newBlockWithReceipts_unsubscribe",
+        item = BlockWithReceipts<B, R>
+    )]
+    async fn reth_new_block_with_receipts(&self) -> jsonrpsee::core::SubscriptionResult;
+}
+
+/// Reth API namespace for introspecting local payload building.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethPayloadApi<B: RpcObject> {
+    /// Builds a payload on top of the latest block with the given attributes and transactions
+    /// from the pool with the payload builder of the node, without inserting it.
+    ///
+    /// Returns the would-be block, why each considered transaction was or wasn't included, and
+    /// the total fees paid to the fee recipient.
+    #[method(name = "buildPayload")]
+    async fn reth_build_payload(
+        &self,
+        attributes: PayloadAttributes,
+    ) -> RpcResult<SimulatedPayload<B>>;
+}
+
+/// Reth API namespace for inspecting the EVM environment of blocks.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethEvmApi {
+    /// Returns the EVM configuration and block environment the node executes the transactions of
+    /// the block with: the active spec id, base fee, blob gas price, prevrandao and excess blob
+    /// gas.
+    ///
+    /// For the `pending` tag, this is the environment of the block that is being built.
+    #[method(name = "getEvmEnv")]
+    async fn reth_get_evm_env(&self, block_id: BlockId) -> RpcResult<BlockEvmEnv>;
+}
+
+/// Reth API namespace for previewing transactions.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethSimulateApi {
+    /// Executes a single raw or unsigned transaction on top of the block, `latest` by default,
+    /// without committing it.
+    ///
+    /// Returns the status, gas used, logs, the generated access list and the balance changes of
+    /// all affected accounts, which would otherwise require multiple calls.
+    #[method(name = "simulateTransaction")]
+    async fn reth_simulate_transaction(
+        &self,
+        transaction: SimulatedTransactionInput,
+        block_id: Option<BlockId>,
+    ) -> RpcResult<TransactionSimulation>;
+}
+
+/// Reth API namespace for transaction preconfirmations.
+#[cfg(feature = "preconf")]
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethPreconfApi {
+    /// Simulates the raw transaction at the end of the block that is being built and, if it fits,
+    /// submits it to the pool and returns a signed commitment to include it in that block.
+    #[method(name = "preconfirmTransaction")]
+    async fn reth_preconfirm_transaction(
+        &self,
+        tx: alloy_primitives::Bytes,
+    ) -> RpcResult<reth_rpc_eth_types::Preconfirmation>;
+}
+
+/// Reth API namespace for the transaction watcher.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethTxWatcherApi {
+    /// Starts watching the transaction for inclusion, confirmation and reorgs.
+    ///
+    /// Returns `false` if the transaction was already watched.
+    #[method(name = "watchTransaction")]
+    async fn reth_watch_transaction(&self, hash: TxHash) -> RpcResult<bool>;
+
+    /// Stops watching the transaction.
+    ///
+    /// Returns `false` if the transaction wasn't watched.
+    #[method(name = "unwatchTransaction")]
+    async fn reth_unwatch_transaction(&self, hash: TxHash) -> RpcResult<bool>;
+
+    /// Starts watching all transactions of the sender for inclusion, confirmation and reorgs.
+    ///
+    /// Returns `false` if the sender was already watched.
+    #[method(name = "watchSender")]
+    async fn reth_watch_sender(&self, sender: Address) -> RpcResult<bool>;
+
+    /// Stops watching the transactions of the sender.
+    ///
+    /// Returns `false` if the sender wasn't watched.
+    #[method(name = "unwatchSender")]
+    async fn reth_unwatch_sender(&self, sender: Address) -> RpcResult<bool>;
+
+    /// Streams the inclusion, confirmation and reorg events of all watched transactions.
+    #[subscription(
+        name = "transactionEvents",
+        unsubscribe = "transactionEvents_unsubscribe",
+        item = reth_node_tx_watcher::TransactionEvent
+    )]
+    async fn reth_transaction_events(&self) -> jsonrpsee::core::SubscriptionResult;
+}
+
+/// Reth API namespace for the accumulator of canonical block hashes.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethHeaderProofApi {
+    /// Returns the inclusion proof of the canonical block hash against the latest root of the
+    /// accumulator of canonical block hashes.
+    ///
+    /// Returns `None` if the block is not accumulated yet.
+    #[method(name = "getHeaderProof")]
+    async fn reth_get_header_proof(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> RpcResult<Option<reth_db_models::HeaderProof>>;
+}
+
+/// Reth API namespace for the status of the installed execution extensions.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethExExApi {
+    /// Returns the installed `ExEx`'s with their finished heights and lag behind the tip, and the
+    /// occupancy of the notification buffer of the `ExEx` manager.
+    #[method(name = "exexStatus")]
+    fn reth_exex_status(&self) -> RpcResult<reth_exex_types::ExExManagerStatus>;
+}
+
+/// Reth API namespace for the index of execution layer requests.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethExecutionRequestsApi {
+    /// Returns the deposit, withdrawal and consolidation requests of the block, in the order of
+    /// the block.
+    ///
+    /// Returns `None` if the block is not indexed.
+    #[method(name = "getRequestsByBlock")]
+    async fn reth_get_requests_by_block(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> RpcResult<Option<Vec<reth_db_models::ExecutionRequest>>>;
+
+    /// Returns all indexed requests that refer to the validator, ordered by block.
+    ///
+    /// Consolidation requests are returned for both the source and the target validator.
+    #[method(name = "getRequestsByValidator")]
+    async fn reth_get_requests_by_validator(
+        &self,
+        pubkey: reth_db_models::ValidatorPubkey,
+    ) -> RpcResult<Vec<reth_db_models::BlockExecutionRequest>>;
+}
+
+/// Reth API namespace for the payments of locally proposed blocks.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethProposerPaymentsApi {
+    /// Returns the payments of the fee recipients of the most recent canonical blocks that were
+    /// proposed through this node, oldest first.
+    ///
+    /// If set, only the latest `limit` payments are returned.
+    #[method(name = "getProposerPayments")]
+    fn reth_get_proposer_payments(&self, limit: Option<usize>) -> RpcResult<Vec<ProposerPayment>>;
+}
+
+/// Reth API namespace for the disk usage of the node.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "reth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "reth"))]
+pub trait RethStorageApi {
+    /// Returns the disk usage of the node attributed to state, history indices, headers,
+    /// transactions, receipts and the transaction index, together with the prune backlog.
+    ///
+    /// The growth rates of the categories are measured against an earlier report of the node, and
+    /// are not set on the first call.
+    #[method(name = "storageBreakdown")]
+    async fn reth_storage_breakdown(&self) -> RpcResult<reth_db_models::StorageBreakdown>;
 }
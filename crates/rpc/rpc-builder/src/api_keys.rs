@@ -0,0 +1,662 @@
+//! API key based access control for the RPC server.
+//!
+//! API keys are extracted from a header or a query parameter of the HTTP request by the
+//! [`ApiKeyLayer`], and enforced for every call by the [`ApiKeyRpcLayer`]. Every key is mapped to
+//! an [`ApiKeyPolicy`] that limits the rate of requests and the methods that can be called.
+//!
+//! The keys can be loaded from a JSON file, which is reloaded on changes once
+//! [`ApiKeys::watch`] is called:
+//!
+//! ```json
+//! {
+//!     "header": "x-api-key",
+//!     "query_param": "apikey",
+//!     "anonymous": { "requests_per_second": 5, "allowed_methods": ["eth_*", "net_version"] },
+//!     "keys": {
+//!         "secret": { "requests_per_second": 100, "namespaces": { "debug": 2, "trace": 2 } }
+//!     }
+//! }
+//! ```
+
+use http::{HeaderMap, Request, Uri};
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, Request as RpcRequest},
+    MethodResponse,
+};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Instant,
+};
+use subtle::ConstantTimeEq;
+use tower::{Layer, Service};
+use tracing::{debug, info, warn};
+
+/// Default name of the HTTP header that carries the API key.
+pub const DEFAULT_API_KEY_HEADER: &str = "x-api-key";
+
+/// Default name of the query parameter that carries the API key.
+pub const DEFAULT_API_KEY_QUERY_PARAM: &str = "apikey";
+
+/// Error code returned for calls with a missing or unknown API key.
+pub const INVALID_API_KEY_CODE: i32 = -32001;
+
+/// Error code returned for calls to methods that are not allowed for the API key.
+pub const METHOD_NOT_ALLOWED_CODE: i32 = -32004;
+
+/// Error code returned for calls that exceed the rate limit of the API key.
+pub const RATE_LIMITED_CODE: i32 = -32005;
+
+/// Configuration of the API keys accepted by the RPC server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiKeysConfig {
+    /// Name of the HTTP header that carries the API key.
+    pub header: String,
+    /// Name of the query parameter that carries the API key, used if the header is not set.
+    pub query_param: String,
+    /// Policy for requests without an API key.
+    ///
+    /// If not set, requests without an API key are rejected.
+    pub anonymous: Option<ApiKeyPolicy>,
+    /// Policies of the accepted API keys.
+    pub keys: HashMap<String, ApiKeyPolicy>,
+}
+
+impl Default for ApiKeysConfig {
+    fn default() -> Self {
+        Self {
+            header: DEFAULT_API_KEY_HEADER.to_string(),
+            query_param: DEFAULT_API_KEY_QUERY_PARAM.to_string(),
+            anonymous: None,
+            keys: HashMap::default(),
+        }
+    }
+}
+
+/// Limits that apply to the calls made with an API key.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiKeyPolicy {
+    /// Maximum number of calls per second across all methods.
+    ///
+    /// If not set, the number of calls is not limited.
+    pub requests_per_second: Option<u32>,
+    /// Maximum number of calls per second per namespace, e.g. `"trace": 5` limits all `trace_`
+    /// methods.
+    pub namespaces: HashMap<String, u32>,
+    /// Methods that can be called. Entries ending with `*` match all methods with that prefix,
+    /// e.g. `eth_*`.
+    ///
+    /// If not set, all methods can be called.
+    pub allowed_methods: Option<Vec<String>>,
+}
+
+impl ApiKeyPolicy {
+    /// Returns `true` if the method can be called with this policy.
+    pub fn is_method_allowed(&self, method: &str) -> bool {
+        let Some(allowed_methods) = &self.allowed_methods else { return true };
+        allowed_methods.iter().any(|allowed| match allowed.strip_suffix('*') {
+            Some(prefix) => method.starts_with(prefix),
+            None => method == allowed,
+        })
+    }
+}
+
+/// Reason why a call was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiKeyRejection {
+    /// The API key is missing or unknown.
+    InvalidKey,
+    /// The method is not allowed for the API key.
+    MethodNotAllowed,
+    /// The rate limit of the API key is exceeded.
+    RateLimited,
+}
+
+impl From<ApiKeyRejection> for ErrorObject<'static> {
+    fn from(rejection: ApiKeyRejection) -> Self {
+        match rejection {
+            ApiKeyRejection::InvalidKey => {
+                ErrorObject::owned(INVALID_API_KEY_CODE, "missing or invalid API key", None::<()>)
+            }
+            ApiKeyRejection::MethodNotAllowed => ErrorObject::owned(
+                METHOD_NOT_ALLOWED_CODE,
+                "method not allowed for API key",
+                None::<()>,
+            ),
+            ApiKeyRejection::RateLimited => {
+                ErrorObject::owned(RATE_LIMITED_CODE, "rate limit exceeded", None::<()>)
+            }
+        }
+    }
+}
+
+/// Errors that can occur when loading or watching the API keys.
+#[derive(Debug, thiserror::Error)]
+pub enum ApiKeysError {
+    /// Failed to read the configuration file.
+    #[error("failed to read API keys from {path:?}: {source}")]
+    Read {
+        /// Path of the configuration file.
+        path: PathBuf,
+        /// The underlying error.
+        source: std::io::Error,
+    },
+    /// Failed to parse the configuration file.
+    #[error("failed to parse API keys from {path:?}: {source}")]
+    Parse {
+        /// Path of the configuration file.
+        path: PathBuf,
+        /// The underlying error.
+        source: serde_json::Error,
+    },
+    /// The API keys were not loaded from a file, so they can't be reloaded.
+    #[error("API keys were not loaded from a file")]
+    NoFile,
+    /// Failed to watch the configuration file.
+    #[error(transparent)]
+    Watch(#[from] notify::Error),
+}
+
+/// API key extracted from a request by the [`ApiKeyLayer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKey(pub String);
+
+/// Shared registry of the accepted API keys and their rate limits.
+///
+/// Cloning this is cheap, and all clones share the same configuration and rate limits.
+#[derive(Debug, Clone)]
+pub struct ApiKeys {
+    inner: Arc<ApiKeysInner>,
+}
+
+#[derive(Debug)]
+struct ApiKeysInner {
+    /// File the configuration was loaded from, if any.
+    path: Option<PathBuf>,
+    /// The active configuration and rate limits.
+    state: RwLock<ApiKeysState>,
+}
+
+impl ApiKeys {
+    /// Creates a new registry with the given configuration.
+    pub fn new(config: ApiKeysConfig) -> Self {
+        Self { inner: Arc::new(ApiKeysInner { path: None, state: RwLock::new(config.into()) }) }
+    }
+
+    /// Loads the configuration from the JSON file at the given path.
+    pub fn from_file(path: impl Into<PathBuf>) -> Result<Self, ApiKeysError> {
+        let path = path.into();
+        let config = read_config(&path)?;
+        Ok(Self {
+            inner: Arc::new(ApiKeysInner { path: Some(path), state: RwLock::new(config.into()) }),
+        })
+    }
+
+    /// Returns the active configuration.
+    pub fn config(&self) -> ApiKeysConfig {
+        self.inner.state.read().config.clone()
+    }
+
+    /// Replaces the active configuration.
+    ///
+    /// This resets the rate limits of all keys.
+    pub fn set_config(&self, config: ApiKeysConfig) {
+        *self.inner.state.write() = config.into();
+    }
+
+    /// Reloads the configuration from the file it was loaded from.
+    ///
+    /// The active configuration is kept if the file can't be loaded.
+    pub fn reload(&self) -> Result<(), ApiKeysError> {
+        let path = self.inner.path.as_ref().ok_or(ApiKeysError::NoFile)?;
+        self.set_config(read_config(path)?);
+        Ok(())
+    }
+
+    /// Spawns a thread that reloads the configuration whenever the file it was loaded from
+    /// changes.
+    pub fn watch(&self) -> Result<(), ApiKeysError> {
+        let path = self.inner.path.clone().ok_or(ApiKeysError::NoFile)?;
+        // Watch the parent directory, because editors commonly replace the file instead of
+        // modifying it.
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut watcher = RecommendedWatcher::new(
+            move |res| {
+                let _ = tx.send(res);
+            },
+            notify::Config::default(),
+        )?;
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+
+        let keys = self.clone();
+        std::thread::spawn(move || {
+            // Keep the watcher alive for as long as the thread runs.
+            let _watcher = watcher;
+            let file_name = path.file_name();
+
+            while let Ok(res) = rx.recv() {
+                let event = match res {
+                    Ok(event) => event,
+                    Err(err) => {
+                        warn!(target: "rpc::api_keys", %err, "Failed to watch API keys file");
+                        continue
+                    }
+                };
+
+                if !matches!(
+                    event.kind,
+                    notify::EventKind::Modify(_) | notify::EventKind::Create(_)
+                ) || !event.paths.iter().any(|changed| changed.file_name() == file_name)
+                {
+                    continue
+                }
+
+                match keys.reload() {
+                    Ok(()) => info!(target: "rpc::api_keys", ?path, "Reloaded API keys"),
+                    Err(err) => {
+                        warn!(target: "rpc::api_keys", %err, "Failed to reload API keys, keeping the active ones")
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Extracts the API key from the headers or, if not set, the query of a request.
+    pub fn extract(&self, headers: &HeaderMap, uri: &Uri) -> Option<String> {
+        let state = self.inner.state.read();
+        let config = &state.config;
+
+        if let Some(key) = headers.get(&config.header).and_then(|value| value.to_str().ok()) {
+            return Some(key.to_string())
+        }
+
+        url::form_urlencoded::parse(uri.query()?.as_bytes())
+            .find_map(|(name, value)| (name == config.query_param).then(|| value.into_owned()))
+    }
+
+    /// Checks whether a call to the method with the given API key is allowed, and consumes from
+    /// the rate limits of the key if so.
+    pub fn check(&self, key: Option<&str>, method: &str) -> Result<(), ApiKeyRejection> {
+        self.check_at(key, method, Instant::now())
+    }
+
+    fn check_at(
+        &self,
+        key: Option<&str>,
+        method: &str,
+        now: Instant,
+    ) -> Result<(), ApiKeyRejection> {
+        let state = self.inner.state.read();
+        let limits = match key {
+            Some(key) => state.find_key(key),
+            None => state.anonymous.as_ref(),
+        }
+        .ok_or(ApiKeyRejection::InvalidKey)?;
+
+        let result = limits.lock().check(method, now);
+        result
+    }
+}
+
+/// The active configuration together with the rate limits of every key.
+#[derive(Debug)]
+struct ApiKeysState {
+    config: ApiKeysConfig,
+    anonymous: Option<Mutex<KeyLimits>>,
+    keys: Vec<(String, Mutex<KeyLimits>)>,
+}
+
+impl ApiKeysState {
+    /// Returns the rate limits of the given API key.
+    ///
+    /// The key is compared against every configured key in constant time, so that the time it
+    /// takes doesn't reveal how much of a key was guessed correctly.
+    fn find_key(&self, key: &str) -> Option<&Mutex<KeyLimits>> {
+        self.keys.iter().fold(None, |found, (candidate, limits)| {
+            if bool::from(candidate.as_bytes().ct_eq(key.as_bytes())) {
+                Some(limits)
+            } else {
+                found
+            }
+        })
+    }
+}
+
+impl From<ApiKeysConfig> for ApiKeysState {
+    fn from(config: ApiKeysConfig) -> Self {
+        let anonymous = config.anonymous.clone().map(|policy| Mutex::new(KeyLimits::new(policy)));
+        let keys = config
+            .keys
+            .iter()
+            .map(|(key, policy)| (key.clone(), Mutex::new(KeyLimits::new(policy.clone()))))
+            .collect();
+        Self { config, anonymous, keys }
+    }
+}
+
+/// Rate limits of a single API key.
+#[derive(Debug)]
+struct KeyLimits {
+    policy: ApiKeyPolicy,
+    /// Limit across all methods.
+    total: Option<TokenBucket>,
+    /// Limits per namespace.
+    namespaces: HashMap<String, TokenBucket>,
+}
+
+impl KeyLimits {
+    fn new(policy: ApiKeyPolicy) -> Self {
+        let total = policy.requests_per_second.map(TokenBucket::new);
+        let namespaces = policy
+            .namespaces
+            .iter()
+            .map(|(namespace, per_second)| (namespace.clone(), TokenBucket::new(*per_second)))
+            .collect();
+        Self { policy, total, namespaces }
+    }
+
+    fn check(&mut self, method: &str, now: Instant) -> Result<(), ApiKeyRejection> {
+        if !self.policy.is_method_allowed(method) {
+            return Err(ApiKeyRejection::MethodNotAllowed)
+        }
+
+        let namespace = method.split_once('_').map(|(namespace, _)| namespace);
+        let mut namespace_limit =
+            namespace.and_then(|namespace| self.namespaces.get_mut(namespace));
+
+        // Only consume from the buckets if all of them have capacity left.
+        if self.total.as_mut().is_some_and(|bucket| !bucket.refill(now)) ||
+            namespace_limit.as_mut().is_some_and(|bucket| !bucket.refill(now))
+        {
+            return Err(ApiKeyRejection::RateLimited)
+        }
+        if let Some(bucket) = self.total.as_mut() {
+            bucket.consume();
+        }
+        if let Some(bucket) = namespace_limit {
+            bucket.consume();
+        }
+
+        Ok(())
+    }
+}
+
+/// Token bucket that allows bursts of up to one second worth of calls.
+#[derive(Debug)]
+struct TokenBucket {
+    per_second: f64,
+    tokens: f64,
+    last_refill: Option<Instant>,
+}
+
+impl TokenBucket {
+    fn new(per_second: u32) -> Self {
+        let per_second = per_second as f64;
+        Self { per_second, tokens: per_second, last_refill: None }
+    }
+
+    /// Refills the bucket and returns `true` if a token is available.
+    fn refill(&mut self, now: Instant) -> bool {
+        if let Some(last_refill) = self.last_refill {
+            let elapsed = now.saturating_duration_since(last_refill).as_secs_f64();
+            self.tokens = elapsed.mul_add(self.per_second, self.tokens).min(self.per_second);
+        }
+        self.last_refill = Some(now);
+        self.tokens >= 1.0
+    }
+
+    fn consume(&mut self) {
+        self.tokens -= 1.0;
+    }
+}
+
+fn read_config(path: &Path) -> Result<ApiKeysConfig, ApiKeysError> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|source| ApiKeysError::Read { path: path.to_path_buf(), source })?;
+    serde_json::from_str(&contents)
+        .map_err(|source| ApiKeysError::Parse { path: path.to_path_buf(), source })
+}
+
+/// Http middleware layer that extracts the API key of a request and stores it as an [`ApiKey`]
+/// request extension, which is passed on to the RPC calls of the request.
+#[derive(Debug, Clone)]
+pub struct ApiKeyLayer {
+    keys: ApiKeys,
+}
+
+impl ApiKeyLayer {
+    /// Creates a new layer that extracts the API keys as configured in the given [`ApiKeys`].
+    pub const fn new(keys: ApiKeys) -> Self {
+        Self { keys }
+    }
+}
+
+impl<S> Layer<S> for ApiKeyLayer {
+    type Service = ApiKeyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiKeyService { keys: self.keys.clone(), inner }
+    }
+}
+
+/// Http service that extracts the API key of a request, see [`ApiKeyLayer`].
+#[derive(Debug, Clone)]
+pub struct ApiKeyService<S> {
+    keys: ApiKeys,
+    inner: S,
+}
+
+impl<S, B> Service<Request<B>> for ApiKeyService<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        if let Some(key) = self.keys.extract(req.headers(), req.uri()) {
+            req.extensions_mut().insert(ApiKey(key));
+        }
+        self.inner.call(req)
+    }
+}
+
+/// RPC middleware layer that enforces the [`ApiKeyPolicy`] of the [`ApiKey`] of every call.
+///
+/// All calls are passed through if no [`ApiKeys`] are configured.
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyRpcLayer {
+    keys: Option<ApiKeys>,
+}
+
+impl ApiKeyRpcLayer {
+    /// Creates a new layer that enforces the given [`ApiKeys`], if any.
+    pub const fn new(keys: Option<ApiKeys>) -> Self {
+        Self { keys }
+    }
+}
+
+impl<S> Layer<S> for ApiKeyRpcLayer {
+    type Service = ApiKeyRpcService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiKeyRpcService { keys: self.keys.clone(), inner }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that rejects calls not allowed by the [`ApiKeyPolicy`] of their
+/// [`ApiKey`].
+#[derive(Debug, Clone)]
+pub struct ApiKeyRpcService<S> {
+    keys: Option<ApiKeys>,
+    inner: S,
+}
+
+impl<'a, S> RpcServiceT<'a> for ApiKeyRpcService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = ApiKeyResponseFuture<S::Future>;
+
+    fn call(&self, req: RpcRequest<'a>) -> Self::Future {
+        let Some(keys) = &self.keys else {
+            return ApiKeyResponseFuture::Call { fut: self.inner.call(req) }
+        };
+
+        let key = req.extensions().get::<ApiKey>().map(|key| key.0.as_str());
+        match keys.check(key, req.method_name()) {
+            Ok(()) => ApiKeyResponseFuture::Call { fut: self.inner.call(req) },
+            Err(rejection) => {
+                debug!(target: "rpc::api_keys", method = req.method_name(), ?rejection, "Rejected call");
+                ApiKeyResponseFuture::Rejected {
+                    response: Some(MethodResponse::error(req.id().into_owned(), rejection)),
+                }
+            }
+        }
+    }
+}
+
+/// Response future of the [`ApiKeyRpcService`].
+#[pin_project::pin_project(project = ApiKeyResponseFutureProj)]
+#[allow(missing_debug_implementations)]
+pub enum ApiKeyResponseFuture<F> {
+    /// The call was allowed and is being processed.
+    Call {
+        /// The inner future.
+        #[pin]
+        fut: F,
+    },
+    /// The call was rejected.
+    Rejected {
+        /// The error response.
+        response: Option<MethodResponse>,
+    },
+}
+
+impl<F: Future<Output = MethodResponse>> Future for ApiKeyResponseFuture<F> {
+    type Output = MethodResponse;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.project() {
+            ApiKeyResponseFutureProj::Call { fut } => fut.poll(cx),
+            ApiKeyResponseFutureProj::Rejected { response } => {
+                Poll::Ready(response.take().expect("polled after completion"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn config() -> ApiKeysConfig {
+        serde_json::from_str(
+            r#"{
+                "anonymous": { "allowed_methods": ["eth_*", "net_version"] },
+                "keys": {
+                    "limited": { "requests_per_second": 2, "namespaces": { "trace": 1 } }
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn extract_api_key() {
+        let keys = ApiKeys::new(config());
+
+        let mut headers = HeaderMap::new();
+        let uri: Uri = "/?foo=bar&apikey=from-query".parse().unwrap();
+        assert_eq!(keys.extract(&headers, &uri), Some("from-query".to_string()));
+
+        headers.insert(DEFAULT_API_KEY_HEADER, "from-header".parse().unwrap());
+        assert_eq!(keys.extract(&headers, &uri), Some("from-header".to_string()));
+
+        assert_eq!(keys.extract(&HeaderMap::new(), &"/".parse().unwrap()), None);
+
+        // query parameters are percent-decoded
+        let uri: Uri = "/?apikey=a%2Bb%3Dc+d".parse().unwrap();
+        assert_eq!(keys.extract(&HeaderMap::new(), &uri), Some("a+b=c d".to_string()));
+    }
+
+    #[test]
+    fn method_allowlist() {
+        let keys = ApiKeys::new(config());
+
+        assert_eq!(keys.check(None, "eth_call"), Ok(()));
+        assert_eq!(keys.check(None, "net_version"), Ok(()));
+        assert_eq!(keys.check(None, "net_peerCount"), Err(ApiKeyRejection::MethodNotAllowed));
+        assert_eq!(keys.check(Some("unknown"), "eth_call"), Err(ApiKeyRejection::InvalidKey));
+        assert_eq!(keys.check(Some("limite"), "eth_call"), Err(ApiKeyRejection::InvalidKey));
+        assert_eq!(keys.check(Some("limitee"), "eth_call"), Err(ApiKeyRejection::InvalidKey));
+        assert_eq!(keys.check(Some("limited"), "eth_call"), Ok(()));
+
+        keys.set_config(ApiKeysConfig { anonymous: None, ..config() });
+        assert_eq!(keys.check(None, "eth_call"), Err(ApiKeyRejection::InvalidKey));
+    }
+
+    #[test]
+    fn rate_limits() {
+        let keys = ApiKeys::new(config());
+        let now = Instant::now();
+        let check = |method, elapsed| keys.check_at(Some("limited"), method, now + elapsed);
+
+        assert_eq!(check("trace_block", Duration::ZERO), Ok(()));
+        // The namespace limit is exhausted, but other namespaces can still be called.
+        assert_eq!(check("trace_block", Duration::ZERO), Err(ApiKeyRejection::RateLimited));
+        assert_eq!(check("eth_call", Duration::ZERO), Ok(()));
+        // The total limit is exhausted.
+        assert_eq!(check("eth_call", Duration::ZERO), Err(ApiKeyRejection::RateLimited));
+
+        // Half a second refills one token of the total limit.
+        assert_eq!(check("eth_call", Duration::from_millis(500)), Ok(()));
+        assert_eq!(
+            check("eth_call", Duration::from_millis(500)),
+            Err(ApiKeyRejection::RateLimited)
+        );
+    }
+
+    #[test]
+    fn reload_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("api-keys.json");
+
+        std::fs::write(&path, r#"{ "keys": { "first": {} } }"#).unwrap();
+        let keys = ApiKeys::from_file(&path).unwrap();
+        assert_eq!(keys.check(Some("first"), "eth_call"), Ok(()));
+
+        std::fs::write(&path, r#"{ "keys": { "second": {} } }"#).unwrap();
+        keys.reload().unwrap();
+        assert_eq!(keys.check(Some("first"), "eth_call"), Err(ApiKeyRejection::InvalidKey));
+        assert_eq!(keys.check(Some("second"), "eth_call"), Ok(()));
+
+        // Invalid files keep the active configuration.
+        std::fs::write(&path, "{").unwrap();
+        assert!(matches!(keys.reload(), Err(ApiKeysError::Parse { .. })));
+        assert_eq!(keys.check(Some("second"), "eth_call"), Ok(()));
+    }
+}
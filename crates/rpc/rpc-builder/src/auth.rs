@@ -1,9 +1,12 @@
-use crate::error::{RpcError, ServerKind};
+use crate::{
+    error::{RpcError, ServerKind},
+    RpcLegacyErrorCodesLayer, RpcRequestSpanLayer,
+};
 use http::header::AUTHORIZATION;
 use jsonrpsee::{
     core::RegisterMethodError,
     http_client::{transport::HttpBackend, HeaderMap},
-    server::{AlreadyStoppedError, RpcModule},
+    server::{AlreadyStoppedError, RpcModule, RpcServiceBuilder},
     Methods,
 };
 use reth_rpc_api::servers::*;
@@ -18,6 +21,7 @@ use tower::layer::util::Identity;
 
 pub use jsonrpsee::server::ServerBuilder;
 pub use reth_ipc::server::Builder as IpcServerBuilder;
+use reth_ipc::server::RpcServiceBuilder as IpcRpcServiceBuilder;
 
 /// Server configuration for the auth server.
 #[derive(Debug)]
@@ -58,6 +62,12 @@ impl AuthServerConfig {
         // By default, both http and ws are enabled.
         let server = server_config
             .set_http_middleware(middleware)
+            .set_rpc_middleware(
+                // consensus clients expect the legacy error codes
+                RpcServiceBuilder::new()
+                    .layer(RpcLegacyErrorCodesLayer::default())
+                    .layer(RpcRequestSpanLayer::new("auth")),
+            )
             .build(socket_addr)
             .await
             .map_err(|err| RpcError::server_error(err, ServerKind::Auth(socket_addr)))?;
@@ -73,7 +83,13 @@ impl AuthServerConfig {
             let ipc_endpoint_str = ipc_endpoint
                 .clone()
                 .unwrap_or_else(|| constants::DEFAULT_ENGINE_API_IPC_ENDPOINT.to_string());
-            let ipc_server = ipc_server_config.build(ipc_endpoint_str);
+            let ipc_server = ipc_server_config
+                .set_rpc_middleware(
+                    IpcRpcServiceBuilder::new()
+                        .layer(RpcLegacyErrorCodesLayer::default())
+                        .layer(RpcRequestSpanLayer::new("auth_ipc")),
+                )
+                .build(ipc_endpoint_str);
             let res = ipc_server.start(module.inner).await?;
             ipc_handle = Some(res);
         }
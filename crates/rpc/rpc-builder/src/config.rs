@@ -1,23 +1,38 @@
-use std::{net::SocketAddr, path::PathBuf};
+use std::path::PathBuf;
 
 use jsonrpsee::server::ServerBuilder;
-use reth_node_core::{args::RpcServerArgs, utils::get_or_create_jwt_secret_from_path};
+use reth_chainspec::EthChainSpec;
 use reth_rpc::ValidationApiConfig;
 use reth_rpc_eth_types::{EthConfig, EthStateCacheConfig, GasPriceOracleConfig};
 use reth_rpc_layer::{JwtError, JwtSecret};
-use reth_rpc_server_types::RpcModuleSelection;
 use tower::layer::util::Identity;
-use tracing::{debug, warn};
 
 use crate::{
-    auth::AuthServerConfig, error::RpcError, IpcServerBuilder, RpcModuleConfig, RpcServerConfig,
-    TransportRpcModuleConfig,
+    api_keys::{ApiKeys, ApiKeysError},
+    auth::AuthServerConfig,
+    error::RpcError,
+    tracing_queue::TracingQueueConfig,
+    IpcServerBuilder, RpcServerConfig, TransportRpcModuleConfig,
+};
+
+#[cfg(feature = "node-core")]
+use {
+    crate::RpcModuleConfig,
+    reth_evm::CallEnvOverrides,
+    reth_node_core::{
+        args::RpcServerArgs,
+        utils::{get_or_create_jwt_secret_from_path, read_jwt_secret},
+    },
+    reth_rpc_eth_types::TxLookupConfig,
+    reth_rpc_server_types::RpcModuleSelection,
+    std::net::SocketAddr,
+    tracing::{debug, warn},
 };
 
 /// A trait that provides a configured RPC server.
 ///
 /// This provides all basic config values for the RPC server and is implemented by the
-/// [`RpcServerArgs`] type.
+/// `RpcServerArgs` type of `reth-node-core` if the `node-core` feature is enabled.
 pub trait RethRpcServerConfig {
     /// Returns whether ipc is enabled.
     fn is_ipc_enabled(&self) -> bool;
@@ -28,6 +43,20 @@ pub trait RethRpcServerConfig {
     /// The configured ethereum RPC settings.
     fn eth_config(&self) -> EthConfig;
 
+    /// Returns the configured number of blocks below the tip after which a block is assumed to be
+    /// final, if any.
+    fn max_reorg_depth(&self) -> Option<u64> {
+        None
+    }
+
+    /// The configured ethereum RPC settings for the given chain.
+    ///
+    /// Unless it's configured, the max reorg depth is the one of the chain.
+    fn eth_config_for_chain<C: EthChainSpec>(&self, chain_spec: &C) -> EthConfig {
+        self.eth_config()
+            .max_reorg_depth(self.max_reorg_depth().unwrap_or_else(|| chain_spec.max_reorg_depth()))
+    }
+
     /// The configured ethereum RPC settings.
     fn flashbots_config(&self) -> ValidationApiConfig;
 
@@ -40,6 +69,12 @@ pub trait RethRpcServerConfig {
     /// Returns the max response size in bytes.
     fn rpc_max_response_size_bytes(&self) -> u32;
 
+    /// Returns the memory budget of a single request in bytes, if any.
+    fn rpc_max_request_memory_bytes(&self) -> Option<usize>;
+
+    /// Returns the config of the queue of `debug_` and `trace_` calls, if enabled.
+    fn tracing_queue_config(&self) -> Option<TracingQueueConfig>;
+
     /// Extracts the gas price oracle config from the args.
     fn gas_price_oracle_config(&self) -> GasPriceOracleConfig;
 
@@ -80,8 +115,12 @@ pub trait RethRpcServerConfig {
     ///
     /// Note: this is not used for the auth server (engine API).
     fn rpc_secret_key(&self) -> Option<JwtSecret>;
+
+    /// Loads the configured [`ApiKeys`] for the regular rpc servers, if any.
+    fn rpc_api_keys(&self) -> Result<Option<ApiKeys>, ApiKeysError>;
 }
 
+#[cfg(feature = "node-core")]
 impl RethRpcServerConfig for RpcServerArgs {
     fn is_ipc_enabled(&self) -> bool {
         // By default IPC is enabled therefore it is enabled if the `ipcdisable` is false.
@@ -93,16 +132,39 @@ impl RethRpcServerConfig for RpcServerArgs {
     }
 
     fn eth_config(&self) -> EthConfig {
-        EthConfig::default()
+        let config = EthConfig::default()
             .max_tracing_requests(self.rpc_max_tracing_requests)
             .max_blocks_per_filter(self.rpc_max_blocks_per_filter.unwrap_or_max())
             .max_logs_per_response(self.rpc_max_logs_per_response.unwrap_or_max() as usize)
             .eth_proof_window(self.rpc_eth_proof_window)
             .rpc_gas_cap(self.rpc_gas_cap)
             .rpc_max_simulate_blocks(self.rpc_max_simulate_blocks)
+            .call_env_overrides(
+                CallEnvOverrides::new()
+                    .with_disable_eip3607(!self.rpc_call_enforce_eip3607)
+                    .with_disable_base_fee(!self.rpc_call_enforce_base_fee)
+                    .with_base_fee(self.rpc_call_base_fee)
+                    .with_allow_zero_gas_price(self.rpc_call_allow_zero_gas_price),
+            )
             .state_cache(self.state_cache_config())
             .gpo_config(self.gas_price_oracle_config())
             .proof_permits(self.rpc_proof_permits)
+            .tx_lookup(
+                TxLookupConfig::default()
+                    .with_fallback(self.rpc_tx_lookup_fallback)
+                    .with_max_scan_blocks(self.rpc_tx_lookup_max_scan_blocks),
+            )
+            .rpc_chain_id(self.rpc_chain_id)
+            .syncing_stage_detail(self.rpc_syncing_stage_detail);
+
+        match self.rpc_max_reorg_depth {
+            Some(max_reorg_depth) => config.max_reorg_depth(max_reorg_depth),
+            None => config,
+        }
+    }
+
+    fn max_reorg_depth(&self) -> Option<u64> {
+        self.rpc_max_reorg_depth
     }
 
     fn flashbots_config(&self) -> ValidationApiConfig {
@@ -113,11 +175,13 @@ impl RethRpcServerConfig for RpcServerArgs {
     }
 
     fn state_cache_config(&self) -> EthStateCacheConfig {
+        let default = EthStateCacheConfig::default();
         EthStateCacheConfig {
             max_blocks: self.rpc_state_cache.max_blocks,
             max_receipts: self.rpc_state_cache.max_receipts,
             max_headers: self.rpc_state_cache.max_headers,
             max_concurrent_db_requests: self.rpc_state_cache.max_concurrent_db_requests,
+            max_reorg_depth: self.rpc_max_reorg_depth.unwrap_or(default.max_reorg_depth),
         }
     }
 
@@ -129,6 +193,19 @@ impl RethRpcServerConfig for RpcServerArgs {
         self.rpc_max_response_size.get().saturating_mul(1024 * 1024)
     }
 
+    fn rpc_max_request_memory_bytes(&self) -> Option<usize> {
+        self.rpc_max_request_memory.map(|mb| (mb as usize).saturating_mul(1024 * 1024))
+    }
+
+    fn tracing_queue_config(&self) -> Option<TracingQueueConfig> {
+        self.rpc_tracing_queue_max_concurrent.map(|max_concurrent| TracingQueueConfig {
+            max_concurrent,
+            max_queued: self.rpc_tracing_queue_max_queued,
+            max_per_client: self.rpc_tracing_queue_max_per_client,
+            timeout: self.rpc_tracing_queue_timeout,
+        })
+    }
+
     fn gas_price_oracle_config(&self) -> GasPriceOracleConfig {
         self.gas_price_oracle.gas_price_oracle_config()
     }
@@ -177,7 +254,11 @@ impl RethRpcServerConfig for RpcServerArgs {
     }
 
     fn rpc_server_config(&self) -> RpcServerConfig {
-        let mut config = RpcServerConfig::default().with_jwt_secret(self.rpc_secret_key());
+        let mut config = RpcServerConfig::default()
+            .with_jwt_secret(self.rpc_secret_key())
+            .with_request_memory_budget(self.rpc_max_request_memory_bytes())
+            .with_tracing_queue(self.tracing_queue_config())
+            .with_structured_error_codes(self.rpc_structured_errors);
 
         if self.http_api.is_some() && !self.http {
             warn!(
@@ -224,7 +305,7 @@ impl RethRpcServerConfig for RpcServerArgs {
         match self.auth_jwtsecret.as_ref() {
             Some(fpath) => {
                 debug!(target: "reth::cli", user_path=?fpath, "Reading JWT auth secret file");
-                JwtSecret::from_file(fpath)
+                read_jwt_secret(fpath)
             }
             None => get_or_create_jwt_secret_from_path(&default_jwt_path),
         }
@@ -233,11 +314,16 @@ impl RethRpcServerConfig for RpcServerArgs {
     fn rpc_secret_key(&self) -> Option<JwtSecret> {
         self.rpc_jwtsecret
     }
+
+    fn rpc_api_keys(&self) -> Result<Option<ApiKeys>, ApiKeysError> {
+        self.rpc_api_keys.as_ref().map(ApiKeys::from_file).transpose()
+    }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "node-core"))]
 mod tests {
     use clap::{Args, Parser};
+    use reth_chainspec::{EthChainSpec, MAINNET};
     use reth_node_core::args::RpcServerArgs;
     use reth_rpc_eth_types::RPC_DEFAULT_GAS_CAP;
     use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
@@ -267,6 +353,22 @@ mod tests {
         assert!(args.is_err());
     }
 
+    #[test]
+    fn test_max_reorg_depth() {
+        let args = CommandParser::<RpcServerArgs>::parse_from(["reth"]).args;
+        let config = args.eth_config_for_chain(&*MAINNET);
+        assert_eq!(config.max_reorg_depth, MAINNET.max_reorg_depth());
+        assert_eq!(config.cache.max_reorg_depth, MAINNET.max_reorg_depth());
+
+        let args =
+            CommandParser::<RpcServerArgs>::parse_from(["reth", "--rpc.max-reorg-depth", "10"])
+                .args;
+        let config = args.eth_config_for_chain(&*MAINNET);
+        assert_eq!(config.max_reorg_depth, 10);
+        assert_eq!(config.cache.max_reorg_depth, 10);
+        assert_eq!(config.filter_config().max_reorg_depth, 10);
+    }
+
     #[test]
     fn test_transport_rpc_module_config() {
         let args = CommandParser::<RpcServerArgs>::parse_from([
@@ -0,0 +1,152 @@
+//! RPC middleware that reports reth specific failures with their legacy error codes.
+//!
+//! Handlers construct reth specific failures, e.g. pruned state or blocks that are not synced yet,
+//! with the structured error codes of [`RethRpcErrorCode`]. Unless structured errors are enabled,
+//! this middleware maps them back to the generic JSON-RPC error codes that were used historically
+//! and drops their data payload.
+
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObjectOwned, Id, Request},
+    MethodResponse,
+};
+use reth_rpc_server_types::error_codes::{to_legacy_rpc_err, RethRpcErrorCode};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tower::Layer;
+
+/// RPC middleware layer that maps structured errors to their legacy error codes.
+///
+/// All responses are passed through if structured errors are enabled.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RpcLegacyErrorCodesLayer {
+    /// Whether reth specific failures are reported with structured error codes.
+    structured: bool,
+}
+
+impl RpcLegacyErrorCodesLayer {
+    /// Creates a new layer, which only maps errors if `structured` errors are disabled.
+    pub const fn new(structured: bool) -> Self {
+        Self { structured }
+    }
+}
+
+impl<S> Layer<S> for RpcLegacyErrorCodesLayer {
+    type Service = RpcLegacyErrorCodesService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcLegacyErrorCodesService { structured: self.structured, inner }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that maps structured errors to their legacy error codes.
+#[derive(Debug, Clone)]
+pub struct RpcLegacyErrorCodesService<S> {
+    structured: bool,
+    inner: S,
+}
+
+impl<'a, S> RpcServiceT<'a> for RpcLegacyErrorCodesService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = LegacyErrorCodesResponseFuture<S::Future>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let id = (!self.structured).then(|| req.id().into_owned());
+        LegacyErrorCodesResponseFuture { fut: self.inner.call(req), id }
+    }
+}
+
+/// Response future of the [`RpcLegacyErrorCodesService`].
+#[pin_project::pin_project]
+pub struct LegacyErrorCodesResponseFuture<F> {
+    #[pin]
+    fut: F,
+    /// The id of the call, if errors are mapped to their legacy error codes.
+    id: Option<Id<'static>>,
+}
+
+impl<F> std::fmt::Debug for LegacyErrorCodesResponseFuture<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("LegacyErrorCodesResponseFuture")
+    }
+}
+
+impl<F: Future<Output = MethodResponse>> Future for LegacyErrorCodesResponseFuture<F> {
+    type Output = MethodResponse;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let response = std::task::ready!(this.fut.poll(cx));
+        let Some(id) = this.id.take() else { return Poll::Ready(response) };
+        if !response.as_error_code().is_some_and(|code| RethRpcErrorCode::from_code(code).is_some())
+        {
+            return Poll::Ready(response)
+        }
+
+        // structured errors are rare, so they are parsed from the serialized response
+        let legacy = serde_json::from_str::<serde_json::Value>(response.as_result())
+            .ok()
+            .and_then(|mut response| {
+                serde_json::from_value::<ErrorObjectOwned>(response["error"].take()).ok()
+            })
+            .and_then(|error| to_legacy_rpc_err(&error));
+        Poll::Ready(match legacy {
+            Some(error) => MethodResponse::error(id, error),
+            None => response,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::server::middleware::rpc::RpcServiceBuilder;
+    use reth_rpc_server_types::error_codes::reth_rpc_err;
+
+    /// Service that fails every call with a structured error.
+    #[derive(Clone)]
+    struct Failing;
+
+    impl<'a> RpcServiceT<'a> for Failing {
+        type Future = Pin<Box<dyn Future<Output = MethodResponse> + Send + 'a>>;
+
+        fn call(&self, req: Request<'a>) -> Self::Future {
+            Box::pin(async move {
+                MethodResponse::error(
+                    req.id,
+                    reth_rpc_err(RethRpcErrorCode::PrunedState, "state at block #1 is pruned"),
+                )
+            })
+        }
+    }
+
+    async fn call(structured: bool) -> serde_json::Value {
+        let service = RpcServiceBuilder::new()
+            .layer(RpcLegacyErrorCodesLayer::new(structured))
+            .service(Failing);
+        let response =
+            service.call(Request::new("eth_getBalance".into(), None, Id::Number(1))).await;
+        let mut response: serde_json::Value = serde_json::from_str(response.as_result()).unwrap();
+        response["error"].take()
+    }
+
+    #[tokio::test]
+    async fn maps_structured_errors() {
+        let error = call(false).await;
+        assert_eq!(error["code"], jsonrpsee::types::error::INTERNAL_ERROR_CODE);
+        assert_eq!(error["message"], "state at block #1 is pruned");
+        assert!(error.get("data").is_none());
+    }
+
+    #[tokio::test]
+    async fn passes_structured_errors() {
+        let error = call(true).await;
+        assert_eq!(error["code"], RethRpcErrorCode::PrunedState.code());
+        assert_eq!(error["data"]["reason"], "pruned_state");
+    }
+}
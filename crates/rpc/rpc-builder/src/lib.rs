@@ -34,16 +34,18 @@ use jsonrpsee::{
 };
 use reth_chainspec::EthereumHardforks;
 use reth_consensus::{ConsensusError, FullConsensus};
+use reth_db::read_metrics::ReadMetricsConfig;
 use reth_evm::{execute::BlockExecutorProvider, ConfigureEvm};
 use reth_network_api::{noop::NoopNetwork, NetworkInfo, Peers};
 use reth_primitives_traits::NodePrimitives;
 use reth_provider::{
     AccountReader, BlockReader, BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider,
-    ChangeSetReader, FullRpcProvider, ProviderBlock, StateProviderFactory,
+    ChangeSetReader, CodeHistoryReader, FullRpcProvider, ProviderBlock, StateProviderFactory,
 };
 use reth_rpc::{
-    AdminApi, DebugApi, EngineEthApi, EthApi, EthApiBuilder, EthBundle, MinerApi, NetApi,
-    OtterscanApi, RPCApi, RethApi, TraceApi, TxPoolApi, ValidationApiConfig, Web3Api,
+    AdminApi, DebugApi, EngineEthApi, EthApi, EthApiBuilder, EthBlockResponse, EthBundle, MinerApi,
+    NetApi, OtterscanApi, PersonalApi, RPCApi, RethApi, RethEvmApi, RethSimulateApi, TraceApi,
+    TxPoolApi, ValidationApiConfig, Web3Api,
 };
 use reth_rpc_api::servers::*;
 use reth_rpc_eth_api::{
@@ -72,7 +74,7 @@ pub use jsonrpsee::server::ServerBuilder;
 pub use reth_ipc::server::{
     Builder as IpcServerBuilder, RpcServiceBuilder as IpcRpcServiceBuilder,
 };
-pub use reth_rpc_server_types::{constants, RethRpcModule, RpcModuleSelection};
+pub use reth_rpc_server_types::{constants, error_codes, RethRpcModule, RpcModuleSelection};
 pub use tower::layer::util::{Identity, Stack};
 
 /// Auth server utilities.
@@ -99,6 +101,28 @@ use reth_rpc::eth::sim_bundle::EthSimBundle;
 // Rpc rate limiter
 pub mod rate_limiter;
 
+// Rpc API key access control
+pub mod api_keys;
+use api_keys::{ApiKeyLayer, ApiKeyRpcLayer, ApiKeyRpcService, ApiKeys};
+
+// Rpc request memory budget
+mod memory_budget;
+pub use memory_budget::{MemoryBudgetResponseFuture, RpcMemoryBudgetLayer, RpcMemoryBudgetService};
+
+// Rpc legacy error codes
+mod legacy_errors;
+pub use legacy_errors::{
+    LegacyErrorCodesResponseFuture, RpcLegacyErrorCodesLayer, RpcLegacyErrorCodesService,
+};
+
+// Rpc tracing call queue
+pub mod tracing_queue;
+use tracing_queue::{RpcTracingQueueLayer, RpcTracingQueueService, TracingQueueConfig};
+
+// Rpc request spans
+mod spans;
+pub use spans::{RpcRequestSpanLayer, RpcRequestSpanService};
+
 /// Convenience function for starting a server in one step.
 #[allow(clippy::too_many_arguments)]
 pub async fn launch<N, Provider, Pool, Network, Tasks, EvmConfig, EthApi, BlockExecutor>(
@@ -118,7 +142,8 @@ where
     Provider: FullRpcProvider<Block = N::Block, Receipt = N::Receipt, Header = N::BlockHeader>
         + CanonStateSubscriptions<Primitives = N>
         + AccountReader
-        + ChangeSetReader,
+        + ChangeSetReader
+        + CodeHistoryReader,
     Pool: TransactionPool + 'static,
     Network: NetworkInfo + Peers + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
@@ -538,7 +563,8 @@ where
     Provider: FullRpcProvider<Block = N::Block, Receipt = N::Receipt, Header = N::BlockHeader>
         + CanonStateSubscriptions<Primitives = N>
         + AccountReader
-        + ChangeSetReader,
+        + ChangeSetReader
+        + CodeHistoryReader,
     Pool: TransactionPool + 'static,
     Network: NetworkInfo + Peers + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
@@ -654,6 +680,7 @@ where
             modules.http = registry.maybe_module(http.as_ref());
             modules.ws = registry.maybe_module(ws.as_ref());
             modules.ipc = registry.maybe_module(ipc.as_ref());
+            registry.merge_personal_module(&mut modules);
         }
 
         modules
@@ -673,6 +700,9 @@ pub struct RpcModuleConfig {
     eth: EthConfig,
     /// `flashbots` namespace settings
     flashbots: ValidationApiConfig,
+    /// Instrumentation settings of database reads that are changed by the `admin` namespace
+    #[serde(skip)]
+    database_read_metrics: Option<ReadMetricsConfig>,
 }
 
 // === impl RpcModuleConfig ===
@@ -685,7 +715,17 @@ impl RpcModuleConfig {
 
     /// Returns a new RPC module config given the eth namespace config
     pub const fn new(eth: EthConfig, flashbots: ValidationApiConfig) -> Self {
-        Self { eth, flashbots }
+        Self { eth, flashbots, database_read_metrics: None }
+    }
+
+    /// Sets the instrumentation settings of database reads that are changed by the `admin`
+    /// namespace
+    pub fn with_database_read_metrics(
+        mut self,
+        database_read_metrics: Option<ReadMetricsConfig>,
+    ) -> Self {
+        self.database_read_metrics = database_read_metrics;
+        self
     }
 
     /// Get a reference to the eth namespace config
@@ -704,6 +744,7 @@ impl RpcModuleConfig {
 pub struct RpcModuleConfigBuilder {
     eth: Option<EthConfig>,
     flashbots: Option<ValidationApiConfig>,
+    database_read_metrics: Option<ReadMetricsConfig>,
 }
 
 // === impl RpcModuleConfigBuilder ===
@@ -721,10 +762,20 @@ impl RpcModuleConfigBuilder {
         self
     }
 
+    /// Configures the instrumentation settings of database reads
+    pub fn database_read_metrics(mut self, database_read_metrics: ReadMetricsConfig) -> Self {
+        self.database_read_metrics = Some(database_read_metrics);
+        self
+    }
+
     /// Consumes the type and creates the [`RpcModuleConfig`]
     pub fn build(self) -> RpcModuleConfig {
-        let Self { eth, flashbots } = self;
-        RpcModuleConfig { eth: eth.unwrap_or_default(), flashbots: flashbots.unwrap_or_default() }
+        let Self { eth, flashbots, database_read_metrics } = self;
+        RpcModuleConfig {
+            eth: eth.unwrap_or_default(),
+            flashbots: flashbots.unwrap_or_default(),
+            database_read_metrics,
+        }
     }
 
     /// Get a reference to the eth namespace config, if any
@@ -767,6 +818,8 @@ pub struct RpcRegistryInner<
     blocking_pool_guard: BlockingTaskGuard,
     /// Contains the [Methods] of a module
     modules: HashMap<RethRpcModule, Methods>,
+    /// Instrumentation settings of database reads that are changed by the `admin` namespace
+    database_read_metrics: Option<ReadMetricsConfig>,
 }
 
 // === impl RpcRegistryInner ===
@@ -817,6 +870,7 @@ where
             modules: Default::default(),
             blocking_pool_guard,
             block_executor,
+            database_read_metrics: config.database_read_metrics,
         }
     }
 }
@@ -881,6 +935,7 @@ where
         Network: Peers,
     {
         AdminApi::new(self.network.clone(), self.provider.chain_spec())
+            .with_database_read_metrics(self.database_read_metrics.clone())
     }
 
     /// Instantiates `Web3Api`
@@ -916,7 +971,8 @@ where
             Receipt = N::Receipt,
             Transaction = N::SignedTx,
         > + AccountReader
-        + ChangeSetReader,
+        + ChangeSetReader
+        + CodeHistoryReader,
     Network: NetworkInfo + Peers + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
     EthApi: EthApiServer<
@@ -1004,7 +1060,10 @@ where
     /// # Panics
     ///
     /// If called outside of the tokio runtime.
-    pub fn register_reth(&mut self) -> &mut Self {
+    pub fn register_reth(&mut self) -> &mut Self
+    where
+        Provider: CanonStateSubscriptions,
+    {
         let rethapi = self.reth_api();
         self.modules.insert(RethRpcModule::Reth, rethapi.into_rpc().into());
         self
@@ -1019,6 +1078,15 @@ where
         let eth_api = self.eth_api().clone();
         OtterscanApi::new(eth_api)
     }
+
+    /// Instantiates `PersonalApi`
+    ///
+    /// # Panics
+    ///
+    /// If called outside of the tokio runtime. See also [`Self::eth_api`]
+    pub fn personal_api(&self) -> PersonalApi<EthApi> {
+        PersonalApi::new(self.eth_api().clone())
+    }
 }
 
 impl<N, Provider, Pool, Network, Tasks, EthApi, BlockExecutor, Consensus>
@@ -1031,7 +1099,8 @@ where
             Transaction = N::SignedTx,
             Receipt = N::Receipt,
         > + AccountReader
-        + ChangeSetReader,
+        + ChangeSetReader
+        + CodeHistoryReader,
     Network: NetworkInfo + Peers + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
     EthApi: EthApiTypes,
@@ -1093,8 +1162,8 @@ where
     }
 
     /// Instantiates `RethApi`
-    pub fn reth_api(&self) -> RethApi<Provider> {
-        RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
+    pub fn reth_api(&self) -> RethApi<Provider, Network> {
+        RethApi::new(self.provider.clone(), self.network.clone(), Box::new(self.executor.clone()))
     }
 }
 
@@ -1105,7 +1174,8 @@ where
     Provider: FullRpcProvider<Block = N::Block>
         + CanonStateSubscriptions<Primitives = N>
         + AccountReader
-        + ChangeSetReader,
+        + ChangeSetReader
+        + CodeHistoryReader,
     Pool: TransactionPool + 'static,
     Network: NetworkInfo + Peers + Clone + 'static,
     Tasks: TaskSpawner + Clone + 'static,
@@ -1151,7 +1221,17 @@ where
         modules.http = http;
         modules.ws = ws;
         modules.ipc = ipc;
+        self.merge_personal_module(&mut modules);
+        modules
+    }
+
+    /// Merges the `personal_` namespace into the transports that select it explicitly.
+    ///
+    /// The namespace manages local keys, so it's not included in `all`.
+    fn merge_personal_module(&self, modules: &mut TransportRpcModules<()>) {
         modules
+            .merge_if_module_selected(RethRpcModule::Personal, self.personal_api().into_rpc())
+            .expect("No conflicts");
     }
 
     /// Populates a new [`RpcModule`] based on the selected [`RethRpcModule`]s in the given
@@ -1191,6 +1271,7 @@ where
                     .or_insert_with(|| match namespace {
                         RethRpcModule::Admin => {
                             AdminApi::new(self.network.clone(), self.provider.chain_spec())
+                                .with_database_read_metrics(self.database_read_metrics.clone())
                                 .into_rpc()
                                 .into()
                         }
@@ -1205,7 +1286,9 @@ where
                             // merge all eth handlers
                             let mut module = eth_api.clone().into_rpc();
                             module.merge(eth_filter.clone().into_rpc()).expect("No conflicts");
-                            module.merge(eth_pubsub.clone().into_rpc()).expect("No conflicts");
+                            module
+                                .merge(EthPubSubExtApiServer::into_rpc(eth_pubsub.clone()))
+                                .expect("No conflicts");
                             module
                                 .merge(
                                     EthBundle::new(
@@ -1215,6 +1298,11 @@ where
                                     .into_rpc(),
                                 )
                                 .expect("No conflicts");
+                            // serve `eth_getBlockByNumber` with the optional response options
+                            module.remove_method("eth_getBlockByNumber");
+                            module
+                                .merge(EthBlockResponse::new(eth_api.clone()).into_rpc())
+                                .expect("No conflicts");
 
                             module.into()
                         }
@@ -1243,9 +1331,19 @@ where
                         .into(),
                         RethRpcModule::Ots => OtterscanApi::new(eth_api.clone()).into_rpc().into(),
                         RethRpcModule::Reth => {
-                            RethApi::new(self.provider.clone(), Box::new(self.executor.clone()))
-                                .into_rpc()
-                                .into()
+                            let mut module = RethApi::new(
+                                self.provider.clone(),
+                                self.network.clone(),
+                                Box::new(self.executor.clone()),
+                            )
+                            .into_rpc();
+                            module
+                                .merge(RethEvmApi::new(eth_api.clone()).into_rpc())
+                                .expect("No conflicts");
+                            module
+                                .merge(RethSimulateApi::new(eth_api.clone()).into_rpc())
+                                .expect("No conflicts");
+                            module.into()
                         }
                         // only relevant for Ethereum and configured in `EthereumAddOns`
                         // implementation
@@ -1257,6 +1355,9 @@ where
                                 .into_rpc()
                                 .into()
                         }
+                        // merged separately into the transports that select it explicitly, see
+                        // `merge_personal_module`
+                        RethRpcModule::Personal => Default::default(),
                     })
                     .clone()
             })
@@ -1295,6 +1396,15 @@ pub struct RpcServerConfig<RpcMiddleware = Identity> {
     ipc_endpoint: Option<String>,
     /// JWT secret for authentication
     jwt_secret: Option<JwtSecret>,
+    /// API keys for access control of the http and ws servers
+    api_keys: Option<ApiKeys>,
+    /// Memory budget of a single call to the http, ws and ipc servers in bytes
+    request_memory_budget: Option<usize>,
+    /// Queue of the `debug_` and `trace_` calls to the http, ws and ipc servers
+    tracing_queue: Option<TracingQueueConfig>,
+    /// Whether the http, ws and ipc servers report reth specific failures with structured error
+    /// codes
+    structured_error_codes: bool,
     /// Configurable RPC middleware
     rpc_middleware: RpcServiceBuilder<RpcMiddleware>,
 }
@@ -1314,6 +1424,10 @@ impl Default for RpcServerConfig<Identity> {
             ipc_server_config: None,
             ipc_endpoint: None,
             jwt_secret: None,
+            api_keys: None,
+            request_memory_budget: None,
+            tracing_queue: None,
+            structured_error_codes: false,
             rpc_middleware: RpcServiceBuilder::new(),
         }
     }
@@ -1377,6 +1491,10 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
             ipc_server_config: self.ipc_server_config,
             ipc_endpoint: self.ipc_endpoint,
             jwt_secret: self.jwt_secret,
+            api_keys: self.api_keys,
+            request_memory_budget: self.request_memory_budget,
+            tracing_queue: self.tracing_queue,
+            structured_error_codes: self.structured_error_codes,
             rpc_middleware,
         }
     }
@@ -1450,6 +1568,37 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         self
     }
 
+    /// Configures the [`ApiKeys`] that control access to the http and ws servers.
+    pub fn with_api_keys(mut self, api_keys: Option<ApiKeys>) -> Self {
+        self.api_keys = api_keys;
+        self
+    }
+
+    /// Configures the memory budget of a single call to the http, ws and ipc servers in bytes.
+    ///
+    /// Calls exceeding the budget are aborted, see [`RpcMemoryBudgetLayer`].
+    pub const fn with_request_memory_budget(mut self, limit: Option<usize>) -> Self {
+        self.request_memory_budget = limit;
+        self
+    }
+
+    /// Configures the queue of the `debug_` and `trace_` calls to the http, ws and ipc servers.
+    ///
+    /// The queue is shared by all servers, see [`RpcTracingQueueLayer`].
+    pub const fn with_tracing_queue(mut self, config: Option<TracingQueueConfig>) -> Self {
+        self.tracing_queue = config;
+        self
+    }
+
+    /// Configures whether the http, ws and ipc servers report reth specific failures with
+    /// structured error codes instead of the legacy JSON-RPC error codes.
+    ///
+    /// See [`RpcLegacyErrorCodesLayer`].
+    pub const fn with_structured_error_codes(mut self, enabled: bool) -> Self {
+        self.structured_error_codes = enabled;
+        self
+    }
+
     /// Returns true if any server is configured.
     ///
     /// If no server is configured, no server will be launched on [`RpcServerConfig::start`].
@@ -1484,6 +1633,11 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         jwt_secret.map(|secret| AuthLayer::new(JwtAuthValidator::new(secret)))
     }
 
+    /// Creates the [`ApiKeyLayer`] if any
+    fn maybe_api_key_layer(api_keys: Option<ApiKeys>) -> Option<ApiKeyLayer> {
+        api_keys.map(ApiKeyLayer::new)
+    }
+
     /// Returns a [`CompressionLayer`] that adds compression support (gzip, deflate, brotli, zstd)
     /// based on the client's `Accept-Encoding` header
     fn maybe_compression_layer() -> Option<CompressionLayer> {
@@ -1497,9 +1651,30 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
     /// Returns the [`RpcServerHandle`] with the handle to the started servers.
     pub async fn start(self, modules: &TransportRpcModules) -> Result<RpcServerHandle, RpcError>
     where
-        RpcMiddleware: Layer<RpcRequestMetricsService<RpcService>> + Clone + Send + 'static,
-        for<'a> <RpcMiddleware as Layer<RpcRequestMetricsService<RpcService>>>::Service:
-            Send + Sync + 'static + RpcServiceT<'a>,
+        RpcMiddleware: Layer<
+                ApiKeyRpcService<
+                    RpcTracingQueueService<
+                        RpcRequestMetricsService<
+                            RpcLegacyErrorCodesService<
+                                RpcMemoryBudgetService<RpcRequestSpanService<RpcService>>,
+                            >,
+                        >,
+                    >,
+                >,
+            > + Clone
+            + Send
+            + 'static,
+        for<'a> <RpcMiddleware as Layer<
+            ApiKeyRpcService<
+                RpcTracingQueueService<
+                    RpcRequestMetricsService<
+                        RpcLegacyErrorCodesService<
+                            RpcMemoryBudgetService<RpcRequestSpanService<RpcService>>,
+                        >,
+                    >,
+                >,
+            >,
+        >>::Service: Send + Sync + 'static + RpcServiceT<'a>,
     {
         let mut http_handle = None;
         let mut ws_handle = None;
@@ -1516,12 +1691,21 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
         )));
 
         let metrics = modules.ipc.as_ref().map(RpcRequestMetrics::ipc).unwrap_or_default();
+        // shared by all servers
+        let tracing_queue = RpcTracingQueueLayer::new(self.tracing_queue);
         let ipc_path =
             self.ipc_endpoint.clone().unwrap_or_else(|| constants::DEFAULT_IPC_ENDPOINT.into());
 
         if let Some(builder) = self.ipc_server_config {
             let ipc = builder
-                .set_rpc_middleware(IpcRpcServiceBuilder::new().layer(metrics))
+                .set_rpc_middleware(
+                    IpcRpcServiceBuilder::new()
+                        .layer(tracing_queue.clone())
+                        .layer(metrics)
+                        .layer(RpcLegacyErrorCodesLayer::new(self.structured_error_codes))
+                        .layer(RpcMemoryBudgetLayer::new(self.request_memory_budget))
+                        .layer(RpcRequestSpanLayer::new("ipc")),
+                )
                 .build(ipc_path);
             ipc_handle = Some(ipc.start(modules.ipc.clone().expect("ipc server error")).await?);
         }
@@ -1555,17 +1739,25 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                         tower::ServiceBuilder::new()
                             .option_layer(Self::maybe_cors_layer(cors)?)
                             .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
+                            .option_layer(Self::maybe_api_key_layer(self.api_keys.clone()))
                             .option_layer(Self::maybe_compression_layer()),
                     )
                     .set_rpc_middleware(
-                        self.rpc_middleware.clone().layer(
-                            modules
-                                .http
-                                .as_ref()
-                                .or(modules.ws.as_ref())
-                                .map(RpcRequestMetrics::same_port)
-                                .unwrap_or_default(),
-                        ),
+                        self.rpc_middleware
+                            .clone()
+                            .layer(ApiKeyRpcLayer::new(self.api_keys.clone()))
+                            .layer(tracing_queue.clone())
+                            .layer(
+                                modules
+                                    .http
+                                    .as_ref()
+                                    .or(modules.ws.as_ref())
+                                    .map(RpcRequestMetrics::same_port)
+                                    .unwrap_or_default(),
+                            )
+                            .layer(RpcLegacyErrorCodesLayer::new(self.structured_error_codes))
+                            .layer(RpcMemoryBudgetLayer::new(self.request_memory_budget))
+                            .layer(RpcRequestSpanLayer::new("http")),
                     )
                     .build(http_socket_addr)
                     .await
@@ -1603,12 +1795,18 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                 .set_http_middleware(
                     tower::ServiceBuilder::new()
                         .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
-                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret)),
+                        .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
+                        .option_layer(Self::maybe_api_key_layer(self.api_keys.clone())),
                 )
                 .set_rpc_middleware(
                     self.rpc_middleware
                         .clone()
-                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default()),
+                        .layer(ApiKeyRpcLayer::new(self.api_keys.clone()))
+                        .layer(tracing_queue.clone())
+                        .layer(modules.ws.as_ref().map(RpcRequestMetrics::ws).unwrap_or_default())
+                        .layer(RpcLegacyErrorCodesLayer::new(self.structured_error_codes))
+                        .layer(RpcMemoryBudgetLayer::new(self.request_memory_budget))
+                        .layer(RpcRequestSpanLayer::new("ws")),
                 )
                 .build(ws_socket_addr)
                 .await
@@ -1629,12 +1827,20 @@ impl<RpcMiddleware> RpcServerConfig<RpcMiddleware> {
                     tower::ServiceBuilder::new()
                         .option_layer(Self::maybe_cors_layer(self.ws_cors_domains.clone())?)
                         .option_layer(Self::maybe_jwt_layer(self.jwt_secret))
+                        .option_layer(Self::maybe_api_key_layer(self.api_keys.clone()))
                         .option_layer(Self::maybe_compression_layer()),
                 )
                 .set_rpc_middleware(
-                    self.rpc_middleware.clone().layer(
-                        modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
-                    ),
+                    self.rpc_middleware
+                        .clone()
+                        .layer(ApiKeyRpcLayer::new(self.api_keys.clone()))
+                        .layer(tracing_queue.clone())
+                        .layer(
+                            modules.http.as_ref().map(RpcRequestMetrics::http).unwrap_or_default(),
+                        )
+                        .layer(RpcLegacyErrorCodesLayer::new(self.structured_error_codes))
+                        .layer(RpcMemoryBudgetLayer::new(self.request_memory_budget))
+                        .layer(RpcRequestSpanLayer::new("http")),
                 )
                 .build(http_socket_addr)
                 .await
@@ -1888,6 +2094,35 @@ impl TransportRpcModules {
         Ok(())
     }
 
+    /// Merge the given [`Methods`] in all configured transport modules if the given
+    /// [`RethRpcModule`] is selected explicitly for the transport, i.e. not only by `all`.
+    ///
+    /// Fails if any of the methods in other is present already.
+    pub fn merge_if_module_selected(
+        &mut self,
+        module: RethRpcModule,
+        other: impl Into<Methods>,
+    ) -> Result<(), RegisterMethodError> {
+        let other = other.into();
+        let config = self.module_config();
+        let (http, ws, ipc) = (
+            config.http().is_some_and(|http| http.contains_explicitly(&module)),
+            config.ws().is_some_and(|ws| ws.contains_explicitly(&module)),
+            config.ipc().is_some_and(|ipc| ipc.contains_explicitly(&module)),
+        );
+        if http {
+            self.merge_http(other.clone())?;
+        }
+        if ws {
+            self.merge_ws(other.clone())?;
+        }
+        if ipc {
+            self.merge_ipc(other)?;
+        }
+
+        Ok(())
+    }
+
     /// Merge the given [Methods] in the configured http methods.
     ///
     /// Fails if any of the methods in other is present already.
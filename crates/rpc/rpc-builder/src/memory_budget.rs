@@ -0,0 +1,294 @@
+//! RPC middleware that enforces a memory budget per call.
+//!
+//! Every call is processed with its own [`RequestMemoryBudget`], which expensive handlers such as
+//! `debug_` and `trace_` methods charge as they build their response. Calls that exceed the budget,
+//! either while they are processed or with the size of their response, are aborted with a
+//! [`MemoryBudgetExceeded`] error.
+//!
+//! The budget is marked as aborted once the response is no longer awaited, so that handlers that
+//! are still running on blocking tasks stop at their next charge.
+
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, Id, Request},
+    MethodResponse,
+};
+use reth_metrics::{
+    metrics::{Counter, Histogram},
+    Metrics,
+};
+use reth_rpc_eth_types::{EthApiError, MemoryBudgetExceeded, RequestMemoryBudget};
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::task::futures::TaskLocalFuture;
+use tower::Layer;
+use tracing::debug;
+
+/// RPC middleware layer that processes every call with a [`RequestMemoryBudget`].
+///
+/// All calls are passed through if no budget is configured.
+#[derive(Debug, Clone, Default)]
+pub struct RpcMemoryBudgetLayer {
+    /// The budget of a single call in bytes.
+    limit: Option<usize>,
+    /// Metrics of the budgeted calls.
+    metrics: RpcMemoryBudgetMetrics,
+}
+
+impl RpcMemoryBudgetLayer {
+    /// Creates a new layer with a budget of `limit` bytes per call, if any.
+    pub fn new(limit: Option<usize>) -> Self {
+        Self { limit, metrics: Default::default() }
+    }
+}
+
+impl<S> Layer<S> for RpcMemoryBudgetLayer {
+    type Service = RpcMemoryBudgetService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcMemoryBudgetService { limit: self.limit, metrics: self.metrics.clone(), inner }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that aborts calls exceeding their [`RequestMemoryBudget`].
+#[derive(Debug, Clone)]
+pub struct RpcMemoryBudgetService<S> {
+    limit: Option<usize>,
+    metrics: RpcMemoryBudgetMetrics,
+    inner: S,
+}
+
+impl<'a, S> RpcServiceT<'a> for RpcMemoryBudgetService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = MemoryBudgetResponseFuture<S::Future>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let Some(limit) = self.limit else {
+            return MemoryBudgetResponseFuture {
+                inner: MemoryBudgetResponseFutureInner::Call { fut: self.inner.call(req) },
+            }
+        };
+
+        let budget = RequestMemoryBudget::new(limit);
+        MemoryBudgetResponseFuture {
+            inner: MemoryBudgetResponseFutureInner::Budgeted {
+                id: req.id().into_owned(),
+                method: req.method_name().to_string(),
+                fut: budget.clone().scope(self.inner.call(req)),
+                budget: AbortOnDrop(budget),
+                metrics: self.metrics.clone(),
+            },
+        }
+    }
+}
+
+/// Response future of the [`RpcMemoryBudgetService`].
+#[pin_project::pin_project]
+pub struct MemoryBudgetResponseFuture<F> {
+    #[pin]
+    inner: MemoryBudgetResponseFutureInner<F>,
+}
+
+impl<F> std::fmt::Debug for MemoryBudgetResponseFuture<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MemoryBudgetResponseFuture")
+    }
+}
+
+#[pin_project::pin_project(project = MemoryBudgetResponseFutureProj)]
+enum MemoryBudgetResponseFutureInner<F> {
+    /// No budget is configured.
+    Call {
+        #[pin]
+        fut: F,
+    },
+    /// The call is processed with a budget.
+    Budgeted {
+        /// The id of the call.
+        id: Id<'static>,
+        /// The name of the called method.
+        method: String,
+        /// The inner future, scoped to the budget.
+        #[pin]
+        fut: TaskLocalFuture<RequestMemoryBudget, F>,
+        /// The budget of the call, which is aborted when the future is dropped.
+        budget: AbortOnDrop,
+        /// Metrics of the budgeted calls.
+        metrics: RpcMemoryBudgetMetrics,
+    },
+}
+
+impl<F: Future<Output = MethodResponse>> Future for MemoryBudgetResponseFuture<F> {
+    type Output = MethodResponse;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let (id, method, fut, budget, metrics) = match self.project().inner.project() {
+            MemoryBudgetResponseFutureProj::Call { fut } => return fut.poll(cx),
+            MemoryBudgetResponseFutureProj::Budgeted { id, method, fut, budget, metrics } => {
+                (id, method, fut, budget, metrics)
+            }
+        };
+
+        // abort the call as soon as a handler exceeded the budget, even if it is still running
+        let exceeded = match budget.check() {
+            Ok(()) => {
+                let response = std::task::ready!(fut.poll(cx));
+                // the response is allocated in addition to everything charged while building it,
+                // but it's usually the serialized form of the charged values
+                let used = budget.used().max(response.as_result().len());
+                metrics.request_bytes.record(used as f64);
+                if used <= budget.limit() {
+                    return Poll::Ready(response)
+                }
+                MemoryBudgetExceeded { limit: budget.limit(), used }
+            }
+            Err(err) => {
+                metrics.request_bytes.record(err.used as f64);
+                err
+            }
+        };
+
+        debug!(target: "rpc::memory_budget", %method, limit = exceeded.limit, used = exceeded.used, "Aborted call exceeding memory budget");
+        metrics.aborted_total.increment(1);
+        // stop the handlers that are still running
+        budget.abort();
+        let error: ErrorObject<'static> = EthApiError::from(exceeded).into();
+        Poll::Ready(MethodResponse::error(id.clone(), error))
+    }
+}
+
+/// Aborts the [`RequestMemoryBudget`] on drop, see [`RequestMemoryBudget::abort`].
+#[derive(Debug)]
+struct AbortOnDrop(RequestMemoryBudget);
+
+impl std::ops::Deref for AbortOnDrop {
+    type Target = RequestMemoryBudget;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Metrics of calls processed with a [`RequestMemoryBudget`].
+#[derive(Metrics, Clone)]
+#[metrics(scope = "rpc_server.memory_budget")]
+struct RpcMemoryBudgetMetrics {
+    /// The number of calls aborted because they exceeded their budget
+    aborted_total: Counter,
+    /// The approximate number of bytes allocated per call
+    request_bytes: Histogram,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{
+        server::middleware::rpc::RpcServiceBuilder,
+        types::{ErrorCode, ResponsePayload},
+    };
+    use parking_lot::Mutex;
+    use reth_rpc_eth_types::memory_budget::charge_request_memory;
+    use std::sync::Arc;
+
+    /// Service that charges `charge` bytes to the budget and responds with a string of `response`
+    /// bytes.
+    #[derive(Clone)]
+    struct Charging {
+        charge: usize,
+        response: usize,
+    }
+
+    impl<'a> RpcServiceT<'a> for Charging {
+        type Future = Pin<Box<dyn Future<Output = MethodResponse> + Send + 'a>>;
+
+        fn call(&self, req: Request<'a>) -> Self::Future {
+            let Self { charge, response } = self.clone();
+            Box::pin(async move {
+                if charge_request_memory(charge).is_err() {
+                    return MethodResponse::error(
+                        req.id,
+                        ErrorObject::from(ErrorCode::InternalError),
+                    )
+                }
+                MethodResponse::response(
+                    req.id,
+                    ResponsePayload::success("x".repeat(response)),
+                    usize::MAX,
+                )
+            })
+        }
+    }
+
+    async fn call(limit: Option<usize>, charge: usize, response: usize) -> MethodResponse {
+        let service = RpcServiceBuilder::new()
+            .layer(RpcMemoryBudgetLayer::new(limit))
+            .service(Charging { charge, response });
+        service.call(Request::new("debug_test".into(), None, Id::Number(1))).await
+    }
+
+    fn error_message(response: &MethodResponse) -> String {
+        let response: serde_json::Value = serde_json::from_str(response.as_result()).unwrap();
+        response["error"]["message"].as_str().unwrap().to_string()
+    }
+
+    /// Service that stores the budget of the call and never responds.
+    #[derive(Clone, Default)]
+    struct Pending {
+        budget: Arc<Mutex<Option<RequestMemoryBudget>>>,
+    }
+
+    impl<'a> RpcServiceT<'a> for Pending {
+        type Future = Pin<Box<dyn Future<Output = MethodResponse> + Send + 'a>>;
+
+        fn call(&self, _req: Request<'a>) -> Self::Future {
+            let budget = self.budget.clone();
+            Box::pin(async move {
+                *budget.lock() = RequestMemoryBudget::current();
+                std::future::pending().await
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn passes_calls_within_budget() {
+        assert!(call(Some(100), 50, 50).await.is_success());
+        assert!(call(None, usize::MAX, 1_000).await.is_success());
+    }
+
+    #[tokio::test]
+    async fn aborts_calls_exceeding_budget() {
+        let response = call(Some(100), 200, 10).await;
+        assert!(response.is_error());
+        assert_eq!(error_message(&response), "request exceeded memory budget of 100 bytes");
+
+        // the response alone exceeds the budget
+        let response = call(Some(100), 0, 200).await;
+        assert!(response.is_error());
+        assert_eq!(error_message(&response), "request exceeded memory budget of 100 bytes");
+    }
+
+    #[tokio::test]
+    async fn aborts_budget_of_dropped_calls() {
+        let pending = Pending::default();
+        let service = RpcServiceBuilder::new()
+            .layer(RpcMemoryBudgetLayer::new(Some(100)))
+            .service(pending.clone());
+        let call = service.call(Request::new("debug_test".into(), None, Id::Number(1)));
+        assert!(tokio::time::timeout(std::time::Duration::from_millis(10), call).await.is_err());
+
+        let budget = pending.budget.lock().clone().unwrap();
+        assert!(budget.is_aborted());
+        assert!(budget.charge(0).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use jsonrpsee::{server::middleware::rpc::RpcServiceT, types::Request};
+use tower::Layer;
+use tracing::{debug_span, instrument::Instrumented, Instrument};
+
+/// A [`Layer`] that wraps every RPC call in a `rpc_request` span.
+///
+/// The span carries the method name and request id of the call, so the spans the handler opens,
+/// e.g. for engine API calls, are attributed to the request that caused them and can be correlated
+/// with the caller when exported.
+#[derive(Debug, Clone, Copy)]
+pub struct RpcRequestSpanLayer {
+    /// The server the calls are made to, e.g. `http` or `auth`.
+    server: &'static str,
+}
+
+impl RpcRequestSpanLayer {
+    /// Creates a new layer for calls made to the given server.
+    pub const fn new(server: &'static str) -> Self {
+        Self { server }
+    }
+}
+
+impl<S> Layer<S> for RpcRequestSpanLayer {
+    type Service = RpcRequestSpanService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcRequestSpanService { server: self.server, inner }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that instruments each call with a `rpc_request` span.
+#[derive(Debug, Clone)]
+pub struct RpcRequestSpanService<S> {
+    server: &'static str,
+    inner: S,
+}
+
+impl<'a, S> RpcServiceT<'a> for RpcRequestSpanService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+{
+    type Future = Instrumented<S::Future>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let span = debug_span!(
+            target: "rpc::request",
+            "rpc_request",
+            server = self.server,
+            method = req.method_name(),
+            id = ?req.id(),
+        );
+        // the handler may do work before returning its future, so enter the span for that as well
+        let fut = span.in_scope(|| self.inner.call(req));
+        fut.instrument(span)
+    }
+}
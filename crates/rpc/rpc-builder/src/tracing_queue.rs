@@ -0,0 +1,452 @@
+//! RPC middleware that queues `debug_` and `trace_` calls.
+//!
+//! Tracing calls can occupy the node for seconds each. The [`RpcTracingQueueLayer`] processes at
+//! most a configured number of them concurrently and lets further calls wait in a bounded queue,
+//! so a burst of tracing load can't starve the `eth_` handlers. Clients, identified by their
+//! [`ApiKey`], can be capped to a number of concurrent calls, and waiting calls of clients with
+//! fewer running calls are processed first.
+//!
+//! Calls that don't fit into the queue, or wait longer than the configured timeout, are rejected
+//! with an error that reports their position in the queue.
+
+use crate::api_keys::ApiKey;
+use jsonrpsee::{
+    server::middleware::rpc::RpcServiceT,
+    types::{ErrorObject, Request},
+    MethodResponse,
+};
+use parking_lot::Mutex;
+use reth_metrics::{
+    metrics::{Counter, Gauge, Histogram},
+    Metrics,
+};
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::oneshot;
+use tower::Layer;
+use tracing::debug;
+
+/// Error code of calls rejected by the [`RpcTracingQueue`].
+pub const TRACING_QUEUE_ERROR_CODE: i32 = -32005;
+
+/// Configuration of the [`RpcTracingQueue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TracingQueueConfig {
+    /// Maximum number of tracing calls that are processed concurrently.
+    pub max_concurrent: usize,
+    /// Maximum number of tracing calls that wait in the queue.
+    pub max_queued: usize,
+    /// Maximum number of tracing calls of a single client that are processed concurrently.
+    pub max_per_client: Option<usize>,
+    /// Maximum time a tracing call waits in the queue.
+    pub timeout: Duration,
+}
+
+/// Returns `true` if the method is queued by the [`RpcTracingQueue`].
+pub fn is_tracing_method(method: &str) -> bool {
+    method.starts_with("debug_") || method.starts_with("trace_")
+}
+
+/// Reason a call was rejected by the [`RpcTracingQueue`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TracingQueueRejection {
+    /// The queue is full.
+    Full {
+        /// The number of calls in the queue.
+        queued: usize,
+    },
+    /// The call waited longer than the configured timeout.
+    Timeout {
+        /// The position of the call in the queue when it timed out, starting at 1.
+        position: usize,
+        /// The number of calls in the queue.
+        queued: usize,
+    },
+}
+
+impl fmt::Display for TracingQueueRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Full { queued } => write!(f, "tracing queue is full, {queued} calls queued"),
+            Self::Timeout { position, queued } => write!(
+                f,
+                "timed out waiting in the tracing queue at position {position} of {queued}"
+            ),
+        }
+    }
+}
+
+impl From<TracingQueueRejection> for ErrorObject<'static> {
+    fn from(rejection: TracingQueueRejection) -> Self {
+        let (position, queued) = match rejection {
+            TracingQueueRejection::Full { queued } => (queued + 1, queued),
+            TracingQueueRejection::Timeout { position, queued } => (position, queued),
+        };
+        Self::owned(
+            TRACING_QUEUE_ERROR_CODE,
+            rejection.to_string(),
+            Some(serde_json::json!({ "position": position, "queued": queued })),
+        )
+    }
+}
+
+/// A call waiting in the queue.
+#[derive(Debug)]
+struct Waiter {
+    /// Monotonically increasing number of the call, used to process calls in order.
+    ticket: u64,
+    /// The client of the call.
+    client: Option<String>,
+    /// Notifies the call that it's processed.
+    tx: oneshot::Sender<()>,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    /// The number of calls being processed.
+    running: usize,
+    /// The number of calls being processed per client.
+    clients: HashMap<Option<String>, usize>,
+    /// The calls waiting in the queue.
+    waiting: Vec<Waiter>,
+    /// The ticket of the next queued call.
+    next_ticket: u64,
+}
+
+impl QueueState {
+    fn running(&self, client: &Option<String>) -> usize {
+        self.clients.get(client).copied().unwrap_or_default()
+    }
+
+    fn start(&mut self, client: &Option<String>) {
+        self.running += 1;
+        *self.clients.entry(client.clone()).or_default() += 1;
+    }
+
+    fn finish(&mut self, client: &Option<String>) {
+        self.running -= 1;
+        if let Some(running) = self.clients.get_mut(client) {
+            *running -= 1;
+            if *running == 0 {
+                self.clients.remove(client);
+            }
+        }
+    }
+
+    /// Returns the priority of a waiting call, lower values are processed first.
+    fn priority(&self, waiter: &Waiter) -> (usize, u64) {
+        (self.running(&waiter.client), waiter.ticket)
+    }
+
+    /// Returns the position of the waiting call with the given ticket, starting at 1.
+    fn position(&self, ticket: u64) -> Option<usize> {
+        let waiter = self.waiting.iter().find(|waiter| waiter.ticket == ticket)?;
+        let priority = self.priority(waiter);
+        Some(1 + self.waiting.iter().filter(|other| self.priority(other) < priority).count())
+    }
+}
+
+/// Bounded queue of tracing calls, shared by all connections of a server.
+#[derive(Debug, Clone)]
+pub struct RpcTracingQueue {
+    config: TracingQueueConfig,
+    state: Arc<Mutex<QueueState>>,
+    metrics: RpcTracingQueueMetrics,
+}
+
+impl RpcTracingQueue {
+    /// Creates a new queue with the given config.
+    pub fn new(config: TracingQueueConfig) -> Self {
+        Self { config, state: Default::default(), metrics: Default::default() }
+    }
+
+    /// Returns the config of the queue.
+    pub const fn config(&self) -> TracingQueueConfig {
+        self.config
+    }
+
+    /// Returns the number of calls being processed and waiting in the queue.
+    pub fn occupancy(&self) -> (usize, usize) {
+        let state = self.state.lock();
+        (state.running, state.waiting.len())
+    }
+
+    /// Returns `true` if a call of the client can be processed right away.
+    fn can_start(&self, state: &QueueState, client: &Option<String>) -> bool {
+        state.running < self.config.max_concurrent &&
+            self.config.max_per_client.is_none_or(|max| state.running(client) < max)
+    }
+
+    /// Starts the waiting calls with the highest priority while there is capacity.
+    fn schedule(&self, state: &mut QueueState) {
+        while state.running < self.config.max_concurrent {
+            let Some(idx) = (0..state.waiting.len())
+                .filter(|idx| {
+                    self.config
+                        .max_per_client
+                        .is_none_or(|max| state.running(&state.waiting[*idx].client) < max)
+                })
+                .min_by_key(|idx| state.priority(&state.waiting[*idx]))
+            else {
+                break
+            };
+
+            let waiter = state.waiting.remove(idx);
+            state.start(&waiter.client);
+            if waiter.tx.send(()).is_err() {
+                // the call was dropped while waiting
+                state.finish(&waiter.client);
+            }
+        }
+        self.metrics.queued.set(state.waiting.len() as f64);
+    }
+
+    /// Waits until a call of the client can be processed.
+    pub async fn acquire(
+        &self,
+        client: Option<String>,
+    ) -> Result<TracingQueuePermit, TracingQueueRejection> {
+        let started_at = Instant::now();
+        let (ticket, mut rx) = {
+            let mut state = self.state.lock();
+            if self.can_start(&state, &client) {
+                state.start(&client);
+                return Ok(TracingQueuePermit { queue: self.clone(), client })
+            }
+
+            let queued = state.waiting.len();
+            if queued >= self.config.max_queued {
+                self.metrics.rejected_total.increment(1);
+                return Err(TracingQueueRejection::Full { queued })
+            }
+
+            let (tx, rx) = oneshot::channel();
+            let ticket = state.next_ticket;
+            state.next_ticket += 1;
+            state.waiting.push(Waiter { ticket, client: client.clone(), tx });
+            self.metrics.queued.set(state.waiting.len() as f64);
+            (ticket, rx)
+        };
+
+        if tokio::time::timeout(self.config.timeout, &mut rx).await.is_err() {
+            let mut state = self.state.lock();
+            if let Some(position) = state.position(ticket) {
+                let queued = state.waiting.len();
+                state.waiting.retain(|waiter| waiter.ticket != ticket);
+                self.metrics.queued.set(state.waiting.len() as f64);
+                self.metrics.rejected_total.increment(1);
+                return Err(TracingQueueRejection::Timeout { position, queued })
+            }
+            // the call was started right when it timed out
+        }
+
+        self.metrics.wait_duration_seconds.record(started_at.elapsed().as_secs_f64());
+        Ok(TracingQueuePermit { queue: self.clone(), client })
+    }
+}
+
+/// Permit to process a tracing call, releases the slot of the call when dropped.
+#[derive(Debug)]
+pub struct TracingQueuePermit {
+    queue: RpcTracingQueue,
+    client: Option<String>,
+}
+
+impl Drop for TracingQueuePermit {
+    fn drop(&mut self) {
+        let mut state = self.queue.state.lock();
+        state.finish(&self.client);
+        self.queue.schedule(&mut state);
+    }
+}
+
+/// RPC middleware layer that processes `debug_` and `trace_` calls through a [`RpcTracingQueue`].
+///
+/// All calls are passed through if no queue is configured.
+#[derive(Debug, Clone, Default)]
+pub struct RpcTracingQueueLayer {
+    queue: Option<RpcTracingQueue>,
+}
+
+impl RpcTracingQueueLayer {
+    /// Creates a new layer with a queue of the given config, if any.
+    pub fn new(config: Option<TracingQueueConfig>) -> Self {
+        Self { queue: config.map(RpcTracingQueue::new) }
+    }
+}
+
+impl<S> Layer<S> for RpcTracingQueueLayer {
+    type Service = RpcTracingQueueService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcTracingQueueService { queue: self.queue.clone(), inner }
+    }
+}
+
+/// A [`RpcServiceT`] middleware that processes tracing calls through a [`RpcTracingQueue`].
+#[derive(Debug, Clone)]
+pub struct RpcTracingQueueService<S> {
+    queue: Option<RpcTracingQueue>,
+    inner: S,
+}
+
+impl<'a, S> RpcServiceT<'a> for RpcTracingQueueService<S>
+where
+    S: RpcServiceT<'a> + Send + Sync + Clone + 'static,
+    S::Future: Send + 'a,
+{
+    type Future = Pin<Box<dyn Future<Output = MethodResponse> + Send + 'a>>;
+
+    fn call(&self, req: Request<'a>) -> Self::Future {
+        let queue = match &self.queue {
+            Some(queue) if is_tracing_method(req.method_name()) => queue.clone(),
+            _ => return Box::pin(self.inner.call(req)),
+        };
+
+        let inner = self.inner.clone();
+        Box::pin(async move {
+            let client = req.extensions().get::<ApiKey>().map(|key| key.0.clone());
+            match queue.acquire(client).await {
+                Ok(_permit) => inner.call(req).await,
+                Err(rejection) => {
+                    debug!(target: "rpc::tracing_queue", method = req.method_name(), %rejection, "Rejected call");
+                    MethodResponse::error(req.id().into_owned(), rejection)
+                }
+            }
+        })
+    }
+}
+
+/// Metrics of the [`RpcTracingQueue`].
+#[derive(Metrics, Clone)]
+#[metrics(scope = "rpc_server.tracing_queue")]
+struct RpcTracingQueueMetrics {
+    /// The number of calls waiting in the queue
+    queued: Gauge,
+    /// The number of calls rejected because the queue was full or they timed out
+    rejected_total: Counter,
+    /// The time calls waited in the queue
+    wait_duration_seconds: Histogram,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(
+        max_concurrent: usize,
+        max_queued: usize,
+        max_per_client: Option<usize>,
+    ) -> RpcTracingQueue {
+        RpcTracingQueue::new(TracingQueueConfig {
+            max_concurrent,
+            max_queued,
+            max_per_client,
+            timeout: Duration::from_secs(60),
+        })
+    }
+
+    #[tokio::test]
+    async fn rejects_calls_when_full() {
+        let queue = queue(1, 1, None);
+        let permit = queue.acquire(None).await.unwrap();
+
+        let waiting = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.acquire(None).await.map(drop) }
+        });
+        while queue.occupancy().1 == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        let rejection = queue.acquire(None).await.unwrap_err();
+        assert_eq!(rejection, TracingQueueRejection::Full { queued: 1 });
+        let error = ErrorObject::from(rejection);
+        assert_eq!(error.code(), TRACING_QUEUE_ERROR_CODE);
+        assert_eq!(error.message(), "tracing queue is full, 1 calls queued");
+
+        drop(permit);
+        assert!(waiting.await.unwrap().is_ok());
+        assert_eq!(queue.occupancy(), (0, 0));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reports_position_on_timeout() {
+        let queue = queue(1, 10, None);
+        let _permit = queue.acquire(Some("a".to_string())).await.unwrap();
+
+        let spawn_waiter = |client: &str| {
+            let queue = queue.clone();
+            let client = Some(client.to_string());
+            tokio::spawn(async move { queue.acquire(client).await.map(drop) })
+        };
+
+        let a = spawn_waiter("a");
+        while queue.occupancy().1 < 1 {
+            tokio::task::yield_now().await;
+        }
+        tokio::time::advance(Duration::from_secs(30)).await;
+
+        // b has no running calls, so it's queued ahead of a
+        let b = spawn_waiter("b");
+        while queue.occupancy().1 < 2 {
+            tokio::task::yield_now().await;
+        }
+
+        // only a waited for the timeout
+        tokio::time::advance(Duration::from_secs(30)).await;
+        assert_eq!(
+            a.await.unwrap().unwrap_err(),
+            TracingQueueRejection::Timeout { position: 2, queued: 2 }
+        );
+        assert!(!b.is_finished());
+
+        tokio::time::advance(Duration::from_secs(30)).await;
+        assert_eq!(
+            b.await.unwrap().unwrap_err(),
+            TracingQueueRejection::Timeout { position: 1, queued: 1 }
+        );
+    }
+
+    #[tokio::test]
+    async fn prioritizes_clients_with_fewer_calls() {
+        let queue = queue(2, 10, Some(2));
+        let busy = Some("busy".to_string());
+        let idle = Some("idle".to_string());
+
+        let first = queue.acquire(busy.clone()).await.unwrap();
+        let _second = queue.acquire(busy.clone()).await.unwrap();
+
+        // the busy client is at its cap, so the idle client is queued behind the capacity
+        let busy_waiting = tokio::spawn({
+            let queue = queue.clone();
+            let busy = busy.clone();
+            async move { queue.acquire(busy).await }
+        });
+        while queue.occupancy().1 == 0 {
+            tokio::task::yield_now().await;
+        }
+        let idle_waiting = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.acquire(idle).await }
+        });
+        while queue.occupancy().1 == 1 {
+            tokio::task::yield_now().await;
+        }
+
+        // the idle client is processed first, even though it was queued later
+        drop(first);
+        let idle_permit = idle_waiting.await.unwrap().unwrap();
+        assert_eq!(idle_permit.client.as_deref(), Some("idle"));
+        assert_eq!(queue.occupancy(), (2, 1));
+
+        drop(idle_permit);
+        assert_eq!(busy_waiting.await.unwrap().unwrap().client, busy);
+    }
+}
@@ -23,9 +23,9 @@ use reth_network_peers::NodeRecord;
 use reth_rpc_api::{
     clients::{AdminApiClient, EthApiClient},
     DebugApiClient, EthCallBundleApiClient, EthFilterApiClient, NetApiClient, OtterscanClient,
-    TraceApiClient, Web3ApiClient,
+    PersonalApiClient, TraceApiClient, Web3ApiClient,
 };
-use reth_rpc_server_types::RethRpcModule;
+use reth_rpc_server_types::{RethRpcModule, RpcModuleSelection};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashSet;
@@ -500,6 +500,27 @@ where
         .is_none());
 }
 
+async fn test_basic_personal_calls<C>(client: &C)
+where
+    C: ClientT + SubscriptionClientT + Sync,
+{
+    assert!(PersonalApiClient::list_accounts(client).await.unwrap().is_empty());
+
+    let account = PersonalApiClient::new_account(client, None).await.unwrap();
+    assert_eq!(PersonalApiClient::list_accounts(client).await.unwrap(), vec![account]);
+    assert!(PersonalApiClient::unlock_account(client, account, None, None).await.unwrap());
+    assert!(!PersonalApiClient::unlock_account(client, Address::ZERO, None, None).await.unwrap());
+
+    let message = Bytes::from_static(b"hello");
+    let signature = PersonalApiClient::sign(client, message.clone(), account, None).await.unwrap();
+    assert_eq!(PersonalApiClient::ec_recover(client, message, signature).await.unwrap(), account);
+
+    let key = "4646464646464646464646464646464646464646464646464646464646464646".to_string();
+    let imported = PersonalApiClient::import_raw_key(client, key.clone(), None).await.unwrap();
+    assert_eq!(imported, Address::from(hex!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f")));
+    PersonalApiClient::import_raw_key(client, key, None).await.unwrap_err();
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_call_filter_functions_http() {
     reth_tracing::init_test_tracing();
@@ -698,6 +719,33 @@ async fn test_call_otterscan_functions_http_and_ws() {
     test_basic_otterscan_calls(&client).await;
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_call_personal_functions_http() {
+    reth_tracing::init_test_tracing();
+
+    let handle = launch_http(vec![RethRpcModule::Personal]).await;
+    let client = handle.http_client().unwrap();
+    test_basic_personal_calls(&client).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_call_personal_functions_ws() {
+    reth_tracing::init_test_tracing();
+
+    let handle = launch_ws(vec![RethRpcModule::Personal]).await;
+    let client = handle.ws_client().await.unwrap();
+    test_basic_personal_calls(&client).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_personal_not_in_all() {
+    reth_tracing::init_test_tracing();
+
+    let handle = launch_http(RpcModuleSelection::All).await;
+    let client = handle.http_client().unwrap();
+    PersonalApiClient::list_accounts(&client).await.unwrap_err();
+}
+
 // <https://github.com/paradigmxyz/reth/issues/5830>
 #[tokio::test(flavor = "multi_thread")]
 async fn test_eth_logs_args() {
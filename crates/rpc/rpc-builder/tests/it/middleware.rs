@@ -1,11 +1,18 @@
 use crate::utils::{test_address, test_rpc_builder};
-use alloy_rpc_types_eth::{Block, Header, Receipt, Transaction};
+use alloy_rpc_types_eth::{Block, BlockId, Header, Receipt, Transaction};
 use jsonrpsee::{
+    core::client::Error,
+    http_client::{HeaderMap, HttpClientBuilder},
     server::{middleware::rpc::RpcServiceT, RpcServiceBuilder},
     types::Request,
     MethodResponse,
 };
-use reth_rpc_builder::{RpcServerConfig, TransportRpcModuleConfig};
+use reth_rpc_api::{DebugApiClient, NetApiClient};
+use reth_rpc_builder::{
+    api_keys::{ApiKeyPolicy, ApiKeys, ApiKeysConfig, METHOD_NOT_ALLOWED_CODE},
+    tracing_queue::{TracingQueueConfig, TRACING_QUEUE_ERROR_CODE},
+    RpcServerConfig, TransportRpcModuleConfig,
+};
 use reth_rpc_eth_api::EthApiClient;
 use reth_rpc_server_types::RpcModuleSelection;
 use std::{
@@ -15,6 +22,7 @@ use std::{
         atomic::{AtomicUsize, Ordering},
         Arc,
     },
+    time::Duration,
 };
 use tower::Layer;
 
@@ -77,3 +85,71 @@ async fn test_rpc_middleware() {
     let count = mylayer.count.load(Ordering::Relaxed);
     assert_eq!(count, 1);
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_rpc_api_keys() {
+    let builder = test_rpc_builder();
+    let eth_api = builder.bootstrap_eth_api();
+    let modules =
+        builder.build(TransportRpcModuleConfig::set_http(RpcModuleSelection::All), eth_api);
+
+    let api_keys = ApiKeys::new(ApiKeysConfig {
+        anonymous: Some(ApiKeyPolicy {
+            allowed_methods: Some(vec!["eth_*".to_string()]),
+            ..Default::default()
+        }),
+        keys: [("secret".to_string(), ApiKeyPolicy::default())].into(),
+        ..Default::default()
+    });
+
+    let handle = RpcServerConfig::http(Default::default())
+        .with_http_address(test_address())
+        .with_api_keys(Some(api_keys))
+        .start(&modules)
+        .await
+        .unwrap();
+
+    let client = handle.http_client().unwrap();
+    EthApiClient::<Transaction, Block, Receipt, Header>::protocol_version(&client).await.unwrap();
+    let err = NetApiClient::version(&client).await.unwrap_err();
+    assert!(matches!(err, Error::Call(err) if err.code() == METHOD_NOT_ALLOWED_CODE));
+
+    let mut headers = HeaderMap::new();
+    headers.insert("x-api-key", "secret".parse().unwrap());
+    let client = HttpClientBuilder::default()
+        .set_headers(headers)
+        .build(handle.http_url().unwrap())
+        .unwrap();
+    NetApiClient::version(&client).await.unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[cfg(unix)]
+async fn test_rpc_tracing_queue_ipc() {
+    let builder = test_rpc_builder();
+    let eth_api = builder.bootstrap_eth_api();
+    let modules =
+        builder.build(TransportRpcModuleConfig::set_ipc(RpcModuleSelection::All), eth_api);
+
+    let dir = tempfile::tempdir().unwrap();
+    let endpoint = dir.path().join("reth.ipc");
+    let handle = RpcServerConfig::ipc(Default::default())
+        .with_ipc_endpoint(endpoint.to_string_lossy())
+        .with_tracing_queue(Some(TracingQueueConfig {
+            max_concurrent: 0,
+            max_queued: 0,
+            max_per_client: None,
+            timeout: Duration::from_secs(60),
+        }))
+        .start(&modules)
+        .await
+        .unwrap();
+
+    let client = reth_ipc::client::IpcClientBuilder::default()
+        .build(&handle.ipc_endpoint().unwrap())
+        .await
+        .unwrap();
+    NetApiClient::version(&client).await.unwrap();
+    let err = DebugApiClient::raw_header(&client, BlockId::latest()).await.unwrap_err();
+    assert!(matches!(err, Error::Call(err) if err.code() == TRACING_QUEUE_ERROR_CODE));
+}
@@ -18,7 +18,9 @@ use async_trait::async_trait;
 use jsonrpsee_core::{server::RpcModule, RpcResult};
 use parking_lot::Mutex;
 use reth_chainspec::{EthereumHardfork, EthereumHardforks};
-use reth_engine_primitives::{BeaconConsensusEngineHandle, EngineTypes, EngineValidator};
+use reth_engine_primitives::{
+    BackfillProgress, BeaconConsensusEngineHandle, EngineTypes, EngineValidator,
+};
 use reth_payload_builder::PayloadStore;
 use reth_payload_primitives::{
     validate_payload_timestamp, EngineApiMessageVersion, ExecutionPayload,
@@ -31,7 +33,7 @@ use reth_tasks::TaskSpawner;
 use reth_transaction_pool::TransactionPool;
 use std::{sync::Arc, time::Instant};
 use tokio::sync::oneshot;
-use tracing::{trace, warn};
+use tracing::{instrument, trace, warn, Span};
 
 /// The Engine API response sender.
 pub type EngineApiSender<Ok> = oneshot::Sender<EngineApiResult<Ok>>;
@@ -56,6 +58,8 @@ const MAX_BLOB_LIMIT: usize = 128;
 /// See also [`EngineApiServer`] implementation for this type which is the L1 implementation.
 pub struct EngineApi<Provider, EngineT: EngineTypes, Pool, Validator, ChainSpec> {
     inner: Arc<EngineApiInner<Provider, EngineT, Pool, Validator, ChainSpec>>,
+    /// The progress of the backfill sync that is reported to the consensus layer.
+    backfill_progress: Option<BackfillProgress>,
 }
 
 struct EngineApiInner<Provider, EngineT: EngineTypes, Pool, Validator, ChainSpec> {
@@ -118,15 +122,30 @@ where
             validator,
             latest_new_payload_response: Mutex::new(None),
         });
-        Self { inner }
+        Self { inner, backfill_progress: None }
+    }
+
+    /// Reports the progress of the backfill sync in the name of the client version, see
+    /// [`Self::get_client_version_v1`].
+    pub fn with_backfill_progress(mut self, backfill_progress: BackfillProgress) -> Self {
+        self.backfill_progress = Some(backfill_progress);
+        self
     }
 
     /// Fetches the client version.
+    ///
+    /// While the backfill sync runs, its progress is appended to the name of the client, so the
+    /// consensus layer can display it, e.g. `Reth (syncing Execution 1000/2000 50.00%)`.
     pub fn get_client_version_v1(
         &self,
         _client: ClientVersionV1,
     ) -> EngineApiResult<Vec<ClientVersionV1>> {
-        Ok(vec![self.inner.client.clone()])
+        let mut client = self.inner.client.clone();
+        if let Some(status) = self.backfill_progress.as_ref().and_then(|progress| progress.status())
+        {
+            client.name = format!("{} ({status})", client.name);
+        }
+        Ok(vec![client])
     }
 
     /// Fetches the attributes for the payload with the given id.
@@ -144,6 +163,7 @@ where
 
     /// See also <https://github.com/ethereum/execution-apis/blob/3d627c95a4d3510a8187dd02e0250ecb4331d27e/src/engine/paris.md#engine_newpayloadv1>
     /// Caution: This should not accept the `withdrawals` field
+    #[instrument(level = "debug", skip_all, fields(block_hash = %payload.block_hash()), target = "rpc::engine")]
     pub async fn new_payload_v1(
         &self,
         payload: EngineT::ExecutionData,
@@ -182,6 +202,7 @@ where
     }
 
     /// See also <https://github.com/ethereum/execution-apis/blob/584905270d8ad665718058060267061ecfd79ca5/src/engine/shanghai.md#engine_newpayloadv2>
+    #[instrument(level = "debug", skip_all, fields(block_hash = %payload.block_hash()), target = "rpc::engine")]
     pub async fn new_payload_v2(
         &self,
         payload: EngineT::ExecutionData,
@@ -218,6 +239,7 @@ where
     }
 
     /// See also <https://github.com/ethereum/execution-apis/blob/fe8e13c288c592ec154ce25c534e26cb7ce0530d/src/engine/cancun.md#engine_newpayloadv3>
+    #[instrument(level = "debug", skip_all, fields(block_hash = %payload.block_hash()), target = "rpc::engine")]
     pub async fn new_payload_v3(
         &self,
         payload: EngineT::ExecutionData,
@@ -255,6 +277,7 @@ where
     }
 
     /// See also <https://github.com/ethereum/execution-apis/blob/7907424db935b93c2fe6a3c0faab943adebe8557/src/engine/prague.md#engine_newpayloadv4>
+    #[instrument(level = "debug", skip_all, fields(block_hash = %payload.block_hash()), target = "rpc::engine")]
     pub async fn new_payload_v4(
         &self,
         payload: EngineT::ExecutionData,
@@ -381,6 +404,7 @@ where
     ///
     /// Note:
     /// > Provider software MAY stop the corresponding build process after serving this call.
+    #[instrument(level = "debug", skip_all, fields(%payload_id), target = "rpc::engine")]
     pub async fn get_payload_v1(
         &self,
         payload_id: PayloadId,
@@ -416,6 +440,7 @@ where
     ///
     /// Note:
     /// > Provider software MAY stop the corresponding build process after serving this call.
+    #[instrument(level = "debug", skip_all, fields(%payload_id), target = "rpc::engine")]
     pub async fn get_payload_v2(
         &self,
         payload_id: PayloadId,
@@ -462,6 +487,7 @@ where
     ///
     /// Note:
     /// > Provider software MAY stop the corresponding build process after serving this call.
+    #[instrument(level = "debug", skip_all, fields(%payload_id), target = "rpc::engine")]
     pub async fn get_payload_v3(
         &self,
         payload_id: PayloadId,
@@ -508,6 +534,7 @@ where
     ///
     /// Note:
     /// > Provider software MAY stop the corresponding build process after serving this call.
+    #[instrument(level = "debug", skip_all, fields(%payload_id), target = "rpc::engine")]
     pub async fn get_payload_v4(
         &self,
         payload_id: PayloadId,
@@ -781,6 +808,7 @@ where
     ///
     /// * If the version above [`EngineApiMessageVersion::V3`], then the payload attributes will be
     ///   validated according to the Cancun rules.
+    #[instrument(level = "debug", skip_all, fields(head = %state.head_block_hash, payload_id = tracing::field::Empty), target = "rpc::engine")]
     async fn validate_and_execute_forkchoice(
         &self,
         version: EngineApiMessageVersion,
@@ -818,7 +846,13 @@ where
             }
         }
 
-        Ok(self.inner.beacon_consensus.fork_choice_updated(state, payload_attrs, version).await?)
+        let res =
+            self.inner.beacon_consensus.fork_choice_updated(state, payload_attrs, version).await?;
+        if let Some(payload_id) = res.payload_id {
+            // lets the trace of the forkchoice update be joined with the later getPayload call
+            Span::current().record("payload_id", tracing::field::display(payload_id));
+        }
+        Ok(res)
     }
 
     /// Returns reference to supported capabilities.
@@ -1159,7 +1193,7 @@ mod tests {
     use alloy_rpc_types_engine::{ClientCode, ClientVersionV1};
     use assert_matches::assert_matches;
     use reth_chainspec::{ChainSpec, EthereumHardfork, MAINNET};
-    use reth_engine_primitives::BeaconEngineMessage;
+    use reth_engine_primitives::{BackfillSyncStatus, BeaconEngineMessage};
     use reth_ethereum_engine_primitives::EthEngineTypes;
     use reth_ethereum_primitives::Block;
     use reth_node_ethereum::EthereumEngineValidator;
@@ -1220,6 +1254,26 @@ mod tests {
         assert_eq!(res.unwrap(), vec![client]);
     }
 
+    #[tokio::test]
+    async fn engine_client_version_v1_backfill_progress() {
+        let (_, api) = setup_engine_api();
+        let progress = BackfillProgress::new();
+        let api = api.with_backfill_progress(progress.clone());
+        let client = api.inner.client.clone();
+        assert_eq!(api.get_client_version_v1(client.clone()).unwrap(), vec![client.clone()]);
+
+        progress.update(Some(BackfillSyncStatus {
+            stage: "Execution".to_string(),
+            checkpoint: 1000,
+            target: Some(2000),
+            stage_progress: Some(50.0),
+            stage_eta: None,
+        }));
+        let res = api.get_client_version_v1(client.clone()).unwrap();
+        assert_eq!(res[0].name, "Reth (syncing Execution 1000/2000 50.00%)");
+        assert_eq!(res[0].commit, client.commit);
+    }
+
     struct EngineApiTestHandle {
         chain_spec: Arc<ChainSpec>,
         provider: Arc<MockEthProvider>,
@@ -13,6 +13,7 @@ use alloy_rpc_types_eth::{
 };
 use alloy_serde::JsonStorageKey;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_eth_types::EthSyncStatus;
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
 use tracing::trace;
 
@@ -57,6 +58,12 @@ pub trait EthApi<T: RpcObject, B: RpcObject, R: RpcObject, H: RpcObject> {
     #[method(name = "syncing")]
     fn syncing(&self) -> RpcResult<SyncStatus>;
 
+    /// Returns an object with data about the sync status or false.
+    ///
+    /// If enabled, the sync info also includes the detail of the stage the pipeline is running.
+    #[method(name = "syncingDetail")]
+    fn syncing_detail(&self) -> RpcResult<EthSyncStatus>;
+
     /// Returns the client coinbase address.
     #[method(name = "coinbase")]
     async fn author(&self) -> RpcResult<Address>;
@@ -348,7 +355,10 @@ pub trait EthApi<T: RpcObject, B: RpcObject, R: RpcObject, H: RpcObject> {
     async fn sign_transaction(&self, transaction: TransactionRequest) -> RpcResult<Bytes>;
 
     /// Signs data via [EIP-712](https://github.com/ethereum/EIPs/blob/master/EIPS/eip-712.md).
-    #[method(name = "signTypedData")]
+    ///
+    /// The typed data may also be passed as a JSON string, as done by wallets for
+    /// `eth_signTypedData_v4`.
+    #[method(name = "signTypedData", aliases = ["eth_signTypedData_v4"])]
     async fn sign_typed_data(&self, address: Address, data: TypedData) -> RpcResult<Bytes>;
 
     /// Returns the account and storage values of the specified account including the Merkle-proof.
@@ -386,6 +396,12 @@ where
         EthApiSpec::sync_status(self).to_rpc_result()
     }
 
+    /// Handler for: `eth_syncingDetail`
+    fn syncing_detail(&self) -> RpcResult<EthSyncStatus> {
+        trace!(target: "rpc::eth", "Serving eth_syncingDetail");
+        EthApiSpec::sync_status_with_stage_detail(self).to_rpc_result()
+    }
+
     /// Handler for: `eth_coinbase`
     async fn author(&self) -> RpcResult<Address> {
         Err(internal_rpc_err("unimplemented"))
@@ -792,10 +808,10 @@ where
         Ok(EthTransactions::sign_transaction(self, request).await?)
     }
 
-    /// Handler for: `eth_signTypedData`
+    /// Handler for: `eth_signTypedData` and `eth_signTypedData_v4`
     async fn sign_typed_data(&self, address: Address, data: TypedData) -> RpcResult<Bytes> {
         trace!(target: "rpc::eth", ?address, ?data, "Serving eth_signTypedData");
-        Ok(EthTransactions::sign_typed_data(self, &data, address)?)
+        Ok(EthTransactions::sign_typed_data_async(self, &data, address).await?)
     }
 
     /// Handler for: `eth_getProof`
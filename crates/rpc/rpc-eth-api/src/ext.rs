@@ -1,8 +1,11 @@
 //! `eth_` Extension traits.
 
+use alloy_eips::BlockNumberOrTag;
+use alloy_json_rpc::RpcObject;
 use alloy_primitives::{Bytes, B256};
 use alloy_rpc_types_eth::erc4337::TransactionConditional;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_rpc_eth_types::BlockResponseOptions;
 
 /// Extension trait for `eth_` namespace for L2s.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "eth"))]
@@ -16,3 +19,21 @@ pub trait L2EthApiExt {
         condition: TransactionConditional,
     ) -> RpcResult<B256>;
 }
+
+/// Extension of `eth_getBlockByNumber` with options to omit heavy fields from the response.
+///
+/// This serves the same method as [`EthApiServer`](crate::EthApiServer), so it must replace that
+/// method when both are installed. Requests without the trailing `options` parameter are answered
+/// like a regular `eth_getBlockByNumber`.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "eth"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "eth"))]
+pub trait EthBlockResponseApi<B: RpcObject> {
+    /// Returns information about a block by number, reduced by the given options.
+    #[method(name = "getBlockByNumber")]
+    async fn block_by_number_with_options(
+        &self,
+        number: BlockNumberOrTag,
+        full: bool,
+        options: Option<BlockResponseOptions>,
+    ) -> RpcResult<Option<B>>;
+}
@@ -2,7 +2,7 @@
 //! are executed on the `tokio` runtime.
 
 use futures::Future;
-use reth_rpc_eth_types::EthApiError;
+use reth_rpc_eth_types::{EthApiError, RequestMemoryBudget};
 use reth_tasks::{
     pool::{BlockingTaskGuard, BlockingTaskPool},
     TaskSpawner,
@@ -43,6 +43,8 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
 
     /// Executes the future on a new blocking task.
     ///
+    /// The [`RequestMemoryBudget`] of the current call, if any, is carried over to the task.
+    ///
     /// Note: This is expected for futures that are dominated by blocking IO operations, for tracing
     /// or CPU bound operations in general use [`spawn_tracing`](Self::spawn_tracing).
     fn spawn_blocking_io<F, R>(&self, f: F) -> impl Future<Output = Result<R, Self::Error>> + Send
@@ -52,7 +54,9 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
     {
         let (tx, rx) = oneshot::channel();
         let this = self.clone();
+        let budget = RequestMemoryBudget::current();
         self.io_task_spawner().spawn_blocking(Box::pin(async move {
+            let _budget = budget.as_ref().map(RequestMemoryBudget::enter);
             let res = f(this);
             let _ = tx.send(res);
         }));
@@ -62,6 +66,8 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
 
     /// Executes a blocking task on the tracing pool.
     ///
+    /// The [`RequestMemoryBudget`] of the current call, if any, is carried over to the task.
+    ///
     /// Note: This is expected for futures that are predominantly CPU bound, as it uses `rayon`
     /// under the hood, for blocking IO futures use [`spawn_blocking`](Self::spawn_blocking_io). See
     /// <https://ryhl.io/blog/async-what-is-blocking/>.
@@ -71,7 +77,11 @@ pub trait SpawnBlocking: EthApiTypes + Clone + Send + Sync + 'static {
         R: Send + 'static,
     {
         let this = self.clone();
-        let fut = self.tracing_task_pool().spawn(move || f(this));
+        let budget = RequestMemoryBudget::current();
+        let fut = self.tracing_task_pool().spawn(move || {
+            let _budget = budget.as_ref().map(RequestMemoryBudget::enter);
+            f(this)
+        });
         async move { fut.await.map_err(|_| EthApiError::InternalBlockingTaskError)? }
     }
 }
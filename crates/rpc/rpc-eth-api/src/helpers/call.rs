@@ -17,11 +17,12 @@ use alloy_rpc_types_eth::{
 use futures::Future;
 use reth_errors::{ProviderError, RethError};
 use reth_evm::{
-    ConfigureEvm, Evm, EvmEnv, EvmEnvFor, HaltReasonFor, InspectorFor, SpecFor, TransactionEnv,
-    TxEnvFor,
+    CallEnvOverrides, ConfigureEvm, Evm, EvmEnv, EvmEnvFor, HaltReasonFor, InspectorFor, SpecFor,
+    TransactionEnv, TxEnvFor,
+};
+use reth_primitives_traits::{
+    BlockBody, NodePrimitives, Recovered, SealedHeader, SignedTransaction,
 };
-use reth_node_api::{BlockBody, NodePrimitives};
-use reth_primitives_traits::{Recovered, SealedHeader, SignedTransaction};
 use reth_provider::{BlockIdReader, ProviderHeader, ProviderTx};
 use reth_revm::{
     database::StateProviderDatabase,
@@ -31,7 +32,9 @@ use reth_revm::{
 use reth_rpc_eth_types::{
     cache::db::{StateCacheDbRefMutWrapper, StateProviderTraitObjWrapper},
     error::{api::FromEvmHalt, ensure_success, FromEthApiError},
-    revm_utils::{apply_block_overrides, apply_state_overrides, caller_gas_allowance},
+    revm_utils::{
+        apply_block_overrides, apply_state_overrides, caller_gas_allowance, prefetch_access_list,
+    },
     simulate::{self, EthSimulateError},
     EthApiError, RevertError, RpcInvalidTransactionError, StateCacheDb,
 };
@@ -367,6 +370,9 @@ pub trait EthCall: EstimateCall + Call + LoadPendingBlock + LoadBlock + FullEthA
     {
         let state = self.state_at_block_id(at)?;
         let mut db = CacheDB::new(StateProviderDatabase::new(state));
+        if let Some(access_list) = &request.access_list {
+            prefetch_access_list(access_list, &mut db)?;
+        }
 
         let mut tx_env = self.create_txn_env(&evm_env, request.clone(), &mut db)?;
 
@@ -448,6 +454,10 @@ pub trait Call:
     /// Returns the maximum number of blocks accepted for `eth_simulateV1`.
     fn max_simulate_blocks(&self) -> u64;
 
+    /// Returns the configured adjustments of the EVM environment for `eth_call` and
+    /// `eth_estimateGas`.
+    fn call_env_overrides(&self) -> CallEnvOverrides;
+
     /// Executes the closure with the state that corresponds to the given [`BlockId`].
     fn with_state_at_block<F, R>(&self, at: BlockId, f: F) -> Result<R, Self::Error>
     where
@@ -502,6 +512,8 @@ pub trait Call:
     }
 
     /// Executes the call request at the given [`BlockId`].
+    ///
+    /// The call environment is adjusted with the configured [`Self::call_env_overrides`].
     #[expect(clippy::type_complexity)]
     fn transact_call_at(
         &self,
@@ -518,9 +530,13 @@ pub trait Call:
         Self: LoadPendingBlock,
     {
         let this = self.clone();
-        self.spawn_with_call_at(request, at, overrides, move |db, evm_env, tx_env| {
-            this.transact(db, evm_env, tx_env)
-        })
+        self.spawn_with_call_env_at(
+            request,
+            at,
+            overrides,
+            self.call_env_overrides(),
+            move |db, evm_env, tx_env| this.transact(db, evm_env, tx_env),
+        )
     }
 
     /// Executes the closure with the state that corresponds to the given [`BlockId`] on a new task
@@ -561,6 +577,30 @@ pub trait Call:
         overrides: EvmOverrides,
         f: F,
     ) -> impl Future<Output = Result<R, Self::Error>> + Send
+    where
+        Self: LoadPendingBlock,
+        F: FnOnce(
+                StateCacheDbRefMutWrapper<'_, '_>,
+                EvmEnvFor<Self::Evm>,
+                TxEnvFor<Self::Evm>,
+            ) -> Result<R, Self::Error>
+            + Send
+            + 'static,
+        R: Send + 'static,
+    {
+        self.spawn_with_call_env_at(request, at, overrides, CallEnvOverrides::default(), f)
+    }
+
+    /// Same as [`Self::spawn_with_call_at`], but adjusts the call environment with the given
+    /// [`CallEnvOverrides`] instead of the defaults.
+    fn spawn_with_call_env_at<F, R>(
+        &self,
+        request: TransactionRequest,
+        at: BlockId,
+        overrides: EvmOverrides,
+        call_env_overrides: CallEnvOverrides,
+        f: F,
+    ) -> impl Future<Output = Result<R, Self::Error>> + Send
     where
         Self: LoadPendingBlock,
         F: FnOnce(
@@ -579,9 +619,17 @@ pub trait Call:
                 let state = this.state_at_block_id(at)?;
                 let mut db =
                     CacheDB::new(StateProviderDatabase::new(StateProviderTraitObjWrapper(&state)));
+                if let Some(access_list) = &request.access_list {
+                    prefetch_access_list(access_list, &mut db)?;
+                }
 
-                let (evm_env, tx_env) =
-                    this.prepare_call_env(evm_env, request, &mut db, overrides)?;
+                let (evm_env, tx_env) = this.prepare_call_env_with(
+                    evm_env,
+                    request,
+                    &mut db,
+                    overrides,
+                    &call_env_overrides,
+                )?;
 
                 f(StateCacheDbRefMutWrapper(&mut db), evm_env, tx_env)
             })
@@ -704,11 +752,31 @@ pub trait Call:
     /// In addition, this changes the block's gas limit to the configured [`Self::call_gas_limit`].
     #[expect(clippy::type_complexity)]
     fn prepare_call_env<DB>(
+        &self,
+        evm_env: EvmEnvFor<Self::Evm>,
+        request: TransactionRequest,
+        db: &mut CacheDB<DB>,
+        overrides: EvmOverrides,
+    ) -> Result<(EvmEnvFor<Self::Evm>, TxEnvFor<Self::Evm>), Self::Error>
+    where
+        DB: DatabaseRef,
+        EthApiError: From<<DB as DatabaseRef>::Error>,
+    {
+        self.prepare_call_env_with(evm_env, request, db, overrides, &CallEnvOverrides::default())
+    }
+
+    /// Same as [`Self::prepare_call_env`], but adjusts the EIP-3607 and base fee settings with the
+    /// given [`CallEnvOverrides`] instead of the defaults.
+    ///
+    /// This is used by `eth_call` with the configured [`Self::call_env_overrides`].
+    #[expect(clippy::type_complexity)]
+    fn prepare_call_env_with<DB>(
         &self,
         mut evm_env: EvmEnvFor<Self::Evm>,
         mut request: TransactionRequest,
         db: &mut CacheDB<DB>,
         overrides: EvmOverrides,
+        call_env_overrides: &CallEnvOverrides,
     ) -> Result<(EvmEnvFor<Self::Evm>, TxEnvFor<Self::Evm>), Self::Error>
     where
         DB: DatabaseRef,
@@ -724,14 +792,13 @@ pub trait Call:
         // apply configured gas cap
         evm_env.block_env.gas_limit = self.call_gas_limit();
 
-        // Disabled because eth_call is sometimes used with eoa senders
+        // By default, EIP-3607 is disabled because eth_call is sometimes used with eoa senders
         // See <https://github.com/paradigmxyz/reth/issues/1959>
-        evm_env.cfg_env.disable_eip3607 = true;
-
-        // The basefee should be ignored for eth_call
+        //
+        // and the basefee is ignored for eth_call
         // See:
         // <https://github.com/ethereum/go-ethereum/blob/ee8e83fa5f6cb261dad2ed0a7bbcde4930c41e6c/internal/ethapi/api.go#L985>
-        evm_env.cfg_env.disable_base_fee = true;
+        self.evm_config().apply_call_env_overrides(&mut evm_env, call_env_overrides);
 
         // set nonce to None so that the correct nonce is chosen by the EVM
         request.nonce = None;
@@ -745,6 +812,7 @@ pub trait Call:
 
         let request_gas = request.gas;
         let mut tx_env = self.create_txn_env(&evm_env, request, &mut *db)?;
+        call_env_overrides.apply_gas_price(&mut evm_env, tx_env.gas_price());
 
         if request_gas.is_none() {
             // No gas limit was provided in the request, so we need to cap the transaction gas limit
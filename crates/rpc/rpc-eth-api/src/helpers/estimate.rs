@@ -7,7 +7,7 @@ use alloy_rpc_types_eth::{state::StateOverride, transaction::TransactionRequest,
 use futures::Future;
 use reth_chainspec::MIN_TRANSACTION_GAS;
 use reth_errors::ProviderError;
-use reth_evm::{Database, EvmEnvFor, TransactionEnv, TxEnvFor};
+use reth_evm::{ConfigureEvm, Database, EvmEnvFor, TransactionEnv, TxEnvFor};
 use reth_provider::StateProvider;
 use reth_revm::{database::StateProviderDatabase, db::CacheDB};
 use reth_rpc_eth_types::{
@@ -32,6 +32,8 @@ pub trait EstimateCall: Call {
     ///  - `disable_eip3607` is set to `true`
     ///  - `disable_base_fee` is set to `true`
     ///  - `nonce` is set to `None`
+    ///
+    /// The EIP-3607 and base fee settings can be adjusted with [`Call::call_env_overrides`].
     fn estimate_gas_with<S>(
         &self,
         mut evm_env: EvmEnvFor<Self::Evm>,
@@ -42,14 +44,15 @@ pub trait EstimateCall: Call {
     where
         S: StateProvider,
     {
-        // Disabled because eth_estimateGas is sometimes used with eoa senders
+        // By default, EIP-3607 is disabled because eth_estimateGas is sometimes used with eoa
+        // senders
         // See <https://github.com/paradigmxyz/reth/issues/1959>
-        evm_env.cfg_env.disable_eip3607 = true;
-
-        // The basefee should be ignored for eth_estimateGas and similar
+        //
+        // and the basefee is ignored for eth_estimateGas and similar
         // See:
         // <https://github.com/ethereum/go-ethereum/blob/ee8e83fa5f6cb261dad2ed0a7bbcde4930c41e6c/internal/ethapi/api.go#L985>
-        evm_env.cfg_env.disable_base_fee = true;
+        let call_env_overrides = self.call_env_overrides();
+        self.evm_config().apply_call_env_overrides(&mut evm_env, &call_env_overrides);
 
         // set nonce to None so that the correct nonce is chosen by the EVM
         request.nonce = None;
@@ -75,6 +78,7 @@ pub trait EstimateCall: Call {
         // Configure the evm env
         let mut db = CacheDB::new(StateProviderDatabase::new(state));
         let mut tx_env = self.create_txn_env(&evm_env, request, &mut db)?;
+        call_env_overrides.apply_gas_price(&mut evm_env, tx_env.gas_price());
 
         // Apply any state overrides if specified.
         if let Some(state_override) = state_override {
@@ -3,7 +3,10 @@
 
 use alloy_consensus::transaction::TransactionMeta;
 use futures::Future;
-use reth_provider::{ProviderReceipt, ProviderTx, ReceiptProvider, TransactionsProvider};
+use reth_primitives_traits::RecoveredBlock;
+use reth_provider::{
+    BlockReader, ProviderBlock, ProviderReceipt, ProviderTx, ReceiptProvider, TransactionsProvider,
+};
 
 use crate::{EthApiTypes, RpcNodeCoreExt, RpcReceipt};
 
@@ -20,4 +23,25 @@ pub trait LoadReceipt:
         meta: TransactionMeta,
         receipt: ProviderReceipt<Self::Provider>,
     ) -> impl Future<Output = Result<RpcReceipt<Self::NetworkTypes>, Self::Error>> + Send;
+
+    /// Builds the receipt of a transaction from the block that includes it and the receipts of
+    /// that block.
+    ///
+    /// Used for blocks that are already at hand, e.g. blocks of canonical state notifications that
+    /// may have been reorged out since. Defaults to
+    /// [`build_transaction_receipt`](Self::build_transaction_receipt), which loads the receipts
+    /// of the block.
+    fn build_transaction_receipt_with_block(
+        &self,
+        tx: &ProviderTx<Self::Provider>,
+        meta: TransactionMeta,
+        receipt: &ProviderReceipt<Self::Provider>,
+        _block: &RecoveredBlock<ProviderBlock<Self::Provider>>,
+        _all_receipts: &[ProviderReceipt<Self::Provider>],
+    ) -> impl Future<Output = Result<RpcReceipt<Self::NetworkTypes>, Self::Error>> + Send
+    where
+        Self::Provider: BlockReader,
+    {
+        self.build_transaction_receipt(tx.clone(), meta, receipt.clone())
+    }
 }
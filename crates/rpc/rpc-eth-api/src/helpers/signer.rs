@@ -29,6 +29,18 @@ pub trait EthSigner<T>: Send + Sync + DynClone {
 
     /// Encodes and signs the typed data according EIP-712. Payload must implement Eip712 trait.
     fn sign_typed_data(&self, address: Address, payload: &TypedData) -> Result<Signature>;
+
+    /// Encodes and signs the typed data according EIP-712, for signers that have to wait on a
+    /// remote service or a device.
+    ///
+    /// Defaults to [`EthSigner::sign_typed_data`].
+    async fn sign_typed_data_async(
+        &self,
+        address: Address,
+        payload: &TypedData,
+    ) -> Result<Signature> {
+        self.sign_typed_data(address, payload)
+    }
 }
 
 dyn_clone::clone_trait_object!(<T> EthSigner<T>);
@@ -7,6 +7,7 @@ use reth_chainspec::{ChainInfo, EthereumHardforks};
 use reth_errors::{RethError, RethResult};
 use reth_network_api::NetworkInfo;
 use reth_provider::{BlockNumReader, ChainSpecProvider, StageCheckpointReader};
+use reth_rpc_eth_types::{EthSyncStatus, SyncStageProgress};
 
 use crate::{helpers::EthSigner, RpcNodeCore};
 
@@ -39,8 +40,16 @@ pub trait EthApiSpec:
         }
     }
 
-    /// Returns the chain id
+    /// Returns the chain id reported by RPC.
+    ///
+    /// This is the [execution chain id](Self::execution_chain_id), unless it's overridden, e.g. for
+    /// forked dev nodes.
     fn chain_id(&self) -> U64 {
+        self.execution_chain_id()
+    }
+
+    /// Returns the chain id of the executed chain, that transactions have to be signed for.
+    fn execution_chain_id(&self) -> U64 {
         U64::from(self.network().chain_id())
     }
 
@@ -59,6 +68,12 @@ pub trait EthApiSpec:
         self.network().is_syncing()
     }
 
+    /// Returns the tracker of the running stage, if the [`EthSyncStatus`] should include the
+    /// detail of the stage the pipeline is running.
+    fn sync_stage_progress(&self) -> Option<&SyncStageProgress> {
+        None
+    }
+
     /// Returns the [`SyncStatus`] of the network
     fn sync_status(&self) -> RethResult<SyncStatus> {
         let status = if self.is_syncing() {
@@ -87,4 +102,19 @@ pub trait EthApiSpec:
         };
         Ok(status)
     }
+
+    /// Returns the [`SyncStatus`] of the network, extended with the detail of the stage the
+    /// pipeline is running if [`Self::sync_stage_progress`] is set.
+    fn sync_status_with_stage_detail(&self) -> RethResult<EthSyncStatus> {
+        let status = EthSyncStatus::from(self.sync_status()?);
+        let Some(progress) = self.sync_stage_progress() else { return Ok(status) };
+        Ok(match status {
+            EthSyncStatus::Info(mut info) => {
+                let checkpoints = self.provider().get_all_checkpoints().unwrap_or_default();
+                info.stage = progress.detail(&checkpoints);
+                EthSyncStatus::Info(info)
+            }
+            EthSyncStatus::None => EthSyncStatus::None,
+        })
+    }
 }
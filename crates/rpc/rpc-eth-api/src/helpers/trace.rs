@@ -1,7 +1,7 @@
 //! Loads a pending block from database. Helper trait for `eth_` call and trace RPC methods.
 
 use super::{Call, LoadBlock, LoadPendingBlock, LoadState, LoadTransaction};
-use crate::FromEvmError;
+use crate::{FromEthApiError, FromEvmError};
 use alloy_consensus::BlockHeader;
 use alloy_primitives::B256;
 use alloy_rpc_types_eth::{BlockId, TransactionInfo};
@@ -12,9 +12,8 @@ use reth_evm::{
     system_calls::SystemCaller, ConfigureEvm, Database, Evm, EvmEnvFor, HaltReasonFor,
     InspectorFor, TxEnvFor,
 };
-use reth_node_api::NodePrimitives;
-use reth_primitives_traits::{BlockBody, RecoveredBlock, SignedTransaction};
-use reth_provider::{BlockReader, ProviderBlock, ProviderHeader, ProviderTx};
+use reth_primitives_traits::{BlockBody, NodePrimitives, RecoveredBlock, SignedTransaction};
+use reth_provider::{BlockIdReader, BlockReader, ProviderBlock, ProviderHeader, ProviderTx};
 use reth_revm::{database::StateProviderDatabase, db::CacheDB};
 use reth_rpc_eth_types::{
     cache::db::{StateCacheDb, StateCacheDbRefMutWrapper, StateProviderTraitObjWrapper},
@@ -295,6 +294,23 @@ pub trait Trace:
         R: Send + 'static,
     {
         async move {
+            // Pin the block by its hash, so the block and its environment are read from the same
+            // block even if the chain advances during the request.
+            let block_id = if block_id.is_pending() {
+                block_id
+            } else if let Some(block) = &block {
+                block.hash().into()
+            } else {
+                match self
+                    .provider()
+                    .block_hash_for_id(block_id)
+                    .map_err(Self::Error::from_eth_err)?
+                {
+                    Some(hash) => hash.into(),
+                    None => return Ok(None),
+                }
+            };
+
             let block = async {
                 if block.is_some() {
                     return Ok(block)
@@ -13,13 +13,16 @@ use alloy_network::TransactionBuilder;
 use alloy_primitives::{Address, Bytes, TxHash, B256};
 use alloy_rpc_types_eth::{transaction::TransactionRequest, BlockNumberOrTag, TransactionInfo};
 use futures::Future;
-use reth_node_api::BlockBody;
-use reth_primitives_traits::{RecoveredBlock, SignedTransaction};
+use reth_primitives_traits::{BlockBody, RecoveredBlock, SignedTransaction};
 use reth_provider::{
-    BlockNumReader, BlockReaderIdExt, ProviderBlock, ProviderReceipt, ProviderTx, ReceiptProvider,
-    TransactionsProvider,
+    BlockNumReader, BlockReaderIdExt, HeaderProvider, ProviderBlock, ProviderReceipt, ProviderTx,
+    ReceiptProvider, TransactionsProvider,
 };
-use reth_rpc_eth_types::{utils::binary_search, EthApiError, SignError, TransactionSource};
+use reth_rpc_eth_types::{
+    utils::binary_search, EthApiError, SignError, TransactionSource, TxLookupConfig,
+    TxLookupFallback,
+};
+use reth_rpc_server_types::constants::MAX_TX_LOOKUP_SCAN_BLOCKS;
 use reth_rpc_types_compat::transaction::TransactionCompat;
 use reth_transaction_pool::{PoolTransaction, TransactionOrigin, TransactionPool};
 use std::sync::Arc;
@@ -176,22 +179,46 @@ pub trait EthTransactions: LoadTransaction<Provider: BlockReaderIdExt> {
         Self: 'static,
     {
         let provider = self.provider().clone();
-        self.spawn_blocking_io(move |_| {
-            let (tx, meta) = match provider
-                .transaction_by_hash_with_meta(hash)
-                .map_err(Self::Error::from_eth_err)?
-            {
-                Some((tx, meta)) => (tx, meta),
-                None => return Ok(None),
-            };
+        async move {
+            let resp = self
+                .spawn_blocking_io(move |_| {
+                    let (tx, meta) = match provider
+                        .transaction_by_hash_with_meta(hash)
+                        .map_err(Self::Error::from_eth_err)?
+                    {
+                        Some((tx, meta)) => (tx, meta),
+                        None => return Ok(None),
+                    };
 
-            let receipt = match provider.receipt_by_hash(hash).map_err(Self::Error::from_eth_err)? {
-                Some(recpt) => recpt,
-                None => return Ok(None),
+                    let receipt =
+                        match provider.receipt_by_hash(hash).map_err(Self::Error::from_eth_err)? {
+                            Some(recpt) => recpt,
+                            None => return Ok(None),
+                        };
+
+                    Ok(Some((tx, meta, receipt)))
+                })
+                .await?;
+            if resp.is_some() || self.pool().contains(&hash) {
+                return Ok(resp)
+            }
+
+            // the transaction may have been mined below the horizon of the transaction hash index
+            let Some((tx, meta)) = self.transaction_by_hash_unindexed(hash).await? else {
+                return Ok(None)
             };
+            let receipt = self
+                .spawn_blocking_io(move |this| {
+                    let receipts = this
+                        .provider()
+                        .receipts_by_block(meta.block_number.into())
+                        .map_err(Self::Error::from_eth_err)?;
+                    Ok(receipts.and_then(|receipts| receipts.into_iter().nth(meta.index as usize)))
+                })
+                .await?;
 
-            Ok(Some((tx, meta, receipt)))
-        })
+            Ok(receipt.map(|receipt| (tx, meta, receipt)))
+        }
     }
 
     /// Get transaction by [`BlockId`] and index of transaction within that block.
@@ -352,7 +379,7 @@ pub trait EthTransactions: LoadTransaction<Provider: BlockReaderIdExt> {
                 request.nonce = Some(nonce);
             }
 
-            let chain_id = self.chain_id();
+            let chain_id = self.execution_chain_id();
             request.chain_id = Some(chain_id.to());
 
             let estimated_gas =
@@ -436,6 +463,24 @@ pub trait EthTransactions: LoadTransaction<Provider: BlockReaderIdExt> {
             .into())
     }
 
+    /// Encodes and signs the typed data according EIP-712, waiting on signers that are backed by
+    /// a remote service or a device.
+    fn sign_typed_data_async(
+        &self,
+        data: &TypedData,
+        account: Address,
+    ) -> impl Future<Output = Result<Bytes, Self::Error>> + Send {
+        async move {
+            Ok(self
+                .find_signer(&account)?
+                .sign_typed_data_async(account, data)
+                .await
+                .map_err(Self::Error::from_eth_err)?
+                .as_bytes()
+                .into())
+        }
+    }
+
     /// Returns the signer for the given account, if found in configured signers.
     #[expect(clippy::type_complexity)]
     fn find_signer(
@@ -456,6 +501,13 @@ pub trait EthTransactions: LoadTransaction<Provider: BlockReaderIdExt> {
 /// Behaviour shared by several `eth_` RPC methods, not exclusive to `eth_` transactions RPC
 /// methods.
 pub trait LoadTransaction: SpawnBlocking + FullEthApiTypes + RpcNodeCoreExt {
+    /// Returns the configuration of lookups of transactions by hash.
+    ///
+    /// Defaults to no fallback for transactions that are not in the transaction hash index.
+    fn tx_lookup_config(&self) -> TxLookupConfig {
+        TxLookupConfig::default()
+    }
+
     /// Returns the transaction by hash.
     ///
     /// Checks the pool and state.
@@ -508,10 +560,92 @@ pub trait LoadTransaction: SpawnBlocking + FullEthApiTypes + RpcNodeCoreExt {
                 }
             }
 
+            if resp.is_none() {
+                // tx may have been mined below the horizon of the transaction hash index
+                if let Some((tx, meta)) = self.transaction_by_hash_unindexed(hash).await? {
+                    let transaction = tx
+                        .into_recovered_unchecked()
+                        .map_err(|_| EthApiError::InvalidTransactionSignature)?;
+                    resp = Some(TransactionSource::Block {
+                        transaction,
+                        index: meta.index,
+                        block_hash: meta.block_hash,
+                        block_number: meta.block_number,
+                        base_fee: meta.base_fee,
+                    });
+                }
+            }
+
             Ok(resp)
         }
     }
 
+    /// Looks up a mined transaction that is not in the transaction hash index, because the index
+    /// was pruned, according to the configured [`TxLookupFallback`].
+    ///
+    /// At most [`MAX_TX_LOOKUP_SCAN_BLOCKS`] blocks are scanned. Returns `Ok(None)` if the
+    /// fallback is disabled or the transaction was not found in the scanned blocks.
+    #[expect(clippy::type_complexity)]
+    fn transaction_by_hash_unindexed(
+        &self,
+        hash: B256,
+    ) -> impl Future<
+        Output = Result<Option<(ProviderTx<Self::Provider>, TransactionMeta)>, Self::Error>,
+    > + Send {
+        let config = self.tx_lookup_config();
+        async move {
+            if config.fallback == TxLookupFallback::Disabled {
+                return Ok(None)
+            }
+
+            self.spawn_blocking_io(move |this| {
+                let provider = this.provider();
+                let tip = provider.best_block_number().map_err(Self::Error::from_eth_err)?;
+                let lowest_indexed_block = config.lowest_indexed_block(tip);
+                if lowest_indexed_block == 0 {
+                    // all blocks are indexed
+                    return Ok(None)
+                }
+
+                // scan the blocks below the horizon, starting with the most recent one
+                let lowest_scanned_block = lowest_indexed_block
+                    .saturating_sub(config.max_scan_blocks.min(MAX_TX_LOOKUP_SCAN_BLOCKS));
+                for block_number in (lowest_scanned_block..lowest_indexed_block).rev() {
+                    let Some(transactions) = provider
+                        .transactions_by_block(block_number.into())
+                        .map_err(Self::Error::from_eth_err)?
+                    else {
+                        continue
+                    };
+                    let Some(index) = transactions.iter().position(|tx| *tx.tx_hash() == hash)
+                    else {
+                        continue
+                    };
+                    let Some(header) =
+                        provider.sealed_header(block_number).map_err(Self::Error::from_eth_err)?
+                    else {
+                        continue
+                    };
+
+                    let meta = TransactionMeta {
+                        tx_hash: hash,
+                        index: index as u64,
+                        block_hash: header.hash(),
+                        block_number,
+                        base_fee: header.base_fee_per_gas(),
+                        excess_blob_gas: header.excess_blob_gas(),
+                        timestamp: header.timestamp(),
+                    };
+                    let tx = transactions.into_iter().nth(index).expect("index is in bounds");
+                    return Ok(Some((tx, meta)))
+                }
+
+                Ok(None)
+            })
+            .await
+        }
+    }
+
     /// Returns the transaction by including its corresponding [`BlockId`].
     ///
     /// Note: this supports pending transactions
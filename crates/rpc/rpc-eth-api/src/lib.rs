@@ -23,10 +23,10 @@ pub mod types;
 
 pub use bundle::{EthBundleApiServer, EthCallBundleApiServer};
 pub use core::{EthApiServer, FullEthApiServer};
-pub use ext::L2EthApiExtServer;
+pub use ext::{EthBlockResponseApiServer, L2EthApiExtServer};
 pub use filter::EthFilterApiServer;
 pub use node::{RpcNodeCore, RpcNodeCoreExt};
-pub use pubsub::EthPubSubApiServer;
+pub use pubsub::{EthPubSubApiServer, EthPubSubExtApiServer};
 pub use reth_rpc_eth_types::error::{
     AsEthApiError, FromEthApiError, FromEvmError, IntoEthApiError,
 };
@@ -38,7 +38,7 @@ pub use bundle::{EthBundleApiClient, EthCallBundleApiClient};
 #[cfg(feature = "client")]
 pub use core::EthApiClient;
 #[cfg(feature = "client")]
-pub use ext::L2EthApiExtClient;
+pub use ext::{EthBlockResponseApiClient, L2EthApiExtClient};
 #[cfg(feature = "client")]
 pub use filter::EthFilterApiClient;
 
@@ -1,16 +1,21 @@
-//! Helper trait for interfacing with [`FullNodeComponents`].
+//! Helper trait for interfacing with `FullNodeComponents`.
 
+#[cfg(feature = "node-api")]
 use reth_node_api::{FullNodeComponents, NodeTypesWithEngine, PrimitivesTy};
+#[cfg(feature = "node-api")]
 use reth_payload_builder::PayloadBuilderHandle;
 use reth_provider::{BlockReader, ProviderBlock, ProviderReceipt};
 use reth_rpc_eth_types::EthStateCache;
 
-/// Helper trait to relax trait bounds on [`FullNodeComponents`].
+/// Helper trait to relax trait bounds on `FullNodeComponents`.
 ///
 /// Helpful when defining types that would otherwise have a generic `N: FullNodeComponents`. Using
-/// `N: RpcNodeCore` instead, allows access to all the associated types on [`FullNodeComponents`]
+/// `N: RpcNodeCore` instead, allows access to all the associated types on `FullNodeComponents`
 /// that are used in RPC, but with more flexibility since they have no trait bounds (asides auto
 /// traits).
+///
+/// This is implemented for all `FullNodeComponents` if the `node-api` feature is enabled.
+/// Without it, the RPC can be built over a standalone provider, without any node components.
 pub trait RpcNodeCore: Clone + Send + Sync {
     /// Blockchain data primitives.
     type Primitives: Send + Sync + Clone + Unpin;
@@ -42,6 +47,7 @@ pub trait RpcNodeCore: Clone + Send + Sync {
     fn provider(&self) -> &Self::Provider;
 }
 
+#[cfg(feature = "node-api")]
 impl<T> RpcNodeCore for T
 where
     T: FullNodeComponents,
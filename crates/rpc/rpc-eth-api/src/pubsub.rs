@@ -3,6 +3,7 @@
 use alloy_json_rpc::RpcObject;
 use alloy_rpc_types_eth::pubsub::{Params, SubscriptionKind};
 use jsonrpsee::proc_macros::rpc;
+use reth_rpc_eth_types::{EthSubscriptionKind, EthSubscriptionParams};
 
 /// Ethereum pub-sub rpc interface.
 #[rpc(server, namespace = "eth")]
@@ -19,3 +20,25 @@ pub trait EthPubSubApi<T: RpcObject> {
         params: Option<Params>,
     ) -> jsonrpsee::core::SubscriptionResult;
 }
+
+/// Ethereum pub-sub rpc interface that also supports reth-specific subscription kinds.
+///
+/// This is a superset of [`EthPubSubApi`] and registers the same methods, so only one of them can
+/// be installed.
+#[rpc(server, namespace = "eth")]
+pub trait EthPubSubExtApi<T: RpcObject> {
+    /// Create an ethereum subscription for the given params
+    ///
+    /// Besides the standard kinds, this supports the reth-specific `accounts` kind which streams
+    /// the changes of the given list of accounts per canonical block.
+    #[subscription(
+        name = "subscribe" => "subscription",
+        unsubscribe = "unsubscribe",
+        item = alloy_rpc_types::pubsub::SubscriptionResult
+    )]
+    async fn subscribe(
+        &self,
+        kind: EthSubscriptionKind,
+        params: Option<EthSubscriptionParams>,
+    ) -> jsonrpsee::core::SubscriptionResult;
+}
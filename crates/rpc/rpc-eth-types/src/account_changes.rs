@@ -0,0 +1,320 @@
+//! Helpers for the `accounts` subscription of `eth_subscribe`.
+//!
+//! The subscription streams the balance, nonce and storage changes of a set of watched accounts,
+//! computed from the per-block changesets of canonical state notifications.
+
+use alloy_primitives::{
+    map::{HashMap, HashSet},
+    Address, B256, U256,
+};
+use reth_execution_types::Chain;
+use reth_primitives_traits::NodePrimitives;
+use revm::state::AccountInfo;
+use revm_database::states::reverts::{AccountInfoRevert, AccountRevert};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The maximum number of accounts that a single `accounts` subscription can watch.
+pub const MAX_WATCHED_ACCOUNTS: usize = 1_000;
+
+/// The changes of watched accounts in a single block, as streamed by the `accounts` subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountChanges {
+    /// The number of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: u64,
+    /// The hash of the block.
+    pub block_hash: B256,
+    /// Whether the block was removed from the canonical chain by a reorg.
+    ///
+    /// The accounts then hold their state from before the block.
+    pub removed: bool,
+    /// The watched accounts changed by the block, ordered by address.
+    pub accounts: Vec<AccountChange>,
+}
+
+/// The state of a watched account after a block that changed it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountChange {
+    /// The address of the account.
+    pub address: Address,
+    /// The balance of the account, zero if the account doesn't exist.
+    pub balance: U256,
+    /// The nonce of the account, zero if the account doesn't exist.
+    #[serde(with = "alloy_serde::quantity")]
+    pub nonce: u64,
+    /// The storage slots changed by the block and their values.
+    pub storage: BTreeMap<B256, U256>,
+    /// Whether the block wiped the storage of the account, for example by destroying it.
+    ///
+    /// The slots that aren't listed in `storage` are then empty after the block. For a removed
+    /// block, the storage is restored to its state before the block instead, which may include
+    /// slots that aren't listed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub storage_wiped: bool,
+}
+
+/// Known state of a watched account while walking a chain backwards.
+#[derive(Debug)]
+struct WatchedAccount {
+    info: Option<AccountInfo>,
+    storage: HashMap<U256, U256>,
+}
+
+impl WatchedAccount {
+    /// Reverts the account to its state before the block of the given revert.
+    fn revert(&mut self, revert: &AccountRevert) {
+        match &revert.account {
+            AccountInfoRevert::DoNothing => {}
+            AccountInfoRevert::DeleteIt => self.info = None,
+            AccountInfoRevert::RevertTo(info) => self.info = Some(info.clone()),
+        }
+        // the storage before the block is restored from the slots of the revert only
+        if revert.wipe_storage {
+            self.storage.clear();
+        }
+        for (slot, previous) in &revert.storage {
+            self.storage.insert(*slot, previous.to_previous_value());
+        }
+    }
+
+    /// Returns the current state of the account and of the storage slots changed by the revert.
+    fn change(&self, address: Address, revert: &AccountRevert) -> AccountChange {
+        let info = self.info.as_ref();
+        AccountChange {
+            address,
+            balance: info.map(|info| info.balance).unwrap_or_default(),
+            nonce: info.map(|info| info.nonce).unwrap_or_default(),
+            storage: revert
+                .storage
+                .keys()
+                .map(|slot| {
+                    (B256::from(*slot), self.storage.get(slot).copied().unwrap_or_default())
+                })
+                .collect(),
+            storage_wiped: revert.wipe_storage,
+        }
+    }
+}
+
+/// Returns the changes of the watched accounts in all blocks of the chain that changed any of
+/// them.
+///
+/// For a committed chain the accounts hold their state after each block and the changes are
+/// ordered by ascending block number. For a `removed` chain the accounts hold their state before
+/// each block and the changes are ordered by descending block number, so that applying them in
+/// order leaves the accounts in their state at the fork block.
+pub fn chain_account_changes<N: NodePrimitives>(
+    chain: &Chain<N>,
+    watched: &HashSet<Address>,
+    removed: bool,
+) -> Vec<AccountChanges> {
+    let bundle = &chain.execution_outcome().bundle;
+
+    // the state of the watched accounts after the tip of the chain
+    let mut accounts = watched
+        .iter()
+        .filter_map(|address| {
+            let account = bundle.account(address)?;
+            let storage =
+                account.storage.iter().map(|(slot, value)| (*slot, value.present_value)).collect();
+            Some((*address, WatchedAccount { info: account.info.clone(), storage }))
+        })
+        .collect::<HashMap<_, _>>();
+    if accounts.is_empty() {
+        return Vec::new()
+    }
+
+    // walk the blocks backwards, reverting the accounts to their state before each block
+    let blocks = chain.blocks_iter().collect::<Vec<_>>();
+    let mut changes = Vec::new();
+    for (block, reverts) in blocks.iter().zip(bundle.reverts.iter()).rev() {
+        let mut changed = Vec::new();
+        for (address, revert) in reverts {
+            let Some(account) = accounts.get_mut(address) else { continue };
+            if removed {
+                account.revert(revert);
+                changed.push(account.change(*address, revert));
+            } else {
+                changed.push(account.change(*address, revert));
+                account.revert(revert);
+            }
+        }
+
+        if !changed.is_empty() {
+            changed.sort_unstable_by_key(|change| change.address);
+            let block = block.num_hash();
+            changes.push(AccountChanges {
+                block_number: block.number,
+                block_hash: block.hash,
+                removed,
+                accounts: changed,
+            });
+        }
+    }
+
+    if !removed {
+        changes.reverse();
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_execution_types::ExecutionOutcome;
+    use reth_primitives::RecoveredBlock;
+    use revm_database::BundleState;
+
+    fn account(balance: u64, nonce: u64) -> AccountInfo {
+        AccountInfo { balance: U256::from(balance), nonce, ..Default::default() }
+    }
+
+    fn block(number: u64) -> RecoveredBlock<reth_primitives::Block> {
+        let mut block = RecoveredBlock::<reth_primitives::Block>::default();
+        block.set_block_number(number);
+        block.set_hash(B256::with_last_byte(number as u8));
+        block
+    }
+
+    /// Watched account `a` is funded in block 1 and spends in block 3, `b` changes a storage slot
+    /// in block 2, unwatched `c` changes in every block.
+    fn chain() -> (Chain, Address, Address) {
+        let a = Address::with_last_byte(1);
+        let b = Address::with_last_byte(2);
+        let c = Address::with_last_byte(3);
+        let slot = U256::from(7);
+
+        let bundle = BundleState::new(
+            [
+                (a, None, Some(account(90, 1)), Default::default()),
+                (
+                    b,
+                    Some(account(5, 0)),
+                    Some(account(5, 0)),
+                    HashMap::from_iter([(slot, (U256::from(1), U256::from(2)))]),
+                ),
+                (c, None, Some(account(3, 0)), Default::default()),
+            ],
+            [
+                vec![(a, Some(None), vec![]), (c, Some(None), vec![])],
+                vec![
+                    (b, None, vec![(slot, U256::from(1))]),
+                    (c, Some(Some(account(1, 0))), vec![]),
+                ],
+                vec![
+                    (a, Some(Some(account(100, 0))), vec![]),
+                    (c, Some(Some(account(2, 0))), vec![]),
+                ],
+            ],
+            [],
+        );
+        let outcome = ExecutionOutcome::new(bundle, vec![vec![]; 3], 1, vec![]);
+        (Chain::new([block(1), block(2), block(3)], outcome, None), a, b)
+    }
+
+    #[test]
+    fn committed_changes() {
+        let (chain, a, b) = chain();
+        let watched = HashSet::from_iter([a, b]);
+
+        let changes = chain_account_changes(&chain, &watched, false);
+        let summary = changes
+            .iter()
+            .map(|change| {
+                let accounts = change
+                    .accounts
+                    .iter()
+                    .map(|account| (account.address, account.balance.to::<u64>(), account.nonce))
+                    .collect::<Vec<_>>();
+                (change.block_number, accounts)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![(1, vec![(a, 100, 0)]), (2, vec![(b, 5, 0)]), (3, vec![(a, 90, 1)])]
+        );
+        assert_eq!(
+            changes[1].accounts[0].storage,
+            BTreeMap::from([(B256::with_last_byte(7), U256::from(2))])
+        );
+        assert!(changes.iter().all(|change| !change.removed));
+    }
+
+    #[test]
+    fn removed_changes() {
+        let (chain, a, b) = chain();
+        let watched = HashSet::from_iter([a, b]);
+
+        let changes = chain_account_changes(&chain, &watched, true);
+        assert_eq!(
+            changes.iter().map(|change| change.block_number).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        assert!(changes.iter().all(|change| change.removed));
+        // state before block 3
+        assert_eq!(changes[0].accounts[0].balance, U256::from(100));
+        // slot before block 2
+        assert_eq!(
+            changes[1].accounts[0].storage,
+            BTreeMap::from([(B256::with_last_byte(7), U256::from(1))])
+        );
+        // account didn't exist before block 1
+        assert_eq!(changes[2].accounts[0].balance, U256::ZERO);
+    }
+
+    #[test]
+    fn wiped_storage() {
+        let a = Address::with_last_byte(1);
+        let (x, z) = (U256::from(1), U256::from(2));
+
+        // `x` is set in block 1, the account is destroyed in block 2 and `z` is set in block 3
+        let mut bundle = BundleState::new(
+            [(
+                a,
+                Some(account(1, 0)),
+                Some(account(0, 0)),
+                HashMap::from_iter([
+                    (x, (U256::ZERO, U256::ZERO)),
+                    (z, (U256::ZERO, U256::from(9))),
+                ]),
+            )],
+            [
+                vec![(a, None, vec![(x, U256::ZERO)])],
+                vec![(a, Some(Some(account(1, 0))), vec![(x, U256::from(1))])],
+                vec![(a, Some(None), vec![(z, U256::ZERO)])],
+            ],
+            [],
+        );
+        for (_, revert) in bundle.reverts[1].iter_mut() {
+            revert.wipe_storage = true;
+        }
+        let outcome = ExecutionOutcome::new(bundle, vec![vec![]; 3], 1, vec![]);
+        let chain = Chain::new([block(1), block(2), block(3)], outcome, None);
+        let watched = HashSet::from_iter([a]);
+
+        let changes = chain_account_changes(&chain, &watched, false);
+        let wiped =
+            changes.iter().map(|change| change.accounts[0].storage_wiped).collect::<Vec<_>>();
+        assert_eq!(wiped, vec![false, true, false]);
+        assert_eq!(changes[1].accounts[0].storage, BTreeMap::from([(B256::from(x), U256::ZERO)]));
+
+        let changes = chain_account_changes(&chain, &watched, true);
+        // the slot of block 1 is restored when block 2 is removed
+        assert!(changes[1].accounts[0].storage_wiped);
+        assert_eq!(
+            changes[1].accounts[0].storage,
+            BTreeMap::from([(B256::from(x), U256::from(1))])
+        );
+        assert_eq!(changes[2].accounts[0].storage, BTreeMap::from([(B256::from(x), U256::ZERO)]));
+    }
+
+    #[test]
+    fn unwatched_accounts_are_ignored() {
+        let (chain, _, _) = chain();
+        let watched = HashSet::from_iter([Address::with_last_byte(4)]);
+        assert!(chain_account_changes(&chain, &watched, false).is_empty());
+    }
+}
@@ -0,0 +1,97 @@
+//! Shaping of block responses.
+
+use alloy_primitives::Address;
+use alloy_rpc_types_eth::{Block, BlockTransactions};
+use serde::{Deserialize, Serialize};
+
+/// Options to omit heavy fields from the blocks returned by `eth_getBlockByNumber`.
+///
+/// This is an extension of the `eth_` namespace, for consumers that are only interested in the
+/// header fields of a block.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BlockResponseOptions {
+    /// Returns an empty transactions list instead of the transaction hashes or objects.
+    pub omit_transactions: bool,
+    /// Omits the withdrawals.
+    pub omit_withdrawals: bool,
+    /// Returns an empty uncles list instead of the uncle hashes.
+    pub omit_uncles: bool,
+}
+
+impl BlockResponseOptions {
+    /// Removes the omitted fields from the block.
+    pub fn apply<T, H>(&self, block: &mut Block<T, H>) {
+        if self.omit_transactions {
+            block.transactions = BlockTransactions::Hashes(Vec::new());
+        }
+        if self.omit_withdrawals {
+            block.withdrawals = None;
+        }
+        if self.omit_uncles {
+            block.uncles = Vec::new();
+        }
+    }
+}
+
+/// A canonical block together with its receipts and the senders of its transactions, as streamed
+/// by `reth_newBlockWithReceipts`.
+///
+/// The block and receipts are the RPC objects of the network, as returned by
+/// `eth_getBlockByHash` with full transactions and `eth_getBlockReceipts`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockWithReceipts<B, R> {
+    /// The block with its full transactions.
+    pub block: B,
+    /// The receipts of the block's transactions, in the order of the transactions.
+    pub receipts: Vec<R>,
+    /// The recovered senders of the block's transactions, in the order of the transactions.
+    pub senders: Vec<Address>,
+    /// Whether the block was removed from the canonical chain by a reorg, after it was streamed
+    /// as canonical.
+    #[serde(default)]
+    pub removed: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rpc_types_eth::Header;
+
+    #[test]
+    fn omits_fields() {
+        let options: BlockResponseOptions =
+            serde_json::from_str(r#"{"omitTransactions":true,"omitWithdrawals":true}"#).unwrap();
+        assert!(!options.omit_uncles);
+
+        let mut block = Block::<(), Header> {
+            transactions: BlockTransactions::Full(vec![(), ()]),
+            withdrawals: Some(Default::default()),
+            ..Default::default()
+        };
+        options.apply(&mut block);
+
+        let json = serde_json::to_value(&block).unwrap();
+        assert_eq!(json["transactions"], serde_json::json!([]));
+        assert!(json.get("withdrawals").is_none());
+    }
+
+    #[test]
+    fn block_with_receipts_roundtrip() {
+        let item = BlockWithReceipts {
+            block: serde_json::json!({"hash": "0x01"}),
+            receipts: vec![serde_json::json!({"status": "0x1"})],
+            senders: vec![Address::with_last_byte(1)],
+            removed: true,
+        };
+
+        let json = serde_json::to_value(&item).unwrap();
+        assert_eq!(json["senders"], serde_json::json!([Address::with_last_byte(1)]));
+        assert_eq!(json["removed"], serde_json::json!(true));
+
+        let decoded: BlockWithReceipts<serde_json::Value, serde_json::Value> =
+            serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, item);
+    }
+}
@@ -0,0 +1,42 @@
+//! Types for compact per-block summaries.
+
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+
+/// Result of `reth_getBlockSummaries` for a single block: the header fields and transaction count
+/// that block explorers chart, without the transactions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockSummary {
+    /// The number of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub number: u64,
+    /// The hash of the block.
+    pub hash: B256,
+    /// The timestamp of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub timestamp: u64,
+    /// The fee recipient of the block.
+    pub miner: Address,
+    /// The number of transactions in the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub transaction_count: u64,
+    /// The gas used by all transactions of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+    /// The gas limit of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_limit: u64,
+    /// The base fee of the block, if London is active.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub base_fee_per_gas: Option<u64>,
+    /// The blob gas used by the block, if Cancun is active.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub blob_gas_used: Option<u64>,
+    /// The excess blob gas of the block, if Cancun is active.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub excess_blob_gas: Option<u64>,
+    /// The size of the RLP encoded block in bytes, only set if requested.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub size: Option<u64>,
+}
@@ -3,8 +3,11 @@
 use std::time::Duration;
 
 use crate::{
-    EthStateCacheConfig, FeeHistoryCacheConfig, GasPriceOracleConfig, RPC_DEFAULT_GAS_CAP,
+    EthStateCacheConfig, FeeHistoryCacheConfig, GasPriceOracleConfig, TxLookupConfig,
+    RPC_DEFAULT_GAS_CAP,
 };
+use reth_chainspec::DEFAULT_MAX_REORG_DEPTH;
+use reth_evm::CallEnvOverrides;
 use reth_rpc_server_types::constants::{
     default_max_tracing_requests, DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_BLOCKS_PER_FILTER,
     DEFAULT_MAX_LOGS_PER_RESPONSE, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_PROOF_PERMITS,
@@ -35,6 +38,8 @@ pub struct EthConfig {
     pub rpc_gas_cap: u64,
     /// Max number of blocks for `eth_simulateV1`.
     pub rpc_max_simulate_blocks: u64,
+    /// Adjustments of the EVM environment for `eth_call` and `eth_estimateGas`.
+    pub call_env_overrides: CallEnvOverrides,
     ///
     /// Sets TTL for stale filters
     pub stale_filter_ttl: Duration,
@@ -42,6 +47,16 @@ pub struct EthConfig {
     pub fee_history_cache: FeeHistoryCacheConfig,
     /// The maximum number of getproof calls that can be executed concurrently.
     pub proof_permits: usize,
+    /// Settings for lookups of transactions by hash
+    pub tx_lookup: TxLookupConfig,
+    /// Number of blocks below the tip after which a block is assumed to be final.
+    ///
+    /// This is shared by the filters and the caching layer.
+    pub max_reorg_depth: u64,
+    /// Chain id reported by `eth_chainId` and `net_version` instead of the execution chain id.
+    pub rpc_chain_id: Option<u64>,
+    /// Whether `eth_syncingDetail` includes the detail of the stage the pipeline is running.
+    pub syncing_stage_detail: bool,
 }
 
 impl EthConfig {
@@ -51,6 +66,7 @@ impl EthConfig {
             .max_blocks_per_filter(self.max_blocks_per_filter)
             .max_logs_per_response(self.max_logs_per_response)
             .stale_filter_ttl(self.stale_filter_ttl)
+            .max_reorg_depth(self.max_reorg_depth)
     }
 }
 
@@ -65,9 +81,14 @@ impl Default for EthConfig {
             max_logs_per_response: DEFAULT_MAX_LOGS_PER_RESPONSE,
             rpc_gas_cap: RPC_DEFAULT_GAS_CAP.into(),
             rpc_max_simulate_blocks: DEFAULT_MAX_SIMULATE_BLOCKS,
+            call_env_overrides: CallEnvOverrides::new(),
             stale_filter_ttl: DEFAULT_STALE_FILTER_TTL,
             fee_history_cache: FeeHistoryCacheConfig::default(),
             proof_permits: DEFAULT_PROOF_PERMITS,
+            tx_lookup: TxLookupConfig::default(),
+            max_reorg_depth: DEFAULT_MAX_REORG_DEPTH,
+            rpc_chain_id: None,
+            syncing_stage_detail: false,
         }
     }
 }
@@ -115,6 +136,12 @@ impl EthConfig {
         self
     }
 
+    /// Configures the adjustments of the EVM environment for `eth_call` and `eth_estimateGas`
+    pub const fn call_env_overrides(mut self, call_env_overrides: CallEnvOverrides) -> Self {
+        self.call_env_overrides = call_env_overrides;
+        self
+    }
+
     /// Configures the maximum proof window for historical proof generation.
     pub const fn eth_proof_window(mut self, window: u64) -> Self {
         self.eth_proof_window = window;
@@ -126,6 +153,33 @@ impl EthConfig {
         self.proof_permits = permits;
         self
     }
+
+    /// Configures the lookups of transactions by hash
+    pub const fn tx_lookup(mut self, tx_lookup: TxLookupConfig) -> Self {
+        self.tx_lookup = tx_lookup;
+        self
+    }
+
+    /// Configures the number of blocks after which a block is assumed to be final, for both the
+    /// filters and the caching layer
+    pub const fn max_reorg_depth(mut self, max_reorg_depth: u64) -> Self {
+        self.max_reorg_depth = max_reorg_depth;
+        self.cache.max_reorg_depth = max_reorg_depth;
+        self
+    }
+
+    /// Configures the chain id reported by RPC instead of the execution chain id.
+    pub const fn rpc_chain_id(mut self, rpc_chain_id: Option<u64>) -> Self {
+        self.rpc_chain_id = rpc_chain_id;
+        self
+    }
+
+    /// Configures whether `eth_syncingDetail` includes the detail of the stage the pipeline is
+    /// running.
+    pub const fn syncing_stage_detail(mut self, syncing_stage_detail: bool) -> Self {
+        self.syncing_stage_detail = syncing_stage_detail;
+        self
+    }
 }
 
 /// Config for the filter
@@ -144,6 +198,11 @@ pub struct EthFilterConfig {
     /// A filter is considered stale if it has not been polled for longer than this duration and
     /// will be removed.
     pub stale_filter_ttl: Duration,
+    /// Number of blocks below the tip after which a block is assumed to be final.
+    ///
+    /// Logs of the most recent blocks are derived from the cached block itself, unless the blocks
+    /// are already final.
+    pub max_reorg_depth: u64,
 }
 
 impl EthFilterConfig {
@@ -165,6 +224,12 @@ impl EthFilterConfig {
         self.stale_filter_ttl = duration;
         self
     }
+
+    /// Sets the number of blocks below the tip after which a block is assumed to be final.
+    pub const fn max_reorg_depth(mut self, max_reorg_depth: u64) -> Self {
+        self.max_reorg_depth = max_reorg_depth;
+        self
+    }
 }
 
 impl Default for EthFilterConfig {
@@ -174,6 +239,7 @@ impl Default for EthFilterConfig {
             max_logs_per_response: None,
             // 5min
             stale_filter_ttl: Duration::from_secs(5 * 60),
+            max_reorg_depth: DEFAULT_MAX_REORG_DEPTH,
         }
     }
 }
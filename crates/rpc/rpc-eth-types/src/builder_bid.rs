@@ -0,0 +1,123 @@
+//! Types of the builder API, see the [builder-specs](https://ethereum.github.io/builder-specs/).
+
+use alloy_eips::eip4844::Bytes48;
+use alloy_primitives::{Address, Bloom, Bytes, B256, U256};
+use alloy_rpc_types_beacon::{
+    payload::{beacon_payload_v1, beacon_payload_v2, beacon_payload_v3},
+    requests::ExecutionRequestsV4,
+    BlsPublicKey, BlsSignature,
+};
+use alloy_rpc_types_engine::{
+    BlobsBundleV1, ExecutionPayloadV1, ExecutionPayloadV2, ExecutionPayloadV3,
+};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+/// A versioned response of the builder API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuilderApiResponse<T> {
+    /// Name of the consensus layer fork the data belongs to.
+    pub version: String,
+    /// The response data.
+    pub data: T,
+}
+
+/// A signed bid of the builder API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedBuilderBid {
+    /// The bid.
+    pub message: BuilderBid,
+    /// Signature of the bid.
+    pub signature: BlsSignature,
+}
+
+/// A bid of the builder API.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BuilderBid {
+    /// Header of the payload.
+    pub header: ExecutionPayloadHeader,
+    /// Commitments of the blobs of the payload, since Deneb.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob_kzg_commitments: Option<Vec<Bytes48>>,
+    /// Execution layer requests of the payload, since Electra.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_requests: Option<ExecutionRequestsV4>,
+    /// Value of the payload paid to the proposer, in wei.
+    #[serde_as(as = "DisplayFromStr")]
+    pub value: U256,
+    /// Public key of the builder.
+    pub pubkey: BlsPublicKey,
+}
+
+/// Header of an execution payload, in the format of the consensus layer.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionPayloadHeader {
+    /// Hash of the parent block.
+    pub parent_hash: B256,
+    /// Recipient of the fees of the block.
+    pub fee_recipient: Address,
+    /// State root after the block.
+    pub state_root: B256,
+    /// Root of the receipts of the block.
+    pub receipts_root: B256,
+    /// Bloom filter of the logs of the block.
+    pub logs_bloom: Bloom,
+    /// Randomness of the block.
+    pub prev_randao: B256,
+    /// Number of the block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub block_number: u64,
+    /// Gas limit of the block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_limit: u64,
+    /// Gas used by the block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_used: u64,
+    /// Timestamp of the block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub timestamp: u64,
+    /// Extra data of the block.
+    pub extra_data: Bytes,
+    /// Base fee of the block.
+    #[serde_as(as = "DisplayFromStr")]
+    pub base_fee_per_gas: U256,
+    /// Hash of the block.
+    pub block_hash: B256,
+    /// Root of the transactions of the block.
+    pub transactions_root: B256,
+    /// Root of the withdrawals of the block, since Capella.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub withdrawals_root: Option<B256>,
+    /// Blob gas used by the block, since Deneb.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blob_gas_used: Option<u64>,
+    /// Excess blob gas of the block, since Deneb.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excess_blob_gas: Option<u64>,
+}
+
+/// The full payload of a bid, in the format of the consensus layer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BuilderPayload {
+    /// Payload with blobs bundle, since Deneb.
+    V3(ExecutionPayloadAndBlobsBundle),
+    /// Capella payload.
+    V2(#[serde(with = "beacon_payload_v2")] ExecutionPayloadV2),
+    /// Bellatrix payload.
+    V1(#[serde(with = "beacon_payload_v1")] ExecutionPayloadV1),
+}
+
+/// An execution payload together with the blobs of its transactions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionPayloadAndBlobsBundle {
+    /// The execution payload.
+    #[serde(with = "beacon_payload_v3")]
+    pub execution_payload: ExecutionPayloadV3,
+    /// The blobs, commitments and proofs of the payload.
+    pub blobs_bundle: BlobsBundleV1,
+}
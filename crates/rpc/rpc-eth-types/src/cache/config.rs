@@ -2,6 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use reth_chainspec::DEFAULT_MAX_REORG_DEPTH;
 use reth_rpc_server_types::constants::cache::{
     DEFAULT_BLOCK_CACHE_MAX_LEN, DEFAULT_CONCURRENT_DB_REQUESTS, DEFAULT_HEADER_CACHE_MAX_LEN,
     DEFAULT_RECEIPT_CACHE_MAX_LEN,
@@ -27,6 +28,12 @@ pub struct EthStateCacheConfig {
     ///
     /// Default is 512.
     pub max_concurrent_db_requests: usize,
+    /// Number of blocks below the tip after which a block is assumed to be final.
+    ///
+    /// The whole cache is cleared on deeper reorgs.
+    ///
+    /// Default is 64.
+    pub max_reorg_depth: u64,
 }
 
 impl Default for EthStateCacheConfig {
@@ -36,6 +43,7 @@ impl Default for EthStateCacheConfig {
             max_receipts: DEFAULT_RECEIPT_CACHE_MAX_LEN,
             max_headers: DEFAULT_HEADER_CACHE_MAX_LEN,
             max_concurrent_db_requests: DEFAULT_CONCURRENT_DB_REQUESTS,
+            max_reorg_depth: DEFAULT_MAX_REORG_DEPTH,
         }
     }
 }
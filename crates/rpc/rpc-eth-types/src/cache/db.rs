@@ -157,6 +157,21 @@ impl StateProvider for StateProviderTraitObjWrapper<'_> {
         self.0.storage(account, storage_key)
     }
 
+    fn storage_multi(
+        &self,
+        account: revm_primitives::Address,
+        storage_keys: &[alloy_primitives::StorageKey],
+    ) -> reth_errors::ProviderResult<Vec<Option<alloy_primitives::StorageValue>>> {
+        self.0.storage_multi(account, storage_keys)
+    }
+
+    fn basic_accounts(
+        &self,
+        addresses: &[revm_primitives::Address],
+    ) -> reth_errors::ProviderResult<Vec<Option<reth_primitives::Account>>> {
+        self.0.basic_accounts(addresses)
+    }
+
     fn bytecode_by_hash(
         &self,
         code_hash: &B256,
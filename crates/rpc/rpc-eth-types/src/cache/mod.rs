@@ -23,6 +23,7 @@ use tokio::sync::{
     oneshot, Semaphore,
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::warn;
 
 pub mod config;
 pub mod db;
@@ -81,6 +82,7 @@ impl<B: Block, R: Send + Sync> EthStateCache<B, R> {
         max_receipts: u32,
         max_headers: u32,
         max_concurrent_db_operations: usize,
+        max_reorg_depth: u64,
     ) -> (Self, EthStateCacheService<Provider, Tasks>)
     where
         Provider: BlockReader<Block = B, Receipt = R>,
@@ -95,6 +97,7 @@ impl<B: Block, R: Send + Sync> EthStateCache<B, R> {
             action_rx: UnboundedReceiverStream::new(rx),
             action_task_spawner,
             rate_limiter: Arc::new(Semaphore::new(max_concurrent_db_operations)),
+            max_reorg_depth,
         };
         let cache = Self { to_service };
         (cache, service)
@@ -129,6 +132,7 @@ impl<B: Block, R: Send + Sync> EthStateCache<B, R> {
             max_receipts,
             max_headers,
             max_concurrent_db_requests,
+            max_reorg_depth,
         } = config;
         let (this, service) = Self::create(
             provider,
@@ -137,6 +141,7 @@ impl<B: Block, R: Send + Sync> EthStateCache<B, R> {
             max_receipts,
             max_headers,
             max_concurrent_db_requests,
+            max_reorg_depth,
         );
         executor.spawn_critical("eth state cache", Box::pin(service));
         this
@@ -236,6 +241,10 @@ pub(crate) struct EthStateCacheService<
     ///
     /// This restricts the max concurrent fetch tasks at the same time.
     rate_limiter: Arc<Semaphore>,
+    /// Number of blocks below the tip after which a block is assumed to be final.
+    ///
+    /// The whole cache is cleared on deeper reorgs.
+    max_reorg_depth: u64,
 }
 
 impl<Provider, Tasks> EthStateCacheService<Provider, Tasks>
@@ -489,6 +498,16 @@ where
                             }
                         }
                         CacheAction::RemoveReorgedChain { chain_change } => {
+                            let depth = chain_change.blocks.len() as u64;
+                            if depth > this.max_reorg_depth {
+                                // blocks that were assumed to be final were reorged out, so start
+                                // over with an empty cache
+                                warn!(target: "rpc::eth::cache", depth, max_reorg_depth = this.max_reorg_depth, "Clearing cache after reorg deeper than the max reorg depth");
+                                this.full_block_cache.clear();
+                                this.receipts_cache.clear();
+                                this.headers_cache.clear();
+                            }
+
                             for block in chain_change.blocks {
                                 this.on_reorg_block(block.hash(), Ok(Some(block)));
                             }
@@ -107,6 +107,12 @@ where
         }
     }
 
+    /// Removes all cached elements, but keeps the queued consumers.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.memory_usage = 0;
+    }
+
     /// Shrinks the capacity of the queue with a lower limit.
     #[inline]
     pub fn shrink_to(&mut self, min_capacity: usize) {
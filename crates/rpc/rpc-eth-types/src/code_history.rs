@@ -0,0 +1,35 @@
+//! Types for the code history of accounts.
+
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+
+/// A single code change of an account, as returned by `reth_getCodeHistory`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CodeChange {
+    /// The number of the block that changed the code.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: u64,
+    /// The kind of the change.
+    pub kind: CodeChangeKind,
+    /// The code hash before the block, `None` if the account had no code.
+    pub previous_code_hash: Option<B256>,
+    /// The code hash after the block, `None` if the account has no code.
+    pub code_hash: Option<B256>,
+}
+
+/// The kind of a [`CodeChange`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CodeChangeKind {
+    /// A contract was deployed to an account without code.
+    Deployed,
+    /// The code of the account was removed, e.g. by a `SELFDESTRUCT`.
+    Destroyed,
+    /// A contract was deployed to an account that had different code before the block.
+    Redeployed,
+    /// An EIP-7702 delegation was set or changed.
+    Delegated,
+    /// An EIP-7702 delegation was cleared.
+    DelegationCleared,
+}
@@ -1,17 +1,20 @@
 //! Implementation specific Errors for the `eth_` namespace.
 
 pub mod api;
-use crate::error::api::FromEvmHalt;
-use alloy_eips::BlockId;
-use alloy_primitives::{Address, Bytes, U256};
+use crate::{error::api::FromEvmHalt, memory_budget::MemoryBudgetExceeded};
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_primitives::{Address, BlockNumber, Bytes, U256};
 use alloy_rpc_types_eth::{error::EthRpcErrorCode, request::TransactionInputError, BlockError};
 use alloy_sol_types::{ContractError, RevertReason};
 pub use api::{AsEthApiError, FromEthApiError, FromEvmError, IntoEthApiError};
 use core::time::Duration;
 use reth_errors::{BlockExecutionError, RethError};
 use reth_primitives_traits::transaction::signed::RecoveryError;
-use reth_rpc_server_types::result::{
-    block_id_to_str, internal_rpc_err, invalid_params_rpc_err, rpc_err, rpc_error_with_code,
+use reth_rpc_server_types::{
+    error_codes::{reth_rpc_err, RethRpcErrorCode},
+    result::{
+        block_id_to_str, internal_rpc_err, invalid_params_rpc_err, rpc_err, rpc_error_with_code,
+    },
 };
 use reth_transaction_pool::error::{
     Eip4844PoolTransactionError, Eip7702PoolTransactionError, InvalidPoolTransactionError,
@@ -65,6 +68,9 @@ pub enum EthApiError {
     /// Thrown when an unknown block or transaction index is encountered
     #[error("unknown block or tx index")]
     UnknownBlockOrTxIndex,
+    /// Thrown when the state of the requested block was pruned
+    #[error("state at block #{0} is pruned")]
+    StateAtBlockPruned(BlockNumber),
     /// When an invalid block range is provided
     #[error("invalid block range")]
     InvalidBlockRange,
@@ -123,6 +129,9 @@ pub enum EthApiError {
     /// Error thrown when a (tracing) call exceeds the configured timeout
     #[error("execution aborted (timeout = {0:?})")]
     ExecutionTimedOut(Duration),
+    /// Error thrown when a call exceeds its memory budget
+    #[error(transparent)]
+    MemoryBudgetExceeded(#[from] MemoryBudgetExceeded),
     /// Internal Error thrown by the javascript tracer
     #[error("{0}")]
     InternalJsTracerError(String),
@@ -201,14 +210,16 @@ impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
             // on the error message.
             //
             // Until https://github.com/ethereum-optimism/optimism/pull/11759 is released, this must be kept around.
-            EthApiError::HeaderNotFound(id) => rpc_error_with_code(
-                EthRpcErrorCode::ResourceNotFound.code(),
+            EthApiError::HeaderNotFound(id) => reth_rpc_err(
+                block_not_found_code(&id),
                 format!("block not found: {}", block_id_to_str(id)),
             ),
-            EthApiError::ReceiptsNotFound(id) => rpc_error_with_code(
-                EthRpcErrorCode::ResourceNotFound.code(),
-                format!("{error}: {}", block_id_to_str(id)),
-            ),
+            EthApiError::ReceiptsNotFound(id) => {
+                reth_rpc_err(block_not_found_code(&id), format!("{error}: {}", block_id_to_str(id)))
+            }
+            err @ EthApiError::StateAtBlockPruned(_) => {
+                reth_rpc_err(RethRpcErrorCode::PrunedState, err.to_string())
+            }
             EthApiError::HeaderRangeNotFound(start_id, end_id) => rpc_error_with_code(
                 EthRpcErrorCode::ResourceNotFound.code(),
                 format!(
@@ -220,10 +231,12 @@ impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
             EthApiError::Unsupported(msg) => internal_rpc_err(msg),
             EthApiError::InternalJsTracerError(msg) => internal_rpc_err(msg),
             EthApiError::InvalidParams(msg) => invalid_params_rpc_err(msg),
-            err @ EthApiError::ExecutionTimedOut(_) => rpc_error_with_code(
-                jsonrpsee_types::error::CALL_EXECUTION_FAILED_CODE,
-                err.to_string(),
-            ),
+            err @ EthApiError::ExecutionTimedOut(_) => {
+                reth_rpc_err(RethRpcErrorCode::ExecutionTimeout, err.to_string())
+            }
+            err @ EthApiError::MemoryBudgetExceeded(_) => {
+                reth_rpc_err(RethRpcErrorCode::MemoryBudgetExceeded, err.to_string())
+            }
             err @ (EthApiError::InternalBlockingTaskError | EthApiError::InternalEthError) => {
                 internal_rpc_err(err.to_string())
             }
@@ -234,6 +247,19 @@ impl From<EthApiError> for jsonrpsee_types::error::ErrorObject<'static> {
     }
 }
 
+/// Returns the error code of a block that is not available.
+///
+/// Blocks requested by number or tag that are not available were not synced yet, while blocks
+/// requested by hash are unknown.
+const fn block_not_found_code(id: &BlockId) -> RethRpcErrorCode {
+    match id {
+        BlockId::Hash(_) | BlockId::Number(BlockNumberOrTag::Earliest) => {
+            RethRpcErrorCode::BlockNotFound
+        }
+        BlockId::Number(_) => RethRpcErrorCode::NotYetSynced,
+    }
+}
+
 #[cfg(feature = "js-tracer")]
 impl From<revm_inspectors::tracing::js::JsInspectorError> for EthApiError {
     fn from(error: revm_inspectors::tracing::js::JsInspectorError) -> Self {
@@ -274,6 +300,7 @@ impl From<reth_errors::ProviderError> for EthApiError {
             ProviderError::TotalDifficultyNotFound(num) => Self::HeaderNotFound(num.into()),
             ProviderError::FinalizedBlockNotFound => Self::HeaderNotFound(BlockId::finalized()),
             ProviderError::SafeBlockNotFound => Self::HeaderNotFound(BlockId::safe()),
+            ProviderError::StateAtBlockPruned(num) => Self::StateAtBlockPruned(num),
             err => Self::Internal(err.into()),
         }
     }
@@ -791,6 +818,15 @@ pub enum SignError {
     /// No chain ID was given.
     #[error("no chainid")]
     NoChainId,
+    /// The request is not allowed by the signing policy of the account.
+    #[error("rejected by signer policy: {0}")]
+    PolicyViolation(String),
+    /// The remote signer returned an error.
+    #[error("remote signer error: {0}")]
+    Remote(String),
+    /// The hardware wallet returned an error.
+    #[error("hardware wallet error: {0}")]
+    HardwareWallet(String),
 }
 
 /// Converts the evm [`ExecutionResult`] into a result where `Ok` variant is the output bytes if it
@@ -810,6 +846,7 @@ pub fn ensure_success<Halt, Error: FromEvmHalt<Halt> + FromEthApiError>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloy_primitives::B256;
     use alloy_sol_types::{Revert, SolError};
     use revm_primitives::b256;
 
@@ -847,6 +884,17 @@ mod tests {
         assert_eq!(err.message(), "block not found: finalized");
     }
 
+    #[test]
+    fn block_not_found_error_codes() {
+        assert_eq!(
+            block_not_found_code(&BlockId::hash(B256::ZERO)),
+            RethRpcErrorCode::BlockNotFound
+        );
+        assert_eq!(block_not_found_code(&BlockId::number(100000)), RethRpcErrorCode::NotYetSynced);
+        assert_eq!(block_not_found_code(&BlockId::latest()), RethRpcErrorCode::NotYetSynced);
+        assert_eq!(block_not_found_code(&BlockId::earliest()), RethRpcErrorCode::BlockNotFound);
+    }
+
     #[test]
     fn revert_err_display() {
         let revert = Revert::from("test_revert_reason");
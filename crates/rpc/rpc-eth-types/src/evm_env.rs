@@ -0,0 +1,68 @@
+//! Types for inspecting the EVM environment of a block.
+
+use alloy_eips::BlockId;
+use alloy_primitives::{Address, B256, U256};
+use reth_evm::EvmEnv;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// Result of `reth_getEvmEnv`: the configuration and block environment the node executes the
+/// transactions of a block with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockEvmEnv {
+    /// The block the environment was configured for: the hash of the block, or the `pending` tag.
+    pub block_id: BlockId,
+    /// The active spec id, e.g. `CANCUN`.
+    pub spec_id: String,
+    /// The chain id.
+    #[serde(with = "alloy_serde::quantity")]
+    pub chain_id: u64,
+    /// The number of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub number: u64,
+    /// The timestamp of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub timestamp: u64,
+    /// The fee recipient of the block.
+    pub beneficiary: Address,
+    /// The gas limit of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_limit: u64,
+    /// The base fee of the block, zero before London.
+    #[serde(with = "alloy_serde::quantity")]
+    pub base_fee: u64,
+    /// The difficulty of the block, zero after the merge.
+    pub difficulty: U256,
+    /// The `prevrandao` value of the block, only set after the merge.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prevrandao: Option<B256>,
+    /// The excess blob gas of the block, if Cancun is active.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub excess_blob_gas: Option<u64>,
+    /// The blob gas price derived from the excess blob gas, if Cancun is active.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub blob_gasprice: Option<u128>,
+}
+
+impl BlockEvmEnv {
+    /// Creates the summary of the [`EvmEnv`] configured for the given block.
+    pub fn new<Spec: Debug>(block_id: BlockId, evm_env: &EvmEnv<Spec>) -> Self {
+        let EvmEnv { cfg_env, block_env } = evm_env;
+        let blob = block_env.blob_excess_gas_and_price.as_ref();
+        Self {
+            block_id,
+            spec_id: format!("{:?}", cfg_env.spec),
+            chain_id: cfg_env.chain_id,
+            number: block_env.number,
+            timestamp: block_env.timestamp,
+            beneficiary: block_env.beneficiary,
+            gas_limit: block_env.gas_limit,
+            base_fee: block_env.basefee,
+            difficulty: block_env.difficulty,
+            prevrandao: block_env.prevrandao,
+            excess_blob_gas: blob.map(|blob| blob.excess_blob_gas),
+            blob_gasprice: blob.map(|blob| blob.blob_gasprice),
+        }
+    }
+}
@@ -8,31 +8,56 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 #![cfg_attr(not(test), warn(unused_crate_dependencies))]
 
+pub mod account_changes;
+pub mod block;
+pub mod block_summary;
 pub mod builder;
+pub mod builder_bid;
 pub mod cache;
+pub mod code_history;
 pub mod error;
+pub mod evm_env;
 pub mod fee_history;
 pub mod gas_oracle;
 pub mod id_provider;
 pub mod logs_utils;
+pub mod memory_budget;
 pub mod pending_block;
+pub mod pubsub;
 pub mod receipt;
 pub mod revm_utils;
+pub mod rewards;
 pub mod simulate;
+pub mod sync_status;
+pub mod trace_file;
 pub mod transaction;
+pub mod tx_lookup;
+pub mod tx_simulation;
 pub mod utils;
 
+pub use account_changes::{AccountChange, AccountChanges};
+pub use block::{BlockResponseOptions, BlockWithReceipts};
+pub use block_summary::BlockSummary;
 pub use builder::config::{EthConfig, EthFilterConfig};
 pub use cache::{
     config::EthStateCacheConfig, db::StateCacheDb, multi_consumer::MultiConsumerLruCache,
     EthStateCache,
 };
+pub use code_history::{CodeChange, CodeChangeKind};
 pub use error::{EthApiError, EthResult, RevertError, RpcInvalidTransactionError, SignError};
+pub use evm_env::BlockEvmEnv;
 pub use fee_history::{FeeHistoryCache, FeeHistoryCacheConfig, FeeHistoryEntry};
 pub use gas_oracle::{
     GasCap, GasPriceOracle, GasPriceOracleConfig, GasPriceOracleResult, RPC_DEFAULT_GAS_CAP,
 };
 pub use id_provider::EthSubscriptionIdProvider;
-pub use pending_block::{PendingBlock, PendingBlockEnv, PendingBlockEnvOrigin};
+pub use memory_budget::{MemoryBudgetExceeded, RequestMemoryBudget, StructLogBudgetInspector};
+pub use pending_block::{PendingBlock, PendingBlockEnv, PendingBlockEnvOrigin, Preconfirmation};
+pub use pubsub::{EthSubscriptionKind, EthSubscriptionParams, RethSubscriptionKind};
 pub use receipt::EthReceiptBuilder;
+pub use rewards::{BlockRewards, BuilderPayment, ProposerPayment};
+pub use sync_status::{EthSyncInfo, EthSyncStatus, SyncStageDetail, SyncStageProgress};
+pub use trace_file::{TraceFile, TraceFileFormat, TraceFileOptions};
 pub use transaction::TransactionSource;
+pub use tx_lookup::{TxLookupConfig, TxLookupFallback};
+pub use tx_simulation::{BalanceChange, SimulatedTransactionInput, TransactionSimulation};
@@ -4,15 +4,56 @@
 
 use alloy_consensus::TxReceipt;
 use alloy_eips::{eip2718::Encodable2718, BlockNumHash};
-use alloy_primitives::TxHash;
+use alloy_primitives::{TxHash, B256};
 use alloy_rpc_types_eth::{FilteredParams, Log};
 use reth_chainspec::ChainInfo;
 use reth_errors::ProviderError;
 use reth_primitives::RecoveredBlock;
 use reth_primitives_traits::{BlockBody, SignedTransaction};
 use reth_storage_api::{BlockReader, ProviderBlock};
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
+/// Position in a log stream up to which all matching logs have been delivered.
+///
+/// Passing this token back when re-subscribing continues the stream right after the block it
+/// points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogStreamResumeToken {
+    /// Number of the last block whose logs were fully delivered.
+    pub block_number: u64,
+    /// Hash of the last block whose logs were fully delivered.
+    ///
+    /// Used to detect whether the block was reorged out in the meantime.
+    pub block_hash: B256,
+}
+
+impl LogStreamResumeToken {
+    /// Creates a token pointing at the given block.
+    pub const fn new(block: BlockNumHash) -> Self {
+        Self { block_number: block.number, block_hash: block.hash }
+    }
+
+    /// Returns the block this token points to.
+    pub const fn block(&self) -> BlockNumHash {
+        BlockNumHash::new(self.block_number, self.block_hash)
+    }
+}
+
+/// A single message of a log stream subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogStreamChunk {
+    /// Matching logs, ordered by block number and log index.
+    pub logs: Vec<Log>,
+    /// Whether this chunk was sourced from canonical state notifications rather than from the
+    /// historical backfill.
+    pub live: bool,
+    /// Token that resumes the stream right after this chunk.
+    pub resume_token: LogStreamResumeToken,
+}
+
 /// Returns all matching of a block's receipts when the transaction hashes are known.
 pub fn matching_block_logs_with_tx_hashes<'a, I, R>(
     filter: &FilteredParams,
@@ -189,6 +230,20 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn log_stream_chunk_serde_roundtrip() {
+        let chunk = LogStreamChunk {
+            logs: vec![Log::default()],
+            live: true,
+            resume_token: LogStreamResumeToken::new(BlockNumHash::new(7, B256::with_last_byte(1))),
+        };
+        let json = serde_json::to_value(&chunk).unwrap();
+        assert_eq!(json["resumeToken"]["blockNumber"], 7);
+        assert_eq!(json["live"], true);
+        let decoded: LogStreamChunk = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, chunk);
+    }
+
     #[test]
     fn test_log_range_from_and_to() {
         let from = 14000000u64;
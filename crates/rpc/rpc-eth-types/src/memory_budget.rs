@@ -0,0 +1,620 @@
+//! Request-scoped memory accounting of RPC calls.
+//!
+//! A [`RequestMemoryBudget`] tracks the approximate number of bytes allocated for the response of a
+//! single RPC call, e.g. the traces collected by `debug_` and `trace_` methods. The RPC server
+//! scopes a budget around every call, and expensive handlers charge the budget as they build their
+//! response, so that calls exceeding it are aborted before they can exhaust the memory of the node.
+//!
+//! The budget of the current call is available to the async handler through a task-local, and is
+//! carried over to blocking tasks with [`RequestMemoryBudget::enter`].
+//!
+//! Values are charged with their [`EstimatedSize`], which approximates their JSON encoded size
+//! without encoding them, since they are encoded once more for the response anyway.
+//!
+//! Blocking tasks can't be cancelled, so a call that is aborted, or whose response is no longer
+//! awaited, is marked with [`RequestMemoryBudget::abort`] and all further charges of its handlers
+//! fail, which stops them at the next charge.
+//!
+//! Struct log traces can grow unbounded while a single transaction is executed, so they are
+//! charged step by step with a [`StructLogBudgetInspector`] instead.
+
+use alloy_primitives::{Address, Bytes, Log, U256};
+use alloy_rpc_types_trace::{
+    geth::{
+        CallFrame, DefaultFrame, GethDefaultTracingOptions, GethTrace, PreStateFrame, StructLog,
+    },
+    parity::{
+        Action, LocalizedTransactionTrace, TraceOutput, TraceResults,
+        TraceResultsWithTransactionHash, TransactionTrace, VmTrace,
+    },
+};
+use revm::{
+    interpreter::{
+        interpreter_types::LoopControl, CallInputs, CallOutcome, CreateInputs, CreateOutcome,
+        EOFCreateInputs, InstructionResult, Interpreter,
+    },
+    Inspector,
+};
+use std::{
+    cell::RefCell,
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+use tokio::task::futures::TaskLocalFuture;
+
+tokio::task_local! {
+    /// The budget of the call processed by the current task.
+    static TASK_BUDGET: RequestMemoryBudget;
+}
+
+thread_local! {
+    /// The budget of the call processed by the current blocking thread.
+    static THREAD_BUDGET: RefCell<Option<RequestMemoryBudget>> = const { RefCell::new(None) };
+}
+
+/// Error returned when a call exceeds its [`RequestMemoryBudget`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("request exceeded memory budget of {limit} bytes")]
+pub struct MemoryBudgetExceeded {
+    /// The budget of the call in bytes.
+    pub limit: usize,
+    /// The bytes charged to the call when it was aborted.
+    pub used: usize,
+}
+
+/// The memory budget of a single RPC call.
+///
+/// Cloning this is cheap, and all clones share the same counter.
+#[derive(Debug, Clone)]
+pub struct RequestMemoryBudget {
+    inner: Arc<RequestMemoryBudgetInner>,
+}
+
+#[derive(Debug)]
+struct RequestMemoryBudgetInner {
+    /// Maximum number of bytes the call can allocate.
+    limit: usize,
+    /// Number of bytes charged so far.
+    used: AtomicUsize,
+    /// Whether the call was aborted.
+    aborted: AtomicBool,
+}
+
+impl RequestMemoryBudget {
+    /// Creates a new budget of `limit` bytes.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            inner: Arc::new(RequestMemoryBudgetInner {
+                limit,
+                used: AtomicUsize::new(0),
+                aborted: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Returns the budget of the current call, if any.
+    pub fn current() -> Option<Self> {
+        TASK_BUDGET
+            .try_with(Clone::clone)
+            .ok()
+            .or_else(|| THREAD_BUDGET.with(|budget| budget.borrow().clone()))
+    }
+
+    /// Returns the maximum number of bytes the call can allocate.
+    pub fn limit(&self) -> usize {
+        self.inner.limit
+    }
+
+    /// Returns the number of bytes charged so far.
+    pub fn used(&self) -> usize {
+        self.inner.used.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the call exceeded its budget.
+    pub fn is_exceeded(&self) -> bool {
+        self.used() > self.limit()
+    }
+
+    /// Marks the call as aborted, so that all further charges fail.
+    ///
+    /// This stops the handlers of a call that is no longer awaited at their next charge.
+    pub fn abort(&self) {
+        self.inner.aborted.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if the call was aborted.
+    pub fn is_aborted(&self) -> bool {
+        self.inner.aborted.load(Ordering::Relaxed)
+    }
+
+    /// Returns the error of the call if it exceeded its budget or was aborted.
+    pub fn check(&self) -> Result<(), MemoryBudgetExceeded> {
+        let used = self.used();
+        if used > self.limit() || self.is_aborted() {
+            return Err(MemoryBudgetExceeded { limit: self.limit(), used })
+        }
+        Ok(())
+    }
+
+    /// Charges `bytes` to the budget.
+    ///
+    /// Returns an error if the call exceeded its budget or was aborted, in which case it should be
+    /// stopped.
+    pub fn charge(&self, bytes: usize) -> Result<(), MemoryBudgetExceeded> {
+        self.inner.used.fetch_add(bytes, Ordering::Relaxed);
+        self.check()
+    }
+
+    /// Charges the [`EstimatedSize`] of `value` to the budget.
+    pub fn charge_estimated<T: EstimatedSize + ?Sized>(
+        &self,
+        value: &T,
+    ) -> Result<(), MemoryBudgetExceeded> {
+        self.charge(value.estimated_size())
+    }
+
+    /// Runs the future with this budget as the budget of the current call.
+    pub fn scope<F: Future>(self, fut: F) -> TaskLocalFuture<Self, F> {
+        TASK_BUDGET.scope(self, fut)
+    }
+
+    /// Sets this budget as the budget of the current call on this thread, until the returned guard
+    /// is dropped.
+    ///
+    /// This should be used to carry the budget over to blocking tasks.
+    pub fn enter(&self) -> RequestMemoryBudgetGuard {
+        let previous = THREAD_BUDGET.with(|budget| budget.borrow_mut().replace(self.clone()));
+        RequestMemoryBudgetGuard { previous }
+    }
+}
+
+/// Guard returned by [`RequestMemoryBudget::enter`] that restores the previous budget of the thread
+/// on drop.
+#[derive(Debug)]
+pub struct RequestMemoryBudgetGuard {
+    previous: Option<RequestMemoryBudget>,
+}
+
+impl Drop for RequestMemoryBudgetGuard {
+    fn drop(&mut self) {
+        THREAD_BUDGET.with(|budget| *budget.borrow_mut() = self.previous.take());
+    }
+}
+
+/// Charges `bytes` to the budget of the current call, if any.
+pub fn charge_request_memory(bytes: usize) -> Result<(), MemoryBudgetExceeded> {
+    RequestMemoryBudget::current().map_or(Ok(()), |budget| budget.charge(bytes))
+}
+
+/// Charges the [`EstimatedSize`] of `value` to the budget of the current call, if any.
+pub fn charge_request_memory_estimated<T: EstimatedSize + ?Sized>(
+    value: &T,
+) -> Result<(), MemoryBudgetExceeded> {
+    RequestMemoryBudget::current().map_or(Ok(()), |budget| budget.charge_estimated(value))
+}
+
+/// An [`Inspector`] that charges the struct log of every executed instruction to the
+/// [`RequestMemoryBudget`] of the current call while the wrapped inspector records it.
+///
+/// Once the budget is exceeded, every frame is halted at its next instruction without recording it,
+/// so that the execution ends early. [`Self::check`] returns the error of the call afterwards.
+#[derive(Debug)]
+pub struct StructLogBudgetInspector<I> {
+    /// The inspector that records the struct logs.
+    inner: I,
+    /// The budget of the current call, if any.
+    budget: Option<RequestMemoryBudget>,
+    /// Whether the stack is recorded for every struct log.
+    stack: bool,
+    /// Whether the memory is recorded for every struct log.
+    memory: bool,
+}
+
+impl<I> StructLogBudgetInspector<I> {
+    /// Wraps the inspector that records struct logs with the given options, charging the budget
+    /// of the current call.
+    pub fn new(inner: I, config: &GethDefaultTracingOptions) -> Self {
+        Self {
+            inner,
+            budget: RequestMemoryBudget::current(),
+            stack: !config.disable_stack.unwrap_or_default(),
+            memory: config.enable_memory.unwrap_or_default(),
+        }
+    }
+
+    /// Returns the error of the call if it exceeded its budget while recording the struct logs.
+    pub fn check(&self) -> Result<(), MemoryBudgetExceeded> {
+        self.budget.as_ref().map_or(Ok(()), RequestMemoryBudget::check)
+    }
+
+    /// Returns the estimated size of the struct log of the next instruction of the interpreter.
+    fn struct_log_size(&self, interp: &Interpreter) -> usize {
+        let stack = if self.stack { interp.stack.len() * WORD_SIZE } else { 0 };
+        let memory =
+            if self.memory { interp.memory.borrow().len().div_ceil(32) * (64 + 3) } else { 0 };
+        STRUCT_LOG_OVERHEAD + stack + memory
+    }
+}
+
+impl<CTX, I: Inspector<CTX>> Inspector<CTX> for StructLogBudgetInspector<I> {
+    fn initialize_interp(&mut self, interp: &mut Interpreter, context: &mut CTX) {
+        self.inner.initialize_interp(interp, context)
+    }
+
+    fn step(&mut self, interp: &mut Interpreter, context: &mut CTX) {
+        if let Some(budget) = &self.budget {
+            if budget.charge(self.struct_log_size(interp)).is_err() {
+                interp.control.set_instruction_result(InstructionResult::OutOfGas);
+                return
+            }
+        }
+        self.inner.step(interp, context)
+    }
+
+    fn step_end(&mut self, interp: &mut Interpreter, context: &mut CTX) {
+        self.inner.step_end(interp, context)
+    }
+
+    fn log(&mut self, interp: &mut Interpreter, context: &mut CTX, log: Log) {
+        self.inner.log(interp, context, log)
+    }
+
+    fn call(&mut self, context: &mut CTX, inputs: &mut CallInputs) -> Option<CallOutcome> {
+        self.inner.call(context, inputs)
+    }
+
+    fn call_end(&mut self, context: &mut CTX, inputs: &CallInputs, outcome: &mut CallOutcome) {
+        self.inner.call_end(context, inputs, outcome)
+    }
+
+    fn create(&mut self, context: &mut CTX, inputs: &mut CreateInputs) -> Option<CreateOutcome> {
+        self.inner.create(context, inputs)
+    }
+
+    fn create_end(
+        &mut self,
+        context: &mut CTX,
+        inputs: &CreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.inner.create_end(context, inputs, outcome)
+    }
+
+    fn eofcreate(
+        &mut self,
+        context: &mut CTX,
+        inputs: &mut EOFCreateInputs,
+    ) -> Option<CreateOutcome> {
+        self.inner.eofcreate(context, inputs)
+    }
+
+    fn eofcreate_end(
+        &mut self,
+        context: &mut CTX,
+        inputs: &EOFCreateInputs,
+        outcome: &mut CreateOutcome,
+    ) {
+        self.inner.eofcreate_end(context, inputs, outcome)
+    }
+
+    fn selfdestruct(&mut self, contract: Address, target: Address, value: U256) {
+        self.inner.selfdestruct(contract, target, value)
+    }
+}
+
+/// Approximate JSON encoded size of a response value, see
+/// [`RequestMemoryBudget::charge_estimated`].
+///
+/// The estimate counts the variable sized parts of a value, like bytes, stack items and nested
+/// frames, exactly, and adds a fixed overhead for the fields of every object.
+pub trait EstimatedSize {
+    /// Returns the approximate JSON encoded size of the value in bytes.
+    fn estimated_size(&self) -> usize;
+}
+
+/// Overhead of the fixed size fields of a parity trace.
+const PARITY_TRACE_OVERHEAD: usize = 512;
+/// Overhead of the fixed size fields of a struct log, without its stack, memory and storage.
+const STRUCT_LOG_OVERHEAD: usize = 128;
+/// Overhead of the fixed size fields of a call frame.
+const CALL_FRAME_OVERHEAD: usize = 256;
+/// Approximate size of a log of a call frame.
+const CALL_LOG_SIZE: usize = 256;
+/// Overhead of the fixed size fields of an account of a state diff or prestate.
+const ACCOUNT_OVERHEAD: usize = 256;
+/// Overhead of the fixed size fields of a vm trace instruction.
+const VM_INSTRUCTION_OVERHEAD: usize = 128;
+/// Size of a hex encoded word with quotes and separator.
+const WORD_SIZE: usize = 2 + 64 + 3;
+/// Size of a storage slot and its value.
+const STORAGE_ENTRY_SIZE: usize = 2 * WORD_SIZE + 1;
+/// Size of a value that isn't estimated more precisely, like a small frame.
+const SMALL_VALUE_SIZE: usize = 64;
+
+/// Returns the size of hex encoded bytes with prefix and quotes.
+const fn hex_size(bytes: &Bytes) -> usize {
+    4 + 2 * bytes.len()
+}
+
+impl<T: EstimatedSize> EstimatedSize for [T] {
+    fn estimated_size(&self) -> usize {
+        2 + self.iter().map(|value| value.estimated_size() + 1).sum::<usize>()
+    }
+}
+
+impl<T: EstimatedSize> EstimatedSize for Vec<T> {
+    fn estimated_size(&self) -> usize {
+        self.as_slice().estimated_size()
+    }
+}
+
+impl<T: EstimatedSize> EstimatedSize for Option<T> {
+    fn estimated_size(&self) -> usize {
+        self.as_ref().map_or(4, EstimatedSize::estimated_size)
+    }
+}
+
+impl EstimatedSize for serde_json::Value {
+    fn estimated_size(&self) -> usize {
+        match self {
+            Self::Null => 4,
+            Self::Bool(_) => 5,
+            Self::Number(_) => 20,
+            Self::String(value) => value.len() + 2,
+            Self::Array(values) => values.estimated_size(),
+            Self::Object(values) => {
+                2 + values
+                    .iter()
+                    .map(|(key, value)| key.len() + 4 + value.estimated_size())
+                    .sum::<usize>()
+            }
+        }
+    }
+}
+
+impl EstimatedSize for TransactionTrace {
+    fn estimated_size(&self) -> usize {
+        let input = match &self.action {
+            Action::Call(call) => hex_size(&call.input),
+            Action::Create(create) => hex_size(&create.init),
+            _ => 0,
+        };
+        let output = match &self.result {
+            Some(TraceOutput::Call(call)) => hex_size(&call.output),
+            Some(TraceOutput::Create(create)) => hex_size(&create.code),
+            None => 0,
+        };
+        PARITY_TRACE_OVERHEAD + input + output + 8 * self.trace_address.len()
+    }
+}
+
+impl EstimatedSize for LocalizedTransactionTrace {
+    fn estimated_size(&self) -> usize {
+        self.trace.estimated_size()
+    }
+}
+
+impl EstimatedSize for TraceResults {
+    fn estimated_size(&self) -> usize {
+        let state_diff = self.state_diff.as_ref().map_or(0, |state_diff| {
+            state_diff
+                .0
+                .values()
+                .map(|account| ACCOUNT_OVERHEAD + account.storage.len() * 2 * STORAGE_ENTRY_SIZE)
+                .sum()
+        });
+        hex_size(&self.output) +
+            state_diff +
+            self.trace.estimated_size() +
+            self.vm_trace.as_ref().map_or(0, vm_trace_size)
+    }
+}
+
+impl EstimatedSize for TraceResultsWithTransactionHash {
+    fn estimated_size(&self) -> usize {
+        WORD_SIZE + self.full_trace.estimated_size()
+    }
+}
+
+/// Returns the estimated size of a vm trace and its nested traces.
+fn vm_trace_size(trace: &VmTrace) -> usize {
+    hex_size(&trace.code) +
+        trace
+            .ops
+            .iter()
+            .map(|op| {
+                let executed = op.ex.as_ref().map_or(0, |ex| {
+                    ex.push.len() * WORD_SIZE + ex.mem.as_ref().map_or(0, |mem| hex_size(&mem.data))
+                });
+                VM_INSTRUCTION_OVERHEAD + executed + op.sub.as_ref().map_or(0, vm_trace_size)
+            })
+            .sum::<usize>()
+}
+
+impl EstimatedSize for StructLog {
+    fn estimated_size(&self) -> usize {
+        STRUCT_LOG_OVERHEAD +
+            self.stack.as_ref().map_or(0, |stack| stack.len() * WORD_SIZE) +
+            self.memory
+                .as_ref()
+                .map_or(0, |memory| memory.iter().map(|word| word.len() + 3).sum()) +
+            self.storage.as_ref().map_or(0, |storage| storage.len() * STORAGE_ENTRY_SIZE) +
+            self.return_data.as_ref().map_or(0, hex_size)
+    }
+}
+
+impl EstimatedSize for DefaultFrame {
+    fn estimated_size(&self) -> usize {
+        SMALL_VALUE_SIZE + hex_size(&self.return_value) + self.struct_logs.estimated_size()
+    }
+}
+
+impl EstimatedSize for CallFrame {
+    fn estimated_size(&self) -> usize {
+        CALL_FRAME_OVERHEAD +
+            hex_size(&self.input) +
+            self.output.as_ref().map_or(0, hex_size) +
+            self.logs.len() * CALL_LOG_SIZE +
+            self.calls.estimated_size()
+    }
+}
+
+impl EstimatedSize for PreStateFrame {
+    fn estimated_size(&self) -> usize {
+        let accounts = match self {
+            Self::Default(mode) => mode.0.values().collect::<Vec<_>>(),
+            Self::Diff(diff) => diff.pre.values().chain(diff.post.values()).collect(),
+        };
+        accounts
+            .into_iter()
+            .map(|account| {
+                ACCOUNT_OVERHEAD +
+                    account.code.as_ref().map_or(0, hex_size) +
+                    account.storage.len() * STORAGE_ENTRY_SIZE
+            })
+            .sum()
+    }
+}
+
+impl EstimatedSize for GethTrace {
+    fn estimated_size(&self) -> usize {
+        match self {
+            Self::Default(frame) => frame.estimated_size(),
+            Self::CallTracer(frame) => frame.estimated_size(),
+            Self::FlatCallTracer(traces) => traces.estimated_size(),
+            Self::PreStateTracer(frame) => frame.estimated_size(),
+            Self::MuxTracer(frame) => {
+                frame.0.values().map(|trace| SMALL_VALUE_SIZE + trace.estimated_size()).sum()
+            }
+            Self::JS(value) => value.estimated_size(),
+            _ => SMALL_VALUE_SIZE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use revm::{
+        bytecode::Bytecode,
+        interpreter::{interpreter::ExtBytecode, InputsImpl, SharedMemory},
+        primitives::hardfork::SpecId,
+    };
+    use std::rc::Rc;
+
+    #[derive(Debug, Default)]
+    struct CountSteps(usize);
+
+    impl<CTX> Inspector<CTX> for CountSteps {
+        fn step(&mut self, _interp: &mut Interpreter, _context: &mut CTX) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    fn charge_until_exceeded() {
+        let budget = RequestMemoryBudget::new(10);
+        assert_eq!(budget.charge(6), Ok(()));
+        assert_eq!(budget.charge(4), Ok(()));
+        assert_eq!(budget.charge(1), Err(MemoryBudgetExceeded { limit: 10, used: 11 }));
+        assert!(budget.is_exceeded());
+    }
+
+    #[test]
+    fn abort_fails_further_charges() {
+        let budget = RequestMemoryBudget::new(10);
+        assert_eq!(budget.charge(1), Ok(()));
+        budget.abort();
+        assert_eq!(budget.charge(1), Err(MemoryBudgetExceeded { limit: 10, used: 2 }));
+    }
+
+    #[test]
+    fn struct_log_budget_halts_execution() {
+        let mut interp = Interpreter::new(
+            Rc::new(RefCell::new(SharedMemory::new())),
+            ExtBytecode::new(Bytecode::default()),
+            InputsImpl::default(),
+            false,
+            false,
+            SpecId::default(),
+            u64::MAX,
+        );
+        assert!(interp.stack.push(U256::ZERO));
+
+        let budget = RequestMemoryBudget::new(2 * (STRUCT_LOG_OVERHEAD + WORD_SIZE));
+        let _guard = budget.enter();
+        let mut inspector =
+            StructLogBudgetInspector::new(CountSteps::default(), &Default::default());
+
+        inspector.step(&mut interp, &mut ());
+        inspector.step(&mut interp, &mut ());
+        assert_eq!(interp.control.instruction_result(), InstructionResult::Continue);
+        assert_eq!(inspector.check(), Ok(()));
+
+        inspector.step(&mut interp, &mut ());
+        assert_eq!(interp.control.instruction_result(), InstructionResult::OutOfGas);
+        assert!(inspector.check().is_err());
+        assert_eq!(inspector.inner.0, 2);
+    }
+
+    #[test]
+    fn estimated_size_is_close_to_encoded_size() {
+        let value = serde_json::json!({
+            "stack": ["0x1", "0x2"],
+            "memory": "0x0000000000000000000000000000000000000000000000000000000000000000",
+            "nested": { "failed": false, "gas": 21000, "returnValue": null },
+        });
+        let encoded = serde_json::to_string(&value).unwrap().len();
+        let estimated = value.estimated_size();
+        assert!(estimated >= encoded && estimated < encoded * 2, "{estimated} vs {encoded}");
+
+        let struct_log = serde_json::json!({
+            "pc": 0,
+            "op": "PUSH1",
+            "gas": 21000,
+            "gasCost": 3,
+            "depth": 1,
+            "stack": vec![format!("{:#x}", alloy_primitives::U256::MAX); 16],
+            "memory": vec!["00".repeat(32); 64],
+        });
+        let frame: DefaultFrame = serde_json::from_value(serde_json::json!({
+            "failed": false,
+            "gas": 21000,
+            "returnValue": "0x",
+            "structLogs": vec![struct_log; 100],
+        }))
+        .unwrap();
+        let encoded = serde_json::to_string(&frame).unwrap().len();
+        let estimated = frame.estimated_size();
+        assert!(estimated >= encoded / 2 && estimated < encoded * 2, "{estimated} vs {encoded}");
+    }
+
+    #[test]
+    fn enter_thread_budget() {
+        assert!(RequestMemoryBudget::current().is_none());
+        assert_eq!(charge_request_memory(usize::MAX), Ok(()));
+
+        let budget = RequestMemoryBudget::new(10);
+        let guard = budget.enter();
+        assert!(charge_request_memory(11).is_err());
+        drop(guard);
+
+        assert!(RequestMemoryBudget::current().is_none());
+        assert_eq!(budget.used(), 11);
+    }
+
+    #[tokio::test]
+    async fn scope_task_budget() {
+        let budget = RequestMemoryBudget::new(10);
+        budget
+            .clone()
+            .scope(async {
+                assert_eq!(charge_request_memory(4), Ok(()));
+            })
+            .await;
+        assert_eq!(budget.used(), 4);
+    }
+}
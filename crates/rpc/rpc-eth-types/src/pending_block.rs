@@ -6,11 +6,12 @@ use std::time::Instant;
 
 use alloy_consensus::BlockHeader;
 use alloy_eips::{BlockId, BlockNumberOrTag};
-use alloy_primitives::B256;
+use alloy_primitives::{keccak256, Address, Bytes, TxHash, B256};
 use derive_more::Constructor;
 use reth_evm::EvmEnv;
 use reth_primitives::{Receipt, RecoveredBlock, SealedHeader};
 use reth_primitives_traits::Block;
+use serde::{Deserialize, Serialize};
 
 /// Configured [`EvmEnv`] for a pending block.
 #[derive(Debug, Clone, Constructor)]
@@ -83,3 +84,63 @@ pub struct PendingBlock<B: Block, R> {
     /// The receipts for the pending block
     pub receipts: Vec<R>,
 }
+
+/// Result of `reth_preconfirmTransaction`: a signed commitment to include a transaction in the
+/// block that is being built.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Preconfirmation {
+    /// Id of the chain the commitment is valid on.
+    #[serde(with = "alloy_serde::quantity")]
+    pub chain_id: u64,
+    /// Hash of the transaction.
+    pub tx_hash: TxHash,
+    /// Number of the block the transaction is committed to.
+    #[serde(with = "alloy_serde::quantity")]
+    pub block_number: u64,
+    /// Hash of the parent of the block.
+    pub parent_hash: B256,
+    /// Gas used by the transaction, simulated at the end of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+    /// Whether the simulated transaction succeeded. Reverting transactions are committed too.
+    pub success: bool,
+    /// Address of the key that signed the commitment.
+    pub signer: Address,
+    /// Signature of the [`Preconfirmation::commitment_hash`].
+    pub signature: Bytes,
+}
+
+impl Preconfirmation {
+    /// Returns the signed hash: `keccak256(chain_id || tx_hash || parent_hash || block_number)`,
+    /// with the chain id and block number big endian encoded.
+    pub fn commitment_hash(
+        chain_id: u64,
+        tx_hash: TxHash,
+        parent_hash: B256,
+        block_number: u64,
+    ) -> B256 {
+        let mut preimage = [0u8; 80];
+        preimage[..8].copy_from_slice(&chain_id.to_be_bytes());
+        preimage[8..40].copy_from_slice(tx_hash.as_slice());
+        preimage[40..72].copy_from_slice(parent_hash.as_slice());
+        preimage[72..].copy_from_slice(&block_number.to_be_bytes());
+        keccak256(preimage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commitment_hash_commits_to_chain_id() {
+        let tx_hash = TxHash::with_last_byte(1);
+        let parent_hash = B256::with_last_byte(2);
+
+        let mainnet = Preconfirmation::commitment_hash(1, tx_hash, parent_hash, 10);
+        assert_eq!(mainnet, Preconfirmation::commitment_hash(1, tx_hash, parent_hash, 10));
+        assert_ne!(mainnet, Preconfirmation::commitment_hash(11155111, tx_hash, parent_hash, 10));
+        assert_ne!(mainnet, Preconfirmation::commitment_hash(1, tx_hash, parent_hash, 11));
+    }
+}
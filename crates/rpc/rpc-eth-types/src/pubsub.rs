@@ -0,0 +1,85 @@
+//! Subscription kinds and params of `eth_subscribe`, including reth-specific ones.
+
+use alloy_primitives::Address;
+use alloy_rpc_types_eth::pubsub::{Params, SubscriptionKind};
+use serde::{Deserialize, Serialize};
+
+/// The kind of an `eth_subscribe` subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EthSubscriptionKind {
+    /// A standard subscription kind.
+    Eth(SubscriptionKind),
+    /// A reth-specific subscription kind.
+    Reth(RethSubscriptionKind),
+}
+
+impl From<SubscriptionKind> for EthSubscriptionKind {
+    fn from(kind: SubscriptionKind) -> Self {
+        Self::Eth(kind)
+    }
+}
+
+/// Reth-specific kinds of `eth_subscribe` subscriptions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RethSubscriptionKind {
+    /// Streams the balance, nonce and storage changes of a set of accounts per canonical block,
+    /// see [`AccountChanges`](crate::AccountChanges).
+    Accounts,
+}
+
+/// The params of an `eth_subscribe` subscription.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EthSubscriptionParams {
+    /// Params of a standard subscription kind.
+    Eth(Params),
+    /// The accounts watched by an `accounts` subscription.
+    Accounts(Vec<Address>),
+}
+
+impl From<Params> for EthSubscriptionParams {
+    fn from(params: Params) -> Self {
+        Self::Eth(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_kinds() {
+        let kind: EthSubscriptionKind = serde_json::from_str(r#""newHeads""#).unwrap();
+        assert_eq!(kind, EthSubscriptionKind::Eth(SubscriptionKind::NewHeads));
+
+        let kind: EthSubscriptionKind = serde_json::from_str(r#""accounts""#).unwrap();
+        assert_eq!(kind, EthSubscriptionKind::Reth(RethSubscriptionKind::Accounts));
+
+        assert!(serde_json::from_str::<EthSubscriptionKind>(r#""unknown""#).is_err());
+    }
+
+    #[test]
+    fn parse_params() {
+        let params: EthSubscriptionParams = serde_json::from_str("true").unwrap();
+        assert_eq!(params, EthSubscriptionParams::Eth(Params::Bool(true)));
+
+        let params: EthSubscriptionParams =
+            serde_json::from_str(r#"{"address":"0x0000000000000000000000000000000000000001"}"#)
+                .unwrap();
+        assert!(matches!(params, EthSubscriptionParams::Eth(Params::Logs(_))));
+
+        let params: EthSubscriptionParams = serde_json::from_str(
+            r#"["0x0000000000000000000000000000000000000001","0x0000000000000000000000000000000000000002"]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            params,
+            EthSubscriptionParams::Accounts(vec![
+                Address::with_last_byte(1),
+                Address::with_last_byte(2)
+            ])
+        );
+    }
+}
@@ -1,20 +1,22 @@
 //! utilities for working with revm
 
+use alloy_eips::eip2930::AccessList;
 use alloy_primitives::{Address, B256, U256};
 use alloy_rpc_types_eth::{
     state::{AccountOverride, StateOverride},
     BlockOverrides,
 };
 use reth_evm::TransactionEnv;
+use reth_revm::database::{EvmStateProvider, StateProviderDatabase};
 use revm::{
     context::BlockEnv,
     state::{Account, AccountStatus, Bytecode, EvmStorageSlot},
     Database, DatabaseCommit,
 };
-use revm_database::{CacheDB, State};
+use revm_database::{AccountState, CacheDB, DbAccount, State};
 use std::{
     cmp::min,
-    collections::{BTreeMap, HashMap},
+    collections::{BTreeMap, BTreeSet, HashMap},
 };
 
 use super::{EthApiError, EthResult, RpcInvalidTransactionError};
@@ -342,10 +344,71 @@ where
     Ok(())
 }
 
+/// Loads all accounts and storage slots of the given [`AccessList`] into the [`CacheDB`], using
+/// batched reads of the underlying state provider.
+///
+/// Entries that are already cached are left untouched.
+pub fn prefetch_access_list<DB>(
+    access_list: &AccessList,
+    db: &mut CacheDB<StateProviderDatabase<DB>>,
+) -> EthResult<()>
+where
+    DB: EvmStateProvider,
+{
+    let addresses = access_list
+        .iter()
+        .map(|item| item.address)
+        .filter(|address| !db.cache.accounts.contains_key(address))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let accounts = db.db.basic_accounts_ref(&addresses)?;
+    for (address, info) in addresses.into_iter().zip(accounts) {
+        match info {
+            Some(info) => db.insert_account_info(address, info),
+            None => {
+                db.cache.accounts.insert(address, DbAccount::new_not_existing());
+            }
+        }
+    }
+
+    let mut slots = BTreeMap::<Address, BTreeSet<U256>>::new();
+    for item in access_list.iter() {
+        let Some(account) = db.cache.accounts.get(&item.address) else { continue };
+        // storage of these accounts is never read from the database
+        if matches!(account.account_state, AccountState::NotExisting | AccountState::StorageCleared)
+        {
+            continue
+        }
+        slots.entry(item.address).or_default().extend(
+            item.storage_keys
+                .iter()
+                .map(|key| U256::from_be_bytes(key.0))
+                .filter(|index| !account.storage.contains_key(index)),
+        );
+    }
+    for (address, indices) in slots {
+        if indices.is_empty() {
+            continue
+        }
+        let indices = indices.into_iter().collect::<Vec<_>>();
+        let values = db.db.storage_multi_ref(address, &indices)?;
+        if let Some(account) = db.cache.accounts.get_mut(&address) {
+            account.storage.extend(indices.into_iter().zip(values));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloy_consensus::constants::GWEI_TO_WEI;
+    use alloy_eips::eip2930::AccessListItem;
+    use alloy_primitives::address;
+    use reth_primitives_traits::Account;
+    use reth_revm::test_utils::StateProviderTest;
 
     #[test]
     fn test_ensure_0_fallback() {
@@ -456,4 +519,35 @@ mod tests {
         );
         assert!(call_fees.is_err());
     }
+
+    #[test]
+    fn prefetch_access_list_loads_accounts_and_slots() {
+        let existing = address!("0x0000000000000000000000000000000000000001");
+        let missing = address!("0x0000000000000000000000000000000000000002");
+        let slot = B256::with_last_byte(1);
+
+        let mut state = StateProviderTest::default();
+        state.insert_account(
+            existing,
+            Account { nonce: 1, ..Default::default() },
+            None,
+            HashMap::from_iter([(slot, U256::from(7))]),
+        );
+        let mut db = CacheDB::new(StateProviderDatabase::new(state));
+
+        let access_list = AccessList(vec![
+            AccessListItem { address: existing, storage_keys: vec![slot, B256::with_last_byte(2)] },
+            AccessListItem { address: missing, storage_keys: vec![slot] },
+        ]);
+        prefetch_access_list(&access_list, &mut db).unwrap();
+
+        let account = &db.cache.accounts[&existing];
+        assert_eq!(account.info.nonce, 1);
+        assert_eq!(account.storage[&U256::from(1)], U256::from(7));
+        assert_eq!(account.storage[&U256::from(2)], U256::ZERO);
+
+        let account = &db.cache.accounts[&missing];
+        assert_eq!(account.account_state, AccountState::NotExisting);
+        assert!(account.storage.is_empty());
+    }
 }
@@ -0,0 +1,89 @@
+//! Types for block rewards and issuance accounting.
+
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+
+/// Result of `reth_getBlockRewards` for a single block: the fees and rewards of the block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockRewards {
+    /// The number of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub number: u64,
+    /// The hash of the block.
+    pub hash: B256,
+    /// The fee recipient of the block.
+    pub beneficiary: Address,
+    /// The base fee of all transactions of the block, which is burned.
+    pub base_fee_burned: U256,
+    /// The blob fee of all blob transactions of the block, which is burned. Zero before Cancun.
+    pub blob_fee_burned: U256,
+    /// The priority fees of all transactions of the block, which are paid to the fee recipient.
+    pub priority_fees: U256,
+    /// The static block reward paid to the fee recipient, including the reward for included
+    /// ommers. Zero after the merge.
+    pub block_reward: U256,
+    /// The rewards paid to the beneficiaries of the included ommers. Zero after the merge.
+    pub ommer_rewards: U256,
+}
+
+impl BlockRewards {
+    /// Returns the burned ether of the block, i.e. the base fees and blob fees.
+    pub fn burned(&self) -> U256 {
+        self.base_fee_burned + self.blob_fee_burned
+    }
+
+    /// Returns the newly issued ether of the block, i.e. the block and ommer rewards.
+    pub fn issuance(&self) -> U256 {
+        self.block_reward + self.ommer_rewards
+    }
+
+    /// Returns the total amount paid to the fee recipient of the block.
+    pub fn beneficiary_reward(&self) -> U256 {
+        self.block_reward + self.priority_fees
+    }
+}
+
+/// Result of `reth_getProposerPayments` for a single locally proposed block: the payment of the
+/// fee recipient that the consensus client requested the block for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProposerPayment {
+    /// The number of the block.
+    #[serde(with = "alloy_serde::quantity")]
+    pub number: u64,
+    /// The hash of the block.
+    pub hash: B256,
+    /// The fee recipient requested in the payload attributes of the block.
+    pub fee_recipient: Address,
+    /// The beneficiary of the block. Differs from the fee recipient if the block was built by an
+    /// external builder.
+    pub beneficiary: Address,
+    /// The priority fees of the block, which are paid to the fee recipient if it's the
+    /// beneficiary of the block.
+    pub priority_fees: U256,
+    /// The payment of the external builder to the fee recipient, if the block was built by one,
+    /// either transferred directly or through a contract.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub builder_payment: Option<BuilderPayment>,
+    /// The withdrawals of the block to the fee recipient.
+    pub withdrawals: U256,
+    /// The amount the fee recipient is expected to receive: the priority fees if it's the
+    /// beneficiary, the builder payment and the withdrawals.
+    pub expected: U256,
+    /// The part of the expected amount the fee recipient received, from the balance changes of the
+    /// fee recipient during the execution of the block.
+    pub received: U256,
+    /// Whether the fee recipient received at least the expected amount.
+    pub verified: bool,
+}
+
+/// A payment of an external builder to the fee recipient of a block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuilderPayment {
+    /// The hash of the last transaction of the builder to the fee recipient in the block.
+    pub transaction_hash: B256,
+    /// The total value the builder transferred to the fee recipient in the block.
+    pub value: U256,
+}
@@ -0,0 +1,250 @@
+//! Types for the stage detail of `eth_syncingDetail`.
+
+use alloy_rpc_types_eth::{SyncInfo, SyncStatus};
+use parking_lot::Mutex;
+use reth_stages_types::{StageCheckpoint, StageId};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::time::{Duration, Instant};
+
+/// Result of `eth_syncingDetail`.
+///
+/// Serializes like the standard [`SyncStatus`]. If stage detail is enabled, the sync info is
+/// extended with the [`SyncStageDetail`] of the stage the pipeline is running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EthSyncStatus {
+    /// Info when syncing
+    Info(Box<EthSyncInfo>),
+    /// Not syncing
+    None,
+}
+
+impl From<SyncStatus> for EthSyncStatus {
+    fn from(status: SyncStatus) -> Self {
+        match status {
+            SyncStatus::Info(info) => {
+                Self::Info(Box::new(EthSyncInfo { info: *info, stage: None }))
+            }
+            SyncStatus::None => Self::None,
+        }
+    }
+}
+
+impl From<EthSyncStatus> for SyncStatus {
+    fn from(status: EthSyncStatus) -> Self {
+        match status {
+            EthSyncStatus::Info(info) => Self::Info(Box::new(info.info)),
+            EthSyncStatus::None => Self::None,
+        }
+    }
+}
+
+impl Serialize for EthSyncStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Info(info) => info.serialize(serializer),
+            Self::None => serializer.serialize_bool(false),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for EthSyncStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Syncing {
+            None(bool),
+            Info(Box<EthSyncInfo>),
+        }
+
+        match Syncing::deserialize(deserializer)? {
+            Syncing::None(false) => Ok(Self::None),
+            Syncing::None(true) => Err(serde::de::Error::custom(
+                "eth_syncing returned `true` that is undefined value.",
+            )),
+            Syncing::Info(info) => Ok(Self::Info(info)),
+        }
+    }
+}
+
+/// The standard [`SyncInfo`], optionally extended with the [`SyncStageDetail`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthSyncInfo {
+    /// The standard sync info.
+    #[serde(flatten)]
+    pub info: SyncInfo,
+    /// The detail of the stage the pipeline is running, only set if enabled.
+    #[serde(flatten)]
+    pub stage: Option<SyncStageDetail>,
+}
+
+/// Detail of the stage the pipeline is running.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncStageDetail {
+    /// The name of the stage.
+    pub current_stage: String,
+    /// The block number of the stage checkpoint.
+    #[serde(with = "alloy_serde::quantity")]
+    pub stage_checkpoint: u64,
+    /// The number of entities the stage processed, if the stage tracks entities.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub entities_processed: Option<u64>,
+    /// The number of entities the stage has to process, if the stage tracks entities.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub entities_total: Option<u64>,
+    /// The estimated number of seconds until the stage is done, once known.
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "alloy_serde::quantity::opt")]
+    pub eta_seconds: Option<u64>,
+}
+
+/// Tracks the progress of the running stage across `eth_syncing` calls to estimate when it's done.
+#[derive(Debug, Default)]
+pub struct SyncStageProgress {
+    /// The first observation of the running stage.
+    first: Mutex<Option<ProgressSample>>,
+}
+
+/// An observation of the number of entities a stage processed.
+#[derive(Debug, Clone, Copy)]
+struct ProgressSample {
+    stage: StageId,
+    processed: u64,
+    at: Instant,
+}
+
+impl SyncStageProgress {
+    /// Returns the [`SyncStageDetail`] of the stage the pipeline is running, given all stage
+    /// checkpoints.
+    ///
+    /// This is the first stage in pipeline order that has entities left to process, or else the
+    /// first stage that is behind the furthest stage checkpoint.
+    pub fn detail(&self, checkpoints: &[(String, StageCheckpoint)]) -> Option<SyncStageDetail> {
+        let checkpoint_of = |id: StageId| {
+            checkpoints
+                .iter()
+                .find(|(name, _)| name == id.as_str())
+                .map(|(_, checkpoint)| checkpoint)
+        };
+        let furthest = checkpoints.iter().map(|(_, checkpoint)| checkpoint.block_number).max()?;
+
+        let (stage, checkpoint) = StageId::ALL
+            .into_iter()
+            .filter_map(|id| checkpoint_of(id).map(|checkpoint| (id, *checkpoint)))
+            .find(|(_, checkpoint)| {
+                checkpoint.entities().is_some_and(|entities| entities.processed < entities.total)
+            })
+            .or_else(|| {
+                StageId::ALL.into_iter().find_map(|id| {
+                    let checkpoint = checkpoint_of(id).copied().unwrap_or_default();
+                    (checkpoint.block_number < furthest).then_some((id, checkpoint))
+                })
+            })?;
+
+        let entities = checkpoint.entities();
+        let eta = entities.and_then(|entities| self.eta(stage, entities.processed, entities.total));
+
+        Some(SyncStageDetail {
+            current_stage: stage.to_string(),
+            stage_checkpoint: checkpoint.block_number,
+            entities_processed: entities.map(|entities| entities.processed),
+            entities_total: entities.map(|entities| entities.total),
+            eta_seconds: eta.map(|eta| eta.as_secs()),
+        })
+    }
+
+    /// Records the progress of the stage and returns the estimated time until it's done, based on
+    /// the rate since the stage was first observed.
+    fn eta(&self, stage: StageId, processed: u64, total: u64) -> Option<Duration> {
+        let now = Instant::now();
+        let mut first = self.first.lock();
+        let sample = match *first {
+            Some(sample) if sample.stage == stage && sample.processed <= processed => sample,
+            _ => {
+                // a different stage started, or the stage restarted
+                *first = Some(ProgressSample { stage, processed, at: now });
+                return None
+            }
+        };
+
+        let done = processed - sample.processed;
+        if done == 0 {
+            return None
+        }
+        let elapsed = now.duration_since(sample.at).as_secs_f64();
+        let remaining = total.saturating_sub(processed) as f64;
+        Some(Duration::from_secs_f64(remaining * elapsed / done as f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_stages_types::{EntitiesCheckpoint, StageUnitCheckpoint};
+
+    fn checkpoint(block_number: u64, entities: Option<(u64, u64)>) -> StageCheckpoint {
+        StageCheckpoint {
+            block_number,
+            stage_checkpoint: entities.map(|(processed, total)| {
+                StageUnitCheckpoint::Entities(EntitiesCheckpoint { processed, total })
+            }),
+        }
+    }
+
+    #[test]
+    fn detail_of_running_stage() {
+        let progress = SyncStageProgress::default();
+        let checkpoints = vec![
+            (StageId::Bodies.to_string(), checkpoint(100, None)),
+            (StageId::Headers.to_string(), checkpoint(100, None)),
+            (StageId::SenderRecovery.to_string(), checkpoint(40, Some((40, 100)))),
+            (StageId::Execution.to_string(), checkpoint(0, None)),
+        ];
+
+        let detail = progress.detail(&checkpoints).unwrap();
+        assert_eq!(detail.current_stage, StageId::SenderRecovery.to_string());
+        assert_eq!(detail.stage_checkpoint, 40);
+        assert_eq!(detail.entities_processed, Some(40));
+        assert_eq!(detail.entities_total, Some(100));
+        // the first observation has no rate yet
+        assert_eq!(detail.eta_seconds, None);
+
+        // without entities, the first stage behind the furthest checkpoint is running
+        let checkpoints = vec![
+            (StageId::Headers.to_string(), checkpoint(100, None)),
+            (StageId::Bodies.to_string(), checkpoint(60, None)),
+        ];
+        let detail = progress.detail(&checkpoints).unwrap();
+        assert_eq!(detail.current_stage, StageId::Bodies.to_string());
+        assert_eq!(detail.entities_processed, None);
+    }
+
+    #[test]
+    fn serde_keeps_standard_shape() {
+        let info =
+            SyncInfo { current_block: alloy_primitives::U256::from(10), ..Default::default() };
+        let status = EthSyncStatus::from(SyncStatus::Info(Box::new(info.clone())));
+        assert_eq!(serde_json::to_value(&status).unwrap(), serde_json::to_value(&info).unwrap());
+        assert_eq!(serde_json::to_value(EthSyncStatus::None).unwrap(), serde_json::json!(false));
+
+        let status = EthSyncStatus::Info(Box::new(EthSyncInfo {
+            info,
+            stage: Some(SyncStageDetail {
+                current_stage: "Execution".to_string(),
+                stage_checkpoint: 5,
+                entities_processed: Some(1),
+                entities_total: Some(2),
+                eta_seconds: Some(3),
+            }),
+        }));
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["currentStage"], "Execution");
+        assert_eq!(json["etaSeconds"], "0x3");
+        assert_eq!(serde_json::from_value::<EthSyncStatus>(json).unwrap(), status);
+    }
+}
@@ -0,0 +1,53 @@
+//! Types for opcode traces that are written to files instead of being returned inline.
+
+use alloy_rpc_types_trace::geth::GethDefaultTracingOptions;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// The format of an opcode trace that is written to a file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TraceFileFormat {
+    /// The JSON output of the geth struct logger, as returned inline by `debug_traceTransaction`.
+    #[default]
+    StructLog,
+    /// Collapsed stacks of call frames and opcodes weighted by gas, one stack per line, as
+    /// consumed by flamegraph tools.
+    Flamegraph,
+}
+
+impl TraceFileFormat {
+    /// Returns the extension of files in this format.
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::StructLog => "json",
+            Self::Flamegraph => "folded",
+        }
+    }
+}
+
+/// Options of `debug_traceTransactionToFile`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceFileOptions {
+    /// The format of the file.
+    #[serde(default)]
+    pub format: TraceFileFormat,
+    /// The options of the struct logger. For flamegraphs, only the opcodes and their gas are
+    /// recorded.
+    #[serde(flatten)]
+    pub config: GethDefaultTracingOptions,
+}
+
+/// Result of `debug_traceTransactionToFile`: the file the trace was written to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceFile {
+    /// The path of the file on the node.
+    pub path: PathBuf,
+    /// The format of the file.
+    pub format: TraceFileFormat,
+    /// The size of the file in bytes.
+    #[serde(with = "alloy_serde::quantity")]
+    pub size: u64,
+}
@@ -0,0 +1,133 @@
+//! Lookups of transactions that are not covered by the transaction hash index.
+
+use alloy_primitives::BlockNumber;
+use reth_prune_types::PruneMode;
+use reth_rpc_server_types::constants::DEFAULT_TX_LOOKUP_MAX_SCAN_BLOCKS;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+/// How transactions are looked up by hash if they are not in the transaction hash index, because
+/// the index was pruned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TxLookupFallback {
+    /// The transaction is reported as not found.
+    #[default]
+    Disabled,
+    /// A bounded number of blocks below the horizon of the index are scanned for the
+    /// transaction, which is reported as not found if it isn't in the scanned blocks.
+    Scan,
+}
+
+impl TxLookupFallback {
+    /// Returns the name of the fallback.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disabled => "disabled",
+            Self::Scan => "scan",
+        }
+    }
+}
+
+impl fmt::Display for TxLookupFallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TxLookupFallback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "disabled" => Ok(Self::Disabled),
+            "scan" => Ok(Self::Scan),
+            _ => Err(format!(
+                "invalid transaction lookup fallback: {s}, expected one of disabled, scan"
+            )),
+        }
+    }
+}
+
+/// Configuration of lookups of transactions by hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxLookupConfig {
+    /// The prune mode of the transaction hash index, `None` if the index is not pruned.
+    pub prune_mode: Option<PruneMode>,
+    /// How transactions below the horizon of the index are looked up.
+    pub fallback: TxLookupFallback,
+    /// The maximum number of blocks below the horizon of the index that are scanned with
+    /// [`TxLookupFallback::Scan`].
+    pub max_scan_blocks: u64,
+}
+
+impl TxLookupConfig {
+    /// Sets the prune mode of the transaction hash index.
+    pub const fn with_prune_mode(mut self, prune_mode: Option<PruneMode>) -> Self {
+        self.prune_mode = prune_mode;
+        self
+    }
+
+    /// Sets how transactions below the horizon of the index are looked up.
+    pub const fn with_fallback(mut self, fallback: TxLookupFallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Sets the maximum number of blocks below the horizon of the index that are scanned.
+    pub const fn with_max_scan_blocks(mut self, max_scan_blocks: u64) -> Self {
+        self.max_scan_blocks = max_scan_blocks;
+        self
+    }
+
+    /// Returns the lowest block whose transactions are in the index, given the tip of the chain.
+    ///
+    /// All blocks are indexed if this returns `0`.
+    pub const fn lowest_indexed_block(&self, tip: BlockNumber) -> BlockNumber {
+        match self.prune_mode {
+            None => 0,
+            Some(PruneMode::Full) => tip + 1,
+            Some(PruneMode::Distance(distance)) => (tip + 1).saturating_sub(distance),
+            Some(PruneMode::Before(block)) => block,
+        }
+    }
+}
+
+impl Default for TxLookupConfig {
+    fn default() -> Self {
+        Self {
+            prune_mode: None,
+            fallback: TxLookupFallback::default(),
+            max_scan_blocks: DEFAULT_TX_LOOKUP_MAX_SCAN_BLOCKS,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lowest_indexed_block() {
+        let config = TxLookupConfig::default();
+        assert_eq!(config.lowest_indexed_block(100), 0);
+
+        let config = config.with_prune_mode(Some(PruneMode::Distance(10)));
+        assert_eq!(config.lowest_indexed_block(100), 91);
+        assert_eq!(config.lowest_indexed_block(5), 0);
+
+        let config = config.with_prune_mode(Some(PruneMode::Before(50)));
+        assert_eq!(config.lowest_indexed_block(100), 50);
+
+        let config = config.with_prune_mode(Some(PruneMode::Full));
+        assert_eq!(config.lowest_indexed_block(100), 101);
+    }
+
+    #[test]
+    fn parse_fallback() {
+        for fallback in [TxLookupFallback::Disabled, TxLookupFallback::Scan] {
+            assert_eq!(fallback.to_string().parse::<TxLookupFallback>().unwrap(), fallback);
+        }
+        assert!("error".parse::<TxLookupFallback>().is_err());
+    }
+}
@@ -0,0 +1,149 @@
+//! Types for simulating a single transaction.
+
+use alloy_eips::eip2930::AccessList;
+use alloy_primitives::{Address, Bytes, Log, I256, U256};
+use alloy_rpc_types_eth::TransactionRequest;
+use revm::{state::EvmState, DatabaseRef};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// The transaction simulated by `reth_simulateTransaction`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SimulatedTransactionInput {
+    /// A signed, encoded transaction.
+    Raw(Bytes),
+    /// An unsigned transaction, like the request of `eth_call`.
+    Request(Box<TransactionRequest>),
+}
+
+/// Result of `reth_simulateTransaction`: the outcome of executing a single transaction on top of a
+/// block, without committing it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSimulation {
+    /// Whether the transaction succeeded.
+    pub status: bool,
+    /// The gas used by the transaction.
+    #[serde(with = "alloy_serde::quantity")]
+    pub gas_used: u64,
+    /// The revert or halt reason, if the transaction failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The output of the transaction, e.g. the return or revert data.
+    pub output: Bytes,
+    /// The logs emitted by the transaction.
+    pub logs: Vec<Log>,
+    /// The access list generated by executing the transaction, as returned by
+    /// `eth_createAccessList`.
+    pub access_list: AccessList,
+    /// The gas used by the transaction with the generated access list.
+    #[serde(with = "alloy_serde::quantity")]
+    pub access_list_gas_used: u64,
+    /// The fee the sender paid for the gas used. Zero if the transaction has no gas price, like
+    /// requests of `eth_call` usually don't.
+    pub fee: U256,
+    /// The balances of all accounts whose balance the transaction changes.
+    ///
+    /// The balance change of the sender includes the [`fee`](Self::fee), and the fee recipient of
+    /// the block receives the priority fee.
+    pub balance_changes: BTreeMap<Address, BalanceChange>,
+}
+
+/// The balance of an account before and after a simulated transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChange {
+    /// The balance before the transaction.
+    pub before: U256,
+    /// The balance after the transaction.
+    pub after: U256,
+    /// The change of the balance, which is negative if the balance decreased.
+    pub delta: I256,
+}
+
+impl BalanceChange {
+    /// Creates the change from the balance before and after the transaction.
+    pub fn new(before: U256, after: U256) -> Self {
+        Self { before, after, delta: I256::from_raw(after.wrapping_sub(before)) }
+    }
+}
+
+/// Returns the balance changes of the accounts that a transaction touched.
+///
+/// The state of the transaction must not be committed to the database yet, so the database returns
+/// the balances before the transaction.
+pub fn balance_changes<DB: DatabaseRef>(
+    state: &EvmState,
+    db: &DB,
+) -> Result<BTreeMap<Address, BalanceChange>, DB::Error> {
+    let mut balance_changes = BTreeMap::new();
+    for (address, account) in state {
+        if !account.is_touched() {
+            continue
+        }
+        let before = db.basic_ref(*address)?.map(|info| info.balance).unwrap_or_default();
+        let after = account.info.balance;
+        if before != after {
+            balance_changes.insert(*address, BalanceChange::new(before, after));
+        }
+    }
+    Ok(balance_changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use revm::state::{Account, AccountInfo};
+    use revm_database::{CacheDB, EmptyDB};
+
+    #[test]
+    fn balance_changes_of_touched_accounts() {
+        let sender = Address::with_last_byte(1);
+        let recipient = Address::with_last_byte(2);
+        let unchanged = Address::with_last_byte(3);
+        let untouched = Address::with_last_byte(4);
+
+        let mut db = CacheDB::new(EmptyDB::default());
+        for address in [sender, unchanged] {
+            db.insert_account_info(
+                address,
+                AccountInfo { balance: U256::from(10), ..Default::default() },
+            );
+        }
+
+        let account = |balance: u64, touched: bool| {
+            let mut account =
+                Account::from(AccountInfo { balance: U256::from(balance), ..Default::default() });
+            if touched {
+                account.mark_touch();
+            }
+            account
+        };
+        let state = EvmState::from_iter([
+            (sender, account(4, true)),
+            (recipient, account(6, true)),
+            (unchanged, account(10, true)),
+            (untouched, account(5, false)),
+        ]);
+
+        let changes = balance_changes(&state, &db).unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[&sender].delta, I256::try_from(-6).unwrap());
+        assert_eq!(changes[&recipient], BalanceChange::new(U256::ZERO, U256::from(6)));
+        assert_eq!(changes[&recipient].delta, I256::try_from(6).unwrap());
+
+        let json = serde_json::to_value(changes[&sender]).unwrap();
+        assert_eq!(json["delta"], "-6");
+    }
+
+    #[test]
+    fn deserialize_simulated_transaction_input() {
+        let input: SimulatedTransactionInput = serde_json::from_str(r#""0x02f8""#).unwrap();
+        assert_eq!(input, SimulatedTransactionInput::Raw(Bytes::from_static(&[0x02, 0xf8])));
+
+        let input: SimulatedTransactionInput =
+            serde_json::from_str(r#"{"to":"0x0000000000000000000000000000000000000001"}"#).unwrap();
+        assert!(matches!(input, SimulatedTransactionInput::Request(request)
+            if request.to == Some(Address::with_last_byte(1).into())));
+    }
+}
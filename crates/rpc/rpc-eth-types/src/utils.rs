@@ -22,6 +22,25 @@ pub fn recover_raw_transaction<T: SignedTransaction>(mut data: &[u8]) -> EthResu
     transaction.try_into_recovered().or(Err(EthApiError::InvalidTransactionSignature))
 }
 
+/// Rejects transactions that are signed for the chain id reported by `eth_chainId`, if that
+/// differs from the execution chain id.
+///
+/// Tooling that reads the chain id from `eth_chainId` signs transactions that can't be executed by
+/// the node, this returns a descriptive error for them instead of the generic chain id mismatch of
+/// the pool.
+pub fn ensure_execution_chain_id(
+    chain_id: Option<u64>,
+    rpc_chain_id: u64,
+    execution_chain_id: u64,
+) -> EthResult<()> {
+    if rpc_chain_id != execution_chain_id && chain_id == Some(rpc_chain_id) {
+        return Err(EthApiError::InvalidParams(format!(
+            "transaction is signed for the chain id {rpc_chain_id} reported by RPC, which differs from the chain id {execution_chain_id} executed by the node"
+        )))
+    }
+    Ok(())
+}
+
 /// Performs a binary search within a given block range to find the desired block number.
 ///
 /// The binary search is performed by calling the provided asynchronous `check` closure on the
@@ -84,4 +103,18 @@ mod tests {
             binary_search(1, 10, |mid| Box::pin(async move { Ok(mid >= 11) })).await;
         assert_eq!(num, Ok(10));
     }
+
+    #[test]
+    fn test_ensure_execution_chain_id() {
+        // no override
+        assert!(ensure_execution_chain_id(Some(1), 1, 1).is_ok());
+        // signed for the execution chain or without replay protection
+        assert!(ensure_execution_chain_id(Some(1), 31337, 1).is_ok());
+        assert!(ensure_execution_chain_id(None, 31337, 1).is_ok());
+        // signed for the chain id reported by RPC
+        assert!(matches!(
+            ensure_execution_chain_id(Some(31337), 31337, 1),
+            Err(EthApiError::InvalidParams(_))
+        ));
+    }
 }
@@ -29,6 +29,17 @@ pub fn default_max_tracing_requests() -> usize {
 /// The default number of getproof calls we are allowing to run concurrently.
 pub const DEFAULT_PROOF_PERMITS: usize = 25;
 
+/// The default maximum number of blocks below the horizon of the transaction hash index that are
+/// scanned when looking up a transaction by hash.
+pub const DEFAULT_TX_LOOKUP_MAX_SCAN_BLOCKS: u64 = 1_000;
+
+/// The upper bound of the number of blocks below the horizon of the transaction hash index that
+/// are scanned when looking up a transaction by hash.
+pub const MAX_TX_LOOKUP_SCAN_BLOCKS: u64 = 10_000;
+
+/// The default maximum number of `debug_` and `trace_` calls waiting in the tracing queue.
+pub const DEFAULT_TRACING_QUEUE_MAX_QUEUED: usize = 256;
+
 /// The default IPC endpoint
 #[cfg(windows)]
 pub const DEFAULT_IPC_ENDPOINT: &str = r"\\.\pipe\reth.ipc";
@@ -55,6 +66,10 @@ pub const DEFAULT_ETH_PROOF_WINDOW: u64 = 0;
 /// second block time, and a month on a 2 second block time.
 pub const MAX_ETH_PROOF_WINDOW: u64 = 28 * 24 * 60 * 60 / 2;
 
+/// The default maximum total size of the traces written by `debug_traceTransactionToFile`, in
+/// megabytes.
+pub const DEFAULT_TRACE_DIR_MAX_SIZE_MB: u64 = 10 * 1024;
+
 /// GPO specific constants
 pub mod gas_oracle {
     use alloy_primitives::U256;
@@ -0,0 +1,145 @@
+//! Registry of machine-readable error codes for reth specific RPC failures.
+//!
+//! Reth specific failures are constructed with their structured error code and data payload, see
+//! [`reth_rpc_err`]. Servers that don't report structured errors map them back to the generic
+//! JSON-RPC error codes that were used historically, see [`RethRpcErrorCode::legacy_code`].
+
+use serde::Serialize;
+
+/// Error codes of reth specific RPC failures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RethRpcErrorCode {
+    /// The state of the requested block was pruned.
+    PrunedState,
+    /// The requested block is not known to the node.
+    BlockNotFound,
+    /// The requested block is not available yet, because the node has not synced it.
+    NotYetSynced,
+    /// The execution exceeded the configured timeout.
+    ExecutionTimeout,
+    /// The request exceeded the configured memory budget.
+    MemoryBudgetExceeded,
+}
+
+impl RethRpcErrorCode {
+    /// Returns the numeric error code.
+    pub const fn code(&self) -> i32 {
+        match self {
+            Self::PrunedState => -39001,
+            Self::BlockNotFound => -39002,
+            Self::NotYetSynced => -39003,
+            Self::ExecutionTimeout => -39004,
+            Self::MemoryBudgetExceeded => -39005,
+        }
+    }
+
+    /// Returns the error code with the given numeric code, if any.
+    pub const fn from_code(code: i32) -> Option<Self> {
+        match code {
+            -39001 => Some(Self::PrunedState),
+            -39002 => Some(Self::BlockNotFound),
+            -39003 => Some(Self::NotYetSynced),
+            -39004 => Some(Self::ExecutionTimeout),
+            -39005 => Some(Self::MemoryBudgetExceeded),
+            _ => None,
+        }
+    }
+
+    /// Returns the generic JSON-RPC error code that was used for these failures before structured
+    /// error codes were introduced.
+    pub const fn legacy_code(&self) -> i32 {
+        match self {
+            Self::PrunedState => jsonrpsee_types::error::INTERNAL_ERROR_CODE,
+            // `EthRpcErrorCode::ResourceNotFound`
+            Self::BlockNotFound | Self::NotYetSynced => -32001,
+            Self::ExecutionTimeout => jsonrpsee_types::error::CALL_EXECUTION_FAILED_CODE,
+            Self::MemoryBudgetExceeded => jsonrpsee_types::error::OVERSIZED_RESPONSE_CODE,
+        }
+    }
+
+    /// Returns the machine-readable reason of the error code.
+    pub const fn reason(&self) -> &'static str {
+        match self {
+            Self::PrunedState => "pruned_state",
+            Self::BlockNotFound => "block_not_found",
+            Self::NotYetSynced => "not_yet_synced",
+            Self::ExecutionTimeout => "execution_timeout",
+            Self::MemoryBudgetExceeded => "memory_budget_exceeded",
+        }
+    }
+
+    /// Returns `true` if the same request can succeed when it is retried later.
+    pub const fn is_retryable(&self) -> bool {
+        matches!(self, Self::NotYetSynced | Self::ExecutionTimeout)
+    }
+
+    /// Returns the data payload of errors with this code.
+    pub const fn data(&self) -> RethRpcErrorData {
+        RethRpcErrorData { reason: self.reason(), retryable: self.is_retryable() }
+    }
+}
+
+/// The data payload of structured errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RethRpcErrorData {
+    /// The machine-readable reason of the error.
+    pub reason: &'static str,
+    /// Whether the same request can succeed when it is retried later.
+    pub retryable: bool,
+}
+
+/// Constructs a JSON-RPC error with the structured error code and data payload of a reth specific
+/// failure.
+pub fn reth_rpc_err(
+    code: RethRpcErrorCode,
+    msg: impl Into<String>,
+) -> jsonrpsee_types::error::ErrorObject<'static> {
+    jsonrpsee_types::error::ErrorObject::owned(code.code(), msg.into(), Some(code.data()))
+}
+
+/// Converts a structured error of a reth specific failure to its legacy error code, without the
+/// data payload.
+///
+/// Returns `None` if the error is not a structured error.
+pub fn to_legacy_rpc_err(
+    err: &jsonrpsee_types::error::ErrorObject<'_>,
+) -> Option<jsonrpsee_types::error::ErrorObject<'static>> {
+    let code = RethRpcErrorCode::from_code(err.code())?;
+    Some(jsonrpsee_types::error::ErrorObject::owned(
+        code.legacy_code(),
+        err.message().to_string(),
+        None::<()>,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structured_error_data() {
+        let err = jsonrpsee_types::error::ErrorObject::owned(
+            RethRpcErrorCode::NotYetSynced.code(),
+            "header not found",
+            Some(RethRpcErrorCode::NotYetSynced.data()),
+        );
+        assert_eq!(err.code(), -39003);
+        assert_eq!(err.data().unwrap().get(), r#"{"reason":"not_yet_synced","retryable":true}"#);
+    }
+
+    #[test]
+    fn legacy_error_codes() {
+        let err = reth_rpc_err(RethRpcErrorCode::NotYetSynced, "block not found: latest");
+        let legacy = to_legacy_rpc_err(&err).unwrap();
+        assert_eq!(legacy.code(), -32001);
+        assert_eq!(legacy.message(), "block not found: latest");
+        assert!(legacy.data().is_none());
+
+        assert!(to_legacy_rpc_err(&jsonrpsee_types::error::ErrorObject::owned(
+            jsonrpsee_types::error::INTERNAL_ERROR_CODE,
+            "internal error",
+            None::<()>,
+        ))
+        .is_none());
+    }
+}
@@ -10,9 +10,13 @@
 
 /// Common RPC constants.
 pub mod constants;
+pub mod error_codes;
 pub mod result;
 
 mod module;
 pub use module::{RethRpcModule, RpcModuleSelection};
 
+mod signer;
+pub use signer::RemoteSignerApi;
+
 pub use result::ToRpcResult;
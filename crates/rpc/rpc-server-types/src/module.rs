@@ -149,6 +149,15 @@ impl RpcModuleSelection {
             Self::Selection(s) => s.contains(module),
         }
     }
+
+    /// Returns true if the selection contains the given module, without it only being included
+    /// by [`RpcModuleSelection::All`].
+    pub fn contains_explicitly(&self, module: &RethRpcModule) -> bool {
+        match self {
+            Self::All => false,
+            Self::Standard | Self::Selection(_) => self.contains(module),
+        }
+    }
 }
 
 impl From<&HashSet<RethRpcModule>> for RpcModuleSelection {
@@ -273,6 +282,8 @@ pub enum RethRpcModule {
     Miner,
     /// `mev_` module
     Mev,
+    /// `personal_` module
+    Personal,
 }
 
 // === impl RethRpcModule ===
@@ -324,6 +335,7 @@ impl FromStr for RethRpcModule {
             "flashbots" => Self::Flashbots,
             "miner" => Self::Miner,
             "mev" => Self::Mev,
+            "personal" => Self::Personal,
             _ => return Err(ParseError::VariantNotFound),
         })
     }
@@ -427,6 +439,16 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_rpc_module_selection_contains_explicitly() {
+        assert!(RpcModuleSelection::All.contains(&RethRpcModule::Admin));
+        assert!(!RpcModuleSelection::All.contains_explicitly(&RethRpcModule::Admin));
+        assert!(RpcModuleSelection::Standard.contains_explicitly(&RethRpcModule::Eth));
+        assert!(!RpcModuleSelection::Standard.contains_explicitly(&RethRpcModule::Admin));
+        assert!(RpcModuleSelection::from([RethRpcModule::Admin])
+            .contains_explicitly(&RethRpcModule::Admin));
+    }
+
     #[test]
     fn test_rpc_module_selection_are_identical() {
         // Test scenario: both selections are `All`
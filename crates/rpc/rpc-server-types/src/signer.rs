@@ -0,0 +1,39 @@
+//! Types of the signers the RPC server can be configured with.
+
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// The API spoken by a remote signer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[serde(rename_all = "lowercase")]
+pub enum RemoteSignerApi {
+    /// The `eth_` namespace of web3signer.
+    #[default]
+    #[cfg_attr(feature = "clap", value(name = "web3signer"))]
+    Web3Signer,
+    /// The `account_` namespace of clef.
+    Clef,
+}
+
+impl fmt::Display for RemoteSignerApi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Web3Signer => f.write_str("web3signer"),
+            Self::Clef => f.write_str("clef"),
+        }
+    }
+}
+
+impl FromStr for RemoteSignerApi {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "web3signer" => Ok(Self::Web3Signer),
+            "clef" => Ok(Self::Clef),
+            _ => Err(format!("unknown remote signer API: {s}")),
+        }
+    }
+}
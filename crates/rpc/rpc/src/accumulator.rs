@@ -0,0 +1,208 @@
+//! Accumulator of canonical block hashes and the `reth_getHeaderProof` endpoint.
+
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockNumberOrTag;
+use alloy_primitives::{BlockHash, BlockNumber};
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use jsonrpsee::core::RpcResult;
+use reth_db::models::HeaderProof;
+use reth_primitives_traits::NodePrimitives;
+use reth_provider::{
+    BlockHashReader, BlockIdReader, BlockNumReader, CanonStateNotification, DBProvider,
+    DatabaseProviderFactory, HeaderAccumulatorReader, HeaderAccumulatorWriter, ProviderResult,
+};
+use reth_rpc_api::RethHeaderProofApiServer;
+use reth_rpc_eth_types::EthApiError;
+use tracing::{debug, info, trace, warn};
+
+/// The number of block hashes that are appended in one database transaction while catching up
+/// with the canonical chain.
+///
+/// Kept small, because the persistence of new blocks waits for the write transaction.
+const BACKFILL_BATCH_SIZE: u64 = 10_000;
+
+/// Maintains the accumulator of canonical block hashes.
+///
+/// On start, the hashes of the blocks that are no longer canonical are removed from the
+/// accumulator, and the hashes of all canonical blocks that are not accumulated yet are appended.
+/// Afterwards the accumulator follows the canonical chain: it's truncated on reorgs and the hashes
+/// of new canonical blocks are appended.
+#[derive(Debug)]
+pub struct HeaderAccumulatorUpdater<Provider> {
+    /// Provider to read the canonical block hashes and to update the accumulator.
+    provider: Provider,
+}
+
+impl<Provider> HeaderAccumulatorUpdater<Provider>
+where
+    Provider: DatabaseProviderFactory + BlockHashReader + BlockNumReader,
+{
+    /// Creates a new updater.
+    pub const fn new(provider: Provider) -> Self {
+        Self { provider }
+    }
+
+    /// Runs the updater until the notification stream ends.
+    pub async fn run<St, N>(self, mut notifications: St)
+    where
+        St: Stream<Item = CanonStateNotification<N>> + Unpin,
+        N: NodePrimitives,
+    {
+        match self.catch_up() {
+            Ok(len) => {
+                info!(target: "rpc::header_accumulator", len, "Header accumulator is up to date")
+            }
+            Err(err) => {
+                warn!(target: "rpc::header_accumulator", %err, "Failed to update header accumulator")
+            }
+        }
+
+        while let Some(notification) = notifications.next().await {
+            let unwind_from = notification.reverted().map(|chain| *chain.range().start());
+            let committed = notification.committed();
+            let tip = committed.tip().number();
+
+            match self.update(
+                unwind_from,
+                committed.blocks_iter().map(|block| (block.number(), block.hash())),
+            ) {
+                Ok(len) => {
+                    trace!(target: "rpc::header_accumulator", tip, len, "Updated header accumulator")
+                }
+                Err(err) => {
+                    warn!(target: "rpc::header_accumulator", %err, "Failed to update header accumulator")
+                }
+            }
+        }
+        debug!(target: "rpc::header_accumulator", "Canonical state notification stream ended");
+    }
+
+    /// Removes the hashes of the blocks starting at `unwind_from` and appends the new canonical
+    /// blocks, catching up with the canonical chain first if blocks are missing.
+    ///
+    /// Returns the number of accumulated block hashes.
+    fn update(
+        &self,
+        unwind_from: Option<BlockNumber>,
+        blocks: impl Iterator<Item = (BlockNumber, BlockHash)>,
+    ) -> ProviderResult<u64> {
+        let mut len = self.provider.database_provider_ro()?.header_accumulator_len()?;
+        if let Some(unwind_from) = unwind_from {
+            len = len.min(unwind_from);
+        }
+
+        let mut blocks = blocks.skip_while(|(number, _)| *number < len).peekable();
+        if blocks.peek().is_some_and(|(number, _)| *number > len) {
+            // blocks are missing, e.g. because the updater failed to write a previous notification
+            if let Some(unwind_from) = unwind_from {
+                let provider = self.provider.database_provider_rw()?;
+                provider.truncate_header_accumulator(unwind_from)?;
+                provider.commit()?;
+            }
+            return self.catch_up()
+        }
+        let hashes = blocks.map(|(_, hash)| hash).collect::<Vec<_>>();
+
+        let provider = self.provider.database_provider_rw()?;
+        if let Some(unwind_from) = unwind_from {
+            provider.truncate_header_accumulator(unwind_from)?;
+        }
+        len += hashes.len() as u64;
+        provider.append_header_accumulator(hashes)?;
+        provider.commit()?;
+        Ok(len)
+    }
+
+    /// Removes the hashes of non-canonical blocks and appends the hashes of all canonical blocks
+    /// that are not accumulated yet, in batches of [`BACKFILL_BATCH_SIZE`].
+    ///
+    /// Everything that can be read up front is read outside of the write transactions, so they are
+    /// only held for the writes.
+    ///
+    /// Returns the number of accumulated block hashes.
+    fn catch_up(&self) -> ProviderResult<u64> {
+        let tip = self.provider.best_block_number()?;
+        loop {
+            let len = self.provider.database_provider_ro()?.header_accumulator_len()?;
+            if let Some(fork_block) = self.first_non_canonical_block(len)? {
+                debug!(target: "rpc::header_accumulator", fork_block, "Removing non-canonical block hashes");
+                let provider = self.provider.database_provider_rw()?;
+                provider.truncate_header_accumulator(fork_block)?;
+                provider.commit()?;
+                continue
+            }
+            if len > tip {
+                return Ok(len)
+            }
+
+            let end = tip.min(len + BACKFILL_BATCH_SIZE - 1);
+            let hashes = self.provider.canonical_hashes_range(len, end + 1)?;
+
+            let provider = self.provider.database_provider_rw()?;
+            provider.append_header_accumulator(hashes)?;
+            provider.commit()?;
+            debug!(target: "rpc::header_accumulator", end, tip, "Appended canonical block hashes");
+        }
+    }
+
+    /// Returns the first accumulated block whose hash is not canonical, walking back from the last
+    /// accumulated block.
+    fn first_non_canonical_block(&self, len: u64) -> ProviderResult<Option<BlockNumber>> {
+        let provider = self.provider.database_provider_ro()?;
+        let mut fork_block = None;
+        for number in (0..len).rev() {
+            let accumulated = provider.accumulated_block_hash(number)?;
+            if accumulated.is_some() && accumulated == self.provider.block_hash(number)? {
+                break
+            }
+            fork_block = Some(number);
+        }
+        Ok(fork_block)
+    }
+}
+
+/// `reth` API implementation for the accumulator of canonical block hashes.
+#[derive(Debug, Clone)]
+pub struct HeaderProofApi<Provider> {
+    /// Provider to read the accumulator.
+    provider: Provider,
+}
+
+impl<Provider> HeaderProofApi<Provider> {
+    /// Creates a new instance of the [`HeaderProofApi`].
+    pub const fn new(provider: Provider) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl<Provider> RethHeaderProofApiServer for HeaderProofApi<Provider>
+where
+    Provider: DatabaseProviderFactory + BlockIdReader + 'static,
+{
+    /// Handler for `reth_getHeaderProof`
+    async fn reth_get_header_proof(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> RpcResult<Option<HeaderProof>> {
+        let proof = (|| {
+            let provider = self.provider.database_provider_ro()?;
+            let block_number = match block {
+                BlockNumberOrTag::Latest => {
+                    let Some(latest) = provider.header_accumulator_len()?.checked_sub(1) else {
+                        return Ok(None)
+                    };
+                    latest
+                }
+                block => match self.provider.convert_block_number(block)? {
+                    Some(block_number) => block_number,
+                    None => return Ok(None),
+                },
+            };
+            provider.header_proof(block_number)
+        })()
+        .map_err(EthApiError::from)?;
+        Ok(proof)
+    }
+}
@@ -1,5 +1,6 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
+use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumberOrTag};
 use alloy_genesis::ChainConfig;
 use alloy_rpc_types_admin::{
     EthInfo, EthPeerInfo, EthProtocolInfo, NodeInfo, PeerInfo, PeerNetworkInfo, PeerProtocolInfo,
@@ -8,11 +9,19 @@ use alloy_rpc_types_admin::{
 use async_trait::async_trait;
 use jsonrpsee::core::RpcResult;
 use reth_chainspec::{EthChainSpec, EthereumHardfork, EthereumHardforks, ForkCondition};
+use reth_db::read_metrics::ReadMetricsConfig;
+use reth_engine_primitives::{SyncTargetError, SyncTargetHandle};
+use reth_ethereum_payload_builder::EthereumBuilderSettings;
 use reth_network_api::{NetworkInfo, Peers};
 use reth_network_peers::{id2pk, AnyNode, NodeRecord};
 use reth_network_types::PeerKind;
-use reth_rpc_api::AdminApiServer;
-use reth_rpc_server_types::ToRpcResult;
+use reth_payload_primitives::{ExtraDataTemplate, FeeRecipient};
+use reth_rpc_api::{AdminApiServer, AdminBuilderApiServer, AdminSyncTargetApiServer};
+use reth_rpc_server_types::{
+    result::{internal_rpc_err, invalid_params_rpc_err},
+    ToRpcResult,
+};
+use tracing::debug;
 
 /// `admin` API implementation.
 ///
@@ -22,12 +31,24 @@ pub struct AdminApi<N, ChainSpec> {
     network: N,
     /// The specification of the blockchain's configuration.
     chain_spec: Arc<ChainSpec>,
+    /// Instrumentation settings of database reads, if they can be changed.
+    database_read_metrics: Option<ReadMetricsConfig>,
 }
 
 impl<N, ChainSpec> AdminApi<N, ChainSpec> {
     /// Creates a new instance of `AdminApi`.
     pub const fn new(network: N, chain_spec: Arc<ChainSpec>) -> Self {
-        Self { network, chain_spec }
+        Self { network, chain_spec, database_read_metrics: None }
+    }
+
+    /// Sets the instrumentation settings of database reads that are changed by
+    /// `admin_setDatabaseReadMetrics`.
+    pub fn with_database_read_metrics(
+        mut self,
+        database_read_metrics: Option<ReadMetricsConfig>,
+    ) -> Self {
+        self.database_read_metrics = database_read_metrics;
+        self
     }
 }
 
@@ -189,6 +210,21 @@ where
     ) -> jsonrpsee::core::SubscriptionResult {
         Err("admin_peerEvents is not implemented yet".into())
     }
+
+    /// Handler for `admin_setDatabaseReadMetrics`
+    fn set_database_read_metrics(
+        &self,
+        enabled: bool,
+        slow_read_threshold_ms: Option<u64>,
+    ) -> RpcResult<bool> {
+        debug!(target: "rpc::admin", enabled, ?slow_read_threshold_ms, "Setting database read metrics");
+        let Some(read_metrics) = &self.database_read_metrics else {
+            return Err(internal_rpc_err("database read metrics are not configurable"))
+        };
+        read_metrics.set_enabled(enabled);
+        read_metrics.set_slow_read_threshold(slow_read_threshold_ms.map(Duration::from_millis));
+        Ok(true)
+    }
 }
 
 impl<N, ChainSpec> std::fmt::Debug for AdminApi<N, ChainSpec> {
@@ -196,3 +232,84 @@ impl<N, ChainSpec> std::fmt::Debug for AdminApi<N, ChainSpec> {
         f.debug_struct("AdminApi").finish_non_exhaustive()
     }
 }
+
+/// `admin` API implementation for the settings of the Ethereum payload builder.
+#[derive(Debug, Clone)]
+pub struct AdminBuilderApi {
+    /// The shareable settings of the payload builder.
+    settings: EthereumBuilderSettings,
+}
+
+impl AdminBuilderApi {
+    /// Creates a new instance of `AdminBuilderApi` with the given, shareable payload builder
+    /// settings.
+    pub const fn new(settings: EthereumBuilderSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl AdminBuilderApiServer for AdminBuilderApi {
+    /// Handler for `admin_builderFeeRecipients`
+    fn builder_fee_recipients(&self) -> RpcResult<Vec<FeeRecipient>> {
+        Ok(self.settings.fee_recipients())
+    }
+
+    /// Handler for `admin_setBuilderFeeRecipients`
+    fn set_builder_fee_recipients(&self, fee_recipients: Vec<FeeRecipient>) -> RpcResult<bool> {
+        if fee_recipients.iter().any(|recipient| recipient.weight == 0) {
+            return Err(invalid_params_rpc_err("fee recipient weight must not be zero"))
+        }
+        debug!(target: "rpc::admin", ?fee_recipients, "Setting builder fee recipients");
+        self.settings.set_fee_recipients(fee_recipients);
+        Ok(true)
+    }
+
+    /// Handler for `admin_builderExtraData`
+    fn builder_extra_data(&self) -> RpcResult<Option<ExtraDataTemplate>> {
+        Ok(self.settings.extra_data_template())
+    }
+
+    /// Handler for `admin_setBuilderExtraData`
+    fn set_builder_extra_data(&self, template: Option<ExtraDataTemplate>) -> RpcResult<bool> {
+        debug!(target: "rpc::admin", ?template, "Setting builder extra data template");
+        self.settings.set_extra_data_template(template);
+        Ok(true)
+    }
+}
+
+/// `admin` API implementation to move the fixed block the node syncs to.
+#[derive(Debug, Clone)]
+pub struct AdminSyncTargetApi {
+    /// The handle to the sync target of the node.
+    sync_target: SyncTargetHandle,
+}
+
+impl AdminSyncTargetApi {
+    /// Creates a new instance of `AdminSyncTargetApi`.
+    pub const fn new(sync_target: SyncTargetHandle) -> Self {
+        Self { sync_target }
+    }
+}
+
+#[async_trait]
+impl AdminSyncTargetApiServer for AdminSyncTargetApi {
+    /// Handler for `admin_setSyncTarget`
+    async fn set_sync_target(&self, target: BlockId) -> RpcResult<bool> {
+        let target = match target {
+            BlockId::Hash(hash) => BlockHashOrNumber::Hash(hash.block_hash),
+            BlockId::Number(BlockNumberOrTag::Number(number)) => BlockHashOrNumber::Number(number),
+            BlockId::Number(tag) => {
+                return Err(invalid_params_rpc_err(format!(
+                    "sync target must be a block number or hash, got {tag}"
+                )))
+            }
+        };
+        debug!(target: "rpc::admin", ?target, "Setting sync target");
+        self.sync_target.set_target(target).await.map(|_| true).map_err(|err| match err {
+            SyncTargetError::Disabled |
+            SyncTargetError::UnknownBlock(_) |
+            SyncTargetError::BelowFinalized { .. } => invalid_params_rpc_err(err.to_string()),
+            SyncTargetError::EngineUnavailable => internal_rpc_err(err.to_string()),
+        })
+    }
+}
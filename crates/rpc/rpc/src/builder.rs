@@ -0,0 +1,333 @@
+use std::{collections::HashMap, sync::Arc};
+
+use alloy_consensus::BlockHeader;
+use alloy_primitives::{B256, U256};
+use alloy_rpc_types_beacon::requests::ExecutionRequestsV4;
+use alloy_rpc_types_engine::{
+    ExecutionPayloadEnvelopeV2, ExecutionPayloadEnvelopeV3, ExecutionPayloadFieldV2, PayloadId,
+};
+use async_trait::async_trait;
+use futures::StreamExt;
+use jsonrpsee::core::RpcResult;
+use parking_lot::RwLock;
+use reth_payload_builder::{EthBuiltPayload, PayloadBuilderHandle};
+use reth_payload_primitives::{
+    BuiltPayload, PayloadBuilderAttributes, PayloadBuilderError, PayloadTypes,
+};
+use reth_primitives_traits::SealedBlock;
+use reth_rpc_api::BuilderApiServer;
+use reth_rpc_eth_types::builder_bid::{
+    BuilderApiResponse, BuilderBid, BuilderPayload, ExecutionPayloadAndBlobsBundle,
+    ExecutionPayloadHeader, SignedBuilderBid,
+};
+use reth_rpc_server_types::result::internal_rpc_err;
+use reth_tasks::TaskSpawner;
+use tracing::trace;
+
+/// Builder API implementation that serves the best payload of the local Ethereum payload builder
+/// to external relays.
+///
+/// The full payload of every bid returned by `builder_getHeader` is kept until a bid for a
+/// different parent block is requested.
+pub struct BuilderApi<Engine: PayloadTypes> {
+    inner: Arc<BuilderApiInner<Engine>>,
+}
+
+struct BuilderApiInner<Engine: PayloadTypes> {
+    /// The handle to the payload builder service.
+    payload_builder: PayloadBuilderHandle<Engine>,
+    /// The id of the most recently started payload job.
+    current_payload: RwLock<Option<PayloadId>>,
+    /// The payloads of the bids handed out for the current parent block, keyed by block hash.
+    bids: RwLock<HashMap<B256, EthBuiltPayload>>,
+}
+
+impl<Engine> BuilderApi<Engine>
+where
+    Engine: PayloadTypes,
+{
+    /// Create a new instance of the [`BuilderApi`]
+    ///
+    /// Spawns a task that keeps track of the payload jobs started by the payload builder.
+    pub fn new(
+        payload_builder: PayloadBuilderHandle<Engine>,
+        task_spawner: Box<dyn TaskSpawner>,
+    ) -> Self {
+        let inner = Arc::new(BuilderApiInner {
+            payload_builder,
+            current_payload: RwLock::new(None),
+            bids: RwLock::new(HashMap::new()),
+        });
+
+        let this = inner.clone();
+        task_spawner.spawn(Box::pin(async move {
+            let Ok(events) = this.payload_builder.subscribe().await else { return };
+            let mut attributes = events.into_attributes_stream();
+            while let Some(attributes) = attributes.next().await {
+                *this.current_payload.write() = Some(attributes.payload_id());
+            }
+        }));
+
+        Self { inner }
+    }
+}
+
+impl<Engine> BuilderApi<Engine>
+where
+    Engine: PayloadTypes<BuiltPayload = EthBuiltPayload>,
+{
+    /// Returns the unsigned bid for the best payload of the payload job in progress, if it builds
+    /// on top of the given parent block.
+    pub async fn header(
+        &self,
+        parent_hash: B256,
+    ) -> Result<Option<BuilderApiResponse<SignedBuilderBid>>, PayloadBuilderError> {
+        let current_payload = *self.inner.current_payload.read();
+        let Some(id) = current_payload else { return Ok(None) };
+        let Some(payload) = self.inner.payload_builder.best_payload(id).await.transpose()? else {
+            return Ok(None)
+        };
+
+        let block = payload.block();
+        if block.parent_hash() != parent_hash {
+            return Ok(None)
+        }
+
+        let response = BuilderApiResponse {
+            version: fork_name(block.header()).to_string(),
+            data: SignedBuilderBid {
+                message: builder_bid(&payload)?,
+                signature: Default::default(),
+            },
+        };
+        trace!(
+            target: "rpc::builder",
+            block_hash = ?block.hash(),
+            value = %payload.fees(),
+            "Serving builder bid"
+        );
+
+        let block_hash = block.hash();
+        let mut bids = self.inner.bids.write();
+        bids.retain(|_, bid| bid.block().parent_hash() == parent_hash);
+        bids.insert(block_hash, payload);
+
+        Ok(Some(response))
+    }
+
+    /// Returns the full payload of a bid that was previously returned by [`Self::header`].
+    pub fn payload(
+        &self,
+        block_hash: B256,
+    ) -> Result<BuilderApiResponse<BuilderPayload>, PayloadBuilderError> {
+        let payload = self
+            .inner
+            .bids
+            .read()
+            .get(&block_hash)
+            .cloned()
+            .ok_or(PayloadBuilderError::MissingPayload)?;
+        let version = fork_name(payload.block().header()).to_string();
+
+        Ok(BuilderApiResponse { version, data: builder_payload(payload) })
+    }
+}
+
+#[async_trait]
+impl<Engine> BuilderApiServer for BuilderApi<Engine>
+where
+    Engine: PayloadTypes<BuiltPayload = EthBuiltPayload>,
+{
+    /// Handler for `builder_getHeader`
+    async fn get_header(
+        &self,
+        parent_hash: B256,
+    ) -> RpcResult<Option<BuilderApiResponse<SignedBuilderBid>>> {
+        trace!(target: "rpc::builder", ?parent_hash, "Serving builder_getHeader");
+        self.header(parent_hash).await.map_err(|err| internal_rpc_err(err.to_string()))
+    }
+
+    /// Handler for `builder_getPayload`
+    async fn get_payload(&self, block_hash: B256) -> RpcResult<BuilderApiResponse<BuilderPayload>> {
+        trace!(target: "rpc::builder", ?block_hash, "Serving builder_getPayload");
+        self.payload(block_hash).map_err(|err| internal_rpc_err(err.to_string()))
+    }
+}
+
+impl<Engine: PayloadTypes> Clone for BuilderApi<Engine> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<Engine: PayloadTypes> std::fmt::Debug for BuilderApi<Engine> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BuilderApi").finish_non_exhaustive()
+    }
+}
+
+/// Returns the name of the consensus layer fork that the header's fields belong to, which is used
+/// as the version of builder API responses.
+fn fork_name<H: BlockHeader>(header: &H) -> &'static str {
+    if header.requests_hash().is_some() {
+        "electra"
+    } else if header.blob_gas_used().is_some() {
+        "deneb"
+    } else if header.withdrawals_root().is_some() {
+        "capella"
+    } else {
+        "bellatrix"
+    }
+}
+
+/// Returns the unsigned bid for the payload.
+///
+/// The blob commitments are set since Deneb and the execution requests since Electra.
+fn builder_bid(payload: &EthBuiltPayload) -> Result<BuilderBid, PayloadBuilderError> {
+    let block = payload.block();
+    let blob_kzg_commitments = block.blob_gas_used().is_some().then(|| {
+        payload.sidecars().iter().flat_map(|sidecar| sidecar.commitments.iter().copied()).collect()
+    });
+    let execution_requests = block
+        .requests_hash()
+        .is_some()
+        .then(|| ExecutionRequestsV4::try_from(&payload.requests().unwrap_or_default()))
+        .transpose()
+        .map_err(PayloadBuilderError::other)?;
+
+    Ok(BuilderBid {
+        header: payload_header(block),
+        blob_kzg_commitments,
+        execution_requests,
+        value: payload.fees(),
+        pubkey: Default::default(),
+    })
+}
+
+/// Converts the payload into the full payload of a bid, with the blobs bundle since Deneb.
+fn builder_payload(payload: EthBuiltPayload) -> BuilderPayload {
+    if payload.block().blob_gas_used().is_some() {
+        let envelope = ExecutionPayloadEnvelopeV3::from(payload);
+        BuilderPayload::V3(ExecutionPayloadAndBlobsBundle {
+            execution_payload: envelope.execution_payload,
+            blobs_bundle: envelope.blobs_bundle,
+        })
+    } else {
+        match ExecutionPayloadEnvelopeV2::from(payload).execution_payload {
+            ExecutionPayloadFieldV2::V1(payload) => BuilderPayload::V1(payload),
+            ExecutionPayloadFieldV2::V2(payload) => BuilderPayload::V2(payload),
+        }
+    }
+}
+
+/// Converts the header of the block into the builder API execution payload header.
+fn payload_header<B: reth_primitives_traits::Block>(
+    block: &SealedBlock<B>,
+) -> ExecutionPayloadHeader {
+    ExecutionPayloadHeader {
+        parent_hash: block.parent_hash(),
+        fee_recipient: block.beneficiary(),
+        state_root: block.state_root(),
+        receipts_root: block.receipts_root(),
+        logs_bloom: block.logs_bloom(),
+        prev_randao: block.mix_hash().unwrap_or_default(),
+        block_number: block.number(),
+        gas_limit: block.gas_limit(),
+        gas_used: block.gas_used(),
+        timestamp: block.timestamp(),
+        extra_data: block.extra_data().clone(),
+        base_fee_per_gas: U256::from(block.base_fee_per_gas().unwrap_or_default()),
+        block_hash: block.hash(),
+        transactions_root: block.transactions_root(),
+        withdrawals_root: block.withdrawals_root(),
+        blob_gas_used: block.blob_gas_used(),
+        excess_blob_gas: block.excess_blob_gas(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{BlobTransactionSidecar, Header};
+    use alloy_eips::eip4844::{Blob, Bytes48};
+    use reth_ethereum_primitives::{Block, BlockBody};
+
+    fn payload(header: Header, sidecars: Vec<BlobTransactionSidecar>) -> EthBuiltPayload {
+        let body = BlockBody {
+            withdrawals: header.withdrawals_root.map(|_| Default::default()),
+            ..Default::default()
+        };
+        let block = SealedBlock::seal_slow(Block { header, body });
+        EthBuiltPayload::new(PayloadId::default(), Arc::new(block), U256::from(7), None)
+            .with_sidecars(sidecars)
+    }
+
+    fn capella_header() -> Header {
+        Header { withdrawals_root: Some(B256::ZERO), ..Default::default() }
+    }
+
+    fn deneb_header() -> Header {
+        Header {
+            blob_gas_used: Some(131072),
+            excess_blob_gas: Some(0),
+            parent_beacon_block_root: Some(B256::ZERO),
+            ..capella_header()
+        }
+    }
+
+    fn sidecar() -> BlobTransactionSidecar {
+        BlobTransactionSidecar {
+            blobs: vec![Blob::default()],
+            commitments: vec![Bytes48::with_last_byte(1)],
+            proofs: vec![Bytes48::with_last_byte(2)],
+        }
+    }
+
+    #[test]
+    fn fork_name_from_header_fields() {
+        let mut header = Header::default();
+        assert_eq!(fork_name(&header), "bellatrix");
+        header.withdrawals_root = Some(B256::ZERO);
+        assert_eq!(fork_name(&header), "capella");
+        header.blob_gas_used = Some(0);
+        assert_eq!(fork_name(&header), "deneb");
+        header.requests_hash = Some(B256::ZERO);
+        assert_eq!(fork_name(&header), "electra");
+    }
+
+    #[test]
+    fn bid_has_fields_of_the_fork() {
+        let bid = builder_bid(&payload(capella_header(), vec![])).unwrap();
+        assert_eq!(bid.header.withdrawals_root, Some(B256::ZERO));
+        assert_eq!(bid.header.blob_gas_used, None);
+        assert_eq!(bid.blob_kzg_commitments, None);
+        assert_eq!(bid.execution_requests, None);
+        assert_eq!(bid.value, U256::from(7));
+
+        let bid = builder_bid(&payload(deneb_header(), vec![sidecar()])).unwrap();
+        assert_eq!(bid.header.blob_gas_used, Some(131072));
+        assert_eq!(bid.header.excess_blob_gas, Some(0));
+        assert_eq!(bid.blob_kzg_commitments, Some(vec![Bytes48::with_last_byte(1)]));
+        assert_eq!(bid.execution_requests, None);
+
+        // uint64 fields are encoded as decimal strings
+        let json = serde_json::to_value(&bid.header).unwrap();
+        assert_eq!(json["blob_gas_used"], "131072");
+    }
+
+    #[test]
+    fn payload_has_blobs_bundle_since_deneb() {
+        assert!(matches!(
+            builder_payload(payload(capella_header(), vec![])),
+            BuilderPayload::V2(_)
+        ));
+
+        let BuilderPayload::V3(payload) = builder_payload(payload(deneb_header(), vec![sidecar()]))
+        else {
+            panic!("expected a payload with blobs bundle")
+        };
+        assert_eq!(payload.blobs_bundle.commitments, vec![Bytes48::with_last_byte(1)]);
+        assert_eq!(payload.blobs_bundle.proofs, vec![Bytes48::with_last_byte(2)]);
+        assert_eq!(payload.blobs_bundle.blobs.len(), 1);
+    }
+}
@@ -0,0 +1,511 @@
+//! Contract creation index and the `eth_getContractCreation` endpoint.
+
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{Address, BlockHash, BlockNumber};
+use alloy_rpc_types_trace::parity::{
+    Action, CreateAction, CreateOutput, TraceOutput, TransactionTrace,
+};
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use jsonrpsee::core::RpcResult;
+use reth_db::{
+    cursor::DbCursorRO,
+    models::{ContractCreation, IndexCheckpoint},
+    tables,
+    transaction::{DbTx, DbTxMut},
+};
+use reth_primitives_traits::NodePrimitives;
+use reth_provider::{
+    BlockIdReader, CanonStateNotification, DBProvider, DatabaseProviderFactory, ProviderResult,
+};
+use reth_prune_types::PruneMode;
+use reth_rpc_api::EthContractCreationApiServer;
+use reth_rpc_eth_api::{
+    helpers::{SpawnBlocking, TraceExt},
+    FromEthApiError,
+};
+use reth_rpc_eth_types::EthApiError;
+use revm_inspectors::tracing::TracingInspectorConfig;
+use tracing::{debug, trace, warn};
+
+/// The id of the contract creation index in [`tables::IndexCheckpoints`].
+const CONTRACT_INDEX_ID: &str = "ContractCreations";
+
+/// The maximum number of blocks that are traced and written in one database transaction.
+const BACKFILL_BATCH_SIZE: u64 = 100;
+
+/// Maintains the contract creation index from the traces of canonical blocks.
+///
+/// For every contract created by a `CREATE` or `CREATE2` in a canonical block, the block, the
+/// transaction and the creator are written to [`tables::ContractCreations`]. Only the first
+/// creation of an address is kept.
+///
+/// The last indexed block is saved in [`tables::IndexCheckpoints`], and on every canonical state
+/// notification the index catches up with the canonical chain from there. Blocks that are missed,
+/// e.g. while the index was disabled or the notifications lagged, are therefore indexed later.
+#[derive(Debug)]
+pub struct ContractIndexer<Provider, Eth> {
+    /// Provider to read the canonical chain and to read and write the index.
+    provider: Provider,
+    /// Eth API to trace the canonical blocks.
+    eth: Eth,
+    /// If set, entries of blocks that are pruned according to this mode are removed, and only
+    /// blocks that are kept are indexed.
+    prune_mode: Option<PruneMode>,
+}
+
+impl<Provider, Eth> ContractIndexer<Provider, Eth>
+where
+    Provider: DatabaseProviderFactory + BlockIdReader + Clone + 'static,
+    Eth: TraceExt + 'static,
+{
+    /// Creates a new indexer.
+    pub const fn new(provider: Provider, eth: Eth, prune_mode: Option<PruneMode>) -> Self {
+        Self { provider, eth, prune_mode }
+    }
+
+    /// Runs the indexer until the notification stream ends.
+    pub async fn run<St, N>(self, mut notifications: St)
+    where
+        St: Stream<Item = CanonStateNotification<N>> + Unpin,
+        N: NodePrimitives,
+    {
+        self.update(None).await;
+        while let Some(notification) = notifications.next().await {
+            self.update(notification.reverted().map(|chain| chain.fork_block())).await;
+        }
+        debug!(target: "rpc::contract_index", "Canonical state notification stream ended");
+    }
+
+    /// Unwinds the index to the fork block of reverted blocks, if any, and catches up with the
+    /// canonical chain.
+    ///
+    /// Failures are retried with the next notification.
+    async fn update(&self, fork_block: Option<BlockNumHash>) {
+        if let Err(err) = self.catch_up(fork_block).await {
+            warn!(target: "rpc::contract_index", %err, "Failed to update contract creation index");
+        }
+    }
+
+    /// Indexes all canonical blocks after the checkpoint, in batches of [`BACKFILL_BATCH_SIZE`]
+    /// blocks.
+    ///
+    /// Every batch is written together with its checkpoint, so a block that can't be traced stops
+    /// the catch up instead of being skipped.
+    async fn catch_up(&self, fork_block: Option<BlockNumHash>) -> Result<(), Eth::Error> {
+        let checkpoint = self.canonical_checkpoint(fork_block).await?;
+        let tip = self.provider.best_block_number().map_err(Eth::Error::from_eth_err)?;
+
+        let mut next_block = checkpoint
+            .map_or_else(|| self.first_block(tip), |checkpoint| checkpoint.block_number + 1);
+        while next_block <= tip {
+            let mut creations = Vec::new();
+            let mut last_block = None;
+            for number in next_block..=tip.min(next_block + BACKFILL_BATCH_SIZE - 1) {
+                let Some(hash) =
+                    self.provider.block_hash(number).map_err(Eth::Error::from_eth_err)?
+                else {
+                    break
+                };
+                creations.extend(self.block_creations(number, hash).await?);
+                last_block = Some(IndexCheckpoint { block_number: number, block_hash: hash });
+            }
+            let Some(last_block) = last_block else { break };
+
+            trace!(target: "rpc::contract_index", from = next_block, to = last_block.block_number, creations = creations.len(), "Indexing contract creations");
+            self.write(creations, last_block, tip).await?;
+            next_block = last_block.block_number + 1;
+        }
+        Ok(())
+    }
+
+    /// Returns the first block to index if the index is empty, which is the first block that is
+    /// kept by the prune mode of the index.
+    fn first_block(&self, tip: BlockNumber) -> BlockNumber {
+        let first_block = match self.prune_mode {
+            Some(PruneMode::Distance(distance)) => tip.saturating_sub(distance),
+            Some(PruneMode::Before(block_number)) => block_number,
+            Some(PruneMode::Full) => tip + 1,
+            None => 0,
+        };
+        // the genesis block has no transactions to trace
+        first_block.max(1)
+    }
+
+    /// Returns the checkpoint of the index, after unwinding the index to the fork block of
+    /// reverted blocks.
+    ///
+    /// If the last indexed block is no longer canonical without a notification, e.g. because the
+    /// pipeline unwound it or the notifications lagged, the fork block is unknown and the index
+    /// is unwound to the finalized block.
+    async fn canonical_checkpoint(
+        &self,
+        fork_block: Option<BlockNumHash>,
+    ) -> Result<Option<IndexCheckpoint>, Eth::Error> {
+        let provider = self.provider.clone();
+        self.eth
+            .spawn_blocking_io(move |_| {
+                let checkpoint = provider
+                    .database_provider_ro()
+                    .and_then(|provider| {
+                        Ok(provider
+                            .tx_ref()
+                            .get::<tables::IndexCheckpoints>(CONTRACT_INDEX_ID.to_string())?)
+                    })
+                    .map_err(Eth::Error::from_eth_err)?;
+                let Some(checkpoint) = checkpoint else { return Ok(None) };
+
+                let unwind_to = if let Some(fork_block) =
+                    fork_block.filter(|fork_block| fork_block.number < checkpoint.block_number)
+                {
+                    Some(IndexCheckpoint {
+                        block_number: fork_block.number,
+                        block_hash: fork_block.hash,
+                    })
+                } else if provider
+                    .block_hash(checkpoint.block_number)
+                    .map_err(Eth::Error::from_eth_err)? !=
+                    Some(checkpoint.block_hash)
+                {
+                    finalized_checkpoint(&provider, checkpoint.block_number)
+                        .map_err(Eth::Error::from_eth_err)?
+                } else {
+                    return Ok(Some(checkpoint))
+                };
+
+                debug!(target: "rpc::contract_index", from = ?checkpoint, to = ?unwind_to, "Unwinding contract creation index");
+                provider
+                    .database_provider_rw()
+                    .and_then(|provider| {
+                        unwind_index(provider.tx_ref(), unwind_to)?;
+                        provider.commit()?;
+                        Ok(unwind_to)
+                    })
+                    .map_err(Eth::Error::from_eth_err)
+            })
+            .await
+    }
+
+    /// Traces the block and returns all successful contract creations, in execution order.
+    async fn block_creations(
+        &self,
+        number: BlockNumber,
+        hash: BlockHash,
+    ) -> Result<Vec<(Address, ContractCreation)>, Eth::Error> {
+        let creations = self
+            .eth
+            .trace_block_with(
+                hash.into(),
+                None,
+                TracingInspectorConfig::default_parity(),
+                move |tx_info, inspector, _, _, _| {
+                    let Some(tx_hash) = tx_info.hash else { return Ok(Vec::new()) };
+                    let traces = inspector
+                        .into_parity_builder()
+                        .into_localized_transaction_traces(tx_info)
+                        .into_iter()
+                        .map(|tx_trace| tx_trace.trace)
+                        .collect::<Vec<_>>();
+                    let creations = successful_creations(&traces)
+                        .into_iter()
+                        .map(|(creator, address)| {
+                            (address, ContractCreation { block_number: number, tx_hash, creator })
+                        })
+                        .collect::<Vec<_>>();
+                    Ok(creations)
+                },
+            )
+            .await?
+            .ok_or(EthApiError::HeaderNotFound(hash.into()))?;
+        Ok(creations.into_iter().flatten().collect())
+    }
+
+    /// Writes the creations of the blocks up to `checkpoint` on a blocking task and prunes the
+    /// index relative to the tip.
+    async fn write(
+        &self,
+        creations: Vec<(Address, ContractCreation)>,
+        checkpoint: IndexCheckpoint,
+        tip: BlockNumber,
+    ) -> Result<(), Eth::Error> {
+        let provider = self.provider.clone();
+        let prune_mode = self.prune_mode;
+        self.eth
+            .spawn_blocking_io(move |_| {
+                provider
+                    .database_provider_rw()
+                    .and_then(|provider| {
+                        write_creations(
+                            provider.tx_ref(),
+                            &creations,
+                            checkpoint,
+                            prune_mode.map(|prune_mode| (prune_mode, tip)),
+                        )?;
+                        provider.commit()?;
+                        Ok(())
+                    })
+                    .map_err(Eth::Error::from_eth_err)
+            })
+            .await
+    }
+}
+
+/// Returns the finalized block as checkpoint, if it's below `block_number`.
+fn finalized_checkpoint<Provider: BlockIdReader>(
+    provider: &Provider,
+    block_number: BlockNumber,
+) -> ProviderResult<Option<IndexCheckpoint>> {
+    let Some(finalized) =
+        provider.finalized_block_number()?.filter(|finalized| *finalized < block_number)
+    else {
+        return Ok(None)
+    };
+    Ok(provider
+        .block_hash(finalized)?
+        .map(|block_hash| IndexCheckpoint { block_number: finalized, block_hash }))
+}
+
+/// Returns the contracts created in the traces of a transaction as `(creator, address)`, in
+/// execution order.
+///
+/// Creations that failed, or that were reverted together with one of their parent calls, are
+/// skipped.
+fn successful_creations(traces: &[TransactionTrace]) -> Vec<(Address, Address)> {
+    let failed = traces
+        .iter()
+        .filter(|trace| trace.error.is_some())
+        .map(|trace| trace.trace_address.as_slice())
+        .collect::<Vec<_>>();
+    traces
+        .iter()
+        .filter(|trace| !failed.iter().any(|failed| trace.trace_address.starts_with(failed)))
+        .filter_map(|trace| match (&trace.action, &trace.result) {
+            (
+                Action::Create(CreateAction { from: creator, .. }),
+                Some(TraceOutput::Create(CreateOutput { address, .. })),
+            ) => Some((*creator, *address)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Writes the creations to the index, moves the checkpoint to `checkpoint` and, if a prune mode
+/// and the tip are given, prunes the index relative to the tip.
+fn write_creations<Tx: DbTx + DbTxMut>(
+    tx: &Tx,
+    creations: &[(Address, ContractCreation)],
+    checkpoint: IndexCheckpoint,
+    prune: Option<(PruneMode, BlockNumber)>,
+) -> ProviderResult<()> {
+    for (address, creation) in creations {
+        // keep the first creation of addresses that are recreated after a selfdestruct
+        if tx.get::<tables::ContractCreations>(*address)?.is_some() {
+            continue
+        }
+        tx.put::<tables::ContractCreations>(*address, *creation)?;
+        tx.put::<tables::BlockContractCreations>(creation.block_number, *address)?;
+    }
+    tx.put::<tables::IndexCheckpoints>(CONTRACT_INDEX_ID.to_string(), checkpoint)?;
+
+    if let Some((prune_mode, tip)) = prune {
+        remove_blocks(tx, .., |block| prune_mode.should_prune(block, tip))?;
+    }
+    Ok(())
+}
+
+/// Removes the entries of all blocks after `checkpoint` and moves the checkpoint there.
+///
+/// If `checkpoint` is `None`, the index is cleared.
+fn unwind_index<Tx: DbTx + DbTxMut>(
+    tx: &Tx,
+    checkpoint: Option<IndexCheckpoint>,
+) -> ProviderResult<()> {
+    remove_blocks(tx, checkpoint.map_or(0, |checkpoint| checkpoint.block_number + 1).., |_| true)?;
+    if let Some(checkpoint) = checkpoint {
+        tx.put::<tables::IndexCheckpoints>(CONTRACT_INDEX_ID.to_string(), checkpoint)?;
+    } else {
+        tx.delete::<tables::IndexCheckpoints>(CONTRACT_INDEX_ID.to_string(), None)?;
+    }
+    Ok(())
+}
+
+/// Removes the entries of the blocks in the range from the index, until `predicate` returns
+/// `false` for a block.
+fn remove_blocks<Tx: DbTx + DbTxMut>(
+    tx: &Tx,
+    range: impl std::ops::RangeBounds<BlockNumber>,
+    predicate: impl Fn(BlockNumber) -> bool,
+) -> ProviderResult<()> {
+    let mut blocks = Vec::new();
+    let mut cursor = tx.cursor_read::<tables::BlockContractCreations>()?;
+    for entry in cursor.walk_range(range)? {
+        let (block, address) = entry?;
+        if !predicate(block) {
+            break
+        }
+        if blocks.last() != Some(&block) {
+            blocks.push(block);
+        }
+        // the address may have been created in an earlier block that is still indexed
+        if tx
+            .get::<tables::ContractCreations>(address)?
+            .is_some_and(|creation| creation.block_number == block)
+        {
+            tx.delete::<tables::ContractCreations>(address, None)?;
+        }
+    }
+
+    for block in blocks {
+        tx.delete::<tables::BlockContractCreations>(block, None)?;
+    }
+    Ok(())
+}
+
+/// `eth` API implementation for the contract creation index.
+#[derive(Debug, Clone)]
+pub struct ContractCreationApi<Provider> {
+    /// Database provider factory to read the index.
+    provider: Provider,
+}
+
+impl<Provider> ContractCreationApi<Provider> {
+    /// Creates a new instance of the [`ContractCreationApi`].
+    pub const fn new(provider: Provider) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl<Provider> EthContractCreationApiServer for ContractCreationApi<Provider>
+where
+    Provider: DatabaseProviderFactory + 'static,
+{
+    /// Handler for `eth_getContractCreation`
+    async fn get_contract_creation(&self, address: Address) -> RpcResult<Option<ContractCreation>> {
+        let creation = self
+            .provider
+            .database_provider_ro()
+            .and_then(|provider| Ok(provider.tx_ref().get::<tables::ContractCreations>(address)?))
+            .map_err(EthApiError::from)?;
+        Ok(creation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use reth_provider::test_utils::create_test_provider_factory;
+    use serde_json::json;
+
+    fn call_trace(trace_address: &[usize], subtraces: usize, error: bool) -> TransactionTrace {
+        let mut trace = json!({
+            "action": {
+                "callType": "call",
+                "from": Address::ZERO,
+                "gas": "0x0",
+                "input": "0x",
+                "to": Address::ZERO,
+                "value": "0x0"
+            },
+            "result": { "gasUsed": "0x0", "output": "0x" },
+            "subtraces": subtraces,
+            "traceAddress": trace_address,
+            "type": "call"
+        });
+        if error {
+            trace["error"] = json!("Reverted");
+            trace["result"] = json!(null);
+        }
+        serde_json::from_value(trace).unwrap()
+    }
+
+    fn create_trace(trace_address: &[usize], address: Address, error: bool) -> TransactionTrace {
+        let mut trace = json!({
+            "action": {
+                "from": Address::with_last_byte(0xff),
+                "gas": "0x0",
+                "init": "0x",
+                "value": "0x0"
+            },
+            "result": { "address": address, "code": "0x", "gasUsed": "0x0" },
+            "subtraces": 0,
+            "traceAddress": trace_address,
+            "type": "create"
+        });
+        if error {
+            trace["error"] = json!("Reverted");
+            trace["result"] = json!(null);
+        }
+        serde_json::from_value(trace).unwrap()
+    }
+
+    #[test]
+    fn skips_reverted_creations() {
+        let traces = vec![
+            call_trace(&[], 3, false),
+            create_trace(&[0], Address::with_last_byte(1), false),
+            call_trace(&[1], 1, true),
+            create_trace(&[1, 0], Address::with_last_byte(2), false),
+            create_trace(&[2], Address::with_last_byte(3), true),
+        ];
+        assert_eq!(
+            successful_creations(&traces),
+            vec![(Address::with_last_byte(0xff), Address::with_last_byte(1))]
+        );
+
+        // nothing is created if the transaction reverts
+        let traces =
+            vec![call_trace(&[], 1, true), create_trace(&[0], Address::with_last_byte(1), false)];
+        assert!(successful_creations(&traces).is_empty());
+    }
+
+    #[test]
+    fn write_unwind_and_prune_index() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+
+        let first = Address::with_last_byte(1);
+        let second = Address::with_last_byte(2);
+        let creation = |block_number: BlockNumber| ContractCreation {
+            block_number,
+            tx_hash: B256::with_last_byte(block_number as u8),
+            creator: Address::ZERO,
+        };
+        let checkpoint = |block_number: BlockNumber| IndexCheckpoint {
+            block_number,
+            block_hash: B256::with_last_byte(block_number as u8),
+        };
+        let get_checkpoint =
+            || tx.get::<tables::IndexCheckpoints>(CONTRACT_INDEX_ID.to_string()).unwrap();
+
+        write_creations(tx, &[(first, creation(1)), (second, creation(2))], checkpoint(2), None)
+            .unwrap();
+        // the first creation of a recreated contract is kept
+        write_creations(tx, &[(first, creation(3))], checkpoint(3), None).unwrap();
+        assert_eq!(tx.get::<tables::ContractCreations>(first).unwrap(), Some(creation(1)));
+        assert_eq!(get_checkpoint(), Some(checkpoint(3)));
+
+        unwind_index(tx, Some(checkpoint(1))).unwrap();
+        assert_eq!(tx.get::<tables::ContractCreations>(first).unwrap(), Some(creation(1)));
+        assert_eq!(tx.get::<tables::ContractCreations>(second).unwrap(), None);
+        assert_eq!(get_checkpoint(), Some(checkpoint(1)));
+
+        // block 1 is pruned with a distance of 3 from block 5
+        write_creations(
+            tx,
+            &[(second, creation(5))],
+            checkpoint(5),
+            Some((PruneMode::Distance(3), 5)),
+        )
+        .unwrap();
+        assert_eq!(tx.get::<tables::ContractCreations>(first).unwrap(), None);
+        assert_eq!(tx.get::<tables::ContractCreations>(second).unwrap(), Some(creation(5)));
+        assert_eq!(tx.get::<tables::BlockContractCreations>(1).unwrap(), None);
+
+        unwind_index(tx, None).unwrap();
+        assert_eq!(tx.get::<tables::ContractCreations>(second).unwrap(), None);
+        assert_eq!(get_checkpoint(), None);
+    }
+}
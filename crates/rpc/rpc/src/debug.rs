@@ -26,7 +26,6 @@ use reth_primitives_traits::{
 use reth_provider::{
     BlockIdReader, BlockReaderIdExt, ChainSpecProvider, HeaderProvider, ProviderBlock,
     ReceiptProviderIdExt, StateProofProvider, StateProvider, StateProviderFactory,
-    TransactionVariant,
 };
 use reth_revm::{
     database::StateProviderDatabase,
@@ -38,7 +37,10 @@ use reth_rpc_eth_api::{
     helpers::{EthTransactions, TraceExt},
     EthApiTypes, FromEthApiError, RpcNodeCore,
 };
-use reth_rpc_eth_types::{EthApiError, StateCacheDb};
+use reth_rpc_eth_types::{
+    memory_budget::charge_request_memory_estimated, EthApiError, StateCacheDb,
+    StructLogBudgetInspector,
+};
 use reth_rpc_server_types::{result::internal_rpc_err, ToRpcResult};
 use reth_tasks::pool::BlockingTaskGuard;
 use revm::{context_interface::Transaction, state::EvmState, DatabaseCommit};
@@ -463,8 +465,10 @@ where
         let (res, tx_gas_limit, inspector) = self
             .eth_api()
             .spawn_with_call_at(call, at, overrides, move |db, evm_env, tx_env| {
+                let mut budget_inspector = StructLogBudgetInspector::new(&mut inspector, &config);
                 let (res, (_, tx_env)) =
-                    this.eth_api().inspect(db, evm_env, tx_env, &mut inspector)?;
+                    this.eth_api().inspect(db, evm_env, tx_env, &mut budget_inspector)?;
+                budget_inspector.check().map_err(Eth::Error::from_eth_err)?;
                 Ok((res, tx_env.gas_limit(), inspector))
             })
             .await?;
@@ -684,6 +688,11 @@ where
     ///
     /// Note: this does not apply any state overrides if they're configured in the `opts`.
     ///
+    /// The trace is charged to the
+    /// [`RequestMemoryBudget`](reth_rpc_eth_types::RequestMemoryBudget) of the call, if any. Struct
+    /// logs are charged while they are recorded, so that the execution is stopped once the budget
+    /// is exceeded.
+    ///
     /// Caution: this is blocking and should be performed on a blocking task.
     fn trace_transaction(
         &self,
@@ -693,6 +702,31 @@ where
         db: &mut StateCacheDb<'_>,
         transaction_context: Option<TransactionContext>,
         fused_inspector: &mut Option<TracingInspector>,
+    ) -> Result<(GethTrace, EvmState), Eth::Error> {
+        let (trace, state) = self.build_transaction_trace(
+            opts,
+            evm_env,
+            tx_env,
+            db,
+            transaction_context,
+            fused_inspector,
+        )?;
+        // struct logs are already charged while they are recorded
+        if !matches!(trace, GethTrace::Default(_)) {
+            charge_request_memory_estimated(&trace).map_err(Eth::Error::from_eth_err)?;
+        }
+        Ok((trace, state))
+    }
+
+    /// Builds the trace of [`Self::trace_transaction`], charging only struct logs.
+    fn build_transaction_trace(
+        &self,
+        opts: &GethDebugTracingOptions,
+        evm_env: EvmEnvFor<Eth::Evm>,
+        tx_env: TxEnvFor<Eth::Evm>,
+        db: &mut StateCacheDb<'_>,
+        transaction_context: Option<TransactionContext>,
+        fused_inspector: &mut Option<TracingInspector>,
     ) -> Result<(GethTrace, EvmState), Eth::Error> {
         let GethDebugTracingOptions { config, tracer, tracer_config, .. } = opts;
 
@@ -827,11 +861,14 @@ where
         }
 
         // default structlog tracer
-        let mut inspector = fused_inspector.get_or_insert_with(|| {
+        let inspector = fused_inspector.get_or_insert_with(|| {
             let inspector_config = TracingInspectorConfig::from_geth_config(config);
             TracingInspector::new(inspector_config)
         });
-        let (res, (_, tx_env)) = self.eth_api().inspect(db, evm_env, tx_env, &mut inspector)?;
+        let mut budget_inspector = StructLogBudgetInspector::new(&mut *inspector, config);
+        let (res, (_, tx_env)) =
+            self.eth_api().inspect(db, evm_env, tx_env, &mut budget_inspector)?;
+        budget_inspector.check().map_err(Eth::Error::from_eth_err)?;
         let gas_used = res.result.gas_used();
         let return_value = res.result.into_output().unwrap_or_default();
         inspector.set_transaction_gas_limit(tx_env.gas_limit());
@@ -894,14 +931,14 @@ where
     }
 
     /// Handler for `debug_getRawTransactions`
-    /// Returns the bytes of the transaction for the given hash.
+    ///
+    /// Returns the EIP-2718 encoded transactions of the given block. The transactions are
+    /// re-encoded as stored, without recovering their senders.
     async fn raw_transactions(&self, block_id: BlockId) -> RpcResult<Vec<Bytes>> {
-        let block = self
-            .provider()
-            .block_with_senders_by_id(block_id, TransactionVariant::NoHash)
-            .to_rpc_result()?
-            .unwrap_or_default();
-        Ok(block.into_transactions_recovered().map(|tx| tx.encoded_2718().into()).collect())
+        let Some(block) = self.provider().block_by_id(block_id).to_rpc_result()? else {
+            return Ok(Vec::new())
+        };
+        Ok(block.body().transactions_iter().map(|tx| tx.encoded_2718().into()).collect())
     }
 
     /// Handler for `debug_getRawReceipts`
@@ -986,9 +1023,14 @@ where
         opts: Option<GethDebugTracingCallOptions>,
     ) -> RpcResult<GethTrace> {
         let _permit = self.acquire_trace_permit().await;
-        Self::debug_trace_call(self, request, block_id, opts.unwrap_or_default())
+        let trace = Self::debug_trace_call(self, request, block_id, opts.unwrap_or_default())
             .await
-            .map_err(Into::into)
+            .map_err(Into::into)?;
+        // struct logs are already charged while they are recorded
+        if !matches!(trace, GethTrace::Default(_)) {
+            charge_request_memory_estimated(&trace).map_err(EthApiError::from)?;
+        }
+        Ok(trace)
     }
 
     async fn debug_trace_call_many(
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_engine_primitives::{
+    EngineStateSnapshotError, EngineStateSnapshotHandle, EngineStateSnapshotId,
+};
+use reth_rpc_api::DebugEngineStateApiServer;
+use reth_rpc_server_types::result::{internal_rpc_err, invalid_params_rpc_err};
+use tracing::trace;
+
+/// `debug` API implementation to capture and restore the in-memory state of the engine.
+#[derive(Debug, Clone)]
+pub struct DebugEngineStateApi {
+    /// The handle to the engine.
+    engine: EngineStateSnapshotHandle,
+}
+
+impl DebugEngineStateApi {
+    /// Create a new instance of the [`DebugEngineStateApi`]
+    pub const fn new(engine: EngineStateSnapshotHandle) -> Self {
+        Self { engine }
+    }
+}
+
+#[async_trait]
+impl DebugEngineStateApiServer for DebugEngineStateApi {
+    /// Handler for `debug_captureEngineState`
+    async fn capture_engine_state(&self) -> RpcResult<EngineStateSnapshotId> {
+        trace!(target: "rpc::debug", "Serving debug_captureEngineState");
+        self.engine.capture().await.map_err(|err| internal_rpc_err(err.to_string()))
+    }
+
+    /// Handler for `debug_restoreEngineState`
+    async fn restore_engine_state(&self, id: EngineStateSnapshotId) -> RpcResult<()> {
+        trace!(target: "rpc::debug", id, "Serving debug_restoreEngineState");
+        self.engine.restore(id).await.map_err(|err| match err {
+            EngineStateSnapshotError::UnknownSnapshot(_) => invalid_params_rpc_err(err.to_string()),
+            _ => internal_rpc_err(err.to_string()),
+        })
+    }
+}
@@ -0,0 +1,122 @@
+//! `eth_getBlockByNumber` with response options.
+
+use alloy_eips::BlockNumberOrTag;
+use jsonrpsee::core::RpcResult;
+use reth_rpc_eth_api::{helpers::EthBlocks, EthBlockResponseApiServer, FullEthApiTypes, RpcBlock};
+use reth_rpc_eth_types::BlockResponseOptions;
+use tracing::trace;
+
+/// `eth_getBlockByNumber` implementation that supports [`BlockResponseOptions`].
+///
+/// This replaces the `eth_getBlockByNumber` handler of the `eth` module.
+#[derive(Debug, Clone)]
+pub struct EthBlockResponse<Eth> {
+    eth_api: Eth,
+}
+
+impl<Eth> EthBlockResponse<Eth> {
+    /// Creates a new instance that serves blocks from the given `eth` API.
+    pub const fn new(eth_api: Eth) -> Self {
+        Self { eth_api }
+    }
+}
+
+#[async_trait::async_trait]
+impl<Eth> EthBlockResponseApiServer<RpcBlock<Eth::NetworkTypes>> for EthBlockResponse<Eth>
+where
+    Eth: EthBlocks + FullEthApiTypes + 'static,
+{
+    /// Handler for: `eth_getBlockByNumber`
+    async fn block_by_number_with_options(
+        &self,
+        number: BlockNumberOrTag,
+        full: bool,
+        options: Option<BlockResponseOptions>,
+    ) -> RpcResult<Option<RpcBlock<Eth::NetworkTypes>>> {
+        trace!(target: "rpc::eth", ?number, ?full, ?options, "Serving eth_getBlockByNumber");
+        let Some(options) = options else {
+            return Ok(self.eth_api.rpc_block(number.into(), full).await?)
+        };
+        // there's no need to convert the transactions if they are omitted
+        let full = full && !options.omit_transactions;
+        let mut block = self.eth_api.rpc_block(number.into(), full).await?;
+        if let Some(block) = &mut block {
+            options.apply(block);
+        }
+        Ok(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EthApiBuilder;
+    use alloy_consensus::{Block, BlockBody, Header};
+    use alloy_eips::eip4895::Withdrawals;
+    use alloy_primitives::{PrimitiveSignature as Signature, B256};
+    use jsonrpsee::rpc_params;
+    use reth_ethereum_primitives::TransactionSigned;
+    use reth_evm_ethereum::EthEvmConfig;
+    use reth_network_api::noop::NoopNetwork;
+    use reth_provider::{test_utils::MockEthProvider, ChainSpecProvider};
+    use reth_transaction_pool::test_utils::testing_pool;
+
+    fn block_response() -> jsonrpsee::RpcModule<()> {
+        let provider = MockEthProvider::default();
+        let transaction = TransactionSigned::new_unhashed(
+            reth_ethereum_primitives::Transaction::Legacy(Default::default()),
+            Signature::test_signature(),
+        );
+        provider.add_block(
+            B256::with_last_byte(1),
+            Block {
+                header: Header::default(),
+                body: BlockBody {
+                    transactions: vec![transaction],
+                    withdrawals: Some(Withdrawals::default()),
+                    ..Default::default()
+                },
+            },
+        );
+        let eth_api = EthApiBuilder::new(
+            provider.clone(),
+            testing_pool(),
+            NoopNetwork::default(),
+            EthEvmConfig::new(provider.chain_spec()),
+        )
+        .build();
+
+        let mut module = jsonrpsee::RpcModule::new(());
+        module.merge(EthBlockResponse::new(eth_api).into_rpc()).unwrap();
+        module
+    }
+
+    #[tokio::test]
+    async fn serves_blocks_without_options() {
+        let module = block_response();
+
+        let block: serde_json::Value =
+            module.call("eth_getBlockByNumber", rpc_params!["0x0", false]).await.unwrap();
+        assert_eq!(block["transactions"].as_array().unwrap().len(), 1);
+        assert_eq!(block["withdrawals"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn omits_fields_with_options() {
+        let module = block_response();
+
+        let block: serde_json::Value = module
+            .call(
+                "eth_getBlockByNumber",
+                rpc_params![
+                    "0x0",
+                    true,
+                    serde_json::json!({ "omitTransactions": true, "omitWithdrawals": true })
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(block["transactions"], serde_json::json!([]));
+        assert!(block.get("withdrawals").is_none());
+    }
+}
@@ -4,13 +4,15 @@ use crate::{
     eth::{core::EthApiInner, EthTxBuilder},
     EthApi,
 };
-use reth_node_api::NodePrimitives;
+use reth_chainspec::EthChainSpec;
+use reth_evm::CallEnvOverrides;
+use reth_primitives_traits::NodePrimitives;
 use reth_provider::{
     BlockReaderIdExt, CanonStateSubscriptions, ChainSpecProvider, StateProviderFactory,
 };
 use reth_rpc_eth_types::{
     fee_history::fee_history_cache_new_blocks_task, EthStateCache, FeeHistoryCache,
-    FeeHistoryCacheConfig, GasCap, GasPriceOracle,
+    FeeHistoryCacheConfig, GasCap, GasPriceOracle, TxLookupConfig,
 };
 use reth_rpc_server_types::constants::{
     DEFAULT_ETH_PROOF_WINDOW, DEFAULT_MAX_SIMULATE_BLOCKS, DEFAULT_PROOF_PERMITS,
@@ -33,6 +35,10 @@ where
     evm_config: EvmConfig,
     gas_cap: GasCap,
     max_simulate_blocks: u64,
+    call_env_overrides: CallEnvOverrides,
+    tx_lookup: TxLookupConfig,
+    rpc_chain_id: Option<u64>,
+    syncing_stage_detail: bool,
     eth_proof_window: u64,
     fee_history_cache_config: FeeHistoryCacheConfig,
     proof_permits: usize,
@@ -60,6 +66,10 @@ where
             gas_oracle: None,
             gas_cap: GasCap::default(),
             max_simulate_blocks: DEFAULT_MAX_SIMULATE_BLOCKS,
+            call_env_overrides: CallEnvOverrides::default(),
+            tx_lookup: TxLookupConfig::default(),
+            rpc_chain_id: None,
+            syncing_stage_detail: false,
             eth_proof_window: DEFAULT_ETH_PROOF_WINDOW,
             blocking_task_pool: None,
             fee_history_cache_config: FeeHistoryCacheConfig::default(),
@@ -101,6 +111,30 @@ where
         self
     }
 
+    /// Sets the adjustments of the EVM environment for `eth_call` and `eth_estimateGas`.
+    pub const fn call_env_overrides(mut self, call_env_overrides: CallEnvOverrides) -> Self {
+        self.call_env_overrides = call_env_overrides;
+        self
+    }
+
+    /// Sets the configuration of lookups of transactions by hash.
+    pub const fn tx_lookup(mut self, tx_lookup: TxLookupConfig) -> Self {
+        self.tx_lookup = tx_lookup;
+        self
+    }
+
+    /// Sets the chain id reported by RPC instead of the execution chain id.
+    pub const fn rpc_chain_id(mut self, rpc_chain_id: Option<u64>) -> Self {
+        self.rpc_chain_id = rpc_chain_id;
+        self
+    }
+
+    /// Sets whether `eth_syncingDetail` includes the detail of the stage the pipeline is running.
+    pub const fn syncing_stage_detail(mut self, syncing_stage_detail: bool) -> Self {
+        self.syncing_stage_detail = syncing_stage_detail;
+        self
+    }
+
     /// Sets the maximum number of blocks into the past for generating state proofs.
     pub const fn eth_proof_window(mut self, eth_proof_window: u64) -> Self {
         self.eth_proof_window = eth_proof_window;
@@ -156,6 +190,10 @@ where
             gas_oracle,
             gas_cap,
             max_simulate_blocks,
+            call_env_overrides,
+            tx_lookup,
+            rpc_chain_id,
+            syncing_stage_detail,
             eth_proof_window,
             blocking_task_pool,
             fee_history_cache_config,
@@ -168,6 +206,9 @@ where
         let gas_oracle = gas_oracle.unwrap_or_else(|| {
             GasPriceOracle::new(provider.clone(), Default::default(), eth_cache.clone())
         });
+        // an override that matches the execution chain id is not an override
+        let execution_chain_id = provider.chain_spec().chain().id();
+        let rpc_chain_id = rpc_chain_id.filter(|id| *id != execution_chain_id);
         let fee_history_cache = FeeHistoryCache::new(fee_history_cache_config);
         let new_canonical_blocks = provider.canonical_state_stream();
         let fhc = fee_history_cache.clone();
@@ -188,6 +229,10 @@ where
             gas_oracle,
             gas_cap,
             max_simulate_blocks,
+            call_env_overrides,
+            tx_lookup,
+            rpc_chain_id,
+            syncing_stage_detail,
             eth_proof_window,
             blocking_task_pool.unwrap_or_else(|| {
                 BlockingTaskPool::build().expect("failed to build blocking task pool")
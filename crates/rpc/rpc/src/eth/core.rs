@@ -9,6 +9,8 @@ use alloy_eips::BlockNumberOrTag;
 use alloy_network::Ethereum;
 use alloy_primitives::{Bytes, U256};
 use derive_more::Deref;
+use reth_evm::CallEnvOverrides;
+#[cfg(feature = "node-api")]
 use reth_node_api::{FullNodeComponents, FullNodeTypes};
 use reth_provider::{
     BlockReader, BlockReaderIdExt, NodePrimitivesProvider, ProviderBlock, ProviderReceipt,
@@ -20,6 +22,7 @@ use reth_rpc_eth_api::{
 };
 use reth_rpc_eth_types::{
     EthApiError, EthStateCache, FeeHistoryCache, GasCap, GasPriceOracle, PendingBlock,
+    SyncStageProgress, TxLookupConfig,
 };
 use reth_tasks::{
     pool::{BlockingTaskGuard, BlockingTaskPool},
@@ -30,6 +33,7 @@ use tokio::sync::{broadcast, Mutex};
 const DEFAULT_BROADCAST_CAPACITY: usize = 2000;
 
 /// Helper type alias for [`EthApi`] with components from the given [`FullNodeComponents`].
+#[cfg(feature = "node-api")]
 pub type EthApiFor<N> = EthApi<
     <N as FullNodeTypes>::Provider,
     <N as FullNodeComponents>::Pool,
@@ -38,6 +42,7 @@ pub type EthApiFor<N> = EthApi<
 >;
 
 /// Helper type alias for [`EthApi`] with components from the given [`FullNodeComponents`].
+#[cfg(feature = "node-api")]
 pub type EthApiBuilderFor<N> = EthApiBuilder<
     <N as FullNodeTypes>::Provider,
     <N as FullNodeComponents>::Pool,
@@ -125,6 +130,7 @@ where
         gas_oracle: GasPriceOracle<Provider>,
         gas_cap: impl Into<GasCap>,
         max_simulate_blocks: u64,
+        call_env_overrides: CallEnvOverrides,
         eth_proof_window: u64,
         blocking_task_pool: BlockingTaskPool,
         fee_history_cache: FeeHistoryCache,
@@ -139,6 +145,10 @@ where
             gas_oracle,
             gas_cap,
             max_simulate_blocks,
+            call_env_overrides,
+            TxLookupConfig::default(),
+            None,
+            false,
             eth_proof_window,
             blocking_task_pool,
             fee_history_cache,
@@ -265,6 +275,14 @@ pub struct EthApiInner<Provider: BlockReader, Pool, Network, EvmConfig> {
     gas_cap: u64,
     /// Maximum number of blocks for `eth_simulateV1`.
     max_simulate_blocks: u64,
+    /// Adjustments of the EVM environment for `eth_call` and `eth_estimateGas`.
+    call_env_overrides: CallEnvOverrides,
+    /// Configuration of lookups of transactions by hash.
+    tx_lookup: TxLookupConfig,
+    /// Chain id reported by RPC instead of the execution chain id.
+    rpc_chain_id: Option<u64>,
+    /// Tracks the running stage for `eth_syncingDetail`, if it includes stage detail.
+    sync_stage_progress: Option<SyncStageProgress>,
     /// The maximum number of blocks into the past for generating state proofs.
     eth_proof_window: u64,
     /// The block number at which the node started
@@ -301,6 +319,10 @@ where
         gas_oracle: GasPriceOracle<Provider>,
         gas_cap: impl Into<GasCap>,
         max_simulate_blocks: u64,
+        call_env_overrides: CallEnvOverrides,
+        tx_lookup: TxLookupConfig,
+        rpc_chain_id: Option<u64>,
+        syncing_stage_detail: bool,
         eth_proof_window: u64,
         blocking_task_pool: BlockingTaskPool,
         fee_history_cache: FeeHistoryCache,
@@ -330,6 +352,10 @@ where
             gas_oracle,
             gas_cap: gas_cap.into().into(),
             max_simulate_blocks,
+            call_env_overrides,
+            tx_lookup,
+            rpc_chain_id,
+            sync_stage_progress: syncing_stage_detail.then(SyncStageProgress::default),
             eth_proof_window,
             starting_block,
             task_spawner,
@@ -403,6 +429,30 @@ where
         self.max_simulate_blocks
     }
 
+    /// Returns the adjustments of the EVM environment for `eth_call` and `eth_estimateGas`.
+    #[inline]
+    pub const fn call_env_overrides(&self) -> CallEnvOverrides {
+        self.call_env_overrides
+    }
+
+    /// Returns the configuration of lookups of transactions by hash.
+    #[inline]
+    pub const fn tx_lookup(&self) -> TxLookupConfig {
+        self.tx_lookup
+    }
+
+    /// Returns the chain id reported by RPC instead of the execution chain id, if any.
+    #[inline]
+    pub const fn rpc_chain_id(&self) -> Option<u64> {
+        self.rpc_chain_id
+    }
+
+    /// Returns the tracker of the running stage, if `eth_syncingDetail` includes stage detail.
+    #[inline]
+    pub const fn sync_stage_progress(&self) -> Option<&SyncStageProgress> {
+        self.sync_stage_progress.as_ref()
+    }
+
     /// Returns a handle to the gas oracle.
     #[inline]
     pub const fn gas_oracle(&self) -> &GasPriceOracle<Provider> {
@@ -91,8 +91,12 @@ where
     /// let filter = EthFilter::new(eth_api, Default::default(), TokioTaskExecutor::default().boxed());
     /// ```
     pub fn new(eth_api: Eth, config: EthFilterConfig, task_spawner: Box<dyn TaskSpawner>) -> Self {
-        let EthFilterConfig { max_blocks_per_filter, max_logs_per_response, stale_filter_ttl } =
-            config;
+        let EthFilterConfig {
+            max_blocks_per_filter,
+            max_logs_per_response,
+            stale_filter_ttl,
+            max_reorg_depth,
+        } = config;
         let inner = EthFilterInner {
             eth_api,
             active_filters: ActiveFilters::new(),
@@ -100,6 +104,7 @@ where
             max_headers_range: MAX_HEADERS_RANGE,
             task_spawner,
             stale_filter_ttl,
+            max_reorg_depth,
             // if not set, use the max value, which is effectively no limit
             max_blocks_per_filter: max_blocks_per_filter.unwrap_or(u64::MAX),
             max_logs_per_response: max_logs_per_response.unwrap_or(usize::MAX),
@@ -377,6 +382,8 @@ struct EthFilterInner<Eth: EthApiTypes> {
     task_spawner: Box<dyn TaskSpawner>,
     /// Duration since the last filter poll, after which the filter is considered stale
     stale_filter_ttl: Duration,
+    /// Number of blocks below the tip after which a block is assumed to be final
+    max_reorg_depth: u64,
 }
 
 impl<Eth> EthFilterInner<Eth>
@@ -476,9 +483,14 @@ where
 
     /// Returns all logs in the given _inclusive_ range that match the filter
     ///
+    /// The headers are read in batches, which are checked to be linked by their hashes and the
+    /// receipts are fetched by block hash, so all logs are from the same chain even if blocks are
+    /// added or reorged during the request.
+    ///
     /// Returns an error if:
     ///  - underlying database error
     ///  - amount of matches exceeds configured limit
+    ///  - the chain was reorged between two batches of headers
     async fn get_logs_in_block_range(
         &self,
         filter: &Filter,
@@ -503,29 +515,30 @@ where
         let address_filter = FilteredParams::address_filter(&filter.address);
         let topics_filter = FilteredParams::topics_filter(&filter.topics);
 
+        // the hash of the last header of the previous batch
+        let mut last_hash = None;
+
         // loop over the range of new blocks and check logs if the filter matches the log's bloom
         // filter
         for (from, to) in
             BlockRangeInclusiveIter::new(from_block..=to_block, self.max_headers_range)
         {
-            let headers = self.provider().headers_range(from..=to)?;
+            let headers = self.provider().sealed_headers_range(from..=to)?;
+
+            // a batch is read at once, but the chain can be reorged between two batches
+            if let (Some(last_hash), Some(first)) = (last_hash, headers.first()) {
+                if first.parent_hash() != last_hash {
+                    return Err(EthFilterError::ChainReorged)
+                }
+            }
+            last_hash = headers.last().map(|header| header.hash());
 
-            for (idx, header) in headers.iter().enumerate() {
+            for header in &headers {
                 // only if filter matches
                 if FilteredParams::matches_address(header.logs_bloom(), &address_filter) &&
                     FilteredParams::matches_topics(header.logs_bloom(), &topics_filter)
                 {
-                    // these are consecutive headers, so we can use the parent hash of the next
-                    // block to get the current header's hash
-                    let block_hash = match headers.get(idx + 1) {
-                        Some(parent) => parent.parent_hash(),
-                        None => self
-                            .provider()
-                            .block_hash(header.number())?
-                            .ok_or_else(|| ProviderError::HeaderNotFound(header.number().into()))?,
-                    };
-
-                    let num_hash = BlockNumHash::new(header.number(), block_hash);
+                    let num_hash = header.num_hash();
                     if let Some((receipts, maybe_block)) =
                         self.receipts_and_maybe_block(&num_hash, chain_info.best_number).await?
                     {
@@ -559,7 +572,8 @@ where
         Ok(all_logs)
     }
 
-    /// Retrieves receipts and block from cache if near the tip (4 blocks), otherwise only receipts.
+    /// Retrieves receipts and block from cache if near the tip (4 blocks) and the block can still
+    /// be reorged out, otherwise only receipts.
     async fn receipts_and_maybe_block(
         &self,
         block_num_hash: &BlockNumHash,
@@ -571,8 +585,9 @@ where
         )>,
         EthFilterError,
     > {
-        // The last 4 blocks are most likely cached, so we can just fetch them
-        let cached_range = best_number.saturating_sub(4)..=best_number;
+        // The last 4 blocks are most likely cached, so we can just fetch them, unless they're final
+        // already
+        let cached_range = best_number.saturating_sub(self.max_reorg_depth.min(4))..=best_number;
         let receipts_block = if cached_range.contains(&block_num_hash.number) {
             self.eth_cache()
                 .get_block_and_receipts(block_num_hash.hash)
@@ -771,6 +786,9 @@ pub enum EthFilterError {
     /// Error thrown when a spawned task failed to deliver a response.
     #[error("internal filter error")]
     InternalError,
+    /// The chain was reorged while the logs were read.
+    #[error("chain reorged during the request")]
+    ChainReorged,
 }
 
 impl From<EthFilterError> for jsonrpsee::types::error::ErrorObject<'static> {
@@ -780,7 +798,7 @@ impl From<EthFilterError> for jsonrpsee::types::error::ErrorObject<'static> {
                 jsonrpsee::types::error::INVALID_PARAMS_CODE,
                 "filter not found",
             ),
-            err @ EthFilterError::InternalError => {
+            err @ (EthFilterError::InternalError | EthFilterError::ChainReorged) => {
                 rpc_error_with_code(jsonrpsee::types::error::INTERNAL_ERROR_CODE, err.to_string())
             }
             EthFilterError::EthAPIError(err) => err.into(),
@@ -5,8 +5,8 @@ use alloy_consensus::TxType;
 use alloy_evm::block::BlockExecutorFactory;
 use alloy_primitives::{TxKind, U256};
 use alloy_rpc_types::TransactionRequest;
-use reth_evm::{ConfigureEvm, EvmEnv, EvmFactory, SpecFor};
-use reth_node_api::NodePrimitives;
+use reth_evm::{CallEnvOverrides, ConfigureEvm, EvmEnv, EvmFactory, SpecFor};
+use reth_primitives_traits::NodePrimitives;
 use reth_provider::{BlockReader, ProviderHeader, ProviderTx};
 use reth_rpc_eth_api::{
     helpers::{estimate::EstimateCall, Call, EthCall, LoadPendingBlock, LoadState, SpawnBlocking},
@@ -46,6 +46,11 @@ where
         self.inner.max_simulate_blocks()
     }
 
+    #[inline]
+    fn call_env_overrides(&self) -> CallEnvOverrides {
+        self.inner.call_env_overrides()
+    }
+
     fn create_txn_env(
         &self,
         evm_env: &EvmEnv<SpecFor<Self::Evm>>,
@@ -0,0 +1,193 @@
+//! A signer backed by a Ledger or Trezor hardware wallet.
+
+use std::{fmt, str::FromStr, sync::Arc};
+
+use alloy_dyn_abi::TypedData;
+use alloy_eips::eip2718::Decodable2718;
+use alloy_network::{eip2718::Encodable2718, EthereumWallet, TransactionBuilder, TxSigner};
+use alloy_primitives::{Address, ChainId, PrimitiveSignature as Signature};
+use alloy_rpc_types_eth::TransactionRequest;
+use alloy_signer::Signer;
+use reth_rpc_eth_api::helpers::{signer::Result, EthSigner};
+use reth_rpc_eth_types::SignError;
+
+/// A supported hardware wallet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HardwareWallet {
+    /// A Ledger device, requires the `ledger` feature.
+    Ledger,
+    /// A Trezor device, requires the `trezor` feature.
+    Trezor,
+}
+
+impl FromStr for HardwareWallet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "ledger" => Ok(Self::Ledger),
+            "trezor" => Ok(Self::Trezor),
+            _ => Err(format!("unknown hardware wallet: {s}")),
+        }
+    }
+}
+
+impl fmt::Display for HardwareWallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ledger => f.write_str("ledger"),
+            Self::Trezor => f.write_str("trezor"),
+        }
+    }
+}
+
+/// A signer for the account of a hardware wallet.
+///
+/// Every signature has to be confirmed on the device.
+#[derive(Clone)]
+pub struct HardwareSigner {
+    wallet: HardwareWallet,
+    address: Address,
+    signer: Arc<dyn Signer + Send + Sync>,
+    tx_signer: EthereumWallet,
+}
+
+impl fmt::Debug for HardwareSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HardwareSigner")
+            .field("wallet", &self.wallet)
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HardwareSigner {
+    /// Connects to the account with the given index of the Ledger Live or Trezor Live derivation
+    /// path of the hardware wallet.
+    pub async fn connect(wallet: HardwareWallet, index: usize, chain_id: ChainId) -> Result<Self> {
+        match wallet {
+            #[cfg(feature = "ledger")]
+            HardwareWallet::Ledger => {
+                use alloy_signer_ledger::{HDPath, LedgerSigner};
+
+                let signer = LedgerSigner::new(HDPath::LedgerLive(index), Some(chain_id))
+                    .await
+                    .map_err(hardware_wallet_error)?;
+                Ok(Self::new(wallet, signer))
+            }
+            #[cfg(feature = "trezor")]
+            HardwareWallet::Trezor => {
+                use alloy_signer_trezor::{HDPath, TrezorSigner};
+
+                let signer = TrezorSigner::new(HDPath::TrezorLive(index), Some(chain_id))
+                    .await
+                    .map_err(hardware_wallet_error)?;
+                Ok(Self::new(wallet, signer))
+            }
+            #[cfg_attr(all(feature = "ledger", feature = "trezor"), expect(unreachable_patterns))]
+            _ => {
+                let _ = (index, chain_id);
+                Err(SignError::HardwareWallet(format!(
+                    "{wallet} support is not enabled, rebuild with the `{wallet}` feature"
+                )))
+            }
+        }
+    }
+
+    /// Creates the signer from a connected hardware wallet signer.
+    pub fn new<S>(wallet: HardwareWallet, signer: S) -> Self
+    where
+        S: Signer + TxSigner<Signature> + Send + Sync + 'static,
+    {
+        let address = Signer::address(&signer);
+        let signer = Arc::new(signer);
+        Self { wallet, address, signer: signer.clone(), tx_signer: EthereumWallet::new(signer) }
+    }
+
+    /// Returns the hardware wallet of the signer.
+    pub const fn wallet(&self) -> HardwareWallet {
+        self.wallet
+    }
+
+    fn ensure_account(&self, address: &Address) -> Result<()> {
+        if *address == self.address {
+            Ok(())
+        } else {
+            Err(SignError::NoAccount)
+        }
+    }
+}
+
+fn hardware_wallet_error(err: impl fmt::Display) -> SignError {
+    SignError::HardwareWallet(err.to_string())
+}
+
+#[async_trait::async_trait]
+impl<T: Decodable2718> EthSigner<T> for HardwareSigner {
+    fn accounts(&self) -> Vec<Address> {
+        vec![self.address]
+    }
+
+    async fn sign(&self, address: Address, message: &[u8]) -> Result<Signature> {
+        self.ensure_account(&address)?;
+        // the device hashes the message according to EIP-191
+        self.signer.sign_message(message).await.map_err(hardware_wallet_error)
+    }
+
+    async fn sign_transaction(&self, request: TransactionRequest, address: &Address) -> Result<T> {
+        self.ensure_account(address)?;
+
+        let txn_envelope = request
+            .from(self.address)
+            .build(&self.tx_signer)
+            .await
+            .map_err(|_| SignError::InvalidTransactionRequest)?;
+
+        // decode transaction into signed transaction type
+        let encoded = txn_envelope.encoded_2718();
+        T::decode_2718(&mut encoded.as_ref()).map_err(|_| SignError::InvalidTransactionRequest)
+    }
+
+    fn sign_typed_data(&self, _address: Address, _payload: &TypedData) -> Result<Signature> {
+        Err(SignError::HardwareWallet("typed data must be signed asynchronously".to_string()))
+    }
+
+    async fn sign_typed_data_async(
+        &self,
+        address: Address,
+        payload: &TypedData,
+    ) -> Result<Signature> {
+        self.ensure_account(&address)?;
+        self.signer.sign_dynamic_typed_data(payload).await.map_err(hardware_wallet_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_signer_local::PrivateKeySigner;
+    use reth_ethereum_primitives::TransactionSigned;
+
+    #[test]
+    fn parse_hardware_wallet() {
+        assert_eq!("ledger".parse::<HardwareWallet>().unwrap(), HardwareWallet::Ledger);
+        assert_eq!("trezor".parse::<HardwareWallet>().unwrap(), HardwareWallet::Trezor);
+        assert!("keepkey".parse::<HardwareWallet>().is_err());
+    }
+
+    #[tokio::test]
+    async fn only_signs_for_device_account() {
+        let signer = PrivateKeySigner::random();
+        let address = signer.address();
+        let signer = HardwareSigner::new(HardwareWallet::Ledger, signer);
+
+        assert_eq!(EthSigner::<TransactionSigned>::accounts(&signer), vec![address]);
+        let signature =
+            EthSigner::<TransactionSigned>::sign(&signer, address, b"hello").await.unwrap();
+        assert_eq!(signature.recover_address_from_msg(b"hello").unwrap(), address);
+        assert!(matches!(
+            EthSigner::<TransactionSigned>::sign(&signer, Address::ZERO, b"hello").await,
+            Err(SignError::NoAccount)
+        ));
+    }
+}
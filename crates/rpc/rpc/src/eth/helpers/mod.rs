@@ -1,6 +1,8 @@
 //! The entire implementation of the namespace is quite large, hence it is divided across several
 //! files.
 
+pub mod hardware_signer;
+pub mod remote_signer;
 pub mod signer;
 pub mod types;
 
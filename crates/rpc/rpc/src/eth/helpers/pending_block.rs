@@ -3,8 +3,7 @@
 use alloy_consensus::BlockHeader;
 use reth_chainspec::{EthChainSpec, EthereumHardforks};
 use reth_evm::{ConfigureEvm, NextBlockEnvAttributes};
-use reth_node_api::NodePrimitives;
-use reth_primitives_traits::SealedHeader;
+use reth_primitives_traits::{NodePrimitives, SealedHeader};
 use reth_provider::{
     BlockReader, BlockReaderIdExt, ChainSpecProvider, ProviderBlock, ProviderHeader,
     ProviderReceipt, ProviderTx, StateProviderFactory,
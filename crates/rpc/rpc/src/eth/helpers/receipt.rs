@@ -3,7 +3,10 @@
 use alloy_consensus::transaction::TransactionMeta;
 use reth_chainspec::EthChainSpec;
 use reth_ethereum_primitives::{Receipt, TransactionSigned};
-use reth_provider::{BlockReader, ChainSpecProvider, ReceiptProvider, TransactionsProvider};
+use reth_primitives_traits::RecoveredBlock;
+use reth_provider::{
+    BlockReader, ChainSpecProvider, ProviderBlock, ReceiptProvider, TransactionsProvider,
+};
 use reth_rpc_eth_api::{helpers::LoadReceipt, FromEthApiError, RpcNodeCoreExt, RpcReceipt};
 use reth_rpc_eth_types::{EthApiError, EthReceiptBuilder};
 
@@ -35,4 +38,17 @@ where
 
         Ok(EthReceiptBuilder::new(&tx, meta, &receipt, &all_receipts, blob_params)?.build())
     }
+
+    async fn build_transaction_receipt_with_block(
+        &self,
+        tx: &TransactionSigned,
+        meta: TransactionMeta,
+        receipt: &Receipt,
+        _block: &RecoveredBlock<ProviderBlock<Provider>>,
+        all_receipts: &[Receipt],
+    ) -> Result<RpcReceipt<Self::NetworkTypes>, Self::Error> {
+        let blob_params = self.provider().chain_spec().blob_params_at_timestamp(meta.timestamp);
+
+        Ok(EthReceiptBuilder::new(tx, meta, receipt, all_receipts, blob_params)?.build())
+    }
 }
@@ -0,0 +1,300 @@
+//! A signer that forwards signing requests to a remote signer service.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use alloy_dyn_abi::TypedData;
+use alloy_eips::eip2718::Decodable2718;
+use alloy_primitives::{Address, Bytes, PrimitiveSignature as Signature, TxKind, U256};
+use alloy_rpc_types_eth::{TransactionInput, TransactionRequest};
+use jsonrpsee::{
+    core::{client::ClientT, params::ArrayParams, DeserializeOwned},
+    http_client::{HttpClient, HttpClientBuilder},
+    rpc_params,
+};
+use reth_rpc_eth_api::helpers::{signer::Result, EthSigner};
+use reth_rpc_eth_types::SignError;
+pub use reth_rpc_server_types::RemoteSignerApi;
+use serde::{Deserialize, Serialize};
+
+/// The restrictions on what an account of a remote signer may sign.
+///
+/// The default policy allows everything.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SignerPolicy {
+    /// The only recipients of transactions, if set.
+    pub allowed_recipients: Option<HashSet<Address>>,
+    /// Whether transactions may create contracts.
+    pub allow_contract_creation: bool,
+    /// The maximum value of a transaction, if set.
+    pub max_value: Option<U256>,
+    /// The maximum gas limit of a transaction, if set.
+    pub max_gas: Option<u64>,
+    /// Whether messages and typed data may be signed.
+    pub allow_message_signing: bool,
+}
+
+impl Default for SignerPolicy {
+    fn default() -> Self {
+        Self {
+            allowed_recipients: None,
+            allow_contract_creation: true,
+            max_value: None,
+            max_gas: None,
+            allow_message_signing: true,
+        }
+    }
+}
+
+impl SignerPolicy {
+    /// Returns an error if the policy doesn't allow signing the transaction.
+    pub fn check_transaction(&self, request: &TransactionRequest) -> Result<()> {
+        match request.to {
+            Some(TxKind::Call(to)) => {
+                if self.allowed_recipients.as_ref().is_some_and(|allowed| !allowed.contains(&to)) {
+                    return Err(policy_violation(format!("recipient {to} is not allowed")))
+                }
+            }
+            _ => {
+                if !self.allow_contract_creation {
+                    return Err(policy_violation("contract creation is not allowed"))
+                }
+            }
+        }
+        if let Some(max_value) = self.max_value {
+            if request.value.unwrap_or_default() > max_value {
+                return Err(policy_violation(format!("value exceeds {max_value}")))
+            }
+        }
+        if let Some(max_gas) = self.max_gas {
+            if request.gas.is_none_or(|gas| gas > max_gas) {
+                return Err(policy_violation(format!("gas limit exceeds {max_gas}")))
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the policy doesn't allow signing messages.
+    pub fn check_message(&self) -> Result<()> {
+        if self.allow_message_signing {
+            Ok(())
+        } else {
+            Err(policy_violation("message signing is not allowed"))
+        }
+    }
+}
+
+/// The signing policies of the accounts of a remote signer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SignerPolicies {
+    /// The policy of accounts without their own policy.
+    pub default: SignerPolicy,
+    /// The policies of individual accounts.
+    pub accounts: HashMap<Address, SignerPolicy>,
+}
+
+impl SignerPolicies {
+    /// Returns the policy of the account.
+    pub fn get(&self, account: &Address) -> &SignerPolicy {
+        self.accounts.get(account).unwrap_or(&self.default)
+    }
+}
+
+fn policy_violation(reason: impl Into<String>) -> SignError {
+    SignError::PolicyViolation(reason.into())
+}
+
+/// The client of the connection to the remote signer.
+#[derive(Debug, Clone)]
+enum RemoteClient {
+    Http(HttpClient),
+    Ipc(Arc<jsonrpsee::core::client::Client>),
+}
+
+impl RemoteClient {
+    async fn request<R: DeserializeOwned>(&self, method: &str, params: ArrayParams) -> Result<R> {
+        match self {
+            Self::Http(client) => client.request(method, params).await,
+            Self::Ipc(client) => client.request(method, params).await,
+        }
+        .map_err(|err| SignError::Remote(err.to_string()))
+    }
+}
+
+/// The response of clef to `account_signTransaction`.
+#[derive(Debug, Deserialize)]
+struct ClefSignedTransaction {
+    raw: Bytes,
+}
+
+/// A signer that forwards signing requests to a web3signer or clef instance, after checking them
+/// against the [`SignerPolicies`] of the accounts.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    client: RemoteClient,
+    api: RemoteSignerApi,
+    accounts: Vec<Address>,
+    policies: Arc<SignerPolicies>,
+}
+
+impl RemoteSigner {
+    /// Connects to the remote signer and fetches its accounts.
+    ///
+    /// The endpoint is either an HTTP URL or the path to an IPC socket.
+    pub async fn connect(
+        endpoint: &str,
+        api: RemoteSignerApi,
+        policies: SignerPolicies,
+    ) -> Result<Self> {
+        let client = if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
+            RemoteClient::Http(
+                HttpClientBuilder::default()
+                    .build(endpoint)
+                    .map_err(|err| SignError::Remote(err.to_string()))?,
+            )
+        } else {
+            RemoteClient::Ipc(Arc::new(
+                reth_ipc::client::IpcClientBuilder::default()
+                    .build(endpoint)
+                    .await
+                    .map_err(|err| SignError::Remote(err.to_string()))?,
+            ))
+        };
+
+        let method = match api {
+            RemoteSignerApi::Web3Signer => "eth_accounts",
+            RemoteSignerApi::Clef => "account_list",
+        };
+        let accounts = client.request(method, rpc_params![]).await?;
+
+        Ok(Self { client, api, accounts, policies: Arc::new(policies) })
+    }
+
+    /// Returns the policy of the account, or an error if the account is not managed by the remote
+    /// signer.
+    fn policy(&self, account: &Address) -> Result<&SignerPolicy> {
+        if !self.accounts.contains(account) {
+            return Err(SignError::NoAccount)
+        }
+        Ok(self.policies.get(account))
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Decodable2718> EthSigner<T> for RemoteSigner {
+    fn accounts(&self) -> Vec<Address> {
+        self.accounts.clone()
+    }
+
+    async fn sign(&self, address: Address, message: &[u8]) -> Result<Signature> {
+        self.policy(&address)?.check_message()?;
+
+        let message = Bytes::copy_from_slice(message);
+        let signature: Bytes = match self.api {
+            RemoteSignerApi::Web3Signer => {
+                self.client.request("eth_sign", rpc_params![address, message]).await?
+            }
+            RemoteSignerApi::Clef => {
+                self.client
+                    .request("account_signData", rpc_params!["text/plain", address, message])
+                    .await?
+            }
+        };
+        Signature::try_from(signature.as_ref()).map_err(|_| SignError::CouldNotSign)
+    }
+
+    async fn sign_transaction(&self, request: TransactionRequest, address: &Address) -> Result<T> {
+        self.policy(address)?.check_transaction(&request)?;
+
+        let mut request = request;
+        request.from = Some(*address);
+        // both signers read the calldata from `data`
+        if let Some(input) = request.input.input().cloned() {
+            request.input = TransactionInput::both(input);
+        }
+
+        let raw = match self.api {
+            RemoteSignerApi::Web3Signer => {
+                self.client.request::<Bytes>("eth_signTransaction", rpc_params![request]).await?
+            }
+            RemoteSignerApi::Clef => {
+                self.client
+                    .request::<ClefSignedTransaction>(
+                        "account_signTransaction",
+                        rpc_params![request],
+                    )
+                    .await?
+                    .raw
+            }
+        };
+        T::decode_2718(&mut raw.as_ref()).map_err(|_| SignError::InvalidTransactionRequest)
+    }
+
+    fn sign_typed_data(&self, _address: Address, _payload: &TypedData) -> Result<Signature> {
+        Err(SignError::Remote("typed data must be signed asynchronously".to_string()))
+    }
+
+    async fn sign_typed_data_async(
+        &self,
+        address: Address,
+        payload: &TypedData,
+    ) -> Result<Signature> {
+        self.policy(&address)?.check_message()?;
+
+        let method = match self.api {
+            RemoteSignerApi::Web3Signer => "eth_signTypedData",
+            RemoteSignerApi::Clef => "account_signTypedData",
+        };
+        let signature: Bytes = self.client.request(method, rpc_params![address, payload]).await?;
+        Signature::try_from(signature.as_ref()).map_err(|_| SignError::CouldNotSign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_transaction_policy() {
+        let recipient = Address::with_last_byte(1);
+        let policy: SignerPolicy = serde_json::from_str(&format!(
+            r#"{{"allowedRecipients":["{recipient}"],"allowContractCreation":false,"maxValue":"0x64","maxGas":100000}}"#
+        ))
+        .unwrap();
+        assert!(policy.allow_message_signing);
+
+        let request =
+            TransactionRequest::default().to(recipient).value(U256::from(100)).gas_limit(21_000);
+        policy.check_transaction(&request).unwrap();
+
+        for request in [
+            request.clone().to(Address::with_last_byte(2)),
+            TransactionRequest { to: Some(TxKind::Create), ..request.clone() },
+            request.clone().value(U256::from(101)),
+            request.clone().gas_limit(100_001),
+            TransactionRequest { gas: None, ..request },
+        ] {
+            assert!(matches!(
+                policy.check_transaction(&request),
+                Err(SignError::PolicyViolation(_))
+            ));
+        }
+
+        SignerPolicy::default().check_transaction(&TransactionRequest::default()).unwrap();
+    }
+
+    #[test]
+    fn account_policies() {
+        let account = Address::with_last_byte(1);
+        let policies: SignerPolicies = serde_json::from_str(&format!(
+            r#"{{"default":{{"allowMessageSigning":false}},"accounts":{{"{account}":{{}}}}}}"#
+        ))
+        .unwrap();
+        policies.get(&account).check_message().unwrap();
+        assert!(policies.get(&Address::with_last_byte(2)).check_message().is_err());
+    }
+}
@@ -45,16 +45,20 @@ impl DevSigner {
         let mut signers = Vec::with_capacity(num as usize);
         for _ in 0..num {
             let sk = PrivateKeySigner::random_with(&mut rand::thread_rng());
-
-            let address = sk.address();
-            let addresses = vec![address];
-
-            let accounts = HashMap::from([(address, sk)]);
-            signers.push(Box::new(Self { addresses, accounts }) as Box<dyn EthSigner<T>>);
+            signers.push(Self::from_key(sk));
         }
         signers
     }
 
+    /// Creates a dev signer for the given key which satisfies [`EthSigner`] trait
+    pub fn from_key<T: Decodable2718>(sk: PrivateKeySigner) -> Box<dyn EthSigner<T>> {
+        let address = sk.address();
+        let addresses = vec![address];
+
+        let accounts = HashMap::from([(address, sk)]);
+        Box::new(Self { addresses, accounts })
+    }
+
     fn get_key(&self, account: Address) -> Result<&PrivateKeySigner> {
         self.accounts.get(&account).ok_or(SignError::NoAccount)
     }
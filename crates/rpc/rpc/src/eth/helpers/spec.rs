@@ -1,10 +1,11 @@
-use alloy_primitives::U256;
+use alloy_primitives::{U256, U64};
 use reth_chainspec::EthereumHardforks;
 use reth_network_api::NetworkInfo;
 use reth_provider::{
     BlockNumReader, BlockReader, ChainSpecProvider, ProviderTx, StageCheckpointReader,
 };
 use reth_rpc_eth_api::{helpers::EthApiSpec, RpcNodeCore};
+use reth_rpc_eth_types::SyncStageProgress;
 
 use crate::EthApi;
 
@@ -30,4 +31,12 @@ where
     {
         self.inner.signers()
     }
+
+    fn chain_id(&self) -> U64 {
+        self.inner.rpc_chain_id().map(U64::from).unwrap_or_else(|| self.execution_chain_id())
+    }
+
+    fn sync_stage_progress(&self) -> Option<&SyncStageProgress> {
+        self.inner.sync_stage_progress()
+    }
 }
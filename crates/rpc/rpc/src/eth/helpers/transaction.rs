@@ -1,19 +1,23 @@
 //! Contains RPC handler implementations specific to transactions
 
 use crate::EthApi;
+use alloy_consensus::Transaction as _;
 use alloy_primitives::{Bytes, B256};
 use reth_provider::{BlockReader, BlockReaderIdExt, ProviderTx, TransactionsProvider};
 use reth_rpc_eth_api::{
-    helpers::{EthSigner, EthTransactions, LoadTransaction, SpawnBlocking},
+    helpers::{EthApiSpec, EthSigner, EthTransactions, LoadTransaction, SpawnBlocking},
     FromEthApiError, FullEthApiTypes, RpcNodeCore, RpcNodeCoreExt,
 };
-use reth_rpc_eth_types::utils::recover_raw_transaction;
+use reth_rpc_eth_types::{
+    utils::{ensure_execution_chain_id, recover_raw_transaction},
+    TxLookupConfig,
+};
 use reth_transaction_pool::{PoolTransaction, TransactionOrigin, TransactionPool};
 
 impl<Provider, Pool, Network, EvmConfig> EthTransactions
     for EthApi<Provider, Pool, Network, EvmConfig>
 where
-    Self: LoadTransaction<Provider: BlockReaderIdExt>,
+    Self: LoadTransaction<Provider: BlockReaderIdExt> + EthApiSpec,
     Provider: BlockReader<Transaction = ProviderTx<Self::Provider>>,
 {
     #[inline]
@@ -26,6 +30,11 @@ where
     /// Returns the hash of the transaction.
     async fn send_raw_transaction(&self, tx: Bytes) -> Result<B256, Self::Error> {
         let recovered = recover_raw_transaction(&tx)?;
+        ensure_execution_chain_id(
+            recovered.chain_id(),
+            EthApiSpec::chain_id(self).to(),
+            self.execution_chain_id().to(),
+        )?;
 
         // broadcast raw transaction to subscribers if there is any.
         self.broadcast_raw_transaction(tx);
@@ -51,6 +60,10 @@ where
         + RpcNodeCoreExt<Provider: TransactionsProvider, Pool: TransactionPool>,
     Provider: BlockReader,
 {
+    #[inline]
+    fn tx_lookup_config(&self) -> TxLookupConfig {
+        self.inner.tx_lookup()
+    }
 }
 
 #[cfg(test)]
@@ -90,6 +103,7 @@ mod tests {
             GasPriceOracle::new(noop_provider, Default::default(), cache.clone()),
             ETHEREUM_BLOCK_GAS_LIMIT_30M,
             DEFAULT_MAX_SIMULATE_BLOCKS,
+            Default::default(),
             DEFAULT_ETH_PROOF_WINDOW,
             BlockingTaskPool::build().expect("failed to build tracing pool"),
             fee_history_cache,
@@ -1,5 +1,6 @@
 //! Sever implementation of `eth` namespace API.
 
+pub mod block_response;
 pub mod builder;
 pub mod bundle;
 pub mod core;
@@ -9,6 +10,7 @@ pub mod pubsub;
 pub mod sim_bundle;
 
 /// Implementation of `eth` namespace API.
+pub use block_response::EthBlockResponse;
 pub use builder::EthApiBuilder;
 pub use bundle::EthBundle;
 pub use core::EthApi;
@@ -16,6 +18,8 @@ pub use filter::EthFilter;
 pub use pubsub::EthPubSub;
 
 pub use helpers::{
+    hardware_signer::{HardwareSigner, HardwareWallet},
+    remote_signer::{RemoteSigner, RemoteSignerApi, SignerPolicies, SignerPolicy},
     signer::DevSigner,
     types::{EthTxBuilder, EthereumEthApiTypes},
 };
@@ -2,7 +2,7 @@
 
 use std::sync::Arc;
 
-use alloy_primitives::TxHash;
+use alloy_primitives::{map::HashSet, Address, TxHash};
 use alloy_rpc_types_eth::{
     pubsub::{Params, PubSubSyncStatus, SubscriptionKind, SyncStatusMetadata},
     FilteredParams, Header, Log,
@@ -15,9 +15,13 @@ use reth_network_api::NetworkInfo;
 use reth_primitives_traits::NodePrimitives;
 use reth_provider::{BlockNumReader, CanonStateSubscriptions};
 use reth_rpc_eth_api::{
-    pubsub::EthPubSubApiServer, EthApiTypes, RpcNodeCore, RpcTransaction, TransactionCompat,
+    pubsub::{EthPubSubApiServer, EthPubSubExtApiServer},
+    EthApiTypes, RpcNodeCore, RpcTransaction, TransactionCompat,
+};
+use reth_rpc_eth_types::{
+    account_changes::{chain_account_changes, AccountChanges, MAX_WATCHED_ACCOUNTS},
+    logs_utils, EthSubscriptionKind, EthSubscriptionParams, RethSubscriptionKind,
 };
-use reth_rpc_eth_types::logs_utils;
 use reth_rpc_server_types::result::{internal_rpc_err, invalid_params_rpc_err};
 use reth_tasks::{TaskSpawner, TokioTaskExecutor};
 use reth_transaction_pool::{NewTransactionEvent, PoolConsensusTx, TransactionPool};
@@ -26,7 +30,7 @@ use tokio_stream::{
     wrappers::{BroadcastStream, ReceiverStream},
     Stream,
 };
-use tracing::error;
+use tracing::{debug, error};
 
 /// `Eth` pubsub RPC implementation.
 ///
@@ -72,6 +76,27 @@ where
         pending: PendingSubscriptionSink,
         kind: SubscriptionKind,
         params: Option<Params>,
+    ) -> jsonrpsee::core::SubscriptionResult {
+        EthPubSubExtApiServer::subscribe(self, pending, kind.into(), params.map(Into::into)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl<Eth> EthPubSubExtApiServer<RpcTransaction<Eth::NetworkTypes>> for EthPubSub<Eth>
+where
+    Eth: RpcNodeCore<
+            Provider: BlockNumReader + CanonStateSubscriptions,
+            Pool: TransactionPool,
+            Network: NetworkInfo,
+        > + EthApiTypes<TransactionCompat: TransactionCompat<PoolConsensusTx<Eth::Pool>>>
+        + 'static,
+{
+    /// Handler for `eth_subscribe`, including the reth-specific subscription kinds
+    async fn subscribe(
+        &self,
+        pending: PendingSubscriptionSink,
+        kind: EthSubscriptionKind,
+        params: Option<EthSubscriptionParams>,
     ) -> jsonrpsee::core::SubscriptionResult {
         let sink = pending.accept().await?;
         let pubsub = self.inner.clone();
@@ -87,8 +112,8 @@ where
 async fn handle_accepted<Eth>(
     pubsub: Arc<EthPubSubInner<Eth>>,
     accepted_sink: SubscriptionSink,
-    kind: SubscriptionKind,
-    params: Option<Params>,
+    kind: EthSubscriptionKind,
+    params: Option<EthSubscriptionParams>,
 ) -> Result<(), ErrorObject<'static>>
 where
     Eth: RpcNodeCore<
@@ -97,6 +122,29 @@ where
             Network: NetworkInfo,
         > + EthApiTypes<TransactionCompat: TransactionCompat<PoolConsensusTx<Eth::Pool>>>,
 {
+    let kind = match kind {
+        EthSubscriptionKind::Eth(kind) => kind,
+        EthSubscriptionKind::Reth(RethSubscriptionKind::Accounts) => {
+            let Some(EthSubscriptionParams::Accounts(addresses)) = params else {
+                return Err(invalid_params_rpc_err("Expected a list of addresses for accounts"))
+            };
+            if addresses.len() > MAX_WATCHED_ACCOUNTS {
+                return Err(invalid_params_rpc_err(format!(
+                    "Too many accounts, at most {MAX_WATCHED_ACCOUNTS} can be watched"
+                )))
+            }
+            let stream = pubsub.account_changes_stream(addresses.into_iter().collect());
+            return pipe_from_stream(accepted_sink, stream).await
+        }
+    };
+    let params = match params {
+        Some(EthSubscriptionParams::Eth(params)) => Some(params),
+        Some(EthSubscriptionParams::Accounts(_)) => {
+            return Err(invalid_params_rpc_err(format!("Invalid params for {kind:?}")))
+        }
+        None => None,
+    };
+
     match kind {
         SubscriptionKind::NewHeads => {
             pipe_from_stream(accepted_sink, pubsub.new_headers_stream()).await
@@ -323,4 +371,33 @@ where
                 futures::stream::iter(all_logs)
             })
     }
+
+    /// Returns a stream that yields the changes of the given accounts per canonical block.
+    ///
+    /// On reorgs, the changes of the reverted blocks are yielded first, marked as removed.
+    ///
+    /// The stream ends if the subscriber lags behind the canonical state notifications, because the
+    /// changes of the missed blocks can't be recovered.
+    fn account_changes_stream(
+        &self,
+        addresses: HashSet<Address>,
+    ) -> impl Stream<Item = AccountChanges> {
+        BroadcastStream::new(self.eth_api.provider().subscribe_to_canonical_state())
+            .take_while(|canon_state| {
+                if let Err(err) = canon_state {
+                    debug!(target: "rpc", %err, "Ending lagging accounts subscription");
+                }
+                std::future::ready(canon_state.is_ok())
+            })
+            .filter_map(|canon_state| std::future::ready(canon_state.ok()))
+            .map(move |canon_state| {
+                let mut changes = Vec::new();
+                if let Some(reverted) = canon_state.reverted() {
+                    changes.extend(chain_account_changes(&*reverted, &addresses, true));
+                }
+                changes.extend(chain_account_changes(&*canon_state.committed(), &addresses, false));
+                changes
+            })
+            .flat_map(futures::stream::iter)
+    }
 }
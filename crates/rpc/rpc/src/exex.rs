@@ -0,0 +1,47 @@
+//! `reth_exexStatus` and the `admin` endpoints to pause and resume execution extensions.
+
+use jsonrpsee::core::RpcResult;
+use reth_exex_types::{ExExManagerStatus, ExExStatusHandle};
+use reth_rpc_api::{AdminExExApiServer, RethExExApiServer};
+use reth_rpc_server_types::result::invalid_params_rpc_err;
+use tracing::debug;
+
+/// `reth` and `admin` API implementation for the installed execution extensions.
+#[derive(Debug, Clone)]
+pub struct ExExApi {
+    /// The handle to the status of the `ExEx` manager.
+    status: ExExStatusHandle,
+}
+
+impl ExExApi {
+    /// Creates a new instance of `ExExApi`.
+    pub const fn new(status: ExExStatusHandle) -> Self {
+        Self { status }
+    }
+
+    fn set_paused(&self, id: &str, paused: bool) -> RpcResult<bool> {
+        debug!(target: "rpc::admin", %id, paused, "Setting ExEx paused");
+        self.status
+            .set_paused(id, paused)
+            .ok_or_else(|| invalid_params_rpc_err(format!("unknown ExEx: {id}")))
+    }
+}
+
+impl RethExExApiServer for ExExApi {
+    /// Handler for `reth_exexStatus`
+    fn reth_exex_status(&self) -> RpcResult<ExExManagerStatus> {
+        Ok(self.status.status())
+    }
+}
+
+impl AdminExExApiServer for ExExApi {
+    /// Handler for `admin_pauseExEx`
+    fn pause_exex(&self, id: String) -> RpcResult<bool> {
+        self.set_paused(&id, true)
+    }
+
+    /// Handler for `admin_resumeExEx`
+    fn resume_exex(&self, id: String) -> RpcResult<bool> {
+        self.set_paused(&id, false)
+    }
+}
@@ -32,30 +32,60 @@ use jsonwebtoken as _;
 use pin_project as _;
 use tower as _;
 
+mod accumulator;
 mod admin;
+#[cfg(feature = "builder-api")]
+mod builder;
+mod contract;
 mod debug;
 mod engine;
+#[cfg(feature = "engine-snapshots")]
+mod engine_state;
 pub mod eth;
+mod exex;
 mod miner;
 mod net;
 mod otterscan;
+mod personal;
+#[cfg(feature = "preconf")]
+mod preconf;
+mod proposer;
+mod requests;
 mod reth;
 mod rpc;
+mod storage;
 mod trace;
+mod trace_file;
 mod txpool;
 mod validation;
 mod web3;
 
-pub use admin::AdminApi;
+pub use accumulator::{HeaderAccumulatorUpdater, HeaderProofApi};
+pub use admin::{AdminApi, AdminBuilderApi, AdminSyncTargetApi};
+#[cfg(feature = "builder-api")]
+pub use builder::BuilderApi;
+pub use contract::{ContractCreationApi, ContractIndexer};
 pub use debug::DebugApi;
 pub use engine::{EngineApi, EngineEthApi};
-pub use eth::{EthApi, EthApiBuilder, EthBundle, EthFilter, EthPubSub};
+#[cfg(feature = "engine-snapshots")]
+pub use engine_state::DebugEngineStateApi;
+pub use eth::{EthApi, EthApiBuilder, EthBlockResponse, EthBundle, EthFilter, EthPubSub};
+pub use exex::ExExApi;
 pub use miner::MinerApi;
 pub use net::NetApi;
 pub use otterscan::OtterscanApi;
-pub use reth::RethApi;
+pub use personal::PersonalApi;
+#[cfg(feature = "preconf")]
+pub use preconf::RethPreconfApi;
+pub use proposer::{ProposerPaymentTracker, ProposerPayments, ProposerPaymentsApi};
+pub use requests::{ExecutionRequestIndexer, ExecutionRequestsApi};
+pub use reth::{
+    RethApi, RethBlockStreamApi, RethEvmApi, RethPayloadApi, RethSimulateApi, TxWatcherApi,
+};
 pub use rpc::RPCApi;
+pub use storage::StorageBreakdownApi;
 pub use trace::TraceApi;
+pub use trace_file::DebugTraceFileApi;
 pub use txpool::TxPoolApi;
 pub use validation::{ValidationApi, ValidationApiConfig};
 pub use web3::Web3Api;
@@ -0,0 +1,118 @@
+use alloy_primitives::{Address, Bytes, PrimitiveSignature as Signature, B256};
+use alloy_rpc_types_eth::TransactionRequest;
+use alloy_signer_local::PrivateKeySigner;
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_rpc_api::PersonalApiServer;
+use reth_rpc_eth_api::helpers::{EthApiSpec, EthTransactions, FullEthApi};
+use reth_rpc_server_types::result::invalid_params_rpc_err;
+
+use crate::eth::DevSigner;
+
+/// `personal` API implementation.
+///
+/// This type provides the functionality for handling `personal` related requests on top of the
+/// signers of the `eth` API.
+#[derive(Debug, Clone)]
+pub struct PersonalApi<Eth> {
+    eth: Eth,
+}
+
+impl<Eth> PersonalApi<Eth> {
+    /// Creates a new instance of `PersonalApi`.
+    pub const fn new(eth: Eth) -> Self {
+        Self { eth }
+    }
+}
+
+impl<Eth: FullEthApi> PersonalApi<Eth> {
+    /// Adds a signer for the key to the `eth` API.
+    fn add_key(&self, sk: PrivateKeySigner) -> RpcResult<Address> {
+        let address = sk.address();
+        if self.eth.find_signer(&address).is_ok() {
+            return Err(invalid_params_rpc_err("account already exists"))
+        }
+        EthTransactions::signers(&self.eth).write().push(DevSigner::from_key(sk));
+        Ok(address)
+    }
+}
+
+#[async_trait]
+impl<Eth> PersonalApiServer for PersonalApi<Eth>
+where
+    Eth: FullEthApi + 'static,
+{
+    /// Handler for `personal_listAccounts`
+    async fn list_accounts(&self) -> RpcResult<Vec<Address>> {
+        Ok(EthApiSpec::accounts(&self.eth))
+    }
+
+    /// Handler for `personal_newAccount`
+    async fn new_account(&self, _password: Option<String>) -> RpcResult<Address> {
+        self.add_key(PrivateKeySigner::random_with(&mut rand::thread_rng()))
+    }
+
+    /// Handler for `personal_importRawKey`
+    async fn import_raw_key(
+        &self,
+        private_key: String,
+        _password: Option<String>,
+    ) -> RpcResult<Address> {
+        let sk = private_key
+            .parse::<PrivateKeySigner>()
+            .map_err(|_| invalid_params_rpc_err("invalid private key"))?;
+        self.add_key(sk)
+    }
+
+    /// Handler for `personal_unlockAccount`
+    async fn unlock_account(
+        &self,
+        address: Address,
+        _password: Option<String>,
+        _duration: Option<u64>,
+    ) -> RpcResult<bool> {
+        Ok(self.eth.find_signer(&address).is_ok())
+    }
+
+    /// Handler for `personal_lockAccount`
+    async fn lock_account(&self, _address: Address) -> RpcResult<bool> {
+        Ok(false)
+    }
+
+    /// Handler for `personal_sign`
+    async fn sign(
+        &self,
+        message: Bytes,
+        address: Address,
+        _password: Option<String>,
+    ) -> RpcResult<Bytes> {
+        Ok(EthTransactions::sign(&self.eth, address, message).await.map_err(Into::into)?)
+    }
+
+    /// Handler for `personal_ecRecover`
+    async fn ec_recover(&self, message: Bytes, signature: Bytes) -> RpcResult<Address> {
+        let signature = Signature::try_from(signature.as_ref())
+            .map_err(|_| invalid_params_rpc_err("invalid signature"))?;
+        signature
+            .recover_address_from_msg(&message)
+            .map_err(|_| invalid_params_rpc_err("invalid signature"))
+    }
+
+    /// Handler for `personal_signTransaction`
+    async fn sign_transaction(
+        &self,
+        request: TransactionRequest,
+        _password: Option<String>,
+    ) -> RpcResult<Bytes> {
+        Ok(EthTransactions::sign_transaction(&self.eth, request).await.map_err(Into::into)?)
+    }
+
+    /// Handler for `personal_sendTransaction`
+    async fn send_transaction(
+        &self,
+        request: TransactionRequest,
+        _password: Option<String>,
+    ) -> RpcResult<B256> {
+        Ok(EthTransactions::send_transaction(&self.eth, request).await.map_err(Into::into)?)
+    }
+}
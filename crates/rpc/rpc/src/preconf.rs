@@ -0,0 +1,236 @@
+use std::sync::Arc;
+
+use alloy_consensus::{BlockHeader, Transaction};
+use alloy_eips::BlockId;
+use alloy_primitives::Bytes;
+use alloy_rpc_types_engine::PayloadId;
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use async_trait::async_trait;
+use futures::StreamExt;
+use jsonrpsee::core::RpcResult;
+use parking_lot::RwLock;
+use reth_chainspec::{ChainSpecProvider, EthChainSpec};
+use reth_ethereum_payload_builder::Preconfirmations;
+use reth_evm::ConfigureEvm;
+use reth_payload_builder::PayloadBuilderHandle;
+use reth_payload_primitives::{BuiltPayload, PayloadBuilderAttributes, PayloadTypes};
+use reth_primitives_traits::{BlockBody, NodePrimitives, RecoveredBlock, SignedTransaction};
+use reth_provider::{BlockNumReader, ProviderBlock, ProviderTx};
+use reth_revm::{database::StateProviderDatabase, db::CacheDB};
+use reth_rpc_api::RethPreconfApiServer;
+use reth_rpc_eth_api::{
+    helpers::{Call, EthTransactions, LoadPendingBlock},
+    FromEthApiError, FullEthApiTypes,
+};
+use reth_rpc_eth_types::{utils::recover_raw_transaction, EthApiError, Preconfirmation};
+use reth_tasks::TaskSpawner;
+use tracing::trace;
+
+/// `reth` API implementation of transaction preconfirmations.
+///
+/// Transactions are simulated at the end of the best block of the payload job that is currently
+/// in progress, or of the local pending block if there is no job. The gas limits of the committed
+/// transactions are reserved in the shared [`Preconfirmations`], which makes the payload builder
+/// include the transactions of their senders first.
+pub struct RethPreconfApi<Eth, T: PayloadTypes> {
+    inner: Arc<RethPreconfApiInner<Eth, T>>,
+}
+
+struct RethPreconfApiInner<Eth, T: PayloadTypes> {
+    /// The `eth` API used to simulate and submit transactions.
+    eth_api: Eth,
+    /// The handle to the payload builder service.
+    payload_builder: PayloadBuilderHandle<T>,
+    /// The id of the most recently started payload job.
+    current_payload: RwLock<Option<PayloadId>>,
+    /// The preconfirmations shared with the payload builder.
+    preconfirmations: Preconfirmations,
+    /// The key that signs the commitments.
+    signer: PrivateKeySigner,
+}
+
+impl<Eth, T> RethPreconfApi<Eth, T>
+where
+    Eth: Send + Sync + 'static,
+    T: PayloadTypes,
+{
+    /// Create a new instance of the [`RethPreconfApi`]
+    ///
+    /// Spawns a task that keeps track of the payload jobs started by the payload builder.
+    pub fn new(
+        eth_api: Eth,
+        payload_builder: PayloadBuilderHandle<T>,
+        preconfirmations: Preconfirmations,
+        signer: PrivateKeySigner,
+        task_spawner: Box<dyn TaskSpawner>,
+    ) -> Self {
+        let inner = Arc::new(RethPreconfApiInner {
+            eth_api,
+            payload_builder,
+            current_payload: RwLock::new(None),
+            preconfirmations,
+            signer,
+        });
+
+        let this = inner.clone();
+        task_spawner.spawn(Box::pin(async move {
+            let Ok(events) = this.payload_builder.subscribe().await else { return };
+            let mut attributes = events.into_attributes_stream();
+            while let Some(attributes) = attributes.next().await {
+                *this.current_payload.write() = Some(attributes.payload_id());
+            }
+        }));
+
+        Self { inner }
+    }
+}
+
+impl<Eth, T> RethPreconfApi<Eth, T>
+where
+    Eth: EthTransactions + Call + LoadPendingBlock + FullEthApiTypes + 'static,
+    T: PayloadTypes<
+        BuiltPayload: BuiltPayload<
+            Primitives: NodePrimitives<Block = ProviderBlock<Eth::Provider>>,
+        >,
+    >,
+{
+    /// Returns the best block of the payload job in progress if it builds on the latest block,
+    /// otherwise the local pending block.
+    async fn pending_block(
+        &self,
+    ) -> Result<RecoveredBlock<ProviderBlock<Eth::Provider>>, Eth::Error> {
+        let eth_api = &self.inner.eth_api;
+        let latest = eth_api.provider().best_block_number().map_err(Eth::Error::from_eth_err)?;
+
+        let current_payload = *self.inner.current_payload.read();
+        if let Some(id) = current_payload {
+            if let Some(Ok(payload)) = self.inner.payload_builder.best_payload(id).await {
+                let block = payload.block();
+                if block.number() == latest + 1 {
+                    if let Ok(block) = block.clone().try_recover() {
+                        return Ok(block)
+                    }
+                }
+            }
+        }
+
+        eth_api
+            .local_pending_block()
+            .await?
+            .map(|(block, _)| block)
+            .ok_or_else(|| EthApiError::HeaderNotFound(BlockId::pending()).into())
+    }
+
+    /// Simulates the raw transaction at the end of the pending block and, if it fits, submits it
+    /// to the pool and returns a signed commitment to include it in the block.
+    pub async fn preconfirm_transaction(&self, tx: Bytes) -> Result<Preconfirmation, Eth::Error> {
+        let transaction = recover_raw_transaction::<ProviderTx<Eth::Provider>>(&tx)?;
+        let tx_hash = *transaction.tx_hash();
+        let sender = transaction.signer();
+        let gas_limit = transaction.gas_limit();
+        let chain_id = self.inner.eth_api.provider().chain_spec().chain_id();
+
+        let block = self.pending_block().await?;
+        let block_number = block.number();
+        let parent_hash = block.parent_hash();
+        let block_gas_limit = block.gas_limit();
+
+        let eth_api = self.inner.eth_api.clone();
+        let (gas_used, success) = self
+            .inner
+            .eth_api
+            .spawn_with_state_at_block(parent_hash.into(), move |state| {
+                let mut db = CacheDB::new(StateProviderDatabase::new(state));
+                let evm_env = eth_api.evm_config().evm_env(block.header());
+
+                let index = eth_api.replay_transactions_until(
+                    &mut db,
+                    evm_env.clone(),
+                    block.transactions_recovered(),
+                    tx_hash,
+                )?;
+                if index < block.body().transactions().len() {
+                    return Err(EthApiError::InvalidParams(
+                        "transaction is already in the pending block".to_string(),
+                    )
+                    .into())
+                }
+
+                let tx_env = eth_api.evm_config().tx_env(transaction.as_recovered_ref());
+                let (res, _) = eth_api.transact(&mut db, evm_env, tx_env)?;
+                Ok((res.result.gas_used(), res.result.is_success()))
+            })
+            .await?;
+
+        // the builder only includes the transaction if its gas limit fits into the block, so the
+        // gas limit is reserved rather than the gas used by the simulation
+        let preconfirmations = &self.inner.preconfirmations;
+        if !preconfirmations.reserve(parent_hash, tx_hash, sender, gas_limit, block_gas_limit) {
+            let available_gas =
+                block_gas_limit.saturating_sub(preconfirmations.reserved_gas(parent_hash));
+            return Err(EthApiError::InvalidParams(format!(
+                "transaction with gas limit {gas_limit} is already preconfirmed or exceeds the {available_gas} gas that is left for preconfirmations in block {block_number}"
+            ))
+            .into())
+        }
+
+        if let Err(err) = EthTransactions::send_raw_transaction(&self.inner.eth_api, tx).await {
+            preconfirmations.release(parent_hash, tx_hash, gas_limit);
+            return Err(err)
+        }
+
+        let signature = self
+            .inner
+            .signer
+            .sign_hash_sync(&Preconfirmation::commitment_hash(
+                chain_id,
+                tx_hash,
+                parent_hash,
+                block_number,
+            ))
+            .map_err(|err| EthApiError::InvalidParams(err.to_string()))?;
+
+        Ok(Preconfirmation {
+            chain_id,
+            tx_hash,
+            block_number,
+            parent_hash,
+            gas_used,
+            success,
+            signer: self.inner.signer.address(),
+            signature: signature.as_bytes().into(),
+        })
+    }
+}
+
+#[async_trait]
+impl<Eth, T> RethPreconfApiServer for RethPreconfApi<Eth, T>
+where
+    Eth: EthTransactions + Call + LoadPendingBlock + FullEthApiTypes + 'static,
+    T: PayloadTypes<
+        BuiltPayload: BuiltPayload<
+            Primitives: NodePrimitives<Block = ProviderBlock<Eth::Provider>>,
+        >,
+    >,
+{
+    /// Handler for `reth_preconfirmTransaction`
+    async fn reth_preconfirm_transaction(&self, tx: Bytes) -> RpcResult<Preconfirmation> {
+        trace!(target: "rpc::reth", ?tx, "Serving reth_preconfirmTransaction");
+        Ok(Self::preconfirm_transaction(self, tx).await.map_err(Into::into)?)
+    }
+}
+
+impl<Eth, T: PayloadTypes> Clone for RethPreconfApi<Eth, T> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<Eth, T: PayloadTypes> std::fmt::Debug for RethPreconfApi<Eth, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RethPreconfApi")
+            .field("signer", &self.inner.signer.address())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,596 @@
+//! Verification of the payments of locally proposed blocks and the `reth_getProposerPayments`
+//! endpoint.
+
+use alloy_consensus::{BlockHeader, Transaction, TxReceipt};
+use alloy_evm::block::StateChangeSource;
+use alloy_primitives::{Address, B256, U256};
+use futures::{stream, Stream, StreamExt};
+use jsonrpsee::core::RpcResult;
+use parking_lot::{Mutex, RwLock};
+use reth_errors::RethError;
+use reth_evm::execute::{BlockExecutorProvider, Executor};
+use reth_metrics::{
+    metrics::{Counter, Gauge},
+    Metrics,
+};
+use reth_payload_primitives::PayloadBuilderAttributes;
+use reth_primitives_traits::{BlockBody, NodePrimitives, RecoveredBlock, SignedTransaction};
+use reth_provider::{CanonStateNotification, StateProviderFactory};
+use reth_revm::{database::StateProviderDatabase, state::EvmState};
+use reth_rpc_api::RethProposerPaymentsApiServer;
+use reth_rpc_eth_api::helpers::SpawnBlocking;
+use reth_rpc_eth_types::{BuilderPayment, ProposerPayment};
+use std::{
+    collections::{HashMap, VecDeque},
+    pin::pin,
+    sync::Arc,
+};
+use tracing::{debug, trace, warn};
+
+/// The payments of the most recent locally proposed blocks, shared by the
+/// [`ProposerPaymentTracker`] and the [`ProposerPaymentsApi`].
+#[derive(Debug, Clone)]
+pub struct ProposerPayments {
+    /// The payments, ordered by block.
+    payments: Arc<RwLock<VecDeque<ProposerPayment>>>,
+    /// The maximum number of payments that are kept.
+    max_blocks: usize,
+}
+
+impl ProposerPayments {
+    /// Creates an empty list that keeps the payments of up to `max_blocks` blocks.
+    pub fn new(max_blocks: usize) -> Self {
+        Self { payments: Default::default(), max_blocks }
+    }
+
+    /// Returns the latest `limit` payments, or all if not set, oldest first.
+    pub fn latest(&self, limit: Option<usize>) -> Vec<ProposerPayment> {
+        let payments = self.payments.read();
+        let skip = limit.map_or(0, |limit| payments.len().saturating_sub(limit));
+        payments.iter().skip(skip).cloned().collect()
+    }
+
+    /// Appends the payment, removing the oldest if the list is full.
+    fn push(&self, payment: ProposerPayment) {
+        let mut payments = self.payments.write();
+        if payments.len() >= self.max_blocks {
+            payments.pop_front();
+        }
+        payments.push_back(payment);
+    }
+
+    /// Removes the payments of all blocks starting at `number`.
+    fn remove_from(&self, number: u64) {
+        self.payments.write().retain(|payment| payment.number < number);
+    }
+}
+
+/// Verifies the payment of the fee recipient of every canonical block that was proposed through
+/// this node.
+///
+/// A block is locally proposed if the consensus client sent payload attributes for its parent and
+/// timestamp. Such a block is re-executed on top of its parent state to observe the balance of the
+/// fee recipient after every transaction, so only the balance changes the payments are expected
+/// from are counted:
+///
+/// - if the fee recipient is the beneficiary, the priority fee of every transaction,
+/// - else the payment of the external builder, which are the balance increases caused by the
+///   transactions of the beneficiary, whether it transfers the value directly or through a
+///   contract,
+/// - and the withdrawals to the fee recipient.
+///
+/// Transfers to the fee recipient by other transactions are not counted, and what the fee
+/// recipient spent on its own transactions in the block is accounted for.
+#[derive(Debug)]
+pub struct ProposerPaymentTracker<Provider, E, Eth> {
+    /// State provider factory to read the balances of the fee recipients.
+    provider: Provider,
+    /// Block executor to re-execute the locally proposed blocks.
+    executor: E,
+    /// Spawns the re-execution of the blocks on blocking tasks.
+    eth: Eth,
+    /// The verified payments.
+    payments: ProposerPayments,
+    /// The fee recipients of the blocks the consensus client requested, by parent hash and
+    /// timestamp.
+    proposals: HashMap<(B256, u64), Address>,
+    /// Metrics of the verified payments.
+    metrics: ProposerPaymentMetrics,
+}
+
+impl<Provider, E, Eth> ProposerPaymentTracker<Provider, E, Eth>
+where
+    Provider: StateProviderFactory + Clone + 'static,
+    E: BlockExecutorProvider,
+    Eth: SpawnBlocking,
+{
+    /// Creates a new tracker that keeps the payments of up to `max_blocks` blocks.
+    pub fn new(provider: Provider, executor: E, eth: Eth, max_blocks: usize) -> Self {
+        Self {
+            provider,
+            executor,
+            eth,
+            payments: ProposerPayments::new(max_blocks),
+            proposals: HashMap::new(),
+            metrics: Default::default(),
+        }
+    }
+
+    /// Returns the list of verified payments.
+    pub fn payments(&self) -> ProposerPayments {
+        self.payments.clone()
+    }
+
+    /// Runs the tracker until both the payload attributes and the notification stream end.
+    pub async fn run<A, At, St>(mut self, attributes: At, notifications: St)
+    where
+        A: PayloadBuilderAttributes,
+        At: Stream<Item = A>,
+        St: Stream<Item = CanonStateNotification<E::Primitives>>,
+    {
+        let mut events = pin!(stream::select(
+            attributes.map(TrackerEvent::Attributes),
+            notifications.map(TrackerEvent::Notification),
+        ));
+
+        while let Some(event) = events.next().await {
+            match event {
+                TrackerEvent::Attributes(attributes) => {
+                    self.proposals.insert(
+                        (attributes.parent(), attributes.timestamp()),
+                        attributes.suggested_fee_recipient(),
+                    );
+                }
+                TrackerEvent::Notification(notification) => {
+                    self.on_notification(notification).await
+                }
+            }
+        }
+        debug!(target: "rpc::proposer_payments", "Payload attributes and canonical state notification streams ended");
+    }
+
+    /// Verifies the payments of the locally proposed blocks of the notification.
+    async fn on_notification(&mut self, notification: CanonStateNotification<E::Primitives>) {
+        if let Some(reverted) = notification.reverted() {
+            self.payments.remove_from(*reverted.range().start());
+        }
+
+        let committed = notification.committed();
+        let proposed = committed
+            .blocks_iter()
+            .filter_map(|block| {
+                let key = (block.header().parent_hash(), block.header().timestamp());
+                self.proposals.remove(&key).map(|fee_recipient| (block.number(), fee_recipient))
+            })
+            .collect::<Vec<_>>();
+
+        // attributes of slots that are in the past can't be proposed anymore
+        let timestamp = committed.tip().header().timestamp();
+        self.proposals.retain(|(_, proposal_timestamp), _| *proposal_timestamp > timestamp);
+
+        if proposed.is_empty() {
+            return
+        }
+
+        let provider = self.provider.clone();
+        let executor = self.executor.clone();
+        let verified = self
+            .eth
+            .spawn_blocking_io(move |_| {
+                Ok(proposed
+                    .into_iter()
+                    .filter_map(|(number, fee_recipient)| {
+                        let block = committed.blocks().get(&number)?;
+                        Some((number, verify_payment(&provider, &executor, block, fee_recipient)))
+                    })
+                    .collect::<Vec<_>>())
+            })
+            .await;
+        let verified = match verified {
+            Ok(verified) => verified,
+            Err(err) => {
+                warn!(target: "rpc::proposer_payments", %err, "Failed to verify proposer payments");
+                return
+            }
+        };
+
+        for (number, payment) in verified {
+            match payment {
+                Ok(payment) => {
+                    trace!(target: "rpc::proposer_payments", ?payment, "Verified proposer payment");
+                    if !payment.verified {
+                        warn!(
+                            target: "rpc::proposer_payments",
+                            block = payment.number,
+                            fee_recipient = %payment.fee_recipient,
+                            expected = %payment.expected,
+                            received = %payment.received,
+                            "Fee recipient received less than expected"
+                        );
+                        self.metrics.shortfalls.increment(1);
+                    }
+                    self.metrics.blocks.increment(1);
+                    if payment.beneficiary != payment.fee_recipient {
+                        self.metrics.builder_blocks.increment(1);
+                    }
+                    self.metrics
+                        .last_received
+                        .set(payment.received.saturating_to::<u128>() as f64 / 1e18);
+                    self.payments.push(payment);
+                }
+                Err(err) => {
+                    warn!(target: "rpc::proposer_payments", block = number, %err, "Failed to verify proposer payment");
+                }
+            }
+        }
+    }
+}
+
+/// Re-executes the block on top of its parent state and returns the payment of the fee recipient.
+fn verify_payment<Provider, E>(
+    provider: &Provider,
+    executor: &E,
+    block: &RecoveredBlock<<E::Primitives as NodePrimitives>::Block>,
+    fee_recipient: Address,
+) -> Result<ProposerPayment, RethError>
+where
+    Provider: StateProviderFactory,
+    E: BlockExecutorProvider,
+{
+    let header = block.header();
+    let state = provider.state_by_block_hash(header.parent_hash())?;
+    let balance_before = state.account_balance(&fee_recipient)?.unwrap_or_default();
+
+    // the balance of the fee recipient after every state change that touched it
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let hook_changes = changes.clone();
+    let state_hook = move |source: StateChangeSource, state: &EvmState| {
+        if let Some(account) = state.get(&fee_recipient) {
+            hook_changes.lock().push((source, account.info.balance));
+        }
+    };
+    let output = executor
+        .executor(StateProviderDatabase::new(state))
+        .execute_with_state_hook(block, state_hook)?;
+
+    // the fees of a transaction are its effective gas price times the gas it used, which is
+    // derived from the cumulative gas used of the receipts
+    let base_fee = header.base_fee_per_gas().unwrap_or_default();
+    let mut cumulative_gas_used = 0;
+    let transactions = block
+        .transactions_with_sender()
+        .zip(&output.result.receipts)
+        .map(|((sender, tx), receipt)| {
+            let gas_used = receipt.cumulative_gas_used() - cumulative_gas_used;
+            cumulative_gas_used = receipt.cumulative_gas_used();
+            // the value is only transferred if the transaction succeeded
+            let value = if receipt.status() { tx.value() } else { U256::ZERO };
+            let fee = U256::from(tx.effective_gas_price(Some(base_fee))) * U256::from(gas_used);
+            PaymentTransaction {
+                hash: *tx.tx_hash(),
+                sender: *sender,
+                priority_fee: U256::from(tx.effective_tip_per_gas(base_fee).unwrap_or_default()) *
+                    U256::from(gas_used),
+                cost: value + fee,
+            }
+        })
+        .collect();
+
+    let withdrawals = block
+        .body()
+        .withdrawals()
+        .iter()
+        .flat_map(|withdrawals| withdrawals.iter())
+        .filter(|withdrawal| withdrawal.address == fee_recipient)
+        .map(|withdrawal| withdrawal.amount_wei())
+        .sum::<U256>();
+
+    let changes = std::mem::take(&mut *changes.lock());
+    Ok(BlockPayments {
+        number: header.number(),
+        hash: block.hash(),
+        fee_recipient,
+        beneficiary: header.beneficiary(),
+        withdrawals,
+        transactions,
+        balance_before,
+        changes,
+    }
+    .into_payment())
+}
+
+/// A transaction of a locally proposed block, as far as it's relevant for the payment of the fee
+/// recipient.
+#[derive(Debug)]
+struct PaymentTransaction {
+    /// The hash of the transaction.
+    hash: B256,
+    /// The sender of the transaction.
+    sender: Address,
+    /// The priority fee the transaction paid to the beneficiary.
+    priority_fee: U256,
+    /// The transferred value and the fees the sender paid.
+    cost: U256,
+}
+
+/// The payments of a locally proposed block and the balance changes of its fee recipient during
+/// the execution of the block.
+#[derive(Debug)]
+struct BlockPayments {
+    /// The number of the block.
+    number: u64,
+    /// The hash of the block.
+    hash: B256,
+    /// The fee recipient requested in the payload attributes of the block.
+    fee_recipient: Address,
+    /// The beneficiary of the block.
+    beneficiary: Address,
+    /// The withdrawals of the block to the fee recipient.
+    withdrawals: U256,
+    /// The transactions of the block.
+    transactions: Vec<PaymentTransaction>,
+    /// The balance of the fee recipient before the block.
+    balance_before: U256,
+    /// The balance of the fee recipient after every state change that touched it.
+    changes: Vec<(StateChangeSource, U256)>,
+}
+
+impl BlockPayments {
+    /// Returns the expected payment of the fee recipient and the part of it that was received.
+    fn into_payment(self) -> ProposerPayment {
+        let Self {
+            number,
+            hash,
+            fee_recipient,
+            beneficiary,
+            withdrawals,
+            transactions,
+            balance_before,
+            changes,
+        } = self;
+        let is_beneficiary = beneficiary == fee_recipient;
+
+        let priority_fees = transactions.iter().map(|tx| tx.priority_fee).sum::<U256>();
+        let mut builder_payment: Option<BuilderPayment> = None;
+        let mut received = U256::ZERO;
+        let mut balance = balance_before;
+        for (source, balance_after) in changes {
+            let increase = balance_after.saturating_sub(balance);
+            match source {
+                StateChangeSource::Transaction(index) => {
+                    let Some(tx) = transactions.get(index) else { continue };
+                    if is_beneficiary {
+                        // only the priority fee is counted, not what else the transaction
+                        // transferred to the fee recipient
+                        let increase = if tx.sender == fee_recipient {
+                            (balance_after + tx.cost).saturating_sub(balance)
+                        } else {
+                            increase
+                        };
+                        received += increase.min(tx.priority_fee);
+                    } else if tx.sender == beneficiary && !increase.is_zero() {
+                        let payment = builder_payment.get_or_insert(BuilderPayment {
+                            transaction_hash: tx.hash,
+                            value: U256::ZERO,
+                        });
+                        payment.transaction_hash = tx.hash;
+                        payment.value += increase;
+                        received += increase;
+                    }
+                }
+                StateChangeSource::PostBlock(_) => received += increase.min(withdrawals),
+                StateChangeSource::PreBlock(_) => {}
+            }
+            balance = balance_after;
+        }
+
+        let fees = if is_beneficiary { priority_fees } else { U256::ZERO };
+        let expected = fees +
+            builder_payment.as_ref().map(|payment| payment.value).unwrap_or_default() +
+            withdrawals;
+        // a block of an external builder that doesn't pay the fee recipient is not verified
+        let verified = received >= expected && (is_beneficiary || builder_payment.is_some());
+
+        ProposerPayment {
+            number,
+            hash,
+            fee_recipient,
+            beneficiary,
+            priority_fees,
+            builder_payment,
+            withdrawals,
+            expected,
+            received,
+            verified,
+        }
+    }
+}
+
+/// An event the [`ProposerPaymentTracker`] reacts to.
+enum TrackerEvent<A, N: NodePrimitives> {
+    /// The consensus client sent payload attributes.
+    Attributes(A),
+    /// The canonical chain changed.
+    Notification(CanonStateNotification<N>),
+}
+
+/// Metrics of the [`ProposerPaymentTracker`].
+#[derive(Metrics)]
+#[metrics(scope = "rpc.proposer_payments")]
+struct ProposerPaymentMetrics {
+    /// The number of verified locally proposed blocks.
+    blocks: Counter,
+    /// The number of locally proposed blocks that were built by an external builder.
+    builder_blocks: Counter,
+    /// The number of locally proposed blocks whose fee recipient received less than expected.
+    shortfalls: Counter,
+    /// The amount the fee recipient received for the last locally proposed block, in ether.
+    last_received: Gauge,
+}
+
+/// `reth` API implementation for the payments of locally proposed blocks.
+#[derive(Debug, Clone)]
+pub struct ProposerPaymentsApi {
+    /// The payments verified by the [`ProposerPaymentTracker`].
+    payments: ProposerPayments,
+}
+
+impl ProposerPaymentsApi {
+    /// Creates a new instance of the [`ProposerPaymentsApi`].
+    pub const fn new(payments: ProposerPayments) -> Self {
+        Self { payments }
+    }
+}
+
+impl RethProposerPaymentsApiServer for ProposerPaymentsApi {
+    /// Handler for `reth_getProposerPayments`
+    fn reth_get_proposer_payments(&self, limit: Option<usize>) -> RpcResult<Vec<ProposerPayment>> {
+        Ok(self.payments.latest(limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_evm::block::StateChangePostBlockSource;
+
+    const FEE_RECIPIENT: Address = Address::with_last_byte(1);
+    const BUILDER: Address = Address::with_last_byte(2);
+    const USER: Address = Address::with_last_byte(3);
+
+    fn transaction(index: u8, sender: Address, priority_fee: u64, cost: u64) -> PaymentTransaction {
+        PaymentTransaction {
+            hash: B256::with_last_byte(index),
+            sender,
+            priority_fee: U256::from(priority_fee),
+            cost: U256::from(cost),
+        }
+    }
+
+    fn block(
+        beneficiary: Address,
+        transactions: Vec<PaymentTransaction>,
+        changes: Vec<(StateChangeSource, u64)>,
+    ) -> BlockPayments {
+        BlockPayments {
+            number: 1,
+            hash: B256::with_last_byte(1),
+            fee_recipient: FEE_RECIPIENT,
+            beneficiary,
+            withdrawals: U256::from(50),
+            transactions,
+            balance_before: U256::from(1_000),
+            changes: changes
+                .into_iter()
+                .map(|(source, balance)| (source, U256::from(balance)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn local_block_ignores_unrelated_transfers() {
+        let payment = block(
+            FEE_RECIPIENT,
+            vec![
+                transaction(1, USER, 10, 0),
+                // transfers 500 to the fee recipient on top of the priority fee
+                transaction(2, USER, 20, 0),
+                // the fee recipient pays 100 for its own transaction and gets its priority fee
+                transaction(3, FEE_RECIPIENT, 30, 100),
+            ],
+            vec![
+                (StateChangeSource::Transaction(0), 1_010),
+                (StateChangeSource::Transaction(1), 1_530),
+                (StateChangeSource::Transaction(2), 1_460),
+                (
+                    StateChangeSource::PostBlock(StateChangePostBlockSource::BalanceIncrements),
+                    1_510,
+                ),
+            ],
+        )
+        .into_payment();
+
+        assert_eq!(payment.priority_fees, U256::from(60));
+        assert_eq!(payment.expected, U256::from(110));
+        assert_eq!(payment.received, U256::from(110));
+        assert!(payment.builder_payment.is_none());
+        assert!(payment.verified);
+    }
+
+    #[test]
+    fn local_block_with_missing_fees_is_not_verified() {
+        let payment = block(
+            FEE_RECIPIENT,
+            vec![transaction(1, USER, 10, 0), transaction(2, USER, 20, 0)],
+            // the second transaction doesn't pay its priority fee, but the first one transfers
+            // more than the missing fee
+            vec![
+                (StateChangeSource::Transaction(0), 1_100),
+                (
+                    StateChangeSource::PostBlock(StateChangePostBlockSource::BalanceIncrements),
+                    1_150,
+                ),
+            ],
+        )
+        .into_payment();
+
+        assert_eq!(payment.expected, U256::from(80));
+        assert_eq!(payment.received, U256::from(60));
+        assert!(!payment.verified);
+    }
+
+    #[test]
+    fn builder_payment_through_contract() {
+        let payment = block(
+            BUILDER,
+            vec![
+                transaction(1, USER, 10, 0),
+                // an unrelated transfer to the fee recipient
+                transaction(2, USER, 20, 0),
+                // the builder pays the fee recipient through a contract
+                transaction(3, BUILDER, 30, 0),
+            ],
+            vec![
+                (StateChangeSource::Transaction(1), 1_200),
+                (StateChangeSource::Transaction(2), 1_500),
+                (
+                    StateChangeSource::PostBlock(StateChangePostBlockSource::BalanceIncrements),
+                    1_550,
+                ),
+            ],
+        )
+        .into_payment();
+
+        assert_eq!(
+            payment.builder_payment,
+            Some(BuilderPayment {
+                transaction_hash: B256::with_last_byte(3),
+                value: U256::from(300)
+            })
+        );
+        assert_eq!(payment.expected, U256::from(350));
+        assert_eq!(payment.received, U256::from(350));
+        assert!(payment.verified);
+    }
+
+    #[test]
+    fn builder_block_without_payment_is_not_verified() {
+        let payment = block(
+            BUILDER,
+            vec![transaction(1, USER, 10, 0)],
+            vec![
+                (StateChangeSource::Transaction(0), 1_200),
+                (
+                    StateChangeSource::PostBlock(StateChangePostBlockSource::BalanceIncrements),
+                    1_250,
+                ),
+            ],
+        )
+        .into_payment();
+
+        assert!(payment.builder_payment.is_none());
+        assert_eq!(payment.expected, U256::from(50));
+        assert_eq!(payment.received, U256::from(50));
+        assert!(!payment.verified);
+    }
+}
@@ -0,0 +1,488 @@
+//! Execution layer request index and the `reth_getRequestsBy*` endpoints.
+
+use alloy_consensus::BlockHeader;
+use alloy_eips::{BlockNumHash, BlockNumberOrTag};
+use alloy_primitives::{keccak256, BlockHash, BlockNumber};
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use jsonrpsee::core::RpcResult;
+use reth_chainspec::{ChainSpecProvider, EthereumHardforks};
+use reth_db::{
+    cursor::DbCursorRO,
+    models::{
+        BlockExecutionRequest, ExecutionRequest, IndexCheckpoint, StoredBlockRequests,
+        ValidatorPubkey,
+    },
+    tables,
+    transaction::{DbTx, DbTxMut},
+    BlockNumberList,
+};
+use reth_evm::execute::{BlockExecutorProvider, Executor};
+use reth_primitives_traits::NodePrimitives;
+use reth_provider::{
+    BlockIdReader, BlockReader, CanonStateNotification, DBProvider, DatabaseProviderFactory,
+    HeaderProvider, ProviderResult, StateProviderFactory, TransactionVariant,
+};
+use reth_revm::database::StateProviderDatabase;
+use reth_rpc_api::RethExecutionRequestsApiServer;
+use reth_rpc_eth_api::{helpers::SpawnBlocking, FromEthApiError};
+use reth_rpc_eth_types::EthApiError;
+use std::collections::{BTreeMap, BTreeSet};
+use tracing::{debug, trace, warn};
+
+/// The id of the execution layer request index in [`tables::IndexCheckpoints`].
+const REQUEST_INDEX_ID: &str = "ExecutionRequests";
+
+/// The maximum number of blocks that are executed and written in one database transaction.
+const BACKFILL_BATCH_SIZE: u64 = 100;
+
+/// Maintains the index of the execution layer requests of canonical blocks.
+///
+/// The [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685) requests of every canonical block with
+/// requests are written to [`tables::ExecutionRequests`], and the blocks with requests that refer
+/// to a validator to [`tables::ValidatorRequestBlocks`].
+///
+/// The last indexed block is saved in [`tables::IndexCheckpoints`], and on every canonical state
+/// notification the index catches up with the canonical chain from there, starting at the Prague
+/// activation if the index is empty. The requests of the blocks of the notification are taken from
+/// their execution outcome, other blocks are executed again. Blocks whose state was pruned by the
+/// node can't be executed, so they can't be indexed.
+#[derive(Debug)]
+pub struct ExecutionRequestIndexer<Provider, Executor, Eth> {
+    /// Provider to read the canonical chain and to read and write the index.
+    provider: Provider,
+    /// Executor of the blocks that are not part of a canonical state notification.
+    executor: Executor,
+    /// Eth API to spawn the blocking database work.
+    eth: Eth,
+}
+
+impl<Provider, E, Eth> ExecutionRequestIndexer<Provider, E, Eth>
+where
+    Provider: DatabaseProviderFactory
+        + BlockIdReader
+        + BlockReader
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthereumHardforks>
+        + Clone
+        + 'static,
+    E: BlockExecutorProvider<Primitives: NodePrimitives<Block = Provider::Block>>,
+    Eth: SpawnBlocking + 'static,
+{
+    /// Creates a new indexer.
+    pub const fn new(provider: Provider, executor: E, eth: Eth) -> Self {
+        Self { provider, executor, eth }
+    }
+
+    /// Runs the indexer until the notification stream ends.
+    pub async fn run<St, N>(self, mut notifications: St)
+    where
+        St: Stream<Item = CanonStateNotification<N>> + Unpin,
+        N: NodePrimitives,
+    {
+        self.update(None, BTreeMap::new()).await;
+        while let Some(notification) = notifications.next().await {
+            let fork_block = notification.reverted().map(|chain| chain.fork_block());
+
+            // the requests of the committed blocks are known, they don't have to be executed
+            let committed = notification.committed();
+            let outcome = committed.execution_outcome();
+            let known = committed
+                .blocks_iter()
+                .zip(&outcome.requests)
+                .map(|(block, requests)| {
+                    (
+                        block.number(),
+                        (block.hash(), StoredBlockRequests { requests: requests.clone().take() }),
+                    )
+                })
+                .collect();
+
+            self.update(fork_block, known).await;
+        }
+        debug!(target: "rpc::request_index", "Canonical state notification stream ended");
+    }
+
+    /// Unwinds the index to the fork block of reverted blocks, if any, and catches up with the
+    /// canonical chain.
+    ///
+    /// Failures are retried with the next notification.
+    async fn update(
+        &self,
+        fork_block: Option<BlockNumHash>,
+        known: BTreeMap<BlockNumber, (BlockHash, StoredBlockRequests)>,
+    ) {
+        if let Err(err) = self.catch_up(fork_block, known).await {
+            warn!(target: "rpc::request_index", %err, "Failed to update execution request index");
+        }
+    }
+
+    /// Indexes all canonical blocks after the checkpoint on a blocking task.
+    async fn catch_up(
+        &self,
+        fork_block: Option<BlockNumHash>,
+        known: BTreeMap<BlockNumber, (BlockHash, StoredBlockRequests)>,
+    ) -> Result<(), Eth::Error> {
+        let provider = self.provider.clone();
+        let executor = self.executor.clone();
+        self.eth
+            .spawn_blocking_io(move |_| {
+                catch_up(&provider, &executor, fork_block, &known).map_err(Eth::Error::from_eth_err)
+            })
+            .await
+    }
+}
+
+/// Indexes all canonical blocks after the checkpoint, in batches of [`BACKFILL_BATCH_SIZE`]
+/// blocks.
+///
+/// Every batch is written together with its checkpoint, so a block that can't be executed stops
+/// the catch up instead of being skipped.
+fn catch_up<Provider, E>(
+    provider: &Provider,
+    executor: &E,
+    fork_block: Option<BlockNumHash>,
+    known: &BTreeMap<BlockNumber, (BlockHash, StoredBlockRequests)>,
+) -> Result<(), EthApiError>
+where
+    Provider: DatabaseProviderFactory
+        + BlockIdReader
+        + BlockReader
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthereumHardforks>,
+    E: BlockExecutorProvider<Primitives: NodePrimitives<Block = Provider::Block>>,
+{
+    let checkpoint = canonical_checkpoint(provider, fork_block)?;
+    let tip = provider.best_block_number()?;
+
+    let mut next_block = match checkpoint {
+        Some(checkpoint) => checkpoint.block_number + 1,
+        None => first_prague_block(provider, tip)?,
+    };
+    while next_block <= tip {
+        let mut blocks = Vec::new();
+        let mut checkpoint = None;
+        for number in next_block..=tip.min(next_block + BACKFILL_BATCH_SIZE - 1) {
+            let Some(hash) = provider.block_hash(number)? else { break };
+            let requests = match known.get(&number) {
+                Some((known_hash, requests)) if *known_hash == hash => requests.clone(),
+                _ => execute_requests(provider, executor, number, hash)?,
+            };
+            if !requests.requests.is_empty() {
+                blocks.push((number, requests));
+            }
+            checkpoint = Some(IndexCheckpoint { block_number: number, block_hash: hash });
+        }
+        let Some(checkpoint) = checkpoint else { break };
+
+        trace!(target: "rpc::request_index", from = next_block, to = checkpoint.block_number, blocks = blocks.len(), "Indexing execution requests");
+        let provider = provider.database_provider_rw()?;
+        write_requests(provider.tx_ref(), blocks, checkpoint)?;
+        provider.commit()?;
+        next_block = checkpoint.block_number + 1;
+    }
+    Ok(())
+}
+
+/// Returns the checkpoint of the index, after unwinding the index to the fork block of reverted
+/// blocks.
+///
+/// If the last indexed block is no longer canonical without a notification, e.g. because the
+/// pipeline unwound it or the notifications lagged, the fork block is unknown and the index is
+/// unwound to the finalized block.
+fn canonical_checkpoint<Provider>(
+    provider: &Provider,
+    fork_block: Option<BlockNumHash>,
+) -> ProviderResult<Option<IndexCheckpoint>>
+where
+    Provider: DatabaseProviderFactory + BlockIdReader,
+{
+    let checkpoint = provider
+        .database_provider_ro()?
+        .tx_ref()
+        .get::<tables::IndexCheckpoints>(REQUEST_INDEX_ID.to_string())?;
+    let Some(checkpoint) = checkpoint else { return Ok(None) };
+
+    let unwind_to = if let Some(fork_block) =
+        fork_block.filter(|fork_block| fork_block.number < checkpoint.block_number)
+    {
+        Some(IndexCheckpoint { block_number: fork_block.number, block_hash: fork_block.hash })
+    } else if provider.block_hash(checkpoint.block_number)? != Some(checkpoint.block_hash) {
+        finalized_checkpoint(provider, checkpoint.block_number)?
+    } else {
+        return Ok(Some(checkpoint))
+    };
+
+    debug!(target: "rpc::request_index", from = ?checkpoint, to = ?unwind_to, "Unwinding execution request index");
+    let provider = provider.database_provider_rw()?;
+    unwind_index(provider.tx_ref(), unwind_to)?;
+    provider.commit()?;
+    Ok(unwind_to)
+}
+
+/// Returns the finalized block as checkpoint, if it's below `block_number`.
+fn finalized_checkpoint<Provider: BlockIdReader>(
+    provider: &Provider,
+    block_number: BlockNumber,
+) -> ProviderResult<Option<IndexCheckpoint>> {
+    let Some(finalized) =
+        provider.finalized_block_number()?.filter(|finalized| *finalized < block_number)
+    else {
+        return Ok(None)
+    };
+    Ok(provider
+        .block_hash(finalized)?
+        .map(|block_hash| IndexCheckpoint { block_number: finalized, block_hash }))
+}
+
+/// Returns the first block up to `tip` that is produced after the Prague activation, or the block
+/// after `tip` if Prague isn't active at the tip.
+///
+/// Earlier blocks have no requests, so they are not indexed.
+fn first_prague_block<Provider>(
+    provider: &Provider,
+    tip: BlockNumber,
+) -> ProviderResult<BlockNumber>
+where
+    Provider: HeaderProvider + ChainSpecProvider<ChainSpec: EthereumHardforks>,
+{
+    let chain_spec = provider.chain_spec();
+    let is_prague = |number| -> ProviderResult<bool> {
+        Ok(provider
+            .header_by_number(number)?
+            .is_some_and(|header| chain_spec.is_prague_active_at_timestamp(header.timestamp())))
+    };
+
+    // block timestamps are increasing, so the blocks after the activation are a suffix
+    let (mut low, mut high) = (0, tip + 1);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if is_prague(mid)? {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Ok(low)
+}
+
+/// Executes the canonical block on top of the state of its parent and returns its requests.
+fn execute_requests<Provider, E>(
+    provider: &Provider,
+    executor: &E,
+    number: BlockNumber,
+    hash: BlockHash,
+) -> Result<StoredBlockRequests, EthApiError>
+where
+    Provider: BlockReader + StateProviderFactory,
+    E: BlockExecutorProvider<Primitives: NodePrimitives<Block = Provider::Block>>,
+{
+    let block = provider
+        .recovered_block(hash.into(), TransactionVariant::WithHash)?
+        .ok_or(EthApiError::HeaderNotFound(hash.into()))?;
+    let state = provider.history_by_block_hash(block.header().parent_hash())?;
+    let result = executor.executor(StateProviderDatabase::new(state)).execute_one(&block)?;
+    trace!(target: "rpc::request_index", number, "Executed block for its requests");
+    Ok(StoredBlockRequests { requests: result.requests.take() })
+}
+
+/// Writes the requests of the blocks to the index and moves the checkpoint to `checkpoint`.
+fn write_requests<Tx: DbTx + DbTxMut>(
+    tx: &Tx,
+    blocks: Vec<(BlockNumber, StoredBlockRequests)>,
+    checkpoint: IndexCheckpoint,
+) -> ProviderResult<()> {
+    for (block_number, requests) in blocks {
+        for pubkey in validator_pubkeys(&requests.decode()) {
+            let key = keccak256(pubkey);
+            let mut block_numbers = tx
+                .get::<tables::ValidatorRequestBlocks>(key)?
+                .unwrap_or_else(BlockNumberList::empty);
+            block_numbers.0.insert(block_number);
+            tx.put::<tables::ValidatorRequestBlocks>(key, block_numbers)?;
+        }
+        tx.put::<tables::ExecutionRequests>(block_number, requests)?;
+    }
+    tx.put::<tables::IndexCheckpoints>(REQUEST_INDEX_ID.to_string(), checkpoint)?;
+    Ok(())
+}
+
+/// Removes the entries of all blocks after `checkpoint` and moves the checkpoint there.
+///
+/// If `checkpoint` is `None`, the index is cleared.
+fn unwind_index<Tx: DbTx + DbTxMut>(
+    tx: &Tx,
+    checkpoint: Option<IndexCheckpoint>,
+) -> ProviderResult<()> {
+    remove_blocks(tx, checkpoint.map_or(0, |checkpoint| checkpoint.block_number + 1))?;
+    if let Some(checkpoint) = checkpoint {
+        tx.put::<tables::IndexCheckpoints>(REQUEST_INDEX_ID.to_string(), checkpoint)?;
+    } else {
+        tx.delete::<tables::IndexCheckpoints>(REQUEST_INDEX_ID.to_string(), None)?;
+    }
+    Ok(())
+}
+
+/// Returns the distinct public keys of the validators the requests refer to.
+fn validator_pubkeys(requests: &[ExecutionRequest]) -> BTreeSet<ValidatorPubkey> {
+    requests.iter().flat_map(ExecutionRequest::validator_pubkeys).collect()
+}
+
+/// Removes the entries of all blocks starting at `unwind_from` from the index.
+fn remove_blocks<Tx: DbTx + DbTxMut>(tx: &Tx, unwind_from: BlockNumber) -> ProviderResult<()> {
+    let mut blocks = Vec::new();
+    let mut pubkeys = BTreeSet::new();
+    let mut cursor = tx.cursor_read::<tables::ExecutionRequests>()?;
+    for entry in cursor.walk_range(unwind_from..)? {
+        let (block_number, requests) = entry?;
+        blocks.push(block_number);
+        pubkeys.extend(validator_pubkeys(&requests.decode()));
+    }
+
+    for pubkey in pubkeys {
+        let key = keccak256(pubkey);
+        let Some(mut block_numbers) = tx.get::<tables::ValidatorRequestBlocks>(key)? else {
+            continue
+        };
+        block_numbers.0.remove_range(unwind_from..);
+        if block_numbers.0.is_empty() {
+            tx.delete::<tables::ValidatorRequestBlocks>(key, None)?;
+        } else {
+            tx.put::<tables::ValidatorRequestBlocks>(key, block_numbers)?;
+        }
+    }
+
+    for block_number in blocks {
+        tx.delete::<tables::ExecutionRequests>(block_number, None)?;
+    }
+    Ok(())
+}
+
+/// `reth` API implementation for the execution layer request index.
+#[derive(Debug, Clone)]
+pub struct ExecutionRequestsApi<Provider> {
+    /// Database provider factory to read the index.
+    provider: Provider,
+}
+
+impl<Provider> ExecutionRequestsApi<Provider> {
+    /// Creates a new instance of the [`ExecutionRequestsApi`].
+    pub const fn new(provider: Provider) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl<Provider> RethExecutionRequestsApiServer for ExecutionRequestsApi<Provider>
+where
+    Provider: DatabaseProviderFactory + BlockIdReader + 'static,
+{
+    /// Handler for `reth_getRequestsByBlock`
+    async fn reth_get_requests_by_block(
+        &self,
+        block: BlockNumberOrTag,
+    ) -> RpcResult<Option<Vec<ExecutionRequest>>> {
+        let requests = (|| -> ProviderResult<_> {
+            let Some(block_number) = self.provider.convert_block_number(block)? else {
+                return Ok(None)
+            };
+            let provider = self.provider.database_provider_ro()?;
+            let tx = provider.tx_ref();
+            if let Some(requests) = tx.get::<tables::ExecutionRequests>(block_number)? {
+                return Ok(Some(requests.decode()))
+            }
+
+            // only blocks with requests are stored, the other indexed blocks have none
+            let indexed = tx
+                .get::<tables::IndexCheckpoints>(REQUEST_INDEX_ID.to_string())?
+                .is_some_and(|checkpoint| block_number <= checkpoint.block_number);
+            Ok(indexed.then(Vec::new))
+        })()
+        .map_err(EthApiError::from)?;
+        Ok(requests)
+    }
+
+    /// Handler for `reth_getRequestsByValidator`
+    async fn reth_get_requests_by_validator(
+        &self,
+        pubkey: ValidatorPubkey,
+    ) -> RpcResult<Vec<BlockExecutionRequest>> {
+        let requests = (|| -> ProviderResult<_> {
+            let provider = self.provider.database_provider_ro()?;
+            let tx = provider.tx_ref();
+            let Some(block_numbers) =
+                tx.get::<tables::ValidatorRequestBlocks>(keccak256(pubkey))?
+            else {
+                return Ok(Vec::new())
+            };
+
+            let mut requests = Vec::new();
+            for block_number in block_numbers.iter() {
+                let Some(block_requests) = tx.get::<tables::ExecutionRequests>(block_number)?
+                else {
+                    continue
+                };
+                requests.extend(
+                    block_requests
+                        .decode()
+                        .into_iter()
+                        .filter(|request| request.validator_pubkeys().contains(&pubkey))
+                        .map(|request| BlockExecutionRequest { block_number, request }),
+                );
+            }
+            Ok(requests)
+        })()
+        .map_err(EthApiError::from)?;
+        Ok(requests)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip7002::WITHDRAWAL_REQUEST_TYPE;
+    use alloy_primitives::{Address, B256};
+    use reth_provider::test_utils::create_test_provider_factory;
+
+    #[test]
+    fn write_and_unwind_index() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        let tx = provider.tx_ref();
+
+        let pubkey = ValidatorPubkey::repeat_byte(1);
+        let withdrawal = || {
+            let mut request = vec![WITHDRAWAL_REQUEST_TYPE];
+            request.extend_from_slice(Address::repeat_byte(2).as_slice());
+            request.extend_from_slice(pubkey.as_slice());
+            request.extend_from_slice(&1_000u64.to_le_bytes());
+            StoredBlockRequests { requests: vec![request.into()] }
+        };
+        let checkpoint = |block_number: BlockNumber| IndexCheckpoint {
+            block_number,
+            block_hash: B256::with_last_byte(block_number as u8),
+        };
+        let get_checkpoint =
+            || tx.get::<tables::IndexCheckpoints>(REQUEST_INDEX_ID.to_string()).unwrap();
+        let get_blocks = || {
+            tx.get::<tables::ValidatorRequestBlocks>(keccak256(pubkey))
+                .unwrap()
+                .map(|blocks| blocks.0.iter().collect::<Vec<_>>())
+        };
+
+        write_requests(tx, vec![(1, withdrawal()), (3, withdrawal())], checkpoint(4)).unwrap();
+        assert_eq!(get_blocks(), Some(vec![1, 3]));
+        assert_eq!(tx.get::<tables::ExecutionRequests>(3).unwrap(), Some(withdrawal()));
+        assert_eq!(get_checkpoint(), Some(checkpoint(4)));
+
+        unwind_index(tx, Some(checkpoint(2))).unwrap();
+        assert_eq!(get_blocks(), Some(vec![1]));
+        assert_eq!(tx.get::<tables::ExecutionRequests>(3).unwrap(), None);
+        assert_eq!(get_checkpoint(), Some(checkpoint(2)));
+
+        unwind_index(tx, None).unwrap();
+        assert_eq!(get_blocks(), None);
+        assert_eq!(tx.get::<tables::ExecutionRequests>(1).unwrap(), None);
+        assert_eq!(get_checkpoint(), None);
+    }
+}
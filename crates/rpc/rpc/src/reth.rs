@@ -1,41 +1,120 @@
-use std::{collections::HashMap, future::Future, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    sync::Arc,
+};
 
-use alloy_eips::BlockId;
-use alloy_primitives::{Address, U256};
+use alloy_consensus::{transaction::TransactionMeta, BlockHeader, Header, Transaction, TxReceipt};
+use alloy_eips::{BlockId, BlockNumHash, BlockNumberOrTag};
+use alloy_evm::block::calc::{base_block_reward_pre_merge, block_reward, ommer_reward};
+use alloy_primitives::{Address, BlockNumber, Bytes, TxHash, B256, U256};
+use alloy_rlp::Encodable;
+use alloy_rpc_types_engine::PayloadAttributes;
+use alloy_rpc_types_eth::{
+    state::EvmOverrides, Block, BlockTransactionsKind, Filter, FilterBlockOption, FilteredParams,
+    TransactionRequest,
+};
 use async_trait::async_trait;
-use jsonrpsee::core::RpcResult;
-use reth_errors::RethResult;
-use reth_provider::{BlockReaderIdExt, ChangeSetReader, StateProviderFactory};
-use reth_rpc_api::RethApiServer;
-use reth_rpc_eth_types::{EthApiError, EthResult};
+use futures::StreamExt;
+use jsonrpsee::{
+    core::{RpcResult, SubscriptionResult},
+    server::SubscriptionMessage,
+    PendingSubscriptionSink, SubscriptionSink,
+};
+use reth_chainspec::{ChainSpec, ChainSpecProvider, EthChainSpec, EthereumHardforks};
+use reth_errors::RethError;
+use reth_ethereum_payload_builder::EthereumPayloadBuilder;
+use reth_ethereum_primitives::{EthPrimitives, TransactionSigned};
+use reth_evm::{ConfigureEvm, NextBlockEnvAttributes, TransactionEnv};
+use reth_network_api::{NetworkInfo, TransactionPropagation};
+use reth_node_tx_watcher::TxWatcherHandle;
+use reth_payload_primitives::SimulatedPayload;
+use reth_primitives_traits::{BlockBody, NodePrimitives, RecoveredBlock, SignedTransaction};
+use reth_provider::{
+    AccountReader, BlockReaderIdExt, CanonStateSubscriptions, ChangeSetReader, CodeHistoryReader,
+    ProviderTx, StateProvider, StateProviderFactory, TransactionVariant,
+};
+use reth_revm::{database::StateProviderDatabase, db::CacheDB};
+use reth_rpc_api::{
+    RethApiServer, RethBlockStreamApiServer, RethEvmApiServer, RethPayloadApiServer,
+    RethSimulateApiServer, RethTxWatcherApiServer,
+};
+use reth_rpc_eth_api::{
+    helpers::{EthCall, LoadPendingBlock, LoadReceipt, LoadState, SpawnBlocking, Trace},
+    FromEthApiError, FullEthApiTypes, RpcBlock, RpcNodeCore, RpcReceipt, RpcTransaction,
+};
+use reth_rpc_eth_types::{
+    error::api::FromEvmHalt,
+    logs_utils::{self, LogStreamChunk, LogStreamResumeToken, ProviderOrBlock},
+    revm_utils::prefetch_access_list,
+    tx_simulation::balance_changes,
+    utils::recover_raw_transaction,
+    BlockEvmEnv, BlockRewards, BlockSummary, BlockWithReceipts, CodeChange, CodeChangeKind,
+    EthApiError, EthResult, RevertError, SimulatedTransactionInput, TransactionSimulation,
+};
+use reth_rpc_server_types::ToRpcResult;
+use reth_rpc_types_compat::block::from_block;
 use reth_tasks::TaskSpawner;
+use reth_transaction_pool::{PoolTransaction, TransactionPool};
+use revm::context_interface::result::ExecutionResult;
+use serde::Serialize;
 use tokio::sync::oneshot;
+use tokio_stream::wrappers::{errors::BroadcastStreamRecvError, BroadcastStream};
+use tracing::trace;
+
+use crate::eth::pubsub::SubscriptionSerializeError;
+
+/// The maximum number of blocks scanned for a single historical chunk of `reth_streamLogs`.
+const STREAM_LOGS_CHUNK_SIZE: u64 = 1_000;
+
+/// The maximum number of headers returned by a single `reth_getBlockHeaders` request.
+const MAX_BLOCK_HEADERS: u64 = 1_000;
+
+/// The maximum number of blocks returned by a single `reth_getBlockRewards` request.
+const MAX_BLOCK_REWARDS: u64 = 1_000;
+
+/// The maximum number of blocks returned by a single `reth_getBlockSummaries` request.
+const MAX_BLOCK_SUMMARIES: u64 = 10_000;
+
+/// The maximum number of blocks returned by a single `reth_getBlockSummaries` request that includes
+/// the block sizes, which requires reading the transactions.
+const MAX_SIZED_BLOCK_SUMMARIES: u64 = 1_000;
+
+/// The number of most recently streamed blocks a `reth_newBlockWithReceipts` subscription keeps
+/// track of.
+const BLOCK_STREAM_TRACKED_BLOCKS: usize = 64;
 
 /// `reth` API implementation.
 ///
 /// This type provides the functionality for handling `reth` prototype RPC requests.
-pub struct RethApi<Provider> {
-    inner: Arc<RethApiInner<Provider>>,
+pub struct RethApi<Provider, Network> {
+    inner: Arc<RethApiInner<Provider, Network>>,
 }
 
 // === impl RethApi ===
 
-impl<Provider> RethApi<Provider> {
+impl<Provider, Network> RethApi<Provider, Network> {
     /// The provider that can interact with the chain.
     pub fn provider(&self) -> &Provider {
         &self.inner.provider
     }
 
+    /// The network handle.
+    pub fn network(&self) -> &Network {
+        &self.inner.network
+    }
+
     /// Create a new instance of the [`RethApi`]
-    pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
-        let inner = Arc::new(RethApiInner { provider, task_spawner });
+    pub fn new(provider: Provider, network: Network, task_spawner: Box<dyn TaskSpawner>) -> Self {
+        let inner = Arc::new(RethApiInner { provider, network, task_spawner });
         Self { inner }
     }
 }
 
-impl<Provider> RethApi<Provider>
+impl<Provider, Network> RethApi<Provider, Network>
 where
     Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Network: Send + Sync + 'static,
 {
     /// Executes the future on a new blocking task.
     async fn on_blocking_task<C, F, R>(&self, c: C) -> EthResult<R>
@@ -70,25 +149,519 @@ where
 
         let state = self.provider().state_by_block_id(block_id)?;
         let accounts_before = self.provider().account_block_changeset(block_number)?;
-        let hash_map = accounts_before.iter().try_fold(
-            HashMap::default(),
-            |mut hash_map, account_before| -> RethResult<_> {
-                let current_balance = state.account_balance(&account_before.address)?;
-                let prev_balance = account_before.info.map(|info| info.balance);
-                if current_balance != prev_balance {
-                    hash_map.insert(account_before.address, current_balance.unwrap_or_default());
-                }
-                Ok(hash_map)
-            },
-        )?;
+        let addresses =
+            accounts_before.iter().map(|account_before| account_before.address).collect::<Vec<_>>();
+        let accounts = state.basic_accounts(&addresses)?;
+
+        let mut hash_map = HashMap::default();
+        for (account_before, account) in accounts_before.iter().zip(accounts) {
+            let current_balance = account.map(|account| account.balance);
+            let prev_balance = account_before.info.map(|info| info.balance);
+            if current_balance != prev_balance {
+                hash_map.insert(account_before.address, current_balance.unwrap_or_default());
+            }
+        }
         Ok(hash_map)
     }
+
+    /// Returns the headers of up to `count` consecutive blocks starting at `from`, reduced to the
+    /// given fields.
+    pub async fn block_headers(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+        fields: Option<Vec<String>>,
+    ) -> EthResult<Vec<serde_json::Value>> {
+        self.on_blocking_task(|this| async move { this.try_block_headers(from, count, fields) })
+            .await
+    }
+
+    fn try_block_headers(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+        fields: Option<Vec<String>>,
+    ) -> EthResult<Vec<serde_json::Value>> {
+        if count > MAX_BLOCK_HEADERS {
+            return Err(EthApiError::InvalidParams(format!(
+                "count exceeds the maximum of {MAX_BLOCK_HEADERS} headers"
+            )))
+        }
+        let Some(start) = self.provider().convert_block_number(from)? else {
+            return Err(EthApiError::HeaderNotFound(from.into()))
+        };
+
+        let headers = self.provider().sealed_headers_range(start..start.saturating_add(count))?;
+        headers
+            .into_iter()
+            .map(|header| {
+                let header = alloy_rpc_types_eth::Header::from_consensus(header.into(), None, None);
+                let mut value = serde_json::to_value(header)
+                    .map_err(|err| EthApiError::Internal(RethError::msg(err.to_string())))?;
+                if let (Some(fields), Some(object)) = (&fields, value.as_object_mut()) {
+                    object.retain(|key, _| fields.contains(key));
+                }
+                Ok(value)
+            })
+            .collect()
+    }
+
+    /// Returns the summaries of up to `count` consecutive blocks starting at `from`.
+    pub async fn block_summaries(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+        include_size: bool,
+    ) -> EthResult<Vec<BlockSummary>> {
+        self.on_blocking_task(
+            |this| async move { this.try_block_summaries(from, count, include_size) },
+        )
+        .await
+    }
+
+    fn try_block_summaries(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+        include_size: bool,
+    ) -> EthResult<Vec<BlockSummary>> {
+        let max_count = if include_size { MAX_SIZED_BLOCK_SUMMARIES } else { MAX_BLOCK_SUMMARIES };
+        if count > max_count {
+            return Err(EthApiError::InvalidParams(format!(
+                "count exceeds the maximum of {max_count} blocks"
+            )))
+        }
+        let Some(start) = self.provider().convert_block_number(from)? else {
+            return Err(EthApiError::HeaderNotFound(from.into()))
+        };
+
+        let headers = self.provider().sealed_headers_range(start..start.saturating_add(count))?;
+        let Some(end) = headers.last().map(|header| header.number()) else { return Ok(Vec::new()) };
+
+        // the transaction counts are part of the body indices, so the transactions themselves
+        // only need to be read for the block sizes
+        let body_indices = self.provider().block_body_indices_range(start..=end)?;
+        let sizes = if include_size {
+            let blocks = self.provider().block_range(start..=end)?;
+            Some(blocks.iter().map(|block| block.length() as u64).collect::<Vec<_>>())
+        } else {
+            None
+        };
+
+        headers
+            .into_iter()
+            .enumerate()
+            .map(|(index, header)| {
+                let number = header.number();
+                let transaction_count = body_indices
+                    .get(index)
+                    .ok_or(EthApiError::HeaderNotFound(number.into()))?
+                    .tx_count;
+                let size = match &sizes {
+                    Some(sizes) => {
+                        Some(*sizes.get(index).ok_or(EthApiError::HeaderNotFound(number.into()))?)
+                    }
+                    None => None,
+                };
+                Ok(BlockSummary {
+                    number,
+                    hash: header.hash(),
+                    timestamp: header.timestamp(),
+                    miner: header.beneficiary(),
+                    transaction_count,
+                    gas_used: header.gas_used(),
+                    gas_limit: header.gas_limit(),
+                    base_fee_per_gas: header.base_fee_per_gas(),
+                    blob_gas_used: header.blob_gas_used(),
+                    excess_blob_gas: header.excess_blob_gas(),
+                    size,
+                })
+            })
+            .collect()
+    }
+}
+
+impl<Provider, Network> RethApi<Provider, Network>
+where
+    Provider:
+        BlockReaderIdExt + ChangeSetReader + CodeHistoryReader + StateProviderFactory + 'static,
+    Network: Send + Sync + 'static,
+{
+    /// Returns all recorded code changes of the account, oldest first.
+    pub async fn code_history(&self, address: Address) -> EthResult<Vec<CodeChange>> {
+        self.on_blocking_task(|this| async move { this.try_code_history(address) }).await
+    }
+
+    fn try_code_history(&self, address: Address) -> EthResult<Vec<CodeChange>> {
+        let changes = self.provider().account_code_changes(address)?;
+        let state = self.provider().latest()?;
+        let current = state.basic_account(&address)?.and_then(|account| account.bytecode_hash);
+
+        // the code hash after a change is the code hash before the next change
+        let after = changes.iter().skip(1).map(|(_, hash)| *hash).chain(std::iter::once(current));
+        let is_delegation = |hash: Option<B256>| -> EthResult<bool> {
+            let Some(hash) = hash else { return Ok(false) };
+            Ok(state.bytecode_by_hash(&hash)?.is_some_and(|code| code.is_eip7702()))
+        };
+
+        changes
+            .iter()
+            .zip(after)
+            .map(|(&(block_number, previous_code_hash), code_hash)| {
+                let kind = if is_delegation(code_hash)? {
+                    CodeChangeKind::Delegated
+                } else if code_hash.is_none() {
+                    if is_delegation(previous_code_hash)? {
+                        CodeChangeKind::DelegationCleared
+                    } else {
+                        CodeChangeKind::Destroyed
+                    }
+                } else if previous_code_hash.is_none() {
+                    CodeChangeKind::Deployed
+                } else {
+                    CodeChangeKind::Redeployed
+                };
+                Ok(CodeChange { block_number, kind, previous_code_hash, code_hash })
+            })
+            .collect()
+    }
+}
+
+impl<Provider, Network> RethApi<Provider, Network>
+where
+    Provider: BlockReaderIdExt
+        + ChangeSetReader
+        + StateProviderFactory
+        + ChainSpecProvider<ChainSpec: EthereumHardforks>
+        + 'static,
+    Network: Send + Sync + 'static,
+{
+    /// Returns the fees and rewards of up to `count` consecutive blocks starting at `from`.
+    pub async fn block_rewards(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+    ) -> EthResult<Vec<BlockRewards>> {
+        self.on_blocking_task(|this| async move { this.try_block_rewards(from, count) }).await
+    }
+
+    fn try_block_rewards(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+    ) -> EthResult<Vec<BlockRewards>> {
+        if count > MAX_BLOCK_REWARDS {
+            return Err(EthApiError::InvalidParams(format!(
+                "count exceeds the maximum of {MAX_BLOCK_REWARDS} blocks"
+            )))
+        }
+        let Some(start) = self.provider().convert_block_number(from)? else {
+            return Err(EthApiError::HeaderNotFound(from.into()))
+        };
+
+        let chain_spec = self.provider().chain_spec();
+        let headers = self.provider().sealed_headers_range(start..start.saturating_add(count))?;
+        headers
+            .into_iter()
+            .map(|header| {
+                let number = header.number();
+                let base_fee = header.base_fee_per_gas().unwrap_or_default();
+
+                let mut priority_fees = U256::ZERO;
+                if header.gas_used() > 0 {
+                    let transactions = self
+                        .provider()
+                        .transactions_by_block(number.into())?
+                        .ok_or(EthApiError::HeaderNotFound(number.into()))?;
+                    let receipts = self
+                        .provider()
+                        .receipts_by_block(number.into())?
+                        .ok_or(EthApiError::ReceiptsNotFound(number.into()))?;
+                    priority_fees = block_priority_fees(&transactions, &receipts, base_fee);
+                }
+
+                // the blob fee is paid for the blob gas of the block at the blob base fee
+                let blob_fee = chain_spec
+                    .blob_params_at_timestamp(header.timestamp())
+                    .and_then(|blob_params| header.blob_fee(blob_params))
+                    .unwrap_or_default();
+                let blob_gas_used = header.blob_gas_used().unwrap_or_default();
+
+                let (block_reward, ommer_rewards) = if chain_spec.is_paris_active_at_block(number) {
+                    (0, 0)
+                } else {
+                    let base_block_reward = base_block_reward_pre_merge(&chain_spec, number);
+                    let ommers = self.provider().ommers(number.into())?.unwrap_or_default();
+                    (
+                        block_reward(base_block_reward, ommers.len()),
+                        ommers
+                            .iter()
+                            .map(|ommer| ommer_reward(base_block_reward, number, ommer.number()))
+                            .sum(),
+                    )
+                };
+
+                Ok(BlockRewards {
+                    number,
+                    hash: header.hash(),
+                    beneficiary: header.beneficiary(),
+                    base_fee_burned: U256::from(base_fee) * U256::from(header.gas_used()),
+                    blob_fee_burned: U256::from(blob_fee) * U256::from(blob_gas_used),
+                    priority_fees,
+                    block_reward: U256::from(block_reward),
+                    ommer_rewards: U256::from(ommer_rewards),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Returns the priority fees of the transactions of a block, which are paid to its fee recipient.
+///
+/// The priority fee of a transaction is its effective tip times the gas it used, which is derived
+/// from the cumulative gas used of the receipts.
+fn block_priority_fees<T: Transaction, R: TxReceipt>(
+    transactions: &[T],
+    receipts: &[R],
+    base_fee: u64,
+) -> U256 {
+    let mut priority_fees = U256::ZERO;
+    let mut cumulative_gas_used = 0;
+    for (tx, receipt) in transactions.iter().zip(receipts) {
+        let gas_used = receipt.cumulative_gas_used() - cumulative_gas_used;
+        cumulative_gas_used = receipt.cumulative_gas_used();
+        let tip = tx.effective_tip_per_gas(base_fee).unwrap_or_default();
+        priority_fees += U256::from(tip) * U256::from(gas_used);
+    }
+    priority_fees
+}
+
+impl<Provider, Network> RethApi<Provider, Network>
+where
+    Provider: BlockReaderIdExt
+        + ChangeSetReader
+        + StateProviderFactory
+        + CanonStateSubscriptions
+        + 'static,
+    Network: Send + Sync + 'static,
+{
+    /// Streams all logs matching the filter to the sink: first the historical logs in chunks of
+    /// at most [`STREAM_LOGS_CHUNK_SIZE`] blocks, then the logs of every newly canonical block.
+    ///
+    /// The stream ends once the filter's `toBlock` has been delivered, if set.
+    async fn stream_logs(
+        &self,
+        sink: SubscriptionSink,
+        filter: Filter,
+        resume_token: Option<LogStreamResumeToken>,
+    ) -> EthResult<()> {
+        let (from_block, to_block) = match filter.block_option {
+            FilterBlockOption::Range { from_block, to_block } => (from_block, to_block),
+            FilterBlockOption::AtBlockHash(_) => {
+                return Err(EthApiError::InvalidParams(
+                    "blockHash filters can't be streamed".to_string(),
+                ))
+            }
+        };
+
+        // subscribe before the backfill so that blocks committed in the meantime are not missed
+        let mut canon_state = BroadcastStream::new(self.provider().subscribe_to_canonical_state());
+
+        let best_number = self.provider().best_block_number()?;
+        let to_block =
+            to_block.map(|num| self.provider().convert_block_number(num)).transpose()?.flatten();
+        let mut next_block = match resume_token {
+            Some(token) => {
+                // if the block of the token was reorged out, redeliver the logs at that height
+                if self.provider().block_hash(token.block_number)? == Some(token.block_hash) {
+                    token.block_number + 1
+                } else {
+                    token.block_number
+                }
+            }
+            None => from_block
+                .map(|num| self.provider().convert_block_number(num))
+                .transpose()?
+                .flatten()
+                .unwrap_or(best_number),
+        };
+        let filter = Arc::new(FilteredParams::new(Some(filter)));
+
+        // backfill all historical blocks up to the current tip
+        let backfill_end = to_block.map_or(best_number, |to| to.min(best_number));
+        if let Some(last) =
+            self.backfill_logs(&sink, filter.clone(), next_block, backfill_end).await?
+        {
+            next_block = last.number + 1;
+        }
+        if to_block.is_some_and(|to| next_block > to) {
+            return Ok(())
+        }
+
+        // follow the canonical chain
+        loop {
+            let notification = tokio::select! {
+                _ = sink.closed() => return Ok(()),
+                notification = canon_state.next() => notification,
+            };
+            let notification = match notification {
+                Some(Ok(notification)) => notification,
+                Some(Err(BroadcastStreamRecvError::Lagged(skipped))) => {
+                    trace!(target: "rpc::reth", skipped, "log stream lagged, backfilling");
+                    let best_number = self.provider().best_block_number()?;
+                    let end = to_block.map_or(best_number, |to| to.min(best_number));
+                    if let Some(last) =
+                        self.backfill_logs(&sink, filter.clone(), next_block, end).await?
+                    {
+                        next_block = last.number + 1;
+                    }
+                    if to_block.is_some_and(|to| next_block > to) {
+                        return Ok(())
+                    }
+                    continue
+                }
+                None => return Ok(()),
+            };
+
+            let mut logs = Vec::new();
+            let mut last = None;
+            for (block_receipts, removed) in notification.block_receipts() {
+                let number = block_receipts.block.number;
+                if removed {
+                    // only blocks that were already delivered need to be retracted
+                    if number >= next_block {
+                        continue
+                    }
+                    next_block = next_block.min(number);
+                } else {
+                    if number < next_block || to_block.is_some_and(|to| number > to) {
+                        continue
+                    }
+                    next_block = number + 1;
+                    last = Some(block_receipts.block);
+                }
+                logs.extend(logs_utils::matching_block_logs_with_tx_hashes(
+                    &filter,
+                    block_receipts.block,
+                    block_receipts.tx_receipts.iter().map(|(tx, receipt)| (*tx, receipt)),
+                    removed,
+                ));
+            }
+
+            let Some(last) = last else { continue };
+            let chunk =
+                LogStreamChunk { logs, live: true, resume_token: LogStreamResumeToken::new(last) };
+            if !send_json(&sink, &chunk).await? {
+                return Ok(())
+            }
+            if to_block.is_some_and(|to| next_block > to) {
+                return Ok(())
+            }
+        }
+    }
+
+    /// Sends the matching logs of the inclusive block range to the sink, in chunks of at most
+    /// [`STREAM_LOGS_CHUNK_SIZE`] blocks.
+    ///
+    /// Returns the last block that was delivered, if any.
+    async fn backfill_logs(
+        &self,
+        sink: &SubscriptionSink,
+        filter: Arc<FilteredParams>,
+        from_block: u64,
+        to_block: u64,
+    ) -> EthResult<Option<BlockNumHash>> {
+        let mut last = None;
+        let mut chunk_start = from_block;
+        while chunk_start <= to_block {
+            let chunk_end = chunk_start.saturating_add(STREAM_LOGS_CHUNK_SIZE - 1).min(to_block);
+            let filter = filter.clone();
+            let chunk = self
+                .on_blocking_task(|this| async move {
+                    this.try_logs_chunk(&filter, chunk_start, chunk_end)
+                })
+                .await?;
+            let Some(chunk) = chunk else { break };
+            last = Some(chunk.resume_token.block());
+            if !send_json(sink, &chunk).await? {
+                break
+            }
+            chunk_start = chunk_end + 1;
+        }
+        Ok(last)
+    }
+
+    /// Collects the matching logs of the inclusive block range.
+    ///
+    /// Returns `None` if the range is empty.
+    fn try_logs_chunk(
+        &self,
+        filter: &FilteredParams,
+        from_block: u64,
+        to_block: u64,
+    ) -> EthResult<Option<LogStreamChunk>> {
+        let headers = self.provider().sealed_headers_range(from_block..=to_block)?;
+        let Some(last) = headers.last().map(|header| header.num_hash()) else { return Ok(None) };
+
+        let (address_filter, topics_filter) =
+            filter.filter.as_ref().map_or_else(Default::default, |filter| {
+                (
+                    FilteredParams::address_filter(&filter.address),
+                    FilteredParams::topics_filter(&filter.topics),
+                )
+            });
+
+        let mut logs = Vec::new();
+        for header in &headers {
+            if !FilteredParams::matches_address(header.logs_bloom(), &address_filter) ||
+                !FilteredParams::matches_topics(header.logs_bloom(), &topics_filter)
+            {
+                continue
+            }
+            let receipts = self
+                .provider()
+                .receipts_by_block(header.number().into())?
+                .ok_or_else(|| EthApiError::ReceiptsNotFound(header.number().into()))?;
+            logs_utils::append_matching_block_logs(
+                &mut logs,
+                ProviderOrBlock::Provider(self.provider()),
+                filter,
+                header.num_hash(),
+                &receipts,
+                false,
+                header.timestamp(),
+            )?;
+        }
+
+        Ok(Some(LogStreamChunk {
+            logs,
+            live: false,
+            resume_token: LogStreamResumeToken::new(last),
+        }))
+    }
+}
+
+/// Sends the item to the subscription sink.
+///
+/// Returns `false` if the subscription was closed.
+async fn send_json<T: Serialize>(sink: &SubscriptionSink, item: &T) -> EthResult<bool> {
+    let msg = SubscriptionMessage::from_json(item).map_err(|err| {
+        EthApiError::Internal(RethError::other(SubscriptionSerializeError::from(err)))
+    })?;
+    Ok(sink.send(msg).await.is_ok())
 }
 
 #[async_trait]
-impl<Provider> RethApiServer for RethApi<Provider>
+impl<Provider, Network> RethApiServer for RethApi<Provider, Network>
 where
-    Provider: BlockReaderIdExt + ChangeSetReader + StateProviderFactory + 'static,
+    Provider: BlockReaderIdExt
+        + ChangeSetReader
+        + CodeHistoryReader
+        + StateProviderFactory
+        + CanonStateSubscriptions
+        + ChainSpecProvider<ChainSpec: EthereumHardforks>
+        + 'static,
+    Network: NetworkInfo + 'static,
 {
     /// Handler for `reth_getBalanceChangesInBlock`
     async fn reth_get_balance_changes_in_block(
@@ -97,23 +670,934 @@ where
     ) -> RpcResult<HashMap<Address, U256>> {
         Ok(Self::balance_changes_in_block(self, block_id).await?)
     }
+
+    /// Handler for `reth_getTransactionPropagation`
+    async fn reth_get_transaction_propagation(
+        &self,
+        hash: TxHash,
+    ) -> RpcResult<Option<TransactionPropagation>> {
+        self.network().transaction_propagation(hash).await.to_rpc_result()
+    }
+
+    /// Handler for `reth_getBlockHeaders`
+    async fn reth_get_block_headers(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+        fields: Option<Vec<String>>,
+    ) -> RpcResult<Vec<serde_json::Value>> {
+        Ok(Self::block_headers(self, from, count, fields).await?)
+    }
+
+    /// Handler for `reth_getBlockRewards`
+    async fn reth_get_block_rewards(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+    ) -> RpcResult<Vec<BlockRewards>> {
+        Ok(Self::block_rewards(self, from, count).await?)
+    }
+
+    /// Handler for `reth_getBlockSummaries`
+    async fn reth_get_block_summaries(
+        &self,
+        from: BlockNumberOrTag,
+        count: u64,
+        include_size: Option<bool>,
+    ) -> RpcResult<Vec<BlockSummary>> {
+        Ok(Self::block_summaries(self, from, count, include_size.unwrap_or_default()).await?)
+    }
+
+    /// Handler for `reth_getCodeHistory`
+    async fn reth_get_code_history(&self, address: Address) -> RpcResult<Vec<CodeChange>> {
+        Ok(Self::code_history(self, address).await?)
+    }
+
+    /// Handler for `reth_streamLogs`
+    async fn reth_stream_logs(
+        &self,
+        pending: PendingSubscriptionSink,
+        filter: Filter,
+        resume_token: Option<LogStreamResumeToken>,
+    ) -> SubscriptionResult {
+        let sink = pending.accept().await?;
+        let this = self.clone();
+        self.inner.task_spawner.spawn(Box::pin(async move {
+            if let Err(err) = this.stream_logs(sink, filter, resume_token).await {
+                trace!(target: "rpc::reth", %err, "log stream terminated");
+            }
+        }));
+        Ok(())
+    }
 }
 
-impl<Provider> std::fmt::Debug for RethApi<Provider> {
+impl<Provider, Network> std::fmt::Debug for RethApi<Provider, Network> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RethApi").finish_non_exhaustive()
     }
 }
 
-impl<Provider> Clone for RethApi<Provider> {
+impl<Provider, Network> Clone for RethApi<Provider, Network> {
     fn clone(&self) -> Self {
         Self { inner: Arc::clone(&self.inner) }
     }
 }
 
-struct RethApiInner<Provider> {
+struct RethApiInner<Provider, Network> {
     /// The provider that can interact with the chain.
     provider: Provider,
+    /// The network handle.
+    network: Network,
     /// The type that can spawn tasks which would otherwise block.
     task_spawner: Box<dyn TaskSpawner>,
 }
+
+/// `reth_buildPayload` implementation.
+///
+/// Builds payloads with the payload builder of the node outside of a payload job, so the result
+/// matches what the node would build for the given attributes right now without affecting
+/// consensus.
+#[derive(Debug, Clone)]
+pub struct RethPayloadApi<Eth, Builder> {
+    /// The `eth` API used to look up the parent block and convert the payload.
+    eth_api: Eth,
+    /// The payload builder of the node.
+    payload_builder: Builder,
+}
+
+impl<Eth, Builder> RethPayloadApi<Eth, Builder> {
+    /// Create a new instance of the [`RethPayloadApi`]
+    pub const fn new(eth_api: Eth, payload_builder: Builder) -> Self {
+        Self { eth_api, payload_builder }
+    }
+
+    /// Returns the `eth` API used to look up the parent block and convert the payload.
+    pub const fn eth_api(&self) -> &Eth {
+        &self.eth_api
+    }
+}
+
+impl<Eth, Pool, Client, EvmConfig>
+    RethPayloadApi<Eth, EthereumPayloadBuilder<Pool, Client, EvmConfig>>
+where
+    Eth: SpawnBlocking
+        + FullEthApiTypes
+        + RpcNodeCore<Provider: BlockReaderIdExt<Header = Header, Transaction = TransactionSigned>>,
+    EvmConfig: ConfigureEvm<Primitives = EthPrimitives, NextBlockEnvCtx = NextBlockEnvAttributes>
+        + 'static,
+    Client: StateProviderFactory + ChainSpecProvider<ChainSpec = ChainSpec> + Clone + 'static,
+    Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>> + 'static,
+{
+    /// Builds a payload on top of the latest block with the given attributes and transactions
+    /// from the pool, without inserting it.
+    pub async fn build_payload(
+        &self,
+        attributes: PayloadAttributes,
+    ) -> Result<SimulatedPayload<Block<RpcTransaction<Eth::NetworkTypes>>>, Eth::Error> {
+        let payload_builder = self.payload_builder.clone();
+        self.eth_api
+            .spawn_blocking_io(move |this| {
+                let parent = this
+                    .provider()
+                    .latest_header()
+                    .map_err(Eth::Error::from_eth_err)?
+                    .ok_or(EthApiError::HeaderNotFound(BlockNumberOrTag::Latest.into()))?;
+
+                let (payload, transactions) = payload_builder
+                    .simulate_payload(Arc::new(parent), attributes)
+                    .map_err(RethError::other)
+                    .map_err(Eth::Error::from_eth_err)?;
+
+                let block = payload
+                    .block()
+                    .clone()
+                    .try_recover()
+                    .map_err(|_| EthApiError::InvalidTransactionSignature)?;
+                let block = from_block(block, BlockTransactionsKind::Full, this.tx_resp_builder())?;
+
+                Ok(SimulatedPayload { block, transactions, total_fees: payload.fees() })
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl<Eth, Pool, Client, EvmConfig> RethPayloadApiServer<Block<RpcTransaction<Eth::NetworkTypes>>>
+    for RethPayloadApi<Eth, EthereumPayloadBuilder<Pool, Client, EvmConfig>>
+where
+    Eth: SpawnBlocking
+        + FullEthApiTypes
+        + RpcNodeCore<Provider: BlockReaderIdExt<Header = Header, Transaction = TransactionSigned>>
+        + 'static,
+    EvmConfig: ConfigureEvm<Primitives = EthPrimitives, NextBlockEnvCtx = NextBlockEnvAttributes>
+        + 'static,
+    Client: StateProviderFactory + ChainSpecProvider<ChainSpec = ChainSpec> + Clone + 'static,
+    Pool: TransactionPool<Transaction: PoolTransaction<Consensus = TransactionSigned>> + 'static,
+{
+    /// Handler for `reth_buildPayload`
+    async fn reth_build_payload(
+        &self,
+        attributes: PayloadAttributes,
+    ) -> RpcResult<SimulatedPayload<Block<RpcTransaction<Eth::NetworkTypes>>>> {
+        trace!(target: "rpc::reth", ?attributes, "Serving reth_buildPayload");
+        self.build_payload(attributes).await.map_err(Into::into)
+    }
+}
+
+/// `reth_getEvmEnv` implementation.
+///
+/// Configures the environment with the [`LoadState`] helpers of the `eth` API, so it matches the
+/// environment the node executes blocks and calls with.
+#[derive(Debug, Clone)]
+pub struct RethEvmApi<Eth> {
+    /// The `eth` API used to configure the environment.
+    eth_api: Eth,
+}
+
+impl<Eth> RethEvmApi<Eth> {
+    /// Create a new instance of the [`RethEvmApi`]
+    pub const fn new(eth_api: Eth) -> Self {
+        Self { eth_api }
+    }
+
+    /// Returns the `eth` API used to configure the environment.
+    pub const fn eth_api(&self) -> &Eth {
+        &self.eth_api
+    }
+}
+
+impl<Eth> RethEvmApi<Eth>
+where
+    Eth: LoadState + LoadPendingBlock + SpawnBlocking,
+{
+    /// Returns the EVM environment the transactions of the block are executed with.
+    pub async fn evm_env(&self, block_id: BlockId) -> Result<BlockEvmEnv, Eth::Error> {
+        let (evm_env, block_id) = self.eth_api.evm_env_at(block_id).await?;
+        Ok(BlockEvmEnv::new(block_id, &evm_env))
+    }
+}
+
+#[async_trait]
+impl<Eth> RethEvmApiServer for RethEvmApi<Eth>
+where
+    Eth: LoadState + LoadPendingBlock + SpawnBlocking + 'static,
+{
+    /// Handler for `reth_getEvmEnv`
+    async fn reth_get_evm_env(&self, block_id: BlockId) -> RpcResult<BlockEvmEnv> {
+        trace!(target: "rpc::reth", ?block_id, "Serving reth_getEvmEnv");
+        self.evm_env(block_id).await.map_err(Into::into)
+    }
+}
+
+/// `reth_simulateTransaction` implementation.
+///
+/// Executes transactions with the [`EthCall`] helpers of the `eth` API, so the results match
+/// `eth_call` and `eth_createAccessList`.
+#[derive(Debug, Clone)]
+pub struct RethSimulateApi<Eth> {
+    /// The `eth` API used to execute the transactions.
+    eth_api: Eth,
+}
+
+impl<Eth> RethSimulateApi<Eth> {
+    /// Create a new instance of the [`RethSimulateApi`]
+    pub const fn new(eth_api: Eth) -> Self {
+        Self { eth_api }
+    }
+
+    /// Returns the `eth` API used to execute the transactions.
+    pub const fn eth_api(&self) -> &Eth {
+        &self.eth_api
+    }
+}
+
+impl<Eth> RethSimulateApi<Eth>
+where
+    Eth: EthCall + Trace + LoadPendingBlock,
+{
+    /// Executes the transaction on top of the block without committing it, and returns its
+    /// outcome, generated access list and balance changes.
+    pub async fn simulate_transaction(
+        &self,
+        transaction: SimulatedTransactionInput,
+        block_id: Option<BlockId>,
+    ) -> Result<TransactionSimulation, Eth::Error> {
+        let request = match transaction {
+            SimulatedTransactionInput::Raw(tx) => {
+                let tx = recover_raw_transaction::<ProviderTx<Eth::Provider>>(&tx)
+                    .map_err(Eth::Error::from_eth_err)?;
+                TransactionRequest::from_recovered_transaction(tx)
+            }
+            SimulatedTransactionInput::Request(request) => *request,
+        };
+        // the block is resolved once, so that the access list and the outcome are both of the
+        // same state even if a new block arrives in between
+        let (evm_env, at) = self.eth_api.evm_env_at(block_id.unwrap_or_default()).await?;
+
+        self.eth_api
+            .spawn_blocking_io(move |this| {
+                let access_list =
+                    this.create_access_list_with(evm_env.clone(), at, request.clone())?;
+
+                let state = this.state_at_block_id(at)?;
+                let mut db = CacheDB::new(StateProviderDatabase::new(state));
+                if let Some(access_list) = &request.access_list {
+                    prefetch_access_list(access_list, &mut db)?;
+                }
+                let (evm_env, tx_env) =
+                    this.prepare_call_env(evm_env, request, &mut db, EvmOverrides::default())?;
+                let base_fee = evm_env.block_env.basefee;
+                let (res, (_, tx_env)) = this.transact(&mut db, evm_env, tx_env)?;
+
+                // the state is not committed, so the database still returns the balances before
+                // the transaction
+                let balance_changes =
+                    balance_changes(&res.state, &db).map_err(Eth::Error::from_eth_err)?;
+
+                let gas_used = res.result.gas_used();
+                let (error, output, logs) = match res.result {
+                    ExecutionResult::Success { output, logs, .. } => {
+                        (None, output.into_data(), logs)
+                    }
+                    ExecutionResult::Revert { output, .. } => {
+                        (Some(RevertError::new(output.clone()).to_string()), output, Vec::new())
+                    }
+                    ExecutionResult::Halt { reason, .. } => {
+                        let error = Eth::Error::from_evm_halt(reason, tx_env.gas_limit());
+                        (Some(error.to_string()), Bytes::new(), Vec::new())
+                    }
+                };
+
+                Ok(TransactionSimulation {
+                    status: error.is_none(),
+                    gas_used,
+                    error,
+                    output,
+                    logs,
+                    access_list: access_list.access_list,
+                    access_list_gas_used: access_list.gas_used.saturating_to(),
+                    fee: U256::from(tx_env.effective_gas_price(base_fee as u128)) *
+                        U256::from(gas_used),
+                    balance_changes,
+                })
+            })
+            .await
+    }
+}
+
+#[async_trait]
+impl<Eth> RethSimulateApiServer for RethSimulateApi<Eth>
+where
+    Eth: EthCall + Trace + LoadPendingBlock + 'static,
+{
+    /// Handler for `reth_simulateTransaction`
+    async fn reth_simulate_transaction(
+        &self,
+        transaction: SimulatedTransactionInput,
+        block_id: Option<BlockId>,
+    ) -> RpcResult<TransactionSimulation> {
+        trace!(target: "rpc::reth", ?block_id, "Serving reth_simulateTransaction");
+        self.simulate_transaction(transaction, block_id).await.map_err(Into::into)
+    }
+}
+
+/// `reth_newBlockWithReceipts` implementation.
+///
+/// Converts blocks and receipts with the helpers of the `eth` API, so the streamed objects match
+/// `eth_getBlockByHash` and `eth_getBlockReceipts`.
+#[derive(Clone)]
+pub struct RethBlockStreamApi<Eth> {
+    /// The `eth` API used to convert the blocks and receipts.
+    eth_api: Eth,
+    /// The type that can spawn the subscription tasks.
+    task_spawner: Box<dyn TaskSpawner>,
+}
+
+impl<Eth> RethBlockStreamApi<Eth> {
+    /// Create a new instance of the [`RethBlockStreamApi`]
+    pub fn new(eth_api: Eth, task_spawner: Box<dyn TaskSpawner>) -> Self {
+        Self { eth_api, task_spawner }
+    }
+
+    /// Returns the `eth` API used to convert the blocks and receipts.
+    pub const fn eth_api(&self) -> &Eth {
+        &self.eth_api
+    }
+}
+
+impl<N, Eth> RethBlockStreamApi<Eth>
+where
+    N: NodePrimitives,
+    Eth: LoadReceipt
+        + FullEthApiTypes
+        + RpcNodeCore<
+            Provider: BlockReaderIdExt<
+                Block = N::Block,
+                Receipt = N::Receipt,
+                Transaction = N::SignedTx,
+            > + CanonStateSubscriptions<Primitives = N>,
+        >,
+{
+    /// Sends every newly canonical block together with its receipts and senders to the sink, and
+    /// the streamed blocks that are reorged out with `removed` set.
+    ///
+    /// If the subscription lags behind the canonical state notifications, the skipped blocks are
+    /// read from the provider instead.
+    async fn new_blocks_with_receipts(&self, sink: SubscriptionSink) -> Result<(), Eth::Error> {
+        let provider = self.eth_api.provider();
+        let mut canon_state = BroadcastStream::new(provider.subscribe_to_canonical_state());
+        // blocks above the current head are streamed even if the subscription lags before the
+        // first notification
+        let best_number = provider.best_block_number().map_err(Eth::Error::from_eth_err)?;
+        let mut streamed = StreamedBlocks::new(best_number + 1);
+
+        loop {
+            let notification = tokio::select! {
+                _ = sink.closed() => return Ok(()),
+                notification = canon_state.next() => notification,
+            };
+            let notification = match notification {
+                Some(Ok(notification)) => notification,
+                Some(Err(BroadcastStreamRecvError::Lagged(skipped))) => {
+                    trace!(target: "rpc::reth", skipped, "block stream lagged, backfilling");
+                    if !self.backfill_blocks(&sink, &mut streamed).await? {
+                        return Ok(())
+                    }
+                    continue
+                }
+                None => return Ok(()),
+            };
+
+            if let Some(reverted) = notification.reverted() {
+                let reverted = reverted.blocks_and_receipts().collect::<Vec<_>>();
+                for (block, receipts) in reverted.into_iter().rev() {
+                    if streamed.remove(BlockNumHash::new(block.header().number(), block.hash())) {
+                        let item = self.block_with_receipts(block, receipts, true).await?;
+                        if !send_json(&sink, &item).await.map_err(Eth::Error::from_eth_err)? {
+                            return Ok(())
+                        }
+                    }
+                }
+            }
+
+            for (block, receipts) in notification.committed().blocks_and_receipts() {
+                if streamed.insert(BlockNumHash::new(block.header().number(), block.hash())) {
+                    let item = self.block_with_receipts(block, receipts, false).await?;
+                    if !send_json(&sink, &item).await.map_err(Eth::Error::from_eth_err)? {
+                        return Ok(())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Sends the canonical blocks that were skipped because the subscription lagged behind the
+    /// canonical state notifications.
+    ///
+    /// Fails if streamed blocks were reorged out in the meantime, because their removal can't be
+    /// signaled once they are gone. Returns `false` if the subscription was closed.
+    async fn backfill_blocks(
+        &self,
+        sink: &SubscriptionSink,
+        streamed: &mut StreamedBlocks,
+    ) -> Result<bool, Eth::Error> {
+        let provider = self.eth_api.provider();
+        for block in streamed.iter() {
+            if provider.block_hash(block.number).map_err(Eth::Error::from_eth_err)? !=
+                Some(block.hash)
+            {
+                return Err(Eth::Error::from_eth_err(EthApiError::Internal(RethError::msg(
+                    "block stream lagged behind a reorg of streamed blocks",
+                ))))
+            }
+        }
+
+        let best_number = provider.best_block_number().map_err(Eth::Error::from_eth_err)?;
+        for number in streamed.next_block()..=best_number {
+            let Some(block) = provider
+                .recovered_block(number.into(), TransactionVariant::WithHash)
+                .map_err(Eth::Error::from_eth_err)?
+            else {
+                break
+            };
+            let receipts = provider
+                .receipts_by_block(number.into())
+                .map_err(Eth::Error::from_eth_err)?
+                .ok_or(EthApiError::ReceiptsNotFound(number.into()))
+                .map_err(Eth::Error::from_eth_err)?;
+            if streamed.insert(BlockNumHash::new(number, block.hash())) {
+                let item = self.block_with_receipts(&block, &receipts, false).await?;
+                if !send_json(sink, &item).await.map_err(Eth::Error::from_eth_err)? {
+                    return Ok(false)
+                }
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Converts the block and its receipts to their RPC objects.
+    #[expect(clippy::type_complexity)]
+    async fn block_with_receipts(
+        &self,
+        block: &RecoveredBlock<N::Block>,
+        receipts: &[N::Receipt],
+        removed: bool,
+    ) -> Result<
+        BlockWithReceipts<RpcBlock<Eth::NetworkTypes>, RpcReceipt<Eth::NetworkTypes>>,
+        Eth::Error,
+    > {
+        let header = block.header();
+        let mut rpc_receipts = Vec::with_capacity(receipts.len());
+        for (index, (tx, receipt)) in block.body().transactions().iter().zip(receipts).enumerate() {
+            let meta = TransactionMeta {
+                tx_hash: *tx.tx_hash(),
+                index: index as u64,
+                block_hash: block.hash(),
+                block_number: header.number(),
+                base_fee: header.base_fee_per_gas(),
+                excess_blob_gas: header.excess_blob_gas(),
+                timestamp: header.timestamp(),
+            };
+            rpc_receipts.push(
+                self.eth_api
+                    .build_transaction_receipt_with_block(tx, meta, receipt, block, receipts)
+                    .await?,
+            );
+        }
+        let senders = block.senders().to_vec();
+        let block =
+            from_block(block.clone(), BlockTransactionsKind::Full, self.eth_api.tx_resp_builder())?;
+
+        Ok(BlockWithReceipts { block, receipts: rpc_receipts, senders, removed })
+    }
+}
+
+#[async_trait]
+impl<N, Eth> RethBlockStreamApiServer<RpcBlock<Eth::NetworkTypes>, RpcReceipt<Eth::NetworkTypes>>
+    for RethBlockStreamApi<Eth>
+where
+    N: NodePrimitives,
+    Eth: LoadReceipt
+        + FullEthApiTypes
+        + RpcNodeCore<
+            Provider: BlockReaderIdExt<
+                Block = N::Block,
+                Receipt = N::Receipt,
+                Transaction = N::SignedTx,
+            > + CanonStateSubscriptions<Primitives = N>,
+        > + Clone
+        + 'static,
+{
+    /// Handler for `reth_newBlockWithReceipts`
+    async fn reth_new_block_with_receipts(
+        &self,
+        pending: PendingSubscriptionSink,
+    ) -> SubscriptionResult {
+        let sink = pending.accept().await?;
+        let this = self.clone();
+        self.task_spawner.spawn(Box::pin(async move {
+            if let Err(err) = this.new_blocks_with_receipts(sink).await {
+                trace!(target: "rpc::reth", %err, "block stream terminated");
+            }
+        }));
+        Ok(())
+    }
+}
+
+impl<Eth> std::fmt::Debug for RethBlockStreamApi<Eth> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RethBlockStreamApi").finish_non_exhaustive()
+    }
+}
+
+/// The blocks a `reth_newBlockWithReceipts` subscription streamed most recently, to skip blocks
+/// that were streamed already and to signal the removal of streamed blocks on reorgs.
+#[derive(Debug)]
+struct StreamedBlocks {
+    /// The hashes of the at most [`BLOCK_STREAM_TRACKED_BLOCKS`] most recently streamed blocks,
+    /// by number.
+    blocks: BTreeMap<BlockNumber, B256>,
+    /// The number of the next block to stream.
+    next_block: BlockNumber,
+}
+
+impl StreamedBlocks {
+    /// Creates an empty set that streams blocks from the given number.
+    const fn new(next_block: BlockNumber) -> Self {
+        Self { blocks: BTreeMap::new(), next_block }
+    }
+
+    /// Returns the number of the next block to stream.
+    const fn next_block(&self) -> BlockNumber {
+        self.next_block
+    }
+
+    /// Returns the tracked streamed blocks.
+    fn iter(&self) -> impl Iterator<Item = BlockNumHash> + '_ {
+        self.blocks.iter().map(|(number, hash)| BlockNumHash::new(*number, *hash))
+    }
+
+    /// Records a newly canonical block. Returns `true` if it has to be streamed, i.e. it wasn't
+    /// streamed already.
+    fn insert(&mut self, block: BlockNumHash) -> bool {
+        if block.number < self.next_block || self.blocks.get(&block.number) == Some(&block.hash) {
+            return false
+        }
+
+        self.blocks.insert(block.number, block.hash);
+        self.next_block = block.number + 1;
+        while self.blocks.len() > BLOCK_STREAM_TRACKED_BLOCKS {
+            self.blocks.pop_first();
+        }
+        true
+    }
+
+    /// Records a block that was reorged out, the blocks of a reorg have to be removed from the
+    /// highest to the lowest. Returns `true` if the block was streamed, so its removal has to be
+    /// streamed as well.
+    fn remove(&mut self, block: BlockNumHash) -> bool {
+        match self.blocks.get(&block.number) {
+            Some(hash) if *hash == block.hash => {
+                self.blocks.split_off(&block.number);
+                self.next_block = block.number;
+                true
+            }
+            // the new canonical block at this height was streamed already, because the
+            // subscription backfilled the reorg after lagging
+            Some(_) => false,
+            None => {
+                // the block wasn't streamed, but the new block at its height has to be
+                if block.number < self.next_block {
+                    self.blocks.split_off(&block.number);
+                    self.next_block = block.number;
+                }
+                false
+            }
+        }
+    }
+}
+
+/// `reth_` transaction watcher API implementation.
+///
+/// Registers watched transactions and senders with a running
+/// [`TxWatcher`](reth_node_tx_watcher::TxWatcher) and streams its events to subscribers.
+pub struct TxWatcherApi {
+    /// Handle to the running transaction watcher.
+    handle: TxWatcherHandle,
+    /// The type that can spawn subscription tasks.
+    task_spawner: Box<dyn TaskSpawner>,
+}
+
+impl TxWatcherApi {
+    /// Create a new instance of the [`TxWatcherApi`]
+    pub fn new(handle: TxWatcherHandle, task_spawner: Box<dyn TaskSpawner>) -> Self {
+        Self { handle, task_spawner }
+    }
+}
+
+#[async_trait]
+impl RethTxWatcherApiServer for TxWatcherApi {
+    /// Handler for `reth_watchTransaction`
+    async fn reth_watch_transaction(&self, hash: TxHash) -> RpcResult<bool> {
+        Ok(self.handle.watch_transaction(hash))
+    }
+
+    /// Handler for `reth_unwatchTransaction`
+    async fn reth_unwatch_transaction(&self, hash: TxHash) -> RpcResult<bool> {
+        Ok(self.handle.unwatch_transaction(hash))
+    }
+
+    /// Handler for `reth_watchSender`
+    async fn reth_watch_sender(&self, sender: Address) -> RpcResult<bool> {
+        Ok(self.handle.watch_sender(sender))
+    }
+
+    /// Handler for `reth_unwatchSender`
+    async fn reth_unwatch_sender(&self, sender: Address) -> RpcResult<bool> {
+        Ok(self.handle.unwatch_sender(sender))
+    }
+
+    /// Handler for `reth_transactionEvents`
+    async fn reth_transaction_events(
+        &self,
+        pending: PendingSubscriptionSink,
+    ) -> SubscriptionResult {
+        let sink = pending.accept().await?;
+        let mut events = BroadcastStream::new(self.handle.subscribe());
+        self.task_spawner.spawn(Box::pin(async move {
+            while let Some(event) = events.next().await {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(BroadcastStreamRecvError::Lagged(missed)) => {
+                        trace!(target: "rpc::reth", missed, "transaction event subscriber lagged");
+                        continue
+                    }
+                };
+                let Ok(msg) = SubscriptionMessage::from_json(&event) else { break };
+                if sink.send(msg).await.is_err() {
+                    break
+                }
+            }
+        }));
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for TxWatcherApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TxWatcherApi").field("handle", &self.handle).finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{Block, BlockBody, Header, TxEip1559, TxLegacy};
+    use alloy_primitives::{BloomInput, PrimitiveSignature as Signature};
+    use reth_ethereum_primitives::{Receipt, Transaction, TransactionSigned};
+    use reth_provider::test_utils::MockEthProvider;
+    use reth_tasks::TokioTaskExecutor;
+
+    fn block(number: BlockNumber, fork: u8) -> BlockNumHash {
+        BlockNumHash::new(number, B256::with_last_byte(fork))
+    }
+
+    #[tokio::test]
+    async fn block_summaries() {
+        let provider = MockEthProvider::default();
+        let blocks = (0..3)
+            .map(|number| Block {
+                header: Header { number, gas_used: 21_000 * number, ..Default::default() },
+                body: BlockBody {
+                    transactions: vec![TransactionSigned::default(); number as usize],
+                    ..Default::default()
+                },
+            })
+            .collect::<Vec<_>>();
+        for block in &blocks {
+            provider.add_block(block.header.hash_slow(), block.clone());
+        }
+        let api = RethApi::new(provider, (), Box::<TokioTaskExecutor>::default());
+
+        // the range is cut off at the latest block
+        let summaries = api.block_summaries(BlockNumberOrTag::Number(1), 5, true).await.unwrap();
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[1].number, 2);
+        assert_eq!(summaries[1].hash, blocks[2].header.hash_slow());
+        assert_eq!(summaries[1].transaction_count, 2);
+        assert_eq!(summaries[1].gas_used, 42_000);
+        assert_eq!(summaries[1].size, Some(blocks[2].length() as u64));
+
+        let summaries = api.block_summaries(BlockNumberOrTag::Number(0), 3, false).await.unwrap();
+        assert!(summaries.iter().all(|summary| summary.size.is_none()));
+
+        // fewer blocks are returned when the transactions are read for the block sizes
+        assert!(api
+            .block_summaries(BlockNumberOrTag::Number(0), MAX_SIZED_BLOCK_SUMMARIES + 1, false)
+            .await
+            .is_ok());
+        assert!(matches!(
+            api.block_summaries(BlockNumberOrTag::Number(0), MAX_SIZED_BLOCK_SUMMARIES + 1, true)
+                .await,
+            Err(EthApiError::InvalidParams(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn block_headers() {
+        let provider = MockEthProvider::default();
+        for number in 0..3 {
+            let header = Header { number, gas_used: 21_000 * number, ..Default::default() };
+            provider.add_header(header.hash_slow(), header);
+        }
+        let api = RethApi::new(provider, (), Box::<TokioTaskExecutor>::default());
+
+        let headers = api.block_headers(BlockNumberOrTag::Number(1), 5, None).await.unwrap();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers[1]["number"], "0x2");
+        assert_eq!(headers[1]["gasUsed"], "0xa410");
+
+        // only the requested fields are returned
+        let headers = api
+            .block_headers(
+                BlockNumberOrTag::Number(0),
+                1,
+                Some(vec!["number".to_string(), "hash".to_string()]),
+            )
+            .await
+            .unwrap();
+        let fields = headers[0].as_object().unwrap();
+        assert_eq!(fields.len(), 2);
+        assert!(fields.contains_key("hash"));
+
+        assert!(matches!(
+            api.block_headers(BlockNumberOrTag::Number(0), MAX_BLOCK_HEADERS + 1, None).await,
+            Err(EthApiError::InvalidParams(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn block_rewards() {
+        let provider = MockEthProvider::default();
+        let pre_merge =
+            Header { number: 1, beneficiary: Address::with_last_byte(1), ..Default::default() };
+        // a Cancun block with the minimum blob base fee of 1 wei
+        let cancun = Header {
+            number: 20_000_000,
+            timestamp: 1_710_338_135,
+            base_fee_per_gas: Some(10),
+            blob_gas_used: Some(131_072),
+            excess_blob_gas: Some(0),
+            ..Default::default()
+        };
+        provider.add_header(pre_merge.hash_slow(), pre_merge.clone());
+        provider.add_header(cancun.hash_slow(), cancun.clone());
+        let api = RethApi::new(provider, (), Box::<TokioTaskExecutor>::default());
+
+        let rewards = api.block_rewards(BlockNumberOrTag::Number(1), 1).await.unwrap();
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(rewards[0].hash, pre_merge.hash_slow());
+        assert_eq!(rewards[0].beneficiary, Address::with_last_byte(1));
+        assert_eq!(rewards[0].block_reward, U256::from(5_000_000_000_000_000_000u128));
+        assert_eq!(rewards[0].issuance(), U256::from(5_000_000_000_000_000_000u128));
+        assert_eq!(rewards[0].burned(), U256::ZERO);
+
+        let rewards = api.block_rewards(BlockNumberOrTag::Number(20_000_000), 1).await.unwrap();
+        assert_eq!(rewards[0].blob_fee_burned, U256::from(131_072));
+        assert_eq!(rewards[0].burned(), U256::from(131_072));
+        assert_eq!(rewards[0].issuance(), U256::ZERO);
+
+        assert!(matches!(
+            api.block_rewards(BlockNumberOrTag::Number(0), MAX_BLOCK_REWARDS + 1).await,
+            Err(EthApiError::InvalidParams(_))
+        ));
+    }
+
+    #[test]
+    fn block_priority_fees_use_gas_of_receipts() {
+        let transactions = [
+            TransactionSigned::new_unhashed(
+                Transaction::Eip1559(TxEip1559 {
+                    max_fee_per_gas: 30,
+                    max_priority_fee_per_gas: 2,
+                    ..Default::default()
+                }),
+                Signature::test_signature(),
+            ),
+            TransactionSigned::new_unhashed(
+                Transaction::Legacy(TxLegacy { gas_price: 15, ..Default::default() }),
+                Signature::test_signature(),
+            ),
+        ];
+        let receipts = [
+            Receipt { cumulative_gas_used: 21_000, ..Default::default() },
+            Receipt { cumulative_gas_used: 71_000, ..Default::default() },
+        ];
+
+        // a tip of 2 for 21000 gas and of 5 for 50000 gas
+        assert_eq!(block_priority_fees(&transactions, &receipts, 10), U256::from(292_000));
+    }
+
+    #[test]
+    fn logs_chunk_skips_blocks_by_bloom() {
+        let provider = MockEthProvider::default();
+        let address = Address::with_last_byte(1);
+        for number in 0..3 {
+            let mut header = Header { number, ..Default::default() };
+            // only the last block may contain logs of the address
+            if number == 2 {
+                header.logs_bloom.accrue(BloomInput::Raw(address.as_slice()));
+            }
+            provider.add_header(header.hash_slow(), header);
+        }
+        let api = RethApi::new(provider, (), Box::<TokioTaskExecutor>::default());
+        let filter = FilteredParams::new(Some(Filter::new().address(address)));
+
+        // the receipts of blocks whose bloom doesn't match the filter aren't read
+        let chunk = api.try_logs_chunk(&filter, 0, 1).unwrap().unwrap();
+        assert!(chunk.logs.is_empty());
+        assert!(!chunk.live);
+        assert_eq!(chunk.resume_token.block_number, 1);
+        assert!(matches!(api.try_logs_chunk(&filter, 0, 5), Err(EthApiError::ReceiptsNotFound(_))));
+
+        // ranges beyond the tip are empty
+        assert!(api.try_logs_chunk(&filter, 3, 5).unwrap().is_none());
+    }
+
+    #[test]
+    fn streamed_blocks_skips_duplicates() {
+        let mut streamed = StreamedBlocks::new(10);
+
+        // blocks below the head at subscription time aren't streamed
+        assert!(!streamed.insert(block(9, 0)));
+        assert!(streamed.insert(block(10, 0)));
+        assert!(streamed.insert(block(11, 0)));
+
+        // notifications of backfilled blocks that were queued when the subscription lagged
+        assert!(!streamed.insert(block(10, 0)));
+        assert!(!streamed.insert(block(11, 0)));
+        assert_eq!(streamed.next_block(), 12);
+    }
+
+    #[test]
+    fn streamed_blocks_removes_reorged_blocks() {
+        let mut streamed = StreamedBlocks::new(10);
+        for number in 10..=12 {
+            assert!(streamed.insert(block(number, 0)));
+        }
+
+        // reorg of blocks 11 and 12
+        assert!(streamed.remove(block(12, 0)));
+        assert!(streamed.remove(block(11, 0)));
+        assert!(streamed.insert(block(11, 1)));
+        assert!(streamed.insert(block(12, 1)));
+        assert!(streamed.insert(block(13, 1)));
+        assert_eq!(
+            streamed.iter().collect::<Vec<_>>(),
+            vec![block(10, 0), block(11, 1), block(12, 1), block(13, 1)]
+        );
+    }
+
+    #[test]
+    fn streamed_blocks_reorg_below_subscription() {
+        let mut streamed = StreamedBlocks::new(10);
+
+        // the reorged blocks weren't streamed, but the new blocks at their heights are
+        assert!(!streamed.remove(block(9, 0)));
+        assert!(!streamed.remove(block(8, 0)));
+        assert!(streamed.insert(block(8, 1)));
+        assert!(streamed.insert(block(9, 1)));
+        assert!(streamed.insert(block(10, 1)));
+    }
+
+    #[test]
+    fn streamed_blocks_reorg_after_backfill() {
+        let mut streamed = StreamedBlocks::new(10);
+        assert!(streamed.insert(block(10, 0)));
+
+        // the subscription lagged and backfilled the new chain
+        assert!(streamed.insert(block(11, 1)));
+        assert!(streamed.insert(block(12, 1)));
+
+        // the queued reorg notification neither removes nor repeats any block
+        assert!(!streamed.remove(block(11, 0)));
+        assert!(!streamed.insert(block(11, 1)));
+        assert!(!streamed.insert(block(12, 1)));
+        assert_eq!(streamed.next_block(), 13);
+    }
+
+    #[test]
+    fn streamed_blocks_tracks_recent_blocks() {
+        let mut streamed = StreamedBlocks::new(0);
+        for number in 0..100 {
+            assert!(streamed.insert(block(number, 0)));
+        }
+
+        assert_eq!(streamed.iter().count(), BLOCK_STREAM_TRACKED_BLOCKS);
+        assert_eq!(
+            streamed.iter().next(),
+            Some(block(100 - BLOCK_STREAM_TRACKED_BLOCKS as u64, 0))
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! `reth_storageBreakdown` endpoint.
+
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use parking_lot::Mutex;
+use reth_db::models::StorageBreakdown;
+use reth_provider::StorageBreakdownProvider;
+use reth_rpc_api::RethStorageApiServer;
+use reth_rpc_eth_types::EthApiError;
+use reth_tasks::TaskSpawner;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::oneshot;
+
+/// The age after which the report that growth rates are measured against is replaced.
+const GROWTH_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// `reth` API implementation for the disk usage of the node.
+#[derive(Debug, Clone)]
+pub struct StorageBreakdownApi<Provider> {
+    /// Provider to read the table and static file sizes.
+    provider: Provider,
+    /// Spawns the measurement on a blocking task, as it reads the stats of every table and the
+    /// metadata of every static file.
+    task_spawner: Box<dyn TaskSpawner>,
+    /// The earlier report that growth rates are measured against, with the time it was created.
+    baseline: Arc<Mutex<Option<(Instant, StorageBreakdown)>>>,
+}
+
+impl<Provider> StorageBreakdownApi<Provider> {
+    /// Creates a new instance of the [`StorageBreakdownApi`].
+    pub fn new(provider: Provider, task_spawner: Box<dyn TaskSpawner>) -> Self {
+        Self { provider, task_spawner, baseline: Default::default() }
+    }
+}
+
+#[async_trait]
+impl<Provider> RethStorageApiServer for StorageBreakdownApi<Provider>
+where
+    Provider: StorageBreakdownProvider + Clone + 'static,
+{
+    /// Handler for `reth_storageBreakdown`
+    async fn reth_storage_breakdown(&self) -> RpcResult<StorageBreakdown> {
+        let (tx, rx) = oneshot::channel();
+        let provider = self.provider.clone();
+        self.task_spawner.spawn_blocking(Box::pin(async move {
+            let _ = tx.send(provider.storage_breakdown());
+        }));
+        let mut breakdown =
+            rx.await.map_err(|_| EthApiError::InternalEthError)?.map_err(EthApiError::from)?;
+        let now = Instant::now();
+
+        let mut baseline = self.baseline.lock();
+        match baseline.as_ref() {
+            Some((created_at, previous)) => {
+                let elapsed = now.duration_since(*created_at);
+                breakdown.set_growth_since(previous, elapsed.as_secs_f64());
+                if elapsed >= GROWTH_WINDOW {
+                    *baseline = Some((now, breakdown.clone()));
+                }
+            }
+            None => *baseline = Some((now, breakdown.clone())),
+        }
+
+        Ok(breakdown)
+    }
+}
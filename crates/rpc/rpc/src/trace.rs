@@ -22,7 +22,10 @@ use reth_provider::{BlockNumReader, BlockReader, ChainSpecProvider};
 use reth_revm::{database::StateProviderDatabase, db::CacheDB};
 use reth_rpc_api::TraceApiServer;
 use reth_rpc_eth_api::{helpers::TraceExt, FromEthApiError, RpcNodeCore};
-use reth_rpc_eth_types::{error::EthApiError, utils::recover_raw_transaction};
+use reth_rpc_eth_types::{
+    error::EthApiError, memory_budget::charge_request_memory_estimated,
+    utils::recover_raw_transaction,
+};
 use reth_tasks::pool::BlockingTaskGuard;
 use reth_transaction_pool::{PoolPooledTx, PoolTransaction, TransactionPool};
 use revm::DatabaseCommit;
@@ -286,6 +289,7 @@ where
                     let mut traces =
                         inspector.into_parity_builder().into_localized_transaction_traces(tx_info);
                     traces.retain(|trace| matcher.matches(&trace.trace));
+                    charge_request_memory_estimated(&traces).map_err(Eth::Error::from_eth_err)?;
                     Ok(Some(traces))
                 },
             );
@@ -370,6 +374,7 @@ where
             |tx_info, inspector, _, _, _| {
                 let traces =
                     inspector.into_parity_builder().into_localized_transaction_traces(tx_info);
+                charge_request_memory_estimated(&traces).map_err(Eth::Error::from_eth_err)?;
                 Ok(traces)
             },
         );
@@ -419,6 +424,7 @@ where
                         transaction_hash: tx_info.hash.expect("tx hash is set"),
                         full_trace,
                     };
+                    charge_request_memory_estimated(&trace).map_err(Eth::Error::from_eth_err)?;
                     Ok(trace)
                 },
             )
@@ -0,0 +1,394 @@
+//! `debug` API extension that writes opcode traces to files.
+
+use alloy_consensus::BlockHeader;
+use alloy_eips::BlockId;
+use alloy_primitives::B256;
+use alloy_rpc_types_trace::geth::GethDefaultTracingOptions;
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use reth_errors::RethError;
+use reth_evm::ConfigureEvm;
+use reth_primitives_traits::{Block as _, SignedTransaction};
+use reth_revm::{database::StateProviderDatabase, db::CacheDB};
+use reth_rpc_api::DebugTraceFileApiServer;
+use reth_rpc_eth_api::{helpers::TraceExt, EthApiTypes, FromEthApiError, RpcNodeCore};
+use reth_rpc_eth_types::{EthApiError, TraceFile, TraceFileFormat, TraceFileOptions};
+use revm::{bytecode::opcode, context_interface::Transaction};
+use revm_inspectors::tracing::{
+    types::{CallKind, CallTraceNode},
+    TracingInspector, TracingInspectorConfig,
+};
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+use tracing::warn;
+
+/// The gas that is added to the gas limit of a call that transfers value, which the caller
+/// doesn't pay for.
+const CALL_STIPEND: u64 = 2_300;
+
+/// `debug` API extension that replays transactions and writes their opcode traces to files in a
+/// directory, instead of returning them inline.
+#[derive(Debug, Clone)]
+pub struct DebugTraceFileApi<Eth> {
+    /// The implementation of `eth` API
+    eth_api: Eth,
+    /// The directory the traces are written to.
+    dir: PathBuf,
+    /// The maximum total size of the traces in the directory in bytes.
+    max_size: u64,
+}
+
+impl<Eth> DebugTraceFileApi<Eth> {
+    /// Creates a new instance that writes the traces to the given directory, removing the oldest
+    /// traces once their total size exceeds `max_size` bytes.
+    pub const fn new(eth_api: Eth, dir: PathBuf, max_size: u64) -> Self {
+        Self { eth_api, dir, max_size }
+    }
+}
+
+impl<Eth> DebugTraceFileApi<Eth>
+where
+    Eth: EthApiTypes + TraceExt + Clone + 'static,
+{
+    /// Replays the transaction and writes its opcode trace to a file in the configured format.
+    pub async fn trace_transaction_to_file(
+        &self,
+        tx_hash: B256,
+        opts: TraceFileOptions,
+    ) -> Result<TraceFile, Eth::Error> {
+        let (transaction, block) = match self.eth_api.transaction_and_block(tx_hash).await? {
+            None => return Err(EthApiError::TransactionNotFound.into()),
+            Some(res) => res,
+        };
+        let (evm_env, _) = self.eth_api.evm_env_at(block.hash().into()).await?;
+
+        // we need to get the state of the parent block because we're essentially replaying the
+        // block the transaction is included in
+        let state_at: BlockId = block.parent_hash().into();
+        let TraceFileOptions { format, config } = opts;
+        let dir = self.dir.clone();
+        let path = dir.join(format!("{tx_hash}.{}", format.extension()));
+        let max_size = self.max_size;
+
+        let eth_api = self.eth_api.clone();
+        self.eth_api
+            .spawn_with_state_at_block(state_at, move |state| {
+                let block_txs = block.transactions_recovered();
+                let tx = transaction.into_recovered();
+
+                let mut db = CacheDB::new(StateProviderDatabase::new(state));
+                eth_api.apply_pre_execution_changes(&block, &mut db, &evm_env)?;
+
+                // replay all transactions prior to the targeted transaction
+                eth_api.replay_transactions_until(
+                    &mut db,
+                    evm_env.clone(),
+                    block_txs,
+                    *tx.tx_hash(),
+                )?;
+
+                let tx_env = eth_api.evm_config().tx_env(&tx);
+                let inspector_config = match format {
+                    TraceFileFormat::StructLog => TracingInspectorConfig::from_geth_config(&config),
+                    // only the opcodes and their gas are needed
+                    TraceFileFormat::Flamegraph => {
+                        TracingInspectorConfig::from_geth_config(&GethDefaultTracingOptions {
+                            disable_storage: Some(true),
+                            disable_stack: Some(true),
+                            enable_memory: Some(false),
+                            enable_return_data: Some(false),
+                            ..Default::default()
+                        })
+                    }
+                };
+                let mut inspector = TracingInspector::new(inspector_config);
+                let (res, (_, tx_env)) =
+                    eth_api.inspect(&mut db, evm_env, tx_env, &mut inspector)?;
+                inspector.set_transaction_gas_limit(tx_env.gas_limit());
+
+                let written = match format {
+                    TraceFileFormat::StructLog => {
+                        let gas_used = res.result.gas_used();
+                        let return_value = res.result.into_output().unwrap_or_default();
+                        let frame =
+                            inspector.geth_builder().geth_traces(gas_used, return_value, config);
+                        write_file(&path, max_size, |out| {
+                            serde_json::to_writer(out, &frame).map_err(io::Error::from)
+                        })
+                    }
+                    TraceFileFormat::Flamegraph => write_file(&path, max_size, |out| {
+                        write_collapsed_stacks(inspector.traces().nodes(), out)
+                    }),
+                };
+                let size = written.map_err(|err| {
+                    Eth::Error::from_eth_err(EthApiError::Internal(RethError::other(err)))
+                })?;
+
+                if let Err(err) = prune_dir(&dir, &path, max_size) {
+                    warn!(target: "rpc::debug", %err, ?dir, "Failed to remove old traces");
+                }
+
+                Ok(TraceFile { path, format, size })
+            })
+            .await
+    }
+}
+
+/// Creates the file and its directory, writes its content and returns its size.
+///
+/// The file is removed again if writing fails or its size exceeds `max_size` bytes.
+fn write_file(
+    path: &Path,
+    max_size: u64,
+    write: impl FnOnce(&mut LimitedWriter<BufWriter<File>>) -> io::Result<()>,
+) -> io::Result<u64> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let written = File::create(path).and_then(|file| {
+        let mut out = LimitedWriter { inner: BufWriter::new(file), remaining: max_size };
+        write(&mut out)?;
+        out.flush()?;
+        Ok(max_size - out.remaining)
+    });
+    if written.is_err() {
+        let _ = fs::remove_file(path);
+    }
+    written
+}
+
+/// A writer that fails once more than the remaining bytes are written to it.
+#[derive(Debug)]
+struct LimitedWriter<W> {
+    /// The inner writer.
+    inner: W,
+    /// The number of bytes that can still be written.
+    remaining: u64,
+}
+
+impl<W: Write> Write for LimitedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.len() as u64 > self.remaining {
+            return Err(io::Error::other("trace exceeds the maximum size of the trace directory"))
+        }
+        let written = self.inner.write(buf)?;
+        self.remaining -= written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Removes the oldest files of the directory, except for `keep`, until the total size of its
+/// files doesn't exceed `max_size` bytes.
+fn prune_dir(dir: &Path, keep: &Path, max_size: u64) -> io::Result<()> {
+    let mut files = Vec::new();
+    let mut total = 0;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue
+        }
+        total += metadata.len();
+        if entry.path() != keep {
+            files.push((metadata.modified()?, metadata.len(), entry.path()));
+        }
+    }
+
+    // oldest first
+    files.sort_unstable();
+    for (_, len, path) in files {
+        if total <= max_size {
+            break
+        }
+        fs::remove_file(path)?;
+        total -= len;
+    }
+    Ok(())
+}
+
+/// The opcodes of a call frame and their gas.
+#[derive(Debug)]
+struct FrameGas {
+    /// The label of the frame in the stacks: the kind of the call and the called address.
+    label: String,
+    /// The index of the frame that spawned this frame.
+    parent: Option<usize>,
+    /// The gas the parent frame forwarded to this frame, i.e. its gas limit without the stipend
+    /// of value transfers.
+    forwarded_gas: u64,
+    /// The name and gas cost of the executed opcodes, with the index of the frame spawned by calls
+    /// and creations.
+    steps: Vec<(&'static str, u64, Option<usize>)>,
+}
+
+impl FrameGas {
+    /// Returns the frames of the call trace, in the order of the trace nodes.
+    fn from_nodes(nodes: &[CallTraceNode]) -> Vec<Self> {
+        nodes
+            .iter()
+            .map(|node| {
+                let trace = &node.trace;
+                let stipend = if matches!(trace.kind, CallKind::Call | CallKind::CallCode) &&
+                    !trace.value.is_zero()
+                {
+                    CALL_STIPEND
+                } else {
+                    0
+                };
+
+                // the calls and creations of the frame spawn its children in order
+                let mut children = node.children.iter().copied();
+                let steps = trace
+                    .steps
+                    .iter()
+                    .map(|step| {
+                        let spawned = matches!(
+                            step.op.get(),
+                            opcode::CALL |
+                                opcode::CALLCODE |
+                                opcode::DELEGATECALL |
+                                opcode::STATICCALL |
+                                opcode::CREATE |
+                                opcode::CREATE2
+                        )
+                        .then(|| children.next())
+                        .flatten();
+                        (step.op.as_str(), step.gas_cost, spawned)
+                    })
+                    .collect();
+
+                Self {
+                    label: format!("{} {}", trace.kind, trace.address),
+                    parent: node.parent,
+                    forwarded_gas: trace.gas_limit.saturating_sub(stipend),
+                    steps,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the total gas of every distinct stack of call frames and opcode.
+///
+/// The cost of a call or creation includes the gas it forwards to the spawned frame, whose opcodes
+/// are counted in the stacks of that frame, so only the overhead of the call is counted for the
+/// calling opcode.
+fn collapse_stacks(frames: &[FrameGas]) -> BTreeMap<String, u64> {
+    let mut stacks = BTreeMap::<String, u64>::new();
+    for frame in frames {
+        // the frames from the outermost call to this one
+        let mut labels = vec![frame.label.as_str()];
+        let mut parent = frame.parent;
+        while let Some(idx) = parent {
+            labels.push(frames[idx].label.as_str());
+            parent = frames[idx].parent;
+        }
+        labels.reverse();
+        let stack = labels.join(";");
+
+        for (op, gas_cost, spawned) in &frame.steps {
+            let forwarded = spawned.map_or(0, |idx| frames[idx].forwarded_gas);
+            *stacks.entry(format!("{stack};{op}")).or_default() +=
+                gas_cost.saturating_sub(forwarded);
+        }
+    }
+    stacks
+}
+
+/// Writes the gas of the opcodes of all call frames as collapsed stacks, i.e. one line per
+/// distinct stack of call frames and opcode, followed by the total gas.
+fn write_collapsed_stacks(nodes: &[CallTraceNode], out: &mut impl Write) -> io::Result<()> {
+    for (stack, gas) in collapse_stacks(&FrameGas::from_nodes(nodes)) {
+        writeln!(out, "{stack} {gas}")?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl<Eth> DebugTraceFileApiServer for DebugTraceFileApi<Eth>
+where
+    Eth: EthApiTypes + TraceExt + RpcNodeCore + Clone + 'static,
+{
+    /// Handler for `debug_traceTransactionToFile`
+    async fn debug_trace_transaction_to_file(
+        &self,
+        tx_hash: B256,
+        opts: Option<TraceFileOptions>,
+    ) -> RpcResult<TraceFile> {
+        Ok(self.trace_transaction_to_file(tx_hash, opts.unwrap_or_default()).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{thread::sleep, time::Duration};
+
+    #[test]
+    fn collapses_stacks_with_call_overhead() {
+        let frames = vec![
+            FrameGas {
+                label: "CALL 0x01".to_string(),
+                parent: None,
+                forwarded_gas: 100_000,
+                steps: vec![("PUSH1", 3, None), ("CALL", 12_700, Some(1)), ("PUSH1", 3, None)],
+            },
+            FrameGas {
+                label: "CALL 0x02".to_string(),
+                parent: Some(0),
+                forwarded_gas: 10_000,
+                steps: vec![("SLOAD", 2_100, None)],
+            },
+        ];
+
+        assert_eq!(
+            collapse_stacks(&frames).into_iter().collect::<Vec<_>>(),
+            vec![
+                ("CALL 0x01;CALL".to_string(), 2_700),
+                ("CALL 0x01;CALL 0x02;SLOAD".to_string(), 2_100),
+                ("CALL 0x01;PUSH1".to_string(), 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn removes_oversized_trace() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trace.json");
+
+        let size = write_file(&path, 4, |out| out.write_all(b"1234")).unwrap();
+        assert_eq!(size, 4);
+
+        assert!(write_file(&path, 4, |out| out.write_all(b"12345")).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn prunes_oldest_traces() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = ["a", "b", "c"].map(|name| dir.path().join(name));
+        for path in &paths {
+            write_file(path, 10, |out| out.write_all(b"1234")).unwrap();
+            // the files are ordered by modification time
+            sleep(Duration::from_millis(10));
+        }
+
+        prune_dir(dir.path(), &paths[2], 8).unwrap();
+        assert_eq!(paths.iter().map(|path| path.exists()).collect::<Vec<_>>(), [false, true, true]);
+
+        // the new file is kept even if it exceeds the size on its own
+        prune_dir(dir.path(), &paths[2], 2).unwrap();
+        assert_eq!(
+            paths.iter().map(|path| path.exists()).collect::<Vec<_>>(),
+            [false, false, true]
+        );
+    }
+}
@@ -6,19 +6,29 @@ use reth_config::config::ExecutionConfig;
 use reth_consensus::{ConsensusError, FullConsensus};
 use reth_db::{static_file::HeaderMask, tables};
 use reth_evm::{
+    block::StateChangeSource,
     execute::{BlockExecutorProvider, Executor},
     metrics::ExecutorMetrics,
+    Database,
 };
-use reth_execution_types::Chain;
+use reth_execution_types::{BlockExecutionResult, Chain};
 use reth_exex::{ExExManagerHandle, ExExNotification, ExExNotificationSource};
-use reth_primitives_traits::{format_gas_throughput, Block, BlockBody, NodePrimitives};
+use reth_primitives_traits::{
+    format_gas_throughput, receipt::gas_spent_by_transactions, Block, BlockBody, GotExpected,
+    NodePrimitives, RecoveredBlock,
+};
 use reth_provider::{
     providers::{StaticFileProvider, StaticFileWriter},
     BlockHashReader, BlockReader, DBProvider, ExecutionOutcome, HeaderProvider,
     LatestStateProviderRef, OriginalValuesKnown, ProviderError, StateCommitmentProvider,
     StateWriter, StaticFileProviderFactory, StatsReader, StorageLocation, TransactionVariant,
 };
-use reth_revm::database::StateProviderDatabase;
+use reth_revm::{
+    database::StateProviderDatabase,
+    db::{states::reverts::Reverts, BundleState},
+    state::EvmState,
+    State,
+};
 use reth_stages_api::{
     BlockErrorKind, CheckpointBlockRange, EntitiesCheckpoint, ExecInput, ExecOutput,
     ExecutionCheckpoint, ExecutionStageThresholds, Stage, StageCheckpoint, StageError, StageId,
@@ -28,13 +38,15 @@ use reth_static_file_types::StaticFileSegment;
 use std::{
     cmp::Ordering,
     ops::RangeInclusive,
-    sync::Arc,
+    sync::{mpsc, Arc, Mutex},
     task::{ready, Context, Poll},
     time::{Duration, Instant},
 };
 use tracing::*;
 
-use super::missing_static_data_error;
+use super::{
+    is_execution_output_mismatch, missing_static_data_error, BlockForensicReport, BlockPrefetcher,
+};
 
 /// The execution stage executes all transactions and
 /// update history indexes.
@@ -59,6 +71,19 @@ use super::missing_static_data_error;
 /// - [`tables::AccountChangeSets`]
 /// - [`tables::StorageChangeSets`]
 ///
+/// If there are no `ExEx`es, changesets and receipts are written right after each block is
+/// executed instead of being buffered until the end of the batch, which bounds the memory used by
+/// large batches. Plain state is always written once the batch is done.
+///
+/// The post-execution validation of a block, i.e. the computation of its receipts root, logs bloom
+/// and requests hash, runs on the rayon pool while the next block is executed.
+///
+/// If a [`BlockPrefetcher`] is configured, the next blocks are read and decoded on background
+/// threads while the current block is executed.
+///
+/// With an [`ExecutionVerificationSampling`], the post-execution validation is only performed for
+/// a sample of the blocks, and only the gas used is compared for the others.
+///
 /// For unwinds we are accessing:
 /// - [`tables::BlockBodyIndices`] get tx index to know what needs to be unwinded
 /// - [`tables::AccountsHistory`] to remove change set and apply old values to
@@ -94,6 +119,10 @@ where
     exex_manager_handle: ExExManagerHandle<E::Primitives>,
     /// Executor metrics.
     metrics: ExecutorMetrics,
+    /// Reads the upcoming blocks ahead of execution, if set.
+    prefetcher: Option<BlockPrefetcher<<E::Primitives as NodePrimitives>::Block>>,
+    /// The blocks whose execution output is fully validated.
+    verification_sampling: ExecutionVerificationSampling,
 }
 
 impl<E> ExecutionStage<E>
@@ -117,9 +146,29 @@ where
             post_unwind_commit_input: None,
             exex_manager_handle,
             metrics: ExecutorMetrics::default(),
+            prefetcher: None,
+            verification_sampling: ExecutionVerificationSampling::default(),
         }
     }
 
+    /// Reads the upcoming blocks ahead of execution with the given prefetcher.
+    pub fn with_block_prefetcher(
+        mut self,
+        prefetcher: BlockPrefetcher<<E::Primitives as NodePrimitives>::Block>,
+    ) -> Self {
+        self.prefetcher = Some(prefetcher);
+        self
+    }
+
+    /// Only fully validates the execution output of the blocks selected by the given sampling.
+    pub const fn with_verification_sampling(
+        mut self,
+        verification_sampling: ExecutionVerificationSampling,
+    ) -> Self {
+        self.verification_sampling = verification_sampling;
+        self
+    }
+
     /// Create an execution stage with the provided executor.
     ///
     /// The commit threshold will be set to [`MERKLE_STAGE_DEFAULT_CLEAN_THRESHOLD`].
@@ -175,6 +224,199 @@ where
             provider.count_entries::<tables::AccountsTrie>()?.is_zero())
     }
 
+    /// Returns whether the changesets of the given block should be pruned, which is the case if
+    /// both account history and storage history pruning is configured for it.
+    fn should_prune_changesets(
+        &self,
+        provider: &impl DBProvider,
+        block_number: BlockNumber,
+        tip: BlockNumber,
+    ) -> bool {
+        let prune_modes = provider.prune_modes_ref();
+        prune_modes.account_history.is_some_and(|m| m.should_prune(block_number, tip)) &&
+            prune_modes.storage_history.is_some_and(|m| m.should_prune(block_number, tip))
+    }
+
+    /// Writes the changesets and receipts of a single executed block.
+    ///
+    /// `reverts` are expected to only contain the reverts of this block.
+    fn write_block_output<Provider>(
+        &self,
+        provider: &Provider,
+        block_number: BlockNumber,
+        tip: BlockNumber,
+        can_prune_changesets: bool,
+        reverts: Reverts,
+        result: BlockExecutionResult<<E::Primitives as NodePrimitives>::Receipt>,
+    ) -> Result<(), StageError>
+    where
+        Provider: DBProvider + StateWriter<Receipt = <E::Primitives as NodePrimitives>::Receipt>,
+    {
+        if !(can_prune_changesets && self.should_prune_changesets(provider, block_number, tip)) {
+            provider.write_state_reverts(reverts.to_plain_state_reverts(), block_number)?;
+        }
+
+        // The bundle is empty, so only the receipts are written.
+        let outcome =
+            ExecutionOutcome::from_blocks(block_number, BundleState::default(), vec![result]);
+        provider.write_state(&outcome, OriginalValuesKnown::Yes, StorageLocation::StaticFiles)?;
+
+        Ok(())
+    }
+
+    /// Prunes the receipts that were streamed into static files for the blocks of a failed batch
+    /// starting at `start_block`.
+    ///
+    /// Unlike the changesets, these receipts are not rolled back with the database transaction and
+    /// would otherwise be committed together with a later batch.
+    fn prune_streamed_receipts<Provider>(
+        &self,
+        provider: &Provider,
+        start_block: BlockNumber,
+    ) -> Result<(), StageError>
+    where
+        Provider: StaticFileProviderFactory + DBProvider + BlockReader,
+    {
+        // receipts are written to the database if receipts pruning is configured
+        if provider.prune_modes_ref().has_receipts_pruning() {
+            return Ok(())
+        }
+
+        let last_block = start_block.saturating_sub(1);
+        let next_receipt_num =
+            provider.block_body_indices(last_block)?.map(|b| b.next_tx_num()).unwrap_or(0);
+
+        let static_file_provider = provider.static_file_provider();
+        let mut writer = static_file_provider.latest_writer(StaticFileSegment::Receipts)?;
+        let next_static_file_receipt_num =
+            writer.user_header().tx_end().map(|num| num + 1).unwrap_or(0);
+        if next_static_file_receipt_num == next_receipt_num &&
+            writer.user_header().block_end() == Some(last_block)
+        {
+            return Ok(())
+        }
+
+        writer.prune_receipts(
+            next_static_file_receipt_num.saturating_sub(next_receipt_num),
+            last_block,
+        )?;
+        writer.commit()?;
+
+        Ok(())
+    }
+
+    /// Re-executes the block on top of its parent state and creates a [`BlockForensicReport`]
+    /// that captures the receipt fields of every transaction.
+    ///
+    /// `bundle` is expected to be the state after executing the block, with the block's reverts
+    /// still being the latest ones.
+    fn forensic_report<DB>(
+        &self,
+        database: DB,
+        mut bundle: BundleState,
+        block: &RecoveredBlock<<E::Primitives as NodePrimitives>::Block>,
+        result: &BlockExecutionResult<<E::Primitives as NodePrimitives>::Receipt>,
+        error: &ConsensusError,
+    ) -> BlockForensicReport
+    where
+        DB: Database,
+    {
+        // roll back the changes of the block to get its parent state
+        bundle.revert(1);
+        let parent_state =
+            State::builder().with_database(database).with_bundle_prestate(bundle).build();
+
+        let touched_accounts = Arc::new(Mutex::new(Vec::new()));
+        let hook_touched_accounts = touched_accounts.clone();
+        let reexecuted = self
+            .executor_provider
+            .executor(parent_state)
+            .execute_one_with_state_hook(block, move |source, state: &EvmState| {
+                if let StateChangeSource::Transaction(_) = source {
+                    if let Ok(mut touched_accounts) = hook_touched_accounts.lock() {
+                        touched_accounts.push(state.len());
+                    }
+                }
+            })
+            .map(|reexecuted| {
+                let touched_accounts =
+                    touched_accounts.lock().map(|touched| touched.clone()).unwrap_or_default();
+                (reexecuted.receipts, touched_accounts)
+            })
+            .map_err(|err| err.to_string());
+
+        BlockForensicReport::new(block, error, &result.receipts, reexecuted)
+    }
+
+    /// Validates the executed block against its execution result on the rayon pool.
+    ///
+    /// If `full` is not set, only the gas used is compared right away instead.
+    ///
+    /// The block is sent back together with the outcome of the validation, see
+    /// [`Self::wait_for_validation`].
+    fn spawn_validation(
+        &self,
+        executed: ExecutedBlock<E::Primitives>,
+        full: bool,
+    ) -> PendingValidation<E> {
+        let (tx, rx) = mpsc::channel();
+        if !full {
+            let outcome = validate_gas_used::<E::Primitives>(&executed.block, &executed.result);
+            let _ = tx.send((executed, outcome));
+            return rx
+        }
+
+        let consensus = self.consensus.clone();
+        rayon::spawn(move || {
+            let outcome =
+                consensus.validate_block_post_execution(&executed.block, &executed.result);
+            let _ = tx.send((executed, outcome));
+        });
+        rx
+    }
+
+    /// Waits for the validation of a block spawned with [`Self::spawn_validation`] and returns the
+    /// block if it's valid.
+    ///
+    /// `later_blocks` is the number of blocks that `executor` executed after the validated block,
+    /// which are rolled back for the forensic report of an execution output mismatch.
+    fn wait_for_validation<DB>(
+        &self,
+        executor: &mut E::Executor<DB>,
+        pending: PendingValidation<E>,
+        later_blocks: usize,
+    ) -> Result<ExecutedBlock<E::Primitives>, StageError>
+    where
+        DB: Database,
+    {
+        // the sender is dropped without an outcome if the rayon job panicked
+        let (executed, outcome) = pending.recv().map_err(|_| StageError::ChannelClosed)?;
+
+        if let Err(err) = outcome {
+            let ExecutedBlock { block, result, reverts } = executed;
+            if is_execution_output_mismatch(&err) {
+                let report = executor.with_state_mut(|state| {
+                    let mut bundle = state.bundle_state.clone();
+                    if let Some(reverts) = reverts {
+                        // the reverts of the block were already taken from the state
+                        let later_reverts = bundle.take_all_reverts();
+                        bundle.reverts = reverts;
+                        bundle.reverts.extend(later_reverts);
+                    }
+                    bundle.revert(later_blocks);
+                    self.forensic_report(&mut state.database, bundle, &block, &result, &err)
+                });
+                error!(target: "sync::stages::execution", %report, "Block execution output mismatch");
+            }
+            return Err(StageError::Block {
+                block: Box::new(block.block_with_parent()),
+                error: BlockErrorKind::Validation(err),
+            })
+        }
+
+        Ok(executed)
+    }
+
     /// Performs consistency check on static files.
     ///
     /// This function compares the highest receipt number recorded in the database with that in the
@@ -320,87 +562,158 @@ where
         let mut cumulative_gas = 0;
         let batch_start = Instant::now();
 
+        // ExExes are notified with the whole execution outcome of the batch, so it needs to be kept
+        // in memory. Otherwise, changesets and receipts are streamed into storage block by block.
+        let stream_outputs = !self.exex_manager_handle.has_exexs();
+        let can_prune_changesets = self.can_prune_changesets(provider, start_block, max_block)?;
+        let mut write_duration = Duration::default();
+
         let mut blocks = Vec::new();
         let mut results = Vec::new();
-        for block_number in start_block..=max_block {
-            // Fetch the block
-            let fetch_block_start = Instant::now();
-
-            // we need the block's transactions but we don't need the transaction hashes
-            let block = provider
-                .recovered_block(block_number.into(), TransactionVariant::NoHash)?
-                .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+        // Writes the output of a validated block, or buffers it until the end of the batch.
+        let has_exexs = self.exex_manager_handle.has_exexs();
+        let mut finish_block = |executed: ExecutedBlock<E::Primitives>| {
+            let ExecutedBlock { block, result, reverts } = executed;
+            if let Some(reverts) = reverts {
+                let write_start = Instant::now();
+                self.write_block_output(
+                    provider,
+                    block.header().number(),
+                    max_block,
+                    can_prune_changesets,
+                    reverts,
+                    result,
+                )?;
+                write_duration += write_start.elapsed();
+            } else {
+                results.push(result);
+            }
 
-            fetch_block_duration += fetch_block_start.elapsed();
+            // If we have ExExes we need to save the block in memory for later
+            if has_exexs {
+                blocks.push(block);
+            }
 
-            cumulative_gas += block.header().gas_used();
+            Ok::<_, StageError>(())
+        };
 
-            // Configure the executor to use the current state.
-            trace!(target: "sync::stages::execution", number = block_number, txs = block.body().transactions().len(), "Executing block");
+        // The upcoming blocks that are read while the current one is executed.
+        let mut prefetched =
+            self.prefetcher.as_ref().map(|prefetcher| prefetcher.spawn(start_block..=max_block));
 
-            // Execute the block
-            let execute_start = Instant::now();
+        // Receipts that were streamed into static files are not rolled back with the database
+        // transaction, so they are pruned again if the batch fails.
+        let mut execute_blocks = || {
+            // The block whose post-execution validation runs while the next block is executed.
+            let mut pending_validation = None;
+            for block_number in start_block..=max_block {
+                // Fetch the block
+                let fetch_block_start = Instant::now();
+
+                let block =
+                    match prefetched.as_mut().and_then(|blocks| blocks.next_block(block_number)) {
+                        Some(block) => block,
+                        // we need the block's transactions but we don't need the transaction hashes
+                        None => provider
+                            .recovered_block(block_number.into(), TransactionVariant::NoHash)?
+                            .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?,
+                    };
+
+                fetch_block_duration += fetch_block_start.elapsed();
+
+                cumulative_gas += block.header().gas_used();
+
+                // Configure the executor to use the current state.
+                trace!(target: "sync::stages::execution", number = block_number, txs = block.body().transactions().len(), "Executing block");
+
+                // Execute the block
+                let execute_start = Instant::now();
+
+                let result = self.metrics.metered_one(&block, |input| {
+                    executor.execute_one(input).map_err(|error| StageError::Block {
+                        block: Box::new(block.block_with_parent()),
+                        error: BlockErrorKind::Execution(error),
+                    })
+                });
+
+                // The previous block was validated while this one was executed. Its outcome is
+                // awaited before the execution error of this block is returned, so
+                // that an invalid previous block is reported as the bad block. A
+                // block that failed to execute left no changes in the bundle state.
+                if let Some(pending) = pending_validation.take() {
+                    let later_blocks = usize::from(result.is_ok());
+                    finish_block(self.wait_for_validation(
+                        &mut executor,
+                        pending,
+                        later_blocks,
+                    )?)?;
+                }
+                let result = result?;
 
-            let result = self.metrics.metered_one(&block, |input| {
-                executor.execute_one(input).map_err(|error| StageError::Block {
-                    block: Box::new(block.block_with_parent()),
-                    error: BlockErrorKind::Execution(error),
-                })
-            })?;
+                execution_duration += execute_start.elapsed();
 
-            if let Err(err) = self.consensus.validate_block_post_execution(&block, &result) {
-                return Err(StageError::Block {
-                    block: Box::new(block.block_with_parent()),
-                    error: BlockErrorKind::Validation(err),
-                })
-            }
-            results.push(result);
-
-            execution_duration += execute_start.elapsed();
-
-            // Log execution throughput
-            if last_log_instant.elapsed() >= log_duration {
-                info!(
-                    target: "sync::stages::execution",
-                    start = last_block,
-                    end = block_number,
-                    throughput = format_gas_throughput(cumulative_gas - last_cumulative_gas, execution_duration - last_execution_duration),
-                    "Executed block range"
+                let gas_used = block.header().gas_used();
+                let end_of_batch = self.thresholds.is_end_of_batch(
+                    block_number - start_block,
+                    executor.size_hint() as u64,
+                    cumulative_gas,
+                    batch_start.elapsed(),
                 );
 
-                last_block = block_number + 1;
-                last_execution_duration = execution_duration;
-                last_cumulative_gas = cumulative_gas;
-                last_log_instant = Instant::now();
-            }
+                // the last block of the batch becomes the checkpoint, so it's always fully
+                // validated
+                let full_validation = end_of_batch ||
+                    block_number == max_block ||
+                    self.verification_sampling.should_verify(block_number, max_block);
+                let reverts = stream_outputs.then(|| {
+                    executor.with_state_mut(|state| state.bundle_state.take_all_reverts())
+                });
+                pending_validation =
+                    Some(self.spawn_validation(
+                        ExecutedBlock { block, result, reverts },
+                        full_validation,
+                    ));
+
+                // Log execution throughput
+                if last_log_instant.elapsed() >= log_duration {
+                    info!(
+                        target: "sync::stages::execution",
+                        start = last_block,
+                        end = block_number,
+                        throughput = format_gas_throughput(cumulative_gas - last_cumulative_gas, execution_duration - last_execution_duration),
+                        "Executed block range"
+                    );
+
+                    last_block = block_number + 1;
+                    last_execution_duration = execution_duration;
+                    last_cumulative_gas = cumulative_gas;
+                    last_log_instant = Instant::now();
+                }
 
-            stage_progress = block_number;
-            stage_checkpoint.progress.processed += block.header().gas_used();
+                stage_progress = block_number;
+                stage_checkpoint.progress.processed += gas_used;
 
-            // If we have ExExes we need to save the block in memory for later
-            if self.exex_manager_handle.has_exexs() {
-                blocks.push(block);
+                // Check if we should commit now
+                if end_of_batch {
+                    break
+                }
             }
 
-            // Check if we should commit now
-            if self.thresholds.is_end_of_batch(
-                block_number - start_block,
-                executor.size_hint() as u64,
-                cumulative_gas,
-                batch_start.elapsed(),
-            ) {
-                break
+            if let Some(pending) = pending_validation {
+                let validation_start = Instant::now();
+                let executed = self.wait_for_validation(&mut executor, pending, 0)?;
+                execution_duration += validation_start.elapsed();
+                finish_block(executed)?;
             }
-        }
 
-        // prepare execution output for writing
-        let time = Instant::now();
-        let mut state = ExecutionOutcome::from_blocks(
-            start_block,
-            executor.into_state().take_bundle(),
-            results,
-        );
-        let write_preparation_duration = time.elapsed();
+            Ok::<_, StageError>(())
+        };
+        if let Err(err) = execute_blocks() {
+            if stream_outputs {
+                self.prune_streamed_receipts(provider, start_block)?;
+            }
+            return Err(err)
+        }
 
         // log the gas per second for the range we just executed
         debug!(
@@ -411,54 +724,67 @@ where
             "Finished executing block range"
         );
 
-        // Prepare the input for post execute commit hook, where an `ExExNotification` will be sent.
-        //
-        // Note: Since we only write to `blocks` if there are any ExExes, we don't need to perform
-        // the `has_exexs` check here as well
-        if !blocks.is_empty() {
-            let previous_input =
-                self.post_execute_commit_input.replace(Chain::new(blocks, state.clone(), None));
-
-            if previous_input.is_some() {
-                // Not processing the previous post execute commit input is a critical error, as it
-                // means that we didn't send the notification to ExExes
-                return Err(StageError::PostExecuteCommit(
-                    "Previous post execute commit input wasn't processed",
-                ))
+        // prepare execution output for writing
+        let time = Instant::now();
+        let (write_preparation_duration, db_write_duration) = if stream_outputs {
+            // Changesets and receipts were already written, only plain state is left.
+            let changes =
+                executor.into_state().take_bundle().to_plain_state(OriginalValuesKnown::Yes);
+            let write_preparation_duration = time.elapsed();
+
+            let time = Instant::now();
+            provider.write_state_changes(changes)?;
+
+            (write_preparation_duration, write_duration + time.elapsed())
+        } else {
+            let mut state = ExecutionOutcome::from_blocks(
+                start_block,
+                executor.into_state().take_bundle(),
+                results,
+            );
+            let write_preparation_duration = time.elapsed();
+
+            // Prepare the input for post execute commit hook, where an `ExExNotification` will be
+            // sent.
+            //
+            // Note: Since we only write to `blocks` if there are any ExExes, we don't need to
+            // perform the `has_exexs` check here as well
+            if !blocks.is_empty() {
+                let previous_input =
+                    self.post_execute_commit_input.replace(Chain::new(blocks, state.clone(), None));
+
+                if previous_input.is_some() {
+                    // Not processing the previous post execute commit input is a critical error,
+                    // as it means that we didn't send the notification to ExExes
+                    return Err(StageError::PostExecuteCommit(
+                        "Previous post execute commit input wasn't processed",
+                    ))
+                }
             }
-        }
 
-        let time = Instant::now();
+            let time = Instant::now();
 
-        if self.can_prune_changesets(provider, start_block, max_block)? {
-            let prune_modes = provider.prune_modes_ref();
+            if can_prune_changesets {
+                // Iterate over all reverts and clear them if pruning is configured.
+                for block_number in start_block..=max_block {
+                    let Some(reverts) =
+                        state.bundle.reverts.get_mut((block_number - start_block) as usize)
+                    else {
+                        break
+                    };
 
-            // Iterate over all reverts and clear them if pruning is configured.
-            for block_number in start_block..=max_block {
-                let Some(reverts) =
-                    state.bundle.reverts.get_mut((block_number - start_block) as usize)
-                else {
-                    break
-                };
-
-                // If both account history and storage history pruning is configured, clear reverts
-                // for this block.
-                if prune_modes
-                    .account_history
-                    .is_some_and(|m| m.should_prune(block_number, max_block)) &&
-                    prune_modes
-                        .storage_history
-                        .is_some_and(|m| m.should_prune(block_number, max_block))
-                {
-                    reverts.clear();
+                    if self.should_prune_changesets(provider, block_number, max_block) {
+                        reverts.clear();
+                    }
                 }
             }
-        }
 
-        // write output
-        provider.write_state(&state, OriginalValuesKnown::Yes, StorageLocation::StaticFiles)?;
+            // write output
+            provider.write_state(&state, OriginalValuesKnown::Yes, StorageLocation::StaticFiles)?;
+
+            (write_preparation_duration, time.elapsed())
+        };
 
-        let db_write_duration = time.elapsed();
         debug!(
             target: "sync::stages::execution",
             block_fetch = ?fetch_block_duration,
@@ -564,6 +890,69 @@ where
     }
 }
 
+/// An executed block whose output is written once it's validated.
+#[derive(Debug)]
+struct ExecutedBlock<N: NodePrimitives> {
+    block: RecoveredBlock<N::Block>,
+    result: BlockExecutionResult<N::Receipt>,
+    /// The reverts of the block, if they were already taken from the executor's state.
+    reverts: Option<Reverts>,
+}
+
+/// Receives an executed block back from the rayon pool, together with its validation outcome.
+type PendingValidation<E> = mpsc::Receiver<(
+    ExecutedBlock<<E as BlockExecutorProvider>::Primitives>,
+    Result<(), ConsensusError>,
+)>;
+
+/// Selects the blocks whose execution output, i.e. the receipts root, logs bloom and requests
+/// hash, is validated by the [`ExecutionStage`] during historical sync.
+///
+/// Computing the receipts root of every block is a noticeable part of the execution time. The
+/// receipts, logs and requests of the blocks that are not sampled are **not verified**: a
+/// divergence in them is not detected, neither by the sampled blocks nor by the blocks near the
+/// tip, because their roots only commit to the receipts of their own block. The state is still
+/// verified by the state root of the checkpointed blocks, and the gas used is compared for all
+/// blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionVerificationSampling {
+    /// Every block whose number is a multiple of the interval is fully validated. All blocks are
+    /// fully validated if set to 0 or 1.
+    pub interval: u64,
+    /// All blocks within this distance of the sync target are fully validated.
+    pub tip_distance: u64,
+}
+
+impl ExecutionVerificationSampling {
+    /// Returns whether the execution output of the block is fully validated when syncing to the
+    /// given target.
+    pub const fn should_verify(&self, block_number: BlockNumber, target: BlockNumber) -> bool {
+        self.interval <= 1 ||
+            block_number % self.interval == 0 ||
+            block_number.saturating_add(self.tip_distance) >= target
+    }
+}
+
+impl Default for ExecutionVerificationSampling {
+    fn default() -> Self {
+        Self { interval: 1, tip_distance: 10_000 }
+    }
+}
+
+/// Compares the gas used in the header of the block to the gas used by its execution.
+fn validate_gas_used<N: NodePrimitives>(
+    block: &RecoveredBlock<N::Block>,
+    result: &BlockExecutionResult<N::Receipt>,
+) -> Result<(), ConsensusError> {
+    if block.header().gas_used() != result.gas_used {
+        return Err(ConsensusError::BlockGasUsed {
+            gas: GotExpected { got: result.gas_used, expected: block.header().gas_used() },
+            gas_spent_by_tx: gas_spent_by_transactions(&result.receipts),
+        })
+    }
+    Ok(())
+}
+
 fn execution_checkpoint<N: NodePrimitives>(
     provider: &StaticFileProvider<N>,
     start_block: BlockNumber,
@@ -659,7 +1048,8 @@ fn calculate_gas_used_from_headers<N: NodePrimitives>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::TestStageDB;
+    use crate::{stages::ReexecutionOutcome, test_utils::TestStageDB};
+    use alloy_consensus::constants::EMPTY_ROOT_HASH;
     use alloy_primitives::{address, hex_literal::hex, keccak256, Address, B256, U256};
     use alloy_rlp::Decodable;
     use assert_matches::assert_matches;
@@ -674,8 +1064,9 @@ mod tests {
     use reth_evm_ethereum::EthEvmConfig;
     use reth_primitives_traits::{Account, Bytecode, SealedBlock, StorageEntry};
     use reth_provider::{
-        test_utils::create_test_provider_factory, AccountReader, DatabaseProviderFactory,
-        ReceiptProvider, StaticFileProviderFactory,
+        test_utils::{create_test_provider_factory, MockNodeTypesWithDB},
+        AccountReader, ChangeSetReader, DatabaseProviderFactory, ProviderFactory, ReceiptProvider,
+        StaticFileProviderFactory,
     };
     use reth_prune::PruneModes;
     use reth_prune_types::{PruneMode, ReceiptsLogPruneConfig};
@@ -703,6 +1094,20 @@ mod tests {
         )
     }
 
+    #[test]
+    fn verification_sampling() {
+        let all = ExecutionVerificationSampling::default();
+        assert!((0..100).all(|block| all.should_verify(block, 1_000_000)));
+
+        let sampling = ExecutionVerificationSampling { interval: 100, tip_distance: 1_000 };
+        assert!(sampling.should_verify(0, 1_000_000));
+        assert!(!sampling.should_verify(1, 1_000_000));
+        assert!(!sampling.should_verify(99, 1_000_000));
+        assert!(sampling.should_verify(200, 1_000_000));
+        assert!(!sampling.should_verify(998_999, 1_000_000));
+        assert!(sampling.should_verify(999_001, 1_000_000));
+    }
+
     #[test]
     fn execution_checkpoint_matches() {
         let factory = create_test_provider_factory();
@@ -985,6 +1390,263 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn forensic_report_of_receipts_root_mismatch() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+        let mut genesis_rlp = hex!("f901faf901f5a00000000000000000000000000000000000000000000000000000000000000000a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942adc25665018aa1fe0e6bc666dac8fc2697ff9baa045571b40ae66ca7480791bbb2887286e4e4c4b1b298b191c889d6959023a32eda056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000083020000808502540be400808000a00000000000000000000000000000000000000000000000000000000000000000880000000000000000c0c0").as_slice();
+        let genesis = SealedBlock::<Block>::decode(&mut genesis_rlp).unwrap();
+        let mut block_rlp = hex!("f90262f901f9a075c371ba45999d87f4542326910a11af515897aebce5265d3f6acd1f1161f82fa01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942adc25665018aa1fe0e6bc666dac8fc2697ff9baa098f2dcd87c8ae4083e7017a05456c14eea4b1db2032126e27b3b1563d57d7cc0a08151d548273f6683169524b66ca9fe338b9ce42bc3540046c828fd939ae23bcba03f4e5c2ec5b2170b711d97ee755c160457bb58d8daa338e835ec02ae6860bbabb901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000083020000018502540be40082a8798203e800a00000000000000000000000000000000000000000000000000000000000000000880000000000000000f863f861800a8405f5e10094100000000000000000000000000000000000000080801ba07e09e26678ed4fac08a249ebe8ed680bf9051a5e14ad223e4b2b9d26e0208f37a05f6e3f188e3e6eab7d7d3b6568f5eac7d687b08d307d3154ccd8c87b4630509bc0").as_slice();
+        let mut block = SealedBlock::<Block>::decode(&mut block_rlp).unwrap().unseal();
+        let receipts_root = block.header.receipts_root;
+        block.header.receipts_root = B256::random();
+        let block = SealedBlock::seal_slow(block);
+        provider.insert_historical_block(genesis.try_recover().unwrap()).unwrap();
+        provider.insert_historical_block(block.clone().try_recover().unwrap()).unwrap();
+        provider
+            .static_file_provider()
+            .latest_writer(StaticFileSegment::Headers)
+            .unwrap()
+            .commit()
+            .unwrap();
+        {
+            let static_file_provider = provider.static_file_provider();
+            let mut receipts_writer =
+                static_file_provider.latest_writer(StaticFileSegment::Receipts).unwrap();
+            receipts_writer.increment_block(0).unwrap();
+            receipts_writer.commit().unwrap();
+        }
+        provider.commit().unwrap();
+
+        // insert pre state
+        let provider = factory.provider_rw().unwrap();
+        let code = hex!("5a465a905090036002900360015500");
+        let code_hash = keccak256(code);
+        provider
+            .tx_ref()
+            .put::<tables::PlainAccountState>(
+                address!("0x1000000000000000000000000000000000000000"),
+                Account { nonce: 0, balance: U256::ZERO, bytecode_hash: Some(code_hash) },
+            )
+            .unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::PlainAccountState>(
+                address!("0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b"),
+                Account {
+                    nonce: 0,
+                    balance: U256::from(0x3635c9adc5dea00000u128),
+                    bytecode_hash: None,
+                },
+            )
+            .unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::Bytecodes>(code_hash, Bytecode::new_raw(code.to_vec().into()))
+            .unwrap();
+        provider.commit().unwrap();
+
+        // the mismatch is still reported as a validation error
+        let provider = factory.database_provider_rw().unwrap();
+        let mut execution_stage = stage();
+        let input = ExecInput { target: Some(1), checkpoint: None };
+        assert_matches!(
+            execution_stage.execute(&provider, input),
+            Err(StageError::Block {
+                error: BlockErrorKind::Validation(ConsensusError::BodyReceiptRootDiff(diff)),
+                ..
+            }) if diff.expected == block.receipts_root && diff.got == receipts_root
+        );
+
+        // re-executing the block on top of its parent state reproduces the receipts
+        let block = block.try_recover().unwrap();
+        let mut executor = execution_stage
+            .executor_provider
+            .executor(StateProviderDatabase(LatestStateProviderRef::new(&provider)));
+        let result = executor.execute_one(&block).unwrap();
+        let error =
+            execution_stage.consensus.validate_block_post_execution(&block, &result).unwrap_err();
+        assert!(is_execution_output_mismatch(&error));
+
+        let mut state = executor.into_state();
+        let bundle = state.take_bundle();
+        let report =
+            execution_stage.forensic_report(state.database, bundle, &block, &result, &error);
+        assert_eq!(report.reexecution, ReexecutionOutcome::Matched);
+        assert_eq!(report.gas_used, block.gas_used);
+        assert_eq!(report.transactions.len(), 1);
+        assert_eq!(report.transactions[0].gas_used, block.gas_used);
+        assert!(report.transactions[0].success);
+        assert!(report.transactions[0].touched_accounts.is_some());
+    }
+
+    /// Inserts the genesis and the block of the execution tests, followed by the given blocks, and
+    /// the pre state of the block's sender and called contract.
+    ///
+    /// Returns the block of the execution tests.
+    fn insert_test_chain(
+        factory: &ProviderFactory<MockNodeTypesWithDB>,
+        modify_block: impl FnOnce(&mut Block),
+        later_blocks: impl FnOnce(&Block) -> Vec<Block>,
+    ) -> SealedBlock<Block> {
+        let mut genesis_rlp = hex!("f901faf901f5a00000000000000000000000000000000000000000000000000000000000000000a01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942adc25665018aa1fe0e6bc666dac8fc2697ff9baa045571b40ae66ca7480791bbb2887286e4e4c4b1b298b191c889d6959023a32eda056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421a056e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421b901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000083020000808502540be400808000a00000000000000000000000000000000000000000000000000000000000000000880000000000000000c0c0").as_slice();
+        let genesis = SealedBlock::<Block>::decode(&mut genesis_rlp).unwrap();
+        let mut block_rlp = hex!("f90262f901f9a075c371ba45999d87f4542326910a11af515897aebce5265d3f6acd1f1161f82fa01dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347942adc25665018aa1fe0e6bc666dac8fc2697ff9baa098f2dcd87c8ae4083e7017a05456c14eea4b1db2032126e27b3b1563d57d7cc0a08151d548273f6683169524b66ca9fe338b9ce42bc3540046c828fd939ae23bcba03f4e5c2ec5b2170b711d97ee755c160457bb58d8daa338e835ec02ae6860bbabb901000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000083020000018502540be40082a8798203e800a00000000000000000000000000000000000000000000000000000000000000000880000000000000000f863f861800a8405f5e10094100000000000000000000000000000000000000080801ba07e09e26678ed4fac08a249ebe8ed680bf9051a5e14ad223e4b2b9d26e0208f37a05f6e3f188e3e6eab7d7d3b6568f5eac7d687b08d307d3154ccd8c87b4630509bc0").as_slice();
+        let mut block = SealedBlock::<Block>::decode(&mut block_rlp).unwrap().unseal();
+        modify_block(&mut block);
+        let later_blocks = later_blocks(&block);
+        let block = SealedBlock::seal_slow(block);
+
+        let provider = factory.provider_rw().unwrap();
+        provider.insert_historical_block(genesis.try_recover().unwrap()).unwrap();
+        provider.insert_historical_block(block.clone().try_recover().unwrap()).unwrap();
+        for later_block in later_blocks {
+            provider
+                .insert_historical_block(SealedBlock::seal_slow(later_block).try_recover().unwrap())
+                .unwrap();
+        }
+        provider
+            .static_file_provider()
+            .latest_writer(StaticFileSegment::Headers)
+            .unwrap()
+            .commit()
+            .unwrap();
+        {
+            let static_file_provider = provider.static_file_provider();
+            let mut receipts_writer =
+                static_file_provider.latest_writer(StaticFileSegment::Receipts).unwrap();
+            receipts_writer.increment_block(0).unwrap();
+            receipts_writer.commit().unwrap();
+        }
+
+        let code = hex!("5a465a905090036002900360015500");
+        let code_hash = keccak256(code);
+        provider
+            .tx_ref()
+            .put::<tables::PlainAccountState>(
+                address!("0x1000000000000000000000000000000000000000"),
+                Account { nonce: 0, balance: U256::ZERO, bytecode_hash: Some(code_hash) },
+            )
+            .unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::PlainAccountState>(
+                address!("0xa94f5374fce5edbc8e2a8697c15331677e6ebf0b"),
+                Account {
+                    nonce: 0,
+                    balance: U256::from(0x3635c9adc5dea00000u128),
+                    bytecode_hash: None,
+                },
+            )
+            .unwrap();
+        provider
+            .tx_ref()
+            .put::<tables::Bytecodes>(code_hash, Bytecode::new_raw(code.to_vec().into()))
+            .unwrap();
+        provider.commit().unwrap();
+
+        block
+    }
+
+    /// Returns a child of the block that contains the same transaction, which fails to execute
+    /// because its nonce was already used.
+    fn replaying_child(block: &Block) -> Block {
+        let mut child = block.clone();
+        child.header.number = block.header.number + 1;
+        child.header.parent_hash = block.header.hash_slow();
+        child
+    }
+
+    #[tokio::test]
+    async fn pipelined_validation_of_consecutive_blocks() {
+        let factory = create_test_provider_factory();
+        let block = insert_test_chain(
+            &factory,
+            |_| {},
+            |block| {
+                // an empty child, which only credits the block reward
+                let mut child = replaying_child(block);
+                child.body.transactions.clear();
+                child.header.gas_used = 0;
+                child.header.receipts_root = EMPTY_ROOT_HASH;
+                child.header.logs_bloom = Default::default();
+                vec![child]
+            },
+        );
+
+        let provider = factory.database_provider_rw().unwrap();
+        let output =
+            stage().execute(&provider, ExecInput { target: Some(2), checkpoint: None }).unwrap();
+        assert_eq!(output.checkpoint.block_number, 2);
+        assert!(output.done);
+
+        assert_eq!(provider.receipts_by_block(1.into()).unwrap().unwrap().len(), 1);
+        assert_eq!(provider.receipts_by_block(2.into()).unwrap().unwrap().len(), 0);
+        // the changesets were streamed block by block
+        for block_number in 1..=2 {
+            assert!(provider
+                .account_block_changeset(block_number)
+                .unwrap()
+                .iter()
+                .any(|change| change.address == block.beneficiary));
+        }
+        assert_eq!(
+            provider.basic_account(&block.beneficiary).unwrap().map(|account| account.balance),
+            // the fees of the transaction and two block rewards
+            Some(U256::from(0x1bc16d674ece94bau128) + U256::from(2_000_000_000_000_000_000u128))
+        );
+    }
+
+    #[tokio::test]
+    async fn pipelined_validation_reports_invalid_block_before_next_execution_error() {
+        let factory = create_test_provider_factory();
+        let mut receipts_root = B256::ZERO;
+        let block = insert_test_chain(
+            &factory,
+            |block| {
+                receipts_root = block.header.receipts_root;
+                block.header.receipts_root = B256::random();
+            },
+            |block| vec![replaying_child(block)],
+        );
+
+        // the child fails to execute while the block is validated, but the invalid block is
+        // reported
+        let provider = factory.database_provider_rw().unwrap();
+        assert_matches!(
+            stage().execute(&provider, ExecInput { target: Some(2), checkpoint: None }),
+            Err(StageError::Block {
+                block: bad_block,
+                error: BlockErrorKind::Validation(ConsensusError::BodyReceiptRootDiff(diff)),
+            }) if bad_block.block.number == 1 &&
+                diff.expected == block.receipts_root && diff.got == receipts_root
+        );
+    }
+
+    #[tokio::test]
+    async fn pipelined_validation_reports_execution_error_of_next_block() {
+        let factory = create_test_provider_factory();
+        insert_test_chain(&factory, |_| {}, |block| vec![replaying_child(block)]);
+
+        let provider = factory.database_provider_rw().unwrap();
+        assert_matches!(
+            stage().execute(&provider, ExecInput { target: Some(2), checkpoint: None }),
+            Err(StageError::Block { block, error: BlockErrorKind::Execution(_) })
+                if block.block.number == 2
+        );
+
+        // the receipt of the first block was streamed into static files, and pruned again
+        let static_file_provider = factory.static_file_provider();
+        assert_eq!(
+            static_file_provider.get_highest_static_file_tx(StaticFileSegment::Receipts),
+            None
+        );
+        assert_eq!(
+            static_file_provider.get_highest_static_file_block(StaticFileSegment::Receipts),
+            Some(0)
+        );
+    }
+
     #[tokio::test]
     async fn sanity_execute_unwind() {
         let factory = create_test_provider_factory();
@@ -0,0 +1,237 @@
+use alloy_consensus::{BlockHeader, TxReceipt};
+use alloy_eips::{eip1898::BlockWithParent, Typed2718};
+use alloy_primitives::TxHash;
+use reth_consensus::ConsensusError;
+use reth_primitives_traits::{Block, BlockBody, RecoveredBlock, SignedTransaction};
+use std::fmt;
+
+/// Returns `true` if the error indicates that the execution output of a block does not match its
+/// header, i.e. the receipts root, logs bloom or gas used differ.
+pub const fn is_execution_output_mismatch(error: &ConsensusError) -> bool {
+    matches!(
+        error,
+        ConsensusError::BodyReceiptRootDiff(_) |
+            ConsensusError::BodyBloomLogDiff(_) |
+            ConsensusError::BlockGasUsed { .. }
+    )
+}
+
+/// The execution details of a single transaction, derived from its receipt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionForensics {
+    /// Index of the transaction in the block.
+    pub index: usize,
+    /// Hash of the transaction.
+    pub hash: TxHash,
+    /// EIP-2718 type of the transaction.
+    pub tx_type: u8,
+    /// Whether the transaction succeeded.
+    pub success: bool,
+    /// Gas used by the transaction.
+    pub gas_used: u64,
+    /// Cumulative gas used in the block up to and including the transaction.
+    pub cumulative_gas_used: u64,
+    /// Number of logs emitted by the transaction.
+    pub logs: usize,
+    /// Number of accounts touched by the transaction, if captured.
+    pub touched_accounts: Option<usize>,
+}
+
+/// The outcome of re-executing a block for a [`BlockForensicReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReexecutionOutcome {
+    /// Re-execution produced the same receipts as the original execution.
+    Matched,
+    /// Re-execution produced different receipts than the original execution, which points at
+    /// non-deterministic execution.
+    Diverged,
+    /// Re-execution failed with the given error.
+    Failed(String),
+}
+
+/// A detailed report of a block whose execution output does not match its header.
+///
+/// This is created by re-executing the offending block on top of its parent state and capturing
+/// the receipt fields of every transaction, so that consensus bugs can be narrowed down to
+/// individual transactions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockForensicReport {
+    /// The offending block.
+    pub block: BlockWithParent,
+    /// The validation error of the block.
+    pub error: String,
+    /// Gas used according to the block header.
+    pub header_gas_used: u64,
+    /// Gas used according to the execution.
+    pub gas_used: u64,
+    /// The outcome of re-executing the block.
+    pub reexecution: ReexecutionOutcome,
+    /// Execution details of all transactions in the block.
+    ///
+    /// These are taken from the re-execution if it succeeded, otherwise from the original
+    /// execution.
+    pub transactions: Vec<TransactionForensics>,
+}
+
+impl BlockForensicReport {
+    /// Creates a new report for the block.
+    ///
+    /// `receipts` are the receipts of the original execution. `reexecuted` holds the receipts of
+    /// the re-execution and the number of accounts touched by each transaction.
+    pub fn new<B, R>(
+        block: &RecoveredBlock<B>,
+        error: &ConsensusError,
+        receipts: &[R],
+        reexecuted: Result<(Vec<R>, Vec<usize>), String>,
+    ) -> Self
+    where
+        B: Block,
+        R: TxReceipt + PartialEq,
+    {
+        let (reexecution, receipts, touched_accounts) = match &reexecuted {
+            Ok((reexecuted, touched)) if reexecuted.as_slice() == receipts => {
+                (ReexecutionOutcome::Matched, reexecuted.as_slice(), touched.as_slice())
+            }
+            Ok((reexecuted, touched)) => {
+                (ReexecutionOutcome::Diverged, reexecuted.as_slice(), touched.as_slice())
+            }
+            Err(err) => (ReexecutionOutcome::Failed(err.clone()), receipts, [].as_slice()),
+        };
+
+        let mut previous_cumulative_gas_used = 0;
+        let transactions = block
+            .body()
+            .transactions()
+            .iter()
+            .zip(receipts)
+            .enumerate()
+            .map(|(index, (tx, receipt))| {
+                let cumulative_gas_used = receipt.cumulative_gas_used();
+                let gas_used = cumulative_gas_used.saturating_sub(previous_cumulative_gas_used);
+                previous_cumulative_gas_used = cumulative_gas_used;
+                TransactionForensics {
+                    index,
+                    hash: *tx.tx_hash(),
+                    tx_type: tx.ty(),
+                    success: receipt.status(),
+                    gas_used,
+                    cumulative_gas_used,
+                    logs: receipt.logs().len(),
+                    touched_accounts: touched_accounts.get(index).copied(),
+                }
+            })
+            .collect();
+
+        Self {
+            block: block.block_with_parent(),
+            error: error.to_string(),
+            header_gas_used: block.header().gas_used(),
+            gas_used: previous_cumulative_gas_used,
+            reexecution,
+            transactions,
+        }
+    }
+}
+
+impl fmt::Display for BlockForensicReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "forensic report for block #{} ({})",
+            self.block.block.number, self.block.block.hash
+        )?;
+        writeln!(f, "  parent: {}", self.block.parent)?;
+        writeln!(f, "  error: {}", self.error)?;
+        writeln!(f, "  gas used: {} (header: {})", self.gas_used, self.header_gas_used)?;
+        match &self.reexecution {
+            ReexecutionOutcome::Matched => writeln!(f, "  re-execution: matched")?,
+            ReexecutionOutcome::Diverged => {
+                writeln!(f, "  re-execution: diverged from original execution")?
+            }
+            ReexecutionOutcome::Failed(err) => writeln!(f, "  re-execution: failed: {err}")?,
+        }
+        writeln!(f, "  transactions:")?;
+        for tx in &self.transactions {
+            write!(
+                f,
+                "    #{} {} type={} success={} gas_used={} cumulative_gas_used={} logs={}",
+                tx.index,
+                tx.hash,
+                tx.tx_type,
+                tx.success,
+                tx.gas_used,
+                tx.cumulative_gas_used,
+                tx.logs,
+            )?;
+            if let Some(touched_accounts) = tx.touched_accounts {
+                write!(f, " touched_accounts={touched_accounts}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::TxType;
+    use alloy_primitives::{Log, B256};
+    use reth_ethereum_primitives::{Block, Receipt};
+    use reth_primitives_traits::GotExpected;
+    use reth_testing_utils::generators::{self, random_block, BlockParams};
+
+    #[test]
+    fn report_from_receipts() {
+        let mut rng = generators::rng();
+        let block =
+            random_block(&mut rng, 1, BlockParams { tx_count: Some(2), ..Default::default() })
+                .try_recover()
+                .unwrap();
+        let error = ConsensusError::BodyReceiptRootDiff(
+            GotExpected { got: B256::random(), expected: B256::random() }.into(),
+        );
+        let receipts = vec![
+            Receipt {
+                tx_type: TxType::Legacy,
+                success: true,
+                cumulative_gas_used: 21_000,
+                logs: vec![Log::default()],
+            },
+            Receipt {
+                tx_type: TxType::Eip1559,
+                success: false,
+                cumulative_gas_used: 50_000,
+                logs: vec![],
+            },
+        ];
+        assert!(is_execution_output_mismatch(&error));
+
+        let report: BlockForensicReport = BlockForensicReport::new::<Block, _>(
+            &block,
+            &error,
+            &receipts,
+            Ok((receipts.clone(), vec![3, 2])),
+        );
+        assert_eq!(report.reexecution, ReexecutionOutcome::Matched);
+        assert_eq!(report.gas_used, 50_000);
+        assert_eq!(report.transactions.len(), 2);
+        assert_eq!(report.transactions[0].gas_used, 21_000);
+        assert_eq!(report.transactions[0].logs, 1);
+        assert_eq!(report.transactions[1].gas_used, 29_000);
+        assert!(!report.transactions[1].success);
+        assert_eq!(report.transactions[1].touched_accounts, Some(2));
+        assert!(report.to_string().contains("re-execution: matched"));
+
+        let mut diverged = receipts.clone();
+        diverged[1].cumulative_gas_used = 60_000;
+        let report =
+            BlockForensicReport::new(&block, &error, &receipts, Ok((diverged, vec![3, 2])));
+        assert_eq!(report.reexecution, ReexecutionOutcome::Diverged);
+        assert_eq!(report.transactions[1].gas_used, 39_000);
+
+        let report = BlockForensicReport::new(&block, &error, &receipts, Err("boom".to_string()));
+        assert_eq!(report.reexecution, ReexecutionOutcome::Failed("boom".to_string()));
+        assert_eq!(report.transactions[1].touched_accounts, None);
+    }
+}
@@ -4,6 +4,8 @@ mod bodies;
 mod execution;
 /// The finish stage
 mod finish;
+/// Forensic reports of blocks whose execution output does not match their header.
+mod forensics;
 /// Account hashing stage.
 mod hashing_account;
 /// Storage hashing stage.
@@ -16,6 +18,7 @@ mod index_account_history;
 mod index_storage_history;
 /// Stage for computing state root.
 mod merkle;
+mod prefetch;
 mod prune;
 /// The s3 download stage
 mod s3;
@@ -27,12 +30,14 @@ mod tx_lookup;
 pub use bodies::*;
 pub use execution::*;
 pub use finish::*;
+pub use forensics::*;
 pub use hashing_account::*;
 pub use hashing_storage::*;
 pub use headers::*;
 pub use index_account_history::*;
 pub use index_storage_history::*;
 pub use merkle::*;
+pub use prefetch::*;
 pub use prune::*;
 pub use s3::*;
 pub use sender_recovery::*;
@@ -0,0 +1,152 @@
+use alloy_primitives::BlockNumber;
+use reth_primitives_traits::{Block, RecoveredBlock};
+use reth_provider::{BlockReader, DatabaseProviderFactory, ProviderResult, TransactionVariant};
+use std::{
+    fmt,
+    ops::RangeInclusive,
+    sync::{
+        mpsc::{sync_channel, Receiver},
+        Arc,
+    },
+};
+use tracing::debug;
+
+/// Reads a block with its senders by number.
+type ReadBlock<B> = dyn Fn(BlockNumber) -> ProviderResult<Option<RecoveredBlock<B>>> + Send + Sync;
+
+/// Reads and decodes the bodies and senders of the upcoming blocks on background threads, while
+/// the [`ExecutionStage`](super::ExecutionStage) executes the current block, so the executor
+/// doesn't stall on static file reads between blocks.
+///
+/// The blocks are read with read-only database transactions, so only blocks that were committed
+/// by the previous stages can be prefetched.
+pub struct BlockPrefetcher<B: Block> {
+    /// Reads a block from a new read-only transaction.
+    read_block: Arc<ReadBlock<B>>,
+    /// The number of blocks that are read ahead.
+    blocks: usize,
+    /// The number of threads that read blocks.
+    threads: usize,
+}
+
+impl<B: Block + 'static> BlockPrefetcher<B> {
+    /// Creates a prefetcher that reads up to `blocks` blocks ahead on `threads` threads from
+    /// providers of the factory.
+    pub fn new<F>(factory: F, blocks: usize, threads: usize) -> Self
+    where
+        F: DatabaseProviderFactory<Provider: BlockReader<Block = B>> + 'static,
+    {
+        let read_block = move |number: BlockNumber| {
+            factory
+                .database_provider_ro()?
+                .recovered_block(number.into(), TransactionVariant::NoHash)
+        };
+        Self { read_block: Arc::new(read_block), blocks: blocks.max(1), threads: threads.max(1) }
+    }
+
+    /// Starts reading the blocks of the range.
+    ///
+    /// The threads stop once the range is read or the returned [`PrefetchedBlocks`] is dropped.
+    pub fn spawn(&self, range: RangeInclusive<BlockNumber>) -> PrefetchedBlocks<B> {
+        let start = *range.start();
+        let end = *range.end();
+        let threads = self.threads;
+        let capacity = self.blocks.div_ceil(threads);
+
+        // Every thread reads every `threads`-th block, so the blocks can be received in order.
+        let receivers = (0..threads)
+            .map(|idx| {
+                let (tx, rx) = sync_channel(capacity);
+                let read_block = self.read_block.clone();
+                std::thread::Builder::new()
+                    .name(format!("block-prefetch-{idx}"))
+                    .spawn(move || {
+                        for number in (start + idx as u64..=end).step_by(threads) {
+                            if tx.send((number, read_block(number))).is_err() {
+                                // the stage is done with the range
+                                break
+                            }
+                        }
+                    })
+                    .expect("failed to spawn block prefetch thread");
+                rx
+            })
+            .collect();
+
+        PrefetchedBlocks { start, receivers }
+    }
+}
+
+impl<B: Block> fmt::Debug for BlockPrefetcher<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockPrefetcher")
+            .field("blocks", &self.blocks)
+            .field("threads", &self.threads)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The blocks of a range that are read ahead by a [`BlockPrefetcher`].
+#[derive(Debug)]
+pub struct PrefetchedBlocks<B: Block> {
+    /// The first block of the range.
+    start: BlockNumber,
+    /// The blocks read by each thread.
+    receivers: Vec<Receiver<(BlockNumber, ProviderResult<Option<RecoveredBlock<B>>>)>>,
+}
+
+impl<B: Block> PrefetchedBlocks<B> {
+    /// Returns the given block, waiting for it to be read if necessary.
+    ///
+    /// The blocks must be requested in order. Returns `None` if the block couldn't be read, in
+    /// which case it should be read directly.
+    pub fn next_block(&mut self, number: BlockNumber) -> Option<RecoveredBlock<B>> {
+        let idx = ((number - self.start) % self.receivers.len() as u64) as usize;
+        match self.receivers[idx].recv() {
+            Ok((prefetched, Ok(Some(block)))) if prefetched == number => Some(block),
+            Ok((prefetched, Err(err))) => {
+                debug!(target: "sync::stages::execution", block = prefetched, %err, "Failed to prefetch block");
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{StorageKind, TestStageDB};
+    use alloy_primitives::B256;
+    use reth_primitives_traits::SignedTransaction;
+    use reth_testing_utils::generators::{self, random_block_range, BlockRangeParams};
+
+    #[test]
+    fn prefetch_blocks_in_order() {
+        let db = TestStageDB::default();
+        let mut rng = generators::rng();
+        let blocks = random_block_range(
+            &mut rng,
+            0..=20,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 0..4, ..Default::default() },
+        );
+        db.insert_blocks(blocks.iter(), StorageKind::Static).unwrap();
+        db.insert_transaction_senders(
+            blocks
+                .iter()
+                .flat_map(|block| block.body().transactions.iter())
+                .enumerate()
+                .map(|(tx_num, tx)| (tx_num as u64, tx.recover_signer().unwrap())),
+        )
+        .unwrap();
+
+        let prefetcher = BlockPrefetcher::new(db.factory.clone(), 4, 3);
+        let mut prefetched = prefetcher.spawn(0..=20);
+        let provider = db.factory.provider().unwrap();
+        for number in 0..=20 {
+            let expected =
+                provider.recovered_block(number.into(), TransactionVariant::NoHash).unwrap();
+            assert_eq!(prefetched.next_block(number), expected);
+        }
+    }
+}
@@ -9,7 +9,9 @@ use reth_db_api::{
     transaction::{DbTx, DbTxMut},
     DbTxUnwindExt, RawValue,
 };
-use reth_primitives_traits::{GotExpected, NodePrimitives, SignedTransaction};
+use reth_primitives_traits::{
+    crypto::secp256k1::recover_signers_unchecked, GotExpected, NodePrimitives, SignedTransaction,
+};
 use reth_provider::{
     BlockReader, DBProvider, HeaderProvider, ProviderError, PruneCheckpointReader,
     StaticFileProviderFactory, StatsReader,
@@ -24,16 +26,15 @@ use std::{fmt::Debug, ops::Range, sync::mpsc};
 use thiserror::Error;
 use tracing::*;
 
-/// Maximum amount of transactions to read from disk at one time before we flush their senders to
-/// disk. Since each rayon worker will hold at most 100 transactions (`WORKER_CHUNK_SIZE`), we
-/// effectively max limit each batch to 1000 channels in memory.
-const BATCH_SIZE: usize = 100_000;
-
 /// Maximum number of senders to recover per rayon worker job.
 const WORKER_CHUNK_SIZE: usize = 100;
 
-/// Type alias for a sender that transmits the result of sender recovery.
-type RecoveryResultSender = mpsc::Sender<Result<(u64, Address), Box<SenderRecoveryStageError>>>;
+/// Maximum number of chunks per rayon thread that are read and recovered ahead of the chunk that
+/// is currently appended to the database.
+const MAX_INFLIGHT_CHUNKS_PER_THREAD: usize = 8;
+
+/// Type alias for the result of recovering the senders of a chunk of transactions.
+type RecoveryResult = Result<Vec<(TxNumber, Address)>, Box<SenderRecoveryStageError>>;
 
 /// The sender recovery stage iterates over existing transactions,
 /// recovers the transaction signer and stores them
@@ -99,18 +100,10 @@ where
 
         info!(target: "sync::stages::sender_recovery", ?tx_range, "Recovering senders");
 
-        // Iterate over transactions in batches, recover the senders and append them
-        let batch = tx_range
-            .clone()
-            .step_by(BATCH_SIZE)
-            .map(|start| start..std::cmp::min(start + BATCH_SIZE as u64, tx_range.end))
-            .collect::<Vec<Range<u64>>>();
-
-        let tx_batch_sender = setup_range_recovery(provider);
-
-        for range in batch {
-            recover_range(range, provider, tx_batch_sender.clone(), &mut senders_cursor)?;
-        }
+        // Stream the transactions to the rayon pool in chunks, and append the recovered senders
+        // in order as soon as their chunk is done
+        let recovered_chunks = spawn_range_recovery(provider, tx_range.clone());
+        recover_range(tx_range, provider, recovered_chunks, &mut senders_cursor)?;
 
         Ok(ExecOutput {
             checkpoint: StageCheckpoint::new(end_block)
@@ -144,76 +137,60 @@ where
 fn recover_range<Provider, CURSOR>(
     tx_range: Range<u64>,
     provider: &Provider,
-    tx_batch_sender: mpsc::Sender<Vec<(Range<u64>, RecoveryResultSender)>>,
+    recovered_chunks: mpsc::Receiver<mpsc::Receiver<RecoveryResult>>,
     senders_cursor: &mut CURSOR,
 ) -> Result<(), StageError>
 where
     Provider: DBProvider + HeaderProvider + StaticFileProviderFactory,
     CURSOR: DbCursorRW<tables::TransactionSenders>,
 {
-    debug!(target: "sync::stages::sender_recovery", ?tx_range, "Sending batch for processing");
-
-    // Preallocate channels for each chunks in the batch
-    let (chunks, receivers): (Vec<_>, Vec<_>) = tx_range
-        .clone()
-        .step_by(WORKER_CHUNK_SIZE)
-        .map(|start| {
-            let range = start..std::cmp::min(start + WORKER_CHUNK_SIZE as u64, tx_range.end);
-            let (tx, rx) = mpsc::channel();
-            // Range and channel sender will be sent to rayon worker
-            ((range, tx), rx)
-        })
-        .unzip();
-
-    if let Some(err) = tx_batch_sender.send(chunks).err() {
-        return Err(StageError::Fatal(err.into()));
-    }
-
     debug!(target: "sync::stages::sender_recovery", ?tx_range, "Appending recovered senders to the database");
 
     let mut processed_transactions = 0;
-    for channel in receivers {
-        while let Ok(recovered) = channel.recv() {
-            let (tx_id, sender) = match recovered {
-                Ok(result) => result,
-                Err(error) => {
-                    return match *error {
-                        SenderRecoveryStageError::FailedRecovery(err) => {
-                            // get the block number for the bad transaction
-                            let block_number = provider
-                                .tx_ref()
-                                .get::<tables::TransactionBlocks>(err.tx)?
-                                .ok_or(ProviderError::BlockNumberForTransactionIndexNotFound)?;
-
-                            // fetch the sealed header so we can use it in the sender recovery
-                            // unwind
-                            let sealed_header =
-                                provider.sealed_header(block_number)?.ok_or_else(|| {
-                                    ProviderError::HeaderNotFound(block_number.into())
-                                })?;
-
-                            Err(StageError::Block {
-                                block: Box::new(sealed_header.block_with_parent()),
-                                error: BlockErrorKind::Validation(
-                                    ConsensusError::TransactionSignerRecoveryError,
-                                ),
-                            })
-                        }
-                        SenderRecoveryStageError::StageError(err) => Err(err),
-                        SenderRecoveryStageError::RecoveredSendersMismatch(expectation) => {
-                            Err(StageError::Fatal(
-                                SenderRecoveryStageError::RecoveredSendersMismatch(expectation)
-                                    .into(),
-                            ))
-                        }
+    for chunk in recovered_chunks {
+        // The chunk was dropped without a result if its rayon job panicked, which is caught by the
+        // sender count check below.
+        let Ok(recovered) = chunk.recv() else { break };
+        let senders = match recovered {
+            Ok(senders) => senders,
+            Err(error) => {
+                return match *error {
+                    SenderRecoveryStageError::FailedRecovery(err) => {
+                        // get the block number for the bad transaction
+                        let block_number = provider
+                            .tx_ref()
+                            .get::<tables::TransactionBlocks>(err.tx)?
+                            .ok_or(ProviderError::BlockNumberForTransactionIndexNotFound)?;
+
+                        // fetch the sealed header so we can use it in the sender recovery
+                        // unwind
+                        let sealed_header = provider
+                            .sealed_header(block_number)?
+                            .ok_or_else(|| ProviderError::HeaderNotFound(block_number.into()))?;
+
+                        Err(StageError::Block {
+                            block: Box::new(sealed_header.block_with_parent()),
+                            error: BlockErrorKind::Validation(
+                                ConsensusError::TransactionSignerRecoveryError,
+                            ),
+                        })
+                    }
+                    SenderRecoveryStageError::StageError(err) => Err(err),
+                    SenderRecoveryStageError::RecoveredSendersMismatch(expectation) => {
+                        Err(StageError::Fatal(
+                            SenderRecoveryStageError::RecoveredSendersMismatch(expectation).into(),
+                        ))
                     }
                 }
-            };
-            senders_cursor.append(tx_id, &sender)?;
-            processed_transactions += 1;
+            }
+        };
+
+        for (tx_id, sender) in &senders {
+            senders_cursor.append(*tx_id, sender)?;
         }
+        processed_transactions += senders.len() as u64;
     }
-    debug!(target: "sync::stages::sender_recovery", ?tx_range, "Finished recovering senders batch");
+    debug!(target: "sync::stages::sender_recovery", ?tx_range, "Finished recovering senders");
 
     // Fail safe to ensure that we do not proceed without having recovered all senders.
     let expected = tx_range.end - tx_range.start;
@@ -229,18 +206,24 @@ where
     Ok(())
 }
 
-/// Spawns a thread to handle the recovery of transaction senders for
-/// specified chunks of a given batch. It processes incoming ranges, fetching and recovering
-/// transactions in parallel using global rayon pool
-fn setup_range_recovery<Provider>(
+/// Spawns a thread that streams the transactions of the given range from static files in chunks
+/// of [`WORKER_CHUNK_SIZE`], and recovers the senders of each chunk in parallel using the global
+/// rayon pool.
+///
+/// Returns a channel that yields a receiver for the recovered senders of each chunk, in the order
+/// of the chunks. The number of chunks in flight is bounded, so that reading from static files
+/// does not run ahead of appending the senders to the database.
+fn spawn_range_recovery<Provider>(
     provider: &Provider,
-) -> mpsc::Sender<Vec<(Range<u64>, RecoveryResultSender)>>
+    tx_range: Range<u64>,
+) -> mpsc::Receiver<mpsc::Receiver<RecoveryResult>>
 where
     Provider: DBProvider
         + HeaderProvider
         + StaticFileProviderFactory<Primitives: NodePrimitives<SignedTx: Value + SignedTransaction>>,
 {
-    let (tx_sender, tx_receiver) = mpsc::channel::<Vec<(Range<u64>, RecoveryResultSender)>>();
+    let (chunks_tx, chunks_rx) =
+        mpsc::sync_channel(rayon::current_num_threads() * MAX_INFLIGHT_CHUNKS_PER_THREAD);
     let static_file_provider = provider.static_file_provider();
 
     // We do not use `tokio::task::spawn_blocking` because, during a shutdown,
@@ -251,75 +234,91 @@ where
     // However, using `std::thread::spawn` allows us to utilize the timeout grace
     // period to complete some work without throwing errors during the shutdown.
     std::thread::spawn(move || {
-        while let Ok(chunks) = tx_receiver.recv() {
-            for (chunk_range, recovered_senders_tx) in chunks {
-                // Read the raw value, and let the rayon worker to decompress & decode.
-                let chunk = match static_file_provider.fetch_range_with_predicate(
-                    StaticFileSegment::Transactions,
-                    chunk_range.clone(),
-                    |cursor, number| {
-                        Ok(cursor
-                            .get_one::<TransactionMask<
-                                RawValue<<Provider::Primitives as NodePrimitives>::SignedTx>,
-                            >>(number.into())?
-                            .map(|tx| (number, tx)))
-                    },
-                    |_| true,
-                ) {
-                    Ok(chunk) => chunk,
-                    Err(err) => {
-                        // We exit early since we could not process this chunk.
-                        let _ = recovered_senders_tx
-                            .send(Err(Box::new(SenderRecoveryStageError::StageError(err.into()))));
-                        break
-                    }
-                };
-
-                // Spawn the task onto the global rayon pool
-                // This task will send the results through the channel after it has read the
-                // transaction and calculated the sender.
-                rayon::spawn(move || {
-                    let mut rlp_buf = Vec::with_capacity(128);
-                    for (number, tx) in chunk {
-                        let res = tx
-                            .value()
-                            .map_err(|err| {
-                                Box::new(SenderRecoveryStageError::StageError(err.into()))
-                            })
-                            .and_then(|tx| recover_sender((number, tx), &mut rlp_buf));
-
-                        let is_err = res.is_err();
-
-                        let _ = recovered_senders_tx.send(res);
-
-                        // Finish early
-                        if is_err {
-                            break
-                        }
-                    }
-                });
+        for start in tx_range.clone().step_by(WORKER_CHUNK_SIZE) {
+            let chunk_range = start..std::cmp::min(start + WORKER_CHUNK_SIZE as u64, tx_range.end);
+            let (recovered_senders_tx, recovered_senders_rx) = mpsc::sync_channel(1);
+
+            // Blocks while too many chunks are in flight, and stops if the receiving side is gone,
+            // e.g. because a previous chunk failed.
+            if chunks_tx.send(recovered_senders_rx).is_err() {
+                break
             }
+
+            // Read the raw value, and let the rayon worker to decompress & decode.
+            let chunk = match static_file_provider.fetch_range_with_predicate(
+                StaticFileSegment::Transactions,
+                chunk_range,
+                |cursor, number| {
+                    Ok(cursor
+                        .get_one::<TransactionMask<
+                            RawValue<<Provider::Primitives as NodePrimitives>::SignedTx>,
+                        >>(number.into())?
+                        .map(|tx| (number, tx)))
+                },
+                |_| true,
+            ) {
+                Ok(chunk) => chunk,
+                Err(err) => {
+                    // We exit early since we could not process this chunk.
+                    let _ = recovered_senders_tx
+                        .send(Err(Box::new(SenderRecoveryStageError::StageError(err.into()))));
+                    break
+                }
+            };
+
+            // Spawn the task onto the global rayon pool
+            // This task will send the recovered senders of the whole chunk through the channel
+            // after it has decoded the transactions and recovered their senders in one batch.
+            rayon::spawn(move || {
+                let _ = recovered_senders_tx.send(recover_chunk(chunk));
+            });
         }
     });
-    tx_sender
+    chunks_rx
 }
 
-#[inline]
-fn recover_sender<T: SignedTransaction>(
-    (tx_id, tx): (TxNumber, T),
-    rlp_buf: &mut Vec<u8>,
-) -> Result<(u64, Address), Box<SenderRecoveryStageError>> {
-    rlp_buf.clear();
-    // We call [Signature::encode_and_recover_unchecked] because transactions run in the pipeline
-    // are known to be valid - this means that we do not need to check whether or not the `s`
-    // value is greater than `secp256k1n / 2` if past EIP-2. There are transactions
-    // pre-homestead which have large `s` values, so using [Signature::recover_signer] here
-    // would not be backwards-compatible.
-    let sender = tx.recover_signer_unchecked_with_buf(rlp_buf).map_err(|_| {
-        SenderRecoveryStageError::FailedRecovery(FailedSenderRecoveryError { tx: tx_id })
-    })?;
-
-    Ok((tx_id, sender))
+/// Recovers the senders of a chunk of raw transactions in one batch.
+///
+/// The transactions are decoded and their signature hashes are computed first, then the signatures
+/// of the whole chunk are recovered together. Transactions without a signature, e.g. deposit
+/// transactions, are recovered on their own.
+fn recover_chunk<T: Value + SignedTransaction>(
+    chunk: Vec<(TxNumber, RawValue<T>)>,
+) -> RecoveryResult {
+    let mut rlp_buf = Vec::with_capacity(128);
+    let mut transactions = Vec::with_capacity(chunk.len());
+    for (tx_id, tx) in chunk {
+        let tx =
+            tx.value().map_err(|err| Box::new(SenderRecoveryStageError::StageError(err.into())))?;
+        let signature_hash = tx.signature_hash_with_buf(&mut rlp_buf);
+        transactions.push((tx_id, tx, signature_hash));
+    }
+
+    // We recover the signers unchecked because transactions run in the pipeline are known to be
+    // valid - this means that we do not need to check whether or not the `s` value is greater
+    // than `secp256k1n / 2` if past EIP-2. There are transactions pre-homestead which have large
+    // `s` values, so checking the `s` value here would not be backwards-compatible.
+    let mut signers = recover_signers_unchecked(
+        transactions
+            .iter()
+            .filter_map(|(_, tx, signature_hash)| Some((tx.signature(), (*signature_hash)?))),
+    )
+    .into_iter();
+
+    transactions
+        .iter()
+        .map(|(tx_id, tx, signature_hash)| {
+            let sender = match signature_hash {
+                Some(_) => signers.next().expect("a signer is recovered per signature hash"),
+                None => tx.recover_signer_unchecked(),
+            };
+            sender.map(|sender| (*tx_id, sender)).map_err(|_| {
+                Box::new(SenderRecoveryStageError::FailedRecovery(FailedSenderRecoveryError {
+                    tx: *tx_id,
+                }))
+            })
+        })
+        .collect()
 }
 
 fn stage_checkpoint<Provider>(provider: &Provider) -> Result<EntitiesCheckpoint, StageError>
@@ -520,6 +519,46 @@ mod tests {
         assert!(runner.validate_execution(first_input, result.ok()).is_ok(), "validation failed");
     }
 
+    /// Execute a range whose transactions span many recovery chunks
+    #[tokio::test]
+    async fn execute_multiple_chunks() {
+        let mut rng = generators::rng();
+
+        let mut runner = SenderRecoveryTestRunner::default();
+        runner.set_threshold(u64::MAX);
+        let (stage_progress, previous_stage) = (0, 200);
+        let seed = random_block_range(
+            &mut rng,
+            stage_progress + 1..=previous_stage,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 5..10, ..Default::default() },
+        );
+        runner
+            .db
+            .insert_blocks(seed.iter(), StorageKind::Static)
+            .expect("failed to seed execution");
+
+        let total_transactions =
+            seed.iter().map(|block| block.transaction_count() as u64).sum::<u64>();
+        assert!(total_transactions > 10 * WORKER_CHUNK_SIZE as u64);
+
+        let input = ExecInput {
+            target: Some(previous_stage),
+            checkpoint: Some(StageCheckpoint::new(stage_progress)),
+        };
+        let result = runner.execute(input).await.unwrap();
+        assert_eq!(
+            result.as_ref().unwrap(),
+            &ExecOutput {
+                checkpoint: StageCheckpoint::new(previous_stage).with_entities_stage_checkpoint(
+                    EntitiesCheckpoint { processed: total_transactions, total: total_transactions }
+                ),
+                done: true
+            }
+        );
+
+        assert!(runner.validate_execution(input, result.ok()).is_ok(), "validation failed");
+    }
+
     #[test]
     fn stage_checkpoint_pruned() {
         let db = TestStageDB::default();
@@ -0,0 +1,273 @@
+//! [`Database`] implementation that is one of two databases, e.g. to select the storage backend
+//! at runtime.
+
+use crate::{
+    common::{IterPairResult, PairResult, ValueOnlyResult},
+    cursor::{
+        DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW, DupWalker, RangeWalker,
+        ReverseWalker, Walker,
+    },
+    database::Database,
+    database_metrics::DatabaseMetrics,
+    table::{DupSort, Encode, Table, TableImporter},
+    transaction::{DbTx, DbTxMut},
+    DatabaseError,
+};
+use metrics::Label;
+use std::ops::{Bound, RangeBounds};
+
+/// An enum type that can hold either of two different database types.
+///
+/// The transactions and cursors of the database are of the same variant as the database.
+#[derive(Debug, Clone)]
+pub enum Either<L, R> {
+    /// A value of type `L`.
+    Left(L),
+    /// A value of type `R`.
+    Right(R),
+}
+
+/// Calls the same expression on the value of either variant.
+macro_rules! either {
+    ($value:expr, $inner:pat => $expr:expr) => {
+        match $value {
+            Either::Left($inner) => $expr,
+            Either::Right($inner) => $expr,
+        }
+    };
+    ($value:expr, $inner:pat => $expr:expr, wrap) => {
+        match $value {
+            Either::Left($inner) => $expr.map(Either::Left),
+            Either::Right($inner) => $expr.map(Either::Right),
+        }
+    };
+}
+
+impl<L: Database, R: Database> Database for Either<L, R> {
+    type TX = Either<L::TX, R::TX>;
+    type TXMut = Either<L::TXMut, R::TXMut>;
+
+    fn tx(&self) -> Result<Self::TX, DatabaseError> {
+        either!(self, db => db.tx(), wrap)
+    }
+
+    fn tx_mut(&self) -> Result<Self::TXMut, DatabaseError> {
+        either!(self, db => db.tx_mut(), wrap)
+    }
+}
+
+impl<L: DatabaseMetrics, R: DatabaseMetrics> DatabaseMetrics for Either<L, R> {
+    fn report_metrics(&self) {
+        either!(self, db => db.report_metrics())
+    }
+
+    fn gauge_metrics(&self) -> Vec<(&'static str, f64, Vec<Label>)> {
+        either!(self, db => db.gauge_metrics())
+    }
+
+    fn counter_metrics(&self) -> Vec<(&'static str, u64, Vec<Label>)> {
+        either!(self, db => db.counter_metrics())
+    }
+
+    fn histogram_metrics(&self) -> Vec<(&'static str, f64, Vec<Label>)> {
+        either!(self, db => db.histogram_metrics())
+    }
+}
+
+impl<L: DbTx, R: DbTx> DbTx for Either<L, R> {
+    type Cursor<T: Table> = Either<L::Cursor<T>, R::Cursor<T>>;
+    type DupCursor<T: DupSort> = Either<L::DupCursor<T>, R::DupCursor<T>>;
+
+    fn get<T: Table>(&self, key: T::Key) -> Result<Option<T::Value>, DatabaseError> {
+        either!(self, tx => tx.get::<T>(key))
+    }
+
+    fn get_by_encoded_key<T: Table>(
+        &self,
+        key: &<T::Key as Encode>::Encoded,
+    ) -> Result<Option<T::Value>, DatabaseError> {
+        either!(self, tx => tx.get_by_encoded_key::<T>(key))
+    }
+
+    fn commit(self) -> Result<bool, DatabaseError> {
+        either!(self, tx => tx.commit())
+    }
+
+    fn abort(self) {
+        either!(self, tx => tx.abort())
+    }
+
+    fn cursor_read<T: Table>(&self) -> Result<Self::Cursor<T>, DatabaseError> {
+        either!(self, tx => tx.cursor_read::<T>(), wrap)
+    }
+
+    fn cursor_dup_read<T: DupSort>(&self) -> Result<Self::DupCursor<T>, DatabaseError> {
+        either!(self, tx => tx.cursor_dup_read::<T>(), wrap)
+    }
+
+    fn entries<T: Table>(&self) -> Result<usize, DatabaseError> {
+        either!(self, tx => tx.entries::<T>())
+    }
+
+    fn table_sizes(&self) -> Result<Vec<(&'static str, u64, u64)>, DatabaseError> {
+        either!(self, tx => tx.table_sizes())
+    }
+
+    fn disable_long_read_transaction_safety(&mut self) {
+        either!(self, tx => tx.disable_long_read_transaction_safety())
+    }
+}
+
+impl<L: DbTxMut, R: DbTxMut> DbTxMut for Either<L, R> {
+    type CursorMut<T: Table> = Either<L::CursorMut<T>, R::CursorMut<T>>;
+    type DupCursorMut<T: DupSort> = Either<L::DupCursorMut<T>, R::DupCursorMut<T>>;
+
+    fn put<T: Table>(&self, key: T::Key, value: T::Value) -> Result<(), DatabaseError> {
+        either!(self, tx => tx.put::<T>(key, value))
+    }
+
+    fn delete<T: Table>(
+        &self,
+        key: T::Key,
+        value: Option<T::Value>,
+    ) -> Result<bool, DatabaseError> {
+        either!(self, tx => tx.delete::<T>(key, value))
+    }
+
+    fn clear<T: Table>(&self) -> Result<(), DatabaseError> {
+        either!(self, tx => tx.clear::<T>())
+    }
+
+    fn cursor_write<T: Table>(&self) -> Result<Self::CursorMut<T>, DatabaseError> {
+        either!(self, tx => tx.cursor_write::<T>(), wrap)
+    }
+
+    fn cursor_dup_write<T: DupSort>(&self) -> Result<Self::DupCursorMut<T>, DatabaseError> {
+        either!(self, tx => tx.cursor_dup_write::<T>(), wrap)
+    }
+}
+
+impl<L: TableImporter, R: TableImporter> TableImporter for Either<L, R> {}
+
+impl<T: Table, L: DbCursorRO<T>, R: DbCursorRO<T>> DbCursorRO<T> for Either<L, R> {
+    fn first(&mut self) -> PairResult<T> {
+        either!(self, cursor => cursor.first())
+    }
+
+    fn seek_exact(&mut self, key: T::Key) -> PairResult<T> {
+        either!(self, cursor => cursor.seek_exact(key))
+    }
+
+    fn seek(&mut self, key: T::Key) -> PairResult<T> {
+        either!(self, cursor => cursor.seek(key))
+    }
+
+    fn next(&mut self) -> PairResult<T> {
+        either!(self, cursor => cursor.next())
+    }
+
+    fn prev(&mut self) -> PairResult<T> {
+        either!(self, cursor => cursor.prev())
+    }
+
+    fn last(&mut self) -> PairResult<T> {
+        either!(self, cursor => cursor.last())
+    }
+
+    fn current(&mut self) -> PairResult<T> {
+        either!(self, cursor => cursor.current())
+    }
+
+    fn walk(&mut self, start_key: Option<T::Key>) -> Result<Walker<'_, T, Self>, DatabaseError> {
+        let start = if let Some(start_key) = start_key {
+            self.seek(start_key).transpose()
+        } else {
+            self.first().transpose()
+        };
+
+        Ok(Walker::new(self, start))
+    }
+
+    fn walk_range(
+        &mut self,
+        range: impl RangeBounds<T::Key>,
+    ) -> Result<RangeWalker<'_, T, Self>, DatabaseError> {
+        let start = match range.start_bound().cloned() {
+            Bound::Included(key) => self.seek(key),
+            Bound::Excluded(_key) => {
+                unreachable!("Rust doesn't allow for Bound::Excluded in starting bounds");
+            }
+            Bound::Unbounded => self.first(),
+        }
+        .transpose();
+        Ok(RangeWalker::new(self, start, range.end_bound().cloned()))
+    }
+
+    fn walk_back(
+        &mut self,
+        start_key: Option<T::Key>,
+    ) -> Result<ReverseWalker<'_, T, Self>, DatabaseError> {
+        let start =
+            if let Some(start_key) = start_key { self.seek(start_key) } else { self.last() }
+                .transpose();
+
+        Ok(ReverseWalker::new(self, start))
+    }
+}
+
+impl<T: DupSort, L: DbDupCursorRO<T>, R: DbDupCursorRO<T>> DbDupCursorRO<T> for Either<L, R> {
+    fn next_dup(&mut self) -> PairResult<T> {
+        either!(self, cursor => cursor.next_dup())
+    }
+
+    fn next_no_dup(&mut self) -> PairResult<T> {
+        either!(self, cursor => cursor.next_no_dup())
+    }
+
+    fn next_dup_val(&mut self) -> ValueOnlyResult<T> {
+        either!(self, cursor => cursor.next_dup_val())
+    }
+
+    fn seek_by_key_subkey(&mut self, key: T::Key, subkey: T::SubKey) -> ValueOnlyResult<T> {
+        either!(self, cursor => cursor.seek_by_key_subkey(key, subkey))
+    }
+
+    fn walk_dup(
+        &mut self,
+        key: Option<T::Key>,
+        subkey: Option<T::SubKey>,
+    ) -> Result<DupWalker<'_, T, Self>, DatabaseError> {
+        // the walker of the inner cursor only positions it
+        let start: IterPairResult<T> =
+            either!(&mut *self, cursor => cursor.walk_dup(key, subkey)?.start);
+        Ok(DupWalker { cursor: self, start })
+    }
+}
+
+impl<T: Table, L: DbCursorRW<T>, R: DbCursorRW<T>> DbCursorRW<T> for Either<L, R> {
+    fn upsert(&mut self, key: T::Key, value: &T::Value) -> Result<(), DatabaseError> {
+        either!(self, cursor => cursor.upsert(key, value))
+    }
+
+    fn insert(&mut self, key: T::Key, value: &T::Value) -> Result<(), DatabaseError> {
+        either!(self, cursor => cursor.insert(key, value))
+    }
+
+    fn append(&mut self, key: T::Key, value: &T::Value) -> Result<(), DatabaseError> {
+        either!(self, cursor => cursor.append(key, value))
+    }
+
+    fn delete_current(&mut self) -> Result<(), DatabaseError> {
+        either!(self, cursor => cursor.delete_current())
+    }
+}
+
+impl<T: DupSort, L: DbDupCursorRW<T>, R: DbDupCursorRW<T>> DbDupCursorRW<T> for Either<L, R> {
+    fn delete_current_duplicates(&mut self) -> Result<(), DatabaseError> {
+        either!(self, cursor => cursor.delete_current_duplicates())
+    }
+
+    fn append_dup(&mut self, key: T::Key, value: T::Value) -> Result<(), DatabaseError> {
+        either!(self, cursor => cursor.append_dup(key, value))
+    }
+}
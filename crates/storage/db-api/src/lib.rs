@@ -71,6 +71,8 @@ pub mod database;
 /// Database metrics trait extensions.
 pub mod database_metrics;
 
+pub mod either;
+
 pub mod mock;
 
 /// Table traits
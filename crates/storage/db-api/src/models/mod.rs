@@ -25,8 +25,10 @@ pub use accounts::*;
 pub use blocks::*;
 pub use integer_list::IntegerList;
 pub use reth_db_models::{
-    AccountBeforeTx, ClientVersion, StaticFileBlockWithdrawals, StoredBlockBodyIndices,
-    StoredBlockWithdrawals,
+    accumulator, storage_breakdown, AccountBeforeTx, BlockExecutionRequest, ClientVersion,
+    CodeBeforeTx, ContractCreation, ExecutionRequest, HeaderProof, IndexCheckpoint,
+    StaticFileBlockWithdrawals, StorageBreakdown, StoredBlockBodyIndices, StoredBlockRequests,
+    StoredBlockWithdrawals, ValidatorPubkey,
 };
 pub use sharded_key::ShardedKey;
 
@@ -226,13 +228,17 @@ impl_compression_for_compact!(
     StoredBlockOmmers<H>,
     StoredBlockWithdrawals,
     StaticFileBlockWithdrawals,
+    StoredBlockRequests,
     Bytecode,
     AccountBeforeTx,
+    CodeBeforeTx,
     TransactionSigned,
     CompactU256,
     StageCheckpoint,
     PruneCheckpoint,
     ClientVersion,
+    ContractCreation,
+    IndexCheckpoint,
     // Non-DB
     GenesisAccount
 );
@@ -336,9 +342,11 @@ mod tests {
         assert_eq!(CompactClientVersion::bitflag_encoded_bytes(), 0);
         assert_eq!(CompactU256::bitflag_encoded_bytes(), 1);
         assert_eq!(CompactU64::bitflag_encoded_bytes(), 1);
+        assert_eq!(ContractCreation::bitflag_encoded_bytes(), 1);
         assert_eq!(EntitiesCheckpoint::bitflag_encoded_bytes(), 1);
         assert_eq!(ExecutionCheckpoint::bitflag_encoded_bytes(), 0);
         assert_eq!(HeadersCheckpoint::bitflag_encoded_bytes(), 0);
+        assert_eq!(IndexCheckpoint::bitflag_encoded_bytes(), 1);
         assert_eq!(IndexHistoryCheckpoint::bitflag_encoded_bytes(), 0);
         assert_eq!(PruneCheckpoint::bitflag_encoded_bytes(), 1);
         assert_eq!(PruneMode::bitflag_encoded_bytes(), 1);
@@ -347,6 +355,7 @@ mod tests {
         assert_eq!(StageCheckpoint::bitflag_encoded_bytes(), 1);
         assert_eq!(StageUnitCheckpoint::bitflag_encoded_bytes(), 1);
         assert_eq!(StoredBlockBodyIndices::bitflag_encoded_bytes(), 1);
+        assert_eq!(StoredBlockRequests::bitflag_encoded_bytes(), 0);
         assert_eq!(StoredBlockWithdrawals::bitflag_encoded_bytes(), 0);
         assert_eq!(StorageHashingCheckpoint::bitflag_encoded_bytes(), 1);
 
@@ -356,9 +365,11 @@ mod tests {
         validate_bitflag_backwards_compat!(CompactClientVersion, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(CompactU256, UnusedBits::NotZero);
         validate_bitflag_backwards_compat!(CompactU64, UnusedBits::NotZero);
+        validate_bitflag_backwards_compat!(ContractCreation, UnusedBits::NotZero);
         validate_bitflag_backwards_compat!(EntitiesCheckpoint, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(ExecutionCheckpoint, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(HeadersCheckpoint, UnusedBits::Zero);
+        validate_bitflag_backwards_compat!(IndexCheckpoint, UnusedBits::NotZero);
         validate_bitflag_backwards_compat!(IndexHistoryCheckpoint, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(PruneCheckpoint, UnusedBits::NotZero);
         validate_bitflag_backwards_compat!(PruneMode, UnusedBits::Zero);
@@ -367,6 +378,7 @@ mod tests {
         validate_bitflag_backwards_compat!(StageCheckpoint, UnusedBits::NotZero);
         validate_bitflag_backwards_compat!(StageUnitCheckpoint, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(StoredBlockBodyIndices, UnusedBits::Zero);
+        validate_bitflag_backwards_compat!(StoredBlockRequests, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(StoredBlockWithdrawals, UnusedBits::Zero);
         validate_bitflag_backwards_compat!(StorageHashingCheckpoint, UnusedBits::NotZero);
     }
@@ -21,8 +21,9 @@ use crate::{
         accounts::BlockNumberAddress,
         blocks::{HeaderHash, StoredBlockOmmers},
         storage_sharded_key::StorageShardedKey,
-        AccountBeforeTx, ClientVersion, CompactU256, IntegerList, ShardedKey,
-        StoredBlockBodyIndices, StoredBlockWithdrawals,
+        AccountBeforeTx, ClientVersion, CodeBeforeTx, CompactU256, ContractCreation,
+        IndexCheckpoint, IntegerList, ShardedKey, StoredBlockBodyIndices, StoredBlockRequests,
+        StoredBlockWithdrawals,
     },
     table::{Decode, DupSort, Encode, Table, TableInfo},
 };
@@ -525,6 +526,70 @@ tables! {
         type Key = ChainStateKey;
         type Value = BlockNumber;
     }
+
+    /// Stores the last indexed block of each optional index, like the contract creation index.
+    table IndexCheckpoints {
+        type Key = IndexId;
+        type Value = IndexCheckpoint;
+    }
+
+    /// Stores the creation of every indexed contract, by contract address.
+    ///
+    /// Only populated if the contract creation index is enabled.
+    table ContractCreations {
+        type Key = Address;
+        type Value = ContractCreation;
+    }
+
+    /// Stores the addresses of the contracts created in a block.
+    ///
+    /// Used to unwind and prune the [`ContractCreations`] table by block.
+    table BlockContractCreations {
+        type Key = BlockNumber;
+        type Value = Address;
+        type SubKey = Address;
+    }
+
+    /// Stores the code hash of an account before the block that changed its code.
+    /// Change on code can be: contract is deployed or selfdestructed, or an EIP-7702 delegation
+    /// is set or cleared.
+    table CodeChangeSets {
+        type Key = BlockNumber;
+        type Value = CodeBeforeTx;
+        type SubKey = Address;
+    }
+
+    /// Stores pointers to the blocks that changed the code of an account.
+    ///
+    /// Sharded the same way as [`AccountsHistory`], the last shard key of an account is
+    /// `u64::MAX`.
+    table CodeHistory {
+        type Key = ShardedKey<Address>;
+        type Value = BlockNumberList;
+    }
+
+    /// Stores the nodes of the Merkle Mountain Range of canonical block hashes, by position.
+    ///
+    /// Only populated if the header accumulator is enabled.
+    table HeaderAccumulator {
+        type Key = u64;
+        type Value = B256;
+    }
+
+    /// Stores the EIP-7685 requests of every indexed block with requests.
+    ///
+    /// Only populated if the execution request index is enabled.
+    table ExecutionRequests {
+        type Key = BlockNumber;
+        type Value = StoredBlockRequests;
+    }
+
+    /// Stores the numbers of the indexed blocks with requests that refer to a validator, by the
+    /// keccak256 hash of the validator public key.
+    table ValidatorRequestBlocks {
+        type Key = B256;
+        type Value = BlockNumberList;
+    }
 }
 
 /// Keys for the `ChainState` table.
@@ -565,6 +630,9 @@ pub type BlockNumberList = IntegerList;
 /// Encoded stage id.
 pub type StageId = String;
 
+/// Encoded id of an optional index.
+pub type IndexId = String;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -31,6 +31,12 @@ pub trait DbTx: Send + Sync {
     fn cursor_dup_read<T: DupSort>(&self) -> Result<Self::DupCursor<T>, DatabaseError>;
     /// Returns number of entries in the table.
     fn entries<T: Table>(&self) -> Result<usize, DatabaseError>;
+    /// Returns the name, the size in bytes and the number of entries of each table.
+    ///
+    /// Databases that can't measure the size of their tables return no tables.
+    fn table_sizes(&self) -> Result<Vec<(&'static str, u64, u64)>, DatabaseError> {
+        Ok(Vec::new())
+    }
     /// Disables long-lived read transaction safety guarantees.
     fn disable_long_read_transaction_safety(&mut self);
 }
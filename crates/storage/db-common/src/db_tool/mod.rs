@@ -7,7 +7,7 @@ use reth_db_api::{
     database::Database,
     table::{Decode, Decompress, DupSort, Table, TableRow},
     transaction::{DbTx, DbTxMut},
-    DatabaseError, RawTable, TableRawRow,
+    DatabaseError, RawKey, RawTable, TableRawRow,
 };
 use reth_fs_util as fs;
 use reth_node_types::NodeTypesWithDB;
@@ -34,6 +34,21 @@ impl<N: NodeTypesWithDB> DbTool<N> {
     /// [`ListFilter`] can be used to further
     /// filter down the desired results. (eg. List only rows which include `0xd3adbeef`)
     pub fn list<T: Table>(&self, filter: &ListFilter) -> Result<(Vec<TableRow<T>>, usize)> {
+        let mut rows = Vec::new();
+        let hits = self.list_with::<T>(filter, |row| {
+            rows.push(row);
+            Ok(())
+        })?;
+        Ok((rows, hits))
+    }
+
+    /// Like [`Self::list`], but passes the entries to the closure as they're read instead of
+    /// collecting them, and returns the number of matching entries.
+    pub fn list_with<T: Table>(
+        &self,
+        filter: &ListFilter,
+        mut f: impl FnMut(TableRow<T>) -> Result<()>,
+    ) -> Result<usize> {
         let bmb = Rc::new(BMByte::from(&filter.search));
         if bmb.is_none() && filter.has_search() {
             eyre::bail!("Invalid search.")
@@ -41,10 +56,20 @@ impl<N: NodeTypesWithDB> DbTool<N> {
 
         let mut hits = 0;
 
-        let data = self.provider_factory.db_ref().view(|tx| {
+        self.provider_factory.db_ref().view(|tx| -> Result<()> {
             let mut cursor =
                 tx.cursor_read::<RawTable<T>>().expect("Was not able to obtain a cursor.");
 
+            let (lower, upper) = filter.key_range();
+            let below_upper = |row: &Result<TableRawRow<T>, DatabaseError>| {
+                row.as_ref()
+                    .map_or(true, |(k, _)| upper.as_ref().is_none_or(|upper| k.raw_key() < upper))
+            };
+            let above_lower = |row: &Result<TableRawRow<T>, DatabaseError>| {
+                row.as_ref()
+                    .map_or(true, |(k, _)| lower.as_ref().is_none_or(|lower| k.raw_key() >= lower))
+            };
+
             let map_filter = |row: Result<TableRawRow<T>, _>| {
                 if let Ok((k, v)) = row {
                     let (key, value) = (k.into_key(), v.into_value());
@@ -88,23 +113,36 @@ impl<N: NodeTypesWithDB> DbTool<N> {
             };
 
             if filter.reverse {
-                Ok(cursor
-                    .walk_back(None)?
+                // start at the first key past the range, or at the last key if there is none
+                let start = match &upper {
+                    Some(upper) => cursor.seek(RawKey::from_vec(upper.clone()))?.map(|(k, _)| k),
+                    None => None,
+                };
+                let rows = cursor
+                    .walk_back(start)?
+                    .skip_while(|row| !below_upper(row))
+                    .take_while(above_lower)
                     .skip(filter.skip)
                     .filter_map(map_filter)
-                    .take(filter.len)
-                    .collect::<Vec<(_, _)>>())
+                    .take(filter.len);
+                for row in rows {
+                    f(row)?;
+                }
             } else {
-                Ok(cursor
-                    .walk(None)?
+                let rows = cursor
+                    .walk(lower.clone().map(RawKey::from_vec))?
+                    .take_while(below_upper)
                     .skip(filter.skip)
                     .filter_map(map_filter)
-                    .take(filter.len)
-                    .collect::<Vec<(_, _)>>())
+                    .take(filter.len);
+                for row in rows {
+                    f(row)?;
+                }
             }
-        })?;
+            Ok(())
+        })??;
 
-        Ok((data.map_err(|e: DatabaseError| eyre::eyre!(e))?, hits))
+        Ok(hits)
     }
 }
 
@@ -130,6 +168,21 @@ impl<N: ProviderNodeTypes> DbTool<N> {
             .map_err(|e| eyre::eyre!(e))
     }
 
+    /// Grabs all values of the `DupSort` table for the given key
+    pub fn get_dups<T: DupSort>(&self, key: T::Key) -> Result<Vec<T::Value>> {
+        self.provider_factory
+            .db_ref()
+            .view(|tx| {
+                tx.cursor_dup_read::<T>()?
+                    .walk_dup(Some(key.clone()), None)?
+                    // the walker starts at the next key if the key doesn't exist
+                    .take_while(|entry| entry.as_ref().map_or(true, |(k, _)| *k == key))
+                    .map(|entry| entry.map(|(_, value)| value))
+                    .collect::<Result<Vec<_>, _>>()
+            })?
+            .map_err(|e| eyre::eyre!(e))
+    }
+
     /// Drops the database, the static files and ExEx WAL at the given paths.
     pub fn drop<P: AsRef<Path>>(
         &self,
@@ -179,6 +232,12 @@ pub struct ListFilter {
     pub reverse: bool,
     /// Only counts the number of filtered entries without decoding and returning them.
     pub only_count: bool,
+    /// Encoded key of the first entry, inclusive.
+    pub start_key: Option<Vec<u8>>,
+    /// Encoded key of the last entry, exclusive.
+    pub end_key: Option<Vec<u8>>,
+    /// Bytes that the encoded keys of all entries start with.
+    pub key_prefix: Vec<u8>,
 }
 
 impl ListFilter {
@@ -192,4 +251,72 @@ impl ListFilter {
         self.skip = skip;
         self.len = len;
     }
+
+    /// Returns the inclusive lower and the exclusive upper bound of the encoded keys, narrowing
+    /// the key range down to the keys with the prefix.
+    pub fn key_range(&self) -> (Option<Vec<u8>>, Option<Vec<u8>>) {
+        if self.key_prefix.is_empty() {
+            return (self.start_key.clone(), self.end_key.clone())
+        }
+
+        let lower = match &self.start_key {
+            Some(start) => start.clone().max(self.key_prefix.clone()),
+            None => self.key_prefix.clone(),
+        };
+        let upper = match (self.end_key.clone(), prefix_successor(&self.key_prefix)) {
+            (Some(end), Some(successor)) => Some(end.min(successor)),
+            (end, successor) => end.or(successor),
+        };
+        (Some(lower), upper)
+    }
+}
+
+/// Returns the smallest byte string that is greater than all byte strings with the prefix, or
+/// `None` if there is none.
+fn prefix_successor(prefix: &[u8]) -> Option<Vec<u8>> {
+    let mut successor = prefix.to_vec();
+    while let Some(last) = successor.pop() {
+        if last < u8::MAX {
+            successor.push(last + 1);
+            return Some(successor)
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(start_key: Option<&[u8]>, end_key: Option<&[u8]>, key_prefix: &[u8]) -> ListFilter {
+        ListFilter {
+            skip: 0,
+            len: 5,
+            search: Vec::new(),
+            min_row_size: 0,
+            min_key_size: 0,
+            min_value_size: 0,
+            reverse: false,
+            only_count: false,
+            start_key: start_key.map(<[u8]>::to_vec),
+            end_key: end_key.map(<[u8]>::to_vec),
+            key_prefix: key_prefix.to_vec(),
+        }
+    }
+
+    #[test]
+    fn key_range_with_prefix() {
+        assert_eq!(filter(None, None, &[]).key_range(), (None, None));
+        assert_eq!(filter(Some(&[1]), Some(&[5]), &[]).key_range(), (Some(vec![1]), Some(vec![5])));
+        assert_eq!(filter(None, None, &[1, 2]).key_range(), (Some(vec![1, 2]), Some(vec![1, 3])));
+        assert_eq!(
+            filter(None, None, &[1, 0xff]).key_range(),
+            (Some(vec![1, 0xff]), Some(vec![2]))
+        );
+        assert_eq!(filter(None, None, &[0xff]).key_range(), (Some(vec![0xff]), None));
+        assert_eq!(
+            filter(Some(&[1, 2, 3]), Some(&[9]), &[1, 2]).key_range(),
+            (Some(vec![1, 2, 3]), Some(vec![1, 3]))
+        );
+    }
 }
@@ -0,0 +1,240 @@
+use alloc::vec::Vec;
+use alloy_primitives::{keccak256, BlockHash, BlockNumber, B256};
+use serde::{Deserialize, Serialize};
+
+/// Returns the number of nodes of a Merkle Mountain Range with the given number of leaves.
+///
+/// This is also the position of the next leaf, as nodes are numbered in the order they are
+/// appended, starting at 0.
+pub const fn mmr_size(leaves: u64) -> u64 {
+    2 * leaves - leaves.count_ones() as u64
+}
+
+/// Returns the number of leaves of a Merkle Mountain Range with the given number of nodes, or
+/// `None` if no range has this size.
+pub const fn mmr_leaves(size: u64) -> Option<u64> {
+    let mut leaves = 0;
+    let mut remaining = size;
+    let mut height = u64::BITS - 1;
+    loop {
+        let peak_leaves = 1u64 << height;
+        let peak_size = (peak_leaves << 1).wrapping_sub(1);
+        if peak_size <= remaining {
+            leaves += peak_leaves;
+            remaining -= peak_size;
+        }
+        if height == 0 {
+            break
+        }
+        height -= 1;
+    }
+    if remaining == 0 {
+        Some(leaves)
+    } else {
+        None
+    }
+}
+
+/// Returns the hash of the parent node of two nodes.
+pub fn mmr_parent(left: &B256, right: &B256) -> B256 {
+    let mut buf = [0u8; 64];
+    buf[..32].copy_from_slice(left.as_slice());
+    buf[32..].copy_from_slice(right.as_slice());
+    keccak256(buf)
+}
+
+/// Returns the positions of the nodes that are merged with a leaf appended to a range with the
+/// given number of leaves, bottom up, together with the position of the leaf.
+///
+/// The merged parent nodes are appended right after the leaf, in the same order.
+pub fn mmr_append_siblings(leaves: u64) -> (u64, Vec<u64>) {
+    let leaf_position = mmr_size(leaves);
+    let mut position = leaf_position;
+    let siblings = (0..leaves.trailing_ones())
+        .map(|height| {
+            let sibling = position + 1 - (2u64 << height);
+            position += 1;
+            sibling
+        })
+        .collect();
+    (leaf_position, siblings)
+}
+
+/// Returns the positions of the peaks of a Merkle Mountain Range with the given number of leaves,
+/// from the highest (leftmost) to the lowest.
+pub fn mmr_peaks(leaves: u64) -> Vec<u64> {
+    let mut offset = 0;
+    (0..u64::BITS)
+        .rev()
+        .filter(|height| leaves & (1 << height) != 0)
+        .map(|height| {
+            offset += (2u64 << height) - 1;
+            offset - 1
+        })
+        .collect()
+}
+
+/// Returns the root of a Merkle Mountain Range, committing to its peaks and number of leaves.
+///
+/// The peaks are bagged from the right, `keccak256(peak_0 || keccak256(peak_1 || ...))`, and the
+/// root is `keccak256(leaves || bag)`, with `leaves` as 8 big endian bytes.
+pub fn mmr_root(leaves: u64, peaks: &[B256]) -> B256 {
+    let bag = peaks.iter().rev().copied().reduce(|bag, peak| mmr_parent(&peak, &bag));
+    let mut buf = [0u8; 40];
+    buf[..8].copy_from_slice(&leaves.to_be_bytes());
+    buf[8..].copy_from_slice(bag.unwrap_or_default().as_slice());
+    keccak256(buf)
+}
+
+/// Locates a leaf in a Merkle Mountain Range: the index of the peak whose tree contains the leaf,
+/// the height of that tree, the position of its first node and the index of the leaf in it.
+const fn mmr_locate(leaves: u64, leaf: u64) -> Option<(usize, u32, u64, u64)> {
+    if leaf >= leaves {
+        return None
+    }
+    let mut peak = 0;
+    let mut leaf_offset = 0;
+    let mut node_offset = 0;
+    let mut height = u64::BITS;
+    while height > 0 {
+        height -= 1;
+        if leaves & (1 << height) == 0 {
+            continue
+        }
+        if leaf < leaf_offset + (1 << height) {
+            return Some((peak, height, node_offset, leaf - leaf_offset))
+        }
+        peak += 1;
+        leaf_offset += 1 << height;
+        node_offset += (2 << height) - 1;
+    }
+    None
+}
+
+/// Returns the positions of the siblings on the path from a leaf to its peak, bottom up, or
+/// `None` if the leaf is not in the range.
+pub fn mmr_proof_positions(leaves: u64, leaf: u64) -> Option<Vec<u64>> {
+    let (_, height, mut start, mut index) = mmr_locate(leaves, leaf)?;
+    let mut siblings = Vec::with_capacity(height as usize);
+    for height in (1..=height).rev() {
+        let half = 1u64 << (height - 1);
+        let left = start + (2 * half - 1) - 1;
+        let right = start + 2 * (2 * half - 1) - 1;
+        if index < half {
+            siblings.push(right);
+        } else {
+            siblings.push(left);
+            start += 2 * half - 1;
+            index -= half;
+        }
+    }
+    siblings.reverse();
+    Some(siblings)
+}
+
+/// An inclusion proof of a canonical block hash in the accumulator of canonical block hashes.
+///
+/// The accumulator is a Merkle Mountain Range whose leaves are the canonical block hashes,
+/// starting with the genesis block, and whose root is computed with [`mmr_root`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeaderProof {
+    /// The number of the proven block.
+    pub block_number: BlockNumber,
+    /// The hash of the proven block.
+    pub block_hash: BlockHash,
+    /// The number of block hashes in the accumulator, i.e. the number of the latest accumulated
+    /// block plus one.
+    pub leaves: u64,
+    /// The root of the accumulator.
+    pub root: B256,
+    /// The siblings on the path from the block hash to its peak, bottom up.
+    pub siblings: Vec<B256>,
+    /// The peaks of the accumulator, from the highest to the lowest.
+    pub peaks: Vec<B256>,
+}
+
+impl HeaderProof {
+    /// Returns `true` if the proof shows that the block hash is in the accumulator with the root.
+    pub fn verify(&self) -> bool {
+        let Some((peak, height, _, index)) = mmr_locate(self.leaves, self.block_number) else {
+            return false
+        };
+        if self.siblings.len() != height as usize ||
+            self.peaks.len() != self.leaves.count_ones() as usize
+        {
+            return false
+        }
+
+        let computed_peak =
+            self.siblings.iter().enumerate().fold(self.block_hash, |node, (height, sibling)| {
+                if index & (1 << height) == 0 {
+                    mmr_parent(&node, sibling)
+                } else {
+                    mmr_parent(sibling, &node)
+                }
+            });
+        computed_peak == self.peaks[peak] && mmr_root(self.leaves, &self.peaks) == self.root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a range of the given leaves, returning all nodes by position.
+    fn build(leaves: &[B256]) -> Vec<B256> {
+        let mut nodes = Vec::new();
+        for (count, leaf) in leaves.iter().enumerate() {
+            let (position, siblings) = mmr_append_siblings(count as u64);
+            assert_eq!(position, nodes.len() as u64);
+            nodes.push(*leaf);
+            let mut node = *leaf;
+            for sibling in siblings {
+                node = mmr_parent(&nodes[sibling as usize], &node);
+                nodes.push(node);
+            }
+        }
+        nodes
+    }
+
+    #[test]
+    fn mmr_sizes() {
+        for leaves in 0..100 {
+            assert_eq!(mmr_leaves(mmr_size(leaves)), Some(leaves));
+        }
+        assert_eq!(mmr_leaves(2), None);
+        assert_eq!(mmr_peaks(7), vec![6, 9, 10]);
+    }
+
+    #[test]
+    fn mmr_proofs() {
+        let leaves = (0..=20).map(B256::with_last_byte).collect::<Vec<_>>();
+        for count in 1..=leaves.len() as u64 {
+            let nodes = build(&leaves[..count as usize]);
+            assert_eq!(nodes.len() as u64, mmr_size(count));
+            let peaks = mmr_peaks(count).into_iter().map(|p| nodes[p as usize]).collect::<Vec<_>>();
+
+            for block_number in 0..count {
+                let siblings = mmr_proof_positions(count, block_number)
+                    .unwrap()
+                    .into_iter()
+                    .map(|p| nodes[p as usize])
+                    .collect();
+                let mut proof = HeaderProof {
+                    block_number,
+                    block_hash: leaves[block_number as usize],
+                    leaves: count,
+                    root: mmr_root(count, &peaks),
+                    siblings,
+                    peaks: peaks.clone(),
+                };
+                assert!(proof.verify());
+
+                proof.block_hash = B256::repeat_byte(0xff);
+                assert!(!proof.verify());
+            }
+            assert_eq!(mmr_proof_positions(count, count), None);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use alloy_primitives::{Address, BlockNumber, TxHash, B256};
+use serde::{Deserialize, Serialize};
+
+/// The creation of a contract, as it is saved in the contract creation index.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[cfg_attr(any(test, feature = "reth-codec"), derive(reth_codecs::Compact))]
+#[cfg_attr(any(test, feature = "reth-codec"), reth_codecs::add_arbitrary_tests(compact))]
+#[serde(rename_all = "camelCase")]
+pub struct ContractCreation {
+    /// The number of the block the contract was created in.
+    pub block_number: BlockNumber,
+    /// The hash of the transaction that created the contract.
+    pub tx_hash: TxHash,
+    /// The account that executed the `CREATE` or `CREATE2`, or the sender of the transaction if
+    /// the contract was created by the transaction itself.
+    pub creator: Address,
+}
+
+/// The code hash of an account from before the block that changed its code, as it is saved in the
+/// code changesets.
+///
+/// [`Address`] is the subkey.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary, serde::Deserialize))]
+#[cfg_attr(any(test, feature = "reth-codec"), reth_codecs::add_arbitrary_tests(compact))]
+pub struct CodeBeforeTx {
+    /// Address of the account. Acts as `DupSort::SubKey`.
+    pub address: Address,
+    /// Code hash of the account before the block, `None` if the account didn't exist or had no
+    /// code.
+    pub code_hash: Option<B256>,
+}
+
+// NOTE: The subkey is encoded as is, same as for `AccountBeforeTx`, so that values can be fetched
+// with seek_by_key_subkey.
+#[cfg(feature = "reth-codec")]
+impl reth_codecs::Compact for CodeBeforeTx {
+    fn to_compact<B>(&self, buf: &mut B) -> usize
+    where
+        B: bytes::BufMut + AsMut<[u8]>,
+    {
+        buf.put_slice(self.address.as_slice());
+        if let Some(code_hash) = self.code_hash {
+            buf.put_slice(code_hash.as_slice());
+            return 52
+        }
+        20
+    }
+
+    fn from_compact(mut buf: &[u8], len: usize) -> (Self, &[u8]) {
+        use bytes::Buf;
+        let address = Address::from_slice(&buf[..20]);
+        buf.advance(20);
+
+        let code_hash = (len > 20).then(|| {
+            let code_hash = B256::from_slice(&buf[..32]);
+            buf.advance(32);
+            code_hash
+        });
+
+        (Self { address, code_hash }, buf)
+    }
+}
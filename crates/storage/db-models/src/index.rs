@@ -0,0 +1,17 @@
+use alloy_primitives::{BlockHash, BlockNumber};
+use serde::{Deserialize, Serialize};
+
+/// The last block that was written to an optional index, like the contract creation index.
+///
+/// The hash is used to detect if the indexed blocks were unwound while the index wasn't updated.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[cfg_attr(any(test, feature = "reth-codec"), derive(reth_codecs::Compact))]
+#[cfg_attr(any(test, feature = "reth-codec"), reth_codecs::add_arbitrary_tests(compact))]
+#[serde(rename_all = "camelCase")]
+pub struct IndexCheckpoint {
+    /// The number of the last indexed block.
+    pub block_number: BlockNumber,
+    /// The hash of the last indexed block.
+    pub block_hash: BlockHash,
+}
@@ -19,6 +19,29 @@ pub use accounts::AccountBeforeTx;
 pub mod blocks;
 pub use blocks::{StaticFileBlockWithdrawals, StoredBlockBodyIndices, StoredBlockWithdrawals};
 
+/// Contracts
+pub mod contracts;
+pub use contracts::{CodeBeforeTx, ContractCreation};
+
+/// Checkpoints of optional indexes
+pub mod index;
+pub use index::IndexCheckpoint;
+
+/// Execution layer requests
+pub mod requests;
+pub use requests::{BlockExecutionRequest, ExecutionRequest, StoredBlockRequests, ValidatorPubkey};
+
+/// Accumulator of canonical block hashes
+pub mod accumulator;
+pub use accumulator::HeaderProof;
+
 /// Client Version
 pub mod client_version;
 pub use client_version::ClientVersion;
+
+/// Disk usage per logical category
+pub mod storage_breakdown;
+pub use storage_breakdown::{
+    CategorySize, PruneBacklog, StaticFileSegmentSize, StorageBreakdown, StorageCategory,
+    StorageGrowth, TableSize,
+};
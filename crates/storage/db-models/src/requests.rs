@@ -0,0 +1,175 @@
+use alloc::{vec, vec::Vec};
+use alloy_eips::{
+    eip6110::{DepositRequest, DEPOSIT_REQUEST_TYPE},
+    eip7002::{WithdrawalRequest, WITHDRAWAL_REQUEST_TYPE},
+    eip7251::{ConsolidationRequest, CONSOLIDATION_REQUEST_TYPE},
+};
+use alloy_primitives::{Address, BlockNumber, Bytes, FixedBytes, B256};
+use serde::{Deserialize, Serialize};
+
+/// The public key of a validator.
+pub type ValidatorPubkey = FixedBytes<48>;
+
+/// The [EIP-7685](https://eips.ethereum.org/EIPS/eip-7685) requests of a block, as they are saved
+/// in the execution request index.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+#[cfg_attr(any(test, feature = "reth-codec"), derive(reth_codecs::Compact))]
+#[cfg_attr(any(test, feature = "reth-codec"), reth_codecs::add_arbitrary_tests(compact))]
+pub struct StoredBlockRequests {
+    /// The opaque requests of the block, each one is the request type followed by the
+    /// concatenated requests of that type.
+    pub requests: Vec<Bytes>,
+}
+
+impl StoredBlockRequests {
+    /// Decodes the deposit, withdrawal and consolidation requests, in the order of the block.
+    ///
+    /// Requests of unknown types and requests data whose length is not a multiple of the request
+    /// size are skipped.
+    pub fn decode(&self) -> Vec<ExecutionRequest> {
+        self.requests
+            .iter()
+            .filter_map(|request| request.split_first())
+            .flat_map(|(request_type, data)| {
+                let size = match *request_type {
+                    DEPOSIT_REQUEST_TYPE => ExecutionRequest::DEPOSIT_SIZE,
+                    WITHDRAWAL_REQUEST_TYPE => ExecutionRequest::WITHDRAWAL_SIZE,
+                    CONSOLIDATION_REQUEST_TYPE => ExecutionRequest::CONSOLIDATION_SIZE,
+                    _ => 0,
+                };
+                let chunks = if size > 0 && data.len() % size == 0 { data.len() / size } else { 0 };
+                (0..chunks).map(move |i| {
+                    ExecutionRequest::decode(*request_type, &data[i * size..(i + 1) * size])
+                })
+            })
+            .collect()
+    }
+}
+
+/// A deposit, withdrawal or consolidation request of a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "request", rename_all = "camelCase")]
+pub enum ExecutionRequest {
+    /// An [EIP-6110](https://eips.ethereum.org/EIPS/eip-6110) deposit request.
+    Deposit(DepositRequest),
+    /// An [EIP-7002](https://eips.ethereum.org/EIPS/eip-7002) withdrawal request.
+    Withdrawal(WithdrawalRequest),
+    /// An [EIP-7251](https://eips.ethereum.org/EIPS/eip-7251) consolidation request.
+    Consolidation(ConsolidationRequest),
+}
+
+impl ExecutionRequest {
+    /// The size of an encoded deposit request: pubkey, withdrawal credentials, amount, signature
+    /// and index.
+    pub const DEPOSIT_SIZE: usize = 48 + 32 + 8 + 96 + 8;
+    /// The size of an encoded withdrawal request: source address, validator pubkey and amount.
+    pub const WITHDRAWAL_SIZE: usize = 20 + 48 + 8;
+    /// The size of an encoded consolidation request: source address, source pubkey and target
+    /// pubkey.
+    pub const CONSOLIDATION_SIZE: usize = 20 + 48 + 48;
+
+    /// Decodes a request of the given type, `data` must have the size of the request type.
+    ///
+    /// The amounts and the deposit index are little endian, as they are SSZ encoded.
+    fn decode(request_type: u8, data: &[u8]) -> Self {
+        let u64_le = |data: &[u8]| u64::from_le_bytes(data.try_into().expect("8 bytes"));
+        match request_type {
+            DEPOSIT_REQUEST_TYPE => Self::Deposit(DepositRequest {
+                pubkey: ValidatorPubkey::from_slice(&data[..48]),
+                withdrawal_credentials: B256::from_slice(&data[48..80]),
+                amount: u64_le(&data[80..88]),
+                signature: FixedBytes::from_slice(&data[88..184]),
+                index: u64_le(&data[184..192]),
+            }),
+            WITHDRAWAL_REQUEST_TYPE => Self::Withdrawal(WithdrawalRequest {
+                source_address: Address::from_slice(&data[..20]),
+                validator_pubkey: ValidatorPubkey::from_slice(&data[20..68]),
+                amount: u64_le(&data[68..76]),
+            }),
+            _ => Self::Consolidation(ConsolidationRequest {
+                source_address: Address::from_slice(&data[..20]),
+                source_pubkey: ValidatorPubkey::from_slice(&data[20..68]),
+                target_pubkey: ValidatorPubkey::from_slice(&data[68..116]),
+            }),
+        }
+    }
+
+    /// Returns the public keys of the validators the request refers to.
+    pub fn validator_pubkeys(&self) -> Vec<ValidatorPubkey> {
+        match self {
+            Self::Deposit(deposit) => vec![deposit.pubkey],
+            Self::Withdrawal(withdrawal) => vec![withdrawal.validator_pubkey],
+            Self::Consolidation(consolidation) => {
+                vec![consolidation.source_pubkey, consolidation.target_pubkey]
+            }
+        }
+    }
+}
+
+/// An execution request together with the block it was included in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockExecutionRequest {
+    /// The number of the block that includes the request.
+    pub block_number: BlockNumber,
+    /// The request.
+    #[serde(flatten)]
+    pub request: ExecutionRequest,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::bytes::BufMut;
+
+    #[test]
+    fn decode_requests() {
+        let pubkey = ValidatorPubkey::repeat_byte(1);
+        let target_pubkey = ValidatorPubkey::repeat_byte(2);
+        let source_address = Address::repeat_byte(3);
+
+        let mut deposits = vec![DEPOSIT_REQUEST_TYPE];
+        deposits.put_slice(pubkey.as_slice());
+        deposits.put_slice(B256::repeat_byte(4).as_slice());
+        deposits.put_u64_le(32_000_000_000);
+        deposits.put_slice(&[5; 96]);
+        deposits.put_u64_le(7);
+
+        let mut consolidations = vec![CONSOLIDATION_REQUEST_TYPE];
+        for _ in 0..2 {
+            consolidations.put_slice(source_address.as_slice());
+            consolidations.put_slice(pubkey.as_slice());
+            consolidations.put_slice(target_pubkey.as_slice());
+        }
+
+        let requests = StoredBlockRequests {
+            requests: vec![
+                deposits.into(),
+                // truncated withdrawal request
+                Bytes::from(vec![WITHDRAWAL_REQUEST_TYPE; 10]),
+                consolidations.into(),
+            ],
+        };
+        let consolidation = ExecutionRequest::Consolidation(ConsolidationRequest {
+            source_address,
+            source_pubkey: pubkey,
+            target_pubkey,
+        });
+        assert_eq!(
+            requests.decode(),
+            vec![
+                ExecutionRequest::Deposit(DepositRequest {
+                    pubkey,
+                    withdrawal_credentials: B256::repeat_byte(4),
+                    amount: 32_000_000_000,
+                    signature: FixedBytes::repeat_byte(5),
+                    index: 7,
+                }),
+                consolidation,
+                consolidation,
+            ]
+        );
+        assert_eq!(consolidation.validator_pubkeys(), vec![pubkey, target_pubkey]);
+    }
+}
@@ -0,0 +1,267 @@
+use alloc::{string::String, vec::Vec};
+use serde::{Deserialize, Serialize};
+
+/// Logical category that the data on disk is attributed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StorageCategory {
+    /// Plain and hashed state, the state trie and the bytecodes.
+    State,
+    /// Changesets and history indices of accounts and storage.
+    History,
+    /// Headers and the header indices.
+    Headers,
+    /// Transactions, their senders and the block bodies.
+    Transactions,
+    /// Receipts.
+    Receipts,
+    /// The index of transaction hashes to transaction numbers.
+    TransactionIndex,
+    /// Everything else, e.g. stage and prune checkpoints and optional indices.
+    Other,
+}
+
+impl StorageCategory {
+    /// Returns the category of the database table with the given name.
+    pub fn of_table(table: &str) -> Self {
+        match table {
+            "PlainAccountState" | "PlainStorageState" | "HashedAccounts" | "HashedStorages" |
+            "AccountsTrie" | "StoragesTrie" | "Bytecodes" => Self::State,
+            "AccountChangeSets" | "StorageChangeSets" | "AccountsHistory" | "StoragesHistory" => {
+                Self::History
+            }
+            "CanonicalHeaders" | "HeaderTerminalDifficulties" | "HeaderNumbers" | "Headers" => {
+                Self::Headers
+            }
+            "BlockBodyIndices" | "BlockOmmers" | "BlockWithdrawals" | "Transactions" |
+            "TransactionBlocks" | "TransactionSenders" => Self::Transactions,
+            "Receipts" => Self::Receipts,
+            "TransactionHashNumbers" => Self::TransactionIndex,
+            _ => Self::Other,
+        }
+    }
+
+    /// Returns the category of the static file segment with the given name.
+    pub fn of_static_file_segment(segment: &str) -> Self {
+        match segment {
+            "headers" => Self::Headers,
+            "transactions" | "blockmeta" => Self::Transactions,
+            "receipts" => Self::Receipts,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Disk usage of the node, attributed to logical categories.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageBreakdown {
+    /// The best block at the time of the report.
+    pub best_block: u64,
+    /// The total size of all tables and static files, in bytes.
+    pub total_size: u64,
+    /// The size of each category, ordered by category.
+    pub categories: Vec<CategorySize>,
+    /// The size of each database table, ordered by name.
+    pub tables: Vec<TableSize>,
+    /// The size of each static file segment.
+    pub static_files: Vec<StaticFileSegmentSize>,
+    /// The blocks that are due for pruning but were not pruned yet, per prune segment.
+    pub prune_backlog: Vec<PruneBacklog>,
+}
+
+impl StorageBreakdown {
+    /// Creates a report from the table and static file sizes, summing them up per category.
+    pub fn new(
+        best_block: u64,
+        mut tables: Vec<TableSize>,
+        static_files: Vec<StaticFileSegmentSize>,
+        prune_backlog: Vec<PruneBacklog>,
+    ) -> Self {
+        tables.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut categories = Vec::<CategorySize>::new();
+        let mut category = |category: StorageCategory| {
+            let idx = match categories.binary_search_by_key(&category, |size| size.category) {
+                Ok(idx) => idx,
+                Err(idx) => {
+                    categories.insert(idx, CategorySize::new(category));
+                    idx
+                }
+            };
+            &mut categories[idx]
+        };
+        for table in &tables {
+            category(table.category).database_size += table.size;
+        }
+        for segment in &static_files {
+            category(segment.category).static_files_size += segment.size;
+        }
+
+        let total_size = categories.iter().map(CategorySize::size).sum();
+        Self { best_block, total_size, categories, tables, static_files, prune_backlog }
+    }
+
+    /// Sets the growth of each category since the `previous` report, which was created
+    /// `elapsed_secs` seconds earlier.
+    pub fn set_growth_since(&mut self, previous: &Self, elapsed_secs: f64) {
+        let blocks = self.best_block.saturating_sub(previous.best_block);
+        for category in &mut self.categories {
+            let previous_size = previous
+                .categories
+                .iter()
+                .find(|size| size.category == category.category)
+                .map_or(0, CategorySize::size);
+            let growth = category.size() as f64 - previous_size as f64;
+            category.growth = Some(StorageGrowth {
+                bytes_per_hour: (elapsed_secs > 0.0).then(|| growth * 3600.0 / elapsed_secs),
+                bytes_per_block: (blocks > 0).then(|| growth / blocks as f64),
+            });
+        }
+    }
+}
+
+/// Disk usage of a category.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategorySize {
+    /// The category.
+    pub category: StorageCategory,
+    /// The size of the category's database tables, in bytes.
+    pub database_size: u64,
+    /// The size of the category's static files, in bytes.
+    pub static_files_size: u64,
+    /// The growth of the category, if it's known.
+    pub growth: Option<StorageGrowth>,
+}
+
+impl CategorySize {
+    /// Creates an empty entry for the category.
+    pub const fn new(category: StorageCategory) -> Self {
+        Self { category, database_size: 0, static_files_size: 0, growth: None }
+    }
+
+    /// Returns the total size of the category, in bytes.
+    pub const fn size(&self) -> u64 {
+        self.database_size + self.static_files_size
+    }
+}
+
+/// Growth rate of a category.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageGrowth {
+    /// Bytes added per hour, negative if the category shrank.
+    pub bytes_per_hour: Option<f64>,
+    /// Bytes added per block, negative if the category shrank.
+    pub bytes_per_block: Option<f64>,
+}
+
+/// Disk usage of a database table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableSize {
+    /// The name of the table.
+    pub name: String,
+    /// The category of the table.
+    pub category: StorageCategory,
+    /// The size of the table's pages, in bytes.
+    pub size: u64,
+    /// The number of entries of the table.
+    pub entries: u64,
+}
+
+impl TableSize {
+    /// Creates an entry for the table with the given name, attributed to its category.
+    pub fn new(name: impl Into<String>, size: u64, entries: u64) -> Self {
+        let name = name.into();
+        Self { category: StorageCategory::of_table(&name), name, size, entries }
+    }
+}
+
+/// Disk usage of a static file segment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StaticFileSegmentSize {
+    /// The name of the segment.
+    pub segment: String,
+    /// The category of the segment.
+    pub category: StorageCategory,
+    /// The size of the segment's data, index, offsets and config files, in bytes.
+    pub size: u64,
+    /// The number of files of the segment.
+    pub files: u64,
+    /// The number of rows of the segment.
+    pub entries: u64,
+}
+
+impl StaticFileSegmentSize {
+    /// Creates an entry for the segment with the given name, attributed to its category.
+    pub fn new(segment: impl Into<String>, size: u64, files: u64, entries: u64) -> Self {
+        let segment = segment.into();
+        Self {
+            category: StorageCategory::of_static_file_segment(&segment),
+            segment,
+            size,
+            files,
+            entries,
+        }
+    }
+}
+
+/// Blocks of a prune segment that are due for pruning but were not pruned yet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneBacklog {
+    /// The name of the prune segment.
+    pub segment: String,
+    /// The block up to which the segment was pruned, if it was pruned before.
+    pub pruned_block: Option<u64>,
+    /// The block up to which the segment should be pruned.
+    pub target_block: u64,
+    /// The number of blocks between the pruned and the target block.
+    pub blocks: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn sums_up_categories() {
+        let tables = vec![
+            TableSize::new("PlainStorageState", 100, 1),
+            TableSize::new("Receipts", 10, 1),
+            TableSize::new("HashedStorages", 50, 1),
+            TableSize::new("StageCheckpoints", 1, 1),
+        ];
+        let static_files = vec![StaticFileSegmentSize::new("receipts", 1_000, 2, 10)];
+        let previous = StorageBreakdown::new(10, tables.clone(), static_files, vec![]);
+
+        assert_eq!(previous.total_size, 1_161);
+        assert_eq!(previous.tables[0].name, "HashedStorages");
+        assert_eq!(
+            previous.categories.iter().map(|size| (size.category, size.size())).collect::<Vec<_>>(),
+            vec![
+                (StorageCategory::State, 150),
+                (StorageCategory::Receipts, 1_010),
+                (StorageCategory::Other, 1)
+            ]
+        );
+
+        let static_files = vec![StaticFileSegmentSize::new("receipts", 1_500, 2, 15)];
+        let mut current = StorageBreakdown::new(20, tables, static_files, vec![]);
+        current.set_growth_since(&previous, 1_800.0);
+
+        let receipts = &current.categories[1];
+        assert_eq!(
+            receipts.growth,
+            Some(StorageGrowth { bytes_per_hour: Some(1_000.0), bytes_per_block: Some(50.0) })
+        );
+        assert_eq!(
+            current.categories[0].growth,
+            Some(StorageGrowth { bytes_per_hour: Some(0.0), bytes_per_block: Some(0.0) })
+        );
+    }
+}
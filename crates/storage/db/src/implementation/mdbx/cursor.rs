@@ -2,7 +2,8 @@
 
 use super::utils::*;
 use crate::{
-    metrics::{DatabaseEnvMetrics, Operation},
+    metrics::{record_read, DatabaseEnvMetrics, Operation, ReadOperation},
+    read_metrics::ReadMetricsConfig,
     DatabaseError,
 };
 use reth_db_api::{
@@ -31,6 +32,8 @@ pub struct Cursor<K: TransactionKind, T: Table> {
     buf: Vec<u8>,
     /// Reference to metric handles in the DB environment. If `None`, metrics are not recorded.
     metrics: Option<Arc<DatabaseEnvMetrics>>,
+    /// Instrumentation settings of reads in the DB environment. If `None`, reads are not timed.
+    read_metrics: Option<ReadMetricsConfig>,
     /// Phantom data to enforce encoding/decoding.
     _dbi: PhantomData<T>,
 }
@@ -39,8 +42,9 @@ impl<K: TransactionKind, T: Table> Cursor<K, T> {
     pub(crate) const fn new_with_metrics(
         inner: reth_libmdbx::Cursor<K>,
         metrics: Option<Arc<DatabaseEnvMetrics>>,
+        read_metrics: Option<ReadMetricsConfig>,
     ) -> Self {
-        Self { inner, buf: Vec::new(), metrics, _dbi: PhantomData }
+        Self { inner, buf: Vec::new(), metrics, read_metrics, _dbi: PhantomData }
     }
 
     /// If `self.metrics` is `Some(...)`, record a metric with the provided operation and value
@@ -59,6 +63,25 @@ impl<K: TransactionKind, T: Table> Cursor<K, T> {
             f(self)
         }
     }
+
+    /// If reads are instrumented, record a metric with the provided read operation if
+    /// `self.metrics` is `Some(...)`, and log the read if it was slow.
+    ///
+    /// Otherwise, just execute the closure.
+    fn execute_with_read_metric<R>(
+        &mut self,
+        operation: ReadOperation,
+        f: impl FnOnce(&mut Self) -> R,
+    ) -> R {
+        if let Some(read_metrics) =
+            self.read_metrics.as_ref().filter(|read_metrics| read_metrics.is_active()).cloned()
+        {
+            let metrics = self.metrics.clone();
+            record_read(metrics.as_deref(), &read_metrics, T::NAME, operation, || f(self))
+        } else {
+            f(self)
+        }
+    }
 }
 
 /// Decodes a `(key, value)` pair from the database.
@@ -90,31 +113,45 @@ macro_rules! compress_to_buf_or_ref {
 
 impl<K: TransactionKind, T: Table> DbCursorRO<T> for Cursor<K, T> {
     fn first(&mut self) -> PairResult<T> {
-        decode::<T>(self.inner.first())
+        self.execute_with_read_metric(ReadOperation::CursorSeek, |this| {
+            decode::<T>(this.inner.first())
+        })
     }
 
     fn seek_exact(&mut self, key: <T as Table>::Key) -> PairResult<T> {
-        decode::<T>(self.inner.set_key(key.encode().as_ref()))
+        self.execute_with_read_metric(ReadOperation::CursorSeek, |this| {
+            decode::<T>(this.inner.set_key(key.encode().as_ref()))
+        })
     }
 
     fn seek(&mut self, key: <T as Table>::Key) -> PairResult<T> {
-        decode::<T>(self.inner.set_range(key.encode().as_ref()))
+        self.execute_with_read_metric(ReadOperation::CursorSeek, |this| {
+            decode::<T>(this.inner.set_range(key.encode().as_ref()))
+        })
     }
 
     fn next(&mut self) -> PairResult<T> {
-        decode::<T>(self.inner.next())
+        self.execute_with_read_metric(ReadOperation::CursorNext, |this| {
+            decode::<T>(this.inner.next())
+        })
     }
 
     fn prev(&mut self) -> PairResult<T> {
-        decode::<T>(self.inner.prev())
+        self.execute_with_read_metric(ReadOperation::CursorNext, |this| {
+            decode::<T>(this.inner.prev())
+        })
     }
 
     fn last(&mut self) -> PairResult<T> {
-        decode::<T>(self.inner.last())
+        self.execute_with_read_metric(ReadOperation::CursorSeek, |this| {
+            decode::<T>(this.inner.last())
+        })
     }
 
     fn current(&mut self) -> PairResult<T> {
-        decode::<T>(self.inner.get_current())
+        self.execute_with_read_metric(ReadOperation::CursorNext, |this| {
+            decode::<T>(this.inner.get_current())
+        })
     }
 
     fn walk(&mut self, start_key: Option<T::Key>) -> Result<Walker<'_, T, Self>, DatabaseError> {
@@ -160,21 +197,27 @@ impl<K: TransactionKind, T: Table> DbCursorRO<T> for Cursor<K, T> {
 impl<K: TransactionKind, T: DupSort> DbDupCursorRO<T> for Cursor<K, T> {
     /// Returns the next `(key, value)` pair of a DUPSORT table.
     fn next_dup(&mut self) -> PairResult<T> {
-        decode::<T>(self.inner.next_dup())
+        self.execute_with_read_metric(ReadOperation::CursorNext, |this| {
+            decode::<T>(this.inner.next_dup())
+        })
     }
 
     /// Returns the next `(key, value)` pair skipping the duplicates.
     fn next_no_dup(&mut self) -> PairResult<T> {
-        decode::<T>(self.inner.next_nodup())
+        self.execute_with_read_metric(ReadOperation::CursorNext, |this| {
+            decode::<T>(this.inner.next_nodup())
+        })
     }
 
     /// Returns the next `value` of a duplicate `key`.
     fn next_dup_val(&mut self) -> ValueOnlyResult<T> {
-        self.inner
-            .next_dup()
-            .map_err(|e| DatabaseError::Read(e.into()))?
-            .map(decode_value::<T>)
-            .transpose()
+        self.execute_with_read_metric(ReadOperation::CursorNext, |this| {
+            this.inner
+                .next_dup()
+                .map_err(|e| DatabaseError::Read(e.into()))?
+                .map(decode_value::<T>)
+                .transpose()
+        })
     }
 
     fn seek_by_key_subkey(
@@ -182,11 +225,13 @@ impl<K: TransactionKind, T: DupSort> DbDupCursorRO<T> for Cursor<K, T> {
         key: <T as Table>::Key,
         subkey: <T as DupSort>::SubKey,
     ) -> ValueOnlyResult<T> {
-        self.inner
-            .get_both_range(key.encode().as_ref(), subkey.encode().as_ref())
-            .map_err(|e| DatabaseError::Read(e.into()))?
-            .map(decode_one::<T>)
-            .transpose()
+        self.execute_with_read_metric(ReadOperation::CursorSeek, |this| {
+            this.inner
+                .get_both_range(key.encode().as_ref(), subkey.encode().as_ref())
+                .map_err(|e| DatabaseError::Read(e.into()))?
+                .map(decode_one::<T>)
+                .transpose()
+        })
     }
 
     /// Depending on its arguments, returns an iterator starting at:
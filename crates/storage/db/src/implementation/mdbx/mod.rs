@@ -3,6 +3,7 @@
 use crate::{
     lockfile::StorageLock,
     metrics::DatabaseEnvMetrics,
+    read_metrics::ReadMetricsConfig,
     tables::{self, Tables},
     utils::default_page_size,
     DatabaseError, TableSet,
@@ -21,7 +22,7 @@ use reth_libmdbx::{
     MaxReadTransactionDuration, Mode, PageSize, SyncMode, RO, RW,
 };
 use reth_storage_errors::db::LogLevel;
-use reth_tracing::tracing::error;
+use reth_tracing::tracing::{error, warn};
 use std::{
     ops::{Deref, Range},
     path::Path,
@@ -99,6 +100,16 @@ pub struct DatabaseArguments {
     ///
     /// This flag affects only at environment opening but can't be changed after.
     exclusive: Option<bool>,
+    /// How commits are flushed to disk in read-write mode. If [None], [`SyncMode::Durable`] is
+    /// used.
+    sync_mode: Option<SyncMode>,
+    /// Disable OS readahead of the database file. If [None], readahead is disabled.
+    ///
+    /// Readahead improves the performance of linear scans, but worsens it for random access.
+    no_readahead: Option<bool>,
+    /// Instrumentation settings of database reads, which can be changed while the database is
+    /// open. Reads are not instrumented by default.
+    read_metrics: ReadMetricsConfig,
 }
 
 impl Default for DatabaseArguments {
@@ -121,6 +132,9 @@ impl DatabaseArguments {
             log_level: None,
             max_read_transaction_duration: None,
             exclusive: None,
+            sync_mode: None,
+            no_readahead: None,
+            read_metrics: ReadMetricsConfig::default(),
         }
     }
 
@@ -161,6 +175,42 @@ impl DatabaseArguments {
         self
     }
 
+    /// Set the sync mode of read-write transactions.
+    pub const fn with_sync_mode(mut self, sync_mode: Option<SyncMode>) -> Self {
+        self.sync_mode = sync_mode;
+        self
+    }
+
+    /// Set whether OS readahead of the database file is disabled.
+    pub const fn with_no_readahead(mut self, no_readahead: Option<bool>) -> Self {
+        self.no_readahead = no_readahead;
+        self
+    }
+
+    /// Set the instrumentation settings of database reads.
+    pub fn with_read_metrics(mut self, read_metrics: ReadMetricsConfig) -> Self {
+        self.read_metrics = read_metrics;
+        self
+    }
+
+    /// Validates the database geometry.
+    ///
+    /// Returns an error if the growth step is zero or exceeds the maximum database size.
+    pub fn validate(&self) -> Result<(), DatabaseError> {
+        let max_size = self.geometry.size.as_ref().map(|size| size.end);
+        match (self.geometry.growth_step, max_size) {
+            (Some(growth_step), _) if growth_step <= 0 => Err(DatabaseError::Other(
+                "database growth step must be greater than zero".to_string(),
+            )),
+            (Some(growth_step), Some(max_size)) if growth_step as usize > max_size => {
+                Err(DatabaseError::Other(format!(
+                    "database growth step {growth_step} exceeds the maximum database size {max_size}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Returns the client version if any.
     pub const fn client_version(&self) -> &ClientVersion {
         &self.client_version
@@ -174,6 +224,8 @@ pub struct DatabaseEnv {
     inner: Environment,
     /// Cache for metric handles. If `None`, metrics are not recorded.
     metrics: Option<Arc<DatabaseEnvMetrics>>,
+    /// Instrumentation settings of reads.
+    read_metrics: ReadMetricsConfig,
     /// Write lock for when dealing with a read-write environment.
     _lock_file: Option<StorageLock>,
 }
@@ -186,6 +238,7 @@ impl Database for DatabaseEnv {
         Tx::new_with_metrics(
             self.inner.begin_ro_txn().map_err(|e| DatabaseError::InitTx(e.into()))?,
             self.metrics.clone(),
+            self.read_metrics.clone(),
         )
         .map_err(|e| DatabaseError::InitTx(e.into()))
     }
@@ -194,6 +247,7 @@ impl Database for DatabaseEnv {
         Tx::new_with_metrics(
             self.inner.begin_rw_txn().map_err(|e| DatabaseError::InitTx(e.into()))?,
             self.metrics.clone(),
+            self.read_metrics.clone(),
         )
         .map_err(|e| DatabaseError::InitTx(e.into()))
     }
@@ -274,6 +328,32 @@ impl DatabaseMetrics for DatabaseEnv {
             vec![],
         ));
 
+        if let Ok(info) = self.info().map_err(|error| error!(%error, "Failed to read db.info")) {
+            metrics.push(("db.reader_slots_used", info.num_readers() as f64, vec![]));
+            metrics.push(("db.reader_slots_max", info.max_readers() as f64, vec![]));
+
+            let geometry = info.geometry();
+            metrics.push(("db.geometry_current_size", geometry.current() as f64, vec![]));
+            metrics.push(("db.geometry_max_size", geometry.max() as f64, vec![]));
+            metrics.push(("db.geometry_growth_step", geometry.grow() as f64, vec![]));
+        }
+
+        metrics.push((
+            "db.active_read_transactions",
+            self.active_read_transactions() as f64,
+            vec![],
+        ));
+        metrics.push((
+            "db.long_read_transactions",
+            self.long_read_transactions(tx::LONG_TRANSACTION_DURATION) as f64,
+            vec![],
+        ));
+        metrics.push((
+            "db.oldest_read_transaction_duration",
+            self.oldest_read_transaction_duration().unwrap_or_default().as_secs_f64(),
+            vec![],
+        ));
+
         metrics
     }
 }
@@ -287,6 +367,8 @@ impl DatabaseEnv {
         kind: DatabaseEnvKind,
         args: DatabaseArguments,
     ) -> Result<Self, DatabaseError> {
+        args.validate()?;
+
         let _lock_file = if kind.is_rw() {
             StorageLock::try_acquire(path)
                 .map_err(|err| DatabaseError::Other(err.to_string()))?
@@ -302,7 +384,15 @@ impl DatabaseEnv {
             DatabaseEnvKind::RW => {
                 // enable writemap mode in RW mode
                 inner_env.write_map();
-                Mode::ReadWrite { sync_mode: SyncMode::Durable }
+                let sync_mode = args.sync_mode.unwrap_or(SyncMode::Durable);
+                if !matches!(sync_mode, SyncMode::Durable) {
+                    warn!(
+                        target: "storage::db::mdbx",
+                        ?sync_mode,
+                        "Database commits are not durable, a system crash may undo the latest committed transactions"
+                    );
+                }
+                Mode::ReadWrite { sync_mode }
             }
         };
 
@@ -359,9 +449,9 @@ impl DatabaseEnv {
 
         inner_env.set_flags(EnvironmentFlags {
             mode,
-            // We disable readahead because it improves performance for linear scans, but
-            // worsens it for random access (which is our access pattern outside of sync)
-            no_rdahead: true,
+            // We disable readahead by default because it improves performance for linear scans,
+            // but worsens it for random access (which is our access pattern outside of sync)
+            no_rdahead: args.no_readahead.unwrap_or(true),
             coalesce: true,
             exclusive: args.exclusive.unwrap_or_default(),
             ..Default::default()
@@ -428,6 +518,7 @@ impl DatabaseEnv {
         let env = Self {
             inner: inner_env.open(path).map_err(|e| DatabaseError::Open(e.into()))?,
             metrics: None,
+            read_metrics: args.read_metrics,
             _lock_file,
         };
 
@@ -440,6 +531,12 @@ impl DatabaseEnv {
         self
     }
 
+    /// Returns the instrumentation settings of reads, which can be changed while the database is
+    /// open.
+    pub const fn read_metrics(&self) -> &ReadMetricsConfig {
+        &self.read_metrics
+    }
+
     /// Creates all the tables defined in [`Tables`], if necessary.
     pub fn create_tables(&self) -> Result<(), DatabaseError> {
         self.create_tables_for::<Tables>()
@@ -512,7 +609,7 @@ mod tests {
     use reth_libmdbx::Error;
     use reth_primitives_traits::{Account, StorageEntry};
     use reth_storage_errors::db::{DatabaseWriteError, DatabaseWriteOperation};
-    use std::str::FromStr;
+    use std::{str::FromStr, time::Duration};
     use tempfile::TempDir;
 
     /// Create database for testing
@@ -547,6 +644,65 @@ mod tests {
         create_test_db(DatabaseEnvKind::RW);
     }
 
+    #[test]
+    fn db_creation_with_environment_tuning() {
+        let path = TempDir::new().expect(ERROR_TEMPDIR).into_path();
+        let args = DatabaseArguments::new(ClientVersion::default())
+            .with_geometry_max_size(Some(GIGABYTE))
+            .with_growth_step(Some(64 * MEGABYTE))
+            .with_sync_mode(Some(SyncMode::SafeNoSync))
+            .with_no_readahead(Some(false));
+        let env = DatabaseEnv::open(&path, DatabaseEnvKind::RW, args).expect(ERROR_DB_CREATION);
+
+        let geometry = env.info().unwrap().geometry();
+        assert_eq!(geometry.max(), GIGABYTE as u64);
+        assert_eq!(geometry.grow(), 64 * MEGABYTE as u64);
+    }
+
+    #[test]
+    fn db_arguments_validation() {
+        let args = DatabaseArguments::new(ClientVersion::default())
+            .with_geometry_max_size(Some(GIGABYTE))
+            .with_growth_step(Some(4 * GIGABYTE));
+        assert!(args.validate().is_err());
+
+        let path = TempDir::new().expect(ERROR_TEMPDIR).into_path();
+        assert!(DatabaseEnv::open(&path, DatabaseEnvKind::RW, args).is_err());
+
+        let args = DatabaseArguments::new(ClientVersion::default()).with_growth_step(Some(0));
+        assert!(args.validate().is_err());
+    }
+
+    #[test]
+    fn db_read_metrics_config() {
+        let read_metrics = ReadMetricsConfig::new(false, None);
+        let path = TempDir::new().expect(ERROR_TEMPDIR).into_path();
+        let env = DatabaseEnv::open(
+            &path,
+            DatabaseEnvKind::RW,
+            DatabaseArguments::new(ClientVersion::default())
+                .with_read_metrics(read_metrics.clone()),
+        )
+        .expect(ERROR_DB_CREATION);
+        env.create_tables().expect(ERROR_TABLE_CREATION);
+
+        // the settings are shared with the opened database
+        assert_eq!(env.read_metrics(), &read_metrics);
+        read_metrics.set_enabled(true);
+        read_metrics.set_slow_read_threshold(Some(Duration::from_millis(50)));
+        assert!(env.read_metrics().is_enabled());
+        assert_eq!(env.read_metrics().slow_read_threshold(), Some(Duration::from_millis(50)));
+
+        // instrumented reads return the same results
+        let tx = env.tx_mut().expect(ERROR_INIT_TX);
+        tx.put::<Headers>(1, Header::default()).expect(ERROR_PUT);
+        tx.commit().expect(ERROR_COMMIT);
+        let tx = env.tx().expect(ERROR_INIT_TX);
+        assert_eq!(tx.get::<Headers>(1).expect(ERROR_GET), Some(Header::default()));
+        let mut cursor = tx.cursor_read::<Headers>().unwrap();
+        assert_eq!(cursor.first().expect(ERROR_GET), Some((1, Header::default())));
+    }
+
     #[test]
     fn db_manual_put_get() {
         let env = create_test_db(DatabaseEnvKind::RW);
@@ -2,7 +2,12 @@
 
 use super::{cursor::Cursor, utils::*};
 use crate::{
-    metrics::{DatabaseEnvMetrics, Operation, TransactionMode, TransactionOutcome},
+    metrics::{
+        record_read, DatabaseEnvMetrics, Operation, ReadOperation, TransactionMode,
+        TransactionOutcome,
+    },
+    read_metrics::ReadMetricsConfig,
+    tables::Tables,
     DatabaseError,
 };
 use reth_db_api::{
@@ -23,7 +28,7 @@ use std::{
 };
 
 /// Duration after which we emit the log about long-lived database transactions.
-const LONG_TRANSACTION_DURATION: Duration = Duration::from_secs(60);
+pub(crate) const LONG_TRANSACTION_DURATION: Duration = Duration::from_secs(60);
 
 /// Wrapper for the libmdbx transaction.
 #[derive(Debug)]
@@ -36,21 +41,27 @@ pub struct Tx<K: TransactionKind> {
     ///
     /// If [Some], then metrics are reported.
     metrics_handler: Option<MetricsHandler<K>>,
+
+    /// Instrumentation settings of reads of the DB environment. If [None], reads are not timed.
+    read_metrics: Option<ReadMetricsConfig>,
 }
 
 impl<K: TransactionKind> Tx<K> {
     /// Creates new `Tx` object with a `RO` or `RW` transaction.
     #[inline]
     pub const fn new(inner: Transaction<K>) -> Self {
-        Self::new_inner(inner, None)
+        Self::new_inner(inner, None, None)
     }
 
     /// Creates new `Tx` object with a `RO` or `RW` transaction and optionally enables metrics.
+    ///
+    /// Reads are instrumented according to `read_metrics`.
     #[inline]
     #[track_caller]
     pub(crate) fn new_with_metrics(
         inner: Transaction<K>,
         env_metrics: Option<Arc<DatabaseEnvMetrics>>,
+        read_metrics: ReadMetricsConfig,
     ) -> reth_libmdbx::Result<Self> {
         let metrics_handler = env_metrics
             .map(|env_metrics| {
@@ -60,12 +71,16 @@ impl<K: TransactionKind> Tx<K> {
                 Ok(handler)
             })
             .transpose()?;
-        Ok(Self::new_inner(inner, metrics_handler))
+        Ok(Self::new_inner(inner, metrics_handler, Some(read_metrics)))
     }
 
     #[inline]
-    const fn new_inner(inner: Transaction<K>, metrics_handler: Option<MetricsHandler<K>>) -> Self {
-        Self { inner, metrics_handler }
+    const fn new_inner(
+        inner: Transaction<K>,
+        metrics_handler: Option<MetricsHandler<K>>,
+        read_metrics: Option<ReadMetricsConfig>,
+    ) -> Self {
+        Self { inner, metrics_handler, read_metrics }
     }
 
     /// Gets this transaction ID.
@@ -91,6 +106,7 @@ impl<K: TransactionKind> Tx<K> {
         Ok(Cursor::new_with_metrics(
             inner,
             self.metrics_handler.as_ref().map(|h| h.env_metrics.clone()),
+            self.read_metrics.clone(),
         ))
     }
 
@@ -160,6 +176,20 @@ impl<K: TransactionKind> Tx<K> {
             f(&self.inner)
         }
     }
+
+    /// If reads are instrumented, record a metric with the provided read operation if
+    /// `self.metrics_handler == Some(_)`, and log the read if it was slow.
+    ///
+    /// Otherwise, just execute the closure.
+    fn execute_with_read_metric<T: Table, R>(
+        &self,
+        operation: ReadOperation,
+        f: impl FnOnce(&Transaction<K>) -> R,
+    ) -> R {
+        let Some(read_metrics) = &self.read_metrics else { return f(&self.inner) };
+        let env_metrics = self.metrics_handler.as_ref().map(|handler| &*handler.env_metrics);
+        record_read(env_metrics, read_metrics, T::NAME, operation, || f(&self.inner))
+    }
 }
 
 #[derive(Debug)]
@@ -289,11 +319,13 @@ impl<K: TransactionKind> DbTx for Tx<K> {
         &self,
         key: &<T::Key as Encode>::Encoded,
     ) -> Result<Option<T::Value>, DatabaseError> {
-        self.execute_with_operation_metric::<T, _>(Operation::Get, None, |tx| {
-            tx.get(self.get_dbi::<T>()?, key.as_ref())
-                .map_err(|e| DatabaseError::Read(e.into()))?
-                .map(decode_one::<T>)
-                .transpose()
+        self.execute_with_operation_metric::<T, _>(Operation::Get, None, |_| {
+            self.execute_with_read_metric::<T, _>(ReadOperation::Get, |tx| {
+                tx.get(self.get_dbi::<T>()?, key.as_ref())
+                    .map_err(|e| DatabaseError::Read(e.into()))?
+                    .map(decode_one::<T>)
+                    .transpose()
+            })
         })
     }
 
@@ -331,6 +363,21 @@ impl<K: TransactionKind> DbTx for Tx<K> {
             .entries())
     }
 
+    fn table_sizes(&self) -> Result<Vec<(&'static str, u64, u64)>, DatabaseError> {
+        Tables::ALL
+            .iter()
+            .map(|table| {
+                let db = self
+                    .inner
+                    .open_db(Some(table.name()))
+                    .map_err(|e| DatabaseError::Open(e.into()))?;
+                let stats = self.inner.db_stat(&db).map_err(|e| DatabaseError::Stats(e.into()))?;
+                let pages = stats.leaf_pages() + stats.branch_pages() + stats.overflow_pages();
+                Ok((table.name(), stats.page_size() as u64 * pages as u64, stats.entries() as u64))
+            })
+            .collect()
+    }
+
     /// Disables long-lived read transaction safety guarantees, such as backtrace recording and
     /// timeout.
     fn disable_long_read_transaction_safety(&mut self) {
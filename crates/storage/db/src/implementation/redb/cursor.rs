@@ -0,0 +1,363 @@
+//! Cursor wrapper for redb.
+
+use super::{
+    dup_prefix, dup_prefix_end, error_info, split_dup_key,
+    tx::{Query, TransactionKind, TxInner, RW},
+};
+use crate::DatabaseError;
+use reth_db_api::{
+    common::{PairResult, ValueOnlyResult},
+    cursor::{
+        DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW, DupWalker, RangeWalker,
+        ReverseWalker, Walker,
+    },
+    table::{Compress, Decode, Decompress, DupSort, Encode, Table},
+};
+use reth_storage_errors::db::{DatabaseWriteError, DatabaseWriteOperation};
+use std::{
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+    sync::Arc,
+};
+
+/// Cursor wrapper to access KV items.
+///
+/// redb has no cursors, so the cursor keeps the raw key it's positioned at and every movement is a
+/// range query relative to that key, on the table that's kept open by the transaction.
+#[derive(Debug)]
+pub struct Cursor<K: TransactionKind, T: Table> {
+    /// Transaction the cursor was opened in.
+    tx: Arc<TxInner>,
+    /// Raw key the cursor is positioned at, `None` if the cursor is not positioned.
+    position: Option<Vec<u8>>,
+    /// Phantom data to enforce encoding/decoding.
+    _dbi: PhantomData<(K, T)>,
+}
+
+impl<K: TransactionKind, T: Table> Cursor<K, T> {
+    pub(crate) const fn new(tx: Arc<TxInner>) -> Self {
+        Self { tx, position: None, _dbi: PhantomData }
+    }
+
+    /// Returns the raw key of the given encoded key that a seek starts at.
+    fn seek_key(key: &[u8]) -> Vec<u8> {
+        if T::DUPSORT {
+            dup_prefix(key)
+        } else {
+            key.to_vec()
+        }
+    }
+
+    /// Returns the [`dup_prefix`] of the row the cursor is positioned at.
+    fn position_prefix(&self) -> Result<Option<Vec<u8>>, DatabaseError> {
+        let Some(position) = &self.position else { return Ok(None) };
+        let (_, prefix_len) = split_dup_key(position)?;
+        Ok(Some(position[..prefix_len].to_vec()))
+    }
+
+    /// Executes the query, moves the cursor to the returned pair and decodes it.
+    ///
+    /// Pairs whose raw key doesn't start with `prefix` are not found. If nothing is found, the
+    /// cursor keeps its position if `keep_position` is `true`, and is unpositioned otherwise.
+    fn query(
+        &mut self,
+        query: Query<'_>,
+        prefix: Option<&[u8]>,
+        keep_position: bool,
+    ) -> PairResult<T> {
+        let pair = self
+            .tx
+            .query(T::NAME, query)?
+            .filter(|(raw, _)| prefix.is_none_or(|prefix| raw.starts_with(prefix)));
+        let Some((raw, value)) = pair else {
+            if !keep_position {
+                self.position = None;
+            }
+            return Ok(None)
+        };
+        let row = decode::<T>(&raw, value)?;
+        self.position = Some(raw);
+        Ok(Some(row))
+    }
+
+    /// Executes the query relative to the position of the cursor, which is kept if nothing is
+    /// found.
+    fn query_from_position(
+        &mut self,
+        query: impl FnOnce(&[u8]) -> Query<'_>,
+        prefix: Option<&[u8]>,
+    ) -> PairResult<T> {
+        let Some(position) = self.position.take() else { return Ok(None) };
+        let row = self.query(query(&position), prefix, true);
+        self.position.get_or_insert(position);
+        row
+    }
+
+    /// Returns the pair with the given key and the first value that's greater than or equal to
+    /// the subkey.
+    fn seek_subkey(&mut self, key: &[u8], subkey: &[u8]) -> PairResult<T> {
+        let prefix = dup_prefix(key);
+        let seek = [prefix.as_slice(), subkey].concat();
+        self.query(Query::Seek(&seek), Some(&prefix), false)
+    }
+
+    /// Writes the raw pair, after checking that the operation is allowed with `check`, and
+    /// positions the cursor at it.
+    fn write(
+        &mut self,
+        key: &[u8],
+        value: &T::Value,
+        operation: DatabaseWriteOperation,
+        check: impl FnOnce(&mut Self, &[u8]) -> Result<Option<&'static str>, DatabaseError>,
+    ) -> Result<(), DatabaseError> {
+        let write_error = |message: String| {
+            DatabaseError::from(DatabaseWriteError {
+                info: error_info(message),
+                operation,
+                table_name: T::NAME,
+                key: key.to_vec(),
+            })
+        };
+
+        let mut buf = Vec::new();
+        let value = match value.uncompressable_ref() {
+            Some(value) => value,
+            None => {
+                value.compress_to_buf(&mut buf);
+                &buf
+            }
+        };
+        let (raw_key, raw_value) = if T::DUPSORT {
+            ([dup_prefix(key), value.to_vec()].concat(), &[][..])
+        } else {
+            (key.to_vec(), value)
+        };
+
+        if let Some(message) = check(self, &raw_key)? {
+            return Err(write_error(message.to_string()))
+        }
+
+        self.tx.write(T::NAME, |table| {
+            table
+                .insert(raw_key.as_slice(), raw_value)
+                .map(|_| ())
+                .map_err(|e| write_error(e.to_string()))
+        })?;
+        self.position = Some(raw_key);
+
+        Ok(())
+    }
+}
+
+/// Decodes a raw pair of table `T`.
+fn decode<T: Table>(raw: &[u8], value: Vec<u8>) -> Result<(T::Key, T::Value), DatabaseError> {
+    if T::DUPSORT {
+        let (key, prefix_len) = split_dup_key(raw)?;
+        Ok((T::Key::decode(&key)?, T::Value::decompress(&raw[prefix_len..])?))
+    } else {
+        Ok((T::Key::decode(raw)?, T::Value::decompress_owned(value)?))
+    }
+}
+
+impl<K: TransactionKind, T: Table> DbCursorRO<T> for Cursor<K, T> {
+    fn first(&mut self) -> PairResult<T> {
+        self.query(Query::First, None, false)
+    }
+
+    fn seek_exact(&mut self, key: <T as Table>::Key) -> PairResult<T> {
+        let key = key.encode();
+        if T::DUPSORT {
+            let prefix = dup_prefix(key.as_ref());
+            self.query(Query::Seek(&prefix), Some(&prefix), false)
+        } else {
+            self.query(Query::Get(key.as_ref()), None, false)
+        }
+    }
+
+    fn seek(&mut self, key: <T as Table>::Key) -> PairResult<T> {
+        let key = Self::seek_key(key.encode().as_ref());
+        self.query(Query::Seek(&key), None, false)
+    }
+
+    fn next(&mut self) -> PairResult<T> {
+        if self.position.is_none() {
+            return self.first()
+        }
+        self.query_from_position(Query::Next, None)
+    }
+
+    fn prev(&mut self) -> PairResult<T> {
+        if self.position.is_none() {
+            return self.last()
+        }
+        self.query_from_position(Query::Prev, None)
+    }
+
+    fn last(&mut self) -> PairResult<T> {
+        self.query(Query::Last, None, false)
+    }
+
+    fn current(&mut self) -> PairResult<T> {
+        self.query_from_position(Query::Get, None)
+    }
+
+    fn walk(&mut self, start_key: Option<T::Key>) -> Result<Walker<'_, T, Self>, DatabaseError> {
+        let start = if let Some(start_key) = start_key {
+            self.seek(start_key).transpose()
+        } else {
+            self.first().transpose()
+        };
+
+        Ok(Walker::new(self, start))
+    }
+
+    fn walk_range(
+        &mut self,
+        range: impl RangeBounds<T::Key>,
+    ) -> Result<RangeWalker<'_, T, Self>, DatabaseError> {
+        let start = match range.start_bound().cloned() {
+            Bound::Included(key) => self.seek(key),
+            Bound::Excluded(_key) => {
+                unreachable!("Rust doesn't allow for Bound::Excluded in starting bounds");
+            }
+            Bound::Unbounded => self.first(),
+        }
+        .transpose();
+        Ok(RangeWalker::new(self, start, range.end_bound().cloned()))
+    }
+
+    fn walk_back(
+        &mut self,
+        start_key: Option<T::Key>,
+    ) -> Result<ReverseWalker<'_, T, Self>, DatabaseError> {
+        let start =
+            if let Some(start_key) = start_key { self.seek(start_key) } else { self.last() }
+                .transpose();
+
+        Ok(ReverseWalker::new(self, start))
+    }
+}
+
+impl<K: TransactionKind, T: DupSort> DbDupCursorRO<T> for Cursor<K, T> {
+    /// Returns the next `(key, value)` pair of a DUPSORT table.
+    fn next_dup(&mut self) -> PairResult<T> {
+        let Some(prefix) = self.position_prefix()? else { return Ok(None) };
+        self.query_from_position(Query::Next, Some(&prefix))
+    }
+
+    /// Returns the next `(key, value)` pair skipping the duplicates.
+    fn next_no_dup(&mut self) -> PairResult<T> {
+        let Some(prefix) = self.position_prefix()? else { return self.first() };
+        let end = dup_prefix_end(&prefix);
+        self.query(Query::Seek(&end), None, true)
+    }
+
+    /// Returns the next `value` of a duplicate `key`.
+    fn next_dup_val(&mut self) -> ValueOnlyResult<T> {
+        Ok(self.next_dup()?.map(|(_, value)| value))
+    }
+
+    fn seek_by_key_subkey(
+        &mut self,
+        key: <T as Table>::Key,
+        subkey: <T as DupSort>::SubKey,
+    ) -> ValueOnlyResult<T> {
+        Ok(self
+            .seek_subkey(key.encode().as_ref(), subkey.encode().as_ref())?
+            .map(|(_, value)| value))
+    }
+
+    /// Depending on its arguments, returns an iterator starting at:
+    /// - Some(key), Some(subkey): a `key` item whose data is >= than `subkey`
+    /// - Some(key), None: first item of a specified `key`
+    /// - None, Some(subkey): like first case, but in the first key
+    /// - None, None: first item in the table of a DUPSORT table.
+    fn walk_dup(
+        &mut self,
+        key: Option<T::Key>,
+        subkey: Option<T::SubKey>,
+    ) -> Result<DupWalker<'_, T, Self>, DatabaseError> {
+        let start = match (key, subkey) {
+            (Some(key), Some(subkey)) => {
+                self.seek_subkey(key.encode().as_ref(), subkey.encode().as_ref())
+            }
+            (Some(key), None) => self.seek_exact(key),
+            (None, Some(subkey)) => match self.first()? {
+                Some((key, _)) => self.seek_subkey(key.encode().as_ref(), subkey.encode().as_ref()),
+                None => Ok(None),
+            },
+            (None, None) => self.first(),
+        }
+        .transpose();
+
+        Ok(DupWalker::<'_, T, Self> { cursor: self, start })
+    }
+}
+
+impl<T: Table> DbCursorRW<T> for Cursor<RW, T> {
+    /// For a DUPSORT table, `upsert` adds the value to the values of the key, like with MDBX.
+    fn upsert(&mut self, key: T::Key, value: &T::Value) -> Result<(), DatabaseError> {
+        let key = key.encode();
+        self.write(key.as_ref(), value, DatabaseWriteOperation::CursorUpsert, |_, _| Ok(None))
+    }
+
+    fn insert(&mut self, key: T::Key, value: &T::Value) -> Result<(), DatabaseError> {
+        let key = key.encode();
+        let prefix = T::DUPSORT.then(|| dup_prefix(key.as_ref()));
+        self.write(key.as_ref(), value, DatabaseWriteOperation::CursorInsert, |this, raw| {
+            let existing = match &prefix {
+                Some(prefix) => this
+                    .tx
+                    .query(T::NAME, Query::Seek(prefix))?
+                    .filter(|(existing, _)| existing.starts_with(prefix)),
+                None => this.tx.query(T::NAME, Query::Get(raw))?,
+            };
+            Ok(existing.map(|_| "key already exists"))
+        })
+    }
+
+    /// Appends the data to the end of the table. Consequently, the append operation
+    /// will fail if the inserted key is less than or equal to the last table key
+    fn append(&mut self, key: T::Key, value: &T::Value) -> Result<(), DatabaseError> {
+        let key = key.encode();
+        self.write(key.as_ref(), value, DatabaseWriteOperation::CursorAppend, |this, raw| {
+            Ok(this
+                .tx
+                .query(T::NAME, Query::Last)?
+                .filter(|(last, _)| last.as_slice() >= raw)
+                .map(|_| "key is not greater than the last key of the table"))
+        })
+    }
+
+    fn delete_current(&mut self) -> Result<(), DatabaseError> {
+        let Some(position) = self.position.as_deref() else { return Ok(()) };
+        self.tx.write(T::NAME, |table| {
+            table.remove(position).map(|_| ()).map_err(|e| DatabaseError::Delete(error_info(e)))
+        })
+    }
+}
+
+impl<T: DupSort> DbDupCursorRW<T> for Cursor<RW, T> {
+    fn delete_current_duplicates(&mut self) -> Result<(), DatabaseError> {
+        let Some(prefix) = self.position_prefix()? else { return Ok(()) };
+        let end = dup_prefix_end(&prefix);
+        self.tx.write(T::NAME, |table| {
+            table
+                .retain_in::<&[u8], _>(prefix.as_slice()..end.as_slice(), |_, _| false)
+                .map_err(|e| DatabaseError::Delete(error_info(e)))
+        })
+    }
+
+    fn append_dup(&mut self, key: T::Key, value: T::Value) -> Result<(), DatabaseError> {
+        let key = key.encode();
+        let prefix = dup_prefix(key.as_ref());
+        let end = dup_prefix_end(&prefix);
+        self.write(key.as_ref(), &value, DatabaseWriteOperation::CursorAppendDup, |this, raw| {
+            Ok(this
+                .tx
+                .query(T::NAME, Query::Prev(&end))?
+                .filter(|(last, _)| last.starts_with(&prefix) && last.as_slice() >= raw)
+                .map(|_| "value is not greater than the last value of the key"))
+        })
+    }
+}
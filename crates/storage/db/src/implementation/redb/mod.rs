@@ -0,0 +1,343 @@
+//! Module that interacts with redb.
+//!
+//! This is an experimental alternative to the MDBX backend, intended for storage research and
+//! embedded use.
+//!
+//! redb has no duplicate keys, so the rows of `DUPSORT` tables are stored as redb keys that consist
+//! of the key and the value, see [`dup_prefix`], with empty redb values.
+
+use crate::{tables::Tables, DatabaseError, TableSet};
+use reth_db_api::{database::Database, database_metrics::DatabaseMetrics};
+use reth_storage_errors::db::DatabaseErrorInfo;
+use std::{fmt::Display, path::Path};
+use tx::{Tx, RO, RW};
+
+pub mod cursor;
+pub mod tx;
+
+/// Name of the database file inside the database directory.
+pub const REDB_FILE_NAME: &str = "reth.redb";
+
+/// Wrapper for the redb database: [`redb::Database`]
+#[derive(Debug)]
+pub struct RedbEnv {
+    /// Inner redb database.
+    inner: redb::Database,
+}
+
+impl Database for RedbEnv {
+    type TX = Tx<RO>;
+    type TXMut = Tx<RW>;
+
+    fn tx(&self) -> Result<Self::TX, DatabaseError> {
+        self.inner.begin_read().map(Tx::new_read).map_err(|e| DatabaseError::InitTx(error_info(e)))
+    }
+
+    fn tx_mut(&self) -> Result<Self::TXMut, DatabaseError> {
+        self.inner
+            .begin_write()
+            .map(Tx::new_write)
+            .map_err(|e| DatabaseError::InitTx(error_info(e)))
+    }
+}
+
+impl DatabaseMetrics for RedbEnv {}
+
+impl RedbEnv {
+    /// Opens the database in the specified directory, creating the database file if it doesn't
+    /// exist.
+    ///
+    /// It does not create the tables, for that call [`RedbEnv::create_tables`].
+    pub fn open(path: &Path) -> Result<Self, DatabaseError> {
+        let inner = redb::Database::create(path.join(REDB_FILE_NAME))
+            .map_err(|e| DatabaseError::Open(error_info(e)))?;
+        Ok(Self { inner })
+    }
+
+    /// Creates all the tables defined in [`Tables`], if necessary.
+    pub fn create_tables(&self) -> Result<(), DatabaseError> {
+        self.create_tables_for::<Tables>()
+    }
+
+    /// Creates all the tables defined in the given [`TableSet`], if necessary.
+    pub fn create_tables_for<TS: TableSet>(&self) -> Result<(), DatabaseError> {
+        let tx = self.inner.begin_write().map_err(|e| DatabaseError::InitTx(error_info(e)))?;
+
+        for table in TS::tables() {
+            tx.open_table(table_definition(table.name()))
+                .map_err(|e| DatabaseError::CreateTable(error_info(e)))?;
+        }
+
+        tx.commit().map_err(|e| DatabaseError::Commit(error_info(e)))?;
+
+        Ok(())
+    }
+}
+
+/// Returns the redb definition of a table with the given name.
+///
+/// Keys and values are stored as raw bytes, encoded and compressed with the codecs of the table.
+pub(crate) const fn table_definition(
+    name: &'static str,
+) -> redb::TableDefinition<'static, &'static [u8], &'static [u8]> {
+    redb::TableDefinition::new(name)
+}
+
+/// Returns the prefix of the redb keys of the rows of a `DUPSORT` table with the given encoded key.
+///
+/// The redb key of a row is the prefix followed by the compressed value, so the rows are ordered by
+/// key and then by value like in MDBX. The prefix is the key with its zero bytes escaped as
+/// `0x00 0xff`, terminated by `0x00 0x00`, which keeps the order of keys of different lengths.
+pub(crate) fn dup_prefix(key: &[u8]) -> Vec<u8> {
+    let mut prefix = Vec::with_capacity(key.len() + 2);
+    for &byte in key {
+        prefix.push(byte);
+        if byte == 0 {
+            prefix.push(0xff);
+        }
+    }
+    prefix.extend_from_slice(&[0, 0]);
+    prefix
+}
+
+/// Returns the smallest redb key that is greater than all redb keys with the given
+/// [`dup_prefix`].
+pub(crate) fn dup_prefix_end(prefix: &[u8]) -> Vec<u8> {
+    let mut end = prefix.to_vec();
+    // the prefix ends with the `0x00 0x00` terminator
+    if let Some(last) = end.last_mut() {
+        *last = 1;
+    }
+    end
+}
+
+/// Returns the encoded key of the redb key of a row of a `DUPSORT` table, and the length of its
+/// [`dup_prefix`].
+pub(crate) fn split_dup_key(raw: &[u8]) -> Result<(Vec<u8>, usize), DatabaseError> {
+    let mut key = Vec::with_capacity(raw.len());
+    let mut bytes = raw.iter().enumerate();
+    while let Some((_, &byte)) = bytes.next() {
+        if byte != 0 {
+            key.push(byte);
+            continue
+        }
+        match bytes.next() {
+            Some((_, 0xff)) => key.push(0),
+            Some((index, 0)) => return Ok((key, index + 1)),
+            _ => break,
+        }
+    }
+    Err(DatabaseError::Decode)
+}
+
+/// Converts a redb error into [`DatabaseErrorInfo`].
+///
+/// redb errors have no error codes, so the code is always `0`.
+pub(crate) fn error_info(error: impl Display) -> DatabaseErrorInfo {
+    DatabaseErrorInfo { message: error.to_string().into(), code: 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        redb::{init_db_with_backend, AnyDatabaseEnv, DatabaseBackend},
+        tables::{CanonicalHeaders, HeaderNumbers, PlainStorageState},
+    };
+    use alloy_primitives::{Address, B256, U256};
+    use reth_db_api::{
+        cursor::{DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW},
+        transaction::{DbTx, DbTxMut},
+    };
+    use reth_primitives_traits::StorageEntry;
+    use tempfile::TempDir;
+
+    fn create_test_db() -> (TempDir, RedbEnv) {
+        let dir = TempDir::new().unwrap();
+        let env = RedbEnv::open(dir.path()).unwrap();
+        env.create_tables().unwrap();
+        (dir, env)
+    }
+
+    #[test]
+    fn redb_put_get_delete() {
+        let (_dir, env) = create_test_db();
+
+        let tx = env.tx_mut().unwrap();
+        tx.put::<CanonicalHeaders>(1, B256::with_last_byte(1)).unwrap();
+        tx.put::<HeaderNumbers>(B256::with_last_byte(1), 1).unwrap();
+        tx.commit().unwrap();
+
+        let tx = env.tx().unwrap();
+        assert_eq!(tx.get::<CanonicalHeaders>(1).unwrap(), Some(B256::with_last_byte(1)));
+        assert_eq!(tx.get::<CanonicalHeaders>(2).unwrap(), None);
+        assert_eq!(tx.get::<HeaderNumbers>(B256::with_last_byte(1)).unwrap(), Some(1));
+        assert_eq!(tx.entries::<CanonicalHeaders>().unwrap(), 1);
+        tx.commit().unwrap();
+
+        let tx = env.tx_mut().unwrap();
+        assert!(tx.delete::<CanonicalHeaders>(1, None).unwrap());
+        assert!(!tx.delete::<CanonicalHeaders>(1, None).unwrap());
+        tx.commit().unwrap();
+
+        assert_eq!(env.tx().unwrap().get::<CanonicalHeaders>(1).unwrap(), None);
+    }
+
+    #[test]
+    fn redb_abort() {
+        let (_dir, env) = create_test_db();
+
+        let tx = env.tx_mut().unwrap();
+        tx.put::<CanonicalHeaders>(1, B256::ZERO).unwrap();
+        tx.abort();
+
+        assert_eq!(env.tx().unwrap().entries::<CanonicalHeaders>().unwrap(), 0);
+    }
+
+    #[test]
+    fn redb_cursor() {
+        let (_dir, env) = create_test_db();
+
+        let tx = env.tx_mut().unwrap();
+        let mut cursor = tx.cursor_write::<CanonicalHeaders>().unwrap();
+        for block in [1, 2, 4, 5] {
+            cursor.append(block, &B256::with_last_byte(block as u8)).unwrap();
+        }
+        assert!(cursor.append(3, &B256::ZERO).is_err());
+        assert!(cursor.insert(4, &B256::ZERO).is_err());
+        cursor.insert(3, &B256::with_last_byte(3)).unwrap();
+        cursor.upsert(5, &B256::with_last_byte(6)).unwrap();
+        tx.commit().unwrap();
+
+        let tx = env.tx().unwrap();
+        let mut cursor = tx.cursor_read::<CanonicalHeaders>().unwrap();
+        assert_eq!(cursor.first().unwrap(), Some((1, B256::with_last_byte(1))));
+        assert_eq!(cursor.next().unwrap(), Some((2, B256::with_last_byte(2))));
+        assert_eq!(cursor.current().unwrap(), Some((2, B256::with_last_byte(2))));
+        assert_eq!(cursor.prev().unwrap(), Some((1, B256::with_last_byte(1))));
+        assert_eq!(cursor.prev().unwrap(), None);
+        assert_eq!(cursor.seek_exact(6).unwrap(), None);
+        assert_eq!(cursor.seek(6).unwrap(), None);
+        assert_eq!(cursor.seek(0).unwrap(), Some((1, B256::with_last_byte(1))));
+        assert_eq!(cursor.last().unwrap(), Some((5, B256::with_last_byte(6))));
+        assert_eq!(cursor.next().unwrap(), None);
+
+        let blocks = cursor.walk_range(2..4).unwrap().map(|row| row.unwrap().0).collect::<Vec<_>>();
+        assert_eq!(blocks, vec![2, 3]);
+        let blocks =
+            cursor.walk_back(Some(3)).unwrap().map(|row| row.unwrap().0).collect::<Vec<_>>();
+        assert_eq!(blocks, vec![3, 2, 1]);
+        tx.commit().unwrap();
+
+        let tx = env.tx_mut().unwrap();
+        let mut cursor = tx.cursor_write::<CanonicalHeaders>().unwrap();
+        let mut walker = cursor.walk(Some(2)).unwrap();
+        while let Some(row) = walker.next() {
+            if row.unwrap().0 % 2 == 0 {
+                walker.delete_current().unwrap();
+            }
+        }
+        tx.commit().unwrap();
+
+        let tx = env.tx().unwrap();
+        let blocks = tx
+            .cursor_read::<CanonicalHeaders>()
+            .unwrap()
+            .walk(None)
+            .unwrap()
+            .map(|row| row.unwrap().0)
+            .collect::<Vec<_>>();
+        assert_eq!(blocks, vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn redb_dup_prefix() {
+        let keys: [&[u8]; 5] = [&[], &[0], &[0, 0], &[0, 1], &[1]];
+        let prefixes = keys.map(dup_prefix);
+        assert!(prefixes.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for (key, prefix) in keys.iter().zip(&prefixes) {
+            let raw = [prefix.as_slice(), &[0, 1, 2]].concat();
+            assert_eq!(split_dup_key(&raw).unwrap(), (key.to_vec(), prefix.len()));
+            assert!(raw < dup_prefix_end(prefix));
+        }
+        assert!(dup_prefix_end(&prefixes[0]) < prefixes[4]);
+    }
+
+    #[test]
+    fn redb_dupsort() {
+        let (_dir, env) = create_test_db();
+        let address = Address::with_last_byte(1);
+        let entry = |slot: u8, value: u64| StorageEntry {
+            key: B256::with_last_byte(slot),
+            value: U256::from(value),
+        };
+
+        let tx = env.tx_mut().unwrap();
+        tx.put::<PlainStorageState>(address, entry(2, 2)).unwrap();
+        tx.put::<PlainStorageState>(address, entry(1, 1)).unwrap();
+        tx.put::<PlainStorageState>(Address::with_last_byte(2), entry(1, 3)).unwrap();
+        let mut cursor = tx.cursor_dup_write::<PlainStorageState>().unwrap();
+        cursor.append_dup(address, entry(3, 3)).unwrap();
+        assert!(cursor.append_dup(address, entry(0, 0)).is_err());
+        assert!(cursor.insert(address, &entry(4, 4)).is_err());
+        tx.commit().unwrap();
+
+        let tx = env.tx().unwrap();
+        assert_eq!(tx.entries::<PlainStorageState>().unwrap(), 4);
+        assert_eq!(tx.get::<PlainStorageState>(address).unwrap(), Some(entry(1, 1)));
+
+        let mut cursor = tx.cursor_dup_read::<PlainStorageState>().unwrap();
+        assert_eq!(
+            cursor.seek_by_key_subkey(address, B256::with_last_byte(2)).unwrap(),
+            Some(entry(2, 2))
+        );
+        assert_eq!(cursor.next_dup_val().unwrap(), Some(entry(3, 3)));
+        assert_eq!(cursor.next_dup().unwrap(), None);
+        assert_eq!(cursor.current().unwrap(), Some((address, entry(3, 3))));
+        assert_eq!(cursor.next().unwrap(), Some((Address::with_last_byte(2), entry(1, 3))));
+        assert_eq!(cursor.seek_exact(address).unwrap(), Some((address, entry(1, 1))));
+        assert_eq!(cursor.next_no_dup().unwrap(), Some((Address::with_last_byte(2), entry(1, 3))));
+
+        let slots = cursor
+            .walk_dup(Some(address), Some(B256::with_last_byte(2)))
+            .unwrap()
+            .map(|row| row.unwrap().1.key)
+            .collect::<Vec<_>>();
+        assert_eq!(slots, vec![B256::with_last_byte(2), B256::with_last_byte(3)]);
+        tx.commit().unwrap();
+
+        let tx = env.tx_mut().unwrap();
+        assert!(tx.delete::<PlainStorageState>(address, Some(entry(2, 2))).unwrap());
+        let mut cursor = tx.cursor_dup_write::<PlainStorageState>().unwrap();
+        cursor.seek_exact(address).unwrap();
+        cursor.delete_current_duplicates().unwrap();
+        assert_eq!(cursor.seek_exact(address).unwrap(), None);
+        assert!(tx.delete::<PlainStorageState>(Address::with_last_byte(2), None).unwrap());
+        assert!(!tx.delete::<PlainStorageState>(Address::with_last_byte(2), None).unwrap());
+        tx.commit().unwrap();
+
+        assert_eq!(env.tx().unwrap().entries::<PlainStorageState>().unwrap(), 0);
+    }
+
+    #[test]
+    fn redb_backend_selection() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(DatabaseBackend::detect(dir.path()), None);
+
+        let db =
+            init_db_with_backend(dir.path(), DatabaseBackend::Redb, Default::default()).unwrap();
+        assert!(matches!(db, AnyDatabaseEnv::Right(_)));
+        db.update(|tx| tx.put::<CanonicalHeaders>(1, B256::ZERO)).unwrap().unwrap();
+        drop(db);
+
+        assert_eq!(DatabaseBackend::detect(dir.path()), Some(DatabaseBackend::Redb));
+        assert!(
+            init_db_with_backend(dir.path(), DatabaseBackend::Mdbx, Default::default()).is_err()
+        );
+
+        let db =
+            init_db_with_backend(dir.path(), DatabaseBackend::Redb, Default::default()).unwrap();
+        assert_eq!(db.view(|tx| tx.get::<CanonicalHeaders>(1)).unwrap().unwrap(), Some(B256::ZERO));
+    }
+}
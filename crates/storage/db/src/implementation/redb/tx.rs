@@ -0,0 +1,390 @@
+//! Transaction wrapper for redb.
+
+use super::{cursor::Cursor, dup_prefix, dup_prefix_end, error_info, table_definition};
+use crate::DatabaseError;
+use parking_lot::Mutex;
+use redb::{ReadableTable, ReadableTableMetadata, TableError};
+use reth_db_api::{
+    table::{Compress, Decompress, DupSort, Encode, Table, TableImporter},
+    transaction::{DbTx, DbTxMut},
+};
+use reth_storage_errors::db::{DatabaseWriteError, DatabaseWriteOperation};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::Bound,
+    sync::Arc,
+};
+
+/// Marker of read-only transactions.
+#[derive(Debug)]
+pub struct RO;
+
+/// Marker of read-write transactions.
+#[derive(Debug)]
+pub struct RW;
+
+/// Kind of a transaction, either [`RO`] or [`RW`].
+pub trait TransactionKind: Debug + Send + Sync + 'static {}
+
+impl TransactionKind for RO {}
+impl TransactionKind for RW {}
+
+/// Wrapper for the redb transaction.
+#[derive(Debug)]
+pub struct Tx<K: TransactionKind> {
+    /// Inner redb transaction, shared with the cursors opened in it.
+    pub(crate) inner: Arc<TxInner>,
+    _kind: PhantomData<K>,
+}
+
+impl Tx<RO> {
+    /// Creates a new read-only transaction.
+    pub(crate) fn new_read(tx: redb::ReadTransaction) -> Self {
+        let tx = RedbTx::Read { tables: HashMap::new(), tx };
+        Self { inner: Arc::new(TxInner::new(tx)), _kind: PhantomData }
+    }
+}
+
+impl Tx<RW> {
+    /// Creates a new read-write transaction.
+    pub(crate) fn new_write(tx: redb::WriteTransaction) -> Self {
+        let tx = RedbTx::Write { tables: HashMap::new(), tx: Box::new(tx) };
+        Self { inner: Arc::new(TxInner::new(tx)), _kind: PhantomData }
+    }
+}
+
+impl<K: TransactionKind> Tx<K> {
+    /// Returns the decoded value of the given encoded key.
+    ///
+    /// For `DUPSORT` tables, this is the first value of the key.
+    fn get_encoded<T: Table>(&self, key: &[u8]) -> Result<Option<T::Value>, DatabaseError> {
+        let value = if T::DUPSORT {
+            let prefix = dup_prefix(key);
+            self.inner
+                .query(T::NAME, Query::Seek(&prefix))?
+                .filter(|(raw, _)| raw.starts_with(&prefix))
+                .map(|(mut raw, _)| raw.split_off(prefix.len()))
+        } else {
+            self.inner.query(T::NAME, Query::Get(key))?.map(|(_, value)| value)
+        };
+        value.map(T::Value::decompress_owned).transpose()
+    }
+}
+
+impl<K: TransactionKind> DbTx for Tx<K> {
+    type Cursor<T: Table> = Cursor<K, T>;
+    type DupCursor<T: DupSort> = Cursor<K, T>;
+
+    fn get<T: Table>(&self, key: T::Key) -> Result<Option<T::Value>, DatabaseError> {
+        self.get_encoded::<T>(key.encode().as_ref())
+    }
+
+    fn get_by_encoded_key<T: Table>(
+        &self,
+        key: &<T::Key as Encode>::Encoded,
+    ) -> Result<Option<T::Value>, DatabaseError> {
+        self.get_encoded::<T>(key.as_ref())
+    }
+
+    fn commit(self) -> Result<bool, DatabaseError> {
+        match self.inner.take()? {
+            RedbTx::Read { .. } => {}
+            RedbTx::Write { tables, tx } => {
+                // the tables borrow the transaction
+                drop(tables);
+                (*tx).commit().map_err(|e| DatabaseError::Commit(error_info(e)))?
+            }
+        }
+        Ok(true)
+    }
+
+    fn abort(self) {
+        if let Ok(RedbTx::Write { tables, tx }) = self.inner.take() {
+            drop(tables);
+            let _ = (*tx).abort();
+        }
+    }
+
+    fn cursor_read<T: Table>(&self) -> Result<Self::Cursor<T>, DatabaseError> {
+        Ok(Cursor::new(self.inner.clone()))
+    }
+
+    fn cursor_dup_read<T: DupSort>(&self) -> Result<Self::DupCursor<T>, DatabaseError> {
+        Ok(Cursor::new(self.inner.clone()))
+    }
+
+    fn entries<T: Table>(&self) -> Result<usize, DatabaseError> {
+        self.inner.len(T::NAME)
+    }
+
+    /// Read transactions of redb are not tracked, this is a no-op.
+    fn disable_long_read_transaction_safety(&mut self) {}
+}
+
+impl DbTxMut for Tx<RW> {
+    type CursorMut<T: Table> = Cursor<RW, T>;
+    type DupCursorMut<T: DupSort> = Cursor<RW, T>;
+
+    /// For `DUPSORT` tables, the value is added to the values of the key.
+    fn put<T: Table>(&self, key: T::Key, value: T::Value) -> Result<(), DatabaseError> {
+        let key = key.encode();
+        let value = value.compress();
+        let (raw_key, raw_value) = if T::DUPSORT {
+            ([dup_prefix(key.as_ref()), value.as_ref().to_vec()].concat(), &[][..])
+        } else {
+            (key.as_ref().to_vec(), value.as_ref())
+        };
+        self.inner.write(T::NAME, |table| {
+            table.insert(raw_key.as_slice(), raw_value).map(|_| ()).map_err(|e| {
+                DatabaseWriteError {
+                    info: error_info(e),
+                    operation: DatabaseWriteOperation::Put,
+                    table_name: T::NAME,
+                    key: key.into(),
+                }
+                .into()
+            })
+        })
+    }
+
+    /// For `DUPSORT` tables, all values of the key are deleted if no value is given.
+    fn delete<T: Table>(
+        &self,
+        key: T::Key,
+        value: Option<T::Value>,
+    ) -> Result<bool, DatabaseError> {
+        let key = key.encode();
+        self.inner.write(T::NAME, |table| {
+            let deleted = if !T::DUPSORT {
+                table.remove(key.as_ref()).map(|removed| removed.is_some())
+            } else if let Some(value) = value {
+                let raw = [dup_prefix(key.as_ref()), value.compress().as_ref().to_vec()].concat();
+                table.remove(raw.as_slice()).map(|removed| removed.is_some())
+            } else {
+                let prefix = dup_prefix(key.as_ref());
+                let end = dup_prefix_end(&prefix);
+                let range = prefix.as_slice()..end.as_slice();
+                match table.range::<&[u8]>(range.clone()).map(|mut rows| rows.next().is_some()) {
+                    Ok(true) => table.retain_in::<&[u8], _>(range, |_, _| false).map(|_| true),
+                    found => found,
+                }
+            };
+            deleted.map_err(|e| DatabaseError::Delete(error_info(e)))
+        })
+    }
+
+    fn clear<T: Table>(&self) -> Result<(), DatabaseError> {
+        self.inner.write(T::NAME, |table| {
+            table.retain(|_, _| false).map_err(|e| DatabaseError::Delete(error_info(e)))
+        })
+    }
+
+    fn cursor_write<T: Table>(&self) -> Result<Self::CursorMut<T>, DatabaseError> {
+        Ok(Cursor::new(self.inner.clone()))
+    }
+
+    fn cursor_dup_write<T: DupSort>(&self) -> Result<Self::DupCursorMut<T>, DatabaseError> {
+        Ok(Cursor::new(self.inner.clone()))
+    }
+}
+
+impl TableImporter for Tx<RW> {}
+
+/// redb table with raw keys and values.
+pub(crate) type RawTable<'txn> = redb::Table<'txn, &'static [u8], &'static [u8]>;
+
+/// Read-only redb table with raw keys and values.
+type ReadOnlyRawTable = redb::ReadOnlyTable<&'static [u8], &'static [u8]>;
+
+/// Read or write redb transaction, with the tables that were opened in it.
+///
+/// Tables are opened on first use and stay open until the transaction is committed or aborted, so
+/// the cursors don't open them on every operation.
+pub(crate) enum RedbTx {
+    Read {
+        /// The opened tables, `None` for tables that don't exist.
+        tables: HashMap<&'static str, Option<ReadOnlyRawTable>>,
+        tx: redb::ReadTransaction,
+    },
+    Write {
+        /// The opened tables, which borrow the transaction.
+        ///
+        /// The tables are declared before the transaction, so they're dropped first.
+        tables: HashMap<&'static str, RawTable<'static>>,
+        /// The transaction, boxed so that the tables keep pointing to it when it's moved.
+        tx: Box<redb::WriteTransaction>,
+    },
+}
+
+impl fmt::Debug for RedbTx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read { tables, .. } => {
+                f.debug_struct("Read").field("tables", &tables.keys()).finish_non_exhaustive()
+            }
+            Self::Write { tables, .. } => {
+                f.debug_struct("Write").field("tables", &tables.keys()).finish_non_exhaustive()
+            }
+        }
+    }
+}
+
+/// Returns the table with the given name of a read transaction, opening it if necessary.
+fn read_table<'a>(
+    tx: &redb::ReadTransaction,
+    tables: &'a mut HashMap<&'static str, Option<ReadOnlyRawTable>>,
+    name: &'static str,
+) -> Result<Option<&'a ReadOnlyRawTable>, DatabaseError> {
+    let table = match tables.entry(name) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => match tx.open_table(table_definition(name)) {
+            Ok(table) => entry.insert(Some(table)),
+            // tables that were never created are empty
+            Err(TableError::TableDoesNotExist(_)) => entry.insert(None),
+            Err(e) => return Err(DatabaseError::Open(error_info(e))),
+        },
+    };
+    Ok(table.as_ref())
+}
+
+/// Returns the table with the given name of a write transaction, opening it if necessary.
+fn write_table<'a>(
+    tx: &redb::WriteTransaction,
+    tables: &'a mut HashMap<&'static str, RawTable<'static>>,
+    name: &'static str,
+) -> Result<&'a mut RawTable<'static>, DatabaseError> {
+    Ok(match tables.entry(name) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            let table = tx
+                .open_table(table_definition(name))
+                .map_err(|e| DatabaseError::Open(error_info(e)))?;
+            // SAFETY: the transaction is boxed and the tables are dropped before it, see
+            // `RedbTx::Write`
+            entry.insert(unsafe { std::mem::transmute::<RawTable<'_>, RawTable<'static>>(table) })
+        }
+    })
+}
+
+/// Raw redb key and value.
+///
+/// For `DUPSORT` tables, the key is the [`dup_prefix`] of the encoded key followed by the
+/// compressed value, and the value is empty. Otherwise, they are the encoded key and the
+/// compressed value.
+pub(crate) type RawPair = (Vec<u8>, Vec<u8>);
+
+/// Read query of a single raw pair.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Query<'a> {
+    /// The pair with the given key.
+    Get(&'a [u8]),
+    /// The first pair of the table.
+    First,
+    /// The last pair of the table.
+    Last,
+    /// The first pair with a key greater than or equal to the given key.
+    Seek(&'a [u8]),
+    /// The first pair with a key greater than the given key.
+    Next(&'a [u8]),
+    /// The last pair with a key less than the given key.
+    Prev(&'a [u8]),
+}
+
+impl Query<'_> {
+    /// Executes the query against the table.
+    fn execute<Tb>(self, table: &Tb) -> Result<Option<RawPair>, redb::StorageError>
+    where
+        Tb: ReadableTable<&'static [u8], &'static [u8]>,
+    {
+        let pair = match self {
+            Self::Get(key) => {
+                return Ok(table.get(key)?.map(|value| (key.to_vec(), value.value().to_vec())))
+            }
+            Self::First => table.first()?,
+            Self::Last => table.last()?,
+            Self::Seek(key) => table
+                .range::<&[u8]>((Bound::Included(key), Bound::Unbounded))?
+                .next()
+                .transpose()?,
+            Self::Next(key) => table
+                .range::<&[u8]>((Bound::Excluded(key), Bound::Unbounded))?
+                .next()
+                .transpose()?,
+            Self::Prev(key) => table
+                .range::<&[u8]>((Bound::Unbounded, Bound::Excluded(key)))?
+                .next_back()
+                .transpose()?,
+        };
+        Ok(pair.map(|(key, value)| (key.value().to_vec(), value.value().to_vec())))
+    }
+}
+
+/// redb transaction shared between a [`Tx`] and its cursors.
+#[derive(Debug)]
+pub(crate) struct TxInner {
+    /// The transaction, `None` once it's committed or aborted.
+    tx: Mutex<Option<RedbTx>>,
+}
+
+impl TxInner {
+    fn new(tx: RedbTx) -> Self {
+        Self { tx: Mutex::new(Some(tx)) }
+    }
+
+    /// Takes the transaction out to commit or abort it.
+    fn take(&self) -> Result<RedbTx, DatabaseError> {
+        self.tx.lock().take().ok_or_else(transaction_closed)
+    }
+
+    /// Executes a read query against the table with the given name.
+    pub(crate) fn query(
+        &self,
+        name: &'static str,
+        query: Query<'_>,
+    ) -> Result<Option<RawPair>, DatabaseError> {
+        let mut tx = self.tx.lock();
+        let result = match tx.as_mut().ok_or_else(transaction_closed)? {
+            RedbTx::Read { tables, tx } => match read_table(tx, tables, name)? {
+                Some(table) => query.execute(table),
+                None => return Ok(None),
+            },
+            RedbTx::Write { tables, tx } => query.execute(write_table(tx, tables, name)?),
+        };
+        result.map_err(|e| DatabaseError::Read(error_info(e)))
+    }
+
+    /// Returns the number of entries in the table with the given name.
+    pub(crate) fn len(&self, name: &'static str) -> Result<usize, DatabaseError> {
+        let mut tx = self.tx.lock();
+        let len = match tx.as_mut().ok_or_else(transaction_closed)? {
+            RedbTx::Read { tables, tx } => match read_table(tx, tables, name)? {
+                Some(table) => table.len(),
+                None => return Ok(0),
+            },
+            RedbTx::Write { tables, tx } => write_table(tx, tables, name)?.len(),
+        };
+        len.map(|len| len as usize).map_err(|e| DatabaseError::Stats(error_info(e)))
+    }
+
+    /// Executes `f` with the table with the given name opened for writing.
+    pub(crate) fn write<R>(
+        &self,
+        name: &'static str,
+        f: impl FnOnce(&mut RawTable<'static>) -> Result<R, DatabaseError>,
+    ) -> Result<R, DatabaseError> {
+        let mut tx = self.tx.lock();
+        match tx.as_mut().ok_or_else(transaction_closed)? {
+            RedbTx::Read { .. } => Err(DatabaseError::Other(
+                "write operation in a read-only redb transaction".to_string(),
+            )),
+            RedbTx::Write { tables, tx } => f(write_table(tx, tables, name)?),
+        }
+    }
+}
+
+/// Returns the error that operations on a committed or aborted transaction fail with.
+fn transaction_closed() -> DatabaseError {
+    DatabaseError::Other("redb transaction is already committed or aborted".to_string())
+}
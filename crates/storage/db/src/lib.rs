@@ -19,6 +19,10 @@ mod implementation;
 pub mod lockfile;
 #[cfg(feature = "mdbx")]
 mod metrics;
+#[cfg(feature = "mdbx")]
+pub mod migration;
+#[cfg(feature = "mdbx")]
+pub mod read_metrics;
 pub mod static_file;
 #[cfg(feature = "mdbx")]
 mod utils;
@@ -26,6 +30,8 @@ pub mod version;
 
 #[cfg(feature = "mdbx")]
 pub mod mdbx;
+#[cfg(feature = "redb")]
+pub mod redb;
 
 pub use reth_storage_errors::db::{DatabaseError, DatabaseWriteOperation};
 #[cfg(feature = "mdbx")]
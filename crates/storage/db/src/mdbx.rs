@@ -1,6 +1,6 @@
 //! Helper functions for initializing and opening a database.
 
-use crate::{is_database_empty, TableSet, Tables};
+use crate::{is_database_empty, migration::MigrationRegistry, TableSet, Tables};
 use eyre::Context;
 use std::path::Path;
 
@@ -9,10 +9,32 @@ pub use reth_libmdbx::*;
 
 /// Creates a new database at the specified path if it doesn't exist. Does NOT create tables. Check
 /// [`init_db`].
+///
+/// An existing database with an older version is rejected, it's upgraded by [`init_db`] once the
+/// tables are created.
 pub fn create_db<P: AsRef<Path>>(path: P, args: DatabaseArguments) -> eyre::Result<DatabaseEnv> {
-    use crate::version::{check_db_version_file, create_db_version_file, DatabaseVersionError};
+    let (db, outdated_version) = create_db_with_version(path, args)?;
+    if let Some(version) = outdated_version {
+        eyre::bail!(
+            "The database has the outdated version v{version}, it has to be opened with `init_db` \
+             to be migrated"
+        )
+    }
+    Ok(db)
+}
+
+/// Creates a new database at the specified path if it doesn't exist, and returns the version of
+/// an existing database that has to be migrated.
+fn create_db_with_version(
+    path: impl AsRef<Path>,
+    args: DatabaseArguments,
+) -> eyre::Result<(DatabaseEnv, Option<u64>)> {
+    use crate::version::{
+        check_db_version_file, create_db_version_file, DatabaseVersionError, DB_VERSION,
+    };
 
     let rpath = path.as_ref();
+    let mut outdated_version = None;
     if is_database_empty(rpath) {
         reth_fs_util::create_dir_all(rpath)
             .wrap_err_with(|| format!("Could not create database directory {}", rpath.display()))?;
@@ -21,11 +43,17 @@ pub fn create_db<P: AsRef<Path>>(path: P, args: DatabaseArguments) -> eyre::Resu
         match check_db_version_file(rpath) {
             Ok(_) => (),
             Err(DatabaseVersionError::MissingFile) => create_db_version_file(rpath)?,
+            Err(DatabaseVersionError::VersionMismatch { version }) if version < DB_VERSION => {
+                // fail before opening the database if it can't be migrated
+                MigrationRegistry::new().plan(version)?;
+                outdated_version = Some(version);
+            }
             Err(err) => return Err(err.into()),
         }
     }
 
-    Ok(DatabaseEnv::open(rpath, DatabaseEnvKind::RW, args)?)
+    let db = DatabaseEnv::open(rpath, DatabaseEnvKind::RW, args)?;
+    Ok((db, outdated_version))
 }
 
 /// Opens up an existing database or creates a new one at the specified path. Creates tables defined
@@ -36,13 +64,21 @@ pub fn init_db<P: AsRef<Path>>(path: P, args: DatabaseArguments) -> eyre::Result
 
 /// Opens up an existing database or creates a new one at the specified path. Creates tables defined
 /// in the given [`TableSet`] if necessary. Read/Write mode.
+///
+/// An existing database with an older version is upgraded with the migrations of the
+/// [`MigrationRegistry`] after the tables are created, so migrations can rely on all tables of
+/// the current version being present.
 pub fn init_db_for<P: AsRef<Path>, TS: TableSet>(
     path: P,
     args: DatabaseArguments,
 ) -> eyre::Result<DatabaseEnv> {
+    let path = path.as_ref();
     let client_version = args.client_version().clone();
-    let db = create_db(path, args)?;
+    let (db, outdated_version) = create_db_with_version(path, args)?;
     db.create_tables_for::<TS>()?;
+    if let Some(version) = outdated_version {
+        MigrationRegistry::new().migrate(&db, path, version)?;
+    }
     db.record_client_version(client_version)?;
     Ok(db)
 }
@@ -1,6 +1,7 @@
-use crate::Tables;
+use crate::{read_metrics::ReadMetricsConfig, Tables};
 use metrics::Histogram;
 use reth_metrics::{metrics::Counter, Metrics};
+use reth_tracing::tracing::warn;
 use rustc_hash::FxHashMap;
 use std::time::{Duration, Instant};
 use strum::{EnumCount, EnumIter, IntoEnumIterator};
@@ -16,6 +17,8 @@ const LARGE_VALUE_THRESHOLD_BYTES: usize = 4096;
 pub(crate) struct DatabaseEnvMetrics {
     /// Caches `OperationMetrics` handles for each table and operation tuple.
     operations: FxHashMap<(&'static str, Operation), OperationMetrics>,
+    /// Caches `ReadMetrics` handles for each table and read operation tuple.
+    reads: FxHashMap<(&'static str, ReadOperation), ReadMetrics>,
     /// Caches `TransactionMetrics` handles for counters grouped by only transaction mode.
     /// Updated both at tx open and close.
     transactions: FxHashMap<TransactionMode, TransactionMetrics>,
@@ -31,6 +34,7 @@ impl DatabaseEnvMetrics {
         // to avoid runtime locks on the map when recording metrics.
         Self {
             operations: Self::generate_operation_handles(),
+            reads: Self::generate_read_handles(),
             transactions: Self::generate_transaction_handles(),
             transaction_outcomes: Self::generate_transaction_outcome_handles(),
         }
@@ -57,6 +61,27 @@ impl DatabaseEnvMetrics {
         operations
     }
 
+    /// Generate a map of all possible read handles for each table and read operation tuple.
+    /// Used for tracking read metrics, if enabled.
+    fn generate_read_handles() -> FxHashMap<(&'static str, ReadOperation), ReadMetrics> {
+        let mut reads = FxHashMap::with_capacity_and_hasher(
+            Tables::COUNT * ReadOperation::COUNT,
+            Default::default(),
+        );
+        for table in Tables::ALL {
+            for operation in ReadOperation::iter() {
+                reads.insert(
+                    (table.name(), operation),
+                    ReadMetrics::new_with_labels(&[
+                        (Labels::Table.as_str(), table.name()),
+                        (Labels::Operation.as_str(), operation.as_str()),
+                    ]),
+                );
+            }
+        }
+        reads
+    }
+
     /// Generate a map of all possible transaction modes to metric handles.
     /// Used for tracking a counter of open transactions.
     fn generate_transaction_handles() -> FxHashMap<TransactionMode, TransactionMetrics> {
@@ -141,6 +166,47 @@ impl DatabaseEnvMetrics {
     }
 }
 
+/// Record a metric for database read executed in `f` and log it if it was slow, if read metrics or
+/// slow read logging are enabled in `read_metrics`.
+///
+/// Metrics are only recorded if `metrics` is `Some(...)`.
+pub(crate) fn record_read<R>(
+    metrics: Option<&DatabaseEnvMetrics>,
+    read_metrics: &ReadMetricsConfig,
+    table: &'static str,
+    operation: ReadOperation,
+    f: impl FnOnce() -> R,
+) -> R {
+    if !read_metrics.is_active() {
+        return f()
+    }
+
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+
+    let metrics = metrics.and_then(|metrics| metrics.reads.get(&(table, operation)));
+    if read_metrics.is_enabled() {
+        if let Some(metrics) = metrics {
+            metrics.record(elapsed);
+        }
+    }
+    if read_metrics.slow_read_threshold().is_some_and(|threshold| elapsed > threshold) {
+        if let Some(metrics) = metrics {
+            metrics.slow_total.increment(1);
+        }
+        warn!(
+            target: "storage::db::mdbx",
+            table,
+            operation = operation.as_str(),
+            ?elapsed,
+            "Slow database read"
+        );
+    }
+
+    result
+}
+
 /// Transaction mode for the database, either read-only or read-write.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumCount, EnumIter)]
 pub(crate) enum TransactionMode {
@@ -232,6 +298,30 @@ impl Operation {
     }
 }
 
+/// Types of read operations conducted on the database: get, and cursor seeks and steps.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumCount, EnumIter)]
+pub(crate) enum ReadOperation {
+    /// Database get operation.
+    Get,
+    /// Database cursor operation that positions the cursor on a key: `first`, `last`, `seek`,
+    /// `seek_exact` and `seek_by_key_subkey`.
+    CursorSeek,
+    /// Database cursor operation that moves the cursor to an adjacent entry: `next`, `prev`,
+    /// `current`, `next_dup`, `next_no_dup` and `next_dup_val`.
+    CursorNext,
+}
+
+impl ReadOperation {
+    /// Returns the read operation as a string.
+    pub(crate) const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Get => "get",
+            Self::CursorSeek => "cursor-seek",
+            Self::CursorNext => "cursor-next",
+        }
+    }
+}
+
 /// Enum defining labels for various aspects used in metrics.
 enum Labels {
     /// Label representing a table.
@@ -360,3 +450,22 @@ impl OperationMetrics {
         }
     }
 }
+
+#[derive(Metrics, Clone)]
+#[metrics(scope = "database.read")]
+pub(crate) struct ReadMetrics {
+    /// Total number of database reads made while read metrics are enabled
+    calls_total: Counter,
+    /// The time it took to execute a database read
+    duration_seconds: Histogram,
+    /// Total number of database reads that exceeded the slow read threshold
+    slow_total: Counter,
+}
+
+impl ReadMetrics {
+    /// Record a database read that took `duration`.
+    pub(crate) fn record(&self, duration: Duration) {
+        self.calls_total.increment(1);
+        self.duration_seconds.record(duration);
+    }
+}
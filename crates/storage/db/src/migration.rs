@@ -0,0 +1,338 @@
+//! Versioned migrations of the database schema.
+//!
+//! Every change of the format of a table increments [`DB_VERSION`] and registers a [`Migration`]
+//! from the previous version in the [`MigrationRegistry`]. When a database with an older version
+//! is opened with [`init_db`](crate::init_db), the tables of the current version are created and
+//! the migrations are applied one version at a time. The version file is updated after each of
+//! them, so an interrupted upgrade resumes from the last completed version, and the interrupted
+//! migration runs again from the start.
+
+use crate::{
+    version::{write_db_version_file, DB_VERSION},
+    DatabaseEnv,
+};
+use reth_storage_errors::db::DatabaseError;
+use reth_tracing::tracing::info;
+use std::{
+    collections::BTreeMap,
+    fmt, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// The interval at which the progress of a migration is logged.
+const PROGRESS_LOG_INTERVAL: Duration = Duration::from_secs(10);
+
+/// A migration of the database from one version to the next.
+pub trait Migration: Send + Sync + fmt::Debug {
+    /// The database version this migration upgrades from, the database has the next version
+    /// afterwards.
+    fn from_version(&self) -> u64;
+
+    /// A short description of the changes to the tables.
+    fn description(&self) -> &'static str;
+
+    /// Notes on rolling back the migration, i.e. what's required to run a reth version that
+    /// expects the previous database version on the migrated database.
+    fn rollback_notes(&self) -> &'static str;
+
+    /// Migrates the tables of the database, reporting the progress of long-running migrations.
+    ///
+    /// All tables of the current version exist when this is called. The migration has to be
+    /// idempotent: if it's interrupted after committing some of its changes, the version file
+    /// still has the previous version and the migration runs again on the partially migrated
+    /// tables.
+    fn migrate(
+        &self,
+        db: &DatabaseEnv,
+        progress: &mut MigrationProgress,
+    ) -> Result<(), DatabaseError>;
+}
+
+/// A step of a migration plan, see [`MigrationRegistry::plan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationStep {
+    /// The database version before the migration.
+    pub from_version: u64,
+    /// The database version after the migration.
+    pub to_version: u64,
+    /// A short description of the changes to the tables.
+    pub description: &'static str,
+    /// Notes on rolling back the migration.
+    pub rollback_notes: &'static str,
+}
+
+impl fmt::Display for MigrationStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "v{} -> v{}: {} (rollback: {})",
+            self.from_version, self.to_version, self.description, self.rollback_notes
+        )
+    }
+}
+
+/// Reports the progress of a running migration.
+#[derive(Debug)]
+pub struct MigrationProgress {
+    /// The database version the migration upgrades from.
+    from_version: u64,
+    /// When the progress was last logged.
+    last_log: Instant,
+}
+
+impl MigrationProgress {
+    fn new(from_version: u64) -> Self {
+        Self { from_version, last_log: Instant::now() }
+    }
+
+    /// Reports the number of processed entries, and the total if it's known.
+    ///
+    /// The progress is logged at most every few seconds, so this can be called for every entry.
+    pub fn report(&mut self, processed: u64, total: Option<u64>) {
+        if self.last_log.elapsed() < PROGRESS_LOG_INTERVAL {
+            return
+        }
+        self.last_log = Instant::now();
+
+        let to_version = self.from_version + 1;
+        match total {
+            Some(total) if total > 0 => {
+                let progress = format!("{:.2}%", 100.0 * processed as f64 / total as f64);
+                info!(target: "reth::db", from_version = self.from_version, to_version, processed, total, %progress, "Migrating database");
+            }
+            _ => {
+                info!(target: "reth::db", from_version = self.from_version, to_version, processed, "Migrating database");
+            }
+        }
+    }
+}
+
+/// Error of a database migration.
+#[derive(thiserror::Error, Debug)]
+pub enum MigrationError {
+    /// There's no migration from the version, the database has to be dropped and resynced.
+    #[error(
+        "no migration of the database from v{version} to v{}, the database has to be dropped \
+         with `reth db drop` and resynced",
+        version + 1
+    )]
+    Missing {
+        /// The version without a migration.
+        version: u64,
+    },
+    /// The database has a newer version than the target version.
+    #[error(
+        "the database version (v{version}) is newer than v{target}, the database was created by \
+         a newer reth version"
+    )]
+    Downgrade {
+        /// The version of the database.
+        version: u64,
+        /// The target version.
+        target: u64,
+    },
+    /// A migration failed.
+    #[error("migration of the database from v{version} failed: {err}")]
+    Database {
+        /// The version the failed migration upgrades from.
+        version: u64,
+        /// The database error.
+        err: DatabaseError,
+    },
+    /// The version file couldn't be updated.
+    #[error("failed to update the database version file: {0}")]
+    VersionFile(#[from] io::Error),
+}
+
+/// The registry of the database migrations, by the version they upgrade from.
+#[derive(Debug, Default)]
+pub struct MigrationRegistry {
+    migrations: BTreeMap<u64, Box<dyn Migration>>,
+}
+
+impl MigrationRegistry {
+    /// Creates a registry with the migrations of reth.
+    ///
+    /// There are no migrations yet, the table formats haven't changed since the database version
+    /// file was introduced.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a migration, replacing the migration from the same version.
+    pub fn with_migration(mut self, migration: impl Migration + 'static) -> Self {
+        self.migrations.insert(migration.from_version(), Box::new(migration));
+        self
+    }
+
+    /// Returns the migrations that upgrade the database from the given version to the current
+    /// [`DB_VERSION`], without applying them.
+    pub fn plan(&self, version: u64) -> Result<Vec<MigrationStep>, MigrationError> {
+        self.plan_to(version, DB_VERSION)
+    }
+
+    /// Applies the migrations that upgrade the database at the given path from the given version
+    /// to the current [`DB_VERSION`], and returns the applied steps.
+    pub fn migrate(
+        &self,
+        db: &DatabaseEnv,
+        db_path: &Path,
+        version: u64,
+    ) -> Result<Vec<MigrationStep>, MigrationError> {
+        self.migrate_to(db, db_path, version, DB_VERSION)
+    }
+
+    /// Returns the migrations that upgrade the database from the given version to the target
+    /// version.
+    pub fn plan_to(&self, version: u64, target: u64) -> Result<Vec<MigrationStep>, MigrationError> {
+        if version > target {
+            return Err(MigrationError::Downgrade { version, target })
+        }
+
+        (version..target)
+            .map(|from_version| {
+                let migration = self
+                    .migrations
+                    .get(&from_version)
+                    .ok_or(MigrationError::Missing { version: from_version })?;
+                Ok(MigrationStep {
+                    from_version,
+                    to_version: from_version + 1,
+                    description: migration.description(),
+                    rollback_notes: migration.rollback_notes(),
+                })
+            })
+            .collect()
+    }
+
+    /// Applies the migrations that upgrade the database from the given version to the target
+    /// version.
+    ///
+    /// The plan is validated before any migration is applied.
+    pub fn migrate_to(
+        &self,
+        db: &DatabaseEnv,
+        db_path: &Path,
+        version: u64,
+        target: u64,
+    ) -> Result<Vec<MigrationStep>, MigrationError> {
+        let steps = self.plan_to(version, target)?;
+
+        for step in &steps {
+            info!(target: "reth::db", %step, "Migrating database");
+            let start = Instant::now();
+
+            let migration = &self.migrations[&step.from_version];
+            let mut progress = MigrationProgress::new(step.from_version);
+            migration
+                .migrate(db, &mut progress)
+                .map_err(|err| MigrationError::Database { version: step.from_version, err })?;
+            write_db_version_file(db_path, step.to_version)?;
+
+            info!(target: "reth::db", version = step.to_version, elapsed = ?start.elapsed(), "Migrated database");
+        }
+
+        Ok(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        tables,
+        test_utils::create_test_rw_db,
+        transaction::{DbTx, DbTxMut},
+        version::get_db_version,
+        Database,
+    };
+    use alloy_primitives::B256;
+    use assert_matches::assert_matches;
+
+    /// Moves the canonical hash of block 1 to block 2.
+    #[derive(Debug)]
+    struct MoveCanonicalHash;
+
+    impl Migration for MoveCanonicalHash {
+        fn from_version(&self) -> u64 {
+            DB_VERSION
+        }
+
+        fn description(&self) -> &'static str {
+            "move canonical hash"
+        }
+
+        fn rollback_notes(&self) -> &'static str {
+            "move it back"
+        }
+
+        fn migrate(
+            &self,
+            db: &DatabaseEnv,
+            progress: &mut MigrationProgress,
+        ) -> Result<(), DatabaseError> {
+            let tx = db.tx_mut()?;
+            if let Some(hash) = tx.get::<tables::CanonicalHeaders>(1)? {
+                tx.delete::<tables::CanonicalHeaders>(1, None)?;
+                tx.put::<tables::CanonicalHeaders>(2, hash)?;
+            }
+            progress.report(1, Some(1));
+            tx.commit()?;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn plan() {
+        let registry = MigrationRegistry::new();
+        assert_eq!(registry.plan(DB_VERSION).unwrap(), vec![]);
+        assert_matches!(
+            registry.plan(DB_VERSION - 1),
+            Err(MigrationError::Missing { version }) if version == DB_VERSION - 1
+        );
+        assert_matches!(registry.plan(DB_VERSION + 1), Err(MigrationError::Downgrade { .. }));
+
+        let registry = registry.with_migration(MoveCanonicalHash);
+        assert_eq!(
+            registry.plan_to(DB_VERSION, DB_VERSION + 1).unwrap(),
+            vec![MigrationStep {
+                from_version: DB_VERSION,
+                to_version: DB_VERSION + 1,
+                description: "move canonical hash",
+                rollback_notes: "move it back",
+            }]
+        );
+        assert_matches!(
+            registry.plan_to(DB_VERSION, DB_VERSION + 2),
+            Err(MigrationError::Missing { version }) if version == DB_VERSION + 1
+        );
+    }
+
+    #[test]
+    fn migrate() {
+        let db = create_test_rw_db();
+        let tx = db.tx_mut().unwrap();
+        tx.put::<tables::CanonicalHeaders>(1, B256::with_last_byte(1)).unwrap();
+        tx.commit().unwrap();
+
+        let registry = MigrationRegistry::new().with_migration(MoveCanonicalHash);
+        let steps = registry.migrate_to(db.db(), db.path(), DB_VERSION, DB_VERSION + 1).unwrap();
+        assert_eq!(steps.len(), 1);
+        assert_eq!(get_db_version(db.path()).unwrap(), DB_VERSION + 1);
+
+        let tx = db.tx().unwrap();
+        assert_eq!(tx.get::<tables::CanonicalHeaders>(1).unwrap(), None);
+        assert_eq!(tx.get::<tables::CanonicalHeaders>(2).unwrap(), Some(B256::with_last_byte(1)));
+        drop(tx);
+
+        // an upgrade interrupted before the version file was written runs the migration again
+        write_db_version_file(db.path(), DB_VERSION).unwrap();
+        registry.migrate_to(db.db(), db.path(), DB_VERSION, DB_VERSION + 1).unwrap();
+        assert_eq!(get_db_version(db.path()).unwrap(), DB_VERSION + 1);
+
+        let tx = db.tx().unwrap();
+        assert_eq!(tx.get::<tables::CanonicalHeaders>(1).unwrap(), None);
+        assert_eq!(tx.get::<tables::CanonicalHeaders>(2).unwrap(), Some(B256::with_last_byte(1)));
+    }
+}
@@ -0,0 +1,82 @@
+//! Runtime toggles of the instrumentation of database reads.
+//!
+//! Reads are not instrumented by default, because timing every `get` and cursor operation has a
+//! measurable cost. The toggles are configured with
+//! [`DatabaseArguments::with_read_metrics`](crate::mdbx::DatabaseArguments::with_read_metrics) and
+//! can be changed through a [`ReadMetricsConfig`] handle while the database is open.
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+/// Shareable handle to the instrumentation settings of database reads.
+///
+/// Clones of the handle share the settings, so changes are picked up by all transactions of the
+/// database that was opened with it.
+#[derive(Debug, Clone, Default)]
+pub struct ReadMetricsConfig {
+    inner: Arc<ReadMetricsInner>,
+}
+
+#[derive(Debug, Default)]
+struct ReadMetricsInner {
+    /// Whether per-table read metrics are recorded.
+    enabled: AtomicBool,
+    /// Duration in nanoseconds above which reads are logged, `0` if slow reads are not logged.
+    slow_read_threshold_nanos: AtomicU64,
+}
+
+impl ReadMetricsConfig {
+    /// Creates new settings with the given toggles.
+    pub fn new(enabled: bool, slow_read_threshold: Option<Duration>) -> Self {
+        let read_metrics = Self::default();
+        read_metrics.set_enabled(enabled);
+        read_metrics.set_slow_read_threshold(slow_read_threshold);
+        read_metrics
+    }
+
+    /// Enables or disables the per-table counters and duration histograms of `get`, cursor seek
+    /// and cursor next operations.
+    pub fn set_enabled(&self, enabled: bool) {
+        self.inner.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if per-table read metrics are recorded.
+    pub fn is_enabled(&self) -> bool {
+        self.inner.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Sets the duration above which reads are logged as slow, `None` disables the logging.
+    pub fn set_slow_read_threshold(&self, threshold: Option<Duration>) {
+        let nanos =
+            threshold.map_or(0, |threshold| threshold.as_nanos().clamp(1, u64::MAX as u128));
+        self.inner.slow_read_threshold_nanos.store(nanos as u64, Ordering::Relaxed);
+    }
+
+    /// Returns the duration above which reads are logged as slow, if any.
+    pub fn slow_read_threshold(&self) -> Option<Duration> {
+        match self.inner.slow_read_threshold_nanos.load(Ordering::Relaxed) {
+            0 => None,
+            nanos => Some(Duration::from_nanos(nanos)),
+        }
+    }
+
+    /// Returns `true` if reads need to be timed, because read metrics or slow read logging are
+    /// enabled.
+    pub(crate) fn is_active(&self) -> bool {
+        self.is_enabled() || self.inner.slow_read_threshold_nanos.load(Ordering::Relaxed) != 0
+    }
+}
+
+/// Handles are equal if they share the same settings.
+impl PartialEq for ReadMetricsConfig {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for ReadMetricsConfig {}
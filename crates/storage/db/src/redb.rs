@@ -0,0 +1,108 @@
+//! Helper functions for initializing and opening an experimental redb database.
+//!
+//! The redb backend implements the same [`Database`](reth_db_api::database::Database) abstraction
+//! as the MDBX backend. It's used either directly with [`init_db`] of this module, or selected at
+//! runtime with [`init_db_with_backend`].
+
+use crate::{TableSet, Tables};
+use eyre::Context;
+use std::{fmt, path::Path, str::FromStr};
+
+pub use crate::implementation::redb::*;
+
+/// Creates a new database at the specified path if it doesn't exist. Does NOT create tables. Check
+/// [`init_db`].
+pub fn create_db<P: AsRef<Path>>(path: P) -> eyre::Result<RedbEnv> {
+    let rpath = path.as_ref();
+    reth_fs_util::create_dir_all(rpath)
+        .wrap_err_with(|| format!("Could not create database directory {}", rpath.display()))?;
+
+    Ok(RedbEnv::open(rpath)?)
+}
+
+/// Opens up an existing database or creates a new one at the specified path. Creates the tables
+/// defined in [`Tables`] if necessary.
+pub fn init_db<P: AsRef<Path>>(path: P) -> eyre::Result<RedbEnv> {
+    init_db_for::<P, Tables>(path)
+}
+
+/// Opens up an existing database or creates a new one at the specified path. Creates the tables
+/// defined in the given [`TableSet`] if necessary.
+pub fn init_db_for<P: AsRef<Path>, TS: TableSet>(path: P) -> eyre::Result<RedbEnv> {
+    let db = create_db(path)?;
+    db.create_tables_for::<TS>()?;
+    Ok(db)
+}
+
+/// Storage backend of a database.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    /// The MDBX backend.
+    #[default]
+    Mdbx,
+    /// The experimental redb backend.
+    Redb,
+}
+
+impl DatabaseBackend {
+    /// Returns the backend of the existing database at the given path, or `None` if there's no
+    /// database.
+    pub fn detect(path: &Path) -> Option<Self> {
+        if path.join(REDB_FILE_NAME).is_file() {
+            Some(Self::Redb)
+        } else if crate::is_database_empty(path) {
+            None
+        } else {
+            Some(Self::Mdbx)
+        }
+    }
+}
+
+impl fmt::Display for DatabaseBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mdbx => f.write_str("mdbx"),
+            Self::Redb => f.write_str("redb"),
+        }
+    }
+}
+
+impl FromStr for DatabaseBackend {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mdbx" => Ok(Self::Mdbx),
+            "redb" => Ok(Self::Redb),
+            _ => Err(format!("unknown database backend {s}, expected mdbx or redb")),
+        }
+    }
+}
+
+/// Database with the storage backend that was selected when it was initialized.
+pub type AnyDatabaseEnv = reth_db_api::either::Either<crate::DatabaseEnv, RedbEnv>;
+
+/// Opens up an existing database or creates a new one at the specified path with the given
+/// backend. Creates the tables defined in [`Tables`] if necessary.
+///
+/// The database arguments only apply to the MDBX backend. An existing database has to have the
+/// given backend.
+pub fn init_db_with_backend<P: AsRef<Path>>(
+    path: P,
+    backend: DatabaseBackend,
+    args: crate::mdbx::DatabaseArguments,
+) -> eyre::Result<AnyDatabaseEnv> {
+    let path = path.as_ref();
+    if let Some(existing) = DatabaseBackend::detect(path).filter(|existing| *existing != backend) {
+        eyre::bail!(
+            "The database at {} uses the {existing} backend, it can't be opened with the {backend} \
+             backend",
+            path.display()
+        )
+    }
+
+    Ok(match backend {
+        DatabaseBackend::Mdbx => AnyDatabaseEnv::Left(crate::init_db(path, args)?),
+        DatabaseBackend::Redb => AnyDatabaseEnv::Right(init_db(path)?),
+    })
+}
@@ -58,6 +58,15 @@ pub enum ProviderError {
         /// The account address.
         address: Address,
     },
+    /// The block number was found in the code history of the address, but the code changeset
+    /// was not found.
+    #[error("code change set for address {address} at block #{block_number} does not exist")]
+    CodeChangesetNotFound {
+        /// Block number found for the address.
+        block_number: BlockNumber,
+        /// The account address.
+        address: Address,
+    },
     /// The total difficulty for a block is missing.
     #[error("total difficulty not found for block #{_0}")]
     TotalDifficultyNotFound(BlockNumber),
@@ -94,6 +94,30 @@ impl Environment {
         self.inner.txn_manager.timed_out_not_aborted_read_transactions().unwrap_or(0)
     }
 
+    /// Returns the number of read transactions that are currently open and monitored for
+    /// timeouts.
+    #[cfg(feature = "read-tx-timeouts")]
+    pub fn active_read_transactions(&self) -> usize {
+        self.inner.txn_manager.active_read_transactions().unwrap_or(0)
+    }
+
+    /// Returns the number of monitored read transactions that have been open for longer than the
+    /// given duration.
+    ///
+    /// Long-lived read transactions keep the pages of their snapshot from being reused, which grows
+    /// the database file.
+    #[cfg(feature = "read-tx-timeouts")]
+    pub fn long_read_transactions(&self, duration: Duration) -> usize {
+        self.inner.txn_manager.long_active_read_transactions(duration).unwrap_or(0)
+    }
+
+    /// Returns the duration for which the oldest monitored read transaction has been open, if
+    /// any.
+    #[cfg(feature = "read-tx-timeouts")]
+    pub fn oldest_read_transaction_duration(&self) -> Option<Duration> {
+        self.inner.txn_manager.oldest_active_read_transaction_duration()
+    }
+
     /// Create a read-only transaction for use with the environment.
     #[inline]
     pub fn begin_ro_txn(&self) -> Result<Transaction<RO>> {
@@ -363,6 +387,26 @@ impl GeometryInfo {
     pub const fn min(&self) -> u64 {
         self.0.lower
     }
+
+    /// Upper size limit of the environment in bytes.
+    pub const fn max(&self) -> u64 {
+        self.0.upper
+    }
+
+    /// Current size of the environment in bytes.
+    pub const fn current(&self) -> u64 {
+        self.0.current
+    }
+
+    /// Shrink threshold of the environment in bytes.
+    pub const fn shrink(&self) -> u64 {
+        self.0.shrink
+    }
+
+    /// Growth step of the environment in bytes.
+    pub const fn grow(&self) -> u64 {
+        self.0.grow
+    }
 }
 
 /// Environment information.
@@ -511,6 +511,32 @@ impl Transaction<RW> {
 }
 
 impl Transaction<RO> {
+    /// Renews the read transaction, moving it to the latest snapshot of the database.
+    ///
+    /// The previous snapshot is released, so the pages that were retained only for this
+    /// transaction can be reused by MDBX. If the transaction has been timed out, it becomes usable
+    /// again and its open duration is reset.
+    ///
+    /// Returns [`Error::BadTxn`] if the transaction is still shared, e.g. by an open [Cursor],
+    /// because cursors can't outlive the snapshot they were opened on.
+    pub fn renew(&mut self) -> Result<()> {
+        if Arc::strong_count(&self.inner) > 1 {
+            return Err(Error::BadTxn)
+        }
+
+        #[cfg(feature = "read-tx-timeouts")]
+        self.env().txn_manager().remove_active_read_transaction(self.inner.txn.txn);
+
+        self.inner.txn.renew()?;
+
+        #[cfg(feature = "read-tx-timeouts")]
+        self.env()
+            .txn_manager()
+            .add_active_read_transaction(self.inner.txn.txn, self.inner.txn.clone());
+
+        Ok(())
+    }
+
     /// Closes the database handle.
     ///
     /// # Safety
@@ -579,6 +605,28 @@ impl TransactionPtr {
         self.timed_out.store(true, std::sync::atomic::Ordering::SeqCst);
     }
 
+    /// Resets the read transaction and renews it at the latest snapshot of the database, clearing
+    /// the timed out flag.
+    fn renew(&self) -> Result<()> {
+        let _lck = self.lock();
+
+        // Timed out transactions are already reset by the `TxnManager`.
+        #[cfg(feature = "read-tx-timeouts")]
+        let is_reset = self.is_timed_out();
+        #[cfg(not(feature = "read-tx-timeouts"))]
+        let is_reset = false;
+
+        if !is_reset {
+            mdbx_result(unsafe { ffi::mdbx_txn_reset(self.txn) })?;
+        }
+        mdbx_result(unsafe { ffi::mdbx_txn_renew(self.txn) })?;
+
+        #[cfg(feature = "read-tx-timeouts")]
+        self.timed_out.store(false, std::sync::atomic::Ordering::SeqCst);
+
+        Ok(())
+    }
+
     /// Acquires the inner transaction lock to guarantee exclusive access to the transaction
     /// pointer.
     fn lock(&self) -> MutexGuard<'_, ()> {
@@ -158,6 +158,26 @@ mod read_transactions {
                 .as_ref()
                 .map(|read_transactions| read_transactions.timed_out_not_aborted())
         }
+
+        /// Returns the number of read transactions that are currently open.
+        pub(crate) fn active_read_transactions(&self) -> Option<usize> {
+            self.read_transactions.as_ref().map(|read_transactions| read_transactions.active.len())
+        }
+
+        /// Returns the number of read transactions that have been open for longer than the given
+        /// duration.
+        pub(crate) fn long_active_read_transactions(&self, duration: Duration) -> Option<usize> {
+            self.read_transactions
+                .as_ref()
+                .map(|read_transactions| read_transactions.long_active(duration))
+        }
+
+        /// Returns the duration for which the oldest active read transaction has been open.
+        pub(crate) fn oldest_active_read_transaction_duration(&self) -> Option<Duration> {
+            self.read_transactions
+                .as_ref()
+                .and_then(|read_transactions| read_transactions.oldest_active_duration())
+        }
     }
 
     #[derive(Debug, Default)]
@@ -206,6 +226,17 @@ mod read_transactions {
             self.timed_out_not_aborted.len()
         }
 
+        /// Returns the number of active read transactions that have been open for longer than the
+        /// given duration.
+        pub(super) fn long_active(&self, duration: Duration) -> usize {
+            self.active.iter().filter(|entry| entry.value().1.elapsed() > duration).count()
+        }
+
+        /// Returns the duration for which the oldest active read transaction has been open.
+        pub(super) fn oldest_active_duration(&self) -> Option<Duration> {
+            self.active.iter().map(|entry| entry.value().1.elapsed()).max()
+        }
+
         /// Spawns a new [`std::thread`] that monitors the list of active read transactions and
         /// timeouts those that are open for longer than `ReadTransactions.max_duration`.
         pub(super) fn start_monitor(self: Arc<Self>) {
@@ -319,7 +350,7 @@ mod read_transactions {
     mod tests {
         use crate::{
             txn_manager::read_transactions::READ_TRANSACTIONS_CHECK_INTERVAL, Environment, Error,
-            MaxReadTransactionDuration,
+            MaxReadTransactionDuration, WriteFlags,
         };
         use std::{thread::sleep, time::Duration};
         use tempfile::tempdir;
@@ -398,6 +429,52 @@ mod read_transactions {
             }
         }
 
+        #[test]
+        fn txn_manager_read_transactions_renew() {
+            const MAX_DURATION: Duration = Duration::from_secs(1);
+
+            let dir = tempdir().unwrap();
+            let env = Environment::builder()
+                .set_max_read_transaction_duration(MaxReadTransactionDuration::Set(MAX_DURATION))
+                .open(dir.path())
+                .unwrap();
+
+            let read_transactions = env.txn_manager().read_transactions.as_ref().unwrap();
+
+            let mut tx = env.begin_ro_txn().unwrap();
+            let tx_ptr = tx.txn() as usize;
+            assert_eq!(env.active_read_transactions(), 1);
+            assert!(env.oldest_read_transaction_duration().is_some());
+            assert_eq!(env.long_read_transactions(Duration::ZERO), 1);
+            assert_eq!(env.long_read_transactions(MAX_DURATION), 0);
+
+            // Renewing is not possible while a cursor is open on the transaction.
+            let db = tx.open_db(None).unwrap();
+            let cursor = tx.cursor(&db).unwrap();
+            assert_eq!(tx.renew().err(), Some(Error::BadTxn));
+            drop(cursor);
+
+            // Wait until the transaction is timed out by the manager.
+            sleep(MAX_DURATION + READ_TRANSACTIONS_CHECK_INTERVAL);
+            assert_eq!(tx.id().err(), Some(Error::ReadTransactionTimeout));
+            assert!(read_transactions.timed_out_not_aborted.contains(&tx_ptr));
+            assert_eq!(env.active_read_transactions(), 0);
+
+            // Renew the transaction at the latest snapshot and observe it's usable again.
+            let rw_tx = env.begin_rw_txn().unwrap();
+            rw_tx.put(db.dbi(), b"key", b"value", WriteFlags::empty()).unwrap();
+            rw_tx.commit().unwrap();
+
+            tx.renew().unwrap();
+            assert!(!read_transactions.timed_out_not_aborted.contains(&tx_ptr));
+            assert!(read_transactions.active.contains_key(&tx_ptr));
+            assert_eq!(tx.get::<Vec<u8>>(db.dbi(), b"key").unwrap(), Some(b"value".to_vec()));
+
+            drop(tx);
+            assert_eq!(env.active_read_transactions(), 0);
+            assert_eq!(env.oldest_read_transaction_duration(), None);
+        }
+
         #[test]
         fn txn_manager_read_transactions_duration_unbounded() {
             let dir = tempdir().unwrap();
@@ -247,6 +247,20 @@ impl<H: NippyJarHeader> NippyJar<H> {
         Ok(())
     }
 
+    /// Computes the BLAKE3 checksum of the data, offsets and config files of this [`NippyJar`].
+    ///
+    /// The files are streamed from disk, so this reads every byte of the jar and can be expensive
+    /// for large files.
+    pub fn checksum(&self) -> Result<[u8; 32], NippyJarError> {
+        let mut hasher = blake3::Hasher::new();
+        for path in [self.data_path().into(), self.offsets_path(), self.config_path()] {
+            let mut file =
+                File::open(&path).map_err(|err| reth_fs_util::FsPathError::open(err, path))?;
+            std::io::copy(&mut file, &mut hasher)?;
+        }
+        Ok(*hasher.finalize().as_bytes())
+    }
+
     /// Returns a [`DataReader`] of the data and offset file
     pub fn open_data_reader(&self) -> Result<DataReader, NippyJarError> {
         DataReader::new(self.data_path())
@@ -629,6 +643,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_checksum() {
+        let (col1, col2) = test_data(Some(1));
+        let num_rows = col1.len() as u64;
+        let file_path = tempfile::NamedTempFile::new().unwrap();
+
+        let nippy = NippyJar::new_without_header(2, file_path.path())
+            .freeze(vec![clone_with_result(&col1), clone_with_result(&col2)], num_rows)
+            .unwrap();
+        let checksum = nippy.checksum().unwrap();
+        assert_eq!(
+            checksum,
+            NippyJar::load_without_header(file_path.path()).unwrap().checksum().unwrap()
+        );
+
+        // flip a single bit of the data file
+        let mut data = std::fs::read(file_path.path()).unwrap();
+        data[0] ^= 1;
+        std::fs::write(file_path.path(), data).unwrap();
+        assert_ne!(checksum, nippy.checksum().unwrap());
+
+        // a missing satellite file fails the checksum
+        std::fs::remove_file(nippy.offsets_path()).unwrap();
+        assert!(nippy.checksum().is_err());
+    }
+
     /// Tests `NippyJar` with everything enabled.
     #[test]
     fn test_full_nippy_jar() {
@@ -0,0 +1,106 @@
+#![allow(missing_docs)]
+
+use alloy_primitives::{Address, B256, U256};
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use reth_db_api::{
+    models::{storage_sharded_key::StorageShardedKey, AccountBeforeTx, ShardedKey},
+    tables,
+    transaction::{DbTx, DbTxMut},
+    BlockNumberList,
+};
+use reth_primitives::{Account, StorageEntry};
+use reth_provider::{
+    test_utils::create_test_provider_factory, AccountReader, DatabaseProviderFactory,
+    HistoricalStateProvider, HistoricalStateProviderRef, StateProvider,
+};
+
+/// Number of accounts, each with a single storage slot.
+const ACCOUNTS: u64 = 1_000;
+/// Number of blocks in which every account and slot changed.
+const CHANGES: u64 = 100;
+/// Number of blocks between two changes.
+const CHANGE_INTERVAL: u64 = 10;
+
+criterion_group!(benches, historical_state);
+criterion_main!(benches);
+
+/// Measures repeated account and storage lookups of fresh historical state providers, with and
+/// without the history lookup cache.
+fn historical_state(c: &mut Criterion) {
+    let mut group = c.benchmark_group("HistoricalState");
+
+    let factory = create_test_provider_factory();
+    let tx = factory.provider_rw().unwrap().into_tx();
+    let addresses =
+        (0..ACCOUNTS).map(|i| Address::left_padding_from(&i.to_be_bytes())).collect::<Vec<_>>();
+    let slot = B256::with_last_byte(1);
+    let blocks = (1..=CHANGES).map(|change| change * CHANGE_INTERVAL).collect::<Vec<_>>();
+    for address in &addresses {
+        tx.put::<tables::AccountsHistory>(
+            ShardedKey::new(*address, u64::MAX),
+            BlockNumberList::new(blocks.iter().copied()).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::StoragesHistory>(
+            StorageShardedKey::new(*address, slot, u64::MAX),
+            BlockNumberList::new(blocks.iter().copied()).unwrap(),
+        )
+        .unwrap();
+    }
+    for block in &blocks {
+        for address in &addresses {
+            let account = Account { nonce: *block, ..Default::default() };
+            tx.put::<tables::AccountChangeSets>(
+                *block,
+                AccountBeforeTx { address: *address, info: Some(account) },
+            )
+            .unwrap();
+            tx.put::<tables::StorageChangeSets>(
+                (*block, *address).into(),
+                StorageEntry { key: slot, value: U256::from(*block) },
+            )
+            .unwrap();
+        }
+    }
+    tx.commit().unwrap();
+
+    let block_number = CHANGES * CHANGE_INTERVAL / 2 + 1;
+
+    // every provider is fresh, as for a single `eth_call`, which accesses the same accounts and
+    // slots a number of times
+    for accesses in [1, 10] {
+        group.bench_function(BenchmarkId::new("uncached", accesses), |b| {
+            b.iter_batched(
+                || factory.database_provider_ro().unwrap(),
+                |provider| {
+                    let state = HistoricalStateProviderRef::new(&provider, block_number);
+                    lookup(&state, &addresses, slot, accesses)
+                },
+                BatchSize::PerIteration,
+            )
+        });
+
+        group.bench_function(BenchmarkId::new("cached", accesses), |b| {
+            b.iter_batched(
+                || {
+                    HistoricalStateProvider::new(
+                        factory.database_provider_ro().unwrap(),
+                        block_number,
+                    )
+                },
+                |state| lookup(&state, &addresses, slot, accesses),
+                BatchSize::PerIteration,
+            )
+        });
+    }
+}
+
+/// Looks up every account and its storage slot the given number of times.
+fn lookup(state: &impl StateProvider, addresses: &[Address], slot: B256, accesses: usize) {
+    for _ in 0..accesses {
+        for address in addresses {
+            black_box(state.basic_account(address).unwrap());
+            black_box(state.storage(*address, slot).unwrap());
+        }
+    }
+}
@@ -43,7 +43,7 @@ pub mod writer;
 
 pub use reth_chain_state::{
     CanonStateNotification, CanonStateNotificationSender, CanonStateNotificationStream,
-    CanonStateNotifications, CanonStateSubscriptions,
+    CanonStateNotifications, CanonStateSubscriptionOptions, CanonStateSubscriptions,
 };
 
 // reexport traits to avoid breaking changes
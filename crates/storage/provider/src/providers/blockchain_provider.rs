@@ -3,7 +3,7 @@ use crate::{
     providers::{ConsistentProvider, ProviderNodeTypes, StaticFileProvider},
     AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
     BlockSource, CanonChainTracker, CanonStateNotifications, CanonStateSubscriptions,
-    ChainSpecProvider, ChainStateBlockReader, ChangeSetReader, DatabaseProvider,
+    ChainSpecProvider, ChainStateBlockReader, ChangeSetReader, CodeHistoryReader, DatabaseProvider,
     DatabaseProviderFactory, FullProvider, HashedPostStateProvider, HeaderProvider, ProviderError,
     ProviderFactory, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
     StageCheckpointReader, StateProviderBox, StateProviderFactory, StateReader,
@@ -17,8 +17,8 @@ use alloy_eips::{
 use alloy_primitives::{Address, BlockHash, BlockNumber, Sealable, TxHash, TxNumber, B256, U256};
 use alloy_rpc_types_engine::ForkchoiceState;
 use reth_chain_state::{
-    BlockState, CanonicalInMemoryState, ForkChoiceNotifications, ForkChoiceSubscriptions,
-    MemoryOverlayStateProvider,
+    BlockState, CanonStateSubscriptionOptions, CanonicalInMemoryState, ForkChoiceNotifications,
+    ForkChoiceSubscriptions, MemoryOverlayStateProvider,
 };
 use reth_chainspec::{ChainInfo, EthereumHardforks};
 use reth_db_api::{
@@ -708,6 +708,13 @@ impl<N: ProviderNodeTypes> CanonStateSubscriptions for BlockchainProvider<N> {
     fn subscribe_to_canonical_state(&self) -> CanonStateNotifications<Self::Primitives> {
         self.canonical_in_memory_state.subscribe_canon_state()
     }
+
+    fn subscribe_to_canonical_state_with(
+        &self,
+        options: CanonStateSubscriptionOptions,
+    ) -> CanonStateNotifications<Self::Primitives> {
+        self.canonical_in_memory_state.subscribe_canon_state_with(options)
+    }
 }
 
 impl<N: ProviderNodeTypes> ForkChoiceSubscriptions for BlockchainProvider<N> {
@@ -742,6 +749,15 @@ impl<N: ProviderNodeTypes> ChangeSetReader for BlockchainProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> CodeHistoryReader for BlockchainProvider<N> {
+    fn account_code_changes(
+        &self,
+        address: Address,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<B256>)>> {
+        self.consistent_provider()?.account_code_changes(address)
+    }
+}
+
 impl<N: ProviderNodeTypes> AccountReader for BlockchainProvider<N> {
     /// Get basic account information.
     fn basic_account(&self, address: &Address) -> ProviderResult<Option<Account>> {
@@ -1191,6 +1207,62 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_consistent_provider_snapshot() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let factory = create_test_provider_factory();
+
+        let blocks = random_block_range(
+            &mut rng,
+            0..=5,
+            BlockRangeParams { parent: Some(B256::ZERO), tx_count: 0..1, ..Default::default() },
+        );
+        let (database_blocks, in_memory_blocks) = blocks.split_at(5);
+
+        let provider_rw = factory.provider_rw()?;
+        for block in database_blocks {
+            provider_rw.insert_historical_block(
+                block.clone().try_recover().expect("failed to seal block with senders"),
+            )?;
+        }
+        provider_rw.commit()?;
+
+        let provider = BlockchainProvider::new(factory)?;
+        let consistent_provider = provider.consistent_provider()?;
+        let last_db_block = database_blocks.last().unwrap();
+        assert_eq!(consistent_provider.best_block_number()?, last_db_block.number);
+
+        // Insert a new block after the consistent provider was created
+        let new_block = in_memory_blocks.first().unwrap();
+        let chain = NewCanonicalChain::Commit {
+            new: vec![ExecutedBlockWithTrieUpdates::new(
+                Arc::new(RecoveredBlock::new_sealed(
+                    new_block.clone(),
+                    new_block.senders().expect("failed to recover senders"),
+                )),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            )],
+        };
+        provider.canonical_in_memory_state.update_chain(chain);
+        provider.set_canonical_head(new_block.clone_sealed_header());
+
+        // The provider sees the new block, the consistent provider doesn't
+        assert_eq!(provider.best_block_number()?, new_block.number);
+        assert!(provider.block(BlockHashOrNumber::Number(new_block.number))?.is_some());
+        assert_eq!(consistent_provider.best_block_number()?, last_db_block.number);
+        assert_eq!(consistent_provider.block(BlockHashOrNumber::Number(new_block.number))?, None);
+        assert_eq!(
+            consistent_provider
+                .header_by_number_or_tag(BlockNumberOrTag::Latest)?
+                .map(|header| header.number),
+            Some(last_db_block.number)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_block_reader_pending_block() -> eyre::Result<()> {
         let mut rng = generators::rng();
@@ -206,6 +206,50 @@ impl<SP: StateProvider, EDP: ExecutionDataProvider> StateProvider for BundleStat
         self.state_provider.storage(account, storage_key)
     }
 
+    fn storage_multi(
+        &self,
+        account: Address,
+        storage_keys: &[alloy_primitives::StorageKey],
+    ) -> ProviderResult<Vec<Option<alloy_primitives::StorageValue>>> {
+        let execution_outcome = self.block_execution_data_provider.execution_outcome();
+        let values = storage_keys
+            .iter()
+            .map(|key| execution_outcome.storage(&account, (*key).into()).map(Some))
+            .collect::<Vec<_>>();
+
+        // only look up the slots that are not part of the execution outcome
+        let missing = storage_keys
+            .iter()
+            .zip(&values)
+            .filter_map(|(key, value)| value.is_none().then_some(*key))
+            .collect::<Vec<_>>();
+        let mut fetched = self.state_provider.storage_multi(account, &missing)?.into_iter();
+
+        Ok(values
+            .into_iter()
+            .map(|value| value.unwrap_or_else(|| fetched.next().flatten()))
+            .collect())
+    }
+
+    fn basic_accounts(&self, addresses: &[Address]) -> ProviderResult<Vec<Option<Account>>> {
+        let execution_outcome = self.block_execution_data_provider.execution_outcome();
+        let accounts =
+            addresses.iter().map(|address| execution_outcome.account(address)).collect::<Vec<_>>();
+
+        // only look up the accounts that are not part of the execution outcome
+        let missing = addresses
+            .iter()
+            .zip(&accounts)
+            .filter_map(|(address, account)| account.is_none().then_some(*address))
+            .collect::<Vec<_>>();
+        let mut fetched = self.state_provider.basic_accounts(&missing)?.into_iter();
+
+        Ok(accounts
+            .into_iter()
+            .map(|account| account.unwrap_or_else(|| fetched.next().flatten()))
+            .collect())
+    }
+
     fn bytecode_by_hash(&self, code_hash: &B256) -> ProviderResult<Option<Bytecode>> {
         if let Some(bytecode) =
             self.block_execution_data_provider.execution_outcome().bytecode(code_hash)
@@ -1,10 +1,10 @@
 use super::{DatabaseProviderRO, ProviderFactory, ProviderNodeTypes};
 use crate::{
     providers::StaticFileProvider, AccountReader, BlockHashReader, BlockIdReader, BlockNumReader,
-    BlockReader, BlockReaderIdExt, BlockSource, ChainSpecProvider, ChangeSetReader, HeaderProvider,
-    ProviderError, PruneCheckpointReader, ReceiptProvider, ReceiptProviderIdExt,
-    StageCheckpointReader, StateReader, StaticFileProviderFactory, TransactionVariant,
-    TransactionsProvider, WithdrawalsProvider,
+    BlockReader, BlockReaderIdExt, BlockSource, ChainSpecProvider, ChangeSetReader,
+    CodeHistoryReader, HeaderProvider, ProviderError, PruneCheckpointReader, ReceiptProvider,
+    ReceiptProviderIdExt, StageCheckpointReader, StateReader, StaticFileProviderFactory,
+    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use alloy_consensus::{transaction::TransactionMeta, BlockHeader};
 use alloy_eips::{
@@ -1273,9 +1273,8 @@ impl<N: ProviderNodeTypes> BlockReaderIdExt for ConsistentProvider<N> {
 
     fn header_by_number_or_tag(&self, id: BlockNumberOrTag) -> ProviderResult<Option<HeaderTy<N>>> {
         Ok(match id {
-            BlockNumberOrTag::Latest => {
-                Some(self.canonical_in_memory_state.get_canonical_head().unseal())
-            }
+            // the head of the snapshot, the canonical head may have advanced since
+            BlockNumberOrTag::Latest => self.header_by_number(self.best_block_number()?)?,
             BlockNumberOrTag::Finalized => {
                 self.canonical_in_memory_state.get_finalized_header().map(|h| h.unseal())
             }
@@ -1294,9 +1293,7 @@ impl<N: ProviderNodeTypes> BlockReaderIdExt for ConsistentProvider<N> {
         id: BlockNumberOrTag,
     ) -> ProviderResult<Option<SealedHeader<HeaderTy<N>>>> {
         match id {
-            BlockNumberOrTag::Latest => {
-                Ok(Some(self.canonical_in_memory_state.get_canonical_head()))
-            }
+            BlockNumberOrTag::Latest => self.sealed_header(self.best_block_number()?),
             BlockNumberOrTag::Finalized => {
                 Ok(self.canonical_in_memory_state.get_finalized_header())
             }
@@ -1439,6 +1436,36 @@ impl<N: ProviderNodeTypes> ChangeSetReader for ConsistentProvider<N> {
     }
 }
 
+impl<N: ProviderNodeTypes> CodeHistoryReader for ConsistentProvider<N> {
+    fn account_code_changes(
+        &self,
+        address: Address,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<B256>)>> {
+        let mut changes = self.storage_provider.account_code_changes(address)?;
+
+        // Append the changes of the in-memory blocks that are not persisted yet, oldest first.
+        let in_mem_chain = self.head_block.iter().flat_map(|b| b.chain()).collect::<Vec<_>>();
+        for block_state in in_mem_chain.into_iter().rev() {
+            let block_number = block_state.number();
+            if changes.last().is_some_and(|(last, _)| *last >= block_number) {
+                continue
+            }
+            if let Some((_, code_hash)) = block_state
+                .block_ref()
+                .execution_output
+                .code_changes()
+                .into_iter()
+                .flatten()
+                .find(|(changed, _)| *changed == address)
+            {
+                changes.push((block_number, code_hash));
+            }
+        }
+
+        Ok(changes)
+    }
+}
+
 impl<N: ProviderNodeTypes> AccountReader for ConsistentProvider<N> {
     /// Get basic account information.
     fn basic_account(&self, address: &Address) -> ProviderResult<Option<Account>> {
@@ -0,0 +1,201 @@
+use super::ProviderFactory;
+use crate::{
+    providers::ProviderNodeTypes, BlockBodyIndicesProvider, PruneCheckpointReader,
+    PruneCheckpointWriter, StageCheckpointReader, StaticFileProviderFactory,
+};
+use alloy_primitives::BlockNumber;
+use reth_stages_types::{PipelineTarget, StageId};
+use reth_storage_errors::provider::ProviderResult;
+use tracing::{info, warn};
+
+impl<N: ProviderNodeTypes> ProviderFactory<N> {
+    /// Checks the consistency of stage checkpoints, static files and prune checkpoints, healing
+    /// what can be healed on the spot.
+    ///
+    /// The following invariants are checked:
+    ///
+    /// * static files and database tables agree with each other, see
+    ///   [`StaticFileProvider::check_consistency`](crate::providers::StaticFileProvider::check_consistency).
+    /// * no stage is ahead of a stage that precedes it in [`StageId::ALL`], because every stage
+    ///   derives its tables from the output of the previous ones.
+    /// * no prune checkpoint is ahead of the highest stage checkpoint. Such checkpoints are lowered
+    ///   to the highest stage checkpoint right away.
+    ///
+    /// Returns a [`PipelineTarget::Unwind`] to the highest consistent block if derived tables need
+    /// to be rolled back.
+    ///
+    /// WARNING: No static file writer should be held before calling this function, otherwise it
+    /// will deadlock.
+    pub fn check_consistency(
+        &self,
+        has_receipt_pruning: bool,
+    ) -> ProviderResult<Option<PipelineTarget>> {
+        let provider = self.provider()?;
+
+        let static_file_target = self
+            .static_file_provider()
+            .check_consistency(&provider, has_receipt_pruning)?
+            .and_then(PipelineTarget::unwind_target);
+        let checkpoints = StageId::ALL
+            .into_iter()
+            .map(|stage| Ok(provider.get_stage_checkpoint(stage)?.map(|c| (stage, c.block_number))))
+            .filter_map(Result::transpose)
+            .collect::<ProviderResult<Vec<_>>>()?;
+        drop(provider);
+
+        let stage_target = check_stage_checkpoints(&checkpoints);
+        if let Some(highest_block) = checkpoints.iter().map(|(_, block_number)| *block_number).max()
+        {
+            self.heal_prune_checkpoints(highest_block)?;
+        }
+
+        let unwind_target = match (static_file_target, stage_target) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+
+        Ok(unwind_target.map(PipelineTarget::Unwind))
+    }
+
+    /// Lowers all prune checkpoints that are ahead of `highest_block` to it.
+    fn heal_prune_checkpoints(&self, highest_block: BlockNumber) -> ProviderResult<()> {
+        let checkpoints = self.get_prune_checkpoints()?;
+        if checkpoints.iter().all(|(_, checkpoint)| {
+            checkpoint.block_number.is_none_or(|block_number| block_number <= highest_block)
+        }) {
+            return Ok(())
+        }
+
+        let provider_rw = self.provider_rw()?;
+        let last_tx_number =
+            provider_rw.block_body_indices(highest_block)?.map(|indices| indices.last_tx_num());
+
+        for (segment, mut checkpoint) in checkpoints {
+            if checkpoint.block_number.is_some_and(|block_number| block_number > highest_block) {
+                warn!(
+                    target: "reth::cli",
+                    ?segment,
+                    checkpoint_block = ?checkpoint.block_number,
+                    highest_block,
+                    "Prune checkpoint is ahead of the stage checkpoints, lowering it."
+                );
+                checkpoint.block_number = Some(highest_block);
+                checkpoint.tx_number = last_tx_number;
+                provider_rw.save_prune_checkpoint(segment, checkpoint)?;
+            }
+        }
+
+        provider_rw.commit()?;
+
+        Ok(())
+    }
+}
+
+/// Returns the block number to unwind to if any stage checkpoint is ahead of the checkpoint of a
+/// stage that precedes it.
+///
+/// Checkpoints are expected in the order of [`StageId::ALL`]. Stages at block zero are skipped,
+/// because they were either never run or deliberately reset with `reth stage drop`, and will be
+/// re-run by the pipeline.
+fn check_stage_checkpoints(checkpoints: &[(StageId, BlockNumber)]) -> Option<BlockNumber> {
+    let mut lowest_preceding: Option<(StageId, BlockNumber)> = None;
+    let mut unwind_target: Option<BlockNumber> = None;
+
+    for &(stage, block_number) in checkpoints.iter().filter(|(_, block_number)| *block_number > 0) {
+        match lowest_preceding {
+            Some((preceding, preceding_block_number)) if block_number > preceding_block_number => {
+                info!(
+                    target: "reth::cli",
+                    %stage,
+                    checkpoint = block_number,
+                    %preceding,
+                    preceding_checkpoint = preceding_block_number,
+                    "Stage checkpoint is ahead of a preceding stage."
+                );
+                unwind_target = Some(
+                    unwind_target.map_or(preceding_block_number, |t| t.min(preceding_block_number)),
+                );
+            }
+            Some((_, preceding_block_number)) if block_number == preceding_block_number => {}
+            _ => lowest_preceding = Some((stage, block_number)),
+        }
+    }
+
+    unwind_target
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_stage_checkpoints;
+    use crate::{
+        test_utils::create_test_provider_factory, PruneCheckpointReader, PruneCheckpointWriter,
+    };
+    use reth_prune_types::{PruneCheckpoint, PruneMode, PruneSegment};
+    use reth_stages_types::StageId;
+
+    #[test]
+    fn stage_ahead_of_preceding_stage() {
+        let checkpoints = |execution, merkle| {
+            StageId::ALL
+                .into_iter()
+                .map(|stage| match stage {
+                    StageId::Headers | StageId::Bodies | StageId::SenderRecovery => (stage, 100),
+                    StageId::Execution => (stage, execution),
+                    StageId::MerkleExecute => (stage, merkle),
+                    _ => (stage, merkle.min(execution)),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(check_stage_checkpoints(&checkpoints(100, 100)), None);
+        // Derived stages lagging behind is the regular state of an interrupted pipeline run.
+        assert_eq!(check_stage_checkpoints(&checkpoints(100, 80)), None);
+        // Execution was rolled back, but the Merkle stage was not.
+        assert_eq!(check_stage_checkpoints(&checkpoints(90, 95)), Some(90));
+        // Only the lowest preceding checkpoint is taken into account.
+        assert_eq!(
+            check_stage_checkpoints(&[
+                (StageId::Headers, 100),
+                (StageId::Bodies, 50),
+                (StageId::SenderRecovery, 70),
+                (StageId::Execution, 60),
+                (StageId::Finish, 40),
+            ]),
+            Some(50)
+        );
+        // Dropped stages are re-run by the pipeline.
+        assert_eq!(check_stage_checkpoints(&checkpoints(0, 100)), None);
+    }
+
+    #[test]
+    fn prune_checkpoint_ahead_of_stages() {
+        let factory = create_test_provider_factory();
+
+        let behind = PruneCheckpoint {
+            block_number: Some(5),
+            tx_number: Some(5),
+            prune_mode: PruneMode::Full,
+        };
+        let ahead = PruneCheckpoint {
+            block_number: Some(20),
+            tx_number: Some(20),
+            prune_mode: PruneMode::Full,
+        };
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.save_prune_checkpoint(PruneSegment::SenderRecovery, behind).unwrap();
+        provider_rw.save_prune_checkpoint(PruneSegment::TransactionLookup, ahead).unwrap();
+        provider_rw.commit().unwrap();
+
+        factory.heal_prune_checkpoints(10).unwrap();
+
+        assert_eq!(
+            factory.get_prune_checkpoint(PruneSegment::SenderRecovery).unwrap(),
+            Some(behind)
+        );
+        // There are no block body indices, so the transaction number is unknown.
+        assert_eq!(
+            factory.get_prune_checkpoint(PruneSegment::TransactionLookup).unwrap(),
+            Some(PruneCheckpoint { block_number: Some(10), tx_number: None, ..ahead })
+        );
+    }
+}
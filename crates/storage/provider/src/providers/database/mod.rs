@@ -26,6 +26,7 @@ use reth_storage_api::{
     TryIntoHistoricalStateProvider,
 };
 use reth_storage_errors::provider::ProviderResult;
+use reth_tasks::TaskSpawner;
 use reth_trie::HashedPostState;
 use reth_trie_db::StateCommitment;
 use revm_database::BundleState;
@@ -47,6 +48,8 @@ pub use builder::{ProviderFactoryBuilder, ReadOnlyConfig};
 
 mod metrics;
 
+mod consistency;
+
 mod chain;
 pub use chain::*;
 
@@ -95,6 +98,12 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
         self
     }
 
+    /// Sets the spawner of the blocking tasks that record the checksums of sealed static files.
+    pub fn with_static_files_task_spawner(mut self, task_spawner: Box<dyn TaskSpawner>) -> Self {
+        self.static_file_provider = self.static_file_provider.with_task_spawner(task_spawner);
+        self
+    }
+
     /// Sets the pruning configuration for an existing [`ProviderFactory`].
     pub fn with_prune_modes(mut self, prune_modes: PruneModes) -> Self {
         self.prune_modes = prune_modes;
@@ -649,8 +658,8 @@ mod tests {
     use crate::{
         providers::{StaticFileProvider, StaticFileWriter},
         test_utils::{blocks::TEST_BLOCK, create_test_provider_factory, MockNodeTypesWithDB},
-        BlockHashReader, BlockNumReader, BlockWriter, DBProvider, HeaderSyncGapProvider,
-        StorageLocation, TransactionsProvider,
+        BlockHashReader, BlockNumReader, BlockWriter, DBProvider, HeaderAccumulatorReader,
+        HeaderAccumulatorWriter, HeaderSyncGapProvider, StorageLocation, TransactionsProvider,
     };
     use alloy_primitives::{TxNumber, B256, U256};
     use assert_matches::assert_matches;
@@ -823,4 +832,363 @@ mod tests {
         assert_eq!(gap.local_head, head);
         assert_eq!(gap.target.tip(), consensus_tip.into());
     }
+
+    #[test]
+    fn header_accumulator() {
+        let factory = create_test_provider_factory();
+        let hashes = (0..=12).map(B256::with_last_byte).collect::<Vec<_>>();
+
+        let provider = factory.provider_rw().unwrap();
+        provider.append_header_accumulator(hashes[..5].iter().copied()).unwrap();
+        provider.append_header_accumulator(hashes[5..].iter().copied()).unwrap();
+        assert_eq!(provider.header_accumulator_len().unwrap(), 13);
+        assert_eq!(provider.accumulated_block_hash(7).unwrap(), Some(hashes[7]));
+        assert_eq!(provider.accumulated_block_hash(13).unwrap(), None);
+
+        let root = provider.header_proof(0).unwrap().unwrap().root;
+        for (block_number, hash) in hashes.iter().enumerate() {
+            let proof = provider.header_proof(block_number as u64).unwrap().unwrap();
+            assert_eq!(proof.block_hash, *hash);
+            assert_eq!(proof.root, root);
+            assert!(proof.verify());
+        }
+        assert_eq!(provider.header_proof(13).unwrap(), None);
+
+        // the accumulator of a truncated and re-extended chain equals the one built at once
+        provider.truncate_header_accumulator(9).unwrap();
+        assert_eq!(provider.header_accumulator_len().unwrap(), 9);
+        provider.append_header_accumulator(hashes[9..].iter().copied()).unwrap();
+        assert_eq!(provider.header_proof(12).unwrap().unwrap().root, root);
+    }
+
+    /// Node types whose header has a field the ethereum header doesn't have.
+    mod custom_header {
+        use super::*;
+        use crate::{
+            test_utils::create_test_provider_factory_with_node_types, EthStorage, HeaderProvider,
+        };
+        use alloy_consensus::{BlockBody, BlockHeader as _, Header};
+        use alloy_eips::eip7840::BlobParams;
+        use alloy_genesis::Genesis;
+        use alloy_primitives::{
+            bytes::BufMut, keccak256, Address, BlockNumber, Bloom, Bytes, Sealable, B64,
+        };
+        use alloy_rlp::{RlpDecodable, RlpEncodable};
+        use reth_chainspec::{
+            BaseFeeParams, Chain, ChainSpec, DepositContract, EthChainSpec, EthereumHardfork,
+            EthereumHardforks, ForkCondition,
+        };
+        use reth_codecs::Compact;
+        use reth_db_api::{
+            table::{Compress, Decompress},
+            DatabaseError,
+        };
+        use reth_network_peers::NodeRecord;
+        use reth_node_types::AnyNodeTypes;
+        use reth_primitives::{Receipt, SealedBlock, TransactionSigned};
+        use reth_primitives_traits::{
+            serde_bincode_compat::{BincodeReprFor, SerdeBincodeCompat},
+            InMemorySize, NodePrimitives,
+        };
+        use reth_trie_db::MerklePatriciaTrie;
+        use serde::{Deserialize, Serialize};
+        use std::fmt::Display;
+
+        #[derive(
+            Debug,
+            Clone,
+            Default,
+            PartialEq,
+            Eq,
+            Hash,
+            Serialize,
+            Deserialize,
+            RlpEncodable,
+            RlpDecodable,
+        )]
+        struct CustomHeader {
+            inner: Header,
+            extension: u64,
+        }
+
+        impl alloy_consensus::BlockHeader for CustomHeader {
+            fn parent_hash(&self) -> B256 {
+                self.inner.parent_hash()
+            }
+
+            fn ommers_hash(&self) -> B256 {
+                self.inner.ommers_hash()
+            }
+
+            fn beneficiary(&self) -> Address {
+                self.inner.beneficiary()
+            }
+
+            fn state_root(&self) -> B256 {
+                self.inner.state_root()
+            }
+
+            fn transactions_root(&self) -> B256 {
+                self.inner.transactions_root()
+            }
+
+            fn receipts_root(&self) -> B256 {
+                self.inner.receipts_root()
+            }
+
+            fn withdrawals_root(&self) -> Option<B256> {
+                self.inner.withdrawals_root()
+            }
+
+            fn logs_bloom(&self) -> Bloom {
+                self.inner.logs_bloom()
+            }
+
+            fn difficulty(&self) -> U256 {
+                self.inner.difficulty()
+            }
+
+            fn number(&self) -> BlockNumber {
+                self.inner.number()
+            }
+
+            fn gas_limit(&self) -> u64 {
+                self.inner.gas_limit()
+            }
+
+            fn gas_used(&self) -> u64 {
+                self.inner.gas_used()
+            }
+
+            fn timestamp(&self) -> u64 {
+                self.inner.timestamp()
+            }
+
+            fn mix_hash(&self) -> Option<B256> {
+                self.inner.mix_hash()
+            }
+
+            fn nonce(&self) -> Option<B64> {
+                self.inner.nonce()
+            }
+
+            fn base_fee_per_gas(&self) -> Option<u64> {
+                self.inner.base_fee_per_gas()
+            }
+
+            fn blob_gas_used(&self) -> Option<u64> {
+                self.inner.blob_gas_used()
+            }
+
+            fn excess_blob_gas(&self) -> Option<u64> {
+                self.inner.excess_blob_gas()
+            }
+
+            fn parent_beacon_block_root(&self) -> Option<B256> {
+                self.inner.parent_beacon_block_root()
+            }
+
+            fn requests_hash(&self) -> Option<B256> {
+                self.inner.requests_hash()
+            }
+
+            fn extra_data(&self) -> &Bytes {
+                self.inner.extra_data()
+            }
+        }
+
+        impl reth_primitives_traits::BlockHeader for CustomHeader {}
+
+        impl Sealable for CustomHeader {
+            fn hash_slow(&self) -> B256 {
+                keccak256(alloy_rlp::encode(self))
+            }
+        }
+
+        impl InMemorySize for CustomHeader {
+            fn size(&self) -> usize {
+                self.inner.size() + core::mem::size_of::<u64>()
+            }
+        }
+
+        impl AsRef<Self> for CustomHeader {
+            fn as_ref(&self) -> &Self {
+                self
+            }
+        }
+
+        impl Compact for CustomHeader {
+            fn to_compact<B>(&self, buf: &mut B) -> usize
+            where
+                B: BufMut + AsMut<[u8]>,
+            {
+                buf.put_u64(self.extension);
+                self.inner.to_compact(buf) + 8
+            }
+
+            fn from_compact(buf: &[u8], len: usize) -> (Self, &[u8]) {
+                let (extension, buf) = buf.split_at(8);
+                let (inner, buf) = Header::from_compact(buf, len - 8);
+                (Self { inner, extension: u64::from_be_bytes(extension.try_into().unwrap()) }, buf)
+            }
+        }
+
+        impl Compress for CustomHeader {
+            type Compressed = Vec<u8>;
+
+            fn compress_to_buf<B: BufMut + AsMut<[u8]>>(&self, buf: &mut B) {
+                let _ = self.to_compact(buf);
+            }
+        }
+
+        impl Decompress for CustomHeader {
+            fn decompress(value: &[u8]) -> Result<Self, DatabaseError> {
+                Ok(Self::from_compact(value, value.len()).0)
+            }
+        }
+
+        #[derive(Debug, Serialize, Deserialize)]
+        struct CustomHeaderRepr<'a> {
+            inner: BincodeReprFor<'a, Header>,
+            extension: u64,
+        }
+
+        impl SerdeBincodeCompat for CustomHeader {
+            type BincodeRepr<'a> = CustomHeaderRepr<'a>;
+
+            fn as_repr(&self) -> Self::BincodeRepr<'_> {
+                CustomHeaderRepr { inner: self.inner.as_repr(), extension: self.extension }
+            }
+
+            fn from_repr(repr: Self::BincodeRepr<'_>) -> Self {
+                Self { inner: Header::from_repr(repr.inner), extension: repr.extension }
+            }
+        }
+
+        #[derive(Debug, Clone, Default, PartialEq, Eq)]
+        struct CustomPrimitives;
+
+        impl NodePrimitives for CustomPrimitives {
+            type Block = alloy_consensus::Block<TransactionSigned, CustomHeader>;
+            type BlockHeader = CustomHeader;
+            type BlockBody = BlockBody<TransactionSigned, CustomHeader>;
+            type SignedTx = TransactionSigned;
+            type Receipt = Receipt;
+        }
+
+        /// Mainnet with a genesis header of the custom header type.
+        #[derive(Debug)]
+        struct CustomChainSpec {
+            inner: ChainSpec,
+            genesis_header: CustomHeader,
+        }
+
+        impl EthChainSpec for CustomChainSpec {
+            type Header = CustomHeader;
+
+            fn chain(&self) -> Chain {
+                self.inner.chain()
+            }
+
+            fn base_fee_params_at_block(&self, block_number: u64) -> BaseFeeParams {
+                self.inner.base_fee_params_at_block(block_number)
+            }
+
+            fn base_fee_params_at_timestamp(&self, timestamp: u64) -> BaseFeeParams {
+                self.inner.base_fee_params_at_timestamp(timestamp)
+            }
+
+            fn blob_params_at_timestamp(&self, timestamp: u64) -> Option<BlobParams> {
+                self.inner.blob_params_at_timestamp(timestamp)
+            }
+
+            fn deposit_contract(&self) -> Option<&DepositContract> {
+                self.inner.deposit_contract()
+            }
+
+            fn genesis_hash(&self) -> B256 {
+                self.inner.genesis_hash()
+            }
+
+            fn prune_delete_limit(&self) -> usize {
+                self.inner.prune_delete_limit()
+            }
+
+            fn display_hardforks(&self) -> Box<dyn Display> {
+                self.inner.display_hardforks()
+            }
+
+            fn genesis_header(&self) -> &Self::Header {
+                &self.genesis_header
+            }
+
+            fn genesis(&self) -> &Genesis {
+                self.inner.genesis()
+            }
+
+            fn bootnodes(&self) -> Option<Vec<NodeRecord>> {
+                self.inner.bootnodes()
+            }
+
+            fn final_paris_total_difficulty(&self) -> Option<U256> {
+                self.inner.final_paris_total_difficulty()
+            }
+        }
+
+        impl EthereumHardforks for CustomChainSpec {
+            fn ethereum_fork_activation(&self, fork: EthereumHardfork) -> ForkCondition {
+                self.inner.ethereum_fork_activation(fork)
+            }
+        }
+
+        type CustomNodeTypes = AnyNodeTypes<
+            CustomPrimitives,
+            CustomChainSpec,
+            MerklePatriciaTrie,
+            EthStorage<TransactionSigned, CustomHeader>,
+        >;
+
+        #[test]
+        fn custom_header_node_types() {
+            let header = CustomHeader {
+                inner: Header { gas_limit: 30_000_000, ..Default::default() },
+                extension: 42,
+            };
+            let chain_spec = Arc::new(CustomChainSpec {
+                inner: ChainSpecBuilder::mainnet().build(),
+                genesis_header: header.clone(),
+            });
+            let factory =
+                create_test_provider_factory_with_node_types::<CustomNodeTypes>(chain_spec);
+
+            let block = SealedBlock::seal_slow(alloy_consensus::Block::new(
+                header.clone(),
+                BlockBody::default(),
+            ));
+            let hash = block.hash();
+            assert_ne!(hash, header.inner.hash_slow());
+
+            let provider = factory.provider_rw().unwrap();
+            provider.insert_block(block.try_recover().unwrap(), StorageLocation::Database).unwrap();
+            provider.commit().unwrap();
+
+            // the extension field survives the roundtrip through the database
+            let provider = factory.provider().unwrap();
+            assert_eq!(provider.header(&hash).unwrap(), Some(header.clone()));
+            assert_eq!(provider.sealed_header(0).unwrap().map(|header| header.hash()), Some(hash));
+            assert_eq!(
+                provider.block_by_number(0).unwrap().map(|block| block.header),
+                Some(header.clone())
+            );
+
+            // and through the static files
+            let static_file_provider = factory.static_file_provider();
+            let mut static_file_writer =
+                static_file_provider.latest_writer(StaticFileSegment::Headers).unwrap();
+            static_file_writer.append_header(&header, U256::ZERO, &hash).unwrap();
+            static_file_writer.commit().unwrap();
+            drop(static_file_writer);
+            assert_eq!(static_file_provider.header_by_number(0).unwrap(), Some(header));
+        }
+    }
 }
@@ -7,7 +7,8 @@ use crate::{
     },
     to_range,
     traits::{
-        AccountExtReader, BlockSource, ChangeSetReader, ReceiptProvider, StageCheckpointWriter,
+        AccountExtReader, BlockSource, ChangeSetReader, CodeHistoryReader, ReceiptProvider,
+        StageCheckpointWriter,
     },
     AccountReader, BlockBodyWriter, BlockExecutionWriter, BlockHashReader, BlockNumReader,
     BlockReader, BlockWriter, BundleStateInit, ChainStateBlockReader, ChainStateBlockWriter,
@@ -19,7 +20,7 @@ use crate::{
     StorageReader, StorageTrieWriter, TransactionVariant, TransactionsProvider,
     TransactionsProviderExt, TrieWriter, WithdrawalsProvider,
 };
-use alloy_consensus::{transaction::TransactionMeta, BlockHeader, Header, TxReceipt};
+use alloy_consensus::{transaction::TransactionMeta, BlockHeader, TxReceipt};
 use alloy_eips::{eip2718::Encodable2718, eip4895::Withdrawals, BlockHashOrNumber};
 use alloy_primitives::{
     keccak256,
@@ -33,8 +34,14 @@ use reth_db_api::{
     cursor::{DbCursorRO, DbCursorRW, DbDupCursorRO, DbDupCursorRW},
     database::Database,
     models::{
-        sharded_key, storage_sharded_key::StorageShardedKey, AccountBeforeTx, BlockNumberAddress,
-        ShardedKey, StoredBlockBodyIndices,
+        accumulator::{
+            mmr_append_siblings, mmr_leaves, mmr_parent, mmr_peaks, mmr_proof_positions, mmr_root,
+            mmr_size,
+        },
+        sharded_key,
+        storage_sharded_key::StorageShardedKey,
+        AccountBeforeTx, BlockNumberAddress, CodeBeforeTx, HeaderProof, ShardedKey,
+        StoredBlockBodyIndices,
     },
     table::Table,
     tables,
@@ -48,14 +55,15 @@ use reth_primitives::{
     Account, Bytecode, GotExpected, NodePrimitives, RecoveredBlock, SealedBlock, SealedHeader,
     StaticFileSegment, StorageEntry,
 };
-use reth_primitives_traits::{Block as _, BlockBody as _, SignedTransaction};
+use reth_primitives_traits::{Block as _, BlockBody as _, BlockHeaderMut, SignedTransaction};
 use reth_prune_types::{
     PruneCheckpoint, PruneMode, PruneModes, PruneSegment, MINIMUM_PRUNING_DISTANCE,
 };
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
-    BlockBodyIndicesProvider, BlockBodyReader, NodePrimitivesProvider, OmmersProvider,
-    StateProvider, StorageChangeSetReader, TryIntoHistoricalStateProvider,
+    BlockBodyIndicesProvider, BlockBodyReader, HeaderAccumulatorReader, HeaderAccumulatorWriter,
+    NodePrimitivesProvider, OmmersProvider, StateProvider, StorageChangeSetReader,
+    TryIntoHistoricalStateProvider,
 };
 use reth_storage_errors::provider::{ProviderResult, RootMismatch};
 use reth_trie::{
@@ -416,7 +424,7 @@ impl<TX: DbTx + 'static, N: NodeTypes> StateCommitmentProvider for DatabaseProvi
 
 impl<
         Tx: DbTx + DbTxMut + 'static,
-        N: NodeTypesForProvider<Primitives: NodePrimitives<BlockHeader = Header>>,
+        N: NodeTypesForProvider<Primitives: NodePrimitives<BlockHeader: BlockHeaderMut>>,
     > DatabaseProvider<Tx, N>
 {
     // TODO: uncomment below, once `reth debug_cmd` has been feature gated with dev.
@@ -441,7 +449,7 @@ impl<
         if segment_header.block_end().is_none() && segment_header.expected_block_start() == 0 {
             for block_number in 0..block.number() {
                 let mut prev = block.clone_header();
-                prev.number = block_number;
+                prev.set_number(block_number);
                 writer.append_header(&prev, U256::ZERO, &B256::ZERO)?;
             }
         }
@@ -850,6 +858,63 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
         }
         Ok(())
     }
+
+    /// Writes the code changes of each block, starting at `first_block`, to the
+    /// [`CodeChangeSets`](tables::CodeChangeSets) and [`CodeHistory`](tables::CodeHistory) tables.
+    ///
+    /// See [`ExecutionOutcome::code_changes`].
+    fn write_code_changes(
+        &self,
+        code_changes: Vec<Vec<(Address, Option<B256>)>>,
+        first_block: BlockNumber,
+    ) -> ProviderResult<()> {
+        let mut changeset_cursor = self.tx.cursor_dup_write::<tables::CodeChangeSets>()?;
+        let mut code_transitions = BTreeMap::<Address, Vec<BlockNumber>>::new();
+        for (block_index, mut block_changes) in code_changes.into_iter().enumerate() {
+            let block_number = first_block + block_index as BlockNumber;
+            // Sort accounts by address.
+            block_changes.sort_unstable_by_key(|(address, _)| *address);
+
+            for (address, code_hash) in block_changes {
+                changeset_cursor.append_dup(block_number, CodeBeforeTx { address, code_hash })?;
+                code_transitions.entry(address).or_default().push(block_number);
+            }
+        }
+
+        // Only the last shard of each account is rewritten.
+        self.append_history_index::<_, tables::CodeHistory>(code_transitions, ShardedKey::new)
+    }
+
+    /// Removes the code changes of all blocks in the range from the
+    /// [`CodeChangeSets`](tables::CodeChangeSets) and [`CodeHistory`](tables::CodeHistory) tables.
+    fn remove_code_changes(&self, range: RangeInclusive<BlockNumber>) -> ProviderResult<()> {
+        let first_block = *range.start();
+        let addresses = self
+            .take::<tables::CodeChangeSets>(range)?
+            .into_iter()
+            .map(|(_, change)| change.address)
+            .collect::<BTreeSet<_>>();
+
+        let mut cursor = self.tx.cursor_write::<tables::CodeHistory>()?;
+        for address in addresses {
+            let partial_shard = unwind_history_shards::<_, tables::CodeHistory, _>(
+                &mut cursor,
+                ShardedKey::last(address),
+                first_block,
+                |sharded_key| sharded_key.key == address,
+            )?;
+
+            // Check the last returned partial shard.
+            // If it's not empty, the shard needs to be reinserted.
+            if !partial_shard.is_empty() {
+                cursor.insert(
+                    ShardedKey::last(address),
+                    &BlockNumberList::new_pre_sorted(partial_shard),
+                )?;
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<TX: DbTx, N: NodeTypes> AccountReader for DatabaseProvider<TX, N> {
@@ -934,6 +999,35 @@ impl<TX: DbTx, N: NodeTypes> ChangeSetReader for DatabaseProvider<TX, N> {
     }
 }
 
+impl<TX: DbTx, N: NodeTypes> CodeHistoryReader for DatabaseProvider<TX, N> {
+    fn account_code_changes(
+        &self,
+        address: Address,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<B256>)>> {
+        let mut history_cursor = self.tx.cursor_read::<tables::CodeHistory>()?;
+        let mut blocks = Vec::new();
+        for entry in history_cursor.walk(Some(ShardedKey::new(address, 0)))? {
+            let (sharded_key, shard) = entry?;
+            if sharded_key.key != address {
+                break
+            }
+            blocks.extend(shard.iter());
+        }
+
+        let mut cursor = self.tx.cursor_dup_read::<tables::CodeChangeSets>()?;
+        blocks
+            .into_iter()
+            .map(|block_number| {
+                let change = cursor
+                    .seek_by_key_subkey(block_number, address)?
+                    .filter(|change| change.address == address)
+                    .ok_or(ProviderError::CodeChangesetNotFound { block_number, address })?;
+                Ok((block_number, change.code_hash))
+            })
+            .collect()
+    }
+}
+
 impl<TX: DbTx + 'static, N: NodeTypesForProvider> HeaderSyncGapProvider
     for DatabaseProvider<TX, N>
 {
@@ -1540,6 +1634,15 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> ReceiptProvider for DatabasePr
         block: BlockHashOrNumber,
     ) -> ProviderResult<Option<Vec<Self::Receipt>>> {
         if let Some(number) = self.convert_hash_or_number(block)? {
+            // Receipts in static files are read with the cached offsets of the block.
+            if let Some(receipts) =
+                self.static_file_provider.receipts_by_block_number(number, || {
+                    Ok(self.block_body_indices(number)?.map(|body| body.tx_num_range()))
+                })?
+            {
+                return Ok(Some(receipts))
+            }
+
             if let Some(body) = self.block_body_indices(number)? {
                 let tx_range = body.tx_num_range();
                 return if tx_range.is_empty() {
@@ -1795,6 +1898,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
             execution_outcome.bundle.to_plain_state_and_reverts(is_value_known);
 
         self.write_state_reverts(reverts, first_block)?;
+        self.write_code_changes(execution_outcome.code_changes(), first_block)?;
         self.write_state_changes(plain_state)?;
 
         // Fetch the first transaction number for each block in the range
@@ -2102,6 +2206,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
         let storage_range = BlockNumberAddress::range(range.clone());
 
         let storage_changeset = self.take::<tables::StorageChangeSets>(storage_range)?;
+        self.remove_code_changes(range.clone())?;
         let account_changeset = self.take::<tables::AccountChangeSets>(range)?;
 
         // This is not working for blocks that are not at tip. as plain state is not the last
@@ -2200,6 +2305,7 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateWriter
         let storage_range = BlockNumberAddress::range(range.clone());
 
         let storage_changeset = self.take::<tables::StorageChangeSets>(storage_range)?;
+        self.remove_code_changes(range.clone())?;
         let account_changeset = self.take::<tables::AccountChangeSets>(range)?;
 
         // This is not working for blocks that are not at tip. as plain state is not the last
@@ -3182,6 +3288,88 @@ impl<TX: DbTxMut, N: NodeTypes> ChainStateBlockWriter for DatabaseProvider<TX, N
     }
 }
 
+impl<TX: DbTx + 'static, N: NodeTypes> HeaderAccumulatorReader for DatabaseProvider<TX, N> {
+    fn header_accumulator_len(&self) -> ProviderResult<u64> {
+        let size = self
+            .tx
+            .cursor_read::<tables::HeaderAccumulator>()?
+            .last()?
+            .map_or(0, |(position, _)| position + 1);
+        mmr_leaves(size).ok_or(ProviderError::InvalidStorageOutput)
+    }
+
+    fn accumulated_block_hash(
+        &self,
+        block_number: BlockNumber,
+    ) -> ProviderResult<Option<BlockHash>> {
+        if block_number >= self.header_accumulator_len()? {
+            return Ok(None)
+        }
+        Ok(self.tx.get::<tables::HeaderAccumulator>(mmr_size(block_number))?)
+    }
+
+    fn header_proof(&self, block_number: BlockNumber) -> ProviderResult<Option<HeaderProof>> {
+        let leaves = self.header_accumulator_len()?;
+        let Some(sibling_positions) = mmr_proof_positions(leaves, block_number) else {
+            return Ok(None)
+        };
+
+        let node = |position| {
+            self.tx
+                .get::<tables::HeaderAccumulator>(position)?
+                .ok_or(ProviderError::InvalidStorageOutput)
+        };
+        let block_hash = node(mmr_size(block_number))?;
+        let siblings =
+            sibling_positions.into_iter().map(node).collect::<ProviderResult<Vec<_>>>()?;
+        let peaks = mmr_peaks(leaves).into_iter().map(node).collect::<ProviderResult<Vec<_>>>()?;
+
+        Ok(Some(HeaderProof {
+            block_number,
+            block_hash,
+            leaves,
+            root: mmr_root(leaves, &peaks),
+            siblings,
+            peaks,
+        }))
+    }
+}
+
+impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes + 'static> HeaderAccumulatorWriter
+    for DatabaseProvider<TX, N>
+{
+    fn append_header_accumulator(
+        &self,
+        hashes: impl IntoIterator<Item = BlockHash>,
+    ) -> ProviderResult<()> {
+        let mut leaves = self.header_accumulator_len()?;
+        let mut cursor = self.tx.cursor_write::<tables::HeaderAccumulator>()?;
+        for hash in hashes {
+            let (mut position, siblings) = mmr_append_siblings(leaves);
+            cursor.append(position, &hash)?;
+
+            // merge the new leaf with the peaks of the same height
+            let mut node = hash;
+            for sibling in siblings {
+                let left = self
+                    .tx
+                    .get::<tables::HeaderAccumulator>(sibling)?
+                    .ok_or(ProviderError::InvalidStorageOutput)?;
+                node = mmr_parent(&left, &node);
+                position += 1;
+                cursor.append(position, &node)?;
+            }
+            leaves += 1;
+        }
+        Ok(())
+    }
+
+    fn truncate_header_accumulator(&self, block_number: BlockNumber) -> ProviderResult<()> {
+        self.remove::<tables::HeaderAccumulator>(mmr_size(block_number)..)?;
+        Ok(())
+    }
+}
+
 impl<TX: DbTx + 'static, N: NodeTypes + 'static> DBProvider for DatabaseProvider<TX, N> {
     type Tx = TX;
 
@@ -9,8 +9,10 @@ pub use database::*;
 
 mod static_file;
 pub use static_file::{
-    StaticFileAccess, StaticFileJarProvider, StaticFileProvider, StaticFileProviderRW,
-    StaticFileProviderRWRefMut, StaticFileWriter,
+    StaticFileAccess, StaticFileChecksums, StaticFileJarProvider, StaticFileProvider,
+    StaticFileProviderRW, StaticFileProviderRWRefMut, StaticFileSegmentStats,
+    StaticFileVerification, StaticFileVerificationOutcome, StaticFileWriter,
+    STATIC_FILE_CHECKSUMS_FILE_NAME,
 };
 
 mod state;
@@ -2,8 +2,10 @@ use crate::{
     providers::state::macros::delegate_provider_impls, AccountReader, BlockHashReader,
     HashedPostStateProvider, ProviderError, StateProvider, StateRootProvider,
 };
+use alloy_consensus::constants::KECCAK_EMPTY;
 use alloy_eips::merge::EPOCH_SLOTS;
 use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, B256};
+use parking_lot::{Mutex, MutexGuard};
 use reth_db_api::{
     cursor::{DbCursorRO, DbDupCursorRO},
     models::{storage_sharded_key::StorageShardedKey, ShardedKey},
@@ -28,6 +30,7 @@ use reth_trie_db::{
     DatabaseHashedPostState, DatabaseHashedStorage, DatabaseProof, DatabaseStateRoot,
     DatabaseStorageProof, DatabaseStorageRoot, DatabaseTrieWitness, StateCommitment,
 };
+use schnellru::{ByLength, LruMap};
 use std::fmt::Debug;
 
 /// State provider for a given block number which takes a tx reference.
@@ -41,6 +44,8 @@ use std::fmt::Debug;
 /// - [`tables::StoragesHistory`]
 /// - [`tables::AccountChangeSets`]
 /// - [`tables::StorageChangeSets`]
+/// - [`tables::CodeHistory`]
+/// - [`tables::CodeChangeSets`]
 #[derive(Debug)]
 pub struct HistoricalStateProviderRef<'b, Provider> {
     /// Database provider
@@ -49,9 +54,11 @@ pub struct HistoricalStateProviderRef<'b, Provider> {
     block_number: BlockNumber,
     /// Lowest blocks at which different parts of the state are available.
     lowest_available_blocks: LowestAvailableBlocks,
+    /// Cache of history index lookups, shared by all references of a [`HistoricalStateProvider`].
+    history_cache: Option<&'b HistoryLookupCache>,
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum HistoryInfo {
     NotYetWritten,
     InChangeset(u64),
@@ -64,7 +71,12 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
 {
     /// Create new `StateProvider` for historical block number
     pub fn new(provider: &'b Provider, block_number: BlockNumber) -> Self {
-        Self { provider, block_number, lowest_available_blocks: Default::default() }
+        Self {
+            provider,
+            block_number,
+            lowest_available_blocks: Default::default(),
+            history_cache: None,
+        }
     }
 
     /// Create new `StateProvider` for historical block number and lowest block numbers at which
@@ -74,7 +86,16 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
         block_number: BlockNumber,
         lowest_available_blocks: LowestAvailableBlocks,
     ) -> Self {
-        Self { provider, block_number, lowest_available_blocks }
+        Self { provider, block_number, lowest_available_blocks, history_cache: None }
+    }
+
+    /// Caches the results of history index lookups in the given cache.
+    ///
+    /// The cache must only be shared by providers for the same block number and lowest available
+    /// blocks.
+    const fn with_history_cache(mut self, history_cache: &'b HistoryLookupCache) -> Self {
+        self.history_cache = Some(history_cache);
+        self
     }
 
     /// Lookup an account in the `AccountsHistory` table
@@ -83,13 +104,64 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
             return Err(ProviderError::StateAtBlockPruned(self.block_number))
         }
 
+        if let Some(info) = self.history_cache.and_then(|cache| {
+            cache.lookups(self.lowest_available_blocks).accounts.get(&address).copied()
+        }) {
+            return Ok(info)
+        }
+
         // history key to search IntegerList of block number changesets.
         let history_key = ShardedKey::new(address, self.block_number);
-        self.history_info::<tables::AccountsHistory, _>(
+        let info = self.history_info::<tables::AccountsHistory, _>(
             history_key,
             |key| key.key == address,
             self.lowest_available_blocks.account_history_block_number,
-        )
+        )?;
+
+        if let Some(cache) = self.history_cache {
+            cache.lookups(self.lowest_available_blocks).accounts.insert(address, info);
+        }
+        Ok(info)
+    }
+
+    /// Lookup the code hash of an account in the `CodeHistory` table.
+    ///
+    /// Returns `None` if the code changesets don't cover the block, i.e. if the first code change
+    /// was written to them after the block. Code changesets are pruned together with the account
+    /// history, in which case the account history lookup reports the pruned block.
+    pub fn code_history_lookup(&self, address: Address) -> ProviderResult<Option<Option<B256>>> {
+        // Every code change after the first recorded one is recorded as well.
+        let Some((first_block, _)) = self.tx().cursor_read::<tables::CodeChangeSets>()?.first()?
+        else {
+            return Ok(None)
+        };
+        if self.block_number < first_block {
+            return Ok(None)
+        }
+
+        // The shard with the next change is the first one whose highest block number is not
+        // lower than the block.
+        let next_change = self
+            .tx()
+            .cursor_read::<tables::CodeHistory>()?
+            .seek(ShardedKey::new(address, self.block_number))?
+            .filter(|(sharded_key, _)| sharded_key.key == address)
+            .and_then(|(_, blocks)| blocks.iter().find(|block| *block >= self.block_number));
+        let code_hash = match next_change {
+            Some(block_number) => {
+                self.tx()
+                    .cursor_dup_read::<tables::CodeChangeSets>()?
+                    .seek_by_key_subkey(block_number, address)?
+                    .filter(|change| change.address == address)
+                    .ok_or(ProviderError::CodeChangesetNotFound { block_number, address })?
+                    .code_hash
+            }
+            None => self
+                .tx()
+                .get_by_encoded_key::<tables::PlainAccountState>(&address)?
+                .and_then(|account| account.bytecode_hash),
+        };
+        Ok(Some(code_hash))
     }
 
     /// Lookup a storage key in the `StoragesHistory` table
@@ -102,13 +174,31 @@ impl<'b, Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
             return Err(ProviderError::StateAtBlockPruned(self.block_number))
         }
 
+        if let Some(info) = self.history_cache.and_then(|cache| {
+            cache
+                .lookups(self.lowest_available_blocks)
+                .storages
+                .get(&(address, storage_key))
+                .copied()
+        }) {
+            return Ok(info)
+        }
+
         // history key to search IntegerList of block number changesets.
         let history_key = StorageShardedKey::new(address, storage_key, self.block_number);
-        self.history_info::<tables::StoragesHistory, _>(
+        let info = self.history_info::<tables::StoragesHistory, _>(
             history_key,
             |key| key.address == address && key.sharded_key.key == storage_key,
             self.lowest_available_blocks.storage_history_block_number,
-        )
+        )?;
+
+        if let Some(cache) = self.history_cache {
+            cache
+                .lookups(self.lowest_available_blocks)
+                .storages
+                .insert((address, storage_key), info);
+        }
+        Ok(info)
     }
 
     /// Checks and returns `true` if distance to historical block exceeds the provided limit.
@@ -438,6 +528,21 @@ impl<Provider: DBProvider + BlockNumReader + BlockHashReader + StateCommitmentPr
     fn bytecode_by_hash(&self, code_hash: &B256) -> ProviderResult<Option<Bytecode>> {
         self.tx().get_by_encoded_key::<tables::Bytecodes>(code_hash).map_err(Into::into)
     }
+
+    /// Get account code by its address.
+    ///
+    /// The code hash is taken from the code changesets if they cover the block, and from the
+    /// account history otherwise.
+    fn account_code(&self, address: &Address) -> ProviderResult<Option<Bytecode>> {
+        let code_hash = match self.code_history_lookup(*address)? {
+            Some(code_hash) => code_hash,
+            None => self.basic_account(address)?.and_then(|account| account.bytecode_hash),
+        };
+        match code_hash {
+            Some(code_hash) if code_hash != KECCAK_EMPTY => self.bytecode_by_hash(&code_hash),
+            _ => Ok(None),
+        }
+    }
 }
 
 impl<Provider: StateCommitmentProvider> StateCommitmentProvider
@@ -456,6 +561,11 @@ pub struct HistoricalStateProvider<Provider> {
     block_number: BlockNumber,
     /// Lowest blocks at which different parts of the state are available.
     lowest_available_blocks: LowestAvailableBlocks,
+    /// Cache of history index lookups.
+    ///
+    /// Every state access goes through a new [`HistoricalStateProviderRef`], so the cache lives
+    /// here to be reused across accesses, e.g. during `eth_call` on an archive node.
+    history_cache: HistoryLookupCache,
 }
 
 impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
@@ -463,7 +573,12 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
 {
     /// Create new `StateProvider` for historical block number
     pub fn new(provider: Provider, block_number: BlockNumber) -> Self {
-        Self { provider, block_number, lowest_available_blocks: Default::default() }
+        Self {
+            provider,
+            block_number,
+            lowest_available_blocks: Default::default(),
+            history_cache: Default::default(),
+        }
     }
 
     /// Set the lowest block number at which the account history is available.
@@ -492,6 +607,7 @@ impl<Provider: DBProvider + BlockNumReader + StateCommitmentProvider>
             self.block_number,
             self.lowest_available_blocks,
         )
+        .with_history_cache(&self.history_cache)
     }
 }
 
@@ -504,9 +620,64 @@ impl<Provider: StateCommitmentProvider> StateCommitmentProvider
 // Delegates all provider impls to [HistoricalStateProviderRef]
 delegate_provider_impls!(HistoricalStateProvider<Provider> where [Provider: DBProvider + BlockNumReader + BlockHashReader + StateCommitmentProvider]);
 
+/// Maximum number of accounts and of storage slots whose history index lookups are cached by a
+/// historical state provider.
+const HISTORY_LOOKUP_CACHE_SIZE: u32 = 10_000;
+
+/// Results of history index lookups of a historical state provider.
+///
+/// Resolving the changeset that holds the state of a key at a block requires a seek into the
+/// history index and decoding of the index shard. The result only depends on the key, the block
+/// number of the provider and whether history is pruned, so the most recently used ones are cached
+/// per account and storage slot to skip the index walk on repeated lookups.
+#[derive(Debug)]
+struct HistoryLookupCache {
+    lookups: Mutex<HistoryLookups>,
+}
+
+impl HistoryLookupCache {
+    /// Returns the cached lookups, which are removed first if they were resolved with different
+    /// lowest available blocks.
+    fn lookups(
+        &self,
+        lowest_available_blocks: LowestAvailableBlocks,
+    ) -> MutexGuard<'_, HistoryLookups> {
+        let mut lookups = self.lookups.lock();
+        if lookups.lowest_available_blocks != lowest_available_blocks {
+            lookups.lowest_available_blocks = lowest_available_blocks;
+            lookups.accounts.clear();
+            lookups.storages.clear();
+        }
+        lookups
+    }
+}
+
+impl Default for HistoryLookupCache {
+    fn default() -> Self {
+        Self {
+            lookups: Mutex::new(HistoryLookups {
+                lowest_available_blocks: Default::default(),
+                accounts: LruMap::new(ByLength::new(HISTORY_LOOKUP_CACHE_SIZE)),
+                storages: LruMap::new(ByLength::new(HISTORY_LOOKUP_CACHE_SIZE)),
+            }),
+        }
+    }
+}
+
+/// History index lookups of a [`HistoryLookupCache`].
+#[derive(Debug)]
+struct HistoryLookups {
+    /// The lowest available blocks the lookups were resolved with.
+    lowest_available_blocks: LowestAvailableBlocks,
+    /// History of accounts by address.
+    accounts: LruMap<Address, HistoryInfo>,
+    /// History of storage slots by address and storage key.
+    storages: LruMap<(Address, StorageKey), HistoryInfo>,
+}
+
 /// Lowest blocks at which different parts of the state are available.
 /// They may be [Some] if pruning is enabled.
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct LowestAvailableBlocks {
     /// Lowest block number at which the account history is available. It may not be available if
     /// [`reth_prune_types::PruneSegment::AccountHistory`] was pruned.
@@ -541,12 +712,14 @@ mod tests {
     };
     use alloy_primitives::{address, b256, Address, B256, U256};
     use reth_db_api::{
-        models::{storage_sharded_key::StorageShardedKey, AccountBeforeTx, ShardedKey},
+        models::{
+            storage_sharded_key::StorageShardedKey, AccountBeforeTx, CodeBeforeTx, ShardedKey,
+        },
         tables,
         transaction::{DbTx, DbTxMut},
         BlockNumberList,
     };
-    use reth_primitives::{Account, StorageEntry};
+    use reth_primitives::{Account, Bytecode, StorageEntry};
     use reth_storage_api::{
         BlockHashReader, BlockNumReader, DBProvider, DatabaseProviderFactory,
         StateCommitmentProvider,
@@ -836,4 +1009,118 @@ mod tests {
             Ok(HistoryInfo::MaybeInPlainState)
         ));
     }
+
+    #[test]
+    fn history_provider_caches_lookups() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let acc_at7 = Account { nonce: 7, balance: U256::ZERO, bytecode_hash: None };
+        let entry_at7 = StorageEntry { key: STORAGE, value: U256::from(7) };
+        tx.put::<tables::AccountsHistory>(
+            ShardedKey { key: ADDRESS, highest_block_number: u64::MAX },
+            BlockNumberList::new([3, 7]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::AccountChangeSets>(
+            7,
+            AccountBeforeTx { address: ADDRESS, info: Some(acc_at7) },
+        )
+        .unwrap();
+        tx.put::<tables::StoragesHistory>(
+            StorageShardedKey {
+                address: ADDRESS,
+                sharded_key: ShardedKey { key: STORAGE, highest_block_number: u64::MAX },
+            },
+            BlockNumberList::new([3, 7]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::StorageChangeSets>((7, ADDRESS).into(), entry_at7).unwrap();
+        tx.commit().unwrap();
+
+        let provider = HistoricalStateProvider::new(factory.provider().unwrap(), 5);
+        for _ in 0..2 {
+            assert_eq!(provider.basic_account(&ADDRESS).unwrap(), Some(acc_at7));
+            assert_eq!(provider.storage(ADDRESS, STORAGE).unwrap(), Some(entry_at7.value));
+            assert_eq!(provider.basic_account(&HIGHER_ADDRESS).unwrap(), None);
+        }
+
+        {
+            let mut lookups = provider.history_cache.lookups(provider.lowest_available_blocks);
+            assert_eq!(lookups.accounts.get(&ADDRESS), Some(&mut HistoryInfo::InChangeset(7)));
+            assert_eq!(
+                lookups.accounts.get(&HIGHER_ADDRESS),
+                Some(&mut HistoryInfo::NotYetWritten)
+            );
+            assert_eq!(
+                lookups.storages.get(&(ADDRESS, STORAGE)),
+                Some(&mut HistoryInfo::InChangeset(7))
+            );
+        }
+
+        // lookups resolved before the history was pruned are dropped
+        let provider = provider.with_lowest_available_account_history_block_number(4);
+        let lookups = provider.history_cache.lookups(provider.lowest_available_blocks);
+        assert!(lookups.accounts.is_empty());
+        assert!(lookups.storages.is_empty());
+    }
+
+    #[test]
+    fn history_provider_get_code() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let code = Bytecode::new_raw(alloy_primitives::bytes!("0x6001"));
+        let code_hash = code.hash_slow();
+        let redeployed_code = Bytecode::new_raw(alloy_primitives::bytes!("0x6002"));
+        let redeployed_code_hash = redeployed_code.hash_slow();
+        tx.put::<tables::Bytecodes>(code_hash, code.clone()).unwrap();
+        tx.put::<tables::Bytecodes>(redeployed_code_hash, redeployed_code.clone()).unwrap();
+
+        // deployed at block 3, selfdestructed at block 7, redeployed at block 10
+        tx.put::<tables::CodeHistory>(
+            ShardedKey { key: ADDRESS, highest_block_number: 7 },
+            BlockNumberList::new([3, 7]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::CodeHistory>(
+            ShardedKey { key: ADDRESS, highest_block_number: u64::MAX },
+            BlockNumberList::new([10]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::CodeChangeSets>(3, CodeBeforeTx { address: ADDRESS, code_hash: None })
+            .unwrap();
+        tx.put::<tables::CodeChangeSets>(
+            7,
+            CodeBeforeTx { address: ADDRESS, code_hash: Some(code_hash) },
+        )
+        .unwrap();
+        tx.put::<tables::CodeChangeSets>(10, CodeBeforeTx { address: ADDRESS, code_hash: None })
+            .unwrap();
+        tx.put::<tables::PlainAccountState>(
+            ADDRESS,
+            Account { nonce: 1, balance: U256::ZERO, bytecode_hash: Some(redeployed_code_hash) },
+        )
+        .unwrap();
+        tx.commit().unwrap();
+
+        let db = factory.provider().unwrap();
+        let code_at = |block_number| {
+            HistoricalStateProviderRef::new(&db, block_number).account_code(&ADDRESS).unwrap()
+        };
+        assert_eq!(code_at(1), None);
+        assert_eq!(code_at(3), None);
+        assert_eq!(code_at(4), Some(code.clone()));
+        assert_eq!(code_at(7), Some(code));
+        assert_eq!(code_at(8), None);
+        assert_eq!(code_at(10), None);
+        assert_eq!(code_at(11), Some(redeployed_code.clone()));
+        assert_eq!(code_at(100), Some(redeployed_code));
+
+        // blocks before the first code change are looked up in the account history
+        assert_eq!(
+            HistoricalStateProviderRef::new(&db, 2).code_history_lookup(ADDRESS).unwrap(),
+            None
+        );
+    }
 }
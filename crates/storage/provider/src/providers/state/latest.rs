@@ -3,7 +3,11 @@ use crate::{
     HashedPostStateProvider, StateProvider, StateRootProvider,
 };
 use alloy_primitives::{Address, BlockNumber, Bytes, StorageKey, StorageValue, B256};
-use reth_db_api::{cursor::DbDupCursorRO, tables, transaction::DbTx};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbDupCursorRO},
+    tables,
+    transaction::DbTx,
+};
 use reth_primitives::{Account, Bytecode};
 use reth_storage_api::{
     DBProvider, StateCommitmentProvider, StateProofProvider, StorageRootProvider,
@@ -178,6 +182,33 @@ impl<Provider: DBProvider + BlockHashReader + StateCommitmentProvider> StateProv
         Ok(None)
     }
 
+    /// Get multiple storage slots using a single cursor.
+    fn storage_multi(
+        &self,
+        account: Address,
+        storage_keys: &[StorageKey],
+    ) -> ProviderResult<Vec<Option<StorageValue>>> {
+        let mut cursor = self.tx().cursor_dup_read::<tables::PlainStorageState>()?;
+        storage_keys
+            .iter()
+            .map(|storage_key| {
+                Ok(cursor
+                    .seek_by_key_subkey(account, *storage_key)?
+                    .filter(|entry| entry.key == *storage_key)
+                    .map(|entry| entry.value))
+            })
+            .collect()
+    }
+
+    /// Get multiple accounts using a single cursor.
+    fn basic_accounts(&self, addresses: &[Address]) -> ProviderResult<Vec<Option<Account>>> {
+        let mut cursor = self.tx().cursor_read::<tables::PlainAccountState>()?;
+        addresses
+            .iter()
+            .map(|address| Ok(cursor.seek_exact(*address)?.map(|(_, account)| account)))
+            .collect()
+    }
+
     /// Get account code by its hash
     fn bytecode_by_hash(&self, code_hash: &B256) -> ProviderResult<Option<Bytecode>> {
         self.tx().get_by_encoded_key::<tables::Bytecodes>(code_hash).map_err(Into::into)
@@ -217,6 +248,10 @@ delegate_provider_impls!(LatestStateProvider<Provider> where [Provider: DBProvid
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::create_test_provider_factory;
+    use alloy_primitives::{address, U256};
+    use reth_db_api::transaction::DbTxMut;
+    use reth_primitives::StorageEntry;
 
     const fn assert_state_provider<T: StateProvider>() {}
     #[allow(dead_code)]
@@ -225,4 +260,37 @@ mod tests {
     >() {
         assert_state_provider::<LatestStateProvider<T>>();
     }
+
+    #[test]
+    fn latest_provider_batched_reads() {
+        let address = address!("0x0000000000000000000000000000000000000001");
+        let other = address!("0x0000000000000000000000000000000000000005");
+        let account = Account { nonce: 1, balance: U256::from(2), bytecode_hash: None };
+
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+        tx.put::<tables::PlainAccountState>(address, account).unwrap();
+        for key in [B256::with_last_byte(1), B256::with_last_byte(3)] {
+            let entry = StorageEntry { key, value: U256::from(key[31]) };
+            tx.put::<tables::PlainStorageState>(address, entry).unwrap();
+        }
+        let entry = StorageEntry { key: B256::with_last_byte(2), value: U256::from(10) };
+        tx.put::<tables::PlainStorageState>(other, entry).unwrap();
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+
+        let keys = [3, 2, 1, 4].map(B256::with_last_byte);
+        let values = state.storage_multi(address, &keys).unwrap();
+        assert_eq!(values, vec![Some(U256::from(3)), None, Some(U256::from(1)), None]);
+        for (key, value) in keys.iter().zip(values) {
+            assert_eq!(state.storage(address, *key).unwrap(), value);
+        }
+
+        assert_eq!(
+            state.basic_accounts(&[other, address, other]).unwrap(),
+            vec![None, Some(account), None]
+        );
+    }
 }
@@ -39,7 +39,10 @@ macro_rules! delegate_provider_impls {
             }
             StateProvider $(where [$($generics)*])? {
                 fn storage(&self, account: alloy_primitives::Address, storage_key: alloy_primitives::StorageKey) -> reth_storage_errors::provider::ProviderResult<Option<alloy_primitives::StorageValue>>;
+                fn storage_multi(&self, account: alloy_primitives::Address, storage_keys: &[alloy_primitives::StorageKey]) -> reth_storage_errors::provider::ProviderResult<Vec<Option<alloy_primitives::StorageValue>>>;
+                fn basic_accounts(&self, addresses: &[alloy_primitives::Address]) -> reth_storage_errors::provider::ProviderResult<Vec<Option<reth_primitives::Account>>>;
                 fn bytecode_by_hash(&self, code_hash: &alloy_primitives::B256) -> reth_storage_errors::provider::ProviderResult<Option<reth_primitives::Bytecode>>;
+                fn account_code(&self, addr: &alloy_primitives::Address) -> reth_storage_errors::provider::ProviderResult<Option<reth_primitives::Bytecode>>;
             }
             StateRootProvider $(where [$($generics)*])? {
                 fn state_root(&self, state: reth_trie::HashedPostState) -> reth_storage_errors::provider::ProviderResult<alloy_primitives::B256>;
@@ -0,0 +1,167 @@
+use alloy_primitives::B256;
+use reth_primitives::{static_file::SegmentRangeInclusive, StaticFileSegment};
+use reth_storage_errors::provider::{ProviderError, ProviderResult};
+use std::{collections::BTreeMap, io, io::Write, path::Path};
+
+/// Name of the file in the static files directory that holds the checksums of sealed static files.
+pub const STATIC_FILE_CHECKSUMS_FILE_NAME: &str = "checksums";
+
+/// Checksums of sealed static files, keyed by the name of their data file.
+///
+/// A static file is only written to again if the chain is unwound past its block range, so its
+/// checksum is recorded when it's sealed, and removed when it's opened for writing again.
+///
+/// The checksums are stored as plain text, with one `<checksum> <file name>` line per static file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StaticFileChecksums {
+    checksums: BTreeMap<String, B256>,
+}
+
+impl StaticFileChecksums {
+    /// Loads the checksums from the static files `directory`.
+    ///
+    /// Returns empty checksums if none were recorded yet.
+    pub fn load(directory: &Path) -> ProviderResult<Self> {
+        let path = directory.join(STATIC_FILE_CHECKSUMS_FILE_NAME);
+        if !path.exists() {
+            return Ok(Self::default())
+        }
+
+        let content = reth_fs_util::read_to_string(&path).map_err(ProviderError::other)?;
+        let mut checksums = BTreeMap::new();
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            let entry = line
+                .split_once(' ')
+                .and_then(|(checksum, file_name)| Some((checksum.parse().ok()?, file_name)));
+            let Some((checksum, file_name)) = entry else {
+                return Err(ProviderError::other(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("malformed static file checksum in {}: {line}", path.display()),
+                )))
+            };
+            checksums.insert(file_name.to_string(), checksum);
+        }
+
+        Ok(Self { checksums })
+    }
+
+    /// Atomically writes the checksums to the static files `directory`.
+    pub fn save(&self, directory: &Path) -> ProviderResult<()> {
+        reth_fs_util::atomic_write_file(
+            &directory.join(STATIC_FILE_CHECKSUMS_FILE_NAME),
+            |file| -> io::Result<()> {
+                let mut writer = io::BufWriter::new(file);
+                for (file_name, checksum) in &self.checksums {
+                    writeln!(writer, "{checksum} {file_name}")?;
+                }
+                writer.flush()
+            },
+        )
+        .map_err(ProviderError::other)
+    }
+
+    /// Returns the checksum of the static file, if any.
+    pub fn get(&self, file_name: &str) -> Option<B256> {
+        self.checksums.get(file_name).copied()
+    }
+
+    /// Records the checksum of the static file, returning the previous one, if any.
+    pub fn insert(&mut self, file_name: String, checksum: B256) -> Option<B256> {
+        self.checksums.insert(file_name, checksum)
+    }
+
+    /// Removes the checksum of the static file, returning it, if any.
+    pub fn remove(&mut self, file_name: &str) -> Option<B256> {
+        self.checksums.remove(file_name)
+    }
+
+    /// Returns the number of recorded checksums.
+    pub fn len(&self) -> usize {
+        self.checksums.len()
+    }
+
+    /// Returns `true` if no checksums are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.checksums.is_empty()
+    }
+
+    /// Returns an iterator over the file names and checksums, ordered by file name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, B256)> + '_ {
+        self.checksums.iter().map(|(file_name, checksum)| (file_name.as_str(), *checksum))
+    }
+}
+
+/// Result of verifying a static file against its recorded checksum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StaticFileVerificationOutcome {
+    /// The static file matches its checksum.
+    Valid,
+    /// No checksum was recorded for the sealed static file, e.g. because it was downloaded with a
+    /// snapshot or sealed before checksums were recorded.
+    Unverified,
+    /// The static file doesn't match its checksum.
+    Corrupt {
+        /// The recorded checksum.
+        expected: B256,
+        /// The checksum of the static file on disk.
+        actual: B256,
+    },
+    /// A checksum was recorded, but the static file doesn't exist.
+    Missing,
+    /// A checksum was recorded, but the static file couldn't be read.
+    Unreadable(String),
+}
+
+/// Verification result of a single static file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticFileVerification {
+    /// The segment of the static file.
+    pub segment: StaticFileSegment,
+    /// The fixed block range of the static file.
+    pub block_range: SegmentRangeInclusive,
+    /// The name of the data file.
+    pub file_name: String,
+    /// The outcome of the verification.
+    pub outcome: StaticFileVerificationOutcome,
+}
+
+impl StaticFileVerification {
+    /// Returns `true` if the static file is not known to be damaged.
+    pub const fn is_ok(&self) -> bool {
+        matches!(
+            self.outcome,
+            StaticFileVerificationOutcome::Valid | StaticFileVerificationOutcome::Unverified
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn save_and_load_checksums() {
+        let directory = tempfile::tempdir().unwrap();
+        assert!(StaticFileChecksums::load(directory.path()).unwrap().is_empty());
+
+        let mut checksums = StaticFileChecksums::default();
+        checksums.insert("static_file_headers_0_499999".to_string(), B256::with_last_byte(1));
+        checksums.insert("static_file_receipts_0_499999".to_string(), B256::with_last_byte(2));
+        checksums.save(directory.path()).unwrap();
+        assert_eq!(StaticFileChecksums::load(directory.path()).unwrap(), checksums);
+
+        assert_eq!(checksums.remove("static_file_headers_0_499999"), Some(B256::with_last_byte(1)));
+        checksums.save(directory.path()).unwrap();
+        let loaded = StaticFileChecksums::load(directory.path()).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded.get("static_file_receipts_0_499999"), Some(B256::with_last_byte(2)));
+    }
+
+    #[test]
+    fn load_malformed_checksums() {
+        let directory = tempfile::tempdir().unwrap();
+        std::fs::write(directory.path().join(STATIC_FILE_CHECKSUMS_FILE_NAME), "0x01 file\n")
+            .unwrap();
+        assert!(StaticFileChecksums::load(directory.path()).is_err());
+    }
+}
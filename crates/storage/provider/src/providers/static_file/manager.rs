@@ -1,6 +1,7 @@
 use super::{
-    metrics::StaticFileProviderMetrics, writer::StaticFileWriters, LoadedJar,
+    metrics::StaticFileProviderMetrics, writer::StaticFileWriters, LoadedJar, StaticFileChecksums,
     StaticFileJarProvider, StaticFileProviderRW, StaticFileProviderRWRefMut,
+    StaticFileVerification, StaticFileVerificationOutcome,
 };
 use crate::{
     to_range, BlockHashReader, BlockNumReader, BlockReader, BlockSource, HeaderProvider,
@@ -14,7 +15,7 @@ use alloy_primitives::{
 };
 use dashmap::DashMap;
 use notify::{RecommendedWatcher, RecursiveMode, Watcher};
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use reth_chainspec::{ChainInfo, ChainSpecProvider, EthChainSpec};
 use reth_db::{
     lockfile::StorageLock,
@@ -43,13 +44,18 @@ use reth_primitives_traits::SignedTransaction;
 use reth_stages_types::{PipelineTarget, StageId};
 use reth_storage_api::{BlockBodyIndicesProvider, DBProvider, OmmersProvider};
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
+use reth_tasks::TaskSpawner;
+use schnellru::{ByLength, LruMap};
 use std::{
     collections::{hash_map::Entry, BTreeMap, HashMap},
     fmt::Debug,
     marker::PhantomData,
     ops::{Deref, Range, RangeBounds, RangeInclusive},
     path::{Path, PathBuf},
-    sync::{mpsc, Arc},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc, Arc, OnceLock,
+    },
 };
 use tracing::{info, trace, warn};
 
@@ -58,6 +64,9 @@ use tracing::{info, trace, warn};
 /// range.
 type SegmentRanges = HashMap<StaticFileSegment, BTreeMap<TxNumber, SegmentRangeInclusive>>;
 
+/// Default number of blocks whose receipt offsets are cached.
+const DEFAULT_RECEIPT_OFFSETS_CACHE_SIZE: u32 = 10_000;
+
 /// Access mode on a static file provider. RO/RW.
 #[derive(Debug, Default, PartialEq, Eq)]
 pub enum StaticFileAccess {
@@ -80,6 +89,19 @@ impl StaticFileAccess {
     }
 }
 
+/// Size on disk and number of rows of a static file segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaticFileSegmentStats {
+    /// The segment.
+    pub segment: StaticFileSegment,
+    /// The size of the data, index, offsets and config files of all segment files, in bytes.
+    pub size: u64,
+    /// The number of segment files.
+    pub files: usize,
+    /// The number of rows of all segment files.
+    pub entries: usize,
+}
+
 /// [`StaticFileProvider`] manages all existing [`StaticFileJarProvider`].
 ///
 /// "Static files" contain immutable chain history data, such as:
@@ -233,6 +255,9 @@ pub struct StaticFileProviderInner<N> {
     static_files_max_block: RwLock<HashMap<StaticFileSegment, u64>>,
     /// Available static file block ranges on disk indexed by max transactions.
     static_files_tx_index: RwLock<SegmentRanges>,
+    /// LRU cache of the transaction number ranges of the receipts of a block in the receipts
+    /// static files, keyed by block number.
+    receipt_offsets: Mutex<LruMap<BlockNumber, Range<TxNumber>>>,
     /// Directory where `static_files` are located
     path: PathBuf,
     /// Maintains a writer set of [`StaticFileSegment`].
@@ -243,6 +268,16 @@ pub struct StaticFileProviderInner<N> {
     access: StaticFileAccess,
     /// Number of blocks per file.
     blocks_per_file: u64,
+    /// Static files whose checksum is being recorded in the background, with the id of the
+    /// recording.
+    ///
+    /// Also serializes updates of the [`StaticFileChecksums`] on disk.
+    pending_checksums: Mutex<HashMap<String, u64>>,
+    /// Id of the next checksum recording.
+    next_checksum_id: AtomicU64,
+    /// Spawner of the blocking tasks that record checksums, they're recorded on the calling
+    /// thread if not set.
+    task_spawner: OnceLock<Box<dyn TaskSpawner>>,
     /// Write lock for when access is [`StaticFileAccess::RW`].
     _lock_file: Option<StorageLock>,
     /// Node primitives
@@ -263,10 +298,16 @@ impl<N: NodePrimitives> StaticFileProviderInner<N> {
             writers: Default::default(),
             static_files_max_block: Default::default(),
             static_files_tx_index: Default::default(),
+            receipt_offsets: Mutex::new(LruMap::new(ByLength::new(
+                DEFAULT_RECEIPT_OFFSETS_CACHE_SIZE,
+            ))),
             path: path.as_ref().to_path_buf(),
             metrics: None,
             access,
             blocks_per_file: DEFAULT_BLOCKS_PER_STATIC_FILE,
+            pending_checksums: Default::default(),
+            next_checksum_id: Default::default(),
+            task_spawner: Default::default(),
             _lock_file,
             _pd: Default::default(),
         };
@@ -303,11 +344,28 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         Self(Arc::new(provider))
     }
 
+    /// Sets the spawner of the blocking tasks that record the checksums of sealed static files.
+    pub fn with_task_spawner(self, task_spawner: Box<dyn TaskSpawner>) -> Self {
+        let _ = self.task_spawner.set(task_spawner);
+        self
+    }
+
     /// Reports metrics for the static files.
     pub fn report_metrics(&self) -> ProviderResult<()> {
         let Some(metrics) = &self.metrics else { return Ok(()) };
 
+        for segment in self.segment_sizes()? {
+            metrics.record_segment(segment.segment, segment.size, segment.files, segment.entries);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the size on disk, the number of files and the number of rows of each static file
+    /// segment.
+    pub fn segment_sizes(&self) -> ProviderResult<Vec<StaticFileSegmentStats>> {
         let static_files = iter_static_files(&self.path).map_err(ProviderError::other)?;
+        let mut sizes = Vec::with_capacity(static_files.len());
         for (segment, ranges) in static_files {
             let mut entries = 0;
             let mut size = 0;
@@ -338,10 +396,10 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
                 size += data_size + index_size + offsets_size + config_size;
             }
 
-            metrics.record_segment(segment, size, ranges.len(), entries);
+            sizes.push(StaticFileSegmentStats { segment, size, files: ranges.len(), entries });
         }
 
-        Ok(())
+        Ok(sizes)
     }
 
     /// Gets the [`StaticFileJarProvider`] of the requested segment and block.
@@ -436,6 +494,7 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         };
 
         jar.delete().map_err(ProviderError::other)?;
+        self.remove_checksum(segment, block)?;
 
         let mut segment_max_block = None;
         if fixed_block_range.start() > 0 {
@@ -446,6 +505,180 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         Ok(())
     }
 
+    /// Computes and records the checksum of the sealed static file of `segment` that contains
+    /// `block`.
+    ///
+    /// This reads the whole static file.
+    pub fn record_checksum(
+        &self,
+        segment: StaticFileSegment,
+        block: BlockNumber,
+    ) -> ProviderResult<B256> {
+        if self.access.is_read_only() {
+            return Err(ProviderError::ReadOnlyStaticFileAccess)
+        }
+
+        let file_name = segment.filename(&self.find_fixed_range(block));
+        let checksum = self.compute_checksum(&file_name)?;
+
+        let mut pending = self.pending_checksums.lock();
+        pending.remove(&file_name);
+        self.save_checksum_locked(file_name, checksum)?;
+
+        Ok(checksum)
+    }
+
+    /// Records the checksum of the sealed static file of `segment` that contains `block` on a
+    /// blocking task, since it reads the whole static file.
+    ///
+    /// Nothing is recorded if the static file is opened for writing again in the meantime.
+    pub(crate) fn spawn_record_checksum(&self, segment: StaticFileSegment, block: BlockNumber) {
+        let file_name = segment.filename(&self.find_fixed_range(block));
+        let id = self.next_checksum_id.fetch_add(1, Ordering::Relaxed);
+        self.pending_checksums.lock().insert(file_name.clone(), id);
+
+        let provider = self.clone();
+        let record = move || {
+            // the static file is hashed without holding the lock, so that it can be opened for
+            // writing meanwhile, in which case the checksum is discarded
+            let checksum = provider.compute_checksum(&file_name);
+
+            let mut pending = provider.pending_checksums.lock();
+            if pending.get(&file_name) != Some(&id) {
+                return
+            }
+            pending.remove(&file_name);
+            if let Err(err) =
+                checksum.and_then(|checksum| provider.save_checksum_locked(file_name, checksum))
+            {
+                warn!(target: "providers::static_file", ?segment, %block, %err, "Failed to record static file checksum");
+            }
+        };
+
+        match self.task_spawner.get() {
+            Some(task_spawner) => {
+                task_spawner.spawn_background_blocking(Box::pin(async move { record() }));
+            }
+            None => record(),
+        }
+    }
+
+    /// Computes the checksum of the static file.
+    fn compute_checksum(&self, file_name: &str) -> ProviderResult<B256> {
+        NippyJar::<SegmentHeader>::load(&self.path.join(file_name))
+            .and_then(|jar| jar.checksum())
+            .map(B256::from)
+            .map_err(ProviderError::other)
+    }
+
+    /// Saves the checksum of the static file. Must be called while holding the
+    /// `pending_checksums` lock.
+    fn save_checksum_locked(&self, file_name: String, checksum: B256) -> ProviderResult<()> {
+        let mut checksums = StaticFileChecksums::load(&self.path)?;
+        checksums.insert(file_name, checksum);
+        checksums.save(&self.path)
+    }
+
+    /// Removes the recorded checksum of the static file of `segment` that contains `block`, since
+    /// it's opened for writing again or deleted.
+    pub fn remove_checksum(
+        &self,
+        segment: StaticFileSegment,
+        block: BlockNumber,
+    ) -> ProviderResult<()> {
+        let file_name = segment.filename(&self.find_fixed_range(block));
+        let mut pending = self.pending_checksums.lock();
+        pending.remove(&file_name);
+
+        let mut checksums = StaticFileChecksums::load(&self.path)?;
+        if checksums.remove(&file_name).is_some() {
+            checksums.save(&self.path)?;
+        }
+        Ok(())
+    }
+
+    /// Verifies every sealed static file against its recorded checksum.
+    ///
+    /// The last static file of each segment is skipped, since it's still written to. This reads
+    /// all sealed static files, which can take a long time on archive nodes.
+    pub fn verify_static_files(&self) -> ProviderResult<Vec<StaticFileVerification>> {
+        let checksums = StaticFileChecksums::load(&self.path)?;
+
+        let mut static_files = BTreeMap::<StaticFileSegment, Vec<SegmentRangeInclusive>>::new();
+        for entry in
+            reth_fs_util::read_dir(&self.path).map_err(ProviderError::other)?.filter_map(Result::ok)
+        {
+            if let Some((segment, block_range)) =
+                StaticFileSegment::parse_filename(&entry.file_name().to_string_lossy())
+            {
+                static_files.entry(segment).or_default().push(block_range);
+            }
+        }
+
+        let mut verifications = Vec::new();
+        for (segment, mut block_ranges) in static_files {
+            block_ranges.sort_unstable_by_key(|block_range| block_range.start());
+            block_ranges.pop();
+
+            for block_range in block_ranges {
+                let file_name = segment.filename(&block_range);
+                let outcome = match checksums.get(&file_name) {
+                    Some(expected) => {
+                        match NippyJar::<SegmentHeader>::load(&self.path.join(&file_name))
+                            .and_then(|jar| jar.checksum())
+                            .map(B256::from)
+                        {
+                            Ok(actual) if expected == actual => {
+                                StaticFileVerificationOutcome::Valid
+                            }
+                            Ok(actual) => {
+                                StaticFileVerificationOutcome::Corrupt { expected, actual }
+                            }
+                            Err(err) => StaticFileVerificationOutcome::Unreadable(err.to_string()),
+                        }
+                    }
+                    None => StaticFileVerificationOutcome::Unverified,
+                };
+                verifications.push(StaticFileVerification {
+                    segment,
+                    block_range,
+                    file_name,
+                    outcome,
+                });
+            }
+        }
+
+        for (file_name, _) in checksums.iter() {
+            if self.path.join(file_name).exists() {
+                continue
+            }
+            if let Some((segment, block_range)) = StaticFileSegment::parse_filename(file_name) {
+                verifications.push(StaticFileVerification {
+                    segment,
+                    block_range,
+                    file_name: file_name.to_string(),
+                    outcome: StaticFileVerificationOutcome::Missing,
+                });
+            }
+        }
+
+        // A static file may have been opened for writing or deleted while it was verified, in
+        // which case its checksum was removed.
+        if verifications.iter().any(|verification| !verification.is_ok()) {
+            let current = StaticFileChecksums::load(&self.path)?;
+            verifications.retain(|verification| {
+                verification.is_ok() ||
+                    current.get(&verification.file_name) ==
+                        checksums.get(&verification.file_name)
+            });
+        }
+
+        verifications.sort_unstable_by_key(|verification| {
+            (verification.segment, verification.block_range.start())
+        });
+        Ok(verifications)
+    }
+
     /// Given a segment and block range it returns a cached
     /// [`StaticFileJarProvider`]. TODO(joshie): we should check the size and pop N if there's too
     /// many.
@@ -592,6 +825,19 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
             }
         };
 
+        if segment.is_receipts() {
+            // Offsets of unwound blocks are no longer valid.
+            let mut receipt_offsets = self.receipt_offsets.lock();
+            let unwound = receipt_offsets
+                .iter()
+                .map(|(block, _)| *block)
+                .filter(|block| segment_max_block.is_none_or(|max_block| *block > max_block))
+                .collect::<Vec<_>>();
+            for block in unwound {
+                receipt_offsets.remove(&block);
+            }
+        }
+
         Ok(())
     }
 
@@ -602,6 +848,7 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
 
         max_block.clear();
         tx_index.clear();
+        self.receipt_offsets.lock().clear();
 
         for (segment, ranges) in iter_static_files(&self.path).map_err(ProviderError::other)? {
             // Update last block for each segment
@@ -989,6 +1236,55 @@ impl<N: NodePrimitives> StaticFileProvider<N> {
         Ok(None)
     }
 
+    /// Returns the receipts of the given block, if they are in static files.
+    ///
+    /// The transaction number range of the receipts is taken from `tx_range` and cached, so that
+    /// repeated lookups of the receipts of a block don't need to read the block body indices and
+    /// search the static file index.
+    pub fn receipts_by_block_number(
+        &self,
+        block: BlockNumber,
+        tx_range: impl FnOnce() -> ProviderResult<Option<Range<TxNumber>>>,
+    ) -> ProviderResult<Option<Vec<N::Receipt>>>
+    where
+        N::Receipt: Value,
+    {
+        if self
+            .get_highest_static_file_block(StaticFileSegment::Receipts)
+            .is_none_or(|highest| block > highest)
+        {
+            return Ok(None)
+        }
+
+        let cached = self.receipt_offsets.lock().get(&block).cloned();
+        let Some(range) = cached.map_or_else(tx_range, |range| Ok(Some(range)))? else {
+            return Ok(None)
+        };
+
+        let mut receipts = Vec::with_capacity(range.clone().count());
+        if !range.is_empty() {
+            // All receipts of a block are in the static file of that block.
+            let provider = self.get_or_create_jar_provider(
+                StaticFileSegment::Receipts,
+                &self.find_fixed_range(block),
+            )?;
+            let mut cursor = provider.cursor()?;
+            for number in range.clone() {
+                let Some(receipt) = cursor.get_one::<ReceiptMask<N::Receipt>>(number.into())?
+                else {
+                    // The receipts are not in static files, e.g. because they were pruned
+                    // before they could be moved there.
+                    self.receipt_offsets.lock().remove(&block);
+                    return Ok(None)
+                };
+                receipts.push(receipt);
+            }
+        }
+
+        self.receipt_offsets.lock().insert(block, range);
+        Ok(Some(receipts))
+    }
+
     /// Fetches data within a specified range across multiple static files.
     ///
     /// This function iteratively retrieves data using `get_fn` for each item in the given range.
@@ -1,5 +1,5 @@
 mod manager;
-pub use manager::{StaticFileAccess, StaticFileProvider, StaticFileWriter};
+pub use manager::{StaticFileAccess, StaticFileProvider, StaticFileSegmentStats, StaticFileWriter};
 
 mod jar;
 pub use jar::StaticFileJarProvider;
@@ -9,6 +9,12 @@ pub use writer::{StaticFileProviderRW, StaticFileProviderRWRefMut};
 
 mod metrics;
 
+mod checksums;
+pub use checksums::{
+    StaticFileChecksums, StaticFileVerification, StaticFileVerificationOutcome,
+    STATIC_FILE_CHECKSUMS_FILE_NAME,
+};
+
 use reth_nippy_jar::NippyJar;
 use reth_primitives::{static_file::SegmentHeader, StaticFileSegment};
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
@@ -404,6 +410,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_receipts_by_block_number() {
+        let segment = StaticFileSegment::Receipts;
+        let blocks_per_file = 10;
+
+        let (static_dir, _) = create_test_static_files_dir();
+        let sf_rw = StaticFileProvider::read_write(&static_dir)
+            .expect("Failed to create static file provider")
+            .with_custom_blocks_per_file(blocks_per_file);
+        setup_tx_based_scenario(&sf_rw, segment, blocks_per_file);
+
+        // Block 5 has the receipt of tx 4, which uses its tx number as cumulative gas used.
+        let receipts = sf_rw.receipts_by_block_number(5, || Ok(Some(4..5))).unwrap().unwrap();
+        assert_eq!(receipts.iter().map(|r| r.cumulative_gas_used).collect::<Vec<_>>(), vec![4]);
+
+        // The offsets of block 5 are cached.
+        let cached = sf_rw
+            .receipts_by_block_number(5, || panic!("offsets should be cached"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached, receipts);
+
+        // Blocks without receipts and blocks above the static files.
+        assert_eq!(sf_rw.receipts_by_block_number(15, || Ok(Some(9..9))).unwrap(), Some(vec![]));
+        assert_eq!(sf_rw.receipts_by_block_number(25, || Ok(None)).unwrap(), None);
+        assert_eq!(sf_rw.receipts_by_block_number(30, || Ok(Some(10..11))).unwrap(), None);
+
+        // Offsets of unwound blocks are dropped.
+        let mut writer = sf_rw.latest_writer(segment).unwrap();
+        writer.prune_receipts(1, blocks_per_file * 2).unwrap();
+        writer.commit().unwrap();
+        drop(writer);
+        let mut writer = sf_rw.latest_writer(segment).unwrap();
+        writer.prune_receipts(5, 4).unwrap();
+        writer.commit().unwrap();
+        assert_eq!(sf_rw.receipts_by_block_number(5, || Ok(None)).unwrap(), None);
+    }
+
     #[test]
     fn test_tx_based_truncation() {
         let segments = [StaticFileSegment::Transactions, StaticFileSegment::Receipts];
@@ -549,10 +593,73 @@ mod tests {
         }
     }
 
-    /// Returns the number of files in the provided path, excluding ".lock" files.
+    #[test]
+    fn test_verify_static_files() {
+        let (static_dir, _) = create_test_static_files_dir();
+        let segment = StaticFileSegment::Headers;
+        let blocks_per_file = 10;
+
+        let sf_rw = StaticFileProvider::<EthPrimitives>::read_write(&static_dir)
+            .expect("Failed to create static file provider")
+            .with_custom_blocks_per_file(blocks_per_file);
+        let mut header_writer = sf_rw.latest_writer(segment).unwrap();
+        let mut header = Header::default();
+        for num in 0..30 {
+            header.number = num;
+            header_writer.append_header(&header, U256::default(), &BlockHash::default()).unwrap();
+        }
+        header_writer.commit().unwrap();
+
+        // Checksums of the sealed files are recorded in the background, wait for them.
+        sf_rw.record_checksum(segment, 0).unwrap();
+        sf_rw.record_checksum(segment, 10).unwrap();
+
+        let outcomes = |sf_rw: &StaticFileProvider<EthPrimitives>| {
+            sf_rw
+                .verify_static_files()
+                .unwrap()
+                .into_iter()
+                .map(|verification| (verification.block_range.start(), verification.outcome))
+                .collect::<Vec<_>>()
+        };
+        // The last file is still written to and is skipped.
+        assert_eq!(
+            outcomes(&sf_rw),
+            vec![
+                (0, StaticFileVerificationOutcome::Valid),
+                (10, StaticFileVerificationOutcome::Valid)
+            ]
+        );
+
+        // Flip a bit in the data file of the first static file.
+        let first_file =
+            static_dir.as_ref().join(segment.filename(&find_fixed_range(0, blocks_per_file)));
+        let mut data = fs::read(&first_file).unwrap();
+        data[0] ^= 1;
+        fs::write(&first_file, data).unwrap();
+        let verifications = outcomes(&sf_rw);
+        assert!(matches!(verifications[0], (0, StaticFileVerificationOutcome::Corrupt { .. })));
+        assert_eq!(verifications[1], (10, StaticFileVerificationOutcome::Valid));
+
+        // Unwinding into the second static file removes its checksum.
+        header_writer.prune_headers(11).unwrap();
+        header_writer.commit().unwrap();
+        let checksums = StaticFileChecksums::load(static_dir.as_ref()).unwrap();
+        assert_eq!(checksums.len(), 1);
+        assert!(checksums.get(&segment.filename(&find_fixed_range(10, blocks_per_file))).is_none());
+
+        // A checksum without its static file is reported as missing.
+        fs::remove_file(&first_file).unwrap();
+        assert_eq!(outcomes(&sf_rw), vec![(0, StaticFileVerificationOutcome::Missing)]);
+    }
+
+    /// Returns the number of files in the provided path, excluding ".lock" and checksum files.
     fn count_files_without_lockfile(path: impl AsRef<Path>) -> eyre::Result<usize> {
         let is_lockfile = |entry: &fs::DirEntry| {
-            entry.path().file_name().map(|name| name == "lock").unwrap_or(false)
+            entry
+                .path()
+                .file_stem()
+                .is_some_and(|name| name == "lock" || name == STATIC_FILE_CHECKSUMS_FILE_NAME)
         };
         let count = fs::read_dir(path)?
             .filter_map(|entry| entry.ok())
@@ -335,6 +335,8 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
                 // Commits offsets and new user_header to disk
                 self.commit()?;
 
+                self.reader().spawn_record_checksum(segment, last_block);
+
                 // Opens the new static file
                 let (writer, data_path) =
                     Self::open(segment, last_block + 1, self.reader.clone(), self.metrics.clone())?;
@@ -460,12 +462,14 @@ impl<N: NodePrimitives> StaticFileProviderRW<N> {
     /// file.
     fn delete_current_and_open_previous(&mut self) -> Result<(), ProviderError> {
         let current_path = self.data_path.clone();
-        let (previous_writer, data_path) = Self::open(
-            self.user_header().segment(),
-            self.writer.user_header().expected_block_start() - 1,
-            self.reader.clone(),
-            self.metrics.clone(),
-        )?;
+        let segment = self.user_header().segment();
+        let previous_block = self.writer.user_header().expected_block_start() - 1;
+
+        // The previous static file is written to again, so its checksum no longer holds
+        self.reader().remove_checksum(segment, previous_block)?;
+
+        let (previous_writer, data_path) =
+            Self::open(segment, previous_block, self.reader.clone(), self.metrics.clone())?;
         self.writer = previous_writer;
         self.writer.set_dirty();
         self.data_path = data_path;
@@ -1,9 +1,9 @@
 use crate::{
     traits::{BlockSource, ReceiptProvider},
     AccountReader, BlockHashReader, BlockIdReader, BlockNumReader, BlockReader, BlockReaderIdExt,
-    ChainSpecProvider, ChangeSetReader, EthStorage, HeaderProvider, ReceiptProviderIdExt,
-    StateProvider, StateProviderBox, StateProviderFactory, StateReader, StateRootProvider,
-    TransactionVariant, TransactionsProvider, WithdrawalsProvider,
+    ChainSpecProvider, ChangeSetReader, CodeHistoryReader, EthStorage, HeaderProvider,
+    ReceiptProviderIdExt, StateProvider, StateProviderBox, StateProviderFactory, StateReader,
+    StateRootProvider, TransactionVariant, TransactionsProvider, WithdrawalsProvider,
 };
 use alloy_consensus::{
     constants::EMPTY_ROOT_HASH, transaction::TransactionMeta, Header, Transaction,
@@ -18,7 +18,7 @@ use reth_chain_state::{CanonStateNotifications, CanonStateSubscriptions};
 use reth_chainspec::{ChainInfo, EthChainSpec};
 use reth_db_api::{
     mock::{DatabaseMock, TxMock},
-    models::{AccountBeforeTx, StoredBlockBodyIndices},
+    models::{AccountBeforeTx, HeaderProof, StoredBlockBodyIndices},
 };
 use reth_execution_types::ExecutionOutcome;
 use reth_node_types::NodeTypes;
@@ -31,8 +31,8 @@ use reth_prune_types::PruneModes;
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::{
     BlockBodyIndicesProvider, DBProvider, DatabaseProviderFactory, HashedPostStateProvider,
-    NodePrimitivesProvider, OmmersProvider, StageCheckpointReader, StateCommitmentProvider,
-    StateProofProvider, StorageRootProvider,
+    HeaderAccumulatorReader, HeaderAccumulatorWriter, NodePrimitivesProvider, OmmersProvider,
+    StageCheckpointReader, StateCommitmentProvider, StateProofProvider, StorageRootProvider,
 };
 use reth_storage_errors::provider::{ConsistentViewError, ProviderError, ProviderResult};
 use reth_trie::{
@@ -255,6 +255,40 @@ impl<T: Transaction, ChainSpec: EthChainSpec + 'static> DBProvider
     }
 }
 
+impl<T: Transaction, ChainSpec: EthChainSpec> HeaderAccumulatorReader
+    for MockEthProvider<T, ChainSpec>
+{
+    fn header_accumulator_len(&self) -> ProviderResult<u64> {
+        Ok(0)
+    }
+
+    fn accumulated_block_hash(
+        &self,
+        _block_number: BlockNumber,
+    ) -> ProviderResult<Option<BlockHash>> {
+        Ok(None)
+    }
+
+    fn header_proof(&self, _block_number: BlockNumber) -> ProviderResult<Option<HeaderProof>> {
+        Ok(None)
+    }
+}
+
+impl<T: Transaction, ChainSpec: EthChainSpec> HeaderAccumulatorWriter
+    for MockEthProvider<T, ChainSpec>
+{
+    fn append_header_accumulator(
+        &self,
+        _hashes: impl IntoIterator<Item = BlockHash>,
+    ) -> ProviderResult<()> {
+        Ok(())
+    }
+
+    fn truncate_header_accumulator(&self, _block_number: BlockNumber) -> ProviderResult<()> {
+        Ok(())
+    }
+}
+
 impl<T: Transaction, ChainSpec: EthChainSpec> HeaderProvider for MockEthProvider<T, ChainSpec> {
     type Header = Header;
 
@@ -879,9 +913,26 @@ impl<T: Transaction, ChainSpec: EthChainSpec> BlockBodyIndicesProvider
     }
     fn block_body_indices_range(
         &self,
-        _range: RangeInclusive<BlockNumber>,
+        range: RangeInclusive<BlockNumber>,
     ) -> ProviderResult<Vec<StoredBlockBodyIndices>> {
-        Ok(vec![])
+        let mut blocks = self
+            .blocks
+            .lock()
+            .values()
+            .map(|block| (block.number, block.body.transactions.len() as u64))
+            .collect::<Vec<_>>();
+        blocks.sort_unstable();
+
+        // the transactions of the stored blocks are numbered consecutively
+        let mut first_tx_num = 0;
+        Ok(blocks
+            .into_iter()
+            .filter_map(|(number, tx_count)| {
+                let indices = StoredBlockBodyIndices { first_tx_num, tx_count };
+                first_tx_num += tx_count;
+                range.contains(&number).then_some(indices)
+            })
+            .collect())
     }
 }
 
@@ -894,6 +945,15 @@ impl<T: Transaction, ChainSpec: EthChainSpec> ChangeSetReader for MockEthProvide
     }
 }
 
+impl<T: Transaction, ChainSpec: EthChainSpec> CodeHistoryReader for MockEthProvider<T, ChainSpec> {
+    fn account_code_changes(
+        &self,
+        _address: Address,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<B256>)>> {
+        Ok(Vec::default())
+    }
+}
+
 impl<T: Transaction, ChainSpec: EthChainSpec> StateReader for MockEthProvider<T, ChainSpec> {
     type Receipt = Receipt;
 
@@ -17,5 +17,8 @@ pub use reth_chainspec::ChainSpecProvider;
 mod static_file_provider;
 pub use static_file_provider::StaticFileProviderFactory;
 
+mod storage_breakdown;
+pub use storage_breakdown::StorageBreakdownProvider;
+
 mod full;
 pub use full::{FullProvider, FullRpcProvider};
@@ -0,0 +1,95 @@
+use crate::{DBProvider, DatabaseProviderFactory, StaticFileProviderFactory};
+use reth_db_api::{
+    models::{
+        storage_breakdown::{PruneBacklog, StaticFileSegmentSize, TableSize},
+        StorageBreakdown,
+    },
+    tables,
+    transaction::DbTx,
+};
+use reth_prune_types::{PrunePurpose, PruneSegment};
+use reth_stages_types::StageId;
+use reth_storage_errors::provider::ProviderResult;
+
+/// The trait for attributing the disk usage of the node to logical categories.
+///
+/// Implemented for every provider that gives access to the database and the static files.
+pub trait StorageBreakdownProvider: Send + Sync {
+    /// Returns the size of the database tables and static file segments, summed up per
+    /// [`StorageCategory`](reth_db_api::models::storage_breakdown::StorageCategory), together with
+    /// the prune backlog.
+    ///
+    /// Growth rates are not set, as they require an earlier report to compare against.
+    fn storage_breakdown(&self) -> ProviderResult<StorageBreakdown>;
+}
+
+impl<T> StorageBreakdownProvider for T
+where
+    T: DatabaseProviderFactory + StaticFileProviderFactory,
+{
+    fn storage_breakdown(&self) -> ProviderResult<StorageBreakdown> {
+        let provider = self.database_provider_ro()?;
+        let tx = provider.tx_ref();
+        let best_block = tx
+            .get::<tables::StageCheckpoints>(StageId::Finish.to_string())?
+            .unwrap_or_default()
+            .block_number;
+
+        let tables = tx
+            .table_sizes()?
+            .into_iter()
+            .map(|(name, size, entries)| TableSize::new(name, size, entries))
+            .collect();
+
+        let static_files = self
+            .static_file_provider()
+            .segment_sizes()?
+            .into_iter()
+            .map(|stats| {
+                StaticFileSegmentSize::new(
+                    stats.segment.as_str(),
+                    stats.size,
+                    stats.files as u64,
+                    stats.entries as u64,
+                )
+            })
+            .collect();
+
+        let prune_modes = provider.prune_modes_ref();
+        let mut prune_backlog = Vec::new();
+        for (segment, mode) in [
+            (PruneSegment::SenderRecovery, prune_modes.sender_recovery),
+            (PruneSegment::TransactionLookup, prune_modes.transaction_lookup),
+            (PruneSegment::Receipts, prune_modes.receipts),
+            (PruneSegment::AccountHistory, prune_modes.account_history),
+            (PruneSegment::StorageHistory, prune_modes.storage_history),
+        ] {
+            let Some((target_block, _)) = mode
+                .and_then(|mode| {
+                    mode.prune_target_block(best_block, segment, PrunePurpose::User).ok()
+                })
+                .flatten()
+            else {
+                continue
+            };
+
+            let pruned_block = tx
+                .get::<tables::PruneCheckpoints>(segment)?
+                .and_then(|checkpoint| checkpoint.block_number);
+            let blocks = match pruned_block {
+                Some(pruned_block) => target_block.saturating_sub(pruned_block),
+                None => target_block + 1,
+            };
+            if blocks > 0 {
+                prune_backlog.push(PruneBacklog {
+                    segment: segment.to_string(),
+                    pruned_block,
+                    target_block,
+                    blocks,
+                });
+            }
+        }
+
+        Ok(StorageBreakdown::new(best_block, tables, static_files, prune_backlog))
+    }
+}
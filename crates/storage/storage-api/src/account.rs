@@ -2,7 +2,7 @@ use alloc::{
     collections::{BTreeMap, BTreeSet},
     vec::Vec,
 };
-use alloy_primitives::{Address, BlockNumber};
+use alloy_primitives::{Address, BlockNumber, B256};
 use auto_impl::auto_impl;
 use core::ops::{RangeBounds, RangeInclusive};
 use reth_db_models::AccountBeforeTx;
@@ -55,3 +55,16 @@ pub trait ChangeSetReader: Send + Sync {
         block_number: BlockNumber,
     ) -> ProviderResult<Vec<AccountBeforeTx>>;
 }
+
+/// Code change reader
+#[auto_impl(&, Arc, Box)]
+pub trait CodeHistoryReader: Send + Sync {
+    /// Returns the numbers of the blocks that changed the code of the account, together with its
+    /// code hash from before each block, in ascending order.
+    ///
+    /// A code hash of `None` means that the account didn't exist or had no code.
+    fn account_code_changes(
+        &self,
+        address: Address,
+    ) -> ProviderResult<Vec<(BlockNumber, Option<B256>)>>;
+}
@@ -0,0 +1,38 @@
+use alloy_primitives::{BlockHash, BlockNumber};
+use auto_impl::auto_impl;
+use reth_db_models::HeaderProof;
+use reth_storage_errors::provider::ProviderResult;
+
+/// Reads the accumulator of canonical block hashes.
+///
+/// The accumulator is a Merkle Mountain Range whose leaves are the canonical block hashes,
+/// starting with the genesis block. See [`reth_db_models::accumulator`].
+#[auto_impl(&, Arc, Box)]
+pub trait HeaderAccumulatorReader: Send + Sync {
+    /// Returns the number of block hashes in the accumulator, i.e. the number of the next block
+    /// to append.
+    fn header_accumulator_len(&self) -> ProviderResult<u64>;
+
+    /// Returns the accumulated hash of the block, or `None` if the block is not accumulated.
+    fn accumulated_block_hash(
+        &self,
+        block_number: BlockNumber,
+    ) -> ProviderResult<Option<BlockHash>>;
+
+    /// Returns the inclusion proof of the hash of the block against the current root of the
+    /// accumulator, or `None` if the block is not accumulated.
+    fn header_proof(&self, block_number: BlockNumber) -> ProviderResult<Option<HeaderProof>>;
+}
+
+/// Updates the accumulator of canonical block hashes.
+#[auto_impl(&, Arc, Box)]
+pub trait HeaderAccumulatorWriter: Send + Sync {
+    /// Appends the hashes of the next canonical blocks, in ascending order.
+    fn append_header_accumulator(
+        &self,
+        hashes: impl IntoIterator<Item = BlockHash>,
+    ) -> ProviderResult<()>;
+
+    /// Removes the hashes of all blocks starting at the block from the accumulator.
+    fn truncate_header_accumulator(&self, block_number: BlockNumber) -> ProviderResult<()>;
+}
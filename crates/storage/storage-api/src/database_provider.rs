@@ -1,3 +1,4 @@
+use crate::{HeaderAccumulatorReader, HeaderAccumulatorWriter};
 use alloc::vec::Vec;
 use core::ops::{Bound, RangeBounds};
 use reth_db_api::{
@@ -147,10 +148,12 @@ pub trait DatabaseProviderFactory: Send + Sync {
     type DB: Database;
 
     /// Provider type returned by the factory.
-    type Provider: DBProvider<Tx = <Self::DB as Database>::TX>;
+    type Provider: DBProvider<Tx = <Self::DB as Database>::TX> + HeaderAccumulatorReader;
 
     /// Read-write provider type returned by the factory.
-    type ProviderRW: DBProvider<Tx = <Self::DB as Database>::TXMut>;
+    type ProviderRW: DBProvider<Tx = <Self::DB as Database>::TXMut>
+        + HeaderAccumulatorReader
+        + HeaderAccumulatorWriter;
 
     /// Create new read-only database provider.
     fn database_provider_ro(&self) -> ProviderResult<Self::Provider>;
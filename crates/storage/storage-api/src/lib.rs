@@ -16,6 +16,9 @@ pub use reth_storage_errors as errors;
 mod account;
 pub use account::*;
 
+mod accumulator;
+pub use accumulator::*;
+
 mod block;
 pub use block::*;
 
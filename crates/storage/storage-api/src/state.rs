@@ -2,12 +2,12 @@ use super::{
     AccountReader, BlockHashReader, BlockIdReader, StateProofProvider, StateRootProvider,
     StorageRootProvider,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 use alloy_consensus::constants::KECCAK_EMPTY;
 use alloy_eips::{BlockId, BlockNumberOrTag};
 use alloy_primitives::{Address, BlockHash, BlockNumber, StorageKey, StorageValue, B256, U256};
 use auto_impl::auto_impl;
-use reth_primitives_traits::Bytecode;
+use reth_primitives_traits::{Account, Bytecode};
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie_common::HashedPostState;
 use revm_database::BundleState;
@@ -34,6 +34,28 @@ pub trait StateProvider:
         storage_key: StorageKey,
     ) -> ProviderResult<Option<StorageValue>>;
 
+    /// Get multiple storage slots of the given account.
+    ///
+    /// Returns the values in the order of the given keys. A more efficient version than calling
+    /// [`StateProvider::storage`] repeatedly, since implementations can reuse the same cursor for
+    /// all lookups.
+    fn storage_multi(
+        &self,
+        account: Address,
+        storage_keys: &[StorageKey],
+    ) -> ProviderResult<Vec<Option<StorageValue>>> {
+        storage_keys.iter().map(|key| self.storage(account, *key)).collect()
+    }
+
+    /// Get basic account information for multiple accounts.
+    ///
+    /// Returns the accounts in the order of the given addresses. A more efficient version than
+    /// calling [`AccountReader::basic_account`] repeatedly, since implementations can reuse the
+    /// same cursor for all lookups.
+    fn basic_accounts(&self, addresses: &[Address]) -> ProviderResult<Vec<Option<Account>>> {
+        addresses.iter().map(|address| self.basic_account(address)).collect()
+    }
+
     /// Get account code by its hash
     fn bytecode_by_hash(&self, code_hash: &B256) -> ProviderResult<Option<Bytecode>>;
 
@@ -0,0 +1,91 @@
+//! Priority lanes of spawned tasks.
+
+use crate::metrics::TaskLaneMetrics;
+use std::{fmt, io};
+use tokio::runtime::Handle;
+
+/// The default number of worker threads of the background runtime.
+pub const DEFAULT_BACKGROUND_WORKER_THREADS: usize = 2;
+
+/// The priority lane a task is spawned on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskLane {
+    /// Tasks the node can not operate without, e.g. the consensus engine.
+    Critical,
+    /// Regular tasks, e.g. RPC handlers.
+    Regular,
+    /// Work that can be delayed without affecting the node, e.g. metrics, static file
+    /// checksums or `ExEx`es.
+    ///
+    /// These tasks run on a dedicated runtime, if configured, so that they can not starve the
+    /// tasks of the other lanes.
+    Background,
+}
+
+impl TaskLane {
+    /// Returns the name of the lane.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Critical => "critical",
+            Self::Regular => "regular",
+            Self::Background => "background",
+        }
+    }
+}
+
+impl fmt::Display for TaskLane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The occupancy metrics of all lanes.
+#[derive(Debug, Clone)]
+pub(crate) struct TaskLanesMetrics {
+    critical: TaskLaneMetrics,
+    regular: TaskLaneMetrics,
+    background: TaskLaneMetrics,
+}
+
+impl TaskLanesMetrics {
+    /// Returns the metrics of the given lane.
+    pub(crate) const fn lane(&self, lane: TaskLane) -> &TaskLaneMetrics {
+        match lane {
+            TaskLane::Critical => &self.critical,
+            TaskLane::Regular => &self.regular,
+            TaskLane::Background => &self.background,
+        }
+    }
+}
+
+impl Default for TaskLanesMetrics {
+    fn default() -> Self {
+        let metrics = |lane: TaskLane| TaskLaneMetrics::new_with_labels(&[("lane", lane.as_str())]);
+        Self {
+            critical: metrics(TaskLane::Critical),
+            regular: metrics(TaskLane::Regular),
+            background: metrics(TaskLane::Background),
+        }
+    }
+}
+
+/// Creates the runtime of the [`TaskLane::Background`] lane with the given number of worker
+/// threads.
+///
+/// The runtime is driven by a dedicated thread until the given future resolves, after which it is
+/// shut down.
+pub(crate) fn spawn_background_runtime(
+    worker_threads: usize,
+    until: impl std::future::Future<Output = ()> + Send + 'static,
+) -> io::Result<Handle> {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(worker_threads)
+        .thread_name("reth-background")
+        .enable_all()
+        .build()?;
+    let handle = runtime.handle().clone();
+    std::thread::Builder::new()
+        .name("reth-background-rt".to_string())
+        .spawn(move || runtime.block_on(until))?;
+    Ok(handle)
+}
@@ -3,6 +3,12 @@
 //! # Feature Flags
 //!
 //! - `rayon`: Enable rayon thread pool for blocking tasks.
+//!
+//! # Task Lanes
+//!
+//! Tasks are spawned on a [`TaskLane`]. Tasks of the [`TaskLane::Background`] lane run on a
+//! dedicated runtime if one is configured via [`TaskManager::with_background_runtime`], so that
+//! background work can not starve critical tasks.
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/paradigmxyz/reth/main/assets/reth-docs.png",
@@ -13,12 +19,13 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 use crate::{
-    metrics::{IncCounterOnDrop, TaskExecutorMetrics},
+    lane::TaskLanesMetrics,
+    metrics::{DecGaugeOnDrop, IncCounterOnDrop, RuntimeMetrics, TaskExecutorMetrics},
     shutdown::{signal, GracefulShutdown, GracefulShutdownGuard, Shutdown, Signal},
 };
 use dyn_clone::DynClone;
 use futures_util::{
-    future::{select, BoxFuture},
+    future::{poll_fn, select, BoxFuture},
     Future, FutureExt, TryFutureExt,
 };
 use std::{
@@ -27,21 +34,36 @@ use std::{
     pin::{pin, Pin},
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc,
+        Arc, Mutex,
     },
     task::{ready, Context, Poll},
+    time::{Duration, Instant},
 };
 use tokio::{
     runtime::Handle,
     sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
     task::JoinHandle,
 };
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
 use tracing_futures::Instrument;
 
+pub mod lane;
 pub mod metrics;
 pub mod shutdown;
 
+pub use lane::TaskLane;
+
+/// The time a blocking task can wait for a thread of the blocking pool before the pool is
+/// considered saturated.
+const BLOCKING_POOL_SATURATION_THRESHOLD: Duration = Duration::from_millis(100);
+
+/// The minimum interval between two warnings about a saturated blocking pool.
+const BLOCKING_POOL_WARNING_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Only one in this many polls of a spawned task is timed, so that recording the poll duration
+/// stays off the hot path.
+const TASK_POLL_SAMPLE_INTERVAL: u32 = 64;
+
 #[cfg(feature = "rayon")]
 pub mod pool;
 
@@ -102,6 +124,13 @@ pub trait TaskSpawner: Send + Sync + Unpin + std::fmt::Debug + DynClone {
         name: &'static str,
         fut: BoxFuture<'static, ()>,
     ) -> JoinHandle<()>;
+
+    /// Spawns a blocking task onto the runtime of the [`TaskLane::Background`] lane.
+    ///
+    /// Spawners without lanes spawn it like any other blocking task.
+    fn spawn_background_blocking(&self, fut: BoxFuture<'static, ()>) -> JoinHandle<()> {
+        self.spawn_blocking(fut)
+    }
 }
 
 dyn_clone::clone_trait_object!(TaskSpawner);
@@ -171,6 +200,10 @@ pub struct TaskManager {
     on_shutdown: Shutdown,
     /// How many [`GracefulShutdown`] tasks are currently active
     graceful_tasks: Arc<AtomicUsize>,
+    /// Tracks the tasks spawned onto the blocking pool.
+    blocking_pool: Arc<BlockingPoolState>,
+    /// Handle to the runtime of the [`TaskLane::Background`] lane, if configured.
+    background: Option<Handle>,
 }
 
 // === impl TaskManager ===
@@ -197,9 +230,21 @@ impl TaskManager {
             signal: Some(signal),
             on_shutdown,
             graceful_tasks: Arc::new(AtomicUsize::new(0)),
+            blocking_pool: Default::default(),
+            background: None,
         }
     }
 
+    /// Runs the tasks of the [`TaskLane::Background`] lane on a dedicated runtime with the given
+    /// number of worker threads.
+    ///
+    /// The runtime is shut down when the shutdown signal fires.
+    pub fn with_background_runtime(mut self, worker_threads: usize) -> std::io::Result<Self> {
+        self.background =
+            Some(lane::spawn_background_runtime(worker_threads, self.on_shutdown.clone())?);
+        Ok(self)
+    }
+
     /// Returns a new [`TaskExecutor`] that can spawn new tasks onto the tokio runtime this type is
     /// connected to.
     pub fn executor(&self) -> TaskExecutor {
@@ -208,7 +253,11 @@ impl TaskManager {
             on_shutdown: self.on_shutdown.clone(),
             panicked_tasks_tx: self.panicked_tasks_tx.clone(),
             metrics: Default::default(),
+            runtime_metrics: Default::default(),
             graceful_tasks: Arc::clone(&self.graceful_tasks),
+            blocking_pool: Arc::clone(&self.blocking_pool),
+            background: self.background.clone(),
+            lane_metrics: Default::default(),
         }
     }
 
@@ -297,8 +346,16 @@ pub struct TaskExecutor {
     panicked_tasks_tx: UnboundedSender<PanickedTaskError>,
     /// Task Executor Metrics
     metrics: TaskExecutorMetrics,
+    /// Tokio runtime metrics
+    runtime_metrics: RuntimeMetrics,
     /// How many [`GracefulShutdown`] tasks are currently active
     graceful_tasks: Arc<AtomicUsize>,
+    /// Tracks the tasks spawned onto the blocking pool.
+    blocking_pool: Arc<BlockingPoolState>,
+    /// Handle to the runtime of the [`TaskLane::Background`] lane, if configured.
+    background: Option<Handle>,
+    /// Occupancy metrics of the task lanes
+    lane_metrics: TaskLanesMetrics,
 }
 
 // === impl TaskExecutor ===
@@ -314,22 +371,73 @@ impl TaskExecutor {
         &self.on_shutdown
     }
 
-    /// Spawns a future on the tokio runtime depending on the [`TaskKind`]
-    fn spawn_on_rt<F>(&self, fut: F, task_kind: TaskKind) -> JoinHandle<()>
+    /// Records the metrics of the tokio runtime.
+    ///
+    /// The metrics of the blocking pool are recorded when blocking tasks are spawned and started.
+    pub fn report_runtime_metrics(&self) {
+        let runtime = self.handle.metrics();
+        self.runtime_metrics.workers.set(runtime.num_workers() as f64);
+        self.runtime_metrics.alive_tasks.set(runtime.num_alive_tasks() as f64);
+        self.runtime_metrics.global_queue_depth.set(runtime.global_queue_depth() as f64);
+    }
+
+    /// Spawns a future on the tokio runtime of the [`TaskLane`] depending on the [`TaskKind`]
+    fn spawn_on_rt<F>(&self, fut: F, task_kind: TaskKind, lane: TaskLane) -> JoinHandle<()>
     where
         F: Future<Output = ()> + Send + 'static,
     {
+        let lane_metrics = self.lane_metrics.lane(lane).clone();
+        lane_metrics.spawned_tasks_total.increment(1);
+        lane_metrics.running_tasks.increment(1);
+        let fut = async move {
+            let _dec_gauge_on_drop = DecGaugeOnDrop::new(lane_metrics.running_tasks);
+            fut.await
+        };
+
+        let background = self.background.as_ref().filter(|_| lane == TaskLane::Background);
         match task_kind {
-            TaskKind::Default => self.handle.spawn(fut),
+            TaskKind::Default => {
+                let poll_duration = self.runtime_metrics.task_poll_duration_seconds.clone();
+                background.unwrap_or(&self.handle).spawn(async move {
+                    let mut fut = pin!(fut);
+                    let mut polls = 0u32;
+                    poll_fn(|cx| {
+                        // time the first poll and every `TASK_POLL_SAMPLE_INTERVAL`th after it
+                        let sampled = polls % TASK_POLL_SAMPLE_INTERVAL == 0;
+                        polls = polls.wrapping_add(1);
+                        if !sampled {
+                            return fut.as_mut().poll(cx)
+                        }
+
+                        let start = Instant::now();
+                        let poll = fut.as_mut().poll(cx);
+                        poll_duration.record(start.elapsed());
+                        poll
+                    })
+                    .await
+                })
+            }
             TaskKind::Blocking => {
+                if let Some(background) = background {
+                    let handle = background.clone();
+                    return background.spawn_blocking(move || handle.block_on(fut))
+                }
+
                 let handle = self.handle.clone();
-                self.handle.spawn_blocking(move || handle.block_on(fut))
+                let blocking_pool = Arc::clone(&self.blocking_pool);
+                let metrics = self.runtime_metrics.clone();
+                blocking_pool.on_queued(&metrics);
+                let queued_at = Instant::now();
+                self.handle.spawn_blocking(move || {
+                    let _running = blocking_pool.on_started(queued_at.elapsed(), &metrics);
+                    handle.block_on(fut)
+                })
             }
         }
     }
 
-    /// Spawns a regular task depending on the given [`TaskKind`]
-    fn spawn_task_as<F>(&self, fut: F, task_kind: TaskKind) -> JoinHandle<()>
+    /// Spawns a regular task on the [`TaskLane`] depending on the given [`TaskKind`]
+    fn spawn_task_as<F>(&self, fut: F, task_kind: TaskKind, lane: TaskLane) -> JoinHandle<()>
     where
         F: Future<Output = ()> + Send + 'static,
     {
@@ -350,7 +458,7 @@ impl TaskExecutor {
         }
         .in_current_span();
 
-        self.spawn_on_rt(task, task_kind)
+        self.spawn_on_rt(task, task_kind, lane)
     }
 
     /// Spawns the task onto the runtime.
@@ -361,7 +469,25 @@ impl TaskExecutor {
     where
         F: Future<Output = ()> + Send + 'static,
     {
-        self.spawn_task_as(fut, TaskKind::Default)
+        self.spawn_task_as(fut, TaskKind::Default, TaskLane::Regular)
+    }
+
+    /// Spawns the task onto the runtime of the [`TaskLane::Background`] lane.
+    /// The given future resolves as soon as the [Shutdown] signal is received.
+    pub fn spawn_background<F>(&self, fut: F) -> JoinHandle<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_task_as(fut, TaskKind::Default, TaskLane::Background)
+    }
+
+    /// Spawns a blocking task onto the runtime of the [`TaskLane::Background`] lane.
+    /// The given future resolves as soon as the [Shutdown] signal is received.
+    pub fn spawn_background_blocking<F>(&self, fut: F) -> JoinHandle<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_task_as(fut, TaskKind::Blocking, TaskLane::Background)
     }
 
     /// Spawns a blocking task onto the runtime.
@@ -372,7 +498,7 @@ impl TaskExecutor {
     where
         F: Future<Output = ()> + Send + 'static,
     {
-        self.spawn_task_as(fut, TaskKind::Blocking)
+        self.spawn_task_as(fut, TaskKind::Blocking, TaskLane::Regular)
     }
 
     /// Spawns the task onto the runtime.
@@ -388,15 +514,16 @@ impl TaskExecutor {
 
         let task = fut.in_current_span();
 
-        self.handle.spawn(task)
+        self.spawn_on_rt(task, TaskKind::Default, TaskLane::Regular)
     }
 
-    /// Spawns a critical task depending on the given [`TaskKind`]
+    /// Spawns a critical task on the [`TaskLane`] depending on the given [`TaskKind`]
     fn spawn_critical_as<F>(
         &self,
         name: &'static str,
         fut: F,
         task_kind: TaskKind,
+        lane: TaskLane,
     ) -> JoinHandle<()>
     where
         F: Future<Output = ()> + Send + 'static,
@@ -424,7 +551,7 @@ impl TaskExecutor {
             let _ = select(on_shutdown, task).await;
         };
 
-        self.spawn_on_rt(task, task_kind)
+        self.spawn_on_rt(task, task_kind, lane)
     }
 
     /// This spawns a critical blocking task onto the runtime.
@@ -435,7 +562,7 @@ impl TaskExecutor {
     where
         F: Future<Output = ()> + Send + 'static,
     {
-        self.spawn_critical_as(name, fut, TaskKind::Blocking)
+        self.spawn_critical_as(name, fut, TaskKind::Blocking, TaskLane::Critical)
     }
 
     /// This spawns a critical task onto the runtime.
@@ -446,7 +573,19 @@ impl TaskExecutor {
     where
         F: Future<Output = ()> + Send + 'static,
     {
-        self.spawn_critical_as(name, fut, TaskKind::Default)
+        self.spawn_critical_as(name, fut, TaskKind::Default, TaskLane::Critical)
+    }
+
+    /// This spawns a critical task onto the runtime of the [`TaskLane::Background`] lane.
+    /// The given future resolves as soon as the [Shutdown] signal is received.
+    ///
+    /// This is intended for long running background work the node depends on, e.g. `ExEx`es. If
+    /// this task panics, the [`TaskManager`] is notified.
+    pub fn spawn_critical_background<F>(&self, name: &'static str, fut: F) -> JoinHandle<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_critical_as(name, fut, TaskKind::Default, TaskLane::Background)
     }
 
     /// This spawns a critical task onto the runtime.
@@ -475,7 +614,7 @@ impl TaskExecutor {
             .map(drop)
             .in_current_span();
 
-        self.handle.spawn(task)
+        self.spawn_on_rt(task, TaskKind::Default, TaskLane::Critical)
     }
 
     /// This spawns a critical task onto the runtime.
@@ -524,7 +663,7 @@ impl TaskExecutor {
             .map(drop)
             .in_current_span();
 
-        self.handle.spawn(task)
+        self.spawn_on_rt(task, TaskKind::Default, TaskLane::Critical)
     }
 
     /// This spawns a regular task onto the runtime.
@@ -550,6 +689,31 @@ impl TaskExecutor {
         &self,
         f: impl FnOnce(GracefulShutdown) -> F,
     ) -> JoinHandle<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_with_graceful_shutdown_signal_as(f, TaskLane::Regular)
+    }
+
+    /// This spawns a task onto the runtime of the [`TaskLane::Background`] lane.
+    ///
+    /// The [`TaskManager`] will wait until the given future has completed before shutting down.
+    pub fn spawn_background_with_graceful_shutdown_signal<F>(
+        &self,
+        f: impl FnOnce(GracefulShutdown) -> F,
+    ) -> JoinHandle<()>
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_with_graceful_shutdown_signal_as(f, TaskLane::Background)
+    }
+
+    /// Spawns a task on the [`TaskLane`] that receives a [`GracefulShutdown`] signal.
+    fn spawn_with_graceful_shutdown_signal_as<F>(
+        &self,
+        f: impl FnOnce(GracefulShutdown) -> F,
+        lane: TaskLane,
+    ) -> JoinHandle<()>
     where
         F: Future<Output = ()> + Send + 'static,
     {
@@ -559,7 +723,7 @@ impl TaskExecutor {
         );
         let fut = f(on_shutdown);
 
-        self.handle.spawn(fut)
+        self.spawn_on_rt(fut, TaskKind::Default, lane)
     }
 }
 
@@ -585,6 +749,10 @@ impl TaskSpawner for TaskExecutor {
     ) -> JoinHandle<()> {
         Self::spawn_critical_blocking(self, name, fut)
     }
+
+    fn spawn_background_blocking(&self, fut: BoxFuture<'static, ()>) -> JoinHandle<()> {
+        Self::spawn_background_blocking(self, fut)
+    }
 }
 
 /// `TaskSpawner` with extended behaviour
@@ -636,6 +804,78 @@ impl TaskSpawnerExt for TaskExecutor {
     }
 }
 
+/// Tracks the tasks that were spawned onto the blocking pool of the runtime.
+#[derive(Debug, Default)]
+struct BlockingPoolState {
+    /// Number of tasks that are waiting for a thread of the blocking pool.
+    queued: AtomicUsize,
+    /// Number of tasks that are running on a thread of the blocking pool.
+    running: AtomicUsize,
+    /// Number of tasks that exceeded [`BLOCKING_POOL_SATURATION_THRESHOLD`] since the last
+    /// warning.
+    saturated: AtomicUsize,
+    /// When the last warning about a saturated blocking pool was logged.
+    last_warning: Mutex<Option<Instant>>,
+}
+
+impl BlockingPoolState {
+    /// Invoked when a task is spawned onto the blocking pool.
+    fn on_queued(&self, metrics: &RuntimeMetrics) {
+        let queued = self.queued.fetch_add(1, Ordering::Relaxed) + 1;
+        metrics.blocking_queue_depth.set(queued as f64);
+    }
+
+    /// Invoked when a task starts running on a thread of the blocking pool after waiting for the
+    /// given duration.
+    ///
+    /// Returns a guard that marks the task as finished when dropped.
+    fn on_started<'a>(
+        &'a self,
+        waited: Duration,
+        metrics: &'a RuntimeMetrics,
+    ) -> RunningBlockingTask<'a> {
+        let queued = self.queued.fetch_sub(1, Ordering::Relaxed) - 1;
+        let running = self.running.fetch_add(1, Ordering::Relaxed) + 1;
+        metrics.blocking_queue_depth.set(queued as f64);
+        metrics.blocking_tasks_running.set(running as f64);
+        metrics.blocking_queue_duration_seconds.record(waited);
+
+        if waited > BLOCKING_POOL_SATURATION_THRESHOLD {
+            metrics.blocking_pool_saturated_total.increment(1);
+            self.saturated.fetch_add(1, Ordering::Relaxed);
+
+            let mut last_warning = self.last_warning.lock().unwrap_or_else(|err| err.into_inner());
+            if last_warning.is_none_or(|last| last.elapsed() >= BLOCKING_POOL_WARNING_INTERVAL) {
+                *last_warning = Some(Instant::now());
+                let saturated = self.saturated.swap(0, Ordering::Relaxed);
+                warn!(
+                    target: "tasks",
+                    ?waited,
+                    saturated,
+                    queued,
+                    running,
+                    "Blocking pool is saturated, blocking tasks are waiting for a thread"
+                );
+            }
+        }
+
+        RunningBlockingTask { pool: self, metrics }
+    }
+}
+
+/// Marks a blocking task as finished when dropped.
+struct RunningBlockingTask<'a> {
+    pool: &'a BlockingPoolState,
+    metrics: &'a RuntimeMetrics,
+}
+
+impl Drop for RunningBlockingTask<'_> {
+    fn drop(&mut self) {
+        let running = self.pool.running.fetch_sub(1, Ordering::Relaxed) - 1;
+        self.metrics.blocking_tasks_running.set(running as f64);
+    }
+}
+
 /// Determines how a task is spawned
 enum TaskKind {
     /// Spawn the task to the default executor [`Handle::spawn`]
@@ -679,6 +919,50 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_blocking_pool_saturation() {
+        let runtime =
+            tokio::runtime::Builder::new_multi_thread().max_blocking_threads(1).build().unwrap();
+        let manager = TaskManager::new(runtime.handle().clone());
+        let executor = manager.executor();
+
+        let first = executor.spawn_blocking(async {
+            std::thread::sleep(BLOCKING_POOL_SATURATION_THRESHOLD * 2);
+        });
+        let second = executor.spawn_blocking(async {});
+
+        runtime.block_on(async move {
+            first.await.unwrap();
+            second.await.unwrap();
+        });
+
+        let pool = &executor.blocking_pool;
+        assert_eq!(pool.queued.load(Ordering::Relaxed), 0);
+        assert_eq!(pool.running.load(Ordering::Relaxed), 0);
+        assert!(pool.last_warning.lock().unwrap().is_some());
+    }
+
+    #[test]
+    fn test_background_lane() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let manager =
+            TaskManager::new(runtime.handle().clone()).with_background_runtime(1).unwrap();
+        let executor = manager.executor();
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let background_tx = tx.clone();
+        executor.spawn_background(async move {
+            background_tx.send(std::thread::current().name().map(ToString::to_string)).unwrap();
+        });
+        executor.spawn(async move {
+            tx.send(std::thread::current().name().map(ToString::to_string)).unwrap();
+        });
+
+        let names = [rx.recv().unwrap(), rx.recv().unwrap()];
+        assert!(names.contains(&Some("reth-background".to_string())));
+        assert!(names.iter().any(|name| name.as_deref() != Some("reth-background")));
+    }
+
     // Tests that spawned tasks are terminated if the `TaskManager` drops
     #[test]
     fn test_manager_shutdown_critical() {
@@ -2,7 +2,10 @@
 
 use core::fmt;
 
-use reth_metrics::{metrics::Counter, Metrics};
+use reth_metrics::{
+    metrics::{Counter, Gauge, Histogram},
+    Metrics,
+};
 
 /// Task Executor Metrics
 #[derive(Metrics, Clone)]
@@ -30,6 +33,38 @@ impl TaskExecutorMetrics {
     }
 }
 
+/// Tokio runtime metrics
+#[derive(Metrics, Clone)]
+#[metrics(scope = "executor.runtime")]
+pub struct RuntimeMetrics {
+    /// Number of worker threads of the runtime
+    pub(crate) workers: Gauge,
+    /// Number of alive tasks in the runtime
+    pub(crate) alive_tasks: Gauge,
+    /// Number of tasks in the global queue of the runtime
+    pub(crate) global_queue_depth: Gauge,
+    /// Duration of a single poll of a spawned task, sampled
+    pub(crate) task_poll_duration_seconds: Histogram,
+    /// Number of blocking tasks that are waiting for a thread of the blocking pool
+    pub(crate) blocking_queue_depth: Gauge,
+    /// Number of blocking tasks that are currently running
+    pub(crate) blocking_tasks_running: Gauge,
+    /// Time a blocking task waited for a thread of the blocking pool
+    pub(crate) blocking_queue_duration_seconds: Histogram,
+    /// Number of blocking tasks that waited longer than the saturation threshold for a thread
+    pub(crate) blocking_pool_saturated_total: Counter,
+}
+
+/// Task lane metrics
+#[derive(Metrics, Clone)]
+#[metrics(scope = "executor.lane")]
+pub struct TaskLaneMetrics {
+    /// Number of tasks spawned on the lane
+    pub(crate) spawned_tasks_total: Counter,
+    /// Number of tasks of the lane that did not finish yet
+    pub(crate) running_tasks: Gauge,
+}
+
 /// Helper type for increasing counters even if a task fails
 pub struct IncCounterOnDrop(Counter);
 
@@ -52,3 +87,26 @@ impl Drop for IncCounterOnDrop {
         self.0.increment(1);
     }
 }
+
+/// Helper type for decreasing gauges even if a task fails
+pub struct DecGaugeOnDrop(Gauge);
+
+impl fmt::Debug for DecGaugeOnDrop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DecGaugeOnDrop").finish()
+    }
+}
+
+impl DecGaugeOnDrop {
+    /// Creates a new instance of `DecGaugeOnDrop` with the given gauge.
+    pub const fn new(gauge: Gauge) -> Self {
+        Self(gauge)
+    }
+}
+
+impl Drop for DecGaugeOnDrop {
+    /// Decrement the gauge when the instance is dropped.
+    fn drop(&mut self) {
+        self.0.decrement(1);
+    }
+}
@@ -1,9 +1,9 @@
 //!  The `tracing` module provides functionalities for setting up and configuring logging.
 //!
 //!  It includes structures and functions to create and manage various logging layers: stdout,
-//!  file, or journald. The module's primary entry point is the `Tracer` struct, which can be
-//!  configured to use different logging formats and destinations. If no layer is specified, it will
-//!  default to stdout.
+//!  file, journald, or an OpenTelemetry span exporter (`otlp` feature). The module's primary
+//!  entry point is the `Tracer` struct, which can be configured to use different logging formats
+//!  and destinations. If no layer is specified, it will default to stdout.
 //!
 //!  # Examples
 //!
@@ -51,10 +51,12 @@ pub use tracing_subscriber;
 // Re-export our types
 pub use formatter::LogFormat;
 pub use layers::{FileInfo, FileWorkerGuard};
+pub use otlp::{shutdown_otlp, OtlpConfig};
 pub use test_tracer::TestTracer;
 
 mod formatter;
 mod layers;
+mod otlp;
 mod test_tracer;
 
 use crate::layers::Layers;
@@ -71,6 +73,7 @@ pub struct RethTracer {
     stdout: LayerInfo,
     journald: Option<String>,
     file: Option<(LayerInfo, FileInfo)>,
+    otlp: Option<OtlpConfig>,
 }
 
 impl RethTracer {
@@ -79,7 +82,7 @@ impl RethTracer {
     ///  Initializes with default stdout layer configuration.
     ///  Journald and file layers are not set by default.
     pub fn new() -> Self {
-        Self { stdout: LayerInfo::default(), journald: None, file: None }
+        Self { stdout: LayerInfo::default(), journald: None, file: None, otlp: None }
     }
 
     ///  Sets a custom configuration for the stdout layer.
@@ -109,6 +112,17 @@ impl RethTracer {
         self.file = Some((config, file_info));
         self
     }
+
+    ///  Sets the OpenTelemetry span exporter configuration.
+    ///
+    ///  Exporting spans requires the `otlp` feature, initialization fails without it.
+    ///
+    ///  # Arguments
+    ///  * `config` - The `OtlpConfig` to use for the exporter.
+    pub fn with_otlp(mut self, config: OtlpConfig) -> Self {
+        self.otlp = Some(config);
+        self
+    }
 }
 
 impl Default for RethTracer {
@@ -183,7 +197,7 @@ impl Tracer for RethTracer {
     ///  Initializes the logging system based on the configured layers.
     ///
     ///  This method sets up the global tracing subscriber with the specified
-    ///  stdout, journald, and file layers.
+    ///  stdout, journald, file, and OTLP layers.
     ///
     ///  The default layer is stdout.
     ///
@@ -210,6 +224,16 @@ impl Tracer for RethTracer {
             None
         };
 
+        if let Some(config) = self.otlp {
+            #[cfg(feature = "otlp")]
+            layers.otlp(config)?;
+            #[cfg(not(feature = "otlp"))]
+            {
+                let _ = config;
+                eyre::bail!("Exporting spans over OTLP requires the `otlp` feature")
+            }
+        }
+
         // The error is returned if the global default subscriber is already set,
         // so it's safe to ignore it
         let _ = tracing_subscriber::registry().with(layers.into_inner()).try_init();
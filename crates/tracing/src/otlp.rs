@@ -0,0 +1,88 @@
+//! Export of tracing spans to an OpenTelemetry collector over OTLP.
+
+/// Configuration of the OTLP span exporter.
+#[derive(Debug, Clone)]
+pub struct OtlpConfig {
+    endpoint: String,
+    service_name: String,
+    sample_ratio: f64,
+    filter: String,
+}
+
+impl OtlpConfig {
+    ///  Constructs a new `OtlpConfig`.
+    ///
+    ///  # Arguments
+    ///  * `endpoint` - The OTLP/HTTP traces endpoint, e.g. `http://localhost:4318/v1/traces`.
+    ///  * `service_name` - The `service.name` resource attribute reported with every span.
+    ///  * `sample_ratio` - The fraction of root traces to sample, between 0.0 and 1.0. Child spans
+    ///    follow the sampling decision of their parent.
+    ///  * `filter` - Filter directives selecting the spans to export.
+    pub const fn new(
+        endpoint: String,
+        service_name: String,
+        sample_ratio: f64,
+        filter: String,
+    ) -> Self {
+        Self { endpoint, service_name, sample_ratio, filter }
+    }
+}
+
+#[cfg(feature = "otlp")]
+mod exporter {
+    use super::OtlpConfig;
+    use crate::layers::{build_env_filter, BoxedLayer};
+    use opentelemetry::trace::TracerProvider as _;
+    use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+    use opentelemetry_sdk::{
+        trace::{Sampler, SdkTracerProvider},
+        Resource,
+    };
+    use std::sync::OnceLock;
+    use tracing_subscriber::{Layer, Registry};
+
+    /// The installed tracer provider, kept around so pending spans can be flushed on shutdown.
+    static PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+    /// Builds a layer that exports spans with a batching OTLP/HTTP exporter.
+    pub(crate) fn layer(config: OtlpConfig) -> eyre::Result<BoxedLayer<Registry>> {
+        if !(0.0..=1.0).contains(&config.sample_ratio) {
+            eyre::bail!("OTLP sample ratio must be between 0.0 and 1.0: {}", config.sample_ratio)
+        }
+
+        let exporter =
+            SpanExporter::builder().with_http().with_endpoint(config.endpoint).build()?;
+        let provider = SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+                config.sample_ratio,
+            ))))
+            .with_resource(Resource::builder().with_service_name(config.service_name).build())
+            .build();
+        let tracer = provider.tracer("reth");
+        let _ = PROVIDER.set(provider);
+
+        let filter = build_env_filter(None, &config.filter)?;
+        Ok(tracing_opentelemetry::layer().with_tracer(tracer).with_filter(filter).boxed())
+    }
+
+    pub(crate) fn shutdown() {
+        if let Some(provider) = PROVIDER.get() {
+            if let Err(err) = provider.shutdown() {
+                tracing::warn!(%err, "Failed to flush OTLP spans");
+            }
+        }
+    }
+}
+
+#[cfg(feature = "otlp")]
+pub(crate) use exporter::layer;
+
+/// Flushes all pending spans and shuts down the OTLP exporter.
+///
+/// This should be called once before the process exits. It does nothing if no OTLP exporter was
+/// installed.
+pub fn shutdown_otlp() {
+    #[cfg(feature = "otlp")]
+    exporter::shutdown();
+}
@@ -0,0 +1,432 @@
+//! Export of blob sidecars that are removed from the blob store to durable storage.
+
+use crate::blobstore::{AwsCredentials, BlobStoreError};
+use alloy_eips::eip4844::{
+    kzg_to_versioned_hash, Blob, BlobTransactionSidecar, Bytes48, BYTES_PER_BLOB,
+    BYTES_PER_COMMITMENT, BYTES_PER_PROOF,
+};
+use alloy_primitives::{TxHash, B256};
+use parking_lot::Mutex;
+use reqwest::{Method, Url};
+use std::{
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    str::FromStr,
+    sync::Arc,
+    time::SystemTime,
+};
+use tokio::runtime::Handle;
+use tracing::debug;
+
+/// Name of the index file of a [`DirBlobArchive`].
+pub const BLOB_ARCHIVE_INDEX_FILE: &str = "index.csv";
+
+/// Length of an encoded [`ArchivedBlob`].
+const ARCHIVED_BLOB_LEN: usize = BYTES_PER_BLOB + BYTES_PER_COMMITMENT + BYTES_PER_PROOF;
+
+/// A durable archive for the blobs of sidecars that are removed from the blob store.
+///
+/// Blobs are stored individually, keyed by their versioned hash, together with an index that maps
+/// each versioned hash to the transaction that carried the blob.
+pub trait BlobArchive: fmt::Debug + Send + Sync + 'static {
+    /// Archives the blobs of the given sidecars.
+    fn archive(
+        &self,
+        sidecars: &[(TxHash, Arc<BlobTransactionSidecar>)],
+    ) -> Result<(), BlobStoreError>;
+
+    /// Retrieves an archived blob by its versioned hash.
+    fn get(&self, versioned_hash: B256) -> Result<Option<ArchivedBlob>, BlobStoreError>;
+}
+
+/// A blob together with its KZG commitment and proof, as stored in a [`BlobArchive`].
+///
+/// The encoding is the concatenation of the blob, the commitment and the proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedBlob {
+    /// The blob data.
+    pub blob: Box<Blob>,
+    /// The KZG commitment of the blob.
+    pub commitment: Bytes48,
+    /// The KZG proof of the blob.
+    pub proof: Bytes48,
+}
+
+impl ArchivedBlob {
+    /// Returns the versioned hash of the blob.
+    pub fn versioned_hash(&self) -> B256 {
+        kzg_to_versioned_hash(self.commitment.as_slice())
+    }
+
+    /// Encodes the blob, commitment and proof.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(ARCHIVED_BLOB_LEN);
+        buf.extend_from_slice(self.blob.as_slice());
+        buf.extend_from_slice(self.commitment.as_slice());
+        buf.extend_from_slice(self.proof.as_slice());
+        buf
+    }
+
+    /// Decodes an archived blob, returns `None` if the data has an invalid length.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() != ARCHIVED_BLOB_LEN {
+            return None
+        }
+        let (blob, rest) = data.split_at(BYTES_PER_BLOB);
+        let (commitment, proof) = rest.split_at(BYTES_PER_COMMITMENT);
+        Some(Self {
+            blob: Box::new(Blob::from_slice(blob)),
+            commitment: Bytes48::from_slice(commitment),
+            proof: Bytes48::from_slice(proof),
+        })
+    }
+
+    /// Returns all blobs of the sidecar.
+    pub fn from_sidecar(sidecar: &BlobTransactionSidecar) -> impl Iterator<Item = Self> + '_ {
+        sidecar.blobs.iter().zip(&sidecar.commitments).zip(&sidecar.proofs).map(
+            |((blob, commitment), proof)| Self {
+                blob: Box::new(*blob),
+                commitment: *commitment,
+                proof: *proof,
+            },
+        )
+    }
+}
+
+/// Returns the index entries of the blobs of the given sidecars, one
+/// `versioned_hash,tx_hash,blob_index` line per blob.
+fn index_entries<'a>(
+    sidecars: impl IntoIterator<Item = &'a (TxHash, Arc<BlobTransactionSidecar>)>,
+) -> String {
+    let mut index = String::new();
+    for (tx, sidecar) in sidecars {
+        for (idx, versioned_hash) in sidecar.versioned_hashes().enumerate() {
+            index.push_str(&format!("{versioned_hash},{tx},{idx}\n"));
+        }
+    }
+    index
+}
+
+/// A [`BlobArchive`] that stores blobs as files in a local directory, and appends to a single
+/// index file, see [`BLOB_ARCHIVE_INDEX_FILE`].
+#[derive(Debug)]
+pub struct DirBlobArchive {
+    dir: PathBuf,
+    index_lock: Mutex<()>,
+}
+
+impl DirBlobArchive {
+    /// Opens the archive in the given directory, creating it if it doesn't exist.
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self, BlobArchiveError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|err| BlobArchiveError::Io(dir.clone(), err))?;
+        Ok(Self { dir, index_lock: Mutex::new(()) })
+    }
+
+    /// Returns the path to the file of the blob with the given versioned hash.
+    fn blob_file(&self, versioned_hash: B256) -> PathBuf {
+        self.dir.join(format!("{versioned_hash:x}.blob"))
+    }
+}
+
+impl BlobArchive for DirBlobArchive {
+    fn archive(
+        &self,
+        sidecars: &[(TxHash, Arc<BlobTransactionSidecar>)],
+    ) -> Result<(), BlobStoreError> {
+        for (_, sidecar) in sidecars {
+            for blob in ArchivedBlob::from_sidecar(sidecar) {
+                let path = self.blob_file(blob.versioned_hash());
+                fs::write(&path, blob.encode()).map_err(|err| BlobArchiveError::Io(path, err))?;
+            }
+        }
+
+        // the index is only written once all blobs it points to exist
+        let path = self.dir.join(BLOB_ARCHIVE_INDEX_FILE);
+        let _lock = self.index_lock.lock();
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(index_entries(sidecars).as_bytes()))
+            .map_err(|err| BlobArchiveError::Io(path, err))?;
+
+        debug!(target: "txpool::blob", dir = ?self.dir, num_txs = sidecars.len(), "Archived blobs");
+        Ok(())
+    }
+
+    fn get(&self, versioned_hash: B256) -> Result<Option<ArchivedBlob>, BlobStoreError> {
+        let path = self.blob_file(versioned_hash);
+        let data = match fs::read(&path) {
+            Ok(data) => data,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(BlobArchiveError::Io(path, err).into()),
+        };
+        ArchivedBlob::decode(&data)
+            .map(Some)
+            .ok_or_else(|| BlobArchiveError::InvalidBlob(versioned_hash).into())
+    }
+}
+
+/// A [`BlobArchive`] that uploads blobs to an S3 compatible HTTP endpoint.
+///
+/// Blobs are stored as `<url>/<versioned_hash>.blob`. Objects can't be appended to, so the index
+/// is written as one `<url>/index/<tx_hash>.csv` object per transaction.
+///
+/// If [`AwsCredentials`] are set, requests are signed with AWS Signature Version 4, otherwise the
+/// bucket must accept unsigned writes from the node, e.g. through a bucket policy.
+///
+/// Requests are sent on the runtime the archive was created in, and the [`BlobArchive`] methods
+/// block until they are done, so they must be called from a blocking task.
+#[derive(Debug)]
+pub struct HttpBlobArchive {
+    url: String,
+    client: reqwest::Client,
+    credentials: Option<AwsCredentials>,
+    /// The runtime the requests are sent on.
+    runtime: Handle,
+}
+
+impl HttpBlobArchive {
+    /// Creates a new archive that stores blobs under the given URL.
+    ///
+    /// Returns an error if it's not called within a tokio runtime.
+    pub fn new(url: impl Into<String>) -> Result<Self, BlobArchiveError> {
+        let url = url.into().trim_end_matches('/').to_string();
+        let runtime =
+            Handle::try_current().map_err(|_| BlobArchiveError::NoRuntime(url.clone()))?;
+        Ok(Self { url, client: reqwest::Client::new(), credentials: None, runtime })
+    }
+
+    /// Signs the requests with the given credentials.
+    pub fn with_credentials(mut self, credentials: AwsCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// Sends a request for the object with the given key, signed if credentials are set.
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        body: Vec<u8>,
+    ) -> Result<reqwest::Response, BlobArchiveError> {
+        let url = format!("{}/{key}", self.url);
+        let parsed =
+            Url::parse(&url).map_err(|_| BlobArchiveError::InvalidLocation(url.clone()))?;
+
+        let mut request = self.client.request(method.clone(), parsed.clone());
+        if let Some(credentials) = &self.credentials {
+            for (name, value) in
+                credentials.signed_headers(method.as_str(), &parsed, &body, SystemTime::now())
+            {
+                request = request.header(name, value);
+            }
+        }
+        request
+            .body(body)
+            .send()
+            .await
+            .and_then(|res| res.error_for_status())
+            .map_err(|err| BlobArchiveError::Http(url, err))
+    }
+}
+
+impl BlobArchive for HttpBlobArchive {
+    fn archive(
+        &self,
+        sidecars: &[(TxHash, Arc<BlobTransactionSidecar>)],
+    ) -> Result<(), BlobStoreError> {
+        self.runtime.block_on(async {
+            for entry @ (tx, sidecar) in sidecars {
+                for blob in ArchivedBlob::from_sidecar(sidecar) {
+                    let key = format!("{:x}.blob", blob.versioned_hash());
+                    self.send(Method::PUT, &key, blob.encode()).await?;
+                }
+                let index = index_entries([entry]).into_bytes();
+                self.send(Method::PUT, &format!("index/{tx:x}.csv"), index).await?;
+            }
+            Ok::<_, BlobArchiveError>(())
+        })?;
+
+        debug!(target: "txpool::blob", url = %self.url, num_txs = sidecars.len(), "Archived blobs");
+        Ok(())
+    }
+
+    fn get(&self, versioned_hash: B256) -> Result<Option<ArchivedBlob>, BlobStoreError> {
+        let key = format!("{versioned_hash:x}.blob");
+        let data = self.runtime.block_on(async {
+            let res = match self.send(Method::GET, &key, Vec::new()).await {
+                Ok(res) => res,
+                Err(BlobArchiveError::Http(_, err))
+                    if err.status() == Some(reqwest::StatusCode::NOT_FOUND) =>
+                {
+                    return Ok(None)
+                }
+                Err(err) => return Err(err),
+            };
+            let url = format!("{}/{key}", self.url);
+            res.bytes().await.map(Some).map_err(|err| BlobArchiveError::Http(url, err))
+        })?;
+        let Some(data) = data else { return Ok(None) };
+        ArchivedBlob::decode(&data)
+            .map(Some)
+            .ok_or_else(|| BlobArchiveError::InvalidBlob(versioned_hash).into())
+    }
+}
+
+/// Where blobs are archived to.
+///
+/// Parsed from either a local directory, an `http(s)://` URL of an S3 compatible endpoint, or an
+/// `s3://<bucket>/<prefix>` URL that is resolved to the AWS endpoint of the bucket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlobArchiveLocation {
+    /// A local directory.
+    Dir(PathBuf),
+    /// The URL of an S3 compatible endpoint.
+    Http(String),
+}
+
+impl BlobArchiveLocation {
+    /// Opens the [`BlobArchive`] at this location.
+    ///
+    /// Requests to an HTTP endpoint are signed if AWS credentials are set, see
+    /// [`AwsCredentials::from_env`]. Must be called within a tokio runtime.
+    pub fn open(&self) -> Result<Arc<dyn BlobArchive>, BlobArchiveError> {
+        Ok(match self {
+            Self::Dir(dir) => Arc::new(DirBlobArchive::open(dir)?),
+            Self::Http(url) => {
+                let archive = HttpBlobArchive::new(url)?;
+                Arc::new(match AwsCredentials::from_env() {
+                    Some(credentials) => archive.with_credentials(credentials),
+                    None => archive,
+                })
+            }
+        })
+    }
+}
+
+impl FromStr for BlobArchiveLocation {
+    type Err = BlobArchiveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("http://") || s.starts_with("https://") {
+            return Ok(Self::Http(s.trim_end_matches('/').to_string()))
+        }
+        if let Some(path) = s.strip_prefix("s3://") {
+            let (bucket, prefix) = path.split_once('/').unwrap_or((path, ""));
+            if bucket.is_empty() {
+                return Err(BlobArchiveError::InvalidLocation(s.to_string()))
+            }
+            let url = format!("https://{bucket}.s3.amazonaws.com/{prefix}");
+            return Ok(Self::Http(url.trim_end_matches('/').to_string()))
+        }
+        if s.is_empty() {
+            return Err(BlobArchiveError::InvalidLocation(s.to_string()))
+        }
+        Ok(Self::Dir(PathBuf::from(s)))
+    }
+}
+
+impl fmt::Display for BlobArchiveLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dir(dir) => write!(f, "{}", dir.display()),
+            Self::Http(url) => f.write_str(url),
+        }
+    }
+}
+
+/// Errors that can occur when interacting with a [`BlobArchive`].
+#[derive(Debug, thiserror::Error)]
+pub enum BlobArchiveError {
+    /// The archive location could not be parsed.
+    #[error("invalid blob archive location: {0:?}")]
+    InvalidLocation(String),
+    /// Failure while accessing a file of the archive.
+    #[error("failed to access blob archive file at {0}: {1}")]
+    Io(PathBuf, io::Error),
+    /// The HTTP archive was not created within a tokio runtime.
+    #[error("blob archive {0} must be opened within a tokio runtime")]
+    NoRuntime(String),
+    /// Failure while accessing an object of the archive.
+    #[error("failed to access blob archive object at {0}: {1}")]
+    Http(String, reqwest::Error),
+    /// The archived blob has an invalid length.
+    #[error("archived blob {0} is invalid")]
+    InvalidBlob(B256),
+}
+
+impl From<BlobArchiveError> for BlobStoreError {
+    fn from(value: BlobArchiveError) -> Self {
+        Self::Other(Box::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sidecar(byte: u8) -> BlobTransactionSidecar {
+        BlobTransactionSidecar {
+            blobs: vec![Blob::repeat_byte(byte), Blob::repeat_byte(byte + 1)],
+            commitments: vec![Bytes48::repeat_byte(byte), Bytes48::repeat_byte(byte + 1)],
+            proofs: vec![Bytes48::repeat_byte(byte + 2), Bytes48::repeat_byte(byte + 3)],
+        }
+    }
+
+    #[test]
+    fn archived_blob_roundtrip() {
+        let sidecar = sidecar(1);
+        let blob = ArchivedBlob::from_sidecar(&sidecar).next().unwrap();
+        let encoded = blob.encode();
+        assert_eq!(encoded.len(), ARCHIVED_BLOB_LEN);
+        assert_eq!(ArchivedBlob::decode(&encoded), Some(blob));
+        assert_eq!(ArchivedBlob::decode(&encoded[..BYTES_PER_BLOB]), None);
+    }
+
+    #[test]
+    fn dir_archive_get_and_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = DirBlobArchive::open(dir.path()).unwrap();
+        let tx = TxHash::random();
+        let sidecar = Arc::new(sidecar(1));
+        archive.archive(&[(tx, sidecar.clone())]).unwrap();
+
+        for (blob, versioned_hash) in
+            ArchivedBlob::from_sidecar(&sidecar).zip(sidecar.versioned_hashes())
+        {
+            assert_eq!(archive.get(versioned_hash).unwrap(), Some(blob));
+        }
+        assert_eq!(archive.get(B256::random()).unwrap(), None);
+
+        let index = fs::read_to_string(dir.path().join(BLOB_ARCHIVE_INDEX_FILE)).unwrap();
+        let lines = index.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        let first_hash = sidecar.versioned_hashes().next().unwrap();
+        assert_eq!(lines[0], format!("{first_hash},{tx},0"));
+    }
+
+    #[test]
+    fn parse_archive_location() {
+        assert_eq!(
+            "/tmp/blobs".parse::<BlobArchiveLocation>().unwrap(),
+            BlobArchiveLocation::Dir(PathBuf::from("/tmp/blobs"))
+        );
+        assert_eq!(
+            "https://blobs.example.com/archive/".parse::<BlobArchiveLocation>().unwrap(),
+            BlobArchiveLocation::Http("https://blobs.example.com/archive".to_string())
+        );
+        assert_eq!(
+            "s3://my-bucket/mainnet".parse::<BlobArchiveLocation>().unwrap(),
+            BlobArchiveLocation::Http("https://my-bucket.s3.amazonaws.com/mainnet".to_string())
+        );
+        assert_eq!(
+            "s3://my-bucket".parse::<BlobArchiveLocation>().unwrap(),
+            BlobArchiveLocation::Http("https://my-bucket.s3.amazonaws.com".to_string())
+        );
+        assert!("s3:///prefix".parse::<BlobArchiveLocation>().is_err());
+    }
+}
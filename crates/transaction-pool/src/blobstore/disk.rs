@@ -1,12 +1,14 @@
 //! A simple diskstore for blobs
 
-use crate::blobstore::{BlobStore, BlobStoreCleanupStat, BlobStoreError, BlobStoreSize};
+use crate::blobstore::{
+    BlobArchive, BlobStore, BlobStoreCleanupStat, BlobStoreError, BlobStoreSize,
+};
 use alloy_eips::eip4844::{BlobAndProofV1, BlobTransactionSidecar};
 use alloy_primitives::{TxHash, B256};
 use parking_lot::{Mutex, RwLock};
 use schnellru::{ByLength, LruMap};
-use std::{collections::HashSet, fmt, fs, io, path::PathBuf, sync::Arc};
-use tracing::{debug, trace};
+use std::{collections::HashSet, fmt, fs, io, path::PathBuf, str::FromStr, sync::Arc};
+use tracing::{debug, trace, warn};
 
 /// How many [`BlobTransactionSidecar`] to cache in memory.
 pub const DEFAULT_MAX_CACHED_BLOBS: u32 = 100;
@@ -16,6 +18,9 @@ pub const DEFAULT_MAX_CACHED_BLOBS: u32 = 100;
 /// The type uses deferred deletion, meaning that blobs are not immediately deleted from disk, but
 /// it's expected that the maintenance task will call [`BlobStore::cleanup`] to remove the deleted
 /// blobs from disk.
+///
+/// If a [`BlobArchive`] is configured, the deleted blobs are exported to it before they are removed
+/// from disk.
 #[derive(Clone, Debug)]
 pub struct DiskFileBlobStore {
     inner: Arc<DiskFileBlobStoreInner>,
@@ -28,11 +33,16 @@ impl DiskFileBlobStore {
         opts: DiskFileBlobStoreConfig,
     ) -> Result<Self, DiskFileBlobStoreError> {
         let blob_dir = blob_dir.into();
-        let DiskFileBlobStoreConfig { max_cached_entries, .. } = opts;
-        let inner = DiskFileBlobStoreInner::new(blob_dir, max_cached_entries);
+        let DiskFileBlobStoreConfig { max_cached_entries, archive, .. } = opts;
+        let inner = DiskFileBlobStoreInner::new(blob_dir, max_cached_entries, archive);
 
         // initialize the blob store
-        inner.delete_all()?;
+        if inner.archive.is_some() {
+            // the blobs of the previous run are archived before they are removed
+            inner.delete_all_deferred()?;
+        } else {
+            inner.delete_all()?;
+        }
         inner.create_blob_dir()?;
 
         Ok(Self { inner: Arc::new(inner) })
@@ -76,6 +86,15 @@ impl BlobStore for DiskFileBlobStore {
 
     fn cleanup(&self) -> BlobStoreCleanupStat {
         let txs_to_delete = std::mem::take(&mut *self.inner.txs_to_delete.write());
+        if let Some(archive) = &self.inner.archive {
+            let sidecars = self.inner.get_for_archive(&txs_to_delete);
+            if let Err(err) = archive.archive(&sidecars) {
+                // keep the blobs until the next cleanup, so that no blob is lost
+                warn!(target:"txpool::blob", %err, num_blobs=%txs_to_delete.len(), "Failed to archive blobs");
+                self.inner.txs_to_delete.write().extend(txs_to_delete);
+                return BlobStoreCleanupStat::default()
+            }
+        }
         let mut stat = BlobStoreCleanupStat::default();
         let mut subsize = 0;
         debug!(target:"txpool::blob", num_blobs=%txs_to_delete.len(), "Removing blobs from disk");
@@ -159,17 +178,20 @@ struct DiskFileBlobStoreInner {
     size_tracker: BlobStoreSize,
     file_lock: RwLock<()>,
     txs_to_delete: RwLock<HashSet<B256>>,
+    archive: Option<Arc<dyn BlobArchive>>,
 }
 
 impl DiskFileBlobStoreInner {
-    /// Creates a new empty disk file blob store with the given maximum length of the blob cache.
-    fn new(blob_dir: PathBuf, max_length: u32) -> Self {
+    /// Creates a new empty disk file blob store with the given maximum length of the blob cache
+    /// and the archive that deleted blobs are exported to.
+    fn new(blob_dir: PathBuf, max_length: u32, archive: Option<Arc<dyn BlobArchive>>) -> Self {
         Self {
             blob_dir,
             blob_cache: Mutex::new(LruMap::new(ByLength::new(max_length))),
             size_tracker: Default::default(),
             file_lock: Default::default(),
             txs_to_delete: Default::default(),
+            archive,
         }
     }
 
@@ -192,6 +214,31 @@ impl DiskFileBlobStoreInner {
         Ok(())
     }
 
+    /// Marks all blobs on disk for deletion, so that they are archived and removed by the next
+    /// [`BlobStore::cleanup`], and tracks their size until then.
+    fn delete_all_deferred(&self) -> Result<(), DiskFileBlobStoreError> {
+        let entries = match fs::read_dir(&self.blob_dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(DiskFileBlobStoreError::Open(self.blob_dir.clone(), err)),
+        };
+
+        let mut txs_to_delete = self.txs_to_delete.write();
+        for entry in entries {
+            let entry =
+                entry.map_err(|err| DiskFileBlobStoreError::Open(self.blob_dir.clone(), err))?;
+            let Some(tx) = entry.file_name().to_str().and_then(|name| B256::from_str(name).ok())
+            else {
+                continue
+            };
+            self.size_tracker.add_size(entry.metadata().map_or(0, |meta| meta.len() as usize));
+            self.size_tracker.inc_len(1);
+            txs_to_delete.insert(tx);
+        }
+        debug!(target:"txpool::blob", blob_dir = ?self.blob_dir, num_blobs = txs_to_delete.len(), "Scheduled blobs of the previous run for archival");
+        Ok(())
+    }
+
     /// Ensures blob is in the blob cache and written to the disk.
     fn insert_one(&self, tx: B256, data: BlobTransactionSidecar) -> Result<(), BlobStoreError> {
         let mut buf = Vec::with_capacity(data.rlp_encoded_fields_length());
@@ -399,6 +446,27 @@ impl DiskFileBlobStoreInner {
         Ok(res)
     }
 
+    /// Retrieves the blobs that are about to be deleted from the blob cache or disk, without
+    /// caching the blobs that are read from disk.
+    fn get_for_archive(&self, txs: &HashSet<B256>) -> Vec<(B256, Arc<BlobTransactionSidecar>)> {
+        let mut res = Vec::with_capacity(txs.len());
+        let mut cache_miss = Vec::new();
+        {
+            let mut cache = self.blob_cache.lock();
+            for tx in txs {
+                if let Some(blob) = cache.peek(tx) {
+                    res.push((*tx, blob.clone()));
+                } else {
+                    cache_miss.push(*tx)
+                }
+            }
+        }
+        res.extend(
+            self.read_many_decoded(cache_miss).into_iter().map(|(tx, data)| (tx, Arc::new(data))),
+        );
+        res
+    }
+
     /// Retrieves blobs for the given transaction hashes from the blob cache or disk.
     ///
     /// Returns an error if there are any missing blobs.
@@ -419,6 +487,7 @@ impl fmt::Debug for DiskFileBlobStoreInner {
             .field("blob_dir", &self.blob_dir)
             .field("cached_blobs", &self.blob_cache.try_lock().map(|lock| lock.len()))
             .field("txs_to_delete", &self.txs_to_delete.try_read())
+            .field("archive", &self.archive)
             .finish()
     }
 }
@@ -457,11 +526,17 @@ pub struct DiskFileBlobStoreConfig {
     pub max_cached_entries: u32,
     /// How to open the blob store.
     pub open: OpenDiskFileBlobStore,
+    /// The archive that blobs are exported to before they are deleted.
+    pub archive: Option<Arc<dyn BlobArchive>>,
 }
 
 impl Default for DiskFileBlobStoreConfig {
     fn default() -> Self {
-        Self { max_cached_entries: DEFAULT_MAX_CACHED_BLOBS, open: Default::default() }
+        Self {
+            max_cached_entries: DEFAULT_MAX_CACHED_BLOBS,
+            open: Default::default(),
+            archive: None,
+        }
     }
 }
 
@@ -471,6 +546,12 @@ impl DiskFileBlobStoreConfig {
         self.max_cached_entries = max_cached_entries;
         self
     }
+
+    /// Set the archive that blobs are exported to before they are deleted.
+    pub fn with_archive(mut self, archive: Arc<dyn BlobArchive>) -> Self {
+        self.archive = Some(archive);
+        self
+    }
 }
 
 /// How to open a disk file blob store.
@@ -675,4 +756,83 @@ mod tests {
         assert_eq!(stat.delete_succeed, 3);
         assert_eq!(stat.delete_failed, 0);
     }
+
+    fn archived_sidecar() -> BlobTransactionSidecar {
+        use alloy_eips::eip4844::{Blob, Bytes48};
+
+        BlobTransactionSidecar {
+            blobs: vec![Blob::repeat_byte(1)],
+            commitments: vec![Bytes48::repeat_byte(2)],
+            proofs: vec![Bytes48::repeat_byte(3)],
+        }
+    }
+
+    #[test]
+    fn disk_cleanup_archives_blobs() {
+        use crate::blobstore::DirBlobArchive;
+
+        let dir = tempfile::tempdir().unwrap();
+        let archive_dir = tempfile::tempdir().unwrap();
+        let archive = Arc::new(DirBlobArchive::open(archive_dir.path()).unwrap());
+        let store = DiskFileBlobStore::open(
+            dir.path(),
+            DiskFileBlobStoreConfig::default().with_archive(archive.clone()),
+        )
+        .unwrap();
+
+        let tx = TxHash::random();
+        let sidecar = archived_sidecar();
+        let versioned_hash = sidecar.versioned_hashes().next().unwrap();
+        store.insert(tx, sidecar).unwrap();
+        store.delete(tx).unwrap();
+        assert_eq!(store.cleanup().delete_succeed, 1);
+        assert!(archive.get(versioned_hash).unwrap().is_some());
+    }
+
+    #[test]
+    fn disk_open_archives_blobs_of_previous_run() {
+        use crate::blobstore::DirBlobArchive;
+
+        let dir = tempfile::tempdir().unwrap();
+        let archive_dir = tempfile::tempdir().unwrap();
+        let archive = Arc::new(DirBlobArchive::open(archive_dir.path()).unwrap());
+        let config = DiskFileBlobStoreConfig::default().with_archive(archive.clone());
+
+        let tx = TxHash::random();
+        let sidecar = archived_sidecar();
+        let versioned_hash = sidecar.versioned_hashes().next().unwrap();
+        DiskFileBlobStore::open(dir.path(), config.clone()).unwrap().insert(tx, sidecar).unwrap();
+
+        // the blob is kept on open and archived by the first cleanup
+        let store = DiskFileBlobStore::open(dir.path(), config).unwrap();
+        assert_eq!(store.blobs_len(), 1);
+        assert!(archive.get(versioned_hash).unwrap().is_none());
+        assert_eq!(store.cleanup().delete_succeed, 1);
+        assert!(archive.get(versioned_hash).unwrap().is_some());
+        assert!(!store.contains(tx).unwrap());
+        assert_eq!(store.blobs_len(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn disk_cleanup_keeps_blobs_that_are_not_archived() {
+        use crate::blobstore::HttpBlobArchive;
+
+        let dir = tempfile::tempdir().unwrap();
+        let store = DiskFileBlobStore::open(
+            dir.path(),
+            DiskFileBlobStoreConfig::default()
+                .with_archive(Arc::new(HttpBlobArchive::new("http://127.0.0.1:0").unwrap())),
+        )
+        .unwrap();
+        let blobs = rng_blobs(1);
+        let tx = blobs[0].0;
+        store.insert_all(blobs).unwrap();
+        store.delete(tx).unwrap();
+
+        // the archive blocks on its requests, so it's used from a blocking task
+        let cleanup_store = store.clone();
+        let stat = tokio::task::spawn_blocking(move || cleanup_store.cleanup()).await.unwrap();
+        assert_eq!(stat.delete_succeed, 0);
+        assert!(store.contains(tx).unwrap());
+    }
 }
@@ -2,9 +2,14 @@
 
 use alloy_eips::eip4844::{BlobAndProofV1, BlobTransactionSidecar};
 use alloy_primitives::B256;
+pub use archive::{
+    ArchivedBlob, BlobArchive, BlobArchiveError, BlobArchiveLocation, DirBlobArchive,
+    HttpBlobArchive,
+};
 pub use disk::{DiskFileBlobStore, DiskFileBlobStoreConfig, OpenDiskFileBlobStore};
 pub use mem::InMemoryBlobStore;
 pub use noop::NoopBlobStore;
+pub use sigv4::AwsCredentials;
 use std::{
     fmt,
     sync::{
@@ -14,9 +19,11 @@ use std::{
 };
 pub use tracker::{BlobStoreCanonTracker, BlobStoreUpdates};
 
+pub mod archive;
 pub mod disk;
 mod mem;
 mod noop;
+mod sigv4;
 mod tracker;
 
 /// A blob store that can be used to store blob data of EIP4844 transactions.
@@ -17,6 +17,9 @@ pub const TXPOOL_SUBPOOL_MAX_TXS_DEFAULT: usize = 10_000;
 /// The default maximum allowed size of the given subpool.
 pub const TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT: usize = 20;
 
+/// The default maximum allowed number of blob transactions in the pending subpool.
+pub const TXPOOL_PENDING_BLOB_MAX_TXS_DEFAULT: usize = 1_000;
+
 /// The default additional validation tasks size.
 pub const DEFAULT_TXPOOL_ADDITIONAL_VALIDATION_TASKS: usize = 1;
 
@@ -42,6 +45,11 @@ pub struct PoolConfig {
     pub queued_limit: SubPoolLimit,
     /// Max number of transactions in the blob sub-pool
     pub blob_limit: SubPoolLimit,
+    /// Max number of blob transactions in the pending sub-pool.
+    ///
+    /// Blob transactions beyond this limit are evicted before any regular pending transaction,
+    /// independent of the [`Self::pending_limit`].
+    pub pending_blob_limit: SubPoolLimit,
     /// Max number of executable transaction slots guaranteed per account
     pub max_account_slots: usize,
     /// Price bump (in %) for the transaction pool underpriced check.
@@ -68,6 +76,8 @@ impl PoolConfig {
     #[inline]
     pub const fn is_exceeded(&self, pool_size: PoolSize) -> bool {
         self.blob_limit.is_exceeded(pool_size.blob, pool_size.blob_size) ||
+            self.pending_blob_limit
+                .is_exceeded(pool_size.pending_blob, pool_size.pending_blob_size) ||
             self.pending_limit.is_exceeded(pool_size.pending, pool_size.pending_size) ||
             self.basefee_limit.is_exceeded(pool_size.basefee, pool_size.basefee_size) ||
             self.queued_limit.is_exceeded(pool_size.queued, pool_size.queued_size)
@@ -81,6 +91,10 @@ impl Default for PoolConfig {
             basefee_limit: Default::default(),
             queued_limit: Default::default(),
             blob_limit: Default::default(),
+            pending_blob_limit: SubPoolLimit::new(
+                TXPOOL_PENDING_BLOB_MAX_TXS_DEFAULT,
+                TXPOOL_SUBPOOL_MAX_SIZE_MB_DEFAULT * 1024 * 1024,
+            ),
             max_account_slots: TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER,
             price_bumps: Default::default(),
             minimal_protocol_basefee: MIN_PROTOCOL_BASE_FEE,
@@ -0,0 +1,422 @@
+//! Helper type that represents one of two possible transaction pool types
+
+use crate::{
+    blobstore::BlobStoreError,
+    error::PoolResult,
+    traits::{BestTransactionsAttributes, GetPooledTransactionLimit, NewBlobSidecar},
+    AllPoolTransactions, AllTransactionsEvents, BestTransactions, BlockInfo, NewTransactionEvent,
+    PoolSize, PoolTransaction, PropagatedTransactions, TransactionEvents, TransactionListenerKind,
+    TransactionOrigin, TransactionPool, ValidPoolTransaction,
+};
+use alloy_eips::eip4844::{BlobAndProofV1, BlobTransactionSidecar};
+use alloy_primitives::{Address, TxHash, B256};
+use reth_eth_wire_types::HandleMempoolData;
+use reth_primitives_traits::Recovered;
+use std::{collections::HashSet, sync::Arc};
+use tokio::sync::mpsc::Receiver;
+
+// re-export Either
+pub use futures_util::future::Either;
+
+impl<L, R> TransactionPool for Either<L, R>
+where
+    L: TransactionPool,
+    R: TransactionPool<Transaction = L::Transaction>,
+{
+    type Transaction = L::Transaction;
+
+    fn pool_size(&self) -> PoolSize {
+        match self {
+            Self::Left(pool) => pool.pool_size(),
+            Self::Right(pool) => pool.pool_size(),
+        }
+    }
+
+    fn block_info(&self) -> BlockInfo {
+        match self {
+            Self::Left(pool) => pool.block_info(),
+            Self::Right(pool) => pool.block_info(),
+        }
+    }
+
+    async fn add_transaction_and_subscribe(
+        &self,
+        origin: TransactionOrigin,
+        transaction: Self::Transaction,
+    ) -> PoolResult<TransactionEvents> {
+        match self {
+            Self::Left(pool) => pool.add_transaction_and_subscribe(origin, transaction).await,
+            Self::Right(pool) => pool.add_transaction_and_subscribe(origin, transaction).await,
+        }
+    }
+
+    async fn add_transaction(
+        &self,
+        origin: TransactionOrigin,
+        transaction: Self::Transaction,
+    ) -> PoolResult<TxHash> {
+        match self {
+            Self::Left(pool) => pool.add_transaction(origin, transaction).await,
+            Self::Right(pool) => pool.add_transaction(origin, transaction).await,
+        }
+    }
+
+    async fn add_transactions(
+        &self,
+        origin: TransactionOrigin,
+        transactions: Vec<Self::Transaction>,
+    ) -> Vec<PoolResult<TxHash>> {
+        match self {
+            Self::Left(pool) => pool.add_transactions(origin, transactions).await,
+            Self::Right(pool) => pool.add_transactions(origin, transactions).await,
+        }
+    }
+
+    fn transaction_event_listener(&self, tx_hash: TxHash) -> Option<TransactionEvents> {
+        match self {
+            Self::Left(pool) => pool.transaction_event_listener(tx_hash),
+            Self::Right(pool) => pool.transaction_event_listener(tx_hash),
+        }
+    }
+
+    fn all_transactions_event_listener(&self) -> AllTransactionsEvents<Self::Transaction> {
+        match self {
+            Self::Left(pool) => pool.all_transactions_event_listener(),
+            Self::Right(pool) => pool.all_transactions_event_listener(),
+        }
+    }
+
+    fn pending_transactions_listener_for(&self, kind: TransactionListenerKind) -> Receiver<TxHash> {
+        match self {
+            Self::Left(pool) => pool.pending_transactions_listener_for(kind),
+            Self::Right(pool) => pool.pending_transactions_listener_for(kind),
+        }
+    }
+
+    fn blob_transaction_sidecars_listener(&self) -> Receiver<NewBlobSidecar> {
+        match self {
+            Self::Left(pool) => pool.blob_transaction_sidecars_listener(),
+            Self::Right(pool) => pool.blob_transaction_sidecars_listener(),
+        }
+    }
+
+    fn new_transactions_listener_for(
+        &self,
+        kind: TransactionListenerKind,
+    ) -> Receiver<NewTransactionEvent<Self::Transaction>> {
+        match self {
+            Self::Left(pool) => pool.new_transactions_listener_for(kind),
+            Self::Right(pool) => pool.new_transactions_listener_for(kind),
+        }
+    }
+
+    fn pooled_transaction_hashes(&self) -> Vec<TxHash> {
+        match self {
+            Self::Left(pool) => pool.pooled_transaction_hashes(),
+            Self::Right(pool) => pool.pooled_transaction_hashes(),
+        }
+    }
+
+    fn pooled_transaction_hashes_max(&self, max: usize) -> Vec<TxHash> {
+        match self {
+            Self::Left(pool) => pool.pooled_transaction_hashes_max(max),
+            Self::Right(pool) => pool.pooled_transaction_hashes_max(max),
+        }
+    }
+
+    fn pooled_transactions(&self) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.pooled_transactions(),
+            Self::Right(pool) => pool.pooled_transactions(),
+        }
+    }
+
+    fn pooled_transactions_max(
+        &self,
+        max: usize,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.pooled_transactions_max(max),
+            Self::Right(pool) => pool.pooled_transactions_max(max),
+        }
+    }
+
+    fn get_pooled_transaction_elements(
+        &self,
+        tx_hashes: Vec<TxHash>,
+        limit: GetPooledTransactionLimit,
+    ) -> Vec<<Self::Transaction as PoolTransaction>::Pooled> {
+        match self {
+            Self::Left(pool) => pool.get_pooled_transaction_elements(tx_hashes, limit),
+            Self::Right(pool) => pool.get_pooled_transaction_elements(tx_hashes, limit),
+        }
+    }
+
+    fn get_pooled_transaction_element(
+        &self,
+        tx_hash: TxHash,
+    ) -> Option<Recovered<<Self::Transaction as PoolTransaction>::Pooled>> {
+        match self {
+            Self::Left(pool) => pool.get_pooled_transaction_element(tx_hash),
+            Self::Right(pool) => pool.get_pooled_transaction_element(tx_hash),
+        }
+    }
+
+    fn best_transactions(
+        &self,
+    ) -> Box<dyn BestTransactions<Item = Arc<ValidPoolTransaction<Self::Transaction>>>> {
+        match self {
+            Self::Left(pool) => pool.best_transactions(),
+            Self::Right(pool) => pool.best_transactions(),
+        }
+    }
+
+    fn best_transactions_with_attributes(
+        &self,
+        best_transactions_attributes: BestTransactionsAttributes,
+    ) -> Box<dyn BestTransactions<Item = Arc<ValidPoolTransaction<Self::Transaction>>>> {
+        match self {
+            Self::Left(pool) => {
+                pool.best_transactions_with_attributes(best_transactions_attributes)
+            }
+            Self::Right(pool) => {
+                pool.best_transactions_with_attributes(best_transactions_attributes)
+            }
+        }
+    }
+
+    fn pending_transactions(&self) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.pending_transactions(),
+            Self::Right(pool) => pool.pending_transactions(),
+        }
+    }
+
+    fn pending_transactions_max(
+        &self,
+        max: usize,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.pending_transactions_max(max),
+            Self::Right(pool) => pool.pending_transactions_max(max),
+        }
+    }
+
+    fn queued_transactions(&self) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.queued_transactions(),
+            Self::Right(pool) => pool.queued_transactions(),
+        }
+    }
+
+    fn all_transactions(&self) -> AllPoolTransactions<Self::Transaction> {
+        match self {
+            Self::Left(pool) => pool.all_transactions(),
+            Self::Right(pool) => pool.all_transactions(),
+        }
+    }
+
+    fn remove_transactions(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.remove_transactions(hashes),
+            Self::Right(pool) => pool.remove_transactions(hashes),
+        }
+    }
+
+    fn remove_transactions_and_descendants(
+        &self,
+        hashes: Vec<TxHash>,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.remove_transactions_and_descendants(hashes),
+            Self::Right(pool) => pool.remove_transactions_and_descendants(hashes),
+        }
+    }
+
+    fn remove_transactions_by_sender(
+        &self,
+        sender: Address,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.remove_transactions_by_sender(sender),
+            Self::Right(pool) => pool.remove_transactions_by_sender(sender),
+        }
+    }
+
+    fn retain_unknown<A>(&self, announcement: &mut A)
+    where
+        A: HandleMempoolData,
+    {
+        match self {
+            Self::Left(pool) => pool.retain_unknown(announcement),
+            Self::Right(pool) => pool.retain_unknown(announcement),
+        }
+    }
+
+    fn get(&self, tx_hash: &TxHash) -> Option<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get(tx_hash),
+            Self::Right(pool) => pool.get(tx_hash),
+        }
+    }
+
+    fn get_all(&self, txs: Vec<TxHash>) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get_all(txs),
+            Self::Right(pool) => pool.get_all(txs),
+        }
+    }
+
+    fn on_propagated(&self, txs: PropagatedTransactions) {
+        match self {
+            Self::Left(pool) => pool.on_propagated(txs),
+            Self::Right(pool) => pool.on_propagated(txs),
+        }
+    }
+
+    fn get_transactions_by_sender(
+        &self,
+        sender: Address,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get_transactions_by_sender(sender),
+            Self::Right(pool) => pool.get_transactions_by_sender(sender),
+        }
+    }
+
+    fn get_pending_transactions_with_predicate(
+        &self,
+        predicate: impl FnMut(&ValidPoolTransaction<Self::Transaction>) -> bool,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get_pending_transactions_with_predicate(predicate),
+            Self::Right(pool) => pool.get_pending_transactions_with_predicate(predicate),
+        }
+    }
+
+    fn get_pending_transactions_by_sender(
+        &self,
+        sender: Address,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get_pending_transactions_by_sender(sender),
+            Self::Right(pool) => pool.get_pending_transactions_by_sender(sender),
+        }
+    }
+
+    fn get_queued_transactions_by_sender(
+        &self,
+        sender: Address,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get_queued_transactions_by_sender(sender),
+            Self::Right(pool) => pool.get_queued_transactions_by_sender(sender),
+        }
+    }
+
+    fn get_highest_transaction_by_sender(
+        &self,
+        sender: Address,
+    ) -> Option<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get_highest_transaction_by_sender(sender),
+            Self::Right(pool) => pool.get_highest_transaction_by_sender(sender),
+        }
+    }
+
+    fn get_highest_consecutive_transaction_by_sender(
+        &self,
+        sender: Address,
+        on_chain_nonce: u64,
+    ) -> Option<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => {
+                pool.get_highest_consecutive_transaction_by_sender(sender, on_chain_nonce)
+            }
+            Self::Right(pool) => {
+                pool.get_highest_consecutive_transaction_by_sender(sender, on_chain_nonce)
+            }
+        }
+    }
+
+    fn get_transaction_by_sender_and_nonce(
+        &self,
+        sender: Address,
+        nonce: u64,
+    ) -> Option<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get_transaction_by_sender_and_nonce(sender, nonce),
+            Self::Right(pool) => pool.get_transaction_by_sender_and_nonce(sender, nonce),
+        }
+    }
+
+    fn get_transactions_by_origin(
+        &self,
+        origin: TransactionOrigin,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get_transactions_by_origin(origin),
+            Self::Right(pool) => pool.get_transactions_by_origin(origin),
+        }
+    }
+
+    fn get_pending_transactions_by_origin(
+        &self,
+        origin: TransactionOrigin,
+    ) -> Vec<Arc<ValidPoolTransaction<Self::Transaction>>> {
+        match self {
+            Self::Left(pool) => pool.get_pending_transactions_by_origin(origin),
+            Self::Right(pool) => pool.get_pending_transactions_by_origin(origin),
+        }
+    }
+
+    fn unique_senders(&self) -> HashSet<Address> {
+        match self {
+            Self::Left(pool) => pool.unique_senders(),
+            Self::Right(pool) => pool.unique_senders(),
+        }
+    }
+
+    fn get_blob(
+        &self,
+        tx_hash: TxHash,
+    ) -> Result<Option<Arc<BlobTransactionSidecar>>, BlobStoreError> {
+        match self {
+            Self::Left(pool) => pool.get_blob(tx_hash),
+            Self::Right(pool) => pool.get_blob(tx_hash),
+        }
+    }
+
+    fn get_all_blobs(
+        &self,
+        tx_hashes: Vec<TxHash>,
+    ) -> Result<Vec<(TxHash, Arc<BlobTransactionSidecar>)>, BlobStoreError> {
+        match self {
+            Self::Left(pool) => pool.get_all_blobs(tx_hashes),
+            Self::Right(pool) => pool.get_all_blobs(tx_hashes),
+        }
+    }
+
+    fn get_all_blobs_exact(
+        &self,
+        tx_hashes: Vec<TxHash>,
+    ) -> Result<Vec<Arc<BlobTransactionSidecar>>, BlobStoreError> {
+        match self {
+            Self::Left(pool) => pool.get_all_blobs_exact(tx_hashes),
+            Self::Right(pool) => pool.get_all_blobs_exact(tx_hashes),
+        }
+    }
+
+    fn get_blobs_for_versioned_hashes(
+        &self,
+        versioned_hashes: &[B256],
+    ) -> Result<Vec<Option<BlobAndProofV1>>, BlobStoreError> {
+        match self {
+            Self::Left(pool) => pool.get_blobs_for_versioned_hashes(versioned_hashes),
+            Self::Right(pool) => pool.get_blobs_for_versioned_hashes(versioned_hashes),
+        }
+    }
+}
@@ -20,6 +20,10 @@ pub struct TxPoolMetrics {
     pub(crate) pending_pool_transactions: Gauge,
     /// Total amount of memory used by the transactions in the pending sub-pool in bytes
     pub(crate) pending_pool_size_bytes: Gauge,
+    /// Number of blob transactions in the pending sub-pool
+    pub(crate) pending_pool_blob_transactions: Gauge,
+    /// Total amount of memory used by the blob transactions in the pending sub-pool in bytes
+    pub(crate) pending_pool_blob_size_bytes: Gauge,
 
     /// Number of transactions in the basefee sub-pool
     pub(crate) basefee_pool_transactions: Gauge,
@@ -58,8 +62,98 @@ pub struct TxPoolMetrics {
     pub(crate) basefee_transactions_evicted: Counter,
     /// Counter for the number of blob transactions evicted
     pub(crate) blob_transactions_evicted: Counter,
+    /// Counter for the number of pending blob transactions evicted because of the pending blob
+    /// limit
+    pub(crate) pending_blob_transactions_evicted: Counter,
+    /// Counter for the number of blob transactions moved to the pending sub-pool because the blob
+    /// fee decreased
+    pub(crate) blob_transactions_promoted: Counter,
+    /// Counter for the number of blob transactions moved out of the pending sub-pool because the
+    /// blob fee increased
+    pub(crate) blob_transactions_demoted: Counter,
     /// Counter for the number of queued transactions evicted
     pub(crate) queued_transactions_evicted: Counter,
+
+    /// Counter for the number of transactions moved to a better sub-pool, e.g. from the basefee
+    /// to the pending sub-pool
+    pub(crate) promoted_transactions: Counter,
+    /// Counter for the number of transactions moved to a worse sub-pool, e.g. from the pending to
+    /// the basefee sub-pool
+    pub(crate) demoted_transactions: Counter,
+}
+
+/// Fee distribution metrics of a single sub-pool, labeled by the sub-pool.
+///
+/// The distributions are estimated from a sample of the transactions of the pool. The priority fee
+/// is the max priority fee per gas, or the gas price of legacy transactions.
+#[derive(Metrics)]
+#[metrics(scope = "transaction_pool.subpool")]
+pub struct SubPoolFeeMetrics {
+    /// 10th percentile of the priority fees of the transactions in the sub-pool
+    pub(crate) priority_fee_p10: Gauge,
+    /// Median of the priority fees of the transactions in the sub-pool
+    pub(crate) priority_fee_p50: Gauge,
+    /// 90th percentile of the priority fees of the transactions in the sub-pool
+    pub(crate) priority_fee_p90: Gauge,
+    /// 99th percentile of the priority fees of the transactions in the sub-pool
+    pub(crate) priority_fee_p99: Gauge,
+    /// 10th percentile of the max fees per blob gas of the blob transactions in the sub-pool
+    pub(crate) blob_fee_p10: Gauge,
+    /// Median of the max fees per blob gas of the blob transactions in the sub-pool
+    pub(crate) blob_fee_p50: Gauge,
+    /// 90th percentile of the max fees per blob gas of the blob transactions in the sub-pool
+    pub(crate) blob_fee_p90: Gauge,
+    /// 99th percentile of the max fees per blob gas of the blob transactions in the sub-pool
+    pub(crate) blob_fee_p99: Gauge,
+}
+
+impl SubPoolFeeMetrics {
+    /// Sets the fee distributions of the sub-pool.
+    ///
+    /// Empty distributions are reported as zero.
+    pub(crate) fn set_fees(&self, priority_fees: &mut [u128], blob_fees: &mut [u128]) {
+        priority_fees.sort_unstable();
+        self.priority_fee_p10.set(percentile(priority_fees, 10) as f64);
+        self.priority_fee_p50.set(percentile(priority_fees, 50) as f64);
+        self.priority_fee_p90.set(percentile(priority_fees, 90) as f64);
+        self.priority_fee_p99.set(percentile(priority_fees, 99) as f64);
+
+        blob_fees.sort_unstable();
+        self.blob_fee_p10.set(percentile(blob_fees, 10) as f64);
+        self.blob_fee_p50.set(percentile(blob_fees, 50) as f64);
+        self.blob_fee_p90.set(percentile(blob_fees, 90) as f64);
+        self.blob_fee_p99.set(percentile(blob_fees, 99) as f64);
+    }
+}
+
+/// Fee distribution metrics of all sub-pools.
+#[derive(Debug)]
+pub struct SubPoolsFeeMetrics {
+    pub(crate) pending: SubPoolFeeMetrics,
+    pub(crate) basefee: SubPoolFeeMetrics,
+    pub(crate) queued: SubPoolFeeMetrics,
+    pub(crate) blob: SubPoolFeeMetrics,
+}
+
+impl Default for SubPoolsFeeMetrics {
+    fn default() -> Self {
+        let metrics =
+            |subpool: &'static str| SubPoolFeeMetrics::new_with_labels(&[("subpool", subpool)]);
+        Self {
+            pending: metrics("pending"),
+            basefee: metrics("basefee"),
+            queued: metrics("queued"),
+            blob: metrics("blob"),
+        }
+    }
+}
+
+/// Returns the nearest-rank percentile of the sorted values, or zero if there are none.
+fn percentile(sorted: &[u128], percentile: usize) -> u128 {
+    if sorted.is_empty() {
+        return 0
+    }
+    sorted[(sorted.len() * percentile).div_ceil(100).saturating_sub(1)]
 }
 
 /// Transaction pool blobstore metrics
@@ -132,3 +226,19 @@ pub struct AllTransactionsMetrics {
     /// The current base fee
     pub(crate) base_fee: Gauge,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_rank_percentile() {
+        assert_eq!(percentile(&[], 50), 0);
+        assert_eq!(percentile(&[7], 99), 7);
+
+        let values = (1..=100).collect::<Vec<_>>();
+        assert_eq!(percentile(&values, 10), 10);
+        assert_eq!(percentile(&values, 50), 50);
+        assert_eq!(percentile(&values, 99), 99);
+    }
+}
@@ -30,12 +30,28 @@ use tokio::sync::{mpsc, mpsc::Receiver};
 ///
 /// All transactions are rejected and no events are emitted.
 /// This type will never hold any transactions and is only useful for wiring components together.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
-pub struct NoopTransactionPool;
+pub struct NoopTransactionPool<T = EthPooledTransaction> {
+    /// Marker for the transaction type.
+    _marker: PhantomData<T>,
+}
+
+impl<T> NoopTransactionPool<T> {
+    /// Creates a new [`NoopTransactionPool`] for transactions of type `T`.
+    pub fn new() -> Self {
+        Self { _marker: Default::default() }
+    }
+}
 
-impl TransactionPool for NoopTransactionPool {
-    type Transaction = EthPooledTransaction;
+impl Default for NoopTransactionPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: EthPoolTransaction> TransactionPool for NoopTransactionPool<T> {
+    type Transaction = T;
 
     fn pool_size(&self) -> PoolSize {
         Default::default()
@@ -366,17 +382,17 @@ impl<T> Default for MockTransactionValidator<T> {
 /// An error that contains the transaction that failed to be inserted into the noop pool.
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("can't insert transaction into the noop pool that does nothing")]
-pub struct NoopInsertError {
-    tx: EthPooledTransaction,
+pub struct NoopInsertError<T = EthPooledTransaction> {
+    tx: T,
 }
 
-impl NoopInsertError {
-    const fn new(tx: EthPooledTransaction) -> Self {
+impl<T> NoopInsertError<T> {
+    const fn new(tx: T) -> Self {
         Self { tx }
     }
 
     /// Returns the transaction that failed to be inserted.
-    pub fn into_inner(self) -> EthPooledTransaction {
+    pub fn into_inner(self) -> T {
         self.tx
     }
 }
@@ -10,7 +10,7 @@ use alloy_primitives::Address;
 use core::fmt;
 use reth_primitives_traits::transaction::error::InvalidTransactionError;
 use std::{
-    collections::{BTreeMap, BTreeSet, HashSet, VecDeque},
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
     sync::Arc,
 };
 use tokio::sync::broadcast::{error::TryRecvError, Receiver};
@@ -96,6 +96,10 @@ pub struct BestTransactions<T: TransactionOrdering> {
     /// These new pending transactions are inserted into this iterator's pool before yielding the
     /// next value
     pub(crate) new_transaction_receiver: Option<Receiver<PendingTransaction<T>>>,
+    /// The nonce of the last transaction that was yielded for each sender.
+    ///
+    /// New transactions that replace an already yielded transaction are ignored.
+    pub(crate) yielded: HashMap<SenderId, u64>,
     /// Flag to control whether to skip blob transactions (EIP4844).
     pub(crate) skip_blobs: bool,
 }
@@ -151,10 +155,21 @@ impl<T: TransactionOrdering> BestTransactions<T> {
 
     /// Checks for new transactions that have come into the `PendingPool` after this iterator was
     /// created and inserts them
+    ///
+    /// A new transaction with the same id as a transaction of this iterator replaced it in the
+    /// pool, so the replaced transaction is dropped. Replacements of transactions that were already
+    /// yielded are ignored.
     fn add_new_transactions(&mut self) {
         while let Some(pending_tx) = self.try_recv() {
-            //  same logic as PendingPool::add_transaction/PendingPool::best_with_unlocked
             let tx_id = *pending_tx.transaction.id();
+            if self.yielded.get(&tx_id.sender).is_some_and(|nonce| tx_id.nonce <= *nonce) {
+                continue
+            }
+            if let Some(replaced) = self.all.remove(&tx_id) {
+                self.independent.remove(&replaced);
+            }
+
+            //  same logic as PendingPool::add_transaction/PendingPool::best_with_unlocked
             if self.ancestor(&tx_id).is_none() {
                 self.independent.insert(pending_tx.clone());
             }
@@ -205,6 +220,7 @@ impl<T: TransactionOrdering> Iterator for BestTransactions<T> {
             if let Some(unlocked) = self.all.get(&best.unlocks()) {
                 self.independent.insert(unlocked.clone());
             }
+            self.yielded.insert(sender_id, best.transaction.nonce());
 
             if self.skip_blobs && best.transaction.transaction.is_eip4844() {
                 // blobs should be skipped, marking them as invalid will ensure that no dependent
@@ -921,5 +937,44 @@ mod tests {
         assert!(best.new_transaction_receiver.is_none());
     }
 
-    // TODO: Same nonce test
+    #[test]
+    fn test_best_add_transaction_replacement() {
+        let mut pool = PendingPool::new(MockOrdering::default());
+        let mut f = MockTransactionFactory::default();
+
+        let tx = MockTransaction::eip1559();
+        for nonce in 0..3 {
+            let tx = tx.clone().rng_hash().with_nonce(nonce);
+            pool.add_transaction(Arc::new(f.validated(tx)), 0);
+        }
+
+        let mut best = pool.best();
+        let (tx_sender, tx_receiver) =
+            tokio::sync::broadcast::channel::<PendingTransaction<MockOrdering>>(1000);
+        best.new_transaction_receiver = Some(tx_receiver);
+
+        let first = best.next().unwrap();
+        assert_eq!(first.nonce(), 0);
+
+        // replacements of the yielded and the next transaction
+        let mut submission_id = 10;
+        let mut replacement = |nonce: u64| {
+            submission_id += 1;
+            PendingTransaction {
+                submission_id,
+                transaction: Arc::new(f.validated(tx.clone().rng_hash().with_nonce(nonce))),
+                priority: Priority::Value(U256::from(1000)),
+            }
+        };
+        let yielded_replacement = replacement(0);
+        let next_replacement = replacement(1);
+        tx_sender.send(yielded_replacement).unwrap();
+        tx_sender.send(next_replacement.clone()).unwrap();
+
+        // the replacement is returned instead of the replaced transaction
+        let next = best.next().unwrap();
+        assert_eq!(next.hash(), next_replacement.transaction.hash());
+        assert_eq!(best.next().unwrap().nonce(), 2);
+        assert!(best.next().is_none());
+    }
 }
@@ -45,6 +45,10 @@ pub struct PendingPool<T: TransactionOrdering> {
     ///
     /// See also [`reth_primitives_traits::InMemorySize::size`].
     size_of: SizeTracker,
+    /// Number of blob transactions in this pool.
+    blob_count: usize,
+    /// Keeps track of the size of the blob transactions in this pool.
+    blob_size_of: SizeTracker,
     /// Used to broadcast new transactions that have been added to the `PendingPool` to existing
     /// `static_files` of this pool.
     new_transaction_notifier: broadcast::Sender<PendingTransaction<T>>,
@@ -63,6 +67,8 @@ impl<T: TransactionOrdering> PendingPool<T> {
             independent_transactions: Default::default(),
             highest_nonces: Default::default(),
             size_of: Default::default(),
+            blob_count: 0,
+            blob_size_of: Default::default(),
             new_transaction_notifier,
         }
     }
@@ -77,6 +83,8 @@ impl<T: TransactionOrdering> PendingPool<T> {
         self.independent_transactions.clear();
         self.highest_nonces.clear();
         self.size_of.reset();
+        self.blob_count = 0;
+        self.blob_size_of.reset();
         std::mem::take(&mut self.by_id)
     }
 
@@ -104,6 +112,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
             independent: self.independent_transactions.values().cloned().collect(),
             invalid: Default::default(),
             new_transaction_receiver: Some(self.new_transaction_notifier.subscribe()),
+            yielded: Default::default(),
             skip_blobs: false,
         }
     }
@@ -189,7 +198,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
                     transactions_iter.next();
                 }
             } else {
-                self.size_of += tx.transaction.size();
+                self.track_added(&tx.transaction);
                 self.update_independents_and_highest_nonces(&tx);
                 self.by_id.insert(id, tx);
             }
@@ -234,7 +243,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
                 // Re-insert the transaction with new priority.
                 tx.priority = self.ordering.priority(&tx.transaction.transaction, base_fee);
 
-                self.size_of += tx.transaction.size();
+                self.track_added(&tx.transaction);
                 self.update_independents_and_highest_nonces(&tx);
                 self.by_id.insert(id, tx);
             }
@@ -293,7 +302,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
         );
 
         // keep track of size
-        self.size_of += tx.size();
+        self.track_added(&tx);
 
         let tx_id = *tx.id();
 
@@ -330,7 +339,7 @@ impl<T: TransactionOrdering> PendingPool<T> {
         }
 
         let tx = self.by_id.remove(id)?;
-        self.size_of -= tx.transaction.size();
+        self.track_removed(&tx.transaction);
 
         if let Some(highest) = self.highest_nonces.get(&id.sender) {
             if highest.transaction.nonce() == id.nonce {
@@ -343,6 +352,24 @@ impl<T: TransactionOrdering> PendingPool<T> {
         Some(tx.transaction)
     }
 
+    /// Keeps track of the size of a transaction that is added to the pool.
+    fn track_added(&mut self, tx: &ValidPoolTransaction<T::Transaction>) {
+        self.size_of += tx.size();
+        if tx.is_eip4844() {
+            self.blob_count += 1;
+            self.blob_size_of += tx.size();
+        }
+    }
+
+    /// Keeps track of the size of a transaction that is removed from the pool.
+    fn track_removed(&mut self, tx: &ValidPoolTransaction<T::Transaction>) {
+        self.size_of -= tx.size();
+        if tx.is_eip4844() {
+            self.blob_count -= 1;
+            self.blob_size_of -= tx.size();
+        }
+    }
+
     fn next_id(&mut self) -> u64 {
         let id = self.submission_id;
         self.submission_id = self.submission_id.wrapping_add(1);
@@ -487,12 +514,58 @@ impl<T: TransactionOrdering> PendingPool<T> {
         removed
     }
 
+    /// Removes blob transactions until the blob transactions in the pool are within the given
+    /// [`SubPoolLimit`], independent of the regular transactions in the pool.
+    ///
+    /// The highest-nonce blob transaction with the lowest priority is removed first, so removed
+    /// transactions never have descendants in this pool. Local transactions are only removed if
+    /// the limit can't be met otherwise. The removed transactions are returned.
+    pub(crate) fn truncate_blob_transactions(
+        &mut self,
+        limit: &SubPoolLimit,
+    ) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
+        let mut removed = Vec::new();
+        for remove_locals in [false, true] {
+            while self.exceeds_blob_limit(limit) {
+                let Some(id) = self
+                    .highest_nonces
+                    .values()
+                    .filter(|tx| {
+                        tx.transaction.is_eip4844() && (remove_locals || !tx.transaction.is_local())
+                    })
+                    .min()
+                    .map(|tx| *tx.transaction.id())
+                else {
+                    break
+                };
+                removed.extend(self.remove_transaction(&id));
+            }
+        }
+        removed
+    }
+
     /// Returns true if the pool exceeds the given limit
     #[inline]
     pub(crate) fn exceeds(&self, limit: &SubPoolLimit) -> bool {
         limit.is_exceeded(self.len(), self.size())
     }
 
+    /// Returns true if the blob transactions in the pool exceed the given limit
+    #[inline]
+    pub(crate) fn exceeds_blob_limit(&self, limit: &SubPoolLimit) -> bool {
+        limit.is_exceeded(self.blob_len(), self.blob_size())
+    }
+
+    /// The reported size of all blob transactions in this pool.
+    pub(crate) fn blob_size(&self) -> usize {
+        self.blob_size_of.into()
+    }
+
+    /// Number of blob transactions in this pool.
+    pub(crate) const fn blob_len(&self) -> usize {
+        self.blob_count
+    }
+
     /// The reported size of all transactions in this pool.
     pub(crate) fn size(&self) -> usize {
         self.size_of.into()
@@ -698,6 +771,29 @@ mod tests {
         assert_eq!(removed[0].hash(), t.hash());
     }
 
+    #[test]
+    fn truncate_blob_transactions() {
+        let mut f = MockTransactionFactory::default();
+        let mut pool = PendingPool::new(MockOrdering::default());
+
+        let regular = MockTransaction::eip1559();
+        pool.add_transaction(f.validated_arc(regular.clone()), 0);
+        let blob = MockTransaction::eip4844();
+        pool.add_transaction(f.validated_arc(blob.clone()), 0);
+        let better_blob = MockTransaction::eip4844().inc_price_by(10);
+        pool.add_transaction(f.validated_arc(better_blob), 0);
+        assert_eq!(pool.blob_len(), 2);
+
+        // only the worst blob transaction is evicted, regardless of the regular transaction
+        let limit = SubPoolLimit { max_txs: 1, max_size: usize::MAX };
+        let removed = pool.truncate_blob_transactions(&limit);
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].hash(), blob.hash());
+        assert_eq!(pool.blob_len(), 1);
+        assert!(pool.contains(f.validated(regular).id()));
+        assert_eq!(pool.len(), 2);
+    }
+
     #[test]
     fn correct_independent_descendants() {
         // this test ensures that we set the right highest nonces set for each sender
@@ -4,7 +4,7 @@ use crate::{
     config::{LocalTransactionConfig, TXPOOL_MAX_ACCOUNT_SLOTS_PER_SENDER},
     error::{Eip4844PoolTransactionError, InvalidPoolTransactionError, PoolError, PoolErrorKind},
     identifier::{SenderId, TransactionId},
-    metrics::{AllTransactionsMetrics, TxPoolMetrics},
+    metrics::{AllTransactionsMetrics, SubPoolsFeeMetrics, TxPoolMetrics},
     pool::{
         best::BestTransactions,
         blob::BlobTransactions,
@@ -39,6 +39,10 @@ use std::{
 };
 use tracing::trace;
 
+/// Maximum number of transactions that are sampled for the fee distribution metrics of the
+/// sub-pools.
+const FEE_METRICS_SAMPLE_SIZE: usize = 1_000;
+
 #[cfg_attr(doc, aquamarine::aquamarine)]
 // TODO: Inlined diagram due to a bug in aquamarine library, should become an include when it's
 // fixed. See https://github.com/mersinvald/aquamarine/issues/50
@@ -113,6 +117,8 @@ pub struct TxPool<T: TransactionOrdering> {
     all_transactions: AllTransactions<T::Transaction>,
     /// Transaction pool metrics
     metrics: TxPoolMetrics,
+    /// Fee distribution metrics of the sub-pools
+    fee_metrics: SubPoolsFeeMetrics,
     /// The last update kind that was applied to the pool.
     latest_update_kind: Option<PoolUpdateKind>,
 }
@@ -131,6 +137,7 @@ impl<T: TransactionOrdering> TxPool<T> {
             all_transactions: AllTransactions::new(&config),
             config,
             metrics: Default::default(),
+            fee_metrics: Default::default(),
             latest_update_kind: None,
         }
     }
@@ -193,6 +200,8 @@ impl<T: TransactionOrdering> TxPool<T> {
         PoolSize {
             pending: self.pending_pool.len(),
             pending_size: self.pending_pool.size(),
+            pending_blob: self.pending_pool.blob_len(),
+            pending_blob_size: self.pending_pool.blob_size(),
             basefee: self.basefee_pool.len(),
             basefee_size: self.basefee_pool.size(),
             queued: self.queued_pool.len(),
@@ -226,6 +235,8 @@ impl<T: TransactionOrdering> TxPool<T> {
                 // increased blob fee: recheck pending pool and remove all that are no longer valid
                 let removed =
                     self.pending_pool.update_blob_fee(self.all_transactions.pending_fees.blob_fee);
+                self.metrics.blob_transactions_demoted.increment(removed.len() as u64);
+                self.metrics.demoted_transactions.increment(removed.len() as u64);
                 for tx in removed {
                     let to = {
                         let tx =
@@ -243,6 +254,8 @@ impl<T: TransactionOrdering> TxPool<T> {
                 // decreased blob/base fee: recheck blob pool and promote all that are now valid
                 let removed =
                     self.blob_pool.enforce_pending_fees(&self.all_transactions.pending_fees);
+                self.metrics.blob_transactions_promoted.increment(removed.len() as u64);
+                self.metrics.promoted_transactions.increment(removed.len() as u64);
                 for tx in removed {
                     let to = {
                         let tx =
@@ -273,6 +286,7 @@ impl<T: TransactionOrdering> TxPool<T> {
                 // increased base fee: recheck pending pool and remove all that are no longer valid
                 let removed =
                     self.pending_pool.update_base_fee(self.all_transactions.pending_fees.base_fee);
+                self.metrics.demoted_transactions.increment(removed.len() as u64);
                 for tx in removed {
                     let to = {
                         let tx =
@@ -290,6 +304,7 @@ impl<T: TransactionOrdering> TxPool<T> {
                 // decreased base fee: recheck basefee pool and promote all that are now valid
                 let removed =
                     self.basefee_pool.enforce_basefee(self.all_transactions.pending_fees.base_fee);
+                self.metrics.promoted_transactions.increment(removed.len() as u64);
                 for tx in removed {
                     let to = {
                         let tx =
@@ -541,6 +556,7 @@ impl<T: TransactionOrdering> TxPool<T> {
         let UpdateOutcome { promoted, discarded } = self.update_accounts(changed_senders);
 
         self.update_transaction_type_metrics();
+        self.update_fee_metrics();
         self.metrics.performed_state_updates.increment(1);
 
         // Update the latest update kind
@@ -554,6 +570,8 @@ impl<T: TransactionOrdering> TxPool<T> {
         let stats = self.size();
         self.metrics.pending_pool_transactions.set(stats.pending as f64);
         self.metrics.pending_pool_size_bytes.set(stats.pending_size as f64);
+        self.metrics.pending_pool_blob_transactions.set(stats.pending_blob as f64);
+        self.metrics.pending_pool_blob_size_bytes.set(stats.pending_blob_size as f64);
         self.metrics.basefee_pool_transactions.set(stats.basefee as f64);
         self.metrics.basefee_pool_size_bytes.set(stats.basefee_size as f64);
         self.metrics.queued_pool_transactions.set(stats.queued as f64);
@@ -589,6 +607,34 @@ impl<T: TransactionOrdering> TxPool<T> {
         self.metrics.total_eip7702_transactions.set(eip7702_count as f64);
     }
 
+    /// Updates the priority fee and blob fee distribution metrics of the sub-pools.
+    ///
+    /// The distributions are estimated from an evenly spaced sample of at most
+    /// [`FEE_METRICS_SAMPLE_SIZE`] transactions, which bounds the work done under the pool lock.
+    pub(crate) fn update_fee_metrics(&self) {
+        let mut priority_fees: [Vec<u128>; 4] = Default::default();
+        let mut blob_fees: [Vec<u128>; 4] = Default::default();
+
+        let step = self.all_transactions.txs.len().div_ceil(FEE_METRICS_SAMPLE_SIZE).max(1);
+        for tx in self.all_transactions.txs.values().step_by(step) {
+            let subpool = tx.subpool as usize;
+            priority_fees[subpool].push(tx.transaction.priority_fee_or_price());
+            if let Some(blob_fee) = tx.transaction.max_fee_per_blob_gas() {
+                blob_fees[subpool].push(blob_fee);
+            }
+        }
+
+        for (subpool, metrics) in [
+            (SubPool::Queued, &self.fee_metrics.queued),
+            (SubPool::BaseFee, &self.fee_metrics.basefee),
+            (SubPool::Blob, &self.fee_metrics.blob),
+            (SubPool::Pending, &self.fee_metrics.pending),
+        ] {
+            let subpool = subpool as usize;
+            metrics.set_fees(&mut priority_fees[subpool], &mut blob_fees[subpool]);
+        }
+    }
+
     /// Adds the transaction into the pool.
     ///
     /// This pool consists of four sub-pools: `Queued`, `Pending`, `BaseFee`, and `Blob`.
@@ -755,6 +801,11 @@ impl<T: TransactionOrdering> TxPool<T> {
         id: &TransactionId,
     ) -> Option<Arc<ValidPoolTransaction<T::Transaction>>> {
         let tx = self.remove_from_subpool(from, id)?;
+        if to.is_promoted(from) {
+            self.metrics.promoted_transactions.increment(1);
+        } else {
+            self.metrics.demoted_transactions.increment(1);
+        }
         self.add_transaction_to_subpool(to, tx.clone());
         Some(tx)
     }
@@ -959,6 +1010,18 @@ impl<T: TransactionOrdering> TxPool<T> {
     pub(crate) fn discard_worst(&mut self) -> Vec<Arc<ValidPoolTransaction<T::Transaction>>> {
         let mut removed = Vec::new();
 
+        // Evict pending blob transactions beyond their own limit first, so that blob
+        // transactions can't displace regular pending transactions.
+        let removed_blobs =
+            self.pending_pool.truncate_blob_transactions(&self.config.pending_blob_limit);
+        self.metrics.pending_blob_transactions_evicted.increment(removed_blobs.len() as u64);
+        for tx in removed_blobs {
+            self.all_transactions.remove_transaction(tx.id());
+            let id = *tx.id();
+            removed.push(tx);
+            self.remove_descendants(&id, &mut removed);
+        }
+
         // Helper macro that discards the worst transactions for the pools
         macro_rules! discard_worst {
             ($this:ident, $removed:ident, [$($limit:ident => ($pool:ident, $metric:ident)),* $(,)*]) => {
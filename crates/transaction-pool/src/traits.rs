@@ -1377,6 +1377,10 @@ pub struct PoolSize {
     pub pending: usize,
     /// Reported size of transactions in the _pending_ sub-pool.
     pub pending_size: usize,
+    /// Number of blob transactions in the _pending_ sub-pool, included in `pending`.
+    pub pending_blob: usize,
+    /// Reported size of blob transactions in the _pending_ sub-pool, included in `pending_size`.
+    pub pending_blob_size: usize,
     /// Number of transactions in the _blob_ pool.
     pub blob: usize,
     /// Reported size of transactions in the _blob_ pool.
@@ -1509,6 +1513,8 @@ mod tests {
         let pool_size = PoolSize {
             pending: 10,
             pending_size: 1000,
+            pending_blob: 2,
+            pending_blob_size: 200,
             blob: 5,
             blob_size: 500,
             basefee: 8,
@@ -1528,6 +1534,8 @@ mod tests {
         let pool_size = PoolSize {
             pending: 10,
             pending_size: 1000,
+            pending_blob: 2,
+            pending_blob_size: 200,
             blob: 5,
             blob_size: 500,
             basefee: 8,
@@ -0,0 +1,113 @@
+//! Example of a minimal RPC-only binary, e.g. for read replicas of a node's database.
+//!
+//! The binary doesn't run a network, an engine or a transaction pool, the pool and the network of
+//! the RPC are noops. The RPC is served over the database of a running node, that keeps syncing
+//! the chain.
+//!
+//! Run with
+//!
+//! ```sh
+//! RETH_DB_PATH=<datadir> cargo run -p example-rpc-only --bin reth-rpc-only
+//! ```
+//!
+//! The server listens on `127.0.0.1:8545` for the mainnet database by default, `RETH_RPC_ADDR`
+//! and `RETH_CHAIN` (`mainnet`, `sepolia` or `holesky`) override the address and the chain.
+
+#![warn(unused_crate_dependencies)]
+
+use reth_ethereum::{
+    chainspec::{ChainSpec, HOLESKY, MAINNET, SEPOLIA},
+    consensus::EthBeaconConsensus,
+    evm::{execute::EthExecutorProvider, EthEvmConfig},
+    provider::{
+        db::{mdbx::DatabaseArguments, open_db_read_only, ClientVersion, DatabaseEnv},
+        providers::{BlockchainProvider, StaticFileProvider},
+        ProviderFactory,
+    },
+    rpc::{
+        builder::{RethRpcModule, RpcModuleBuilder, RpcServerConfig, TransportRpcModuleConfig},
+        EthApiBuilder,
+    },
+    storage::EthStorage,
+    EthPrimitives,
+};
+use reth_network_api::noop::NoopNetwork;
+use reth_node_types::{NodeTypes, NodeTypesWithDBAdapter};
+use reth_tasks::TokioTaskExecutor;
+use reth_transaction_pool::noop::NoopTransactionPool;
+use reth_trie_db::MerklePatriciaTrie;
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    path::Path,
+    sync::Arc,
+};
+
+/// The types of an ethereum node's database, without any of the node's components.
+#[derive(Debug, Clone, Default)]
+struct RpcOnlyNode;
+
+impl NodeTypes for RpcOnlyNode {
+    type Primitives = EthPrimitives;
+    type ChainSpec = ChainSpec;
+    type StateCommitment = MerklePatriciaTrie;
+    type Storage = EthStorage;
+}
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    // 1. Open the database of the node read-only
+    let db_path = std::env::var("RETH_DB_PATH")?;
+    let db_path = Path::new(&db_path);
+    let db = Arc::new(open_db_read_only(
+        db_path.join("db").as_path(),
+        DatabaseArguments::new(ClientVersion::default()),
+    )?);
+    let spec = match std::env::var("RETH_CHAIN").as_deref() {
+        Ok("mainnet") | Err(_) => MAINNET.clone(),
+        Ok("sepolia") => SEPOLIA.clone(),
+        Ok("holesky") => HOLESKY.clone(),
+        Ok(chain) => eyre::bail!("unsupported chain: {chain}"),
+    };
+    let factory = ProviderFactory::<NodeTypesWithDBAdapter<RpcOnlyNode, Arc<DatabaseEnv>>>::new(
+        db,
+        spec.clone(),
+        StaticFileProvider::read_only(db_path.join("static_files"), true)?,
+    );
+    let provider = BlockchainProvider::new(factory)?;
+
+    // 2. Set up the RPC modules, the pool and the network are noops
+    let rpc_builder = RpcModuleBuilder::default()
+        .with_provider(provider.clone())
+        .with_noop_pool()
+        .with_noop_network()
+        .with_executor(TokioTaskExecutor::default())
+        .with_evm_config(EthEvmConfig::new(spec.clone()))
+        .with_block_executor(EthExecutorProvider::ethereum(spec.clone()))
+        .with_consensus(EthBeaconConsensus::new(spec.clone()));
+
+    let eth_api = EthApiBuilder::new(
+        provider,
+        NoopTransactionPool::default(),
+        NoopNetwork::default(),
+        EthEvmConfig::new(spec),
+    )
+    .build();
+
+    let config = TransportRpcModuleConfig::default().with_http([
+        RethRpcModule::Eth,
+        RethRpcModule::Net,
+        RethRpcModule::Web3,
+    ]);
+    let server = rpc_builder.build(config, eth_api);
+
+    // 3. Start the server and keep it alive
+    let addr = match std::env::var("RETH_RPC_ADDR") {
+        Ok(addr) => addr.parse()?,
+        Err(_) => SocketAddr::from((Ipv4Addr::LOCALHOST, 8545)),
+    };
+    let server_config = RpcServerConfig::http(Default::default()).with_http_address(addr);
+    let _handle = server_config.start(&server).await?;
+    futures::future::pending::<()>().await;
+
+    Ok(())
+}